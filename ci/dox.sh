@@ -52,4 +52,5 @@ dox powerpc powerpc-unknown-linux-gnu
 dox powerpc64le powerpc64le-unknown-linux-gnu
 dox mips mips-unknown-linux-gnu
 dox mips64 mips64-unknown-linux-gnuabi64
+dox riscv64 riscv64gc-unknown-linux-gnu
 dox wasm32 wasm32-unknown-unknown
@@ -0,0 +1,52 @@
+#!/usr/bin/env sh
+
+# Runs the tests of the x86 intrinsics that are implemented with platform
+# intrinsics Miri understands (see the "Miri" section of
+# `crates/core_arch/src/core_arch_docs.md`) under Miri.
+
+set -ex
+
+# Under Miri only the target features enabled at compile time are detected,
+# so enable the ones needed by the tests below.
+export RUSTFLAGS="${RUSTFLAGS} -C target-feature=+ssse3,+avx2"
+
+cargo miri test --manifest-path crates/core_arch/Cargo.toml -- \
+    x86::sse2::tests::test_mm_add_epi \
+    x86::sse2::tests::test_mm_adds_ \
+    x86::sse2::tests::test_mm_sub_epi \
+    x86::sse2::tests::test_mm_subs_ \
+    x86::sse2::tests::test_mm_avg_ \
+    x86::sse2::tests::test_mm_max_ep \
+    x86::sse2::tests::test_mm_min_ep \
+    x86::sse2::tests::test_mm_cmpeq_epi \
+    x86::sse2::tests::test_mm_cmpgt_epi \
+    x86::sse2::tests::test_mm_cmplt_epi \
+    x86::sse2::tests::test_mm_slli_epi \
+    x86::sse2::tests::test_mm_srli_epi \
+    x86::sse2::tests::test_mm_srai_epi \
+    x86::sse2::tests::test_mm_and_si128 \
+    x86::sse2::tests::test_mm_andnot_si128 \
+    x86::sse2::tests::test_mm_or_si128 \
+    x86::sse2::tests::test_mm_xor_si128 \
+    x86::sse2::tests::test_mm_movemask_epi8 \
+    x86::sse2::tests::test_mm_shuffle_epi32 \
+    x86::sse2::tests::test_mm_unpack \
+    x86::ssse3::tests::test_mm_shuffle_epi8 \
+    x86::avx2::tests::test_mm256_add_epi \
+    x86::avx2::tests::test_mm256_adds_ \
+    x86::avx2::tests::test_mm256_sub_epi \
+    x86::avx2::tests::test_mm256_subs_ \
+    x86::avx2::tests::test_mm256_avg_ \
+    x86::avx2::tests::test_mm256_max_ep \
+    x86::avx2::tests::test_mm256_min_ep \
+    x86::avx2::tests::test_mm256_cmpeq_epi \
+    x86::avx2::tests::test_mm256_cmpgt_epi \
+    x86::avx2::tests::test_mm256_slli_epi \
+    x86::avx2::tests::test_mm256_srli_epi \
+    x86::avx2::tests::test_mm256_srai_epi \
+    x86::avx2::tests::test_mm256_and_si256 \
+    x86::avx2::tests::test_mm256_andnot_si256 \
+    x86::avx2::tests::test_mm256_or_si256 \
+    x86::avx2::tests::test_mm256_xor_si256 \
+    x86::avx2::tests::test_mm256_movemask_epi8 \
+    x86::avx2::tests::test_mm256_shuffle_epi8
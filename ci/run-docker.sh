@@ -23,6 +23,7 @@ run() {
       --env STDARCH_DISABLE_ASSERT_INSTR \
       --env NOSTD \
       --env NORUN \
+      --env CARGO_UNSTABLE_BUILD_STD \
       --env RUSTFLAGS \
       --env STDARCH_TEST_NORUN \
       --volume "$(dirname "$(dirname "$(command -v cargo)")")":/cargo \
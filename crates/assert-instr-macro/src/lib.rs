@@ -141,7 +141,7 @@ pub fn assert_instr(
             // calls functions. Turns out functions aren't similar enough to get
             // merged on wasm32 anyway. This bug is tracked at
             // rust-lang/rust#74320.
-            #[cfg(not(target_arch = "wasm32"))]
+            #[cfg(not(any(target_arch = "wasm32", target_arch = "wasm64")))]
             ::stdarch_test::_DONT_DEDUP.store(
                 std::mem::transmute(#shim_name_str.as_bytes().as_ptr()),
                 std::sync::atomic::Ordering::Relaxed,
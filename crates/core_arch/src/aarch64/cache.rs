@@ -0,0 +1,125 @@
+//! Cache maintenance and data cache zeroing.
+//!
+//! Whether these can be executed at EL0 is controlled by the OS: DC ZVA
+//! needs `SCTLR_EL1.DZE` to be set, which [`__dczid_el0`] reports, and DC
+//! CVAC, DC CIVAC and IC IVAU need `SCTLR_EL1.UCI` to be set. Otherwise they
+//! trap, which Linux for example reports as `SIGILL`. Linux allows all of
+//! them.
+//!
+//! The reference is the "Cache maintenance instructions" section of the
+//! [Arm Architecture Reference Manual][arm].
+//!
+//! [arm]: https://developer.arm.com/documentation/ddi0487/latest
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Returns the value of the DCZID_EL0 register, which describes DC ZVA.
+///
+/// Bits 3 to 0 are the log2 of the size of the block zeroed by
+/// [`__dc_zva`] in 4-byte words, and bit 4 is set if DC ZVA is prohibited.
+/// This is always accessible at EL0.
+#[inline]
+#[cfg_attr(test, assert_instr(mrs))]
+pub unsafe fn __dczid_el0() -> u64 {
+    super::__rsr64(super::DCZID_EL0)
+}
+
+/// Zeroes the block of memory containing `addr`, whose size and alignment
+/// are given by [`__dczid_el0`].
+///
+/// DC ZVA must not be prohibited, see [`__dczid_el0`].
+#[inline]
+#[cfg_attr(test, assert_instr(dc))]
+pub unsafe fn __dc_zva(addr: *mut u8) {
+    asm!("dc zva, {}", in(reg) addr, options(nostack, preserves_flags));
+}
+
+/// Cleans the data cache line containing `addr` to the point of coherency,
+/// writing it back to memory if it is dirty.
+#[inline]
+#[cfg_attr(test, assert_instr(dc))]
+pub unsafe fn __dc_cvac(addr: *const u8) {
+    asm!("dc cvac, {}", in(reg) addr, options(nostack, preserves_flags));
+}
+
+/// Cleans and invalidates the data cache line containing `addr` to the point
+/// of coherency.
+#[inline]
+#[cfg_attr(test, assert_instr(dc))]
+pub unsafe fn __dc_civac(addr: *const u8) {
+    asm!("dc civac, {}", in(reg) addr, options(nostack, preserves_flags));
+}
+
+/// Invalidates the instruction cache line containing `addr` to the point of
+/// unification.
+///
+/// Making code written to memory executable also needs the data cache to be
+/// cleaned to the point of unification, and barriers in between.
+#[inline]
+#[cfg_attr(test, assert_instr(ic))]
+pub unsafe fn __ic_ivau(addr: *const u8) {
+    asm!("ic ivau, {}", in(reg) addr, options(nostack, preserves_flags));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::aarch64::*;
+
+    // Three blocks of 2KiB, the largest size DCZID_EL0 can describe.
+    #[repr(C, align(2048))]
+    struct Blocks([u8; 3 * 2048]);
+
+    #[test]
+    fn test_dczid_el0() {
+        unsafe {
+            let dczid = __dczid_el0();
+            assert!(dczid & 0xf <= 9);
+            assert_eq!(dczid >> 5, 0);
+        }
+    }
+
+    #[test]
+    fn test_dc_zva() {
+        unsafe {
+            let dczid = __dczid_el0();
+            if dczid & (1 << 4) != 0 {
+                // DC ZVA is prohibited.
+                return;
+            }
+            let size: usize = 4 << (dczid & 0xf);
+            let mut blocks = Blocks([0xff; 3 * 2048]);
+            // Zeroes the whole block containing the address.
+            __dc_zva(blocks.0.as_mut_ptr().add(2048 + size / 2));
+            for (i, b) in blocks.0.iter().enumerate() {
+                let zeroed = i >= 2048 && i < 2048 + size;
+                assert_eq!(*b, if zeroed { 0 } else { 0xff }, "at {}", i);
+            }
+        }
+    }
+
+    #[test]
+    fn test_dc_cvac() {
+        unsafe {
+            let x = [1_u8; 64];
+            __dc_cvac(x.as_ptr());
+            assert_eq!(x, [1; 64]);
+        }
+    }
+
+    #[test]
+    fn test_dc_civac() {
+        unsafe {
+            let x = [1_u8; 64];
+            __dc_civac(x.as_ptr());
+            assert_eq!(x, [1; 64]);
+        }
+    }
+
+    #[test]
+    fn test_ic_ivau() {
+        unsafe {
+            __ic_ivau(test_ic_ivau as *const u8);
+        }
+    }
+}
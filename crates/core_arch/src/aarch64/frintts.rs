@@ -0,0 +1,198 @@
+//! Floating-point rounding to 32-bit and 64-bit integers (FEAT_FRINTTS).
+//!
+//! These round to an integral value like `frintx` and `frintz`, but return
+//! the most negative integer of the 32-bit or 64-bit range, still as a
+//! floating-point value, when the result doesn't fit it, including for NaNs
+//! and infinities. The vector forms are the `vrnd32` and `vrnd64` NEON
+//! intrinsics.
+//!
+//! The reference is the "Floating-point data-processing intrinsics" section
+//! of [ACLE][acle].
+//!
+//! [acle]: https://github.com/ARM-software/acle
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+extern "C" {
+    #[link_name = "llvm.aarch64.neon.frint32x.f32"]
+    fn frint32x_f32(a: f32) -> f32;
+    #[link_name = "llvm.aarch64.neon.frint32x.f64"]
+    fn frint32x_f64(a: f64) -> f64;
+    #[link_name = "llvm.aarch64.neon.frint32z.f32"]
+    fn frint32z_f32(a: f32) -> f32;
+    #[link_name = "llvm.aarch64.neon.frint32z.f64"]
+    fn frint32z_f64(a: f64) -> f64;
+    #[link_name = "llvm.aarch64.neon.frint64x.f32"]
+    fn frint64x_f32(a: f32) -> f32;
+    #[link_name = "llvm.aarch64.neon.frint64x.f64"]
+    fn frint64x_f64(a: f64) -> f64;
+    #[link_name = "llvm.aarch64.neon.frint64z.f32"]
+    fn frint64z_f32(a: f32) -> f32;
+    #[link_name = "llvm.aarch64.neon.frint64z.f64"]
+    fn frint64z_f64(a: f64) -> f64;
+}
+
+/// Rounds `a` to a 32-bit integer, using the current rounding mode.
+#[inline]
+#[target_feature(enable = "frintts")]
+#[cfg_attr(test, assert_instr(frint32x))]
+pub unsafe fn __rint32xf(a: f32) -> f32 {
+    frint32x_f32(a)
+}
+
+/// Rounds `a` to a 32-bit integer, using the current rounding mode.
+#[inline]
+#[target_feature(enable = "frintts")]
+#[cfg_attr(test, assert_instr(frint32x))]
+pub unsafe fn __rint32x(a: f64) -> f64 {
+    frint32x_f64(a)
+}
+
+/// Rounds `a` to a 32-bit integer, toward zero.
+#[inline]
+#[target_feature(enable = "frintts")]
+#[cfg_attr(test, assert_instr(frint32z))]
+pub unsafe fn __rint32zf(a: f32) -> f32 {
+    frint32z_f32(a)
+}
+
+/// Rounds `a` to a 32-bit integer, toward zero.
+#[inline]
+#[target_feature(enable = "frintts")]
+#[cfg_attr(test, assert_instr(frint32z))]
+pub unsafe fn __rint32z(a: f64) -> f64 {
+    frint32z_f64(a)
+}
+
+/// Rounds `a` to a 64-bit integer, using the current rounding mode.
+#[inline]
+#[target_feature(enable = "frintts")]
+#[cfg_attr(test, assert_instr(frint64x))]
+pub unsafe fn __rint64xf(a: f32) -> f32 {
+    frint64x_f32(a)
+}
+
+/// Rounds `a` to a 64-bit integer, using the current rounding mode.
+#[inline]
+#[target_feature(enable = "frintts")]
+#[cfg_attr(test, assert_instr(frint64x))]
+pub unsafe fn __rint64x(a: f64) -> f64 {
+    frint64x_f64(a)
+}
+
+/// Rounds `a` to a 64-bit integer, toward zero.
+#[inline]
+#[target_feature(enable = "frintts")]
+#[cfg_attr(test, assert_instr(frint64z))]
+pub unsafe fn __rint64zf(a: f32) -> f32 {
+    frint64z_f32(a)
+}
+
+/// Rounds `a` to a 64-bit integer, toward zero.
+#[inline]
+#[target_feature(enable = "frintts")]
+#[cfg_attr(test, assert_instr(frint64z))]
+pub unsafe fn __rint64z(a: f64) -> f64 {
+    frint64z_f64(a)
+}
+
+#[cfg(test)]
+mod tests {
+    use stdarch_test::simd_test;
+
+    use crate::core_arch::aarch64::*;
+
+    const MIN32: f64 = i32::MIN as f64;
+    const MIN64: f64 = i64::MIN as f64;
+
+    #[simd_test(enable = "frintts")]
+    unsafe fn test_rint32xf() {
+        // Ties round to even in the default rounding mode.
+        assert_eq!(__rint32xf(2.5), 2.0);
+        assert_eq!(__rint32xf(-2.7), -3.0);
+        assert!(__rint32xf(-0.5).is_sign_negative());
+        assert_eq!(__rint32xf(2147483520.0), 2147483520.0);
+        assert_eq!(__rint32xf(2147483648.0), MIN32 as f32);
+        assert_eq!(__rint32xf(f32::NAN), MIN32 as f32);
+        assert_eq!(__rint32xf(f32::NEG_INFINITY), MIN32 as f32);
+    }
+
+    #[simd_test(enable = "frintts")]
+    unsafe fn test_rint32x() {
+        assert_eq!(__rint32x(2.5), 2.0);
+        assert_eq!(__rint32x(-2.7), -3.0);
+        // Rounds up to 2^31, which doesn't fit.
+        assert_eq!(__rint32x(2147483647.5), MIN32);
+        assert_eq!(__rint32x(-2147483648.4), MIN32);
+        assert_eq!(__rint32x(f64::NAN), MIN32);
+        assert_eq!(__rint32x(f64::INFINITY), MIN32);
+    }
+
+    #[simd_test(enable = "frintts")]
+    unsafe fn test_rint32zf() {
+        assert_eq!(__rint32zf(2.5), 2.0);
+        assert_eq!(__rint32zf(-2.7), -2.0);
+        assert!(__rint32zf(-0.5).is_sign_negative());
+        assert_eq!(__rint32zf(2147483520.0), 2147483520.0);
+        assert_eq!(__rint32zf(2147483648.0), MIN32 as f32);
+        assert_eq!(__rint32zf(f32::NAN), MIN32 as f32);
+        assert_eq!(__rint32zf(f32::INFINITY), MIN32 as f32);
+    }
+
+    #[simd_test(enable = "frintts")]
+    unsafe fn test_rint32z() {
+        assert_eq!(__rint32z(2.5), 2.0);
+        assert_eq!(__rint32z(-2.7), -2.0);
+        // Unlike with `__rint32x`, these fit after rounding.
+        assert_eq!(__rint32z(2147483647.5), 2147483647.0);
+        assert_eq!(__rint32z(-2147483648.9), MIN32);
+        assert_eq!(__rint32z(2147483648.0), MIN32);
+        assert_eq!(__rint32z(f64::NAN), MIN32);
+        assert_eq!(__rint32z(f64::NEG_INFINITY), MIN32);
+    }
+
+    #[simd_test(enable = "frintts")]
+    unsafe fn test_rint64xf() {
+        assert_eq!(__rint64xf(2.5), 2.0);
+        assert_eq!(__rint64xf(-2.7), -3.0);
+        assert_eq!(__rint64xf(3000000000.0), 3000000000.0);
+        assert_eq!(__rint64xf(9223371487098961920.0), 9223371487098961920.0);
+        assert_eq!(__rint64xf(9223372036854775808.0), MIN64 as f32);
+        assert_eq!(__rint64xf(f32::NAN), MIN64 as f32);
+        assert_eq!(__rint64xf(f32::INFINITY), MIN64 as f32);
+    }
+
+    #[simd_test(enable = "frintts")]
+    unsafe fn test_rint64x() {
+        assert_eq!(__rint64x(2.5), 2.0);
+        assert_eq!(__rint64x(-2.7), -3.0);
+        assert_eq!(__rint64x(3000000000.5), 3000000000.0);
+        assert_eq!(__rint64x(9223372036854774784.0), 9223372036854774784.0);
+        assert_eq!(__rint64x(9223372036854775808.0), MIN64);
+        assert_eq!(__rint64x(f64::NAN), MIN64);
+        assert_eq!(__rint64x(f64::NEG_INFINITY), MIN64);
+    }
+
+    #[simd_test(enable = "frintts")]
+    unsafe fn test_rint64zf() {
+        assert_eq!(__rint64zf(2.5), 2.0);
+        assert_eq!(__rint64zf(-2.7), -2.0);
+        assert_eq!(__rint64zf(3000000000.0), 3000000000.0);
+        assert_eq!(__rint64zf(-9223372036854775808.0), MIN64 as f32);
+        assert_eq!(__rint64zf(1.0e19), MIN64 as f32);
+        assert_eq!(__rint64zf(f32::NAN), MIN64 as f32);
+        assert_eq!(__rint64zf(f32::NEG_INFINITY), MIN64 as f32);
+    }
+
+    #[simd_test(enable = "frintts")]
+    unsafe fn test_rint64z() {
+        assert_eq!(__rint64z(2.5), 2.0);
+        assert_eq!(__rint64z(-2.7), -2.0);
+        assert_eq!(__rint64z(3000000000.5), 3000000000.0);
+        assert_eq!(__rint64z(9223372036854774784.0), 9223372036854774784.0);
+        assert_eq!(__rint64z(1.0e19), MIN64);
+        assert_eq!(__rint64z(f64::NAN), MIN64);
+        assert_eq!(__rint64z(f64::INFINITY), MIN64);
+    }
+}
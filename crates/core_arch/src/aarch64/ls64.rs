@@ -0,0 +1,174 @@
+//! 64-byte loads and stores (FEAT_LS64).
+//!
+//! These instructions transfer 64 bytes between memory and eight general
+//! purpose registers in a single access, which is how descriptors are written
+//! to and read from accelerators. The accesses are only single-copy atomic
+//! for device memory that supports them. For other memory types they aren't
+//! guaranteed to be atomic, and depending on the memory type and the CPU they
+//! may fault.
+//!
+//! The reference is the "64-byte load and store intrinsics" section of
+//! [ACLE][acle].
+//!
+//! [acle]: https://github.com/ARM-software/acle
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// The 64 bytes transferred by the LS64 intrinsics.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[allow(non_camel_case_types)]
+pub struct data512_t {
+    /// The eight 64-bit words, in increasing address order.
+    pub val: [u64; 8],
+}
+
+// The words are transferred through eight consecutive registers starting at
+// an even one, so they are always passed in x0 to x7. The status register
+// must not overlap with them.
+macro_rules! st64b {
+    ($instr:literal, $addr:expr, $value:expr $(, $status:ident)?) => {{
+        let v = $value.val;
+        asm!(
+            concat!($instr, " ", $("{", stringify!($status), "}, ",)? "x0, [{addr}]"),
+            addr = in(reg) $addr,
+            $($status = out(reg) $status,)?
+            in("x0") v[0],
+            in("x1") v[1],
+            in("x2") v[2],
+            in("x3") v[3],
+            in("x4") v[4],
+            in("x5") v[5],
+            in("x6") v[6],
+            in("x7") v[7],
+            options(nostack, preserves_flags),
+        );
+    }};
+}
+
+/// Loads 64 bytes from `addr` with a single LD64B instruction.
+///
+/// `addr` has to be 64-byte aligned. The load is only single-copy atomic for
+/// device memory that supports it.
+#[inline]
+#[target_feature(enable = "ls64")]
+#[cfg_attr(test, assert_instr(ld64b))]
+pub unsafe fn __arm_ld64b(addr: *const u8) -> data512_t {
+    let mut val = [0_u64; 8];
+    asm!(
+        "ld64b x0, [{addr}]",
+        addr = in(reg) addr,
+        out("x0") val[0],
+        out("x1") val[1],
+        out("x2") val[2],
+        out("x3") val[3],
+        out("x4") val[4],
+        out("x5") val[5],
+        out("x6") val[6],
+        out("x7") val[7],
+        options(nostack, preserves_flags),
+    );
+    data512_t { val }
+}
+
+/// Stores the 64 bytes of `value` to `addr` with a single ST64B instruction.
+///
+/// `addr` has to be 64-byte aligned. The store is only single-copy atomic for
+/// device memory that supports it.
+#[inline]
+#[target_feature(enable = "ls64")]
+#[cfg_attr(test, assert_instr(st64b))]
+pub unsafe fn __arm_st64b(addr: *mut u8, value: data512_t) {
+    st64b!("st64b", addr, value)
+}
+
+/// Stores the 64 bytes of `value` to `addr` with a single ST64BV instruction
+/// and returns the status the device responded with.
+///
+/// `addr` has to be 64-byte aligned. The store is only single-copy atomic for
+/// device memory that supports it, and the meaning of the status is defined
+/// by the device.
+#[inline]
+#[target_feature(enable = "ls64")]
+#[cfg_attr(test, assert_instr(st64bv))]
+pub unsafe fn __arm_st64bv(addr: *mut u8, value: data512_t) -> u64 {
+    let status: u64;
+    st64b!("st64bv", addr, value, status);
+    status
+}
+
+/// Stores the 64 bytes of `value` to `addr` with a single ST64BV0
+/// instruction and returns the status the device responded with.
+///
+/// Unlike [`__arm_st64bv`], this replaces the low 32 bits of the first word
+/// with those of the ACCDATA_EL1 register, which is set up by the OS, for
+/// example to identify the process to the device.
+///
+/// `addr` has to be 64-byte aligned. The store is only single-copy atomic for
+/// device memory that supports it, and the meaning of the status is defined
+/// by the device.
+#[inline]
+#[target_feature(enable = "ls64")]
+#[cfg_attr(test, assert_instr(st64bv0))]
+pub unsafe fn __arm_st64bv0(addr: *mut u8, value: data512_t) -> u64 {
+    let status: u64;
+    st64b!("st64bv0", addr, value, status);
+    status
+}
+
+#[cfg(test)]
+mod tests {
+    use stdarch_test::simd_test;
+
+    use crate::core_arch::aarch64::*;
+
+    // The accesses are only atomic for device memory, but the data has to
+    // round-trip through normal memory as well.
+    #[repr(C, align(64))]
+    struct Block([u64; 8]);
+
+    const DATA: data512_t = data512_t {
+        val: [
+            0x0011223344556677,
+            0x8899aabbccddeeff,
+            0x0123456789abcdef,
+            0xfedcba9876543210,
+            1,
+            2,
+            u64::MAX,
+            0,
+        ],
+    };
+
+    #[simd_test(enable = "ls64")]
+    unsafe fn test_arm_ld64b() {
+        let block = Block(DATA.val);
+        assert_eq!(__arm_ld64b(block.0.as_ptr() as *const u8), DATA);
+    }
+
+    #[simd_test(enable = "ls64")]
+    unsafe fn test_arm_st64b() {
+        let mut block = Block([0; 8]);
+        __arm_st64b(block.0.as_mut_ptr() as *mut u8, DATA);
+        assert_eq!(block.0, DATA.val);
+        assert_eq!(__arm_ld64b(block.0.as_ptr() as *const u8), DATA);
+    }
+
+    #[simd_test(enable = "ls64")]
+    unsafe fn test_arm_st64bv() {
+        let mut block = Block([0; 8]);
+        // Normal memory doesn't respond with a meaningful status.
+        let _ = __arm_st64bv(block.0.as_mut_ptr() as *mut u8, DATA);
+        assert_eq!(block.0, DATA.val);
+    }
+
+    #[simd_test(enable = "ls64")]
+    unsafe fn test_arm_st64bv0() {
+        let mut block = Block([0; 8]);
+        let _ = __arm_st64bv0(block.0.as_mut_ptr() as *mut u8, DATA);
+        // The low half of the first word comes from ACCDATA_EL1.
+        assert_eq!(block.0[0] >> 32, DATA.val[0] >> 32);
+        assert_eq!(block.0[1..], DATA.val[1..]);
+    }
+}
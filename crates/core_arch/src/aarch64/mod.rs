@@ -15,12 +15,36 @@ pub use self::neon::*;
 mod tme;
 pub use self::tme::*;
 
-mod crc;
-pub use self::crc::*;
-
 mod prefetch;
 pub use self::prefetch::*;
 
+mod rand;
+pub use self::rand::*;
+
+mod mte;
+pub use self::mte::*;
+
+mod ls64;
+pub use self::ls64::*;
+
+mod pauth;
+pub use self::pauth::*;
+
+mod frintts;
+pub use self::frintts::*;
+
+mod cache;
+pub use self::cache::*;
+
+mod sve;
+pub use self::sve::*;
+
+mod sme;
+pub use self::sme::*;
+
+mod wfxt;
+pub use self::wfxt::*;
+
 pub use super::arm_shared::*;
 
 mod armclang;
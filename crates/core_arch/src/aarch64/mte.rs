@@ -0,0 +1,280 @@
+//! ARM's Memory Tagging Extension (MTE).
+//!
+//! MTE associates a 4-bit allocation tag with every 16-byte granule of
+//! tagged memory, and a 4-bit logical tag with pointers, stored in bits 56 to
+//! 59 of the address. Depending on the configuration of the OS, accessing
+//! memory through a pointer whose logical tag differs from the allocation tag
+//! of the memory faults.
+//!
+//! The reference is the "Memory tagging intrinsics" section of
+//! [ACLE][acle]. Like the ACLE intrinsics, these are generic over the type
+//! the pointers point to.
+//!
+//! [acle]: https://github.com/ARM-software/acle
+
+extern "C" {
+    #[link_name = "llvm.aarch64.irg"]
+    fn irg_(ptr: *const u8, exclude: i64) -> *const u8;
+    #[link_name = "llvm.aarch64.addg"]
+    fn addg_(ptr: *const u8, value: i64) -> *const u8;
+    #[link_name = "llvm.aarch64.gmi"]
+    fn gmi_(ptr: *const u8, exclude: i64) -> i64;
+    #[link_name = "llvm.aarch64.ldg"]
+    fn ldg_(ptr: *const u8, tag_ptr: *const u8) -> *const u8;
+    #[link_name = "llvm.aarch64.stg"]
+    fn stg_(tagged_ptr: *const u8, addr_to_tag: *const u8);
+    #[link_name = "llvm.aarch64.subp"]
+    fn subp_(ptr_a: *const u8, ptr_b: *const u8) -> i64;
+}
+
+/// Returns `src` with a random logical tag which is neither in the set of
+/// tags excluded by the OS nor in `mask`, a bitmask with bit `n` set to
+/// exclude tag `n`. If all tags are excluded the tag is 0.
+///
+/// The memory `src` points to doesn't need to be tagged.
+#[inline]
+#[target_feature(enable = "mte")]
+pub unsafe fn __arm_mte_create_random_tag<T>(src: *const T, mask: u64) -> *const T {
+    irg_(src as *const u8, mask as i64) as *const T
+}
+
+/// Returns `src` with `OFFSET` added to its logical tag, modulo 16, skipping
+/// the tags excluded by the OS.
+///
+/// The memory `src` points to doesn't need to be tagged.
+#[inline]
+#[target_feature(enable = "mte")]
+pub unsafe fn __arm_mte_increment_tag<T, const OFFSET: i32>(src: *const T) -> *const T {
+    static_assert_imm4!(OFFSET);
+    addg_(src as *const u8, OFFSET as i64) as *const T
+}
+
+/// Adds the logical tag of `src` to the set of excluded tags `excluded`, a
+/// bitmask with bit `n` set to exclude tag `n`, and returns the new set, to
+/// be passed to [`__arm_mte_create_random_tag`].
+#[inline]
+#[target_feature(enable = "mte")]
+pub unsafe fn __arm_mte_exclude_tag<T>(src: *const T, excluded: u64) -> u64 {
+    gmi_(src as *const u8, excluded as i64) as u64
+}
+
+/// Sets the allocation tag of the 16-byte aligned granule `tag_address`
+/// points to to the logical tag of `tag_address`.
+///
+/// `tag_address` has to be 16-byte aligned and point to tagged memory.
+#[inline]
+#[target_feature(enable = "mte")]
+pub unsafe fn __arm_mte_set_tag<T>(tag_address: *const T) {
+    stg_(tag_address as *const u8, tag_address as *const u8)
+}
+
+/// Returns `address` with its logical tag replaced with the allocation tag
+/// of the granule it points into.
+///
+/// `address` has to point to tagged memory.
+#[inline]
+#[target_feature(enable = "mte")]
+pub unsafe fn __arm_mte_get_tag<T>(address: *const T) -> *const T {
+    ldg_(address as *const u8, address as *const u8) as *const T
+}
+
+/// Returns the difference between the addresses of `a` and `b` in bytes,
+/// ignoring their logical tags.
+#[inline]
+#[target_feature(enable = "mte")]
+pub unsafe fn __arm_mte_ptrdiff<T, U>(a: *const T, b: *const U) -> i64 {
+    subp_(a as *const u8, b as *const u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use stdarch_test::{assert_instr, simd_test};
+
+    use crate::core_arch::aarch64::*;
+
+    // The intrinsics are generic, so their instructions are checked on
+    // instantiations of them.
+    #[allow(dead_code)]
+    #[target_feature(enable = "mte")]
+    #[assert_instr(irg)]
+    unsafe fn irg(src: *const (), mask: u64) -> *const () {
+        __arm_mte_create_random_tag(src, mask)
+    }
+
+    #[allow(dead_code)]
+    #[target_feature(enable = "mte")]
+    #[assert_instr(addg)]
+    unsafe fn addg(src: *const ()) -> *const () {
+        __arm_mte_increment_tag::<_, 1>(src)
+    }
+
+    #[allow(dead_code)]
+    #[target_feature(enable = "mte")]
+    #[assert_instr(gmi)]
+    unsafe fn gmi(src: *const (), excluded: u64) -> u64 {
+        __arm_mte_exclude_tag(src, excluded)
+    }
+
+    #[allow(dead_code)]
+    #[target_feature(enable = "mte")]
+    #[assert_instr(stg)]
+    unsafe fn stg(tag_address: *const ()) {
+        __arm_mte_set_tag(tag_address)
+    }
+
+    #[allow(dead_code)]
+    #[target_feature(enable = "mte")]
+    #[assert_instr(ldg)]
+    unsafe fn ldg(address: *const ()) -> *const () {
+        __arm_mte_get_tag(address)
+    }
+
+    #[allow(dead_code)]
+    #[target_feature(enable = "mte")]
+    #[assert_instr(subp)]
+    unsafe fn subp(a: *const (), b: *const ()) -> i64 {
+        __arm_mte_ptrdiff(a, b)
+    }
+
+    fn tag<T>(p: *const T) -> u64 {
+        (p as u64 >> 56) & 0xf
+    }
+
+    fn with_tag<T>(p: *const T, tag: u64) -> *const T {
+        ((p as u64 & !(0xf << 56)) | (tag << 56)) as *const T
+    }
+
+    #[cfg(target_os = "linux")]
+    extern "C" {
+        fn prctl(option: i32, ...) -> i32;
+        fn mmap(addr: *mut u8, len: usize, prot: i32, flags: i32, fd: i32, off: i64) -> *mut u8;
+    }
+
+    /// Makes the OS include all tags for `irg` and `addg`, which it excludes
+    /// by default, without enabling tag check faults. Returns `false` if the
+    /// OS doesn't support this.
+    unsafe fn include_all_tags() -> bool {
+        #[cfg(target_os = "linux")]
+        {
+            const PR_SET_TAGGED_ADDR_CTRL: i32 = 55;
+            const PR_TAGGED_ADDR_ENABLE: u64 = 1 << 0;
+            const PR_MTE_TAG_SHIFT: u64 = 3;
+            let ctrl = PR_TAGGED_ADDR_ENABLE | 0xffff << PR_MTE_TAG_SHIFT;
+            prctl(PR_SET_TAGGED_ADDR_CTRL, ctrl, 0_u64, 0_u64, 0_u64) == 0
+        }
+        #[cfg(not(target_os = "linux"))]
+        {
+            false
+        }
+    }
+
+    /// Maps a page of tagged memory, with all allocation tags 0.
+    unsafe fn tagged_page() -> Option<*mut u8> {
+        #[cfg(target_os = "linux")]
+        {
+            const PROT_READ: i32 = 0x1;
+            const PROT_WRITE: i32 = 0x2;
+            const PROT_MTE: i32 = 0x20;
+            const MAP_PRIVATE: i32 = 0x02;
+            const MAP_ANONYMOUS: i32 = 0x20;
+            let p = mmap(
+                core::ptr::null_mut(),
+                4096,
+                PROT_READ | PROT_WRITE | PROT_MTE,
+                MAP_PRIVATE | MAP_ANONYMOUS,
+                -1,
+                0,
+            );
+            if p as isize == -1 {
+                None
+            } else {
+                Some(p)
+            }
+        }
+        #[cfg(not(target_os = "linux"))]
+        {
+            None
+        }
+    }
+
+    #[simd_test(enable = "mte")]
+    unsafe fn test_arm_mte_create_random_tag() {
+        let x = 0_u64;
+        let p = with_tag(&x as *const u64, 0);
+        let all_tags = include_all_tags();
+        for mask in [0x0000, 0x0001, 0x7fff, 0xfffe, 0xaaaa].iter() {
+            let r = __arm_mte_create_random_tag(p, *mask);
+            assert_eq!(with_tag(r, 0), p);
+            assert!(mask & (1 << tag(r)) == 0 || tag(r) == 0);
+        }
+        assert_eq!(__arm_mte_create_random_tag(p, 0xffff), p);
+        if all_tags {
+            assert_eq!(tag(__arm_mte_create_random_tag(p, 0x7fff)), 15);
+        }
+    }
+
+    #[simd_test(enable = "mte")]
+    unsafe fn test_arm_mte_increment_tag() {
+        if !include_all_tags() {
+            return;
+        }
+        let x = 0_u64;
+        let p = with_tag(&x as *const u64, 0);
+        let r = __arm_mte_increment_tag::<_, 3>(p);
+        assert_eq!(r, with_tag(p, 3));
+        let r = __arm_mte_increment_tag::<_, 15>(r);
+        assert_eq!(r, with_tag(p, 2));
+        let r = __arm_mte_increment_tag::<_, 0>(r);
+        assert_eq!(r, with_tag(p, 2));
+    }
+
+    #[simd_test(enable = "mte")]
+    unsafe fn test_arm_mte_exclude_tag() {
+        let x = 0_u64;
+        let p = &x as *const u64;
+        assert_eq!(__arm_mte_exclude_tag(with_tag(p, 3), 0x0001), 0x0009);
+        assert_eq!(__arm_mte_exclude_tag(with_tag(p, 15), 0x0000), 0x8000);
+        assert_eq!(__arm_mte_exclude_tag(with_tag(p, 0), 0x0001), 0x0001);
+    }
+
+    #[simd_test(enable = "mte")]
+    unsafe fn test_arm_mte_ptrdiff() {
+        let x = [0_u64; 4];
+        let a = with_tag(&x[0] as *const u64, 5);
+        let b = with_tag(&x[3] as *const u64, 9);
+        assert_eq!(__arm_mte_ptrdiff(b, a), 24);
+        assert_eq!(__arm_mte_ptrdiff(a, b), -24);
+        assert_eq!(__arm_mte_ptrdiff(a, with_tag(a, 0)), 0);
+    }
+
+    #[simd_test(enable = "mte")]
+    unsafe fn test_arm_mte_set_tag() {
+        let page = match tagged_page() {
+            Some(page) => page as *const u8,
+            None => return,
+        };
+        // Tag the second granule with 5 and the third with 12.
+        __arm_mte_set_tag(with_tag(page.add(16), 5));
+        __arm_mte_set_tag(with_tag(page.add(32), 12));
+        assert_eq!(__arm_mte_get_tag(page), page);
+        assert_eq!(__arm_mte_get_tag(page.add(16)), with_tag(page.add(16), 5));
+        assert_eq!(__arm_mte_get_tag(page.add(47)), with_tag(page.add(47), 12));
+        assert_eq!(__arm_mte_get_tag(page.add(48)), page.add(48));
+    }
+
+    #[simd_test(enable = "mte")]
+    unsafe fn test_arm_mte_get_tag() {
+        let page = match tagged_page() {
+            Some(page) => page as *const u8,
+            None => return,
+        };
+        // The logical tag of the address passed in doesn't matter.
+        assert_eq!(__arm_mte_get_tag(with_tag(page, 7)), page);
+        for t in 0..16 {
+            let p = with_tag(page.add(64), t);
+            __arm_mte_set_tag(p);
+            assert_eq!(__arm_mte_get_tag(with_tag(p, 0)), p);
+            assert_eq!(__arm_mte_get_tag(page.add(72)), with_tag(page.add(72), t));
+        }
+    }
+}
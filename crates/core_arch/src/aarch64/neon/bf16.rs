@@ -0,0 +1,355 @@
+//! BFloat16 intrinsics (FEAT_BF16)
+//!
+//! Rust doesn't have a `bfloat16` type, so the elements of `bfloat16x4_t`
+//! and `bfloat16x8_t` are the `u16` bit patterns of `bfloat16` values, which
+//! are the upper halves of the bit patterns of the `f32` values with the same
+//! sign, exponent and leading mantissa bits. The types are only meant for
+//! storage and for passing values to the intrinsics here; converting from
+//! `f32` rounds to nearest with ties to even.
+
+use crate::{
+    core_arch::{aarch64::neon::*, arm_shared::*, simd_llvm::*},
+    mem::transmute,
+};
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+// LLVM models these instructions on vectors of `bfloat`, which doesn't have a
+// Rust equivalent that can cross the FFI boundary, so they're implemented
+// with inline assembly instead.
+macro_rules! bf16_acc {
+    ($inst:expr, $acc:expr, $a:expr, $b:expr) => {{
+        let mut acc = $acc;
+        asm!(
+            $inst,
+            acc = inout(vreg) acc,
+            a = in(vreg) $a,
+            b = in(vreg) $b,
+            options(pure, nomem, nostack, preserves_flags),
+        );
+        acc
+    }};
+}
+
+/// Floating-point convert from single-precision to BFloat16 format
+#[inline]
+#[target_feature(enable = "neon,bf16")]
+#[cfg_attr(test, assert_instr(bfcvtn))]
+pub unsafe fn vcvt_bf16_f32(a: float32x4_t) -> bfloat16x4_t {
+    let dst: bfloat16x4_t;
+    asm!(
+        "bfcvtn {dst:v}.4h, {a:v}.4s",
+        dst = lateout(vreg) dst,
+        a = in(vreg) a,
+        options(pure, nomem, nostack, preserves_flags),
+    );
+    dst
+}
+
+/// Floating-point convert from single-precision to BFloat16 format, into the
+/// lower half of the result with the upper half set to zero
+#[inline]
+#[target_feature(enable = "neon,bf16")]
+#[cfg_attr(test, assert_instr(bfcvtn))]
+pub unsafe fn vcvtq_low_bf16_f32(a: float32x4_t) -> bfloat16x8_t {
+    let dst: bfloat16x8_t;
+    // Writing the lower 64 bits of a vector register clears the upper ones.
+    asm!(
+        "bfcvtn {dst:v}.4h, {a:v}.4s",
+        dst = lateout(vreg) dst,
+        a = in(vreg) a,
+        options(pure, nomem, nostack, preserves_flags),
+    );
+    dst
+}
+
+/// Floating-point convert from single-precision to BFloat16 format, into the
+/// upper half of the result with the lower half taken from `inactive`
+#[inline]
+#[target_feature(enable = "neon,bf16")]
+#[cfg_attr(test, assert_instr(bfcvtn2))]
+pub unsafe fn vcvtq_high_bf16_f32(inactive: bfloat16x8_t, a: float32x4_t) -> bfloat16x8_t {
+    let mut dst = inactive;
+    asm!(
+        "bfcvtn2 {dst:v}.8h, {a:v}.4s",
+        dst = inout(vreg) dst,
+        a = in(vreg) a,
+        options(pure, nomem, nostack, preserves_flags),
+    );
+    dst
+}
+
+/// BFloat16 floating-point dot product
+#[inline]
+#[target_feature(enable = "neon,bf16")]
+#[cfg_attr(test, assert_instr(bfdot))]
+pub unsafe fn vbfdot_f32(r: float32x2_t, a: bfloat16x4_t, b: bfloat16x4_t) -> float32x2_t {
+    bf16_acc!("bfdot {acc:v}.2s, {a:v}.4h, {b:v}.4h", r, a, b)
+}
+
+/// BFloat16 floating-point dot product
+#[inline]
+#[target_feature(enable = "neon,bf16")]
+#[cfg_attr(test, assert_instr(bfdot))]
+pub unsafe fn vbfdotq_f32(r: float32x4_t, a: bfloat16x8_t, b: bfloat16x8_t) -> float32x4_t {
+    bf16_acc!("bfdot {acc:v}.4s, {a:v}.8h, {b:v}.8h", r, a, b)
+}
+
+/// BFloat16 floating-point dot product, with the pair of elements `LANE` of
+/// `b` used for all elements of `a`
+#[inline]
+#[target_feature(enable = "neon,bf16")]
+#[cfg_attr(test, assert_instr(bfdot, LANE = 1))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn vbfdot_lane_f32<const LANE: i32>(
+    r: float32x2_t,
+    a: bfloat16x4_t,
+    b: bfloat16x4_t,
+) -> float32x2_t {
+    static_assert_imm1!(LANE);
+    let b: float32x2_t = transmute(b);
+    let b: float32x2_t = simd_shuffle2(b, b, [LANE as u32, LANE as u32]);
+    vbfdot_f32(r, a, transmute(b))
+}
+
+/// BFloat16 floating-point dot product, with the pair of elements `LANE` of
+/// `b` used for all elements of `a`
+#[inline]
+#[target_feature(enable = "neon,bf16")]
+#[cfg_attr(test, assert_instr(bfdot, LANE = 3))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn vbfdot_laneq_f32<const LANE: i32>(
+    r: float32x2_t,
+    a: bfloat16x4_t,
+    b: bfloat16x8_t,
+) -> float32x2_t {
+    static_assert_imm2!(LANE);
+    let b: float32x4_t = transmute(b);
+    let b: float32x2_t = simd_shuffle2(b, b, [LANE as u32, LANE as u32]);
+    vbfdot_f32(r, a, transmute(b))
+}
+
+/// BFloat16 floating-point dot product, with the pair of elements `LANE` of
+/// `b` used for all elements of `a`
+#[inline]
+#[target_feature(enable = "neon,bf16")]
+#[cfg_attr(test, assert_instr(bfdot, LANE = 1))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn vbfdotq_lane_f32<const LANE: i32>(
+    r: float32x4_t,
+    a: bfloat16x8_t,
+    b: bfloat16x4_t,
+) -> float32x4_t {
+    static_assert_imm1!(LANE);
+    let b: float32x2_t = transmute(b);
+    let b: float32x4_t = simd_shuffle4(b, b, [LANE as u32, LANE as u32, LANE as u32, LANE as u32]);
+    vbfdotq_f32(r, a, transmute(b))
+}
+
+/// BFloat16 floating-point dot product, with the pair of elements `LANE` of
+/// `b` used for all elements of `a`
+#[inline]
+#[target_feature(enable = "neon,bf16")]
+#[cfg_attr(test, assert_instr(bfdot, LANE = 3))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn vbfdotq_laneq_f32<const LANE: i32>(
+    r: float32x4_t,
+    a: bfloat16x8_t,
+    b: bfloat16x8_t,
+) -> float32x4_t {
+    static_assert_imm2!(LANE);
+    let b: float32x4_t = transmute(b);
+    let b: float32x4_t = simd_shuffle4(b, b, [LANE as u32, LANE as u32, LANE as u32, LANE as u32]);
+    vbfdotq_f32(r, a, transmute(b))
+}
+
+/// BFloat16 floating-point matrix multiply-accumulate
+///
+/// `a` is a 2x4 matrix and `b` the transpose of a 4x2 matrix, both stored
+/// row by row, and their 2x2 product is added to `r`.
+#[inline]
+#[target_feature(enable = "neon,bf16")]
+#[cfg_attr(test, assert_instr(bfmmla))]
+pub unsafe fn vbfmmlaq_f32(r: float32x4_t, a: bfloat16x8_t, b: bfloat16x8_t) -> float32x4_t {
+    bf16_acc!("bfmmla {acc:v}.4s, {a:v}.8h, {b:v}.8h", r, a, b)
+}
+
+/// BFloat16 floating-point widening multiply-add long, of the even elements
+/// of `a` and `b` (bottom)
+#[inline]
+#[target_feature(enable = "neon,bf16")]
+#[cfg_attr(test, assert_instr(bfmlalb))]
+pub unsafe fn vbfmlalbq_f32(r: float32x4_t, a: bfloat16x8_t, b: bfloat16x8_t) -> float32x4_t {
+    bf16_acc!("bfmlalb {acc:v}.4s, {a:v}.8h, {b:v}.8h", r, a, b)
+}
+
+/// BFloat16 floating-point widening multiply-add long, of the odd elements
+/// of `a` and `b` (top)
+#[inline]
+#[target_feature(enable = "neon,bf16")]
+#[cfg_attr(test, assert_instr(bfmlalt))]
+pub unsafe fn vbfmlaltq_f32(r: float32x4_t, a: bfloat16x8_t, b: bfloat16x8_t) -> float32x4_t {
+    bf16_acc!("bfmlalt {acc:v}.4s, {a:v}.8h, {b:v}.8h", r, a, b)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::{aarch64::*, simd::*};
+    use std::mem::transmute;
+    use stdarch_test::simd_test;
+
+    fn to_f32(x: u16) -> f32 {
+        f32::from_bits((x as u32) << 16)
+    }
+
+    // Rounds to nearest with ties to even, like `BFCVTN` (NaNs aren't
+    // handled).
+    fn to_bf16(x: f32) -> u16 {
+        let bits = x.to_bits();
+        ((bits + 0x7fff + ((bits >> 16) & 1)) >> 16) as u16
+    }
+
+    fn bf16x4(a: &[f32]) -> bfloat16x4_t {
+        unsafe {
+            transmute(u16x4::new(
+                to_bf16(a[0]),
+                to_bf16(a[1]),
+                to_bf16(a[2]),
+                to_bf16(a[3]),
+            ))
+        }
+    }
+
+    fn bf16x8(a: &[f32]) -> bfloat16x8_t {
+        unsafe { transmute([bf16x4(&a[..4]), bf16x4(&a[4..])]) }
+    }
+
+    // 1 + 2^-8 and 1 + 3 * 2^-8 are ties, pi rounds down and 0.1 up.
+    const INEXACT: [f32; 4] = [1.00390625, 1.01171875, -3.14159265, 0.1];
+
+    #[simd_test(enable = "neon,bf16")]
+    unsafe fn test_vcvt_bf16_f32() {
+        let r: u16x4 = transmute(vcvt_bf16_f32(transmute(INEXACT)));
+        assert_eq!(r, transmute(bf16x4(&INEXACT)));
+        assert_eq!(r, u16x4::new(0x3f80, 0x3f82, 0xc049, 0x3dcd));
+        assert_eq!(to_f32(0xc049), -3.140625);
+    }
+
+    #[simd_test(enable = "neon,bf16")]
+    unsafe fn test_vcvtq_low_bf16_f32() {
+        let r: u16x8 = transmute(vcvtq_low_bf16_f32(transmute(INEXACT)));
+        assert_eq!(r, u16x8::new(0x3f80, 0x3f82, 0xc049, 0x3dcd, 0, 0, 0, 0));
+    }
+
+    #[simd_test(enable = "neon,bf16")]
+    unsafe fn test_vcvtq_high_bf16_f32() {
+        let inactive = transmute(u16x8::new(1, 2, 3, 4, 5, 6, 7, 8));
+        let r: u16x8 = transmute(vcvtq_high_bf16_f32(inactive, transmute(INEXACT)));
+        assert_eq!(r, u16x8::new(1, 2, 3, 4, 0x3f80, 0x3f82, 0xc049, 0x3dcd));
+    }
+
+    // All of these are exact in `bfloat16`, and the products and sums below
+    // are exact in `f32`, so the results don't depend on the intermediate
+    // rounding done by the instructions.
+    const A: [f32; 8] = [1.0, -2.0, 0.5, 3.0, -1.5, 4.0, 0.25, -8.0];
+    const B: [f32; 8] = [2.0, 0.5, -4.0, 1.5, 3.0, -0.75, 16.0, 0.125];
+    const R: [f32; 4] = [10.0, -20.0, 0.5, 100.0];
+
+    // Adds the dot product of the pairs `a[i..]` and `b[j..]` to `r`.
+    fn dot(r: f32, i: usize, j: usize) -> f32 {
+        r + A[i] * B[j] + A[i + 1] * B[j + 1]
+    }
+
+    #[simd_test(enable = "neon,bf16")]
+    unsafe fn test_vbfdot_f32() {
+        let r: f32x2 = transmute(vbfdot_f32(transmute([R[0], R[1]]), bf16x4(&A), bf16x4(&B)));
+        assert_eq!(r, f32x2::new(dot(R[0], 0, 0), dot(R[1], 2, 2)));
+    }
+
+    #[simd_test(enable = "neon,bf16")]
+    unsafe fn test_vbfdotq_f32() {
+        let r: f32x4 = transmute(vbfdotq_f32(transmute(R), bf16x8(&A), bf16x8(&B)));
+        let e = f32x4::new(
+            dot(R[0], 0, 0),
+            dot(R[1], 2, 2),
+            dot(R[2], 4, 4),
+            dot(R[3], 6, 6),
+        );
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,bf16")]
+    unsafe fn test_vbfdot_lane_f32() {
+        let r = vbfdot_lane_f32::<1>(transmute([R[0], R[1]]), bf16x4(&A), bf16x4(&B));
+        let r: f32x2 = transmute(r);
+        assert_eq!(r, f32x2::new(dot(R[0], 0, 2), dot(R[1], 2, 2)));
+    }
+
+    #[simd_test(enable = "neon,bf16")]
+    unsafe fn test_vbfdot_laneq_f32() {
+        let r = vbfdot_laneq_f32::<3>(transmute([R[0], R[1]]), bf16x4(&A), bf16x8(&B));
+        let r: f32x2 = transmute(r);
+        assert_eq!(r, f32x2::new(dot(R[0], 0, 6), dot(R[1], 2, 6)));
+    }
+
+    #[simd_test(enable = "neon,bf16")]
+    unsafe fn test_vbfdotq_lane_f32() {
+        let r: f32x4 = transmute(vbfdotq_lane_f32::<0>(transmute(R), bf16x8(&A), bf16x4(&B)));
+        let e = f32x4::new(
+            dot(R[0], 0, 0),
+            dot(R[1], 2, 0),
+            dot(R[2], 4, 0),
+            dot(R[3], 6, 0),
+        );
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,bf16")]
+    unsafe fn test_vbfdotq_laneq_f32() {
+        let r: f32x4 = transmute(vbfdotq_laneq_f32::<2>(transmute(R), bf16x8(&A), bf16x8(&B)));
+        let e = f32x4::new(
+            dot(R[0], 0, 4),
+            dot(R[1], 2, 4),
+            dot(R[2], 4, 4),
+            dot(R[3], 6, 4),
+        );
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,bf16")]
+    unsafe fn test_vbfmmlaq_f32() {
+        let r: f32x4 = transmute(vbfmmlaq_f32(transmute(R), bf16x8(&A), bf16x8(&B)));
+        let e = f32x4::new(
+            dot(dot(R[0], 0, 0), 2, 2),
+            dot(dot(R[1], 0, 4), 2, 6),
+            dot(dot(R[2], 4, 0), 6, 2),
+            dot(dot(R[3], 4, 4), 6, 6),
+        );
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,bf16")]
+    unsafe fn test_vbfmlalbq_f32() {
+        let r: f32x4 = transmute(vbfmlalbq_f32(transmute(R), bf16x8(&A), bf16x8(&B)));
+        let e = f32x4::new(
+            R[0] + A[0] * B[0],
+            R[1] + A[2] * B[2],
+            R[2] + A[4] * B[4],
+            R[3] + A[6] * B[6],
+        );
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,bf16")]
+    unsafe fn test_vbfmlaltq_f32() {
+        let r: f32x4 = transmute(vbfmlaltq_f32(transmute(R), bf16x8(&A), bf16x8(&B)));
+        let e = f32x4::new(
+            R[0] + A[1] * B[1],
+            R[1] + A[3] * B[3],
+            R[2] + A[5] * B[5],
+            R[3] + A[7] * B[7],
+        );
+        assert_eq!(r, e);
+    }
+}
@@ -0,0 +1,276 @@
+//! Floating-point absolute minimum and maximum (FEAT_FAMINMAX)
+//!
+//! `FAMAX` and `FAMIN` return the larger or the smaller of the absolute
+//! values of their operands, so the results are never negative and `-0.0`
+//! becomes `+0.0`. Unlike with `vmaxnm` and `vminnm`, the result is a NaN if
+//! either operand is one. The `f16` versions, whose elements are the `u16`
+//! bit patterns of the values, also need the `fp16` target feature.
+//!
+//! The reference is the "Floating-point absolute minimum and maximum"
+//! section of [ACLE][acle].
+//!
+//! [acle]: https://github.com/ARM-software/acle
+
+use crate::core_arch::{aarch64::neon::*, arm_shared::*};
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.aarch64.neon.famax.v2f32"]
+    fn vamax_f32_(a: float32x2_t, b: float32x2_t) -> float32x2_t;
+    #[link_name = "llvm.aarch64.neon.famax.v4f32"]
+    fn vamaxq_f32_(a: float32x4_t, b: float32x4_t) -> float32x4_t;
+    #[link_name = "llvm.aarch64.neon.famax.v2f64"]
+    fn vamaxq_f64_(a: float64x2_t, b: float64x2_t) -> float64x2_t;
+    #[link_name = "llvm.aarch64.neon.famin.v2f32"]
+    fn vamin_f32_(a: float32x2_t, b: float32x2_t) -> float32x2_t;
+    #[link_name = "llvm.aarch64.neon.famin.v4f32"]
+    fn vaminq_f32_(a: float32x4_t, b: float32x4_t) -> float32x4_t;
+    #[link_name = "llvm.aarch64.neon.famin.v2f64"]
+    fn vaminq_f64_(a: float64x2_t, b: float64x2_t) -> float64x2_t;
+}
+
+// LLVM models the `f16` versions on vectors of `half`, which don't have a
+// Rust equivalent that can cross the FFI boundary, so they're implemented
+// with inline assembly instead, like in `fp16.rs`.
+macro_rules! f16_op {
+    ($inst:expr, $a:expr, $b:expr) => {{
+        let dst;
+        asm!(
+            $inst,
+            dst = lateout(vreg) dst,
+            a = in(vreg) $a,
+            b = in(vreg) $b,
+            options(pure, nomem, nostack, preserves_flags),
+        );
+        dst
+    }};
+}
+
+/// Floating-point absolute maximum
+#[inline]
+#[target_feature(enable = "neon,fp16,faminmax")]
+#[cfg_attr(test, assert_instr(famax))]
+pub unsafe fn vamax_f16(a: float16x4_t, b: float16x4_t) -> float16x4_t {
+    f16_op!("famax {dst:v}.4h, {a:v}.4h, {b:v}.4h", a, b)
+}
+
+/// Floating-point absolute maximum
+#[inline]
+#[target_feature(enable = "neon,fp16,faminmax")]
+#[cfg_attr(test, assert_instr(famax))]
+pub unsafe fn vamaxq_f16(a: float16x8_t, b: float16x8_t) -> float16x8_t {
+    f16_op!("famax {dst:v}.8h, {a:v}.8h, {b:v}.8h", a, b)
+}
+
+/// Floating-point absolute maximum
+#[inline]
+#[target_feature(enable = "neon,faminmax")]
+#[cfg_attr(test, assert_instr(famax))]
+pub unsafe fn vamax_f32(a: float32x2_t, b: float32x2_t) -> float32x2_t {
+    vamax_f32_(a, b)
+}
+
+/// Floating-point absolute maximum
+#[inline]
+#[target_feature(enable = "neon,faminmax")]
+#[cfg_attr(test, assert_instr(famax))]
+pub unsafe fn vamaxq_f32(a: float32x4_t, b: float32x4_t) -> float32x4_t {
+    vamaxq_f32_(a, b)
+}
+
+/// Floating-point absolute maximum
+#[inline]
+#[target_feature(enable = "neon,faminmax")]
+#[cfg_attr(test, assert_instr(famax))]
+pub unsafe fn vamaxq_f64(a: float64x2_t, b: float64x2_t) -> float64x2_t {
+    vamaxq_f64_(a, b)
+}
+
+/// Floating-point absolute minimum
+#[inline]
+#[target_feature(enable = "neon,fp16,faminmax")]
+#[cfg_attr(test, assert_instr(famin))]
+pub unsafe fn vamin_f16(a: float16x4_t, b: float16x4_t) -> float16x4_t {
+    f16_op!("famin {dst:v}.4h, {a:v}.4h, {b:v}.4h", a, b)
+}
+
+/// Floating-point absolute minimum
+#[inline]
+#[target_feature(enable = "neon,fp16,faminmax")]
+#[cfg_attr(test, assert_instr(famin))]
+pub unsafe fn vaminq_f16(a: float16x8_t, b: float16x8_t) -> float16x8_t {
+    f16_op!("famin {dst:v}.8h, {a:v}.8h, {b:v}.8h", a, b)
+}
+
+/// Floating-point absolute minimum
+#[inline]
+#[target_feature(enable = "neon,faminmax")]
+#[cfg_attr(test, assert_instr(famin))]
+pub unsafe fn vamin_f32(a: float32x2_t, b: float32x2_t) -> float32x2_t {
+    vamin_f32_(a, b)
+}
+
+/// Floating-point absolute minimum
+#[inline]
+#[target_feature(enable = "neon,faminmax")]
+#[cfg_attr(test, assert_instr(famin))]
+pub unsafe fn vaminq_f32(a: float32x4_t, b: float32x4_t) -> float32x4_t {
+    vaminq_f32_(a, b)
+}
+
+/// Floating-point absolute minimum
+#[inline]
+#[target_feature(enable = "neon,faminmax")]
+#[cfg_attr(test, assert_instr(famin))]
+pub unsafe fn vaminq_f64(a: float64x2_t, b: float64x2_t) -> float64x2_t {
+    vaminq_f64_(a, b)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::aarch64::*;
+    use std::{
+        f64::{INFINITY, NAN},
+        mem::transmute_copy,
+    };
+    use stdarch_test::simd_test;
+
+    // The operands, and their absolute maximum and minimum.
+    const CASES: [(f64, f64, f64, f64); 8] = [
+        (-3.0, 2.0, 3.0, 2.0),
+        (3.0, -2.0, 3.0, 2.0),
+        (-1.5, 1.5, 1.5, 1.5),
+        (-0.0, 0.0, 0.0, 0.0),
+        (-0.0, -0.0, 0.0, 0.0),
+        (NAN, 1.0, NAN, NAN),
+        (-1.0, NAN, NAN, NAN),
+        (-INFINITY, 0.5, INFINITY, 0.5),
+    ];
+
+    trait Elem: Copy {
+        fn from_f64(x: f64) -> Self;
+        fn is_nan(self) -> bool;
+        fn to_bits(self) -> u64;
+    }
+
+    impl Elem for f32 {
+        fn from_f64(x: f64) -> Self {
+            x as f32
+        }
+        fn is_nan(self) -> bool {
+            self.is_nan()
+        }
+        fn to_bits(self) -> u64 {
+            self.to_bits() as u64
+        }
+    }
+
+    impl Elem for f64 {
+        fn from_f64(x: f64) -> Self {
+            x
+        }
+        fn is_nan(self) -> bool {
+            self.is_nan()
+        }
+        fn to_bits(self) -> u64 {
+            self.to_bits()
+        }
+    }
+
+    // The bit patterns of `f16` values, only exact for the values in `CASES`.
+    impl Elem for u16 {
+        fn from_f64(x: f64) -> Self {
+            let bits = (x as f32).to_bits();
+            let sign = (bits >> 16) as u16 & 0x8000;
+            if x.is_nan() {
+                sign | 0x7e00
+            } else if x.is_infinite() {
+                sign | 0x7c00
+            } else if x == 0.0 {
+                sign
+            } else {
+                let exp = ((bits >> 23) & 0xff) as u16 + 15 - 127;
+                sign | exp << 10 | (bits >> 13) as u16 & 0x3ff
+            }
+        }
+        fn is_nan(self) -> bool {
+            self & 0x7fff > 0x7c00
+        }
+        fn to_bits(self) -> u64 {
+            self as u64
+        }
+    }
+
+    unsafe fn check<V, E: Elem, const N: usize>(f: unsafe fn(V, V) -> V, max: bool) {
+        for cases in CASES.chunks(N) {
+            let mut a = [E::from_f64(0.0); N];
+            let mut b = a;
+            for (i, &(x, y, _, _)) in cases.iter().enumerate() {
+                a[i] = E::from_f64(x);
+                b[i] = E::from_f64(y);
+            }
+            let r: [E; N] = transmute_copy(&f(transmute_copy(&a), transmute_copy(&b)));
+            for (&r, &(x, y, amax, amin)) in r.iter().zip(cases) {
+                let e = E::from_f64(if max { amax } else { amin });
+                if e.is_nan() {
+                    assert!(r.is_nan(), "{} and {}", x, y);
+                } else {
+                    assert_eq!(r.to_bits(), e.to_bits(), "{} and {}", x, y);
+                }
+            }
+        }
+    }
+
+    #[simd_test(enable = "neon,fp16,faminmax")]
+    unsafe fn test_vamax_f16() {
+        check::<_, u16, 4>(vamax_f16, true);
+    }
+
+    #[simd_test(enable = "neon,fp16,faminmax")]
+    unsafe fn test_vamaxq_f16() {
+        check::<_, u16, 8>(vamaxq_f16, true);
+    }
+
+    #[simd_test(enable = "neon,faminmax")]
+    unsafe fn test_vamax_f32() {
+        check::<_, f32, 2>(vamax_f32, true);
+    }
+
+    #[simd_test(enable = "neon,faminmax")]
+    unsafe fn test_vamaxq_f32() {
+        check::<_, f32, 4>(vamaxq_f32, true);
+    }
+
+    #[simd_test(enable = "neon,faminmax")]
+    unsafe fn test_vamaxq_f64() {
+        check::<_, f64, 2>(vamaxq_f64, true);
+    }
+
+    #[simd_test(enable = "neon,fp16,faminmax")]
+    unsafe fn test_vamin_f16() {
+        check::<_, u16, 4>(vamin_f16, false);
+    }
+
+    #[simd_test(enable = "neon,fp16,faminmax")]
+    unsafe fn test_vaminq_f16() {
+        check::<_, u16, 8>(vaminq_f16, false);
+    }
+
+    #[simd_test(enable = "neon,faminmax")]
+    unsafe fn test_vamin_f32() {
+        check::<_, f32, 2>(vamin_f32, false);
+    }
+
+    #[simd_test(enable = "neon,faminmax")]
+    unsafe fn test_vaminq_f32() {
+        check::<_, f32, 4>(vaminq_f32, false);
+    }
+
+    #[simd_test(enable = "neon,faminmax")]
+    unsafe fn test_vaminq_f64() {
+        check::<_, f64, 2>(vaminq_f64, false);
+    }
+}
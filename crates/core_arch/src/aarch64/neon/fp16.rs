@@ -0,0 +1,2777 @@
+//! Half-precision floating-point intrinsics (FEAT_FP16)
+//!
+//! Rust doesn't have a half-precision floating point type, so scalar
+//! arguments and results are the `u16` bit patterns of IEEE 754 `binary16`
+//! values, and so are the elements of `float16x4_t` and `float16x8_t`.
+//! Except for the conversions from and to `float32x4_t` and the loads and
+//! stores, which are part of the base ASIMD instruction set, these intrinsics
+//! need the `fp16` target feature.
+
+use crate::{
+    core_arch::{aarch64::neon::*, arm_shared::*, simd_llvm::*},
+    mem::transmute,
+};
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+// LLVM models these instructions on `half` and vectors of it, which don't
+// have a Rust equivalent that can cross the FFI boundary, so they're
+// implemented with inline assembly instead. Operand `{0}` is the result and
+// the following ones are the arguments.
+macro_rules! fp16_op {
+    ($inst:expr, $dreg:ident $(, $reg:ident $arg:expr)*) => {{
+        let dst;
+        asm!(
+            $inst,
+            lateout($dreg) dst,
+            $(in($reg) $arg,)*
+            options(pure, nomem, nostack, preserves_flags),
+        );
+        dst
+    }};
+}
+
+// Like `fp16_op!`, for instructions which accumulate into their first
+// argument.
+macro_rules! fp16_acc {
+    ($inst:expr, $acc:expr, $a:expr, $b:expr) => {{
+        let mut acc = $acc;
+        asm!(
+            $inst,
+            inout(vreg) acc,
+            in(vreg) $a,
+            in(vreg) $b,
+            options(pure, nomem, nostack, preserves_flags),
+        );
+        acc
+    }};
+}
+
+// Scalar operations
+
+/// Floating-point absolute value
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fabs))]
+pub unsafe fn vabsh_f16(a: u16) -> u16 {
+    fp16_op!("fabs {0:h}, {1:h}", vreg, vreg a)
+}
+
+/// Negate
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fneg))]
+pub unsafe fn vnegh_f16(a: u16) -> u16 {
+    fp16_op!("fneg {0:h}, {1:h}", vreg, vreg a)
+}
+
+/// Calculates the square root
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fsqrt))]
+pub unsafe fn vsqrth_f16(a: u16) -> u16 {
+    fp16_op!("fsqrt {0:h}, {1:h}", vreg, vreg a)
+}
+
+/// Floating-point round to integral, toward zero
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(frintz))]
+pub unsafe fn vrndh_f16(a: u16) -> u16 {
+    fp16_op!("frintz {0:h}, {1:h}", vreg, vreg a)
+}
+
+/// Floating-point round to integral, to nearest with ties to away
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(frinta))]
+pub unsafe fn vrndah_f16(a: u16) -> u16 {
+    fp16_op!("frinta {0:h}, {1:h}", vreg, vreg a)
+}
+
+/// Floating-point round to integral, toward minus infinity
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(frintm))]
+pub unsafe fn vrndmh_f16(a: u16) -> u16 {
+    fp16_op!("frintm {0:h}, {1:h}", vreg, vreg a)
+}
+
+/// Floating-point round to integral, to nearest with ties to even
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(frintn))]
+pub unsafe fn vrndnh_f16(a: u16) -> u16 {
+    fp16_op!("frintn {0:h}, {1:h}", vreg, vreg a)
+}
+
+/// Floating-point round to integral, toward plus infinity
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(frintp))]
+pub unsafe fn vrndph_f16(a: u16) -> u16 {
+    fp16_op!("frintp {0:h}, {1:h}", vreg, vreg a)
+}
+
+/// Floating-point round to integral exact, using current rounding mode
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(frintx))]
+pub unsafe fn vrndxh_f16(a: u16) -> u16 {
+    fp16_op!("frintx {0:h}, {1:h}", vreg, vreg a)
+}
+
+/// Floating-point round to integral, using current rounding mode
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(frinti))]
+pub unsafe fn vrndih_f16(a: u16) -> u16 {
+    fp16_op!("frinti {0:h}, {1:h}", vreg, vreg a)
+}
+
+/// Add
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fadd))]
+pub unsafe fn vaddh_f16(a: u16, b: u16) -> u16 {
+    fp16_op!("fadd {0:h}, {1:h}, {2:h}", vreg, vreg a, vreg b)
+}
+
+/// Subtract
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fsub))]
+pub unsafe fn vsubh_f16(a: u16, b: u16) -> u16 {
+    fp16_op!("fsub {0:h}, {1:h}, {2:h}", vreg, vreg a, vreg b)
+}
+
+/// Multiply
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fmul))]
+pub unsafe fn vmulh_f16(a: u16, b: u16) -> u16 {
+    fp16_op!("fmul {0:h}, {1:h}, {2:h}", vreg, vreg a, vreg b)
+}
+
+/// Divide
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fdiv))]
+pub unsafe fn vdivh_f16(a: u16, b: u16) -> u16 {
+    fp16_op!("fdiv {0:h}, {1:h}, {2:h}", vreg, vreg a, vreg b)
+}
+
+/// Floating-point absolute difference
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fabd))]
+pub unsafe fn vabdh_f16(a: u16, b: u16) -> u16 {
+    fp16_op!("fabd {0:h}, {1:h}, {2:h}", vreg, vreg a, vreg b)
+}
+
+/// Maximum
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fmax))]
+pub unsafe fn vmaxh_f16(a: u16, b: u16) -> u16 {
+    fp16_op!("fmax {0:h}, {1:h}, {2:h}", vreg, vreg a, vreg b)
+}
+
+/// Minimum
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fmin))]
+pub unsafe fn vminh_f16(a: u16, b: u16) -> u16 {
+    fp16_op!("fmin {0:h}, {1:h}, {2:h}", vreg, vreg a, vreg b)
+}
+
+/// Floating-point Maximum Number
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fmaxnm))]
+pub unsafe fn vmaxnmh_f16(a: u16, b: u16) -> u16 {
+    fp16_op!("fmaxnm {0:h}, {1:h}, {2:h}", vreg, vreg a, vreg b)
+}
+
+/// Floating-point Minimum Number
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fminnm))]
+pub unsafe fn vminnmh_f16(a: u16, b: u16) -> u16 {
+    fp16_op!("fminnm {0:h}, {1:h}, {2:h}", vreg, vreg a, vreg b)
+}
+
+/// Floating-point compare equal
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fcmeq))]
+pub unsafe fn vceqh_f16(a: u16, b: u16) -> u16 {
+    fp16_op!("fcmeq {0:h}, {1:h}, {2:h}", vreg, vreg a, vreg b)
+}
+
+/// Floating-point compare greater than or equal
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fcmge))]
+pub unsafe fn vcgeh_f16(a: u16, b: u16) -> u16 {
+    fp16_op!("fcmge {0:h}, {1:h}, {2:h}", vreg, vreg a, vreg b)
+}
+
+/// Floating-point compare greater than
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fcmgt))]
+pub unsafe fn vcgth_f16(a: u16, b: u16) -> u16 {
+    fp16_op!("fcmgt {0:h}, {1:h}, {2:h}", vreg, vreg a, vreg b)
+}
+
+/// Floating-point compare less than or equal
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fcmge))]
+pub unsafe fn vcleh_f16(a: u16, b: u16) -> u16 {
+    fp16_op!("fcmge {0:h}, {1:h}, {2:h}", vreg, vreg b, vreg a)
+}
+
+/// Floating-point compare less than
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fcmgt))]
+pub unsafe fn vclth_f16(a: u16, b: u16) -> u16 {
+    fp16_op!("fcmgt {0:h}, {1:h}, {2:h}", vreg, vreg b, vreg a)
+}
+
+/// Floating-point fused multiply-add, computing `a + b * c`
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fmadd))]
+pub unsafe fn vfmah_f16(a: u16, b: u16, c: u16) -> u16 {
+    fp16_op!("fmadd {0:h}, {2:h}, {3:h}, {1:h}", vreg, vreg a, vreg b, vreg c)
+}
+
+/// Floating-point fused multiply-subtract, computing `a - b * c`
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fmsub))]
+pub unsafe fn vfmsh_f16(a: u16, b: u16, c: u16) -> u16 {
+    fp16_op!("fmsub {0:h}, {2:h}, {3:h}, {1:h}", vreg, vreg a, vreg b, vreg c)
+}
+
+/// Fixed-point convert to floating-point
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(scvtf))]
+pub unsafe fn vcvth_f16_s32(a: i32) -> u16 {
+    fp16_op!("scvtf {0:h}, {1:w}", vreg, reg a)
+}
+
+/// Fixed-point convert to floating-point
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(scvtf))]
+pub unsafe fn vcvth_f16_s64(a: i64) -> u16 {
+    fp16_op!("scvtf {0:h}, {1:x}", vreg, reg a)
+}
+
+/// Fixed-point convert to floating-point
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(ucvtf))]
+pub unsafe fn vcvth_f16_u32(a: u32) -> u16 {
+    fp16_op!("ucvtf {0:h}, {1:w}", vreg, reg a)
+}
+
+/// Fixed-point convert to floating-point
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(ucvtf))]
+pub unsafe fn vcvth_f16_u64(a: u64) -> u16 {
+    fp16_op!("ucvtf {0:h}, {1:x}", vreg, reg a)
+}
+
+/// Floating-point convert to signed fixed-point, rounding toward zero
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fcvtzs))]
+pub unsafe fn vcvth_s32_f16(a: u16) -> i32 {
+    fp16_op!("fcvtzs {0:w}, {1:h}", reg, vreg a)
+}
+
+/// Floating-point convert to signed fixed-point, rounding toward zero
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fcvtzs))]
+pub unsafe fn vcvth_s64_f16(a: u16) -> i64 {
+    fp16_op!("fcvtzs {0:x}, {1:h}", reg, vreg a)
+}
+
+/// Floating-point convert to unsigned fixed-point, rounding toward zero
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fcvtzu))]
+pub unsafe fn vcvth_u32_f16(a: u16) -> u32 {
+    fp16_op!("fcvtzu {0:w}, {1:h}", reg, vreg a)
+}
+
+/// Floating-point convert to unsigned fixed-point, rounding toward zero
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fcvtzu))]
+pub unsafe fn vcvth_u64_f16(a: u16) -> u64 {
+    fp16_op!("fcvtzu {0:x}, {1:h}", reg, vreg a)
+}
+
+/// Floating-point convert to signed integer, rounding to nearest with ties to away
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fcvtas))]
+pub unsafe fn vcvtah_s32_f16(a: u16) -> i32 {
+    fp16_op!("fcvtas {0:w}, {1:h}", reg, vreg a)
+}
+
+/// Floating-point convert to signed integer, rounding to nearest with ties to away
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fcvtas))]
+pub unsafe fn vcvtah_s64_f16(a: u16) -> i64 {
+    fp16_op!("fcvtas {0:x}, {1:h}", reg, vreg a)
+}
+
+/// Floating-point convert to unsigned integer, rounding to nearest with ties to away
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fcvtau))]
+pub unsafe fn vcvtah_u32_f16(a: u16) -> u32 {
+    fp16_op!("fcvtau {0:w}, {1:h}", reg, vreg a)
+}
+
+/// Floating-point convert to unsigned integer, rounding to nearest with ties to away
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fcvtau))]
+pub unsafe fn vcvtah_u64_f16(a: u16) -> u64 {
+    fp16_op!("fcvtau {0:x}, {1:h}", reg, vreg a)
+}
+
+/// Floating-point convert to signed integer, rounding toward minus infinity
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fcvtms))]
+pub unsafe fn vcvtmh_s32_f16(a: u16) -> i32 {
+    fp16_op!("fcvtms {0:w}, {1:h}", reg, vreg a)
+}
+
+/// Floating-point convert to signed integer, rounding toward minus infinity
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fcvtms))]
+pub unsafe fn vcvtmh_s64_f16(a: u16) -> i64 {
+    fp16_op!("fcvtms {0:x}, {1:h}", reg, vreg a)
+}
+
+/// Floating-point convert to unsigned integer, rounding toward minus infinity
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fcvtmu))]
+pub unsafe fn vcvtmh_u32_f16(a: u16) -> u32 {
+    fp16_op!("fcvtmu {0:w}, {1:h}", reg, vreg a)
+}
+
+/// Floating-point convert to unsigned integer, rounding toward minus infinity
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fcvtmu))]
+pub unsafe fn vcvtmh_u64_f16(a: u16) -> u64 {
+    fp16_op!("fcvtmu {0:x}, {1:h}", reg, vreg a)
+}
+
+/// Floating-point convert to signed integer, rounding to nearest with ties to even
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fcvtns))]
+pub unsafe fn vcvtnh_s32_f16(a: u16) -> i32 {
+    fp16_op!("fcvtns {0:w}, {1:h}", reg, vreg a)
+}
+
+/// Floating-point convert to signed integer, rounding to nearest with ties to even
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fcvtns))]
+pub unsafe fn vcvtnh_s64_f16(a: u16) -> i64 {
+    fp16_op!("fcvtns {0:x}, {1:h}", reg, vreg a)
+}
+
+/// Floating-point convert to unsigned integer, rounding to nearest with ties to even
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fcvtnu))]
+pub unsafe fn vcvtnh_u32_f16(a: u16) -> u32 {
+    fp16_op!("fcvtnu {0:w}, {1:h}", reg, vreg a)
+}
+
+/// Floating-point convert to unsigned integer, rounding to nearest with ties to even
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fcvtnu))]
+pub unsafe fn vcvtnh_u64_f16(a: u16) -> u64 {
+    fp16_op!("fcvtnu {0:x}, {1:h}", reg, vreg a)
+}
+
+/// Floating-point convert to signed integer, rounding toward plus infinity
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fcvtps))]
+pub unsafe fn vcvtph_s32_f16(a: u16) -> i32 {
+    fp16_op!("fcvtps {0:w}, {1:h}", reg, vreg a)
+}
+
+/// Floating-point convert to signed integer, rounding toward plus infinity
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fcvtps))]
+pub unsafe fn vcvtph_s64_f16(a: u16) -> i64 {
+    fp16_op!("fcvtps {0:x}, {1:h}", reg, vreg a)
+}
+
+/// Floating-point convert to unsigned integer, rounding toward plus infinity
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fcvtpu))]
+pub unsafe fn vcvtph_u32_f16(a: u16) -> u32 {
+    fp16_op!("fcvtpu {0:w}, {1:h}", reg, vreg a)
+}
+
+/// Floating-point convert to unsigned integer, rounding toward plus infinity
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fcvtpu))]
+pub unsafe fn vcvtph_u64_f16(a: u16) -> u64 {
+    fp16_op!("fcvtpu {0:x}, {1:h}", reg, vreg a)
+}
+
+// Vector operations
+
+/// Floating-point absolute value
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fabs))]
+pub unsafe fn vabs_f16(a: float16x4_t) -> float16x4_t {
+    fp16_op!("fabs {0:v}.4h, {1:v}.4h", vreg, vreg a)
+}
+
+/// Floating-point absolute value
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fabs))]
+pub unsafe fn vabsq_f16(a: float16x8_t) -> float16x8_t {
+    fp16_op!("fabs {0:v}.8h, {1:v}.8h", vreg, vreg a)
+}
+
+/// Negate
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fneg))]
+pub unsafe fn vneg_f16(a: float16x4_t) -> float16x4_t {
+    fp16_op!("fneg {0:v}.4h, {1:v}.4h", vreg, vreg a)
+}
+
+/// Negate
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fneg))]
+pub unsafe fn vnegq_f16(a: float16x8_t) -> float16x8_t {
+    fp16_op!("fneg {0:v}.8h, {1:v}.8h", vreg, vreg a)
+}
+
+/// Calculates the square root of each lane.
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fsqrt))]
+pub unsafe fn vsqrt_f16(a: float16x4_t) -> float16x4_t {
+    fp16_op!("fsqrt {0:v}.4h, {1:v}.4h", vreg, vreg a)
+}
+
+/// Calculates the square root of each lane.
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fsqrt))]
+pub unsafe fn vsqrtq_f16(a: float16x8_t) -> float16x8_t {
+    fp16_op!("fsqrt {0:v}.8h, {1:v}.8h", vreg, vreg a)
+}
+
+/// Floating-point round to integral, toward zero
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(frintz))]
+pub unsafe fn vrnd_f16(a: float16x4_t) -> float16x4_t {
+    fp16_op!("frintz {0:v}.4h, {1:v}.4h", vreg, vreg a)
+}
+
+/// Floating-point round to integral, toward zero
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(frintz))]
+pub unsafe fn vrndq_f16(a: float16x8_t) -> float16x8_t {
+    fp16_op!("frintz {0:v}.8h, {1:v}.8h", vreg, vreg a)
+}
+
+/// Floating-point round to integral, to nearest with ties to away
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(frinta))]
+pub unsafe fn vrnda_f16(a: float16x4_t) -> float16x4_t {
+    fp16_op!("frinta {0:v}.4h, {1:v}.4h", vreg, vreg a)
+}
+
+/// Floating-point round to integral, to nearest with ties to away
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(frinta))]
+pub unsafe fn vrndaq_f16(a: float16x8_t) -> float16x8_t {
+    fp16_op!("frinta {0:v}.8h, {1:v}.8h", vreg, vreg a)
+}
+
+/// Floating-point round to integral, toward minus infinity
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(frintm))]
+pub unsafe fn vrndm_f16(a: float16x4_t) -> float16x4_t {
+    fp16_op!("frintm {0:v}.4h, {1:v}.4h", vreg, vreg a)
+}
+
+/// Floating-point round to integral, toward minus infinity
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(frintm))]
+pub unsafe fn vrndmq_f16(a: float16x8_t) -> float16x8_t {
+    fp16_op!("frintm {0:v}.8h, {1:v}.8h", vreg, vreg a)
+}
+
+/// Floating-point round to integral, to nearest with ties to even
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(frintn))]
+pub unsafe fn vrndn_f16(a: float16x4_t) -> float16x4_t {
+    fp16_op!("frintn {0:v}.4h, {1:v}.4h", vreg, vreg a)
+}
+
+/// Floating-point round to integral, to nearest with ties to even
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(frintn))]
+pub unsafe fn vrndnq_f16(a: float16x8_t) -> float16x8_t {
+    fp16_op!("frintn {0:v}.8h, {1:v}.8h", vreg, vreg a)
+}
+
+/// Floating-point round to integral, toward plus infinity
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(frintp))]
+pub unsafe fn vrndp_f16(a: float16x4_t) -> float16x4_t {
+    fp16_op!("frintp {0:v}.4h, {1:v}.4h", vreg, vreg a)
+}
+
+/// Floating-point round to integral, toward plus infinity
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(frintp))]
+pub unsafe fn vrndpq_f16(a: float16x8_t) -> float16x8_t {
+    fp16_op!("frintp {0:v}.8h, {1:v}.8h", vreg, vreg a)
+}
+
+/// Floating-point round to integral exact, using current rounding mode
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(frintx))]
+pub unsafe fn vrndx_f16(a: float16x4_t) -> float16x4_t {
+    fp16_op!("frintx {0:v}.4h, {1:v}.4h", vreg, vreg a)
+}
+
+/// Floating-point round to integral exact, using current rounding mode
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(frintx))]
+pub unsafe fn vrndxq_f16(a: float16x8_t) -> float16x8_t {
+    fp16_op!("frintx {0:v}.8h, {1:v}.8h", vreg, vreg a)
+}
+
+/// Floating-point round to integral, using current rounding mode
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(frinti))]
+pub unsafe fn vrndi_f16(a: float16x4_t) -> float16x4_t {
+    fp16_op!("frinti {0:v}.4h, {1:v}.4h", vreg, vreg a)
+}
+
+/// Floating-point round to integral, using current rounding mode
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(frinti))]
+pub unsafe fn vrndiq_f16(a: float16x8_t) -> float16x8_t {
+    fp16_op!("frinti {0:v}.8h, {1:v}.8h", vreg, vreg a)
+}
+
+/// Add
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fadd))]
+pub unsafe fn vadd_f16(a: float16x4_t, b: float16x4_t) -> float16x4_t {
+    fp16_op!("fadd {0:v}.4h, {1:v}.4h, {2:v}.4h", vreg, vreg a, vreg b)
+}
+
+/// Add
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fadd))]
+pub unsafe fn vaddq_f16(a: float16x8_t, b: float16x8_t) -> float16x8_t {
+    fp16_op!("fadd {0:v}.8h, {1:v}.8h, {2:v}.8h", vreg, vreg a, vreg b)
+}
+
+/// Subtract
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fsub))]
+pub unsafe fn vsub_f16(a: float16x4_t, b: float16x4_t) -> float16x4_t {
+    fp16_op!("fsub {0:v}.4h, {1:v}.4h, {2:v}.4h", vreg, vreg a, vreg b)
+}
+
+/// Subtract
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fsub))]
+pub unsafe fn vsubq_f16(a: float16x8_t, b: float16x8_t) -> float16x8_t {
+    fp16_op!("fsub {0:v}.8h, {1:v}.8h, {2:v}.8h", vreg, vreg a, vreg b)
+}
+
+/// Multiply
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fmul))]
+pub unsafe fn vmul_f16(a: float16x4_t, b: float16x4_t) -> float16x4_t {
+    fp16_op!("fmul {0:v}.4h, {1:v}.4h, {2:v}.4h", vreg, vreg a, vreg b)
+}
+
+/// Multiply
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fmul))]
+pub unsafe fn vmulq_f16(a: float16x8_t, b: float16x8_t) -> float16x8_t {
+    fp16_op!("fmul {0:v}.8h, {1:v}.8h, {2:v}.8h", vreg, vreg a, vreg b)
+}
+
+/// Divide
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fdiv))]
+pub unsafe fn vdiv_f16(a: float16x4_t, b: float16x4_t) -> float16x4_t {
+    fp16_op!("fdiv {0:v}.4h, {1:v}.4h, {2:v}.4h", vreg, vreg a, vreg b)
+}
+
+/// Divide
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fdiv))]
+pub unsafe fn vdivq_f16(a: float16x8_t, b: float16x8_t) -> float16x8_t {
+    fp16_op!("fdiv {0:v}.8h, {1:v}.8h, {2:v}.8h", vreg, vreg a, vreg b)
+}
+
+/// Floating-point absolute difference
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fabd))]
+pub unsafe fn vabd_f16(a: float16x4_t, b: float16x4_t) -> float16x4_t {
+    fp16_op!("fabd {0:v}.4h, {1:v}.4h, {2:v}.4h", vreg, vreg a, vreg b)
+}
+
+/// Floating-point absolute difference
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fabd))]
+pub unsafe fn vabdq_f16(a: float16x8_t, b: float16x8_t) -> float16x8_t {
+    fp16_op!("fabd {0:v}.8h, {1:v}.8h, {2:v}.8h", vreg, vreg a, vreg b)
+}
+
+/// Maximum
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fmax))]
+pub unsafe fn vmax_f16(a: float16x4_t, b: float16x4_t) -> float16x4_t {
+    fp16_op!("fmax {0:v}.4h, {1:v}.4h, {2:v}.4h", vreg, vreg a, vreg b)
+}
+
+/// Maximum
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fmax))]
+pub unsafe fn vmaxq_f16(a: float16x8_t, b: float16x8_t) -> float16x8_t {
+    fp16_op!("fmax {0:v}.8h, {1:v}.8h, {2:v}.8h", vreg, vreg a, vreg b)
+}
+
+/// Minimum
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fmin))]
+pub unsafe fn vmin_f16(a: float16x4_t, b: float16x4_t) -> float16x4_t {
+    fp16_op!("fmin {0:v}.4h, {1:v}.4h, {2:v}.4h", vreg, vreg a, vreg b)
+}
+
+/// Minimum
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fmin))]
+pub unsafe fn vminq_f16(a: float16x8_t, b: float16x8_t) -> float16x8_t {
+    fp16_op!("fmin {0:v}.8h, {1:v}.8h, {2:v}.8h", vreg, vreg a, vreg b)
+}
+
+/// Floating-point Maximum Number
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fmaxnm))]
+pub unsafe fn vmaxnm_f16(a: float16x4_t, b: float16x4_t) -> float16x4_t {
+    fp16_op!("fmaxnm {0:v}.4h, {1:v}.4h, {2:v}.4h", vreg, vreg a, vreg b)
+}
+
+/// Floating-point Maximum Number
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fmaxnm))]
+pub unsafe fn vmaxnmq_f16(a: float16x8_t, b: float16x8_t) -> float16x8_t {
+    fp16_op!("fmaxnm {0:v}.8h, {1:v}.8h, {2:v}.8h", vreg, vreg a, vreg b)
+}
+
+/// Floating-point Minimum Number
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fminnm))]
+pub unsafe fn vminnm_f16(a: float16x4_t, b: float16x4_t) -> float16x4_t {
+    fp16_op!("fminnm {0:v}.4h, {1:v}.4h, {2:v}.4h", vreg, vreg a, vreg b)
+}
+
+/// Floating-point Minimum Number
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fminnm))]
+pub unsafe fn vminnmq_f16(a: float16x8_t, b: float16x8_t) -> float16x8_t {
+    fp16_op!("fminnm {0:v}.8h, {1:v}.8h, {2:v}.8h", vreg, vreg a, vreg b)
+}
+
+/// Floating-point compare equal
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fcmeq))]
+pub unsafe fn vceq_f16(a: float16x4_t, b: float16x4_t) -> uint16x4_t {
+    fp16_op!("fcmeq {0:v}.4h, {1:v}.4h, {2:v}.4h", vreg, vreg a, vreg b)
+}
+
+/// Floating-point compare equal
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fcmeq))]
+pub unsafe fn vceqq_f16(a: float16x8_t, b: float16x8_t) -> uint16x8_t {
+    fp16_op!("fcmeq {0:v}.8h, {1:v}.8h, {2:v}.8h", vreg, vreg a, vreg b)
+}
+
+/// Floating-point compare greater than or equal
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fcmge))]
+pub unsafe fn vcge_f16(a: float16x4_t, b: float16x4_t) -> uint16x4_t {
+    fp16_op!("fcmge {0:v}.4h, {1:v}.4h, {2:v}.4h", vreg, vreg a, vreg b)
+}
+
+/// Floating-point compare greater than or equal
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fcmge))]
+pub unsafe fn vcgeq_f16(a: float16x8_t, b: float16x8_t) -> uint16x8_t {
+    fp16_op!("fcmge {0:v}.8h, {1:v}.8h, {2:v}.8h", vreg, vreg a, vreg b)
+}
+
+/// Floating-point compare greater than
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fcmgt))]
+pub unsafe fn vcgt_f16(a: float16x4_t, b: float16x4_t) -> uint16x4_t {
+    fp16_op!("fcmgt {0:v}.4h, {1:v}.4h, {2:v}.4h", vreg, vreg a, vreg b)
+}
+
+/// Floating-point compare greater than
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fcmgt))]
+pub unsafe fn vcgtq_f16(a: float16x8_t, b: float16x8_t) -> uint16x8_t {
+    fp16_op!("fcmgt {0:v}.8h, {1:v}.8h, {2:v}.8h", vreg, vreg a, vreg b)
+}
+
+/// Floating-point compare less than or equal
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fcmge))]
+pub unsafe fn vcle_f16(a: float16x4_t, b: float16x4_t) -> uint16x4_t {
+    fp16_op!("fcmge {0:v}.4h, {1:v}.4h, {2:v}.4h", vreg, vreg b, vreg a)
+}
+
+/// Floating-point compare less than or equal
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fcmge))]
+pub unsafe fn vcleq_f16(a: float16x8_t, b: float16x8_t) -> uint16x8_t {
+    fp16_op!("fcmge {0:v}.8h, {1:v}.8h, {2:v}.8h", vreg, vreg b, vreg a)
+}
+
+/// Floating-point compare less than
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fcmgt))]
+pub unsafe fn vclt_f16(a: float16x4_t, b: float16x4_t) -> uint16x4_t {
+    fp16_op!("fcmgt {0:v}.4h, {1:v}.4h, {2:v}.4h", vreg, vreg b, vreg a)
+}
+
+/// Floating-point compare less than
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fcmgt))]
+pub unsafe fn vcltq_f16(a: float16x8_t, b: float16x8_t) -> uint16x8_t {
+    fp16_op!("fcmgt {0:v}.8h, {1:v}.8h, {2:v}.8h", vreg, vreg b, vreg a)
+}
+
+/// Floating-point fused multiply-add to accumulator, computing `a + b * c`
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fmla))]
+pub unsafe fn vfma_f16(a: float16x4_t, b: float16x4_t, c: float16x4_t) -> float16x4_t {
+    fp16_acc!("fmla {0:v}.4h, {1:v}.4h, {2:v}.4h", a, b, c)
+}
+
+/// Floating-point fused multiply-add to accumulator, computing `a + b * c`
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fmla))]
+pub unsafe fn vfmaq_f16(a: float16x8_t, b: float16x8_t, c: float16x8_t) -> float16x8_t {
+    fp16_acc!("fmla {0:v}.8h, {1:v}.8h, {2:v}.8h", a, b, c)
+}
+
+/// Floating-point fused multiply-subtract from accumulator, computing `a - b * c`
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fmls))]
+pub unsafe fn vfms_f16(a: float16x4_t, b: float16x4_t, c: float16x4_t) -> float16x4_t {
+    fp16_acc!("fmls {0:v}.4h, {1:v}.4h, {2:v}.4h", a, b, c)
+}
+
+/// Floating-point fused multiply-subtract from accumulator, computing `a - b * c`
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fmls))]
+pub unsafe fn vfmsq_f16(a: float16x8_t, b: float16x8_t, c: float16x8_t) -> float16x8_t {
+    fp16_acc!("fmls {0:v}.8h, {1:v}.8h, {2:v}.8h", a, b, c)
+}
+
+/// Floating-point fused multiply-add to accumulator, with element `LANE` of
+/// `c` used for all elements of `b`
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fmla, LANE = 3))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn vfma_lane_f16<const LANE: i32>(
+    a: float16x4_t,
+    b: float16x4_t,
+    c: float16x4_t,
+) -> float16x4_t {
+    static_assert_imm2!(LANE);
+    let c: float16x4_t = simd_shuffle4(c, c, [LANE as u32, LANE as u32, LANE as u32, LANE as u32]);
+    vfma_f16(a, b, c)
+}
+
+/// Floating-point fused multiply-add to accumulator, with element `LANE` of
+/// `c` used for all elements of `b`
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fmla, LANE = 7))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn vfma_laneq_f16<const LANE: i32>(
+    a: float16x4_t,
+    b: float16x4_t,
+    c: float16x8_t,
+) -> float16x4_t {
+    static_assert_imm3!(LANE);
+    let c: float16x4_t = simd_shuffle4(c, c, [LANE as u32, LANE as u32, LANE as u32, LANE as u32]);
+    vfma_f16(a, b, c)
+}
+
+/// Floating-point fused multiply-add to accumulator, with element `LANE` of
+/// `c` used for all elements of `b`
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fmla, LANE = 3))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn vfmaq_lane_f16<const LANE: i32>(
+    a: float16x8_t,
+    b: float16x8_t,
+    c: float16x4_t,
+) -> float16x8_t {
+    static_assert_imm2!(LANE);
+    let c: float16x8_t = simd_shuffle8(
+        c,
+        c,
+        [
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+        ],
+    );
+    vfmaq_f16(a, b, c)
+}
+
+/// Floating-point fused multiply-add to accumulator, with element `LANE` of
+/// `c` used for all elements of `b`
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fmla, LANE = 7))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn vfmaq_laneq_f16<const LANE: i32>(
+    a: float16x8_t,
+    b: float16x8_t,
+    c: float16x8_t,
+) -> float16x8_t {
+    static_assert_imm3!(LANE);
+    let c: float16x8_t = simd_shuffle8(
+        c,
+        c,
+        [
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+        ],
+    );
+    vfmaq_f16(a, b, c)
+}
+
+/// Fixed-point convert to floating-point
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(scvtf))]
+pub unsafe fn vcvt_f16_s16(a: int16x4_t) -> float16x4_t {
+    fp16_op!("scvtf {0:v}.4h, {1:v}.4h", vreg, vreg a)
+}
+
+/// Fixed-point convert to floating-point
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(scvtf))]
+pub unsafe fn vcvtq_f16_s16(a: int16x8_t) -> float16x8_t {
+    fp16_op!("scvtf {0:v}.8h, {1:v}.8h", vreg, vreg a)
+}
+
+/// Fixed-point convert to floating-point
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(ucvtf))]
+pub unsafe fn vcvt_f16_u16(a: uint16x4_t) -> float16x4_t {
+    fp16_op!("ucvtf {0:v}.4h, {1:v}.4h", vreg, vreg a)
+}
+
+/// Fixed-point convert to floating-point
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(ucvtf))]
+pub unsafe fn vcvtq_f16_u16(a: uint16x8_t) -> float16x8_t {
+    fp16_op!("ucvtf {0:v}.8h, {1:v}.8h", vreg, vreg a)
+}
+
+/// Floating-point convert to signed fixed-point, rounding toward zero
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fcvtzs))]
+pub unsafe fn vcvt_s16_f16(a: float16x4_t) -> int16x4_t {
+    fp16_op!("fcvtzs {0:v}.4h, {1:v}.4h", vreg, vreg a)
+}
+
+/// Floating-point convert to signed fixed-point, rounding toward zero
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fcvtzs))]
+pub unsafe fn vcvtq_s16_f16(a: float16x8_t) -> int16x8_t {
+    fp16_op!("fcvtzs {0:v}.8h, {1:v}.8h", vreg, vreg a)
+}
+
+/// Floating-point convert to unsigned fixed-point, rounding toward zero
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fcvtzu))]
+pub unsafe fn vcvt_u16_f16(a: float16x4_t) -> uint16x4_t {
+    fp16_op!("fcvtzu {0:v}.4h, {1:v}.4h", vreg, vreg a)
+}
+
+/// Floating-point convert to unsigned fixed-point, rounding toward zero
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fcvtzu))]
+pub unsafe fn vcvtq_u16_f16(a: float16x8_t) -> uint16x8_t {
+    fp16_op!("fcvtzu {0:v}.8h, {1:v}.8h", vreg, vreg a)
+}
+
+/// Floating-point convert to signed integer, rounding to nearest with ties to away
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fcvtas))]
+pub unsafe fn vcvta_s16_f16(a: float16x4_t) -> int16x4_t {
+    fp16_op!("fcvtas {0:v}.4h, {1:v}.4h", vreg, vreg a)
+}
+
+/// Floating-point convert to signed integer, rounding to nearest with ties to away
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fcvtas))]
+pub unsafe fn vcvtaq_s16_f16(a: float16x8_t) -> int16x8_t {
+    fp16_op!("fcvtas {0:v}.8h, {1:v}.8h", vreg, vreg a)
+}
+
+/// Floating-point convert to unsigned integer, rounding to nearest with ties to away
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fcvtau))]
+pub unsafe fn vcvta_u16_f16(a: float16x4_t) -> uint16x4_t {
+    fp16_op!("fcvtau {0:v}.4h, {1:v}.4h", vreg, vreg a)
+}
+
+/// Floating-point convert to unsigned integer, rounding to nearest with ties to away
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fcvtau))]
+pub unsafe fn vcvtaq_u16_f16(a: float16x8_t) -> uint16x8_t {
+    fp16_op!("fcvtau {0:v}.8h, {1:v}.8h", vreg, vreg a)
+}
+
+/// Floating-point convert to signed integer, rounding toward minus infinity
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fcvtms))]
+pub unsafe fn vcvtm_s16_f16(a: float16x4_t) -> int16x4_t {
+    fp16_op!("fcvtms {0:v}.4h, {1:v}.4h", vreg, vreg a)
+}
+
+/// Floating-point convert to signed integer, rounding toward minus infinity
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fcvtms))]
+pub unsafe fn vcvtmq_s16_f16(a: float16x8_t) -> int16x8_t {
+    fp16_op!("fcvtms {0:v}.8h, {1:v}.8h", vreg, vreg a)
+}
+
+/// Floating-point convert to unsigned integer, rounding toward minus infinity
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fcvtmu))]
+pub unsafe fn vcvtm_u16_f16(a: float16x4_t) -> uint16x4_t {
+    fp16_op!("fcvtmu {0:v}.4h, {1:v}.4h", vreg, vreg a)
+}
+
+/// Floating-point convert to unsigned integer, rounding toward minus infinity
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fcvtmu))]
+pub unsafe fn vcvtmq_u16_f16(a: float16x8_t) -> uint16x8_t {
+    fp16_op!("fcvtmu {0:v}.8h, {1:v}.8h", vreg, vreg a)
+}
+
+/// Floating-point convert to signed integer, rounding to nearest with ties to even
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fcvtns))]
+pub unsafe fn vcvtn_s16_f16(a: float16x4_t) -> int16x4_t {
+    fp16_op!("fcvtns {0:v}.4h, {1:v}.4h", vreg, vreg a)
+}
+
+/// Floating-point convert to signed integer, rounding to nearest with ties to even
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fcvtns))]
+pub unsafe fn vcvtnq_s16_f16(a: float16x8_t) -> int16x8_t {
+    fp16_op!("fcvtns {0:v}.8h, {1:v}.8h", vreg, vreg a)
+}
+
+/// Floating-point convert to unsigned integer, rounding to nearest with ties to even
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fcvtnu))]
+pub unsafe fn vcvtn_u16_f16(a: float16x4_t) -> uint16x4_t {
+    fp16_op!("fcvtnu {0:v}.4h, {1:v}.4h", vreg, vreg a)
+}
+
+/// Floating-point convert to unsigned integer, rounding to nearest with ties to even
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fcvtnu))]
+pub unsafe fn vcvtnq_u16_f16(a: float16x8_t) -> uint16x8_t {
+    fp16_op!("fcvtnu {0:v}.8h, {1:v}.8h", vreg, vreg a)
+}
+
+/// Floating-point convert to signed integer, rounding toward plus infinity
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fcvtps))]
+pub unsafe fn vcvtp_s16_f16(a: float16x4_t) -> int16x4_t {
+    fp16_op!("fcvtps {0:v}.4h, {1:v}.4h", vreg, vreg a)
+}
+
+/// Floating-point convert to signed integer, rounding toward plus infinity
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fcvtps))]
+pub unsafe fn vcvtpq_s16_f16(a: float16x8_t) -> int16x8_t {
+    fp16_op!("fcvtps {0:v}.8h, {1:v}.8h", vreg, vreg a)
+}
+
+/// Floating-point convert to unsigned integer, rounding toward plus infinity
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fcvtpu))]
+pub unsafe fn vcvtp_u16_f16(a: float16x4_t) -> uint16x4_t {
+    fp16_op!("fcvtpu {0:v}.4h, {1:v}.4h", vreg, vreg a)
+}
+
+/// Floating-point convert to unsigned integer, rounding toward plus infinity
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fcvtpu))]
+pub unsafe fn vcvtpq_u16_f16(a: float16x8_t) -> uint16x8_t {
+    fp16_op!("fcvtpu {0:v}.8h, {1:v}.8h", vreg, vreg a)
+}
+
+/// Floating-point maximum across vector
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fmaxv))]
+pub unsafe fn vmaxv_f16(a: float16x4_t) -> u16 {
+    fp16_op!("fmaxv {0:h}, {1:v}.4h", vreg, vreg a)
+}
+
+/// Floating-point maximum across vector
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fmaxv))]
+pub unsafe fn vmaxvq_f16(a: float16x8_t) -> u16 {
+    fp16_op!("fmaxv {0:h}, {1:v}.8h", vreg, vreg a)
+}
+
+/// Floating-point minimum across vector
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fminv))]
+pub unsafe fn vminv_f16(a: float16x4_t) -> u16 {
+    fp16_op!("fminv {0:h}, {1:v}.4h", vreg, vreg a)
+}
+
+/// Floating-point minimum across vector
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fminv))]
+pub unsafe fn vminvq_f16(a: float16x8_t) -> u16 {
+    fp16_op!("fminv {0:h}, {1:v}.8h", vreg, vreg a)
+}
+
+/// Floating-point maximum number across vector
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fmaxnmv))]
+pub unsafe fn vmaxnmv_f16(a: float16x4_t) -> u16 {
+    fp16_op!("fmaxnmv {0:h}, {1:v}.4h", vreg, vreg a)
+}
+
+/// Floating-point maximum number across vector
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fmaxnmv))]
+pub unsafe fn vmaxnmvq_f16(a: float16x8_t) -> u16 {
+    fp16_op!("fmaxnmv {0:h}, {1:v}.8h", vreg, vreg a)
+}
+
+/// Floating-point minimum number across vector
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fminnmv))]
+pub unsafe fn vminnmv_f16(a: float16x4_t) -> u16 {
+    fp16_op!("fminnmv {0:h}, {1:v}.4h", vreg, vreg a)
+}
+
+/// Floating-point minimum number across vector
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fminnmv))]
+pub unsafe fn vminnmvq_f16(a: float16x8_t) -> u16 {
+    fp16_op!("fminnmv {0:h}, {1:v}.8h", vreg, vreg a)
+}
+
+// Conversions between `f32` and half-precision vectors
+
+/// Floating-point convert to lower precision narrow
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(test, assert_instr(fcvtn))]
+pub unsafe fn vcvt_f16_f32(a: float32x4_t) -> float16x4_t {
+    fp16_op!("fcvtn {0:v}.4h, {1:v}.4s", vreg, vreg a)
+}
+
+/// Floating-point convert to lower precision narrow
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(test, assert_instr(fcvtn))]
+pub unsafe fn vcvt_high_f16_f32(r: float16x4_t, a: float32x4_t) -> float16x8_t {
+    simd_shuffle8(r, vcvt_f16_f32(a), [0, 1, 2, 3, 4, 5, 6, 7])
+}
+
+/// Floating-point convert to higher precision long
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(test, assert_instr(fcvtl))]
+pub unsafe fn vcvt_f32_f16(a: float16x4_t) -> float32x4_t {
+    fp16_op!("fcvtl {0:v}.4s, {1:v}.4h", vreg, vreg a)
+}
+
+/// Floating-point convert to higher precision long
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(test, assert_instr(fcvtl))]
+pub unsafe fn vcvt_high_f32_f16(a: float16x8_t) -> float32x4_t {
+    let a: float16x4_t = simd_shuffle4(a, a, [4, 5, 6, 7]);
+    vcvt_f32_f16(a)
+}
+
+// Loads and stores of several vectors, which are the same as for `u16`
+
+/// Load multiple single-element structures to one, two, three, or four registers
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(test, assert_instr(ld1))]
+pub unsafe fn vld1_f16_x2(a: *const u16) -> float16x4x2_t {
+    transmute(vld1_u16_x2(a))
+}
+
+/// Load multiple single-element structures to one, two, three, or four registers
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(test, assert_instr(ld1))]
+pub unsafe fn vld1_f16_x3(a: *const u16) -> float16x4x3_t {
+    transmute(vld1_u16_x3(a))
+}
+
+/// Load multiple single-element structures to one, two, three, or four registers
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(test, assert_instr(ld1))]
+pub unsafe fn vld1_f16_x4(a: *const u16) -> float16x4x4_t {
+    transmute(vld1_u16_x4(a))
+}
+
+/// Load multiple single-element structures to one, two, three, or four registers
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(test, assert_instr(ld1))]
+pub unsafe fn vld1q_f16_x2(a: *const u16) -> float16x8x2_t {
+    transmute(vld1q_u16_x2(a))
+}
+
+/// Load multiple single-element structures to one, two, three, or four registers
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(test, assert_instr(ld1))]
+pub unsafe fn vld1q_f16_x3(a: *const u16) -> float16x8x3_t {
+    transmute(vld1q_u16_x3(a))
+}
+
+/// Load multiple single-element structures to one, two, three, or four registers
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(test, assert_instr(ld1))]
+pub unsafe fn vld1q_f16_x4(a: *const u16) -> float16x8x4_t {
+    transmute(vld1q_u16_x4(a))
+}
+
+/// Store multiple single-element structures from one, two, three, or four registers
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(test, assert_instr(st1))]
+pub unsafe fn vst1_f16_x2(a: *mut u16, b: float16x4x2_t) {
+    vst1_u16_x2(a, transmute(b))
+}
+
+/// Store multiple single-element structures from one, two, three, or four registers
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(test, assert_instr(st1))]
+pub unsafe fn vst1_f16_x3(a: *mut u16, b: float16x4x3_t) {
+    vst1_u16_x3(a, transmute(b))
+}
+
+/// Store multiple single-element structures from one, two, three, or four registers
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(test, assert_instr(st1))]
+pub unsafe fn vst1_f16_x4(a: *mut u16, b: float16x4x4_t) {
+    vst1_u16_x4(a, transmute(b))
+}
+
+/// Store multiple single-element structures from one, two, three, or four registers
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(test, assert_instr(st1))]
+pub unsafe fn vst1q_f16_x2(a: *mut u16, b: float16x8x2_t) {
+    vst1q_u16_x2(a, transmute(b))
+}
+
+/// Store multiple single-element structures from one, two, three, or four registers
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(test, assert_instr(st1))]
+pub unsafe fn vst1q_f16_x3(a: *mut u16, b: float16x8x3_t) {
+    vst1q_u16_x3(a, transmute(b))
+}
+
+/// Store multiple single-element structures from one, two, three, or four registers
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(test, assert_instr(st1))]
+pub unsafe fn vst1q_f16_x4(a: *mut u16, b: float16x8x4_t) {
+    vst1q_u16_x4(a, transmute(b))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::aarch64::*;
+    use std::{
+        mem::{self, transmute_copy},
+        slice,
+    };
+    use stdarch_test::simd_test;
+
+    const NAN: u16 = 0x7e00;
+
+    fn is_nan(x: u16) -> bool {
+        x & 0x7fff > 0x7c00
+    }
+
+    // Exact for all values, including subnormals and infinities.
+    fn to_f64(x: u16) -> f64 {
+        let sign = if x & 0x8000 != 0 { -1.0 } else { 1.0 };
+        let exp = (x >> 10) & 0x1f;
+        let mant = (x & 0x3ff) as f64;
+        sign * match exp {
+            0 => mant * 2f64.powi(-24),
+            31 if mant == 0.0 => f64::INFINITY,
+            31 => f64::NAN,
+            _ => (1024.0 + mant) * 2f64.powi(exp as i32 - 25),
+        }
+    }
+
+    fn to_f32(x: u16) -> f32 {
+        to_f64(x) as f32
+    }
+
+    // Rounds to nearest with ties to even, the default rounding mode.
+    fn to_f16(x: f64) -> u16 {
+        let bits = x.to_bits();
+        let sign = (bits >> 48) as u16 & 0x8000;
+        let abs = bits & !(1 << 63);
+        if abs > 0x7ff0_0000_0000_0000 {
+            return sign | NAN;
+        }
+        let exp = (abs >> 52) as i32 - 1023;
+        if exp > 15 {
+            return sign | 0x7c00;
+        }
+        if exp < -25 {
+            return sign;
+        }
+        // `mant` is the significand with its implicit bit, and `shift` the
+        // number of its bits that don't fit into the result.
+        let mant = (abs & ((1 << 52) - 1)) | (1 << 52);
+        let (base, shift) = if exp >= -14 {
+            (((exp + 14) as u64) << 10, 42)
+        } else {
+            (0, 28 - exp)
+        };
+        let mut r = base + (mant >> shift);
+        let rem = mant & ((1 << shift) - 1);
+        let half = 1 << (shift - 1);
+        if rem > half || (rem == half && r & 1 == 1) {
+            r += 1;
+        }
+        sign | r as u16
+    }
+
+    // `f32` has more than twice as many significand bits as `binary16`, so
+    // computing the basic operations in `f32` and rounding the result gives
+    // the correctly rounded half-precision result.
+    fn from_f32(x: f32) -> u16 {
+        to_f16(x as f64)
+    }
+
+    fn round_even(x: f32) -> f32 {
+        if (x - x.trunc()).abs() == 0.5 {
+            2.0 * (x / 2.0).round()
+        } else {
+            x.round()
+        }
+    }
+
+    fn max(a: u16, b: u16) -> u16 {
+        let (x, y) = (to_f32(a), to_f32(b));
+        if is_nan(a) || is_nan(b) {
+            NAN
+        } else if x == y {
+            // `+0.0` is greater than `-0.0`.
+            a & b
+        } else if x > y {
+            a
+        } else {
+            b
+        }
+    }
+
+    fn min(a: u16, b: u16) -> u16 {
+        let (x, y) = (to_f32(a), to_f32(b));
+        if is_nan(a) || is_nan(b) {
+            NAN
+        } else if x == y {
+            a | b
+        } else if x < y {
+            a
+        } else {
+            b
+        }
+    }
+
+    // Only correct for quiet NaNs, a signaling one makes the result a NaN.
+    fn maxnm(a: u16, b: u16) -> u16 {
+        match (is_nan(a), is_nan(b)) {
+            (true, false) => b,
+            (false, true) => a,
+            _ => max(a, b),
+        }
+    }
+
+    fn minnm(a: u16, b: u16) -> u16 {
+        match (is_nan(a), is_nan(b)) {
+            (true, false) => b,
+            (false, true) => a,
+            _ => min(a, b),
+        }
+    }
+
+    fn mask(b: bool) -> u16 {
+        if b {
+            !0
+        } else {
+            0
+        }
+    }
+
+    // NaN results only need to be some NaN, for everything else the bits
+    // have to match.
+    fn check_f16(r: u16, e: u16, args: &[u16]) {
+        if is_nan(e) {
+            assert!(is_nan(r), "{:#06x} isn't a NaN for {:#06x?}", r, args);
+        } else {
+            assert_eq!(r, e, "for {:#06x?}", args);
+        }
+    }
+
+    fn check_bits(r: u16, e: u16, args: &[u16]) {
+        assert_eq!(r, e, "for {:#06x?}", args);
+    }
+
+    // Zeros, subnormals, the extreme normals, infinities and NaN, and a few
+    // numbers whose sums, products and quotients need rounding.
+    const SPECIAL: [u16; 24] = [
+        0x0000, 0x8000, 0x0001, 0x8001, 0x03ff, 0x0400, 0x8400, 0x7bff, 0xfbff, 0x7c00, 0xfc00,
+        NAN, 0x3c00, 0xbc00, 0x3c01, 0x3e00, 0x3800, 0xb800, 0x4248, 0x4000, 0x4100, 0xc100,
+        0x3555, 0x5640,
+    ];
+
+    // `SPECIAL` and some arbitrary bit patterns, with NaNs replaced by a
+    // quiet one.
+    fn values() -> Vec<u16> {
+        let mut v = SPECIAL.to_vec();
+        let mut x = 1u32;
+        for _ in 0..64 {
+            x = x.wrapping_mul(1103515245).wrapping_add(12345);
+            let h = (x >> 16) as u16;
+            v.push(if is_nan(h) { NAN } else { h });
+        }
+        v
+    }
+
+    fn all() -> Vec<u16> {
+        (0..=u16::MAX).collect()
+    }
+
+    fn pairs() -> (Vec<u16>, Vec<u16>) {
+        let v = values();
+        let a = v.iter().flat_map(|&a| v.iter().map(move |_| a)).collect();
+        let b = v.iter().flat_map(|_| v.iter().cloned()).collect();
+        (a, b)
+    }
+
+    fn triples() -> (Vec<u16>, Vec<u16>, Vec<u16>) {
+        let v = values();
+        let (a, b) = pairs();
+        let c = (0..a.len())
+            .map(|i| v[(i * 7 + i / v.len()) % v.len()])
+            .collect();
+        (a, b, c)
+    }
+
+    fn lanes<const N: usize>(a: &[u16]) -> [u16; N] {
+        let mut r = [0; N];
+        r.copy_from_slice(a);
+        r
+    }
+
+    // Runs `op` on `a`, `N` lanes at a time, and compares every lane of the
+    // results with `reference`.
+    // Lanes in memory which aren't aligned to the vectors, including NaNs.
+    fn memory(n: usize) -> Vec<u16> {
+        (0..=n as u16).map(|i| i.wrapping_mul(0x1fff)).collect()
+    }
+
+    unsafe fn check_load<T>(load: unsafe fn(*const u16) -> T) {
+        let n = mem::size_of::<T>() / 2;
+        let a = memory(n);
+        let r = load(a[1..].as_ptr());
+        assert_eq!(
+            slice::from_raw_parts(&r as *const T as *const u16, n),
+            &a[1..]
+        );
+    }
+
+    unsafe fn check_store<T>(load: unsafe fn(*const u16) -> T, store: unsafe fn(*mut u16, T)) {
+        let n = mem::size_of::<T>() / 2;
+        let a = memory(n);
+        let mut r = vec![0; n];
+        store(r.as_mut_ptr(), load(a[1..].as_ptr()));
+        assert_eq!(r, &a[1..]);
+    }
+
+    unsafe fn check_unary<A, R, const N: usize>(
+        op: unsafe fn(A) -> R,
+        a: &[u16],
+        reference: impl Fn(u16) -> u16,
+        check: fn(u16, u16, &[u16]),
+    ) {
+        for a in a.chunks_exact(N) {
+            let a: [u16; N] = lanes(a);
+            let r: [u16; N] = transmute_copy(&op(transmute_copy(&a)));
+            for i in 0..N {
+                check(r[i], reference(a[i]), &[a[i]]);
+            }
+        }
+    }
+
+    unsafe fn check_binary<A, R, const N: usize>(
+        op: unsafe fn(A, A) -> R,
+        reference: impl Fn(u16, u16) -> u16,
+        check: fn(u16, u16, &[u16]),
+    ) {
+        let (a, b) = pairs();
+        for (a, b) in a.chunks_exact(N).zip(b.chunks_exact(N)) {
+            let (a, b): ([u16; N], [u16; N]) = (lanes(a), lanes(b));
+            let r: [u16; N] = transmute_copy(&op(transmute_copy(&a), transmute_copy(&b)));
+            for i in 0..N {
+                check(r[i], reference(a[i], b[i]), &[a[i], b[i]]);
+            }
+        }
+    }
+
+    unsafe fn check_ternary<A, const N: usize>(
+        op: unsafe fn(A, A, A) -> A,
+        reference: impl Fn(u16, u16, u16) -> u16,
+    ) {
+        let (a, b, c) = triples();
+        let chunks = a
+            .chunks_exact(N)
+            .zip(b.chunks_exact(N))
+            .zip(c.chunks_exact(N));
+        for ((a, b), c) in chunks {
+            let (a, b, c): ([u16; N], [u16; N], [u16; N]) = (lanes(a), lanes(b), lanes(c));
+            let r: [u16; N] = transmute_copy(&op(
+                transmute_copy(&a),
+                transmute_copy(&b),
+                transmute_copy(&c),
+            ));
+            for i in 0..N {
+                check_f16(r[i], reference(a[i], b[i], c[i]), &[a[i], b[i], c[i]]);
+            }
+        }
+    }
+
+    // Checks a reduction of every group of `N` lanes of `values()`.
+    unsafe fn check_across<A, const N: usize>(
+        op: unsafe fn(A) -> u16,
+        reference: fn(u16, u16) -> u16,
+    ) {
+        for a in values().chunks_exact(N) {
+            let e = a.iter().cloned().fold(a[0], reference);
+            let a: [u16; N] = lanes(a);
+            check_f16(op(transmute_copy(&a)), e, &a);
+        }
+    }
+
+    // Checks `op` with the lanes of `c` in turn, with `N` lanes in the result
+    // and `M` in `c`.
+    unsafe fn check_lane<A, C, const N: usize, const M: usize>(op: impl Fn(A, A, C, usize) -> A) {
+        let v = values();
+        let (a, b, c): ([u16; N], [u16; N], [u16; M]) = (
+            lanes(&v[24..24 + N]),
+            lanes(&v[32..32 + N]),
+            lanes(&v[40..40 + M]),
+        );
+        for lane in 0..M {
+            let r: [u16; N] = transmute_copy(&op(
+                transmute_copy(&a),
+                transmute_copy(&b),
+                transmute_copy(&c),
+                lane,
+            ));
+            for i in 0..N {
+                let e = fma(a[i], b[i], c[lane]);
+                check_f16(r[i], e, &[a[i], b[i], c[lane]]);
+            }
+        }
+    }
+
+    unsafe fn check_scalar_unary(op: unsafe fn(u16) -> u16, reference: impl Fn(u16) -> u16) {
+        for a in all() {
+            check_f16(op(a), reference(a), &[a]);
+        }
+    }
+
+    unsafe fn check_scalar_binary(
+        op: unsafe fn(u16, u16) -> u16,
+        reference: impl Fn(u16, u16) -> u16,
+        check: fn(u16, u16, &[u16]),
+    ) {
+        let (a, b) = pairs();
+        for (&a, &b) in a.iter().zip(&b) {
+            check(op(a, b), reference(a, b), &[a, b]);
+        }
+    }
+
+    // Every `binary16` value and numbers around the ties between neighbouring
+    // ones.
+    fn floats() -> Vec<f32> {
+        let mut v = Vec::new();
+        for x in all() {
+            let bits = to_f32(x).to_bits();
+            for &d in &[0, 0x0fff, 0x1000, 0x1001] {
+                v.push(f32::from_bits(bits.wrapping_add(d)));
+            }
+        }
+        v
+    }
+
+    fn check_f32(r: f32, a: u16) {
+        if is_nan(a) {
+            assert!(r.is_nan(), "{} isn't a NaN for {:#06x}", r, a);
+        } else {
+            assert_eq!(r.to_bits(), to_f32(a).to_bits(), "for {:#06x}", a);
+        }
+    }
+
+    fn fma(a: u16, b: u16, c: u16) -> u16 {
+        // The product is exact in `f64`, and the sum is either exact as well
+        // or too far from a tie to round differently.
+        to_f16(to_f64(a) + to_f64(b) * to_f64(c))
+    }
+
+    fn fms(a: u16, b: u16, c: u16) -> u16 {
+        to_f16(to_f64(a) - to_f64(b) * to_f64(c))
+    }
+
+    #[test]
+    fn test_reference_conversions() {
+        assert_eq!(to_f16(1.0), 0x3c00);
+        assert_eq!(to_f16(-2.0), 0xc000);
+        assert_eq!(to_f16(65504.0), 0x7bff);
+        assert_eq!(to_f16(65519.99), 0x7bff);
+        assert_eq!(to_f16(65520.0), 0x7c00);
+        assert_eq!(to_f16(2f64.powi(-24)), 0x0001);
+        assert_eq!(to_f16(2f64.powi(-25)), 0x0000);
+        assert_eq!(to_f16(1.5 * 2f64.powi(-25)), 0x0001);
+        assert_eq!(to_f16(1.0 + 2f64.powi(-11)), 0x3c00);
+        assert_eq!(to_f16(1.0 + 3.0 * 2f64.powi(-11)), 0x3c02);
+        assert_eq!(to_f16(-0.0), 0x8000);
+        for x in all().into_iter().filter(|&x| !is_nan(x)) {
+            assert_eq!(to_f16(to_f64(x)), x);
+        }
+        assert_eq!(round_even(2.5), 2.0);
+        assert_eq!(round_even(-3.5), -4.0);
+        assert_eq!(round_even(0.75), 1.0);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vabsh_f16() {
+        for a in all() {
+            assert_eq!(vabsh_f16(a), a & 0x7fff);
+        }
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vnegh_f16() {
+        for a in all() {
+            assert_eq!(vnegh_f16(a), a ^ 0x8000);
+        }
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vsqrth_f16() {
+        check_scalar_unary(vsqrth_f16, |a| from_f32(to_f32(a).sqrt()));
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vrndh_f16() {
+        check_scalar_unary(vrndh_f16, |a| from_f32(to_f32(a).trunc()));
+        assert_eq!(vrndh_f16(0xb800), 0x8000);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vrndah_f16() {
+        check_scalar_unary(vrndah_f16, |a| from_f32(to_f32(a).round()));
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vrndmh_f16() {
+        check_scalar_unary(vrndmh_f16, |a| from_f32(to_f32(a).floor()));
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vrndnh_f16() {
+        check_scalar_unary(vrndnh_f16, |a| from_f32(round_even(to_f32(a))));
+        assert_eq!(vrndnh_f16(0x4100), 0x4000);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vrndph_f16() {
+        check_scalar_unary(vrndph_f16, |a| from_f32(to_f32(a).ceil()));
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vrndxh_f16() {
+        check_scalar_unary(vrndxh_f16, |a| from_f32(round_even(to_f32(a))));
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vrndih_f16() {
+        check_scalar_unary(vrndih_f16, |a| from_f32(round_even(to_f32(a))));
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vaddh_f16() {
+        let reference = |a, b| from_f32(to_f32(a) + to_f32(b));
+        check_scalar_binary(vaddh_f16, reference, check_f16);
+        assert_eq!(vaddh_f16(0x3c00, 0x1000), 0x3c00);
+        assert_eq!(vaddh_f16(0x7bff, 0x7bff), 0x7c00);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vsubh_f16() {
+        let reference = |a, b| from_f32(to_f32(a) - to_f32(b));
+        check_scalar_binary(vsubh_f16, reference, check_f16);
+        assert_eq!(vsubh_f16(0x3c00, 0x3c00), 0x0000);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vmulh_f16() {
+        let reference = |a, b| from_f32(to_f32(a) * to_f32(b));
+        check_scalar_binary(vmulh_f16, reference, check_f16);
+        assert!(is_nan(vmulh_f16(0x7c00, 0x0000)));
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vdivh_f16() {
+        let reference = |a, b| from_f32(to_f32(a) / to_f32(b));
+        check_scalar_binary(vdivh_f16, reference, check_f16);
+        assert_eq!(vdivh_f16(0xbc00, 0x0000), 0xfc00);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vabdh_f16() {
+        let reference = |a, b| from_f32((to_f32(a) - to_f32(b)).abs());
+        check_scalar_binary(vabdh_f16, reference, check_f16);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vmaxh_f16() {
+        check_scalar_binary(vmaxh_f16, max, check_f16);
+        assert_eq!(vmaxh_f16(0x8000, 0x0000), 0x0000);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vminh_f16() {
+        check_scalar_binary(vminh_f16, min, check_f16);
+        assert_eq!(vminh_f16(0x0000, 0x8000), 0x8000);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vmaxnmh_f16() {
+        check_scalar_binary(vmaxnmh_f16, maxnm, check_f16);
+        assert_eq!(vmaxnmh_f16(NAN, 0xbc00), 0xbc00);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vminnmh_f16() {
+        check_scalar_binary(vminnmh_f16, minnm, check_f16);
+        assert_eq!(vminnmh_f16(0x3c00, NAN), 0x3c00);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vceqh_f16() {
+        let reference = |a, b| mask(to_f32(a) == to_f32(b));
+        check_scalar_binary(vceqh_f16, reference, check_bits);
+        assert_eq!(vceqh_f16(0x0000, 0x8000), 0xffff);
+        assert_eq!(vceqh_f16(NAN, NAN), 0);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcgeh_f16() {
+        let reference = |a, b| mask(to_f32(a) >= to_f32(b));
+        check_scalar_binary(vcgeh_f16, reference, check_bits);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcgth_f16() {
+        let reference = |a, b| mask(to_f32(a) > to_f32(b));
+        check_scalar_binary(vcgth_f16, reference, check_bits);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcleh_f16() {
+        let reference = |a, b| mask(to_f32(a) <= to_f32(b));
+        check_scalar_binary(vcleh_f16, reference, check_bits);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vclth_f16() {
+        let reference = |a, b| mask(to_f32(a) < to_f32(b));
+        check_scalar_binary(vclth_f16, reference, check_bits);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vfmah_f16() {
+        let (a, b, c) = triples();
+        for i in 0..a.len() {
+            let (a, b, c) = (a[i], b[i], c[i]);
+            check_f16(vfmah_f16(a, b, c), fma(a, b, c), &[a, b, c]);
+        }
+        // (1 + 2^-10)^2 - (1 + 2^-9) is 2^-20, which is lost when the product
+        // is rounded first.
+        assert_eq!(vfmah_f16(0xbc02, 0x3c01, 0x3c01), 0x0010);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vfmsh_f16() {
+        let (a, b, c) = triples();
+        for i in 0..a.len() {
+            let (a, b, c) = (a[i], b[i], c[i]);
+            check_f16(vfmsh_f16(a, b, c), fms(a, b, c), &[a, b, c]);
+        }
+        assert_eq!(vfmsh_f16(0x4000, 0x3c00, 0x4000), 0x0000);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvth_f16_s32() {
+        for x in (-70_000..=70_000).chain(vec![i32::MIN, i32::MAX]) {
+            assert_eq!(vcvth_f16_s32(x), to_f16(x as f64), "for {}", x);
+        }
+        // 2049 is a tie between 2048 and 2050, and 2051 one between 2050 and 2052.
+        assert_eq!(vcvth_f16_s32(2049), 0x6800);
+        assert_eq!(vcvth_f16_s32(2051), 0x6802);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvth_f16_u32() {
+        for x in (0..=140_000).chain(vec![u32::MAX]) {
+            assert_eq!(vcvth_f16_u32(x), to_f16(x as f64), "for {}", x);
+        }
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvth_f16_s64() {
+        for x in (-70_000..=70_000).chain(vec![i64::MIN, i64::MAX]) {
+            assert_eq!(vcvth_f16_s64(x), to_f16(x as f64), "for {}", x);
+        }
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvth_f16_u64() {
+        for x in (0..=140_000).chain(vec![u64::MAX]) {
+            assert_eq!(vcvth_f16_u64(x), to_f16(x as f64), "for {}", x);
+        }
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvth_s32_f16() {
+        // `as` saturates and converts NaN to zero, like the instruction.
+        for a in all() {
+            assert_eq!(vcvth_s32_f16(a), to_f32(a).trunc() as i32, "for {:#06x}", a);
+        }
+        assert_eq!(vcvth_s32_f16(0xc0f0), -2);
+        assert_eq!(vcvth_s32_f16(NAN), 0);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvth_u32_f16() {
+        for a in all() {
+            assert_eq!(vcvth_u32_f16(a), to_f32(a).trunc() as u32, "for {:#06x}", a);
+        }
+        assert_eq!(vcvth_u32_f16(0xc0f0), 0);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvth_s64_f16() {
+        for a in all() {
+            assert_eq!(vcvth_s64_f16(a), to_f32(a).trunc() as i64, "for {:#06x}", a);
+        }
+        assert_eq!(vcvth_s64_f16(0xfc00), i64::MIN);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvth_u64_f16() {
+        for a in all() {
+            assert_eq!(vcvth_u64_f16(a), to_f32(a).trunc() as u64, "for {:#06x}", a);
+        }
+        assert_eq!(vcvth_u64_f16(0x7c00), u64::MAX);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvtah_s32_f16() {
+        for a in all() {
+            assert_eq!(
+                vcvtah_s32_f16(a),
+                to_f32(a).round() as i32,
+                "for {:#06x}",
+                a
+            );
+        }
+        assert_eq!(vcvtah_s32_f16(0xc100), -3);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvtah_u32_f16() {
+        for a in all() {
+            assert_eq!(
+                vcvtah_u32_f16(a),
+                to_f32(a).round() as u32,
+                "for {:#06x}",
+                a
+            );
+        }
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvtah_s64_f16() {
+        for a in all() {
+            assert_eq!(
+                vcvtah_s64_f16(a),
+                to_f32(a).round() as i64,
+                "for {:#06x}",
+                a
+            );
+        }
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvtah_u64_f16() {
+        for a in all() {
+            assert_eq!(
+                vcvtah_u64_f16(a),
+                to_f32(a).round() as u64,
+                "for {:#06x}",
+                a
+            );
+        }
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvtmh_s32_f16() {
+        for a in all() {
+            assert_eq!(
+                vcvtmh_s32_f16(a),
+                to_f32(a).floor() as i32,
+                "for {:#06x}",
+                a
+            );
+        }
+        assert_eq!(vcvtmh_s32_f16(0xb800), -1);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvtmh_u32_f16() {
+        for a in all() {
+            assert_eq!(
+                vcvtmh_u32_f16(a),
+                to_f32(a).floor() as u32,
+                "for {:#06x}",
+                a
+            );
+        }
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvtmh_s64_f16() {
+        for a in all() {
+            assert_eq!(
+                vcvtmh_s64_f16(a),
+                to_f32(a).floor() as i64,
+                "for {:#06x}",
+                a
+            );
+        }
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvtmh_u64_f16() {
+        for a in all() {
+            assert_eq!(
+                vcvtmh_u64_f16(a),
+                to_f32(a).floor() as u64,
+                "for {:#06x}",
+                a
+            );
+        }
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvtnh_s32_f16() {
+        for a in all() {
+            assert_eq!(
+                vcvtnh_s32_f16(a),
+                round_even(to_f32(a)) as i32,
+                "for {:#06x}",
+                a
+            );
+        }
+        assert_eq!(vcvtnh_s32_f16(0xc100), -2);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvtnh_u32_f16() {
+        for a in all() {
+            assert_eq!(
+                vcvtnh_u32_f16(a),
+                round_even(to_f32(a)) as u32,
+                "for {:#06x}",
+                a
+            );
+        }
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvtnh_s64_f16() {
+        for a in all() {
+            assert_eq!(
+                vcvtnh_s64_f16(a),
+                round_even(to_f32(a)) as i64,
+                "for {:#06x}",
+                a
+            );
+        }
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvtnh_u64_f16() {
+        for a in all() {
+            assert_eq!(
+                vcvtnh_u64_f16(a),
+                round_even(to_f32(a)) as u64,
+                "for {:#06x}",
+                a
+            );
+        }
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvtph_s32_f16() {
+        for a in all() {
+            assert_eq!(vcvtph_s32_f16(a), to_f32(a).ceil() as i32, "for {:#06x}", a);
+        }
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvtph_u32_f16() {
+        for a in all() {
+            assert_eq!(vcvtph_u32_f16(a), to_f32(a).ceil() as u32, "for {:#06x}", a);
+        }
+        assert_eq!(vcvtph_u32_f16(0x3800), 1);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvtph_s64_f16() {
+        for a in all() {
+            assert_eq!(vcvtph_s64_f16(a), to_f32(a).ceil() as i64, "for {:#06x}", a);
+        }
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvtph_u64_f16() {
+        for a in all() {
+            assert_eq!(vcvtph_u64_f16(a), to_f32(a).ceil() as u64, "for {:#06x}", a);
+        }
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vabs_f16() {
+        check_unary::<_, _, 4>(vabs_f16, &all(), |a| a & 0x7fff, check_bits);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vabsq_f16() {
+        check_unary::<_, _, 8>(vabsq_f16, &all(), |a| a & 0x7fff, check_bits);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vneg_f16() {
+        check_unary::<_, _, 4>(vneg_f16, &all(), |a| a ^ 0x8000, check_bits);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vnegq_f16() {
+        check_unary::<_, _, 8>(vnegq_f16, &all(), |a| a ^ 0x8000, check_bits);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vsqrt_f16() {
+        let reference = |a| from_f32(to_f32(a).sqrt());
+        check_unary::<_, _, 4>(vsqrt_f16, &all(), reference, check_f16);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vsqrtq_f16() {
+        let reference = |a| from_f32(to_f32(a).sqrt());
+        check_unary::<_, _, 8>(vsqrtq_f16, &all(), reference, check_f16);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vrnd_f16() {
+        let reference = |a| from_f32(to_f32(a).trunc());
+        check_unary::<_, _, 4>(vrnd_f16, &all(), reference, check_f16);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vrndq_f16() {
+        let reference = |a| from_f32(to_f32(a).trunc());
+        check_unary::<_, _, 8>(vrndq_f16, &all(), reference, check_f16);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vrnda_f16() {
+        let reference = |a| from_f32(to_f32(a).round());
+        check_unary::<_, _, 4>(vrnda_f16, &all(), reference, check_f16);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vrndaq_f16() {
+        let reference = |a| from_f32(to_f32(a).round());
+        check_unary::<_, _, 8>(vrndaq_f16, &all(), reference, check_f16);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vrndm_f16() {
+        let reference = |a| from_f32(to_f32(a).floor());
+        check_unary::<_, _, 4>(vrndm_f16, &all(), reference, check_f16);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vrndmq_f16() {
+        let reference = |a| from_f32(to_f32(a).floor());
+        check_unary::<_, _, 8>(vrndmq_f16, &all(), reference, check_f16);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vrndn_f16() {
+        let reference = |a| from_f32(round_even(to_f32(a)));
+        check_unary::<_, _, 4>(vrndn_f16, &all(), reference, check_f16);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vrndnq_f16() {
+        let reference = |a| from_f32(round_even(to_f32(a)));
+        check_unary::<_, _, 8>(vrndnq_f16, &all(), reference, check_f16);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vrndp_f16() {
+        let reference = |a| from_f32(to_f32(a).ceil());
+        check_unary::<_, _, 4>(vrndp_f16, &all(), reference, check_f16);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vrndpq_f16() {
+        let reference = |a| from_f32(to_f32(a).ceil());
+        check_unary::<_, _, 8>(vrndpq_f16, &all(), reference, check_f16);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vrndx_f16() {
+        let reference = |a| from_f32(round_even(to_f32(a)));
+        check_unary::<_, _, 4>(vrndx_f16, &all(), reference, check_f16);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vrndxq_f16() {
+        let reference = |a| from_f32(round_even(to_f32(a)));
+        check_unary::<_, _, 8>(vrndxq_f16, &all(), reference, check_f16);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vrndi_f16() {
+        let reference = |a| from_f32(round_even(to_f32(a)));
+        check_unary::<_, _, 4>(vrndi_f16, &all(), reference, check_f16);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vrndiq_f16() {
+        let reference = |a| from_f32(round_even(to_f32(a)));
+        check_unary::<_, _, 8>(vrndiq_f16, &all(), reference, check_f16);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vadd_f16() {
+        let reference = |a, b| from_f32(to_f32(a) + to_f32(b));
+        check_binary::<_, _, 4>(vadd_f16, reference, check_f16);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vaddq_f16() {
+        let reference = |a, b| from_f32(to_f32(a) + to_f32(b));
+        check_binary::<_, _, 8>(vaddq_f16, reference, check_f16);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vsub_f16() {
+        let reference = |a, b| from_f32(to_f32(a) - to_f32(b));
+        check_binary::<_, _, 4>(vsub_f16, reference, check_f16);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vsubq_f16() {
+        let reference = |a, b| from_f32(to_f32(a) - to_f32(b));
+        check_binary::<_, _, 8>(vsubq_f16, reference, check_f16);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vmul_f16() {
+        let reference = |a, b| from_f32(to_f32(a) * to_f32(b));
+        check_binary::<_, _, 4>(vmul_f16, reference, check_f16);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vmulq_f16() {
+        let reference = |a, b| from_f32(to_f32(a) * to_f32(b));
+        check_binary::<_, _, 8>(vmulq_f16, reference, check_f16);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vdiv_f16() {
+        let reference = |a, b| from_f32(to_f32(a) / to_f32(b));
+        check_binary::<_, _, 4>(vdiv_f16, reference, check_f16);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vdivq_f16() {
+        let reference = |a, b| from_f32(to_f32(a) / to_f32(b));
+        check_binary::<_, _, 8>(vdivq_f16, reference, check_f16);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vabd_f16() {
+        let reference = |a, b| from_f32((to_f32(a) - to_f32(b)).abs());
+        check_binary::<_, _, 4>(vabd_f16, reference, check_f16);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vabdq_f16() {
+        let reference = |a, b| from_f32((to_f32(a) - to_f32(b)).abs());
+        check_binary::<_, _, 8>(vabdq_f16, reference, check_f16);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vmax_f16() {
+        check_binary::<_, _, 4>(vmax_f16, max, check_f16);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vmaxq_f16() {
+        check_binary::<_, _, 8>(vmaxq_f16, max, check_f16);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vmin_f16() {
+        check_binary::<_, _, 4>(vmin_f16, min, check_f16);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vminq_f16() {
+        check_binary::<_, _, 8>(vminq_f16, min, check_f16);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vmaxnm_f16() {
+        check_binary::<_, _, 4>(vmaxnm_f16, maxnm, check_f16);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vmaxnmq_f16() {
+        check_binary::<_, _, 8>(vmaxnmq_f16, maxnm, check_f16);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vminnm_f16() {
+        check_binary::<_, _, 4>(vminnm_f16, minnm, check_f16);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vminnmq_f16() {
+        check_binary::<_, _, 8>(vminnmq_f16, minnm, check_f16);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vceq_f16() {
+        let reference = |a, b| mask(to_f32(a) == to_f32(b));
+        check_binary::<_, _, 4>(vceq_f16, reference, check_bits);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vceqq_f16() {
+        let reference = |a, b| mask(to_f32(a) == to_f32(b));
+        check_binary::<_, _, 8>(vceqq_f16, reference, check_bits);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcge_f16() {
+        let reference = |a, b| mask(to_f32(a) >= to_f32(b));
+        check_binary::<_, _, 4>(vcge_f16, reference, check_bits);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcgeq_f16() {
+        let reference = |a, b| mask(to_f32(a) >= to_f32(b));
+        check_binary::<_, _, 8>(vcgeq_f16, reference, check_bits);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcgt_f16() {
+        let reference = |a, b| mask(to_f32(a) > to_f32(b));
+        check_binary::<_, _, 4>(vcgt_f16, reference, check_bits);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcgtq_f16() {
+        let reference = |a, b| mask(to_f32(a) > to_f32(b));
+        check_binary::<_, _, 8>(vcgtq_f16, reference, check_bits);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcle_f16() {
+        let reference = |a, b| mask(to_f32(a) <= to_f32(b));
+        check_binary::<_, _, 4>(vcle_f16, reference, check_bits);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcleq_f16() {
+        let reference = |a, b| mask(to_f32(a) <= to_f32(b));
+        check_binary::<_, _, 8>(vcleq_f16, reference, check_bits);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vclt_f16() {
+        let reference = |a, b| mask(to_f32(a) < to_f32(b));
+        check_binary::<_, _, 4>(vclt_f16, reference, check_bits);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcltq_f16() {
+        let reference = |a, b| mask(to_f32(a) < to_f32(b));
+        check_binary::<_, _, 8>(vcltq_f16, reference, check_bits);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vfma_f16() {
+        check_ternary::<_, 4>(vfma_f16, fma);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vfmaq_f16() {
+        check_ternary::<_, 8>(vfmaq_f16, fma);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vfms_f16() {
+        check_ternary::<_, 4>(vfms_f16, fms);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vfmsq_f16() {
+        check_ternary::<_, 8>(vfmsq_f16, fms);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vfma_lane_f16() {
+        check_lane::<_, _, 4, 4>(|a, b, c, lane| match lane {
+            0 => vfma_lane_f16::<0>(a, b, c),
+            1 => vfma_lane_f16::<1>(a, b, c),
+            2 => vfma_lane_f16::<2>(a, b, c),
+            3 => vfma_lane_f16::<3>(a, b, c),
+            _ => unreachable!(),
+        });
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vfma_laneq_f16() {
+        check_lane::<_, _, 4, 8>(|a, b, c, lane| match lane {
+            0 => vfma_laneq_f16::<0>(a, b, c),
+            1 => vfma_laneq_f16::<1>(a, b, c),
+            2 => vfma_laneq_f16::<2>(a, b, c),
+            3 => vfma_laneq_f16::<3>(a, b, c),
+            4 => vfma_laneq_f16::<4>(a, b, c),
+            5 => vfma_laneq_f16::<5>(a, b, c),
+            6 => vfma_laneq_f16::<6>(a, b, c),
+            7 => vfma_laneq_f16::<7>(a, b, c),
+            _ => unreachable!(),
+        });
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vfmaq_lane_f16() {
+        check_lane::<_, _, 8, 4>(|a, b, c, lane| match lane {
+            0 => vfmaq_lane_f16::<0>(a, b, c),
+            1 => vfmaq_lane_f16::<1>(a, b, c),
+            2 => vfmaq_lane_f16::<2>(a, b, c),
+            3 => vfmaq_lane_f16::<3>(a, b, c),
+            _ => unreachable!(),
+        });
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vfmaq_laneq_f16() {
+        check_lane::<_, _, 8, 8>(|a, b, c, lane| match lane {
+            0 => vfmaq_laneq_f16::<0>(a, b, c),
+            1 => vfmaq_laneq_f16::<1>(a, b, c),
+            2 => vfmaq_laneq_f16::<2>(a, b, c),
+            3 => vfmaq_laneq_f16::<3>(a, b, c),
+            4 => vfmaq_laneq_f16::<4>(a, b, c),
+            5 => vfmaq_laneq_f16::<5>(a, b, c),
+            6 => vfmaq_laneq_f16::<6>(a, b, c),
+            7 => vfmaq_laneq_f16::<7>(a, b, c),
+            _ => unreachable!(),
+        });
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvt_f16_s16() {
+        let reference = |a| to_f16(a as i16 as f64);
+        check_unary::<_, _, 4>(vcvt_f16_s16, &all(), reference, check_bits);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvtq_f16_s16() {
+        let reference = |a| to_f16(a as i16 as f64);
+        check_unary::<_, _, 8>(vcvtq_f16_s16, &all(), reference, check_bits);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvt_f16_u16() {
+        let reference = |a| to_f16(a as f64);
+        check_unary::<_, _, 4>(vcvt_f16_u16, &all(), reference, check_bits);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvtq_f16_u16() {
+        let reference = |a| to_f16(a as f64);
+        check_unary::<_, _, 8>(vcvtq_f16_u16, &all(), reference, check_bits);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvt_s16_f16() {
+        let reference = |a| to_f32(a).trunc() as i16 as u16;
+        check_unary::<_, _, 4>(vcvt_s16_f16, &all(), reference, check_bits);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvtq_s16_f16() {
+        let reference = |a| to_f32(a).trunc() as i16 as u16;
+        check_unary::<_, _, 8>(vcvtq_s16_f16, &all(), reference, check_bits);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvt_u16_f16() {
+        let reference = |a| to_f32(a).trunc() as u16;
+        check_unary::<_, _, 4>(vcvt_u16_f16, &all(), reference, check_bits);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvtq_u16_f16() {
+        let reference = |a| to_f32(a).trunc() as u16;
+        check_unary::<_, _, 8>(vcvtq_u16_f16, &all(), reference, check_bits);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvta_s16_f16() {
+        let reference = |a| to_f32(a).round() as i16 as u16;
+        check_unary::<_, _, 4>(vcvta_s16_f16, &all(), reference, check_bits);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvtaq_s16_f16() {
+        let reference = |a| to_f32(a).round() as i16 as u16;
+        check_unary::<_, _, 8>(vcvtaq_s16_f16, &all(), reference, check_bits);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvta_u16_f16() {
+        let reference = |a| to_f32(a).round() as u16;
+        check_unary::<_, _, 4>(vcvta_u16_f16, &all(), reference, check_bits);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvtaq_u16_f16() {
+        let reference = |a| to_f32(a).round() as u16;
+        check_unary::<_, _, 8>(vcvtaq_u16_f16, &all(), reference, check_bits);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvtm_s16_f16() {
+        let reference = |a| to_f32(a).floor() as i16 as u16;
+        check_unary::<_, _, 4>(vcvtm_s16_f16, &all(), reference, check_bits);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvtmq_s16_f16() {
+        let reference = |a| to_f32(a).floor() as i16 as u16;
+        check_unary::<_, _, 8>(vcvtmq_s16_f16, &all(), reference, check_bits);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvtm_u16_f16() {
+        let reference = |a| to_f32(a).floor() as u16;
+        check_unary::<_, _, 4>(vcvtm_u16_f16, &all(), reference, check_bits);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvtmq_u16_f16() {
+        let reference = |a| to_f32(a).floor() as u16;
+        check_unary::<_, _, 8>(vcvtmq_u16_f16, &all(), reference, check_bits);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvtn_s16_f16() {
+        let reference = |a| round_even(to_f32(a)) as i16 as u16;
+        check_unary::<_, _, 4>(vcvtn_s16_f16, &all(), reference, check_bits);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvtnq_s16_f16() {
+        let reference = |a| round_even(to_f32(a)) as i16 as u16;
+        check_unary::<_, _, 8>(vcvtnq_s16_f16, &all(), reference, check_bits);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvtn_u16_f16() {
+        let reference = |a| round_even(to_f32(a)) as u16;
+        check_unary::<_, _, 4>(vcvtn_u16_f16, &all(), reference, check_bits);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvtnq_u16_f16() {
+        let reference = |a| round_even(to_f32(a)) as u16;
+        check_unary::<_, _, 8>(vcvtnq_u16_f16, &all(), reference, check_bits);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvtp_s16_f16() {
+        let reference = |a| to_f32(a).ceil() as i16 as u16;
+        check_unary::<_, _, 4>(vcvtp_s16_f16, &all(), reference, check_bits);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvtpq_s16_f16() {
+        let reference = |a| to_f32(a).ceil() as i16 as u16;
+        check_unary::<_, _, 8>(vcvtpq_s16_f16, &all(), reference, check_bits);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvtp_u16_f16() {
+        let reference = |a| to_f32(a).ceil() as u16;
+        check_unary::<_, _, 4>(vcvtp_u16_f16, &all(), reference, check_bits);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvtpq_u16_f16() {
+        let reference = |a| to_f32(a).ceil() as u16;
+        check_unary::<_, _, 8>(vcvtpq_u16_f16, &all(), reference, check_bits);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vmaxv_f16() {
+        check_across::<_, 4>(vmaxv_f16, max);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vmaxvq_f16() {
+        check_across::<_, 8>(vmaxvq_f16, max);
+        let a = [0xbc00, NAN, 0x4000, 0x8000, 0x0000, 0x3c00, 0xc000, 0x3800];
+        assert!(is_nan(vmaxvq_f16(transmute_copy(&a))));
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vminv_f16() {
+        check_across::<_, 4>(vminv_f16, min);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vminvq_f16() {
+        check_across::<_, 8>(vminvq_f16, min);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vmaxnmv_f16() {
+        check_across::<_, 4>(vmaxnmv_f16, maxnm);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vmaxnmvq_f16() {
+        check_across::<_, 8>(vmaxnmvq_f16, maxnm);
+        let a = [0xbc00, NAN, 0x4000, 0x8000, 0x0000, 0x3c00, 0xc000, 0x3800];
+        assert_eq!(vmaxnmvq_f16(transmute_copy(&a)), 0x4000);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vminnmv_f16() {
+        check_across::<_, 4>(vminnmv_f16, minnm);
+        let a = [0xbc00, NAN, 0x4000, 0x8000];
+        assert_eq!(vminnmv_f16(transmute_copy(&a)), 0xbc00);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vminnmvq_f16() {
+        check_across::<_, 8>(vminnmvq_f16, minnm);
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vcvt_f16_f32() {
+        for a in floats().chunks_exact(4) {
+            let a = [a[0], a[1], a[2], a[3]];
+            let r: [u16; 4] = transmute_copy(&vcvt_f16_f32(transmute_copy(&a)));
+            for i in 0..4 {
+                check_f16(r[i], from_f32(a[i]), &[]);
+            }
+        }
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vcvt_high_f16_f32() {
+        let low = [1u16, 2, 3, 4];
+        for a in floats().chunks_exact(4) {
+            let a = [a[0], a[1], a[2], a[3]];
+            let r = vcvt_high_f16_f32(transmute_copy(&low), transmute_copy(&a));
+            let r: [u16; 8] = transmute_copy(&r);
+            assert_eq!(r[..4], low);
+            for i in 0..4 {
+                check_f16(r[i + 4], from_f32(a[i]), &[]);
+            }
+        }
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vcvt_f32_f16() {
+        for a in all().chunks_exact(4) {
+            let a: [u16; 4] = lanes(a);
+            let r: [f32; 4] = transmute_copy(&vcvt_f32_f16(transmute_copy(&a)));
+            for i in 0..4 {
+                check_f32(r[i], a[i]);
+            }
+        }
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vcvt_high_f32_f16() {
+        for a in all().chunks_exact(8) {
+            let a: [u16; 8] = lanes(a);
+            let r: [f32; 4] = transmute_copy(&vcvt_high_f32_f16(transmute_copy(&a)));
+            for i in 0..4 {
+                check_f32(r[i], a[i + 4]);
+            }
+        }
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vld1_f16_x2() {
+        check_load(vld1_f16_x2);
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vld1_f16_x3() {
+        check_load(vld1_f16_x3);
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vld1_f16_x4() {
+        check_load(vld1_f16_x4);
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vld1q_f16_x2() {
+        check_load(vld1q_f16_x2);
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vld1q_f16_x3() {
+        check_load(vld1q_f16_x3);
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vld1q_f16_x4() {
+        check_load(vld1q_f16_x4);
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vst1_f16_x2() {
+        check_store(vld1_f16_x2, vst1_f16_x2);
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vst1_f16_x3() {
+        check_store(vld1_f16_x3, vst1_f16_x3);
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vst1_f16_x4() {
+        check_store(vld1_f16_x4, vst1_f16_x4);
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vst1q_f16_x2() {
+        check_store(vld1q_f16_x2, vst1q_f16_x2);
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vst1q_f16_x3() {
+        check_store(vld1q_f16_x3, vst1q_f16_x3);
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vst1q_f16_x4() {
+        check_store(vld1q_f16_x4, vst1q_f16_x4);
+    }
+}
@@ -0,0 +1,1699 @@
+//! 8-bit floating-point intrinsics (FEAT_FP8, FEAT_FP8DOT2, FEAT_FP8DOT4,
+//! FEAT_FP8FMA)
+//!
+//! The elements of `mfloat8x8_t` and `mfloat8x16_t` are the `u8` bit
+//! patterns of 8-bit floating-point values, in either the E5M2 or the E4M3
+//! format. Which one, as well as how results are scaled and how overflow is
+//! handled, is selected by the FPMR register. Every intrinsic takes the
+//! value to write to it as its last argument, `fpm`, which is built with the
+//! `__arm_fpm_init` and `__arm_set_fpm_*` functions. FPMR keeps that value
+//! after the call.
+//!
+//! The conversions need the `fp8` target feature, the dot products `fp8dot2`
+//! or `fp8dot4` and the widening multiply-adds `fp8fma`.
+//!
+//! The reference is the "FP8 intrinsics" section of [ACLE][acle].
+//!
+//! [acle]: https://github.com/ARM-software/acle
+
+use crate::core_arch::{aarch64::neon::*, arm_shared::*, simd_llvm::*};
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// The value of FPMR, the Floating-point Mode Register.
+pub type fpm_t = u64;
+
+/// The E5M2 format, with 5 exponent and 2 mantissa bits.
+pub const __ARM_FPM_E5M2: u64 = 0;
+/// The E4M3 format, with 4 exponent and 3 mantissa bits.
+pub const __ARM_FPM_E4M3: u64 = 1;
+
+/// Results that overflow become infinity, or NaN in the E4M3 format.
+pub const __ARM_FPM_INFNAN: u64 = 0;
+/// Results that overflow become the largest finite value.
+pub const __ARM_FPM_SATURATE: u64 = 1;
+
+/// Returns the FPMR value that selects the E5M2 format for all operands and
+/// results, no scaling, and overflow to infinity or NaN.
+#[inline]
+pub const fn __arm_fpm_init() -> fpm_t {
+    0
+}
+
+// Replaces the `bits`-bit field at `shift` of `fpm` with `value`.
+const fn set_field(fpm: fpm_t, shift: u32, bits: u32, value: u64) -> fpm_t {
+    let mask = ((1 << bits) - 1) << shift;
+    (fpm & !mask) | ((value << shift) & mask)
+}
+
+/// Sets the format of the first source operand, F8S1, to `format`.
+#[inline]
+pub const fn __arm_set_fpm_src1_format(fpm: fpm_t, format: u64) -> fpm_t {
+    set_field(fpm, 0, 3, format)
+}
+
+/// Sets the format of the second source operand, F8S2, to `format`.
+#[inline]
+pub const fn __arm_set_fpm_src2_format(fpm: fpm_t, format: u64) -> fpm_t {
+    set_field(fpm, 3, 3, format)
+}
+
+/// Sets the format of the destination, F8D, to `format`.
+#[inline]
+pub const fn __arm_set_fpm_dst_format(fpm: fpm_t, format: u64) -> fpm_t {
+    set_field(fpm, 6, 3, format)
+}
+
+/// Sets how overflow is handled by the multiplications, OSM, to
+/// `overflow`.
+#[inline]
+pub const fn __arm_set_fpm_overflow_mul(fpm: fpm_t, overflow: u64) -> fpm_t {
+    set_field(fpm, 14, 1, overflow)
+}
+
+/// Sets how overflow is handled by the conversions, OSC, to `overflow`.
+#[inline]
+pub const fn __arm_set_fpm_overflow_cvt(fpm: fpm_t, overflow: u64) -> fpm_t {
+    set_field(fpm, 15, 1, overflow)
+}
+
+/// Sets the scaling of the products and of the conversions to 16-bit
+/// values, LSCALE, which scales them by 2^-`scale`.
+#[inline]
+pub const fn __arm_set_fpm_lscale(fpm: fpm_t, scale: u64) -> fpm_t {
+    set_field(fpm, 16, 7, scale)
+}
+
+/// Sets the scaling of the conversions to 8-bit values, NSCALE, which
+/// scales them by 2^`scale`.
+#[inline]
+pub const fn __arm_set_fpm_nscale(fpm: fpm_t, scale: i64) -> fpm_t {
+    set_field(fpm, 24, 8, scale as u64)
+}
+
+/// Sets the scaling of the conversions of the upper halves to 16-bit
+/// values, LSCALE2, which scales them by 2^-`scale`.
+#[inline]
+pub const fn __arm_set_fpm_lscale2(fpm: fpm_t, scale: u64) -> fpm_t {
+    set_field(fpm, 32, 6, scale)
+}
+
+// `fpm` is written to FPMR right before the instruction, so nothing can
+// change it in between. FPMR is named by its encoding since older assemblers
+// don't know its name. LLVM models the `f16` and `bf16` operands on vectors
+// of `half` and `bfloat`, which don't have a Rust equivalent that can cross
+// the FFI boundary, so all of these use inline assembly, like in `fp16.rs`.
+// Operand `{0}` is the result and the following ones are the arguments.
+macro_rules! fp8_op {
+    ($inst:expr, $fpm:expr $(, $arg:expr)*) => {{
+        let dst;
+        asm!(
+            "msr S3_3_C4_C4_2, {fpm}",
+            $inst,
+            lateout(vreg) dst,
+            $(in(vreg) $arg,)*
+            fpm = in(reg) $fpm,
+            options(nomem, nostack, preserves_flags),
+        );
+        dst
+    }};
+}
+
+// Like `fp8_op!`, for instructions which accumulate into their first
+// argument.
+macro_rules! fp8_acc {
+    ($inst:expr, $fpm:expr, $acc:expr, $a:expr, $b:expr) => {{
+        let mut acc = $acc;
+        asm!(
+            "msr S3_3_C4_C4_2, {fpm}",
+            $inst,
+            inout(vreg) acc,
+            in(vreg) $a,
+            in(vreg) $b,
+            fpm = in(reg) $fpm,
+            options(nomem, nostack, preserves_flags),
+        );
+        acc
+    }};
+}
+
+// Conversions
+
+/// 8-bit floating-point convert to BFloat16, in the format of the first
+/// source operand
+#[inline]
+#[target_feature(enable = "neon,fp8")]
+#[cfg_attr(test, assert_instr(bf1cvtl))]
+pub unsafe fn vcvt1_bf16_mf8_fpm(a: mfloat8x8_t, fpm: fpm_t) -> bfloat16x8_t {
+    fp8_op!("bf1cvtl {0:v}.8h, {1:v}.8b", fpm, a)
+}
+
+/// 8-bit floating-point convert the lower half to BFloat16, in the format of
+/// the first source operand
+#[inline]
+#[target_feature(enable = "neon,fp8")]
+#[cfg_attr(test, assert_instr(bf1cvtl))]
+pub unsafe fn vcvt1_low_bf16_mf8_fpm(a: mfloat8x16_t, fpm: fpm_t) -> bfloat16x8_t {
+    fp8_op!("bf1cvtl {0:v}.8h, {1:v}.8b", fpm, a)
+}
+
+/// 8-bit floating-point convert the upper half to BFloat16, in the format of
+/// the first source operand
+#[inline]
+#[target_feature(enable = "neon,fp8")]
+#[cfg_attr(test, assert_instr(bf1cvtl2))]
+pub unsafe fn vcvt1_high_bf16_mf8_fpm(a: mfloat8x16_t, fpm: fpm_t) -> bfloat16x8_t {
+    fp8_op!("bf1cvtl2 {0:v}.8h, {1:v}.16b", fpm, a)
+}
+
+/// 8-bit floating-point convert to half-precision, in the format of the
+/// first source operand
+#[inline]
+#[target_feature(enable = "neon,fp8")]
+#[cfg_attr(test, assert_instr(f1cvtl))]
+pub unsafe fn vcvt1_f16_mf8_fpm(a: mfloat8x8_t, fpm: fpm_t) -> float16x8_t {
+    fp8_op!("f1cvtl {0:v}.8h, {1:v}.8b", fpm, a)
+}
+
+/// 8-bit floating-point convert the lower half to half-precision, in the
+/// format of the first source operand
+#[inline]
+#[target_feature(enable = "neon,fp8")]
+#[cfg_attr(test, assert_instr(f1cvtl))]
+pub unsafe fn vcvt1_low_f16_mf8_fpm(a: mfloat8x16_t, fpm: fpm_t) -> float16x8_t {
+    fp8_op!("f1cvtl {0:v}.8h, {1:v}.8b", fpm, a)
+}
+
+/// 8-bit floating-point convert the upper half to half-precision, in the
+/// format of the first source operand
+#[inline]
+#[target_feature(enable = "neon,fp8")]
+#[cfg_attr(test, assert_instr(f1cvtl2))]
+pub unsafe fn vcvt1_high_f16_mf8_fpm(a: mfloat8x16_t, fpm: fpm_t) -> float16x8_t {
+    fp8_op!("f1cvtl2 {0:v}.8h, {1:v}.16b", fpm, a)
+}
+
+/// 8-bit floating-point convert to BFloat16, in the format of the second
+/// source operand
+#[inline]
+#[target_feature(enable = "neon,fp8")]
+#[cfg_attr(test, assert_instr(bf2cvtl))]
+pub unsafe fn vcvt2_bf16_mf8_fpm(a: mfloat8x8_t, fpm: fpm_t) -> bfloat16x8_t {
+    fp8_op!("bf2cvtl {0:v}.8h, {1:v}.8b", fpm, a)
+}
+
+/// 8-bit floating-point convert the lower half to BFloat16, in the format of
+/// the second source operand
+#[inline]
+#[target_feature(enable = "neon,fp8")]
+#[cfg_attr(test, assert_instr(bf2cvtl))]
+pub unsafe fn vcvt2_low_bf16_mf8_fpm(a: mfloat8x16_t, fpm: fpm_t) -> bfloat16x8_t {
+    fp8_op!("bf2cvtl {0:v}.8h, {1:v}.8b", fpm, a)
+}
+
+/// 8-bit floating-point convert the upper half to BFloat16, in the format of
+/// the second source operand
+#[inline]
+#[target_feature(enable = "neon,fp8")]
+#[cfg_attr(test, assert_instr(bf2cvtl2))]
+pub unsafe fn vcvt2_high_bf16_mf8_fpm(a: mfloat8x16_t, fpm: fpm_t) -> bfloat16x8_t {
+    fp8_op!("bf2cvtl2 {0:v}.8h, {1:v}.16b", fpm, a)
+}
+
+/// 8-bit floating-point convert to half-precision, in the format of the
+/// second source operand
+#[inline]
+#[target_feature(enable = "neon,fp8")]
+#[cfg_attr(test, assert_instr(f2cvtl))]
+pub unsafe fn vcvt2_f16_mf8_fpm(a: mfloat8x8_t, fpm: fpm_t) -> float16x8_t {
+    fp8_op!("f2cvtl {0:v}.8h, {1:v}.8b", fpm, a)
+}
+
+/// 8-bit floating-point convert the lower half to half-precision, in the
+/// format of the second source operand
+#[inline]
+#[target_feature(enable = "neon,fp8")]
+#[cfg_attr(test, assert_instr(f2cvtl))]
+pub unsafe fn vcvt2_low_f16_mf8_fpm(a: mfloat8x16_t, fpm: fpm_t) -> float16x8_t {
+    fp8_op!("f2cvtl {0:v}.8h, {1:v}.8b", fpm, a)
+}
+
+/// 8-bit floating-point convert the upper half to half-precision, in the
+/// format of the second source operand
+#[inline]
+#[target_feature(enable = "neon,fp8")]
+#[cfg_attr(test, assert_instr(f2cvtl2))]
+pub unsafe fn vcvt2_high_f16_mf8_fpm(a: mfloat8x16_t, fpm: fpm_t) -> float16x8_t {
+    fp8_op!("f2cvtl2 {0:v}.8h, {1:v}.16b", fpm, a)
+}
+
+/// Floating-point convert to 8-bit floating-point, narrowing `a` into the
+/// lower and `b` into the upper half of the result
+#[inline]
+#[target_feature(enable = "neon,fp8")]
+#[cfg_attr(test, assert_instr(fcvtn))]
+pub unsafe fn vcvt_mf8_f32_fpm(a: float32x4_t, b: float32x4_t, fpm: fpm_t) -> mfloat8x8_t {
+    fp8_op!("fcvtn {0:v}.8b, {1:v}.4s, {2:v}.4s", fpm, a, b)
+}
+
+/// Floating-point convert to 8-bit floating-point, narrowing `b` and `c`
+/// into the upper half of the result, whose lower half is `a`
+#[inline]
+#[target_feature(enable = "neon,fp8")]
+#[cfg_attr(test, assert_instr(fcvtn2))]
+pub unsafe fn vcvt_high_mf8_f32_fpm(
+    a: mfloat8x8_t,
+    b: float32x4_t,
+    c: float32x4_t,
+    fpm: fpm_t,
+) -> mfloat8x16_t {
+    let a: mfloat8x16_t = simd_shuffle16(a, a, [0, 1, 2, 3, 4, 5, 6, 7, 0, 1, 2, 3, 4, 5, 6, 7]);
+    fp8_acc!("fcvtn2 {0:v}.16b, {1:v}.4s, {2:v}.4s", fpm, a, b, c)
+}
+
+/// Half-precision convert to 8-bit floating-point, narrowing `a` into the
+/// lower and `b` into the upper half of the result
+#[inline]
+#[target_feature(enable = "neon,fp8")]
+#[cfg_attr(test, assert_instr(fcvtn))]
+pub unsafe fn vcvt_mf8_f16_fpm(a: float16x4_t, b: float16x4_t, fpm: fpm_t) -> mfloat8x8_t {
+    fp8_op!("fcvtn {0:v}.8b, {1:v}.4h, {2:v}.4h", fpm, a, b)
+}
+
+/// Half-precision convert to 8-bit floating-point, narrowing `a` into the
+/// lower and `b` into the upper half of the result
+#[inline]
+#[target_feature(enable = "neon,fp8")]
+#[cfg_attr(test, assert_instr(fcvtn))]
+pub unsafe fn vcvtq_mf8_f16_fpm(a: float16x8_t, b: float16x8_t, fpm: fpm_t) -> mfloat8x16_t {
+    fp8_op!("fcvtn {0:v}.16b, {1:v}.8h, {2:v}.8h", fpm, a, b)
+}
+
+// Dot products
+
+/// 8-bit floating-point dot product, adding the sums of the products of
+/// groups of two elements of `b` and `c` to the elements of `a`
+#[inline]
+#[target_feature(enable = "neon,fp8dot2")]
+#[cfg_attr(test, assert_instr(fdot))]
+pub unsafe fn vdot_f16_mf8_fpm(
+    a: float16x4_t,
+    b: mfloat8x8_t,
+    c: mfloat8x8_t,
+    fpm: fpm_t,
+) -> float16x4_t {
+    fp8_acc!("fdot {0:v}.4h, {1:v}.8b, {2:v}.8b", fpm, a, b, c)
+}
+
+/// 8-bit floating-point dot product, adding the sums of the products of
+/// groups of two elements of `b` and `c` to the elements of `a`
+#[inline]
+#[target_feature(enable = "neon,fp8dot2")]
+#[cfg_attr(test, assert_instr(fdot))]
+pub unsafe fn vdotq_f16_mf8_fpm(
+    a: float16x8_t,
+    b: mfloat8x16_t,
+    c: mfloat8x16_t,
+    fpm: fpm_t,
+) -> float16x8_t {
+    fp8_acc!("fdot {0:v}.8h, {1:v}.16b, {2:v}.16b", fpm, a, b, c)
+}
+
+/// 8-bit floating-point dot product, adding the sums of the products of
+/// groups of two elements of `b` and group `LANE` of `c` to the elements of
+/// `a`
+#[inline]
+#[target_feature(enable = "neon,fp8dot2")]
+#[cfg_attr(test, assert_instr(fdot, LANE = 3))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn vdot_lane_f16_mf8_fpm<const LANE: i32>(
+    a: float16x4_t,
+    b: mfloat8x8_t,
+    c: mfloat8x8_t,
+    fpm: fpm_t,
+) -> float16x4_t {
+    static_assert_imm2!(LANE);
+    let c: mfloat8x8_t = simd_shuffle8(
+        c,
+        c,
+        [
+            2 * LANE as u32,
+            2 * LANE as u32 + 1,
+            2 * LANE as u32,
+            2 * LANE as u32 + 1,
+            2 * LANE as u32,
+            2 * LANE as u32 + 1,
+            2 * LANE as u32,
+            2 * LANE as u32 + 1,
+        ],
+    );
+    vdot_f16_mf8_fpm(a, b, c, fpm)
+}
+
+/// 8-bit floating-point dot product, adding the sums of the products of
+/// groups of two elements of `b` and group `LANE` of `c` to the elements of
+/// `a`
+#[inline]
+#[target_feature(enable = "neon,fp8dot2")]
+#[cfg_attr(test, assert_instr(fdot, LANE = 7))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn vdot_laneq_f16_mf8_fpm<const LANE: i32>(
+    a: float16x4_t,
+    b: mfloat8x8_t,
+    c: mfloat8x16_t,
+    fpm: fpm_t,
+) -> float16x4_t {
+    static_assert_imm3!(LANE);
+    let c: mfloat8x8_t = simd_shuffle8(
+        c,
+        c,
+        [
+            2 * LANE as u32,
+            2 * LANE as u32 + 1,
+            2 * LANE as u32,
+            2 * LANE as u32 + 1,
+            2 * LANE as u32,
+            2 * LANE as u32 + 1,
+            2 * LANE as u32,
+            2 * LANE as u32 + 1,
+        ],
+    );
+    vdot_f16_mf8_fpm(a, b, c, fpm)
+}
+
+/// 8-bit floating-point dot product, adding the sums of the products of
+/// groups of two elements of `b` and group `LANE` of `c` to the elements of
+/// `a`
+#[inline]
+#[target_feature(enable = "neon,fp8dot2")]
+#[cfg_attr(test, assert_instr(fdot, LANE = 3))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn vdotq_lane_f16_mf8_fpm<const LANE: i32>(
+    a: float16x8_t,
+    b: mfloat8x16_t,
+    c: mfloat8x8_t,
+    fpm: fpm_t,
+) -> float16x8_t {
+    static_assert_imm2!(LANE);
+    let c: mfloat8x16_t = simd_shuffle16(
+        c,
+        c,
+        [
+            2 * LANE as u32,
+            2 * LANE as u32 + 1,
+            2 * LANE as u32,
+            2 * LANE as u32 + 1,
+            2 * LANE as u32,
+            2 * LANE as u32 + 1,
+            2 * LANE as u32,
+            2 * LANE as u32 + 1,
+            2 * LANE as u32,
+            2 * LANE as u32 + 1,
+            2 * LANE as u32,
+            2 * LANE as u32 + 1,
+            2 * LANE as u32,
+            2 * LANE as u32 + 1,
+            2 * LANE as u32,
+            2 * LANE as u32 + 1,
+        ],
+    );
+    vdotq_f16_mf8_fpm(a, b, c, fpm)
+}
+
+/// 8-bit floating-point dot product, adding the sums of the products of
+/// groups of two elements of `b` and group `LANE` of `c` to the elements of
+/// `a`
+#[inline]
+#[target_feature(enable = "neon,fp8dot2")]
+#[cfg_attr(test, assert_instr(fdot, LANE = 7))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn vdotq_laneq_f16_mf8_fpm<const LANE: i32>(
+    a: float16x8_t,
+    b: mfloat8x16_t,
+    c: mfloat8x16_t,
+    fpm: fpm_t,
+) -> float16x8_t {
+    static_assert_imm3!(LANE);
+    let c: mfloat8x16_t = simd_shuffle16(
+        c,
+        c,
+        [
+            2 * LANE as u32,
+            2 * LANE as u32 + 1,
+            2 * LANE as u32,
+            2 * LANE as u32 + 1,
+            2 * LANE as u32,
+            2 * LANE as u32 + 1,
+            2 * LANE as u32,
+            2 * LANE as u32 + 1,
+            2 * LANE as u32,
+            2 * LANE as u32 + 1,
+            2 * LANE as u32,
+            2 * LANE as u32 + 1,
+            2 * LANE as u32,
+            2 * LANE as u32 + 1,
+            2 * LANE as u32,
+            2 * LANE as u32 + 1,
+        ],
+    );
+    vdotq_f16_mf8_fpm(a, b, c, fpm)
+}
+
+/// 8-bit floating-point dot product, adding the sums of the products of
+/// groups of four elements of `b` and `c` to the elements of `a`
+#[inline]
+#[target_feature(enable = "neon,fp8dot4")]
+#[cfg_attr(test, assert_instr(fdot))]
+pub unsafe fn vdot_f32_mf8_fpm(
+    a: float32x2_t,
+    b: mfloat8x8_t,
+    c: mfloat8x8_t,
+    fpm: fpm_t,
+) -> float32x2_t {
+    fp8_acc!("fdot {0:v}.2s, {1:v}.8b, {2:v}.8b", fpm, a, b, c)
+}
+
+/// 8-bit floating-point dot product, adding the sums of the products of
+/// groups of four elements of `b` and `c` to the elements of `a`
+#[inline]
+#[target_feature(enable = "neon,fp8dot4")]
+#[cfg_attr(test, assert_instr(fdot))]
+pub unsafe fn vdotq_f32_mf8_fpm(
+    a: float32x4_t,
+    b: mfloat8x16_t,
+    c: mfloat8x16_t,
+    fpm: fpm_t,
+) -> float32x4_t {
+    fp8_acc!("fdot {0:v}.4s, {1:v}.16b, {2:v}.16b", fpm, a, b, c)
+}
+
+/// 8-bit floating-point dot product, adding the sums of the products of
+/// groups of four elements of `b` and group `LANE` of `c` to the elements of
+/// `a`
+#[inline]
+#[target_feature(enable = "neon,fp8dot4")]
+#[cfg_attr(test, assert_instr(fdot, LANE = 1))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn vdot_lane_f32_mf8_fpm<const LANE: i32>(
+    a: float32x2_t,
+    b: mfloat8x8_t,
+    c: mfloat8x8_t,
+    fpm: fpm_t,
+) -> float32x2_t {
+    static_assert_imm1!(LANE);
+    let c: mfloat8x8_t = simd_shuffle8(
+        c,
+        c,
+        [
+            4 * LANE as u32,
+            4 * LANE as u32 + 1,
+            4 * LANE as u32 + 2,
+            4 * LANE as u32 + 3,
+            4 * LANE as u32,
+            4 * LANE as u32 + 1,
+            4 * LANE as u32 + 2,
+            4 * LANE as u32 + 3,
+        ],
+    );
+    vdot_f32_mf8_fpm(a, b, c, fpm)
+}
+
+/// 8-bit floating-point dot product, adding the sums of the products of
+/// groups of four elements of `b` and group `LANE` of `c` to the elements of
+/// `a`
+#[inline]
+#[target_feature(enable = "neon,fp8dot4")]
+#[cfg_attr(test, assert_instr(fdot, LANE = 3))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn vdot_laneq_f32_mf8_fpm<const LANE: i32>(
+    a: float32x2_t,
+    b: mfloat8x8_t,
+    c: mfloat8x16_t,
+    fpm: fpm_t,
+) -> float32x2_t {
+    static_assert_imm2!(LANE);
+    let c: mfloat8x8_t = simd_shuffle8(
+        c,
+        c,
+        [
+            4 * LANE as u32,
+            4 * LANE as u32 + 1,
+            4 * LANE as u32 + 2,
+            4 * LANE as u32 + 3,
+            4 * LANE as u32,
+            4 * LANE as u32 + 1,
+            4 * LANE as u32 + 2,
+            4 * LANE as u32 + 3,
+        ],
+    );
+    vdot_f32_mf8_fpm(a, b, c, fpm)
+}
+
+/// 8-bit floating-point dot product, adding the sums of the products of
+/// groups of four elements of `b` and group `LANE` of `c` to the elements of
+/// `a`
+#[inline]
+#[target_feature(enable = "neon,fp8dot4")]
+#[cfg_attr(test, assert_instr(fdot, LANE = 1))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn vdotq_lane_f32_mf8_fpm<const LANE: i32>(
+    a: float32x4_t,
+    b: mfloat8x16_t,
+    c: mfloat8x8_t,
+    fpm: fpm_t,
+) -> float32x4_t {
+    static_assert_imm1!(LANE);
+    let c: mfloat8x16_t = simd_shuffle16(
+        c,
+        c,
+        [
+            4 * LANE as u32,
+            4 * LANE as u32 + 1,
+            4 * LANE as u32 + 2,
+            4 * LANE as u32 + 3,
+            4 * LANE as u32,
+            4 * LANE as u32 + 1,
+            4 * LANE as u32 + 2,
+            4 * LANE as u32 + 3,
+            4 * LANE as u32,
+            4 * LANE as u32 + 1,
+            4 * LANE as u32 + 2,
+            4 * LANE as u32 + 3,
+            4 * LANE as u32,
+            4 * LANE as u32 + 1,
+            4 * LANE as u32 + 2,
+            4 * LANE as u32 + 3,
+        ],
+    );
+    vdotq_f32_mf8_fpm(a, b, c, fpm)
+}
+
+/// 8-bit floating-point dot product, adding the sums of the products of
+/// groups of four elements of `b` and group `LANE` of `c` to the elements of
+/// `a`
+#[inline]
+#[target_feature(enable = "neon,fp8dot4")]
+#[cfg_attr(test, assert_instr(fdot, LANE = 3))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn vdotq_laneq_f32_mf8_fpm<const LANE: i32>(
+    a: float32x4_t,
+    b: mfloat8x16_t,
+    c: mfloat8x16_t,
+    fpm: fpm_t,
+) -> float32x4_t {
+    static_assert_imm2!(LANE);
+    let c: mfloat8x16_t = simd_shuffle16(
+        c,
+        c,
+        [
+            4 * LANE as u32,
+            4 * LANE as u32 + 1,
+            4 * LANE as u32 + 2,
+            4 * LANE as u32 + 3,
+            4 * LANE as u32,
+            4 * LANE as u32 + 1,
+            4 * LANE as u32 + 2,
+            4 * LANE as u32 + 3,
+            4 * LANE as u32,
+            4 * LANE as u32 + 1,
+            4 * LANE as u32 + 2,
+            4 * LANE as u32 + 3,
+            4 * LANE as u32,
+            4 * LANE as u32 + 1,
+            4 * LANE as u32 + 2,
+            4 * LANE as u32 + 3,
+        ],
+    );
+    vdotq_f32_mf8_fpm(a, b, c, fpm)
+}
+
+// Widening multiply-adds
+
+/// Widening 8-bit floating-point multiply-add, adding the products of the
+/// even elements of `b` and `c` to the elements of `a`
+#[inline]
+#[target_feature(enable = "neon,fp8fma")]
+#[cfg_attr(test, assert_instr(fmlalb))]
+pub unsafe fn vmlalbq_f16_mf8_fpm(
+    a: float16x8_t,
+    b: mfloat8x16_t,
+    c: mfloat8x16_t,
+    fpm: fpm_t,
+) -> float16x8_t {
+    fp8_acc!("fmlalb {0:v}.8h, {1:v}.16b, {2:v}.16b", fpm, a, b, c)
+}
+
+/// Widening 8-bit floating-point multiply-add, adding the products of the
+/// even elements of `b` and element `LANE` of `c` to the elements of `a`
+#[inline]
+#[target_feature(enable = "neon,fp8fma")]
+#[cfg_attr(test, assert_instr(fmlalb, LANE = 7))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn vmlalbq_lane_f16_mf8_fpm<const LANE: i32>(
+    a: float16x8_t,
+    b: mfloat8x16_t,
+    c: mfloat8x8_t,
+    fpm: fpm_t,
+) -> float16x8_t {
+    static_assert_imm3!(LANE);
+    let c: mfloat8x16_t = simd_shuffle16(
+        c,
+        c,
+        [
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+        ],
+    );
+    vmlalbq_f16_mf8_fpm(a, b, c, fpm)
+}
+
+/// Widening 8-bit floating-point multiply-add, adding the products of the
+/// even elements of `b` and element `LANE` of `c` to the elements of `a`
+#[inline]
+#[target_feature(enable = "neon,fp8fma")]
+#[cfg_attr(test, assert_instr(fmlalb, LANE = 15))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn vmlalbq_laneq_f16_mf8_fpm<const LANE: i32>(
+    a: float16x8_t,
+    b: mfloat8x16_t,
+    c: mfloat8x16_t,
+    fpm: fpm_t,
+) -> float16x8_t {
+    static_assert_imm4!(LANE);
+    let c: mfloat8x16_t = simd_shuffle16(
+        c,
+        c,
+        [
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+        ],
+    );
+    vmlalbq_f16_mf8_fpm(a, b, c, fpm)
+}
+
+/// Widening 8-bit floating-point multiply-add, adding the products of the
+/// odd elements of `b` and `c` to the elements of `a`
+#[inline]
+#[target_feature(enable = "neon,fp8fma")]
+#[cfg_attr(test, assert_instr(fmlalt))]
+pub unsafe fn vmlaltq_f16_mf8_fpm(
+    a: float16x8_t,
+    b: mfloat8x16_t,
+    c: mfloat8x16_t,
+    fpm: fpm_t,
+) -> float16x8_t {
+    fp8_acc!("fmlalt {0:v}.8h, {1:v}.16b, {2:v}.16b", fpm, a, b, c)
+}
+
+/// Widening 8-bit floating-point multiply-add, adding the products of the
+/// odd elements of `b` and element `LANE` of `c` to the elements of `a`
+#[inline]
+#[target_feature(enable = "neon,fp8fma")]
+#[cfg_attr(test, assert_instr(fmlalt, LANE = 7))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn vmlaltq_lane_f16_mf8_fpm<const LANE: i32>(
+    a: float16x8_t,
+    b: mfloat8x16_t,
+    c: mfloat8x8_t,
+    fpm: fpm_t,
+) -> float16x8_t {
+    static_assert_imm3!(LANE);
+    let c: mfloat8x16_t = simd_shuffle16(
+        c,
+        c,
+        [
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+        ],
+    );
+    vmlaltq_f16_mf8_fpm(a, b, c, fpm)
+}
+
+/// Widening 8-bit floating-point multiply-add, adding the products of the
+/// odd elements of `b` and element `LANE` of `c` to the elements of `a`
+#[inline]
+#[target_feature(enable = "neon,fp8fma")]
+#[cfg_attr(test, assert_instr(fmlalt, LANE = 15))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn vmlaltq_laneq_f16_mf8_fpm<const LANE: i32>(
+    a: float16x8_t,
+    b: mfloat8x16_t,
+    c: mfloat8x16_t,
+    fpm: fpm_t,
+) -> float16x8_t {
+    static_assert_imm4!(LANE);
+    let c: mfloat8x16_t = simd_shuffle16(
+        c,
+        c,
+        [
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+        ],
+    );
+    vmlaltq_f16_mf8_fpm(a, b, c, fpm)
+}
+
+/// Widening 8-bit floating-point multiply-add, adding the products of the
+/// first elements of each group of four of `b` and `c` to the elements of
+/// `a`
+#[inline]
+#[target_feature(enable = "neon,fp8fma")]
+#[cfg_attr(test, assert_instr(fmlallbb))]
+pub unsafe fn vmlallbbq_f32_mf8_fpm(
+    a: float32x4_t,
+    b: mfloat8x16_t,
+    c: mfloat8x16_t,
+    fpm: fpm_t,
+) -> float32x4_t {
+    fp8_acc!("fmlallbb {0:v}.4s, {1:v}.16b, {2:v}.16b", fpm, a, b, c)
+}
+
+/// Widening 8-bit floating-point multiply-add, adding the products of the
+/// first elements of each group of four of `b` and element `LANE` of `c` to
+/// the elements of `a`
+#[inline]
+#[target_feature(enable = "neon,fp8fma")]
+#[cfg_attr(test, assert_instr(fmlallbb, LANE = 7))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn vmlallbbq_lane_f32_mf8_fpm<const LANE: i32>(
+    a: float32x4_t,
+    b: mfloat8x16_t,
+    c: mfloat8x8_t,
+    fpm: fpm_t,
+) -> float32x4_t {
+    static_assert_imm3!(LANE);
+    let c: mfloat8x16_t = simd_shuffle16(
+        c,
+        c,
+        [
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+        ],
+    );
+    vmlallbbq_f32_mf8_fpm(a, b, c, fpm)
+}
+
+/// Widening 8-bit floating-point multiply-add, adding the products of the
+/// first elements of each group of four of `b` and element `LANE` of `c` to
+/// the elements of `a`
+#[inline]
+#[target_feature(enable = "neon,fp8fma")]
+#[cfg_attr(test, assert_instr(fmlallbb, LANE = 15))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn vmlallbbq_laneq_f32_mf8_fpm<const LANE: i32>(
+    a: float32x4_t,
+    b: mfloat8x16_t,
+    c: mfloat8x16_t,
+    fpm: fpm_t,
+) -> float32x4_t {
+    static_assert_imm4!(LANE);
+    let c: mfloat8x16_t = simd_shuffle16(
+        c,
+        c,
+        [
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+        ],
+    );
+    vmlallbbq_f32_mf8_fpm(a, b, c, fpm)
+}
+
+/// Widening 8-bit floating-point multiply-add, adding the products of the
+/// second elements of each group of four of `b` and `c` to the elements of
+/// `a`
+#[inline]
+#[target_feature(enable = "neon,fp8fma")]
+#[cfg_attr(test, assert_instr(fmlallbt))]
+pub unsafe fn vmlallbtq_f32_mf8_fpm(
+    a: float32x4_t,
+    b: mfloat8x16_t,
+    c: mfloat8x16_t,
+    fpm: fpm_t,
+) -> float32x4_t {
+    fp8_acc!("fmlallbt {0:v}.4s, {1:v}.16b, {2:v}.16b", fpm, a, b, c)
+}
+
+/// Widening 8-bit floating-point multiply-add, adding the products of the
+/// second elements of each group of four of `b` and element `LANE` of `c` to
+/// the elements of `a`
+#[inline]
+#[target_feature(enable = "neon,fp8fma")]
+#[cfg_attr(test, assert_instr(fmlallbt, LANE = 7))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn vmlallbtq_lane_f32_mf8_fpm<const LANE: i32>(
+    a: float32x4_t,
+    b: mfloat8x16_t,
+    c: mfloat8x8_t,
+    fpm: fpm_t,
+) -> float32x4_t {
+    static_assert_imm3!(LANE);
+    let c: mfloat8x16_t = simd_shuffle16(
+        c,
+        c,
+        [
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+        ],
+    );
+    vmlallbtq_f32_mf8_fpm(a, b, c, fpm)
+}
+
+/// Widening 8-bit floating-point multiply-add, adding the products of the
+/// second elements of each group of four of `b` and element `LANE` of `c` to
+/// the elements of `a`
+#[inline]
+#[target_feature(enable = "neon,fp8fma")]
+#[cfg_attr(test, assert_instr(fmlallbt, LANE = 15))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn vmlallbtq_laneq_f32_mf8_fpm<const LANE: i32>(
+    a: float32x4_t,
+    b: mfloat8x16_t,
+    c: mfloat8x16_t,
+    fpm: fpm_t,
+) -> float32x4_t {
+    static_assert_imm4!(LANE);
+    let c: mfloat8x16_t = simd_shuffle16(
+        c,
+        c,
+        [
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+        ],
+    );
+    vmlallbtq_f32_mf8_fpm(a, b, c, fpm)
+}
+
+/// Widening 8-bit floating-point multiply-add, adding the products of the
+/// third elements of each group of four of `b` and `c` to the elements of
+/// `a`
+#[inline]
+#[target_feature(enable = "neon,fp8fma")]
+#[cfg_attr(test, assert_instr(fmlalltb))]
+pub unsafe fn vmlalltbq_f32_mf8_fpm(
+    a: float32x4_t,
+    b: mfloat8x16_t,
+    c: mfloat8x16_t,
+    fpm: fpm_t,
+) -> float32x4_t {
+    fp8_acc!("fmlalltb {0:v}.4s, {1:v}.16b, {2:v}.16b", fpm, a, b, c)
+}
+
+/// Widening 8-bit floating-point multiply-add, adding the products of the
+/// third elements of each group of four of `b` and element `LANE` of `c` to
+/// the elements of `a`
+#[inline]
+#[target_feature(enable = "neon,fp8fma")]
+#[cfg_attr(test, assert_instr(fmlalltb, LANE = 7))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn vmlalltbq_lane_f32_mf8_fpm<const LANE: i32>(
+    a: float32x4_t,
+    b: mfloat8x16_t,
+    c: mfloat8x8_t,
+    fpm: fpm_t,
+) -> float32x4_t {
+    static_assert_imm3!(LANE);
+    let c: mfloat8x16_t = simd_shuffle16(
+        c,
+        c,
+        [
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+        ],
+    );
+    vmlalltbq_f32_mf8_fpm(a, b, c, fpm)
+}
+
+/// Widening 8-bit floating-point multiply-add, adding the products of the
+/// third elements of each group of four of `b` and element `LANE` of `c` to
+/// the elements of `a`
+#[inline]
+#[target_feature(enable = "neon,fp8fma")]
+#[cfg_attr(test, assert_instr(fmlalltb, LANE = 15))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn vmlalltbq_laneq_f32_mf8_fpm<const LANE: i32>(
+    a: float32x4_t,
+    b: mfloat8x16_t,
+    c: mfloat8x16_t,
+    fpm: fpm_t,
+) -> float32x4_t {
+    static_assert_imm4!(LANE);
+    let c: mfloat8x16_t = simd_shuffle16(
+        c,
+        c,
+        [
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+        ],
+    );
+    vmlalltbq_f32_mf8_fpm(a, b, c, fpm)
+}
+
+/// Widening 8-bit floating-point multiply-add, adding the products of the
+/// fourth elements of each group of four of `b` and `c` to the elements of
+/// `a`
+#[inline]
+#[target_feature(enable = "neon,fp8fma")]
+#[cfg_attr(test, assert_instr(fmlalltt))]
+pub unsafe fn vmlallttq_f32_mf8_fpm(
+    a: float32x4_t,
+    b: mfloat8x16_t,
+    c: mfloat8x16_t,
+    fpm: fpm_t,
+) -> float32x4_t {
+    fp8_acc!("fmlalltt {0:v}.4s, {1:v}.16b, {2:v}.16b", fpm, a, b, c)
+}
+
+/// Widening 8-bit floating-point multiply-add, adding the products of the
+/// fourth elements of each group of four of `b` and element `LANE` of `c` to
+/// the elements of `a`
+#[inline]
+#[target_feature(enable = "neon,fp8fma")]
+#[cfg_attr(test, assert_instr(fmlalltt, LANE = 7))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn vmlallttq_lane_f32_mf8_fpm<const LANE: i32>(
+    a: float32x4_t,
+    b: mfloat8x16_t,
+    c: mfloat8x8_t,
+    fpm: fpm_t,
+) -> float32x4_t {
+    static_assert_imm3!(LANE);
+    let c: mfloat8x16_t = simd_shuffle16(
+        c,
+        c,
+        [
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+        ],
+    );
+    vmlallttq_f32_mf8_fpm(a, b, c, fpm)
+}
+
+/// Widening 8-bit floating-point multiply-add, adding the products of the
+/// fourth elements of each group of four of `b` and element `LANE` of `c` to
+/// the elements of `a`
+#[inline]
+#[target_feature(enable = "neon,fp8fma")]
+#[cfg_attr(test, assert_instr(fmlalltt, LANE = 15))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn vmlallttq_laneq_f32_mf8_fpm<const LANE: i32>(
+    a: float32x4_t,
+    b: mfloat8x16_t,
+    c: mfloat8x16_t,
+    fpm: fpm_t,
+) -> float32x4_t {
+    static_assert_imm4!(LANE);
+    let c: mfloat8x16_t = simd_shuffle16(
+        c,
+        c,
+        [
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+            LANE as u32,
+        ],
+    );
+    vmlallttq_f32_mf8_fpm(a, b, c, fpm)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::aarch64::*;
+    use std::mem::transmute;
+    use stdarch_test::simd_test;
+
+    // E4M3 has no infinities and only uses the largest exponent and
+    // mantissa for NaN.
+    fn e4m3(x: u8) -> f64 {
+        let sign = if x & 0x80 != 0 { -1.0 } else { 1.0 };
+        let exp = (x >> 3) & 0xf;
+        let mant = x & 7;
+        sign * match (exp, mant) {
+            (15, 7) => f64::NAN,
+            (0, _) => mant as f64 * 2f64.powi(-9),
+            _ => (8 + mant) as f64 * 2f64.powi(exp as i32 - 10),
+        }
+    }
+
+    fn e5m2(x: u8) -> f64 {
+        let sign = if x & 0x80 != 0 { -1.0 } else { 1.0 };
+        let exp = (x >> 2) & 0x1f;
+        let mant = x & 3;
+        sign * match (exp, mant) {
+            (31, 0) => f64::INFINITY,
+            (31, _) => f64::NAN,
+            (0, _) => mant as f64 * 2f64.powi(-16),
+            _ => (4 + mant) as f64 * 2f64.powi(exp as i32 - 17),
+        }
+    }
+
+    fn from_e4m3(x: f64) -> u8 {
+        (0..=255).find(|&c| e4m3(c) == x).unwrap()
+    }
+
+    // The `binary16` bit pattern of `x`, which has to be exactly
+    // representable.
+    fn f16_bits(x: f64) -> u16 {
+        let sign = if x.is_sign_negative() { 0x8000 } else { 0 };
+        let x = x.abs();
+        if x.is_infinite() {
+            sign | 0x7c00
+        } else if x < 2f64.powi(-14) {
+            sign | (x * 2f64.powi(24)) as u16
+        } else {
+            let bits = x.to_bits();
+            let exp = (bits >> 52) as i32 - 1023;
+            sign | ((exp + 15) as u16) << 10 | ((bits >> 42) & 0x3ff) as u16
+        }
+    }
+
+    fn bf16_bits(x: f64) -> u16 {
+        ((x as f32).to_bits() >> 16) as u16
+    }
+
+    fn check(a: &[u8], r: &[u16], decode: fn(u8) -> f64, bits: fn(f64) -> u16) {
+        for (&a, &r) in a.iter().zip(r) {
+            let e = decode(a);
+            if e.is_nan() {
+                assert!(r & 0x7fff > bits(f64::INFINITY), "{:#x}: {:#x}", a, r);
+            } else {
+                assert_eq!(r, bits(e), "{:#x}", a);
+            }
+        }
+    }
+
+    // All 8-bit values, eight at a time.
+    fn all() -> impl Iterator<Item = [u8; 8]> {
+        (0..32).map(|i| {
+            let mut a = [0; 8];
+            for (j, a) in a.iter_mut().enumerate() {
+                *a = (i * 8 + j) as u8;
+            }
+            a
+        })
+    }
+
+    // The values which aren't NaN, eight at a time, padded with zeros.
+    fn not_nan(decode: fn(u8) -> f64) -> Vec<[u8; 8]> {
+        let values: Vec<u8> = (0..=255).filter(|&c| !decode(c).is_nan()).collect();
+        values
+            .chunks(8)
+            .map(|c| {
+                let mut a = [0; 8];
+                a[..c.len()].copy_from_slice(c);
+                a
+            })
+            .collect()
+    }
+
+    fn low(a: [u8; 8]) -> [u8; 16] {
+        let mut r = [0x55; 16];
+        r[..8].copy_from_slice(&a);
+        r
+    }
+
+    fn high(a: [u8; 8]) -> [u8; 16] {
+        let mut r = [0x55; 16];
+        r[8..].copy_from_slice(&a);
+        r
+    }
+
+    fn src1_e4m3() -> fpm_t {
+        __arm_set_fpm_src1_format(__arm_fpm_init(), __ARM_FPM_E4M3)
+    }
+
+    // The second source operand is E5M2 and the first E4M3, to check which
+    // one is used.
+    fn src2_e5m2() -> fpm_t {
+        __arm_set_fpm_src2_format(src1_e4m3(), __ARM_FPM_E5M2)
+    }
+
+    #[test]
+    fn test_arm_fpm_init() {
+        assert_eq!(__arm_fpm_init(), 0);
+    }
+
+    #[test]
+    fn test_arm_set_fpm_fields() {
+        let fpm = __arm_fpm_init();
+        assert_eq!(__arm_set_fpm_src1_format(fpm, __ARM_FPM_E4M3), 1);
+        assert_eq!(__arm_set_fpm_src2_format(fpm, __ARM_FPM_E4M3), 1 << 3);
+        assert_eq!(__arm_set_fpm_dst_format(fpm, __ARM_FPM_E4M3), 1 << 6);
+        assert_eq!(__arm_set_fpm_overflow_mul(fpm, __ARM_FPM_SATURATE), 1 << 14);
+        assert_eq!(__arm_set_fpm_overflow_cvt(fpm, __ARM_FPM_SATURATE), 1 << 15);
+        assert_eq!(__arm_set_fpm_lscale(fpm, 0x7f), 0x7f << 16);
+        assert_eq!(__arm_set_fpm_nscale(fpm, -1), 0xff << 24);
+        assert_eq!(__arm_set_fpm_lscale2(fpm, 0x3f), 0x3f << 32);
+        // Setting a field replaces its old value and keeps the others.
+        let fpm = __arm_set_fpm_src1_format(__arm_set_fpm_lscale(fpm, 3), __ARM_FPM_E4M3);
+        assert_eq!(__arm_set_fpm_src1_format(fpm, __ARM_FPM_E5M2), 3 << 16);
+    }
+
+    fn both_e4m3() -> fpm_t {
+        __arm_set_fpm_src2_format(src1_e4m3(), __ARM_FPM_E4M3)
+    }
+
+    fn ident(a: [u8; 8]) -> [u8; 8] {
+        a
+    }
+
+    macro_rules! test_cvt {
+        ($f:ident, $input:ident, $fpm:expr, $decode:ident, $bits:ident) => {
+            for a in all() {
+                let r: [u16; 8] = transmute($f(transmute($input(a)), $fpm));
+                check(&a, &r, $decode, $bits);
+            }
+        };
+    }
+
+    #[simd_test(enable = "fp8")]
+    unsafe fn test_vcvt1_bf16_mf8_fpm() {
+        test_cvt!(vcvt1_bf16_mf8_fpm, ident, src1_e4m3(), e4m3, bf16_bits);
+    }
+
+    #[simd_test(enable = "fp8")]
+    unsafe fn test_vcvt1_low_bf16_mf8_fpm() {
+        test_cvt!(vcvt1_low_bf16_mf8_fpm, low, src1_e4m3(), e4m3, bf16_bits);
+    }
+
+    #[simd_test(enable = "fp8")]
+    unsafe fn test_vcvt1_high_bf16_mf8_fpm() {
+        test_cvt!(vcvt1_high_bf16_mf8_fpm, high, src1_e4m3(), e4m3, bf16_bits);
+    }
+
+    #[simd_test(enable = "fp8")]
+    unsafe fn test_vcvt1_f16_mf8_fpm() {
+        test_cvt!(vcvt1_f16_mf8_fpm, ident, src1_e4m3(), e4m3, f16_bits);
+    }
+
+    #[simd_test(enable = "fp8")]
+    unsafe fn test_vcvt1_low_f16_mf8_fpm() {
+        test_cvt!(vcvt1_low_f16_mf8_fpm, low, src1_e4m3(), e4m3, f16_bits);
+    }
+
+    #[simd_test(enable = "fp8")]
+    unsafe fn test_vcvt1_high_f16_mf8_fpm() {
+        test_cvt!(vcvt1_high_f16_mf8_fpm, high, src1_e4m3(), e4m3, f16_bits);
+    }
+
+    #[simd_test(enable = "fp8")]
+    unsafe fn test_vcvt2_bf16_mf8_fpm() {
+        test_cvt!(vcvt2_bf16_mf8_fpm, ident, src2_e5m2(), e5m2, bf16_bits);
+    }
+
+    #[simd_test(enable = "fp8")]
+    unsafe fn test_vcvt2_low_bf16_mf8_fpm() {
+        test_cvt!(vcvt2_low_bf16_mf8_fpm, low, src2_e5m2(), e5m2, bf16_bits);
+    }
+
+    #[simd_test(enable = "fp8")]
+    unsafe fn test_vcvt2_high_bf16_mf8_fpm() {
+        test_cvt!(vcvt2_high_bf16_mf8_fpm, high, src2_e5m2(), e5m2, bf16_bits);
+    }
+
+    #[simd_test(enable = "fp8")]
+    unsafe fn test_vcvt2_f16_mf8_fpm() {
+        test_cvt!(vcvt2_f16_mf8_fpm, ident, src2_e5m2(), e5m2, f16_bits);
+    }
+
+    #[simd_test(enable = "fp8")]
+    unsafe fn test_vcvt2_low_f16_mf8_fpm() {
+        test_cvt!(vcvt2_low_f16_mf8_fpm, low, src2_e5m2(), e5m2, f16_bits);
+    }
+
+    #[simd_test(enable = "fp8")]
+    unsafe fn test_vcvt2_high_f16_mf8_fpm() {
+        test_cvt!(vcvt2_high_f16_mf8_fpm, high, src2_e5m2(), e5m2, f16_bits);
+    }
+
+    #[simd_test(enable = "fp8")]
+    unsafe fn test_vcvt_mf8_f32_fpm() {
+        let fpm = __arm_set_fpm_dst_format(__arm_fpm_init(), __ARM_FPM_E4M3);
+        for a in not_nan(e4m3) {
+            let [lo, hi]: [[f32; 4]; 2] = transmute(a.map(|x| e4m3(x) as f32));
+            let r: [u8; 8] = transmute(vcvt_mf8_f32_fpm(transmute(lo), transmute(hi), fpm));
+            assert_eq!(r, a);
+        }
+    }
+
+    #[simd_test(enable = "fp8")]
+    unsafe fn test_vcvt_high_mf8_f32_fpm() {
+        let fpm = __arm_set_fpm_dst_format(__arm_fpm_init(), __ARM_FPM_E4M3);
+        for a in not_nan(e4m3) {
+            let [lo, hi]: [[f32; 4]; 2] = transmute(a.map(|x| e4m3(x) as f32));
+            let r: [u8; 16] = transmute(vcvt_high_mf8_f32_fpm(
+                transmute([0x55u8; 8]),
+                transmute(lo),
+                transmute(hi),
+                fpm,
+            ));
+            assert_eq!(r, high(a));
+        }
+    }
+
+    // Every E5M2 value is the upper byte of the `binary16` one.
+    #[simd_test(enable = "fp8")]
+    unsafe fn test_vcvt_mf8_f16_fpm() {
+        for a in not_nan(e5m2) {
+            let [lo, hi]: [[u16; 4]; 2] = transmute(a.map(|x| (x as u16) << 8));
+            let r: [u8; 8] = transmute(vcvt_mf8_f16_fpm(
+                transmute(lo),
+                transmute(hi),
+                __arm_fpm_init(),
+            ));
+            assert_eq!(r, a);
+        }
+    }
+
+    #[simd_test(enable = "fp8")]
+    unsafe fn test_vcvtq_mf8_f16_fpm() {
+        for a in not_nan(e5m2).chunks_exact(2) {
+            let lo = a[0].map(|x| (x as u16) << 8);
+            let hi = a[1].map(|x| (x as u16) << 8);
+            let r: [[u8; 8]; 2] = transmute(vcvtq_mf8_f16_fpm(
+                transmute(lo),
+                transmute(hi),
+                __arm_fpm_init(),
+            ));
+            assert_eq!(r, [a[0], a[1]]);
+        }
+    }
+
+    // Small values whose products and sums are exact.
+    const A: [f64; 8] = [1.0, 2.0, 0.0, 0.5, -1.0, 3.0, 0.5, 2.0];
+    const B: [f64; 16] = [
+        1.0, 2.0, 3.0, 0.5, 2.0, 2.0, -1.0, 1.0, 0.5, 3.0, 1.0, 1.0, -2.0, 0.5, 2.0, 1.0,
+    ];
+    const C: [f64; 16] = [
+        2.0, 0.5, 1.0, 2.0, 0.5, 0.5, 1.0, 3.0, 1.0, 1.0, 2.0, -1.0, 0.5, 2.0, 3.0, 0.5,
+    ];
+
+    fn mf8<const N: usize>(x: &[f64]) -> [u8; N] {
+        let mut r = [0; N];
+        for (r, &x) in r.iter_mut().zip(x) {
+            *r = from_e4m3(x);
+        }
+        r
+    }
+
+    fn f16s<const N: usize>(x: &[f64]) -> [u16; N] {
+        let mut r = [0; N];
+        for (r, &x) in r.iter_mut().zip(x) {
+            *r = f16_bits(x);
+        }
+        r
+    }
+
+    fn f32s<const N: usize>(x: &[f64]) -> [f32; N] {
+        let mut r = [0.0; N];
+        for (r, &x) in r.iter_mut().zip(x) {
+            *r = x as f32;
+        }
+        r
+    }
+
+    // Adds the sums of the products of the groups of elements of `b` and `c`
+    // to the elements of `a`.
+    fn dot(a: &[f64], b: &[f64], c: &[f64]) -> Vec<f64> {
+        let n = b.len() / a.len();
+        (0..a.len())
+            .map(|i| a[i] + (0..n).map(|j| b[i * n + j] * c[i * n + j]).sum::<f64>())
+            .collect()
+    }
+
+    // Adds the products of element `k` of the groups of elements of `b` and
+    // `c` to the elements of `a`.
+    fn mla(a: &[f64], b: &[f64], c: &[f64], k: usize) -> Vec<f64> {
+        let n = b.len() / a.len();
+        (0..a.len())
+            .map(|i| a[i] + b[i * n + k] * c[i * n + k])
+            .collect()
+    }
+
+    // Repeats group `lane` of `n` elements of `c` `len` times.
+    fn group(c: &[f64], n: usize, lane: usize, len: usize) -> Vec<f64> {
+        (0..len).map(|i| c[lane * n + i % n]).collect()
+    }
+
+    macro_rules! test_dot {
+        ($f:ident, $ty:ty, $acc:ident, $lanes:literal, $bytes:literal) => {
+            let (a, b, c) = (&A[..$lanes], &B[..$bytes], &C[..$bytes]);
+            let r: [$ty; $lanes] = transmute($f(
+                transmute($acc::<$lanes>(a)),
+                transmute(mf8::<$bytes>(b)),
+                transmute(mf8::<$bytes>(c)),
+                both_e4m3(),
+            ));
+            assert_eq!(r, $acc::<$lanes>(&dot(a, b, c)));
+        };
+    }
+
+    macro_rules! test_dot_lane {
+        ($f:ident, $ty:ty, $acc:ident, $lanes:literal, $bytes:literal, $c_bytes:literal, $lane:literal) => {
+            let (a, b, c) = (&A[..$lanes], &B[..$bytes], &C[..$c_bytes]);
+            let r: [$ty; $lanes] = transmute($f::<$lane>(
+                transmute($acc::<$lanes>(a)),
+                transmute(mf8::<$bytes>(b)),
+                transmute(mf8::<$c_bytes>(c)),
+                both_e4m3(),
+            ));
+            let c = group(c, $bytes / $lanes, $lane, $bytes);
+            assert_eq!(r, $acc::<$lanes>(&dot(a, b, &c)));
+        };
+    }
+
+    macro_rules! test_mla {
+        ($f:ident, $ty:ty, $acc:ident, $lanes:literal, $k:literal) => {
+            let a = &A[..$lanes];
+            let r: [$ty; $lanes] = transmute($f(
+                transmute($acc::<$lanes>(a)),
+                transmute(mf8::<16>(&B)),
+                transmute(mf8::<16>(&C)),
+                both_e4m3(),
+            ));
+            assert_eq!(r, $acc::<$lanes>(&mla(a, &B, &C, $k)));
+        };
+    }
+
+    macro_rules! test_mla_lane {
+        ($f:ident, $ty:ty, $acc:ident, $lanes:literal, $k:literal, $c_bytes:literal, $lane:literal) => {
+            let (a, c) = (&A[..$lanes], &C[..$c_bytes]);
+            let r: [$ty; $lanes] = transmute($f::<$lane>(
+                transmute($acc::<$lanes>(a)),
+                transmute(mf8::<16>(&B)),
+                transmute(mf8::<$c_bytes>(c)),
+                both_e4m3(),
+            ));
+            let c = group(c, 1, $lane, 16);
+            assert_eq!(r, $acc::<$lanes>(&mla(a, &B, &c, $k)));
+        };
+    }
+
+    #[simd_test(enable = "fp8dot2")]
+    unsafe fn test_vdot_f16_mf8_fpm() {
+        test_dot!(vdot_f16_mf8_fpm, u16, f16s, 4, 8);
+    }
+
+    #[simd_test(enable = "fp8dot2")]
+    unsafe fn test_vdotq_f16_mf8_fpm() {
+        test_dot!(vdotq_f16_mf8_fpm, u16, f16s, 8, 16);
+    }
+    #[simd_test(enable = "fp8dot2")]
+    unsafe fn test_vdot_lane_f16_mf8_fpm() {
+        test_dot_lane!(vdot_lane_f16_mf8_fpm, u16, f16s, 4, 8, 8, 3);
+    }
+    #[simd_test(enable = "fp8dot2")]
+    unsafe fn test_vdot_laneq_f16_mf8_fpm() {
+        test_dot_lane!(vdot_laneq_f16_mf8_fpm, u16, f16s, 4, 8, 16, 7);
+    }
+    #[simd_test(enable = "fp8dot2")]
+    unsafe fn test_vdotq_lane_f16_mf8_fpm() {
+        test_dot_lane!(vdotq_lane_f16_mf8_fpm, u16, f16s, 8, 16, 8, 3);
+    }
+    #[simd_test(enable = "fp8dot2")]
+    unsafe fn test_vdotq_laneq_f16_mf8_fpm() {
+        test_dot_lane!(vdotq_laneq_f16_mf8_fpm, u16, f16s, 8, 16, 16, 7);
+    }
+
+    #[simd_test(enable = "fp8dot4")]
+    unsafe fn test_vdot_f32_mf8_fpm() {
+        test_dot!(vdot_f32_mf8_fpm, f32, f32s, 2, 8);
+    }
+
+    #[simd_test(enable = "fp8dot4")]
+    unsafe fn test_vdotq_f32_mf8_fpm() {
+        test_dot!(vdotq_f32_mf8_fpm, f32, f32s, 4, 16);
+    }
+    #[simd_test(enable = "fp8dot4")]
+    unsafe fn test_vdot_lane_f32_mf8_fpm() {
+        test_dot_lane!(vdot_lane_f32_mf8_fpm, f32, f32s, 2, 8, 8, 1);
+    }
+    #[simd_test(enable = "fp8dot4")]
+    unsafe fn test_vdot_laneq_f32_mf8_fpm() {
+        test_dot_lane!(vdot_laneq_f32_mf8_fpm, f32, f32s, 2, 8, 16, 3);
+    }
+    #[simd_test(enable = "fp8dot4")]
+    unsafe fn test_vdotq_lane_f32_mf8_fpm() {
+        test_dot_lane!(vdotq_lane_f32_mf8_fpm, f32, f32s, 4, 16, 8, 1);
+    }
+    #[simd_test(enable = "fp8dot4")]
+    unsafe fn test_vdotq_laneq_f32_mf8_fpm() {
+        test_dot_lane!(vdotq_laneq_f32_mf8_fpm, f32, f32s, 4, 16, 16, 3);
+    }
+
+    #[simd_test(enable = "fp8fma")]
+    unsafe fn test_vmlalbq_f16_mf8_fpm() {
+        test_mla!(vmlalbq_f16_mf8_fpm, u16, f16s, 8, 0);
+    }
+
+    #[simd_test(enable = "fp8fma")]
+    unsafe fn test_vmlalbq_lane_f16_mf8_fpm() {
+        test_mla_lane!(vmlalbq_lane_f16_mf8_fpm, u16, f16s, 8, 0, 8, 7);
+    }
+
+    #[simd_test(enable = "fp8fma")]
+    unsafe fn test_vmlalbq_laneq_f16_mf8_fpm() {
+        test_mla_lane!(vmlalbq_laneq_f16_mf8_fpm, u16, f16s, 8, 0, 16, 15);
+    }
+
+    #[simd_test(enable = "fp8fma")]
+    unsafe fn test_vmlaltq_f16_mf8_fpm() {
+        test_mla!(vmlaltq_f16_mf8_fpm, u16, f16s, 8, 1);
+    }
+
+    #[simd_test(enable = "fp8fma")]
+    unsafe fn test_vmlaltq_lane_f16_mf8_fpm() {
+        test_mla_lane!(vmlaltq_lane_f16_mf8_fpm, u16, f16s, 8, 1, 8, 7);
+    }
+
+    #[simd_test(enable = "fp8fma")]
+    unsafe fn test_vmlaltq_laneq_f16_mf8_fpm() {
+        test_mla_lane!(vmlaltq_laneq_f16_mf8_fpm, u16, f16s, 8, 1, 16, 15);
+    }
+
+    #[simd_test(enable = "fp8fma")]
+    unsafe fn test_vmlallbbq_f32_mf8_fpm() {
+        test_mla!(vmlallbbq_f32_mf8_fpm, f32, f32s, 4, 0);
+    }
+
+    #[simd_test(enable = "fp8fma")]
+    unsafe fn test_vmlallbbq_lane_f32_mf8_fpm() {
+        test_mla_lane!(vmlallbbq_lane_f32_mf8_fpm, f32, f32s, 4, 0, 8, 7);
+    }
+
+    #[simd_test(enable = "fp8fma")]
+    unsafe fn test_vmlallbbq_laneq_f32_mf8_fpm() {
+        test_mla_lane!(vmlallbbq_laneq_f32_mf8_fpm, f32, f32s, 4, 0, 16, 15);
+    }
+
+    #[simd_test(enable = "fp8fma")]
+    unsafe fn test_vmlallbtq_f32_mf8_fpm() {
+        test_mla!(vmlallbtq_f32_mf8_fpm, f32, f32s, 4, 1);
+    }
+
+    #[simd_test(enable = "fp8fma")]
+    unsafe fn test_vmlallbtq_lane_f32_mf8_fpm() {
+        test_mla_lane!(vmlallbtq_lane_f32_mf8_fpm, f32, f32s, 4, 1, 8, 7);
+    }
+
+    #[simd_test(enable = "fp8fma")]
+    unsafe fn test_vmlallbtq_laneq_f32_mf8_fpm() {
+        test_mla_lane!(vmlallbtq_laneq_f32_mf8_fpm, f32, f32s, 4, 1, 16, 15);
+    }
+
+    #[simd_test(enable = "fp8fma")]
+    unsafe fn test_vmlalltbq_f32_mf8_fpm() {
+        test_mla!(vmlalltbq_f32_mf8_fpm, f32, f32s, 4, 2);
+    }
+
+    #[simd_test(enable = "fp8fma")]
+    unsafe fn test_vmlalltbq_lane_f32_mf8_fpm() {
+        test_mla_lane!(vmlalltbq_lane_f32_mf8_fpm, f32, f32s, 4, 2, 8, 7);
+    }
+
+    #[simd_test(enable = "fp8fma")]
+    unsafe fn test_vmlalltbq_laneq_f32_mf8_fpm() {
+        test_mla_lane!(vmlalltbq_laneq_f32_mf8_fpm, f32, f32s, 4, 2, 16, 15);
+    }
+
+    #[simd_test(enable = "fp8fma")]
+    unsafe fn test_vmlallttq_f32_mf8_fpm() {
+        test_mla!(vmlallttq_f32_mf8_fpm, f32, f32s, 4, 3);
+    }
+
+    #[simd_test(enable = "fp8fma")]
+    unsafe fn test_vmlallttq_lane_f32_mf8_fpm() {
+        test_mla_lane!(vmlallttq_lane_f32_mf8_fpm, f32, f32s, 4, 3, 8, 7);
+    }
+
+    #[simd_test(enable = "fp8fma")]
+    unsafe fn test_vmlallttq_laneq_f32_mf8_fpm() {
+        test_mla_lane!(vmlallttq_laneq_f32_mf8_fpm, f32, f32s, 4, 3, 16, 15);
+    }
+}
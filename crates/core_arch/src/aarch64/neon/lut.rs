@@ -0,0 +1,1030 @@
+//! Lookup table read intrinsics (FEAT_LUT)
+//!
+//! `LUTI2` and `LUTI4` expand packed 2-bit and 4-bit indices to the elements
+//! of a table they select. The indices are a segment of `b`, selected by
+//! `LANE`, which holds as many indices as the result has elements: 32 bits
+//! of it for 8-bit elements and 16 bits for 16-bit elements with `LUTI2`,
+//! and 64 and 32 bits with `LUTI4`. The indices are in order starting with
+//! the least significant bits. `LUTI2` reads the first four elements of `a`
+//! and `LUTI4` sixteen elements, which for 16-bit elements are spread over
+//! the two vectors of `a`.
+//!
+//! The `f16` and `bf16` versions only move the bit patterns of the
+//! elements, so they don't need the `fp16` or `bf16` target features.
+//!
+//! The reference is the "Lookup table" section of [ACLE][acle].
+//!
+//! [acle]: https://github.com/ARM-software/acle
+
+use crate::{
+    core_arch::{aarch64::neon::*, arm_shared::*},
+    mem::transmute,
+};
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.aarch64.neon.vluti2.lane.v16i8.v8i8"]
+    fn vluti2_lane_s8_(a: int8x8_t, b: uint8x8_t, n: i32) -> int8x16_t;
+    #[link_name = "llvm.aarch64.neon.vluti2.laneq.v16i8.v8i8"]
+    fn vluti2_laneq_s8_(a: int8x8_t, b: uint8x16_t, n: i32) -> int8x16_t;
+    #[link_name = "llvm.aarch64.neon.vluti2.lane.v16i8.v16i8"]
+    fn vluti2q_lane_s8_(a: int8x16_t, b: uint8x8_t, n: i32) -> int8x16_t;
+    #[link_name = "llvm.aarch64.neon.vluti2.laneq.v16i8.v16i8"]
+    fn vluti2q_laneq_s8_(a: int8x16_t, b: uint8x16_t, n: i32) -> int8x16_t;
+    #[link_name = "llvm.aarch64.neon.vluti2.lane.v8i16.v4i16"]
+    fn vluti2_lane_s16_(a: int16x4_t, b: uint8x8_t, n: i32) -> int16x8_t;
+    #[link_name = "llvm.aarch64.neon.vluti2.laneq.v8i16.v4i16"]
+    fn vluti2_laneq_s16_(a: int16x4_t, b: uint8x16_t, n: i32) -> int16x8_t;
+    #[link_name = "llvm.aarch64.neon.vluti2.lane.v8i16.v8i16"]
+    fn vluti2q_lane_s16_(a: int16x8_t, b: uint8x8_t, n: i32) -> int16x8_t;
+    #[link_name = "llvm.aarch64.neon.vluti2.laneq.v8i16.v8i16"]
+    fn vluti2q_laneq_s16_(a: int16x8_t, b: uint8x16_t, n: i32) -> int16x8_t;
+    #[link_name = "llvm.aarch64.neon.vluti4q.lane.v16i8"]
+    fn vluti4q_lane_s8_(a: int8x16_t, b: uint8x8_t, n: i32) -> int8x16_t;
+    #[link_name = "llvm.aarch64.neon.vluti4q.laneq.v16i8"]
+    fn vluti4q_laneq_s8_(a: int8x16_t, b: uint8x16_t, n: i32) -> int8x16_t;
+    #[link_name = "llvm.aarch64.neon.vluti4q.lane.x2.v8i16"]
+    fn vluti4q_lane_s16_x2_(a0: int16x8_t, a1: int16x8_t, b: uint8x8_t, n: i32) -> int16x8_t;
+    #[link_name = "llvm.aarch64.neon.vluti4q.laneq.x2.v8i16"]
+    fn vluti4q_laneq_s16_x2_(a0: int16x8_t, a1: int16x8_t, b: uint8x16_t, n: i32) -> int16x8_t;
+}
+
+/// Lookup table read with 2-bit indices, from the first four elements of `a`
+/// with the indices in segment `LANE` of `b`
+#[inline]
+#[target_feature(enable = "neon,lut")]
+#[cfg_attr(test, assert_instr(luti2, LANE = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn vluti2_lane_s8<const LANE: i32>(a: int8x8_t, b: uint8x8_t) -> int8x16_t {
+    static_assert_imm1!(LANE);
+    vluti2_lane_s8_(a, b, LANE)
+}
+
+/// Lookup table read with 2-bit indices, from the first four elements of `a`
+/// with the indices in segment `LANE` of `b`
+#[inline]
+#[target_feature(enable = "neon,lut")]
+#[cfg_attr(test, assert_instr(luti2, LANE = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn vluti2_lane_u8<const LANE: i32>(a: uint8x8_t, b: uint8x8_t) -> uint8x16_t {
+    static_assert_imm1!(LANE);
+    transmute(vluti2_lane_s8_(transmute(a), b, LANE))
+}
+
+/// Lookup table read with 2-bit indices, from the first four elements of `a`
+/// with the indices in segment `LANE` of `b`
+#[inline]
+#[target_feature(enable = "neon,lut")]
+#[cfg_attr(test, assert_instr(luti2, LANE = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn vluti2_lane_p8<const LANE: i32>(a: poly8x8_t, b: uint8x8_t) -> poly8x16_t {
+    static_assert_imm1!(LANE);
+    transmute(vluti2_lane_s8_(transmute(a), b, LANE))
+}
+
+/// Lookup table read with 2-bit indices, from the first four elements of `a`
+/// with the indices in segment `LANE` of `b`
+#[inline]
+#[target_feature(enable = "neon,lut")]
+#[cfg_attr(test, assert_instr(luti2, LANE = 3))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn vluti2_laneq_s8<const LANE: i32>(a: int8x8_t, b: uint8x16_t) -> int8x16_t {
+    static_assert_imm2!(LANE);
+    vluti2_laneq_s8_(a, b, LANE)
+}
+
+/// Lookup table read with 2-bit indices, from the first four elements of `a`
+/// with the indices in segment `LANE` of `b`
+#[inline]
+#[target_feature(enable = "neon,lut")]
+#[cfg_attr(test, assert_instr(luti2, LANE = 3))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn vluti2_laneq_u8<const LANE: i32>(a: uint8x8_t, b: uint8x16_t) -> uint8x16_t {
+    static_assert_imm2!(LANE);
+    transmute(vluti2_laneq_s8_(transmute(a), b, LANE))
+}
+
+/// Lookup table read with 2-bit indices, from the first four elements of `a`
+/// with the indices in segment `LANE` of `b`
+#[inline]
+#[target_feature(enable = "neon,lut")]
+#[cfg_attr(test, assert_instr(luti2, LANE = 3))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn vluti2_laneq_p8<const LANE: i32>(a: poly8x8_t, b: uint8x16_t) -> poly8x16_t {
+    static_assert_imm2!(LANE);
+    transmute(vluti2_laneq_s8_(transmute(a), b, LANE))
+}
+
+/// Lookup table read with 2-bit indices, from the first four elements of `a`
+/// with the indices in segment `LANE` of `b`
+#[inline]
+#[target_feature(enable = "neon,lut")]
+#[cfg_attr(test, assert_instr(luti2, LANE = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn vluti2q_lane_s8<const LANE: i32>(a: int8x16_t, b: uint8x8_t) -> int8x16_t {
+    static_assert_imm1!(LANE);
+    vluti2q_lane_s8_(a, b, LANE)
+}
+
+/// Lookup table read with 2-bit indices, from the first four elements of `a`
+/// with the indices in segment `LANE` of `b`
+#[inline]
+#[target_feature(enable = "neon,lut")]
+#[cfg_attr(test, assert_instr(luti2, LANE = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn vluti2q_lane_u8<const LANE: i32>(a: uint8x16_t, b: uint8x8_t) -> uint8x16_t {
+    static_assert_imm1!(LANE);
+    transmute(vluti2q_lane_s8_(transmute(a), b, LANE))
+}
+
+/// Lookup table read with 2-bit indices, from the first four elements of `a`
+/// with the indices in segment `LANE` of `b`
+#[inline]
+#[target_feature(enable = "neon,lut")]
+#[cfg_attr(test, assert_instr(luti2, LANE = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn vluti2q_lane_p8<const LANE: i32>(a: poly8x16_t, b: uint8x8_t) -> poly8x16_t {
+    static_assert_imm1!(LANE);
+    transmute(vluti2q_lane_s8_(transmute(a), b, LANE))
+}
+
+/// Lookup table read with 2-bit indices, from the first four elements of `a`
+/// with the indices in segment `LANE` of `b`
+#[inline]
+#[target_feature(enable = "neon,lut")]
+#[cfg_attr(test, assert_instr(luti2, LANE = 3))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn vluti2q_laneq_s8<const LANE: i32>(a: int8x16_t, b: uint8x16_t) -> int8x16_t {
+    static_assert_imm2!(LANE);
+    vluti2q_laneq_s8_(a, b, LANE)
+}
+
+/// Lookup table read with 2-bit indices, from the first four elements of `a`
+/// with the indices in segment `LANE` of `b`
+#[inline]
+#[target_feature(enable = "neon,lut")]
+#[cfg_attr(test, assert_instr(luti2, LANE = 3))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn vluti2q_laneq_u8<const LANE: i32>(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
+    static_assert_imm2!(LANE);
+    transmute(vluti2q_laneq_s8_(transmute(a), b, LANE))
+}
+
+/// Lookup table read with 2-bit indices, from the first four elements of `a`
+/// with the indices in segment `LANE` of `b`
+#[inline]
+#[target_feature(enable = "neon,lut")]
+#[cfg_attr(test, assert_instr(luti2, LANE = 3))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn vluti2q_laneq_p8<const LANE: i32>(a: poly8x16_t, b: uint8x16_t) -> poly8x16_t {
+    static_assert_imm2!(LANE);
+    transmute(vluti2q_laneq_s8_(transmute(a), b, LANE))
+}
+
+/// Lookup table read with 2-bit indices, from the first four elements of `a`
+/// with the indices in segment `LANE` of `b`
+#[inline]
+#[target_feature(enable = "neon,lut")]
+#[cfg_attr(test, assert_instr(luti2, LANE = 3))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn vluti2_lane_s16<const LANE: i32>(a: int16x4_t, b: uint8x8_t) -> int16x8_t {
+    static_assert_imm2!(LANE);
+    vluti2_lane_s16_(a, b, LANE)
+}
+
+/// Lookup table read with 2-bit indices, from the first four elements of `a`
+/// with the indices in segment `LANE` of `b`
+#[inline]
+#[target_feature(enable = "neon,lut")]
+#[cfg_attr(test, assert_instr(luti2, LANE = 3))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn vluti2_lane_u16<const LANE: i32>(a: uint16x4_t, b: uint8x8_t) -> uint16x8_t {
+    static_assert_imm2!(LANE);
+    transmute(vluti2_lane_s16_(transmute(a), b, LANE))
+}
+
+/// Lookup table read with 2-bit indices, from the first four elements of `a`
+/// with the indices in segment `LANE` of `b`
+#[inline]
+#[target_feature(enable = "neon,lut")]
+#[cfg_attr(test, assert_instr(luti2, LANE = 3))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn vluti2_lane_p16<const LANE: i32>(a: poly16x4_t, b: uint8x8_t) -> poly16x8_t {
+    static_assert_imm2!(LANE);
+    transmute(vluti2_lane_s16_(transmute(a), b, LANE))
+}
+
+/// Lookup table read with 2-bit indices, from the first four elements of `a`
+/// with the indices in segment `LANE` of `b`
+#[inline]
+#[target_feature(enable = "neon,lut")]
+#[cfg_attr(test, assert_instr(luti2, LANE = 3))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn vluti2_lane_f16<const LANE: i32>(a: float16x4_t, b: uint8x8_t) -> float16x8_t {
+    static_assert_imm2!(LANE);
+    transmute(vluti2_lane_s16_(transmute(a), b, LANE))
+}
+
+/// Lookup table read with 2-bit indices, from the first four elements of `a`
+/// with the indices in segment `LANE` of `b`
+#[inline]
+#[target_feature(enable = "neon,lut")]
+#[cfg_attr(test, assert_instr(luti2, LANE = 3))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn vluti2_lane_bf16<const LANE: i32>(a: bfloat16x4_t, b: uint8x8_t) -> bfloat16x8_t {
+    static_assert_imm2!(LANE);
+    transmute(vluti2_lane_s16_(transmute(a), b, LANE))
+}
+
+/// Lookup table read with 2-bit indices, from the first four elements of `a`
+/// with the indices in segment `LANE` of `b`
+#[inline]
+#[target_feature(enable = "neon,lut")]
+#[cfg_attr(test, assert_instr(luti2, LANE = 7))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn vluti2_laneq_s16<const LANE: i32>(a: int16x4_t, b: uint8x16_t) -> int16x8_t {
+    static_assert_imm3!(LANE);
+    vluti2_laneq_s16_(a, b, LANE)
+}
+
+/// Lookup table read with 2-bit indices, from the first four elements of `a`
+/// with the indices in segment `LANE` of `b`
+#[inline]
+#[target_feature(enable = "neon,lut")]
+#[cfg_attr(test, assert_instr(luti2, LANE = 7))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn vluti2_laneq_u16<const LANE: i32>(a: uint16x4_t, b: uint8x16_t) -> uint16x8_t {
+    static_assert_imm3!(LANE);
+    transmute(vluti2_laneq_s16_(transmute(a), b, LANE))
+}
+
+/// Lookup table read with 2-bit indices, from the first four elements of `a`
+/// with the indices in segment `LANE` of `b`
+#[inline]
+#[target_feature(enable = "neon,lut")]
+#[cfg_attr(test, assert_instr(luti2, LANE = 7))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn vluti2_laneq_p16<const LANE: i32>(a: poly16x4_t, b: uint8x16_t) -> poly16x8_t {
+    static_assert_imm3!(LANE);
+    transmute(vluti2_laneq_s16_(transmute(a), b, LANE))
+}
+
+/// Lookup table read with 2-bit indices, from the first four elements of `a`
+/// with the indices in segment `LANE` of `b`
+#[inline]
+#[target_feature(enable = "neon,lut")]
+#[cfg_attr(test, assert_instr(luti2, LANE = 7))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn vluti2_laneq_f16<const LANE: i32>(a: float16x4_t, b: uint8x16_t) -> float16x8_t {
+    static_assert_imm3!(LANE);
+    transmute(vluti2_laneq_s16_(transmute(a), b, LANE))
+}
+
+/// Lookup table read with 2-bit indices, from the first four elements of `a`
+/// with the indices in segment `LANE` of `b`
+#[inline]
+#[target_feature(enable = "neon,lut")]
+#[cfg_attr(test, assert_instr(luti2, LANE = 7))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn vluti2_laneq_bf16<const LANE: i32>(a: bfloat16x4_t, b: uint8x16_t) -> bfloat16x8_t {
+    static_assert_imm3!(LANE);
+    transmute(vluti2_laneq_s16_(transmute(a), b, LANE))
+}
+
+/// Lookup table read with 2-bit indices, from the first four elements of `a`
+/// with the indices in segment `LANE` of `b`
+#[inline]
+#[target_feature(enable = "neon,lut")]
+#[cfg_attr(test, assert_instr(luti2, LANE = 3))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn vluti2q_lane_s16<const LANE: i32>(a: int16x8_t, b: uint8x8_t) -> int16x8_t {
+    static_assert_imm2!(LANE);
+    vluti2q_lane_s16_(a, b, LANE)
+}
+
+/// Lookup table read with 2-bit indices, from the first four elements of `a`
+/// with the indices in segment `LANE` of `b`
+#[inline]
+#[target_feature(enable = "neon,lut")]
+#[cfg_attr(test, assert_instr(luti2, LANE = 3))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn vluti2q_lane_u16<const LANE: i32>(a: uint16x8_t, b: uint8x8_t) -> uint16x8_t {
+    static_assert_imm2!(LANE);
+    transmute(vluti2q_lane_s16_(transmute(a), b, LANE))
+}
+
+/// Lookup table read with 2-bit indices, from the first four elements of `a`
+/// with the indices in segment `LANE` of `b`
+#[inline]
+#[target_feature(enable = "neon,lut")]
+#[cfg_attr(test, assert_instr(luti2, LANE = 3))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn vluti2q_lane_p16<const LANE: i32>(a: poly16x8_t, b: uint8x8_t) -> poly16x8_t {
+    static_assert_imm2!(LANE);
+    transmute(vluti2q_lane_s16_(transmute(a), b, LANE))
+}
+
+/// Lookup table read with 2-bit indices, from the first four elements of `a`
+/// with the indices in segment `LANE` of `b`
+#[inline]
+#[target_feature(enable = "neon,lut")]
+#[cfg_attr(test, assert_instr(luti2, LANE = 3))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn vluti2q_lane_f16<const LANE: i32>(a: float16x8_t, b: uint8x8_t) -> float16x8_t {
+    static_assert_imm2!(LANE);
+    transmute(vluti2q_lane_s16_(transmute(a), b, LANE))
+}
+
+/// Lookup table read with 2-bit indices, from the first four elements of `a`
+/// with the indices in segment `LANE` of `b`
+#[inline]
+#[target_feature(enable = "neon,lut")]
+#[cfg_attr(test, assert_instr(luti2, LANE = 3))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn vluti2q_lane_bf16<const LANE: i32>(a: bfloat16x8_t, b: uint8x8_t) -> bfloat16x8_t {
+    static_assert_imm2!(LANE);
+    transmute(vluti2q_lane_s16_(transmute(a), b, LANE))
+}
+
+/// Lookup table read with 2-bit indices, from the first four elements of `a`
+/// with the indices in segment `LANE` of `b`
+#[inline]
+#[target_feature(enable = "neon,lut")]
+#[cfg_attr(test, assert_instr(luti2, LANE = 7))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn vluti2q_laneq_s16<const LANE: i32>(a: int16x8_t, b: uint8x16_t) -> int16x8_t {
+    static_assert_imm3!(LANE);
+    vluti2q_laneq_s16_(a, b, LANE)
+}
+
+/// Lookup table read with 2-bit indices, from the first four elements of `a`
+/// with the indices in segment `LANE` of `b`
+#[inline]
+#[target_feature(enable = "neon,lut")]
+#[cfg_attr(test, assert_instr(luti2, LANE = 7))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn vluti2q_laneq_u16<const LANE: i32>(a: uint16x8_t, b: uint8x16_t) -> uint16x8_t {
+    static_assert_imm3!(LANE);
+    transmute(vluti2q_laneq_s16_(transmute(a), b, LANE))
+}
+
+/// Lookup table read with 2-bit indices, from the first four elements of `a`
+/// with the indices in segment `LANE` of `b`
+#[inline]
+#[target_feature(enable = "neon,lut")]
+#[cfg_attr(test, assert_instr(luti2, LANE = 7))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn vluti2q_laneq_p16<const LANE: i32>(a: poly16x8_t, b: uint8x16_t) -> poly16x8_t {
+    static_assert_imm3!(LANE);
+    transmute(vluti2q_laneq_s16_(transmute(a), b, LANE))
+}
+
+/// Lookup table read with 2-bit indices, from the first four elements of `a`
+/// with the indices in segment `LANE` of `b`
+#[inline]
+#[target_feature(enable = "neon,lut")]
+#[cfg_attr(test, assert_instr(luti2, LANE = 7))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn vluti2q_laneq_f16<const LANE: i32>(a: float16x8_t, b: uint8x16_t) -> float16x8_t {
+    static_assert_imm3!(LANE);
+    transmute(vluti2q_laneq_s16_(transmute(a), b, LANE))
+}
+
+/// Lookup table read with 2-bit indices, from the first four elements of `a`
+/// with the indices in segment `LANE` of `b`
+#[inline]
+#[target_feature(enable = "neon,lut")]
+#[cfg_attr(test, assert_instr(luti2, LANE = 7))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn vluti2q_laneq_bf16<const LANE: i32>(a: bfloat16x8_t, b: uint8x16_t) -> bfloat16x8_t {
+    static_assert_imm3!(LANE);
+    transmute(vluti2q_laneq_s16_(transmute(a), b, LANE))
+}
+
+/// Lookup table read with 4-bit indices, from the elements of `a` with the
+/// indices in segment `LANE` of `b`
+#[inline]
+#[target_feature(enable = "neon,lut")]
+#[cfg_attr(test, assert_instr(luti4, LANE = 0))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn vluti4q_lane_s8<const LANE: i32>(a: int8x16_t, b: uint8x8_t) -> int8x16_t {
+    static_assert!(LANE : i32 where LANE == 0);
+    vluti4q_lane_s8_(a, b, LANE)
+}
+
+/// Lookup table read with 4-bit indices, from the elements of `a` with the
+/// indices in segment `LANE` of `b`
+#[inline]
+#[target_feature(enable = "neon,lut")]
+#[cfg_attr(test, assert_instr(luti4, LANE = 0))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn vluti4q_lane_u8<const LANE: i32>(a: uint8x16_t, b: uint8x8_t) -> uint8x16_t {
+    static_assert!(LANE : i32 where LANE == 0);
+    transmute(vluti4q_lane_s8_(transmute(a), b, LANE))
+}
+
+/// Lookup table read with 4-bit indices, from the elements of `a` with the
+/// indices in segment `LANE` of `b`
+#[inline]
+#[target_feature(enable = "neon,lut")]
+#[cfg_attr(test, assert_instr(luti4, LANE = 0))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn vluti4q_lane_p8<const LANE: i32>(a: poly8x16_t, b: uint8x8_t) -> poly8x16_t {
+    static_assert!(LANE : i32 where LANE == 0);
+    transmute(vluti4q_lane_s8_(transmute(a), b, LANE))
+}
+
+/// Lookup table read with 4-bit indices, from the elements of `a` with the
+/// indices in segment `LANE` of `b`
+#[inline]
+#[target_feature(enable = "neon,lut")]
+#[cfg_attr(test, assert_instr(luti4, LANE = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn vluti4q_laneq_s8<const LANE: i32>(a: int8x16_t, b: uint8x16_t) -> int8x16_t {
+    static_assert_imm1!(LANE);
+    vluti4q_laneq_s8_(a, b, LANE)
+}
+
+/// Lookup table read with 4-bit indices, from the elements of `a` with the
+/// indices in segment `LANE` of `b`
+#[inline]
+#[target_feature(enable = "neon,lut")]
+#[cfg_attr(test, assert_instr(luti4, LANE = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn vluti4q_laneq_u8<const LANE: i32>(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
+    static_assert_imm1!(LANE);
+    transmute(vluti4q_laneq_s8_(transmute(a), b, LANE))
+}
+
+/// Lookup table read with 4-bit indices, from the elements of `a` with the
+/// indices in segment `LANE` of `b`
+#[inline]
+#[target_feature(enable = "neon,lut")]
+#[cfg_attr(test, assert_instr(luti4, LANE = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn vluti4q_laneq_p8<const LANE: i32>(a: poly8x16_t, b: uint8x16_t) -> poly8x16_t {
+    static_assert_imm1!(LANE);
+    transmute(vluti4q_laneq_s8_(transmute(a), b, LANE))
+}
+
+/// Lookup table read with 4-bit indices, from the elements of both vectors
+/// of `a` with the indices in segment `LANE` of `b`
+#[inline]
+#[target_feature(enable = "neon,lut")]
+#[cfg_attr(test, assert_instr(luti4, LANE = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn vluti4q_lane_s16_x2<const LANE: i32>(a: int16x8x2_t, b: uint8x8_t) -> int16x8_t {
+    static_assert_imm1!(LANE);
+    vluti4q_lane_s16_x2_(a.0, a.1, b, LANE)
+}
+
+/// Lookup table read with 4-bit indices, from the elements of both vectors
+/// of `a` with the indices in segment `LANE` of `b`
+#[inline]
+#[target_feature(enable = "neon,lut")]
+#[cfg_attr(test, assert_instr(luti4, LANE = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn vluti4q_lane_u16_x2<const LANE: i32>(a: uint16x8x2_t, b: uint8x8_t) -> uint16x8_t {
+    static_assert_imm1!(LANE);
+    transmute(vluti4q_lane_s16_x2_(
+        transmute(a.0),
+        transmute(a.1),
+        b,
+        LANE,
+    ))
+}
+
+/// Lookup table read with 4-bit indices, from the elements of both vectors
+/// of `a` with the indices in segment `LANE` of `b`
+#[inline]
+#[target_feature(enable = "neon,lut")]
+#[cfg_attr(test, assert_instr(luti4, LANE = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn vluti4q_lane_p16_x2<const LANE: i32>(a: poly16x8x2_t, b: uint8x8_t) -> poly16x8_t {
+    static_assert_imm1!(LANE);
+    transmute(vluti4q_lane_s16_x2_(
+        transmute(a.0),
+        transmute(a.1),
+        b,
+        LANE,
+    ))
+}
+
+/// Lookup table read with 4-bit indices, from the elements of both vectors
+/// of `a` with the indices in segment `LANE` of `b`
+#[inline]
+#[target_feature(enable = "neon,lut")]
+#[cfg_attr(test, assert_instr(luti4, LANE = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn vluti4q_lane_f16_x2<const LANE: i32>(a: float16x8x2_t, b: uint8x8_t) -> float16x8_t {
+    static_assert_imm1!(LANE);
+    transmute(vluti4q_lane_s16_x2_(
+        transmute(a.0),
+        transmute(a.1),
+        b,
+        LANE,
+    ))
+}
+
+/// Lookup table read with 4-bit indices, from the elements of both vectors
+/// of `a` with the indices in segment `LANE` of `b`
+#[inline]
+#[target_feature(enable = "neon,lut")]
+#[cfg_attr(test, assert_instr(luti4, LANE = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn vluti4q_lane_bf16_x2<const LANE: i32>(
+    a: bfloat16x8x2_t,
+    b: uint8x8_t,
+) -> bfloat16x8_t {
+    static_assert_imm1!(LANE);
+    transmute(vluti4q_lane_s16_x2_(
+        transmute(a.0),
+        transmute(a.1),
+        b,
+        LANE,
+    ))
+}
+
+/// Lookup table read with 4-bit indices, from the elements of both vectors
+/// of `a` with the indices in segment `LANE` of `b`
+#[inline]
+#[target_feature(enable = "neon,lut")]
+#[cfg_attr(test, assert_instr(luti4, LANE = 3))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn vluti4q_laneq_s16_x2<const LANE: i32>(a: int16x8x2_t, b: uint8x16_t) -> int16x8_t {
+    static_assert_imm2!(LANE);
+    vluti4q_laneq_s16_x2_(a.0, a.1, b, LANE)
+}
+
+/// Lookup table read with 4-bit indices, from the elements of both vectors
+/// of `a` with the indices in segment `LANE` of `b`
+#[inline]
+#[target_feature(enable = "neon,lut")]
+#[cfg_attr(test, assert_instr(luti4, LANE = 3))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn vluti4q_laneq_u16_x2<const LANE: i32>(a: uint16x8x2_t, b: uint8x16_t) -> uint16x8_t {
+    static_assert_imm2!(LANE);
+    transmute(vluti4q_laneq_s16_x2_(
+        transmute(a.0),
+        transmute(a.1),
+        b,
+        LANE,
+    ))
+}
+
+/// Lookup table read with 4-bit indices, from the elements of both vectors
+/// of `a` with the indices in segment `LANE` of `b`
+#[inline]
+#[target_feature(enable = "neon,lut")]
+#[cfg_attr(test, assert_instr(luti4, LANE = 3))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn vluti4q_laneq_p16_x2<const LANE: i32>(a: poly16x8x2_t, b: uint8x16_t) -> poly16x8_t {
+    static_assert_imm2!(LANE);
+    transmute(vluti4q_laneq_s16_x2_(
+        transmute(a.0),
+        transmute(a.1),
+        b,
+        LANE,
+    ))
+}
+
+/// Lookup table read with 4-bit indices, from the elements of both vectors
+/// of `a` with the indices in segment `LANE` of `b`
+#[inline]
+#[target_feature(enable = "neon,lut")]
+#[cfg_attr(test, assert_instr(luti4, LANE = 3))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn vluti4q_laneq_f16_x2<const LANE: i32>(
+    a: float16x8x2_t,
+    b: uint8x16_t,
+) -> float16x8_t {
+    static_assert_imm2!(LANE);
+    transmute(vluti4q_laneq_s16_x2_(
+        transmute(a.0),
+        transmute(a.1),
+        b,
+        LANE,
+    ))
+}
+
+/// Lookup table read with 4-bit indices, from the elements of both vectors
+/// of `a` with the indices in segment `LANE` of `b`
+#[inline]
+#[target_feature(enable = "neon,lut")]
+#[cfg_attr(test, assert_instr(luti4, LANE = 3))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn vluti4q_laneq_bf16_x2<const LANE: i32>(
+    a: bfloat16x8x2_t,
+    b: uint8x16_t,
+) -> bfloat16x8_t {
+    static_assert_imm2!(LANE);
+    transmute(vluti4q_laneq_s16_x2_(
+        transmute(a.0),
+        transmute(a.1),
+        b,
+        LANE,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::{aarch64::*, simd::*};
+    use std::{
+        mem::{self, transmute},
+        ptr,
+    };
+    use stdarch_test::simd_test;
+
+    const TABLE8: [u8; 16] = [
+        0x10, 0x81, 0x32, 0xa3, 0x54, 0xc5, 0x76, 0xe7, 0x98, 0x09, 0xba, 0x2b, 0xdc, 0x4d, 0xfe,
+        0x6f,
+    ];
+    const TABLE16: [u16; 16] = [
+        0x0000, 0x8001, 0x3c00, 0xbf80, 0x7c00, 0xfc01, 0x1234, 0xfedc, 0x4000, 0xc000, 0x3f80,
+        0x0400, 0x5555, 0xaaaa, 0x7fff, 0xffff,
+    ];
+
+    // The packed indices of one segment, and the indices they expand to. The
+    // other segments are filled with `FILL_*`, whose indices are all 1 for
+    // `LUTI2` and 7 for `LUTI4`.
+    const LUTI2_8: ([u8; 4], [usize; 16]) = (
+        [0xe4, 0x1b, 0x00, 0xff],
+        [0, 1, 2, 3, 3, 2, 1, 0, 0, 0, 0, 0, 3, 3, 3, 3],
+    );
+    const LUTI2_16: ([u8; 2], [usize; 8]) = ([0xe4, 0x1b], [0, 1, 2, 3, 3, 2, 1, 0]);
+    const LUTI4_8: ([u8; 8], [usize; 16]) = (
+        [0x5a, 0x0f, 0xc3, 0x96, 0x21, 0x87, 0xed, 0x4b],
+        [10, 5, 15, 0, 3, 12, 6, 9, 1, 2, 7, 8, 13, 14, 11, 4],
+    );
+    const LUTI4_16: ([u8; 4], [usize; 8]) = ([0x5a, 0x0f, 0xc3, 0x96], [10, 5, 15, 0, 3, 12, 6, 9]);
+    const FILL_LUTI2: u8 = 0x55;
+    const FILL_LUTI4: u8 = 0x77;
+
+    /// Reads a vector, or a tuple of vectors, from the start of `a`.
+    unsafe fn read<T, E>(a: &[E]) -> T {
+        assert!(mem::size_of_val(a) >= mem::size_of::<T>());
+        ptr::read_unaligned(a.as_ptr() as *const T)
+    }
+
+    /// Returns indices with `segment` in segment `lane`.
+    unsafe fn indices<T>(segment: &[u8], lane: usize, fill: u8) -> T {
+        let mut b = [fill; 16];
+        b[lane * segment.len()..][..segment.len()].copy_from_slice(segment);
+        read(&b)
+    }
+
+    fn expected8(indices: &[usize; 16]) -> u8x16 {
+        let mut r = [0; 16];
+        for (r, &i) in r.iter_mut().zip(indices) {
+            *r = TABLE8[i];
+        }
+        unsafe { transmute(r) }
+    }
+
+    fn expected16(indices: &[usize; 8]) -> u16x8 {
+        let mut r = [0; 8];
+        for (r, &i) in r.iter_mut().zip(indices) {
+            *r = TABLE16[i];
+        }
+        unsafe { transmute(r) }
+    }
+
+    #[simd_test(enable = "neon,lut")]
+    unsafe fn test_vluti2_lane_s8() {
+        let b = indices(&LUTI2_8.0, 0, FILL_LUTI2);
+        let r: u8x16 = transmute(vluti2_lane_s8::<0>(read(&TABLE8), b));
+        assert_eq!(r, expected8(&LUTI2_8.1));
+    }
+
+    #[simd_test(enable = "neon,lut")]
+    unsafe fn test_vluti2_lane_u8() {
+        let b = indices(&LUTI2_8.0, 1, FILL_LUTI2);
+        let r: u8x16 = transmute(vluti2_lane_u8::<1>(read(&TABLE8), b));
+        assert_eq!(r, expected8(&LUTI2_8.1));
+    }
+
+    #[simd_test(enable = "neon,lut")]
+    unsafe fn test_vluti2_lane_p8() {
+        let b = indices(&LUTI2_8.0, 1, FILL_LUTI2);
+        let r: u8x16 = transmute(vluti2_lane_p8::<1>(read(&TABLE8), b));
+        assert_eq!(r, expected8(&LUTI2_8.1));
+    }
+
+    #[simd_test(enable = "neon,lut")]
+    unsafe fn test_vluti2_laneq_s8() {
+        let b = indices(&LUTI2_8.0, 0, FILL_LUTI2);
+        let r: u8x16 = transmute(vluti2_laneq_s8::<0>(read(&TABLE8), b));
+        assert_eq!(r, expected8(&LUTI2_8.1));
+    }
+
+    #[simd_test(enable = "neon,lut")]
+    unsafe fn test_vluti2_laneq_u8() {
+        let b = indices(&LUTI2_8.0, 3, FILL_LUTI2);
+        let r: u8x16 = transmute(vluti2_laneq_u8::<3>(read(&TABLE8), b));
+        assert_eq!(r, expected8(&LUTI2_8.1));
+    }
+
+    #[simd_test(enable = "neon,lut")]
+    unsafe fn test_vluti2_laneq_p8() {
+        let b = indices(&LUTI2_8.0, 3, FILL_LUTI2);
+        let r: u8x16 = transmute(vluti2_laneq_p8::<3>(read(&TABLE8), b));
+        assert_eq!(r, expected8(&LUTI2_8.1));
+    }
+
+    #[simd_test(enable = "neon,lut")]
+    unsafe fn test_vluti2q_lane_s8() {
+        let b = indices(&LUTI2_8.0, 0, FILL_LUTI2);
+        let r: u8x16 = transmute(vluti2q_lane_s8::<0>(read(&TABLE8), b));
+        assert_eq!(r, expected8(&LUTI2_8.1));
+    }
+
+    #[simd_test(enable = "neon,lut")]
+    unsafe fn test_vluti2q_lane_u8() {
+        let b = indices(&LUTI2_8.0, 1, FILL_LUTI2);
+        let r: u8x16 = transmute(vluti2q_lane_u8::<1>(read(&TABLE8), b));
+        assert_eq!(r, expected8(&LUTI2_8.1));
+    }
+
+    #[simd_test(enable = "neon,lut")]
+    unsafe fn test_vluti2q_lane_p8() {
+        let b = indices(&LUTI2_8.0, 1, FILL_LUTI2);
+        let r: u8x16 = transmute(vluti2q_lane_p8::<1>(read(&TABLE8), b));
+        assert_eq!(r, expected8(&LUTI2_8.1));
+    }
+
+    #[simd_test(enable = "neon,lut")]
+    unsafe fn test_vluti2q_laneq_s8() {
+        let b = indices(&LUTI2_8.0, 0, FILL_LUTI2);
+        let r: u8x16 = transmute(vluti2q_laneq_s8::<0>(read(&TABLE8), b));
+        assert_eq!(r, expected8(&LUTI2_8.1));
+    }
+
+    #[simd_test(enable = "neon,lut")]
+    unsafe fn test_vluti2q_laneq_u8() {
+        let b = indices(&LUTI2_8.0, 3, FILL_LUTI2);
+        let r: u8x16 = transmute(vluti2q_laneq_u8::<3>(read(&TABLE8), b));
+        assert_eq!(r, expected8(&LUTI2_8.1));
+    }
+
+    #[simd_test(enable = "neon,lut")]
+    unsafe fn test_vluti2q_laneq_p8() {
+        let b = indices(&LUTI2_8.0, 3, FILL_LUTI2);
+        let r: u8x16 = transmute(vluti2q_laneq_p8::<3>(read(&TABLE8), b));
+        assert_eq!(r, expected8(&LUTI2_8.1));
+    }
+
+    #[simd_test(enable = "neon,lut")]
+    unsafe fn test_vluti2_lane_s16() {
+        let b = indices(&LUTI2_16.0, 0, FILL_LUTI2);
+        let r: u16x8 = transmute(vluti2_lane_s16::<0>(read(&TABLE16), b));
+        assert_eq!(r, expected16(&LUTI2_16.1));
+    }
+
+    #[simd_test(enable = "neon,lut")]
+    unsafe fn test_vluti2_lane_u16() {
+        let b = indices(&LUTI2_16.0, 3, FILL_LUTI2);
+        let r: u16x8 = transmute(vluti2_lane_u16::<3>(read(&TABLE16), b));
+        assert_eq!(r, expected16(&LUTI2_16.1));
+    }
+
+    #[simd_test(enable = "neon,lut")]
+    unsafe fn test_vluti2_lane_p16() {
+        let b = indices(&LUTI2_16.0, 3, FILL_LUTI2);
+        let r: u16x8 = transmute(vluti2_lane_p16::<3>(read(&TABLE16), b));
+        assert_eq!(r, expected16(&LUTI2_16.1));
+    }
+
+    #[simd_test(enable = "neon,lut")]
+    unsafe fn test_vluti2_lane_f16() {
+        let b = indices(&LUTI2_16.0, 3, FILL_LUTI2);
+        let r: u16x8 = transmute(vluti2_lane_f16::<3>(read(&TABLE16), b));
+        assert_eq!(r, expected16(&LUTI2_16.1));
+    }
+
+    #[simd_test(enable = "neon,lut")]
+    unsafe fn test_vluti2_lane_bf16() {
+        let b = indices(&LUTI2_16.0, 3, FILL_LUTI2);
+        let r: u16x8 = transmute(vluti2_lane_bf16::<3>(read(&TABLE16), b));
+        assert_eq!(r, expected16(&LUTI2_16.1));
+    }
+
+    #[simd_test(enable = "neon,lut")]
+    unsafe fn test_vluti2_laneq_s16() {
+        let b = indices(&LUTI2_16.0, 0, FILL_LUTI2);
+        let r: u16x8 = transmute(vluti2_laneq_s16::<0>(read(&TABLE16), b));
+        assert_eq!(r, expected16(&LUTI2_16.1));
+    }
+
+    #[simd_test(enable = "neon,lut")]
+    unsafe fn test_vluti2_laneq_u16() {
+        let b = indices(&LUTI2_16.0, 7, FILL_LUTI2);
+        let r: u16x8 = transmute(vluti2_laneq_u16::<7>(read(&TABLE16), b));
+        assert_eq!(r, expected16(&LUTI2_16.1));
+    }
+
+    #[simd_test(enable = "neon,lut")]
+    unsafe fn test_vluti2_laneq_p16() {
+        let b = indices(&LUTI2_16.0, 7, FILL_LUTI2);
+        let r: u16x8 = transmute(vluti2_laneq_p16::<7>(read(&TABLE16), b));
+        assert_eq!(r, expected16(&LUTI2_16.1));
+    }
+
+    #[simd_test(enable = "neon,lut")]
+    unsafe fn test_vluti2_laneq_f16() {
+        let b = indices(&LUTI2_16.0, 7, FILL_LUTI2);
+        let r: u16x8 = transmute(vluti2_laneq_f16::<7>(read(&TABLE16), b));
+        assert_eq!(r, expected16(&LUTI2_16.1));
+    }
+
+    #[simd_test(enable = "neon,lut")]
+    unsafe fn test_vluti2_laneq_bf16() {
+        let b = indices(&LUTI2_16.0, 7, FILL_LUTI2);
+        let r: u16x8 = transmute(vluti2_laneq_bf16::<7>(read(&TABLE16), b));
+        assert_eq!(r, expected16(&LUTI2_16.1));
+    }
+
+    #[simd_test(enable = "neon,lut")]
+    unsafe fn test_vluti2q_lane_s16() {
+        let b = indices(&LUTI2_16.0, 0, FILL_LUTI2);
+        let r: u16x8 = transmute(vluti2q_lane_s16::<0>(read(&TABLE16), b));
+        assert_eq!(r, expected16(&LUTI2_16.1));
+    }
+
+    #[simd_test(enable = "neon,lut")]
+    unsafe fn test_vluti2q_lane_u16() {
+        let b = indices(&LUTI2_16.0, 3, FILL_LUTI2);
+        let r: u16x8 = transmute(vluti2q_lane_u16::<3>(read(&TABLE16), b));
+        assert_eq!(r, expected16(&LUTI2_16.1));
+    }
+
+    #[simd_test(enable = "neon,lut")]
+    unsafe fn test_vluti2q_lane_p16() {
+        let b = indices(&LUTI2_16.0, 3, FILL_LUTI2);
+        let r: u16x8 = transmute(vluti2q_lane_p16::<3>(read(&TABLE16), b));
+        assert_eq!(r, expected16(&LUTI2_16.1));
+    }
+
+    #[simd_test(enable = "neon,lut")]
+    unsafe fn test_vluti2q_lane_f16() {
+        let b = indices(&LUTI2_16.0, 3, FILL_LUTI2);
+        let r: u16x8 = transmute(vluti2q_lane_f16::<3>(read(&TABLE16), b));
+        assert_eq!(r, expected16(&LUTI2_16.1));
+    }
+
+    #[simd_test(enable = "neon,lut")]
+    unsafe fn test_vluti2q_lane_bf16() {
+        let b = indices(&LUTI2_16.0, 3, FILL_LUTI2);
+        let r: u16x8 = transmute(vluti2q_lane_bf16::<3>(read(&TABLE16), b));
+        assert_eq!(r, expected16(&LUTI2_16.1));
+    }
+
+    #[simd_test(enable = "neon,lut")]
+    unsafe fn test_vluti2q_laneq_s16() {
+        let b = indices(&LUTI2_16.0, 0, FILL_LUTI2);
+        let r: u16x8 = transmute(vluti2q_laneq_s16::<0>(read(&TABLE16), b));
+        assert_eq!(r, expected16(&LUTI2_16.1));
+    }
+
+    #[simd_test(enable = "neon,lut")]
+    unsafe fn test_vluti2q_laneq_u16() {
+        let b = indices(&LUTI2_16.0, 7, FILL_LUTI2);
+        let r: u16x8 = transmute(vluti2q_laneq_u16::<7>(read(&TABLE16), b));
+        assert_eq!(r, expected16(&LUTI2_16.1));
+    }
+
+    #[simd_test(enable = "neon,lut")]
+    unsafe fn test_vluti2q_laneq_p16() {
+        let b = indices(&LUTI2_16.0, 7, FILL_LUTI2);
+        let r: u16x8 = transmute(vluti2q_laneq_p16::<7>(read(&TABLE16), b));
+        assert_eq!(r, expected16(&LUTI2_16.1));
+    }
+
+    #[simd_test(enable = "neon,lut")]
+    unsafe fn test_vluti2q_laneq_f16() {
+        let b = indices(&LUTI2_16.0, 7, FILL_LUTI2);
+        let r: u16x8 = transmute(vluti2q_laneq_f16::<7>(read(&TABLE16), b));
+        assert_eq!(r, expected16(&LUTI2_16.1));
+    }
+
+    #[simd_test(enable = "neon,lut")]
+    unsafe fn test_vluti2q_laneq_bf16() {
+        let b = indices(&LUTI2_16.0, 7, FILL_LUTI2);
+        let r: u16x8 = transmute(vluti2q_laneq_bf16::<7>(read(&TABLE16), b));
+        assert_eq!(r, expected16(&LUTI2_16.1));
+    }
+
+    #[simd_test(enable = "neon,lut")]
+    unsafe fn test_vluti4q_lane_s8() {
+        let b = indices(&LUTI4_8.0, 0, FILL_LUTI4);
+        let r: u8x16 = transmute(vluti4q_lane_s8::<0>(read(&TABLE8), b));
+        assert_eq!(r, expected8(&LUTI4_8.1));
+    }
+
+    #[simd_test(enable = "neon,lut")]
+    unsafe fn test_vluti4q_lane_u8() {
+        let b = indices(&LUTI4_8.0, 0, FILL_LUTI4);
+        let r: u8x16 = transmute(vluti4q_lane_u8::<0>(read(&TABLE8), b));
+        assert_eq!(r, expected8(&LUTI4_8.1));
+    }
+
+    #[simd_test(enable = "neon,lut")]
+    unsafe fn test_vluti4q_lane_p8() {
+        let b = indices(&LUTI4_8.0, 0, FILL_LUTI4);
+        let r: u8x16 = transmute(vluti4q_lane_p8::<0>(read(&TABLE8), b));
+        assert_eq!(r, expected8(&LUTI4_8.1));
+    }
+
+    #[simd_test(enable = "neon,lut")]
+    unsafe fn test_vluti4q_laneq_s8() {
+        let b = indices(&LUTI4_8.0, 0, FILL_LUTI4);
+        let r: u8x16 = transmute(vluti4q_laneq_s8::<0>(read(&TABLE8), b));
+        assert_eq!(r, expected8(&LUTI4_8.1));
+    }
+
+    #[simd_test(enable = "neon,lut")]
+    unsafe fn test_vluti4q_laneq_u8() {
+        let b = indices(&LUTI4_8.0, 1, FILL_LUTI4);
+        let r: u8x16 = transmute(vluti4q_laneq_u8::<1>(read(&TABLE8), b));
+        assert_eq!(r, expected8(&LUTI4_8.1));
+    }
+
+    #[simd_test(enable = "neon,lut")]
+    unsafe fn test_vluti4q_laneq_p8() {
+        let b = indices(&LUTI4_8.0, 1, FILL_LUTI4);
+        let r: u8x16 = transmute(vluti4q_laneq_p8::<1>(read(&TABLE8), b));
+        assert_eq!(r, expected8(&LUTI4_8.1));
+    }
+
+    #[simd_test(enable = "neon,lut")]
+    unsafe fn test_vluti4q_lane_s16_x2() {
+        let b = indices(&LUTI4_16.0, 0, FILL_LUTI4);
+        let r: u16x8 = transmute(vluti4q_lane_s16_x2::<0>(read(&TABLE16), b));
+        assert_eq!(r, expected16(&LUTI4_16.1));
+    }
+
+    #[simd_test(enable = "neon,lut")]
+    unsafe fn test_vluti4q_lane_u16_x2() {
+        let b = indices(&LUTI4_16.0, 1, FILL_LUTI4);
+        let r: u16x8 = transmute(vluti4q_lane_u16_x2::<1>(read(&TABLE16), b));
+        assert_eq!(r, expected16(&LUTI4_16.1));
+    }
+
+    #[simd_test(enable = "neon,lut")]
+    unsafe fn test_vluti4q_lane_p16_x2() {
+        let b = indices(&LUTI4_16.0, 1, FILL_LUTI4);
+        let r: u16x8 = transmute(vluti4q_lane_p16_x2::<1>(read(&TABLE16), b));
+        assert_eq!(r, expected16(&LUTI4_16.1));
+    }
+
+    #[simd_test(enable = "neon,lut")]
+    unsafe fn test_vluti4q_lane_f16_x2() {
+        let b = indices(&LUTI4_16.0, 1, FILL_LUTI4);
+        let r: u16x8 = transmute(vluti4q_lane_f16_x2::<1>(read(&TABLE16), b));
+        assert_eq!(r, expected16(&LUTI4_16.1));
+    }
+
+    #[simd_test(enable = "neon,lut")]
+    unsafe fn test_vluti4q_lane_bf16_x2() {
+        let b = indices(&LUTI4_16.0, 1, FILL_LUTI4);
+        let r: u16x8 = transmute(vluti4q_lane_bf16_x2::<1>(read(&TABLE16), b));
+        assert_eq!(r, expected16(&LUTI4_16.1));
+    }
+
+    #[simd_test(enable = "neon,lut")]
+    unsafe fn test_vluti4q_laneq_s16_x2() {
+        let b = indices(&LUTI4_16.0, 0, FILL_LUTI4);
+        let r: u16x8 = transmute(vluti4q_laneq_s16_x2::<0>(read(&TABLE16), b));
+        assert_eq!(r, expected16(&LUTI4_16.1));
+    }
+
+    #[simd_test(enable = "neon,lut")]
+    unsafe fn test_vluti4q_laneq_u16_x2() {
+        let b = indices(&LUTI4_16.0, 3, FILL_LUTI4);
+        let r: u16x8 = transmute(vluti4q_laneq_u16_x2::<3>(read(&TABLE16), b));
+        assert_eq!(r, expected16(&LUTI4_16.1));
+    }
+
+    #[simd_test(enable = "neon,lut")]
+    unsafe fn test_vluti4q_laneq_p16_x2() {
+        let b = indices(&LUTI4_16.0, 3, FILL_LUTI4);
+        let r: u16x8 = transmute(vluti4q_laneq_p16_x2::<3>(read(&TABLE16), b));
+        assert_eq!(r, expected16(&LUTI4_16.1));
+    }
+
+    #[simd_test(enable = "neon,lut")]
+    unsafe fn test_vluti4q_laneq_f16_x2() {
+        let b = indices(&LUTI4_16.0, 3, FILL_LUTI4);
+        let r: u16x8 = transmute(vluti4q_laneq_f16_x2::<3>(read(&TABLE16), b));
+        assert_eq!(r, expected16(&LUTI4_16.1));
+    }
+
+    #[simd_test(enable = "neon,lut")]
+    unsafe fn test_vluti4q_laneq_bf16_x2() {
+        let b = indices(&LUTI4_16.0, 3, FILL_LUTI4);
+        let r: u16x8 = transmute(vluti4q_laneq_bf16_x2::<3>(read(&TABLE16), b));
+        assert_eq!(r, expected16(&LUTI4_16.1));
+    }
+}
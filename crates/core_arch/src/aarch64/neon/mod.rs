@@ -7,6 +7,21 @@ mod generated;
 #[rustfmt::skip]
 pub use self::generated::*;
 
+mod bf16;
+pub use self::bf16::*;
+
+mod faminmax;
+pub use self::faminmax::*;
+
+mod fp8;
+pub use self::fp8::*;
+
+mod fp16;
+pub use self::fp16::*;
+
+mod lut;
+pub use self::lut::*;
+
 // FIXME: replace neon with asimd
 
 use crate::{
@@ -22,46 +37,133 @@ types! {
     pub struct float64x1_t(f64); // FIXME: check this!
     /// ARM-specific 128-bit wide vector of two packed `f64`.
     pub struct float64x2_t(f64, f64);
+    /// ARM-specific 64-bit wide vector of four packed `bfloat16`, stored as
+    /// their `u16` bit patterns.
+    pub struct bfloat16x4_t(u16, u16, u16, u16);
+    /// ARM-specific 128-bit wide vector of eight packed `bfloat16`, stored as
+    /// their `u16` bit patterns.
+    pub struct bfloat16x8_t(u16, u16, u16, u16, u16, u16, u16, u16);
+    /// ARM-specific 64-bit wide vector of four packed `f16`, stored as their
+    /// `u16` bit patterns.
+    pub struct float16x4_t(u16, u16, u16, u16);
+    /// ARM-specific 128-bit wide vector of eight packed `f16`, stored as their
+    /// `u16` bit patterns.
+    pub struct float16x8_t(u16, u16, u16, u16, u16, u16, u16, u16);
+    /// ARM-specific 64-bit wide vector of eight packed 8-bit floating-point
+    /// values, stored as their `u8` bit patterns. Their format is selected
+    /// by FPMR.
+    pub struct mfloat8x8_t(u8, u8, u8, u8, u8, u8, u8, u8);
+    /// ARM-specific 128-bit wide vector of sixteen packed 8-bit floating-point
+    /// values, stored as their `u8` bit patterns. Their format is selected
+    /// by FPMR.
+    pub struct mfloat8x16_t(
+        u8, u8, u8, u8, u8, u8, u8, u8,
+        u8, u8, u8, u8, u8, u8, u8, u8,
+    );
 }
 
-/// ARM-specific type containing two `int8x16_t` vectors.
+impl ReverseLanes for float64x1_t {
+    #[inline]
+    unsafe fn reverse_lanes(self) -> Self {
+        self
+    }
+}
+
+impl ReverseLanes for float64x2_t {
+    #[inline]
+    unsafe fn reverse_lanes(self) -> Self {
+        simd_shuffle2(self, self, [1, 0])
+    }
+}
+
+/// ARM-specific type containing two `bfloat16x4_t` vectors.
 #[derive(Copy, Clone)]
-pub struct int8x16x2_t(pub int8x16_t, pub int8x16_t);
-/// ARM-specific type containing three `int8x16_t` vectors.
+pub struct bfloat16x4x2_t(pub bfloat16x4_t, pub bfloat16x4_t);
+/// ARM-specific type containing three `bfloat16x4_t` vectors.
 #[derive(Copy, Clone)]
-pub struct int8x16x3_t(pub int8x16_t, pub int8x16_t, pub int8x16_t);
-/// ARM-specific type containing four `int8x16_t` vectors.
+pub struct bfloat16x4x3_t(pub bfloat16x4_t, pub bfloat16x4_t, pub bfloat16x4_t);
+/// ARM-specific type containing four `bfloat16x4_t` vectors.
 #[derive(Copy, Clone)]
-pub struct int8x16x4_t(pub int8x16_t, pub int8x16_t, pub int8x16_t, pub int8x16_t);
+pub struct bfloat16x4x4_t(
+    pub bfloat16x4_t,
+    pub bfloat16x4_t,
+    pub bfloat16x4_t,
+    pub bfloat16x4_t,
+);
 
-/// ARM-specific type containing two `uint8x16_t` vectors.
+/// ARM-specific type containing two `bfloat16x8_t` vectors.
 #[derive(Copy, Clone)]
-pub struct uint8x16x2_t(pub uint8x16_t, pub uint8x16_t);
-/// ARM-specific type containing three `uint8x16_t` vectors.
+pub struct bfloat16x8x2_t(pub bfloat16x8_t, pub bfloat16x8_t);
+/// ARM-specific type containing three `bfloat16x8_t` vectors.
 #[derive(Copy, Clone)]
-pub struct uint8x16x3_t(pub uint8x16_t, pub uint8x16_t, pub uint8x16_t);
-/// ARM-specific type containing four `uint8x16_t` vectors.
+pub struct bfloat16x8x3_t(pub bfloat16x8_t, pub bfloat16x8_t, pub bfloat16x8_t);
+/// ARM-specific type containing four `bfloat16x8_t` vectors.
 #[derive(Copy, Clone)]
-pub struct uint8x16x4_t(
-    pub uint8x16_t,
-    pub uint8x16_t,
-    pub uint8x16_t,
-    pub uint8x16_t,
+pub struct bfloat16x8x4_t(
+    pub bfloat16x8_t,
+    pub bfloat16x8_t,
+    pub bfloat16x8_t,
+    pub bfloat16x8_t,
 );
 
-/// ARM-specific type containing two `poly8x16_t` vectors.
+/// ARM-specific type containing two `float16x4_t` vectors.
 #[derive(Copy, Clone)]
-pub struct poly8x16x2_t(pub poly8x16_t, pub poly8x16_t);
-/// ARM-specific type containing three `poly8x16_t` vectors.
+pub struct float16x4x2_t(pub float16x4_t, pub float16x4_t);
+/// ARM-specific type containing three `float16x4_t` vectors.
 #[derive(Copy, Clone)]
-pub struct poly8x16x3_t(pub poly8x16_t, pub poly8x16_t, pub poly8x16_t);
-/// ARM-specific type containing four `poly8x16_t` vectors.
+pub struct float16x4x3_t(pub float16x4_t, pub float16x4_t, pub float16x4_t);
+/// ARM-specific type containing four `float16x4_t` vectors.
 #[derive(Copy, Clone)]
-pub struct poly8x16x4_t(
-    pub poly8x16_t,
-    pub poly8x16_t,
-    pub poly8x16_t,
-    pub poly8x16_t,
+pub struct float16x4x4_t(
+    pub float16x4_t,
+    pub float16x4_t,
+    pub float16x4_t,
+    pub float16x4_t,
+);
+
+/// ARM-specific type containing two `float16x8_t` vectors.
+#[derive(Copy, Clone)]
+pub struct float16x8x2_t(pub float16x8_t, pub float16x8_t);
+/// ARM-specific type containing three `float16x8_t` vectors.
+#[derive(Copy, Clone)]
+pub struct float16x8x3_t(pub float16x8_t, pub float16x8_t, pub float16x8_t);
+/// ARM-specific type containing four `float16x8_t` vectors.
+#[derive(Copy, Clone)]
+pub struct float16x8x4_t(
+    pub float16x8_t,
+    pub float16x8_t,
+    pub float16x8_t,
+    pub float16x8_t,
+);
+
+/// ARM-specific type containing two `float64x1_t` vectors.
+#[derive(Copy, Clone)]
+pub struct float64x1x2_t(pub float64x1_t, pub float64x1_t);
+/// ARM-specific type containing three `float64x1_t` vectors.
+#[derive(Copy, Clone)]
+pub struct float64x1x3_t(pub float64x1_t, pub float64x1_t, pub float64x1_t);
+/// ARM-specific type containing four `float64x1_t` vectors.
+#[derive(Copy, Clone)]
+pub struct float64x1x4_t(
+    pub float64x1_t,
+    pub float64x1_t,
+    pub float64x1_t,
+    pub float64x1_t,
+);
+
+/// ARM-specific type containing two `float64x2_t` vectors.
+#[derive(Copy, Clone)]
+pub struct float64x2x2_t(pub float64x2_t, pub float64x2_t);
+/// ARM-specific type containing three `float64x2_t` vectors.
+#[derive(Copy, Clone)]
+pub struct float64x2x3_t(pub float64x2_t, pub float64x2_t, pub float64x2_t);
+/// ARM-specific type containing four `float64x2_t` vectors.
+#[derive(Copy, Clone)]
+pub struct float64x2x4_t(
+    pub float64x2_t,
+    pub float64x2_t,
+    pub float64x2_t,
+    pub float64x2_t,
 );
 
 #[allow(improper_ctypes)]
@@ -1766,15 +1868,13 @@ pub unsafe fn vget_low_p64(a: poly64x2_t) -> poly64x1_t {
     transmute(u64x1::new(simd_extract(a, 0)))
 }
 
-/* FIXME: 16-bit float
 /// Vector combine
 #[inline]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(mov))]
-pub unsafe fn vcombine_f16 ( low: float16x4_t,  high: float16x4_t) -> float16x8_t {
+pub unsafe fn vcombine_f16(low: float16x4_t, high: float16x4_t) -> float16x8_t {
     simd_shuffle8(low, high, [0, 1, 2, 3, 4, 5, 6, 7])
 }
-*/
 
 /// Vector combine
 #[inline]
@@ -1814,7 +1914,6 @@ pub unsafe fn vcombine_f64(low: float64x1_t, high: float64x1_t) -> float64x2_t {
 
 /// Table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbl))]
 pub unsafe fn vtbl1_s8(a: int8x8_t, b: int8x8_t) -> int8x8_t {
@@ -1823,7 +1922,6 @@ pub unsafe fn vtbl1_s8(a: int8x8_t, b: int8x8_t) -> int8x8_t {
 
 /// Table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbl))]
 pub unsafe fn vtbl1_u8(a: uint8x8_t, b: uint8x8_t) -> uint8x8_t {
@@ -1832,7 +1930,6 @@ pub unsafe fn vtbl1_u8(a: uint8x8_t, b: uint8x8_t) -> uint8x8_t {
 
 /// Table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbl))]
 pub unsafe fn vtbl1_p8(a: poly8x8_t, b: uint8x8_t) -> poly8x8_t {
@@ -1841,7 +1938,6 @@ pub unsafe fn vtbl1_p8(a: poly8x8_t, b: uint8x8_t) -> poly8x8_t {
 
 /// Table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbl))]
 pub unsafe fn vtbl2_s8(a: int8x8x2_t, b: int8x8_t) -> int8x8_t {
@@ -1850,7 +1946,6 @@ pub unsafe fn vtbl2_s8(a: int8x8x2_t, b: int8x8_t) -> int8x8_t {
 
 /// Table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbl))]
 pub unsafe fn vtbl2_u8(a: uint8x8x2_t, b: uint8x8_t) -> uint8x8_t {
@@ -1859,7 +1954,6 @@ pub unsafe fn vtbl2_u8(a: uint8x8x2_t, b: uint8x8_t) -> uint8x8_t {
 
 /// Table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbl))]
 pub unsafe fn vtbl2_p8(a: poly8x8x2_t, b: uint8x8_t) -> poly8x8_t {
@@ -1868,7 +1962,6 @@ pub unsafe fn vtbl2_p8(a: poly8x8x2_t, b: uint8x8_t) -> poly8x8_t {
 
 /// Table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbl))]
 pub unsafe fn vtbl3_s8(a: int8x8x3_t, b: int8x8_t) -> int8x8_t {
@@ -1880,7 +1973,6 @@ pub unsafe fn vtbl3_s8(a: int8x8x3_t, b: int8x8_t) -> int8x8_t {
 
 /// Table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbl))]
 pub unsafe fn vtbl3_u8(a: uint8x8x3_t, b: uint8x8_t) -> uint8x8_t {
@@ -1892,7 +1984,6 @@ pub unsafe fn vtbl3_u8(a: uint8x8x3_t, b: uint8x8_t) -> uint8x8_t {
 
 /// Table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbl))]
 pub unsafe fn vtbl3_p8(a: poly8x8x3_t, b: uint8x8_t) -> poly8x8_t {
@@ -1904,7 +1995,6 @@ pub unsafe fn vtbl3_p8(a: poly8x8x3_t, b: uint8x8_t) -> poly8x8_t {
 
 /// Table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbl))]
 pub unsafe fn vtbl4_s8(a: int8x8x4_t, b: int8x8_t) -> int8x8_t {
@@ -1916,7 +2006,6 @@ pub unsafe fn vtbl4_s8(a: int8x8x4_t, b: int8x8_t) -> int8x8_t {
 
 /// Table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbl))]
 pub unsafe fn vtbl4_u8(a: uint8x8x4_t, b: uint8x8_t) -> uint8x8_t {
@@ -1928,7 +2017,6 @@ pub unsafe fn vtbl4_u8(a: uint8x8x4_t, b: uint8x8_t) -> uint8x8_t {
 
 /// Table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbl))]
 pub unsafe fn vtbl4_p8(a: poly8x8x4_t, b: uint8x8_t) -> poly8x8_t {
@@ -1940,7 +2028,6 @@ pub unsafe fn vtbl4_p8(a: poly8x8x4_t, b: uint8x8_t) -> poly8x8_t {
 
 /// Extended table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbx))]
 pub unsafe fn vtbx1_s8(a: int8x8_t, b: int8x8_t, c: int8x8_t) -> int8x8_t {
@@ -1951,7 +2038,6 @@ pub unsafe fn vtbx1_s8(a: int8x8_t, b: int8x8_t, c: int8x8_t) -> int8x8_t {
 
 /// Extended table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbx))]
 pub unsafe fn vtbx1_u8(a: uint8x8_t, b: uint8x8_t, c: uint8x8_t) -> uint8x8_t {
@@ -1962,7 +2048,6 @@ pub unsafe fn vtbx1_u8(a: uint8x8_t, b: uint8x8_t, c: uint8x8_t) -> uint8x8_t {
 
 /// Extended table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbx))]
 pub unsafe fn vtbx1_p8(a: poly8x8_t, b: poly8x8_t, c: uint8x8_t) -> poly8x8_t {
@@ -1973,7 +2058,6 @@ pub unsafe fn vtbx1_p8(a: poly8x8_t, b: poly8x8_t, c: uint8x8_t) -> poly8x8_t {
 
 /// Extended table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbx))]
 pub unsafe fn vtbx2_s8(a: int8x8_t, b: int8x8x2_t, c: int8x8_t) -> int8x8_t {
@@ -1982,7 +2066,6 @@ pub unsafe fn vtbx2_s8(a: int8x8_t, b: int8x8x2_t, c: int8x8_t) -> int8x8_t {
 
 /// Extended table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbx))]
 pub unsafe fn vtbx2_u8(a: uint8x8_t, b: uint8x8x2_t, c: uint8x8_t) -> uint8x8_t {
@@ -1991,7 +2074,6 @@ pub unsafe fn vtbx2_u8(a: uint8x8_t, b: uint8x8x2_t, c: uint8x8_t) -> uint8x8_t
 
 /// Extended table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbx))]
 pub unsafe fn vtbx2_p8(a: poly8x8_t, b: poly8x8x2_t, c: uint8x8_t) -> poly8x8_t {
@@ -2000,7 +2082,6 @@ pub unsafe fn vtbx2_p8(a: poly8x8_t, b: poly8x8x2_t, c: uint8x8_t) -> poly8x8_t
 
 /// Extended table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbx))]
 pub unsafe fn vtbx3_s8(a: int8x8_t, b: int8x8x3_t, c: int8x8_t) -> int8x8_t {
@@ -2015,7 +2096,6 @@ pub unsafe fn vtbx3_s8(a: int8x8_t, b: int8x8x3_t, c: int8x8_t) -> int8x8_t {
 
 /// Extended table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbx))]
 pub unsafe fn vtbx3_u8(a: uint8x8_t, b: uint8x8x3_t, c: uint8x8_t) -> uint8x8_t {
@@ -2030,7 +2110,6 @@ pub unsafe fn vtbx3_u8(a: uint8x8_t, b: uint8x8x3_t, c: uint8x8_t) -> uint8x8_t
 
 /// Extended table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbx))]
 pub unsafe fn vtbx3_p8(a: poly8x8_t, b: poly8x8x3_t, c: uint8x8_t) -> poly8x8_t {
@@ -2045,7 +2124,6 @@ pub unsafe fn vtbx3_p8(a: poly8x8_t, b: poly8x8x3_t, c: uint8x8_t) -> poly8x8_t
 
 /// Extended table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbx))]
 pub unsafe fn vtbx4_s8(a: int8x8_t, b: int8x8x4_t, c: int8x8_t) -> int8x8_t {
@@ -2058,7 +2136,6 @@ pub unsafe fn vtbx4_s8(a: int8x8_t, b: int8x8x4_t, c: int8x8_t) -> int8x8_t {
 
 /// Extended table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbx))]
 pub unsafe fn vtbx4_u8(a: uint8x8_t, b: uint8x8x4_t, c: uint8x8_t) -> uint8x8_t {
@@ -2071,7 +2148,6 @@ pub unsafe fn vtbx4_u8(a: uint8x8_t, b: uint8x8x4_t, c: uint8x8_t) -> uint8x8_t
 
 /// Extended table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbx))]
 pub unsafe fn vtbx4_p8(a: poly8x8_t, b: poly8x8x4_t, c: uint8x8_t) -> poly8x8_t {
@@ -2084,7 +2160,6 @@ pub unsafe fn vtbx4_p8(a: poly8x8_t, b: poly8x8x4_t, c: uint8x8_t) -> poly8x8_t
 
 /// Table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbl))]
 pub unsafe fn vqtbl1_s8(t: int8x16_t, idx: uint8x8_t) -> int8x8_t {
@@ -2092,7 +2167,6 @@ pub unsafe fn vqtbl1_s8(t: int8x16_t, idx: uint8x8_t) -> int8x8_t {
 }
 /// Table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbl))]
 pub unsafe fn vqtbl1q_s8(t: int8x16_t, idx: uint8x16_t) -> int8x16_t {
@@ -2100,7 +2174,6 @@ pub unsafe fn vqtbl1q_s8(t: int8x16_t, idx: uint8x16_t) -> int8x16_t {
 }
 /// Table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbl))]
 pub unsafe fn vqtbl1_u8(t: uint8x16_t, idx: uint8x8_t) -> uint8x8_t {
@@ -2108,7 +2181,6 @@ pub unsafe fn vqtbl1_u8(t: uint8x16_t, idx: uint8x8_t) -> uint8x8_t {
 }
 /// Table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbl))]
 pub unsafe fn vqtbl1q_u8(t: uint8x16_t, idx: uint8x16_t) -> uint8x16_t {
@@ -2116,7 +2188,6 @@ pub unsafe fn vqtbl1q_u8(t: uint8x16_t, idx: uint8x16_t) -> uint8x16_t {
 }
 /// Table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbl))]
 pub unsafe fn vqtbl1_p8(t: poly8x16_t, idx: uint8x8_t) -> poly8x8_t {
@@ -2124,7 +2195,6 @@ pub unsafe fn vqtbl1_p8(t: poly8x16_t, idx: uint8x8_t) -> poly8x8_t {
 }
 /// Table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbl))]
 pub unsafe fn vqtbl1q_p8(t: poly8x16_t, idx: uint8x16_t) -> poly8x16_t {
@@ -2132,7 +2202,6 @@ pub unsafe fn vqtbl1q_p8(t: poly8x16_t, idx: uint8x16_t) -> poly8x16_t {
 }
 /// Extended table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbx))]
 pub unsafe fn vqtbx1_s8(a: int8x8_t, t: int8x16_t, idx: uint8x8_t) -> int8x8_t {
@@ -2140,7 +2209,6 @@ pub unsafe fn vqtbx1_s8(a: int8x8_t, t: int8x16_t, idx: uint8x8_t) -> int8x8_t {
 }
 /// Extended table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbx))]
 pub unsafe fn vqtbx1q_s8(a: int8x16_t, t: int8x16_t, idx: uint8x16_t) -> int8x16_t {
@@ -2148,7 +2216,6 @@ pub unsafe fn vqtbx1q_s8(a: int8x16_t, t: int8x16_t, idx: uint8x16_t) -> int8x16
 }
 /// Extended table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbx))]
 pub unsafe fn vqtbx1_u8(a: uint8x8_t, t: uint8x16_t, idx: uint8x8_t) -> uint8x8_t {
@@ -2156,7 +2223,6 @@ pub unsafe fn vqtbx1_u8(a: uint8x8_t, t: uint8x16_t, idx: uint8x8_t) -> uint8x8_
 }
 /// Extended table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbx))]
 pub unsafe fn vqtbx1q_u8(a: uint8x16_t, t: uint8x16_t, idx: uint8x16_t) -> uint8x16_t {
@@ -2164,7 +2230,6 @@ pub unsafe fn vqtbx1q_u8(a: uint8x16_t, t: uint8x16_t, idx: uint8x16_t) -> uint8
 }
 /// Extended table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbx))]
 pub unsafe fn vqtbx1_p8(a: poly8x8_t, t: poly8x16_t, idx: uint8x8_t) -> poly8x8_t {
@@ -2172,7 +2237,6 @@ pub unsafe fn vqtbx1_p8(a: poly8x8_t, t: poly8x16_t, idx: uint8x8_t) -> poly8x8_
 }
 /// Extended table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbx))]
 pub unsafe fn vqtbx1q_p8(a: poly8x16_t, t: poly8x16_t, idx: uint8x16_t) -> poly8x16_t {
@@ -2181,7 +2245,6 @@ pub unsafe fn vqtbx1q_p8(a: poly8x16_t, t: poly8x16_t, idx: uint8x16_t) -> poly8
 
 /// Table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbl))]
 pub unsafe fn vqtbl2_s8(t: int8x16x2_t, idx: uint8x8_t) -> int8x8_t {
@@ -2189,7 +2252,6 @@ pub unsafe fn vqtbl2_s8(t: int8x16x2_t, idx: uint8x8_t) -> int8x8_t {
 }
 /// Table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbl))]
 pub unsafe fn vqtbl2q_s8(t: int8x16x2_t, idx: uint8x16_t) -> int8x16_t {
@@ -2197,7 +2259,6 @@ pub unsafe fn vqtbl2q_s8(t: int8x16x2_t, idx: uint8x16_t) -> int8x16_t {
 }
 /// Table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbl))]
 pub unsafe fn vqtbl2_u8(t: uint8x16x2_t, idx: uint8x8_t) -> uint8x8_t {
@@ -2205,7 +2266,6 @@ pub unsafe fn vqtbl2_u8(t: uint8x16x2_t, idx: uint8x8_t) -> uint8x8_t {
 }
 /// Table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbl))]
 pub unsafe fn vqtbl2q_u8(t: uint8x16x2_t, idx: uint8x16_t) -> uint8x16_t {
@@ -2213,7 +2273,6 @@ pub unsafe fn vqtbl2q_u8(t: uint8x16x2_t, idx: uint8x16_t) -> uint8x16_t {
 }
 /// Table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbl))]
 pub unsafe fn vqtbl2_p8(t: poly8x16x2_t, idx: uint8x8_t) -> poly8x8_t {
@@ -2221,7 +2280,6 @@ pub unsafe fn vqtbl2_p8(t: poly8x16x2_t, idx: uint8x8_t) -> poly8x8_t {
 }
 /// Table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbl))]
 pub unsafe fn vqtbl2q_p8(t: poly8x16x2_t, idx: uint8x16_t) -> poly8x16_t {
@@ -2229,7 +2287,6 @@ pub unsafe fn vqtbl2q_p8(t: poly8x16x2_t, idx: uint8x16_t) -> poly8x16_t {
 }
 /// Extended table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbx))]
 pub unsafe fn vqtbx2_s8(a: int8x8_t, t: int8x16x2_t, idx: uint8x8_t) -> int8x8_t {
@@ -2237,7 +2294,6 @@ pub unsafe fn vqtbx2_s8(a: int8x8_t, t: int8x16x2_t, idx: uint8x8_t) -> int8x8_t
 }
 /// Extended table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbx))]
 pub unsafe fn vqtbx2q_s8(a: int8x16_t, t: int8x16x2_t, idx: uint8x16_t) -> int8x16_t {
@@ -2245,7 +2301,6 @@ pub unsafe fn vqtbx2q_s8(a: int8x16_t, t: int8x16x2_t, idx: uint8x16_t) -> int8x
 }
 /// Extended table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbx))]
 pub unsafe fn vqtbx2_u8(a: uint8x8_t, t: uint8x16x2_t, idx: uint8x8_t) -> uint8x8_t {
@@ -2258,7 +2313,6 @@ pub unsafe fn vqtbx2_u8(a: uint8x8_t, t: uint8x16x2_t, idx: uint8x8_t) -> uint8x
 }
 /// Extended table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbx))]
 pub unsafe fn vqtbx2q_u8(a: uint8x16_t, t: uint8x16x2_t, idx: uint8x16_t) -> uint8x16_t {
@@ -2271,7 +2325,6 @@ pub unsafe fn vqtbx2q_u8(a: uint8x16_t, t: uint8x16x2_t, idx: uint8x16_t) -> uin
 }
 /// Extended table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbx))]
 pub unsafe fn vqtbx2_p8(a: poly8x8_t, t: poly8x16x2_t, idx: uint8x8_t) -> poly8x8_t {
@@ -2284,7 +2337,6 @@ pub unsafe fn vqtbx2_p8(a: poly8x8_t, t: poly8x16x2_t, idx: uint8x8_t) -> poly8x
 }
 /// Extended table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbx))]
 pub unsafe fn vqtbx2q_p8(a: poly8x16_t, t: poly8x16x2_t, idx: uint8x16_t) -> poly8x16_t {
@@ -2298,7 +2350,6 @@ pub unsafe fn vqtbx2q_p8(a: poly8x16_t, t: poly8x16x2_t, idx: uint8x16_t) -> pol
 
 /// Table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbl))]
 pub unsafe fn vqtbl3_s8(t: int8x16x3_t, idx: uint8x8_t) -> int8x8_t {
@@ -2306,7 +2357,6 @@ pub unsafe fn vqtbl3_s8(t: int8x16x3_t, idx: uint8x8_t) -> int8x8_t {
 }
 /// Table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbl))]
 pub unsafe fn vqtbl3q_s8(t: int8x16x3_t, idx: uint8x16_t) -> int8x16_t {
@@ -2314,7 +2364,6 @@ pub unsafe fn vqtbl3q_s8(t: int8x16x3_t, idx: uint8x16_t) -> int8x16_t {
 }
 /// Table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbl))]
 pub unsafe fn vqtbl3_u8(t: uint8x16x3_t, idx: uint8x8_t) -> uint8x8_t {
@@ -2327,7 +2376,6 @@ pub unsafe fn vqtbl3_u8(t: uint8x16x3_t, idx: uint8x8_t) -> uint8x8_t {
 }
 /// Table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbl))]
 pub unsafe fn vqtbl3q_u8(t: uint8x16x3_t, idx: uint8x16_t) -> uint8x16_t {
@@ -2340,7 +2388,6 @@ pub unsafe fn vqtbl3q_u8(t: uint8x16x3_t, idx: uint8x16_t) -> uint8x16_t {
 }
 /// Table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbl))]
 pub unsafe fn vqtbl3_p8(t: poly8x16x3_t, idx: uint8x8_t) -> poly8x8_t {
@@ -2353,7 +2400,6 @@ pub unsafe fn vqtbl3_p8(t: poly8x16x3_t, idx: uint8x8_t) -> poly8x8_t {
 }
 /// Table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbl))]
 pub unsafe fn vqtbl3q_p8(t: poly8x16x3_t, idx: uint8x16_t) -> poly8x16_t {
@@ -2366,7 +2412,6 @@ pub unsafe fn vqtbl3q_p8(t: poly8x16x3_t, idx: uint8x16_t) -> poly8x16_t {
 }
 /// Extended table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbx))]
 pub unsafe fn vqtbx3_s8(a: int8x8_t, t: int8x16x3_t, idx: uint8x8_t) -> int8x8_t {
@@ -2374,7 +2419,6 @@ pub unsafe fn vqtbx3_s8(a: int8x8_t, t: int8x16x3_t, idx: uint8x8_t) -> int8x8_t
 }
 /// Extended table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbx))]
 pub unsafe fn vqtbx3q_s8(a: int8x16_t, t: int8x16x3_t, idx: uint8x16_t) -> int8x16_t {
@@ -2382,7 +2426,6 @@ pub unsafe fn vqtbx3q_s8(a: int8x16_t, t: int8x16x3_t, idx: uint8x16_t) -> int8x
 }
 /// Extended table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbx))]
 pub unsafe fn vqtbx3_u8(a: uint8x8_t, t: uint8x16x3_t, idx: uint8x8_t) -> uint8x8_t {
@@ -2396,7 +2439,6 @@ pub unsafe fn vqtbx3_u8(a: uint8x8_t, t: uint8x16x3_t, idx: uint8x8_t) -> uint8x
 }
 /// Extended table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbx))]
 pub unsafe fn vqtbx3q_u8(a: uint8x16_t, t: uint8x16x3_t, idx: uint8x16_t) -> uint8x16_t {
@@ -2410,7 +2452,6 @@ pub unsafe fn vqtbx3q_u8(a: uint8x16_t, t: uint8x16x3_t, idx: uint8x16_t) -> uin
 }
 /// Extended table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbx))]
 pub unsafe fn vqtbx3_p8(a: poly8x8_t, t: poly8x16x3_t, idx: uint8x8_t) -> poly8x8_t {
@@ -2424,7 +2465,6 @@ pub unsafe fn vqtbx3_p8(a: poly8x8_t, t: poly8x16x3_t, idx: uint8x8_t) -> poly8x
 }
 /// Extended table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbx))]
 pub unsafe fn vqtbx3q_p8(a: poly8x16_t, t: poly8x16x3_t, idx: uint8x16_t) -> poly8x16_t {
@@ -2439,7 +2479,6 @@ pub unsafe fn vqtbx3q_p8(a: poly8x16_t, t: poly8x16x3_t, idx: uint8x16_t) -> pol
 
 /// Table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbl))]
 pub unsafe fn vqtbl4_s8(t: int8x16x4_t, idx: uint8x8_t) -> int8x8_t {
@@ -2447,7 +2486,6 @@ pub unsafe fn vqtbl4_s8(t: int8x16x4_t, idx: uint8x8_t) -> int8x8_t {
 }
 /// Table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbl))]
 pub unsafe fn vqtbl4q_s8(t: int8x16x4_t, idx: uint8x16_t) -> int8x16_t {
@@ -2455,7 +2493,6 @@ pub unsafe fn vqtbl4q_s8(t: int8x16x4_t, idx: uint8x16_t) -> int8x16_t {
 }
 /// Table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbl))]
 pub unsafe fn vqtbl4_u8(t: uint8x16x4_t, idx: uint8x8_t) -> uint8x8_t {
@@ -2469,7 +2506,6 @@ pub unsafe fn vqtbl4_u8(t: uint8x16x4_t, idx: uint8x8_t) -> uint8x8_t {
 }
 /// Table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbl))]
 pub unsafe fn vqtbl4q_u8(t: uint8x16x4_t, idx: uint8x16_t) -> uint8x16_t {
@@ -2483,7 +2519,6 @@ pub unsafe fn vqtbl4q_u8(t: uint8x16x4_t, idx: uint8x16_t) -> uint8x16_t {
 }
 /// Table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbl))]
 pub unsafe fn vqtbl4_p8(t: poly8x16x4_t, idx: uint8x8_t) -> poly8x8_t {
@@ -2497,7 +2532,6 @@ pub unsafe fn vqtbl4_p8(t: poly8x16x4_t, idx: uint8x8_t) -> poly8x8_t {
 }
 /// Table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbl))]
 pub unsafe fn vqtbl4q_p8(t: poly8x16x4_t, idx: uint8x16_t) -> poly8x16_t {
@@ -2511,7 +2545,6 @@ pub unsafe fn vqtbl4q_p8(t: poly8x16x4_t, idx: uint8x16_t) -> poly8x16_t {
 }
 /// Extended table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbx))]
 pub unsafe fn vqtbx4_s8(a: int8x8_t, t: int8x16x4_t, idx: uint8x8_t) -> int8x8_t {
@@ -2519,7 +2552,6 @@ pub unsafe fn vqtbx4_s8(a: int8x8_t, t: int8x16x4_t, idx: uint8x8_t) -> int8x8_t
 }
 /// Extended table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbx))]
 pub unsafe fn vqtbx4q_s8(a: int8x16_t, t: int8x16x4_t, idx: uint8x16_t) -> int8x16_t {
@@ -2527,7 +2559,6 @@ pub unsafe fn vqtbx4q_s8(a: int8x16_t, t: int8x16x4_t, idx: uint8x16_t) -> int8x
 }
 /// Extended table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbx))]
 pub unsafe fn vqtbx4_u8(a: uint8x8_t, t: uint8x16x4_t, idx: uint8x8_t) -> uint8x8_t {
@@ -2542,7 +2573,6 @@ pub unsafe fn vqtbx4_u8(a: uint8x8_t, t: uint8x16x4_t, idx: uint8x8_t) -> uint8x
 }
 /// Extended table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbx))]
 pub unsafe fn vqtbx4q_u8(a: uint8x16_t, t: uint8x16x4_t, idx: uint8x16_t) -> uint8x16_t {
@@ -2557,7 +2587,6 @@ pub unsafe fn vqtbx4q_u8(a: uint8x16_t, t: uint8x16x4_t, idx: uint8x16_t) -> uin
 }
 /// Extended table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbx))]
 pub unsafe fn vqtbx4_p8(a: poly8x8_t, t: poly8x16x4_t, idx: uint8x8_t) -> poly8x8_t {
@@ -2572,7 +2601,6 @@ pub unsafe fn vqtbx4_p8(a: poly8x8_t, t: poly8x16x4_t, idx: uint8x8_t) -> poly8x
 }
 /// Extended table look-up
 #[inline]
-#[cfg(target_endian = "little")]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(tbx))]
 pub unsafe fn vqtbx4q_p8(a: poly8x16_t, t: poly8x16x4_t, idx: uint8x16_t) -> poly8x16_t {
@@ -3754,9 +3782,8 @@ mod tests {
     test_vcombine!(test_vcombine_s16 => vcombine_s16([3_i16, -4, 5, -6], [13_i16, -14, 15, -16]));
     test_vcombine!(test_vcombine_u16 => vcombine_u16([3_u16, 4, 5, 6], [13_u16, 14, 15, 16]));
     test_vcombine!(test_vcombine_p16 => vcombine_p16([3_u16, 4, 5, 6], [13_u16, 14, 15, 16]));
-    // FIXME: 16-bit floats
-    // test_vcombine!(test_vcombine_f16 => vcombine_f16([3_f16, 4., 5., 6.],
-    // [13_f16, 14., 15., 16.]));
+    // The `f16` lanes are bit patterns, these are 3.0 to 6.0 and 13.0 to 16.0.
+    test_vcombine!(test_vcombine_f16 => vcombine_f16([0x4200_u16, 0x4400, 0x4500, 0x4600], [0x4a80_u16, 0x4b00, 0x4b80, 0x4c00]));
 
     test_vcombine!(test_vcombine_s32 => vcombine_s32([3_i32, -4], [13_i32, -14]));
     test_vcombine!(test_vcombine_u32 => vcombine_u32([3_u32, 4], [13_u32, 14]));
@@ -4577,10 +4604,475 @@ mod tests {
         let e = 136_u16;
         assert_eq!(r, e);
     }
+
+    #[simd_test(enable = "neon,fcma")]
+    unsafe fn test_vcmla_complex_multiply() {
+        // Pairs of rotations add up to a full complex multiply-accumulate:
+        // 0 and 90 compute `acc + b * c`, 180 and 270 `acc - b * c`, and
+        // 0 and 270 `acc + conj(b) * c`.
+        fn mul(b: (f64, f64), c: (f64, f64)) -> (f64, f64) {
+            (b.0 * c.0 - b.1 * c.1, b.0 * c.1 + b.1 * c.0)
+        }
+        let acc = (0.5, -1.5);
+        let b = (2.0, 3.0);
+        let c = (-4.0, 0.25);
+        let (vacc, vb, vc) = (
+            transmute(f64x2::new(acc.0, acc.1)),
+            transmute(f64x2::new(b.0, b.1)),
+            transmute(f64x2::new(c.0, c.1)),
+        );
+
+        let p = mul(b, c);
+        let r: f64x2 = transmute(vcmlaq_rot90_f64(vcmlaq_f64(vacc, vb, vc), vb, vc));
+        assert_eq!(r, f64x2::new(acc.0 + p.0, acc.1 + p.1));
+        let r: f64x2 = transmute(vcmlaq_rot270_f64(vcmlaq_rot180_f64(vacc, vb, vc), vb, vc));
+        assert_eq!(r, f64x2::new(acc.0 - p.0, acc.1 - p.1));
+        let p = mul((b.0, -b.1), c);
+        let r: f64x2 = transmute(vcmlaq_rot270_f64(vcmlaq_f64(vacc, vb, vc), vb, vc));
+        assert_eq!(r, f64x2::new(acc.0 + p.0, acc.1 + p.1));
+
+        // The same with two complex numbers per vector, `c` broadcast from a
+        // lane.
+        let a = f32x4::new(1.0, 2.0, -3.0, 4.0);
+        let b = f32x4::new(0.5, -1.0, 2.0, 1.5);
+        let c = f32x4::new(7.0, 7.0, 3.0, -2.0);
+        let r: f32x4 = transmute(vcmlaq_rot90_laneq_f32::<1>(
+            vcmlaq_laneq_f32::<1>(transmute(a), transmute(b), transmute(c)),
+            transmute(b),
+            transmute(c),
+        ));
+        let p0 = mul((0.5, -1.0), (3.0, -2.0));
+        let p1 = mul((2.0, 1.5), (3.0, -2.0));
+        let e = f32x4::new(
+            1.0 + p0.0 as f32,
+            2.0 + p0.1 as f32,
+            -3.0 + p1.0 as f32,
+            4.0 + p1.1 as f32,
+        );
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,i8mm")]
+    unsafe fn test_vmmlaq_matrix_multiply() {
+        // `a` holds a 2x8 matrix and `b` the transpose of an 8x2 matrix, both
+        // row by row, and the 2x2 product is added to `acc`.
+        let a: [i8; 16] = [
+            -128, 127, 1, -1, 64, -64, 3, 5, 7, -11, 13, -17, 19, -23, 29, 127,
+        ];
+        let b: [i8; 16] = [
+            127, -128, 2, 4, -8, 16, -32, 64, 100, 50, -25, 12, -6, 3, -1, -128,
+        ];
+        let acc: [i32; 4] = [1, -2, 3, -4];
+        let mut e = [0i32; 4];
+        for (i, e) in e.iter_mut().enumerate() {
+            let row = &a[(i / 2) * 8..][..8];
+            let col = &b[(i % 2) * 8..][..8];
+            let dot: i32 = row
+                .iter()
+                .zip(col)
+                .map(|(x, y)| *x as i32 * *y as i32)
+                .sum();
+            *e = acc[i] + dot;
+        }
+        let r: [i32; 4] = transmute(vmmlaq_s32(transmute(acc), transmute(a), transmute(b)));
+        assert_eq!(r, e);
+
+        // The same matrices with only `a` or both reinterpreted as unsigned.
+        let mut e = [0i32; 4];
+        let mut eu = [0u32; 4];
+        for (i, (e, eu)) in e.iter_mut().zip(&mut eu).enumerate() {
+            let row = &a[(i / 2) * 8..][..8];
+            let col = &b[(i % 2) * 8..][..8];
+            let us: i32 = row
+                .iter()
+                .zip(col)
+                .map(|(x, y)| *x as u8 as i32 * *y as i32)
+                .sum();
+            let uu: u32 = row
+                .iter()
+                .zip(col)
+                .map(|(x, y)| *x as u8 as u32 * *y as u8 as u32)
+                .sum();
+            *e = acc[i] + us;
+            *eu = (acc[i] as u32).wrapping_add(uu);
+        }
+        let r: [i32; 4] = transmute(vusmmlaq_s32(transmute(acc), transmute(a), transmute(b)));
+        assert_eq!(r, e);
+        let r: [u32; 4] = transmute(vmmlaq_u32(transmute(acc), transmute(a), transmute(b)));
+        assert_eq!(r, eu);
+    }
+
+    #[simd_test(enable = "neon,sha3")]
+    unsafe fn test_sha3_keccak_f1600() {
+        // Two Keccak-f[1600] states side by side, the lane at `x + 5 * y` of
+        // each is element `x + 5 * y` of the vectors: theta uses `veor3q` and
+        // `vrax1q`, rho and pi `vxarq`, and chi `vbcaxq`.
+        const RC: [u64; 24] = [
+            0x0000000000000001,
+            0x0000000000008082,
+            0x800000000000808a,
+            0x8000000080008000,
+            0x000000000000808b,
+            0x0000000080000001,
+            0x8000000080008081,
+            0x8000000000008009,
+            0x000000000000008a,
+            0x0000000000000088,
+            0x0000000080008009,
+            0x000000008000000a,
+            0x000000008000808b,
+            0x800000000000008b,
+            0x8000000000008089,
+            0x8000000000008003,
+            0x8000000000008002,
+            0x8000000000000080,
+            0x000000000000800a,
+            0x800000008000000a,
+            0x8000000080008081,
+            0x8000000000008080,
+            0x0000000080000001,
+            0x8000000080008008,
+        ];
+        const RHO: [u32; 25] = [
+            0, 1, 62, 28, 27, 36, 44, 6, 55, 20, 3, 10, 43, 25, 39, 41, 45, 15, 21, 8, 18, 2, 61,
+            56, 14,
+        ];
+
+        fn round(a: &mut [u64; 25], rc: u64) {
+            let mut c = [0; 5];
+            for x in 0..5 {
+                c[x] = a[x] ^ a[x + 5] ^ a[x + 10] ^ a[x + 15] ^ a[x + 20];
+            }
+            let mut b = [0; 25];
+            for i in 0..25 {
+                let (x, y) = (i % 5, i / 5);
+                let d = c[(x + 4) % 5] ^ c[(x + 1) % 5].rotate_left(1);
+                b[y + 5 * ((2 * x + 3 * y) % 5)] = (a[i] ^ d).rotate_left(RHO[i]);
+            }
+            for i in 0..25 {
+                let (x, y) = (i % 5, i / 5);
+                a[i] = b[i] ^ (!b[(x + 1) % 5 + 5 * y] & b[(x + 2) % 5 + 5 * y]);
+            }
+            a[0] ^= rc;
+        }
+
+        unsafe fn vround(a: &mut [uint64x2_t; 25], rc: u64) {
+            let mut c = [vdupq_n_u64(0); 5];
+            for x in 0..5 {
+                c[x] = veor3q_u64(a[x], a[x + 5], veor3q_u64(a[x + 10], a[x + 15], a[x + 20]));
+            }
+            let mut d = [vdupq_n_u64(0); 5];
+            for x in 0..5 {
+                d[x] = vrax1q_u64(c[(x + 4) % 5], c[(x + 1) % 5]);
+            }
+            // `vxarq_u64` rotates right, and its rotation has to be a constant.
+            let mut b = [vdupq_n_u64(0); 25];
+            macro_rules! rho_pi {
+                ($($i:literal: $r:literal),*) => {$(
+                    let (x, y) = ($i % 5, $i / 5);
+                    b[y + 5 * ((2 * x + 3 * y) % 5)] =
+                        vxarq_u64::<{ (64 - $r) % 64 }>(a[$i], d[x]);
+                )*};
+            }
+            rho_pi!(
+                0: 0, 1: 1, 2: 62, 3: 28, 4: 27, 5: 36, 6: 44, 7: 6, 8: 55, 9: 20, 10: 3, 11: 10,
+                12: 43, 13: 25, 14: 39, 15: 41, 16: 45, 17: 15, 18: 21, 19: 8, 20: 18, 21: 2,
+                22: 61, 23: 56, 24: 14
+            );
+            for i in 0..25 {
+                let (x, y) = (i % 5, i / 5);
+                a[i] = vbcaxq_u64(b[i], b[(x + 2) % 5 + 5 * y], b[(x + 1) % 5 + 5 * y]);
+            }
+            a[0] = veorq_u64(a[0], vdupq_n_u64(rc));
+        }
+
+        // The all-zero state and a pseudo-random one.
+        let mut s0 = [0_u64; 25];
+        let mut s1 = [0_u64; 25];
+        let mut x = 0x0123456789abcdef_u64;
+        for v in s1.iter_mut() {
+            x = x
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            *v = x;
+        }
+        let mut v = [vdupq_n_u64(0); 25];
+        for i in 0..25 {
+            v[i] = transmute(u64x2::new(s0[i], s1[i]));
+        }
+
+        for rc in RC.iter() {
+            round(&mut s0, *rc);
+            round(&mut s1, *rc);
+            vround(&mut v, *rc);
+            for i in 0..25 {
+                let r: u64x2 = transmute(v[i]);
+                assert_eq!(r, u64x2::new(s0[i], s1[i]));
+            }
+        }
+        // The first lane of Keccak-f[1600] applied to the all-zero state.
+        assert_eq!(s0[0], 0xf1258f7940e1dde7);
+    }
+
+    #[simd_test(enable = "neon,sm4")]
+    unsafe fn test_sm4_encrypt_block() {
+        #[rustfmt::skip]
+        const SBOX: [u8; 256] = [
+            0xd6, 0x90, 0xe9, 0xfe, 0xcc, 0xe1, 0x3d, 0xb7, 0x16, 0xb6, 0x14, 0xc2, 0x28, 0xfb, 0x2c, 0x05,
+            0x2b, 0x67, 0x9a, 0x76, 0x2a, 0xbe, 0x04, 0xc3, 0xaa, 0x44, 0x13, 0x26, 0x49, 0x86, 0x06, 0x99,
+            0x9c, 0x42, 0x50, 0xf4, 0x91, 0xef, 0x98, 0x7a, 0x33, 0x54, 0x0b, 0x43, 0xed, 0xcf, 0xac, 0x62,
+            0xe4, 0xb3, 0x1c, 0xa9, 0xc9, 0x08, 0xe8, 0x95, 0x80, 0xdf, 0x94, 0xfa, 0x75, 0x8f, 0x3f, 0xa6,
+            0x47, 0x07, 0xa7, 0xfc, 0xf3, 0x73, 0x17, 0xba, 0x83, 0x59, 0x3c, 0x19, 0xe6, 0x85, 0x4f, 0xa8,
+            0x68, 0x6b, 0x81, 0xb2, 0x71, 0x64, 0xda, 0x8b, 0xf8, 0xeb, 0x0f, 0x4b, 0x70, 0x56, 0x9d, 0x35,
+            0x1e, 0x24, 0x0e, 0x5e, 0x63, 0x58, 0xd1, 0xa2, 0x25, 0x22, 0x7c, 0x3b, 0x01, 0x21, 0x78, 0x87,
+            0xd4, 0x00, 0x46, 0x57, 0x9f, 0xd3, 0x27, 0x52, 0x4c, 0x36, 0x02, 0xe7, 0xa0, 0xc4, 0xc8, 0x9e,
+            0xea, 0xbf, 0x8a, 0xd2, 0x40, 0xc7, 0x38, 0xb5, 0xa3, 0xf7, 0xf2, 0xce, 0xf9, 0x61, 0x15, 0xa1,
+            0xe0, 0xae, 0x5d, 0xa4, 0x9b, 0x34, 0x1a, 0x55, 0xad, 0x93, 0x32, 0x30, 0xf5, 0x8c, 0xb1, 0xe3,
+            0x1d, 0xf6, 0xe2, 0x2e, 0x82, 0x66, 0xca, 0x60, 0xc0, 0x29, 0x23, 0xab, 0x0d, 0x53, 0x4e, 0x6f,
+            0xd5, 0xdb, 0x37, 0x45, 0xde, 0xfd, 0x8e, 0x2f, 0x03, 0xff, 0x6a, 0x72, 0x6d, 0x6c, 0x5b, 0x51,
+            0x8d, 0x1b, 0xaf, 0x92, 0xbb, 0xdd, 0xbc, 0x7f, 0x11, 0xd9, 0x5c, 0x41, 0x1f, 0x10, 0x5a, 0xd8,
+            0x0a, 0xc1, 0x31, 0x88, 0xa5, 0xcd, 0x7b, 0xbd, 0x2d, 0x74, 0xd0, 0x12, 0xb8, 0xe5, 0xb4, 0xb0,
+            0x89, 0x69, 0x97, 0x4a, 0x0c, 0x96, 0x77, 0x7e, 0x65, 0xb9, 0xf1, 0x09, 0xc5, 0x6e, 0xc6, 0x84,
+            0x18, 0xf0, 0x7d, 0xec, 0x3a, 0xdc, 0x4d, 0x20, 0x79, 0xee, 0x5f, 0x3e, 0xd7, 0xcb, 0x39, 0x48,
+        ];
+        const FK: [u32; 4] = [0xa3b1bac6, 0x56aa3350, 0x677d9197, 0xb27022dc];
+        fn ck() -> [u32; 32] {
+            let mut ck = [0; 32];
+            for (i, ck) in ck.iter_mut().enumerate() {
+                let b = |j: usize| ((4 * i + j) * 7) as u8;
+                *ck = u32::from_be_bytes([b(0), b(1), b(2), b(3)]);
+            }
+            ck
+        }
+
+        fn encrypt(key: [u32; 4], block: [u32; 4]) -> [u32; 4] {
+            let tau = |x: u32| {
+                let b = x.to_be_bytes();
+                let s = |i: usize| SBOX[b[i] as usize];
+                u32::from_be_bytes([s(0), s(1), s(2), s(3)])
+            };
+            let ck = ck();
+            let mut k = [0; 36];
+            let mut x = [0; 36];
+            for i in 0..4 {
+                k[i] = key[i] ^ FK[i];
+                x[i] = block[i];
+            }
+            for i in 0..32 {
+                let t = tau(k[i + 1] ^ k[i + 2] ^ k[i + 3] ^ ck[i]);
+                k[i + 4] = k[i] ^ t ^ t.rotate_left(13) ^ t.rotate_left(23);
+                let t = tau(x[i + 1] ^ x[i + 2] ^ x[i + 3] ^ k[i + 4]);
+                x[i + 4] = x[i] ^ t ^ t.rotate_left(2) ^ t.rotate_left(10);
+                x[i + 4] ^= t.rotate_left(18) ^ t.rotate_left(24);
+            }
+            [x[35], x[34], x[33], x[32]]
+        }
+
+        // `vsm4ekeyq_u32` derives the next four round keys from the previous
+        // four, and `vsm4eq_u32` runs four rounds with them.
+        unsafe fn vencrypt(key: [u32; 4], block: [u32; 4]) -> [u32; 4] {
+            let ck = ck();
+            let mut k: uint32x4_t = transmute(key);
+            k = veorq_u32(k, transmute(FK));
+            let mut x: uint32x4_t = transmute(block);
+            for ck in ck.chunks(4) {
+                k = vsm4ekeyq_u32(k, transmute([ck[0], ck[1], ck[2], ck[3]]));
+                x = vsm4eq_u32(x, k);
+            }
+            let x: [u32; 4] = transmute(x);
+            [x[3], x[2], x[1], x[0]]
+        }
+
+        // The example from the standard, which uses the key as plaintext.
+        let k = [0x01234567, 0x89abcdef, 0xfedcba98, 0x76543210];
+        let e = [0x681edf34, 0xd206965e, 0x86b3e94f, 0x536e4246];
+        assert_eq!(encrypt(k, k), e);
+        assert_eq!(vencrypt(k, k), e);
+
+        let mut x = 0x0123456789abcdef_u64;
+        for _ in 0..8 {
+            let mut v = [0; 8];
+            for v in v.iter_mut() {
+                x = x
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                *v = (x >> 32) as u32;
+            }
+            let key = [v[0], v[1], v[2], v[3]];
+            let block = [v[4], v[5], v[6], v[7]];
+            assert_eq!(vencrypt(key, block), encrypt(key, block));
+        }
+    }
+
+    #[simd_test(enable = "neon,sm4")]
+    unsafe fn test_sm3_compress() {
+        const IV: [u32; 8] = [
+            0x7380166f, 0x4914b2b9, 0x172442d7, 0xda8a0600, 0xa96f30bc, 0x163138aa, 0xe38dee4d,
+            0xb0fb0e4e,
+        ];
+        const T0: u32 = 0x79cc4519;
+        const T1: u32 = 0x7a879d8a;
+
+        fn compress(v: &[u32; 8], block: &[u32; 16]) -> [u32; 8] {
+            let p1 = |x: u32| x ^ x.rotate_left(15) ^ x.rotate_left(23);
+            let mut w = [0; 68];
+            w[..16].copy_from_slice(block);
+            for j in 16..68 {
+                w[j] = p1(w[j - 16] ^ w[j - 9] ^ w[j - 3].rotate_left(15))
+                    ^ w[j - 13].rotate_left(7)
+                    ^ w[j - 6];
+            }
+            let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = *v;
+            for j in 0..64 {
+                let (t, ff, gg) = if j < 16 {
+                    (T0, a ^ b ^ c, e ^ f ^ g)
+                } else {
+                    (T1, (a & b) | (a & c) | (b & c), (e & f) | (!e & g))
+                };
+                let ss1 = a
+                    .rotate_left(12)
+                    .wrapping_add(e)
+                    .wrapping_add(t.rotate_left(j as u32 % 32))
+                    .rotate_left(7);
+                let ss2 = ss1 ^ a.rotate_left(12);
+                let tt1 = ff
+                    .wrapping_add(d)
+                    .wrapping_add(ss2)
+                    .wrapping_add(w[j] ^ w[j + 4]);
+                let tt2 = gg.wrapping_add(h).wrapping_add(ss1).wrapping_add(w[j]);
+                d = c;
+                c = b.rotate_left(9);
+                b = a;
+                a = tt1;
+                h = g;
+                g = f.rotate_left(19);
+                f = e;
+                e = tt2 ^ tt2.rotate_left(9) ^ tt2.rotate_left(17);
+            }
+            let mut r = [a, b, c, d, e, f, g, h];
+            for (r, v) in r.iter_mut().zip(v) {
+                *r ^= v;
+            }
+            r
+        }
+
+        unsafe fn vcompress(v: &[u32; 8], block: &[u32; 16]) -> [u32; 8] {
+            let ld = |w: &[u32]| -> uint32x4_t { transmute([w[0], w[1], w[2], w[3]]) };
+            // The message expansion, four words at a time.
+            let mut w = [0; 68];
+            w[..16].copy_from_slice(block);
+            for j in (16..68).step_by(4) {
+                let p = vsm3partw1q_u32(ld(&w[j - 16..]), ld(&w[j - 9..]), ld(&w[j - 4..]));
+                let p = vsm3partw2q_u32(p, ld(&w[j - 6..]), ld(&w[j - 13..]));
+                let p: [u32; 4] = transmute(p);
+                w[j..j + 4].copy_from_slice(&p);
+            }
+            // The state is kept as (D, C, B, A) and (H, G, F, E), and the
+            // TT1/TT2 intrinsics pick the message word of the round with
+            // their immediate.
+            let mut abcd: uint32x4_t = transmute([v[3], v[2], v[1], v[0]]);
+            let mut efgh: uint32x4_t = transmute([v[7], v[6], v[5], v[4]]);
+            macro_rules! rounds {
+                ($j:expr, $t:expr, $tt1:ident, $tt2:ident) => {
+                    let wj = ld(&w[$j..]);
+                    let wj2 = veorq_u32(wj, ld(&w[$j + 4..]));
+                    rounds!(@ $j, $t, $tt1, $tt2, wj, wj2, 0, 1, 2, 3);
+                };
+                (@ $j:expr, $t:expr, $tt1:ident, $tt2:ident, $w:ident, $w2:ident, $($i:literal),*) => {$(
+                    let t = $t.rotate_left(($j as u32 + $i) % 32);
+                    let ss1 = vsm3ss1q_u32(abcd, efgh, transmute([0, 0, 0, t]));
+                    abcd = $tt1::<$i>(abcd, ss1, $w2);
+                    efgh = $tt2::<$i>(efgh, ss1, $w);
+                )*};
+            }
+            for j in (0..16).step_by(4) {
+                rounds!(j, T0, vsm3tt1aq_u32, vsm3tt2aq_u32);
+            }
+            for j in (16..64).step_by(4) {
+                rounds!(j, T1, vsm3tt1bq_u32, vsm3tt2bq_u32);
+            }
+            let abcd: [u32; 4] = transmute(abcd);
+            let efgh: [u32; 4] = transmute(efgh);
+            let mut r = [0; 8];
+            for i in 0..4 {
+                r[i] = abcd[3 - i] ^ v[i];
+                r[i + 4] = efgh[3 - i] ^ v[i + 4];
+            }
+            r
+        }
+
+        // The padded message "abc" is a single block, so this is its hash.
+        let mut block = [0; 16];
+        block[0] = 0x61626380;
+        block[15] = 24;
+        let e = [
+            0x66c7f0f4, 0x62eeedd9, 0xd1f2d46b, 0xdc10e4e2, 0x4167c487, 0x5cf2f7a2, 0x297da02b,
+            0x8f4ba8e0,
+        ];
+        assert_eq!(compress(&IV, &block), e);
+        assert_eq!(vcompress(&IV, &block), e);
+
+        let mut v = IV;
+        let mut x = 0x0123456789abcdef_u64;
+        for _ in 0..8 {
+            for w in block.iter_mut() {
+                x = x
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                *w = (x >> 32) as u32;
+            }
+            let r = compress(&v, &block);
+            assert_eq!(vcompress(&v, &block), r);
+            v = r;
+        }
+    }
+
+    #[simd_test(enable = "neon,frintts")]
+    unsafe fn test_vrnd_frintts() {
+        // Results that don't fit the integer range, including those of NaNs
+        // and infinities, are the most negative integer.
+        const MIN32: f64 = i32::MIN as f64;
+        const MIN64: f64 = i64::MIN as f64;
+        type F = unsafe fn(float64x2_t) -> float64x2_t;
+        unsafe fn rnd(f: F, a: [f64; 2]) -> [f64; 2] {
+            transmute(f(transmute(a)))
+        }
+
+        for f in [vrnd32xq_f64 as F, vrnd32zq_f64].iter() {
+            assert_eq!(rnd(*f, [f64::NAN, f64::INFINITY]), [MIN32; 2]);
+            assert_eq!(rnd(*f, [f64::NEG_INFINITY, 1.0e10]), [MIN32; 2]);
+            assert_eq!(rnd(*f, [2147483648.0, -2147483649.0]), [MIN32; 2]);
+        }
+        for f in [vrnd64xq_f64 as F, vrnd64zq_f64].iter() {
+            assert_eq!(rnd(*f, [f64::NAN, f64::INFINITY]), [MIN64; 2]);
+            assert_eq!(rnd(*f, [f64::NEG_INFINITY, 1.0e19]), [MIN64; 2]);
+            assert_eq!(rnd(*f, [1.0e10, -1.0e10]), [1.0e10, -1.0e10]);
+        }
+
+        // The x forms round in the current rounding mode, to nearest with ties
+        // to even by default, and the z forms toward zero.
+        let a = [2.5, -2.7];
+        assert_eq!(rnd(vrnd32xq_f64, a), [2.0, -3.0]);
+        assert_eq!(rnd(vrnd32zq_f64, a), [2.0, -2.0]);
+        assert_eq!(rnd(vrnd64xq_f64, a), [2.0, -3.0]);
+        assert_eq!(rnd(vrnd64zq_f64, a), [2.0, -2.0]);
+        let a = [2147483647.5, -0.5];
+        let r = rnd(vrnd32xq_f64, a);
+        assert_eq!(r, [MIN32, 0.0]);
+        assert!(r[1].is_sign_negative());
+        let r = rnd(vrnd32zq_f64, a);
+        assert_eq!(r, [2147483647.0, 0.0]);
+        assert!(r[1].is_sign_negative());
+
+        let a = [f32::NAN, 2.5, -2.7, 3.0e9];
+        let r: [f32; 4] = transmute(vrnd32xq_f32(transmute(a)));
+        assert_eq!(r, [MIN32 as f32, 2.0, -3.0, MIN32 as f32]);
+        let r: [f32; 4] = transmute(vrnd64zq_f32(transmute(a)));
+        assert_eq!(r, [MIN64 as f32, 2.0, -2.0, 3.0e9]);
+    }
 }
 
 #[cfg(test)]
-#[cfg(target_endian = "little")]
 #[path = "../../arm_shared/neon/table_lookup_tests.rs"]
 mod table_lookup_tests;
 
@@ -0,0 +1,275 @@
+//! Pointer authentication (FEAT_PAuth).
+//!
+//! Pointer authentication signs pointers by storing a MAC of the pointer, a
+//! 64-bit modifier and a key in the bits of the pointer that aren't used for
+//! the address, the pointer authentication code (PAC). The size of the PAC
+//! depends on the size of the address space and on whether the top byte is
+//! ignored. Authenticating a pointer checks the PAC and removes it.
+//!
+//! There are two keys for instruction addresses (IA and IB), two for data
+//! addresses (DA and DB) and one for computing generic MACs (GA), which are
+//! all managed by the OS.
+//!
+//! The reference is the "Pointer authentication" section of the
+//! [Arm Architecture Reference Manual][arm].
+//!
+//! [arm]: https://developer.arm.com/documentation/ddi0487/latest
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+// Signs or authenticates `$ptr` in place, with `$modifier`. Signing is pure,
+// but authentication faults on CPUs with FEAT_FPAC when it fails, so it must
+// not be removed when its result is unused.
+macro_rules! pac {
+    ($instr:literal, $ptr:expr, $modifier:expr $(, $pure:ident)?) => {{
+        let mut ptr: u64 = $ptr;
+        asm!(
+            concat!($instr, " {ptr}, {modifier}"),
+            ptr = inout(reg) ptr,
+            modifier = in(reg) $modifier,
+            options($($pure,)? nomem, nostack, preserves_flags),
+        );
+        ptr
+    }};
+}
+
+// Removes the PAC of `$ptr` without checking it.
+macro_rules! xpac {
+    ($instr:literal, $ptr:expr) => {{
+        let mut ptr: u64 = $ptr;
+        asm!(
+            concat!($instr, " {ptr}"),
+            ptr = inout(reg) ptr,
+            options(pure, nomem, nostack, preserves_flags),
+        );
+        ptr
+    }};
+}
+
+/// Computes a MAC of `x` with the modifier `y` and the GA key.
+///
+/// The 32-bit MAC is returned in the upper 32 bits, the lower 32 bits are 0.
+#[inline]
+#[target_feature(enable = "pacg")]
+#[cfg_attr(test, assert_instr(pacga))]
+pub unsafe fn __pacga(x: u64, y: u64) -> u64 {
+    let r: u64;
+    asm!(
+        "pacga {r}, {x}, {y}",
+        r = lateout(reg) r,
+        x = in(reg) x,
+        y = in(reg) y,
+        options(pure, nomem, nostack, preserves_flags),
+    );
+    r
+}
+
+/// Signs the instruction address `ptr` with `modifier` and the IA key.
+#[inline]
+#[target_feature(enable = "paca")]
+#[cfg_attr(test, assert_instr(pacia))]
+pub unsafe fn __pacia(ptr: u64, modifier: u64) -> u64 {
+    pac!("pacia", ptr, modifier, pure)
+}
+
+/// Signs the instruction address `ptr` with `modifier` and the IB key.
+#[inline]
+#[target_feature(enable = "paca")]
+#[cfg_attr(test, assert_instr(pacib))]
+pub unsafe fn __pacib(ptr: u64, modifier: u64) -> u64 {
+    pac!("pacib", ptr, modifier, pure)
+}
+
+/// Signs the data address `ptr` with `modifier` and the DA key.
+#[inline]
+#[target_feature(enable = "paca")]
+#[cfg_attr(test, assert_instr(pacda))]
+pub unsafe fn __pacda(ptr: u64, modifier: u64) -> u64 {
+    pac!("pacda", ptr, modifier, pure)
+}
+
+/// Signs the data address `ptr` with `modifier` and the DB key.
+#[inline]
+#[target_feature(enable = "paca")]
+#[cfg_attr(test, assert_instr(pacdb))]
+pub unsafe fn __pacdb(ptr: u64, modifier: u64) -> u64 {
+    pac!("pacdb", ptr, modifier, pure)
+}
+
+/// Authenticates the instruction address `ptr`, signed with `modifier` and
+/// the IA key, and returns it without its PAC.
+///
+/// If the PAC is invalid, this either faults, on CPUs with FEAT_FPAC, or
+/// returns an invalid address which faults when it is used. Compare the
+/// result of [`__pacia`] on the result of [`__xpaci`] with `ptr` to check the
+/// PAC without faulting.
+#[inline]
+#[target_feature(enable = "paca")]
+#[cfg_attr(test, assert_instr(autia))]
+pub unsafe fn __autia(ptr: u64, modifier: u64) -> u64 {
+    pac!("autia", ptr, modifier)
+}
+
+/// Authenticates the instruction address `ptr`, signed with `modifier` and
+/// the IB key, and returns it without its PAC.
+///
+/// If the PAC is invalid, this either faults, on CPUs with FEAT_FPAC, or
+/// returns an invalid address which faults when it is used. Compare the
+/// result of [`__pacib`] on the result of [`__xpaci`] with `ptr` to check the
+/// PAC without faulting.
+#[inline]
+#[target_feature(enable = "paca")]
+#[cfg_attr(test, assert_instr(autib))]
+pub unsafe fn __autib(ptr: u64, modifier: u64) -> u64 {
+    pac!("autib", ptr, modifier)
+}
+
+/// Authenticates the data address `ptr`, signed with `modifier` and the DA
+/// key, and returns it without its PAC.
+///
+/// If the PAC is invalid, this either faults, on CPUs with FEAT_FPAC, or
+/// returns an invalid address which faults when it is used. Compare the
+/// result of [`__pacda`] on the result of [`__xpacd`] with `ptr` to check the
+/// PAC without faulting.
+#[inline]
+#[target_feature(enable = "paca")]
+#[cfg_attr(test, assert_instr(autda))]
+pub unsafe fn __autda(ptr: u64, modifier: u64) -> u64 {
+    pac!("autda", ptr, modifier)
+}
+
+/// Authenticates the data address `ptr`, signed with `modifier` and the DB
+/// key, and returns it without its PAC.
+///
+/// If the PAC is invalid, this either faults, on CPUs with FEAT_FPAC, or
+/// returns an invalid address which faults when it is used. Compare the
+/// result of [`__pacdb`] on the result of [`__xpacd`] with `ptr` to check the
+/// PAC without faulting.
+#[inline]
+#[target_feature(enable = "paca")]
+#[cfg_attr(test, assert_instr(autdb))]
+pub unsafe fn __autdb(ptr: u64, modifier: u64) -> u64 {
+    pac!("autdb", ptr, modifier)
+}
+
+/// Removes the PAC of the instruction address `ptr` without authenticating
+/// it.
+#[inline]
+#[target_feature(enable = "paca")]
+#[cfg_attr(test, assert_instr(xpaci))]
+pub unsafe fn __xpaci(ptr: u64) -> u64 {
+    xpac!("xpaci", ptr)
+}
+
+/// Removes the PAC of the data address `ptr` without authenticating it.
+#[inline]
+#[target_feature(enable = "paca")]
+#[cfg_attr(test, assert_instr(xpacd))]
+pub unsafe fn __xpacd(ptr: u64) -> u64 {
+    xpac!("xpacd", ptr)
+}
+
+#[cfg(test)]
+mod tests {
+    use stdarch_test::simd_test;
+
+    use crate::core_arch::aarch64::*;
+
+    type Sign = unsafe fn(u64, u64) -> u64;
+    type Strip = unsafe fn(u64) -> u64;
+
+    const MODIFIER: u64 = 0x0123_4567_89ab_cdef;
+
+    // Bit 55 selects the half of the address space, and bit 54 is part of
+    // the PAC for every size of the address space.
+    const PAC_BIT: u64 = 1 << 54;
+
+    fn address() -> u64 {
+        address as usize as u64
+    }
+
+    /// Checks the PAC of `signed` without authenticating it, which faults on
+    /// CPUs with FEAT_FPAC if the PAC is invalid.
+    unsafe fn valid(signed: u64, sign: Sign, strip: Strip) -> bool {
+        sign(strip(signed), MODIFIER) == signed
+    }
+
+    unsafe fn check_sign(sign: Sign, strip: Strip) {
+        let ptr = address();
+        let signed = sign(ptr, MODIFIER);
+        assert_eq!(strip(signed), ptr);
+        assert!(valid(signed, sign, strip));
+        assert!(!valid(signed ^ PAC_BIT, sign, strip));
+    }
+
+    unsafe fn check_auth(sign: Sign, auth: Sign) {
+        let ptr = address();
+        assert_eq!(auth(sign(ptr, MODIFIER), MODIFIER), ptr);
+    }
+
+    #[simd_test(enable = "pacg")]
+    unsafe fn test_pacga() {
+        let mac = __pacga(address(), MODIFIER);
+        assert_eq!(mac & 0xffff_ffff, 0);
+        assert_eq!(__pacga(address(), MODIFIER), mac);
+        // The MACs of different inputs are equal with a chance of 2^-32.
+        assert_ne!(__pacga(address(), !MODIFIER), mac);
+    }
+
+    #[simd_test(enable = "paca")]
+    unsafe fn test_pacia() {
+        check_sign(__pacia, __xpaci);
+    }
+
+    #[simd_test(enable = "paca")]
+    unsafe fn test_pacib() {
+        check_sign(__pacib, __xpaci);
+    }
+
+    #[simd_test(enable = "paca")]
+    unsafe fn test_pacda() {
+        check_sign(__pacda, __xpacd);
+    }
+
+    #[simd_test(enable = "paca")]
+    unsafe fn test_pacdb() {
+        check_sign(__pacdb, __xpacd);
+    }
+
+    #[simd_test(enable = "paca")]
+    unsafe fn test_autia() {
+        check_auth(__pacia, __autia);
+    }
+
+    #[simd_test(enable = "paca")]
+    unsafe fn test_autib() {
+        check_auth(__pacib, __autib);
+    }
+
+    #[simd_test(enable = "paca")]
+    unsafe fn test_autda() {
+        check_auth(__pacda, __autda);
+    }
+
+    #[simd_test(enable = "paca")]
+    unsafe fn test_autdb() {
+        check_auth(__pacdb, __autdb);
+    }
+
+    #[simd_test(enable = "paca")]
+    unsafe fn test_xpaci() {
+        let ptr = address();
+        assert_eq!(__xpaci(ptr), ptr);
+        assert_eq!(__xpaci(__pacia(ptr, MODIFIER)), ptr);
+        assert_eq!(__xpaci(__pacib(ptr, MODIFIER)), ptr);
+    }
+
+    #[simd_test(enable = "paca")]
+    unsafe fn test_xpacd() {
+        let ptr = address();
+        assert_eq!(__xpacd(ptr), ptr);
+        assert_eq!(__xpacd(__pacda(ptr, MODIFIER)), ptr);
+        assert_eq!(__xpacd(__pacdb(ptr, MODIFIER)), ptr);
+    }
+}
@@ -0,0 +1,88 @@
+//! Random number generation (FEAT_RNG).
+//!
+//! The reference is the "Random number generation intrinsics" section of
+//! [ACLE][acle].
+//!
+//! [acle]: https://github.com/ARM-software/acle
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+// The registers are named by their encoding, which assemblers without
+// support for FEAT_RNG accept as well: `S3_3_C2_C4_0` is RNDR and
+// `S3_3_C2_C4_1` RNDRRS. Reading them sets `NZCV` to `0b0100` when no random
+// number could be returned in a reasonable amount of time and to `0b0000`
+// otherwise.
+macro_rules! read_rndr {
+    ($reg:literal, $value:expr) => {{
+        let r: u64;
+        let failed: i32;
+        asm!(
+            concat!("mrs {r}, ", $reg),
+            "cset {failed:w}, eq",
+            r = lateout(reg) r,
+            failed = lateout(reg) failed,
+            options(nomem, nostack),
+        );
+        *$value = r;
+        failed
+    }};
+}
+
+/// Stores a 64-bit random number into the object pointed to by `value`,
+/// reading it from the RNDR register, which is reseeded from the hardware
+/// entropy source at an implementation defined rate.
+///
+/// Returns 0 on success. If the hardware couldn't return a random number in
+/// a reasonable amount of time, 0 is stored into `value` and a non-zero value
+/// is returned; the call may then be retried.
+#[inline]
+#[target_feature(enable = "rand")]
+#[cfg_attr(test, assert_instr(mrs))]
+pub unsafe fn __rndr(value: *mut u64) -> i32 {
+    read_rndr!("S3_3_C2_C4_0", value)
+}
+
+/// Stores a 64-bit random number into the object pointed to by `value`,
+/// reading it from the RNDRRS register, which is reseeded from the hardware
+/// entropy source right before the number is generated.
+///
+/// Returns 0 on success. If the hardware couldn't return a random number in
+/// a reasonable amount of time, 0 is stored into `value` and a non-zero value
+/// is returned; the call may then be retried.
+#[inline]
+#[target_feature(enable = "rand")]
+#[cfg_attr(test, assert_instr(mrs))]
+pub unsafe fn __rndrrs(value: *mut u64) -> i32 {
+    read_rndr!("S3_3_C2_C4_1", value)
+}
+
+#[cfg(test)]
+mod tests {
+    use stdarch_test::simd_test;
+
+    use crate::core_arch::aarch64::*;
+
+    unsafe fn next(f: unsafe fn(*mut u64) -> i32) -> u64 {
+        let mut value = 0;
+        while f(&mut value) != 0 {
+            assert_eq!(value, 0);
+        }
+        value
+    }
+
+    #[simd_test(enable = "rand")]
+    unsafe fn test_rndr() {
+        // Two equal numbers in a row have a chance of 2^-64.
+        let a = next(__rndr);
+        let b = next(__rndr);
+        assert_ne!(a, b);
+    }
+
+    #[simd_test(enable = "rand")]
+    unsafe fn test_rndrrs() {
+        let a = next(__rndrrs);
+        let b = next(__rndrrs);
+        assert_ne!(a, b);
+    }
+}
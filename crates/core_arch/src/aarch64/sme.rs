@@ -0,0 +1,128 @@
+//! ARM's Scalable Matrix Extension (SME).
+//!
+//! SME adds a streaming mode, in which the SVE instructions operate on
+//! vectors of the streaming vector length, and the ZA array of matrix
+//! tiles. The ZA intrinsics (`svzero_za`, `svld1_hor_za32`,
+//! `svmopa_za32_f32_m`, ...) and everything that runs in streaming mode need
+//! the compiler to support streaming functions and ZA state, as well as the
+//! sizeless SVE types, so only the intrinsics that can be called from
+//! ordinary functions are provided for now. These query the streaming
+//! vector length and whether the processor is in streaming mode.
+//!
+//! The reference is the "SME language extensions and intrinsics" section of
+//! [ACLE][acle].
+//!
+//! [acle]: https://github.com/ARM-software/acle
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+// The number of bytes in a streaming vector, which RDSVL also reads outside
+// of streaming mode.
+#[inline(always)]
+unsafe fn rdsvl() -> u64 {
+    let n: u64;
+    asm!("rdsvl {}, #1", out(reg) n, options(pure, nomem, nostack, preserves_flags));
+    n
+}
+
+/// Returns the number of 8-bit elements in a streaming vector.
+#[inline]
+#[target_feature(enable = "sme")]
+#[cfg_attr(test, assert_instr(rdsvl))]
+pub unsafe fn svcntsb() -> u64 {
+    rdsvl()
+}
+
+/// Returns the number of 16-bit elements in a streaming vector.
+#[inline]
+#[target_feature(enable = "sme")]
+#[cfg_attr(test, assert_instr(rdsvl))]
+pub unsafe fn svcntsh() -> u64 {
+    rdsvl() / 2
+}
+
+/// Returns the number of 32-bit elements in a streaming vector.
+#[inline]
+#[target_feature(enable = "sme")]
+#[cfg_attr(test, assert_instr(rdsvl))]
+pub unsafe fn svcntsw() -> u64 {
+    rdsvl() / 4
+}
+
+/// Returns the number of 64-bit elements in a streaming vector.
+#[inline]
+#[target_feature(enable = "sme")]
+#[cfg_attr(test, assert_instr(rdsvl))]
+pub unsafe fn svcntsd() -> u64 {
+    rdsvl() / 8
+}
+
+/// Returns whether the processor is in streaming mode, from bit 0 (SM) of
+/// the SVCR register.
+///
+/// Rust code never enters streaming mode, so this is only `true` when
+/// called from foreign code that runs in streaming mode.
+#[inline]
+#[target_feature(enable = "sme")]
+#[cfg_attr(test, assert_instr(mrs))]
+pub unsafe fn __arm_in_streaming_mode() -> bool {
+    let svcr: u64;
+    // SVCR, by its encoding since older assemblers don't know its name.
+    asm!("mrs {}, S3_3_C4_C2_2", out(reg) svcr, options(nomem, nostack, preserves_flags));
+    svcr & 1 != 0
+}
+
+#[cfg(test)]
+mod tests {
+    use stdarch_test::simd_test;
+
+    use crate::core_arch::aarch64::*;
+
+    #[simd_test(enable = "sme")]
+    unsafe fn test_svcntsb() {
+        let n = svcntsb();
+        assert!(n >= 16 && n <= 256);
+        assert!(n.is_power_of_two());
+
+        // Entering streaming mode zeroes the vector registers, so this
+        // can't run any Rust code in between.
+        let cntb: u64;
+        asm!(
+            "smstart sm",
+            "cntb {}",
+            "smstop sm",
+            out(reg) cntb,
+            out("v0") _, out("v1") _, out("v2") _, out("v3") _,
+            out("v4") _, out("v5") _, out("v6") _, out("v7") _,
+            out("v8") _, out("v9") _, out("v10") _, out("v11") _,
+            out("v12") _, out("v13") _, out("v14") _, out("v15") _,
+            out("v16") _, out("v17") _, out("v18") _, out("v19") _,
+            out("v20") _, out("v21") _, out("v22") _, out("v23") _,
+            out("v24") _, out("v25") _, out("v26") _, out("v27") _,
+            out("v28") _, out("v29") _, out("v30") _, out("v31") _,
+            options(nomem, nostack),
+        );
+        assert_eq!(n, cntb);
+    }
+
+    #[simd_test(enable = "sme")]
+    unsafe fn test_svcntsh() {
+        assert_eq!(svcntsh() * 2, svcntsb());
+    }
+
+    #[simd_test(enable = "sme")]
+    unsafe fn test_svcntsw() {
+        assert_eq!(svcntsw() * 4, svcntsb());
+    }
+
+    #[simd_test(enable = "sme")]
+    unsafe fn test_svcntsd() {
+        assert_eq!(svcntsd() * 8, svcntsb());
+    }
+
+    #[simd_test(enable = "sme")]
+    unsafe fn test_arm_in_streaming_mode() {
+        assert!(!__arm_in_streaming_mode());
+    }
+}
@@ -0,0 +1,215 @@
+//! ARM's Scalable Vector Extension (SVE).
+//!
+//! SVE vectors have a length that is a multiple of 128 bits between 128 and
+//! 2048 bits, which is fixed by the hardware and only known at run-time. The
+//! SVE data and predicate types (`svint8_t`, `svbool_t`, ...) are therefore
+//! sizeless, which needs support for scalable vectors in the compiler that
+//! doesn't exist yet, so only the intrinsics that don't take or return SVE
+//! types are provided for now. These query the length of the vectors, which
+//! is useful to size buffers or pick an implementation at run-time.
+//!
+//! The reference is [Arm C Language Extensions for SVE][acle_sve].
+//!
+//! [acle_sve]: https://developer.arm.com/documentation/100987/latest
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+extern "C" {
+    #[link_name = "llvm.aarch64.sve.cntb"]
+    fn sve_cntb(pattern: i32) -> i64;
+    #[link_name = "llvm.aarch64.sve.cnth"]
+    fn sve_cnth(pattern: i32) -> i64;
+    #[link_name = "llvm.aarch64.sve.cntw"]
+    fn sve_cntw(pattern: i32) -> i64;
+    #[link_name = "llvm.aarch64.sve.cntd"]
+    fn sve_cntd(pattern: i32) -> i64;
+}
+
+/// The largest power of two elements that fits in a vector.
+pub const SV_POW2: i32 = 0;
+/// 1 element if a vector has at least that many, 0 otherwise.
+pub const SV_VL1: i32 = 1;
+/// 2 elements if a vector has at least that many, 0 otherwise.
+pub const SV_VL2: i32 = 2;
+/// 3 elements if a vector has at least that many, 0 otherwise.
+pub const SV_VL3: i32 = 3;
+/// 4 elements if a vector has at least that many, 0 otherwise.
+pub const SV_VL4: i32 = 4;
+/// 5 elements if a vector has at least that many, 0 otherwise.
+pub const SV_VL5: i32 = 5;
+/// 6 elements if a vector has at least that many, 0 otherwise.
+pub const SV_VL6: i32 = 6;
+/// 7 elements if a vector has at least that many, 0 otherwise.
+pub const SV_VL7: i32 = 7;
+/// 8 elements if a vector has at least that many, 0 otherwise.
+pub const SV_VL8: i32 = 8;
+/// 16 elements if a vector has at least that many, 0 otherwise.
+pub const SV_VL16: i32 = 9;
+/// 32 elements if a vector has at least that many, 0 otherwise.
+pub const SV_VL32: i32 = 10;
+/// 64 elements if a vector has at least that many, 0 otherwise.
+pub const SV_VL64: i32 = 11;
+/// 128 elements if a vector has at least that many, 0 otherwise.
+pub const SV_VL128: i32 = 12;
+/// 256 elements if a vector has at least that many, 0 otherwise.
+pub const SV_VL256: i32 = 13;
+/// The largest multiple of 4 elements that fits in a vector.
+pub const SV_MUL4: i32 = 29;
+/// The largest multiple of 3 elements that fits in a vector.
+pub const SV_MUL3: i32 = 30;
+/// All elements of a vector.
+pub const SV_ALL: i32 = 31;
+
+/// Returns the number of 8-bit elements in a vector.
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/svcntb)
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(cntb))]
+pub unsafe fn svcntb() -> u64 {
+    sve_cntb(SV_ALL) as u64
+}
+
+/// Returns the number of 16-bit elements in a vector.
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/svcnth)
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(cnth))]
+pub unsafe fn svcnth() -> u64 {
+    sve_cnth(SV_ALL) as u64
+}
+
+/// Returns the number of 32-bit elements in a vector.
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/svcntw)
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(cntw))]
+pub unsafe fn svcntw() -> u64 {
+    sve_cntw(SV_ALL) as u64
+}
+
+/// Returns the number of 64-bit elements in a vector.
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/svcntd)
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(cntd))]
+pub unsafe fn svcntd() -> u64 {
+    sve_cntd(SV_ALL) as u64
+}
+
+/// Returns the number of 8-bit elements selected by `PATTERN`, which is one
+/// of the `SV_*` constants.
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/svcntb_pat)
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(cntb, PATTERN = SV_VL8))]
+#[rustc_legacy_const_generics(0)]
+pub unsafe fn svcntb_pat<const PATTERN: i32>() -> u64 {
+    static_assert_imm5!(PATTERN);
+    sve_cntb(PATTERN) as u64
+}
+
+/// Returns the number of 16-bit elements selected by `PATTERN`, which is one
+/// of the `SV_*` constants.
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/svcnth_pat)
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(cnth, PATTERN = SV_VL8))]
+#[rustc_legacy_const_generics(0)]
+pub unsafe fn svcnth_pat<const PATTERN: i32>() -> u64 {
+    static_assert_imm5!(PATTERN);
+    sve_cnth(PATTERN) as u64
+}
+
+/// Returns the number of 32-bit elements selected by `PATTERN`, which is one
+/// of the `SV_*` constants.
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/svcntw_pat)
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(cntw, PATTERN = SV_VL8))]
+#[rustc_legacy_const_generics(0)]
+pub unsafe fn svcntw_pat<const PATTERN: i32>() -> u64 {
+    static_assert_imm5!(PATTERN);
+    sve_cntw(PATTERN) as u64
+}
+
+/// Returns the number of 64-bit elements selected by `PATTERN`, which is one
+/// of the `SV_*` constants.
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/svcntd_pat)
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(cntd, PATTERN = SV_VL8))]
+#[rustc_legacy_const_generics(0)]
+pub unsafe fn svcntd_pat<const PATTERN: i32>() -> u64 {
+    static_assert_imm5!(PATTERN);
+    sve_cntd(PATTERN) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use stdarch_test::simd_test;
+
+    use crate::core_arch::aarch64::*;
+
+    #[simd_test(enable = "sve")]
+    unsafe fn test_svcntb() {
+        let n = svcntb();
+        assert!(n >= 16 && n <= 256);
+        assert_eq!(n % 16, 0);
+    }
+
+    #[simd_test(enable = "sve")]
+    unsafe fn test_svcnth() {
+        assert_eq!(svcnth() * 2, svcntb());
+    }
+
+    #[simd_test(enable = "sve")]
+    unsafe fn test_svcntw() {
+        assert_eq!(svcntw() * 4, svcntb());
+    }
+
+    #[simd_test(enable = "sve")]
+    unsafe fn test_svcntd() {
+        assert_eq!(svcntd() * 8, svcntb());
+    }
+
+    #[simd_test(enable = "sve")]
+    unsafe fn test_svcntb_pat() {
+        let n = svcntb();
+        assert_eq!(svcntb_pat::<SV_ALL>(), n);
+        assert_eq!(svcntb_pat::<SV_VL16>(), 16);
+        assert_eq!(svcntb_pat::<SV_MUL3>(), n - n % 3);
+        let pow2 = svcntb_pat::<SV_POW2>();
+        assert!(pow2.is_power_of_two() && pow2 <= n && pow2 * 2 > n);
+    }
+
+    #[simd_test(enable = "sve")]
+    unsafe fn test_svcnth_pat() {
+        let n = svcnth();
+        assert_eq!(svcnth_pat::<SV_ALL>(), n);
+        assert_eq!(svcnth_pat::<SV_VL8>(), 8);
+        assert_eq!(svcnth_pat::<SV_MUL4>(), n - n % 4);
+    }
+
+    #[simd_test(enable = "sve")]
+    unsafe fn test_svcntw_pat() {
+        assert_eq!(svcntw_pat::<SV_ALL>(), svcntw());
+        assert_eq!(svcntw_pat::<SV_VL4>(), 4);
+        assert_eq!(svcntw_pat::<SV_VL256>(), 0);
+    }
+
+    #[simd_test(enable = "sve")]
+    unsafe fn test_svcntd_pat() {
+        assert_eq!(svcntd_pat::<SV_ALL>(), svcntd());
+        assert_eq!(svcntd_pat::<SV_VL2>(), 2);
+        assert_eq!(svcntd_pat::<SV_VL1>(), 1);
+    }
+}
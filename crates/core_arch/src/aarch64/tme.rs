@@ -28,10 +28,17 @@ extern "C" {
     fn aarch64_ttest() -> u64;
 }
 
+// The value returned by `__tstart` is 0 when the transaction started. When
+// the transaction failed, execution continues after the `__tstart` that
+// started it, which returns the failure code: the reason passed to
+// `__tcancel` in the bits of `_TMFAILURE_REASON` if it was cancelled, and one
+// of the cause bits below.
+
 /// Transaction successfully started.
 pub const _TMSTART_SUCCESS: u64 = 0x00_u64;
 
-/// Extraction mask for failure reason
+/// Extraction mask for the reason passed to `__tcancel`, valid when
+/// `_TMFAILURE_CNCL` is set.
 pub const _TMFAILURE_REASON: u64 = 0x00007FFF_u64;
 
 /// Transaction retry is possible.
@@ -90,14 +97,17 @@ pub unsafe fn __tcommit() {
 
 /// Cancels the current transaction and discards all state modifications that were performed transactionally.
 ///
+/// `REASON` is a 15-bit value which the `__tstart` that started the transaction returns in the bits
+/// of `_TMFAILURE_REASON`, together with `_TMFAILURE_CNCL`.
+///
 /// [ARM TME Intrinsics](https://developer.arm.com/docs/101028/0010/transactional-memory-extension-tme-intrinsics).
 #[inline]
 #[target_feature(enable = "tme")]
-#[cfg_attr(test, assert_instr(tcancel, IMM16 = 0x0))]
+#[cfg_attr(test, assert_instr(tcancel, REASON = 0x0))]
 #[rustc_legacy_const_generics(0)]
-pub unsafe fn __tcancel<const IMM16: u64>() {
-    static_assert!(IMM16: u64 where IMM16 <= 65535);
-    aarch64_tcancel(IMM16);
+pub unsafe fn __tcancel<const REASON: u64>() {
+    static_assert!(REASON: u64 where REASON <= _TMFAILURE_REASON);
+    aarch64_tcancel(REASON);
 }
 
 /// Tests if executing inside a transaction. If no transaction is currently executing,
@@ -116,63 +126,81 @@ mod tests {
     use stdarch_test::simd_test;
 
     use crate::core_arch::aarch64::*;
+    use crate::ptr;
+
+    const CANCEL_CODE: u64 = 0x123;
 
-    const CANCEL_CODE: u64 = (0 | (0x123 & _TMFAILURE_REASON) as u64) as u64;
+    // The transactions may fail for reasons outside of our control, like an
+    // interrupt, so each test retries them a few times.
+    const RETRIES: usize = 10;
 
     #[simd_test(enable = "tme")]
     unsafe fn test_tstart() {
-        let mut x = 0;
-        for i in 0..10 {
-            let code = tme::__tstart();
+        for _ in 0..RETRIES {
+            let code = __tstart();
             if code == _TMSTART_SUCCESS {
-                x += 1;
-                assert_eq!(x, i + 1);
-                break;
+                __tcommit();
+                return;
             }
-            assert_eq!(x, 0);
+            assert_eq!(code & _TMFAILURE_CNCL, 0);
         }
     }
 
     #[simd_test(enable = "tme")]
     unsafe fn test_tcommit() {
-        let mut x = 0;
-        for i in 0..10 {
-            let code = tme::__tstart();
+        let mut x = 0_u64;
+        for _ in 0..RETRIES {
+            let code = __tstart();
             if code == _TMSTART_SUCCESS {
-                x += 1;
-                assert_eq!(x, i + 1);
-                tme::__tcommit();
+                ptr::write_volatile(&mut x, 42);
+                __tcommit();
+                assert_eq!(ptr::read_volatile(&x), 42);
+                return;
             }
-            assert_eq!(x, i + 1);
+            // A failed transaction discards the write.
+            assert_eq!(ptr::read_volatile(&x), 0);
         }
     }
 
     #[simd_test(enable = "tme")]
     unsafe fn test_tcancel() {
-        let mut x = 0;
-
-        for i in 0..10 {
-            let code = tme::__tstart();
+        let mut x = 0_u64;
+        for _ in 0..RETRIES {
+            let code = __tstart();
             if code == _TMSTART_SUCCESS {
-                x += 1;
-                assert_eq!(x, i + 1);
-                tme::__tcancel::<CANCEL_CODE>();
-                break;
+                ptr::write_volatile(&mut x, 42);
+                __tcancel::<CANCEL_CODE>();
+                unreachable!();
+            }
+            assert_eq!(ptr::read_volatile(&x), 0);
+            if code & _TMFAILURE_CNCL != 0 {
+                assert_eq!(code & _TMFAILURE_REASON, CANCEL_CODE);
+                assert_eq!(code & _TMFAILURE_RTRY, 0);
+                return;
             }
         }
-
-        assert_eq!(x, 0);
     }
 
     #[simd_test(enable = "tme")]
     unsafe fn test_ttest() {
-        for _ in 0..10 {
-            let code = tme::__tstart();
+        assert_eq!(__ttest(), 0);
+        for _ in 0..RETRIES {
+            let code = __tstart();
             if code == _TMSTART_SUCCESS {
-                if tme::__ttest() == 2 {
-                    tme::__tcancel::<CANCEL_CODE>();
-                    break;
+                let outer = __ttest();
+                let code = __tstart();
+                if code == _TMSTART_SUCCESS {
+                    let inner = __ttest();
+                    __tcommit();
+                    let after = __ttest();
+                    __tcommit();
+                    assert_eq!((outer, inner, after), (1, 2, 1));
+                    assert_eq!(__ttest(), 0);
+                    return;
                 }
+                // Failing the inner transaction fails the outer one as well,
+                // so execution never gets here.
+                unreachable!();
             }
         }
     }
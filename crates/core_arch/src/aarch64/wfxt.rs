@@ -0,0 +1,69 @@
+//! Wait for event and wait for interrupt with timeout (FEAT_WFxT).
+//!
+//! The timeout is an absolute value of the virtual counter, `CNTVCT_EL0`,
+//! after which the processor stops waiting, so these can't wait forever like
+//! `__wfe` and `__wfi`. They may still return before the timeout for the same
+//! reasons as those.
+//!
+//! The reference is the "Hints" section of [ACLE][acle].
+//!
+//! [acle]: https://github.com/ARM-software/acle
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Generates a WFET (wait for event with timeout) instruction.
+///
+/// The processor may enter a low-power state until some event occurs, such
+/// as a SEV being issued by another processor, or until the virtual counter
+/// reaches `timeout`.
+#[inline]
+#[target_feature(enable = "wfxt")]
+#[cfg_attr(test, assert_instr(wfet))]
+pub unsafe fn __wfet(timeout: u64) {
+    asm!("wfet {}", in(reg) timeout, options(nomem, nostack, preserves_flags));
+}
+
+/// Generates a WFIT (wait for interrupt with timeout) instruction.
+///
+/// The processor may enter a low-power state until one of a number of
+/// asynchronous events occurs, or until the virtual counter reaches
+/// `timeout`.
+#[inline]
+#[target_feature(enable = "wfxt")]
+#[cfg_attr(test, assert_instr(wfit))]
+pub unsafe fn __wfit(timeout: u64) {
+    asm!("wfit {}", in(reg) timeout, options(nomem, nostack, preserves_flags));
+}
+
+#[cfg(test)]
+mod tests {
+    use stdarch_test::simd_test;
+
+    use crate::core_arch::aarch64::*;
+
+    unsafe fn cntvct() -> u64 {
+        let cnt: u64;
+        asm!("isb", "mrs {}, CNTVCT_EL0", out(reg) cnt, options(nomem, nostack));
+        cnt
+    }
+
+    // Both may return early, so only waiting until the counter has passed
+    // the timeout is guaranteed to return.
+    unsafe fn wait_until(f: unsafe fn(u64)) {
+        let timeout = cntvct() + 4096;
+        while cntvct() < timeout {
+            f(timeout);
+        }
+    }
+
+    #[simd_test(enable = "wfxt")]
+    unsafe fn test_wfet() {
+        wait_until(__wfet);
+    }
+
+    #[simd_test(enable = "wfxt")]
+    unsafe fn test_wfit() {
+        wait_until(__wfit);
+    }
+}
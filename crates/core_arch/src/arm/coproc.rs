@@ -0,0 +1,223 @@
+//! Coprocessor intrinsics
+//!
+//! The coprocessor number, opcodes and coprocessor registers are encoded in
+//! the instructions, so they're const generics which are checked against the
+//! width of their fields at compile time.
+//!
+//! These give access to the CP15 system control registers, for example, but
+//! most of those can only be accessed from privileged modes and accessing
+//! them from user mode raises an undefined instruction exception.
+//!
+//! # References
+//!
+//! - Section 10.3 "Coprocessor Intrinsics" of ACLE
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+extern "C" {
+    #[link_name = "llvm.arm.cdp"]
+    fn arm_cdp(coproc: i32, opc1: i32, crd: i32, crn: i32, crm: i32, opc2: i32);
+    #[link_name = "llvm.arm.ldc"]
+    fn arm_ldc(coproc: i32, crd: i32, p: *const u8);
+    #[link_name = "llvm.arm.ldcl"]
+    fn arm_ldcl(coproc: i32, crd: i32, p: *const u8);
+    #[link_name = "llvm.arm.stc"]
+    fn arm_stc(coproc: i32, crd: i32, p: *mut u8);
+    #[link_name = "llvm.arm.stcl"]
+    fn arm_stcl(coproc: i32, crd: i32, p: *mut u8);
+    #[link_name = "llvm.arm.mcr"]
+    fn arm_mcr(coproc: i32, opc1: i32, value: u32, crn: i32, crm: i32, opc2: i32);
+    #[link_name = "llvm.arm.mrc"]
+    fn arm_mrc(coproc: i32, opc1: i32, crn: i32, crm: i32, opc2: i32) -> u32;
+    #[link_name = "llvm.arm.mcrr"]
+    fn arm_mcrr(coproc: i32, opc1: i32, lo: u32, hi: u32, crm: i32);
+    #[link_name = "llvm.arm.mrrc"]
+    fn arm_mrrc(coproc: i32, opc1: i32, crm: i32) -> (u32, u32);
+}
+
+/// Performs the coprocessor data operation `OPC1`, `OPC2` of coprocessor
+/// `COPROC` on the coprocessor registers `CRN` and `CRM`, with destination
+/// `CRD` (CDP).
+#[inline]
+#[rustc_legacy_const_generics(0, 1, 2, 3, 4, 5)]
+#[cfg_attr(
+    test,
+    assert_instr(cdp, COPROC = 7, OPC1 = 1, CRD = 2, CRN = 3, CRM = 4, OPC2 = 5)
+)]
+pub unsafe fn __arm_cdp<
+    const COPROC: i32,
+    const OPC1: i32,
+    const CRD: i32,
+    const CRN: i32,
+    const CRM: i32,
+    const OPC2: i32,
+>() {
+    static_assert_imm4!(COPROC);
+    static_assert_imm4!(OPC1);
+    static_assert_imm4!(CRD);
+    static_assert_imm4!(CRN);
+    static_assert_imm4!(CRM);
+    static_assert_imm3!(OPC2);
+    arm_cdp(COPROC, OPC1, CRD, CRN, CRM, OPC2)
+}
+
+/// Loads the coprocessor register `CRD` of coprocessor `COPROC` from `p`
+/// (LDC).
+#[inline]
+#[rustc_legacy_const_generics(0, 1)]
+#[cfg_attr(test, assert_instr(ldc, COPROC = 7, CRD = 2))]
+pub unsafe fn __arm_ldc<const COPROC: i32, const CRD: i32>(p: *const u8) {
+    static_assert_imm4!(COPROC);
+    static_assert_imm4!(CRD);
+    arm_ldc(COPROC, CRD, p)
+}
+
+/// Loads the coprocessor register `CRD` of coprocessor `COPROC` from `p`,
+/// with a long transfer (LDCL).
+#[inline]
+#[rustc_legacy_const_generics(0, 1)]
+#[cfg_attr(test, assert_instr(ldcl, COPROC = 7, CRD = 2))]
+pub unsafe fn __arm_ldcl<const COPROC: i32, const CRD: i32>(p: *const u8) {
+    static_assert_imm4!(COPROC);
+    static_assert_imm4!(CRD);
+    arm_ldcl(COPROC, CRD, p)
+}
+
+/// Stores the coprocessor register `CRD` of coprocessor `COPROC` to `p`
+/// (STC).
+#[inline]
+#[rustc_legacy_const_generics(0, 1)]
+#[cfg_attr(test, assert_instr(stc, COPROC = 7, CRD = 2))]
+pub unsafe fn __arm_stc<const COPROC: i32, const CRD: i32>(p: *mut u8) {
+    static_assert_imm4!(COPROC);
+    static_assert_imm4!(CRD);
+    arm_stc(COPROC, CRD, p)
+}
+
+/// Stores the coprocessor register `CRD` of coprocessor `COPROC` to `p`,
+/// with a long transfer (STCL).
+#[inline]
+#[rustc_legacy_const_generics(0, 1)]
+#[cfg_attr(test, assert_instr(stcl, COPROC = 7, CRD = 2))]
+pub unsafe fn __arm_stcl<const COPROC: i32, const CRD: i32>(p: *mut u8) {
+    static_assert_imm4!(COPROC);
+    static_assert_imm4!(CRD);
+    arm_stcl(COPROC, CRD, p)
+}
+
+/// Writes `value` to the 32-bit register of coprocessor `COPROC` encoded as
+/// `OPC1, CRN, CRM, OPC2` (MCR).
+#[inline]
+#[rustc_legacy_const_generics(0, 1, 3, 4, 5)]
+#[cfg_attr(
+    test,
+    assert_instr(mcr, COPROC = 15, OPC1 = 0, CRN = 13, CRM = 0, OPC2 = 2)
+)]
+pub unsafe fn __arm_mcr<
+    const COPROC: i32,
+    const OPC1: i32,
+    const CRN: i32,
+    const CRM: i32,
+    const OPC2: i32,
+>(
+    value: u32,
+) {
+    static_assert_imm4!(COPROC);
+    static_assert_imm3!(OPC1);
+    static_assert_imm4!(CRN);
+    static_assert_imm4!(CRM);
+    static_assert_imm3!(OPC2);
+    arm_mcr(COPROC, OPC1, value, CRN, CRM, OPC2)
+}
+
+/// Reads the 32-bit register of coprocessor `COPROC` encoded as
+/// `OPC1, CRN, CRM, OPC2` (MRC).
+#[inline]
+#[rustc_legacy_const_generics(0, 1, 2, 3, 4)]
+#[cfg_attr(
+    test,
+    assert_instr(mrc, COPROC = 15, OPC1 = 0, CRN = 13, CRM = 0, OPC2 = 2)
+)]
+pub unsafe fn __arm_mrc<
+    const COPROC: i32,
+    const OPC1: i32,
+    const CRN: i32,
+    const CRM: i32,
+    const OPC2: i32,
+>() -> u32 {
+    static_assert_imm4!(COPROC);
+    static_assert_imm3!(OPC1);
+    static_assert_imm4!(CRN);
+    static_assert_imm4!(CRM);
+    static_assert_imm3!(OPC2);
+    arm_mrc(COPROC, OPC1, CRN, CRM, OPC2)
+}
+
+/// Writes `value` to the 64-bit register of coprocessor `COPROC` encoded as
+/// `OPC1, CRM` (MCRR).
+// LLVM says "instruction requires: armv5te"
+#[cfg(any(target_feature = "v5te", doc))]
+#[inline]
+#[rustc_legacy_const_generics(0, 1, 3)]
+#[cfg_attr(test, assert_instr(mcrr, COPROC = 15, OPC1 = 1, CRM = 14))]
+pub unsafe fn __arm_mcrr<const COPROC: i32, const OPC1: i32, const CRM: i32>(value: u64) {
+    static_assert_imm4!(COPROC);
+    static_assert_imm4!(OPC1);
+    static_assert_imm4!(CRM);
+    arm_mcrr(COPROC, OPC1, value as u32, (value >> 32) as u32, CRM)
+}
+
+/// Reads the 64-bit register of coprocessor `COPROC` encoded as `OPC1, CRM`
+/// (MRRC).
+// LLVM says "instruction requires: armv5te"
+#[cfg(any(target_feature = "v5te", doc))]
+#[inline]
+#[rustc_legacy_const_generics(0, 1, 2)]
+#[cfg_attr(test, assert_instr(mrrc, COPROC = 15, OPC1 = 1, CRM = 14))]
+pub unsafe fn __arm_mrrc<const COPROC: i32, const OPC1: i32, const CRM: i32>() -> u64 {
+    static_assert_imm4!(COPROC);
+    static_assert_imm4!(OPC1);
+    static_assert_imm4!(CRM);
+    let (lo, hi) = arm_mrrc(COPROC, OPC1, CRM);
+    (hi as u64) << 32 | lo as u64
+}
+
+// The CP15 registers only exist on the A and R profiles.
+#[cfg(all(test, target_feature = "v6", not(target_feature = "mclass")))]
+mod tests {
+    use crate::core_arch::arm::*;
+
+    // TPIDRURW, the PL0 Read/Write Software Thread ID Register, is one of the
+    // few CP15 registers that can be written from user mode.
+    #[test]
+    fn test_arm_mcr() {
+        unsafe {
+            let old = __arm_mrc::<15, 0, 13, 0, 2>();
+            __arm_mcr::<15, 0, 13, 0, 2>(0x89ab_cdef);
+            let r = __arm_mrc::<15, 0, 13, 0, 2>();
+            __arm_mcr::<15, 0, 13, 0, 2>(old);
+            assert_eq!(r, 0x89ab_cdef);
+        }
+    }
+
+    #[test]
+    fn test_arm_mrc() {
+        unsafe {
+            assert_eq!(__arm_mrc::<15, 0, 13, 0, 2>(), __rsr(TPIDRURW));
+        }
+    }
+
+    // CNTVCT, the Counter-timer Virtual Count register, which Linux lets
+    // user mode read.
+    #[cfg(target_feature = "v7")]
+    #[test]
+    fn test_arm_mrrc() {
+        unsafe {
+            let a = __arm_mrrc::<15, 1, 14>();
+            let b = __rsr64(CNTVCT);
+            let c = __arm_mrrc::<15, 1, 14>();
+            assert!(a <= b && b <= c);
+        }
+    }
+}
@@ -2,6 +2,15 @@
 //!
 //! - Section 8.3 "16-bit multiplications"
 //!
+//! # The Q flag
+//!
+//! The saturating intrinsics set the Q flag of the APSR when they saturate,
+//! and the accumulating multiplications set it when the accumulation
+//! overflows, which wraps around instead of saturating. The flag is sticky:
+//! nothing here clears it. The compiler doesn't know that the intrinsics set
+//! it though, and is free to reorder or remove them, so the flag can't be
+//! relied upon to find out whether saturation occurred.
+//!
 //! Intrinsics that could live here:
 //!
 //! - \[x\] __smulbb
@@ -303,6 +312,8 @@ mod tests {
             assert_eq!(super::__qadd(-10, 60), 50);
             assert_eq!(super::__qadd(i32::MAX, 10), i32::MAX);
             assert_eq!(super::__qadd(i32::MIN, -10), i32::MIN);
+            assert_eq!(super::__qadd(0x7fff_ffff, 1), i32::MAX);
+            assert_eq!(super::__qadd(i32::MAX, i32::MIN), -1);
         }
     }
 
@@ -312,16 +323,21 @@ mod tests {
             assert_eq!(super::__qsub(10, 60), -50);
             assert_eq!(super::__qsub(i32::MAX, -10), i32::MAX);
             assert_eq!(super::__qsub(i32::MIN, 10), i32::MIN);
+            assert_eq!(super::__qsub(0, i32::MIN), i32::MAX);
         }
     }
 
+    #[test]
     fn qdbl() {
         unsafe {
             assert_eq!(super::__qdbl(10), 20);
             assert_eq!(super::__qdbl(i32::MAX), i32::MAX);
+            assert_eq!(super::__qdbl(0x4000_0000), i32::MAX);
+            assert_eq!(super::__qdbl(i32::MIN), i32::MIN);
         }
     }
 
+    #[test]
     fn smlabb() {
         unsafe {
             let a = i16x2::new(10, 20);
@@ -329,9 +345,14 @@ mod tests {
             let c = 50;
             let r = (10 * 30) + c;
             assert_eq!(super::__smlabb(transmute(a), transmute(b), c), r);
+            // The accumulation wraps around instead of saturating.
+            let a = i16x2::new(i16::MIN, 0);
+            let r = super::__smlabb(transmute(a), transmute(a), 0x4000_0000);
+            assert_eq!(r, i32::MIN);
         }
     }
 
+    #[test]
     fn smlabt() {
         unsafe {
             let a = i16x2::new(10, 20);
@@ -342,16 +363,18 @@ mod tests {
         }
     }
 
+    #[test]
     fn smlatb() {
         unsafe {
             let a = i16x2::new(10, 20);
             let b = i16x2::new(30, 40);
             let c = 50;
             let r = (20 * 30) + c;
-            assert_eq!(super::__smlabt(transmute(a), transmute(b), c), r);
+            assert_eq!(super::__smlatb(transmute(a), transmute(b), c), r);
         }
     }
 
+    #[test]
     fn smlatt() {
         unsafe {
             let a = i16x2::new(10, 20);
@@ -362,6 +385,7 @@ mod tests {
         }
     }
 
+    #[test]
     fn smlawb() {
         unsafe {
             let a: i32 = 10;
@@ -372,6 +396,7 @@ mod tests {
         }
     }
 
+    #[test]
     fn smlawt() {
         unsafe {
             let a: i32 = 10;
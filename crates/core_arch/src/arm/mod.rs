@@ -62,6 +62,12 @@ mod simd32;
 ))]
 pub use self::simd32::*;
 
+// Every profile but v6-M and v8-M Baseline has coprocessor instructions.
+#[cfg(any(target_feature = "v7", not(target_feature = "mclass"), doc))]
+mod coproc;
+#[cfg(any(target_feature = "v7", not(target_feature = "mclass"), doc))]
+pub use self::coproc::*;
+
 #[cfg(any(target_feature = "v7", doc))]
 mod v7;
 #[cfg(any(target_feature = "v7", doc))]
@@ -70,6 +76,11 @@ pub use self::v7::*;
 mod ex;
 pub use self::ex::*;
 
+// Armv8.1-M. Many of the intrinsics have the same names as NEON ones, so
+// they're only available from their module.
+#[cfg(any(target_feature = "mve", doc))]
+pub mod mve;
+
 pub use crate::core_arch::arm_shared::*;
 
 #[cfg(test)]
@@ -0,0 +1,2367 @@
+//! M-profile Vector Extension (MVE) intrinsics
+//!
+//! MVE, also known as Helium, is the vector extension of Armv8.1-M. It uses
+//! the 128-bit vector types of NEON, and many of its intrinsics have the same
+//! names as NEON ones, so they're in this module instead of in `core::arch::arm`
+//! itself. The integer intrinsics need the `mve` target feature and the
+//! floating-point ones `mve.fp`.
+//!
+//! Most MVE instructions can be predicated by an [`mve_pred16_t`], which has
+//! one bit for each byte of a vector, starting with the least significant bit
+//! for the first byte. The `_m` forms of the intrinsics take the inactive
+//! elements of the result from their first argument and in the `_x` forms
+//! they're unspecified. The `_z` forms of the loads zero the inactive elements
+//! and the `_p` forms of the stores don't write them, and neither accesses the
+//! memory of the inactive elements, so together with the predicates returned by
+//! [`vctp8q`] and friends they can handle the tails of buffers.
+//!
+//! The reference is the "M-profile Vector Extension (MVE) intrinsics" section
+//! of [ACLE][acle].
+//!
+//! [acle]: https://github.com/ARM-software/acle
+
+pub use crate::core_arch::arm_shared::{
+    float32x4_t, int16x8_t, int32x4_t, int8x16_t, uint16x8_t, uint32x4_t, uint8x16_t,
+};
+
+use crate::{
+    core_arch::{simd::*, simd_llvm::*},
+    mem::transmute,
+    ptr,
+};
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// MVE predicate, with one bit for each byte of a vector.
+#[allow(non_camel_case_types)]
+pub type mve_pred16_t = u16;
+
+// The vector loads and stores only need the alignment of the elements.
+#[repr(C, packed(2))]
+struct Align2<T>(T);
+#[repr(C, packed(4))]
+struct Align4<T>(T);
+
+#[repr(simd)]
+#[derive(Copy, Clone)]
+#[allow(non_camel_case_types)]
+#[rustfmt::skip]
+struct ptrx16(
+    *const u8, *const u8, *const u8, *const u8, *const u8, *const u8, *const u8, *const u8,
+    *const u8, *const u8, *const u8, *const u8, *const u8, *const u8, *const u8, *const u8,
+);
+
+#[repr(simd)]
+#[derive(Copy, Clone)]
+#[allow(non_camel_case_types)]
+#[rustfmt::skip]
+struct mutptrx16(
+    *mut u8, *mut u8, *mut u8, *mut u8, *mut u8, *mut u8, *mut u8, *mut u8,
+    *mut u8, *mut u8, *mut u8, *mut u8, *mut u8, *mut u8, *mut u8, *mut u8,
+);
+
+// The addresses of the bytes of the vector at `base`.
+#[inline(always)]
+#[rustfmt::skip]
+fn addresses(base: *const u8) -> ptrx16 {
+    let b = |i| base.wrapping_add(i);
+    ptrx16(
+        b(0), b(1), b(2), b(3), b(4), b(5), b(6), b(7),
+        b(8), b(9), b(10), b(11), b(12), b(13), b(14), b(15),
+    )
+}
+
+// Turns a predicate into a mask for `simd_gather` and `simd_scatter`.
+#[inline(always)]
+unsafe fn mask(p: mve_pred16_t) -> i8x16 {
+    simd_select_bitmask(p, i8x16::splat(-1), i8x16::splat(0))
+}
+
+// The predicated loads and stores access the active bytes one at a time,
+// since LLVM doesn't turn masked gathers and scatters of consecutive bytes
+// into `VLDRB` and `VSTRB` in a `VPST` block yet.
+#[inline(always)]
+unsafe fn load_z(base: *const u8, p: mve_pred16_t) -> u8x16 {
+    simd_gather(u8x16::splat(0), addresses(base), mask(p))
+}
+
+#[inline(always)]
+unsafe fn store_p(base: *mut u8, value: u8x16, p: mve_pred16_t) {
+    let ptrs: mutptrx16 = transmute(addresses(base));
+    simd_scatter(value, ptrs, mask(p))
+}
+
+// Takes the active bytes from `a` and the others from `inactive`.
+macro_rules! merge {
+    ($p:expr, $a:expr, $inactive:expr) => {{
+        let a: u8x16 = transmute($a);
+        let inactive: u8x16 = transmute($inactive);
+        transmute(simd_select_bitmask($p, a, inactive))
+    }};
+}
+
+/// Vector load of bytes
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vldrb.u8"))]
+pub unsafe fn vldrbq_s8(base: *const i8) -> int8x16_t {
+    ptr::read_unaligned(base as *const int8x16_t)
+}
+
+/// Predicated vector load of bytes, with the inactive elements zeroed
+#[inline]
+#[target_feature(enable = "mve")]
+pub unsafe fn vldrbq_z_s8(base: *const i8, p: mve_pred16_t) -> int8x16_t {
+    transmute(load_z(base as *const u8, p))
+}
+
+/// Vector load of bytes
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vldrb.u8"))]
+pub unsafe fn vldrbq_u8(base: *const u8) -> uint8x16_t {
+    ptr::read_unaligned(base as *const uint8x16_t)
+}
+
+/// Predicated vector load of bytes, with the inactive elements zeroed
+#[inline]
+#[target_feature(enable = "mve")]
+pub unsafe fn vldrbq_z_u8(base: *const u8, p: mve_pred16_t) -> uint8x16_t {
+    transmute(load_z(base as *const u8, p))
+}
+
+/// Vector load of halfwords
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vldrh.u16"))]
+pub unsafe fn vldrhq_s16(base: *const i16) -> int16x8_t {
+    ptr::read(base as *const Align2<int16x8_t>).0
+}
+
+/// Predicated vector load of halfwords, with the inactive elements zeroed
+#[inline]
+#[target_feature(enable = "mve")]
+pub unsafe fn vldrhq_z_s16(base: *const i16, p: mve_pred16_t) -> int16x8_t {
+    transmute(load_z(base as *const u8, p))
+}
+
+/// Vector load of halfwords
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vldrh.u16"))]
+pub unsafe fn vldrhq_u16(base: *const u16) -> uint16x8_t {
+    ptr::read(base as *const Align2<uint16x8_t>).0
+}
+
+/// Predicated vector load of halfwords, with the inactive elements zeroed
+#[inline]
+#[target_feature(enable = "mve")]
+pub unsafe fn vldrhq_z_u16(base: *const u16, p: mve_pred16_t) -> uint16x8_t {
+    transmute(load_z(base as *const u8, p))
+}
+
+/// Vector load of words
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vldrw.u32"))]
+pub unsafe fn vldrwq_s32(base: *const i32) -> int32x4_t {
+    ptr::read(base as *const Align4<int32x4_t>).0
+}
+
+/// Predicated vector load of words, with the inactive elements zeroed
+#[inline]
+#[target_feature(enable = "mve")]
+pub unsafe fn vldrwq_z_s32(base: *const i32, p: mve_pred16_t) -> int32x4_t {
+    transmute(load_z(base as *const u8, p))
+}
+
+/// Vector load of words
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vldrw.u32"))]
+pub unsafe fn vldrwq_u32(base: *const u32) -> uint32x4_t {
+    ptr::read(base as *const Align4<uint32x4_t>).0
+}
+
+/// Predicated vector load of words, with the inactive elements zeroed
+#[inline]
+#[target_feature(enable = "mve")]
+pub unsafe fn vldrwq_z_u32(base: *const u32, p: mve_pred16_t) -> uint32x4_t {
+    transmute(load_z(base as *const u8, p))
+}
+
+/// Vector load of words
+#[inline]
+#[target_feature(enable = "mve.fp")]
+#[cfg_attr(test, assert_instr("vldrw.u32"))]
+pub unsafe fn vldrwq_f32(base: *const f32) -> float32x4_t {
+    ptr::read(base as *const Align4<float32x4_t>).0
+}
+
+/// Predicated vector load of words, with the inactive elements zeroed
+#[inline]
+#[target_feature(enable = "mve.fp")]
+pub unsafe fn vldrwq_z_f32(base: *const f32, p: mve_pred16_t) -> float32x4_t {
+    transmute(load_z(base as *const u8, p))
+}
+
+/// Vector store of bytes
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vstrb.8"))]
+pub unsafe fn vstrbq_s8(base: *mut i8, value: int8x16_t) {
+    ptr::write_unaligned(base as *mut int8x16_t, value)
+}
+
+/// Predicated vector store of bytes, which only writes the active elements
+#[inline]
+#[target_feature(enable = "mve")]
+pub unsafe fn vstrbq_p_s8(base: *mut i8, value: int8x16_t, p: mve_pred16_t) {
+    store_p(base as *mut u8, transmute(value), p)
+}
+
+/// Vector store of bytes
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vstrb.8"))]
+pub unsafe fn vstrbq_u8(base: *mut u8, value: uint8x16_t) {
+    ptr::write_unaligned(base as *mut uint8x16_t, value)
+}
+
+/// Predicated vector store of bytes, which only writes the active elements
+#[inline]
+#[target_feature(enable = "mve")]
+pub unsafe fn vstrbq_p_u8(base: *mut u8, value: uint8x16_t, p: mve_pred16_t) {
+    store_p(base as *mut u8, transmute(value), p)
+}
+
+/// Vector store of halfwords
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vstrh.16"))]
+pub unsafe fn vstrhq_s16(base: *mut i16, value: int16x8_t) {
+    ptr::write(base as *mut Align2<int16x8_t>, Align2(value))
+}
+
+/// Predicated vector store of halfwords, which only writes the active elements
+#[inline]
+#[target_feature(enable = "mve")]
+pub unsafe fn vstrhq_p_s16(base: *mut i16, value: int16x8_t, p: mve_pred16_t) {
+    store_p(base as *mut u8, transmute(value), p)
+}
+
+/// Vector store of halfwords
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vstrh.16"))]
+pub unsafe fn vstrhq_u16(base: *mut u16, value: uint16x8_t) {
+    ptr::write(base as *mut Align2<uint16x8_t>, Align2(value))
+}
+
+/// Predicated vector store of halfwords, which only writes the active elements
+#[inline]
+#[target_feature(enable = "mve")]
+pub unsafe fn vstrhq_p_u16(base: *mut u16, value: uint16x8_t, p: mve_pred16_t) {
+    store_p(base as *mut u8, transmute(value), p)
+}
+
+/// Vector store of words
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vstrw.32"))]
+pub unsafe fn vstrwq_s32(base: *mut i32, value: int32x4_t) {
+    ptr::write(base as *mut Align4<int32x4_t>, Align4(value))
+}
+
+/// Predicated vector store of words, which only writes the active elements
+#[inline]
+#[target_feature(enable = "mve")]
+pub unsafe fn vstrwq_p_s32(base: *mut i32, value: int32x4_t, p: mve_pred16_t) {
+    store_p(base as *mut u8, transmute(value), p)
+}
+
+/// Vector store of words
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vstrw.32"))]
+pub unsafe fn vstrwq_u32(base: *mut u32, value: uint32x4_t) {
+    ptr::write(base as *mut Align4<uint32x4_t>, Align4(value))
+}
+
+/// Predicated vector store of words, which only writes the active elements
+#[inline]
+#[target_feature(enable = "mve")]
+pub unsafe fn vstrwq_p_u32(base: *mut u32, value: uint32x4_t, p: mve_pred16_t) {
+    store_p(base as *mut u8, transmute(value), p)
+}
+
+/// Vector store of words
+#[inline]
+#[target_feature(enable = "mve.fp")]
+#[cfg_attr(test, assert_instr("vstrw.32"))]
+pub unsafe fn vstrwq_f32(base: *mut f32, value: float32x4_t) {
+    ptr::write(base as *mut Align4<float32x4_t>, Align4(value))
+}
+
+/// Predicated vector store of words, which only writes the active elements
+#[inline]
+#[target_feature(enable = "mve.fp")]
+pub unsafe fn vstrwq_p_f32(base: *mut f32, value: float32x4_t, p: mve_pred16_t) {
+    store_p(base as *mut u8, transmute(value), p)
+}
+
+/// Vector add
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vadd.i8"))]
+pub unsafe fn vaddq_s8(a: int8x16_t, b: int8x16_t) -> int8x16_t {
+    simd_add(a, b)
+}
+
+/// Predicated vector add, with the inactive elements taken from `inactive`
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vaddt.i8"))]
+pub unsafe fn vaddq_m_s8(
+    inactive: int8x16_t,
+    a: int8x16_t,
+    b: int8x16_t,
+    p: mve_pred16_t,
+) -> int8x16_t {
+    merge!(p, simd_add(a, b), inactive)
+}
+
+/// Predicated vector add, with unspecified inactive elements
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vadd.i8"))]
+pub unsafe fn vaddq_x_s8(a: int8x16_t, b: int8x16_t, _p: mve_pred16_t) -> int8x16_t {
+    simd_add(a, b)
+}
+
+/// Vector add
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vadd.i8"))]
+pub unsafe fn vaddq_u8(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
+    simd_add(a, b)
+}
+
+/// Predicated vector add, with the inactive elements taken from `inactive`
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vaddt.i8"))]
+pub unsafe fn vaddq_m_u8(
+    inactive: uint8x16_t,
+    a: uint8x16_t,
+    b: uint8x16_t,
+    p: mve_pred16_t,
+) -> uint8x16_t {
+    merge!(p, simd_add(a, b), inactive)
+}
+
+/// Predicated vector add, with unspecified inactive elements
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vadd.i8"))]
+pub unsafe fn vaddq_x_u8(a: uint8x16_t, b: uint8x16_t, _p: mve_pred16_t) -> uint8x16_t {
+    simd_add(a, b)
+}
+
+/// Vector add
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vadd.i16"))]
+pub unsafe fn vaddq_s16(a: int16x8_t, b: int16x8_t) -> int16x8_t {
+    simd_add(a, b)
+}
+
+/// Predicated vector add, with the inactive elements taken from `inactive`
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vpsel"))]
+pub unsafe fn vaddq_m_s16(
+    inactive: int16x8_t,
+    a: int16x8_t,
+    b: int16x8_t,
+    p: mve_pred16_t,
+) -> int16x8_t {
+    merge!(p, simd_add(a, b), inactive)
+}
+
+/// Predicated vector add, with unspecified inactive elements
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vadd.i16"))]
+pub unsafe fn vaddq_x_s16(a: int16x8_t, b: int16x8_t, _p: mve_pred16_t) -> int16x8_t {
+    simd_add(a, b)
+}
+
+/// Vector add
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vadd.i16"))]
+pub unsafe fn vaddq_u16(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t {
+    simd_add(a, b)
+}
+
+/// Predicated vector add, with the inactive elements taken from `inactive`
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vpsel"))]
+pub unsafe fn vaddq_m_u16(
+    inactive: uint16x8_t,
+    a: uint16x8_t,
+    b: uint16x8_t,
+    p: mve_pred16_t,
+) -> uint16x8_t {
+    merge!(p, simd_add(a, b), inactive)
+}
+
+/// Predicated vector add, with unspecified inactive elements
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vadd.i16"))]
+pub unsafe fn vaddq_x_u16(a: uint16x8_t, b: uint16x8_t, _p: mve_pred16_t) -> uint16x8_t {
+    simd_add(a, b)
+}
+
+/// Vector add
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vadd.i32"))]
+pub unsafe fn vaddq_s32(a: int32x4_t, b: int32x4_t) -> int32x4_t {
+    simd_add(a, b)
+}
+
+/// Predicated vector add, with the inactive elements taken from `inactive`
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vpsel"))]
+pub unsafe fn vaddq_m_s32(
+    inactive: int32x4_t,
+    a: int32x4_t,
+    b: int32x4_t,
+    p: mve_pred16_t,
+) -> int32x4_t {
+    merge!(p, simd_add(a, b), inactive)
+}
+
+/// Predicated vector add, with unspecified inactive elements
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vadd.i32"))]
+pub unsafe fn vaddq_x_s32(a: int32x4_t, b: int32x4_t, _p: mve_pred16_t) -> int32x4_t {
+    simd_add(a, b)
+}
+
+/// Vector add
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vadd.i32"))]
+pub unsafe fn vaddq_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
+    simd_add(a, b)
+}
+
+/// Predicated vector add, with the inactive elements taken from `inactive`
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vpsel"))]
+pub unsafe fn vaddq_m_u32(
+    inactive: uint32x4_t,
+    a: uint32x4_t,
+    b: uint32x4_t,
+    p: mve_pred16_t,
+) -> uint32x4_t {
+    merge!(p, simd_add(a, b), inactive)
+}
+
+/// Predicated vector add, with unspecified inactive elements
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vadd.i32"))]
+pub unsafe fn vaddq_x_u32(a: uint32x4_t, b: uint32x4_t, _p: mve_pred16_t) -> uint32x4_t {
+    simd_add(a, b)
+}
+
+/// Vector add
+#[inline]
+#[target_feature(enable = "mve.fp")]
+#[cfg_attr(test, assert_instr("vadd.f32"))]
+pub unsafe fn vaddq_f32(a: float32x4_t, b: float32x4_t) -> float32x4_t {
+    simd_add(a, b)
+}
+
+/// Predicated vector add, with the inactive elements taken from `inactive`
+#[inline]
+#[target_feature(enable = "mve.fp")]
+#[cfg_attr(test, assert_instr("vpsel"))]
+pub unsafe fn vaddq_m_f32(
+    inactive: float32x4_t,
+    a: float32x4_t,
+    b: float32x4_t,
+    p: mve_pred16_t,
+) -> float32x4_t {
+    merge!(p, simd_add(a, b), inactive)
+}
+
+/// Predicated vector add, with unspecified inactive elements
+#[inline]
+#[target_feature(enable = "mve.fp")]
+#[cfg_attr(test, assert_instr("vadd.f32"))]
+pub unsafe fn vaddq_x_f32(a: float32x4_t, b: float32x4_t, _p: mve_pred16_t) -> float32x4_t {
+    simd_add(a, b)
+}
+
+/// Vector subtract
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vsub.i8"))]
+pub unsafe fn vsubq_s8(a: int8x16_t, b: int8x16_t) -> int8x16_t {
+    simd_sub(a, b)
+}
+
+/// Predicated vector subtract, with the inactive elements taken from `inactive`
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vsubt.i8"))]
+pub unsafe fn vsubq_m_s8(
+    inactive: int8x16_t,
+    a: int8x16_t,
+    b: int8x16_t,
+    p: mve_pred16_t,
+) -> int8x16_t {
+    merge!(p, simd_sub(a, b), inactive)
+}
+
+/// Predicated vector subtract, with unspecified inactive elements
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vsub.i8"))]
+pub unsafe fn vsubq_x_s8(a: int8x16_t, b: int8x16_t, _p: mve_pred16_t) -> int8x16_t {
+    simd_sub(a, b)
+}
+
+/// Vector subtract
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vsub.i8"))]
+pub unsafe fn vsubq_u8(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
+    simd_sub(a, b)
+}
+
+/// Predicated vector subtract, with the inactive elements taken from `inactive`
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vsubt.i8"))]
+pub unsafe fn vsubq_m_u8(
+    inactive: uint8x16_t,
+    a: uint8x16_t,
+    b: uint8x16_t,
+    p: mve_pred16_t,
+) -> uint8x16_t {
+    merge!(p, simd_sub(a, b), inactive)
+}
+
+/// Predicated vector subtract, with unspecified inactive elements
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vsub.i8"))]
+pub unsafe fn vsubq_x_u8(a: uint8x16_t, b: uint8x16_t, _p: mve_pred16_t) -> uint8x16_t {
+    simd_sub(a, b)
+}
+
+/// Vector subtract
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vsub.i16"))]
+pub unsafe fn vsubq_s16(a: int16x8_t, b: int16x8_t) -> int16x8_t {
+    simd_sub(a, b)
+}
+
+/// Predicated vector subtract, with the inactive elements taken from `inactive`
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vpsel"))]
+pub unsafe fn vsubq_m_s16(
+    inactive: int16x8_t,
+    a: int16x8_t,
+    b: int16x8_t,
+    p: mve_pred16_t,
+) -> int16x8_t {
+    merge!(p, simd_sub(a, b), inactive)
+}
+
+/// Predicated vector subtract, with unspecified inactive elements
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vsub.i16"))]
+pub unsafe fn vsubq_x_s16(a: int16x8_t, b: int16x8_t, _p: mve_pred16_t) -> int16x8_t {
+    simd_sub(a, b)
+}
+
+/// Vector subtract
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vsub.i16"))]
+pub unsafe fn vsubq_u16(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t {
+    simd_sub(a, b)
+}
+
+/// Predicated vector subtract, with the inactive elements taken from `inactive`
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vpsel"))]
+pub unsafe fn vsubq_m_u16(
+    inactive: uint16x8_t,
+    a: uint16x8_t,
+    b: uint16x8_t,
+    p: mve_pred16_t,
+) -> uint16x8_t {
+    merge!(p, simd_sub(a, b), inactive)
+}
+
+/// Predicated vector subtract, with unspecified inactive elements
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vsub.i16"))]
+pub unsafe fn vsubq_x_u16(a: uint16x8_t, b: uint16x8_t, _p: mve_pred16_t) -> uint16x8_t {
+    simd_sub(a, b)
+}
+
+/// Vector subtract
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vsub.i32"))]
+pub unsafe fn vsubq_s32(a: int32x4_t, b: int32x4_t) -> int32x4_t {
+    simd_sub(a, b)
+}
+
+/// Predicated vector subtract, with the inactive elements taken from `inactive`
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vpsel"))]
+pub unsafe fn vsubq_m_s32(
+    inactive: int32x4_t,
+    a: int32x4_t,
+    b: int32x4_t,
+    p: mve_pred16_t,
+) -> int32x4_t {
+    merge!(p, simd_sub(a, b), inactive)
+}
+
+/// Predicated vector subtract, with unspecified inactive elements
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vsub.i32"))]
+pub unsafe fn vsubq_x_s32(a: int32x4_t, b: int32x4_t, _p: mve_pred16_t) -> int32x4_t {
+    simd_sub(a, b)
+}
+
+/// Vector subtract
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vsub.i32"))]
+pub unsafe fn vsubq_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
+    simd_sub(a, b)
+}
+
+/// Predicated vector subtract, with the inactive elements taken from `inactive`
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vpsel"))]
+pub unsafe fn vsubq_m_u32(
+    inactive: uint32x4_t,
+    a: uint32x4_t,
+    b: uint32x4_t,
+    p: mve_pred16_t,
+) -> uint32x4_t {
+    merge!(p, simd_sub(a, b), inactive)
+}
+
+/// Predicated vector subtract, with unspecified inactive elements
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vsub.i32"))]
+pub unsafe fn vsubq_x_u32(a: uint32x4_t, b: uint32x4_t, _p: mve_pred16_t) -> uint32x4_t {
+    simd_sub(a, b)
+}
+
+/// Vector subtract
+#[inline]
+#[target_feature(enable = "mve.fp")]
+#[cfg_attr(test, assert_instr("vsub.f32"))]
+pub unsafe fn vsubq_f32(a: float32x4_t, b: float32x4_t) -> float32x4_t {
+    simd_sub(a, b)
+}
+
+/// Predicated vector subtract, with the inactive elements taken from `inactive`
+#[inline]
+#[target_feature(enable = "mve.fp")]
+#[cfg_attr(test, assert_instr("vpsel"))]
+pub unsafe fn vsubq_m_f32(
+    inactive: float32x4_t,
+    a: float32x4_t,
+    b: float32x4_t,
+    p: mve_pred16_t,
+) -> float32x4_t {
+    merge!(p, simd_sub(a, b), inactive)
+}
+
+/// Predicated vector subtract, with unspecified inactive elements
+#[inline]
+#[target_feature(enable = "mve.fp")]
+#[cfg_attr(test, assert_instr("vsub.f32"))]
+pub unsafe fn vsubq_x_f32(a: float32x4_t, b: float32x4_t, _p: mve_pred16_t) -> float32x4_t {
+    simd_sub(a, b)
+}
+
+/// Vector multiply
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vmul.i8"))]
+pub unsafe fn vmulq_s8(a: int8x16_t, b: int8x16_t) -> int8x16_t {
+    simd_mul(a, b)
+}
+
+/// Predicated vector multiply, with the inactive elements taken from `inactive`
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vmult.i8"))]
+pub unsafe fn vmulq_m_s8(
+    inactive: int8x16_t,
+    a: int8x16_t,
+    b: int8x16_t,
+    p: mve_pred16_t,
+) -> int8x16_t {
+    merge!(p, simd_mul(a, b), inactive)
+}
+
+/// Predicated vector multiply, with unspecified inactive elements
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vmul.i8"))]
+pub unsafe fn vmulq_x_s8(a: int8x16_t, b: int8x16_t, _p: mve_pred16_t) -> int8x16_t {
+    simd_mul(a, b)
+}
+
+/// Vector multiply
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vmul.i8"))]
+pub unsafe fn vmulq_u8(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
+    simd_mul(a, b)
+}
+
+/// Predicated vector multiply, with the inactive elements taken from `inactive`
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vmult.i8"))]
+pub unsafe fn vmulq_m_u8(
+    inactive: uint8x16_t,
+    a: uint8x16_t,
+    b: uint8x16_t,
+    p: mve_pred16_t,
+) -> uint8x16_t {
+    merge!(p, simd_mul(a, b), inactive)
+}
+
+/// Predicated vector multiply, with unspecified inactive elements
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vmul.i8"))]
+pub unsafe fn vmulq_x_u8(a: uint8x16_t, b: uint8x16_t, _p: mve_pred16_t) -> uint8x16_t {
+    simd_mul(a, b)
+}
+
+/// Vector multiply
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vmul.i16"))]
+pub unsafe fn vmulq_s16(a: int16x8_t, b: int16x8_t) -> int16x8_t {
+    simd_mul(a, b)
+}
+
+/// Predicated vector multiply, with the inactive elements taken from `inactive`
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vpsel"))]
+pub unsafe fn vmulq_m_s16(
+    inactive: int16x8_t,
+    a: int16x8_t,
+    b: int16x8_t,
+    p: mve_pred16_t,
+) -> int16x8_t {
+    merge!(p, simd_mul(a, b), inactive)
+}
+
+/// Predicated vector multiply, with unspecified inactive elements
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vmul.i16"))]
+pub unsafe fn vmulq_x_s16(a: int16x8_t, b: int16x8_t, _p: mve_pred16_t) -> int16x8_t {
+    simd_mul(a, b)
+}
+
+/// Vector multiply
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vmul.i16"))]
+pub unsafe fn vmulq_u16(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t {
+    simd_mul(a, b)
+}
+
+/// Predicated vector multiply, with the inactive elements taken from `inactive`
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vpsel"))]
+pub unsafe fn vmulq_m_u16(
+    inactive: uint16x8_t,
+    a: uint16x8_t,
+    b: uint16x8_t,
+    p: mve_pred16_t,
+) -> uint16x8_t {
+    merge!(p, simd_mul(a, b), inactive)
+}
+
+/// Predicated vector multiply, with unspecified inactive elements
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vmul.i16"))]
+pub unsafe fn vmulq_x_u16(a: uint16x8_t, b: uint16x8_t, _p: mve_pred16_t) -> uint16x8_t {
+    simd_mul(a, b)
+}
+
+/// Vector multiply
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vmul.i32"))]
+pub unsafe fn vmulq_s32(a: int32x4_t, b: int32x4_t) -> int32x4_t {
+    simd_mul(a, b)
+}
+
+/// Predicated vector multiply, with the inactive elements taken from `inactive`
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vpsel"))]
+pub unsafe fn vmulq_m_s32(
+    inactive: int32x4_t,
+    a: int32x4_t,
+    b: int32x4_t,
+    p: mve_pred16_t,
+) -> int32x4_t {
+    merge!(p, simd_mul(a, b), inactive)
+}
+
+/// Predicated vector multiply, with unspecified inactive elements
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vmul.i32"))]
+pub unsafe fn vmulq_x_s32(a: int32x4_t, b: int32x4_t, _p: mve_pred16_t) -> int32x4_t {
+    simd_mul(a, b)
+}
+
+/// Vector multiply
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vmul.i32"))]
+pub unsafe fn vmulq_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
+    simd_mul(a, b)
+}
+
+/// Predicated vector multiply, with the inactive elements taken from `inactive`
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vpsel"))]
+pub unsafe fn vmulq_m_u32(
+    inactive: uint32x4_t,
+    a: uint32x4_t,
+    b: uint32x4_t,
+    p: mve_pred16_t,
+) -> uint32x4_t {
+    merge!(p, simd_mul(a, b), inactive)
+}
+
+/// Predicated vector multiply, with unspecified inactive elements
+#[inline]
+#[target_feature(enable = "mve")]
+#[cfg_attr(test, assert_instr("vmul.i32"))]
+pub unsafe fn vmulq_x_u32(a: uint32x4_t, b: uint32x4_t, _p: mve_pred16_t) -> uint32x4_t {
+    simd_mul(a, b)
+}
+
+/// Vector multiply
+#[inline]
+#[target_feature(enable = "mve.fp")]
+#[cfg_attr(test, assert_instr("vmul.f32"))]
+pub unsafe fn vmulq_f32(a: float32x4_t, b: float32x4_t) -> float32x4_t {
+    simd_mul(a, b)
+}
+
+/// Predicated vector multiply, with the inactive elements taken from `inactive`
+#[inline]
+#[target_feature(enable = "mve.fp")]
+#[cfg_attr(test, assert_instr("vpsel"))]
+pub unsafe fn vmulq_m_f32(
+    inactive: float32x4_t,
+    a: float32x4_t,
+    b: float32x4_t,
+    p: mve_pred16_t,
+) -> float32x4_t {
+    merge!(p, simd_mul(a, b), inactive)
+}
+
+/// Predicated vector multiply, with unspecified inactive elements
+#[inline]
+#[target_feature(enable = "mve.fp")]
+#[cfg_attr(test, assert_instr("vmul.f32"))]
+pub unsafe fn vmulq_x_f32(a: float32x4_t, b: float32x4_t, _p: mve_pred16_t) -> float32x4_t {
+    simd_mul(a, b)
+}
+
+/// Creates a predicate with the first `a` 8-bit elements active
+#[inline]
+#[target_feature(enable = "mve")]
+pub unsafe fn vctp8q(a: u32) -> mve_pred16_t {
+    if a >= 16 {
+        !0
+    } else {
+        (1 << a) - 1
+    }
+}
+
+/// Creates a predicate with the first `a` 16-bit elements active
+#[inline]
+#[target_feature(enable = "mve")]
+pub unsafe fn vctp16q(a: u32) -> mve_pred16_t {
+    if a >= 8 {
+        !0
+    } else {
+        (1 << (a * 2)) - 1
+    }
+}
+
+/// Creates a predicate with the first `a` 32-bit elements active
+#[inline]
+#[target_feature(enable = "mve")]
+pub unsafe fn vctp32q(a: u32) -> mve_pred16_t {
+    if a >= 4 {
+        !0
+    } else {
+        (1 << (a * 4)) - 1
+    }
+}
+
+/// Inverts a predicate
+#[inline]
+#[target_feature(enable = "mve")]
+pub unsafe fn vpnot(a: mve_pred16_t) -> mve_pred16_t {
+    !a
+}
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::{arm::mve::*, simd::*};
+    use std::mem::transmute;
+
+    #[test]
+    fn test_vldrbq_s8() {
+        unsafe {
+            let a: [i8; 17] = [
+                -7, -4, -1, 2, 5, 8, 11, 14, 17, 20, 23, 26, 29, 32, 35, 38, 41,
+            ];
+            let e = i8x16::new(-4, -1, 2, 5, 8, 11, 14, 17, 20, 23, 26, 29, 32, 35, 38, 41);
+            let r: i8x16 = transmute(vldrbq_s8(a[1..].as_ptr()));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn test_vldrbq_z_s8() {
+        unsafe {
+            // Nothing past the end of `a` is read.
+            let a: [i8; 3] = [-7, -4, -1];
+            let e = i8x16::new(-7, -4, -1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0);
+            let r: i8x16 = transmute(vldrbq_z_s8(a.as_ptr(), vctp8q(3)));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn test_vstrbq_s8() {
+        unsafe {
+            let mut r = [0; 17];
+            vstrbq_s8(
+                r[1..].as_mut_ptr(),
+                transmute(i8x16::new(
+                    -4, -1, 2, 5, 8, 11, 14, 17, 20, 23, 26, 29, 32, 35, 38, 41,
+                )),
+            );
+            assert_eq!(
+                r,
+                [0, -4, -1, 2, 5, 8, 11, 14, 17, 20, 23, 26, 29, 32, 35, 38, 41]
+            );
+        }
+    }
+
+    #[test]
+    fn test_vstrbq_p_s8() {
+        unsafe {
+            let mut r = [0; 16];
+            vstrbq_p_s8(
+                r.as_mut_ptr(),
+                transmute(i8x16::new(
+                    -4, -1, 2, 5, 8, 11, 14, 17, 20, 23, 26, 29, 32, 35, 38, 41,
+                )),
+                vctp8q(3),
+            );
+            assert_eq!(r, [-4, -1, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+        }
+    }
+
+    #[test]
+    fn test_vldrbq_u8() {
+        unsafe {
+            let a: [u8; 17] = [
+                1, 4, 7, 10, 13, 16, 19, 22, 25, 28, 31, 34, 37, 40, 43, 46, 49,
+            ];
+            let e = u8x16::new(4, 7, 10, 13, 16, 19, 22, 25, 28, 31, 34, 37, 40, 43, 46, 49);
+            let r: u8x16 = transmute(vldrbq_u8(a[1..].as_ptr()));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn test_vldrbq_z_u8() {
+        unsafe {
+            // Nothing past the end of `a` is read.
+            let a: [u8; 3] = [1, 4, 7];
+            let e = u8x16::new(1, 4, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0);
+            let r: u8x16 = transmute(vldrbq_z_u8(a.as_ptr(), vctp8q(3)));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn test_vstrbq_u8() {
+        unsafe {
+            let mut r = [0; 17];
+            vstrbq_u8(
+                r[1..].as_mut_ptr(),
+                transmute(u8x16::new(
+                    4, 7, 10, 13, 16, 19, 22, 25, 28, 31, 34, 37, 40, 43, 46, 49,
+                )),
+            );
+            assert_eq!(
+                r,
+                [0, 4, 7, 10, 13, 16, 19, 22, 25, 28, 31, 34, 37, 40, 43, 46, 49]
+            );
+        }
+    }
+
+    #[test]
+    fn test_vstrbq_p_u8() {
+        unsafe {
+            let mut r = [0; 16];
+            vstrbq_p_u8(
+                r.as_mut_ptr(),
+                transmute(u8x16::new(
+                    4, 7, 10, 13, 16, 19, 22, 25, 28, 31, 34, 37, 40, 43, 46, 49,
+                )),
+                vctp8q(3),
+            );
+            assert_eq!(r, [4, 7, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+        }
+    }
+
+    #[test]
+    fn test_vldrhq_s16() {
+        unsafe {
+            let a: [i16; 9] = [-7, -4, -1, 2, 5, 8, 11, 14, 17];
+            let e = i16x8::new(-4, -1, 2, 5, 8, 11, 14, 17);
+            let r: i16x8 = transmute(vldrhq_s16(a[1..].as_ptr()));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn test_vldrhq_z_s16() {
+        unsafe {
+            // Nothing past the end of `a` is read.
+            let a: [i16; 3] = [-7, -4, -1];
+            let e = i16x8::new(-7, -4, -1, 0, 0, 0, 0, 0);
+            let r: i16x8 = transmute(vldrhq_z_s16(a.as_ptr(), vctp16q(3)));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn test_vstrhq_s16() {
+        unsafe {
+            let mut r = [0; 9];
+            vstrhq_s16(
+                r[1..].as_mut_ptr(),
+                transmute(i16x8::new(-4, -1, 2, 5, 8, 11, 14, 17)),
+            );
+            assert_eq!(r, [0, -4, -1, 2, 5, 8, 11, 14, 17]);
+        }
+    }
+
+    #[test]
+    fn test_vstrhq_p_s16() {
+        unsafe {
+            let mut r = [0; 8];
+            vstrhq_p_s16(
+                r.as_mut_ptr(),
+                transmute(i16x8::new(-4, -1, 2, 5, 8, 11, 14, 17)),
+                vctp16q(3),
+            );
+            assert_eq!(r, [-4, -1, 2, 0, 0, 0, 0, 0]);
+        }
+    }
+
+    #[test]
+    fn test_vldrhq_u16() {
+        unsafe {
+            let a: [u16; 9] = [1, 4, 7, 10, 13, 16, 19, 22, 25];
+            let e = u16x8::new(4, 7, 10, 13, 16, 19, 22, 25);
+            let r: u16x8 = transmute(vldrhq_u16(a[1..].as_ptr()));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn test_vldrhq_z_u16() {
+        unsafe {
+            // Nothing past the end of `a` is read.
+            let a: [u16; 3] = [1, 4, 7];
+            let e = u16x8::new(1, 4, 7, 0, 0, 0, 0, 0);
+            let r: u16x8 = transmute(vldrhq_z_u16(a.as_ptr(), vctp16q(3)));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn test_vstrhq_u16() {
+        unsafe {
+            let mut r = [0; 9];
+            vstrhq_u16(
+                r[1..].as_mut_ptr(),
+                transmute(u16x8::new(4, 7, 10, 13, 16, 19, 22, 25)),
+            );
+            assert_eq!(r, [0, 4, 7, 10, 13, 16, 19, 22, 25]);
+        }
+    }
+
+    #[test]
+    fn test_vstrhq_p_u16() {
+        unsafe {
+            let mut r = [0; 8];
+            vstrhq_p_u16(
+                r.as_mut_ptr(),
+                transmute(u16x8::new(4, 7, 10, 13, 16, 19, 22, 25)),
+                vctp16q(3),
+            );
+            assert_eq!(r, [4, 7, 10, 0, 0, 0, 0, 0]);
+        }
+    }
+
+    #[test]
+    fn test_vldrwq_s32() {
+        unsafe {
+            let a: [i32; 5] = [-7, -4, -1, 2, 5];
+            let e = i32x4::new(-4, -1, 2, 5);
+            let r: i32x4 = transmute(vldrwq_s32(a[1..].as_ptr()));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn test_vldrwq_z_s32() {
+        unsafe {
+            // Nothing past the end of `a` is read.
+            let a: [i32; 3] = [-7, -4, -1];
+            let e = i32x4::new(-7, -4, -1, 0);
+            let r: i32x4 = transmute(vldrwq_z_s32(a.as_ptr(), vctp32q(3)));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn test_vstrwq_s32() {
+        unsafe {
+            let mut r = [0; 5];
+            vstrwq_s32(r[1..].as_mut_ptr(), transmute(i32x4::new(-4, -1, 2, 5)));
+            assert_eq!(r, [0, -4, -1, 2, 5]);
+        }
+    }
+
+    #[test]
+    fn test_vstrwq_p_s32() {
+        unsafe {
+            let mut r = [0; 4];
+            vstrwq_p_s32(
+                r.as_mut_ptr(),
+                transmute(i32x4::new(-4, -1, 2, 5)),
+                vctp32q(3),
+            );
+            assert_eq!(r, [-4, -1, 2, 0]);
+        }
+    }
+
+    #[test]
+    fn test_vldrwq_u32() {
+        unsafe {
+            let a: [u32; 5] = [1, 4, 7, 10, 13];
+            let e = u32x4::new(4, 7, 10, 13);
+            let r: u32x4 = transmute(vldrwq_u32(a[1..].as_ptr()));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn test_vldrwq_z_u32() {
+        unsafe {
+            // Nothing past the end of `a` is read.
+            let a: [u32; 3] = [1, 4, 7];
+            let e = u32x4::new(1, 4, 7, 0);
+            let r: u32x4 = transmute(vldrwq_z_u32(a.as_ptr(), vctp32q(3)));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn test_vstrwq_u32() {
+        unsafe {
+            let mut r = [0; 5];
+            vstrwq_u32(r[1..].as_mut_ptr(), transmute(u32x4::new(4, 7, 10, 13)));
+            assert_eq!(r, [0, 4, 7, 10, 13]);
+        }
+    }
+
+    #[test]
+    fn test_vstrwq_p_u32() {
+        unsafe {
+            let mut r = [0; 4];
+            vstrwq_p_u32(
+                r.as_mut_ptr(),
+                transmute(u32x4::new(4, 7, 10, 13)),
+                vctp32q(3),
+            );
+            assert_eq!(r, [4, 7, 10, 0]);
+        }
+    }
+
+    #[test]
+    fn test_vldrwq_f32() {
+        unsafe {
+            let a: [f32; 5] = [-7.0, -4.0, -1.0, 2.0, 5.0];
+            let e = f32x4::new(-4.0, -1.0, 2.0, 5.0);
+            let r: f32x4 = transmute(vldrwq_f32(a[1..].as_ptr()));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn test_vldrwq_z_f32() {
+        unsafe {
+            // Nothing past the end of `a` is read.
+            let a: [f32; 3] = [-7.0, -4.0, -1.0];
+            let e = f32x4::new(-7.0, -4.0, -1.0, 0.0);
+            let r: f32x4 = transmute(vldrwq_z_f32(a.as_ptr(), vctp32q(3)));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn test_vstrwq_f32() {
+        unsafe {
+            let mut r = [0.0; 5];
+            vstrwq_f32(
+                r[1..].as_mut_ptr(),
+                transmute(f32x4::new(-4.0, -1.0, 2.0, 5.0)),
+            );
+            assert_eq!(r, [0.0, -4.0, -1.0, 2.0, 5.0]);
+        }
+    }
+
+    #[test]
+    fn test_vstrwq_p_f32() {
+        unsafe {
+            let mut r = [0.0; 4];
+            vstrwq_p_f32(
+                r.as_mut_ptr(),
+                transmute(f32x4::new(-4.0, -1.0, 2.0, 5.0)),
+                vctp32q(3),
+            );
+            assert_eq!(r, [-4.0, -1.0, 2.0, 0.0]);
+        }
+    }
+
+    #[test]
+    fn test_vaddq_s8() {
+        unsafe {
+            let a = i8x16::new(-8, -7, -6, -5, -4, -3, -2, -1, 0, 1, 2, 3, 4, 5, 6, 127);
+            let b = i8x16::new(3, 5, 7, 9, 11, 13, 15, 17, 19, 21, 23, 25, 27, 29, 31, 33);
+            let e = i8x16::new(-5, -2, 1, 4, 7, 10, 13, 16, 19, 22, 25, 28, 31, 34, 37, -96);
+            let r: i8x16 = transmute(vaddq_s8(transmute(a), transmute(b)));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn test_vaddq_m_s8() {
+        unsafe {
+            let inactive = i8x16::new(
+                100, 101, 102, 103, 104, 105, 106, 107, 108, 109, 110, 111, 112, 113, 114, 115,
+            );
+            let a = i8x16::new(-8, -7, -6, -5, -4, -3, -2, -1, 0, 1, 2, 3, 4, 5, 6, 127);
+            let b = i8x16::new(3, 5, 7, 9, 11, 13, 15, 17, 19, 21, 23, 25, 27, 29, 31, 33);
+            let e = i8x16::new(
+                -5, -2, 102, 103, 104, 105, 13, 16, 19, 109, 25, 111, 112, 34, 114, -96,
+            );
+            let p = 0b1010_0101_1100_0011;
+            let r: i8x16 = transmute(vaddq_m_s8(
+                transmute(inactive),
+                transmute(a),
+                transmute(b),
+                p,
+            ));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn test_vaddq_x_s8() {
+        unsafe {
+            let a = i8x16::new(-8, -7, -6, -5, -4, -3, -2, -1, 0, 1, 2, 3, 4, 5, 6, 127);
+            let b = i8x16::new(3, 5, 7, 9, 11, 13, 15, 17, 19, 21, 23, 25, 27, 29, 31, 33);
+            let r: [i8; 16] = transmute(vaddq_x_s8(
+                transmute(a),
+                transmute(b),
+                0b1010_0101_1100_0011,
+            ));
+            // Only the active elements are specified.
+            assert_eq!(r[0], -5);
+            assert_eq!(r[1], -2);
+            assert_eq!(r[6], 13);
+            assert_eq!(r[7], 16);
+            assert_eq!(r[8], 19);
+            assert_eq!(r[10], 25);
+            assert_eq!(r[13], 34);
+            assert_eq!(r[15], -96);
+        }
+    }
+
+    #[test]
+    fn test_vaddq_u8() {
+        unsafe {
+            let a = u8x16::new(8, 7, 6, 5, 4, 3, 2, 1, 0, 1, 2, 3, 4, 5, 6, 255);
+            let b = u8x16::new(3, 5, 7, 9, 11, 13, 15, 17, 19, 21, 23, 25, 27, 29, 31, 33);
+            let e = u8x16::new(
+                11, 12, 13, 14, 15, 16, 17, 18, 19, 22, 25, 28, 31, 34, 37, 32,
+            );
+            let r: u8x16 = transmute(vaddq_u8(transmute(a), transmute(b)));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn test_vaddq_m_u8() {
+        unsafe {
+            let inactive = u8x16::new(
+                100, 101, 102, 103, 104, 105, 106, 107, 108, 109, 110, 111, 112, 113, 114, 115,
+            );
+            let a = u8x16::new(8, 7, 6, 5, 4, 3, 2, 1, 0, 1, 2, 3, 4, 5, 6, 255);
+            let b = u8x16::new(3, 5, 7, 9, 11, 13, 15, 17, 19, 21, 23, 25, 27, 29, 31, 33);
+            let e = u8x16::new(
+                11, 12, 102, 103, 104, 105, 17, 18, 19, 109, 25, 111, 112, 34, 114, 32,
+            );
+            let p = 0b1010_0101_1100_0011;
+            let r: u8x16 = transmute(vaddq_m_u8(
+                transmute(inactive),
+                transmute(a),
+                transmute(b),
+                p,
+            ));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn test_vaddq_x_u8() {
+        unsafe {
+            let a = u8x16::new(8, 7, 6, 5, 4, 3, 2, 1, 0, 1, 2, 3, 4, 5, 6, 255);
+            let b = u8x16::new(3, 5, 7, 9, 11, 13, 15, 17, 19, 21, 23, 25, 27, 29, 31, 33);
+            let r: [u8; 16] = transmute(vaddq_x_u8(
+                transmute(a),
+                transmute(b),
+                0b1010_0101_1100_0011,
+            ));
+            // Only the active elements are specified.
+            assert_eq!(r[0], 11);
+            assert_eq!(r[1], 12);
+            assert_eq!(r[6], 17);
+            assert_eq!(r[7], 18);
+            assert_eq!(r[8], 19);
+            assert_eq!(r[10], 25);
+            assert_eq!(r[13], 34);
+            assert_eq!(r[15], 32);
+        }
+    }
+
+    #[test]
+    fn test_vaddq_s16() {
+        unsafe {
+            let a = i16x8::new(-4, -3, -2, -1, 0, 1, 2, 32767);
+            let b = i16x8::new(3, 5, 7, 9, 11, 13, 15, 17);
+            let e = i16x8::new(-1, 2, 5, 8, 11, 14, 17, -32752);
+            let r: i16x8 = transmute(vaddq_s16(transmute(a), transmute(b)));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn test_vaddq_m_s16() {
+        unsafe {
+            let inactive = i16x8::new(100, 101, 102, 103, 104, 105, 106, 107);
+            let a = i16x8::new(-4, -3, -2, -1, 0, 1, 2, 32767);
+            let b = i16x8::new(3, 5, 7, 9, 11, 13, 15, 17);
+            let e = i16x8::new(100, 2, 102, 8, 11, 105, 17, 107);
+            let p = 0b0011_0011_1100_1100;
+            let r: i16x8 = transmute(vaddq_m_s16(
+                transmute(inactive),
+                transmute(a),
+                transmute(b),
+                p,
+            ));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn test_vaddq_x_s16() {
+        unsafe {
+            let a = i16x8::new(-4, -3, -2, -1, 0, 1, 2, 32767);
+            let b = i16x8::new(3, 5, 7, 9, 11, 13, 15, 17);
+            let r: [i16; 8] = transmute(vaddq_x_s16(
+                transmute(a),
+                transmute(b),
+                0b0011_0011_1100_1100,
+            ));
+            // Only the active elements are specified.
+            assert_eq!(r[1], 2);
+            assert_eq!(r[3], 8);
+            assert_eq!(r[4], 11);
+            assert_eq!(r[6], 17);
+        }
+    }
+
+    #[test]
+    fn test_vaddq_u16() {
+        unsafe {
+            let a = u16x8::new(4, 3, 2, 1, 0, 1, 2, 65535);
+            let b = u16x8::new(3, 5, 7, 9, 11, 13, 15, 17);
+            let e = u16x8::new(7, 8, 9, 10, 11, 14, 17, 16);
+            let r: u16x8 = transmute(vaddq_u16(transmute(a), transmute(b)));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn test_vaddq_m_u16() {
+        unsafe {
+            let inactive = u16x8::new(100, 101, 102, 103, 104, 105, 106, 107);
+            let a = u16x8::new(4, 3, 2, 1, 0, 1, 2, 65535);
+            let b = u16x8::new(3, 5, 7, 9, 11, 13, 15, 17);
+            let e = u16x8::new(100, 8, 102, 10, 11, 105, 17, 107);
+            let p = 0b0011_0011_1100_1100;
+            let r: u16x8 = transmute(vaddq_m_u16(
+                transmute(inactive),
+                transmute(a),
+                transmute(b),
+                p,
+            ));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn test_vaddq_x_u16() {
+        unsafe {
+            let a = u16x8::new(4, 3, 2, 1, 0, 1, 2, 65535);
+            let b = u16x8::new(3, 5, 7, 9, 11, 13, 15, 17);
+            let r: [u16; 8] = transmute(vaddq_x_u16(
+                transmute(a),
+                transmute(b),
+                0b0011_0011_1100_1100,
+            ));
+            // Only the active elements are specified.
+            assert_eq!(r[1], 8);
+            assert_eq!(r[3], 10);
+            assert_eq!(r[4], 11);
+            assert_eq!(r[6], 17);
+        }
+    }
+
+    #[test]
+    fn test_vaddq_s32() {
+        unsafe {
+            let a = i32x4::new(-2, -1, 0, 2147483647);
+            let b = i32x4::new(3, 5, 7, 9);
+            let e = i32x4::new(1, 4, 7, -2147483640);
+            let r: i32x4 = transmute(vaddq_s32(transmute(a), transmute(b)));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn test_vaddq_m_s32() {
+        unsafe {
+            let inactive = i32x4::new(100, 101, 102, 103);
+            let a = i32x4::new(-2, -1, 0, 2147483647);
+            let b = i32x4::new(3, 5, 7, 9);
+            let e = i32x4::new(100, 4, 7, 103);
+            let p = 0b0000_1111_1111_0000;
+            let r: i32x4 = transmute(vaddq_m_s32(
+                transmute(inactive),
+                transmute(a),
+                transmute(b),
+                p,
+            ));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn test_vaddq_x_s32() {
+        unsafe {
+            let a = i32x4::new(-2, -1, 0, 2147483647);
+            let b = i32x4::new(3, 5, 7, 9);
+            let r: [i32; 4] = transmute(vaddq_x_s32(
+                transmute(a),
+                transmute(b),
+                0b0000_1111_1111_0000,
+            ));
+            // Only the active elements are specified.
+            assert_eq!(r[1], 4);
+            assert_eq!(r[2], 7);
+        }
+    }
+
+    #[test]
+    fn test_vaddq_u32() {
+        unsafe {
+            let a = u32x4::new(2, 1, 0, 4294967295);
+            let b = u32x4::new(3, 5, 7, 9);
+            let e = u32x4::new(5, 6, 7, 8);
+            let r: u32x4 = transmute(vaddq_u32(transmute(a), transmute(b)));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn test_vaddq_m_u32() {
+        unsafe {
+            let inactive = u32x4::new(100, 101, 102, 103);
+            let a = u32x4::new(2, 1, 0, 4294967295);
+            let b = u32x4::new(3, 5, 7, 9);
+            let e = u32x4::new(100, 6, 7, 103);
+            let p = 0b0000_1111_1111_0000;
+            let r: u32x4 = transmute(vaddq_m_u32(
+                transmute(inactive),
+                transmute(a),
+                transmute(b),
+                p,
+            ));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn test_vaddq_x_u32() {
+        unsafe {
+            let a = u32x4::new(2, 1, 0, 4294967295);
+            let b = u32x4::new(3, 5, 7, 9);
+            let r: [u32; 4] = transmute(vaddq_x_u32(
+                transmute(a),
+                transmute(b),
+                0b0000_1111_1111_0000,
+            ));
+            // Only the active elements are specified.
+            assert_eq!(r[1], 6);
+            assert_eq!(r[2], 7);
+        }
+    }
+
+    #[test]
+    fn test_vaddq_f32() {
+        unsafe {
+            let a = f32x4::new(1.5, -2.0, 3.25, 4.0);
+            let b = f32x4::new(0.5, 4.0, -1.0, 2.5);
+            let e = f32x4::new(2.0, 2.0, 2.25, 6.5);
+            let r: f32x4 = transmute(vaddq_f32(transmute(a), transmute(b)));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn test_vaddq_m_f32() {
+        unsafe {
+            let inactive = f32x4::new(100.0, 101.0, 102.0, 103.0);
+            let a = f32x4::new(1.5, -2.0, 3.25, 4.0);
+            let b = f32x4::new(0.5, 4.0, -1.0, 2.5);
+            let e = f32x4::new(100.0, 2.0, 2.25, 103.0);
+            let p = 0b0000_1111_1111_0000;
+            let r: f32x4 = transmute(vaddq_m_f32(
+                transmute(inactive),
+                transmute(a),
+                transmute(b),
+                p,
+            ));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn test_vaddq_x_f32() {
+        unsafe {
+            let a = f32x4::new(1.5, -2.0, 3.25, 4.0);
+            let b = f32x4::new(0.5, 4.0, -1.0, 2.5);
+            let r: [f32; 4] = transmute(vaddq_x_f32(
+                transmute(a),
+                transmute(b),
+                0b0000_1111_1111_0000,
+            ));
+            // Only the active elements are specified.
+            assert_eq!(r[1], 2.0);
+            assert_eq!(r[2], 2.25);
+        }
+    }
+
+    #[test]
+    fn test_vsubq_s8() {
+        unsafe {
+            let a = i8x16::new(-8, -7, -6, -5, -4, -3, -2, -1, 0, 1, 2, 3, 4, 5, 6, 127);
+            let b = i8x16::new(3, 5, 7, 9, 11, 13, 15, 17, 19, 21, 23, 25, 27, 29, 31, 33);
+            let e = i8x16::new(
+                -11, -12, -13, -14, -15, -16, -17, -18, -19, -20, -21, -22, -23, -24, -25, 94,
+            );
+            let r: i8x16 = transmute(vsubq_s8(transmute(a), transmute(b)));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn test_vsubq_m_s8() {
+        unsafe {
+            let inactive = i8x16::new(
+                100, 101, 102, 103, 104, 105, 106, 107, 108, 109, 110, 111, 112, 113, 114, 115,
+            );
+            let a = i8x16::new(-8, -7, -6, -5, -4, -3, -2, -1, 0, 1, 2, 3, 4, 5, 6, 127);
+            let b = i8x16::new(3, 5, 7, 9, 11, 13, 15, 17, 19, 21, 23, 25, 27, 29, 31, 33);
+            let e = i8x16::new(
+                -11, -12, 102, 103, 104, 105, -17, -18, -19, 109, -21, 111, 112, -24, 114, 94,
+            );
+            let p = 0b1010_0101_1100_0011;
+            let r: i8x16 = transmute(vsubq_m_s8(
+                transmute(inactive),
+                transmute(a),
+                transmute(b),
+                p,
+            ));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn test_vsubq_x_s8() {
+        unsafe {
+            let a = i8x16::new(-8, -7, -6, -5, -4, -3, -2, -1, 0, 1, 2, 3, 4, 5, 6, 127);
+            let b = i8x16::new(3, 5, 7, 9, 11, 13, 15, 17, 19, 21, 23, 25, 27, 29, 31, 33);
+            let r: [i8; 16] = transmute(vsubq_x_s8(
+                transmute(a),
+                transmute(b),
+                0b1010_0101_1100_0011,
+            ));
+            // Only the active elements are specified.
+            assert_eq!(r[0], -11);
+            assert_eq!(r[1], -12);
+            assert_eq!(r[6], -17);
+            assert_eq!(r[7], -18);
+            assert_eq!(r[8], -19);
+            assert_eq!(r[10], -21);
+            assert_eq!(r[13], -24);
+            assert_eq!(r[15], 94);
+        }
+    }
+
+    #[test]
+    fn test_vsubq_u8() {
+        unsafe {
+            let a = u8x16::new(8, 7, 6, 5, 4, 3, 2, 1, 0, 1, 2, 3, 4, 5, 6, 255);
+            let b = u8x16::new(3, 5, 7, 9, 11, 13, 15, 17, 19, 21, 23, 25, 27, 29, 31, 33);
+            let e = u8x16::new(
+                5, 2, 255, 252, 249, 246, 243, 240, 237, 236, 235, 234, 233, 232, 231, 222,
+            );
+            let r: u8x16 = transmute(vsubq_u8(transmute(a), transmute(b)));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn test_vsubq_m_u8() {
+        unsafe {
+            let inactive = u8x16::new(
+                100, 101, 102, 103, 104, 105, 106, 107, 108, 109, 110, 111, 112, 113, 114, 115,
+            );
+            let a = u8x16::new(8, 7, 6, 5, 4, 3, 2, 1, 0, 1, 2, 3, 4, 5, 6, 255);
+            let b = u8x16::new(3, 5, 7, 9, 11, 13, 15, 17, 19, 21, 23, 25, 27, 29, 31, 33);
+            let e = u8x16::new(
+                5, 2, 102, 103, 104, 105, 243, 240, 237, 109, 235, 111, 112, 232, 114, 222,
+            );
+            let p = 0b1010_0101_1100_0011;
+            let r: u8x16 = transmute(vsubq_m_u8(
+                transmute(inactive),
+                transmute(a),
+                transmute(b),
+                p,
+            ));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn test_vsubq_x_u8() {
+        unsafe {
+            let a = u8x16::new(8, 7, 6, 5, 4, 3, 2, 1, 0, 1, 2, 3, 4, 5, 6, 255);
+            let b = u8x16::new(3, 5, 7, 9, 11, 13, 15, 17, 19, 21, 23, 25, 27, 29, 31, 33);
+            let r: [u8; 16] = transmute(vsubq_x_u8(
+                transmute(a),
+                transmute(b),
+                0b1010_0101_1100_0011,
+            ));
+            // Only the active elements are specified.
+            assert_eq!(r[0], 5);
+            assert_eq!(r[1], 2);
+            assert_eq!(r[6], 243);
+            assert_eq!(r[7], 240);
+            assert_eq!(r[8], 237);
+            assert_eq!(r[10], 235);
+            assert_eq!(r[13], 232);
+            assert_eq!(r[15], 222);
+        }
+    }
+
+    #[test]
+    fn test_vsubq_s16() {
+        unsafe {
+            let a = i16x8::new(-4, -3, -2, -1, 0, 1, 2, 32767);
+            let b = i16x8::new(3, 5, 7, 9, 11, 13, 15, 17);
+            let e = i16x8::new(-7, -8, -9, -10, -11, -12, -13, 32750);
+            let r: i16x8 = transmute(vsubq_s16(transmute(a), transmute(b)));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn test_vsubq_m_s16() {
+        unsafe {
+            let inactive = i16x8::new(100, 101, 102, 103, 104, 105, 106, 107);
+            let a = i16x8::new(-4, -3, -2, -1, 0, 1, 2, 32767);
+            let b = i16x8::new(3, 5, 7, 9, 11, 13, 15, 17);
+            let e = i16x8::new(100, -8, 102, -10, -11, 105, -13, 107);
+            let p = 0b0011_0011_1100_1100;
+            let r: i16x8 = transmute(vsubq_m_s16(
+                transmute(inactive),
+                transmute(a),
+                transmute(b),
+                p,
+            ));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn test_vsubq_x_s16() {
+        unsafe {
+            let a = i16x8::new(-4, -3, -2, -1, 0, 1, 2, 32767);
+            let b = i16x8::new(3, 5, 7, 9, 11, 13, 15, 17);
+            let r: [i16; 8] = transmute(vsubq_x_s16(
+                transmute(a),
+                transmute(b),
+                0b0011_0011_1100_1100,
+            ));
+            // Only the active elements are specified.
+            assert_eq!(r[1], -8);
+            assert_eq!(r[3], -10);
+            assert_eq!(r[4], -11);
+            assert_eq!(r[6], -13);
+        }
+    }
+
+    #[test]
+    fn test_vsubq_u16() {
+        unsafe {
+            let a = u16x8::new(4, 3, 2, 1, 0, 1, 2, 65535);
+            let b = u16x8::new(3, 5, 7, 9, 11, 13, 15, 17);
+            let e = u16x8::new(1, 65534, 65531, 65528, 65525, 65524, 65523, 65518);
+            let r: u16x8 = transmute(vsubq_u16(transmute(a), transmute(b)));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn test_vsubq_m_u16() {
+        unsafe {
+            let inactive = u16x8::new(100, 101, 102, 103, 104, 105, 106, 107);
+            let a = u16x8::new(4, 3, 2, 1, 0, 1, 2, 65535);
+            let b = u16x8::new(3, 5, 7, 9, 11, 13, 15, 17);
+            let e = u16x8::new(100, 65534, 102, 65528, 65525, 105, 65523, 107);
+            let p = 0b0011_0011_1100_1100;
+            let r: u16x8 = transmute(vsubq_m_u16(
+                transmute(inactive),
+                transmute(a),
+                transmute(b),
+                p,
+            ));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn test_vsubq_x_u16() {
+        unsafe {
+            let a = u16x8::new(4, 3, 2, 1, 0, 1, 2, 65535);
+            let b = u16x8::new(3, 5, 7, 9, 11, 13, 15, 17);
+            let r: [u16; 8] = transmute(vsubq_x_u16(
+                transmute(a),
+                transmute(b),
+                0b0011_0011_1100_1100,
+            ));
+            // Only the active elements are specified.
+            assert_eq!(r[1], 65534);
+            assert_eq!(r[3], 65528);
+            assert_eq!(r[4], 65525);
+            assert_eq!(r[6], 65523);
+        }
+    }
+
+    #[test]
+    fn test_vsubq_s32() {
+        unsafe {
+            let a = i32x4::new(-2, -1, 0, 2147483647);
+            let b = i32x4::new(3, 5, 7, 9);
+            let e = i32x4::new(-5, -6, -7, 2147483638);
+            let r: i32x4 = transmute(vsubq_s32(transmute(a), transmute(b)));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn test_vsubq_m_s32() {
+        unsafe {
+            let inactive = i32x4::new(100, 101, 102, 103);
+            let a = i32x4::new(-2, -1, 0, 2147483647);
+            let b = i32x4::new(3, 5, 7, 9);
+            let e = i32x4::new(100, -6, -7, 103);
+            let p = 0b0000_1111_1111_0000;
+            let r: i32x4 = transmute(vsubq_m_s32(
+                transmute(inactive),
+                transmute(a),
+                transmute(b),
+                p,
+            ));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn test_vsubq_x_s32() {
+        unsafe {
+            let a = i32x4::new(-2, -1, 0, 2147483647);
+            let b = i32x4::new(3, 5, 7, 9);
+            let r: [i32; 4] = transmute(vsubq_x_s32(
+                transmute(a),
+                transmute(b),
+                0b0000_1111_1111_0000,
+            ));
+            // Only the active elements are specified.
+            assert_eq!(r[1], -6);
+            assert_eq!(r[2], -7);
+        }
+    }
+
+    #[test]
+    fn test_vsubq_u32() {
+        unsafe {
+            let a = u32x4::new(2, 1, 0, 4294967295);
+            let b = u32x4::new(3, 5, 7, 9);
+            let e = u32x4::new(4294967295, 4294967292, 4294967289, 4294967286);
+            let r: u32x4 = transmute(vsubq_u32(transmute(a), transmute(b)));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn test_vsubq_m_u32() {
+        unsafe {
+            let inactive = u32x4::new(100, 101, 102, 103);
+            let a = u32x4::new(2, 1, 0, 4294967295);
+            let b = u32x4::new(3, 5, 7, 9);
+            let e = u32x4::new(100, 4294967292, 4294967289, 103);
+            let p = 0b0000_1111_1111_0000;
+            let r: u32x4 = transmute(vsubq_m_u32(
+                transmute(inactive),
+                transmute(a),
+                transmute(b),
+                p,
+            ));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn test_vsubq_x_u32() {
+        unsafe {
+            let a = u32x4::new(2, 1, 0, 4294967295);
+            let b = u32x4::new(3, 5, 7, 9);
+            let r: [u32; 4] = transmute(vsubq_x_u32(
+                transmute(a),
+                transmute(b),
+                0b0000_1111_1111_0000,
+            ));
+            // Only the active elements are specified.
+            assert_eq!(r[1], 4294967292);
+            assert_eq!(r[2], 4294967289);
+        }
+    }
+
+    #[test]
+    fn test_vsubq_f32() {
+        unsafe {
+            let a = f32x4::new(1.5, -2.0, 3.25, 4.0);
+            let b = f32x4::new(0.5, 4.0, -1.0, 2.5);
+            let e = f32x4::new(1.0, -6.0, 4.25, 1.5);
+            let r: f32x4 = transmute(vsubq_f32(transmute(a), transmute(b)));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn test_vsubq_m_f32() {
+        unsafe {
+            let inactive = f32x4::new(100.0, 101.0, 102.0, 103.0);
+            let a = f32x4::new(1.5, -2.0, 3.25, 4.0);
+            let b = f32x4::new(0.5, 4.0, -1.0, 2.5);
+            let e = f32x4::new(100.0, -6.0, 4.25, 103.0);
+            let p = 0b0000_1111_1111_0000;
+            let r: f32x4 = transmute(vsubq_m_f32(
+                transmute(inactive),
+                transmute(a),
+                transmute(b),
+                p,
+            ));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn test_vsubq_x_f32() {
+        unsafe {
+            let a = f32x4::new(1.5, -2.0, 3.25, 4.0);
+            let b = f32x4::new(0.5, 4.0, -1.0, 2.5);
+            let r: [f32; 4] = transmute(vsubq_x_f32(
+                transmute(a),
+                transmute(b),
+                0b0000_1111_1111_0000,
+            ));
+            // Only the active elements are specified.
+            assert_eq!(r[1], -6.0);
+            assert_eq!(r[2], 4.25);
+        }
+    }
+
+    #[test]
+    fn test_vmulq_s8() {
+        unsafe {
+            let a = i8x16::new(-8, -7, -6, -5, -4, -3, -2, -1, 0, 1, 2, 3, 4, 5, 6, 127);
+            let b = i8x16::new(3, 5, 7, 9, 11, 13, 15, 17, 19, 21, 23, 25, 27, 29, 31, 33);
+            let e = i8x16::new(
+                -24, -35, -42, -45, -44, -39, -30, -17, 0, 21, 46, 75, 108, -111, -70, 95,
+            );
+            let r: i8x16 = transmute(vmulq_s8(transmute(a), transmute(b)));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn test_vmulq_m_s8() {
+        unsafe {
+            let inactive = i8x16::new(
+                100, 101, 102, 103, 104, 105, 106, 107, 108, 109, 110, 111, 112, 113, 114, 115,
+            );
+            let a = i8x16::new(-8, -7, -6, -5, -4, -3, -2, -1, 0, 1, 2, 3, 4, 5, 6, 127);
+            let b = i8x16::new(3, 5, 7, 9, 11, 13, 15, 17, 19, 21, 23, 25, 27, 29, 31, 33);
+            let e = i8x16::new(
+                -24, -35, 102, 103, 104, 105, -30, -17, 0, 109, 46, 111, 112, -111, 114, 95,
+            );
+            let p = 0b1010_0101_1100_0011;
+            let r: i8x16 = transmute(vmulq_m_s8(
+                transmute(inactive),
+                transmute(a),
+                transmute(b),
+                p,
+            ));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn test_vmulq_x_s8() {
+        unsafe {
+            let a = i8x16::new(-8, -7, -6, -5, -4, -3, -2, -1, 0, 1, 2, 3, 4, 5, 6, 127);
+            let b = i8x16::new(3, 5, 7, 9, 11, 13, 15, 17, 19, 21, 23, 25, 27, 29, 31, 33);
+            let r: [i8; 16] = transmute(vmulq_x_s8(
+                transmute(a),
+                transmute(b),
+                0b1010_0101_1100_0011,
+            ));
+            // Only the active elements are specified.
+            assert_eq!(r[0], -24);
+            assert_eq!(r[1], -35);
+            assert_eq!(r[6], -30);
+            assert_eq!(r[7], -17);
+            assert_eq!(r[8], 0);
+            assert_eq!(r[10], 46);
+            assert_eq!(r[13], -111);
+            assert_eq!(r[15], 95);
+        }
+    }
+
+    #[test]
+    fn test_vmulq_u8() {
+        unsafe {
+            let a = u8x16::new(8, 7, 6, 5, 4, 3, 2, 1, 0, 1, 2, 3, 4, 5, 6, 255);
+            let b = u8x16::new(3, 5, 7, 9, 11, 13, 15, 17, 19, 21, 23, 25, 27, 29, 31, 33);
+            let e = u8x16::new(
+                24, 35, 42, 45, 44, 39, 30, 17, 0, 21, 46, 75, 108, 145, 186, 223,
+            );
+            let r: u8x16 = transmute(vmulq_u8(transmute(a), transmute(b)));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn test_vmulq_m_u8() {
+        unsafe {
+            let inactive = u8x16::new(
+                100, 101, 102, 103, 104, 105, 106, 107, 108, 109, 110, 111, 112, 113, 114, 115,
+            );
+            let a = u8x16::new(8, 7, 6, 5, 4, 3, 2, 1, 0, 1, 2, 3, 4, 5, 6, 255);
+            let b = u8x16::new(3, 5, 7, 9, 11, 13, 15, 17, 19, 21, 23, 25, 27, 29, 31, 33);
+            let e = u8x16::new(
+                24, 35, 102, 103, 104, 105, 30, 17, 0, 109, 46, 111, 112, 145, 114, 223,
+            );
+            let p = 0b1010_0101_1100_0011;
+            let r: u8x16 = transmute(vmulq_m_u8(
+                transmute(inactive),
+                transmute(a),
+                transmute(b),
+                p,
+            ));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn test_vmulq_x_u8() {
+        unsafe {
+            let a = u8x16::new(8, 7, 6, 5, 4, 3, 2, 1, 0, 1, 2, 3, 4, 5, 6, 255);
+            let b = u8x16::new(3, 5, 7, 9, 11, 13, 15, 17, 19, 21, 23, 25, 27, 29, 31, 33);
+            let r: [u8; 16] = transmute(vmulq_x_u8(
+                transmute(a),
+                transmute(b),
+                0b1010_0101_1100_0011,
+            ));
+            // Only the active elements are specified.
+            assert_eq!(r[0], 24);
+            assert_eq!(r[1], 35);
+            assert_eq!(r[6], 30);
+            assert_eq!(r[7], 17);
+            assert_eq!(r[8], 0);
+            assert_eq!(r[10], 46);
+            assert_eq!(r[13], 145);
+            assert_eq!(r[15], 223);
+        }
+    }
+
+    #[test]
+    fn test_vmulq_s16() {
+        unsafe {
+            let a = i16x8::new(-4, -3, -2, -1, 0, 1, 2, 32767);
+            let b = i16x8::new(3, 5, 7, 9, 11, 13, 15, 17);
+            let e = i16x8::new(-12, -15, -14, -9, 0, 13, 30, 32751);
+            let r: i16x8 = transmute(vmulq_s16(transmute(a), transmute(b)));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn test_vmulq_m_s16() {
+        unsafe {
+            let inactive = i16x8::new(100, 101, 102, 103, 104, 105, 106, 107);
+            let a = i16x8::new(-4, -3, -2, -1, 0, 1, 2, 32767);
+            let b = i16x8::new(3, 5, 7, 9, 11, 13, 15, 17);
+            let e = i16x8::new(100, -15, 102, -9, 0, 105, 30, 107);
+            let p = 0b0011_0011_1100_1100;
+            let r: i16x8 = transmute(vmulq_m_s16(
+                transmute(inactive),
+                transmute(a),
+                transmute(b),
+                p,
+            ));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn test_vmulq_x_s16() {
+        unsafe {
+            let a = i16x8::new(-4, -3, -2, -1, 0, 1, 2, 32767);
+            let b = i16x8::new(3, 5, 7, 9, 11, 13, 15, 17);
+            let r: [i16; 8] = transmute(vmulq_x_s16(
+                transmute(a),
+                transmute(b),
+                0b0011_0011_1100_1100,
+            ));
+            // Only the active elements are specified.
+            assert_eq!(r[1], -15);
+            assert_eq!(r[3], -9);
+            assert_eq!(r[4], 0);
+            assert_eq!(r[6], 30);
+        }
+    }
+
+    #[test]
+    fn test_vmulq_u16() {
+        unsafe {
+            let a = u16x8::new(4, 3, 2, 1, 0, 1, 2, 65535);
+            let b = u16x8::new(3, 5, 7, 9, 11, 13, 15, 17);
+            let e = u16x8::new(12, 15, 14, 9, 0, 13, 30, 65519);
+            let r: u16x8 = transmute(vmulq_u16(transmute(a), transmute(b)));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn test_vmulq_m_u16() {
+        unsafe {
+            let inactive = u16x8::new(100, 101, 102, 103, 104, 105, 106, 107);
+            let a = u16x8::new(4, 3, 2, 1, 0, 1, 2, 65535);
+            let b = u16x8::new(3, 5, 7, 9, 11, 13, 15, 17);
+            let e = u16x8::new(100, 15, 102, 9, 0, 105, 30, 107);
+            let p = 0b0011_0011_1100_1100;
+            let r: u16x8 = transmute(vmulq_m_u16(
+                transmute(inactive),
+                transmute(a),
+                transmute(b),
+                p,
+            ));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn test_vmulq_x_u16() {
+        unsafe {
+            let a = u16x8::new(4, 3, 2, 1, 0, 1, 2, 65535);
+            let b = u16x8::new(3, 5, 7, 9, 11, 13, 15, 17);
+            let r: [u16; 8] = transmute(vmulq_x_u16(
+                transmute(a),
+                transmute(b),
+                0b0011_0011_1100_1100,
+            ));
+            // Only the active elements are specified.
+            assert_eq!(r[1], 15);
+            assert_eq!(r[3], 9);
+            assert_eq!(r[4], 0);
+            assert_eq!(r[6], 30);
+        }
+    }
+
+    #[test]
+    fn test_vmulq_s32() {
+        unsafe {
+            let a = i32x4::new(-2, -1, 0, 2147483647);
+            let b = i32x4::new(3, 5, 7, 9);
+            let e = i32x4::new(-6, -5, 0, 2147483639);
+            let r: i32x4 = transmute(vmulq_s32(transmute(a), transmute(b)));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn test_vmulq_m_s32() {
+        unsafe {
+            let inactive = i32x4::new(100, 101, 102, 103);
+            let a = i32x4::new(-2, -1, 0, 2147483647);
+            let b = i32x4::new(3, 5, 7, 9);
+            let e = i32x4::new(100, -5, 0, 103);
+            let p = 0b0000_1111_1111_0000;
+            let r: i32x4 = transmute(vmulq_m_s32(
+                transmute(inactive),
+                transmute(a),
+                transmute(b),
+                p,
+            ));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn test_vmulq_x_s32() {
+        unsafe {
+            let a = i32x4::new(-2, -1, 0, 2147483647);
+            let b = i32x4::new(3, 5, 7, 9);
+            let r: [i32; 4] = transmute(vmulq_x_s32(
+                transmute(a),
+                transmute(b),
+                0b0000_1111_1111_0000,
+            ));
+            // Only the active elements are specified.
+            assert_eq!(r[1], -5);
+            assert_eq!(r[2], 0);
+        }
+    }
+
+    #[test]
+    fn test_vmulq_u32() {
+        unsafe {
+            let a = u32x4::new(2, 1, 0, 4294967295);
+            let b = u32x4::new(3, 5, 7, 9);
+            let e = u32x4::new(6, 5, 0, 4294967287);
+            let r: u32x4 = transmute(vmulq_u32(transmute(a), transmute(b)));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn test_vmulq_m_u32() {
+        unsafe {
+            let inactive = u32x4::new(100, 101, 102, 103);
+            let a = u32x4::new(2, 1, 0, 4294967295);
+            let b = u32x4::new(3, 5, 7, 9);
+            let e = u32x4::new(100, 5, 0, 103);
+            let p = 0b0000_1111_1111_0000;
+            let r: u32x4 = transmute(vmulq_m_u32(
+                transmute(inactive),
+                transmute(a),
+                transmute(b),
+                p,
+            ));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn test_vmulq_x_u32() {
+        unsafe {
+            let a = u32x4::new(2, 1, 0, 4294967295);
+            let b = u32x4::new(3, 5, 7, 9);
+            let r: [u32; 4] = transmute(vmulq_x_u32(
+                transmute(a),
+                transmute(b),
+                0b0000_1111_1111_0000,
+            ));
+            // Only the active elements are specified.
+            assert_eq!(r[1], 5);
+            assert_eq!(r[2], 0);
+        }
+    }
+
+    #[test]
+    fn test_vmulq_f32() {
+        unsafe {
+            let a = f32x4::new(1.5, -2.0, 3.25, 4.0);
+            let b = f32x4::new(0.5, 4.0, -1.0, 2.5);
+            let e = f32x4::new(0.75, -8.0, -3.25, 10.0);
+            let r: f32x4 = transmute(vmulq_f32(transmute(a), transmute(b)));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn test_vmulq_m_f32() {
+        unsafe {
+            let inactive = f32x4::new(100.0, 101.0, 102.0, 103.0);
+            let a = f32x4::new(1.5, -2.0, 3.25, 4.0);
+            let b = f32x4::new(0.5, 4.0, -1.0, 2.5);
+            let e = f32x4::new(100.0, -8.0, -3.25, 103.0);
+            let p = 0b0000_1111_1111_0000;
+            let r: f32x4 = transmute(vmulq_m_f32(
+                transmute(inactive),
+                transmute(a),
+                transmute(b),
+                p,
+            ));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn test_vmulq_x_f32() {
+        unsafe {
+            let a = f32x4::new(1.5, -2.0, 3.25, 4.0);
+            let b = f32x4::new(0.5, 4.0, -1.0, 2.5);
+            let r: [f32; 4] = transmute(vmulq_x_f32(
+                transmute(a),
+                transmute(b),
+                0b0000_1111_1111_0000,
+            ));
+            // Only the active elements are specified.
+            assert_eq!(r[1], -8.0);
+            assert_eq!(r[2], -3.25);
+        }
+    }
+
+    #[test]
+    fn test_vctp8q() {
+        unsafe {
+            assert_eq!(vctp8q(0), 0);
+            assert_eq!(vctp8q(3), 0b111);
+            assert_eq!(vctp8q(15), 0x7fff);
+            assert_eq!(vctp8q(16), 0xffff);
+            assert_eq!(vctp8q(100), 0xffff);
+        }
+    }
+
+    #[test]
+    fn test_vctp16q() {
+        unsafe {
+            assert_eq!(vctp16q(0), 0);
+            assert_eq!(vctp16q(3), 0b11_1111);
+            assert_eq!(vctp16q(8), 0xffff);
+            assert_eq!(vctp16q(100), 0xffff);
+        }
+    }
+
+    #[test]
+    fn test_vctp32q() {
+        unsafe {
+            assert_eq!(vctp32q(0), 0);
+            assert_eq!(vctp32q(1), 0xf);
+            assert_eq!(vctp32q(3), 0x0fff);
+            assert_eq!(vctp32q(4), 0xffff);
+            assert_eq!(vctp32q(100), 0xffff);
+        }
+    }
+
+    #[test]
+    fn test_vpnot() {
+        unsafe {
+            assert_eq!(vpnot(0x00ff), 0xff00);
+            assert_eq!(vpnot(vctp32q(1)), 0xfff0);
+        }
+    }
+}
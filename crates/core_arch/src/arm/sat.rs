@@ -6,3 +6,64 @@
 //!
 //! - __ssat
 //! - __usat
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+extern "C" {
+    #[link_name = "llvm.arm.ssat"]
+    fn arm_ssat(a: i32, sat: i32) -> i32;
+
+    #[link_name = "llvm.arm.usat"]
+    fn arm_usat(a: i32, sat: i32) -> i32;
+}
+
+/// Signed saturation to `SAT` bits, from 1 to 32.
+///
+/// Saturates `a` to the range -2^(SAT-1) to 2^(SAT-1) - 1 and sets the Q
+/// flag if it saturates.
+#[inline]
+#[rustc_legacy_const_generics(1)]
+#[cfg_attr(test, assert_instr(ssat, SAT = 8))]
+pub unsafe fn __ssat<const SAT: i32>(a: i32) -> i32 {
+    static_assert!(SAT: i32 where SAT >= 1 && SAT <= 32);
+    arm_ssat(a, SAT)
+}
+
+/// Unsigned saturation to `SAT` bits, from 0 to 31.
+///
+/// Saturates the signed `a` to the range 0 to 2^SAT - 1 and sets the Q flag
+/// if it saturates.
+#[inline]
+#[rustc_legacy_const_generics(1)]
+#[cfg_attr(test, assert_instr(usat, SAT = 8))]
+pub unsafe fn __usat<const SAT: i32>(a: i32) -> u32 {
+    static_assert!(SAT: i32 where SAT >= 0 && SAT <= 31);
+    arm_usat(a, SAT) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn ssat() {
+        unsafe {
+            assert_eq!(super::__ssat::<8>(100), 100);
+            assert_eq!(super::__ssat::<8>(128), 127);
+            assert_eq!(super::__ssat::<8>(-129), -128);
+            assert_eq!(super::__ssat::<1>(5), 0);
+            assert_eq!(super::__ssat::<1>(-5), -1);
+            assert_eq!(super::__ssat::<32>(i32::MIN), i32::MIN);
+        }
+    }
+
+    #[test]
+    fn usat() {
+        unsafe {
+            assert_eq!(super::__usat::<8>(100), 100);
+            assert_eq!(super::__usat::<8>(256), 255);
+            assert_eq!(super::__usat::<8>(-1), 0);
+            assert_eq!(super::__usat::<0>(5), 0);
+            assert_eq!(super::__usat::<31>(i32::MAX), i32::MAX as u32);
+        }
+    }
+}
@@ -2,26 +2,38 @@
 //!
 //! - Section 8.5 "32-bit SIMD intrinsics" of ACLE
 //!
+//! # The GE flags
+//!
+//! The parallel additions and subtractions whose documentation says so set
+//! the four GE bits of the APSR, one for each byte of the result or two for
+//! each halfword. A GE bit is set if the exact result of a signed operation
+//! is non-negative, if an unsigned addition carries or if an unsigned
+//! subtraction doesn't borrow. [`__sel`] then takes each byte from its first
+//! operand if its GE bit is set and from the second one otherwise, which for
+//! example selects the larger bytes after [`__usub8`]. The compiler doesn't
+//! track the flags between intrinsics though, so they're only reliable if
+//! nothing in between changes them, which it doesn't guarantee.
+//!
 //! Intrinsics that could live here
 //!
 //! - \[x\] __sel
-//! - \[ \] __ssat16
-//! - \[ \] __usat16
-//! - \[ \] __sxtab16
-//! - \[ \] __sxtb16
-//! - \[ \] __uxtab16
-//! - \[ \] __uxtb16
+//! - \[x\] __ssat16
+//! - \[x\] __usat16
+//! - \[x\] __sxtab16
+//! - \[x\] __sxtb16
+//! - \[x\] __uxtab16
+//! - \[x\] __uxtb16
 //! - \[x\] __qadd8
 //! - \[x\] __qsub8
 //! - \[x\] __sadd8
 //! - \[x\] __shadd8
 //! - \[x\] __shsub8
 //! - \[x\] __ssub8
-//! - \[ \] __uadd8
-//! - \[ \] __uhadd8
-//! - \[ \] __uhsub8
-//! - \[ \] __uqadd8
-//! - \[ \] __uqsub8
+//! - \[x\] __uadd8
+//! - \[x\] __uhadd8
+//! - \[x\] __uhsub8
+//! - \[x\] __uqadd8
+//! - \[x\] __uqsub8
 //! - \[x\] __usub8
 //! - \[x\] __usad8
 //! - \[x\] __usada8
@@ -32,31 +44,31 @@
 //! - \[x\] __sadd16
 //! - \[x\] __sasx
 //! - \[x\] __shadd16
-//! - \[ \] __shasx
-//! - \[ \] __shsax
+//! - \[x\] __shasx
+//! - \[x\] __shsax
 //! - \[x\] __shsub16
-//! - \[ \] __ssax
-//! - \[ \] __ssub16
-//! - \[ \] __uadd16
-//! - \[ \] __uasx
-//! - \[ \] __uhadd16
-//! - \[ \] __uhasx
-//! - \[ \] __uhsax
-//! - \[ \] __uhsub16
-//! - \[ \] __uqadd16
-//! - \[ \] __uqasx
+//! - \[x\] __ssax
+//! - \[x\] __ssub16
+//! - \[x\] __uadd16
+//! - \[x\] __uasx
+//! - \[x\] __uhadd16
+//! - \[x\] __uhasx
+//! - \[x\] __uhsax
+//! - \[x\] __uhsub16
+//! - \[x\] __uqadd16
+//! - \[x\] __uqasx
 //! - \[x\] __uqsax
-//! - \[ \] __uqsub16
-//! - \[ \] __usax
-//! - \[ \] __usub16
+//! - \[x\] __uqsub16
+//! - \[x\] __usax
+//! - \[x\] __usub16
 //! - \[x\] __smlad
-//! - \[ \] __smladx
-//! - \[ \] __smlald
-//! - \[ \] __smlaldx
+//! - \[x\] __smladx
+//! - \[x\] __smlald
+//! - \[x\] __smlaldx
 //! - \[x\] __smlsd
-//! - \[ \] __smlsdx
-//! - \[ \] __smlsld
-//! - \[ \] __smlsldx
+//! - \[x\] __smlsdx
+//! - \[x\] __smlsld
+//! - \[x\] __smlsldx
 //! - \[x\] __smuad
 //! - \[x\] __smuadx
 //! - \[x\] __smusd
@@ -65,7 +77,10 @@
 #[cfg(test)]
 use stdarch_test::assert_instr;
 
-use crate::{core_arch::arm::dsp::int16x2_t, mem::transmute};
+use crate::{
+    core_arch::arm::dsp::{int16x2_t, uint16x2_t},
+    mem::transmute,
+};
 
 types! {
     /// ARM-specific 32-bit wide vector of four packed `i8`.
@@ -149,6 +164,105 @@ extern "C" {
 
     #[link_name = "llvm.arm.usad8"]
     fn arm_usad8(a: i32, b: i32) -> u32;
+
+    #[link_name = "llvm.arm.uadd8"]
+    fn arm_uadd8(a: i32, b: i32) -> i32;
+
+    #[link_name = "llvm.arm.uhadd8"]
+    fn arm_uhadd8(a: i32, b: i32) -> i32;
+
+    #[link_name = "llvm.arm.uhsub8"]
+    fn arm_uhsub8(a: i32, b: i32) -> i32;
+
+    #[link_name = "llvm.arm.uqadd8"]
+    fn arm_uqadd8(a: i32, b: i32) -> i32;
+
+    #[link_name = "llvm.arm.uqsub8"]
+    fn arm_uqsub8(a: i32, b: i32) -> i32;
+
+    #[link_name = "llvm.arm.shasx"]
+    fn arm_shasx(a: i32, b: i32) -> i32;
+
+    #[link_name = "llvm.arm.shsax"]
+    fn arm_shsax(a: i32, b: i32) -> i32;
+
+    #[link_name = "llvm.arm.ssax"]
+    fn arm_ssax(a: i32, b: i32) -> i32;
+
+    #[link_name = "llvm.arm.ssub16"]
+    fn arm_ssub16(a: i32, b: i32) -> i32;
+
+    #[link_name = "llvm.arm.uadd16"]
+    fn arm_uadd16(a: i32, b: i32) -> i32;
+
+    #[link_name = "llvm.arm.uasx"]
+    fn arm_uasx(a: i32, b: i32) -> i32;
+
+    #[link_name = "llvm.arm.uhadd16"]
+    fn arm_uhadd16(a: i32, b: i32) -> i32;
+
+    #[link_name = "llvm.arm.uhasx"]
+    fn arm_uhasx(a: i32, b: i32) -> i32;
+
+    #[link_name = "llvm.arm.uhsax"]
+    fn arm_uhsax(a: i32, b: i32) -> i32;
+
+    #[link_name = "llvm.arm.uhsub16"]
+    fn arm_uhsub16(a: i32, b: i32) -> i32;
+
+    #[link_name = "llvm.arm.uqadd16"]
+    fn arm_uqadd16(a: i32, b: i32) -> i32;
+
+    #[link_name = "llvm.arm.uqasx"]
+    fn arm_uqasx(a: i32, b: i32) -> i32;
+
+    #[link_name = "llvm.arm.uqsax"]
+    fn arm_uqsax(a: i32, b: i32) -> i32;
+
+    #[link_name = "llvm.arm.uqsub16"]
+    fn arm_uqsub16(a: i32, b: i32) -> i32;
+
+    #[link_name = "llvm.arm.usax"]
+    fn arm_usax(a: i32, b: i32) -> i32;
+
+    #[link_name = "llvm.arm.usub16"]
+    fn arm_usub16(a: i32, b: i32) -> i32;
+
+    #[link_name = "llvm.arm.smladx"]
+    fn arm_smladx(a: i32, b: i32, c: i32) -> i32;
+
+    #[link_name = "llvm.arm.smlsdx"]
+    fn arm_smlsdx(a: i32, b: i32, c: i32) -> i32;
+
+    #[link_name = "llvm.arm.smlald"]
+    fn arm_smlald(a: i32, b: i32, c: i64) -> i64;
+
+    #[link_name = "llvm.arm.smlaldx"]
+    fn arm_smlaldx(a: i32, b: i32, c: i64) -> i64;
+
+    #[link_name = "llvm.arm.smlsld"]
+    fn arm_smlsld(a: i32, b: i32, c: i64) -> i64;
+
+    #[link_name = "llvm.arm.smlsldx"]
+    fn arm_smlsldx(a: i32, b: i32, c: i64) -> i64;
+
+    #[link_name = "llvm.arm.sxtb16"]
+    fn arm_sxtb16(a: i32) -> i32;
+
+    #[link_name = "llvm.arm.sxtab16"]
+    fn arm_sxtab16(a: i32, b: i32) -> i32;
+
+    #[link_name = "llvm.arm.uxtb16"]
+    fn arm_uxtb16(a: i32) -> i32;
+
+    #[link_name = "llvm.arm.uxtab16"]
+    fn arm_uxtab16(a: i32, b: i32) -> i32;
+
+    #[link_name = "llvm.arm.ssat16"]
+    fn arm_ssat16(a: i32, sat: i32) -> i32;
+
+    #[link_name = "llvm.arm.usat16"]
+    fn arm_usat16(a: i32, sat: i32) -> i32;
 }
 
 /// Saturating four 8-bit integer additions
@@ -461,9 +575,441 @@ pub unsafe fn __usada8(a: int8x4_t, b: int8x4_t, c: u32) -> u32 {
     __usad8(a, b) + c
 }
 
+/// Unsigned parallel byte-wise addition.
+///
+/// Returns the 8-bit unsigned equivalent of
+///
+/// res\[0\] = a\[0\] + b\[0\]
+/// res\[1\] = a\[1\] + b\[1\]
+/// res\[2\] = a\[2\] + b\[2\]
+/// res\[3\] = a\[3\] + b\[3\]
+///
+/// and the GE bits of the APSR are set.
+#[inline]
+#[cfg_attr(test, assert_instr(uadd8))]
+pub unsafe fn __uadd8(a: uint8x4_t, b: uint8x4_t) -> uint8x4_t {
+    dsp_call!(arm_uadd8, a, b)
+}
+
+/// Unsigned halving parallel byte-wise addition.
+///
+/// Returns the 8-bit unsigned equivalent of
+///
+/// res\[0\] = (a\[0\] + b\[0\]) / 2
+/// res\[1\] = (a\[1\] + b\[1\]) / 2
+/// res\[2\] = (a\[2\] + b\[2\]) / 2
+/// res\[3\] = (a\[3\] + b\[3\]) / 2
+#[inline]
+#[cfg_attr(test, assert_instr(uhadd8))]
+pub unsafe fn __uhadd8(a: uint8x4_t, b: uint8x4_t) -> uint8x4_t {
+    dsp_call!(arm_uhadd8, a, b)
+}
+
+/// Unsigned halving parallel byte-wise subtraction.
+///
+/// Returns the 8-bit unsigned equivalent of
+///
+/// res\[0\] = (a\[0\] - b\[0\]) / 2
+/// res\[1\] = (a\[1\] - b\[1\]) / 2
+/// res\[2\] = (a\[2\] - b\[2\]) / 2
+/// res\[3\] = (a\[3\] - b\[3\]) / 2
+#[inline]
+#[cfg_attr(test, assert_instr(uhsub8))]
+pub unsafe fn __uhsub8(a: uint8x4_t, b: uint8x4_t) -> uint8x4_t {
+    dsp_call!(arm_uhsub8, a, b)
+}
+
+/// Unsigned saturating parallel byte-wise addition.
+///
+/// Returns the 8-bit unsigned saturated equivalent of
+///
+/// res\[0\] = a\[0\] + b\[0\]
+/// res\[1\] = a\[1\] + b\[1\]
+/// res\[2\] = a\[2\] + b\[2\]
+/// res\[3\] = a\[3\] + b\[3\]
+#[inline]
+#[cfg_attr(test, assert_instr(uqadd8))]
+pub unsafe fn __uqadd8(a: uint8x4_t, b: uint8x4_t) -> uint8x4_t {
+    dsp_call!(arm_uqadd8, a, b)
+}
+
+/// Unsigned saturating parallel byte-wise subtraction.
+///
+/// Returns the 8-bit unsigned saturated equivalent of
+///
+/// res\[0\] = a\[0\] - b\[0\]
+/// res\[1\] = a\[1\] - b\[1\]
+/// res\[2\] = a\[2\] - b\[2\]
+/// res\[3\] = a\[3\] - b\[3\]
+#[inline]
+#[cfg_attr(test, assert_instr(uqsub8))]
+pub unsafe fn __uqsub8(a: uint8x4_t, b: uint8x4_t) -> uint8x4_t {
+    dsp_call!(arm_uqsub8, a, b)
+}
+
+/// Signed halving parallel halfword-wise addition and subtraction with exchange.
+///
+/// Returns the 16-bit signed equivalent of
+///
+/// res\[0\] = (a\[0\] - b\[1\]) / 2
+/// res\[1\] = (a\[1\] + b\[0\]) / 2
+#[inline]
+#[cfg_attr(test, assert_instr(shasx))]
+pub unsafe fn __shasx(a: int16x2_t, b: int16x2_t) -> int16x2_t {
+    dsp_call!(arm_shasx, a, b)
+}
+
+/// Signed halving parallel halfword-wise subtraction and addition with exchange.
+///
+/// Returns the 16-bit signed equivalent of
+///
+/// res\[0\] = (a\[0\] + b\[1\]) / 2
+/// res\[1\] = (a\[1\] - b\[0\]) / 2
+#[inline]
+#[cfg_attr(test, assert_instr(shsax))]
+pub unsafe fn __shsax(a: int16x2_t, b: int16x2_t) -> int16x2_t {
+    dsp_call!(arm_shsax, a, b)
+}
+
+/// Signed parallel halfword-wise subtraction and addition with exchange.
+///
+/// Returns the 16-bit signed equivalent of
+///
+/// res\[0\] = a\[0\] + b\[1\]
+/// res\[1\] = a\[1\] - b\[0\]
+///
+/// and the GE bits of the APSR are set.
+#[inline]
+#[cfg_attr(test, assert_instr(ssax))]
+pub unsafe fn __ssax(a: int16x2_t, b: int16x2_t) -> int16x2_t {
+    dsp_call!(arm_ssax, a, b)
+}
+
+/// Signed parallel halfword-wise subtraction.
+///
+/// Returns the 16-bit signed equivalent of
+///
+/// res\[0\] = a\[0\] - b\[0\]
+/// res\[1\] = a\[1\] - b\[1\]
+///
+/// and the GE bits of the APSR are set.
+#[inline]
+#[cfg_attr(test, assert_instr(ssub16))]
+pub unsafe fn __ssub16(a: int16x2_t, b: int16x2_t) -> int16x2_t {
+    dsp_call!(arm_ssub16, a, b)
+}
+
+/// Unsigned parallel halfword-wise addition.
+///
+/// Returns the 16-bit unsigned equivalent of
+///
+/// res\[0\] = a\[0\] + b\[0\]
+/// res\[1\] = a\[1\] + b\[1\]
+///
+/// and the GE bits of the APSR are set.
+#[inline]
+#[cfg_attr(test, assert_instr(uadd16))]
+pub unsafe fn __uadd16(a: uint16x2_t, b: uint16x2_t) -> uint16x2_t {
+    dsp_call!(arm_uadd16, a, b)
+}
+
+/// Unsigned parallel halfword-wise addition and subtraction with exchange.
+///
+/// Returns the 16-bit unsigned equivalent of
+///
+/// res\[0\] = a\[0\] - b\[1\]
+/// res\[1\] = a\[1\] + b\[0\]
+///
+/// and the GE bits of the APSR are set.
+#[inline]
+#[cfg_attr(test, assert_instr(uasx))]
+pub unsafe fn __uasx(a: uint16x2_t, b: uint16x2_t) -> uint16x2_t {
+    dsp_call!(arm_uasx, a, b)
+}
+
+/// Unsigned halving parallel halfword-wise addition.
+///
+/// Returns the 16-bit unsigned equivalent of
+///
+/// res\[0\] = (a\[0\] + b\[0\]) / 2
+/// res\[1\] = (a\[1\] + b\[1\]) / 2
+#[inline]
+#[cfg_attr(test, assert_instr(uhadd16))]
+pub unsafe fn __uhadd16(a: uint16x2_t, b: uint16x2_t) -> uint16x2_t {
+    dsp_call!(arm_uhadd16, a, b)
+}
+
+/// Unsigned halving parallel halfword-wise addition and subtraction with exchange.
+///
+/// Returns the 16-bit unsigned equivalent of
+///
+/// res\[0\] = (a\[0\] - b\[1\]) / 2
+/// res\[1\] = (a\[1\] + b\[0\]) / 2
+#[inline]
+#[cfg_attr(test, assert_instr(uhasx))]
+pub unsafe fn __uhasx(a: uint16x2_t, b: uint16x2_t) -> uint16x2_t {
+    dsp_call!(arm_uhasx, a, b)
+}
+
+/// Unsigned halving parallel halfword-wise subtraction and addition with exchange.
+///
+/// Returns the 16-bit unsigned equivalent of
+///
+/// res\[0\] = (a\[0\] + b\[1\]) / 2
+/// res\[1\] = (a\[1\] - b\[0\]) / 2
+#[inline]
+#[cfg_attr(test, assert_instr(uhsax))]
+pub unsafe fn __uhsax(a: uint16x2_t, b: uint16x2_t) -> uint16x2_t {
+    dsp_call!(arm_uhsax, a, b)
+}
+
+/// Unsigned halving parallel halfword-wise subtraction.
+///
+/// Returns the 16-bit unsigned equivalent of
+///
+/// res\[0\] = (a\[0\] - b\[0\]) / 2
+/// res\[1\] = (a\[1\] - b\[1\]) / 2
+#[inline]
+#[cfg_attr(test, assert_instr(uhsub16))]
+pub unsafe fn __uhsub16(a: uint16x2_t, b: uint16x2_t) -> uint16x2_t {
+    dsp_call!(arm_uhsub16, a, b)
+}
+
+/// Unsigned saturating parallel halfword-wise addition.
+///
+/// Returns the 16-bit unsigned saturated equivalent of
+///
+/// res\[0\] = a\[0\] + b\[0\]
+/// res\[1\] = a\[1\] + b\[1\]
+#[inline]
+#[cfg_attr(test, assert_instr(uqadd16))]
+pub unsafe fn __uqadd16(a: uint16x2_t, b: uint16x2_t) -> uint16x2_t {
+    dsp_call!(arm_uqadd16, a, b)
+}
+
+/// Unsigned saturating parallel halfword-wise addition and subtraction with exchange.
+///
+/// Returns the 16-bit unsigned saturated equivalent of
+///
+/// res\[0\] = a\[0\] - b\[1\]
+/// res\[1\] = a\[1\] + b\[0\]
+#[inline]
+#[cfg_attr(test, assert_instr(uqasx))]
+pub unsafe fn __uqasx(a: uint16x2_t, b: uint16x2_t) -> uint16x2_t {
+    dsp_call!(arm_uqasx, a, b)
+}
+
+/// Unsigned saturating parallel halfword-wise subtraction and addition with exchange.
+///
+/// Returns the 16-bit unsigned saturated equivalent of
+///
+/// res\[0\] = a\[0\] + b\[1\]
+/// res\[1\] = a\[1\] - b\[0\]
+#[inline]
+#[cfg_attr(test, assert_instr(uqsax))]
+pub unsafe fn __uqsax(a: uint16x2_t, b: uint16x2_t) -> uint16x2_t {
+    dsp_call!(arm_uqsax, a, b)
+}
+
+/// Unsigned saturating parallel halfword-wise subtraction.
+///
+/// Returns the 16-bit unsigned saturated equivalent of
+///
+/// res\[0\] = a\[0\] - b\[0\]
+/// res\[1\] = a\[1\] - b\[1\]
+#[inline]
+#[cfg_attr(test, assert_instr(uqsub16))]
+pub unsafe fn __uqsub16(a: uint16x2_t, b: uint16x2_t) -> uint16x2_t {
+    dsp_call!(arm_uqsub16, a, b)
+}
+
+/// Unsigned parallel halfword-wise subtraction and addition with exchange.
+///
+/// Returns the 16-bit unsigned equivalent of
+///
+/// res\[0\] = a\[0\] + b\[1\]
+/// res\[1\] = a\[1\] - b\[0\]
+///
+/// and the GE bits of the APSR are set.
+#[inline]
+#[cfg_attr(test, assert_instr(usax))]
+pub unsafe fn __usax(a: uint16x2_t, b: uint16x2_t) -> uint16x2_t {
+    dsp_call!(arm_usax, a, b)
+}
+
+/// Unsigned parallel halfword-wise subtraction.
+///
+/// Returns the 16-bit unsigned equivalent of
+///
+/// res\[0\] = a\[0\] - b\[0\]
+/// res\[1\] = a\[1\] - b\[1\]
+///
+/// and the GE bits of the APSR are set.
+#[inline]
+#[cfg_attr(test, assert_instr(usub16))]
+pub unsafe fn __usub16(a: uint16x2_t, b: uint16x2_t) -> uint16x2_t {
+    dsp_call!(arm_usub16, a, b)
+}
+
+/// Dual 16-bit Signed Multiply with Addition of exchanged products
+/// and 32-bit accumulation.
+///
+/// Returns the equivalent of
+///
+/// res = a\[0\] * b\[1\] + a\[1\] * b\[0\] + c
+///
+/// and sets the Q flag if overflow occurs on the addition.
+#[inline]
+#[cfg_attr(test, assert_instr(smladx))]
+pub unsafe fn __smladx(a: int16x2_t, b: int16x2_t, c: i32) -> i32 {
+    arm_smladx(transmute(a), transmute(b), c)
+}
+
+/// Dual 16-bit Signed Multiply with Subtraction of exchanged products
+/// and 32-bit accumulation.
+///
+/// Returns the equivalent of
+///
+/// res = a\[0\] * b\[1\] - a\[1\] * b\[0\] + c
+///
+/// and sets the Q flag if overflow occurs on the addition.
+#[inline]
+#[cfg_attr(test, assert_instr(smlsdx))]
+pub unsafe fn __smlsdx(a: int16x2_t, b: int16x2_t, c: i32) -> i32 {
+    arm_smlsdx(transmute(a), transmute(b), c)
+}
+
+/// Dual 16-bit Signed Multiply with Addition of products
+/// and 64-bit accumulation.
+///
+/// Returns the equivalent of
+///
+/// res = a\[0\] * b\[0\] + a\[1\] * b\[1\] + c
+///
+/// The accumulation wraps around instead of setting the Q flag.
+#[inline]
+#[cfg_attr(test, assert_instr(smlald))]
+pub unsafe fn __smlald(a: int16x2_t, b: int16x2_t, c: i64) -> i64 {
+    arm_smlald(transmute(a), transmute(b), c)
+}
+
+/// Dual 16-bit Signed Multiply with Addition of exchanged products
+/// and 64-bit accumulation.
+///
+/// Returns the equivalent of
+///
+/// res = a\[0\] * b\[1\] + a\[1\] * b\[0\] + c
+///
+/// The accumulation wraps around instead of setting the Q flag.
+#[inline]
+#[cfg_attr(test, assert_instr(smlaldx))]
+pub unsafe fn __smlaldx(a: int16x2_t, b: int16x2_t, c: i64) -> i64 {
+    arm_smlaldx(transmute(a), transmute(b), c)
+}
+
+/// Dual 16-bit Signed Multiply with Subtraction of products
+/// and 64-bit accumulation.
+///
+/// Returns the equivalent of
+///
+/// res = a\[0\] * b\[0\] - a\[1\] * b\[1\] + c
+///
+/// The accumulation wraps around instead of setting the Q flag.
+#[inline]
+#[cfg_attr(test, assert_instr(smlsld))]
+pub unsafe fn __smlsld(a: int16x2_t, b: int16x2_t, c: i64) -> i64 {
+    arm_smlsld(transmute(a), transmute(b), c)
+}
+
+/// Dual 16-bit Signed Multiply with Subtraction of exchanged products
+/// and 64-bit accumulation.
+///
+/// Returns the equivalent of
+///
+/// res = a\[0\] * b\[1\] - a\[1\] * b\[0\] + c
+///
+/// The accumulation wraps around instead of setting the Q flag.
+#[inline]
+#[cfg_attr(test, assert_instr(smlsldx))]
+pub unsafe fn __smlsldx(a: int16x2_t, b: int16x2_t, c: i64) -> i64 {
+    arm_smlsldx(transmute(a), transmute(b), c)
+}
+
+/// Dual sign-extension of bytes to halfwords.
+///
+/// Returns the 16-bit signed equivalent of
+///
+/// res\[0\] = a\[0\]
+/// res\[1\] = a\[2\]
+#[inline]
+#[cfg_attr(test, assert_instr(sxtb16))]
+pub unsafe fn __sxtb16(a: int8x4_t) -> int16x2_t {
+    transmute(arm_sxtb16(transmute(a)))
+}
+
+/// Dual sign-extension of bytes to halfwords with addition.
+///
+/// Returns the 16-bit signed equivalent of
+///
+/// res\[0\] = a\[0\] + b\[0\]
+/// res\[1\] = a\[1\] + b\[2\]
+#[inline]
+#[cfg_attr(test, assert_instr(sxtab16))]
+pub unsafe fn __sxtab16(a: int16x2_t, b: int8x4_t) -> int16x2_t {
+    dsp_call!(arm_sxtab16, a, b)
+}
+
+/// Dual zero-extension of bytes to halfwords.
+///
+/// Returns the 16-bit unsigned equivalent of
+///
+/// res\[0\] = a\[0\]
+/// res\[1\] = a\[2\]
+#[inline]
+#[cfg_attr(test, assert_instr(uxtb16))]
+pub unsafe fn __uxtb16(a: uint8x4_t) -> uint16x2_t {
+    transmute(arm_uxtb16(transmute(a)))
+}
+
+/// Dual zero-extension of bytes to halfwords with addition.
+///
+/// Returns the 16-bit unsigned equivalent of
+///
+/// res\[0\] = a\[0\] + b\[0\]
+/// res\[1\] = a\[1\] + b\[2\]
+#[inline]
+#[cfg_attr(test, assert_instr(uxtab16))]
+pub unsafe fn __uxtab16(a: uint16x2_t, b: uint8x4_t) -> uint16x2_t {
+    dsp_call!(arm_uxtab16, a, b)
+}
+
+/// Dual signed saturation of halfwords to `SAT` bits, from 1 to 16.
+///
+/// Saturates both halfwords to the range -2^(SAT-1) to 2^(SAT-1) - 1 and
+/// sets the Q flag if either of them saturates.
+#[inline]
+#[rustc_legacy_const_generics(1)]
+#[cfg_attr(test, assert_instr(ssat16, SAT = 8))]
+pub unsafe fn __ssat16<const SAT: i32>(a: int16x2_t) -> int16x2_t {
+    static_assert!(SAT: i32 where SAT >= 1 && SAT <= 16);
+    transmute(arm_ssat16(transmute(a), SAT))
+}
+
+/// Dual unsigned saturation of halfwords to `SAT` bits, from 0 to 15.
+///
+/// Saturates both signed halfwords to the range 0 to 2^SAT - 1 and sets the
+/// Q flag if either of them saturates.
+#[inline]
+#[rustc_legacy_const_generics(1)]
+#[cfg_attr(test, assert_instr(usat16, SAT = 8))]
+pub unsafe fn __usat16<const SAT: i32>(a: int16x2_t) -> int16x2_t {
+    static_assert!(SAT: i32 where SAT >= 0 && SAT <= 15);
+    transmute(arm_usat16(transmute(a), SAT))
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::core_arch::simd::{i16x2, i8x4, u8x4};
+    use crate::core_arch::simd::{i16x2, i8x4, u16x2, u8x4};
     use std::mem::transmute;
     use stdarch_test::simd_test;
 
@@ -725,4 +1271,383 @@ mod tests {
             assert_eq!(r, 8 + c);
         }
     }
+
+    #[test]
+    fn uadd8() {
+        unsafe {
+            let a = u8x4::new(1, 2, 3, u8::MAX);
+            let b = u8x4::new(4, 3, 2, 2);
+            let c = u8x4::new(5, 5, 5, 1);
+            let r: u8x4 = dsp_call!(super::__uadd8, a, b);
+            assert_eq!(r, c);
+        }
+    }
+
+    #[test]
+    fn uhadd8() {
+        unsafe {
+            let a = u8x4::new(1, 2, 3, u8::MAX);
+            let b = u8x4::new(4, 3, 2, 2);
+            let c = u8x4::new(2, 2, 2, 128);
+            let r: u8x4 = dsp_call!(super::__uhadd8, a, b);
+            assert_eq!(r, c);
+        }
+    }
+
+    #[test]
+    fn uhsub8() {
+        unsafe {
+            let a = u8x4::new(1, 2, 3, u8::MAX);
+            let b = u8x4::new(4, 3, 2, 2);
+            let c = u8x4::new(254, u8::MAX, 0, 126);
+            let r: u8x4 = dsp_call!(super::__uhsub8, a, b);
+            assert_eq!(r, c);
+        }
+    }
+
+    #[test]
+    fn uqadd8() {
+        unsafe {
+            let a = u8x4::new(1, 2, 3, u8::MAX);
+            let b = u8x4::new(4, 3, 2, 2);
+            let c = u8x4::new(5, 5, 5, u8::MAX);
+            let r: u8x4 = dsp_call!(super::__uqadd8, a, b);
+            assert_eq!(r, c);
+        }
+    }
+
+    #[test]
+    fn uqsub8() {
+        unsafe {
+            let a = u8x4::new(1, 2, 3, u8::MAX);
+            let b = u8x4::new(4, 3, 2, 2);
+            let c = u8x4::new(0, 0, 1, 253);
+            let r: u8x4 = dsp_call!(super::__uqsub8, a, b);
+            assert_eq!(r, c);
+        }
+    }
+
+    #[test]
+    fn shasx() {
+        unsafe {
+            let a = i16x2::new(1, i16::MAX);
+            let b = i16x2::new(i16::MIN, 3);
+            let c = i16x2::new(-1, -1);
+            let r: i16x2 = dsp_call!(super::__shasx, a, b);
+            assert_eq!(r, c);
+        }
+    }
+
+    #[test]
+    fn shsax() {
+        unsafe {
+            let a = i16x2::new(1, i16::MIN);
+            let b = i16x2::new(2, i16::MAX);
+            let c = i16x2::new(16384, -16385);
+            let r: i16x2 = dsp_call!(super::__shsax, a, b);
+            assert_eq!(r, c);
+        }
+    }
+
+    #[test]
+    fn ssax() {
+        unsafe {
+            let a = i16x2::new(1, i16::MIN);
+            let b = i16x2::new(2, i16::MAX);
+            let c = i16x2::new(i16::MIN, 32766);
+            let r: i16x2 = dsp_call!(super::__ssax, a, b);
+            assert_eq!(r, c);
+        }
+    }
+
+    #[test]
+    fn ssub16() {
+        unsafe {
+            let a = i16x2::new(1, i16::MIN);
+            let b = i16x2::new(2, 1);
+            let c = i16x2::new(-1, i16::MAX);
+            let r: i16x2 = dsp_call!(super::__ssub16, a, b);
+            assert_eq!(r, c);
+        }
+    }
+
+    #[test]
+    fn uadd16() {
+        unsafe {
+            let a = u16x2::new(1, u16::MAX);
+            let b = u16x2::new(2, 2);
+            let c = u16x2::new(3, 1);
+            let r: u16x2 = dsp_call!(super::__uadd16, a, b);
+            assert_eq!(r, c);
+        }
+    }
+
+    #[test]
+    fn uasx() {
+        unsafe {
+            let a = u16x2::new(1, u16::MAX);
+            let b = u16x2::new(2, 2);
+            let c = u16x2::new(u16::MAX, 1);
+            let r: u16x2 = dsp_call!(super::__uasx, a, b);
+            assert_eq!(r, c);
+        }
+    }
+
+    #[test]
+    fn uhadd16() {
+        unsafe {
+            let a = u16x2::new(1, u16::MAX);
+            let b = u16x2::new(5, u16::MAX);
+            let c = u16x2::new(3, u16::MAX);
+            let r: u16x2 = dsp_call!(super::__uhadd16, a, b);
+            assert_eq!(r, c);
+        }
+    }
+
+    #[test]
+    fn uhasx() {
+        unsafe {
+            let a = u16x2::new(1, u16::MAX);
+            let b = u16x2::new(2, 5);
+            let c = u16x2::new(65534, 32768);
+            let r: u16x2 = dsp_call!(super::__uhasx, a, b);
+            assert_eq!(r, c);
+        }
+    }
+
+    #[test]
+    fn uhsax() {
+        unsafe {
+            let a = u16x2::new(1, 2);
+            let b = u16x2::new(5, u16::MAX);
+            let c = u16x2::new(32768, 65534);
+            let r: u16x2 = dsp_call!(super::__uhsax, a, b);
+            assert_eq!(r, c);
+        }
+    }
+
+    #[test]
+    fn uhsub16() {
+        unsafe {
+            let a = u16x2::new(1, u16::MAX);
+            let b = u16x2::new(5, 2);
+            let c = u16x2::new(65534, 32766);
+            let r: u16x2 = dsp_call!(super::__uhsub16, a, b);
+            assert_eq!(r, c);
+        }
+    }
+
+    #[test]
+    fn uqadd16() {
+        unsafe {
+            let a = u16x2::new(1, u16::MAX);
+            let b = u16x2::new(2, 2);
+            let c = u16x2::new(3, u16::MAX);
+            let r: u16x2 = dsp_call!(super::__uqadd16, a, b);
+            assert_eq!(r, c);
+        }
+    }
+
+    #[test]
+    fn uqasx() {
+        unsafe {
+            let a = u16x2::new(1, u16::MAX);
+            let b = u16x2::new(2, 2);
+            let c = u16x2::new(0, u16::MAX);
+            let r: u16x2 = dsp_call!(super::__uqasx, a, b);
+            assert_eq!(r, c);
+        }
+    }
+
+    #[test]
+    fn uqsax() {
+        unsafe {
+            let a = u16x2::new(1, 1);
+            let b = u16x2::new(2, u16::MAX);
+            let c = u16x2::new(u16::MAX, 0);
+            let r: u16x2 = dsp_call!(super::__uqsax, a, b);
+            assert_eq!(r, c);
+        }
+    }
+
+    #[test]
+    fn uqsub16() {
+        unsafe {
+            let a = u16x2::new(1, u16::MAX);
+            let b = u16x2::new(2, 2);
+            let c = u16x2::new(0, 65533);
+            let r: u16x2 = dsp_call!(super::__uqsub16, a, b);
+            assert_eq!(r, c);
+        }
+    }
+
+    #[test]
+    fn usax() {
+        unsafe {
+            let a = u16x2::new(1, 1);
+            let b = u16x2::new(2, u16::MAX);
+            let c = u16x2::new(0, u16::MAX);
+            let r: u16x2 = dsp_call!(super::__usax, a, b);
+            assert_eq!(r, c);
+        }
+    }
+
+    #[test]
+    fn usub16() {
+        unsafe {
+            let a = u16x2::new(1, u16::MAX);
+            let b = u16x2::new(2, 2);
+            let c = u16x2::new(u16::MAX, 65533);
+            let r: u16x2 = dsp_call!(super::__usub16, a, b);
+            assert_eq!(r, c);
+        }
+    }
+
+    #[test]
+    fn smladx() {
+        unsafe {
+            let a = i16x2::new(1, 2);
+            let b = i16x2::new(3, 4);
+            let r = super::__smladx(transmute(a), transmute(b), 10);
+            assert_eq!(r, (1 * 4) + (2 * 3) + 10);
+            // The accumulation wraps around.
+            let a = i16x2::new(i16::MIN, i16::MIN);
+            let r = super::__smladx(transmute(a), transmute(a), 0);
+            assert_eq!(r, i32::MIN);
+        }
+    }
+
+    #[test]
+    fn smlsdx() {
+        unsafe {
+            let a = i16x2::new(1, 2);
+            let b = i16x2::new(3, 4);
+            let r = super::__smlsdx(transmute(a), transmute(b), 10);
+            assert_eq!(r, ((1 * 4) - (2 * 3)) + 10);
+        }
+    }
+
+    #[test]
+    fn smlald() {
+        unsafe {
+            let a = i16x2::new(i16::MIN, i16::MIN);
+            let r = super::__smlald(transmute(a), transmute(a), 10);
+            assert_eq!(r, (1 << 31) + 10);
+            let r = super::__smlald(transmute(a), transmute(a), i64::MAX);
+            assert_eq!(r, i64::MIN + (1 << 31) - 1);
+        }
+    }
+
+    #[test]
+    fn smlaldx() {
+        unsafe {
+            let a = i16x2::new(1, 2);
+            let b = i16x2::new(3, 4);
+            let r = super::__smlaldx(transmute(a), transmute(b), 1 << 40);
+            assert_eq!(r, (1 * 4) + (2 * 3) + (1 << 40));
+        }
+    }
+
+    #[test]
+    fn smlsld() {
+        unsafe {
+            let a = i16x2::new(1, 2);
+            let b = i16x2::new(3, 4);
+            let r = super::__smlsld(transmute(a), transmute(b), 1 << 40);
+            assert_eq!(r, ((1 * 3) - (2 * 4)) + (1 << 40));
+        }
+    }
+
+    #[test]
+    fn smlsldx() {
+        unsafe {
+            let a = i16x2::new(1, 2);
+            let b = i16x2::new(3, 4);
+            let r = super::__smlsldx(transmute(a), transmute(b), -(1 << 40));
+            assert_eq!(r, ((1 * 4) - (2 * 3)) - (1 << 40));
+        }
+    }
+
+    #[test]
+    fn sxtb16() {
+        unsafe {
+            let a = i8x4::new(-1, 2, i8::MIN, 4);
+            let c = i16x2::new(-1, -128);
+            let r: i16x2 = transmute(super::__sxtb16(transmute(a)));
+            assert_eq!(r, c);
+        }
+    }
+
+    #[test]
+    fn sxtab16() {
+        unsafe {
+            let a = i16x2::new(10, i16::MAX);
+            let b = i8x4::new(-1, 2, 1, 4);
+            let c = i16x2::new(9, i16::MIN);
+            let r: i16x2 = dsp_call!(super::__sxtab16, a, b);
+            assert_eq!(r, c);
+        }
+    }
+
+    #[test]
+    fn uxtb16() {
+        unsafe {
+            let a = u8x4::new(u8::MAX, 2, 3, 4);
+            let c = u16x2::new(255, 3);
+            let r: u16x2 = transmute(super::__uxtb16(transmute(a)));
+            assert_eq!(r, c);
+        }
+    }
+
+    #[test]
+    fn uxtab16() {
+        unsafe {
+            let a = u16x2::new(10, u16::MAX);
+            let b = u8x4::new(u8::MAX, 2, 1, 4);
+            let c = u16x2::new(265, 0);
+            let r: u16x2 = dsp_call!(super::__uxtab16, a, b);
+            assert_eq!(r, c);
+        }
+    }
+
+    #[test]
+    fn ssat16() {
+        unsafe {
+            let a = i16x2::new(100, -100);
+            let r: i16x2 = transmute(super::__ssat16::<8>(transmute(a)));
+            assert_eq!(r, a);
+            let a = i16x2::new(i16::MAX, i16::MIN);
+            let r: i16x2 = transmute(super::__ssat16::<8>(transmute(a)));
+            assert_eq!(r, i16x2::new(127, -128));
+            let r: i16x2 = transmute(super::__ssat16::<16>(transmute(a)));
+            assert_eq!(r, a);
+        }
+    }
+
+    #[test]
+    fn usat16() {
+        unsafe {
+            let a = i16x2::new(100, 255);
+            let r: i16x2 = transmute(super::__usat16::<8>(transmute(a)));
+            assert_eq!(r, a);
+            let a = i16x2::new(i16::MAX, -1);
+            let r: i16x2 = transmute(super::__usat16::<8>(transmute(a)));
+            assert_eq!(r, i16x2::new(255, 0));
+            let r: i16x2 = transmute(super::__usat16::<0>(transmute(a)));
+            assert_eq!(r, i16x2::new(0, 0));
+        }
+    }
+
+    #[test]
+    fn usub8_sel() {
+        unsafe {
+            // The GE bits set by `__usub8` make `__sel` pick the larger bytes.
+            let a = u8x4::new(1, 200, 3, 4);
+            let b = u8x4::new(5, 100, 3, 2);
+            let r: u8x4 = dsp_call!(super::__usub8, a, b);
+            assert_eq!(r, u8x4::new(252, 100, 0, 2));
+            let r: u8x4 = dsp_call!(super::__sel, a, b);
+            assert_eq!(r, u8x4::new(5, 200, 3, 4));
+        }
+    }
 }
@@ -20,6 +20,14 @@ extern "C" {
     fn crc32cw_(crc: u32, data: u32) -> u32;
 }
 
+#[cfg(target_arch = "aarch64")]
+extern "C" {
+    #[link_name = "llvm.aarch64.crc32x"]
+    fn crc32x_(crc: u32, data: u64) -> u32;
+    #[link_name = "llvm.aarch64.crc32cx"]
+    fn crc32cx_(crc: u32, data: u64) -> u32;
+}
+
 #[cfg(test)]
 use stdarch_test::assert_instr;
 
@@ -50,6 +58,26 @@ pub unsafe fn __crc32w(crc: u32, data: u32) -> u32 {
     crc32w_(crc, data)
 }
 
+/// CRC32 single round checksum for double words (64 bits).
+///
+/// AArch32 doesn't have a 64-bit CRC32 instruction, so there this is two
+/// rounds for words, starting with the lower one.
+#[inline]
+#[target_feature(enable = "crc")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "aarch64"), assert_instr(crc32x))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(crc32w))]
+pub unsafe fn __crc32d(crc: u32, data: u64) -> u32 {
+    #[cfg(target_arch = "aarch64")]
+    {
+        crc32x_(crc, data)
+    }
+    #[cfg(target_arch = "arm")]
+    {
+        crc32w_(crc32w_(crc, data as u32), (data >> 32) as u32)
+    }
+}
+
 /// CRC32-C single round checksum for bytes (8 bits).
 #[inline]
 #[target_feature(enable = "crc")]
@@ -77,6 +105,26 @@ pub unsafe fn __crc32cw(crc: u32, data: u32) -> u32 {
     crc32cw_(crc, data)
 }
 
+/// CRC32-C single round checksum for double words (64 bits).
+///
+/// AArch32 doesn't have a 64-bit CRC32-C instruction, so there this is two
+/// rounds for words, starting with the lower one.
+#[inline]
+#[target_feature(enable = "crc")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "aarch64"), assert_instr(crc32cx))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(crc32cw))]
+pub unsafe fn __crc32cd(crc: u32, data: u64) -> u32 {
+    #[cfg(target_arch = "aarch64")]
+    {
+        crc32cx_(crc, data)
+    }
+    #[cfg(target_arch = "arm")]
+    {
+        crc32cw_(crc32cw_(crc, data as u32), (data >> 32) as u32)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::core_arch::{arm_shared::*, simd::*};
@@ -101,6 +149,18 @@ mod tests {
         assert_eq!(__crc32w(0, 4294967295), 3736805603);
     }
 
+    #[simd_test(enable = "crc")]
+    unsafe fn test_crc32d() {
+        assert_eq!(__crc32d(0, 0), 0);
+        assert_eq!(__crc32d(0, 18446744073709551615), 1147535477);
+        // The lower word goes first.
+        let data = 0x0123_4567_89ab_cdef;
+        assert_eq!(
+            __crc32d(1, data),
+            __crc32w(__crc32w(1, 0x89ab_cdef), 0x0123_4567)
+        );
+    }
+
     #[simd_test(enable = "crc")]
     unsafe fn test_crc32cb() {
         assert_eq!(__crc32cb(0, 0), 0);
@@ -118,4 +178,15 @@ mod tests {
         assert_eq!(__crc32cw(0, 0), 0);
         assert_eq!(__crc32cw(0, 4294967295), 3080238136);
     }
+
+    #[simd_test(enable = "crc")]
+    unsafe fn test_crc32cd() {
+        assert_eq!(__crc32cd(0, 0), 0);
+        assert_eq!(__crc32cd(0, 18446744073709551615), 3293575501);
+        let data = 0x0123_4567_89ab_cdef;
+        assert_eq!(
+            __crc32cd(1, data),
+            __crc32cw(__crc32cw(1, 0x89ab_cdef), 0x0123_4567)
+        );
+    }
 }
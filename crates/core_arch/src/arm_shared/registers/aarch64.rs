@@ -0,0 +1,185 @@
+// All system registers are 64-bit on AArch64. `__rsr` and `__wsr` access
+// their lower 32 bits. Registers which older assemblers don't know the name
+// of are accessed by their encoding.
+macro_rules! rsr64 {
+    ($R:ident) => {
+        rsr64!($R, stringify!($R));
+    };
+    ($R:ident, $name:expr) => {
+        impl super::super::sealed::Rsr for $R {
+            unsafe fn __rsr(&self) -> u32 {
+                super::super::sealed::Rsr64::__rsr64(self) as u32
+            }
+        }
+
+        impl super::super::sealed::Rsr64 for $R {
+            unsafe fn __rsr64(&self) -> u64 {
+                let r: u64;
+                asm!(concat!("mrs {}, ", $name), out(reg) r, options(nomem, nostack));
+                r
+            }
+        }
+    };
+}
+
+macro_rules! wsr64 {
+    ($R:ident) => {
+        wsr64!($R, stringify!($R));
+    };
+    ($R:ident, $name:expr) => {
+        impl super::super::sealed::Wsr for $R {
+            unsafe fn __wsr(&self, value: u32) {
+                super::super::sealed::Wsr64::__wsr64(self, value as u64)
+            }
+        }
+
+        impl super::super::sealed::Wsr64 for $R {
+            unsafe fn __wsr64(&self, value: u64) {
+                asm!(concat!("msr ", $name, ", {}"), in(reg) value, options(nomem, nostack));
+            }
+        }
+    };
+}
+
+/// Counter-timer Frequency register
+#[allow(non_camel_case_types)]
+pub struct CNTFRQ_EL0;
+
+rsr64!(CNTFRQ_EL0);
+
+/// Counter-timer Physical Count register
+#[allow(non_camel_case_types)]
+pub struct CNTPCT_EL0;
+
+rsr64!(CNTPCT_EL0);
+
+/// Counter-timer Virtual Count register
+#[allow(non_camel_case_types)]
+pub struct CNTVCT_EL0;
+
+rsr64!(CNTVCT_EL0);
+
+/// Cache Type Register
+#[allow(non_camel_case_types)]
+pub struct CTR_EL0;
+
+rsr64!(CTR_EL0);
+
+/// Current Exception Level
+pub struct CurrentEL;
+
+rsr64!(CurrentEL);
+
+/// Interrupt Mask Bits
+pub struct DAIF;
+
+rsr64!(DAIF);
+wsr64!(DAIF);
+
+/// Data Cache Zero ID register
+#[allow(non_camel_case_types)]
+pub struct DCZID_EL0;
+
+rsr64!(DCZID_EL0);
+
+/// Floating-point Control Register
+pub struct FPCR;
+
+rsr64!(FPCR);
+wsr64!(FPCR);
+
+/// Floating-point Status Register
+pub struct FPSR;
+
+rsr64!(FPSR);
+wsr64!(FPSR);
+
+/// Floating-point Mode Register, which only exists with the `fp8` feature
+pub struct FPMR;
+
+rsr64!(FPMR, "S3_3_C4_C4_2");
+wsr64!(FPMR, "S3_3_C4_C4_2");
+
+/// Main ID Register
+#[allow(non_camel_case_types)]
+pub struct MIDR_EL1;
+
+rsr64!(MIDR_EL1);
+
+/// Multiprocessor Affinity Register
+#[allow(non_camel_case_types)]
+pub struct MPIDR_EL1;
+
+rsr64!(MPIDR_EL1);
+
+/// Condition Flags
+pub struct NZCV;
+
+rsr64!(NZCV);
+wsr64!(NZCV);
+
+/// Performance Monitors Cycle Count Register
+#[allow(non_camel_case_types)]
+pub struct PMCCNTR_EL0;
+
+rsr64!(PMCCNTR_EL0);
+wsr64!(PMCCNTR_EL0);
+
+/// EL0 Read/Write Software Thread ID Register
+#[allow(non_camel_case_types)]
+pub struct TPIDR_EL0;
+
+rsr64!(TPIDR_EL0);
+wsr64!(TPIDR_EL0);
+rsrp!(TPIDR_EL0);
+wsrp!(TPIDR_EL0);
+
+/// EL0 Read-Only Software Thread ID Register
+#[allow(non_camel_case_types)]
+pub struct TPIDRRO_EL0;
+
+rsr64!(TPIDRRO_EL0);
+wsr64!(TPIDRRO_EL0);
+rsrp!(TPIDRRO_EL0);
+wsrp!(TPIDRRO_EL0);
+
+/// EL1 Software Thread ID Register
+#[allow(non_camel_case_types)]
+pub struct TPIDR_EL1;
+
+rsr64!(TPIDR_EL1);
+wsr64!(TPIDR_EL1);
+rsrp!(TPIDR_EL1);
+wsrp!(TPIDR_EL1);
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::aarch64::*;
+
+    #[test]
+    fn cntvct_el0() {
+        unsafe {
+            let frequency = __rsr64(CNTFRQ_EL0);
+            assert_ne!(frequency, 0);
+            let a = __rsr64(CNTVCT_EL0);
+            let b = __rsr64(CNTVCT_EL0);
+            assert!(b >= a);
+        }
+    }
+
+    #[test]
+    fn tpidr_el0() {
+        unsafe {
+            // TPIDR_EL0 holds the thread pointer, so it has to be restored
+            // before anything accesses thread locals.
+            let tp = __rsr64(TPIDR_EL0);
+            __wsr64(TPIDR_EL0, 0x0123_4567_89ab_cdef);
+            let r = __rsr64(TPIDR_EL0);
+            let r32 = __rsr(TPIDR_EL0);
+            __wsr64(TPIDR_EL0, tp);
+            assert_eq!(r, 0x0123_4567_89ab_cdef);
+            assert_eq!(r32, 0x89ab_cdef);
+            assert_eq!(__rsrp(TPIDR_EL0), tp as *const u8);
+        }
+    }
+}
@@ -0,0 +1,141 @@
+// Reference: ARM Architecture Reference Manual ARMv7-A and ARMv7-R edition
+// (ARM DDI 0406C) Section B3.17 "Organization of the CP15 registers in a
+// VMSA implementation"
+
+// 32-bit registers are accessed with MRC and MCR, and encoded as
+// `opc1, CRn, CRm, opc2`.
+macro_rules! mrc {
+    ($R:ident, $opc1:literal, $CRn:ident, $CRm:ident, $opc2:literal) => {
+        impl super::super::sealed::Rsr for $R {
+            unsafe fn __rsr(&self) -> u32 {
+                let r: u32;
+                asm!(
+                    concat!(
+                        "mrc p15, ", $opc1, ", {}, ", stringify!($CRn), ", ", stringify!($CRm), ", ", $opc2
+                    ),
+                    out(reg) r,
+                    options(nomem, nostack, preserves_flags)
+                );
+                r
+            }
+        }
+    };
+}
+
+macro_rules! mcr {
+    ($R:ident, $opc1:literal, $CRn:ident, $CRm:ident, $opc2:literal) => {
+        impl super::super::sealed::Wsr for $R {
+            unsafe fn __wsr(&self, value: u32) {
+                asm!(
+                    concat!(
+                        "mcr p15, ", $opc1, ", {}, ", stringify!($CRn), ", ", stringify!($CRm), ", ", $opc2
+                    ),
+                    in(reg) value,
+                    options(nomem, nostack, preserves_flags)
+                );
+            }
+        }
+    };
+}
+
+// 64-bit registers are accessed with MRRC, and encoded as `opc1, CRm`.
+macro_rules! mrrc {
+    ($R:ident, $opc1:literal, $CRm:ident) => {
+        impl super::super::sealed::Rsr64 for $R {
+            unsafe fn __rsr64(&self) -> u64 {
+                let lo: u32;
+                let hi: u32;
+                asm!(
+                    concat!("mrrc p15, ", $opc1, ", {}, {}, ", stringify!($CRm)),
+                    out(reg) lo,
+                    out(reg) hi,
+                    options(nomem, nostack, preserves_flags)
+                );
+                (hi as u64) << 32 | lo as u64
+            }
+        }
+    };
+}
+
+// The thread ID registers hold addresses as well.
+macro_rules! thread_id {
+    ($R:ident, $opc2:literal) => {
+        mrc!($R, 0, c13, c0, $opc2);
+        mcr!($R, 0, c13, c0, $opc2);
+
+        impl super::super::sealed::Rsrp for $R {
+            unsafe fn __rsrp(&self) -> *const u8 {
+                super::super::sealed::Rsr::__rsr(self) as *const u8
+            }
+        }
+
+        impl super::super::sealed::Wsrp for $R {
+            unsafe fn __wsrp(&self, value: *const u8) {
+                super::super::sealed::Wsr::__wsr(self, value as u32)
+            }
+        }
+    };
+}
+
+/// Counter-timer Frequency register
+pub struct CNTFRQ;
+
+mrc!(CNTFRQ, 0, c14, c0, 0);
+
+/// Counter-timer Physical Count register
+pub struct CNTPCT;
+
+mrrc!(CNTPCT, 0, c14);
+
+/// Counter-timer Virtual Count register
+pub struct CNTVCT;
+
+mrrc!(CNTVCT, 1, c14);
+
+/// Main ID Register
+pub struct MIDR;
+
+mrc!(MIDR, 0, c0, c0, 0);
+
+/// Multiprocessor Affinity Register
+pub struct MPIDR;
+
+mrc!(MPIDR, 0, c0, c0, 5);
+
+/// Performance Monitors Cycle Count Register
+pub struct PMCCNTR;
+
+mrc!(PMCCNTR, 0, c9, c13, 0);
+mcr!(PMCCNTR, 0, c9, c13, 0);
+
+/// PL0 Read/Write Software Thread ID Register
+pub struct TPIDRURW;
+
+thread_id!(TPIDRURW, 2);
+
+/// PL0 Read-Only Software Thread ID Register
+pub struct TPIDRURO;
+
+thread_id!(TPIDRURO, 3);
+
+/// PL1 Software Thread ID Register
+pub struct TPIDRPRW;
+
+thread_id!(TPIDRPRW, 4);
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::arm::*;
+
+    #[test]
+    fn tpidrurw() {
+        unsafe {
+            let old = __rsr(TPIDRURW);
+            __wsr(TPIDRURW, 0x89ab_cdef);
+            let r = __rsr(TPIDRURW);
+            __wsr(TPIDRURW, old);
+            assert_eq!(r, 0x89ab_cdef);
+            assert_eq!(__rsrp(TPIDRURW), old as *const u8);
+        }
+    }
+}
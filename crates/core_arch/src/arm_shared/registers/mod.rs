@@ -64,6 +64,26 @@ mod aarch32;
 #[cfg(not(target_arch = "aarch64"))]
 pub use self::aarch32::*;
 
+#[cfg(all(
+    not(target_arch = "aarch64"),
+    target_feature = "v6",
+    not(target_feature = "mclass")
+))]
+mod cp15;
+
+#[cfg(all(
+    not(target_arch = "aarch64"),
+    target_feature = "v6",
+    not(target_feature = "mclass")
+))]
+pub use self::cp15::*;
+
+#[cfg(target_arch = "aarch64")]
+mod aarch64;
+
+#[cfg(target_arch = "aarch64")]
+pub use self::aarch64::*;
+
 /// Reads a 32-bit system register
 #[inline(always)]
 pub unsafe fn __rsr<R>(reg: R) -> u32
@@ -74,7 +94,6 @@ where
 }
 
 /// Reads a 64-bit system register
-#[cfg(target_arch = "aarch64")]
 #[inline(always)]
 pub unsafe fn __rsr64<R>(reg: R) -> u64
 where
@@ -102,7 +121,6 @@ where
 }
 
 /// Writes a 64-bit system register
-#[cfg(target_arch = "aarch64")]
 #[inline(always)]
 pub unsafe fn __wsr64<R>(reg: R, value: u64)
 where
@@ -28,6 +28,7 @@
     avx512_target_feature,
     mips_target_feature,
     powerpc_target_feature,
+    riscv_target_feature,
     wasm_target_feature,
     abi_unadjusted,
     adx_target_feature,
@@ -37,10 +38,14 @@
     allow_internal_unstable,
     decl_macro,
     extended_key_value_attributes,
-    bench_black_box
+    bench_black_box,
+    target_feature_11
 )]
 #![cfg_attr(test, feature(test, abi_vectorcall))]
-#![cfg_attr(all(test, target_arch = "wasm32"), feature(wasm_simd))]
+#![cfg_attr(
+    all(test, any(target_arch = "wasm32", target_arch = "wasm64")),
+    feature(wasm_simd, wasm_relaxed_simd, wasm_f16x8)
+)]
 #![deny(clippy::missing_inline_in_public_items)]
 #![allow(
     clippy::inline_always,
@@ -75,4 +80,4 @@ mod core_arch;
 pub use self::core_arch::arch;
 
 #[allow(unused_imports)]
-use core::{convert, ffi, hint, intrinsics, marker, mem, ops, ptr, sync};
+use core::{convert, ffi, hint, intrinsics, marker, mem, ops, ptr, sync, time};
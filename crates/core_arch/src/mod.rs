@@ -6,6 +6,9 @@ mod macros;
 #[cfg(any(target_arch = "arm", target_arch = "aarch64", doc))]
 mod arm_shared;
 
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64", doc))]
+mod riscv_shared;
+
 mod simd;
 
 #[doc = include_str!("core_arch_docs.md")]
@@ -127,6 +130,13 @@ pub mod arch {
     /// }
     /// ```
     ///
+    /// Intrinsics which only operate on `v128` values, such as arithmetic,
+    /// comparisons and shuffles, are safe functions. Within a function that
+    /// enables `simd128`, like `uses_simd` above, they can be called without
+    /// an `unsafe` block, while calling them from elsewhere is `unsafe` as for
+    /// any other `#[target_feature]` function. Loads and stores take raw
+    /// pointers and remain `unsafe fn`s.
+    ///
     /// Unlike `x86_64`, however, WebAssembly does not currently have dynamic
     /// detection at runtime as to whether SIMD is supported (this is one of the
     /// motivators for the [conditional sections][condsections] and [feature
@@ -170,6 +180,21 @@ pub mod arch {
         pub use crate::core_arch::wasm32::*;
     }
 
+    /// Platform-specific intrinsics for the `wasm64` platform.
+    ///
+    /// These are the same intrinsics as in the `wasm32` module, with pointers
+    /// and memory sizes into 64-bit memories from the [memory64] proposal.
+    ///
+    /// [memory64]: https://github.com/WebAssembly/memory64
+    ///
+    /// See the [module documentation](../index.html) for more details.
+    #[cfg(any(target_arch = "wasm64", doc))]
+    #[doc(cfg(target_arch = "wasm64"))]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub mod wasm64 {
+        pub use crate::core_arch::wasm32::*;
+    }
+
     /// Platform-specific intrinsics for the `mips` platform.
     ///
     /// See the [module documentation](../index.html) for more details.
@@ -210,6 +235,26 @@ pub mod arch {
         pub use crate::core_arch::powerpc64::*;
     }
 
+    /// Platform-specific intrinsics for the `riscv32` platform.
+    ///
+    /// See the [module documentation](../index.html) for more details.
+    #[cfg(any(target_arch = "riscv32", doc))]
+    #[doc(cfg(target_arch = "riscv32"))]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub mod riscv32 {
+        pub use crate::core_arch::riscv32::*;
+    }
+
+    /// Platform-specific intrinsics for the `riscv64` platform.
+    ///
+    /// See the [module documentation](../index.html) for more details.
+    #[cfg(any(target_arch = "riscv64", doc))]
+    #[doc(cfg(target_arch = "riscv64"))]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub mod riscv64 {
+        pub use crate::core_arch::riscv64::*;
+    }
+
     /// Platform-specific intrinsics for the `NVPTX` platform.
     ///
     /// See the [module documentation](../index.html) for more details.
@@ -237,8 +282,8 @@ mod aarch64;
 #[doc(cfg(any(target_arch = "arm")))]
 mod arm;
 
-#[cfg(any(target_arch = "wasm32", doc))]
-#[doc(cfg(target_arch = "wasm32"))]
+#[cfg(any(target_arch = "wasm32", target_arch = "wasm64", doc))]
+#[doc(cfg(any(target_arch = "wasm32", target_arch = "wasm64")))]
 mod wasm32;
 
 #[cfg(any(target_arch = "mips", target_arch = "mips64", doc))]
@@ -253,6 +298,14 @@ mod powerpc;
 #[doc(cfg(target_arch = "powerpc64"))]
 mod powerpc64;
 
+#[cfg(any(target_arch = "riscv32", doc))]
+#[doc(cfg(target_arch = "riscv32"))]
+mod riscv32;
+
+#[cfg(any(target_arch = "riscv64", doc))]
+#[doc(cfg(target_arch = "riscv64"))]
+mod riscv64;
+
 #[cfg(any(target_arch = "nvptx", target_arch = "nvptx64", doc))]
 #[doc(cfg(any(target_arch = "nvptx", target_arch = "nvptx64")))]
 mod nvptx;
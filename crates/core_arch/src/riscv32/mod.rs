@@ -0,0 +1,6 @@
+//! RISC-V RV32 intrinsics.
+
+pub use crate::core_arch::riscv_shared::*;
+
+mod zk;
+pub use self::zk::*;
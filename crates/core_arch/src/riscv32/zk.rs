@@ -0,0 +1,383 @@
+//! RV32 scalar cryptography, NIST suite (Zkn): AES and SHA-512.
+//!
+//! The AES instructions process a single byte of a column of the state at a
+//! time. `BS` selects the byte of `rs2` that is looked up in the S-box, and
+//! the result is rotated into the same byte position before it is XORed into
+//! `rs1`. A full round computes each output column with four instructions.
+//!
+//! The SHA-512 instructions each compute one 32-bit half of the 64-bit
+//! functions, taking the two halves of the input in `rs1` and `rs2`.
+//!
+//! The reference is the [RISC-V Cryptography Extensions][spec] specification,
+//! volume I.
+//!
+//! [spec]: https://github.com/riscv/riscv-crypto
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// AES final round encryption.
+///
+/// Applies SubBytes to byte `BS` of `rs2` and XORs it into `rs1`.
+#[inline]
+#[target_feature(enable = "zkne")]
+#[cfg_attr(test, assert_instr(aes32esi, BS = 0))]
+pub unsafe fn aes32esi<const BS: u8>(rs1: u32, rs2: u32) -> u32 {
+    static_assert!(BS: u8 where BS <= 3);
+    let rd;
+    asm!(
+        "aes32esi {}, {}, {}, {}",
+        lateout(reg) rd,
+        in(reg) rs1,
+        in(reg) rs2,
+        const BS,
+        options(pure, nomem, nostack),
+    );
+    rd
+}
+
+/// AES middle round encryption.
+///
+/// Applies SubBytes and the MixColumns contribution to byte `BS` of `rs2` and
+/// XORs the resulting column into `rs1`.
+#[inline]
+#[target_feature(enable = "zkne")]
+#[cfg_attr(test, assert_instr(aes32esmi, BS = 0))]
+pub unsafe fn aes32esmi<const BS: u8>(rs1: u32, rs2: u32) -> u32 {
+    static_assert!(BS: u8 where BS <= 3);
+    let rd;
+    asm!(
+        "aes32esmi {}, {}, {}, {}",
+        lateout(reg) rd,
+        in(reg) rs1,
+        in(reg) rs2,
+        const BS,
+        options(pure, nomem, nostack),
+    );
+    rd
+}
+
+/// AES final round decryption.
+///
+/// Applies InvSubBytes to byte `BS` of `rs2` and XORs it into `rs1`.
+#[inline]
+#[target_feature(enable = "zknd")]
+#[cfg_attr(test, assert_instr(aes32dsi, BS = 0))]
+pub unsafe fn aes32dsi<const BS: u8>(rs1: u32, rs2: u32) -> u32 {
+    static_assert!(BS: u8 where BS <= 3);
+    let rd;
+    asm!(
+        "aes32dsi {}, {}, {}, {}",
+        lateout(reg) rd,
+        in(reg) rs1,
+        in(reg) rs2,
+        const BS,
+        options(pure, nomem, nostack),
+    );
+    rd
+}
+
+/// AES middle round decryption.
+///
+/// Applies InvSubBytes and the InvMixColumns contribution to byte `BS` of
+/// `rs2` and XORs the resulting column into `rs1`.
+#[inline]
+#[target_feature(enable = "zknd")]
+#[cfg_attr(test, assert_instr(aes32dsmi, BS = 0))]
+pub unsafe fn aes32dsmi<const BS: u8>(rs1: u32, rs2: u32) -> u32 {
+    static_assert!(BS: u8 where BS <= 3);
+    let rd;
+    asm!(
+        "aes32dsmi {}, {}, {}, {}",
+        lateout(reg) rd,
+        in(reg) rs1,
+        in(reg) rs2,
+        const BS,
+        options(pure, nomem, nostack),
+    );
+    rd
+}
+
+/// The high half of the SHA-512 `σ0` function.
+///
+/// `rs1` is the high and `rs2` the low half of the input.
+#[inline]
+#[target_feature(enable = "zknh")]
+#[cfg_attr(test, assert_instr(sha512sig0h))]
+pub unsafe fn sha512sig0h(rs1: u32, rs2: u32) -> u32 {
+    let rd;
+    asm!(
+        "sha512sig0h {}, {}, {}",
+        lateout(reg) rd,
+        in(reg) rs1,
+        in(reg) rs2,
+        options(pure, nomem, nostack),
+    );
+    rd
+}
+
+/// The low half of the SHA-512 `σ0` function.
+///
+/// `rs1` is the low and `rs2` the high half of the input.
+#[inline]
+#[target_feature(enable = "zknh")]
+#[cfg_attr(test, assert_instr(sha512sig0l))]
+pub unsafe fn sha512sig0l(rs1: u32, rs2: u32) -> u32 {
+    let rd;
+    asm!(
+        "sha512sig0l {}, {}, {}",
+        lateout(reg) rd,
+        in(reg) rs1,
+        in(reg) rs2,
+        options(pure, nomem, nostack),
+    );
+    rd
+}
+
+/// The high half of the SHA-512 `σ1` function.
+///
+/// `rs1` is the high and `rs2` the low half of the input.
+#[inline]
+#[target_feature(enable = "zknh")]
+#[cfg_attr(test, assert_instr(sha512sig1h))]
+pub unsafe fn sha512sig1h(rs1: u32, rs2: u32) -> u32 {
+    let rd;
+    asm!(
+        "sha512sig1h {}, {}, {}",
+        lateout(reg) rd,
+        in(reg) rs1,
+        in(reg) rs2,
+        options(pure, nomem, nostack),
+    );
+    rd
+}
+
+/// The low half of the SHA-512 `σ1` function.
+///
+/// `rs1` is the low and `rs2` the high half of the input.
+#[inline]
+#[target_feature(enable = "zknh")]
+#[cfg_attr(test, assert_instr(sha512sig1l))]
+pub unsafe fn sha512sig1l(rs1: u32, rs2: u32) -> u32 {
+    let rd;
+    asm!(
+        "sha512sig1l {}, {}, {}",
+        lateout(reg) rd,
+        in(reg) rs1,
+        in(reg) rs2,
+        options(pure, nomem, nostack),
+    );
+    rd
+}
+
+/// One half of the SHA-512 `Σ0` function.
+///
+/// Returns the high half if `rs1` is the high and `rs2` the low half of the
+/// input, and the low half if they are swapped.
+#[inline]
+#[target_feature(enable = "zknh")]
+#[cfg_attr(test, assert_instr(sha512sum0r))]
+pub unsafe fn sha512sum0r(rs1: u32, rs2: u32) -> u32 {
+    let rd;
+    asm!(
+        "sha512sum0r {}, {}, {}",
+        lateout(reg) rd,
+        in(reg) rs1,
+        in(reg) rs2,
+        options(pure, nomem, nostack),
+    );
+    rd
+}
+
+/// One half of the SHA-512 `Σ1` function.
+///
+/// Returns the high half if `rs1` is the high and `rs2` the low half of the
+/// input, and the low half if they are swapped.
+#[inline]
+#[target_feature(enable = "zknh")]
+#[cfg_attr(test, assert_instr(sha512sum1r))]
+pub unsafe fn sha512sum1r(rs1: u32, rs2: u32) -> u32 {
+    let rd;
+    asm!(
+        "sha512sum1r {}, {}, {}",
+        lateout(reg) rd,
+        in(reg) rs1,
+        in(reg) rs2,
+        options(pure, nomem, nostack),
+    );
+    rd
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::riscv32::*;
+    use stdarch_test::simd_test;
+
+    // FIPS 197, appendix C.1.
+    const KEY: [u8; 16] = [
+        0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e,
+        0x0f,
+    ];
+    const PLAINTEXT: [u8; 16] = [
+        0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd, 0xee,
+        0xff,
+    ];
+    const CIPHERTEXT: [u8; 16] = [
+        0x69, 0xc4, 0xe0, 0xd8, 0x6a, 0x7b, 0x04, 0x30, 0xd8, 0xcd, 0xb7, 0x80, 0x70, 0xb4, 0xc5,
+        0x5a,
+    ];
+    const RCON: [u32; 10] = [0x01, 0x02, 0x04, 0x08, 0x10, 0x20, 0x40, 0x80, 0x1b, 0x36];
+
+    // The AES state and round keys are held as four little-endian columns.
+    fn load(b: &[u8; 16]) -> [u32; 4] {
+        let mut s = [0; 4];
+        for (s, c) in s.iter_mut().zip(b.chunks_exact(4)) {
+            *s = u32::from_le_bytes([c[0], c[1], c[2], c[3]]);
+        }
+        s
+    }
+
+    fn store(s: [u32; 4]) -> [u8; 16] {
+        let mut b = [0; 16];
+        for (c, s) in b.chunks_exact_mut(4).zip(&s) {
+            c.copy_from_slice(&s.to_le_bytes());
+        }
+        b
+    }
+
+    #[target_feature(enable = "zkne")]
+    unsafe fn sub_word(w: u32) -> u32 {
+        let w0 = aes32esi::<0>(0, w);
+        let w1 = aes32esi::<1>(w0, w);
+        let w2 = aes32esi::<2>(w1, w);
+        aes32esi::<3>(w2, w)
+    }
+
+    #[target_feature(enable = "zkne")]
+    unsafe fn aes128_key_schedule(key: &[u8; 16]) -> [[u32; 4]; 11] {
+        let mut rk = [load(key); 11];
+        for i in 0..10 {
+            let k = rk[i];
+            let w0 = k[0] ^ sub_word(k[3].rotate_right(8)) ^ RCON[i];
+            let w1 = k[1] ^ w0;
+            let w2 = k[2] ^ w1;
+            let w3 = k[3] ^ w2;
+            rk[i + 1] = [w0, w1, w2, w3];
+        }
+        rk
+    }
+
+    // Each output column collects one byte from each of four input columns,
+    // which is where ShiftRows happens.
+    macro_rules! aes_round {
+        ($f:ident, $s:expr, $k:expr, $shift:expr) => {{
+            let (s, k) = ($s, $k);
+            let mut n = [0; 4];
+            for j in 0..4 {
+                let col = |r: usize| s[(j + $shift(r)) % 4];
+                let n0 = $f::<0>(k[j], col(0));
+                let n1 = $f::<1>(n0, col(1));
+                let n2 = $f::<2>(n1, col(2));
+                n[j] = $f::<3>(n2, col(3));
+            }
+            n
+        }};
+    }
+
+    #[target_feature(enable = "zkne")]
+    unsafe fn aes128_encrypt(rk: &[[u32; 4]; 11], block: &[u8; 16]) -> [u8; 16] {
+        let b = load(block);
+        let mut s = [0; 4];
+        for j in 0..4 {
+            s[j] = b[j] ^ rk[0][j];
+        }
+        for k in &rk[1..10] {
+            s = aes_round!(aes32esmi, s, k, |r| r);
+        }
+        store(aes_round!(aes32esi, s, rk[10], |r| r))
+    }
+
+    // InvMixColumns on a round key: the S-box lookup of `aes32esi` cancels
+    // the inverse S-box lookup of `aes32dsmi`.
+    #[target_feature(enable = "zkne,zknd")]
+    unsafe fn inv_mix_columns(w: u32) -> u32 {
+        let w0 = aes32dsmi::<0>(0, aes32esi::<0>(0, w));
+        let w1 = aes32dsmi::<1>(w0, aes32esi::<1>(0, w));
+        let w2 = aes32dsmi::<2>(w1, aes32esi::<2>(0, w));
+        aes32dsmi::<3>(w2, aes32esi::<3>(0, w))
+    }
+
+    // Uses the equivalent inverse cipher, so the inner round keys have to go
+    // through InvMixColumns.
+    #[target_feature(enable = "zkne,zknd")]
+    unsafe fn aes128_decrypt(rk: &[[u32; 4]; 11], block: &[u8; 16]) -> [u8; 16] {
+        let b = load(block);
+        let mut s = [0; 4];
+        for j in 0..4 {
+            s[j] = b[j] ^ rk[10][j];
+        }
+        for k in rk[1..10].iter().rev() {
+            let mut k = *k;
+            for w in k.iter_mut() {
+                *w = inv_mix_columns(*w);
+            }
+            s = aes_round!(aes32dsmi, s, k, |r| 4 - r);
+        }
+        store(aes_round!(aes32dsi, s, rk[0], |r| 4 - r))
+    }
+
+    #[simd_test(enable = "zkne")]
+    unsafe fn test_aes32_encrypt() {
+        let rk = aes128_key_schedule(&KEY);
+        // The last round key from FIPS 197, appendix A.1.
+        assert_eq!(
+            store(rk[10]),
+            [
+                0x13, 0x11, 0x1d, 0x7f, 0xe3, 0x94, 0x4a, 0x17, 0xf3, 0x07, 0xa7, 0x8b, 0x4d, 0x2b,
+                0x30, 0xc5
+            ]
+        );
+        assert_eq!(aes128_encrypt(&rk, &PLAINTEXT), CIPHERTEXT);
+    }
+
+    #[simd_test(enable = "zkne,zknd")]
+    unsafe fn test_aes32_decrypt() {
+        let rk = aes128_key_schedule(&KEY);
+        assert_eq!(aes128_decrypt(&rk, &CIPHERTEXT), PLAINTEXT);
+    }
+
+    // The RV32 instructions each compute one half of the 64-bit functions.
+    #[simd_test(enable = "zknh")]
+    unsafe fn test_sha512() {
+        let values = [
+            0,
+            1,
+            u64::MAX,
+            1 << 63,
+            0x0123_4567_89ab_cdef,
+            0x6a09_e667_f3bc_c908,
+            0x510e_527f_ade6_82d1,
+        ];
+        for &x in values.iter() {
+            let (hi, lo) = ((x >> 32) as u32, x as u32);
+            let join = |hi: u32, lo: u32| (hi as u64) << 32 | lo as u64;
+            let r = |n| x.rotate_right(n);
+            assert_eq!(
+                join(sha512sig0h(hi, lo), sha512sig0l(lo, hi)),
+                r(1) ^ r(8) ^ (x >> 7)
+            );
+            assert_eq!(
+                join(sha512sig1h(hi, lo), sha512sig1l(lo, hi)),
+                r(19) ^ r(61) ^ (x >> 6)
+            );
+            assert_eq!(
+                join(sha512sum0r(hi, lo), sha512sum0r(lo, hi)),
+                r(28) ^ r(34) ^ r(39)
+            );
+            assert_eq!(
+                join(sha512sum1r(hi, lo), sha512sum1r(lo, hi)),
+                r(14) ^ r(18) ^ r(41)
+            );
+        }
+    }
+}
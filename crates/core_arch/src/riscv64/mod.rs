@@ -0,0 +1,9 @@
+//! RISC-V RV64 intrinsics.
+
+pub use crate::core_arch::riscv_shared::*;
+
+mod zbb;
+pub use self::zbb::*;
+
+mod zk;
+pub use self::zk::*;
@@ -0,0 +1,115 @@
+//! RV64-only word variants of the basic bit-manipulation (Zbb) instructions.
+//!
+//! These only look at the low 32 bits of their operands, like the
+//! corresponding `u32` methods.
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Counts the leading zero bits of `rs`.
+///
+/// Returns 32 if `rs` is zero.
+#[inline]
+#[target_feature(enable = "zbb")]
+#[cfg_attr(test, assert_instr(clzw))]
+pub unsafe fn clzw(rs: u32) -> u32 {
+    let rd;
+    asm!("clzw {}, {}", lateout(reg) rd, in(reg) rs, options(pure, nomem, nostack));
+    rd
+}
+
+/// Counts the trailing zero bits of `rs`.
+///
+/// Returns 32 if `rs` is zero.
+#[inline]
+#[target_feature(enable = "zbb")]
+#[cfg_attr(test, assert_instr(ctzw))]
+pub unsafe fn ctzw(rs: u32) -> u32 {
+    let rd;
+    asm!("ctzw {}, {}", lateout(reg) rd, in(reg) rs, options(pure, nomem, nostack));
+    rd
+}
+
+/// Counts the bits of `rs` that are set.
+#[inline]
+#[target_feature(enable = "zbb")]
+#[cfg_attr(test, assert_instr(cpopw))]
+pub unsafe fn cpopw(rs: u32) -> u32 {
+    let rd;
+    asm!("cpopw {}, {}", lateout(reg) rd, in(reg) rs, options(pure, nomem, nostack));
+    rd
+}
+
+/// Rotates `rs1` left by `rs2` bits.
+///
+/// Only the low 5 bits of `rs2` are used.
+#[inline]
+#[target_feature(enable = "zbb")]
+#[cfg_attr(test, assert_instr(rolw))]
+pub unsafe fn rolw(rs1: u32, rs2: u32) -> u32 {
+    let rd;
+    asm!(
+        "rolw {}, {}, {}",
+        lateout(reg) rd,
+        in(reg) rs1,
+        in(reg) rs2,
+        options(pure, nomem, nostack),
+    );
+    rd
+}
+
+/// Rotates `rs1` right by `rs2` bits.
+///
+/// Only the low 5 bits of `rs2` are used.
+#[inline]
+#[target_feature(enable = "zbb")]
+#[cfg_attr(test, assert_instr(rorw))]
+pub unsafe fn rorw(rs1: u32, rs2: u32) -> u32 {
+    let rd;
+    asm!(
+        "rorw {}, {}, {}",
+        lateout(reg) rd,
+        in(reg) rs1,
+        in(reg) rs2,
+        options(pure, nomem, nostack),
+    );
+    rd
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::riscv64::*;
+    use stdarch_test::simd_test;
+
+    const VALUES: [u32; 8] = [
+        0,
+        1,
+        u32::MAX,
+        0x8000_0000,
+        0x7fff_ffff,
+        0x0080_0100,
+        0x10ff_7f00,
+        0x89ab_cdef,
+    ];
+
+    #[simd_test(enable = "zbb")]
+    unsafe fn test_clzw_ctzw_cpopw() {
+        assert_eq!(clzw(0), 32);
+        assert_eq!(ctzw(0), 32);
+        for &a in VALUES.iter() {
+            assert_eq!(clzw(a), a.leading_zeros());
+            assert_eq!(ctzw(a), a.trailing_zeros());
+            assert_eq!(cpopw(a), a.count_ones());
+        }
+    }
+
+    #[simd_test(enable = "zbb")]
+    unsafe fn test_rolw_rorw() {
+        for &a in VALUES.iter() {
+            for n in 0..64 {
+                assert_eq!(rolw(a, n), a.rotate_left(n % 32));
+                assert_eq!(rorw(a, n), a.rotate_right(n % 32));
+            }
+        }
+    }
+}
@@ -0,0 +1,310 @@
+//! RV64 scalar cryptography, NIST suite (Zkn): AES and SHA-512.
+//!
+//! An AES state of 128 bits is held in two registers, each containing two
+//! columns of the state in little-endian byte order.
+//!
+//! The key schedule instructions `aes64ks1i` and `aes64ks2` are part of both
+//! Zkne and Zknd and are enabled with the `zkne` target feature. A CPU that
+//! implements either extension can execute them.
+//!
+//! The reference is the [RISC-V Cryptography Extensions][spec] specification,
+//! volume I.
+//!
+//! [spec]: https://github.com/riscv/riscv-crypto
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+macro_rules! zk_unary {
+    ($instr:literal, $rs:expr) => {{
+        let rd;
+        asm!(
+            concat!($instr, " {}, {}"),
+            lateout(reg) rd,
+            in(reg) $rs,
+            options(pure, nomem, nostack),
+        );
+        rd
+    }};
+}
+
+macro_rules! zk_binary {
+    ($instr:literal, $rs1:expr, $rs2:expr) => {{
+        let rd;
+        asm!(
+            concat!($instr, " {}, {}, {}"),
+            lateout(reg) rd,
+            in(reg) $rs1,
+            in(reg) $rs2,
+            options(pure, nomem, nostack),
+        );
+        rd
+    }};
+}
+
+/// AES final round encryption.
+///
+/// Applies ShiftRows and SubBytes to the state `rs1` (low half) and `rs2`
+/// (high half) and returns the low half of the result. Swap the operands to
+/// get the high half.
+#[inline]
+#[target_feature(enable = "zkne")]
+#[cfg_attr(test, assert_instr(aes64es))]
+pub unsafe fn aes64es(rs1: u64, rs2: u64) -> u64 {
+    zk_binary!("aes64es", rs1, rs2)
+}
+
+/// AES middle round encryption.
+///
+/// Like [`aes64es`], followed by MixColumns on the two resulting columns.
+#[inline]
+#[target_feature(enable = "zkne")]
+#[cfg_attr(test, assert_instr(aes64esm))]
+pub unsafe fn aes64esm(rs1: u64, rs2: u64) -> u64 {
+    zk_binary!("aes64esm", rs1, rs2)
+}
+
+/// AES final round decryption.
+///
+/// Applies InvShiftRows and InvSubBytes to the state `rs1` (low half) and
+/// `rs2` (high half) and returns the low half of the result. Swap the
+/// operands to get the high half.
+#[inline]
+#[target_feature(enable = "zknd")]
+#[cfg_attr(test, assert_instr(aes64ds))]
+pub unsafe fn aes64ds(rs1: u64, rs2: u64) -> u64 {
+    zk_binary!("aes64ds", rs1, rs2)
+}
+
+/// AES middle round decryption.
+///
+/// Like [`aes64ds`], followed by InvMixColumns on the two resulting columns.
+/// The round keys have to be transformed with [`aes64im`] accordingly.
+#[inline]
+#[target_feature(enable = "zknd")]
+#[cfg_attr(test, assert_instr(aes64dsm))]
+pub unsafe fn aes64dsm(rs1: u64, rs2: u64) -> u64 {
+    zk_binary!("aes64dsm", rs1, rs2)
+}
+
+/// AES InvMixColumns on the two columns in `rs1`.
+///
+/// This turns encryption round keys into the ones needed by the equivalent
+/// inverse cipher that [`aes64dsm`] implements.
+#[inline]
+#[target_feature(enable = "zknd")]
+#[cfg_attr(test, assert_instr(aes64im))]
+pub unsafe fn aes64im(rs1: u64) -> u64 {
+    zk_unary!("aes64im", rs1)
+}
+
+/// AES key schedule, first step.
+///
+/// Applies RotWord and SubWord to the high word of `rs1` and adds the round
+/// constant of round `RNUM`, returning the result in both words. `RNUM` has
+/// to be in `0..=10`. Round 10 skips RotWord and the round constant, which
+/// the AES-256 key schedule needs.
+#[inline]
+#[target_feature(enable = "zkne")]
+#[cfg_attr(test, assert_instr(aes64ks1i, RNUM = 0))]
+pub unsafe fn aes64ks1i<const RNUM: u8>(rs1: u64) -> u64 {
+    static_assert!(RNUM: u8 where RNUM <= 10);
+    let rd;
+    asm!(
+        "aes64ks1i {}, {}, {}",
+        lateout(reg) rd,
+        in(reg) rs1,
+        const RNUM,
+        options(pure, nomem, nostack),
+    );
+    rd
+}
+
+/// AES key schedule, second step.
+///
+/// XORs the high word of `rs1` into the words of the previous round key
+/// half `rs2`, returning the next round key half.
+#[inline]
+#[target_feature(enable = "zkne")]
+#[cfg_attr(test, assert_instr(aes64ks2))]
+pub unsafe fn aes64ks2(rs1: u64, rs2: u64) -> u64 {
+    zk_binary!("aes64ks2", rs1, rs2)
+}
+
+/// The SHA-512 `σ0` function.
+///
+/// Computes `rs1.rotate_right(1) ^ rs1.rotate_right(8) ^ (rs1 >> 7)`.
+#[inline]
+#[target_feature(enable = "zknh")]
+#[cfg_attr(test, assert_instr(sha512sig0))]
+pub unsafe fn sha512sig0(rs1: u64) -> u64 {
+    zk_unary!("sha512sig0", rs1)
+}
+
+/// The SHA-512 `σ1` function.
+///
+/// Computes `rs1.rotate_right(19) ^ rs1.rotate_right(61) ^ (rs1 >> 6)`.
+#[inline]
+#[target_feature(enable = "zknh")]
+#[cfg_attr(test, assert_instr(sha512sig1))]
+pub unsafe fn sha512sig1(rs1: u64) -> u64 {
+    zk_unary!("sha512sig1", rs1)
+}
+
+/// The SHA-512 `Σ0` function.
+///
+/// Computes `rs1.rotate_right(28) ^ rs1.rotate_right(34) ^ rs1.rotate_right(39)`.
+#[inline]
+#[target_feature(enable = "zknh")]
+#[cfg_attr(test, assert_instr(sha512sum0))]
+pub unsafe fn sha512sum0(rs1: u64) -> u64 {
+    zk_unary!("sha512sum0", rs1)
+}
+
+/// The SHA-512 `Σ1` function.
+///
+/// Computes `rs1.rotate_right(14) ^ rs1.rotate_right(18) ^ rs1.rotate_right(41)`.
+#[inline]
+#[target_feature(enable = "zknh")]
+#[cfg_attr(test, assert_instr(sha512sum1))]
+pub unsafe fn sha512sum1(rs1: u64) -> u64 {
+    zk_unary!("sha512sum1", rs1)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::riscv64::*;
+    use stdarch_test::simd_test;
+
+    // FIPS 197, appendix C.1.
+    const KEY: [u8; 16] = [
+        0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e,
+        0x0f,
+    ];
+    const PLAINTEXT: [u8; 16] = [
+        0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd, 0xee,
+        0xff,
+    ];
+    const CIPHERTEXT: [u8; 16] = [
+        0x69, 0xc4, 0xe0, 0xd8, 0x6a, 0x7b, 0x04, 0x30, 0xd8, 0xcd, 0xb7, 0x80, 0x70, 0xb4, 0xc5,
+        0x5a,
+    ];
+
+    // The 128-bit AES state and round keys are held in two registers, each
+    // holding two little-endian columns.
+    fn load(b: &[u8; 16]) -> [u64; 2] {
+        let mut lo = [0; 8];
+        let mut hi = [0; 8];
+        lo.copy_from_slice(&b[..8]);
+        hi.copy_from_slice(&b[8..]);
+        [u64::from_le_bytes(lo), u64::from_le_bytes(hi)]
+    }
+
+    fn store(s: [u64; 2]) -> [u8; 16] {
+        let mut b = [0; 16];
+        b[..8].copy_from_slice(&s[0].to_le_bytes());
+        b[8..].copy_from_slice(&s[1].to_le_bytes());
+        b
+    }
+
+    #[target_feature(enable = "zkne")]
+    unsafe fn aes128_key_schedule(key: &[u8; 16]) -> [[u64; 2]; 11] {
+        let mut rk = [load(key); 11];
+        macro_rules! round {
+            ($($i:literal)*) => {$(
+                let t = aes64ks1i::<$i>(rk[$i][1]);
+                rk[$i + 1][0] = aes64ks2(t, rk[$i][0]);
+                rk[$i + 1][1] = aes64ks2(rk[$i + 1][0], rk[$i][1]);
+            )*};
+        }
+        round!(0 1 2 3 4 5 6 7 8 9);
+        rk
+    }
+
+    #[target_feature(enable = "zkne")]
+    unsafe fn aes128_encrypt(rk: &[[u64; 2]; 11], block: &[u8; 16]) -> [u8; 16] {
+        let mut s = load(block);
+        s = [s[0] ^ rk[0][0], s[1] ^ rk[0][1]];
+        for k in &rk[1..10] {
+            s = [aes64esm(s[0], s[1]) ^ k[0], aes64esm(s[1], s[0]) ^ k[1]];
+        }
+        s = [
+            aes64es(s[0], s[1]) ^ rk[10][0],
+            aes64es(s[1], s[0]) ^ rk[10][1],
+        ];
+        store(s)
+    }
+
+    // Uses the equivalent inverse cipher, so the inner round keys have to go
+    // through InvMixColumns.
+    #[target_feature(enable = "zknd")]
+    unsafe fn aes128_decrypt(rk: &[[u64; 2]; 11], block: &[u8; 16]) -> [u8; 16] {
+        let mut s = load(block);
+        s = [s[0] ^ rk[10][0], s[1] ^ rk[10][1]];
+        for k in rk[1..10].iter().rev() {
+            s = [
+                aes64dsm(s[0], s[1]) ^ aes64im(k[0]),
+                aes64dsm(s[1], s[0]) ^ aes64im(k[1]),
+            ];
+        }
+        s = [
+            aes64ds(s[0], s[1]) ^ rk[0][0],
+            aes64ds(s[1], s[0]) ^ rk[0][1],
+        ];
+        store(s)
+    }
+
+    #[simd_test(enable = "zkne")]
+    unsafe fn test_aes64_encrypt() {
+        let rk = aes128_key_schedule(&KEY);
+        // The last round key from FIPS 197, appendix A.1.
+        assert_eq!(
+            store(rk[10]),
+            [
+                0x13, 0x11, 0x1d, 0x7f, 0xe3, 0x94, 0x4a, 0x17, 0xf3, 0x07, 0xa7, 0x8b, 0x4d, 0x2b,
+                0x30, 0xc5
+            ]
+        );
+        assert_eq!(aes128_encrypt(&rk, &PLAINTEXT), CIPHERTEXT);
+    }
+
+    #[simd_test(enable = "zkne,zknd")]
+    unsafe fn test_aes64_decrypt() {
+        let rk = aes128_key_schedule(&KEY);
+        assert_eq!(aes128_decrypt(&rk, &CIPHERTEXT), PLAINTEXT);
+    }
+
+    #[simd_test(enable = "zkne")]
+    unsafe fn test_aes64ks1i_last_round() {
+        // The round number 10 skips the rotation and the round constant.
+        assert_eq!(
+            aes64ks1i::<10>(0x0001_0203_0000_0000),
+            0x637c_777b_637c_777b
+        );
+        assert_eq!(aes64ks1i::<0>(0x0001_0203_0000_0000), 0x7b63_7c76_7b63_7c76);
+    }
+
+    fn values() -> impl Iterator<Item = u64> {
+        vec![
+            0,
+            1,
+            u64::MAX,
+            1 << 63,
+            0x0123_4567_89ab_cdef,
+            0x6a09_e667_f3bc_c908,
+            0x510e_527f_ade6_82d1,
+        ]
+        .into_iter()
+    }
+
+    #[simd_test(enable = "zknh")]
+    unsafe fn test_sha512() {
+        for x in values() {
+            let r = |n| x.rotate_right(n);
+            assert_eq!(sha512sig0(x), r(1) ^ r(8) ^ (x >> 7));
+            assert_eq!(sha512sig1(x), r(19) ^ r(61) ^ (x >> 6));
+            assert_eq!(sha512sum0(x), r(28) ^ r(34) ^ r(39));
+            assert_eq!(sha512sum1(x), r(14) ^ r(18) ^ r(41));
+        }
+    }
+}
@@ -0,0 +1,27 @@
+//! Intrinsics shared by the `riscv32` and `riscv64` platforms.
+//!
+//! Unless noted otherwise, integer operands are `XLEN` bits wide and are
+//! passed as `usize`/`isize`.
+//!
+//! The reference is the [RISC-V Instruction Set Manual][isa] together with
+//! the specifications of the individual ratified extensions.
+//!
+//! [isa]: https://github.com/riscv/riscv-isa-manual
+
+mod v;
+pub use self::v::*;
+
+mod zbb;
+pub use self::zbb::*;
+
+mod zbc;
+pub use self::zbc::*;
+
+mod zicbo;
+pub use self::zicbo::*;
+
+mod zk;
+pub use self::zk::*;
+
+mod zks;
+pub use self::zks::*;
@@ -0,0 +1,508 @@
+//! The RISC-V Vector extension (V): vector length configuration.
+//!
+//! RVV vectors are `VLEN` bits long, which is fixed by the hardware and only
+//! known at run-time, and instructions work on groups of `LMUL` of them. The
+//! RVV data and mask types (`vint32m1_t`, `vbool32_t`, ...) are therefore
+//! sizeless, like the SVE ones, which needs support for scalable vectors in
+//! the compiler that doesn't exist yet. Only the intrinsics that don't take
+//! or return vectors are provided for now: the ones that compute the vector
+//! length for an element width (`SEW`) and `LMUL`, which is what strip-mined
+//! loops are built around.
+//!
+//! `vsetvl_e<SEW><LMUL>(avl)` returns the number of elements `vl` that the
+//! next iteration of a loop over `avl` remaining elements processes. That is
+//! `avl` if it is at most `vsetvlmax_e<SEW><LMUL>()`, the maximum if `avl`
+//! is at least twice the maximum, and something in between otherwise. Note
+//! that the intrinsics don't leave the vector unit configured: inline
+//! assembly using the result has to execute its own `vsetvli`.
+//!
+//! The fractional `LMUL`s are provided for the element widths for which the
+//! V extension guarantees them. The names are those of the C intrinsics
+//! without their `__riscv_` prefix.
+//!
+//! The references are the [RISC-V "V" Vector Extension][spec] specification
+//! and the [RISC-V Vector C intrinsics][c_intrinsics].
+//!
+//! [spec]: https://github.com/riscv/riscv-v-spec
+//! [c_intrinsics]: https://github.com/riscv-non-isa/rvv-intrinsic-doc
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+extern "C" {
+    #[cfg_attr(target_arch = "riscv32", link_name = "llvm.riscv.vsetvli.i32")]
+    #[cfg_attr(target_arch = "riscv64", link_name = "llvm.riscv.vsetvli.i64")]
+    fn vsetvli(avl: usize, sew: usize, lmul: usize) -> usize;
+    #[cfg_attr(target_arch = "riscv32", link_name = "llvm.riscv.vsetvlimax.i32")]
+    #[cfg_attr(target_arch = "riscv64", link_name = "llvm.riscv.vsetvlimax.i64")]
+    fn vsetvlimax(sew: usize, lmul: usize) -> usize;
+}
+
+// The `vsew` and `vlmul` encodings of `vtype`.
+const E8: usize = 0;
+const E16: usize = 1;
+const E32: usize = 2;
+const E64: usize = 3;
+const M1: usize = 0;
+const M2: usize = 1;
+const M4: usize = 2;
+const M8: usize = 3;
+const MF8: usize = 5;
+const MF4: usize = 6;
+const MF2: usize = 7;
+
+/// Returns the vector length for `avl` 8-bit elements with LMUL 1/8.
+#[inline]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+pub unsafe fn vsetvl_e8mf8(avl: usize) -> usize {
+    vsetvli(avl, E8, MF8)
+}
+
+/// Returns the largest vector length for 8-bit elements with LMUL 1/8.
+#[inline]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+pub unsafe fn vsetvlmax_e8mf8() -> usize {
+    vsetvlimax(E8, MF8)
+}
+
+/// Returns the vector length for `avl` 8-bit elements with LMUL 1/4.
+#[inline]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+pub unsafe fn vsetvl_e8mf4(avl: usize) -> usize {
+    vsetvli(avl, E8, MF4)
+}
+
+/// Returns the largest vector length for 8-bit elements with LMUL 1/4.
+#[inline]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+pub unsafe fn vsetvlmax_e8mf4() -> usize {
+    vsetvlimax(E8, MF4)
+}
+
+/// Returns the vector length for `avl` 8-bit elements with LMUL 1/2.
+#[inline]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+pub unsafe fn vsetvl_e8mf2(avl: usize) -> usize {
+    vsetvli(avl, E8, MF2)
+}
+
+/// Returns the largest vector length for 8-bit elements with LMUL 1/2.
+#[inline]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+pub unsafe fn vsetvlmax_e8mf2() -> usize {
+    vsetvlimax(E8, MF2)
+}
+
+/// Returns the vector length for `avl` 8-bit elements with LMUL 1.
+#[inline]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+pub unsafe fn vsetvl_e8m1(avl: usize) -> usize {
+    vsetvli(avl, E8, M1)
+}
+
+/// Returns the largest vector length for 8-bit elements with LMUL 1.
+#[inline]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+pub unsafe fn vsetvlmax_e8m1() -> usize {
+    vsetvlimax(E8, M1)
+}
+
+/// Returns the vector length for `avl` 8-bit elements with LMUL 2.
+#[inline]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+pub unsafe fn vsetvl_e8m2(avl: usize) -> usize {
+    vsetvli(avl, E8, M2)
+}
+
+/// Returns the largest vector length for 8-bit elements with LMUL 2.
+#[inline]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+pub unsafe fn vsetvlmax_e8m2() -> usize {
+    vsetvlimax(E8, M2)
+}
+
+/// Returns the vector length for `avl` 8-bit elements with LMUL 4.
+#[inline]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+pub unsafe fn vsetvl_e8m4(avl: usize) -> usize {
+    vsetvli(avl, E8, M4)
+}
+
+/// Returns the largest vector length for 8-bit elements with LMUL 4.
+#[inline]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+pub unsafe fn vsetvlmax_e8m4() -> usize {
+    vsetvlimax(E8, M4)
+}
+
+/// Returns the vector length for `avl` 8-bit elements with LMUL 8.
+#[inline]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+pub unsafe fn vsetvl_e8m8(avl: usize) -> usize {
+    vsetvli(avl, E8, M8)
+}
+
+/// Returns the largest vector length for 8-bit elements with LMUL 8.
+#[inline]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+pub unsafe fn vsetvlmax_e8m8() -> usize {
+    vsetvlimax(E8, M8)
+}
+
+/// Returns the vector length for `avl` 16-bit elements with LMUL 1/4.
+#[inline]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+pub unsafe fn vsetvl_e16mf4(avl: usize) -> usize {
+    vsetvli(avl, E16, MF4)
+}
+
+/// Returns the largest vector length for 16-bit elements with LMUL 1/4.
+#[inline]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+pub unsafe fn vsetvlmax_e16mf4() -> usize {
+    vsetvlimax(E16, MF4)
+}
+
+/// Returns the vector length for `avl` 16-bit elements with LMUL 1/2.
+#[inline]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+pub unsafe fn vsetvl_e16mf2(avl: usize) -> usize {
+    vsetvli(avl, E16, MF2)
+}
+
+/// Returns the largest vector length for 16-bit elements with LMUL 1/2.
+#[inline]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+pub unsafe fn vsetvlmax_e16mf2() -> usize {
+    vsetvlimax(E16, MF2)
+}
+
+/// Returns the vector length for `avl` 16-bit elements with LMUL 1.
+#[inline]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+pub unsafe fn vsetvl_e16m1(avl: usize) -> usize {
+    vsetvli(avl, E16, M1)
+}
+
+/// Returns the largest vector length for 16-bit elements with LMUL 1.
+#[inline]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+pub unsafe fn vsetvlmax_e16m1() -> usize {
+    vsetvlimax(E16, M1)
+}
+
+/// Returns the vector length for `avl` 16-bit elements with LMUL 2.
+#[inline]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+pub unsafe fn vsetvl_e16m2(avl: usize) -> usize {
+    vsetvli(avl, E16, M2)
+}
+
+/// Returns the largest vector length for 16-bit elements with LMUL 2.
+#[inline]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+pub unsafe fn vsetvlmax_e16m2() -> usize {
+    vsetvlimax(E16, M2)
+}
+
+/// Returns the vector length for `avl` 16-bit elements with LMUL 4.
+#[inline]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+pub unsafe fn vsetvl_e16m4(avl: usize) -> usize {
+    vsetvli(avl, E16, M4)
+}
+
+/// Returns the largest vector length for 16-bit elements with LMUL 4.
+#[inline]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+pub unsafe fn vsetvlmax_e16m4() -> usize {
+    vsetvlimax(E16, M4)
+}
+
+/// Returns the vector length for `avl` 16-bit elements with LMUL 8.
+#[inline]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+pub unsafe fn vsetvl_e16m8(avl: usize) -> usize {
+    vsetvli(avl, E16, M8)
+}
+
+/// Returns the largest vector length for 16-bit elements with LMUL 8.
+#[inline]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+pub unsafe fn vsetvlmax_e16m8() -> usize {
+    vsetvlimax(E16, M8)
+}
+
+/// Returns the vector length for `avl` 32-bit elements with LMUL 1/2.
+#[inline]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+pub unsafe fn vsetvl_e32mf2(avl: usize) -> usize {
+    vsetvli(avl, E32, MF2)
+}
+
+/// Returns the largest vector length for 32-bit elements with LMUL 1/2.
+#[inline]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+pub unsafe fn vsetvlmax_e32mf2() -> usize {
+    vsetvlimax(E32, MF2)
+}
+
+/// Returns the vector length for `avl` 32-bit elements with LMUL 1.
+#[inline]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+pub unsafe fn vsetvl_e32m1(avl: usize) -> usize {
+    vsetvli(avl, E32, M1)
+}
+
+/// Returns the largest vector length for 32-bit elements with LMUL 1.
+#[inline]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+pub unsafe fn vsetvlmax_e32m1() -> usize {
+    vsetvlimax(E32, M1)
+}
+
+/// Returns the vector length for `avl` 32-bit elements with LMUL 2.
+#[inline]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+pub unsafe fn vsetvl_e32m2(avl: usize) -> usize {
+    vsetvli(avl, E32, M2)
+}
+
+/// Returns the largest vector length for 32-bit elements with LMUL 2.
+#[inline]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+pub unsafe fn vsetvlmax_e32m2() -> usize {
+    vsetvlimax(E32, M2)
+}
+
+/// Returns the vector length for `avl` 32-bit elements with LMUL 4.
+#[inline]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+pub unsafe fn vsetvl_e32m4(avl: usize) -> usize {
+    vsetvli(avl, E32, M4)
+}
+
+/// Returns the largest vector length for 32-bit elements with LMUL 4.
+#[inline]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+pub unsafe fn vsetvlmax_e32m4() -> usize {
+    vsetvlimax(E32, M4)
+}
+
+/// Returns the vector length for `avl` 32-bit elements with LMUL 8.
+#[inline]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+pub unsafe fn vsetvl_e32m8(avl: usize) -> usize {
+    vsetvli(avl, E32, M8)
+}
+
+/// Returns the largest vector length for 32-bit elements with LMUL 8.
+#[inline]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+pub unsafe fn vsetvlmax_e32m8() -> usize {
+    vsetvlimax(E32, M8)
+}
+
+/// Returns the vector length for `avl` 64-bit elements with LMUL 1.
+#[inline]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+pub unsafe fn vsetvl_e64m1(avl: usize) -> usize {
+    vsetvli(avl, E64, M1)
+}
+
+/// Returns the largest vector length for 64-bit elements with LMUL 1.
+#[inline]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+pub unsafe fn vsetvlmax_e64m1() -> usize {
+    vsetvlimax(E64, M1)
+}
+
+/// Returns the vector length for `avl` 64-bit elements with LMUL 2.
+#[inline]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+pub unsafe fn vsetvl_e64m2(avl: usize) -> usize {
+    vsetvli(avl, E64, M2)
+}
+
+/// Returns the largest vector length for 64-bit elements with LMUL 2.
+#[inline]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+pub unsafe fn vsetvlmax_e64m2() -> usize {
+    vsetvlimax(E64, M2)
+}
+
+/// Returns the vector length for `avl` 64-bit elements with LMUL 4.
+#[inline]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+pub unsafe fn vsetvl_e64m4(avl: usize) -> usize {
+    vsetvli(avl, E64, M4)
+}
+
+/// Returns the largest vector length for 64-bit elements with LMUL 4.
+#[inline]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+pub unsafe fn vsetvlmax_e64m4() -> usize {
+    vsetvlimax(E64, M4)
+}
+
+/// Returns the vector length for `avl` 64-bit elements with LMUL 8.
+#[inline]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+pub unsafe fn vsetvl_e64m8(avl: usize) -> usize {
+    vsetvli(avl, E64, M8)
+}
+
+/// Returns the largest vector length for 64-bit elements with LMUL 8.
+#[inline]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+pub unsafe fn vsetvlmax_e64m8() -> usize {
+    vsetvlimax(E64, M8)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::riscv_shared::*;
+    use stdarch_test::simd_test;
+
+    #[simd_test(enable = "v")]
+    unsafe fn test_vsetvlmax() {
+        // The V extension requires `VLEN` to be a power of two of at least
+        // 128 bits.
+        let vlmax = vsetvlmax_e8m1();
+        assert!(vlmax >= 16);
+        assert!(vlmax.is_power_of_two());
+
+        assert_eq!(vsetvlmax_e8mf8(), vlmax / 8);
+        assert_eq!(vsetvlmax_e8mf4(), vlmax / 4);
+        assert_eq!(vsetvlmax_e8mf2(), vlmax / 2);
+        assert_eq!(vsetvlmax_e8m2(), vlmax * 2);
+        assert_eq!(vsetvlmax_e8m4(), vlmax * 4);
+        assert_eq!(vsetvlmax_e8m8(), vlmax * 8);
+
+        assert_eq!(vsetvlmax_e16mf4(), vlmax / 8);
+        assert_eq!(vsetvlmax_e16mf2(), vlmax / 4);
+        assert_eq!(vsetvlmax_e16m1(), vlmax / 2);
+        assert_eq!(vsetvlmax_e16m2(), vlmax);
+        assert_eq!(vsetvlmax_e16m4(), vlmax * 2);
+        assert_eq!(vsetvlmax_e16m8(), vlmax * 4);
+
+        assert_eq!(vsetvlmax_e32mf2(), vlmax / 8);
+        assert_eq!(vsetvlmax_e32m1(), vlmax / 4);
+        assert_eq!(vsetvlmax_e32m2(), vlmax / 2);
+        assert_eq!(vsetvlmax_e32m4(), vlmax);
+        assert_eq!(vsetvlmax_e32m8(), vlmax * 2);
+
+        assert_eq!(vsetvlmax_e64m1(), vlmax / 8);
+        assert_eq!(vsetvlmax_e64m2(), vlmax / 4);
+        assert_eq!(vsetvlmax_e64m4(), vlmax / 2);
+        assert_eq!(vsetvlmax_e64m8(), vlmax);
+    }
+
+    fn check_vl(avl: usize, vl: usize, vlmax: usize) {
+        if avl <= vlmax {
+            assert_eq!(vl, avl);
+        } else if avl >= 2 * vlmax {
+            assert_eq!(vl, vlmax);
+        } else {
+            assert!((avl + 1) / 2 <= vl && vl <= vlmax, "{} {}", avl, vl);
+        }
+    }
+
+    #[simd_test(enable = "v")]
+    unsafe fn test_vsetvl() {
+        for avl in 0..=3 * vsetvlmax_e8m8() {
+            check_vl(avl, vsetvl_e8mf8(avl), vsetvlmax_e8mf8());
+            check_vl(avl, vsetvl_e8m8(avl), vsetvlmax_e8m8());
+            check_vl(avl, vsetvl_e16mf2(avl), vsetvlmax_e16mf2());
+            check_vl(avl, vsetvl_e32m1(avl), vsetvlmax_e32m1());
+            check_vl(avl, vsetvl_e32m4(avl), vsetvlmax_e32m4());
+            check_vl(avl, vsetvl_e64m2(avl), vsetvlmax_e64m2());
+        }
+        assert_eq!(vsetvl_e32m1(usize::MAX), vsetvlmax_e32m1());
+    }
+
+    // Computes `y = a * x + y`, strip-mined with the vector length from
+    // `vsetvl_e32m2` and with the loop body in inline assembly.
+    #[target_feature(enable = "v")]
+    unsafe fn saxpy(a: f32, x: &[f32], y: &mut [f32]) {
+        assert_eq!(x.len(), y.len());
+        let mut i = 0;
+        while i < x.len() {
+            let vl = vsetvl_e32m2(x.len() - i);
+            asm!(
+                "vsetvli zero, {vl}, e32, m2, ta, ma",
+                "vle32.v v8, ({x})",
+                "vle32.v v10, ({y})",
+                "vfmacc.vf v10, {a}, v8",
+                "vse32.v v10, ({y})",
+                vl = in(reg) vl,
+                x = in(reg) x.as_ptr().add(i),
+                y = in(reg) y.as_mut_ptr().add(i),
+                a = in(freg) a,
+                out("v8") _,
+                out("v9") _,
+                out("v10") _,
+                out("v11") _,
+                options(nostack),
+            );
+            i += vl;
+        }
+    }
+
+    #[simd_test(enable = "v")]
+    unsafe fn test_saxpy() {
+        let vlmax = vsetvlmax_e32m2();
+        for &len in [0, 1, vlmax - 1, vlmax, vlmax + 1, 3 * vlmax + 2, 1003].iter() {
+            let x: Vec<f32> = (0..len).map(|i| i as f32).collect();
+            let mut y: Vec<f32> = (0..len).map(|i| (i % 7) as f32).collect();
+            saxpy(2.5, &x, &mut y);
+            for (i, y) in y.iter().enumerate() {
+                assert_eq!(*y, 2.5 * i as f32 + (i % 7) as f32, "{} of {}", i, len);
+            }
+        }
+    }
+}
@@ -0,0 +1,282 @@
+//! Basic bit-manipulation (Zbb).
+//!
+//! The operands and results of these intrinsics are `XLEN` bits wide, that is
+//! `usize`/`isize`. The 32-bit word variants that only exist on RV64 are in
+//! the `riscv64` module.
+//!
+//! Several of them have portable equivalents in `core`, but those are only
+//! lowered to these instructions when the optimizer sees fit. The intrinsics
+//! always emit exactly the named instruction, which constant-time code relies
+//! on.
+//!
+//! The reference is the [RISC-V Bit-Manipulation ISA-extensions][spec]
+//! specification.
+//!
+//! [spec]: https://github.com/riscv/riscv-bitmanip
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+macro_rules! zbb_unary {
+    ($instr:literal, $rs:expr) => {{
+        let rd;
+        asm!(
+            concat!($instr, " {}, {}"),
+            lateout(reg) rd,
+            in(reg) $rs,
+            options(pure, nomem, nostack),
+        );
+        rd
+    }};
+}
+
+macro_rules! zbb_binary {
+    ($instr:literal, $rs1:expr, $rs2:expr) => {{
+        let rd;
+        asm!(
+            concat!($instr, " {}, {}, {}"),
+            lateout(reg) rd,
+            in(reg) $rs1,
+            in(reg) $rs2,
+            options(pure, nomem, nostack),
+        );
+        rd
+    }};
+}
+
+/// Bitwise OR-combine within each byte.
+///
+/// Every byte of the result is `0xff` if the corresponding byte of `rs` has
+/// any bit set, and `0x00` otherwise. This is mostly useful to find the zero
+/// byte terminating a string a whole register at a time.
+#[inline]
+#[target_feature(enable = "zbb")]
+#[cfg_attr(test, assert_instr(orc.b))]
+pub unsafe fn orc_b(rs: usize) -> usize {
+    zbb_unary!("orc.b", rs)
+}
+
+/// Reverses the order of the bytes in `rs`.
+#[inline]
+#[target_feature(enable = "zbb")]
+#[cfg_attr(test, assert_instr(rev8))]
+pub unsafe fn rev8(rs: usize) -> usize {
+    zbb_unary!("rev8", rs)
+}
+
+/// Counts the leading zero bits of `rs`.
+///
+/// Returns `XLEN` if `rs` is zero.
+#[inline]
+#[target_feature(enable = "zbb")]
+#[cfg_attr(test, assert_instr(clz))]
+pub unsafe fn clz(rs: usize) -> u32 {
+    zbb_unary!("clz", rs)
+}
+
+/// Counts the trailing zero bits of `rs`.
+///
+/// Returns `XLEN` if `rs` is zero.
+#[inline]
+#[target_feature(enable = "zbb")]
+#[cfg_attr(test, assert_instr(ctz))]
+pub unsafe fn ctz(rs: usize) -> u32 {
+    zbb_unary!("ctz", rs)
+}
+
+/// Counts the bits of `rs` that are set.
+#[inline]
+#[target_feature(enable = "zbb")]
+#[cfg_attr(test, assert_instr(cpop))]
+pub unsafe fn cpop(rs: usize) -> u32 {
+    zbb_unary!("cpop", rs)
+}
+
+/// Returns the larger of `rs1` and `rs2`, compared as signed integers.
+#[inline]
+#[target_feature(enable = "zbb")]
+#[cfg_attr(test, assert_instr(max))]
+pub unsafe fn max(rs1: isize, rs2: isize) -> isize {
+    zbb_binary!("max", rs1, rs2)
+}
+
+/// Returns the smaller of `rs1` and `rs2`, compared as signed integers.
+#[inline]
+#[target_feature(enable = "zbb")]
+#[cfg_attr(test, assert_instr(min))]
+pub unsafe fn min(rs1: isize, rs2: isize) -> isize {
+    zbb_binary!("min", rs1, rs2)
+}
+
+/// Returns the larger of `rs1` and `rs2`, compared as unsigned integers.
+#[inline]
+#[target_feature(enable = "zbb")]
+#[cfg_attr(test, assert_instr(maxu))]
+pub unsafe fn maxu(rs1: usize, rs2: usize) -> usize {
+    zbb_binary!("maxu", rs1, rs2)
+}
+
+/// Returns the smaller of `rs1` and `rs2`, compared as unsigned integers.
+#[inline]
+#[target_feature(enable = "zbb")]
+#[cfg_attr(test, assert_instr(minu))]
+pub unsafe fn minu(rs1: usize, rs2: usize) -> usize {
+    zbb_binary!("minu", rs1, rs2)
+}
+
+/// Computes `rs1 & !rs2`.
+#[inline]
+#[target_feature(enable = "zbb")]
+#[cfg_attr(test, assert_instr(andn))]
+pub unsafe fn andn(rs1: usize, rs2: usize) -> usize {
+    zbb_binary!("andn", rs1, rs2)
+}
+
+/// Computes `rs1 | !rs2`.
+#[inline]
+#[target_feature(enable = "zbb")]
+#[cfg_attr(test, assert_instr(orn))]
+pub unsafe fn orn(rs1: usize, rs2: usize) -> usize {
+    zbb_binary!("orn", rs1, rs2)
+}
+
+/// Computes `!(rs1 ^ rs2)`.
+#[inline]
+#[target_feature(enable = "zbb")]
+#[cfg_attr(test, assert_instr(xnor))]
+pub unsafe fn xnor(rs1: usize, rs2: usize) -> usize {
+    zbb_binary!("xnor", rs1, rs2)
+}
+
+/// Rotates `rs1` left by `rs2` bits.
+///
+/// Only the low `log2(XLEN)` bits of `rs2` are used.
+#[inline]
+#[target_feature(enable = "zbb")]
+#[cfg_attr(test, assert_instr(rol))]
+pub unsafe fn rol(rs1: usize, rs2: u32) -> usize {
+    zbb_binary!("rol", rs1, rs2)
+}
+
+/// Rotates `rs1` right by `rs2` bits.
+///
+/// Only the low `log2(XLEN)` bits of `rs2` are used.
+#[inline]
+#[target_feature(enable = "zbb")]
+#[cfg_attr(test, assert_instr(ror))]
+pub unsafe fn ror(rs1: usize, rs2: u32) -> usize {
+    zbb_binary!("ror", rs1, rs2)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::riscv_shared::*;
+    use stdarch_test::simd_test;
+
+    const BITS: u32 = usize::MAX.count_ones();
+
+    // Values exercising every byte and both ends of the register. The 64-bit
+    // constants are truncated on RV32.
+    fn values() -> impl Iterator<Item = usize> {
+        let mixed = 0x1000_ff7f_0080_0100_u64 as usize;
+        vec![
+            0,
+            1,
+            usize::MAX,
+            usize::MAX >> 1,
+            !(usize::MAX >> 1),
+            mixed,
+            !mixed,
+            mixed.rotate_left(13),
+            0x10ff_7f00,
+            0x0123_4567_89ab_cdef_u64 as usize,
+        ]
+        .into_iter()
+    }
+
+    #[simd_test(enable = "zbb")]
+    unsafe fn test_orc_b() {
+        assert_eq!(orc_b(0), 0);
+        assert_eq!(orc_b(usize::MAX), usize::MAX);
+        assert_eq!(orc_b(0x0001_8000), 0x00ff_ff00);
+        assert_eq!(orc_b(0x1000_0f01), 0xff00_ffff);
+        assert_eq!(orc_b(!(usize::MAX >> 1)), !(usize::MAX >> 8));
+        for a in values() {
+            let mut expected = a.to_ne_bytes();
+            for b in expected.iter_mut() {
+                *b = if *b == 0 { 0 } else { 0xff };
+            }
+            assert_eq!(orc_b(a), usize::from_ne_bytes(expected), "{:#x}", a);
+        }
+    }
+
+    #[simd_test(enable = "zbb")]
+    unsafe fn test_rev8() {
+        assert_eq!(rev8(0xff), !(usize::MAX >> 8));
+        for a in values() {
+            assert_eq!(rev8(a), a.swap_bytes());
+        }
+    }
+
+    #[simd_test(enable = "zbb")]
+    unsafe fn test_clz() {
+        assert_eq!(clz(0), BITS);
+        for a in values() {
+            assert_eq!(clz(a), a.leading_zeros());
+        }
+    }
+
+    #[simd_test(enable = "zbb")]
+    unsafe fn test_ctz() {
+        assert_eq!(ctz(0), BITS);
+        for a in values() {
+            assert_eq!(ctz(a), a.trailing_zeros());
+        }
+    }
+
+    #[simd_test(enable = "zbb")]
+    unsafe fn test_cpop() {
+        for a in values() {
+            assert_eq!(cpop(a), a.count_ones());
+        }
+    }
+
+    #[simd_test(enable = "zbb")]
+    unsafe fn test_max_min() {
+        assert_eq!(max(-1, 1), 1);
+        assert_eq!(min(-1, 1), -1);
+        assert_eq!(maxu(usize::MAX, 1), usize::MAX);
+        assert_eq!(minu(usize::MAX, 1), 1);
+        for a in values() {
+            for b in values() {
+                assert_eq!(max(a as isize, b as isize), (a as isize).max(b as isize));
+                assert_eq!(min(a as isize, b as isize), (a as isize).min(b as isize));
+                assert_eq!(maxu(a, b), a.max(b));
+                assert_eq!(minu(a, b), a.min(b));
+            }
+        }
+    }
+
+    #[simd_test(enable = "zbb")]
+    unsafe fn test_logic() {
+        for a in values() {
+            for b in values() {
+                assert_eq!(andn(a, b), a & !b);
+                assert_eq!(orn(a, b), a | !b);
+                assert_eq!(xnor(a, b), !(a ^ b));
+            }
+        }
+    }
+
+    #[simd_test(enable = "zbb")]
+    unsafe fn test_rol_ror() {
+        assert_eq!(rol(1, BITS - 1), !(usize::MAX >> 1));
+        assert_eq!(ror(1, 1), !(usize::MAX >> 1));
+        for a in values() {
+            for n in 0..2 * BITS {
+                assert_eq!(rol(a, n), a.rotate_left(n % BITS));
+                assert_eq!(ror(a, n), a.rotate_right(n % BITS));
+            }
+        }
+    }
+}
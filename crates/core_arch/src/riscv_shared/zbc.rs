@@ -0,0 +1,193 @@
+//! Carry-less multiplication (Zbc and Zbkc).
+//!
+//! The operands are `XLEN` bits wide and the three intrinsics return the low,
+//! high and reversed `XLEN` bits of the `2 * XLEN` bit product.
+//!
+//! `clmul` and `clmulh` are part of both Zbc and Zbkc and are enabled with
+//! the `zbkc` target feature, which is the smaller of the two. A CPU that
+//! implements either extension can execute them, so detect them with
+//! `is_riscv_feature_detected!("zbc") || is_riscv_feature_detected!("zbkc")`.
+//! `clmulr` is only part of Zbc.
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Carry-less multiply, returning the low `XLEN` bits of the product.
+#[inline]
+#[target_feature(enable = "zbkc")]
+#[cfg_attr(test, assert_instr(clmul))]
+pub unsafe fn clmul(rs1: usize, rs2: usize) -> usize {
+    let rd;
+    asm!(
+        "clmul {}, {}, {}",
+        lateout(reg) rd,
+        in(reg) rs1,
+        in(reg) rs2,
+        options(pure, nomem, nostack),
+    );
+    rd
+}
+
+/// Carry-less multiply, returning the high `XLEN` bits of the product.
+#[inline]
+#[target_feature(enable = "zbkc")]
+#[cfg_attr(test, assert_instr(clmulh))]
+pub unsafe fn clmulh(rs1: usize, rs2: usize) -> usize {
+    let rd;
+    asm!(
+        "clmulh {}, {}, {}",
+        lateout(reg) rd,
+        in(reg) rs1,
+        in(reg) rs2,
+        options(pure, nomem, nostack),
+    );
+    rd
+}
+
+/// Reversed carry-less multiply, returning bits `2 * XLEN - 2` to `XLEN - 1`
+/// of the product.
+///
+/// This is the same as bit-reversing the operands, taking the low half of
+/// their carry-less product and bit-reversing that, which is what
+/// computations on bit-reflected polynomials like CRC-32 need.
+#[inline]
+#[target_feature(enable = "zbc")]
+#[cfg_attr(test, assert_instr(clmulr))]
+pub unsafe fn clmulr(rs1: usize, rs2: usize) -> usize {
+    let rd;
+    asm!(
+        "clmulr {}, {}, {}",
+        lateout(reg) rd,
+        in(reg) rs1,
+        in(reg) rs2,
+        options(pure, nomem, nostack),
+    );
+    rd
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::riscv_shared::*;
+    use stdarch_test::simd_test;
+
+    const BITS: u32 = usize::MAX.count_ones();
+
+    // The full product as (low, high) halves.
+    fn clmul_ref(a: usize, b: usize) -> (usize, usize) {
+        let (mut lo, mut hi) = (0, 0);
+        for i in 0..BITS {
+            if b & (1 << i) != 0 {
+                lo ^= a << i;
+                if i != 0 {
+                    hi ^= a >> (BITS - i);
+                }
+            }
+        }
+        (lo, hi)
+    }
+
+    fn values() -> impl Iterator<Item = usize> {
+        vec![
+            0,
+            1,
+            3,
+            usize::MAX,
+            !(usize::MAX >> 1),
+            0x8765_4321,
+            0x1234_5678_9abc_def0_u64 as usize,
+            0x0fed_cba9_8765_4321_u64 as usize,
+        ]
+        .into_iter()
+    }
+
+    #[simd_test(enable = "zbkc")]
+    unsafe fn test_clmul() {
+        assert_eq!(clmul(3, 3), 5);
+        assert_eq!(clmul(usize::MAX, usize::MAX), usize::MAX / 3);
+        for a in values() {
+            for b in values() {
+                assert_eq!(clmul(a, b), clmul_ref(a, b).0);
+            }
+        }
+    }
+
+    #[simd_test(enable = "zbkc")]
+    unsafe fn test_clmulh() {
+        assert_eq!(clmulh(3, 3), 0);
+        assert_eq!(clmulh(!(usize::MAX >> 1), 2), 1);
+        for a in values() {
+            for b in values() {
+                assert_eq!(clmulh(a, b), clmul_ref(a, b).1);
+            }
+        }
+    }
+
+    #[simd_test(enable = "zbc")]
+    unsafe fn test_clmulr() {
+        let top = !(usize::MAX >> 1);
+        assert_eq!(clmulr(top, top), top);
+        assert_eq!(clmulr(usize::MAX, usize::MAX), !(usize::MAX / 3));
+        for a in values() {
+            for b in values() {
+                let (lo, hi) = clmul_ref(a, b);
+                assert_eq!(clmulr(a, b), (hi << 1) | (lo >> (BITS - 1)));
+                let reversed = clmul_ref(a.reverse_bits(), b.reverse_bits()).0;
+                assert_eq!(clmulr(a, b), reversed.reverse_bits());
+            }
+        }
+    }
+
+    // The reflected CRC-32 polynomial of IEEE 802.3 without its x^32 term,
+    // and floor(x^64 / P) without its x^32 term, bit-reversed.
+    const POLY: usize = 0xedb8_8320;
+    const MU: usize = 0xfb80_8b20;
+
+    fn crc32_table(data: &[u8]) -> u32 {
+        let mut table = [0_u32; 256];
+        for (i, entry) in table.iter_mut().enumerate() {
+            let mut c = i as u32;
+            for _ in 0..8 {
+                c = if c & 1 != 0 {
+                    (c >> 1) ^ POLY as u32
+                } else {
+                    c >> 1
+                };
+            }
+            *entry = c;
+        }
+        let mut crc = !0_u32;
+        for &b in data {
+            crc = table[((crc ^ b as u32) & 0xff) as usize] ^ (crc >> 8);
+        }
+        !crc
+    }
+
+    // Folds the data in one 32-bit word at a time with a Barrett reduction.
+    // The CRC register holds the bit-reflected remainder in its low 32 bits,
+    // so the quotient is placed at the top of the register for `clmulr` to
+    // return the reflected low half of `quotient * P`.
+    #[target_feature(enable = "zbc")]
+    unsafe fn crc32_clmul(data: &[u8]) -> u32 {
+        assert_eq!(data.len() % 4, 0);
+        let mut crc = !0_u32;
+        for word in data.chunks_exact(4) {
+            let t = (crc ^ u32::from_le_bytes([word[0], word[1], word[2], word[3]])) as usize;
+            let q = t ^ ((clmul(t, MU) << 1) & 0xffff_ffff);
+            crc = clmulr(q << (BITS - 32), POLY) as u32;
+        }
+        !crc
+    }
+
+    #[simd_test(enable = "zbc")]
+    unsafe fn test_crc32_clmulr() {
+        assert_eq!(crc32_table(b"123456789"), 0xcbf4_3926);
+
+        let fox = b"The quick brown fox jumps over the lazy dog.";
+        assert_eq!(crc32_clmul(fox), 0x5190_25e9);
+
+        let data: Vec<u8> = (0..256_u32).map(|i| ((i * 167) ^ (i >> 3)) as u8).collect();
+        for len in (0..=data.len()).step_by(4) {
+            assert_eq!(crc32_clmul(&data[..len]), crc32_table(&data[..len]));
+        }
+    }
+}
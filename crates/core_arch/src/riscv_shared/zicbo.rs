@@ -0,0 +1,165 @@
+//! Cache-block management (Zicbom), zeroing (Zicboz) and prefetching
+//! (Zicbop).
+//!
+//! Each of these operates on the whole cache block containing `addr`, not
+//! only on the bytes it points to. The blocks are naturally aligned and
+//! their size is a power of two that is fixed by the hardware but can't be
+//! read by software. The platform has to provide it: on Linux, the size of
+//! the blocks zeroed by [`cbo_zero`] is returned by [`zicboz_block_size`].
+//!
+//! Whether the management and zeroing instructions can be executed in user
+//! mode is controlled by the OS through the `senvcfg` register. Otherwise
+//! they trap, which Linux for example reports as `SIGILL`. Linux only
+//! allows `cbo.zero`.
+//!
+//! The prefetch instructions are hints that are encoded as `ori` with `x0` as
+//! destination, so they execute as no-ops on CPUs without Zicbop.
+//!
+//! The reference is the [RISC-V Base Cache Management Operation ISA
+//! Extensions][spec] specification.
+//!
+//! [spec]: https://github.com/riscv/riscv-CMOs
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Writes the cache block containing `addr` back to memory if it is dirty.
+#[inline]
+#[target_feature(enable = "zicbom")]
+#[cfg_attr(test, assert_instr(cbo.clean))]
+pub unsafe fn cbo_clean(addr: *const u8) {
+    asm!("cbo.clean ({})", in(reg) addr, options(nostack));
+}
+
+/// Writes the cache block containing `addr` back to memory if it is dirty,
+/// then invalidates it.
+#[inline]
+#[target_feature(enable = "zicbom")]
+#[cfg_attr(test, assert_instr(cbo.flush))]
+pub unsafe fn cbo_flush(addr: *const u8) {
+    asm!("cbo.flush ({})", in(reg) addr, options(nostack));
+}
+
+/// Invalidates the cache block containing `addr`.
+///
+/// Depending on the OS, this either discards stores to the block that
+/// haven't been written back to memory yet or performs [`cbo_flush`]. This
+/// is meant for buffers that a device writes to memory directly, before
+/// reading what it wrote.
+#[inline]
+#[target_feature(enable = "zicbom")]
+#[cfg_attr(test, assert_instr(cbo.inval))]
+pub unsafe fn cbo_inval(addr: *mut u8) {
+    asm!("cbo.inval ({})", in(reg) addr, options(nostack));
+}
+
+/// Zeroes the cache block containing `addr`.
+///
+/// The size of the block is given by [`zicboz_block_size`] on Linux.
+#[inline]
+#[target_feature(enable = "zicboz")]
+#[cfg_attr(test, assert_instr(cbo.zero))]
+pub unsafe fn cbo_zero(addr: *mut u8) {
+    asm!("cbo.zero ({})", in(reg) addr, options(nostack));
+}
+
+/// Hints that the cache block containing `addr` is going to be executed.
+#[inline]
+#[target_feature(enable = "zicbop")]
+#[cfg_attr(test, assert_instr(prefetch.i))]
+pub unsafe fn prefetch_i(addr: *const u8) {
+    asm!("prefetch.i 0({})", in(reg) addr, options(readonly, nostack));
+}
+
+/// Hints that the cache block containing `addr` is going to be read.
+#[inline]
+#[target_feature(enable = "zicbop")]
+#[cfg_attr(test, assert_instr(prefetch.r))]
+pub unsafe fn prefetch_r(addr: *const u8) {
+    asm!("prefetch.r 0({})", in(reg) addr, options(readonly, nostack));
+}
+
+/// Hints that the cache block containing `addr` is going to be written.
+#[inline]
+#[target_feature(enable = "zicbop")]
+#[cfg_attr(test, assert_instr(prefetch.w))]
+pub unsafe fn prefetch_w(addr: *const u8) {
+    asm!("prefetch.w 0({})", in(reg) addr, options(readonly, nostack));
+}
+
+/// Returns the size in bytes of the blocks zeroed by [`cbo_zero`].
+///
+/// This comes from the `riscv_hwprobe` system call of Linux, which returns
+/// `None` before Linux 6.7 or if not all CPUs implement Zicboz.
+#[cfg(target_os = "linux")]
+#[inline]
+pub unsafe fn zicboz_block_size() -> Option<usize> {
+    // The system call number, and the `RISCV_HWPROBE_KEY_ZICBOZ_BLOCK_SIZE`
+    // key and its value.
+    const SYS_RISCV_HWPROBE: usize = 258;
+    const KEY: i64 = 6;
+    let mut pair: [i64; 2] = [KEY, 0];
+    let ret: isize;
+    // A single pair and a zero-sized, null CPU set for the value common to
+    // all CPUs.
+    asm!(
+        "ecall",
+        inlateout("a0") pair.as_mut_ptr() => ret,
+        in("a1") 1_usize,
+        in("a2") 0_usize,
+        in("a3") 0_usize,
+        in("a4") 0_usize,
+        in("a7") SYS_RISCV_HWPROBE,
+        options(nostack),
+    );
+    // Unknown keys are reported by setting the key to -1.
+    if ret != 0 || pair[0] != KEY || pair[1] == 0 {
+        None
+    } else {
+        Some(pair[1] as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::riscv_shared::*;
+    use stdarch_test::simd_test;
+
+    // Three blocks of 4KiB, larger than any cache block in practice.
+    #[repr(C, align(4096))]
+    struct Blocks([u8; 3 * 4096]);
+
+    #[simd_test(enable = "zicboz")]
+    unsafe fn test_cbo_zero() {
+        let mut blocks = Blocks([0xff; 3 * 4096]);
+        // Zeroes the whole block containing the address, which starts at
+        // the second 4KiB block for all block sizes of at least 32 bytes.
+        cbo_zero(blocks.0.as_mut_ptr().add(4096 + 17));
+        let size = blocks.0[4096..].iter().take_while(|b| **b == 0).count();
+        assert!(size >= 32 && size <= 4096, "{}", size);
+        assert!(size.is_power_of_two(), "{}", size);
+        for (i, b) in blocks.0.iter().enumerate() {
+            let zeroed = i >= 4096 && i < 4096 + size;
+            assert_eq!(*b, if zeroed { 0 } else { 0xff }, "at {}", i);
+        }
+
+        #[cfg(target_os = "linux")]
+        {
+            if let Some(block_size) = zicboz_block_size() {
+                assert_eq!(block_size, size);
+            }
+        }
+    }
+
+    // The prefetch hints can be executed without Zicbop.
+    #[test]
+    fn test_prefetch() {
+        unsafe {
+            let x = [1_u8; 64];
+            prefetch_r(x.as_ptr());
+            prefetch_w(x.as_ptr());
+            prefetch_i(test_prefetch as *const u8);
+            assert_eq!(x, [1; 64]);
+        }
+    }
+}
@@ -0,0 +1,159 @@
+//! Scalar cryptography, NIST suite (Zkn): the SHA-256 functions.
+//!
+//! These are the same on RV32 and RV64, where they only look at the low 32
+//! bits of the register. The AES and SHA-512 instructions differ between the
+//! two and are in the `riscv32` and `riscv64` modules.
+//!
+//! The reference is the [RISC-V Cryptography Extensions][spec] specification,
+//! volume I.
+//!
+//! [spec]: https://github.com/riscv/riscv-crypto
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+macro_rules! zk_unary {
+    ($instr:literal, $rs:expr) => {{
+        let rd;
+        asm!(
+            concat!($instr, " {}, {}"),
+            lateout(reg) rd,
+            in(reg) $rs,
+            options(pure, nomem, nostack),
+        );
+        rd
+    }};
+}
+
+/// The SHA-256 `σ0` function.
+///
+/// Computes `rs1.rotate_right(7) ^ rs1.rotate_right(18) ^ (rs1 >> 3)`.
+#[inline]
+#[target_feature(enable = "zknh")]
+#[cfg_attr(test, assert_instr(sha256sig0))]
+pub unsafe fn sha256sig0(rs1: u32) -> u32 {
+    zk_unary!("sha256sig0", rs1)
+}
+
+/// The SHA-256 `σ1` function.
+///
+/// Computes `rs1.rotate_right(17) ^ rs1.rotate_right(19) ^ (rs1 >> 10)`.
+#[inline]
+#[target_feature(enable = "zknh")]
+#[cfg_attr(test, assert_instr(sha256sig1))]
+pub unsafe fn sha256sig1(rs1: u32) -> u32 {
+    zk_unary!("sha256sig1", rs1)
+}
+
+/// The SHA-256 `Σ0` function.
+///
+/// Computes `rs1.rotate_right(2) ^ rs1.rotate_right(13) ^ rs1.rotate_right(22)`.
+#[inline]
+#[target_feature(enable = "zknh")]
+#[cfg_attr(test, assert_instr(sha256sum0))]
+pub unsafe fn sha256sum0(rs1: u32) -> u32 {
+    zk_unary!("sha256sum0", rs1)
+}
+
+/// The SHA-256 `Σ1` function.
+///
+/// Computes `rs1.rotate_right(6) ^ rs1.rotate_right(11) ^ rs1.rotate_right(25)`.
+#[inline]
+#[target_feature(enable = "zknh")]
+#[cfg_attr(test, assert_instr(sha256sum1))]
+pub unsafe fn sha256sum1(rs1: u32) -> u32 {
+    zk_unary!("sha256sum1", rs1)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::riscv_shared::*;
+    use stdarch_test::simd_test;
+
+    const K: [u32; 64] = [
+        0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4,
+        0xab1c5ed5, 0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe,
+        0x9bdc06a7, 0xc19bf174, 0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f,
+        0x4a7484aa, 0x5cb0a9dc, 0x76f988da, 0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7,
+        0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967, 0x27b70a85, 0x2e1b2138, 0x4d2c6dfc,
+        0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85, 0xa2bfe8a1, 0xa81a664b,
+        0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070, 0x19a4c116,
+        0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+        0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7,
+        0xc67178f2,
+    ];
+
+    // SHA-256 with all the sigma functions going through the intrinsics.
+    #[target_feature(enable = "zknh")]
+    unsafe fn sha256(message: &[u8]) -> [u32; 8] {
+        let mut h: [u32; 8] = [
+            0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab,
+            0x5be0cd19,
+        ];
+        let mut padded = message.to_vec();
+        padded.push(0x80);
+        while padded.len() % 64 != 56 {
+            padded.push(0);
+        }
+        padded.extend_from_slice(&(message.len() as u64 * 8).to_be_bytes());
+
+        for block in padded.chunks_exact(64) {
+            let mut w = [0_u32; 64];
+            for (i, word) in block.chunks_exact(4).enumerate() {
+                w[i] = u32::from_be_bytes([word[0], word[1], word[2], word[3]]);
+            }
+            for i in 16..64 {
+                w[i] = sha256sig1(w[i - 2])
+                    .wrapping_add(w[i - 7])
+                    .wrapping_add(sha256sig0(w[i - 15]))
+                    .wrapping_add(w[i - 16]);
+            }
+            let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut hh] = h;
+            for i in 0..64 {
+                let t1 = hh
+                    .wrapping_add(sha256sum1(e))
+                    .wrapping_add((e & f) ^ (!e & g))
+                    .wrapping_add(K[i])
+                    .wrapping_add(w[i]);
+                let t2 = sha256sum0(a).wrapping_add((a & b) ^ (a & c) ^ (b & c));
+                hh = g;
+                g = f;
+                f = e;
+                e = d.wrapping_add(t1);
+                d = c;
+                c = b;
+                b = a;
+                a = t1.wrapping_add(t2);
+            }
+            for (h, v) in h.iter_mut().zip(&[a, b, c, d, e, f, g, hh]) {
+                *h = h.wrapping_add(*v);
+            }
+        }
+        h
+    }
+
+    #[simd_test(enable = "zknh")]
+    unsafe fn test_sha256() {
+        assert_eq!(
+            sha256(b""),
+            [
+                0xe3b0c442, 0x98fc1c14, 0x9afbf4c8, 0x996fb924, 0x27ae41e4, 0x649b934c, 0xa495991b,
+                0x7852b855
+            ]
+        );
+        assert_eq!(
+            sha256(b"abc"),
+            [
+                0xba7816bf, 0x8f01cfea, 0x414140de, 0x5dae2223, 0xb00361a3, 0x96177a9c, 0xb410ff61,
+                0xf20015ad
+            ]
+        );
+        assert_eq!(
+            sha256(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq"),
+            [
+                0x248d6a61, 0xd20638b8, 0xe5c02693, 0x0c3e6039, 0xa33ce459, 0x64ff2167, 0xf6ecedd4,
+                0x19db06c1
+            ]
+        );
+    }
+}
@@ -0,0 +1,237 @@
+//! Scalar cryptography, ShangMi suite (Zks): SM3 and SM4.
+//!
+//! The SM4 instructions process a single byte of a word at a time, like the
+//! RV32 AES instructions. `BS` selects the byte of `rs2` that is looked up in
+//! the S-box; the linear transform of the result is rotated into the same
+//! byte position and XORed into `rs1`. Applying all four byte positions to
+//! the same `rs2` computes the full round function `T` (with `sm4ed`) or the
+//! key schedule function `T'` (with `sm4ks`).
+//!
+//! The intrinsics only look at the low 32 bits of the register on RV64.
+//!
+//! The reference is the [RISC-V Cryptography Extensions][spec] specification,
+//! volume I.
+//!
+//! [spec]: https://github.com/riscv/riscv-crypto
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// The SM3 `P0` permutation.
+///
+/// Computes `rs1 ^ rs1.rotate_left(9) ^ rs1.rotate_left(17)`.
+#[inline]
+#[target_feature(enable = "zksh")]
+#[cfg_attr(test, assert_instr(sm3p0))]
+pub unsafe fn sm3p0(rs1: u32) -> u32 {
+    let rd;
+    asm!("sm3p0 {}, {}", lateout(reg) rd, in(reg) rs1, options(pure, nomem, nostack));
+    rd
+}
+
+/// The SM3 `P1` permutation.
+///
+/// Computes `rs1 ^ rs1.rotate_left(15) ^ rs1.rotate_left(23)`.
+#[inline]
+#[target_feature(enable = "zksh")]
+#[cfg_attr(test, assert_instr(sm3p1))]
+pub unsafe fn sm3p1(rs1: u32) -> u32 {
+    let rd;
+    asm!("sm3p1 {}, {}", lateout(reg) rd, in(reg) rs1, options(pure, nomem, nostack));
+    rd
+}
+
+/// SM4 encryption and decryption round.
+///
+/// Applies the S-box to byte `BS` of `rs2`, followed by the linear transform
+/// `L` of the round function, and XORs the result into `rs1`.
+#[inline]
+#[target_feature(enable = "zksed")]
+#[cfg_attr(test, assert_instr(sm4ed, BS = 0))]
+pub unsafe fn sm4ed<const BS: u8>(rs1: u32, rs2: u32) -> u32 {
+    static_assert!(BS: u8 where BS <= 3);
+    let rd;
+    asm!(
+        "sm4ed {}, {}, {}, {}",
+        lateout(reg) rd,
+        in(reg) rs1,
+        in(reg) rs2,
+        const BS,
+        options(pure, nomem, nostack),
+    );
+    rd
+}
+
+/// SM4 key schedule round.
+///
+/// Applies the S-box to byte `BS` of `rs2`, followed by the linear transform
+/// `L'` of the key schedule, and XORs the result into `rs1`.
+#[inline]
+#[target_feature(enable = "zksed")]
+#[cfg_attr(test, assert_instr(sm4ks, BS = 0))]
+pub unsafe fn sm4ks<const BS: u8>(rs1: u32, rs2: u32) -> u32 {
+    static_assert!(BS: u8 where BS <= 3);
+    let rd;
+    asm!(
+        "sm4ks {}, {}, {}, {}",
+        lateout(reg) rd,
+        in(reg) rs1,
+        in(reg) rs2,
+        const BS,
+        options(pure, nomem, nostack),
+    );
+    rd
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::riscv_shared::*;
+    use stdarch_test::simd_test;
+
+    #[simd_test(enable = "zksh")]
+    unsafe fn test_sm3p0_sm3p1() {
+        for &x in [0, 1, u32::MAX, 0x8000_0001, 0x0123_4567, 0xfedc_ba98].iter() {
+            assert_eq!(sm3p0(x), x ^ x.rotate_left(9) ^ x.rotate_left(17));
+            assert_eq!(sm3p1(x), x ^ x.rotate_left(15) ^ x.rotate_left(23));
+        }
+    }
+
+    // The SM3 compression function, with both permutations going through
+    // the intrinsics.
+    #[target_feature(enable = "zksh")]
+    unsafe fn sm3(message: &[u8]) -> [u32; 8] {
+        let mut v: [u32; 8] = [
+            0x7380166f, 0x4914b2b9, 0x172442d7, 0xda8a0600, 0xa96f30bc, 0x163138aa, 0xe38dee4d,
+            0xb0fb0e4e,
+        ];
+        let mut padded = message.to_vec();
+        padded.push(0x80);
+        while padded.len() % 64 != 56 {
+            padded.push(0);
+        }
+        padded.extend_from_slice(&(message.len() as u64 * 8).to_be_bytes());
+
+        for block in padded.chunks_exact(64) {
+            let mut w = [0_u32; 68];
+            for (i, word) in block.chunks_exact(4).enumerate() {
+                w[i] = u32::from_be_bytes([word[0], word[1], word[2], word[3]]);
+            }
+            for j in 16..68 {
+                w[j] = sm3p1(w[j - 16] ^ w[j - 9] ^ w[j - 3].rotate_left(15))
+                    ^ w[j - 13].rotate_left(7)
+                    ^ w[j - 6];
+            }
+            let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = v;
+            for j in 0..64 {
+                let t: u32 = if j < 16 { 0x79cc4519 } else { 0x7a879d8a };
+                let a12 = a.rotate_left(12);
+                let ss1 = a12
+                    .wrapping_add(e)
+                    .wrapping_add(t.rotate_left(j as u32 % 32))
+                    .rotate_left(7);
+                let ss2 = ss1 ^ a12;
+                let (ff, gg) = if j < 16 {
+                    (a ^ b ^ c, e ^ f ^ g)
+                } else {
+                    ((a & b) | (a & c) | (b & c), (e & f) | (!e & g))
+                };
+                let tt1 = ff
+                    .wrapping_add(d)
+                    .wrapping_add(ss2)
+                    .wrapping_add(w[j] ^ w[j + 4]);
+                let tt2 = gg.wrapping_add(h).wrapping_add(ss1).wrapping_add(w[j]);
+                d = c;
+                c = b.rotate_left(9);
+                b = a;
+                a = tt1;
+                h = g;
+                g = f.rotate_left(19);
+                f = e;
+                e = sm3p0(tt2);
+            }
+            for (v, x) in v.iter_mut().zip(&[a, b, c, d, e, f, g, h]) {
+                *v ^= *x;
+            }
+        }
+        v
+    }
+
+    #[simd_test(enable = "zksh")]
+    unsafe fn test_sm3() {
+        assert_eq!(
+            sm3(b"abc"),
+            [
+                0x66c7f0f4, 0x62eeedd9, 0xd1f2d46b, 0xdc10e4e2, 0x4167c487, 0x5cf2f7a2, 0x297da02b,
+                0x8f4ba8e0
+            ]
+        );
+        assert_eq!(
+            sm3(&b"abcd".repeat(16)),
+            [
+                0xdebe9ff9, 0x2275b8a1, 0x38604889, 0xc18e5a4d, 0x6fdb70e5, 0x387e5765, 0x293dcba3,
+                0x9c0c5732
+            ]
+        );
+    }
+
+    #[target_feature(enable = "zksed")]
+    unsafe fn sm4_t_key(rs1: u32, x: u32) -> u32 {
+        let rs1 = sm4ks::<0>(rs1, x);
+        let rs1 = sm4ks::<1>(rs1, x);
+        let rs1 = sm4ks::<2>(rs1, x);
+        sm4ks::<3>(rs1, x)
+    }
+
+    #[target_feature(enable = "zksed")]
+    unsafe fn sm4_t(rs1: u32, x: u32) -> u32 {
+        let rs1 = sm4ed::<0>(rs1, x);
+        let rs1 = sm4ed::<1>(rs1, x);
+        let rs1 = sm4ed::<2>(rs1, x);
+        sm4ed::<3>(rs1, x)
+    }
+
+    #[target_feature(enable = "zksed")]
+    unsafe fn sm4_expand_key(key: [u32; 4]) -> [u32; 32] {
+        const FK: [u32; 4] = [0xa3b1bac6, 0x56aa3350, 0x677d9197, 0xb27022dc];
+        let mut k = [
+            key[0] ^ FK[0],
+            key[1] ^ FK[1],
+            key[2] ^ FK[2],
+            key[3] ^ FK[3],
+        ];
+        let mut rk = [0_u32; 32];
+        for (i, rk) in rk.iter_mut().enumerate() {
+            let mut ck = [0_u8; 4];
+            for (j, b) in ck.iter_mut().enumerate() {
+                *b = ((4 * i + j) * 7) as u8;
+            }
+            let ck = u32::from_be_bytes(ck);
+            *rk = sm4_t_key(k[0], k[1] ^ k[2] ^ k[3] ^ ck);
+            k = [k[1], k[2], k[3], *rk];
+        }
+        rk
+    }
+
+    #[target_feature(enable = "zksed")]
+    unsafe fn sm4_crypt(rk: impl Iterator<Item = u32>, block: [u32; 4]) -> [u32; 4] {
+        let mut x = block;
+        for rk in rk {
+            let next = sm4_t(x[0], x[1] ^ x[2] ^ x[3] ^ rk);
+            x = [x[1], x[2], x[3], next];
+        }
+        [x[3], x[2], x[1], x[0]]
+    }
+
+    #[simd_test(enable = "zksed")]
+    unsafe fn test_sm4() {
+        // The example from appendix A of GB/T 32907-2016.
+        let key = [0x01234567, 0x89abcdef, 0xfedcba98, 0x76543210];
+        let ciphertext = [0x681edf34, 0xd206965e, 0x86b3e94f, 0x536e4246];
+
+        let rk = sm4_expand_key(key);
+        assert_eq!(rk[0], 0xf12186f9);
+        assert_eq!(rk[31], 0x9124a012);
+        assert_eq!(sm4_crypt(rk.iter().copied(), key), ciphertext);
+        assert_eq!(sm4_crypt(rk.iter().rev().copied(), ciphertext), key);
+    }
+}
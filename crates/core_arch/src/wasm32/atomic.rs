@@ -1,6 +1,8 @@
 #[cfg(test)]
 use stdarch_test::assert_instr;
 
+use crate::{convert::TryFrom, time::Duration};
+
 extern "C" {
     #[link_name = "llvm.wasm.memory.atomic.wait32"]
     fn llvm_atomic_wait_i32(ptr: *mut i32, exp: i32, timeout: i64) -> i32;
@@ -88,3 +90,139 @@ pub unsafe fn memory_atomic_wait64(ptr: *mut i64, expression: i64, timeout_ns: i
 pub unsafe fn memory_atomic_notify(ptr: *mut i32, waiters: u32) -> u32 {
     llvm_atomic_notify(ptr, waiters as i32) as u32
 }
+
+/// The outcome of [`atomic_wait32`] and [`atomic_wait64`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum WaitResult {
+    /// The thread blocked and was then woken up by [`memory_atomic_notify`].
+    Woken,
+    /// The value behind the pointer didn't match the expected value, so the
+    /// thread didn't block.
+    ValueMismatch,
+    /// The thread blocked, but the timeout expired before it was woken up.
+    TimedOut,
+}
+
+impl WaitResult {
+    #[inline]
+    fn from_raw(ret: i32) -> WaitResult {
+        match ret {
+            0 => WaitResult::Woken,
+            1 => WaitResult::ValueMismatch,
+            _ => WaitResult::TimedOut,
+        }
+    }
+}
+
+// The `wait` instructions take a signed number of nanoseconds, where a
+// negative value means waiting forever. Timeouts too long to represent are
+// rounded down to about 292 years.
+#[inline]
+fn timeout_ns(timeout: Option<Duration>) -> i64 {
+    match timeout {
+        Some(timeout) => i64::try_from(timeout.as_nanos()).unwrap_or(i64::MAX),
+        None => -1,
+    }
+}
+
+/// Blocks the current thread while the 32-bit value at `ptr` is equal to
+/// `expected`, until it's woken up by [`memory_atomic_notify`] or `timeout`
+/// expires.
+///
+/// This is [`memory_atomic_wait32`] with a typed timeout and result: `None`
+/// waits forever.
+///
+/// # Safety
+///
+/// `ptr` must be aligned to 4 bytes and point into the linear memory of the
+/// module. The wait instruction traps if it doesn't, and also if the memory
+/// isn't a shared memory, as is the case unless the module was built with the
+/// `atomics` target feature and linked with `--shared-memory`. Some
+/// embeddings also trap when waiting on a thread which isn't allowed to
+/// block, like the main thread of a web browser.
+#[inline]
+#[target_feature(enable = "atomics")]
+pub unsafe fn atomic_wait32(ptr: *mut i32, expected: i32, timeout: Option<Duration>) -> WaitResult {
+    WaitResult::from_raw(memory_atomic_wait32(ptr, expected, timeout_ns(timeout)))
+}
+
+/// Blocks the current thread while the 64-bit value at `ptr` is equal to
+/// `expected`, until it's woken up by [`memory_atomic_notify`] or `timeout`
+/// expires.
+///
+/// This is [`memory_atomic_wait64`] with a typed timeout and result: `None`
+/// waits forever.
+///
+/// # Safety
+///
+/// `ptr` must be aligned to 8 bytes and point into the linear memory of the
+/// module. The wait instruction traps if it doesn't, and also if the memory
+/// isn't a shared memory, as is the case unless the module was built with the
+/// `atomics` target feature and linked with `--shared-memory`. Some
+/// embeddings also trap when waiting on a thread which isn't allowed to
+/// block, like the main thread of a web browser.
+#[inline]
+#[target_feature(enable = "atomics")]
+pub unsafe fn atomic_wait64(ptr: *mut i64, expected: i64, timeout: Option<Duration>) -> WaitResult {
+    WaitResult::from_raw(memory_atomic_wait64(ptr, expected, timeout_ns(timeout)))
+}
+
+// Waiting needs a shared memory, and notifying from another thread needs a
+// runtime with threads, so these only run when the tests are built for one
+// with `-Ctarget-feature=+atomics`.
+#[cfg(all(test, target_feature = "atomics"))]
+mod tests {
+    use super::*;
+    use std::{
+        sync::atomic::{AtomicI32, AtomicI64, Ordering},
+        thread,
+        time::{Duration, Instant},
+    };
+
+    #[test]
+    fn test_timeout_ns() {
+        assert_eq!(timeout_ns(None), -1);
+        assert_eq!(timeout_ns(Some(Duration::from_micros(3))), 3000);
+        assert_eq!(timeout_ns(Some(Duration::from_secs(u64::MAX))), i64::MAX);
+    }
+
+    #[test]
+    fn test_atomic_wait32_mismatch_and_timeout() {
+        let x = AtomicI32::new(1);
+        let ptr = &x as *const AtomicI32 as *mut i32;
+        unsafe {
+            assert_eq!(atomic_wait32(ptr, 0, None), WaitResult::ValueMismatch);
+            let start = Instant::now();
+            let timeout = Duration::from_millis(10);
+            assert_eq!(atomic_wait32(ptr, 1, Some(timeout)), WaitResult::TimedOut);
+            assert!(start.elapsed() >= timeout);
+        }
+    }
+
+    #[test]
+    fn test_atomic_wait64_mismatch_and_timeout() {
+        let x = AtomicI64::new(1 << 40);
+        let ptr = &x as *const AtomicI64 as *mut i64;
+        unsafe {
+            assert_eq!(atomic_wait64(ptr, 1, None), WaitResult::ValueMismatch);
+            let timeout = Some(Duration::from_millis(10));
+            assert_eq!(atomic_wait64(ptr, 1 << 40, timeout), WaitResult::TimedOut);
+        }
+    }
+
+    #[test]
+    fn test_atomic_wait32_notify() {
+        static X: AtomicI32 = AtomicI32::new(0);
+        let address = || &X as *const AtomicI32 as *mut i32;
+        let waiter = thread::spawn(move || unsafe { atomic_wait32(address(), 0, None) });
+        // The value never changes, so the waiter blocks until it's notified.
+        // Notify until it's actually waiting.
+        unsafe {
+            while memory_atomic_notify(address(), 1) == 0 {
+                thread::yield_now();
+            }
+        }
+        assert_eq!(waiter.join().unwrap(), WaitResult::Woken);
+        assert_eq!(X.load(Ordering::SeqCst), 0);
+    }
+}
@@ -0,0 +1,750 @@
+//! This module implements the `f16x8` instructions of the [WebAssembly
+//! half-precision proposal].
+//!
+//! The proposal doesn't add a scalar `f16` type to WebAssembly: lanes are
+//! extracted as `f32` values, and the `f32` arguments of `f16x8_splat` and
+//! `f16x8_replace_lane` are rounded to half precision. The same is done
+//! here since Rust doesn't have a half-precision floating point type either.
+//!
+//! [WebAssembly half-precision proposal]:
+//! https://github.com/WebAssembly/half-precision/blob/main/proposals/half-precision/Overview.md
+
+#![unstable(feature = "wasm_f16x8", issue = "none")]
+#![allow(non_camel_case_types)]
+
+use super::v128;
+use crate::mem::MaybeUninit;
+
+#[cfg(all(test, target_feature = "fp16"))]
+use stdarch_test::assert_instr;
+
+// LLVM models these instructions on vectors of `half`, which Rust can't pass
+// to LLVM intrinsics, so they're written in inline assembly instead. Inline
+// assembly can't take `v128` operands either, so the vector operands and the
+// result are passed through memory.
+macro_rules! f16x8_op {
+    ($inst:expr, $a:expr) => {{
+        let mut dst = MaybeUninit::<v128>::uninit();
+        asm!(
+            "local.get {dst}",
+            "local.get {a}",
+            "v128.load 0",
+            $inst,
+            "v128.store 0",
+            dst = in(local) dst.as_mut_ptr(),
+            a = in(local) &$a as *const v128,
+            options(nostack),
+        );
+        dst.assume_init()
+    }};
+    ($inst:expr, $a:expr, $b:expr) => {{
+        let mut dst = MaybeUninit::<v128>::uninit();
+        asm!(
+            "local.get {dst}",
+            "local.get {a}",
+            "v128.load 0",
+            "local.get {b}",
+            "v128.load 0",
+            $inst,
+            "v128.store 0",
+            dst = in(local) dst.as_mut_ptr(),
+            a = in(local) &$a as *const v128,
+            b = in(local) &$b as *const v128,
+            options(nostack),
+        );
+        dst.assume_init()
+    }};
+    ($inst:expr, $a:expr, $b:expr, $c:expr) => {{
+        let mut dst = MaybeUninit::<v128>::uninit();
+        asm!(
+            "local.get {dst}",
+            "local.get {a}",
+            "v128.load 0",
+            "local.get {b}",
+            "v128.load 0",
+            "local.get {c}",
+            "v128.load 0",
+            $inst,
+            "v128.store 0",
+            dst = in(local) dst.as_mut_ptr(),
+            a = in(local) &$a as *const v128,
+            b = in(local) &$b as *const v128,
+            c = in(local) &$c as *const v128,
+            options(nostack),
+        );
+        dst.assume_init()
+    }};
+}
+
+/// Creates a vector with identical lanes.
+///
+/// Constructs a vector with `a` rounded to half precision and replicated to
+/// all 8 lanes.
+#[inline]
+#[cfg_attr(all(test, target_feature = "fp16"), assert_instr(f16x8.splat))]
+#[target_feature(enable = "fp16")]
+pub fn f16x8_splat(a: f32) -> v128 {
+    unsafe {
+        let mut dst = MaybeUninit::<v128>::uninit();
+        asm!(
+            "local.get {dst}",
+            "local.get {a}",
+            "f16x8.splat",
+            "v128.store 0",
+            dst = in(local) dst.as_mut_ptr(),
+            a = in(local) a,
+            options(nostack),
+        );
+        dst.assume_init()
+    }
+}
+
+/// Extracts a lane from a 128-bit vector interpreted as 8 packed f16 numbers.
+///
+/// Extracts the scalar value of lane specified in the immediate mode operand
+/// `N` from `a`, converted to `f32`. If `N` is out of bounds then it is a
+/// compile time error.
+#[inline]
+#[cfg_attr(
+    all(test, target_feature = "fp16"),
+    assert_instr(f16x8.extract_lane, N = 1)
+)]
+#[target_feature(enable = "fp16")]
+pub fn f16x8_extract_lane<const N: usize>(a: v128) -> f32 {
+    static_assert!(N: usize where N < 8);
+    unsafe {
+        let dst: f32;
+        asm!(
+            "local.get {a}",
+            "v128.load 0",
+            "f16x8.extract_lane {n}",
+            "local.set {dst}",
+            a = in(local) &a as *const v128,
+            n = const N,
+            dst = lateout(local) dst,
+            options(nostack, readonly),
+        );
+        dst
+    }
+}
+
+/// Replaces a lane from a 128-bit vector interpreted as 8 packed f16 numbers.
+///
+/// Replaces the scalar value of lane specified in the immediate mode operand
+/// `N` from `a` with `val` rounded to half precision. If `N` is out of bounds
+/// then it is a compile time error.
+#[inline]
+#[cfg_attr(
+    all(test, target_feature = "fp16"),
+    assert_instr(f16x8.replace_lane, N = 1)
+)]
+#[target_feature(enable = "fp16")]
+pub fn f16x8_replace_lane<const N: usize>(a: v128, val: f32) -> v128 {
+    static_assert!(N: usize where N < 8);
+    unsafe {
+        let mut dst = MaybeUninit::<v128>::uninit();
+        asm!(
+            "local.get {dst}",
+            "local.get {a}",
+            "v128.load 0",
+            "local.get {val}",
+            "f16x8.replace_lane {n}",
+            "v128.store 0",
+            dst = in(local) dst.as_mut_ptr(),
+            a = in(local) &a as *const v128,
+            val = in(local) val,
+            n = const N,
+            options(nostack),
+        );
+        dst.assume_init()
+    }
+}
+
+/// Calculates the absolute value of each lane of a 128-bit vector interpreted
+/// as eight 16-bit floating point numbers.
+#[inline]
+#[cfg_attr(all(test, target_feature = "fp16"), assert_instr(f16x8.abs))]
+#[target_feature(enable = "fp16")]
+pub fn f16x8_abs(a: v128) -> v128 {
+    unsafe { f16x8_op!("f16x8.abs", a) }
+}
+
+/// Negates each lane of a 128-bit vector interpreted as eight 16-bit floating
+/// point numbers.
+#[inline]
+#[cfg_attr(all(test, target_feature = "fp16"), assert_instr(f16x8.neg))]
+#[target_feature(enable = "fp16")]
+pub fn f16x8_neg(a: v128) -> v128 {
+    unsafe { f16x8_op!("f16x8.neg", a) }
+}
+
+/// Calculates the square root of each lane of a 128-bit vector interpreted as
+/// eight 16-bit floating point numbers.
+#[inline]
+#[cfg_attr(all(test, target_feature = "fp16"), assert_instr(f16x8.sqrt))]
+#[target_feature(enable = "fp16")]
+pub fn f16x8_sqrt(a: v128) -> v128 {
+    unsafe { f16x8_op!("f16x8.sqrt", a) }
+}
+
+/// Lane-wise rounding to the nearest integral value not smaller than the input.
+#[inline]
+#[cfg_attr(all(test, target_feature = "fp16"), assert_instr(f16x8.ceil))]
+#[target_feature(enable = "fp16")]
+pub fn f16x8_ceil(a: v128) -> v128 {
+    unsafe { f16x8_op!("f16x8.ceil", a) }
+}
+
+/// Lane-wise rounding to the nearest integral value not greater than the input.
+#[inline]
+#[cfg_attr(all(test, target_feature = "fp16"), assert_instr(f16x8.floor))]
+#[target_feature(enable = "fp16")]
+pub fn f16x8_floor(a: v128) -> v128 {
+    unsafe { f16x8_op!("f16x8.floor", a) }
+}
+
+/// Lane-wise rounding to the nearest integral value with the magnitude not
+/// larger than the input.
+#[inline]
+#[cfg_attr(all(test, target_feature = "fp16"), assert_instr(f16x8.trunc))]
+#[target_feature(enable = "fp16")]
+pub fn f16x8_trunc(a: v128) -> v128 {
+    unsafe { f16x8_op!("f16x8.trunc", a) }
+}
+
+/// Lane-wise rounding to the nearest integral value; if two values are equally
+/// near, rounds to the even one.
+#[inline]
+#[cfg_attr(all(test, target_feature = "fp16"), assert_instr(f16x8.nearest))]
+#[target_feature(enable = "fp16")]
+pub fn f16x8_nearest(a: v128) -> v128 {
+    unsafe { f16x8_op!("f16x8.nearest", a) }
+}
+
+/// Adds pairwise lanes of two 128-bit vectors interpreted as eight 16-bit
+/// floating point numbers.
+#[inline]
+#[cfg_attr(all(test, target_feature = "fp16"), assert_instr(f16x8.add))]
+#[target_feature(enable = "fp16")]
+pub fn f16x8_add(a: v128, b: v128) -> v128 {
+    unsafe { f16x8_op!("f16x8.add", a, b) }
+}
+
+/// Subtracts pairwise lanes of two 128-bit vectors interpreted as eight 16-bit
+/// floating point numbers.
+#[inline]
+#[cfg_attr(all(test, target_feature = "fp16"), assert_instr(f16x8.sub))]
+#[target_feature(enable = "fp16")]
+pub fn f16x8_sub(a: v128, b: v128) -> v128 {
+    unsafe { f16x8_op!("f16x8.sub", a, b) }
+}
+
+/// Multiplies pairwise lanes of two 128-bit vectors interpreted as eight
+/// 16-bit floating point numbers.
+#[inline]
+#[cfg_attr(all(test, target_feature = "fp16"), assert_instr(f16x8.mul))]
+#[target_feature(enable = "fp16")]
+pub fn f16x8_mul(a: v128, b: v128) -> v128 {
+    unsafe { f16x8_op!("f16x8.mul", a, b) }
+}
+
+/// Divides pairwise lanes of two 128-bit vectors interpreted as eight 16-bit
+/// floating point numbers.
+#[inline]
+#[cfg_attr(all(test, target_feature = "fp16"), assert_instr(f16x8.div))]
+#[target_feature(enable = "fp16")]
+pub fn f16x8_div(a: v128, b: v128) -> v128 {
+    unsafe { f16x8_op!("f16x8.div", a, b) }
+}
+
+/// Calculates the minimum of pairwise lanes of two 128-bit vectors interpreted
+/// as eight 16-bit floating point numbers.
+#[inline]
+#[cfg_attr(all(test, target_feature = "fp16"), assert_instr(f16x8.min))]
+#[target_feature(enable = "fp16")]
+pub fn f16x8_min(a: v128, b: v128) -> v128 {
+    unsafe { f16x8_op!("f16x8.min", a, b) }
+}
+
+/// Calculates the maximum of pairwise lanes of two 128-bit vectors interpreted
+/// as eight 16-bit floating point numbers.
+#[inline]
+#[cfg_attr(all(test, target_feature = "fp16"), assert_instr(f16x8.max))]
+#[target_feature(enable = "fp16")]
+pub fn f16x8_max(a: v128, b: v128) -> v128 {
+    unsafe { f16x8_op!("f16x8.max", a, b) }
+}
+
+/// Lane-wise minimum value, defined as `b < a ? b : a`
+#[inline]
+#[cfg_attr(all(test, target_feature = "fp16"), assert_instr(f16x8.pmin))]
+#[target_feature(enable = "fp16")]
+pub fn f16x8_pmin(a: v128, b: v128) -> v128 {
+    unsafe { f16x8_op!("f16x8.pmin", a, b) }
+}
+
+/// Lane-wise maximum value, defined as `a < b ? b : a`
+#[inline]
+#[cfg_attr(all(test, target_feature = "fp16"), assert_instr(f16x8.pmax))]
+#[target_feature(enable = "fp16")]
+pub fn f16x8_pmax(a: v128, b: v128) -> v128 {
+    unsafe { f16x8_op!("f16x8.pmax", a, b) }
+}
+
+/// Compares two 128-bit vectors as if they were two vectors of 8 sixteen-bit
+/// floating point numbers.
+///
+/// Returns a new vector where each lane is all ones if the pairwise elements
+/// were equal, or all zeros if the elements were not equal.
+#[inline]
+#[cfg_attr(all(test, target_feature = "fp16"), assert_instr(f16x8.eq))]
+#[target_feature(enable = "fp16")]
+pub fn f16x8_eq(a: v128, b: v128) -> v128 {
+    unsafe { f16x8_op!("f16x8.eq", a, b) }
+}
+
+/// Compares two 128-bit vectors as if they were two vectors of 8 sixteen-bit
+/// floating point numbers.
+///
+/// Returns a new vector where each lane is all ones if the pairwise elements
+/// were not equal, or all zeros if the elements were equal.
+#[inline]
+#[cfg_attr(all(test, target_feature = "fp16"), assert_instr(f16x8.ne))]
+#[target_feature(enable = "fp16")]
+pub fn f16x8_ne(a: v128, b: v128) -> v128 {
+    unsafe { f16x8_op!("f16x8.ne", a, b) }
+}
+
+/// Compares two 128-bit vectors as if they were two vectors of 8 sixteen-bit
+/// floating point numbers.
+///
+/// Returns a new vector where each lane is all ones if the pairwise left
+/// element is less than the pairwise right element, or all zeros otherwise.
+#[inline]
+#[cfg_attr(all(test, target_feature = "fp16"), assert_instr(f16x8.lt))]
+#[target_feature(enable = "fp16")]
+pub fn f16x8_lt(a: v128, b: v128) -> v128 {
+    unsafe { f16x8_op!("f16x8.lt", a, b) }
+}
+
+/// Compares two 128-bit vectors as if they were two vectors of 8 sixteen-bit
+/// floating point numbers.
+///
+/// Returns a new vector where each lane is all ones if the pairwise left
+/// element is greater than the pairwise right element, or all zeros otherwise.
+#[inline]
+#[cfg_attr(all(test, target_feature = "fp16"), assert_instr(f16x8.gt))]
+#[target_feature(enable = "fp16")]
+pub fn f16x8_gt(a: v128, b: v128) -> v128 {
+    unsafe { f16x8_op!("f16x8.gt", a, b) }
+}
+
+/// Compares two 128-bit vectors as if they were two vectors of 8 sixteen-bit
+/// floating point numbers.
+///
+/// Returns a new vector where each lane is all ones if the pairwise left
+/// element is less than the pairwise right element, or all zeros otherwise.
+#[inline]
+#[cfg_attr(all(test, target_feature = "fp16"), assert_instr(f16x8.le))]
+#[target_feature(enable = "fp16")]
+pub fn f16x8_le(a: v128, b: v128) -> v128 {
+    unsafe { f16x8_op!("f16x8.le", a, b) }
+}
+
+/// Compares two 128-bit vectors as if they were two vectors of 8 sixteen-bit
+/// floating point numbers.
+///
+/// Returns a new vector where each lane is all ones if the pairwise left
+/// element is greater than the pairwise right element, or all zeros otherwise.
+#[inline]
+#[cfg_attr(all(test, target_feature = "fp16"), assert_instr(f16x8.ge))]
+#[target_feature(enable = "fp16")]
+pub fn f16x8_ge(a: v128, b: v128) -> v128 {
+    unsafe { f16x8_op!("f16x8.ge", a, b) }
+}
+
+/// Computes `a * b + c` on the `f16` lanes of the inputs.
+///
+/// The result is either fused, rounded once, or the rounded product added to
+/// `c` and rounded again.
+#[inline]
+#[cfg_attr(
+    all(test, target_feature = "fp16"),
+    assert_instr(f16x8.relaxed_madd)
+)]
+#[target_feature(enable = "fp16")]
+pub fn f16x8_relaxed_madd(a: v128, b: v128, c: v128) -> v128 {
+    unsafe { f16x8_op!("f16x8.relaxed_madd", a, b, c) }
+}
+
+/// Computes `-a * b + c` on the `f16` lanes of the inputs.
+///
+/// The result is either fused, rounded once, or the rounded product
+/// subtracted from `c` and rounded again.
+#[inline]
+#[cfg_attr(
+    all(test, target_feature = "fp16"),
+    assert_instr(f16x8.relaxed_nmadd)
+)]
+#[target_feature(enable = "fp16")]
+pub fn f16x8_relaxed_nmadd(a: v128, b: v128, c: v128) -> v128 {
+    unsafe { f16x8_op!("f16x8.relaxed_nmadd", a, b, c) }
+}
+
+/// Converts a 128-bit vector interpreted as eight 16-bit floating point
+/// numbers into a 128-bit vector of eight 16-bit signed integers.
+///
+/// NaN is converted to 0 and if it's out of bounds it becomes the nearest
+/// representable intger.
+#[inline]
+#[cfg_attr(
+    all(test, target_feature = "fp16"),
+    assert_instr(i16x8.trunc_sat_f16x8_s)
+)]
+#[target_feature(enable = "fp16")]
+pub fn i16x8_trunc_sat_f16x8(a: v128) -> v128 {
+    unsafe { f16x8_op!("i16x8.trunc_sat_f16x8_s", a) }
+}
+
+/// Converts a 128-bit vector interpreted as eight 16-bit floating point
+/// numbers into a 128-bit vector of eight 16-bit unsigned integers.
+///
+/// NaN is converted to 0 and if it's out of bounds it becomes the nearest
+/// representable intger.
+#[inline]
+#[cfg_attr(
+    all(test, target_feature = "fp16"),
+    assert_instr(i16x8.trunc_sat_f16x8_u)
+)]
+#[target_feature(enable = "fp16")]
+pub fn u16x8_trunc_sat_f16x8(a: v128) -> v128 {
+    unsafe { f16x8_op!("i16x8.trunc_sat_f16x8_u", a) }
+}
+
+/// Converts a 128-bit vector interpreted as eight 16-bit signed integers into
+/// a 128-bit vector of eight 16-bit floating point numbers.
+#[inline]
+#[cfg_attr(
+    all(test, target_feature = "fp16"),
+    assert_instr(f16x8.convert_i16x8_s)
+)]
+#[target_feature(enable = "fp16")]
+pub fn f16x8_convert_i16x8(a: v128) -> v128 {
+    unsafe { f16x8_op!("f16x8.convert_i16x8_s", a) }
+}
+
+/// Converts a 128-bit vector interpreted as eight 16-bit unsigned integers
+/// into a 128-bit vector of eight 16-bit floating point numbers.
+#[inline]
+#[cfg_attr(
+    all(test, target_feature = "fp16"),
+    assert_instr(f16x8.convert_i16x8_u)
+)]
+#[target_feature(enable = "fp16")]
+pub fn f16x8_convert_u16x8(a: v128) -> v128 {
+    unsafe { f16x8_op!("f16x8.convert_i16x8_u", a) }
+}
+
+/// Conversion of the four single-precision floating point lanes to the four
+/// lower half-precision lanes of the result. The four higher lanes of the
+/// result are initialized to zero. If the conversion result is not
+/// representable as a half-precision floating point number, it is rounded to
+/// the nearest-even representable number.
+#[inline]
+#[cfg_attr(
+    all(test, target_feature = "fp16"),
+    assert_instr(f16x8.demote_f32x4_zero)
+)]
+#[target_feature(enable = "fp16")]
+pub fn f16x8_demote_f32x4_zero(a: v128) -> v128 {
+    unsafe { f16x8_op!("f16x8.demote_f32x4_zero", a) }
+}
+
+/// Conversion of the four lower half-precision floating point lanes to the
+/// four single-precision lanes of the result.
+#[inline]
+#[cfg_attr(
+    all(test, target_feature = "fp16"),
+    assert_instr(f32x4.promote_low_f16x8)
+)]
+#[target_feature(enable = "fp16")]
+pub fn f32x4_promote_low_f16x8(a: v128) -> v128 {
+    unsafe { f16x8_op!("f32x4.promote_low_f16x8", a) }
+}
+
+// The engines and disassemblers running the test suite need to support the
+// proposal, so these only run when the tests are built with
+// `-Ctarget-feature=+fp16`.
+#[cfg(all(test, target_feature = "fp16"))]
+pub mod tests {
+    use super::*;
+    use crate::core_arch::wasm32::*;
+    use std::mem::transmute;
+
+    // Rounds `x` to the nearest `f16`, ties to even, and returns it as an
+    // `f32`. An `f32` has more than twice the precision of an `f16`, so
+    // rounding the `f32` result of an operation on `f16` values gives the
+    // correctly rounded `f16` result.
+    fn round(x: f32) -> f32 {
+        f16_bits_to_f32(f32_to_f16_bits(x))
+    }
+
+    fn f32_to_f16_bits(x: f32) -> u16 {
+        let bits = x.to_bits();
+        let sign = ((bits >> 16) & 0x8000) as u16;
+        if x.is_nan() {
+            return sign | 0x7e00;
+        }
+        let abs = f32::from_bits(bits & 0x7fff_ffff);
+        if abs >= 65520.0 {
+            return sign | 0x7c00;
+        }
+        // Pick the scale of the result's unit in the last place, `2^-24` for
+        // subnormals, then round to a multiple of it.
+        let exp = ((bits >> 23) & 0xff) as i32 - 127;
+        let ulp_exp = if exp < -14 { -24 } else { exp - 10 };
+        let ulp = f32::from_bits(((ulp_exp + 127) as u32) << 23);
+        let q = abs / ulp;
+        let mut n = q as u32;
+        let frac = q - n as f32;
+        if frac > 0.5 || (frac == 0.5 && n & 1 == 1) {
+            n += 1;
+        }
+        let r = n as f32 * ulp;
+        if r < f32::from_bits(0x3880_0000) {
+            // Subnormal: a multiple of `2^-24`.
+            sign | n as u16
+        } else {
+            let rbits = r.to_bits();
+            let e = (((rbits >> 23) & 0xff) as i32 - 127 + 15) as u16;
+            sign | e << 10 | ((rbits >> 13) & 0x3ff) as u16
+        }
+    }
+
+    fn f16_bits_to_f32(h: u16) -> f32 {
+        let sign = if h & 0x8000 != 0 { -1.0 } else { 1.0 };
+        let exp = ((h >> 10) & 0x1f) as i32;
+        let mant = (h & 0x3ff) as f32;
+        sign * match exp {
+            0 => mant * f32::from_bits(0x3380_0000),
+            0x1f if mant == 0.0 => f32::INFINITY,
+            0x1f => f32::NAN,
+            _ => (1.0 + mant / 1024.0) * f32::from_bits(((exp - 15 + 127) as u32) << 23),
+        }
+    }
+
+    fn lanes(a: v128) -> [f32; 8] {
+        unsafe {
+            [
+                f16x8_extract_lane::<0>(a),
+                f16x8_extract_lane::<1>(a),
+                f16x8_extract_lane::<2>(a),
+                f16x8_extract_lane::<3>(a),
+                f16x8_extract_lane::<4>(a),
+                f16x8_extract_lane::<5>(a),
+                f16x8_extract_lane::<6>(a),
+                f16x8_extract_lane::<7>(a),
+            ]
+        }
+    }
+
+    fn vector(a: [f32; 8]) -> v128 {
+        unsafe {
+            let mut r = f16x8_splat(0.0);
+            r = f16x8_replace_lane::<0>(r, a[0]);
+            r = f16x8_replace_lane::<1>(r, a[1]);
+            r = f16x8_replace_lane::<2>(r, a[2]);
+            r = f16x8_replace_lane::<3>(r, a[3]);
+            r = f16x8_replace_lane::<4>(r, a[4]);
+            r = f16x8_replace_lane::<5>(r, a[5]);
+            r = f16x8_replace_lane::<6>(r, a[6]);
+            r = f16x8_replace_lane::<7>(r, a[7]);
+            r
+        }
+    }
+
+    const A: [f32; 8] = [1.0, -2.5, 0.1, 3.0e4, 6.1e-5, -0.0, 1.0e-7, 1000.3];
+    const B: [f32; 8] = [3.0, 0.7, -0.2, 5.0e4, 3.0e-5, 0.0, 7.0, -0.001];
+
+    fn assert_lanes(r: v128, expected: [f32; 8]) {
+        let r = lanes(r);
+        for i in 0..8 {
+            assert!(
+                r[i] == expected[i] || (r[i].is_nan() && expected[i].is_nan()),
+                "lane {}: {} != {}",
+                i,
+                r[i],
+                expected[i],
+            );
+        }
+    }
+
+    fn map(a: [f32; 8], f: impl Fn(f32) -> f32) -> [f32; 8] {
+        let mut r = [0.0; 8];
+        for i in 0..8 {
+            r[i] = round(f(round(a[i])));
+        }
+        r
+    }
+
+    fn zip(a: [f32; 8], b: [f32; 8], f: impl Fn(f32, f32) -> f32) -> [f32; 8] {
+        let mut r = [0.0; 8];
+        for i in 0..8 {
+            r[i] = round(f(round(a[i]), round(b[i])));
+        }
+        r
+    }
+
+    #[test]
+    fn test_splat_extract_replace() {
+        unsafe {
+            assert_lanes(f16x8_splat(0.1), [round(0.1); 8]);
+            assert_eq!(f16x8_extract_lane::<3>(f16x8_splat(70000.0)), f32::INFINITY);
+            assert_eq!(f16x8_extract_lane::<5>(f16x8_splat(2049.0)), 2048.0);
+            assert_eq!(f16x8_extract_lane::<5>(f16x8_splat(2051.0)), 2052.0);
+            assert_lanes(vector(A), map(A, |x| x));
+        }
+    }
+
+    #[test]
+    fn test_unops() {
+        unsafe {
+            let a = vector(A);
+            assert_lanes(f16x8_abs(a), map(A, f32::abs));
+            assert_lanes(f16x8_neg(a), map(A, |x| -x));
+            assert_lanes(f16x8_sqrt(f16x8_abs(a)), map(A, |x| x.abs().sqrt()));
+            let c = [0.5, -0.5, 1.5, 2.5, -2.5, 1000.6, -7.9, 0.0];
+            let v = vector(c);
+            assert_lanes(f16x8_ceil(v), map(c, f32::ceil));
+            assert_lanes(f16x8_floor(v), map(c, f32::floor));
+            assert_lanes(f16x8_trunc(v), map(c, f32::trunc));
+            assert_lanes(
+                f16x8_nearest(v),
+                vector([0.0, -0.0, 2.0, 2.0, -2.0, 1000.0, -8.0, 0.0]),
+            );
+        }
+    }
+
+    #[test]
+    fn test_binops() {
+        unsafe {
+            let (a, b) = (vector(A), vector(B));
+            assert_lanes(f16x8_add(a, b), zip(A, B, |x, y| x + y));
+            assert_lanes(f16x8_sub(a, b), zip(A, B, |x, y| x - y));
+            assert_lanes(f16x8_mul(a, b), zip(A, B, |x, y| x * y));
+            assert_lanes(f16x8_div(a, b), zip(A, B, |x, y| x / y));
+            assert_lanes(f16x8_min(a, b), zip(A, B, f32::min));
+            assert_lanes(f16x8_max(a, b), zip(A, B, f32::max));
+            assert_lanes(
+                f16x8_pmin(a, b),
+                zip(A, B, |x, y| if y < x { y } else { x }),
+            );
+            assert_lanes(
+                f16x8_pmax(a, b),
+                zip(A, B, |x, y| if x < y { y } else { x }),
+            );
+        }
+    }
+
+    #[test]
+    fn test_min_max_nan() {
+        unsafe {
+            let a = f16x8_splat(f32::NAN);
+            let b = f16x8_splat(1.0);
+            assert!(f16x8_extract_lane::<0>(f16x8_min(a, b)).is_nan());
+            assert!(f16x8_extract_lane::<0>(f16x8_max(b, a)).is_nan());
+            assert!(f16x8_extract_lane::<0>(f16x8_pmin(a, b)).is_nan());
+            assert_eq!(f16x8_extract_lane::<0>(f16x8_pmin(b, a)), 1.0);
+        }
+    }
+
+    #[test]
+    fn test_comparisons() {
+        unsafe {
+            let a = vector([1.0, 2.0, 3.0, f32::NAN, -0.0, 5.0, 6.0, 7.0]);
+            let b = vector([1.0, 3.0, 2.0, f32::NAN, 0.0, 5.0, 5.0, 8.0]);
+            let mask = |r: v128| -> [i16; 8] { unsafe { transmute(r) } };
+            assert_eq!(mask(f16x8_eq(a, b)), [-1, 0, 0, 0, -1, -1, 0, 0]);
+            assert_eq!(mask(f16x8_ne(a, b)), [0, -1, -1, -1, 0, 0, -1, -1]);
+            assert_eq!(mask(f16x8_lt(a, b)), [0, -1, 0, 0, 0, 0, 0, -1]);
+            assert_eq!(mask(f16x8_gt(a, b)), [0, 0, -1, 0, 0, 0, -1, 0]);
+            assert_eq!(mask(f16x8_le(a, b)), [-1, -1, 0, 0, -1, -1, 0, -1]);
+            assert_eq!(mask(f16x8_ge(a, b)), [-1, 0, -1, 0, -1, -1, -1, 0]);
+        }
+    }
+
+    // `1 + 2^-10` squared is `1 + 2^-9 + 2^-20`, where the last term is lost
+    // when the product is rounded, so fused and unfused results differ.
+    #[test]
+    fn test_relaxed_madd_nmadd() {
+        unsafe {
+            let x = 1.0 + 1.0 / 1024.0;
+            let a = vector([x, 2.0, -3.0, 0.5, 0.0, 1.0, 1.0, 1.0]);
+            let b = vector([x, 3.0, 4.0, 0.5, 0.0, 1.0, 1.0, 1.0]);
+            let c = vector([-(1.0 + 1.0 / 512.0), 1.0, 1.0, -0.25, 0.0, 0.0, 0.0, 0.0]);
+            let fused = 1.0 / 1048576.0;
+
+            let r = lanes(f16x8_relaxed_madd(a, b, c));
+            assert!(r[0] == fused || r[0] == 0.0, "{}", r[0]);
+            assert_eq!(&r[1..4], [7.0, -11.0, 0.0]);
+
+            let c = f16x8_neg(c);
+            let r = lanes(f16x8_relaxed_nmadd(a, b, c));
+            assert!(r[0] == -fused || r[0] == 0.0, "{}", r[0]);
+            assert_eq!(&r[1..4], [-7.0, 11.0, 0.0]);
+        }
+    }
+
+    #[test]
+    fn test_integer_conversions() {
+        unsafe {
+            let a = vector([1.9, -1.9, 40000.0, -40000.0, f32::NAN, 65504.0, -0.5, 3.0]);
+            let r: [i16; 8] = unsafe { transmute(i16x8_trunc_sat_f16x8(a)) };
+            assert_eq!(r, [1, -1, i16::MAX, i16::MIN, 0, i16::MAX, 0, 3]);
+            let r: [u16; 8] = unsafe { transmute(u16x8_trunc_sat_f16x8(a)) };
+            assert_eq!(r, [1, 0, 40000, 0, 0, 65504, 0, 3]);
+
+            let i = i16x8(0, 1, -1, 2049, i16::MAX, i16::MIN, 100, -100);
+            assert_lanes(
+                f16x8_convert_i16x8(i),
+                [0.0, 1.0, -1.0, 2048.0, 32768.0, -32768.0, 100.0, -100.0],
+            );
+            let u = u16x8(0, 1, 65535, 2049, 65504, 32768, 100, 4097);
+            assert_lanes(
+                f16x8_convert_u16x8(u),
+                [
+                    0.0,
+                    1.0,
+                    f32::INFINITY,
+                    2048.0,
+                    65504.0,
+                    32768.0,
+                    100.0,
+                    4096.0,
+                ],
+            );
+        }
+    }
+
+    #[test]
+    fn test_f32_conversions() {
+        unsafe {
+            let a = f32x4(0.1, -70000.0, 6.0e-8, 1.0e-9);
+            assert_lanes(
+                f16x8_demote_f32x4_zero(a),
+                [
+                    round(0.1),
+                    -f32::INFINITY,
+                    round(6.0e-8),
+                    0.0,
+                    0.0,
+                    0.0,
+                    0.0,
+                    0.0,
+                ],
+            );
+            let r: [f32; 4] = unsafe { transmute(f32x4_promote_low_f16x8(vector(A))) };
+            assert_eq!(r, [round(A[0]), round(A[1]), round(A[2]), round(A[3])]);
+        }
+    }
+}
@@ -1,11 +1,14 @@
 #[cfg(test)]
 use stdarch_test::assert_instr;
 
+// Page counts are 64-bit on wasm64, where memories can be larger than 4 GiB.
 extern "C" {
-    #[link_name = "llvm.wasm.memory.grow.i32"]
-    fn llvm_memory_grow(mem: u32, pages: i32) -> i32;
-    #[link_name = "llvm.wasm.memory.size.i32"]
-    fn llvm_memory_size(mem: u32) -> i32;
+    #[cfg_attr(target_arch = "wasm32", link_name = "llvm.wasm.memory.grow.i32")]
+    #[cfg_attr(target_arch = "wasm64", link_name = "llvm.wasm.memory.grow.i64")]
+    fn llvm_memory_grow(mem: u32, pages: isize) -> isize;
+    #[cfg_attr(target_arch = "wasm32", link_name = "llvm.wasm.memory.size.i32")]
+    #[cfg_attr(target_arch = "wasm64", link_name = "llvm.wasm.memory.size.i64")]
+    fn llvm_memory_size(mem: u32) -> isize;
 }
 
 /// Corresponding intrinsic to wasm's [`memory.size` instruction][instr]
@@ -51,6 +54,6 @@ pub fn memory_size<const MEM: u32>() -> usize {
 pub fn memory_grow<const MEM: u32>(delta: usize) -> usize {
     unsafe {
         static_assert!(MEM: u32 where MEM == 0);
-        llvm_memory_grow(MEM, delta as i32) as isize as usize
+        llvm_memory_grow(MEM, delta as isize) as usize
     }
 }
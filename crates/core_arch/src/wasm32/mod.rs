@@ -9,6 +9,12 @@ pub use self::atomic::*;
 mod simd128;
 pub use self::simd128::*;
 
+mod relaxed_simd;
+pub use self::relaxed_simd::*;
+
+mod f16x8;
+pub use self::f16x8::*;
+
 mod memory;
 pub use self::memory::*;
 
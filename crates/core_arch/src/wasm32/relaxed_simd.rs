@@ -0,0 +1,682 @@
+//! This module implements the [WebAssembly relaxed SIMD proposal].
+//!
+//! The instructions in this proposal trade determinism for speed: for some
+//! inputs their result is only specified to be one of a small set of values,
+//! and which one is picked depends on the engine and the hardware it runs on.
+//! The documentation of each function describes the results it can produce.
+//!
+//! [WebAssembly relaxed SIMD proposal]:
+//! https://github.com/WebAssembly/relaxed-simd/blob/main/proposals/relaxed-simd/Overview.md
+
+#![unstable(feature = "wasm_relaxed_simd", issue = "none")]
+#![allow(non_camel_case_types)]
+
+use super::v128;
+use crate::{core_arch::simd, mem::transmute};
+
+#[cfg(all(test, target_feature = "relaxed-simd"))]
+use stdarch_test::assert_instr;
+
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.wasm.relaxed.swizzle"]
+    fn llvm_relaxed_swizzle(a: simd::i8x16, s: simd::i8x16) -> simd::i8x16;
+    #[link_name = "llvm.wasm.relaxed.trunc.signed"]
+    fn llvm_relaxed_trunc_signed(a: simd::f32x4) -> simd::i32x4;
+    #[link_name = "llvm.wasm.relaxed.trunc.unsigned"]
+    fn llvm_relaxed_trunc_unsigned(a: simd::f32x4) -> simd::i32x4;
+    #[link_name = "llvm.wasm.relaxed.trunc.signed.zero"]
+    fn llvm_relaxed_trunc_signed_zero(a: simd::f64x2) -> simd::i32x4;
+    #[link_name = "llvm.wasm.relaxed.trunc.unsigned.zero"]
+    fn llvm_relaxed_trunc_unsigned_zero(a: simd::f64x2) -> simd::i32x4;
+
+    #[link_name = "llvm.wasm.relaxed.madd.v4f32"]
+    fn llvm_f32x4_fma(a: simd::f32x4, b: simd::f32x4, c: simd::f32x4) -> simd::f32x4;
+    #[link_name = "llvm.wasm.relaxed.nmadd.v4f32"]
+    fn llvm_f32x4_fms(a: simd::f32x4, b: simd::f32x4, c: simd::f32x4) -> simd::f32x4;
+    #[link_name = "llvm.wasm.relaxed.madd.v2f64"]
+    fn llvm_f64x2_fma(a: simd::f64x2, b: simd::f64x2, c: simd::f64x2) -> simd::f64x2;
+    #[link_name = "llvm.wasm.relaxed.nmadd.v2f64"]
+    fn llvm_f64x2_fms(a: simd::f64x2, b: simd::f64x2, c: simd::f64x2) -> simd::f64x2;
+
+    #[link_name = "llvm.wasm.relaxed.laneselect.v16i8"]
+    fn llvm_i8x16_laneselect(a: simd::i8x16, b: simd::i8x16, m: simd::i8x16) -> simd::i8x16;
+    #[link_name = "llvm.wasm.relaxed.laneselect.v8i16"]
+    fn llvm_i16x8_laneselect(a: simd::i16x8, b: simd::i16x8, m: simd::i16x8) -> simd::i16x8;
+    #[link_name = "llvm.wasm.relaxed.laneselect.v4i32"]
+    fn llvm_i32x4_laneselect(a: simd::i32x4, b: simd::i32x4, m: simd::i32x4) -> simd::i32x4;
+    #[link_name = "llvm.wasm.relaxed.laneselect.v2i64"]
+    fn llvm_i64x2_laneselect(a: simd::i64x2, b: simd::i64x2, m: simd::i64x2) -> simd::i64x2;
+
+    #[link_name = "llvm.wasm.relaxed.min.v4f32"]
+    fn llvm_f32x4_relaxed_min(a: simd::f32x4, b: simd::f32x4) -> simd::f32x4;
+    #[link_name = "llvm.wasm.relaxed.min.v2f64"]
+    fn llvm_f64x2_relaxed_min(a: simd::f64x2, b: simd::f64x2) -> simd::f64x2;
+    #[link_name = "llvm.wasm.relaxed.max.v4f32"]
+    fn llvm_f32x4_relaxed_max(a: simd::f32x4, b: simd::f32x4) -> simd::f32x4;
+    #[link_name = "llvm.wasm.relaxed.max.v2f64"]
+    fn llvm_f64x2_relaxed_max(a: simd::f64x2, b: simd::f64x2) -> simd::f64x2;
+
+    #[link_name = "llvm.wasm.relaxed.q15mulr.signed"]
+    fn llvm_relaxed_q15mulr_signed(a: simd::i16x8, b: simd::i16x8) -> simd::i16x8;
+    #[link_name = "llvm.wasm.relaxed.dot.i8x16.i7x16.signed"]
+    fn llvm_i16x8_relaxed_dot_i8x16_i7x16_s(a: simd::i8x16, b: simd::i8x16) -> simd::i16x8;
+    #[link_name = "llvm.wasm.relaxed.dot.i8x16.i7x16.add.signed"]
+    fn llvm_i32x4_relaxed_dot_i8x16_i7x16_add_s(
+        a: simd::i8x16,
+        b: simd::i8x16,
+        c: simd::i32x4,
+    ) -> simd::i32x4;
+}
+
+/// A relaxed version of `i8x16_swizzle(a, s)` which selects lanes from `a`
+/// using the indices in `s`.
+///
+/// Indices in the range `[0,15]` select the `i`-th element of `a`. For
+/// indices outside of that range the resulting lane is either `0` or
+/// `a[s[i] % 16]`.
+#[inline]
+#[cfg_attr(
+    all(test, target_feature = "relaxed-simd"),
+    assert_instr(i8x16.relaxed_swizzle)
+)]
+#[target_feature(enable = "relaxed-simd")]
+pub fn i8x16_relaxed_swizzle(a: v128, s: v128) -> v128 {
+    unsafe { transmute(llvm_relaxed_swizzle(transmute(a), transmute(s))) }
+}
+
+/// A relaxed version of `i32x4_trunc_sat_f32x4(a)` which converts the `f32`
+/// lanes of `a` to signed integers.
+///
+/// NaN and out-of-range lanes produce either the result of
+/// `i32x4_trunc_sat_f32x4` or `i32::MIN`.
+#[inline]
+#[cfg_attr(
+    all(test, target_feature = "relaxed-simd"),
+    assert_instr(i32x4.relaxed_trunc_f32x4_s)
+)]
+#[target_feature(enable = "relaxed-simd")]
+pub fn i32x4_relaxed_trunc_f32x4(a: v128) -> v128 {
+    unsafe { transmute(llvm_relaxed_trunc_signed(transmute(a))) }
+}
+
+/// A relaxed version of `u32x4_trunc_sat_f32x4(a)` which converts the `f32`
+/// lanes of `a` to unsigned integers.
+///
+/// NaN and out-of-range lanes produce either the result of
+/// `u32x4_trunc_sat_f32x4` or `u32::MAX`.
+#[inline]
+#[cfg_attr(
+    all(test, target_feature = "relaxed-simd"),
+    assert_instr(i32x4.relaxed_trunc_f32x4_u)
+)]
+#[target_feature(enable = "relaxed-simd")]
+pub fn u32x4_relaxed_trunc_f32x4(a: v128) -> v128 {
+    unsafe { transmute(llvm_relaxed_trunc_unsigned(transmute(a))) }
+}
+
+/// A relaxed version of `i32x4_trunc_sat_f64x2_zero(a)` which converts the
+/// two `f64` lanes of `a` to signed integers in the two lower lanes of the
+/// result, and sets the two higher lanes to zero.
+///
+/// NaN and out-of-range lanes produce either the result of
+/// `i32x4_trunc_sat_f64x2_zero` or `i32::MIN`.
+#[inline]
+#[cfg_attr(
+    all(test, target_feature = "relaxed-simd"),
+    assert_instr(i32x4.relaxed_trunc_f64x2_s_zero)
+)]
+#[target_feature(enable = "relaxed-simd")]
+pub fn i32x4_relaxed_trunc_f64x2_zero(a: v128) -> v128 {
+    unsafe { transmute(llvm_relaxed_trunc_signed_zero(transmute(a))) }
+}
+
+/// A relaxed version of `u32x4_trunc_sat_f64x2_zero(a)` which converts the
+/// two `f64` lanes of `a` to unsigned integers in the two lower lanes of the
+/// result, and sets the two higher lanes to zero.
+///
+/// NaN and out-of-range lanes produce either the result of
+/// `u32x4_trunc_sat_f64x2_zero` or `u32::MAX`.
+#[inline]
+#[cfg_attr(
+    all(test, target_feature = "relaxed-simd"),
+    assert_instr(i32x4.relaxed_trunc_f64x2_u_zero)
+)]
+#[target_feature(enable = "relaxed-simd")]
+pub fn u32x4_relaxed_trunc_f64x2_zero(a: v128) -> v128 {
+    unsafe { transmute(llvm_relaxed_trunc_unsigned_zero(transmute(a))) }
+}
+
+/// Computes `a * b + c` on the `f32` lanes of the inputs.
+///
+/// The result is either fused, rounded once, or the rounded product added to
+/// `c` and rounded again.
+#[inline]
+#[cfg_attr(
+    all(test, target_feature = "relaxed-simd"),
+    assert_instr(f32x4.relaxed_madd)
+)]
+#[target_feature(enable = "relaxed-simd")]
+pub fn f32x4_relaxed_madd(a: v128, b: v128, c: v128) -> v128 {
+    unsafe { transmute(llvm_f32x4_fma(transmute(a), transmute(b), transmute(c))) }
+}
+
+/// Computes `-a * b + c` on the `f32` lanes of the inputs.
+///
+/// The result is either fused, rounded once, or the rounded product
+/// subtracted from `c` and rounded again.
+#[inline]
+#[cfg_attr(
+    all(test, target_feature = "relaxed-simd"),
+    assert_instr(f32x4.relaxed_nmadd)
+)]
+#[target_feature(enable = "relaxed-simd")]
+pub fn f32x4_relaxed_nmadd(a: v128, b: v128, c: v128) -> v128 {
+    unsafe { transmute(llvm_f32x4_fms(transmute(a), transmute(b), transmute(c))) }
+}
+
+/// Computes `a * b + c` on the `f64` lanes of the inputs.
+///
+/// The result is either fused, rounded once, or the rounded product added to
+/// `c` and rounded again.
+#[inline]
+#[cfg_attr(
+    all(test, target_feature = "relaxed-simd"),
+    assert_instr(f64x2.relaxed_madd)
+)]
+#[target_feature(enable = "relaxed-simd")]
+pub fn f64x2_relaxed_madd(a: v128, b: v128, c: v128) -> v128 {
+    unsafe { transmute(llvm_f64x2_fma(transmute(a), transmute(b), transmute(c))) }
+}
+
+/// Computes `-a * b + c` on the `f64` lanes of the inputs.
+///
+/// The result is either fused, rounded once, or the rounded product
+/// subtracted from `c` and rounded again.
+#[inline]
+#[cfg_attr(
+    all(test, target_feature = "relaxed-simd"),
+    assert_instr(f64x2.relaxed_nmadd)
+)]
+#[target_feature(enable = "relaxed-simd")]
+pub fn f64x2_relaxed_nmadd(a: v128, b: v128, c: v128) -> v128 {
+    unsafe { transmute(llvm_f64x2_fms(transmute(a), transmute(b), transmute(c))) }
+}
+
+/// A relaxed version of `v128_bitselect` where the `i8` lanes of `m` select
+/// whether the corresponding lane of the result comes from `a` or `b`.
+///
+/// Lanes of `m` which are all ones select `a` and lanes which are all zeros
+/// select `b`. For any other lane of `m` the result is either that of
+/// `v128_bitselect(a, b, m)` or selected by the top bit of the lane of `m`.
+#[inline]
+#[cfg_attr(
+    all(test, target_feature = "relaxed-simd"),
+    assert_instr(i8x16.relaxed_laneselect)
+)]
+#[target_feature(enable = "relaxed-simd")]
+pub fn i8x16_relaxed_laneselect(a: v128, b: v128, m: v128) -> v128 {
+    unsafe {
+        transmute(llvm_i8x16_laneselect(
+            transmute(a),
+            transmute(b),
+            transmute(m),
+        ))
+    }
+}
+
+/// A relaxed version of `v128_bitselect` where the `i16` lanes of `m` select
+/// whether the corresponding lane of the result comes from `a` or `b`.
+///
+/// Lanes of `m` which are all ones select `a` and lanes which are all zeros
+/// select `b`. For any other lane of `m` the result is either that of
+/// `v128_bitselect(a, b, m)` or selected by the top bit of the lane of `m`.
+#[inline]
+#[cfg_attr(
+    all(test, target_feature = "relaxed-simd"),
+    assert_instr(i16x8.relaxed_laneselect)
+)]
+#[target_feature(enable = "relaxed-simd")]
+pub fn i16x8_relaxed_laneselect(a: v128, b: v128, m: v128) -> v128 {
+    unsafe {
+        transmute(llvm_i16x8_laneselect(
+            transmute(a),
+            transmute(b),
+            transmute(m),
+        ))
+    }
+}
+
+/// A relaxed version of `v128_bitselect` where the `i32` lanes of `m` select
+/// whether the corresponding lane of the result comes from `a` or `b`.
+///
+/// Lanes of `m` which are all ones select `a` and lanes which are all zeros
+/// select `b`. For any other lane of `m` the result is either that of
+/// `v128_bitselect(a, b, m)` or selected by the top bit of the lane of `m`.
+#[inline]
+#[cfg_attr(
+    all(test, target_feature = "relaxed-simd"),
+    assert_instr(i32x4.relaxed_laneselect)
+)]
+#[target_feature(enable = "relaxed-simd")]
+pub fn i32x4_relaxed_laneselect(a: v128, b: v128, m: v128) -> v128 {
+    unsafe {
+        transmute(llvm_i32x4_laneselect(
+            transmute(a),
+            transmute(b),
+            transmute(m),
+        ))
+    }
+}
+
+/// A relaxed version of `v128_bitselect` where the `i64` lanes of `m` select
+/// whether the corresponding lane of the result comes from `a` or `b`.
+///
+/// Lanes of `m` which are all ones select `a` and lanes which are all zeros
+/// select `b`. For any other lane of `m` the result is either that of
+/// `v128_bitselect(a, b, m)` or selected by the top bit of the lane of `m`.
+#[inline]
+#[cfg_attr(
+    all(test, target_feature = "relaxed-simd"),
+    assert_instr(i64x2.relaxed_laneselect)
+)]
+#[target_feature(enable = "relaxed-simd")]
+pub fn i64x2_relaxed_laneselect(a: v128, b: v128, m: v128) -> v128 {
+    unsafe {
+        transmute(llvm_i64x2_laneselect(
+            transmute(a),
+            transmute(b),
+            transmute(m),
+        ))
+    }
+}
+
+/// A relaxed version of `f32x4_min` which computes the lane-wise minimum of
+/// `a` and `b`.
+///
+/// If either lane is NaN, or the lanes are `0.0` and `-0.0`, the result is
+/// either that of `f32x4_min` or that of `f32x4_pmin`.
+#[inline]
+#[cfg_attr(
+    all(test, target_feature = "relaxed-simd"),
+    assert_instr(f32x4.relaxed_min)
+)]
+#[target_feature(enable = "relaxed-simd")]
+pub fn f32x4_relaxed_min(a: v128, b: v128) -> v128 {
+    unsafe { transmute(llvm_f32x4_relaxed_min(transmute(a), transmute(b))) }
+}
+
+/// A relaxed version of `f32x4_max` which computes the lane-wise maximum of
+/// `a` and `b`.
+///
+/// If either lane is NaN, or the lanes are `0.0` and `-0.0`, the result is
+/// either that of `f32x4_max` or that of `f32x4_pmax`.
+#[inline]
+#[cfg_attr(
+    all(test, target_feature = "relaxed-simd"),
+    assert_instr(f32x4.relaxed_max)
+)]
+#[target_feature(enable = "relaxed-simd")]
+pub fn f32x4_relaxed_max(a: v128, b: v128) -> v128 {
+    unsafe { transmute(llvm_f32x4_relaxed_max(transmute(a), transmute(b))) }
+}
+
+/// A relaxed version of `f64x2_min` which computes the lane-wise minimum of
+/// `a` and `b`.
+///
+/// If either lane is NaN, or the lanes are `0.0` and `-0.0`, the result is
+/// either that of `f64x2_min` or that of `f64x2_pmin`.
+#[inline]
+#[cfg_attr(
+    all(test, target_feature = "relaxed-simd"),
+    assert_instr(f64x2.relaxed_min)
+)]
+#[target_feature(enable = "relaxed-simd")]
+pub fn f64x2_relaxed_min(a: v128, b: v128) -> v128 {
+    unsafe { transmute(llvm_f64x2_relaxed_min(transmute(a), transmute(b))) }
+}
+
+/// A relaxed version of `f64x2_max` which computes the lane-wise maximum of
+/// `a` and `b`.
+///
+/// If either lane is NaN, or the lanes are `0.0` and `-0.0`, the result is
+/// either that of `f64x2_max` or that of `f64x2_pmax`.
+#[inline]
+#[cfg_attr(
+    all(test, target_feature = "relaxed-simd"),
+    assert_instr(f64x2.relaxed_max)
+)]
+#[target_feature(enable = "relaxed-simd")]
+pub fn f64x2_relaxed_max(a: v128, b: v128) -> v128 {
+    unsafe { transmute(llvm_f64x2_relaxed_max(transmute(a), transmute(b))) }
+}
+
+/// A relaxed version of `i16x8_q15mulr_sat` which computes the lane-wise
+/// rounding multiplication in Q15 format.
+///
+/// If both lanes are `i16::MIN` the result is either `i16::MIN` or
+/// `i16::MAX`.
+#[inline]
+#[cfg_attr(
+    all(test, target_feature = "relaxed-simd"),
+    assert_instr(i16x8.relaxed_q15mulr_s)
+)]
+#[target_feature(enable = "relaxed-simd")]
+pub fn i16x8_relaxed_q15mulr(a: v128, b: v128) -> v128 {
+    unsafe { transmute(llvm_relaxed_q15mulr_signed(transmute(a), transmute(b))) }
+}
+
+/// Multiplies the `i8` lanes of `a` by the 7-bit lanes of `b` and adds
+/// adjacent pairs of the products into the `i16` lanes of the result.
+///
+/// If a lane of `b` has its top bit set it is interpreted either as signed
+/// or as unsigned, and when the sum of a pair overflows it either wraps or
+/// saturates.
+#[inline]
+#[cfg_attr(
+    all(test, target_feature = "relaxed-simd"),
+    assert_instr(i16x8.relaxed_dot_i8x16_i7x16_s)
+)]
+#[target_feature(enable = "relaxed-simd")]
+pub fn i16x8_relaxed_dot_i8x16_i7x16(a: v128, b: v128) -> v128 {
+    unsafe {
+        transmute(llvm_i16x8_relaxed_dot_i8x16_i7x16_s(
+            transmute(a),
+            transmute(b),
+        ))
+    }
+}
+
+/// Multiplies the `i8` lanes of `a` by the 7-bit lanes of `b`, adds groups
+/// of four adjacent products and adds the sums to the `i32` lanes of `c`.
+///
+/// If a lane of `b` has its top bit set it is interpreted either as signed
+/// or as unsigned, and the sums of adjacent pairs of products may saturate
+/// to `i16` before they are added together.
+#[inline]
+#[cfg_attr(
+    all(test, target_feature = "relaxed-simd"),
+    assert_instr(i32x4.relaxed_dot_i8x16_i7x16_add_s)
+)]
+#[target_feature(enable = "relaxed-simd")]
+pub fn i32x4_relaxed_dot_i8x16_i7x16_add(a: v128, b: v128, c: v128) -> v128 {
+    unsafe {
+        transmute(llvm_i32x4_relaxed_dot_i8x16_i7x16_add_s(
+            transmute(a),
+            transmute(b),
+            transmute(c),
+        ))
+    }
+}
+
+// The engines and disassemblers running the test suite need to support the
+// proposal, so these only run when the tests are built with
+// `-Ctarget-feature=+relaxed-simd`.
+#[cfg(all(test, target_feature = "relaxed-simd"))]
+pub mod tests {
+    use super::*;
+    use crate::core_arch::wasm32::*;
+    use std::mem::transmute;
+
+    #[test]
+    fn test_i8x16_relaxed_swizzle() {
+        let a: [u8; 16] = [
+            10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25,
+        ];
+        let s: [u8; 16] = [15, 0, 3, 16, 17, 31, 128, 255, 1, 2, 4, 8, 32, 0, 0, 0];
+        let r: [u8; 16] = unsafe { transmute(i8x16_relaxed_swizzle(transmute(a), transmute(s))) };
+        for i in 0..16 {
+            let idx = s[i] as usize;
+            if idx < 16 {
+                assert_eq!(r[i], a[idx]);
+            } else {
+                assert!(r[i] == 0 || r[i] == a[idx % 16], "lane {}: {}", i, r[i]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_i32x4_relaxed_trunc_f32x4() {
+        let a = [1.5f32, -2.5, 3e9, f32::NAN];
+        let r: [i32; 4] = unsafe { transmute(i32x4_relaxed_trunc_f32x4(transmute(a))) };
+        assert_eq!(r[0], 1);
+        assert_eq!(r[1], -2);
+        assert!(r[2] == i32::MAX || r[2] == i32::MIN);
+        assert!(r[3] == 0 || r[3] == i32::MIN);
+    }
+
+    #[test]
+    fn test_u32x4_relaxed_trunc_f32x4() {
+        let a = [1.5f32, -2.5, 5e9, f32::NAN];
+        let r: [u32; 4] = unsafe { transmute(u32x4_relaxed_trunc_f32x4(transmute(a))) };
+        assert_eq!(r[0], 1);
+        assert!(r[1] == 0 || r[1] == u32::MAX);
+        assert_eq!(r[2], u32::MAX);
+        assert!(r[3] == 0 || r[3] == u32::MAX);
+    }
+
+    #[test]
+    fn test_i32x4_relaxed_trunc_f64x2_zero() {
+        let a = [-7.9f64, f64::INFINITY];
+        let r: [i32; 4] = unsafe { transmute(i32x4_relaxed_trunc_f64x2_zero(transmute(a))) };
+        assert_eq!(r[0], -7);
+        assert!(r[1] == i32::MAX || r[1] == i32::MIN);
+        assert_eq!(&r[2..], [0, 0]);
+    }
+
+    #[test]
+    fn test_u32x4_relaxed_trunc_f64x2_zero() {
+        let a = [7.9f64, -1.0];
+        let r: [u32; 4] = unsafe { transmute(u32x4_relaxed_trunc_f64x2_zero(transmute(a))) };
+        assert_eq!(r[0], 7);
+        assert!(r[1] == 0 || r[1] == u32::MAX);
+        assert_eq!(&r[2..], [0, 0]);
+    }
+
+    // `1 + 2^-23` squared is `1 + 2^-22 + 2^-46`, where the last term is lost
+    // when the product is rounded, so fused and unfused results differ.
+    #[test]
+    fn test_f32x4_relaxed_madd() {
+        let x = 1.0 + f32::EPSILON;
+        let a = [x, 2.0, -3.0, 0.5];
+        let b = [x, 3.0, 4.0, 0.5];
+        let c = [-(1.0 + 2.0 * f32::EPSILON), 1.0, 1.0, -0.25];
+        let r: [f32; 4] =
+            unsafe { transmute(f32x4_relaxed_madd(transmute(a), transmute(b), transmute(c))) };
+        let fused = f32::EPSILON * f32::EPSILON;
+        assert!(r[0] == fused || r[0] == 0.0, "{}", r[0]);
+        assert_eq!(&r[1..], [7.0, -11.0, 0.0]);
+    }
+
+    #[test]
+    fn test_f32x4_relaxed_nmadd() {
+        let x = 1.0 + f32::EPSILON;
+        let a = [x, 2.0, -3.0, 0.5];
+        let b = [x, 3.0, 4.0, 0.5];
+        let c = [1.0 + 2.0 * f32::EPSILON, 1.0, 1.0, 0.25];
+        let r: [f32; 4] = unsafe {
+            transmute(f32x4_relaxed_nmadd(
+                transmute(a),
+                transmute(b),
+                transmute(c),
+            ))
+        };
+        let fused = -(f32::EPSILON * f32::EPSILON);
+        assert!(r[0] == fused || r[0] == 0.0, "{}", r[0]);
+        assert_eq!(&r[1..], [-5.0, 13.0, 0.0]);
+    }
+
+    #[test]
+    fn test_f64x2_relaxed_madd() {
+        let x = 1.0 + f64::EPSILON;
+        let a = [x, 2.0];
+        let b = [x, 3.0];
+        let c = [-(1.0 + 2.0 * f64::EPSILON), 1.0];
+        let r: [f64; 2] =
+            unsafe { transmute(f64x2_relaxed_madd(transmute(a), transmute(b), transmute(c))) };
+        let fused = f64::EPSILON * f64::EPSILON;
+        assert!(r[0] == fused || r[0] == 0.0, "{}", r[0]);
+        assert_eq!(r[1], 7.0);
+    }
+
+    #[test]
+    fn test_f64x2_relaxed_nmadd() {
+        let x = 1.0 + f64::EPSILON;
+        let a = [x, 2.0];
+        let b = [x, 3.0];
+        let c = [1.0 + 2.0 * f64::EPSILON, 1.0];
+        let r: [f64; 2] = unsafe {
+            transmute(f64x2_relaxed_nmadd(
+                transmute(a),
+                transmute(b),
+                transmute(c),
+            ))
+        };
+        let fused = -(f64::EPSILON * f64::EPSILON);
+        assert!(r[0] == fused || r[0] == 0.0, "{}", r[0]);
+        assert_eq!(r[1], -5.0);
+    }
+
+    #[test]
+    fn test_i8x16_relaxed_laneselect() {
+        let a = [0x5au8; 16];
+        let b = [0xa5u8; 16];
+        let mut m = [0u8; 16];
+        m[0] = 0xff;
+        m[1] = 0x80;
+        m[2] = 0x0f;
+        let r: [u8; 16] = unsafe {
+            transmute(i8x16_relaxed_laneselect(
+                transmute(a),
+                transmute(b),
+                transmute(m),
+            ))
+        };
+        assert_eq!(r[0], 0x5a);
+        assert!(r[1] == 0x25 || r[1] == 0x5a, "{:#x}", r[1]);
+        assert!(r[2] == 0xaa || r[2] == 0xa5, "{:#x}", r[2]);
+        assert_eq!(&r[3..], [0xa5; 13]);
+    }
+
+    #[test]
+    fn test_i16x8_relaxed_laneselect() {
+        let a = [0x1234u16; 8];
+        let b = [0xabcdu16; 8];
+        let m = [0xffff, 0, 0x8000, 0x00ff, 0xffff, 0, 0xffff, 0];
+        let r: [u16; 8] = unsafe {
+            transmute(i16x8_relaxed_laneselect(
+                transmute(a),
+                transmute(b),
+                transmute(m),
+            ))
+        };
+        assert_eq!([r[0], r[1]], [0x1234, 0xabcd]);
+        assert!(r[2] == 0x2bcd || r[2] == 0x1234, "{:#x}", r[2]);
+        assert!(r[3] == 0xab34 || r[3] == 0xabcd, "{:#x}", r[3]);
+        assert_eq!(&r[4..], [0x1234, 0xabcd, 0x1234, 0xabcd]);
+    }
+
+    #[test]
+    fn test_i32x4_relaxed_laneselect() {
+        let a = [1u32, 2, 3, 4];
+        let b = [5u32, 6, 7, 8];
+        let m = [u32::MAX, 0, 0x8000_0000, 0];
+        let r: [u32; 4] = unsafe {
+            transmute(i32x4_relaxed_laneselect(
+                transmute(a),
+                transmute(b),
+                transmute(m),
+            ))
+        };
+        assert_eq!([r[0], r[1], r[3]], [1, 6, 8]);
+        assert!(r[2] == 7 || r[2] == 3, "{}", r[2]);
+    }
+
+    #[test]
+    fn test_i64x2_relaxed_laneselect() {
+        let a = [1u64, 3];
+        let b = [3u64, 4];
+        let m = [0, 1];
+        let r: [u64; 2] = unsafe {
+            transmute(i64x2_relaxed_laneselect(
+                transmute(a),
+                transmute(b),
+                transmute(m),
+            ))
+        };
+        assert_eq!(r[0], 3);
+        assert!(r[1] == 4 || r[1] == 5, "{}", r[1]);
+    }
+
+    #[test]
+    fn test_f32x4_relaxed_min_max() {
+        let a = [1.0f32, -1.0, 0.0, f32::NAN];
+        let b = [2.0f32, -2.0, -0.0, 1.0];
+        unsafe {
+            let (a, b): (v128, v128) = (transmute(a), transmute(b));
+            let min: [f32; 4] = transmute(f32x4_relaxed_min(a, b));
+            let max: [f32; 4] = transmute(f32x4_relaxed_max(a, b));
+            assert_eq!(&min[..2], [1.0, -2.0]);
+            assert_eq!(&max[..2], [2.0, -1.0]);
+            assert_eq!(min[2], 0.0);
+            assert_eq!(max[2], 0.0);
+            assert!(min[3].is_nan() || min[3] == 1.0);
+            assert!(max[3].is_nan() || max[3] == 1.0);
+        }
+    }
+
+    #[test]
+    fn test_f64x2_relaxed_min_max() {
+        let a = [3.0f64, -0.0];
+        let b = [-4.0f64, 0.0];
+        unsafe {
+            let (a, b): (v128, v128) = (transmute(a), transmute(b));
+            let min: [f64; 2] = transmute(f64x2_relaxed_min(a, b));
+            let max: [f64; 2] = transmute(f64x2_relaxed_max(a, b));
+            assert_eq!([min[0], max[0]], [-4.0, 3.0]);
+            assert_eq!([min[1], max[1]], [0.0, 0.0]);
+        }
+    }
+
+    #[test]
+    fn test_i16x8_relaxed_q15mulr() {
+        let a = [i16::MIN, 0x4000, -0x4000, 1, i16::MAX, 0, 3, -3];
+        let b = [i16::MIN, 0x4000, 0x4000, 1, i16::MAX, 5, 0x2000, 0x2000];
+        let r: [i16; 8] = unsafe { transmute(i16x8_relaxed_q15mulr(transmute(a), transmute(b))) };
+        assert!(r[0] == i16::MIN || r[0] == i16::MAX, "{}", r[0]);
+        assert_eq!(&r[1..], [0x2000, -0x2000, 0, 0x7ffe, 0, 1, -1]);
+    }
+
+    #[test]
+    fn test_i16x8_relaxed_dot_i8x16_i7x16() {
+        let a: [i8; 16] = [
+            1, 2, -3, 4, 127, 127, -128, -128, 0, 5, 6, 7, -1, -1, 10, -10,
+        ];
+        let b: [i8; 16] = [5, 6, 7, 8, 127, 127, 127, 127, 9, 10, 11, 12, -1, 2, 3, 4];
+        let r: [i16; 8] =
+            unsafe { transmute(i16x8_relaxed_dot_i8x16_i7x16(transmute(a), transmute(b))) };
+        assert_eq!(&r[..6], [17, 11, 32258, -32512, 50, 150]);
+        // A lane of `b` with its top bit set is either -1 or 255.
+        assert!(r[6] == -1 || r[6] == -257, "{}", r[6]);
+        assert_eq!(r[7], -10);
+    }
+
+    #[test]
+    fn test_i32x4_relaxed_dot_i8x16_i7x16_add() {
+        let a: [i8; 16] = [1, 2, 3, 4, -1, -2, -3, -4, 100, 100, 100, 100, 1, 0, 0, 0];
+        let b: [i8; 16] = [1, 1, 1, 1, 5, 6, 7, 8, 100, 100, 100, 100, -128, 0, 0, 0];
+        let c = [10i32, 20, 30, 40];
+        let r: [i32; 4] = unsafe {
+            transmute(i32x4_relaxed_dot_i8x16_i7x16_add(
+                transmute(a),
+                transmute(b),
+                transmute(c),
+            ))
+        };
+        assert_eq!(r[0], 20);
+        assert_eq!(r[1], -50);
+        // Each pair of products is 20000, which fits in an `i16`.
+        assert_eq!(r[2], 40000 + 30);
+        assert!(r[3] == -128 + 40 || r[3] == 128 + 40, "{}", r[3]);
+    }
+}
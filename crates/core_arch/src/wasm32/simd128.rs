@@ -111,21 +111,21 @@ extern "C" {
     #[link_name = "llvm.wasm.load64.zero"]
     fn llvm_load64_zero(x: *const u64) -> simd::i64x2;
     #[link_name = "llvm.wasm.load8.lane"]
-    fn llvm_load8_lane(x: *const u8, v: simd::u8x16, l: usize) -> simd::u8x16;
+    fn llvm_load8_lane(x: *const u8, v: simd::u8x16, l: u32) -> simd::u8x16;
     #[link_name = "llvm.wasm.load16.lane"]
-    fn llvm_load16_lane(x: *const u16, v: simd::u16x8, l: usize) -> simd::u16x8;
+    fn llvm_load16_lane(x: *const u16, v: simd::u16x8, l: u32) -> simd::u16x8;
     #[link_name = "llvm.wasm.load32.lane"]
-    fn llvm_load32_lane(x: *const u32, v: simd::u32x4, l: usize) -> simd::u32x4;
+    fn llvm_load32_lane(x: *const u32, v: simd::u32x4, l: u32) -> simd::u32x4;
     #[link_name = "llvm.wasm.load64.lane"]
-    fn llvm_load64_lane(x: *const u64, v: simd::u64x2, l: usize) -> simd::u64x2;
+    fn llvm_load64_lane(x: *const u64, v: simd::u64x2, l: u32) -> simd::u64x2;
     #[link_name = "llvm.wasm.store8.lane"]
-    fn llvm_store8_lane(x: *mut u8, v: simd::u8x16, l: usize);
+    fn llvm_store8_lane(x: *mut u8, v: simd::u8x16, l: u32);
     #[link_name = "llvm.wasm.store16.lane"]
-    fn llvm_store16_lane(x: *mut u16, v: simd::u16x8, l: usize);
+    fn llvm_store16_lane(x: *mut u16, v: simd::u16x8, l: u32);
     #[link_name = "llvm.wasm.store32.lane"]
-    fn llvm_store32_lane(x: *mut u32, v: simd::u32x4, l: usize);
+    fn llvm_store32_lane(x: *mut u32, v: simd::u32x4, l: u32);
     #[link_name = "llvm.wasm.store64.lane"]
-    fn llvm_store64_lane(x: *mut u64, v: simd::u64x2, l: usize);
+    fn llvm_store64_lane(x: *mut u64, v: simd::u64x2, l: u32);
 
     #[link_name = "llvm.wasm.swizzle"]
     fn llvm_swizzle(a: simd::i8x16, b: simd::i8x16) -> simd::i8x16;
@@ -397,7 +397,7 @@ pub unsafe fn v128_store(m: *mut v128, a: v128) {
 #[target_feature(enable = "simd128")]
 pub unsafe fn v128_load8_lane<const L: usize>(v: v128, m: *const u8) -> v128 {
     static_assert!(L: usize where L < 16);
-    transmute(llvm_load8_lane(m, v.as_u8x16(), L))
+    transmute(llvm_load8_lane(m, v.as_u8x16(), L as u32))
 }
 
 /// Loads a 16-bit value from `m` and sets lane `L` of `v` to that value.
@@ -406,7 +406,7 @@ pub unsafe fn v128_load8_lane<const L: usize>(v: v128, m: *const u8) -> v128 {
 #[target_feature(enable = "simd128")]
 pub unsafe fn v128_load16_lane<const L: usize>(v: v128, m: *const u16) -> v128 {
     static_assert!(L: usize where L < 8);
-    transmute(llvm_load16_lane(m, v.as_u16x8(), L))
+    transmute(llvm_load16_lane(m, v.as_u16x8(), L as u32))
 }
 
 /// Loads a 32-bit value from `m` and sets lane `L` of `v` to that value.
@@ -415,7 +415,7 @@ pub unsafe fn v128_load16_lane<const L: usize>(v: v128, m: *const u16) -> v128 {
 #[target_feature(enable = "simd128")]
 pub unsafe fn v128_load32_lane<const L: usize>(v: v128, m: *const u32) -> v128 {
     static_assert!(L: usize where L < 4);
-    transmute(llvm_load32_lane(m, v.as_u32x4(), L))
+    transmute(llvm_load32_lane(m, v.as_u32x4(), L as u32))
 }
 
 /// Loads a 64-bit value from `m` and sets lane `L` of `v` to that value.
@@ -424,7 +424,7 @@ pub unsafe fn v128_load32_lane<const L: usize>(v: v128, m: *const u32) -> v128 {
 #[target_feature(enable = "simd128")]
 pub unsafe fn v128_load64_lane<const L: usize>(v: v128, m: *const u64) -> v128 {
     static_assert!(L: usize where L < 2);
-    transmute(llvm_load64_lane(m, v.as_u64x2(), L))
+    transmute(llvm_load64_lane(m, v.as_u64x2(), L as u32))
 }
 
 /// Stores the 8-bit value from lane `L` of `v` into `m`
@@ -433,7 +433,7 @@ pub unsafe fn v128_load64_lane<const L: usize>(v: v128, m: *const u64) -> v128 {
 #[target_feature(enable = "simd128")]
 pub unsafe fn v128_store8_lane<const L: usize>(v: v128, m: *mut u8) {
     static_assert!(L: usize where L < 16);
-    llvm_store8_lane(m, v.as_u8x16(), L);
+    llvm_store8_lane(m, v.as_u8x16(), L as u32);
 }
 
 /// Stores the 16-bit value from lane `L` of `v` into `m`
@@ -442,7 +442,7 @@ pub unsafe fn v128_store8_lane<const L: usize>(v: v128, m: *mut u8) {
 #[target_feature(enable = "simd128")]
 pub unsafe fn v128_store16_lane<const L: usize>(v: v128, m: *mut u16) {
     static_assert!(L: usize where L < 8);
-    llvm_store16_lane(m, v.as_u16x8(), L)
+    llvm_store16_lane(m, v.as_u16x8(), L as u32)
 }
 
 /// Stores the 32-bit value from lane `L` of `v` into `m`
@@ -451,7 +451,7 @@ pub unsafe fn v128_store16_lane<const L: usize>(v: v128, m: *mut u16) {
 #[target_feature(enable = "simd128")]
 pub unsafe fn v128_store32_lane<const L: usize>(v: v128, m: *mut u32) {
     static_assert!(L: usize where L < 4);
-    llvm_store32_lane(m, v.as_u32x4(), L)
+    llvm_store32_lane(m, v.as_u32x4(), L as u32)
 }
 
 /// Stores the 64-bit value from lane `L` of `v` into `m`
@@ -460,7 +460,7 @@ pub unsafe fn v128_store32_lane<const L: usize>(v: v128, m: *mut u32) {
 #[target_feature(enable = "simd128")]
 pub unsafe fn v128_store64_lane<const L: usize>(v: v128, m: *mut u64) {
     static_assert!(L: usize where L < 2);
-    llvm_store64_lane(m, v.as_u64x2(), L)
+    llvm_store64_lane(m, v.as_u64x2(), L as u32)
 }
 
 /// Materializes a SIMD value from the provided operands.
@@ -491,7 +491,7 @@ pub unsafe fn v128_store64_lane<const L: usize>(v: v128, m: *mut u64) {
         a15 = 15,
     )
 )]
-pub const unsafe fn i8x16(
+pub const fn i8x16(
     a0: i8,
     a1: i8,
     a2: i8,
@@ -509,9 +509,11 @@ pub const unsafe fn i8x16(
     a14: i8,
     a15: i8,
 ) -> v128 {
-    transmute(simd::i8x16(
-        a0, a1, a2, a3, a4, a5, a6, a7, a8, a9, a10, a11, a12, a13, a14, a15,
-    ))
+    unsafe {
+        transmute(simd::i8x16(
+            a0, a1, a2, a3, a4, a5, a6, a7, a8, a9, a10, a11, a12, a13, a14, a15,
+        ))
+    }
 }
 
 /// Materializes a SIMD value from the provided operands.
@@ -520,7 +522,7 @@ pub const unsafe fn i8x16(
 /// be lowered to a sequence of instructions to materialize the vector value.
 #[inline]
 #[target_feature(enable = "simd128")]
-pub const unsafe fn u8x16(
+pub const fn u8x16(
     a0: u8,
     a1: u8,
     a2: u8,
@@ -564,17 +566,8 @@ pub const unsafe fn u8x16(
         a7 = 7,
     )
 )]
-pub const unsafe fn i16x8(
-    a0: i16,
-    a1: i16,
-    a2: i16,
-    a3: i16,
-    a4: i16,
-    a5: i16,
-    a6: i16,
-    a7: i16,
-) -> v128 {
-    transmute(simd::i16x8(a0, a1, a2, a3, a4, a5, a6, a7))
+pub const fn i16x8(a0: i16, a1: i16, a2: i16, a3: i16, a4: i16, a5: i16, a6: i16, a7: i16) -> v128 {
+    unsafe { transmute(simd::i16x8(a0, a1, a2, a3, a4, a5, a6, a7)) }
 }
 
 /// Materializes a SIMD value from the provided operands.
@@ -583,16 +576,7 @@ pub const unsafe fn i16x8(
 /// be lowered to a sequence of instructions to materialize the vector value.
 #[inline]
 #[target_feature(enable = "simd128")]
-pub const unsafe fn u16x8(
-    a0: u16,
-    a1: u16,
-    a2: u16,
-    a3: u16,
-    a4: u16,
-    a5: u16,
-    a6: u16,
-    a7: u16,
-) -> v128 {
+pub const fn u16x8(a0: u16, a1: u16, a2: u16, a3: u16, a4: u16, a5: u16, a6: u16, a7: u16) -> v128 {
     i16x8(
         a0 as i16, a1 as i16, a2 as i16, a3 as i16, a4 as i16, a5 as i16, a6 as i16, a7 as i16,
     )
@@ -605,8 +589,8 @@ pub const unsafe fn u16x8(
 #[inline]
 #[target_feature(enable = "simd128")]
 #[cfg_attr(test, assert_instr(v128.const, a0 = 0, a1 = 1, a2 = 2, a3 = 3))]
-pub const unsafe fn i32x4(a0: i32, a1: i32, a2: i32, a3: i32) -> v128 {
-    transmute(simd::i32x4(a0, a1, a2, a3))
+pub const fn i32x4(a0: i32, a1: i32, a2: i32, a3: i32) -> v128 {
+    unsafe { transmute(simd::i32x4(a0, a1, a2, a3)) }
 }
 
 /// Materializes a SIMD value from the provided operands.
@@ -615,7 +599,7 @@ pub const unsafe fn i32x4(a0: i32, a1: i32, a2: i32, a3: i32) -> v128 {
 /// be lowered to a sequence of instructions to materialize the vector value.
 #[inline]
 #[target_feature(enable = "simd128")]
-pub const unsafe fn u32x4(a0: u32, a1: u32, a2: u32, a3: u32) -> v128 {
+pub const fn u32x4(a0: u32, a1: u32, a2: u32, a3: u32) -> v128 {
     i32x4(a0 as i32, a1 as i32, a2 as i32, a3 as i32)
 }
 
@@ -626,8 +610,8 @@ pub const unsafe fn u32x4(a0: u32, a1: u32, a2: u32, a3: u32) -> v128 {
 #[inline]
 #[target_feature(enable = "simd128")]
 #[cfg_attr(test, assert_instr(v128.const, a0 = 0, a1 = 1))]
-pub const unsafe fn i64x2(a0: i64, a1: i64) -> v128 {
-    transmute(simd::i64x2(a0, a1))
+pub const fn i64x2(a0: i64, a1: i64) -> v128 {
+    unsafe { transmute(simd::i64x2(a0, a1)) }
 }
 
 /// Materializes a SIMD value from the provided operands.
@@ -636,7 +620,7 @@ pub const unsafe fn i64x2(a0: i64, a1: i64) -> v128 {
 /// be lowered to a sequence of instructions to materialize the vector value.
 #[inline]
 #[target_feature(enable = "simd128")]
-pub const unsafe fn u64x2(a0: u64, a1: u64) -> v128 {
+pub const fn u64x2(a0: u64, a1: u64) -> v128 {
     i64x2(a0 as i64, a1 as i64)
 }
 
@@ -647,8 +631,8 @@ pub const unsafe fn u64x2(a0: u64, a1: u64) -> v128 {
 #[inline]
 #[target_feature(enable = "simd128")]
 #[cfg_attr(test, assert_instr(v128.const, a0 = 0.0, a1 = 1.0, a2 = 2.0, a3 = 3.0))]
-pub const unsafe fn f32x4(a0: f32, a1: f32, a2: f32, a3: f32) -> v128 {
-    transmute(simd::f32x4(a0, a1, a2, a3))
+pub const fn f32x4(a0: f32, a1: f32, a2: f32, a3: f32) -> v128 {
+    unsafe { transmute(simd::f32x4(a0, a1, a2, a3)) }
 }
 
 /// Materializes a SIMD value from the provided operands.
@@ -658,8 +642,8 @@ pub const unsafe fn f32x4(a0: f32, a1: f32, a2: f32, a3: f32) -> v128 {
 #[inline]
 #[target_feature(enable = "simd128")]
 #[cfg_attr(test, assert_instr(v128.const, a0 = 0.0, a1 = 1.0))]
-pub const unsafe fn f64x2(a0: f64, a1: f64) -> v128 {
-    transmute(simd::f64x2(a0, a1))
+pub const fn f64x2(a0: f64, a1: f64) -> v128 {
+    unsafe { transmute(simd::f64x2(a0, a1)) }
 }
 
 /// Returns a new vector with lanes selected from the lanes of the two input
@@ -699,7 +683,7 @@ pub const unsafe fn f64x2(a0: f64, a1: f64) -> v128 {
     )
 )]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i8x16_shuffle<
+pub fn i8x16_shuffle<
     const I0: usize,
     const I1: usize,
     const I2: usize,
@@ -736,16 +720,18 @@ pub unsafe fn i8x16_shuffle<
     static_assert!(I13: usize where I13 < 32);
     static_assert!(I14: usize where I14 < 32);
     static_assert!(I15: usize where I15 < 32);
-    let shuf = simd_shuffle16::<simd::u8x16, simd::u8x16>(
-        a.as_u8x16(),
-        b.as_u8x16(),
-        [
-            I0 as u32, I1 as u32, I2 as u32, I3 as u32, I4 as u32, I5 as u32, I6 as u32, I7 as u32,
-            I8 as u32, I9 as u32, I10 as u32, I11 as u32, I12 as u32, I13 as u32, I14 as u32,
-            I15 as u32,
-        ],
-    );
-    transmute(shuf)
+    unsafe {
+        let shuf = simd_shuffle16::<simd::u8x16, simd::u8x16>(
+            a.as_u8x16(),
+            b.as_u8x16(),
+            [
+                I0 as u32, I1 as u32, I2 as u32, I3 as u32, I4 as u32, I5 as u32, I6 as u32,
+                I7 as u32, I8 as u32, I9 as u32, I10 as u32, I11 as u32, I12 as u32, I13 as u32,
+                I14 as u32, I15 as u32,
+            ],
+        );
+        transmute(shuf)
+    }
 }
 
 /// Same as [`i8x16_shuffle`], except operates as if the inputs were eight
@@ -770,7 +756,7 @@ pub unsafe fn i8x16_shuffle<
     )
 )]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i16x8_shuffle<
+pub fn i16x8_shuffle<
     const I0: usize,
     const I1: usize,
     const I2: usize,
@@ -791,14 +777,17 @@ pub unsafe fn i16x8_shuffle<
     static_assert!(I5: usize where I5 < 16);
     static_assert!(I6: usize where I6 < 16);
     static_assert!(I7: usize where I7 < 16);
-    let shuf = simd_shuffle8::<simd::u16x8, simd::u16x8>(
-        a.as_u16x8(),
-        b.as_u16x8(),
-        [
-            I0 as u32, I1 as u32, I2 as u32, I3 as u32, I4 as u32, I5 as u32, I6 as u32, I7 as u32,
-        ],
-    );
-    transmute(shuf)
+    unsafe {
+        let shuf = simd_shuffle8::<simd::u16x8, simd::u16x8>(
+            a.as_u16x8(),
+            b.as_u16x8(),
+            [
+                I0 as u32, I1 as u32, I2 as u32, I3 as u32, I4 as u32, I5 as u32, I6 as u32,
+                I7 as u32,
+            ],
+        );
+        transmute(shuf)
+    }
 }
 
 /// Same as [`i8x16_shuffle`], except operates as if the inputs were four
@@ -811,7 +800,7 @@ pub unsafe fn i16x8_shuffle<
 #[inline]
 #[cfg_attr(test, assert_instr(i8x16.shuffle, I0 = 0, I1 = 2, I2 = 4, I3 = 6))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i32x4_shuffle<const I0: usize, const I1: usize, const I2: usize, const I3: usize>(
+pub fn i32x4_shuffle<const I0: usize, const I1: usize, const I2: usize, const I3: usize>(
     a: v128,
     b: v128,
 ) -> v128 {
@@ -819,12 +808,14 @@ pub unsafe fn i32x4_shuffle<const I0: usize, const I1: usize, const I2: usize, c
     static_assert!(I1: usize where I1 < 8);
     static_assert!(I2: usize where I2 < 8);
     static_assert!(I3: usize where I3 < 8);
-    let shuf = simd_shuffle4::<simd::u32x4, simd::u32x4>(
-        a.as_u32x4(),
-        b.as_u32x4(),
-        [I0 as u32, I1 as u32, I2 as u32, I3 as u32],
-    );
-    transmute(shuf)
+    unsafe {
+        let shuf = simd_shuffle4::<simd::u32x4, simd::u32x4>(
+            a.as_u32x4(),
+            b.as_u32x4(),
+            [I0 as u32, I1 as u32, I2 as u32, I3 as u32],
+        );
+        transmute(shuf)
+    }
 }
 
 /// Same as [`i8x16_shuffle`], except operates as if the inputs were two
@@ -837,15 +828,17 @@ pub unsafe fn i32x4_shuffle<const I0: usize, const I1: usize, const I2: usize, c
 #[inline]
 #[cfg_attr(test, assert_instr(i8x16.shuffle, I0 = 0, I1 = 2))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i64x2_shuffle<const I0: usize, const I1: usize>(a: v128, b: v128) -> v128 {
+pub fn i64x2_shuffle<const I0: usize, const I1: usize>(a: v128, b: v128) -> v128 {
     static_assert!(I0: usize where I0 < 4);
     static_assert!(I1: usize where I1 < 4);
-    let shuf = simd_shuffle2::<simd::u64x2, simd::u64x2>(
-        a.as_u64x2(),
-        b.as_u64x2(),
-        [I0 as u32, I1 as u32],
-    );
-    transmute(shuf)
+    unsafe {
+        let shuf = simd_shuffle2::<simd::u64x2, simd::u64x2>(
+            a.as_u64x2(),
+            b.as_u64x2(),
+            [I0 as u32, I1 as u32],
+        );
+        transmute(shuf)
+    }
 }
 
 /// Extracts a lane from a 128-bit vector interpreted as 16 packed i8 numbers.
@@ -855,9 +848,9 @@ pub unsafe fn i64x2_shuffle<const I0: usize, const I1: usize>(a: v128, b: v128)
 #[inline]
 #[cfg_attr(test, assert_instr(i8x16.extract_lane_s, N = 3))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i8x16_extract_lane<const N: usize>(a: v128) -> i8 {
+pub fn i8x16_extract_lane<const N: usize>(a: v128) -> i8 {
     static_assert!(N: usize where N < 16);
-    simd_extract(a.as_i8x16(), N as u32)
+    unsafe { simd_extract(a.as_i8x16(), N as u32) }
 }
 
 /// Extracts a lane from a 128-bit vector interpreted as 16 packed u8 numbers.
@@ -867,9 +860,9 @@ pub unsafe fn i8x16_extract_lane<const N: usize>(a: v128) -> i8 {
 #[inline]
 #[cfg_attr(test, assert_instr(i8x16.extract_lane_u, N = 3))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn u8x16_extract_lane<const N: usize>(a: v128) -> u8 {
+pub fn u8x16_extract_lane<const N: usize>(a: v128) -> u8 {
     static_assert!(N: usize where N < 16);
-    simd_extract(a.as_u8x16(), N as u32)
+    unsafe { simd_extract(a.as_u8x16(), N as u32) }
 }
 
 /// Replaces a lane from a 128-bit vector interpreted as 16 packed i8 numbers.
@@ -879,9 +872,9 @@ pub unsafe fn u8x16_extract_lane<const N: usize>(a: v128) -> u8 {
 #[inline]
 #[cfg_attr(test, assert_instr(i8x16.replace_lane, N = 2))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i8x16_replace_lane<const N: usize>(a: v128, val: i8) -> v128 {
+pub fn i8x16_replace_lane<const N: usize>(a: v128, val: i8) -> v128 {
     static_assert!(N: usize where N < 16);
-    transmute(simd_insert(a.as_i8x16(), N as u32, val))
+    unsafe { transmute(simd_insert(a.as_i8x16(), N as u32, val)) }
 }
 
 /// Replaces a lane from a 128-bit vector interpreted as 16 packed u8 numbers.
@@ -891,9 +884,9 @@ pub unsafe fn i8x16_replace_lane<const N: usize>(a: v128, val: i8) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i8x16.replace_lane, N = 2))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn u8x16_replace_lane<const N: usize>(a: v128, val: u8) -> v128 {
+pub fn u8x16_replace_lane<const N: usize>(a: v128, val: u8) -> v128 {
     static_assert!(N: usize where N < 16);
-    transmute(simd_insert(a.as_u8x16(), N as u32, val))
+    unsafe { transmute(simd_insert(a.as_u8x16(), N as u32, val)) }
 }
 
 /// Extracts a lane from a 128-bit vector interpreted as 8 packed i16 numbers.
@@ -903,9 +896,9 @@ pub unsafe fn u8x16_replace_lane<const N: usize>(a: v128, val: u8) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i16x8.extract_lane_s, N = 2))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i16x8_extract_lane<const N: usize>(a: v128) -> i16 {
+pub fn i16x8_extract_lane<const N: usize>(a: v128) -> i16 {
     static_assert!(N: usize where N < 8);
-    simd_extract(a.as_i16x8(), N as u32)
+    unsafe { simd_extract(a.as_i16x8(), N as u32) }
 }
 
 /// Extracts a lane from a 128-bit vector interpreted as 8 packed u16 numbers.
@@ -915,9 +908,9 @@ pub unsafe fn i16x8_extract_lane<const N: usize>(a: v128) -> i16 {
 #[inline]
 #[cfg_attr(test, assert_instr(i16x8.extract_lane_u, N = 2))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn u16x8_extract_lane<const N: usize>(a: v128) -> u16 {
+pub fn u16x8_extract_lane<const N: usize>(a: v128) -> u16 {
     static_assert!(N: usize where N < 8);
-    simd_extract(a.as_u16x8(), N as u32)
+    unsafe { simd_extract(a.as_u16x8(), N as u32) }
 }
 
 /// Replaces a lane from a 128-bit vector interpreted as 8 packed i16 numbers.
@@ -927,9 +920,9 @@ pub unsafe fn u16x8_extract_lane<const N: usize>(a: v128) -> u16 {
 #[inline]
 #[cfg_attr(test, assert_instr(i16x8.replace_lane, N = 2))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i16x8_replace_lane<const N: usize>(a: v128, val: i16) -> v128 {
+pub fn i16x8_replace_lane<const N: usize>(a: v128, val: i16) -> v128 {
     static_assert!(N: usize where N < 8);
-    transmute(simd_insert(a.as_i16x8(), N as u32, val))
+    unsafe { transmute(simd_insert(a.as_i16x8(), N as u32, val)) }
 }
 
 /// Replaces a lane from a 128-bit vector interpreted as 8 packed u16 numbers.
@@ -939,9 +932,9 @@ pub unsafe fn i16x8_replace_lane<const N: usize>(a: v128, val: i16) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i16x8.replace_lane, N = 2))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn u16x8_replace_lane<const N: usize>(a: v128, val: u16) -> v128 {
+pub fn u16x8_replace_lane<const N: usize>(a: v128, val: u16) -> v128 {
     static_assert!(N: usize where N < 8);
-    transmute(simd_insert(a.as_u16x8(), N as u32, val))
+    unsafe { transmute(simd_insert(a.as_u16x8(), N as u32, val)) }
 }
 
 /// Extracts a lane from a 128-bit vector interpreted as 4 packed i32 numbers.
@@ -951,9 +944,9 @@ pub unsafe fn u16x8_replace_lane<const N: usize>(a: v128, val: u16) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i32x4.extract_lane, N = 2))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i32x4_extract_lane<const N: usize>(a: v128) -> i32 {
+pub fn i32x4_extract_lane<const N: usize>(a: v128) -> i32 {
     static_assert!(N: usize where N < 4);
-    simd_extract(a.as_i32x4(), N as u32)
+    unsafe { simd_extract(a.as_i32x4(), N as u32) }
 }
 
 /// Extracts a lane from a 128-bit vector interpreted as 4 packed u32 numbers.
@@ -962,7 +955,7 @@ pub unsafe fn i32x4_extract_lane<const N: usize>(a: v128) -> i32 {
 /// `N` from `a`. If `N` is out of bounds then it is a compile time error.
 #[inline]
 #[target_feature(enable = "simd128")]
-pub unsafe fn u32x4_extract_lane<const N: usize>(a: v128) -> u32 {
+pub fn u32x4_extract_lane<const N: usize>(a: v128) -> u32 {
     i32x4_extract_lane::<N>(a) as u32
 }
 
@@ -973,9 +966,9 @@ pub unsafe fn u32x4_extract_lane<const N: usize>(a: v128) -> u32 {
 #[inline]
 #[cfg_attr(test, assert_instr(i32x4.replace_lane, N = 2))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i32x4_replace_lane<const N: usize>(a: v128, val: i32) -> v128 {
+pub fn i32x4_replace_lane<const N: usize>(a: v128, val: i32) -> v128 {
     static_assert!(N: usize where N < 4);
-    transmute(simd_insert(a.as_i32x4(), N as u32, val))
+    unsafe { transmute(simd_insert(a.as_i32x4(), N as u32, val)) }
 }
 
 /// Replaces a lane from a 128-bit vector interpreted as 4 packed u32 numbers.
@@ -984,7 +977,7 @@ pub unsafe fn i32x4_replace_lane<const N: usize>(a: v128, val: i32) -> v128 {
 /// `N` from `a`. If `N` is out of bounds then it is a compile time error.
 #[inline]
 #[target_feature(enable = "simd128")]
-pub unsafe fn u32x4_replace_lane<const N: usize>(a: v128, val: u32) -> v128 {
+pub fn u32x4_replace_lane<const N: usize>(a: v128, val: u32) -> v128 {
     i32x4_replace_lane::<N>(a, val as i32)
 }
 
@@ -995,9 +988,9 @@ pub unsafe fn u32x4_replace_lane<const N: usize>(a: v128, val: u32) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i64x2.extract_lane, N = 1))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i64x2_extract_lane<const N: usize>(a: v128) -> i64 {
+pub fn i64x2_extract_lane<const N: usize>(a: v128) -> i64 {
     static_assert!(N: usize where N < 2);
-    simd_extract(a.as_i64x2(), N as u32)
+    unsafe { simd_extract(a.as_i64x2(), N as u32) }
 }
 
 /// Extracts a lane from a 128-bit vector interpreted as 2 packed u64 numbers.
@@ -1006,7 +999,7 @@ pub unsafe fn i64x2_extract_lane<const N: usize>(a: v128) -> i64 {
 /// `N` from `a`. If `N` is out of bounds then it is a compile time error.
 #[inline]
 #[target_feature(enable = "simd128")]
-pub unsafe fn u64x2_extract_lane<const N: usize>(a: v128) -> u64 {
+pub fn u64x2_extract_lane<const N: usize>(a: v128) -> u64 {
     i64x2_extract_lane::<N>(a) as u64
 }
 
@@ -1017,9 +1010,9 @@ pub unsafe fn u64x2_extract_lane<const N: usize>(a: v128) -> u64 {
 #[inline]
 #[cfg_attr(test, assert_instr(i64x2.replace_lane, N = 0))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i64x2_replace_lane<const N: usize>(a: v128, val: i64) -> v128 {
+pub fn i64x2_replace_lane<const N: usize>(a: v128, val: i64) -> v128 {
     static_assert!(N: usize where N < 2);
-    transmute(simd_insert(a.as_i64x2(), N as u32, val))
+    unsafe { transmute(simd_insert(a.as_i64x2(), N as u32, val)) }
 }
 
 /// Replaces a lane from a 128-bit vector interpreted as 2 packed u64 numbers.
@@ -1028,7 +1021,7 @@ pub unsafe fn i64x2_replace_lane<const N: usize>(a: v128, val: i64) -> v128 {
 /// `N` from `a`. If `N` is out of bounds then it is a compile time error.
 #[inline]
 #[target_feature(enable = "simd128")]
-pub unsafe fn u64x2_replace_lane<const N: usize>(a: v128, val: u64) -> v128 {
+pub fn u64x2_replace_lane<const N: usize>(a: v128, val: u64) -> v128 {
     i64x2_replace_lane::<N>(a, val as i64)
 }
 
@@ -1039,9 +1032,9 @@ pub unsafe fn u64x2_replace_lane<const N: usize>(a: v128, val: u64) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(f32x4.extract_lane, N = 1))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn f32x4_extract_lane<const N: usize>(a: v128) -> f32 {
+pub fn f32x4_extract_lane<const N: usize>(a: v128) -> f32 {
     static_assert!(N: usize where N < 4);
-    simd_extract(a.as_f32x4(), N as u32)
+    unsafe { simd_extract(a.as_f32x4(), N as u32) }
 }
 
 /// Replaces a lane from a 128-bit vector interpreted as 4 packed f32 numbers.
@@ -1051,9 +1044,9 @@ pub unsafe fn f32x4_extract_lane<const N: usize>(a: v128) -> f32 {
 #[inline]
 #[cfg_attr(test, assert_instr(f32x4.replace_lane, N = 1))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn f32x4_replace_lane<const N: usize>(a: v128, val: f32) -> v128 {
+pub fn f32x4_replace_lane<const N: usize>(a: v128, val: f32) -> v128 {
     static_assert!(N: usize where N < 4);
-    transmute(simd_insert(a.as_f32x4(), N as u32, val))
+    unsafe { transmute(simd_insert(a.as_f32x4(), N as u32, val)) }
 }
 
 /// Extracts a lane from a 128-bit vector interpreted as 2 packed f64 numbers.
@@ -1063,9 +1056,9 @@ pub unsafe fn f32x4_replace_lane<const N: usize>(a: v128, val: f32) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(f64x2.extract_lane, N = 1))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn f64x2_extract_lane<const N: usize>(a: v128) -> f64 {
+pub fn f64x2_extract_lane<const N: usize>(a: v128) -> f64 {
     static_assert!(N: usize where N < 2);
-    simd_extract(a.as_f64x2(), N as u32)
+    unsafe { simd_extract(a.as_f64x2(), N as u32) }
 }
 
 /// Replaces a lane from a 128-bit vector interpreted as 2 packed f64 numbers.
@@ -1075,9 +1068,9 @@ pub unsafe fn f64x2_extract_lane<const N: usize>(a: v128) -> f64 {
 #[inline]
 #[cfg_attr(test, assert_instr(f64x2.replace_lane, N = 1))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn f64x2_replace_lane<const N: usize>(a: v128, val: f64) -> v128 {
+pub fn f64x2_replace_lane<const N: usize>(a: v128, val: f64) -> v128 {
     static_assert!(N: usize where N < 2);
-    transmute(simd_insert(a.as_f64x2(), N as u32, val))
+    unsafe { transmute(simd_insert(a.as_f64x2(), N as u32, val)) }
 }
 
 /// Returns a new vector with lanes selected from the lanes of the first input
@@ -1088,8 +1081,8 @@ pub unsafe fn f64x2_replace_lane<const N: usize>(a: v128, val: f64) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i8x16.swizzle))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i8x16_swizzle(a: v128, s: v128) -> v128 {
-    transmute(llvm_swizzle(transmute(a), transmute(s)))
+pub fn i8x16_swizzle(a: v128, s: v128) -> v128 {
+    unsafe { transmute(llvm_swizzle(transmute(a), transmute(s))) }
 }
 
 /// Creates a vector with identical lanes.
@@ -1098,8 +1091,8 @@ pub unsafe fn i8x16_swizzle(a: v128, s: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i8x16.splat))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i8x16_splat(a: i8) -> v128 {
-    transmute(simd::i8x16::splat(a))
+pub fn i8x16_splat(a: i8) -> v128 {
+    unsafe { transmute(simd::i8x16::splat(a)) }
 }
 
 /// Creates a vector with identical lanes.
@@ -1108,8 +1101,8 @@ pub unsafe fn i8x16_splat(a: i8) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i8x16.splat))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn u8x16_splat(a: u8) -> v128 {
-    transmute(simd::u8x16::splat(a))
+pub fn u8x16_splat(a: u8) -> v128 {
+    unsafe { transmute(simd::u8x16::splat(a)) }
 }
 
 /// Creates a vector with identical lanes.
@@ -1118,8 +1111,8 @@ pub unsafe fn u8x16_splat(a: u8) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i16x8.splat))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i16x8_splat(a: i16) -> v128 {
-    transmute(simd::i16x8::splat(a))
+pub fn i16x8_splat(a: i16) -> v128 {
+    unsafe { transmute(simd::i16x8::splat(a)) }
 }
 
 /// Creates a vector with identical lanes.
@@ -1128,8 +1121,8 @@ pub unsafe fn i16x8_splat(a: i16) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i16x8.splat))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn u16x8_splat(a: u16) -> v128 {
-    transmute(simd::u16x8::splat(a))
+pub fn u16x8_splat(a: u16) -> v128 {
+    unsafe { transmute(simd::u16x8::splat(a)) }
 }
 
 /// Creates a vector with identical lanes.
@@ -1138,8 +1131,8 @@ pub unsafe fn u16x8_splat(a: u16) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i32x4.splat))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i32x4_splat(a: i32) -> v128 {
-    transmute(simd::i32x4::splat(a))
+pub fn i32x4_splat(a: i32) -> v128 {
+    unsafe { transmute(simd::i32x4::splat(a)) }
 }
 
 /// Creates a vector with identical lanes.
@@ -1147,7 +1140,7 @@ pub unsafe fn i32x4_splat(a: i32) -> v128 {
 /// Constructs a vector with `x` replicated to all 4 lanes.
 #[inline]
 #[target_feature(enable = "simd128")]
-pub unsafe fn u32x4_splat(a: u32) -> v128 {
+pub fn u32x4_splat(a: u32) -> v128 {
     i32x4_splat(a as i32)
 }
 
@@ -1157,8 +1150,8 @@ pub unsafe fn u32x4_splat(a: u32) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i64x2.splat))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i64x2_splat(a: i64) -> v128 {
-    transmute(simd::i64x2::splat(a))
+pub fn i64x2_splat(a: i64) -> v128 {
+    unsafe { transmute(simd::i64x2::splat(a)) }
 }
 
 /// Creates a vector with identical lanes.
@@ -1166,7 +1159,7 @@ pub unsafe fn i64x2_splat(a: i64) -> v128 {
 /// Construct a vector with `x` replicated to all 2 lanes.
 #[inline]
 #[target_feature(enable = "simd128")]
-pub unsafe fn u64x2_splat(a: u64) -> v128 {
+pub fn u64x2_splat(a: u64) -> v128 {
     i64x2_splat(a as i64)
 }
 
@@ -1176,8 +1169,8 @@ pub unsafe fn u64x2_splat(a: u64) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(f32x4.splat))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn f32x4_splat(a: f32) -> v128 {
-    transmute(simd::f32x4::splat(a))
+pub fn f32x4_splat(a: f32) -> v128 {
+    unsafe { transmute(simd::f32x4::splat(a)) }
 }
 
 /// Creates a vector with identical lanes.
@@ -1186,8 +1179,8 @@ pub unsafe fn f32x4_splat(a: f32) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(f64x2.splat))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn f64x2_splat(a: f64) -> v128 {
-    transmute(simd::f64x2::splat(a))
+pub fn f64x2_splat(a: f64) -> v128 {
+    unsafe { transmute(simd::f64x2::splat(a)) }
 }
 
 /// Compares two 128-bit vectors as if they were two vectors of 16 eight-bit
@@ -1198,8 +1191,8 @@ pub unsafe fn f64x2_splat(a: f64) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i8x16.eq))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i8x16_eq(a: v128, b: v128) -> v128 {
-    transmute(simd_eq::<_, simd::i8x16>(a.as_i8x16(), b.as_i8x16()))
+pub fn i8x16_eq(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_eq::<_, simd::i8x16>(a.as_i8x16(), b.as_i8x16())) }
 }
 
 /// Compares two 128-bit vectors as if they were two vectors of 16 eight-bit
@@ -1210,8 +1203,8 @@ pub unsafe fn i8x16_eq(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i8x16.ne))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i8x16_ne(a: v128, b: v128) -> v128 {
-    transmute(simd_ne::<_, simd::i8x16>(a.as_i8x16(), b.as_i8x16()))
+pub fn i8x16_ne(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_ne::<_, simd::i8x16>(a.as_i8x16(), b.as_i8x16())) }
 }
 
 /// Compares two 128-bit vectors as if they were two vectors of 16 eight-bit
@@ -1222,8 +1215,8 @@ pub unsafe fn i8x16_ne(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i8x16.lt_s))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i8x16_lt(a: v128, b: v128) -> v128 {
-    transmute(simd_lt::<_, simd::i8x16>(a.as_i8x16(), b.as_i8x16()))
+pub fn i8x16_lt(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_lt::<_, simd::i8x16>(a.as_i8x16(), b.as_i8x16())) }
 }
 
 /// Compares two 128-bit vectors as if they were two vectors of 16 eight-bit
@@ -1234,8 +1227,8 @@ pub unsafe fn i8x16_lt(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i8x16.lt_u))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn u8x16_lt(a: v128, b: v128) -> v128 {
-    transmute(simd_lt::<_, simd::i8x16>(a.as_u8x16(), b.as_u8x16()))
+pub fn u8x16_lt(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_lt::<_, simd::i8x16>(a.as_u8x16(), b.as_u8x16())) }
 }
 
 /// Compares two 128-bit vectors as if they were two vectors of 16 eight-bit
@@ -1246,8 +1239,8 @@ pub unsafe fn u8x16_lt(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i8x16.gt_s))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i8x16_gt(a: v128, b: v128) -> v128 {
-    transmute(simd_gt::<_, simd::i8x16>(a.as_i8x16(), b.as_i8x16()))
+pub fn i8x16_gt(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_gt::<_, simd::i8x16>(a.as_i8x16(), b.as_i8x16())) }
 }
 
 /// Compares two 128-bit vectors as if they were two vectors of 16 eight-bit
@@ -1258,8 +1251,8 @@ pub unsafe fn i8x16_gt(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i8x16.gt_u))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn u8x16_gt(a: v128, b: v128) -> v128 {
-    transmute(simd_gt::<_, simd::i8x16>(a.as_u8x16(), b.as_u8x16()))
+pub fn u8x16_gt(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_gt::<_, simd::i8x16>(a.as_u8x16(), b.as_u8x16())) }
 }
 
 /// Compares two 128-bit vectors as if they were two vectors of 16 eight-bit
@@ -1270,8 +1263,8 @@ pub unsafe fn u8x16_gt(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i8x16.le_s))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i8x16_le(a: v128, b: v128) -> v128 {
-    transmute(simd_le::<_, simd::i8x16>(a.as_i8x16(), b.as_i8x16()))
+pub fn i8x16_le(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_le::<_, simd::i8x16>(a.as_i8x16(), b.as_i8x16())) }
 }
 
 /// Compares two 128-bit vectors as if they were two vectors of 16 eight-bit
@@ -1282,8 +1275,8 @@ pub unsafe fn i8x16_le(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i8x16.le_u))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn u8x16_le(a: v128, b: v128) -> v128 {
-    transmute(simd_le::<_, simd::i8x16>(a.as_u8x16(), b.as_u8x16()))
+pub fn u8x16_le(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_le::<_, simd::i8x16>(a.as_u8x16(), b.as_u8x16())) }
 }
 
 /// Compares two 128-bit vectors as if they were two vectors of 16 eight-bit
@@ -1294,8 +1287,8 @@ pub unsafe fn u8x16_le(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i8x16.ge_s))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i8x16_ge(a: v128, b: v128) -> v128 {
-    transmute(simd_ge::<_, simd::i8x16>(a.as_i8x16(), b.as_i8x16()))
+pub fn i8x16_ge(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_ge::<_, simd::i8x16>(a.as_i8x16(), b.as_i8x16())) }
 }
 
 /// Compares two 128-bit vectors as if they were two vectors of 16 eight-bit
@@ -1306,8 +1299,8 @@ pub unsafe fn i8x16_ge(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i8x16.ge_u))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn u8x16_ge(a: v128, b: v128) -> v128 {
-    transmute(simd_ge::<_, simd::i8x16>(a.as_u8x16(), b.as_u8x16()))
+pub fn u8x16_ge(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_ge::<_, simd::i8x16>(a.as_u8x16(), b.as_u8x16())) }
 }
 
 /// Compares two 128-bit vectors as if they were two vectors of 8 sixteen-bit
@@ -1318,8 +1311,8 @@ pub unsafe fn u8x16_ge(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i16x8.eq))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i16x8_eq(a: v128, b: v128) -> v128 {
-    transmute(simd_eq::<_, simd::i16x8>(a.as_i16x8(), b.as_i16x8()))
+pub fn i16x8_eq(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_eq::<_, simd::i16x8>(a.as_i16x8(), b.as_i16x8())) }
 }
 
 /// Compares two 128-bit vectors as if they were two vectors of 8 sixteen-bit
@@ -1330,8 +1323,8 @@ pub unsafe fn i16x8_eq(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i16x8.ne))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i16x8_ne(a: v128, b: v128) -> v128 {
-    transmute(simd_ne::<_, simd::i16x8>(a.as_i16x8(), b.as_i16x8()))
+pub fn i16x8_ne(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_ne::<_, simd::i16x8>(a.as_i16x8(), b.as_i16x8())) }
 }
 
 /// Compares two 128-bit vectors as if they were two vectors of 8 sixteen-bit
@@ -1342,8 +1335,8 @@ pub unsafe fn i16x8_ne(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i16x8.lt_s))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i16x8_lt(a: v128, b: v128) -> v128 {
-    transmute(simd_lt::<_, simd::i16x8>(a.as_i16x8(), b.as_i16x8()))
+pub fn i16x8_lt(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_lt::<_, simd::i16x8>(a.as_i16x8(), b.as_i16x8())) }
 }
 
 /// Compares two 128-bit vectors as if they were two vectors of 8 sixteen-bit
@@ -1354,8 +1347,8 @@ pub unsafe fn i16x8_lt(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i16x8.lt_u))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn u16x8_lt(a: v128, b: v128) -> v128 {
-    transmute(simd_lt::<_, simd::i16x8>(a.as_u16x8(), b.as_u16x8()))
+pub fn u16x8_lt(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_lt::<_, simd::i16x8>(a.as_u16x8(), b.as_u16x8())) }
 }
 
 /// Compares two 128-bit vectors as if they were two vectors of 8 sixteen-bit
@@ -1366,8 +1359,8 @@ pub unsafe fn u16x8_lt(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i16x8.gt_s))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i16x8_gt(a: v128, b: v128) -> v128 {
-    transmute(simd_gt::<_, simd::i16x8>(a.as_i16x8(), b.as_i16x8()))
+pub fn i16x8_gt(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_gt::<_, simd::i16x8>(a.as_i16x8(), b.as_i16x8())) }
 }
 
 /// Compares two 128-bit vectors as if they were two vectors of 8 sixteen-bit
@@ -1378,8 +1371,8 @@ pub unsafe fn i16x8_gt(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i16x8.gt_u))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn u16x8_gt(a: v128, b: v128) -> v128 {
-    transmute(simd_gt::<_, simd::i16x8>(a.as_u16x8(), b.as_u16x8()))
+pub fn u16x8_gt(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_gt::<_, simd::i16x8>(a.as_u16x8(), b.as_u16x8())) }
 }
 
 /// Compares two 128-bit vectors as if they were two vectors of 8 sixteen-bit
@@ -1390,8 +1383,8 @@ pub unsafe fn u16x8_gt(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i16x8.le_s))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i16x8_le(a: v128, b: v128) -> v128 {
-    transmute(simd_le::<_, simd::i16x8>(a.as_i16x8(), b.as_i16x8()))
+pub fn i16x8_le(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_le::<_, simd::i16x8>(a.as_i16x8(), b.as_i16x8())) }
 }
 
 /// Compares two 128-bit vectors as if they were two vectors of 8 sixteen-bit
@@ -1402,8 +1395,8 @@ pub unsafe fn i16x8_le(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i16x8.le_u))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn u16x8_le(a: v128, b: v128) -> v128 {
-    transmute(simd_le::<_, simd::i16x8>(a.as_u16x8(), b.as_u16x8()))
+pub fn u16x8_le(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_le::<_, simd::i16x8>(a.as_u16x8(), b.as_u16x8())) }
 }
 
 /// Compares two 128-bit vectors as if they were two vectors of 8 sixteen-bit
@@ -1414,8 +1407,8 @@ pub unsafe fn u16x8_le(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i16x8.ge_s))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i16x8_ge(a: v128, b: v128) -> v128 {
-    transmute(simd_ge::<_, simd::i16x8>(a.as_i16x8(), b.as_i16x8()))
+pub fn i16x8_ge(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_ge::<_, simd::i16x8>(a.as_i16x8(), b.as_i16x8())) }
 }
 
 /// Compares two 128-bit vectors as if they were two vectors of 8 sixteen-bit
@@ -1426,8 +1419,8 @@ pub unsafe fn i16x8_ge(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i16x8.ge_u))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn u16x8_ge(a: v128, b: v128) -> v128 {
-    transmute(simd_ge::<_, simd::i16x8>(a.as_u16x8(), b.as_u16x8()))
+pub fn u16x8_ge(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_ge::<_, simd::i16x8>(a.as_u16x8(), b.as_u16x8())) }
 }
 
 /// Compares two 128-bit vectors as if they were two vectors of 4 thirty-two-bit
@@ -1438,8 +1431,8 @@ pub unsafe fn u16x8_ge(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i32x4.eq))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i32x4_eq(a: v128, b: v128) -> v128 {
-    transmute(simd_eq::<_, simd::i32x4>(a.as_i32x4(), b.as_i32x4()))
+pub fn i32x4_eq(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_eq::<_, simd::i32x4>(a.as_i32x4(), b.as_i32x4())) }
 }
 
 /// Compares two 128-bit vectors as if they were two vectors of 4 thirty-two-bit
@@ -1450,8 +1443,8 @@ pub unsafe fn i32x4_eq(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i32x4.ne))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i32x4_ne(a: v128, b: v128) -> v128 {
-    transmute(simd_ne::<_, simd::i32x4>(a.as_i32x4(), b.as_i32x4()))
+pub fn i32x4_ne(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_ne::<_, simd::i32x4>(a.as_i32x4(), b.as_i32x4())) }
 }
 
 /// Compares two 128-bit vectors as if they were two vectors of 4 thirty-two-bit
@@ -1462,8 +1455,8 @@ pub unsafe fn i32x4_ne(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i32x4.lt_s))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i32x4_lt(a: v128, b: v128) -> v128 {
-    transmute(simd_lt::<_, simd::i32x4>(a.as_i32x4(), b.as_i32x4()))
+pub fn i32x4_lt(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_lt::<_, simd::i32x4>(a.as_i32x4(), b.as_i32x4())) }
 }
 
 /// Compares two 128-bit vectors as if they were two vectors of 4 thirty-two-bit
@@ -1474,8 +1467,8 @@ pub unsafe fn i32x4_lt(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i32x4.lt_u))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn u32x4_lt(a: v128, b: v128) -> v128 {
-    transmute(simd_lt::<_, simd::i32x4>(a.as_u32x4(), b.as_u32x4()))
+pub fn u32x4_lt(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_lt::<_, simd::i32x4>(a.as_u32x4(), b.as_u32x4())) }
 }
 
 /// Compares two 128-bit vectors as if they were two vectors of 4 thirty-two-bit
@@ -1486,8 +1479,8 @@ pub unsafe fn u32x4_lt(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i32x4.gt_s))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i32x4_gt(a: v128, b: v128) -> v128 {
-    transmute(simd_gt::<_, simd::i32x4>(a.as_i32x4(), b.as_i32x4()))
+pub fn i32x4_gt(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_gt::<_, simd::i32x4>(a.as_i32x4(), b.as_i32x4())) }
 }
 
 /// Compares two 128-bit vectors as if they were two vectors of 4 thirty-two-bit
@@ -1498,8 +1491,8 @@ pub unsafe fn i32x4_gt(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i32x4.gt_u))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn u32x4_gt(a: v128, b: v128) -> v128 {
-    transmute(simd_gt::<_, simd::i32x4>(a.as_u32x4(), b.as_u32x4()))
+pub fn u32x4_gt(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_gt::<_, simd::i32x4>(a.as_u32x4(), b.as_u32x4())) }
 }
 
 /// Compares two 128-bit vectors as if they were two vectors of 4 thirty-two-bit
@@ -1510,8 +1503,8 @@ pub unsafe fn u32x4_gt(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i32x4.le_s))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i32x4_le(a: v128, b: v128) -> v128 {
-    transmute(simd_le::<_, simd::i32x4>(a.as_i32x4(), b.as_i32x4()))
+pub fn i32x4_le(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_le::<_, simd::i32x4>(a.as_i32x4(), b.as_i32x4())) }
 }
 
 /// Compares two 128-bit vectors as if they were two vectors of 4 thirty-two-bit
@@ -1522,8 +1515,8 @@ pub unsafe fn i32x4_le(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i32x4.le_u))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn u32x4_le(a: v128, b: v128) -> v128 {
-    transmute(simd_le::<_, simd::i32x4>(a.as_u32x4(), b.as_u32x4()))
+pub fn u32x4_le(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_le::<_, simd::i32x4>(a.as_u32x4(), b.as_u32x4())) }
 }
 
 /// Compares two 128-bit vectors as if they were two vectors of 4 thirty-two-bit
@@ -1534,8 +1527,8 @@ pub unsafe fn u32x4_le(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i32x4.ge_s))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i32x4_ge(a: v128, b: v128) -> v128 {
-    transmute(simd_ge::<_, simd::i32x4>(a.as_i32x4(), b.as_i32x4()))
+pub fn i32x4_ge(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_ge::<_, simd::i32x4>(a.as_i32x4(), b.as_i32x4())) }
 }
 
 /// Compares two 128-bit vectors as if they were two vectors of 4 thirty-two-bit
@@ -1546,8 +1539,8 @@ pub unsafe fn i32x4_ge(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i32x4.ge_u))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn u32x4_ge(a: v128, b: v128) -> v128 {
-    transmute(simd_ge::<_, simd::i32x4>(a.as_u32x4(), b.as_u32x4()))
+pub fn u32x4_ge(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_ge::<_, simd::i32x4>(a.as_u32x4(), b.as_u32x4())) }
 }
 
 /// Compares two 128-bit vectors as if they were two vectors of 2 sixty-four-bit
@@ -1558,8 +1551,8 @@ pub unsafe fn u32x4_ge(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i64x2.eq))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i64x2_eq(a: v128, b: v128) -> v128 {
-    transmute(simd_eq::<_, simd::i64x2>(a.as_i64x2(), b.as_i64x2()))
+pub fn i64x2_eq(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_eq::<_, simd::i64x2>(a.as_i64x2(), b.as_i64x2())) }
 }
 
 /// Compares two 128-bit vectors as if they were two vectors of 2 sixty-four-bit
@@ -1570,8 +1563,8 @@ pub unsafe fn i64x2_eq(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i64x2.ne))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i64x2_ne(a: v128, b: v128) -> v128 {
-    transmute(simd_ne::<_, simd::i64x2>(a.as_i64x2(), b.as_i64x2()))
+pub fn i64x2_ne(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_ne::<_, simd::i64x2>(a.as_i64x2(), b.as_i64x2())) }
 }
 
 /// Compares two 128-bit vectors as if they were two vectors of 2 sixty-four-bit
@@ -1582,8 +1575,8 @@ pub unsafe fn i64x2_ne(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i64x2.lt_s))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i64x2_lt(a: v128, b: v128) -> v128 {
-    transmute(simd_lt::<_, simd::i64x2>(a.as_i64x2(), b.as_i64x2()))
+pub fn i64x2_lt(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_lt::<_, simd::i64x2>(a.as_i64x2(), b.as_i64x2())) }
 }
 
 /// Compares two 128-bit vectors as if they were two vectors of 2 sixty-four-bit
@@ -1594,8 +1587,8 @@ pub unsafe fn i64x2_lt(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i64x2.gt_s))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i64x2_gt(a: v128, b: v128) -> v128 {
-    transmute(simd_gt::<_, simd::i64x2>(a.as_i64x2(), b.as_i64x2()))
+pub fn i64x2_gt(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_gt::<_, simd::i64x2>(a.as_i64x2(), b.as_i64x2())) }
 }
 
 /// Compares two 128-bit vectors as if they were two vectors of 2 sixty-four-bit
@@ -1606,8 +1599,8 @@ pub unsafe fn i64x2_gt(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i64x2.le_s))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i64x2_le(a: v128, b: v128) -> v128 {
-    transmute(simd_le::<_, simd::i64x2>(a.as_i64x2(), b.as_i64x2()))
+pub fn i64x2_le(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_le::<_, simd::i64x2>(a.as_i64x2(), b.as_i64x2())) }
 }
 
 /// Compares two 128-bit vectors as if they were two vectors of 2 sixty-four-bit
@@ -1618,8 +1611,8 @@ pub unsafe fn i64x2_le(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i64x2.ge_s))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i64x2_ge(a: v128, b: v128) -> v128 {
-    transmute(simd_ge::<_, simd::i64x2>(a.as_i64x2(), b.as_i64x2()))
+pub fn i64x2_ge(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_ge::<_, simd::i64x2>(a.as_i64x2(), b.as_i64x2())) }
 }
 
 /// Compares two 128-bit vectors as if they were two vectors of 4 thirty-two-bit
@@ -1630,8 +1623,8 @@ pub unsafe fn i64x2_ge(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(f32x4.eq))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn f32x4_eq(a: v128, b: v128) -> v128 {
-    transmute(simd_eq::<_, simd::i32x4>(a.as_f32x4(), b.as_f32x4()))
+pub fn f32x4_eq(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_eq::<_, simd::i32x4>(a.as_f32x4(), b.as_f32x4())) }
 }
 
 /// Compares two 128-bit vectors as if they were two vectors of 4 thirty-two-bit
@@ -1642,8 +1635,8 @@ pub unsafe fn f32x4_eq(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(f32x4.ne))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn f32x4_ne(a: v128, b: v128) -> v128 {
-    transmute(simd_ne::<_, simd::i32x4>(a.as_f32x4(), b.as_f32x4()))
+pub fn f32x4_ne(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_ne::<_, simd::i32x4>(a.as_f32x4(), b.as_f32x4())) }
 }
 
 /// Compares two 128-bit vectors as if they were two vectors of 4 thirty-two-bit
@@ -1654,8 +1647,8 @@ pub unsafe fn f32x4_ne(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(f32x4.lt))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn f32x4_lt(a: v128, b: v128) -> v128 {
-    transmute(simd_lt::<_, simd::i32x4>(a.as_f32x4(), b.as_f32x4()))
+pub fn f32x4_lt(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_lt::<_, simd::i32x4>(a.as_f32x4(), b.as_f32x4())) }
 }
 
 /// Compares two 128-bit vectors as if they were two vectors of 4 thirty-two-bit
@@ -1666,8 +1659,8 @@ pub unsafe fn f32x4_lt(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(f32x4.gt))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn f32x4_gt(a: v128, b: v128) -> v128 {
-    transmute(simd_gt::<_, simd::i32x4>(a.as_f32x4(), b.as_f32x4()))
+pub fn f32x4_gt(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_gt::<_, simd::i32x4>(a.as_f32x4(), b.as_f32x4())) }
 }
 
 /// Compares two 128-bit vectors as if they were two vectors of 4 thirty-two-bit
@@ -1678,8 +1671,8 @@ pub unsafe fn f32x4_gt(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(f32x4.le))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn f32x4_le(a: v128, b: v128) -> v128 {
-    transmute(simd_le::<_, simd::i32x4>(a.as_f32x4(), b.as_f32x4()))
+pub fn f32x4_le(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_le::<_, simd::i32x4>(a.as_f32x4(), b.as_f32x4())) }
 }
 
 /// Compares two 128-bit vectors as if they were two vectors of 4 thirty-two-bit
@@ -1690,8 +1683,8 @@ pub unsafe fn f32x4_le(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(f32x4.ge))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn f32x4_ge(a: v128, b: v128) -> v128 {
-    transmute(simd_ge::<_, simd::i32x4>(a.as_f32x4(), b.as_f32x4()))
+pub fn f32x4_ge(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_ge::<_, simd::i32x4>(a.as_f32x4(), b.as_f32x4())) }
 }
 
 /// Compares two 128-bit vectors as if they were two vectors of 2 sixty-four-bit
@@ -1702,8 +1695,8 @@ pub unsafe fn f32x4_ge(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(f64x2.eq))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn f64x2_eq(a: v128, b: v128) -> v128 {
-    transmute(simd_eq::<_, simd::i64x2>(a.as_f64x2(), b.as_f64x2()))
+pub fn f64x2_eq(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_eq::<_, simd::i64x2>(a.as_f64x2(), b.as_f64x2())) }
 }
 
 /// Compares two 128-bit vectors as if they were two vectors of 2 sixty-four-bit
@@ -1714,8 +1707,8 @@ pub unsafe fn f64x2_eq(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(f64x2.ne))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn f64x2_ne(a: v128, b: v128) -> v128 {
-    transmute(simd_ne::<_, simd::i64x2>(a.as_f64x2(), b.as_f64x2()))
+pub fn f64x2_ne(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_ne::<_, simd::i64x2>(a.as_f64x2(), b.as_f64x2())) }
 }
 
 /// Compares two 128-bit vectors as if they were two vectors of 2 sixty-four-bit
@@ -1726,8 +1719,8 @@ pub unsafe fn f64x2_ne(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(f64x2.lt))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn f64x2_lt(a: v128, b: v128) -> v128 {
-    transmute(simd_lt::<_, simd::i64x2>(a.as_f64x2(), b.as_f64x2()))
+pub fn f64x2_lt(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_lt::<_, simd::i64x2>(a.as_f64x2(), b.as_f64x2())) }
 }
 
 /// Compares two 128-bit vectors as if they were two vectors of 2 sixty-four-bit
@@ -1738,8 +1731,8 @@ pub unsafe fn f64x2_lt(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(f64x2.gt))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn f64x2_gt(a: v128, b: v128) -> v128 {
-    transmute(simd_gt::<_, simd::i64x2>(a.as_f64x2(), b.as_f64x2()))
+pub fn f64x2_gt(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_gt::<_, simd::i64x2>(a.as_f64x2(), b.as_f64x2())) }
 }
 
 /// Compares two 128-bit vectors as if they were two vectors of 2 sixty-four-bit
@@ -1750,8 +1743,8 @@ pub unsafe fn f64x2_gt(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(f64x2.le))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn f64x2_le(a: v128, b: v128) -> v128 {
-    transmute(simd_le::<_, simd::i64x2>(a.as_f64x2(), b.as_f64x2()))
+pub fn f64x2_le(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_le::<_, simd::i64x2>(a.as_f64x2(), b.as_f64x2())) }
 }
 
 /// Compares two 128-bit vectors as if they were two vectors of 2 sixty-four-bit
@@ -1762,16 +1755,16 @@ pub unsafe fn f64x2_le(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(f64x2.ge))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn f64x2_ge(a: v128, b: v128) -> v128 {
-    transmute(simd_ge::<_, simd::i64x2>(a.as_f64x2(), b.as_f64x2()))
+pub fn f64x2_ge(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_ge::<_, simd::i64x2>(a.as_f64x2(), b.as_f64x2())) }
 }
 
 /// Flips each bit of the 128-bit input vector.
 #[inline]
 #[cfg_attr(test, assert_instr(v128.not))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn v128_not(a: v128) -> v128 {
-    transmute(simd_xor(a.as_i64x2(), simd::i64x2(!0, !0)))
+pub fn v128_not(a: v128) -> v128 {
+    unsafe { transmute(simd_xor(a.as_i64x2(), simd::i64x2(!0, !0))) }
 }
 
 /// Performs a bitwise and of the two input 128-bit vectors, returning the
@@ -1779,8 +1772,8 @@ pub unsafe fn v128_not(a: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(v128.and))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn v128_and(a: v128, b: v128) -> v128 {
-    transmute(simd_and(a.as_i64x2(), b.as_i64x2()))
+pub fn v128_and(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_and(a.as_i64x2(), b.as_i64x2())) }
 }
 
 /// Bitwise AND of bits of `a` and the logical inverse of bits of `b`.
@@ -1789,11 +1782,13 @@ pub unsafe fn v128_and(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(v128.andnot))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn v128_andnot(a: v128, b: v128) -> v128 {
-    transmute(simd_and(
-        a.as_i64x2(),
-        simd_xor(b.as_i64x2(), simd::i64x2(-1, -1)),
-    ))
+pub fn v128_andnot(a: v128, b: v128) -> v128 {
+    unsafe {
+        transmute(simd_and(
+            a.as_i64x2(),
+            simd_xor(b.as_i64x2(), simd::i64x2(-1, -1)),
+        ))
+    }
 }
 
 /// Performs a bitwise or of the two input 128-bit vectors, returning the
@@ -1801,8 +1796,8 @@ pub unsafe fn v128_andnot(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(v128.or))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn v128_or(a: v128, b: v128) -> v128 {
-    transmute(simd_or(a.as_i64x2(), b.as_i64x2()))
+pub fn v128_or(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_or(a.as_i64x2(), b.as_i64x2())) }
 }
 
 /// Performs a bitwise xor of the two input 128-bit vectors, returning the
@@ -1810,62 +1805,64 @@ pub unsafe fn v128_or(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(v128.xor))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn v128_xor(a: v128, b: v128) -> v128 {
-    transmute(simd_xor(a.as_i64x2(), b.as_i64x2()))
+pub fn v128_xor(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_xor(a.as_i64x2(), b.as_i64x2())) }
 }
 
 /// Use the bitmask in `c` to select bits from `v1` when 1 and `v2` when 0.
 #[inline]
 #[cfg_attr(test, assert_instr(v128.bitselect))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn v128_bitselect(v1: v128, v2: v128, c: v128) -> v128 {
-    transmute(llvm_bitselect(v1.as_i8x16(), v2.as_i8x16(), c.as_i8x16()))
+pub fn v128_bitselect(v1: v128, v2: v128, c: v128) -> v128 {
+    unsafe { transmute(llvm_bitselect(v1.as_i8x16(), v2.as_i8x16(), c.as_i8x16())) }
 }
 
 /// Returns `true` if any bit in `a` is set, or `false` otherwise.
 #[inline]
 #[cfg_attr(test, assert_instr(v128.any_true))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn v128_any_true(a: v128) -> bool {
-    llvm_any_true_i8x16(a.as_i8x16()) != 0
+pub fn v128_any_true(a: v128) -> bool {
+    unsafe { llvm_any_true_i8x16(a.as_i8x16()) != 0 }
 }
 
 /// Lane-wise wrapping absolute value.
 #[inline]
 #[cfg_attr(test, assert_instr(i8x16.abs))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i8x16_abs(a: v128) -> v128 {
-    let a = transmute::<_, simd::i8x16>(a);
-    let zero = simd::i8x16::splat(0);
-    transmute(simd_select::<simd::m8x16, simd::i8x16>(
-        simd_lt(a, zero),
-        simd_sub(zero, a),
-        a,
-    ))
+pub fn i8x16_abs(a: v128) -> v128 {
+    unsafe {
+        let a = transmute::<_, simd::i8x16>(a);
+        let zero = simd::i8x16::splat(0);
+        transmute(simd_select::<simd::m8x16, simd::i8x16>(
+            simd_lt(a, zero),
+            simd_sub(zero, a),
+            a,
+        ))
+    }
 }
 
 /// Negates a 128-bit vectors intepreted as sixteen 8-bit signed integers
 #[inline]
 #[cfg_attr(test, assert_instr(i8x16.neg))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i8x16_neg(a: v128) -> v128 {
-    transmute(simd_mul(a.as_i8x16(), simd::i8x16::splat(-1)))
+pub fn i8x16_neg(a: v128) -> v128 {
+    unsafe { transmute(simd_mul(a.as_i8x16(), simd::i8x16::splat(-1))) }
 }
 
 /// Count the number of bits set to one within each lane.
 #[inline]
 // #[cfg_attr(test, assert_instr(i8x16.popcnt))] // FIXME wasmtime
 #[target_feature(enable = "simd128")]
-pub unsafe fn i8x16_popcnt(v: v128) -> v128 {
-    transmute(llvm_popcnt(v.as_i8x16()))
+pub fn i8x16_popcnt(v: v128) -> v128 {
+    unsafe { transmute(llvm_popcnt(v.as_i8x16())) }
 }
 
 /// Returns true if all lanes are nonzero or false if any lane is nonzero.
 #[inline]
 #[cfg_attr(test, assert_instr(i8x16.all_true))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i8x16_all_true(a: v128) -> bool {
-    llvm_i8x16_all_true(a.as_i8x16()) != 0
+pub fn i8x16_all_true(a: v128) -> bool {
+    unsafe { llvm_i8x16_all_true(a.as_i8x16()) != 0 }
 }
 
 /// Extracts the high bit for each lane in `a` and produce a scalar mask with
@@ -1873,8 +1870,8 @@ pub unsafe fn i8x16_all_true(a: v128) -> bool {
 #[inline]
 #[cfg_attr(test, assert_instr(i8x16.bitmask))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i8x16_bitmask(a: v128) -> i32 {
-    llvm_bitmask_i8x16(transmute(a))
+pub fn i8x16_bitmask(a: v128) -> i32 {
+    unsafe { llvm_bitmask_i8x16(transmute(a)) }
 }
 
 /// Converts two input vectors into a smaller lane vector by narrowing each
@@ -1885,8 +1882,8 @@ pub unsafe fn i8x16_bitmask(a: v128) -> i32 {
 #[inline]
 #[cfg_attr(test, assert_instr(i8x16.narrow_i16x8_s))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i8x16_narrow_i16x8(a: v128, b: v128) -> v128 {
-    transmute(llvm_narrow_i8x16_s(transmute(a), transmute(b)))
+pub fn i8x16_narrow_i16x8(a: v128, b: v128) -> v128 {
+    unsafe { transmute(llvm_narrow_i8x16_s(transmute(a), transmute(b))) }
 }
 
 /// Converts two input vectors into a smaller lane vector by narrowing each
@@ -1897,8 +1894,8 @@ pub unsafe fn i8x16_narrow_i16x8(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i8x16.narrow_i16x8_u))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn u8x16_narrow_i16x8(a: v128, b: v128) -> v128 {
-    transmute(llvm_narrow_i8x16_u(transmute(a), transmute(b)))
+pub fn u8x16_narrow_i16x8(a: v128, b: v128) -> v128 {
+    unsafe { transmute(llvm_narrow_i8x16_u(transmute(a), transmute(b))) }
 }
 
 /// Shifts each lane to the left by the specified number of bits.
@@ -1908,8 +1905,8 @@ pub unsafe fn u8x16_narrow_i16x8(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i8x16.shl))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i8x16_shl(a: v128, amt: u32) -> v128 {
-    transmute(simd_shl(a.as_i8x16(), simd::i8x16::splat(amt as i8)))
+pub fn i8x16_shl(a: v128, amt: u32) -> v128 {
+    unsafe { transmute(simd_shl(a.as_i8x16(), simd::i8x16::splat(amt as i8))) }
 }
 
 /// Shifts each lane to the right by the specified number of bits, sign
@@ -1920,8 +1917,8 @@ pub unsafe fn i8x16_shl(a: v128, amt: u32) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i8x16.shr_s))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i8x16_shr(a: v128, amt: u32) -> v128 {
-    transmute(simd_shr(a.as_i8x16(), simd::i8x16::splat(amt as i8)))
+pub fn i8x16_shr(a: v128, amt: u32) -> v128 {
+    unsafe { transmute(simd_shr(a.as_i8x16(), simd::i8x16::splat(amt as i8))) }
 }
 
 /// Shifts each lane to the right by the specified number of bits, shifting in
@@ -1932,16 +1929,16 @@ pub unsafe fn i8x16_shr(a: v128, amt: u32) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i8x16.shr_u))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn u8x16_shr(a: v128, amt: u32) -> v128 {
-    transmute(simd_shr(a.as_u8x16(), simd::u8x16::splat(amt as u8)))
+pub fn u8x16_shr(a: v128, amt: u32) -> v128 {
+    unsafe { transmute(simd_shr(a.as_u8x16(), simd::u8x16::splat(amt as u8))) }
 }
 
 /// Adds two 128-bit vectors as if they were two packed sixteen 8-bit integers.
 #[inline]
 #[cfg_attr(test, assert_instr(i8x16.add))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i8x16_add(a: v128, b: v128) -> v128 {
-    transmute(simd_add(a.as_i8x16(), b.as_i8x16()))
+pub fn i8x16_add(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_add(a.as_i8x16(), b.as_i8x16())) }
 }
 
 /// Adds two 128-bit vectors as if they were two packed sixteen 8-bit signed
@@ -1949,8 +1946,8 @@ pub unsafe fn i8x16_add(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i8x16.add_sat_s))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i8x16_add_sat(a: v128, b: v128) -> v128 {
-    transmute(llvm_i8x16_add_sat_s(a.as_i8x16(), b.as_i8x16()))
+pub fn i8x16_add_sat(a: v128, b: v128) -> v128 {
+    unsafe { transmute(llvm_i8x16_add_sat_s(a.as_i8x16(), b.as_i8x16())) }
 }
 
 /// Adds two 128-bit vectors as if they were two packed sixteen 8-bit unsigned
@@ -1958,16 +1955,16 @@ pub unsafe fn i8x16_add_sat(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i8x16.add_sat_u))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn u8x16_add_sat(a: v128, b: v128) -> v128 {
-    transmute(llvm_i8x16_add_sat_u(a.as_i8x16(), b.as_i8x16()))
+pub fn u8x16_add_sat(a: v128, b: v128) -> v128 {
+    unsafe { transmute(llvm_i8x16_add_sat_u(a.as_i8x16(), b.as_i8x16())) }
 }
 
 /// Subtracts two 128-bit vectors as if they were two packed sixteen 8-bit integers.
 #[inline]
 #[cfg_attr(test, assert_instr(i8x16.sub))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i8x16_sub(a: v128, b: v128) -> v128 {
-    transmute(simd_sub(a.as_i8x16(), b.as_i8x16()))
+pub fn i8x16_sub(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_sub(a.as_i8x16(), b.as_i8x16())) }
 }
 
 /// Subtracts two 128-bit vectors as if they were two packed sixteen 8-bit
@@ -1975,8 +1972,8 @@ pub unsafe fn i8x16_sub(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i8x16.sub_sat_s))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i8x16_sub_sat(a: v128, b: v128) -> v128 {
-    transmute(llvm_i8x16_sub_sat_s(a.as_i8x16(), b.as_i8x16()))
+pub fn i8x16_sub_sat(a: v128, b: v128) -> v128 {
+    unsafe { transmute(llvm_i8x16_sub_sat_s(a.as_i8x16(), b.as_i8x16())) }
 }
 
 /// Subtracts two 128-bit vectors as if they were two packed sixteen 8-bit
@@ -1984,8 +1981,8 @@ pub unsafe fn i8x16_sub_sat(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i8x16.sub_sat_u))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn u8x16_sub_sat(a: v128, b: v128) -> v128 {
-    transmute(llvm_i8x16_sub_sat_u(a.as_i8x16(), b.as_i8x16()))
+pub fn u8x16_sub_sat(a: v128, b: v128) -> v128 {
+    unsafe { transmute(llvm_i8x16_sub_sat_u(a.as_i8x16(), b.as_i8x16())) }
 }
 
 /// Compares lane-wise signed integers, and returns the minimum of
@@ -1993,10 +1990,12 @@ pub unsafe fn u8x16_sub_sat(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i8x16.min_s))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i8x16_min(a: v128, b: v128) -> v128 {
-    let a = a.as_i8x16();
-    let b = b.as_i8x16();
-    transmute(simd_select::<simd::i8x16, _>(simd_lt(a, b), a, b))
+pub fn i8x16_min(a: v128, b: v128) -> v128 {
+    unsafe {
+        let a = a.as_i8x16();
+        let b = b.as_i8x16();
+        transmute(simd_select::<simd::i8x16, _>(simd_lt(a, b), a, b))
+    }
 }
 
 /// Compares lane-wise unsigned integers, and returns the minimum of
@@ -2004,10 +2003,12 @@ pub unsafe fn i8x16_min(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i8x16.min_u))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn u8x16_min(a: v128, b: v128) -> v128 {
-    let a = transmute::<_, simd::u8x16>(a);
-    let b = transmute::<_, simd::u8x16>(b);
-    transmute(simd_select::<simd::i8x16, _>(simd_lt(a, b), a, b))
+pub fn u8x16_min(a: v128, b: v128) -> v128 {
+    unsafe {
+        let a = transmute::<_, simd::u8x16>(a);
+        let b = transmute::<_, simd::u8x16>(b);
+        transmute(simd_select::<simd::i8x16, _>(simd_lt(a, b), a, b))
+    }
 }
 
 /// Compares lane-wise signed integers, and returns the maximum of
@@ -2015,10 +2016,12 @@ pub unsafe fn u8x16_min(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i8x16.max_s))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i8x16_max(a: v128, b: v128) -> v128 {
-    let a = transmute::<_, simd::i8x16>(a);
-    let b = transmute::<_, simd::i8x16>(b);
-    transmute(simd_select::<simd::i8x16, _>(simd_gt(a, b), a, b))
+pub fn i8x16_max(a: v128, b: v128) -> v128 {
+    unsafe {
+        let a = transmute::<_, simd::i8x16>(a);
+        let b = transmute::<_, simd::i8x16>(b);
+        transmute(simd_select::<simd::i8x16, _>(simd_gt(a, b), a, b))
+    }
 }
 
 /// Compares lane-wise unsigned integers, and returns the maximum of
@@ -2026,18 +2029,20 @@ pub unsafe fn i8x16_max(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i8x16.max_u))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn u8x16_max(a: v128, b: v128) -> v128 {
-    let a = transmute::<_, simd::u8x16>(a);
-    let b = transmute::<_, simd::u8x16>(b);
-    transmute(simd_select::<simd::i8x16, _>(simd_gt(a, b), a, b))
+pub fn u8x16_max(a: v128, b: v128) -> v128 {
+    unsafe {
+        let a = transmute::<_, simd::u8x16>(a);
+        let b = transmute::<_, simd::u8x16>(b);
+        transmute(simd_select::<simd::i8x16, _>(simd_gt(a, b), a, b))
+    }
 }
 
 /// Lane-wise rounding average.
 #[inline]
 #[cfg_attr(test, assert_instr(i8x16.avgr_u))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn u8x16_avgr(a: v128, b: v128) -> v128 {
-    transmute(llvm_avgr_u_i8x16(transmute(a), transmute(b)))
+pub fn u8x16_avgr(a: v128, b: v128) -> v128 {
+    unsafe { transmute(llvm_avgr_u_i8x16(transmute(a), transmute(b))) }
 }
 
 /// Lane-wise integer extended pairwise addition producing extended results
@@ -2045,8 +2050,8 @@ pub unsafe fn u8x16_avgr(a: v128, b: v128) -> v128 {
 #[inline]
 // #[cfg_attr(test, assert_instr(i16x8.extadd_pairwise_i8x16_s))] // FIXME wasmtime
 #[target_feature(enable = "simd128")]
-pub unsafe fn i16x8_extadd_pairwise_i8x16(a: v128) -> v128 {
-    transmute(llvm_i16x8_extadd_pairwise_i8x16_s(a.as_i8x16()))
+pub fn i16x8_extadd_pairwise_i8x16(a: v128) -> v128 {
+    unsafe { transmute(llvm_i16x8_extadd_pairwise_i8x16_s(a.as_i8x16())) }
 }
 
 /// Lane-wise integer extended pairwise addition producing extended results
@@ -2054,46 +2059,48 @@ pub unsafe fn i16x8_extadd_pairwise_i8x16(a: v128) -> v128 {
 #[inline]
 // #[cfg_attr(test, assert_instr(i16x8.extadd_pairwise_i8x16_u))] // FIXME wasmtime
 #[target_feature(enable = "simd128")]
-pub unsafe fn i16x8_extadd_pairwise_u8x16(a: v128) -> v128 {
-    transmute(llvm_i16x8_extadd_pairwise_i8x16_u(a.as_i8x16()))
+pub fn i16x8_extadd_pairwise_u8x16(a: v128) -> v128 {
+    unsafe { transmute(llvm_i16x8_extadd_pairwise_i8x16_u(a.as_i8x16())) }
 }
 
 /// Lane-wise wrapping absolute value.
 #[inline]
 #[cfg_attr(test, assert_instr(i16x8.abs))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i16x8_abs(a: v128) -> v128 {
-    let a = transmute::<_, simd::i16x8>(a);
-    let zero = simd::i16x8::splat(0);
-    transmute(simd_select::<simd::m16x8, simd::i16x8>(
-        simd_lt(a, zero),
-        simd_sub(zero, a),
-        a,
-    ))
+pub fn i16x8_abs(a: v128) -> v128 {
+    unsafe {
+        let a = transmute::<_, simd::i16x8>(a);
+        let zero = simd::i16x8::splat(0);
+        transmute(simd_select::<simd::m16x8, simd::i16x8>(
+            simd_lt(a, zero),
+            simd_sub(zero, a),
+            a,
+        ))
+    }
 }
 
 /// Negates a 128-bit vectors intepreted as eight 16-bit signed integers
 #[inline]
 #[cfg_attr(test, assert_instr(i16x8.neg))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i16x8_neg(a: v128) -> v128 {
-    transmute(simd_mul(a.as_i16x8(), simd::i16x8::splat(-1)))
+pub fn i16x8_neg(a: v128) -> v128 {
+    unsafe { transmute(simd_mul(a.as_i16x8(), simd::i16x8::splat(-1))) }
 }
 
 /// Lane-wise saturating rounding multiplication in Q15 format.
 #[inline]
 // #[cfg_attr(test, assert_instr(i16x8.qmulr_sat_s))] // FIXME wasmtime
 #[target_feature(enable = "simd128")]
-pub unsafe fn i16x8_q15mulr_sat(a: v128, b: v128) -> v128 {
-    transmute(llvm_q15mulr(a.as_i16x8(), b.as_i16x8()))
+pub fn i16x8_q15mulr_sat(a: v128, b: v128) -> v128 {
+    unsafe { transmute(llvm_q15mulr(a.as_i16x8(), b.as_i16x8())) }
 }
 
 /// Returns 1 if all lanes are nonzero or 0 if any lane is nonzero.
 #[inline]
 #[cfg_attr(test, assert_instr(i16x8.all_true))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i16x8_all_true(a: v128) -> bool {
-    llvm_i16x8_all_true(a.as_i16x8()) != 0
+pub fn i16x8_all_true(a: v128) -> bool {
+    unsafe { llvm_i16x8_all_true(a.as_i16x8()) != 0 }
 }
 
 /// Extracts the high bit for each lane in `a` and produce a scalar mask with
@@ -2101,8 +2108,8 @@ pub unsafe fn i16x8_all_true(a: v128) -> bool {
 #[inline]
 #[cfg_attr(test, assert_instr(i16x8.bitmask))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i16x8_bitmask(a: v128) -> i32 {
-    llvm_bitmask_i16x8(transmute(a))
+pub fn i16x8_bitmask(a: v128) -> i32 {
+    unsafe { llvm_bitmask_i16x8(transmute(a)) }
 }
 
 /// Converts two input vectors into a smaller lane vector by narrowing each
@@ -2113,8 +2120,8 @@ pub unsafe fn i16x8_bitmask(a: v128) -> i32 {
 #[inline]
 #[cfg_attr(test, assert_instr(i16x8.narrow_i32x4_s))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i16x8_narrow_i32x4(a: v128, b: v128) -> v128 {
-    transmute(llvm_narrow_i16x8_s(transmute(a), transmute(b)))
+pub fn i16x8_narrow_i32x4(a: v128, b: v128) -> v128 {
+    unsafe { transmute(llvm_narrow_i16x8_s(transmute(a), transmute(b))) }
 }
 
 /// Converts two input vectors into a smaller lane vector by narrowing each
@@ -2125,8 +2132,8 @@ pub unsafe fn i16x8_narrow_i32x4(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i16x8.narrow_i32x4_u))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn u16x8_narrow_i32x4(a: v128, b: v128) -> v128 {
-    transmute(llvm_narrow_i16x8_u(transmute(a), transmute(b)))
+pub fn u16x8_narrow_i32x4(a: v128, b: v128) -> v128 {
+    unsafe { transmute(llvm_narrow_i16x8_u(transmute(a), transmute(b))) }
 }
 
 /// Converts low half of the smaller lane vector to a larger lane
@@ -2134,12 +2141,14 @@ pub unsafe fn u16x8_narrow_i32x4(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i16x8.extend_low_i8x16_s))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i16x8_extend_low_i8x16(a: v128) -> v128 {
-    transmute(simd_cast::<_, simd::i16x8>(simd_shuffle8::<_, simd::i8x8>(
-        a.as_i8x16(),
-        a.as_i8x16(),
-        [0, 1, 2, 3, 4, 5, 6, 7],
-    )))
+pub fn i16x8_extend_low_i8x16(a: v128) -> v128 {
+    unsafe {
+        transmute(simd_cast::<_, simd::i16x8>(simd_shuffle8::<_, simd::i8x8>(
+            a.as_i8x16(),
+            a.as_i8x16(),
+            [0, 1, 2, 3, 4, 5, 6, 7],
+        )))
+    }
 }
 
 /// Converts high half of the smaller lane vector to a larger lane
@@ -2147,12 +2156,14 @@ pub unsafe fn i16x8_extend_low_i8x16(a: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i16x8.extend_high_i8x16_s))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i16x8_extend_high_i8x16(a: v128) -> v128 {
-    transmute(simd_cast::<_, simd::i16x8>(simd_shuffle8::<_, simd::i8x8>(
-        a.as_i8x16(),
-        a.as_i8x16(),
-        [8, 9, 10, 11, 12, 13, 14, 15],
-    )))
+pub fn i16x8_extend_high_i8x16(a: v128) -> v128 {
+    unsafe {
+        transmute(simd_cast::<_, simd::i16x8>(simd_shuffle8::<_, simd::i8x8>(
+            a.as_i8x16(),
+            a.as_i8x16(),
+            [8, 9, 10, 11, 12, 13, 14, 15],
+        )))
+    }
 }
 
 /// Converts low half of the smaller lane vector to a larger lane
@@ -2160,12 +2171,14 @@ pub unsafe fn i16x8_extend_high_i8x16(a: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i16x8.extend_low_i8x16_u))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i16x8_extend_low_u8x16(a: v128) -> v128 {
-    transmute(simd_cast::<_, simd::u16x8>(simd_shuffle8::<_, simd::u8x8>(
-        a.as_u8x16(),
-        a.as_u8x16(),
-        [0, 1, 2, 3, 4, 5, 6, 7],
-    )))
+pub fn i16x8_extend_low_u8x16(a: v128) -> v128 {
+    unsafe {
+        transmute(simd_cast::<_, simd::u16x8>(simd_shuffle8::<_, simd::u8x8>(
+            a.as_u8x16(),
+            a.as_u8x16(),
+            [0, 1, 2, 3, 4, 5, 6, 7],
+        )))
+    }
 }
 
 /// Converts high half of the smaller lane vector to a larger lane
@@ -2173,12 +2186,14 @@ pub unsafe fn i16x8_extend_low_u8x16(a: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i16x8.extend_high_i8x16_u))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i16x8_extend_high_u8x16(a: v128) -> v128 {
-    transmute(simd_cast::<_, simd::u16x8>(simd_shuffle8::<_, simd::u8x8>(
-        a.as_u8x16(),
-        a.as_u8x16(),
-        [8, 9, 10, 11, 12, 13, 14, 15],
-    )))
+pub fn i16x8_extend_high_u8x16(a: v128) -> v128 {
+    unsafe {
+        transmute(simd_cast::<_, simd::u16x8>(simd_shuffle8::<_, simd::u8x8>(
+            a.as_u8x16(),
+            a.as_u8x16(),
+            [8, 9, 10, 11, 12, 13, 14, 15],
+        )))
+    }
 }
 
 /// Shifts each lane to the left by the specified number of bits.
@@ -2188,8 +2203,8 @@ pub unsafe fn i16x8_extend_high_u8x16(a: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i16x8.shl))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i16x8_shl(a: v128, amt: u32) -> v128 {
-    transmute(simd_shl(a.as_i16x8(), simd::i16x8::splat(amt as i16)))
+pub fn i16x8_shl(a: v128, amt: u32) -> v128 {
+    unsafe { transmute(simd_shl(a.as_i16x8(), simd::i16x8::splat(amt as i16))) }
 }
 
 /// Shifts each lane to the right by the specified number of bits, sign
@@ -2200,8 +2215,8 @@ pub unsafe fn i16x8_shl(a: v128, amt: u32) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i16x8.shr_s))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i16x8_shr(a: v128, amt: u32) -> v128 {
-    transmute(simd_shr(a.as_i16x8(), simd::i16x8::splat(amt as i16)))
+pub fn i16x8_shr(a: v128, amt: u32) -> v128 {
+    unsafe { transmute(simd_shr(a.as_i16x8(), simd::i16x8::splat(amt as i16))) }
 }
 
 /// Shifts each lane to the right by the specified number of bits, shifting in
@@ -2212,16 +2227,16 @@ pub unsafe fn i16x8_shr(a: v128, amt: u32) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i16x8.shr_u))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn u16x8_shr(a: v128, amt: u32) -> v128 {
-    transmute(simd_shr(a.as_u16x8(), simd::u16x8::splat(amt as u16)))
+pub fn u16x8_shr(a: v128, amt: u32) -> v128 {
+    unsafe { transmute(simd_shr(a.as_u16x8(), simd::u16x8::splat(amt as u16))) }
 }
 
 /// Adds two 128-bit vectors as if they were two packed eight 16-bit integers.
 #[inline]
 #[cfg_attr(test, assert_instr(i16x8.add))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i16x8_add(a: v128, b: v128) -> v128 {
-    transmute(simd_add(a.as_i16x8(), b.as_i16x8()))
+pub fn i16x8_add(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_add(a.as_i16x8(), b.as_i16x8())) }
 }
 
 /// Adds two 128-bit vectors as if they were two packed eight 16-bit signed
@@ -2229,8 +2244,8 @@ pub unsafe fn i16x8_add(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i16x8.add_sat_s))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i16x8_add_sat(a: v128, b: v128) -> v128 {
-    transmute(llvm_i16x8_add_sat_s(a.as_i16x8(), b.as_i16x8()))
+pub fn i16x8_add_sat(a: v128, b: v128) -> v128 {
+    unsafe { transmute(llvm_i16x8_add_sat_s(a.as_i16x8(), b.as_i16x8())) }
 }
 
 /// Adds two 128-bit vectors as if they were two packed eight 16-bit unsigned
@@ -2238,16 +2253,16 @@ pub unsafe fn i16x8_add_sat(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i16x8.add_sat_u))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn u16x8_add_sat(a: v128, b: v128) -> v128 {
-    transmute(llvm_i16x8_add_sat_u(a.as_i16x8(), b.as_i16x8()))
+pub fn u16x8_add_sat(a: v128, b: v128) -> v128 {
+    unsafe { transmute(llvm_i16x8_add_sat_u(a.as_i16x8(), b.as_i16x8())) }
 }
 
 /// Subtracts two 128-bit vectors as if they were two packed eight 16-bit integers.
 #[inline]
 #[cfg_attr(test, assert_instr(i16x8.sub))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i16x8_sub(a: v128, b: v128) -> v128 {
-    transmute(simd_sub(a.as_i16x8(), b.as_i16x8()))
+pub fn i16x8_sub(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_sub(a.as_i16x8(), b.as_i16x8())) }
 }
 
 /// Subtracts two 128-bit vectors as if they were two packed eight 16-bit
@@ -2255,8 +2270,8 @@ pub unsafe fn i16x8_sub(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i16x8.sub_sat_s))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i16x8_sub_sat(a: v128, b: v128) -> v128 {
-    transmute(llvm_i16x8_sub_sat_s(a.as_i16x8(), b.as_i16x8()))
+pub fn i16x8_sub_sat(a: v128, b: v128) -> v128 {
+    unsafe { transmute(llvm_i16x8_sub_sat_s(a.as_i16x8(), b.as_i16x8())) }
 }
 
 /// Subtracts two 128-bit vectors as if they were two packed eight 16-bit
@@ -2264,8 +2279,8 @@ pub unsafe fn i16x8_sub_sat(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i16x8.sub_sat_u))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn u16x8_sub_sat(a: v128, b: v128) -> v128 {
-    transmute(llvm_i16x8_sub_sat_u(a.as_i16x8(), b.as_i16x8()))
+pub fn u16x8_sub_sat(a: v128, b: v128) -> v128 {
+    unsafe { transmute(llvm_i16x8_sub_sat_u(a.as_i16x8(), b.as_i16x8())) }
 }
 
 /// Multiplies two 128-bit vectors as if they were two packed eight 16-bit
@@ -2273,8 +2288,8 @@ pub unsafe fn u16x8_sub_sat(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i16x8.mul))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i16x8_mul(a: v128, b: v128) -> v128 {
-    transmute(simd_mul(a.as_i16x8(), b.as_i16x8()))
+pub fn i16x8_mul(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_mul(a.as_i16x8(), b.as_i16x8())) }
 }
 
 /// Compares lane-wise signed integers, and returns the minimum of
@@ -2282,10 +2297,12 @@ pub unsafe fn i16x8_mul(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i16x8.min_s))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i16x8_min(a: v128, b: v128) -> v128 {
-    let a = transmute::<_, simd::i16x8>(a);
-    let b = transmute::<_, simd::i16x8>(b);
-    transmute(simd_select::<simd::i16x8, _>(simd_lt(a, b), a, b))
+pub fn i16x8_min(a: v128, b: v128) -> v128 {
+    unsafe {
+        let a = transmute::<_, simd::i16x8>(a);
+        let b = transmute::<_, simd::i16x8>(b);
+        transmute(simd_select::<simd::i16x8, _>(simd_lt(a, b), a, b))
+    }
 }
 
 /// Compares lane-wise unsigned integers, and returns the minimum of
@@ -2293,10 +2310,12 @@ pub unsafe fn i16x8_min(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i16x8.min_u))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn u16x8_min(a: v128, b: v128) -> v128 {
-    let a = transmute::<_, simd::u16x8>(a);
-    let b = transmute::<_, simd::u16x8>(b);
-    transmute(simd_select::<simd::i16x8, _>(simd_lt(a, b), a, b))
+pub fn u16x8_min(a: v128, b: v128) -> v128 {
+    unsafe {
+        let a = transmute::<_, simd::u16x8>(a);
+        let b = transmute::<_, simd::u16x8>(b);
+        transmute(simd_select::<simd::i16x8, _>(simd_lt(a, b), a, b))
+    }
 }
 
 /// Compares lane-wise signed integers, and returns the maximum of
@@ -2304,10 +2323,12 @@ pub unsafe fn u16x8_min(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i16x8.max_s))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i16x8_max(a: v128, b: v128) -> v128 {
-    let a = transmute::<_, simd::i16x8>(a);
-    let b = transmute::<_, simd::i16x8>(b);
-    transmute(simd_select::<simd::i16x8, _>(simd_gt(a, b), a, b))
+pub fn i16x8_max(a: v128, b: v128) -> v128 {
+    unsafe {
+        let a = transmute::<_, simd::i16x8>(a);
+        let b = transmute::<_, simd::i16x8>(b);
+        transmute(simd_select::<simd::i16x8, _>(simd_gt(a, b), a, b))
+    }
 }
 
 /// Compares lane-wise unsigned integers, and returns the maximum of
@@ -2315,18 +2336,20 @@ pub unsafe fn i16x8_max(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i16x8.max_u))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn u16x8_max(a: v128, b: v128) -> v128 {
-    let a = transmute::<_, simd::u16x8>(a);
-    let b = transmute::<_, simd::u16x8>(b);
-    transmute(simd_select::<simd::i16x8, _>(simd_gt(a, b), a, b))
+pub fn u16x8_max(a: v128, b: v128) -> v128 {
+    unsafe {
+        let a = transmute::<_, simd::u16x8>(a);
+        let b = transmute::<_, simd::u16x8>(b);
+        transmute(simd_select::<simd::i16x8, _>(simd_gt(a, b), a, b))
+    }
 }
 
 /// Lane-wise rounding average.
 #[inline]
 #[cfg_attr(test, assert_instr(i16x8.avgr_u))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn u16x8_avgr(a: v128, b: v128) -> v128 {
-    transmute(llvm_avgr_u_i16x8(transmute(a), transmute(b)))
+pub fn u16x8_avgr(a: v128, b: v128) -> v128 {
+    unsafe { transmute(llvm_avgr_u_i16x8(transmute(a), transmute(b))) }
 }
 
 /// Lane-wise integer extended multiplication producing twice wider result than
@@ -2336,8 +2359,8 @@ pub unsafe fn u16x8_avgr(a: v128, b: v128) -> v128 {
 #[inline]
 // #[cfg_attr(test, assert_instr(i16x8.extmul_low_i8x16_s))] // FIXME wasmtime
 #[target_feature(enable = "simd128")]
-pub unsafe fn i16x8_extmul_low_i8x16(a: v128, b: v128) -> v128 {
-    transmute(llvm_i16x8_extmul_low_i8x16_s(a.as_i8x16(), b.as_i8x16()))
+pub fn i16x8_extmul_low_i8x16(a: v128, b: v128) -> v128 {
+    unsafe { transmute(llvm_i16x8_extmul_low_i8x16_s(a.as_i8x16(), b.as_i8x16())) }
 }
 
 /// Lane-wise integer extended multiplication producing twice wider result than
@@ -2347,8 +2370,8 @@ pub unsafe fn i16x8_extmul_low_i8x16(a: v128, b: v128) -> v128 {
 #[inline]
 // #[cfg_attr(test, assert_instr(i16x8.extmul_high_i8x16_s))] // FIXME wasmtime
 #[target_feature(enable = "simd128")]
-pub unsafe fn i16x8_extmul_high_i8x16(a: v128, b: v128) -> v128 {
-    transmute(llvm_i16x8_extmul_high_i8x16_s(a.as_i8x16(), b.as_i8x16()))
+pub fn i16x8_extmul_high_i8x16(a: v128, b: v128) -> v128 {
+    unsafe { transmute(llvm_i16x8_extmul_high_i8x16_s(a.as_i8x16(), b.as_i8x16())) }
 }
 
 /// Lane-wise integer extended multiplication producing twice wider result than
@@ -2358,8 +2381,8 @@ pub unsafe fn i16x8_extmul_high_i8x16(a: v128, b: v128) -> v128 {
 #[inline]
 // #[cfg_attr(test, assert_instr(i16x8.extmul_low_i8x16_u))] // FIXME wasmtime
 #[target_feature(enable = "simd128")]
-pub unsafe fn i16x8_extmul_low_u8x16(a: v128, b: v128) -> v128 {
-    transmute(llvm_i16x8_extmul_low_i8x16_u(a.as_i8x16(), b.as_i8x16()))
+pub fn i16x8_extmul_low_u8x16(a: v128, b: v128) -> v128 {
+    unsafe { transmute(llvm_i16x8_extmul_low_i8x16_u(a.as_i8x16(), b.as_i8x16())) }
 }
 
 /// Lane-wise integer extended multiplication producing twice wider result than
@@ -2369,8 +2392,8 @@ pub unsafe fn i16x8_extmul_low_u8x16(a: v128, b: v128) -> v128 {
 #[inline]
 // #[cfg_attr(test, assert_instr(i16x8.extmul_high_i8x16_u))] // FIXME wasmtime
 #[target_feature(enable = "simd128")]
-pub unsafe fn i16x8_extmul_high_u8x16(a: v128, b: v128) -> v128 {
-    transmute(llvm_i16x8_extmul_high_i8x16_u(a.as_i8x16(), b.as_i8x16()))
+pub fn i16x8_extmul_high_u8x16(a: v128, b: v128) -> v128 {
+    unsafe { transmute(llvm_i16x8_extmul_high_i8x16_u(a.as_i8x16(), b.as_i8x16())) }
 }
 
 /// Lane-wise integer extended pairwise addition producing extended results
@@ -2378,8 +2401,8 @@ pub unsafe fn i16x8_extmul_high_u8x16(a: v128, b: v128) -> v128 {
 #[inline]
 // #[cfg_attr(test, assert_instr(i32x4.extadd_pairwise_i16x8_s))] // FIXME wasmtime
 #[target_feature(enable = "simd128")]
-pub unsafe fn i32x4_extadd_pairwise_i16x8(a: v128) -> v128 {
-    transmute(llvm_i32x4_extadd_pairwise_i16x8_s(a.as_i16x8()))
+pub fn i32x4_extadd_pairwise_i16x8(a: v128) -> v128 {
+    unsafe { transmute(llvm_i32x4_extadd_pairwise_i16x8_s(a.as_i16x8())) }
 }
 
 /// Lane-wise integer extended pairwise addition producing extended results
@@ -2387,38 +2410,40 @@ pub unsafe fn i32x4_extadd_pairwise_i16x8(a: v128) -> v128 {
 #[inline]
 // #[cfg_attr(test, assert_instr(i32x4.extadd_pairwise_i16x8_u))] // FIXME wasmtime
 #[target_feature(enable = "simd128")]
-pub unsafe fn i32x4_extadd_pairwise_u16x8(a: v128) -> v128 {
-    transmute(llvm_i32x4_extadd_pairwise_i16x8_u(a.as_i16x8()))
+pub fn i32x4_extadd_pairwise_u16x8(a: v128) -> v128 {
+    unsafe { transmute(llvm_i32x4_extadd_pairwise_i16x8_u(a.as_i16x8())) }
 }
 
 /// Lane-wise wrapping absolute value.
 #[inline]
 #[cfg_attr(test, assert_instr(i32x4.abs))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i32x4_abs(a: v128) -> v128 {
-    let a = transmute::<_, simd::i32x4>(a);
-    let zero = simd::i32x4::splat(0);
-    transmute(simd_select::<simd::m32x4, simd::i32x4>(
-        simd_lt(a, zero),
-        simd_sub(zero, a),
-        a,
-    ))
+pub fn i32x4_abs(a: v128) -> v128 {
+    unsafe {
+        let a = transmute::<_, simd::i32x4>(a);
+        let zero = simd::i32x4::splat(0);
+        transmute(simd_select::<simd::m32x4, simd::i32x4>(
+            simd_lt(a, zero),
+            simd_sub(zero, a),
+            a,
+        ))
+    }
 }
 
 /// Negates a 128-bit vectors intepreted as four 32-bit signed integers
 #[inline]
 #[cfg_attr(test, assert_instr(i32x4.neg))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i32x4_neg(a: v128) -> v128 {
-    transmute(simd_mul(a.as_i32x4(), simd::i32x4::splat(-1)))
+pub fn i32x4_neg(a: v128) -> v128 {
+    unsafe { transmute(simd_mul(a.as_i32x4(), simd::i32x4::splat(-1))) }
 }
 
 /// Returns 1 if all lanes are nonzero or 0 if any lane is nonzero.
 #[inline]
 #[cfg_attr(test, assert_instr(i32x4.all_true))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i32x4_all_true(a: v128) -> bool {
-    llvm_i32x4_all_true(a.as_i32x4()) != 0
+pub fn i32x4_all_true(a: v128) -> bool {
+    unsafe { llvm_i32x4_all_true(a.as_i32x4()) != 0 }
 }
 
 /// Extracts the high bit for each lane in `a` and produce a scalar mask with
@@ -2426,8 +2451,8 @@ pub unsafe fn i32x4_all_true(a: v128) -> bool {
 #[inline]
 #[cfg_attr(test, assert_instr(i32x4.bitmask))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i32x4_bitmask(a: v128) -> i32 {
-    llvm_bitmask_i32x4(transmute(a))
+pub fn i32x4_bitmask(a: v128) -> i32 {
+    unsafe { llvm_bitmask_i32x4(transmute(a)) }
 }
 
 /// Converts low half of the smaller lane vector to a larger lane
@@ -2435,10 +2460,12 @@ pub unsafe fn i32x4_bitmask(a: v128) -> i32 {
 #[inline]
 #[cfg_attr(test, assert_instr(i32x4.extend_low_i16x8_s))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i32x4_extend_low_i16x8(a: v128) -> v128 {
-    transmute(simd_cast::<_, simd::i32x4>(
-        simd_shuffle4::<_, simd::i16x4>(a.as_i16x8(), a.as_i16x8(), [0, 1, 2, 3]),
-    ))
+pub fn i32x4_extend_low_i16x8(a: v128) -> v128 {
+    unsafe {
+        transmute(simd_cast::<_, simd::i32x4>(
+            simd_shuffle4::<_, simd::i16x4>(a.as_i16x8(), a.as_i16x8(), [0, 1, 2, 3]),
+        ))
+    }
 }
 
 /// Converts high half of the smaller lane vector to a larger lane
@@ -2446,10 +2473,12 @@ pub unsafe fn i32x4_extend_low_i16x8(a: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i32x4.extend_high_i16x8_s))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i32x4_extend_high_i16x8(a: v128) -> v128 {
-    transmute(simd_cast::<_, simd::i32x4>(
-        simd_shuffle4::<_, simd::i16x4>(a.as_i16x8(), a.as_i16x8(), [4, 5, 6, 7]),
-    ))
+pub fn i32x4_extend_high_i16x8(a: v128) -> v128 {
+    unsafe {
+        transmute(simd_cast::<_, simd::i32x4>(
+            simd_shuffle4::<_, simd::i16x4>(a.as_i16x8(), a.as_i16x8(), [4, 5, 6, 7]),
+        ))
+    }
 }
 
 /// Converts low half of the smaller lane vector to a larger lane
@@ -2457,10 +2486,12 @@ pub unsafe fn i32x4_extend_high_i16x8(a: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i32x4.extend_low_i16x8_u))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i32x4_extend_low_u16x8(a: v128) -> v128 {
-    transmute(simd_cast::<_, simd::u32x4>(
-        simd_shuffle4::<_, simd::u16x4>(a.as_u16x8(), a.as_u16x8(), [0, 1, 2, 3]),
-    ))
+pub fn i32x4_extend_low_u16x8(a: v128) -> v128 {
+    unsafe {
+        transmute(simd_cast::<_, simd::u32x4>(
+            simd_shuffle4::<_, simd::u16x4>(a.as_u16x8(), a.as_u16x8(), [0, 1, 2, 3]),
+        ))
+    }
 }
 
 /// Converts high half of the smaller lane vector to a larger lane
@@ -2468,10 +2499,12 @@ pub unsafe fn i32x4_extend_low_u16x8(a: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i32x4.extend_high_i16x8_u))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i32x4_extend_high_u16x8(a: v128) -> v128 {
-    transmute(simd_cast::<_, simd::u32x4>(
-        simd_shuffle4::<_, simd::u16x4>(a.as_u16x8(), a.as_u16x8(), [4, 5, 6, 7]),
-    ))
+pub fn i32x4_extend_high_u16x8(a: v128) -> v128 {
+    unsafe {
+        transmute(simd_cast::<_, simd::u32x4>(
+            simd_shuffle4::<_, simd::u16x4>(a.as_u16x8(), a.as_u16x8(), [4, 5, 6, 7]),
+        ))
+    }
 }
 
 /// Shifts each lane to the left by the specified number of bits.
@@ -2481,8 +2514,8 @@ pub unsafe fn i32x4_extend_high_u16x8(a: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i32x4.shl))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i32x4_shl(a: v128, amt: u32) -> v128 {
-    transmute(simd_shl(a.as_i32x4(), simd::i32x4::splat(amt as i32)))
+pub fn i32x4_shl(a: v128, amt: u32) -> v128 {
+    unsafe { transmute(simd_shl(a.as_i32x4(), simd::i32x4::splat(amt as i32))) }
 }
 
 /// Shifts each lane to the right by the specified number of bits, sign
@@ -2493,8 +2526,8 @@ pub unsafe fn i32x4_shl(a: v128, amt: u32) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i32x4.shr_s))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i32x4_shr(a: v128, amt: u32) -> v128 {
-    transmute(simd_shr(a.as_i32x4(), simd::i32x4::splat(amt as i32)))
+pub fn i32x4_shr(a: v128, amt: u32) -> v128 {
+    unsafe { transmute(simd_shr(a.as_i32x4(), simd::i32x4::splat(amt as i32))) }
 }
 
 /// Shifts each lane to the right by the specified number of bits, shifting in
@@ -2505,24 +2538,24 @@ pub unsafe fn i32x4_shr(a: v128, amt: u32) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i32x4.shr_u))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn u32x4_shr(a: v128, amt: u32) -> v128 {
-    transmute(simd_shr(a.as_u32x4(), simd::u32x4::splat(amt as u32)))
+pub fn u32x4_shr(a: v128, amt: u32) -> v128 {
+    unsafe { transmute(simd_shr(a.as_u32x4(), simd::u32x4::splat(amt as u32))) }
 }
 
 /// Adds two 128-bit vectors as if they were two packed four 32-bit integers.
 #[inline]
 #[cfg_attr(test, assert_instr(i32x4.add))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i32x4_add(a: v128, b: v128) -> v128 {
-    transmute(simd_add(a.as_i32x4(), b.as_i32x4()))
+pub fn i32x4_add(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_add(a.as_i32x4(), b.as_i32x4())) }
 }
 
 /// Subtracts two 128-bit vectors as if they were two packed four 32-bit integers.
 #[inline]
 #[cfg_attr(test, assert_instr(i32x4.sub))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i32x4_sub(a: v128, b: v128) -> v128 {
-    transmute(simd_sub(a.as_i32x4(), b.as_i32x4()))
+pub fn i32x4_sub(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_sub(a.as_i32x4(), b.as_i32x4())) }
 }
 
 /// Multiplies two 128-bit vectors as if they were two packed four 32-bit
@@ -2530,8 +2563,8 @@ pub unsafe fn i32x4_sub(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i32x4.mul))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i32x4_mul(a: v128, b: v128) -> v128 {
-    transmute(simd_mul(a.as_i32x4(), b.as_i32x4()))
+pub fn i32x4_mul(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_mul(a.as_i32x4(), b.as_i32x4())) }
 }
 
 /// Compares lane-wise signed integers, and returns the minimum of
@@ -2539,10 +2572,12 @@ pub unsafe fn i32x4_mul(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i32x4.min_s))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i32x4_min(a: v128, b: v128) -> v128 {
-    let a = transmute::<_, simd::i32x4>(a);
-    let b = transmute::<_, simd::i32x4>(b);
-    transmute(simd_select::<simd::i32x4, _>(simd_lt(a, b), a, b))
+pub fn i32x4_min(a: v128, b: v128) -> v128 {
+    unsafe {
+        let a = transmute::<_, simd::i32x4>(a);
+        let b = transmute::<_, simd::i32x4>(b);
+        transmute(simd_select::<simd::i32x4, _>(simd_lt(a, b), a, b))
+    }
 }
 
 /// Compares lane-wise unsigned integers, and returns the minimum of
@@ -2550,10 +2585,12 @@ pub unsafe fn i32x4_min(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i32x4.min_u))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn u32x4_min(a: v128, b: v128) -> v128 {
-    let a = transmute::<_, simd::u32x4>(a);
-    let b = transmute::<_, simd::u32x4>(b);
-    transmute(simd_select::<simd::i32x4, _>(simd_lt(a, b), a, b))
+pub fn u32x4_min(a: v128, b: v128) -> v128 {
+    unsafe {
+        let a = transmute::<_, simd::u32x4>(a);
+        let b = transmute::<_, simd::u32x4>(b);
+        transmute(simd_select::<simd::i32x4, _>(simd_lt(a, b), a, b))
+    }
 }
 
 /// Compares lane-wise signed integers, and returns the maximum of
@@ -2561,10 +2598,12 @@ pub unsafe fn u32x4_min(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i32x4.max_s))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i32x4_max(a: v128, b: v128) -> v128 {
-    let a = transmute::<_, simd::i32x4>(a);
-    let b = transmute::<_, simd::i32x4>(b);
-    transmute(simd_select::<simd::i32x4, _>(simd_gt(a, b), a, b))
+pub fn i32x4_max(a: v128, b: v128) -> v128 {
+    unsafe {
+        let a = transmute::<_, simd::i32x4>(a);
+        let b = transmute::<_, simd::i32x4>(b);
+        transmute(simd_select::<simd::i32x4, _>(simd_gt(a, b), a, b))
+    }
 }
 
 /// Compares lane-wise unsigned integers, and returns the maximum of
@@ -2572,10 +2611,12 @@ pub unsafe fn i32x4_max(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i32x4.max_u))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn u32x4_max(a: v128, b: v128) -> v128 {
-    let a = transmute::<_, simd::u32x4>(a);
-    let b = transmute::<_, simd::u32x4>(b);
-    transmute(simd_select::<simd::i32x4, _>(simd_gt(a, b), a, b))
+pub fn u32x4_max(a: v128, b: v128) -> v128 {
+    unsafe {
+        let a = transmute::<_, simd::u32x4>(a);
+        let b = transmute::<_, simd::u32x4>(b);
+        transmute(simd_select::<simd::i32x4, _>(simd_gt(a, b), a, b))
+    }
 }
 
 /// Lane-wise multiply signed 16-bit integers in the two input vectors and add
@@ -2583,8 +2624,8 @@ pub unsafe fn u32x4_max(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i32x4.dot_i16x8_s))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i32x4_dot_i16x8(a: v128, b: v128) -> v128 {
-    transmute(llvm_i32x4_dot_i16x8_s(a.as_i16x8(), b.as_i16x8()))
+pub fn i32x4_dot_i16x8(a: v128, b: v128) -> v128 {
+    unsafe { transmute(llvm_i32x4_dot_i16x8_s(a.as_i16x8(), b.as_i16x8())) }
 }
 
 /// Lane-wise integer extended multiplication producing twice wider result than
@@ -2594,8 +2635,8 @@ pub unsafe fn i32x4_dot_i16x8(a: v128, b: v128) -> v128 {
 #[inline]
 // #[cfg_attr(test, assert_instr(i32x4.extmul_low_i16x8_s))] // FIXME wasmtime
 #[target_feature(enable = "simd128")]
-pub unsafe fn i32x4_extmul_low_i16x8(a: v128, b: v128) -> v128 {
-    transmute(llvm_i32x4_extmul_low_i16x8_s(a.as_i16x8(), b.as_i16x8()))
+pub fn i32x4_extmul_low_i16x8(a: v128, b: v128) -> v128 {
+    unsafe { transmute(llvm_i32x4_extmul_low_i16x8_s(a.as_i16x8(), b.as_i16x8())) }
 }
 
 /// Lane-wise integer extended multiplication producing twice wider result than
@@ -2605,8 +2646,8 @@ pub unsafe fn i32x4_extmul_low_i16x8(a: v128, b: v128) -> v128 {
 #[inline]
 // #[cfg_attr(test, assert_instr(i32x4.extmul_high_i16x8_s))] // FIXME wasmtime
 #[target_feature(enable = "simd128")]
-pub unsafe fn i32x4_extmul_high_i16x8(a: v128, b: v128) -> v128 {
-    transmute(llvm_i32x4_extmul_high_i16x8_s(a.as_i16x8(), b.as_i16x8()))
+pub fn i32x4_extmul_high_i16x8(a: v128, b: v128) -> v128 {
+    unsafe { transmute(llvm_i32x4_extmul_high_i16x8_s(a.as_i16x8(), b.as_i16x8())) }
 }
 
 /// Lane-wise integer extended multiplication producing twice wider result than
@@ -2616,8 +2657,8 @@ pub unsafe fn i32x4_extmul_high_i16x8(a: v128, b: v128) -> v128 {
 #[inline]
 // #[cfg_attr(test, assert_instr(i32x4.extmul_low_i16x8_u))] // FIXME wasmtime
 #[target_feature(enable = "simd128")]
-pub unsafe fn i32x4_extmul_low_u16x8(a: v128, b: v128) -> v128 {
-    transmute(llvm_i32x4_extmul_low_i16x8_u(a.as_i16x8(), b.as_i16x8()))
+pub fn i32x4_extmul_low_u16x8(a: v128, b: v128) -> v128 {
+    unsafe { transmute(llvm_i32x4_extmul_low_i16x8_u(a.as_i16x8(), b.as_i16x8())) }
 }
 
 /// Lane-wise integer extended multiplication producing twice wider result than
@@ -2627,38 +2668,40 @@ pub unsafe fn i32x4_extmul_low_u16x8(a: v128, b: v128) -> v128 {
 #[inline]
 // #[cfg_attr(test, assert_instr(i32x4.extmul_high_i16x8_u))] // FIXME wasmtime
 #[target_feature(enable = "simd128")]
-pub unsafe fn i32x4_extmul_high_u16x8(a: v128, b: v128) -> v128 {
-    transmute(llvm_i32x4_extmul_high_i16x8_u(a.as_i16x8(), b.as_i16x8()))
+pub fn i32x4_extmul_high_u16x8(a: v128, b: v128) -> v128 {
+    unsafe { transmute(llvm_i32x4_extmul_high_i16x8_u(a.as_i16x8(), b.as_i16x8())) }
 }
 
 /// Lane-wise wrapping absolute value.
 #[inline]
 // #[cfg_attr(test, assert_instr(i64x2.abs))] // FIXME llvm
 #[target_feature(enable = "simd128")]
-pub unsafe fn i64x2_abs(a: v128) -> v128 {
-    let a = transmute::<_, simd::i64x2>(a);
-    let zero = simd::i64x2::splat(0);
-    transmute(simd_select::<simd::m64x2, simd::i64x2>(
-        simd_lt(a, zero),
-        simd_sub(zero, a),
-        a,
-    ))
+pub fn i64x2_abs(a: v128) -> v128 {
+    unsafe {
+        let a = transmute::<_, simd::i64x2>(a);
+        let zero = simd::i64x2::splat(0);
+        transmute(simd_select::<simd::m64x2, simd::i64x2>(
+            simd_lt(a, zero),
+            simd_sub(zero, a),
+            a,
+        ))
+    }
 }
 
 /// Negates a 128-bit vectors intepreted as two 64-bit signed integers
 #[inline]
 #[cfg_attr(test, assert_instr(i64x2.neg))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i64x2_neg(a: v128) -> v128 {
-    transmute(simd_mul(a.as_i64x2(), simd::i64x2::splat(-1)))
+pub fn i64x2_neg(a: v128) -> v128 {
+    unsafe { transmute(simd_mul(a.as_i64x2(), simd::i64x2::splat(-1))) }
 }
 
 /// Returns 1 if all lanes are nonzero or 0 if any lane is nonzero.
 #[inline]
 #[cfg_attr(test, assert_instr(i64x2.all_true))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i64x2_all_true(a: v128) -> bool {
-    llvm_i64x2_all_true(a.as_i64x2()) != 0
+pub fn i64x2_all_true(a: v128) -> bool {
+    unsafe { llvm_i64x2_all_true(a.as_i64x2()) != 0 }
 }
 
 /// Extracts the high bit for each lane in `a` and produce a scalar mask with
@@ -2666,8 +2709,8 @@ pub unsafe fn i64x2_all_true(a: v128) -> bool {
 #[inline]
 #[cfg_attr(test, assert_instr(i64x2.bitmask))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i64x2_bitmask(a: v128) -> i32 {
-    llvm_bitmask_i64x2(transmute(a))
+pub fn i64x2_bitmask(a: v128) -> i32 {
+    unsafe { llvm_bitmask_i64x2(transmute(a)) }
 }
 
 /// Converts low half of the smaller lane vector to a larger lane
@@ -2675,10 +2718,12 @@ pub unsafe fn i64x2_bitmask(a: v128) -> i32 {
 #[inline]
 // #[cfg_attr(test, assert_instr(i64x2.extend_low_i32x4_s))] // FIXME wasmtime
 #[target_feature(enable = "simd128")]
-pub unsafe fn i64x2_extend_low_i32x4(a: v128) -> v128 {
-    transmute(simd_cast::<_, simd::i64x2>(
-        simd_shuffle2::<_, simd::i32x2>(a.as_i32x4(), a.as_i32x4(), [0, 1]),
-    ))
+pub fn i64x2_extend_low_i32x4(a: v128) -> v128 {
+    unsafe {
+        transmute(simd_cast::<_, simd::i64x2>(
+            simd_shuffle2::<_, simd::i32x2>(a.as_i32x4(), a.as_i32x4(), [0, 1]),
+        ))
+    }
 }
 
 /// Converts high half of the smaller lane vector to a larger lane
@@ -2686,10 +2731,12 @@ pub unsafe fn i64x2_extend_low_i32x4(a: v128) -> v128 {
 #[inline]
 // #[cfg_attr(test, assert_instr(i64x2.extend_high_i32x4_s))] // FIXME wasmtime
 #[target_feature(enable = "simd128")]
-pub unsafe fn i64x2_extend_high_i32x4(a: v128) -> v128 {
-    transmute(simd_cast::<_, simd::i64x2>(
-        simd_shuffle2::<_, simd::i32x2>(a.as_i32x4(), a.as_i32x4(), [2, 3]),
-    ))
+pub fn i64x2_extend_high_i32x4(a: v128) -> v128 {
+    unsafe {
+        transmute(simd_cast::<_, simd::i64x2>(
+            simd_shuffle2::<_, simd::i32x2>(a.as_i32x4(), a.as_i32x4(), [2, 3]),
+        ))
+    }
 }
 
 /// Converts low half of the smaller lane vector to a larger lane
@@ -2697,10 +2744,12 @@ pub unsafe fn i64x2_extend_high_i32x4(a: v128) -> v128 {
 #[inline]
 // #[cfg_attr(test, assert_instr(i64x2.extend_low_i32x4_u))] // FIXME wasmtime
 #[target_feature(enable = "simd128")]
-pub unsafe fn i64x2_extend_low_u32x4(a: v128) -> v128 {
-    transmute(simd_cast::<_, simd::i64x2>(
-        simd_shuffle2::<_, simd::u32x2>(a.as_u32x4(), a.as_u32x4(), [0, 1]),
-    ))
+pub fn i64x2_extend_low_u32x4(a: v128) -> v128 {
+    unsafe {
+        transmute(simd_cast::<_, simd::i64x2>(
+            simd_shuffle2::<_, simd::u32x2>(a.as_u32x4(), a.as_u32x4(), [0, 1]),
+        ))
+    }
 }
 
 /// Converts high half of the smaller lane vector to a larger lane
@@ -2708,10 +2757,12 @@ pub unsafe fn i64x2_extend_low_u32x4(a: v128) -> v128 {
 #[inline]
 // #[cfg_attr(test, assert_instr(i64x2.extend_high_i32x4_u))] // FIXME wasmtime
 #[target_feature(enable = "simd128")]
-pub unsafe fn i64x2_extend_high_u32x4(a: v128) -> v128 {
-    transmute(simd_cast::<_, simd::i64x2>(
-        simd_shuffle2::<_, simd::u32x2>(a.as_u32x4(), a.as_u32x4(), [2, 3]),
-    ))
+pub fn i64x2_extend_high_u32x4(a: v128) -> v128 {
+    unsafe {
+        transmute(simd_cast::<_, simd::i64x2>(
+            simd_shuffle2::<_, simd::u32x2>(a.as_u32x4(), a.as_u32x4(), [2, 3]),
+        ))
+    }
 }
 
 /// Shifts each lane to the left by the specified number of bits.
@@ -2721,8 +2772,8 @@ pub unsafe fn i64x2_extend_high_u32x4(a: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i64x2.shl))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i64x2_shl(a: v128, amt: u32) -> v128 {
-    transmute(simd_shl(a.as_i64x2(), simd::i64x2::splat(amt as i64)))
+pub fn i64x2_shl(a: v128, amt: u32) -> v128 {
+    unsafe { transmute(simd_shl(a.as_i64x2(), simd::i64x2::splat(amt as i64))) }
 }
 
 /// Shifts each lane to the right by the specified number of bits, sign
@@ -2733,8 +2784,8 @@ pub unsafe fn i64x2_shl(a: v128, amt: u32) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i64x2.shr_s))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i64x2_shr(a: v128, amt: u32) -> v128 {
-    transmute(simd_shr(a.as_i64x2(), simd::i64x2::splat(amt as i64)))
+pub fn i64x2_shr(a: v128, amt: u32) -> v128 {
+    unsafe { transmute(simd_shr(a.as_i64x2(), simd::i64x2::splat(amt as i64))) }
 }
 
 /// Shifts each lane to the right by the specified number of bits, shifting in
@@ -2745,32 +2796,32 @@ pub unsafe fn i64x2_shr(a: v128, amt: u32) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i64x2.shr_u))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn u64x2_shr(a: v128, amt: u32) -> v128 {
-    transmute(simd_shr(a.as_u64x2(), simd::u64x2::splat(amt as u64)))
+pub fn u64x2_shr(a: v128, amt: u32) -> v128 {
+    unsafe { transmute(simd_shr(a.as_u64x2(), simd::u64x2::splat(amt as u64))) }
 }
 
 /// Adds two 128-bit vectors as if they were two packed two 64-bit integers.
 #[inline]
 #[cfg_attr(test, assert_instr(i64x2.add))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i64x2_add(a: v128, b: v128) -> v128 {
-    transmute(simd_add(a.as_i64x2(), b.as_i64x2()))
+pub fn i64x2_add(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_add(a.as_i64x2(), b.as_i64x2())) }
 }
 
 /// Subtracts two 128-bit vectors as if they were two packed two 64-bit integers.
 #[inline]
 #[cfg_attr(test, assert_instr(i64x2.sub))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i64x2_sub(a: v128, b: v128) -> v128 {
-    transmute(simd_sub(a.as_i64x2(), b.as_i64x2()))
+pub fn i64x2_sub(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_sub(a.as_i64x2(), b.as_i64x2())) }
 }
 
 /// Multiplies two 128-bit vectors as if they were two packed two 64-bit integers.
 #[inline]
 #[cfg_attr(test, assert_instr(i64x2.mul))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i64x2_mul(a: v128, b: v128) -> v128 {
-    transmute(simd_mul(a.as_i64x2(), b.as_i64x2()))
+pub fn i64x2_mul(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_mul(a.as_i64x2(), b.as_i64x2())) }
 }
 
 /// Lane-wise integer extended multiplication producing twice wider result than
@@ -2780,8 +2831,8 @@ pub unsafe fn i64x2_mul(a: v128, b: v128) -> v128 {
 #[inline]
 // #[cfg_attr(test, assert_instr(i64x2.extmul_low_i32x4_s))] // FIXME wasmtime
 #[target_feature(enable = "simd128")]
-pub unsafe fn i64x2_extmul_low_i32x4(a: v128, b: v128) -> v128 {
-    transmute(llvm_i64x2_extmul_low_i32x4_s(a.as_i32x4(), b.as_i32x4()))
+pub fn i64x2_extmul_low_i32x4(a: v128, b: v128) -> v128 {
+    unsafe { transmute(llvm_i64x2_extmul_low_i32x4_s(a.as_i32x4(), b.as_i32x4())) }
 }
 
 /// Lane-wise integer extended multiplication producing twice wider result than
@@ -2791,8 +2842,8 @@ pub unsafe fn i64x2_extmul_low_i32x4(a: v128, b: v128) -> v128 {
 #[inline]
 // #[cfg_attr(test, assert_instr(i64x2.extmul_high_i32x4_s))] // FIXME wasmtime
 #[target_feature(enable = "simd128")]
-pub unsafe fn i64x2_extmul_high_i32x4(a: v128, b: v128) -> v128 {
-    transmute(llvm_i64x2_extmul_high_i32x4_s(a.as_i32x4(), b.as_i32x4()))
+pub fn i64x2_extmul_high_i32x4(a: v128, b: v128) -> v128 {
+    unsafe { transmute(llvm_i64x2_extmul_high_i32x4_s(a.as_i32x4(), b.as_i32x4())) }
 }
 
 /// Lane-wise integer extended multiplication producing twice wider result than
@@ -2802,8 +2853,8 @@ pub unsafe fn i64x2_extmul_high_i32x4(a: v128, b: v128) -> v128 {
 #[inline]
 // #[cfg_attr(test, assert_instr(i64x2.extmul_low_i32x4_u))] // FIXME wasmtime
 #[target_feature(enable = "simd128")]
-pub unsafe fn i64x2_extmul_low_u32x4(a: v128, b: v128) -> v128 {
-    transmute(llvm_i64x2_extmul_low_i32x4_u(a.as_i32x4(), b.as_i32x4()))
+pub fn i64x2_extmul_low_u32x4(a: v128, b: v128) -> v128 {
+    unsafe { transmute(llvm_i64x2_extmul_low_i32x4_u(a.as_i32x4(), b.as_i32x4())) }
 }
 
 /// Lane-wise integer extended multiplication producing twice wider result than
@@ -2813,24 +2864,24 @@ pub unsafe fn i64x2_extmul_low_u32x4(a: v128, b: v128) -> v128 {
 #[inline]
 // #[cfg_attr(test, assert_instr(i64x2.extmul_high_i32x4_u))] // FIXME wasmtime
 #[target_feature(enable = "simd128")]
-pub unsafe fn i64x2_extmul_high_u32x4(a: v128, b: v128) -> v128 {
-    transmute(llvm_i64x2_extmul_high_i32x4_u(a.as_i32x4(), b.as_i32x4()))
+pub fn i64x2_extmul_high_u32x4(a: v128, b: v128) -> v128 {
+    unsafe { transmute(llvm_i64x2_extmul_high_i32x4_u(a.as_i32x4(), b.as_i32x4())) }
 }
 
 /// Lane-wise rounding to the nearest integral value not smaller than the input.
 #[inline]
 #[cfg_attr(test, assert_instr(f32x4.ceil))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn f32x4_ceil(a: v128) -> v128 {
-    transmute(llvm_f32x4_ceil(a.as_f32x4()))
+pub fn f32x4_ceil(a: v128) -> v128 {
+    unsafe { transmute(llvm_f32x4_ceil(a.as_f32x4())) }
 }
 
 /// Lane-wise rounding to the nearest integral value not greater than the input.
 #[inline]
 #[cfg_attr(test, assert_instr(f32x4.floor))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn f32x4_floor(a: v128) -> v128 {
-    transmute(llvm_f32x4_floor(a.as_f32x4()))
+pub fn f32x4_floor(a: v128) -> v128 {
+    unsafe { transmute(llvm_f32x4_floor(a.as_f32x4())) }
 }
 
 /// Lane-wise rounding to the nearest integral value with the magnitude not
@@ -2838,8 +2889,8 @@ pub unsafe fn f32x4_floor(a: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(f32x4.trunc))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn f32x4_trunc(a: v128) -> v128 {
-    transmute(llvm_f32x4_trunc(a.as_f32x4()))
+pub fn f32x4_trunc(a: v128) -> v128 {
+    unsafe { transmute(llvm_f32x4_trunc(a.as_f32x4())) }
 }
 
 /// Lane-wise rounding to the nearest integral value; if two values are equally
@@ -2847,8 +2898,8 @@ pub unsafe fn f32x4_trunc(a: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(f32x4.nearest))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn f32x4_nearest(a: v128) -> v128 {
-    transmute(llvm_f32x4_nearest(a.as_f32x4()))
+pub fn f32x4_nearest(a: v128) -> v128 {
+    unsafe { transmute(llvm_f32x4_nearest(a.as_f32x4())) }
 }
 
 /// Calculates the absolute value of each lane of a 128-bit vector interpreted
@@ -2856,8 +2907,8 @@ pub unsafe fn f32x4_nearest(a: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(f32x4.abs))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn f32x4_abs(a: v128) -> v128 {
-    transmute(llvm_f32x4_abs(a.as_f32x4()))
+pub fn f32x4_abs(a: v128) -> v128 {
+    unsafe { transmute(llvm_f32x4_abs(a.as_f32x4())) }
 }
 
 /// Negates each lane of a 128-bit vector interpreted as four 32-bit floating
@@ -2865,7 +2916,7 @@ pub unsafe fn f32x4_abs(a: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(f32x4.neg))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn f32x4_neg(a: v128) -> v128 {
+pub fn f32x4_neg(a: v128) -> v128 {
     f32x4_mul(a, f32x4_splat(-1.))
 }
 
@@ -2874,8 +2925,8 @@ pub unsafe fn f32x4_neg(a: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(f32x4.sqrt))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn f32x4_sqrt(a: v128) -> v128 {
-    transmute(llvm_f32x4_sqrt(a.as_f32x4()))
+pub fn f32x4_sqrt(a: v128) -> v128 {
+    unsafe { transmute(llvm_f32x4_sqrt(a.as_f32x4())) }
 }
 
 /// Adds pairwise lanes of two 128-bit vectors interpreted as four 32-bit
@@ -2883,8 +2934,8 @@ pub unsafe fn f32x4_sqrt(a: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(f32x4.add))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn f32x4_add(a: v128, b: v128) -> v128 {
-    transmute(simd_add(a.as_f32x4(), b.as_f32x4()))
+pub fn f32x4_add(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_add(a.as_f32x4(), b.as_f32x4())) }
 }
 
 /// Subtracts pairwise lanes of two 128-bit vectors interpreted as four 32-bit
@@ -2892,8 +2943,8 @@ pub unsafe fn f32x4_add(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(f32x4.sub))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn f32x4_sub(a: v128, b: v128) -> v128 {
-    transmute(simd_sub(a.as_f32x4(), b.as_f32x4()))
+pub fn f32x4_sub(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_sub(a.as_f32x4(), b.as_f32x4())) }
 }
 
 /// Multiplies pairwise lanes of two 128-bit vectors interpreted as four 32-bit
@@ -2901,8 +2952,8 @@ pub unsafe fn f32x4_sub(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(f32x4.mul))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn f32x4_mul(a: v128, b: v128) -> v128 {
-    transmute(simd_mul(a.as_f32x4(), b.as_f32x4()))
+pub fn f32x4_mul(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_mul(a.as_f32x4(), b.as_f32x4())) }
 }
 
 /// Divides pairwise lanes of two 128-bit vectors interpreted as four 32-bit
@@ -2910,8 +2961,8 @@ pub unsafe fn f32x4_mul(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(f32x4.div))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn f32x4_div(a: v128, b: v128) -> v128 {
-    transmute(simd_div(a.as_f32x4(), b.as_f32x4()))
+pub fn f32x4_div(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_div(a.as_f32x4(), b.as_f32x4())) }
 }
 
 /// Calculates the minimum of pairwise lanes of two 128-bit vectors interpreted
@@ -2919,8 +2970,8 @@ pub unsafe fn f32x4_div(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(f32x4.min))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn f32x4_min(a: v128, b: v128) -> v128 {
-    transmute(llvm_f32x4_min(a.as_f32x4(), b.as_f32x4()))
+pub fn f32x4_min(a: v128, b: v128) -> v128 {
+    unsafe { transmute(llvm_f32x4_min(a.as_f32x4(), b.as_f32x4())) }
 }
 
 /// Calculates the maximum of pairwise lanes of two 128-bit vectors interpreted
@@ -2928,40 +2979,40 @@ pub unsafe fn f32x4_min(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(f32x4.max))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn f32x4_max(a: v128, b: v128) -> v128 {
-    transmute(llvm_f32x4_max(a.as_f32x4(), b.as_f32x4()))
+pub fn f32x4_max(a: v128, b: v128) -> v128 {
+    unsafe { transmute(llvm_f32x4_max(a.as_f32x4(), b.as_f32x4())) }
 }
 
 /// Lane-wise minimum value, defined as `b < a ? b : a`
 #[inline]
 #[cfg_attr(test, assert_instr(f32x4.pmin))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn f32x4_pmin(a: v128, b: v128) -> v128 {
-    transmute(llvm_f32x4_pmin(a.as_f32x4(), b.as_f32x4()))
+pub fn f32x4_pmin(a: v128, b: v128) -> v128 {
+    unsafe { transmute(llvm_f32x4_pmin(a.as_f32x4(), b.as_f32x4())) }
 }
 
 /// Lane-wise maximum value, defined as `a < b ? b : a`
 #[inline]
 #[cfg_attr(test, assert_instr(f32x4.pmax))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn f32x4_pmax(a: v128, b: v128) -> v128 {
-    transmute(llvm_f32x4_pmax(a.as_f32x4(), b.as_f32x4()))
+pub fn f32x4_pmax(a: v128, b: v128) -> v128 {
+    unsafe { transmute(llvm_f32x4_pmax(a.as_f32x4(), b.as_f32x4())) }
 }
 
 /// Lane-wise rounding to the nearest integral value not smaller than the input.
 #[inline]
 #[cfg_attr(test, assert_instr(f64x2.ceil))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn f64x2_ceil(a: v128) -> v128 {
-    transmute(llvm_f64x2_ceil(a.as_f64x2()))
+pub fn f64x2_ceil(a: v128) -> v128 {
+    unsafe { transmute(llvm_f64x2_ceil(a.as_f64x2())) }
 }
 
 /// Lane-wise rounding to the nearest integral value not greater than the input.
 #[inline]
 #[cfg_attr(test, assert_instr(f64x2.floor))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn f64x2_floor(a: v128) -> v128 {
-    transmute(llvm_f64x2_floor(a.as_f64x2()))
+pub fn f64x2_floor(a: v128) -> v128 {
+    unsafe { transmute(llvm_f64x2_floor(a.as_f64x2())) }
 }
 
 /// Lane-wise rounding to the nearest integral value with the magnitude not
@@ -2969,8 +3020,8 @@ pub unsafe fn f64x2_floor(a: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(f64x2.trunc))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn f64x2_trunc(a: v128) -> v128 {
-    transmute(llvm_f64x2_trunc(a.as_f64x2()))
+pub fn f64x2_trunc(a: v128) -> v128 {
+    unsafe { transmute(llvm_f64x2_trunc(a.as_f64x2())) }
 }
 
 /// Lane-wise rounding to the nearest integral value; if two values are equally
@@ -2978,8 +3029,8 @@ pub unsafe fn f64x2_trunc(a: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(f64x2.nearest))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn f64x2_nearest(a: v128) -> v128 {
-    transmute(llvm_f64x2_nearest(a.as_f64x2()))
+pub fn f64x2_nearest(a: v128) -> v128 {
+    unsafe { transmute(llvm_f64x2_nearest(a.as_f64x2())) }
 }
 
 /// Calculates the absolute value of each lane of a 128-bit vector interpreted
@@ -2987,8 +3038,8 @@ pub unsafe fn f64x2_nearest(a: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(f64x2.abs))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn f64x2_abs(a: v128) -> v128 {
-    transmute(llvm_f64x2_abs(a.as_f64x2()))
+pub fn f64x2_abs(a: v128) -> v128 {
+    unsafe { transmute(llvm_f64x2_abs(a.as_f64x2())) }
 }
 
 /// Negates each lane of a 128-bit vector interpreted as two 64-bit floating
@@ -2996,7 +3047,7 @@ pub unsafe fn f64x2_abs(a: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(f64x2.neg))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn f64x2_neg(a: v128) -> v128 {
+pub fn f64x2_neg(a: v128) -> v128 {
     f64x2_mul(a, f64x2_splat(-1.0))
 }
 
@@ -3005,8 +3056,8 @@ pub unsafe fn f64x2_neg(a: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(f64x2.sqrt))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn f64x2_sqrt(a: v128) -> v128 {
-    transmute(llvm_f64x2_sqrt(a.as_f64x2()))
+pub fn f64x2_sqrt(a: v128) -> v128 {
+    unsafe { transmute(llvm_f64x2_sqrt(a.as_f64x2())) }
 }
 
 /// Adds pairwise lanes of two 128-bit vectors interpreted as two 64-bit
@@ -3014,8 +3065,8 @@ pub unsafe fn f64x2_sqrt(a: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(f64x2.add))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn f64x2_add(a: v128, b: v128) -> v128 {
-    transmute(simd_add(a.as_f64x2(), b.as_f64x2()))
+pub fn f64x2_add(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_add(a.as_f64x2(), b.as_f64x2())) }
 }
 
 /// Subtracts pairwise lanes of two 128-bit vectors interpreted as two 64-bit
@@ -3023,8 +3074,8 @@ pub unsafe fn f64x2_add(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(f64x2.sub))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn f64x2_sub(a: v128, b: v128) -> v128 {
-    transmute(simd_sub(a.as_f64x2(), b.as_f64x2()))
+pub fn f64x2_sub(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_sub(a.as_f64x2(), b.as_f64x2())) }
 }
 
 /// Multiplies pairwise lanes of two 128-bit vectors interpreted as two 64-bit
@@ -3032,8 +3083,8 @@ pub unsafe fn f64x2_sub(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(f64x2.mul))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn f64x2_mul(a: v128, b: v128) -> v128 {
-    transmute(simd_mul(a.as_f64x2(), b.as_f64x2()))
+pub fn f64x2_mul(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_mul(a.as_f64x2(), b.as_f64x2())) }
 }
 
 /// Divides pairwise lanes of two 128-bit vectors interpreted as two 64-bit
@@ -3041,8 +3092,8 @@ pub unsafe fn f64x2_mul(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(f64x2.div))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn f64x2_div(a: v128, b: v128) -> v128 {
-    transmute(simd_div(a.as_f64x2(), b.as_f64x2()))
+pub fn f64x2_div(a: v128, b: v128) -> v128 {
+    unsafe { transmute(simd_div(a.as_f64x2(), b.as_f64x2())) }
 }
 
 /// Calculates the minimum of pairwise lanes of two 128-bit vectors interpreted
@@ -3050,8 +3101,8 @@ pub unsafe fn f64x2_div(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(f64x2.min))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn f64x2_min(a: v128, b: v128) -> v128 {
-    transmute(llvm_f64x2_min(a.as_f64x2(), b.as_f64x2()))
+pub fn f64x2_min(a: v128, b: v128) -> v128 {
+    unsafe { transmute(llvm_f64x2_min(a.as_f64x2(), b.as_f64x2())) }
 }
 
 /// Calculates the maximum of pairwise lanes of two 128-bit vectors interpreted
@@ -3059,24 +3110,24 @@ pub unsafe fn f64x2_min(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(f64x2.max))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn f64x2_max(a: v128, b: v128) -> v128 {
-    transmute(llvm_f64x2_max(a.as_f64x2(), b.as_f64x2()))
+pub fn f64x2_max(a: v128, b: v128) -> v128 {
+    unsafe { transmute(llvm_f64x2_max(a.as_f64x2(), b.as_f64x2())) }
 }
 
 /// Lane-wise minimum value, defined as `b < a ? b : a`
 #[inline]
 #[cfg_attr(test, assert_instr(f64x2.pmin))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn f64x2_pmin(a: v128, b: v128) -> v128 {
-    transmute(llvm_f64x2_pmin(a.as_f64x2(), b.as_f64x2()))
+pub fn f64x2_pmin(a: v128, b: v128) -> v128 {
+    unsafe { transmute(llvm_f64x2_pmin(a.as_f64x2(), b.as_f64x2())) }
 }
 
 /// Lane-wise maximum value, defined as `a < b ? b : a`
 #[inline]
 #[cfg_attr(test, assert_instr(f64x2.pmax))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn f64x2_pmax(a: v128, b: v128) -> v128 {
-    transmute(llvm_f64x2_pmax(a.as_f64x2(), b.as_f64x2()))
+pub fn f64x2_pmax(a: v128, b: v128) -> v128 {
+    unsafe { transmute(llvm_f64x2_pmax(a.as_f64x2(), b.as_f64x2())) }
 }
 
 /// Converts a 128-bit vector interpreted as four 32-bit floating point numbers
@@ -3087,8 +3138,8 @@ pub unsafe fn f64x2_pmax(a: v128, b: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i32x4.trunc_sat_f32x4_s))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn i32x4_trunc_sat_f32x4(a: v128) -> v128 {
-    transmute(llvm_i32x4_trunc_sat_f32x4_s(a.as_f32x4()))
+pub fn i32x4_trunc_sat_f32x4(a: v128) -> v128 {
+    unsafe { transmute(llvm_i32x4_trunc_sat_f32x4_s(a.as_f32x4())) }
 }
 
 /// Converts a 128-bit vector interpreted as four 32-bit floating point numbers
@@ -3099,8 +3150,8 @@ pub unsafe fn i32x4_trunc_sat_f32x4(a: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(i32x4.trunc_sat_f32x4_u))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn u32x4_trunc_sat_f32x4(a: v128) -> v128 {
-    transmute(llvm_i32x4_trunc_sat_f32x4_u(a.as_f32x4()))
+pub fn u32x4_trunc_sat_f32x4(a: v128) -> v128 {
+    unsafe { transmute(llvm_i32x4_trunc_sat_f32x4_u(a.as_f32x4())) }
 }
 
 /// Converts a 128-bit vector interpreted as four 32-bit signed integers into a
@@ -3108,8 +3159,8 @@ pub unsafe fn u32x4_trunc_sat_f32x4(a: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(f32x4.convert_i32x4_s))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn f32x4_convert_i32x4(a: v128) -> v128 {
-    transmute(simd_cast::<_, simd::f32x4>(a.as_i32x4()))
+pub fn f32x4_convert_i32x4(a: v128) -> v128 {
+    unsafe { transmute(simd_cast::<_, simd::f32x4>(a.as_i32x4())) }
 }
 
 /// Converts a 128-bit vector interpreted as four 32-bit unsigned integers into a
@@ -3117,8 +3168,8 @@ pub unsafe fn f32x4_convert_i32x4(a: v128) -> v128 {
 #[inline]
 #[cfg_attr(test, assert_instr(f32x4.convert_i32x4_u))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn f32x4_convert_u32x4(a: v128) -> v128 {
-    transmute(simd_cast::<_, simd::f32x4>(a.as_u32x4()))
+pub fn f32x4_convert_u32x4(a: v128) -> v128 {
+    unsafe { transmute(simd_cast::<_, simd::f32x4>(a.as_u32x4())) }
 }
 
 /// Saturating conversion of the two double-precision floating point lanes to
@@ -3132,12 +3183,14 @@ pub unsafe fn f32x4_convert_u32x4(a: v128) -> v128 {
 #[inline]
 // #[cfg_attr(test, assert_instr(i32x4.trunc_sat_f64x2_s_zero))] // FIXME wasmtime
 #[target_feature(enable = "simd128")]
-pub unsafe fn i32x4_trunc_sat_f64x2_zero(a: v128) -> v128 {
-    transmute(simd_shuffle4::<simd::i32x2, simd::i32x4>(
-        llvm_i32x2_trunc_sat_f64x2_s(a.as_f64x2()),
-        simd::i32x2::splat(0),
-        [0, 1, 2, 3],
-    ))
+pub fn i32x4_trunc_sat_f64x2_zero(a: v128) -> v128 {
+    unsafe {
+        transmute(simd_shuffle4::<simd::i32x2, simd::i32x4>(
+            llvm_i32x2_trunc_sat_f64x2_s(a.as_f64x2()),
+            simd::i32x2::splat(0),
+            [0, 1, 2, 3],
+        ))
+    }
 }
 
 /// Saturating conversion of the two double-precision floating point lanes to
@@ -3151,42 +3204,48 @@ pub unsafe fn i32x4_trunc_sat_f64x2_zero(a: v128) -> v128 {
 #[inline]
 // #[cfg_attr(test, assert_instr(i32x4.trunc_sat_f64x2_u_zero))] // FIXME wasmtime
 #[target_feature(enable = "simd128")]
-pub unsafe fn u32x4_trunc_sat_f64x2_zero(a: v128) -> v128 {
-    transmute(simd_shuffle4::<simd::i32x2, simd::i32x4>(
-        llvm_i32x2_trunc_sat_f64x2_u(a.as_f64x2()),
-        simd::i32x2::splat(0),
-        [0, 1, 2, 3],
-    ))
+pub fn u32x4_trunc_sat_f64x2_zero(a: v128) -> v128 {
+    unsafe {
+        transmute(simd_shuffle4::<simd::i32x2, simd::i32x4>(
+            llvm_i32x2_trunc_sat_f64x2_u(a.as_f64x2()),
+            simd::i32x2::splat(0),
+            [0, 1, 2, 3],
+        ))
+    }
 }
 
 /// Lane-wise conversion from integer to floating point.
 #[inline]
 #[cfg_attr(test, assert_instr(f64x2.convert_low_i32x4_s))]
 #[target_feature(enable = "simd128")]
-pub unsafe fn f64x2_convert_low_i32x4(a: v128) -> v128 {
-    transmute(simd_cast::<_, simd::f64x2>(simd_shuffle2::<
-        simd::i32x4,
-        simd::i32x2,
-    >(
-        a.as_i32x4(),
-        a.as_i32x4(),
-        [0, 1],
-    )))
+pub fn f64x2_convert_low_i32x4(a: v128) -> v128 {
+    unsafe {
+        transmute(simd_cast::<_, simd::f64x2>(simd_shuffle2::<
+            simd::i32x4,
+            simd::i32x2,
+        >(
+            a.as_i32x4(),
+            a.as_i32x4(),
+            [0, 1],
+        )))
+    }
 }
 
 /// Lane-wise conversion from integer to floating point.
 #[inline]
 // #[cfg_attr(test, assert_instr(f64x2.convert_low_i32x4_u))] // FIXME wasmtime
 #[target_feature(enable = "simd128")]
-pub unsafe fn f64x2_convert_low_u32x4(a: v128) -> v128 {
-    transmute(simd_cast::<_, simd::f64x2>(simd_shuffle2::<
-        simd::u32x4,
-        simd::u32x2,
-    >(
-        a.as_u32x4(),
-        a.as_u32x4(),
-        [0, 1],
-    )))
+pub fn f64x2_convert_low_u32x4(a: v128) -> v128 {
+    unsafe {
+        transmute(simd_cast::<_, simd::f64x2>(simd_shuffle2::<
+            simd::u32x4,
+            simd::u32x2,
+        >(
+            a.as_u32x4(),
+            a.as_u32x4(),
+            [0, 1],
+        )))
+    }
 }
 
 /// Conversion of the two double-precision floating point lanes to two lower
@@ -3197,8 +3256,8 @@ pub unsafe fn f64x2_convert_low_u32x4(a: v128) -> v128 {
 #[inline]
 // #[cfg_attr(test, assert_instr(f32x4.demote_f64x2_zero))] // FIXME wasmtime
 #[target_feature(enable = "simd128")]
-pub unsafe fn f32x4_demote_f64x2_zero(a: v128) -> v128 {
-    transmute(llvm_f32x4_demote_f64x2_zero(a.as_f64x2()))
+pub fn f32x4_demote_f64x2_zero(a: v128) -> v128 {
+    unsafe { transmute(llvm_f32x4_demote_f64x2_zero(a.as_f64x2())) }
 }
 
 /// Conversion of the two lower single-precision floating point lanes to the two
@@ -3206,8 +3265,8 @@ pub unsafe fn f32x4_demote_f64x2_zero(a: v128) -> v128 {
 #[inline]
 // #[cfg_attr(test, assert_instr(f64x2.promote_low_f32x4))] // FIXME wasmtime
 #[target_feature(enable = "simd128")]
-pub unsafe fn f64x2_promote_low_f32x4(a: v128) -> v128 {
-    transmute(llvm_f64x2_promote_low_f32x4(a.as_f32x4()))
+pub fn f64x2_promote_low_f32x4(a: v128) -> v128 {
+    unsafe { transmute(llvm_f64x2_promote_low_f32x4(a.as_f32x4())) }
 }
 
 #[cfg(test)]
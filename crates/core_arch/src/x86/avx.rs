@@ -1362,6 +1362,7 @@ pub unsafe fn _mm256_insert_epi32<const INDEX: i32>(a: __m256i, i: i32) -> __m25
 #[stable(feature = "simd_x86", since = "1.27.0")]
 #[allow(clippy::cast_ptr_alignment)]
 pub unsafe fn _mm256_load_pd(mem_addr: *const f64) -> __m256d {
+    debug_assert_aligned!(mem_addr, 32);
     *(mem_addr as *const __m256d)
 }
 
@@ -1377,6 +1378,7 @@ pub unsafe fn _mm256_load_pd(mem_addr: *const f64) -> __m256d {
 #[stable(feature = "simd_x86", since = "1.27.0")]
 #[allow(clippy::cast_ptr_alignment)]
 pub unsafe fn _mm256_store_pd(mem_addr: *mut f64, a: __m256d) {
+    debug_assert_aligned!(mem_addr, 32);
     *(mem_addr as *mut __m256d) = a;
 }
 
@@ -1392,6 +1394,7 @@ pub unsafe fn _mm256_store_pd(mem_addr: *mut f64, a: __m256d) {
 #[stable(feature = "simd_x86", since = "1.27.0")]
 #[allow(clippy::cast_ptr_alignment)]
 pub unsafe fn _mm256_load_ps(mem_addr: *const f32) -> __m256 {
+    debug_assert_aligned!(mem_addr, 32);
     *(mem_addr as *const __m256)
 }
 
@@ -1407,6 +1410,7 @@ pub unsafe fn _mm256_load_ps(mem_addr: *const f32) -> __m256 {
 #[stable(feature = "simd_x86", since = "1.27.0")]
 #[allow(clippy::cast_ptr_alignment)]
 pub unsafe fn _mm256_store_ps(mem_addr: *mut f32, a: __m256) {
+    debug_assert_aligned!(mem_addr, 32);
     *(mem_addr as *mut __m256) = a;
 }
 
@@ -1484,6 +1488,7 @@ pub unsafe fn _mm256_storeu_ps(mem_addr: *mut f32, a: __m256) {
 #[cfg_attr(test, assert_instr(vmovaps))] // FIXME vmovdqa expected
 #[stable(feature = "simd_x86", since = "1.27.0")]
 pub unsafe fn _mm256_load_si256(mem_addr: *const __m256i) -> __m256i {
+    debug_assert_aligned!(mem_addr, 32);
     *mem_addr
 }
 
@@ -1497,6 +1502,7 @@ pub unsafe fn _mm256_load_si256(mem_addr: *const __m256i) -> __m256i {
 #[cfg_attr(test, assert_instr(vmovaps))] // FIXME vmovdqa expected
 #[stable(feature = "simd_x86", since = "1.27.0")]
 pub unsafe fn _mm256_store_si256(mem_addr: *mut __m256i, a: __m256i) {
+    debug_assert_aligned!(mem_addr, 32);
     *mem_addr = a;
 }
 
@@ -1689,6 +1695,7 @@ pub unsafe fn _mm256_lddqu_si256(mem_addr: *const __m256i) -> __m256i {
 #[cfg_attr(test, assert_instr(vmovntps))] // FIXME vmovntdq
 #[stable(feature = "simd_x86", since = "1.27.0")]
 pub unsafe fn _mm256_stream_si256(mem_addr: *mut __m256i, a: __m256i) {
+    debug_assert_aligned!(mem_addr, 32);
     intrinsics::nontemporal_store(mem_addr, a);
 }
 
@@ -1703,6 +1710,7 @@ pub unsafe fn _mm256_stream_si256(mem_addr: *mut __m256i, a: __m256i) {
 #[stable(feature = "simd_x86", since = "1.27.0")]
 #[allow(clippy::cast_ptr_alignment)]
 pub unsafe fn _mm256_stream_pd(mem_addr: *mut f64, a: __m256d) {
+    debug_assert_aligned!(mem_addr, 32);
     intrinsics::nontemporal_store(mem_addr as *mut __m256d, a);
 }
 
@@ -1718,6 +1726,7 @@ pub unsafe fn _mm256_stream_pd(mem_addr: *mut f64, a: __m256d) {
 #[stable(feature = "simd_x86", since = "1.27.0")]
 #[allow(clippy::cast_ptr_alignment)]
 pub unsafe fn _mm256_stream_ps(mem_addr: *mut f32, a: __m256) {
+    debug_assert_aligned!(mem_addr, 32);
     intrinsics::nontemporal_store(mem_addr as *mut __m256, a);
 }
 
@@ -2079,7 +2088,8 @@ pub unsafe fn _mm256_movemask_ps(a: __m256) -> i32 {
 #[target_feature(enable = "avx")]
 #[cfg_attr(test, assert_instr(vxorps))] // FIXME vxorpd expected
 #[stable(feature = "simd_x86", since = "1.27.0")]
-pub unsafe fn _mm256_setzero_pd() -> __m256d {
+#[rustc_const_unstable(feature = "stdarch_const_x86", issue = "none")]
+pub const unsafe fn _mm256_setzero_pd() -> __m256d {
     _mm256_set1_pd(0.0)
 }
 
@@ -2090,7 +2100,8 @@ pub unsafe fn _mm256_setzero_pd() -> __m256d {
 #[target_feature(enable = "avx")]
 #[cfg_attr(test, assert_instr(vxorps))]
 #[stable(feature = "simd_x86", since = "1.27.0")]
-pub unsafe fn _mm256_setzero_ps() -> __m256 {
+#[rustc_const_unstable(feature = "stdarch_const_x86", issue = "none")]
+pub const unsafe fn _mm256_setzero_ps() -> __m256 {
     _mm256_set1_ps(0.0)
 }
 
@@ -2101,7 +2112,8 @@ pub unsafe fn _mm256_setzero_ps() -> __m256 {
 #[target_feature(enable = "avx")]
 #[cfg_attr(test, assert_instr(vxor))]
 #[stable(feature = "simd_x86", since = "1.27.0")]
-pub unsafe fn _mm256_setzero_si256() -> __m256i {
+#[rustc_const_unstable(feature = "stdarch_const_x86", issue = "none")]
+pub const unsafe fn _mm256_setzero_si256() -> __m256i {
     _mm256_set1_epi8(0)
 }
 
@@ -2114,7 +2126,8 @@ pub unsafe fn _mm256_setzero_si256() -> __m256i {
 // This intrinsic has no corresponding instruction.
 #[cfg_attr(test, assert_instr(vinsertf128))]
 #[stable(feature = "simd_x86", since = "1.27.0")]
-pub unsafe fn _mm256_set_pd(a: f64, b: f64, c: f64, d: f64) -> __m256d {
+#[rustc_const_unstable(feature = "stdarch_const_x86", issue = "none")]
+pub const unsafe fn _mm256_set_pd(a: f64, b: f64, c: f64, d: f64) -> __m256d {
     _mm256_setr_pd(d, c, b, a)
 }
 
@@ -2126,7 +2139,8 @@ pub unsafe fn _mm256_set_pd(a: f64, b: f64, c: f64, d: f64) -> __m256d {
 #[target_feature(enable = "avx")]
 // This intrinsic has no corresponding instruction.
 #[stable(feature = "simd_x86", since = "1.27.0")]
-pub unsafe fn _mm256_set_ps(
+#[rustc_const_unstable(feature = "stdarch_const_x86", issue = "none")]
+pub const unsafe fn _mm256_set_ps(
     a: f32,
     b: f32,
     c: f32,
@@ -2147,7 +2161,8 @@ pub unsafe fn _mm256_set_ps(
 #[target_feature(enable = "avx")]
 // This intrinsic has no corresponding instruction.
 #[stable(feature = "simd_x86", since = "1.27.0")]
-pub unsafe fn _mm256_set_epi8(
+#[rustc_const_unstable(feature = "stdarch_const_x86", issue = "none")]
+pub const unsafe fn _mm256_set_epi8(
     e00: i8,
     e01: i8,
     e02: i8,
@@ -2197,7 +2212,8 @@ pub unsafe fn _mm256_set_epi8(
 #[target_feature(enable = "avx")]
 // This intrinsic has no corresponding instruction.
 #[stable(feature = "simd_x86", since = "1.27.0")]
-pub unsafe fn _mm256_set_epi16(
+#[rustc_const_unstable(feature = "stdarch_const_x86", issue = "none")]
+pub const unsafe fn _mm256_set_epi16(
     e00: i16,
     e01: i16,
     e02: i16,
@@ -2231,7 +2247,8 @@ pub unsafe fn _mm256_set_epi16(
 #[target_feature(enable = "avx")]
 // This intrinsic has no corresponding instruction.
 #[stable(feature = "simd_x86", since = "1.27.0")]
-pub unsafe fn _mm256_set_epi32(
+#[rustc_const_unstable(feature = "stdarch_const_x86", issue = "none")]
+pub const unsafe fn _mm256_set_epi32(
     e0: i32,
     e1: i32,
     e2: i32,
@@ -2251,7 +2268,8 @@ pub unsafe fn _mm256_set_epi32(
 #[target_feature(enable = "avx")]
 // This intrinsic has no corresponding instruction.
 #[stable(feature = "simd_x86", since = "1.27.0")]
-pub unsafe fn _mm256_set_epi64x(a: i64, b: i64, c: i64, d: i64) -> __m256i {
+#[rustc_const_unstable(feature = "stdarch_const_x86", issue = "none")]
+pub const unsafe fn _mm256_set_epi64x(a: i64, b: i64, c: i64, d: i64) -> __m256i {
     _mm256_setr_epi64x(d, c, b, a)
 }
 
@@ -2263,7 +2281,8 @@ pub unsafe fn _mm256_set_epi64x(a: i64, b: i64, c: i64, d: i64) -> __m256i {
 #[target_feature(enable = "avx")]
 // This intrinsic has no corresponding instruction.
 #[stable(feature = "simd_x86", since = "1.27.0")]
-pub unsafe fn _mm256_setr_pd(a: f64, b: f64, c: f64, d: f64) -> __m256d {
+#[rustc_const_unstable(feature = "stdarch_const_x86", issue = "none")]
+pub const unsafe fn _mm256_setr_pd(a: f64, b: f64, c: f64, d: f64) -> __m256d {
     __m256d(a, b, c, d)
 }
 
@@ -2275,7 +2294,8 @@ pub unsafe fn _mm256_setr_pd(a: f64, b: f64, c: f64, d: f64) -> __m256d {
 #[target_feature(enable = "avx")]
 // This intrinsic has no corresponding instruction.
 #[stable(feature = "simd_x86", since = "1.27.0")]
-pub unsafe fn _mm256_setr_ps(
+#[rustc_const_unstable(feature = "stdarch_const_x86", issue = "none")]
+pub const unsafe fn _mm256_setr_ps(
     a: f32,
     b: f32,
     c: f32,
@@ -2296,7 +2316,8 @@ pub unsafe fn _mm256_setr_ps(
 #[target_feature(enable = "avx")]
 // This intrinsic has no corresponding instruction.
 #[stable(feature = "simd_x86", since = "1.27.0")]
-pub unsafe fn _mm256_setr_epi8(
+#[rustc_const_unstable(feature = "stdarch_const_x86", issue = "none")]
+pub const unsafe fn _mm256_setr_epi8(
     e00: i8,
     e01: i8,
     e02: i8,
@@ -2347,7 +2368,8 @@ pub unsafe fn _mm256_setr_epi8(
 #[target_feature(enable = "avx")]
 // This intrinsic has no corresponding instruction.
 #[stable(feature = "simd_x86", since = "1.27.0")]
-pub unsafe fn _mm256_setr_epi16(
+#[rustc_const_unstable(feature = "stdarch_const_x86", issue = "none")]
+pub const unsafe fn _mm256_setr_epi16(
     e00: i16,
     e01: i16,
     e02: i16,
@@ -2382,7 +2404,8 @@ pub unsafe fn _mm256_setr_epi16(
 #[target_feature(enable = "avx")]
 // This intrinsic has no corresponding instruction.
 #[stable(feature = "simd_x86", since = "1.27.0")]
-pub unsafe fn _mm256_setr_epi32(
+#[rustc_const_unstable(feature = "stdarch_const_x86", issue = "none")]
+pub const unsafe fn _mm256_setr_epi32(
     e0: i32,
     e1: i32,
     e2: i32,
@@ -2403,7 +2426,8 @@ pub unsafe fn _mm256_setr_epi32(
 #[target_feature(enable = "avx")]
 // This intrinsic has no corresponding instruction.
 #[stable(feature = "simd_x86", since = "1.27.0")]
-pub unsafe fn _mm256_setr_epi64x(a: i64, b: i64, c: i64, d: i64) -> __m256i {
+#[rustc_const_unstable(feature = "stdarch_const_x86", issue = "none")]
+pub const unsafe fn _mm256_setr_epi64x(a: i64, b: i64, c: i64, d: i64) -> __m256i {
     transmute(i64x4::new(a, b, c, d))
 }
 
@@ -2415,7 +2439,8 @@ pub unsafe fn _mm256_setr_epi64x(a: i64, b: i64, c: i64, d: i64) -> __m256i {
 #[target_feature(enable = "avx")]
 // This intrinsic has no corresponding instruction.
 #[stable(feature = "simd_x86", since = "1.27.0")]
-pub unsafe fn _mm256_set1_pd(a: f64) -> __m256d {
+#[rustc_const_unstable(feature = "stdarch_const_x86", issue = "none")]
+pub const unsafe fn _mm256_set1_pd(a: f64) -> __m256d {
     _mm256_setr_pd(a, a, a, a)
 }
 
@@ -2427,7 +2452,8 @@ pub unsafe fn _mm256_set1_pd(a: f64) -> __m256d {
 #[target_feature(enable = "avx")]
 // This intrinsic has no corresponding instruction.
 #[stable(feature = "simd_x86", since = "1.27.0")]
-pub unsafe fn _mm256_set1_ps(a: f32) -> __m256 {
+#[rustc_const_unstable(feature = "stdarch_const_x86", issue = "none")]
+pub const unsafe fn _mm256_set1_ps(a: f32) -> __m256 {
     _mm256_setr_ps(a, a, a, a, a, a, a, a)
 }
 
@@ -2441,7 +2467,8 @@ pub unsafe fn _mm256_set1_ps(a: f32) -> __m256 {
 #[cfg_attr(test, assert_instr(vinsertf128))]
 // This intrinsic has no corresponding instruction.
 #[stable(feature = "simd_x86", since = "1.27.0")]
-pub unsafe fn _mm256_set1_epi8(a: i8) -> __m256i {
+#[rustc_const_unstable(feature = "stdarch_const_x86", issue = "none")]
+pub const unsafe fn _mm256_set1_epi8(a: i8) -> __m256i {
     #[rustfmt::skip]
     _mm256_setr_epi8(
         a, a, a, a, a, a, a, a,
@@ -2461,7 +2488,8 @@ pub unsafe fn _mm256_set1_epi8(a: i8) -> __m256i {
 #[cfg_attr(test, assert_instr(vinsertf128))]
 // This intrinsic has no corresponding instruction.
 #[stable(feature = "simd_x86", since = "1.27.0")]
-pub unsafe fn _mm256_set1_epi16(a: i16) -> __m256i {
+#[rustc_const_unstable(feature = "stdarch_const_x86", issue = "none")]
+pub const unsafe fn _mm256_set1_epi16(a: i16) -> __m256i {
     _mm256_setr_epi16(a, a, a, a, a, a, a, a, a, a, a, a, a, a, a, a)
 }
 
@@ -2473,7 +2501,8 @@ pub unsafe fn _mm256_set1_epi16(a: i16) -> __m256i {
 #[target_feature(enable = "avx")]
 // This intrinsic has no corresponding instruction.
 #[stable(feature = "simd_x86", since = "1.27.0")]
-pub unsafe fn _mm256_set1_epi32(a: i32) -> __m256i {
+#[rustc_const_unstable(feature = "stdarch_const_x86", issue = "none")]
+pub const unsafe fn _mm256_set1_epi32(a: i32) -> __m256i {
     _mm256_setr_epi32(a, a, a, a, a, a, a, a)
 }
 
@@ -2487,7 +2516,8 @@ pub unsafe fn _mm256_set1_epi32(a: i32) -> __m256i {
 #[cfg_attr(all(test, target_arch = "x86"), assert_instr(vbroadcastsd))]
 // This intrinsic has no corresponding instruction.
 #[stable(feature = "simd_x86", since = "1.27.0")]
-pub unsafe fn _mm256_set1_epi64x(a: i64) -> __m256i {
+#[rustc_const_unstable(feature = "stdarch_const_x86", issue = "none")]
+pub const unsafe fn _mm256_set1_epi64x(a: i64) -> __m256i {
     _mm256_setr_epi64x(a, a, a, a)
 }
 
@@ -3959,6 +3989,35 @@ mod tests {
         assert_eq_m256i(r, e);
     }
 
+    #[cfg(debug_assertions)]
+    #[simd_test(enable = "avx")]
+    unsafe fn test_mm256_load_store_si256_misaligned() {
+        use std::panic::catch_unwind;
+
+        let mut vals = [0u8; 64];
+        let mut p = vals.as_mut_ptr();
+        if (p as usize) & 0x1f == 0 {
+            p = p.add(16);
+        }
+        let p = p as *mut __m256i;
+        assert!(catch_unwind(|| {
+            _mm256_load_si256(p);
+        })
+        .is_err());
+        assert!(catch_unwind(|| _mm256_store_si256(p, _mm256_set1_epi8(1))).is_err());
+        assert!(catch_unwind(|| _mm256_stream_si256(p, _mm256_set1_epi8(1))).is_err());
+        assert!(catch_unwind(|| {
+            _mm256_load_pd(p as *const f64);
+        })
+        .is_err());
+        assert_eq!(vals, [0; 64]);
+
+        let a = _mm256_setr_epi64x(1, 2, 3, 4);
+        let mut r = _mm256_setzero_si256();
+        _mm256_store_si256(&mut r, a);
+        assert_eq_m256i(_mm256_load_si256(&r), a);
+    }
+
     #[simd_test(enable = "avx")]
     unsafe fn test_mm256_store_si256() {
         let a = _mm256_setr_epi64x(1, 2, 3, 4);
@@ -4564,6 +4623,23 @@ mod tests {
         assert_eq_m256i(r, _mm256_set1_epi64x(1));
     }
 
+    #[simd_test(enable = "avx")]
+    unsafe fn test_mm256_set_const() {
+        const A: __m256i = unsafe { _mm256_set_epi32(7, 6, 5, 4, 3, 2, 1, 0) };
+        static B: __m256i =
+            unsafe { _mm256_setr_epi64x(1 << 32, 3 << 32 | 2, 5 << 32 | 4, 7 << 32 | 6) };
+        const C: __m256i = unsafe { _mm256_set1_epi16(0x0101) };
+        static D: __m256i = unsafe { _mm256_set1_epi8(1) };
+        const E: __m256 = unsafe { _mm256_set_ps(8., 7., 6., 5., 4., 3., 2., 1.) };
+        const F: __m256d = unsafe { _mm256_setr_pd(1., 2., 3., 4.) };
+        const ZERO: __m256i = unsafe { _mm256_setzero_si256() };
+        assert_eq_m256i(A, B);
+        assert_eq_m256i(C, D);
+        assert_eq_m256(E, _mm256_setr_ps(1., 2., 3., 4., 5., 6., 7., 8.));
+        assert_eq_m256d(F, _mm256_set_pd(4., 3., 2., 1.));
+        assert_eq_m256i(ZERO, _mm256_set1_epi64x(0));
+    }
+
     #[simd_test(enable = "avx")]
     unsafe fn test_mm256_castpd_ps() {
         let a = _mm256_setr_pd(1., 2., 3., 4.);
@@ -4649,6 +4725,13 @@ mod tests {
         let r = _mm256_zextps128_ps256(a);
         let e = _mm256_setr_ps(1., 2., 3., 4., 0., 0., 0., 0.);
         assert_eq_m256(r, e);
+        // The upper lanes must be zeroed even if `a` was taken out of a
+        // wider register that still holds other data there. `black_box`
+        // keeps the compiler from folding the wider vector into a constant.
+        let a = _mm256_castps256_ps128(black_box(_mm256_set1_ps(-1.)));
+        let r = _mm256_zextps128_ps256(a);
+        let e = _mm256_setr_ps(-1., -1., -1., -1., 0., 0., 0., 0.);
+        assert_eq_m256(r, e);
     }
 
     #[simd_test(enable = "avx")]
@@ -4657,6 +4740,10 @@ mod tests {
         let r = _mm256_zextsi128_si256(a);
         let e = _mm256_setr_epi64x(1, 2, 0, 0);
         assert_eq_m256i(r, e);
+        let a = _mm256_castsi256_si128(black_box(_mm256_set1_epi64x(-1)));
+        let r = _mm256_zextsi128_si256(a);
+        let e = _mm256_setr_epi64x(-1, -1, 0, 0);
+        assert_eq_m256i(r, e);
     }
 
     #[simd_test(enable = "avx")]
@@ -4665,6 +4752,10 @@ mod tests {
         let r = _mm256_zextpd128_pd256(a);
         let e = _mm256_setr_pd(1., 2., 0., 0.);
         assert_eq_m256d(r, e);
+        let a = _mm256_castpd256_pd128(black_box(_mm256_set1_pd(-1.)));
+        let r = _mm256_zextpd128_pd256(a);
+        let e = _mm256_setr_pd(-1., -1., 0., 0.);
+        assert_eq_m256d(r, e);
     }
 
     #[simd_test(enable = "avx")]
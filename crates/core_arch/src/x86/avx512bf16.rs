@@ -152,6 +152,66 @@ pub unsafe fn _mm512_maskz_cvtne2ps_pbh(k: __mmask32, a: __m512, b: __m512) -> _
     transmute(simd_select_bitmask(k, cvt, zero))
 }
 
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed BF16 (16-bit)
+/// floating-point elements, and store the results in the lower half of dst. The upper half of
+/// dst is zeroed.
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#expand=1769,1651,1654,1657,1660&avx512techs=AVX512_BF16&text=_mm_cvtneps_pbh)
+#[inline]
+#[target_feature(enable = "avx512bf16,avx512vl")]
+#[cfg_attr(test, assert_instr("vcvtneps2bf16"))]
+pub unsafe fn _mm_cvtneps_pbh(a: __m128) -> __m128bh {
+    // LLVM only exposes the 128-bit form as a masked intrinsic taking a
+    // `<4 x i1>` mask, which can't be expressed here, so use inline assembly.
+    let dst: __m128bh;
+    asm!(
+        "vcvtneps2bf16 {dst}, {src}",
+        dst = lateout(xmm_reg) dst,
+        src = in(xmm_reg) a,
+        options(pure, nomem, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed BF16 (16-bit)
+/// floating-point elements, and store the results in the lower half of dst using writemask k
+/// (elements are copied from src when the corresponding mask bit is not set). The upper half of
+/// dst is zeroed.
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#expand=1769,1651,1654,1657,1660&avx512techs=AVX512_BF16&text=_mm_mask_cvtneps_pbh)
+#[inline]
+#[target_feature(enable = "avx512bf16,avx512vl")]
+#[cfg_attr(test, assert_instr("vcvtneps2bf16"))]
+pub unsafe fn _mm_mask_cvtneps_pbh(src: __m128bh, k: __mmask8, a: __m128) -> __m128bh {
+    let mut dst = src;
+    asm!(
+        "vcvtneps2bf16 {dst}{{{k}}}, {src}",
+        dst = inlateout(xmm_reg) dst,
+        src = in(xmm_reg) a,
+        k = in(kreg) k,
+        options(pure, nomem, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed BF16 (16-bit)
+/// floating-point elements, and store the results in the lower half of dst using zeromask k
+/// (elements are zeroed out when the corresponding mask bit is not set). The upper half of dst
+/// is zeroed.
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#expand=1769,1651,1654,1657,1660&avx512techs=AVX512_BF16&text=_mm_maskz_cvtneps_pbh)
+#[inline]
+#[target_feature(enable = "avx512bf16,avx512vl")]
+#[cfg_attr(test, assert_instr("vcvtneps2bf16"))]
+pub unsafe fn _mm_maskz_cvtneps_pbh(k: __mmask8, a: __m128) -> __m128bh {
+    let dst: __m128bh;
+    asm!(
+        "vcvtneps2bf16 {dst}{{{k}}}{{z}}, {src}",
+        dst = lateout(xmm_reg) dst,
+        src = in(xmm_reg) a,
+        k = in(kreg) k,
+        options(pure, nomem, nostack, preserves_flags)
+    );
+    dst
+}
+
 /// Convert packed single-precision (32-bit) floating-point elements in a to packed BF16 (16-bit)
 /// floating-point elements, and store the results in dst.
 /// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#expand=1769,1651,1654,1657,1660&avx512techs=AVX512_BF16&text=_mm256_cvtneps_pbh)
@@ -968,6 +1028,77 @@ mod tests {
         assert_eq!(result, expected_result);
     }
 
+    /// Scalar reference for the round-to-nearest-even conversion performed by
+    /// `vcvtneps2bf16` on non-NaN inputs.
+    fn f32_to_bf16(x: f32) -> u16 {
+        let bits = x.to_bits();
+        let lsb = (bits >> 16) & 1;
+        (bits.wrapping_add(0x7fff + lsb) >> 16) as u16
+    }
+
+    #[simd_test(enable = "avx512bf16,avx512vl")]
+    unsafe fn test_mm_cvtneps_pbh() {
+        // Values just below, exactly at and just above the halfway point
+        // between two adjacent BF16 values, with both even and odd lower
+        // halves.
+        let a_array = [
+            f32::from_bits(0x3f80_8000),
+            f32::from_bits(0x3f81_8000),
+            f32::from_bits(0x3f80_8001),
+            f32::from_bits(0xbf80_7fff),
+        ];
+        let a: __m128 = transmute(a_array);
+        let c: __m128bh = _mm_cvtneps_pbh(a);
+        let result: [u16; 8] = transmute(c.as_u16x8());
+        let expected_result: [u16; 8] = [0x3f80, 0x3f82, 0x3f81, 0xbf80, 0, 0, 0, 0];
+        assert_eq!(result, expected_result);
+        for i in 0..4 {
+            assert_eq!(result[i], f32_to_bf16(a_array[i]));
+        }
+    }
+
+    #[simd_test(enable = "avx512bf16,avx512vl")]
+    unsafe fn test_mm_mask_cvtneps_pbh() {
+        let a_array = [178.125_f32, 10.5_f32, 3.75_f32, 50.25_f32];
+        let src_array: [u16; 8] = [1, 2, 3, 4, 5, 6, 7, 8];
+        let a: __m128 = transmute(a_array);
+        let src: __m128bh = transmute(src_array);
+        let c: __m128bh = _mm_mask_cvtneps_pbh(src, 0b0000_0101, a);
+        let result: [u16; 8] = transmute(c.as_u16x8());
+        #[rustfmt::skip]
+        let expected_result: [u16; 8] = [
+            0b0_10000110_0110010,
+            2,
+            0b0_10000000_1110000,
+            4,
+            0,
+            0,
+            0,
+            0,
+        ];
+        assert_eq!(result, expected_result);
+    }
+
+    #[simd_test(enable = "avx512bf16,avx512vl")]
+    unsafe fn test_mm_maskz_cvtneps_pbh() {
+        let a_array = [178.125_f32, 10.5_f32, 3.75_f32, 50.25_f32];
+        let a: __m128 = transmute(a_array);
+        let c: __m128bh = _mm_maskz_cvtneps_pbh(0b0000_1010, a);
+        let result: [u16; 8] = transmute(c.as_u16x8());
+        #[rustfmt::skip]
+        let expected_result: [u16; 8] = [
+            0,
+            0b0_10000010_0101000,
+            0,
+            0b0_10000100_1001001,
+            0,
+            0,
+            0,
+            0,
+        ];
+        assert_eq!(result, expected_result);
+    }
+
     #[simd_test(enable = "avx512bf16,avx512vl")]
     unsafe fn test_mm256_cvtneps_pbh() {
         #[rustfmt::skip]
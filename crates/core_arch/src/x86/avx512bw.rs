@@ -4227,6 +4227,354 @@ pub unsafe fn _mm_storeu_epi8(mem_addr: *mut i8, a: __m128i) {
     ptr::write_unaligned(mem_addr as *mut __m128i, a);
 }
 
+/// Load packed 16-bit integers from memory into dst using writemask k (elements are copied from src when the corresponding mask bit is not set). mem_addr does not need to be aligned on any particular boundary.
+///
+/// Elements whose mask bit is not set are not read, so they do not need to be valid memory.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_loadu_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vmovdqu16))]
+pub unsafe fn _mm512_mask_loadu_epi16(src: __m512i, k: __mmask32, mem_addr: *const i16) -> __m512i {
+    let mut dst: __m512i = src;
+    asm!(
+        "vmovdqu16 {dst}{{{k}}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = inout(zmm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Load packed 16-bit integers from memory into dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set). mem_addr does not need to be aligned on any particular boundary.
+///
+/// Elements whose mask bit is not set are not read, so they do not need to be valid memory.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_loadu_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vmovdqu16))]
+pub unsafe fn _mm512_maskz_loadu_epi16(k: __mmask32, mem_addr: *const i16) -> __m512i {
+    let dst: __m512i;
+    asm!(
+        "vmovdqu16 {dst}{{{k}}}{{z}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = out(zmm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Store packed 16-bit integers from a into memory using writemask k. mem_addr does not need to be aligned on any particular boundary.
+///
+/// Elements whose mask bit is not set are not written, so they do not need to be valid memory.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_storeu_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vmovdqu16))]
+pub unsafe fn _mm512_mask_storeu_epi16(mem_addr: *mut i16, k: __mmask32, a: __m512i) {
+    asm!(
+        "vmovdqu16 [{p}]{{{k}}}, {a}",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        a = in(zmm_reg) a,
+        options(nostack, preserves_flags)
+    );
+}
+
+/// Load packed 16-bit integers from memory into dst using writemask k (elements are copied from src when the corresponding mask bit is not set). mem_addr does not need to be aligned on any particular boundary.
+///
+/// Elements whose mask bit is not set are not read, so they do not need to be valid memory.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_loadu_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vmovdqu16))]
+pub unsafe fn _mm256_mask_loadu_epi16(src: __m256i, k: __mmask16, mem_addr: *const i16) -> __m256i {
+    let mut dst: __m256i = src;
+    asm!(
+        "vmovdqu16 {dst}{{{k}}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = inout(ymm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Load packed 16-bit integers from memory into dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set). mem_addr does not need to be aligned on any particular boundary.
+///
+/// Elements whose mask bit is not set are not read, so they do not need to be valid memory.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_loadu_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vmovdqu16))]
+pub unsafe fn _mm256_maskz_loadu_epi16(k: __mmask16, mem_addr: *const i16) -> __m256i {
+    let dst: __m256i;
+    asm!(
+        "vmovdqu16 {dst}{{{k}}}{{z}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = out(ymm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Store packed 16-bit integers from a into memory using writemask k. mem_addr does not need to be aligned on any particular boundary.
+///
+/// Elements whose mask bit is not set are not written, so they do not need to be valid memory.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_storeu_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vmovdqu16))]
+pub unsafe fn _mm256_mask_storeu_epi16(mem_addr: *mut i16, k: __mmask16, a: __m256i) {
+    asm!(
+        "vmovdqu16 [{p}]{{{k}}}, {a}",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        a = in(ymm_reg) a,
+        options(nostack, preserves_flags)
+    );
+}
+
+/// Load packed 16-bit integers from memory into dst using writemask k (elements are copied from src when the corresponding mask bit is not set). mem_addr does not need to be aligned on any particular boundary.
+///
+/// Elements whose mask bit is not set are not read, so they do not need to be valid memory.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_loadu_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vmovdqu16))]
+pub unsafe fn _mm_mask_loadu_epi16(src: __m128i, k: __mmask8, mem_addr: *const i16) -> __m128i {
+    let mut dst: __m128i = src;
+    asm!(
+        "vmovdqu16 {dst}{{{k}}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = inout(xmm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Load packed 16-bit integers from memory into dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set). mem_addr does not need to be aligned on any particular boundary.
+///
+/// Elements whose mask bit is not set are not read, so they do not need to be valid memory.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_loadu_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vmovdqu16))]
+pub unsafe fn _mm_maskz_loadu_epi16(k: __mmask8, mem_addr: *const i16) -> __m128i {
+    let dst: __m128i;
+    asm!(
+        "vmovdqu16 {dst}{{{k}}}{{z}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = out(xmm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Store packed 16-bit integers from a into memory using writemask k. mem_addr does not need to be aligned on any particular boundary.
+///
+/// Elements whose mask bit is not set are not written, so they do not need to be valid memory.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_storeu_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vmovdqu16))]
+pub unsafe fn _mm_mask_storeu_epi16(mem_addr: *mut i16, k: __mmask8, a: __m128i) {
+    asm!(
+        "vmovdqu16 [{p}]{{{k}}}, {a}",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        a = in(xmm_reg) a,
+        options(nostack, preserves_flags)
+    );
+}
+
+/// Load packed 8-bit integers from memory into dst using writemask k (elements are copied from src when the corresponding mask bit is not set). mem_addr does not need to be aligned on any particular boundary.
+///
+/// Elements whose mask bit is not set are not read, so they do not need to be valid memory.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_loadu_epi8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vmovdqu8))]
+pub unsafe fn _mm512_mask_loadu_epi8(src: __m512i, k: __mmask64, mem_addr: *const i8) -> __m512i {
+    let mut dst: __m512i = src;
+    asm!(
+        "vmovdqu8 {dst}{{{k}}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = inout(zmm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Load packed 8-bit integers from memory into dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set). mem_addr does not need to be aligned on any particular boundary.
+///
+/// Elements whose mask bit is not set are not read, so they do not need to be valid memory.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_loadu_epi8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vmovdqu8))]
+pub unsafe fn _mm512_maskz_loadu_epi8(k: __mmask64, mem_addr: *const i8) -> __m512i {
+    let dst: __m512i;
+    asm!(
+        "vmovdqu8 {dst}{{{k}}}{{z}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = out(zmm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Store packed 8-bit integers from a into memory using writemask k. mem_addr does not need to be aligned on any particular boundary.
+///
+/// Elements whose mask bit is not set are not written, so they do not need to be valid memory.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_storeu_epi8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vmovdqu8))]
+pub unsafe fn _mm512_mask_storeu_epi8(mem_addr: *mut i8, k: __mmask64, a: __m512i) {
+    asm!(
+        "vmovdqu8 [{p}]{{{k}}}, {a}",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        a = in(zmm_reg) a,
+        options(nostack, preserves_flags)
+    );
+}
+
+/// Load packed 8-bit integers from memory into dst using writemask k (elements are copied from src when the corresponding mask bit is not set). mem_addr does not need to be aligned on any particular boundary.
+///
+/// Elements whose mask bit is not set are not read, so they do not need to be valid memory.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_loadu_epi8)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vmovdqu8))]
+pub unsafe fn _mm256_mask_loadu_epi8(src: __m256i, k: __mmask32, mem_addr: *const i8) -> __m256i {
+    let mut dst: __m256i = src;
+    asm!(
+        "vmovdqu8 {dst}{{{k}}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = inout(ymm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Load packed 8-bit integers from memory into dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set). mem_addr does not need to be aligned on any particular boundary.
+///
+/// Elements whose mask bit is not set are not read, so they do not need to be valid memory.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_loadu_epi8)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vmovdqu8))]
+pub unsafe fn _mm256_maskz_loadu_epi8(k: __mmask32, mem_addr: *const i8) -> __m256i {
+    let dst: __m256i;
+    asm!(
+        "vmovdqu8 {dst}{{{k}}}{{z}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = out(ymm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Store packed 8-bit integers from a into memory using writemask k. mem_addr does not need to be aligned on any particular boundary.
+///
+/// Elements whose mask bit is not set are not written, so they do not need to be valid memory.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_storeu_epi8)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vmovdqu8))]
+pub unsafe fn _mm256_mask_storeu_epi8(mem_addr: *mut i8, k: __mmask32, a: __m256i) {
+    asm!(
+        "vmovdqu8 [{p}]{{{k}}}, {a}",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        a = in(ymm_reg) a,
+        options(nostack, preserves_flags)
+    );
+}
+
+/// Load packed 8-bit integers from memory into dst using writemask k (elements are copied from src when the corresponding mask bit is not set). mem_addr does not need to be aligned on any particular boundary.
+///
+/// Elements whose mask bit is not set are not read, so they do not need to be valid memory.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_loadu_epi8)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vmovdqu8))]
+pub unsafe fn _mm_mask_loadu_epi8(src: __m128i, k: __mmask16, mem_addr: *const i8) -> __m128i {
+    let mut dst: __m128i = src;
+    asm!(
+        "vmovdqu8 {dst}{{{k}}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = inout(xmm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Load packed 8-bit integers from memory into dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set). mem_addr does not need to be aligned on any particular boundary.
+///
+/// Elements whose mask bit is not set are not read, so they do not need to be valid memory.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_loadu_epi8)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vmovdqu8))]
+pub unsafe fn _mm_maskz_loadu_epi8(k: __mmask16, mem_addr: *const i8) -> __m128i {
+    let dst: __m128i;
+    asm!(
+        "vmovdqu8 {dst}{{{k}}}{{z}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = out(xmm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Store packed 8-bit integers from a into memory using writemask k. mem_addr does not need to be aligned on any particular boundary.
+///
+/// Elements whose mask bit is not set are not written, so they do not need to be valid memory.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_storeu_epi8)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vmovdqu8))]
+pub unsafe fn _mm_mask_storeu_epi8(mem_addr: *mut i8, k: __mmask16, a: __m128i) {
+    asm!(
+        "vmovdqu8 [{p}]{{{k}}}, {a}",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        a = in(xmm_reg) a,
+        options(nostack, preserves_flags)
+    );
+}
+
 /// Multiply packed signed 16-bit integers in a and b, producing intermediate signed 32-bit integers. Horizontally add adjacent pairs of intermediate 32-bit integers, and pack the results in dst.
 ///
 /// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_madd_epi16&expand=3511)
@@ -7828,6 +8176,40 @@ pub unsafe fn _load_mask32(mem_addr: *const u32) -> __mmask32 {
     ptr::read(mem_addr as *const __mmask32)
 }
 
+/// Convert 32-bit mask a into an integer value, and store the result in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_cvtmask32_u32)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(kmovd))]
+pub unsafe fn _cvtmask32_u32(a: __mmask32) -> u32 {
+    let r: u32;
+    asm!(
+        "kmovd {r:e}, {a}",
+        a = in(kreg) a,
+        r = lateout(reg) r,
+        options(pure, nomem, nostack, preserves_flags)
+    );
+    r
+}
+
+/// Convert integer value a into a 32-bit mask, and store the result in k.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_cvtu32_mask32)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(kmovd))]
+pub unsafe fn _cvtu32_mask32(a: u32) -> __mmask32 {
+    let r: __mmask32;
+    asm!(
+        "kmovd {r}, {a:e}",
+        a = in(reg) a,
+        r = lateout(kreg) r,
+        options(pure, nomem, nostack, preserves_flags)
+    );
+    r
+}
+
 /// Compute the absolute differences of packed unsigned 8-bit integers in a and b, then horizontally sum each consecutive 8 differences to produce eight unsigned 16-bit integers, and pack these unsigned 16-bit integers in the low 16 bits of 64-bit elements in dst.
 ///
 /// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_sad_epu8&expand=4855)
@@ -8216,10 +8598,17 @@ pub unsafe fn _mm_movm_epi8(k: __mmask16) -> __m128i {
 /// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kadd_mask32&expand=3207)
 #[inline]
 #[target_feature(enable = "avx512bw")]
-#[cfg_attr(test, assert_instr(mov))] // generate normal and code instead of kaddd
-                                     //llvm.x86.avx512.kadd.d
+#[cfg_attr(test, assert_instr(kaddd))]
 pub unsafe fn _kadd_mask32(a: __mmask32, b: __mmask32) -> __mmask32 {
-    transmute(a + b)
+    let r: __mmask32;
+    asm!(
+        "kaddd {r}, {a}, {b}",
+        a = in(kreg) a,
+        b = in(kreg) b,
+        r = lateout(kreg) r,
+        options(pure, nomem, nostack, preserves_flags)
+    );
+    r
 }
 
 /// Add 64-bit masks in a and b, and store the result in k.
@@ -8227,9 +8616,17 @@ pub unsafe fn _kadd_mask32(a: __mmask32, b: __mmask32) -> __mmask32 {
 /// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kadd_mask64&expand=3208)
 #[inline]
 #[target_feature(enable = "avx512bw")]
-#[cfg_attr(test, assert_instr(mov))] // generate normal and code instead of kaddq
+#[cfg_attr(test, assert_instr(kaddq))]
 pub unsafe fn _kadd_mask64(a: __mmask64, b: __mmask64) -> __mmask64 {
-    transmute(a + b)
+    let r: __mmask64;
+    asm!(
+        "kaddq {r}, {a}, {b}",
+        a = in(kreg) a,
+        b = in(kreg) b,
+        r = lateout(kreg) r,
+        options(pure, nomem, nostack, preserves_flags)
+    );
+    r
 }
 
 /// Compute the bitwise AND of 32-bit masks a and b, and store the result in k.
@@ -8350,6 +8747,306 @@ pub unsafe fn _kxnor_mask64(a: __mmask64, b: __mmask64) -> __mmask64 {
     transmute(_knot_mask64(a ^ b))
 }
 
+/// Shift the bits of 32-bit mask a left by count while shifting in zeros, and store the least significant 32 bits of the result in k.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kshiftli_mask32)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(kshiftld, COUNT = 5))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _kshiftli_mask32<const COUNT: u32>(a: __mmask32) -> __mmask32 {
+    static_assert_imm_u8!(COUNT);
+    let r: __mmask32;
+    asm!(
+        "kshiftld {r}, {a}, {count}",
+        a = in(kreg) a,
+        r = lateout(kreg) r,
+        count = const COUNT,
+        options(pure, nomem, nostack, preserves_flags)
+    );
+    r
+}
+
+/// Shift the bits of 32-bit mask a right by count while shifting in zeros, and store the least significant 32 bits of the result in k.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kshiftri_mask32)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(kshiftrd, COUNT = 5))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _kshiftri_mask32<const COUNT: u32>(a: __mmask32) -> __mmask32 {
+    static_assert_imm_u8!(COUNT);
+    let r: __mmask32;
+    asm!(
+        "kshiftrd {r}, {a}, {count}",
+        a = in(kreg) a,
+        r = lateout(kreg) r,
+        count = const COUNT,
+        options(pure, nomem, nostack, preserves_flags)
+    );
+    r
+}
+
+/// Compute the bitwise OR of 32-bit masks a and b. If the result is all zeros, store 1 in dst, otherwise store 0 in dst. If the result is all ones, store 1 in all_ones, otherwise store 0 in all_ones.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kortest_mask32_u8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(kortestd))]
+pub unsafe fn _kortest_mask32_u8(a: __mmask32, b: __mmask32, all_ones: *mut u8) -> u8 {
+    *all_ones = _kortestc_mask32_u8(a, b);
+    _kortestz_mask32_u8(a, b)
+}
+
+/// Compute the bitwise OR of 32-bit masks a and b. If the result is all ones, store 1 in dst, otherwise store 0 in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kortestc_mask32_u8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(kortestd))]
+pub unsafe fn _kortestc_mask32_u8(a: __mmask32, b: __mmask32) -> u8 {
+    let r: u8;
+    asm!(
+        "kortestd {a}, {b}",
+        "setc {r}",
+        a = in(kreg) a,
+        b = in(kreg) b,
+        r = out(reg_byte) r,
+        options(pure, nomem, nostack)
+    );
+    r
+}
+
+/// Compute the bitwise OR of 32-bit masks a and b. If the result is all zeros, store 1 in dst, otherwise store 0 in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kortestz_mask32_u8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(kortestd))]
+pub unsafe fn _kortestz_mask32_u8(a: __mmask32, b: __mmask32) -> u8 {
+    let r: u8;
+    asm!(
+        "kortestd {a}, {b}",
+        "setz {r}",
+        a = in(kreg) a,
+        b = in(kreg) b,
+        r = out(reg_byte) r,
+        options(pure, nomem, nostack)
+    );
+    r
+}
+
+/// Compute the bitwise AND of 32-bit masks a and b, and if the result is all zeros, store 1 in dst, otherwise store 0 in dst. Compute the bitwise NOT of a and then AND with b, if the result is all zeros, store 1 in and_not, otherwise store 0 in and_not.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_ktest_mask32_u8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(ktestd))]
+pub unsafe fn _ktest_mask32_u8(a: __mmask32, b: __mmask32, and_not: *mut u8) -> u8 {
+    *and_not = _ktestc_mask32_u8(a, b);
+    _ktestz_mask32_u8(a, b)
+}
+
+/// Compute the bitwise NOT of 32-bit mask a and then AND with b, if the result is all zeros, store 1 in dst, otherwise store 0 in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_ktestc_mask32_u8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(ktestd))]
+pub unsafe fn _ktestc_mask32_u8(a: __mmask32, b: __mmask32) -> u8 {
+    let r: u8;
+    asm!(
+        "ktestd {a}, {b}",
+        "setc {r}",
+        a = in(kreg) a,
+        b = in(kreg) b,
+        r = out(reg_byte) r,
+        options(pure, nomem, nostack)
+    );
+    r
+}
+
+/// Compute the bitwise AND of 32-bit masks a and b, if the result is all zeros, store 1 in dst, otherwise store 0 in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_ktestz_mask32_u8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(ktestd))]
+pub unsafe fn _ktestz_mask32_u8(a: __mmask32, b: __mmask32) -> u8 {
+    let r: u8;
+    asm!(
+        "ktestd {a}, {b}",
+        "setz {r}",
+        a = in(kreg) a,
+        b = in(kreg) b,
+        r = out(reg_byte) r,
+        options(pure, nomem, nostack)
+    );
+    r
+}
+
+/// Shift the bits of 64-bit mask a left by count while shifting in zeros, and store the least significant 64 bits of the result in k.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kshiftli_mask64)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(kshiftlq, COUNT = 5))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _kshiftli_mask64<const COUNT: u32>(a: __mmask64) -> __mmask64 {
+    static_assert_imm_u8!(COUNT);
+    let r: __mmask64;
+    asm!(
+        "kshiftlq {r}, {a}, {count}",
+        a = in(kreg) a,
+        r = lateout(kreg) r,
+        count = const COUNT,
+        options(pure, nomem, nostack, preserves_flags)
+    );
+    r
+}
+
+/// Shift the bits of 64-bit mask a right by count while shifting in zeros, and store the least significant 64 bits of the result in k.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kshiftri_mask64)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(kshiftrq, COUNT = 5))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _kshiftri_mask64<const COUNT: u32>(a: __mmask64) -> __mmask64 {
+    static_assert_imm_u8!(COUNT);
+    let r: __mmask64;
+    asm!(
+        "kshiftrq {r}, {a}, {count}",
+        a = in(kreg) a,
+        r = lateout(kreg) r,
+        count = const COUNT,
+        options(pure, nomem, nostack, preserves_flags)
+    );
+    r
+}
+
+/// Compute the bitwise OR of 64-bit masks a and b. If the result is all zeros, store 1 in dst, otherwise store 0 in dst. If the result is all ones, store 1 in all_ones, otherwise store 0 in all_ones.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kortest_mask64_u8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(kortestq))]
+pub unsafe fn _kortest_mask64_u8(a: __mmask64, b: __mmask64, all_ones: *mut u8) -> u8 {
+    *all_ones = _kortestc_mask64_u8(a, b);
+    _kortestz_mask64_u8(a, b)
+}
+
+/// Compute the bitwise OR of 64-bit masks a and b. If the result is all ones, store 1 in dst, otherwise store 0 in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kortestc_mask64_u8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(kortestq))]
+pub unsafe fn _kortestc_mask64_u8(a: __mmask64, b: __mmask64) -> u8 {
+    let r: u8;
+    asm!(
+        "kortestq {a}, {b}",
+        "setc {r}",
+        a = in(kreg) a,
+        b = in(kreg) b,
+        r = out(reg_byte) r,
+        options(pure, nomem, nostack)
+    );
+    r
+}
+
+/// Compute the bitwise OR of 64-bit masks a and b. If the result is all zeros, store 1 in dst, otherwise store 0 in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kortestz_mask64_u8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(kortestq))]
+pub unsafe fn _kortestz_mask64_u8(a: __mmask64, b: __mmask64) -> u8 {
+    let r: u8;
+    asm!(
+        "kortestq {a}, {b}",
+        "setz {r}",
+        a = in(kreg) a,
+        b = in(kreg) b,
+        r = out(reg_byte) r,
+        options(pure, nomem, nostack)
+    );
+    r
+}
+
+/// Compute the bitwise AND of 64-bit masks a and b, and if the result is all zeros, store 1 in dst, otherwise store 0 in dst. Compute the bitwise NOT of a and then AND with b, if the result is all zeros, store 1 in and_not, otherwise store 0 in and_not.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_ktest_mask64_u8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(ktestq))]
+pub unsafe fn _ktest_mask64_u8(a: __mmask64, b: __mmask64, and_not: *mut u8) -> u8 {
+    *and_not = _ktestc_mask64_u8(a, b);
+    _ktestz_mask64_u8(a, b)
+}
+
+/// Compute the bitwise NOT of 64-bit mask a and then AND with b, if the result is all zeros, store 1 in dst, otherwise store 0 in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_ktestc_mask64_u8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(ktestq))]
+pub unsafe fn _ktestc_mask64_u8(a: __mmask64, b: __mmask64) -> u8 {
+    let r: u8;
+    asm!(
+        "ktestq {a}, {b}",
+        "setc {r}",
+        a = in(kreg) a,
+        b = in(kreg) b,
+        r = out(reg_byte) r,
+        options(pure, nomem, nostack)
+    );
+    r
+}
+
+/// Compute the bitwise AND of 64-bit masks a and b, if the result is all zeros, store 1 in dst, otherwise store 0 in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_ktestz_mask64_u8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(ktestq))]
+pub unsafe fn _ktestz_mask64_u8(a: __mmask64, b: __mmask64) -> u8 {
+    let r: u8;
+    asm!(
+        "ktestq {a}, {b}",
+        "setz {r}",
+        a = in(kreg) a,
+        b = in(kreg) b,
+        r = out(reg_byte) r,
+        options(pure, nomem, nostack)
+    );
+    r
+}
+
+/// Unpack and interleave 16 bits from masks a and b, and store the 32-bit result in k.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=512_kunpackw)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(mov))] // generate normal and code instead of kunpckwd
+pub unsafe fn _mm512_kunpackw(a: __mmask32, b: __mmask32) -> __mmask32 {
+    let a = (a & 0b00000000_00000000_11111111_11111111) << 16;
+    let b = b & 0b00000000_00000000_11111111_11111111;
+    a | b
+}
+
+/// Unpack and interleave 32 bits from masks a and b, and store the 64-bit result in k.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=512_kunpackd)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(mov))] // generate normal and code instead of kunpckdq
+pub unsafe fn _mm512_kunpackd(a: __mmask64, b: __mmask64) -> __mmask64 {
+    let a = (a & 0b11111111_11111111_11111111_11111111) << 32;
+    let b = b & 0b11111111_11111111_11111111_11111111;
+    a | b
+}
+
 /// Convert packed 16-bit integers in a to packed 8-bit integers with truncation, and store the results in dst.
 ///
 /// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvtepi16_epi8&expand=1407)
@@ -13813,6 +14510,354 @@ mod tests {
         assert_eq_m128i(r, a);
     }
 
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_loadu_epi16() {
+        let mut buf = [0_i16; 33];
+        for (i, x) in buf.iter_mut().enumerate() {
+            *x = i as i16;
+        }
+        let src = _mm512_set1_epi32(-1);
+        let m: __mmask32 = 0x9abc_def1;
+        // Offset by one element so that the vector is misaligned.
+        let r = _mm512_mask_loadu_epi16(src, m, black_box(buf.as_ptr().add(1)));
+        let mut e = [-1_i16; 32];
+        for i in 0..32 {
+            if m >> i & 1 != 0 {
+                e[i] = buf[i + 1];
+            }
+        }
+        assert_eq_m512i(r, _mm512_loadu_epi16(e.as_ptr()));
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_maskz_loadu_epi16() {
+        let mut buf = [0_i16; 33];
+        for (i, x) in buf.iter_mut().enumerate() {
+            *x = i as i16 + 1;
+        }
+        let m: __mmask32 = 0x9abc_def1;
+        // Offset by one element so that the vector is misaligned.
+        let r = _mm512_maskz_loadu_epi16(m, black_box(buf.as_ptr().add(1)));
+        let mut e = [0_i16; 32];
+        for i in 0..32 {
+            if m >> i & 1 != 0 {
+                e[i] = buf[i + 1];
+            }
+        }
+        assert_eq_m512i(r, _mm512_loadu_epi16(e.as_ptr()));
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_storeu_epi16() {
+        let mut v = [0_i16; 32];
+        for (i, x) in v.iter_mut().enumerate() {
+            *x = i as i16;
+        }
+        let a = _mm512_loadu_epi16(v.as_ptr());
+        let m: __mmask32 = 0x9abc_def1;
+        let mut buf = [-1_i16; 34];
+        // Offset by one element so that the vector is misaligned.
+        _mm512_mask_storeu_epi16(black_box(buf.as_mut_ptr().add(1)), m, a);
+        // Neither the unselected elements nor the bytes around the vector
+        // are touched.
+        assert_eq!(buf[0], -1);
+        assert_eq!(buf[33], -1);
+        for i in 0..32 {
+            let e = if m >> i & 1 != 0 { v[i] } else { -1 };
+            assert_eq!(buf[i + 1], e);
+        }
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_mask_loadu_epi16() {
+        let mut buf = [0_i16; 17];
+        for (i, x) in buf.iter_mut().enumerate() {
+            *x = i as i16;
+        }
+        let src = _mm256_set1_epi32(-1);
+        let m: __mmask16 = 0xdef1;
+        // Offset by one element so that the vector is misaligned.
+        let r = _mm256_mask_loadu_epi16(src, m, black_box(buf.as_ptr().add(1)));
+        let mut e = [-1_i16; 16];
+        for i in 0..16 {
+            if m >> i & 1 != 0 {
+                e[i] = buf[i + 1];
+            }
+        }
+        assert_eq_m256i(r, _mm256_loadu_epi16(e.as_ptr()));
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_maskz_loadu_epi16() {
+        let mut buf = [0_i16; 17];
+        for (i, x) in buf.iter_mut().enumerate() {
+            *x = i as i16 + 1;
+        }
+        let m: __mmask16 = 0xdef1;
+        // Offset by one element so that the vector is misaligned.
+        let r = _mm256_maskz_loadu_epi16(m, black_box(buf.as_ptr().add(1)));
+        let mut e = [0_i16; 16];
+        for i in 0..16 {
+            if m >> i & 1 != 0 {
+                e[i] = buf[i + 1];
+            }
+        }
+        assert_eq_m256i(r, _mm256_loadu_epi16(e.as_ptr()));
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_mask_storeu_epi16() {
+        let mut v = [0_i16; 16];
+        for (i, x) in v.iter_mut().enumerate() {
+            *x = i as i16;
+        }
+        let a = _mm256_loadu_epi16(v.as_ptr());
+        let m: __mmask16 = 0xdef1;
+        let mut buf = [-1_i16; 18];
+        // Offset by one element so that the vector is misaligned.
+        _mm256_mask_storeu_epi16(black_box(buf.as_mut_ptr().add(1)), m, a);
+        // Neither the unselected elements nor the bytes around the vector
+        // are touched.
+        assert_eq!(buf[0], -1);
+        assert_eq!(buf[17], -1);
+        for i in 0..16 {
+            let e = if m >> i & 1 != 0 { v[i] } else { -1 };
+            assert_eq!(buf[i + 1], e);
+        }
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_mask_loadu_epi16() {
+        let mut buf = [0_i16; 9];
+        for (i, x) in buf.iter_mut().enumerate() {
+            *x = i as i16;
+        }
+        let src = _mm_set1_epi32(-1);
+        let m: __mmask8 = 0xf1;
+        // Offset by one element so that the vector is misaligned.
+        let r = _mm_mask_loadu_epi16(src, m, black_box(buf.as_ptr().add(1)));
+        let mut e = [-1_i16; 8];
+        for i in 0..8 {
+            if m >> i & 1 != 0 {
+                e[i] = buf[i + 1];
+            }
+        }
+        assert_eq_m128i(r, _mm_loadu_epi16(e.as_ptr()));
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_maskz_loadu_epi16() {
+        let mut buf = [0_i16; 9];
+        for (i, x) in buf.iter_mut().enumerate() {
+            *x = i as i16 + 1;
+        }
+        let m: __mmask8 = 0xf1;
+        // Offset by one element so that the vector is misaligned.
+        let r = _mm_maskz_loadu_epi16(m, black_box(buf.as_ptr().add(1)));
+        let mut e = [0_i16; 8];
+        for i in 0..8 {
+            if m >> i & 1 != 0 {
+                e[i] = buf[i + 1];
+            }
+        }
+        assert_eq_m128i(r, _mm_loadu_epi16(e.as_ptr()));
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_mask_storeu_epi16() {
+        let mut v = [0_i16; 8];
+        for (i, x) in v.iter_mut().enumerate() {
+            *x = i as i16;
+        }
+        let a = _mm_loadu_epi16(v.as_ptr());
+        let m: __mmask8 = 0xf1;
+        let mut buf = [-1_i16; 10];
+        // Offset by one element so that the vector is misaligned.
+        _mm_mask_storeu_epi16(black_box(buf.as_mut_ptr().add(1)), m, a);
+        // Neither the unselected elements nor the bytes around the vector
+        // are touched.
+        assert_eq!(buf[0], -1);
+        assert_eq!(buf[9], -1);
+        for i in 0..8 {
+            let e = if m >> i & 1 != 0 { v[i] } else { -1 };
+            assert_eq!(buf[i + 1], e);
+        }
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_loadu_epi8() {
+        let mut buf = [0_i8; 65];
+        for (i, x) in buf.iter_mut().enumerate() {
+            *x = i as i8;
+        }
+        let src = _mm512_set1_epi32(-1);
+        let m: __mmask64 = 0x9234_5678_9abc_def1;
+        // Offset by one element so that the vector is misaligned.
+        let r = _mm512_mask_loadu_epi8(src, m, black_box(buf.as_ptr().add(1)));
+        let mut e = [-1_i8; 64];
+        for i in 0..64 {
+            if m >> i & 1 != 0 {
+                e[i] = buf[i + 1];
+            }
+        }
+        assert_eq_m512i(r, _mm512_loadu_epi8(e.as_ptr()));
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_maskz_loadu_epi8() {
+        let mut buf = [0_i8; 65];
+        for (i, x) in buf.iter_mut().enumerate() {
+            *x = i as i8 + 1;
+        }
+        let m: __mmask64 = 0x9234_5678_9abc_def1;
+        // Offset by one element so that the vector is misaligned.
+        let r = _mm512_maskz_loadu_epi8(m, black_box(buf.as_ptr().add(1)));
+        let mut e = [0_i8; 64];
+        for i in 0..64 {
+            if m >> i & 1 != 0 {
+                e[i] = buf[i + 1];
+            }
+        }
+        assert_eq_m512i(r, _mm512_loadu_epi8(e.as_ptr()));
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_storeu_epi8() {
+        let mut v = [0_i8; 64];
+        for (i, x) in v.iter_mut().enumerate() {
+            *x = i as i8;
+        }
+        let a = _mm512_loadu_epi8(v.as_ptr());
+        let m: __mmask64 = 0x9234_5678_9abc_def1;
+        let mut buf = [-1_i8; 66];
+        // Offset by one element so that the vector is misaligned.
+        _mm512_mask_storeu_epi8(black_box(buf.as_mut_ptr().add(1)), m, a);
+        // Neither the unselected elements nor the bytes around the vector
+        // are touched.
+        assert_eq!(buf[0], -1);
+        assert_eq!(buf[65], -1);
+        for i in 0..64 {
+            let e = if m >> i & 1 != 0 { v[i] } else { -1 };
+            assert_eq!(buf[i + 1], e);
+        }
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_mask_loadu_epi8() {
+        let mut buf = [0_i8; 33];
+        for (i, x) in buf.iter_mut().enumerate() {
+            *x = i as i8;
+        }
+        let src = _mm256_set1_epi32(-1);
+        let m: __mmask32 = 0x9abc_def1;
+        // Offset by one element so that the vector is misaligned.
+        let r = _mm256_mask_loadu_epi8(src, m, black_box(buf.as_ptr().add(1)));
+        let mut e = [-1_i8; 32];
+        for i in 0..32 {
+            if m >> i & 1 != 0 {
+                e[i] = buf[i + 1];
+            }
+        }
+        assert_eq_m256i(r, _mm256_loadu_epi8(e.as_ptr()));
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_maskz_loadu_epi8() {
+        let mut buf = [0_i8; 33];
+        for (i, x) in buf.iter_mut().enumerate() {
+            *x = i as i8 + 1;
+        }
+        let m: __mmask32 = 0x9abc_def1;
+        // Offset by one element so that the vector is misaligned.
+        let r = _mm256_maskz_loadu_epi8(m, black_box(buf.as_ptr().add(1)));
+        let mut e = [0_i8; 32];
+        for i in 0..32 {
+            if m >> i & 1 != 0 {
+                e[i] = buf[i + 1];
+            }
+        }
+        assert_eq_m256i(r, _mm256_loadu_epi8(e.as_ptr()));
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_mask_storeu_epi8() {
+        let mut v = [0_i8; 32];
+        for (i, x) in v.iter_mut().enumerate() {
+            *x = i as i8;
+        }
+        let a = _mm256_loadu_epi8(v.as_ptr());
+        let m: __mmask32 = 0x9abc_def1;
+        let mut buf = [-1_i8; 34];
+        // Offset by one element so that the vector is misaligned.
+        _mm256_mask_storeu_epi8(black_box(buf.as_mut_ptr().add(1)), m, a);
+        // Neither the unselected elements nor the bytes around the vector
+        // are touched.
+        assert_eq!(buf[0], -1);
+        assert_eq!(buf[33], -1);
+        for i in 0..32 {
+            let e = if m >> i & 1 != 0 { v[i] } else { -1 };
+            assert_eq!(buf[i + 1], e);
+        }
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_mask_loadu_epi8() {
+        let mut buf = [0_i8; 17];
+        for (i, x) in buf.iter_mut().enumerate() {
+            *x = i as i8;
+        }
+        let src = _mm_set1_epi32(-1);
+        let m: __mmask16 = 0xdef1;
+        // Offset by one element so that the vector is misaligned.
+        let r = _mm_mask_loadu_epi8(src, m, black_box(buf.as_ptr().add(1)));
+        let mut e = [-1_i8; 16];
+        for i in 0..16 {
+            if m >> i & 1 != 0 {
+                e[i] = buf[i + 1];
+            }
+        }
+        assert_eq_m128i(r, _mm_loadu_epi8(e.as_ptr()));
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_maskz_loadu_epi8() {
+        let mut buf = [0_i8; 17];
+        for (i, x) in buf.iter_mut().enumerate() {
+            *x = i as i8 + 1;
+        }
+        let m: __mmask16 = 0xdef1;
+        // Offset by one element so that the vector is misaligned.
+        let r = _mm_maskz_loadu_epi8(m, black_box(buf.as_ptr().add(1)));
+        let mut e = [0_i8; 16];
+        for i in 0..16 {
+            if m >> i & 1 != 0 {
+                e[i] = buf[i + 1];
+            }
+        }
+        assert_eq_m128i(r, _mm_loadu_epi8(e.as_ptr()));
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_mask_storeu_epi8() {
+        let mut v = [0_i8; 16];
+        for (i, x) in v.iter_mut().enumerate() {
+            *x = i as i8;
+        }
+        let a = _mm_loadu_epi8(v.as_ptr());
+        let m: __mmask16 = 0xdef1;
+        let mut buf = [-1_i8; 18];
+        // Offset by one element so that the vector is misaligned.
+        _mm_mask_storeu_epi8(black_box(buf.as_mut_ptr().add(1)), m, a);
+        // Neither the unselected elements nor the bytes around the vector
+        // are touched.
+        assert_eq!(buf[0], -1);
+        assert_eq!(buf[17], -1);
+        for i in 0..16 {
+            let e = if m >> i & 1 != 0 { v[i] } else { -1 };
+            assert_eq!(buf[i + 1], e);
+        }
+    }
+
     #[simd_test(enable = "avx512bw")]
     unsafe fn test_mm512_madd_epi16() {
         let a = _mm512_set1_epi16(1);
@@ -17082,6 +18127,23 @@ mod tests {
         assert_eq!(r, e);
     }
 
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_cvtmask32_u32() {
+        let a: __mmask32 = 0b11111111_00000000_11001100_00110011;
+        let r = _cvtmask32_u32(a);
+        let e: u32 = 0b11111111_00000000_11001100_00110011;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_cvtu32_mask32() {
+        let a: u32 = 0b11111111_00000000_11001100_00110011;
+        let r = _cvtu32_mask32(a);
+        let e: __mmask32 = 0b11111111_00000000_11001100_00110011;
+        assert_eq!(r, e);
+        assert_eq!(_cvtmask32_u32(_cvtu32_mask32(a)), a);
+    }
+
     #[simd_test(enable = "avx512bw")]
     unsafe fn test_mm512_sad_epu8() {
         let a = _mm512_set1_epi8(2);
@@ -17480,6 +18542,284 @@ mod tests {
         assert_eq!(r, e);
     }
 
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_kshiftli_mask32() {
+        let a: __mmask32 = 0b11001100_00110011_11001100_00110011;
+        assert_eq!(_kshiftli_mask32::<0>(a), a);
+        assert_eq!(
+            _kshiftli_mask32::<1>(a),
+            0b10011000_01100111_10011000_01100110
+        );
+        assert_eq!(
+            _kshiftli_mask32::<5>(a),
+            0b10000110_01111001_10000110_01100000
+        );
+        assert_eq!(
+            _kshiftli_mask32::<31>(a),
+            0b10000000_00000000_00000000_00000000
+        );
+        assert_eq!(_kshiftli_mask32::<32>(a), 0);
+        assert_eq!(_kshiftli_mask32::<255>(a), 0);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_kshiftri_mask32() {
+        let a: __mmask32 = 0b11001100_00110011_11001100_00110011;
+        assert_eq!(_kshiftri_mask32::<0>(a), a);
+        assert_eq!(
+            _kshiftri_mask32::<1>(a),
+            0b01100110_00011001_11100110_00011001
+        );
+        assert_eq!(
+            _kshiftri_mask32::<5>(a),
+            0b00000110_01100001_10011110_01100001
+        );
+        assert_eq!(
+            _kshiftri_mask32::<31>(a),
+            0b00000000_00000000_00000000_00000001
+        );
+        assert_eq!(_kshiftri_mask32::<32>(a), 0);
+        assert_eq!(_kshiftri_mask32::<255>(a), 0);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_kortest_mask32_u8() {
+        let a: __mmask32 = 0b11001100_00110011_11001100_00110011;
+        let mut all_ones = 2;
+        let r = _kortest_mask32_u8(a, 0b00110011_11001100_00110011_11001100, &mut all_ones);
+        assert_eq!((r, all_ones), (0, 1));
+        let r = _kortest_mask32_u8(a, 0b00110011_11001100_00110011_11001000, &mut all_ones);
+        assert_eq!((r, all_ones), (0, 0));
+        let r = _kortest_mask32_u8(0, 0, &mut all_ones);
+        assert_eq!((r, all_ones), (1, 0));
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_kortestc_mask32_u8() {
+        let a: __mmask32 = 0b11001100_00110011_11001100_00110011;
+        assert_eq!(
+            _kortestc_mask32_u8(a, 0b00110011_11001100_00110011_11001100),
+            1
+        );
+        assert_eq!(_kortestc_mask32_u8(a, u32::MAX), 1);
+        assert_eq!(
+            _kortestc_mask32_u8(a, 0b00110011_11001100_00110011_11001000),
+            0
+        );
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_kortestz_mask32_u8() {
+        assert_eq!(_kortestz_mask32_u8(0, 0), 1);
+        assert_eq!(_kortestz_mask32_u8(0, 1 << 31), 0);
+        assert_eq!(_kortestz_mask32_u8(1, 0), 0);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_ktest_mask32_u8() {
+        let a: __mmask32 = 0b11001100_00110011_11001100_00110011;
+        let mut and_not = 2;
+        let r = _ktest_mask32_u8(a, 0b00110011_11001100_00110011_11001100, &mut and_not);
+        assert_eq!((r, and_not), (1, 0));
+        let r = _ktest_mask32_u8(a, 0b00000000_00010001_00000000_00010001, &mut and_not);
+        assert_eq!((r, and_not), (0, 1));
+        let r = _ktest_mask32_u8(a, 0b00000000_00010001_00000000_00010101, &mut and_not);
+        assert_eq!((r, and_not), (0, 0));
+        let r = _ktest_mask32_u8(a, 0, &mut and_not);
+        assert_eq!((r, and_not), (1, 1));
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_ktestc_mask32_u8() {
+        let a: __mmask32 = 0b11001100_00110011_11001100_00110011;
+        assert_eq!(
+            _ktestc_mask32_u8(a, 0b00000000_00010001_00000000_00010001),
+            1
+        );
+        assert_eq!(_ktestc_mask32_u8(a, 4), 0);
+        assert_eq!(_ktestc_mask32_u8(u32::MAX, u32::MAX), 1);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_ktestz_mask32_u8() {
+        let a: __mmask32 = 0b11001100_00110011_11001100_00110011;
+        assert_eq!(
+            _ktestz_mask32_u8(a, 0b00110011_11001100_00110011_11001100),
+            1
+        );
+        assert_eq!(_ktestz_mask32_u8(a, 1 << 30), 0);
+        assert_eq!(_ktestz_mask32_u8(u32::MAX, u32::MAX), 0);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_kshiftli_mask64() {
+        let a: __mmask64 =
+            0b11001100_00110011_11001100_00110011_11001100_00110011_11001100_00110011;
+        assert_eq!(_kshiftli_mask64::<0>(a), a);
+        assert_eq!(
+            _kshiftli_mask64::<1>(a),
+            0b10011000_01100111_10011000_01100111_10011000_01100111_10011000_01100110
+        );
+        assert_eq!(
+            _kshiftli_mask64::<5>(a),
+            0b10000110_01111001_10000110_01111001_10000110_01111001_10000110_01100000
+        );
+        assert_eq!(
+            _kshiftli_mask64::<63>(a),
+            0b10000000_00000000_00000000_00000000_00000000_00000000_00000000_00000000
+        );
+        assert_eq!(_kshiftli_mask64::<64>(a), 0);
+        assert_eq!(_kshiftli_mask64::<255>(a), 0);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_kshiftri_mask64() {
+        let a: __mmask64 =
+            0b11001100_00110011_11001100_00110011_11001100_00110011_11001100_00110011;
+        assert_eq!(_kshiftri_mask64::<0>(a), a);
+        assert_eq!(
+            _kshiftri_mask64::<1>(a),
+            0b01100110_00011001_11100110_00011001_11100110_00011001_11100110_00011001
+        );
+        assert_eq!(
+            _kshiftri_mask64::<5>(a),
+            0b00000110_01100001_10011110_01100001_10011110_01100001_10011110_01100001
+        );
+        assert_eq!(
+            _kshiftri_mask64::<63>(a),
+            0b00000000_00000000_00000000_00000000_00000000_00000000_00000000_00000001
+        );
+        assert_eq!(_kshiftri_mask64::<64>(a), 0);
+        assert_eq!(_kshiftri_mask64::<255>(a), 0);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_kortest_mask64_u8() {
+        let a: __mmask64 =
+            0b11001100_00110011_11001100_00110011_11001100_00110011_11001100_00110011;
+        let mut all_ones = 2;
+        let r = _kortest_mask64_u8(
+            a,
+            0b00110011_11001100_00110011_11001100_00110011_11001100_00110011_11001100,
+            &mut all_ones,
+        );
+        assert_eq!((r, all_ones), (0, 1));
+        let r = _kortest_mask64_u8(
+            a,
+            0b00110011_11001100_00110011_11001100_00110011_11001100_00110011_11001000,
+            &mut all_ones,
+        );
+        assert_eq!((r, all_ones), (0, 0));
+        let r = _kortest_mask64_u8(0, 0, &mut all_ones);
+        assert_eq!((r, all_ones), (1, 0));
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_kortestc_mask64_u8() {
+        let a: __mmask64 =
+            0b11001100_00110011_11001100_00110011_11001100_00110011_11001100_00110011;
+        assert_eq!(
+            _kortestc_mask64_u8(
+                a,
+                0b00110011_11001100_00110011_11001100_00110011_11001100_00110011_11001100
+            ),
+            1
+        );
+        assert_eq!(_kortestc_mask64_u8(a, u64::MAX), 1);
+        assert_eq!(
+            _kortestc_mask64_u8(
+                a,
+                0b00110011_11001100_00110011_11001100_00110011_11001100_00110011_11001000
+            ),
+            0
+        );
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_kortestz_mask64_u8() {
+        assert_eq!(_kortestz_mask64_u8(0, 0), 1);
+        assert_eq!(_kortestz_mask64_u8(0, 1 << 63), 0);
+        assert_eq!(_kortestz_mask64_u8(1, 0), 0);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_ktest_mask64_u8() {
+        let a: __mmask64 =
+            0b11001100_00110011_11001100_00110011_11001100_00110011_11001100_00110011;
+        let mut and_not = 2;
+        let r = _ktest_mask64_u8(
+            a,
+            0b00110011_11001100_00110011_11001100_00110011_11001100_00110011_11001100,
+            &mut and_not,
+        );
+        assert_eq!((r, and_not), (1, 0));
+        let r = _ktest_mask64_u8(
+            a,
+            0b00000000_00010001_00000000_00010001_00000000_00010001_00000000_00010001,
+            &mut and_not,
+        );
+        assert_eq!((r, and_not), (0, 1));
+        let r = _ktest_mask64_u8(
+            a,
+            0b00000000_00010001_00000000_00010001_00000000_00010001_00000000_00010101,
+            &mut and_not,
+        );
+        assert_eq!((r, and_not), (0, 0));
+        let r = _ktest_mask64_u8(a, 0, &mut and_not);
+        assert_eq!((r, and_not), (1, 1));
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_ktestc_mask64_u8() {
+        let a: __mmask64 =
+            0b11001100_00110011_11001100_00110011_11001100_00110011_11001100_00110011;
+        assert_eq!(
+            _ktestc_mask64_u8(
+                a,
+                0b00000000_00010001_00000000_00010001_00000000_00010001_00000000_00010001
+            ),
+            1
+        );
+        assert_eq!(_ktestc_mask64_u8(a, 4), 0);
+        assert_eq!(_ktestc_mask64_u8(u64::MAX, u64::MAX), 1);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_ktestz_mask64_u8() {
+        let a: __mmask64 =
+            0b11001100_00110011_11001100_00110011_11001100_00110011_11001100_00110011;
+        assert_eq!(
+            _ktestz_mask64_u8(
+                a,
+                0b00110011_11001100_00110011_11001100_00110011_11001100_00110011_11001100
+            ),
+            1
+        );
+        assert_eq!(_ktestz_mask64_u8(a, 1 << 62), 0);
+        assert_eq!(_ktestz_mask64_u8(u64::MAX, u64::MAX), 0);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_kunpackw() {
+        let a: __mmask32 = 0b00110011_11001100_00110011_11001100;
+        let b: __mmask32 = 0b11001100_00110011_11110000_00001111;
+        let r = _mm512_kunpackw(a, b);
+        let e: __mmask32 = 0b00110011_11001100_11110000_00001111;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_kunpackd() {
+        let a: __mmask64 =
+            0b00000000_00000000_00000000_00000000_00110011_11001100_00110011_11001100;
+        let b: __mmask64 =
+            0b11111111_11111111_11111111_11111111_11001100_00110011_11110000_00001111;
+        let r = _mm512_kunpackd(a, b);
+        let e: __mmask64 =
+            0b00110011_11001100_00110011_11001100_11001100_00110011_11110000_00001111;
+        assert_eq!(r, e);
+    }
+
     #[simd_test(enable = "avx512bw")]
     unsafe fn test_mm512_cvtepi16_epi8() {
         let a = _mm512_set1_epi16(2);
@@ -0,0 +1,633 @@
+//! Doubleword and Quadword Instructions (AVX512DQ)
+//!
+//! The intrinsics here correspond to those in the `immintrin.h` C header.
+//!
+//! The reference is [Intel 64 and IA-32 Architectures Software Developer's
+//! Manual Volume 2: Instruction Set Reference, A-Z][intel64_ref].
+//!
+//! [intel64_ref]: http://www.intel.de/content/dam/www/public/us/en/documents/manuals/64-ia-32-architectures-software-developer-instruction-set-reference-manual-325383.pdf
+
+use crate::{
+    core_arch::x86::{__mmask16, __mmask8},
+    ptr,
+};
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Add 8-bit masks in a and b, and store the result in k.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kadd_mask8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(kaddb))]
+pub unsafe fn _kadd_mask8(a: __mmask8, b: __mmask8) -> __mmask8 {
+    let r: __mmask8;
+    asm!(
+        "kaddb {r}, {a}, {b}",
+        a = in(kreg) a,
+        b = in(kreg) b,
+        r = lateout(kreg) r,
+        options(pure, nomem, nostack, preserves_flags)
+    );
+    r
+}
+
+/// Add 16-bit masks in a and b, and store the result in k.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kadd_mask16)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(kaddw))]
+pub unsafe fn _kadd_mask16(a: __mmask16, b: __mmask16) -> __mmask16 {
+    let r: __mmask16;
+    asm!(
+        "kaddw {r}, {a}, {b}",
+        a = in(kreg) a,
+        b = in(kreg) b,
+        r = lateout(kreg) r,
+        options(pure, nomem, nostack, preserves_flags)
+    );
+    r
+}
+
+/// Compute the bitwise AND of 8-bit masks a and b, and store the result in k.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kand_mask8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(kandb))]
+pub unsafe fn _kand_mask8(a: __mmask8, b: __mmask8) -> __mmask8 {
+    let r: __mmask8;
+    asm!(
+        "kandb {r}, {a}, {b}",
+        a = in(kreg) a,
+        b = in(kreg) b,
+        r = lateout(kreg) r,
+        options(pure, nomem, nostack, preserves_flags)
+    );
+    r
+}
+
+/// Compute the bitwise NOT of 8-bit mask a, and store the result in k.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_knot_mask8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(knotb))]
+pub unsafe fn _knot_mask8(a: __mmask8) -> __mmask8 {
+    let r: __mmask8;
+    asm!(
+        "knotb {r}, {a}",
+        a = in(kreg) a,
+        r = lateout(kreg) r,
+        options(pure, nomem, nostack, preserves_flags)
+    );
+    r
+}
+
+/// Compute the bitwise NOT of 8-bit masks a and then AND with b, and store the result in k.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kandn_mask8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(kandnb))]
+pub unsafe fn _kandn_mask8(a: __mmask8, b: __mmask8) -> __mmask8 {
+    let r: __mmask8;
+    asm!(
+        "kandnb {r}, {a}, {b}",
+        a = in(kreg) a,
+        b = in(kreg) b,
+        r = lateout(kreg) r,
+        options(pure, nomem, nostack, preserves_flags)
+    );
+    r
+}
+
+/// Compute the bitwise OR of 8-bit masks a and b, and store the result in k.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kor_mask8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(korb))]
+pub unsafe fn _kor_mask8(a: __mmask8, b: __mmask8) -> __mmask8 {
+    let r: __mmask8;
+    asm!(
+        "korb {r}, {a}, {b}",
+        a = in(kreg) a,
+        b = in(kreg) b,
+        r = lateout(kreg) r,
+        options(pure, nomem, nostack, preserves_flags)
+    );
+    r
+}
+
+/// Compute the bitwise XOR of 8-bit masks a and b, and store the result in k.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kxor_mask8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(kxorb))]
+pub unsafe fn _kxor_mask8(a: __mmask8, b: __mmask8) -> __mmask8 {
+    let r: __mmask8;
+    asm!(
+        "kxorb {r}, {a}, {b}",
+        a = in(kreg) a,
+        b = in(kreg) b,
+        r = lateout(kreg) r,
+        options(pure, nomem, nostack, preserves_flags)
+    );
+    r
+}
+
+/// Compute the bitwise XNOR of 8-bit masks a and b, and store the result in k.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kxnor_mask8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(kxnorb))]
+pub unsafe fn _kxnor_mask8(a: __mmask8, b: __mmask8) -> __mmask8 {
+    let r: __mmask8;
+    asm!(
+        "kxnorb {r}, {a}, {b}",
+        a = in(kreg) a,
+        b = in(kreg) b,
+        r = lateout(kreg) r,
+        options(pure, nomem, nostack, preserves_flags)
+    );
+    r
+}
+
+/// Shift the bits of 8-bit mask a left by count while shifting in zeros, and store the least significant 8 bits of the result in k.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kshiftli_mask8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(kshiftlb, COUNT = 5))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _kshiftli_mask8<const COUNT: u32>(a: __mmask8) -> __mmask8 {
+    static_assert_imm_u8!(COUNT);
+    let r: __mmask8;
+    asm!(
+        "kshiftlb {r}, {a}, {count}",
+        a = in(kreg) a,
+        r = lateout(kreg) r,
+        count = const COUNT,
+        options(pure, nomem, nostack, preserves_flags)
+    );
+    r
+}
+
+/// Shift the bits of 8-bit mask a right by count while shifting in zeros, and store the least significant 8 bits of the result in k.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kshiftri_mask8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(kshiftrb, COUNT = 5))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _kshiftri_mask8<const COUNT: u32>(a: __mmask8) -> __mmask8 {
+    static_assert_imm_u8!(COUNT);
+    let r: __mmask8;
+    asm!(
+        "kshiftrb {r}, {a}, {count}",
+        a = in(kreg) a,
+        r = lateout(kreg) r,
+        count = const COUNT,
+        options(pure, nomem, nostack, preserves_flags)
+    );
+    r
+}
+
+/// Compute the bitwise OR of 8-bit masks a and b. If the result is all zeros, store 1 in dst, otherwise store 0 in dst. If the result is all ones, store 1 in all_ones, otherwise store 0 in all_ones.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kortest_mask8_u8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(kortestb))]
+pub unsafe fn _kortest_mask8_u8(a: __mmask8, b: __mmask8, all_ones: *mut u8) -> u8 {
+    *all_ones = _kortestc_mask8_u8(a, b);
+    _kortestz_mask8_u8(a, b)
+}
+
+/// Compute the bitwise OR of 8-bit masks a and b. If the result is all ones, store 1 in dst, otherwise store 0 in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kortestc_mask8_u8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(kortestb))]
+pub unsafe fn _kortestc_mask8_u8(a: __mmask8, b: __mmask8) -> u8 {
+    let r: u8;
+    asm!(
+        "kortestb {a}, {b}",
+        "setc {r}",
+        a = in(kreg) a,
+        b = in(kreg) b,
+        r = out(reg_byte) r,
+        options(pure, nomem, nostack)
+    );
+    r
+}
+
+/// Compute the bitwise OR of 8-bit masks a and b. If the result is all zeros, store 1 in dst, otherwise store 0 in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kortestz_mask8_u8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(kortestb))]
+pub unsafe fn _kortestz_mask8_u8(a: __mmask8, b: __mmask8) -> u8 {
+    let r: u8;
+    asm!(
+        "kortestb {a}, {b}",
+        "setz {r}",
+        a = in(kreg) a,
+        b = in(kreg) b,
+        r = out(reg_byte) r,
+        options(pure, nomem, nostack)
+    );
+    r
+}
+
+/// Compute the bitwise AND of 8-bit masks a and b, and if the result is all zeros, store 1 in dst, otherwise store 0 in dst. Compute the bitwise NOT of a and then AND with b, if the result is all zeros, store 1 in and_not, otherwise store 0 in and_not.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_ktest_mask8_u8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(ktestb))]
+pub unsafe fn _ktest_mask8_u8(a: __mmask8, b: __mmask8, and_not: *mut u8) -> u8 {
+    *and_not = _ktestc_mask8_u8(a, b);
+    _ktestz_mask8_u8(a, b)
+}
+
+/// Compute the bitwise NOT of 8-bit mask a and then AND with b, if the result is all zeros, store 1 in dst, otherwise store 0 in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_ktestc_mask8_u8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(ktestb))]
+pub unsafe fn _ktestc_mask8_u8(a: __mmask8, b: __mmask8) -> u8 {
+    let r: u8;
+    asm!(
+        "ktestb {a}, {b}",
+        "setc {r}",
+        a = in(kreg) a,
+        b = in(kreg) b,
+        r = out(reg_byte) r,
+        options(pure, nomem, nostack)
+    );
+    r
+}
+
+/// Compute the bitwise AND of 8-bit masks a and b, if the result is all zeros, store 1 in dst, otherwise store 0 in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_ktestz_mask8_u8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(ktestb))]
+pub unsafe fn _ktestz_mask8_u8(a: __mmask8, b: __mmask8) -> u8 {
+    let r: u8;
+    asm!(
+        "ktestb {a}, {b}",
+        "setz {r}",
+        a = in(kreg) a,
+        b = in(kreg) b,
+        r = out(reg_byte) r,
+        options(pure, nomem, nostack)
+    );
+    r
+}
+
+/// Compute the bitwise AND of 16-bit masks a and b, and if the result is all zeros, store 1 in dst, otherwise store 0 in dst. Compute the bitwise NOT of a and then AND with b, if the result is all zeros, store 1 in and_not, otherwise store 0 in and_not.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_ktest_mask16_u8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(ktestw))]
+pub unsafe fn _ktest_mask16_u8(a: __mmask16, b: __mmask16, and_not: *mut u8) -> u8 {
+    *and_not = _ktestc_mask16_u8(a, b);
+    _ktestz_mask16_u8(a, b)
+}
+
+/// Compute the bitwise NOT of 16-bit mask a and then AND with b, if the result is all zeros, store 1 in dst, otherwise store 0 in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_ktestc_mask16_u8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(ktestw))]
+pub unsafe fn _ktestc_mask16_u8(a: __mmask16, b: __mmask16) -> u8 {
+    let r: u8;
+    asm!(
+        "ktestw {a}, {b}",
+        "setc {r}",
+        a = in(kreg) a,
+        b = in(kreg) b,
+        r = out(reg_byte) r,
+        options(pure, nomem, nostack)
+    );
+    r
+}
+
+/// Compute the bitwise AND of 16-bit masks a and b, if the result is all zeros, store 1 in dst, otherwise store 0 in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_ktestz_mask16_u8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(ktestw))]
+pub unsafe fn _ktestz_mask16_u8(a: __mmask16, b: __mmask16) -> u8 {
+    let r: u8;
+    asm!(
+        "ktestw {a}, {b}",
+        "setz {r}",
+        a = in(kreg) a,
+        b = in(kreg) b,
+        r = out(reg_byte) r,
+        options(pure, nomem, nostack)
+    );
+    r
+}
+
+/// Store 8-bit mask from a into memory.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_store_mask8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(kmovb))]
+pub unsafe fn _store_mask8(mem_addr: *mut __mmask8, a: __mmask8) {
+    asm!(
+        "kmovb byte ptr [{p}], {a}",
+        p = in(reg) mem_addr,
+        a = in(kreg) a,
+        options(nostack, preserves_flags)
+    );
+}
+
+/// Load 8-bit mask from memory into k.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_load_mask8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(kmovb))]
+pub unsafe fn _load_mask8(mem_addr: *const __mmask8) -> __mmask8 {
+    let r: __mmask8;
+    asm!(
+        "kmovb {r}, byte ptr [{p}]",
+        p = in(reg) mem_addr,
+        r = lateout(kreg) r,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    r
+}
+
+/// Convert 8-bit mask a into an integer value, and store the result in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_cvtmask8_u32)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(kmovb))]
+pub unsafe fn _cvtmask8_u32(a: __mmask8) -> u32 {
+    let r: u32;
+    asm!(
+        "kmovb {r:e}, {a}",
+        a = in(kreg) a,
+        r = lateout(reg) r,
+        options(pure, nomem, nostack, preserves_flags)
+    );
+    r
+}
+
+/// Convert integer value a into an 8-bit mask, and store the result in k.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_cvtu32_mask8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(kmovb))]
+pub unsafe fn _cvtu32_mask8(a: u32) -> __mmask8 {
+    let r: __mmask8;
+    asm!(
+        "kmovb {r}, {a:e}",
+        a = in(reg) a,
+        r = lateout(kreg) r,
+        options(pure, nomem, nostack, preserves_flags)
+    );
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use stdarch_test::simd_test;
+
+    use crate::core_arch::x86::*;
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_kadd_mask8() {
+        for a in 0..=u8::MAX {
+            for b in 0..=u8::MAX {
+                assert_eq!(_kadd_mask8(a, b), a.wrapping_add(b));
+            }
+        }
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_kadd_mask16() {
+        let a: u16 = 0b11001100_00110011;
+        let b: u16 = 0b00101110_00001011;
+        let r = _kadd_mask16(a, b);
+        let e: u16 = 0b11111010_00111110;
+        assert_eq!(r, e);
+        let r = _kadd_mask16(u16::MAX, 2);
+        assert_eq!(r, 1);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_kand_mask8() {
+        for a in 0..=u8::MAX {
+            for b in 0..=u8::MAX {
+                assert_eq!(_kand_mask8(a, b), a & b);
+            }
+        }
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_knot_mask8() {
+        for a in 0..=u8::MAX {
+            assert_eq!(_knot_mask8(a), !a);
+        }
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_kandn_mask8() {
+        for a in 0..=u8::MAX {
+            for b in 0..=u8::MAX {
+                assert_eq!(_kandn_mask8(a, b), !a & b);
+            }
+        }
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_kor_mask8() {
+        for a in 0..=u8::MAX {
+            for b in 0..=u8::MAX {
+                assert_eq!(_kor_mask8(a, b), a | b);
+            }
+        }
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_kxor_mask8() {
+        for a in 0..=u8::MAX {
+            for b in 0..=u8::MAX {
+                assert_eq!(_kxor_mask8(a, b), a ^ b);
+            }
+        }
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_kxnor_mask8() {
+        for a in 0..=u8::MAX {
+            for b in 0..=u8::MAX {
+                assert_eq!(_kxnor_mask8(a, b), !(a ^ b));
+            }
+        }
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_kshiftli_mask8() {
+        let a: u8 = 0b10110011;
+        assert_eq!(_kshiftli_mask8::<0>(a), a);
+        assert_eq!(_kshiftli_mask8::<1>(a), 0b01100110);
+        assert_eq!(_kshiftli_mask8::<5>(a), 0b01100000);
+        assert_eq!(_kshiftli_mask8::<7>(a), 0b10000000);
+        assert_eq!(_kshiftli_mask8::<8>(a), 0);
+        assert_eq!(_kshiftli_mask8::<255>(a), 0);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_kshiftri_mask8() {
+        let a: u8 = 0b10110011;
+        assert_eq!(_kshiftri_mask8::<0>(a), a);
+        assert_eq!(_kshiftri_mask8::<1>(a), 0b01011001);
+        assert_eq!(_kshiftri_mask8::<5>(a), 0b00000101);
+        assert_eq!(_kshiftri_mask8::<7>(a), 0b00000001);
+        assert_eq!(_kshiftri_mask8::<8>(a), 0);
+        assert_eq!(_kshiftri_mask8::<255>(a), 0);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_kortest_mask8_u8() {
+        for a in 0..=u8::MAX {
+            for b in 0..=u8::MAX {
+                let mut all_ones = 2;
+                let r = _kortest_mask8_u8(a, b, &mut all_ones);
+                assert_eq!(r, ((a | b) == 0) as u8);
+                assert_eq!(all_ones, ((a | b) == u8::MAX) as u8);
+            }
+        }
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_kortestc_mask8_u8() {
+        assert_eq!(_kortestc_mask8_u8(0b11001100, 0b00110011), 1);
+        assert_eq!(_kortestc_mask8_u8(0b11001100, 0b00110010), 0);
+        assert_eq!(_kortestc_mask8_u8(0, 0), 0);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_kortestz_mask8_u8() {
+        assert_eq!(_kortestz_mask8_u8(0, 0), 1);
+        assert_eq!(_kortestz_mask8_u8(0, 0b00010000), 0);
+        assert_eq!(_kortestz_mask8_u8(0b10000000, 0), 0);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_ktest_mask8_u8() {
+        for a in 0..=u8::MAX {
+            for b in 0..=u8::MAX {
+                let mut and_not = 2;
+                let r = _ktest_mask8_u8(a, b, &mut and_not);
+                assert_eq!(r, ((a & b) == 0) as u8);
+                assert_eq!(and_not, ((!a & b) == 0) as u8);
+            }
+        }
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_ktestc_mask8_u8() {
+        assert_eq!(_ktestc_mask8_u8(0b11001100, 0b01001100), 1);
+        assert_eq!(_ktestc_mask8_u8(0b11001100, 0b00000010), 0);
+        assert_eq!(_ktestc_mask8_u8(0, 0), 1);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_ktestz_mask8_u8() {
+        assert_eq!(_ktestz_mask8_u8(0b11001100, 0b00110011), 1);
+        assert_eq!(_ktestz_mask8_u8(0b11001100, 0b00000100), 0);
+        assert_eq!(_ktestz_mask8_u8(0, 0), 1);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_ktest_mask16_u8() {
+        let a: u16 = 0b11001100_00110011;
+        let mut and_not = 2;
+        let r = _ktest_mask16_u8(a, 0b00110011_11001100, &mut and_not);
+        assert_eq!((r, and_not), (1, 0));
+        let r = _ktest_mask16_u8(a, 0b10001000_00010001, &mut and_not);
+        assert_eq!((r, and_not), (0, 1));
+        let r = _ktest_mask16_u8(a, 0b10001000_00010101, &mut and_not);
+        assert_eq!((r, and_not), (0, 0));
+        let r = _ktest_mask16_u8(a, 0, &mut and_not);
+        assert_eq!((r, and_not), (1, 1));
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_ktestc_mask16_u8() {
+        let a: u16 = 0b11001100_00110011;
+        assert_eq!(_ktestc_mask16_u8(a, 0b10001000_00010001), 1);
+        assert_eq!(_ktestc_mask16_u8(a, 0b00000000_00000100), 0);
+        assert_eq!(_ktestc_mask16_u8(u16::MAX, u16::MAX), 1);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_ktestz_mask16_u8() {
+        let a: u16 = 0b11001100_00110011;
+        assert_eq!(_ktestz_mask16_u8(a, 0b00110011_11001100), 1);
+        assert_eq!(_ktestz_mask16_u8(a, 0b01000000_00000000), 0);
+        assert_eq!(_ktestz_mask16_u8(u16::MAX, u16::MAX), 0);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_store_mask8() {
+        let a: __mmask8 = 0b11001100;
+        let mut r = 0;
+        _store_mask8(&mut r, a);
+        assert_eq!(r, a);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_load_mask8() {
+        let p: __mmask8 = 0b11001100;
+        let r = _load_mask8(&p);
+        let e: __mmask8 = 0b11001100;
+        assert_eq!(r, e);
+        let mut m: __mmask8 = 0;
+        _store_mask8(&mut m, _load_mask8(&p));
+        assert_eq!(m, p);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_cvtmask8_u32() {
+        let a: __mmask8 = 0b11001100;
+        let r = _cvtmask8_u32(a);
+        let e: u32 = 0b11001100;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_cvtu32_mask8() {
+        let a: u32 = 0b11001100;
+        let r = _cvtu32_mask8(a);
+        let e: __mmask8 = 0b11001100;
+        assert_eq!(r, e);
+        assert_eq!(_cvtmask8_u32(_cvtu32_mask8(a)), a);
+        let r = _cvtu32_mask8(u32::MAX);
+        assert_eq!(r, __mmask8::MAX);
+    }
+}
@@ -14908,9 +14908,8 @@ pub unsafe fn _mm_maskz_cvttpd_epu32(k: __mmask8, a: __m128d) -> __m128i {
 #[inline]
 #[target_feature(enable = "avx512f")]
 #[cfg_attr(test, assert_instr(vxorps))]
-pub unsafe fn _mm512_setzero_pd() -> __m512d {
-    // All-0 is a properly initialized __m512d
-    mem::zeroed()
+pub const unsafe fn _mm512_setzero_pd() -> __m512d {
+    transmute(f64x8::splat(0.0))
 }
 
 /// Returns vector of type `__m512d` with all elements set to zero.
@@ -14919,9 +14918,8 @@ pub unsafe fn _mm512_setzero_pd() -> __m512d {
 #[inline]
 #[target_feature(enable = "avx512f")]
 #[cfg_attr(test, assert_instr(vxorps))]
-pub unsafe fn _mm512_setzero_ps() -> __m512 {
-    // All-0 is a properly initialized __m512
-    mem::zeroed()
+pub const unsafe fn _mm512_setzero_ps() -> __m512 {
+    transmute(f32x16::splat(0.0))
 }
 
 /// Return vector of type __m512 with all elements set to zero.
@@ -14930,9 +14928,8 @@ pub unsafe fn _mm512_setzero_ps() -> __m512 {
 #[inline]
 #[target_feature(enable = "avx512f")]
 #[cfg_attr(test, assert_instr(vxorps))]
-pub unsafe fn _mm512_setzero() -> __m512 {
-    // All-0 is a properly initialized __m512
-    mem::zeroed()
+pub const unsafe fn _mm512_setzero() -> __m512 {
+    transmute(f32x16::splat(0.0))
 }
 
 /// Returns vector of type `__m512i` with all elements set to zero.
@@ -14941,9 +14938,8 @@ pub unsafe fn _mm512_setzero() -> __m512 {
 #[inline]
 #[target_feature(enable = "avx512f")]
 #[cfg_attr(test, assert_instr(vxorps))]
-pub unsafe fn _mm512_setzero_si512() -> __m512i {
-    // All-0 is a properly initialized __m512i
-    mem::zeroed()
+pub const unsafe fn _mm512_setzero_si512() -> __m512i {
+    transmute(i64x8::splat(0))
 }
 
 /// Return vector of type __m512i with all elements set to zero.
@@ -14952,9 +14948,8 @@ pub unsafe fn _mm512_setzero_si512() -> __m512i {
 #[inline]
 #[target_feature(enable = "avx512f")]
 #[cfg_attr(test, assert_instr(vxorps))]
-pub unsafe fn _mm512_setzero_epi32() -> __m512i {
-    // All-0 is a properly initialized __m512i
-    mem::zeroed()
+pub const unsafe fn _mm512_setzero_epi32() -> __m512i {
+    transmute(i32x16::splat(0))
 }
 
 /// Sets packed 32-bit integers in `dst` with the supplied values in reverse
@@ -14963,7 +14958,7 @@ pub unsafe fn _mm512_setzero_epi32() -> __m512i {
 /// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_setr_epi32&expand=4991)
 #[inline]
 #[target_feature(enable = "avx512f")]
-pub unsafe fn _mm512_setr_epi32(
+pub const unsafe fn _mm512_setr_epi32(
     e15: i32,
     e14: i32,
     e13: i32,
@@ -14992,7 +14987,7 @@ pub unsafe fn _mm512_setr_epi32(
 /// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_set_epi8&expand=4915)
 #[inline]
 #[target_feature(enable = "avx512f")]
-pub unsafe fn _mm512_set_epi8(
+pub const unsafe fn _mm512_set_epi8(
     e63: i8,
     e62: i8,
     e61: i8,
@@ -15072,7 +15067,7 @@ pub unsafe fn _mm512_set_epi8(
 /// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_set_epi16&expand=4905)
 #[inline]
 #[target_feature(enable = "avx512f")]
-pub unsafe fn _mm512_set_epi16(
+pub const unsafe fn _mm512_set_epi16(
     e31: i16,
     e30: i16,
     e29: i16,
@@ -15118,7 +15113,7 @@ pub unsafe fn _mm512_set_epi16(
 /// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_set4_epi32&expand=4982)
 #[inline]
 #[target_feature(enable = "avx512f")]
-pub unsafe fn _mm512_set4_epi32(d: i32, c: i32, b: i32, a: i32) -> __m512i {
+pub const unsafe fn _mm512_set4_epi32(d: i32, c: i32, b: i32, a: i32) -> __m512i {
     _mm512_set_epi32(d, c, b, a, d, c, b, a, d, c, b, a, d, c, b, a)
 }
 
@@ -15127,7 +15122,7 @@ pub unsafe fn _mm512_set4_epi32(d: i32, c: i32, b: i32, a: i32) -> __m512i {
 /// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_set4_ps&expand=4985)
 #[inline]
 #[target_feature(enable = "avx512f")]
-pub unsafe fn _mm512_set4_ps(d: f32, c: f32, b: f32, a: f32) -> __m512 {
+pub const unsafe fn _mm512_set4_ps(d: f32, c: f32, b: f32, a: f32) -> __m512 {
     _mm512_set_ps(d, c, b, a, d, c, b, a, d, c, b, a, d, c, b, a)
 }
 
@@ -15136,7 +15131,7 @@ pub unsafe fn _mm512_set4_ps(d: f32, c: f32, b: f32, a: f32) -> __m512 {
 /// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_set4_pd&expand=4984)
 #[inline]
 #[target_feature(enable = "avx512f")]
-pub unsafe fn _mm512_set4_pd(d: f64, c: f64, b: f64, a: f64) -> __m512d {
+pub const unsafe fn _mm512_set4_pd(d: f64, c: f64, b: f64, a: f64) -> __m512d {
     _mm512_set_pd(d, c, b, a, d, c, b, a)
 }
 
@@ -15145,7 +15140,7 @@ pub unsafe fn _mm512_set4_pd(d: f64, c: f64, b: f64, a: f64) -> __m512d {
 /// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_setr4_epi32&expand=5009)
 #[inline]
 #[target_feature(enable = "avx512f")]
-pub unsafe fn _mm512_setr4_epi32(d: i32, c: i32, b: i32, a: i32) -> __m512i {
+pub const unsafe fn _mm512_setr4_epi32(d: i32, c: i32, b: i32, a: i32) -> __m512i {
     _mm512_set_epi32(a, b, c, d, a, b, c, d, a, b, c, d, a, b, c, d)
 }
 
@@ -15154,7 +15149,7 @@ pub unsafe fn _mm512_setr4_epi32(d: i32, c: i32, b: i32, a: i32) -> __m512i {
 /// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_setr4_ps&expand=5012)
 #[inline]
 #[target_feature(enable = "avx512f")]
-pub unsafe fn _mm512_setr4_ps(d: f32, c: f32, b: f32, a: f32) -> __m512 {
+pub const unsafe fn _mm512_setr4_ps(d: f32, c: f32, b: f32, a: f32) -> __m512 {
     _mm512_set_ps(a, b, c, d, a, b, c, d, a, b, c, d, a, b, c, d)
 }
 
@@ -15163,7 +15158,7 @@ pub unsafe fn _mm512_setr4_ps(d: f32, c: f32, b: f32, a: f32) -> __m512 {
 /// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_setr4_pd&expand=5011)
 #[inline]
 #[target_feature(enable = "avx512f")]
-pub unsafe fn _mm512_setr4_pd(d: f64, c: f64, b: f64, a: f64) -> __m512d {
+pub const unsafe fn _mm512_setr4_pd(d: f64, c: f64, b: f64, a: f64) -> __m512d {
     _mm512_set_pd(a, b, c, d, a, b, c, d)
 }
 
@@ -15172,7 +15167,7 @@ pub unsafe fn _mm512_setr4_pd(d: f64, c: f64, b: f64, a: f64) -> __m512d {
 /// [Intel's documentation]( https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_set_epi64&expand=4910)
 #[inline]
 #[target_feature(enable = "avx512f")]
-pub unsafe fn _mm512_set_epi64(
+pub const unsafe fn _mm512_set_epi64(
     e0: i64,
     e1: i64,
     e2: i64,
@@ -15190,7 +15185,7 @@ pub unsafe fn _mm512_set_epi64(
 /// [Intel's documentation]( https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_setr_epi64&expand=4993)
 #[inline]
 #[target_feature(enable = "avx512f")]
-pub unsafe fn _mm512_setr_epi64(
+pub const unsafe fn _mm512_setr_epi64(
     e0: i64,
     e1: i64,
     e2: i64,
@@ -16396,6 +16391,666 @@ pub unsafe fn _mm_maskz_expand_pd(k: __mmask8, a: __m128d) -> __m128d {
     transmute(vexpandpd128(a.as_f64x2(), _mm_setzero_pd().as_f64x2(), k))
 }
 
+/// Contiguously store the active 32-bit integers in a (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+///
+/// Only the `popcnt(k)` active elements are written, to consecutive locations starting at `base_addr`; the memory after them is neither read nor written, so `base_addr` only needs to be valid for that many elements.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_compressstoreu_epi32)
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpcompressd))]
+pub unsafe fn _mm512_mask_compressstoreu_epi32(base_addr: *mut i32, k: __mmask16, a: __m512i) {
+    vcompressstored(base_addr as *mut _, a.as_i32x16(), k)
+}
+
+/// Load contiguous active 32-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// Only the `popcnt(k)` elements starting at `mem_addr` are read, so `mem_addr` only needs to be valid for that many elements.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_expandloadu_epi32)
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpexpandd))]
+pub unsafe fn _mm512_mask_expandloadu_epi32(
+    src: __m512i,
+    k: __mmask16,
+    mem_addr: *const i32,
+) -> __m512i {
+    let mut dst: __m512i = src;
+    asm!(
+        "vpexpandd {dst}{{{k}}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = inout(zmm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Load contiguous active 32-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// Only the `popcnt(k)` elements starting at `mem_addr` are read, so `mem_addr` only needs to be valid for that many elements.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_expandloadu_epi32)
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpexpandd))]
+pub unsafe fn _mm512_maskz_expandloadu_epi32(k: __mmask16, mem_addr: *const i32) -> __m512i {
+    let dst: __m512i;
+    asm!(
+        "vpexpandd {dst}{{{k}}}{{z}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = out(zmm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Contiguously store the active 32-bit integers in a (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+///
+/// Only the `popcnt(k)` active elements are written, to consecutive locations starting at `base_addr`; the memory after them is neither read nor written, so `base_addr` only needs to be valid for that many elements.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_compressstoreu_epi32)
+#[inline]
+#[target_feature(enable = "avx512f,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcompressd))]
+pub unsafe fn _mm256_mask_compressstoreu_epi32(base_addr: *mut i32, k: __mmask8, a: __m256i) {
+    vcompressstored256(base_addr as *mut _, a.as_i32x8(), k)
+}
+
+/// Load contiguous active 32-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// Only the `popcnt(k)` elements starting at `mem_addr` are read, so `mem_addr` only needs to be valid for that many elements.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_expandloadu_epi32)
+#[inline]
+#[target_feature(enable = "avx512f,avx512vl")]
+#[cfg_attr(test, assert_instr(vpexpandd))]
+pub unsafe fn _mm256_mask_expandloadu_epi32(
+    src: __m256i,
+    k: __mmask8,
+    mem_addr: *const i32,
+) -> __m256i {
+    let mut dst: __m256i = src;
+    asm!(
+        "vpexpandd {dst}{{{k}}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = inout(ymm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Load contiguous active 32-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// Only the `popcnt(k)` elements starting at `mem_addr` are read, so `mem_addr` only needs to be valid for that many elements.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_expandloadu_epi32)
+#[inline]
+#[target_feature(enable = "avx512f,avx512vl")]
+#[cfg_attr(test, assert_instr(vpexpandd))]
+pub unsafe fn _mm256_maskz_expandloadu_epi32(k: __mmask8, mem_addr: *const i32) -> __m256i {
+    let dst: __m256i;
+    asm!(
+        "vpexpandd {dst}{{{k}}}{{z}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = out(ymm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Contiguously store the active 32-bit integers in a (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+///
+/// Only the `popcnt(k)` active elements are written, to consecutive locations starting at `base_addr`; the memory after them is neither read nor written, so `base_addr` only needs to be valid for that many elements.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_compressstoreu_epi32)
+#[inline]
+#[target_feature(enable = "avx512f,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcompressd))]
+pub unsafe fn _mm_mask_compressstoreu_epi32(base_addr: *mut i32, k: __mmask8, a: __m128i) {
+    vcompressstored128(base_addr as *mut _, a.as_i32x4(), k)
+}
+
+/// Load contiguous active 32-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// Only the `popcnt(k)` elements starting at `mem_addr` are read, so `mem_addr` only needs to be valid for that many elements.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_expandloadu_epi32)
+#[inline]
+#[target_feature(enable = "avx512f,avx512vl")]
+#[cfg_attr(test, assert_instr(vpexpandd))]
+pub unsafe fn _mm_mask_expandloadu_epi32(
+    src: __m128i,
+    k: __mmask8,
+    mem_addr: *const i32,
+) -> __m128i {
+    let mut dst: __m128i = src;
+    asm!(
+        "vpexpandd {dst}{{{k}}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = inout(xmm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Load contiguous active 32-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// Only the `popcnt(k)` elements starting at `mem_addr` are read, so `mem_addr` only needs to be valid for that many elements.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_expandloadu_epi32)
+#[inline]
+#[target_feature(enable = "avx512f,avx512vl")]
+#[cfg_attr(test, assert_instr(vpexpandd))]
+pub unsafe fn _mm_maskz_expandloadu_epi32(k: __mmask8, mem_addr: *const i32) -> __m128i {
+    let dst: __m128i;
+    asm!(
+        "vpexpandd {dst}{{{k}}}{{z}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = out(xmm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Contiguously store the active 64-bit integers in a (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+///
+/// Only the `popcnt(k)` active elements are written, to consecutive locations starting at `base_addr`; the memory after them is neither read nor written, so `base_addr` only needs to be valid for that many elements.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_compressstoreu_epi64)
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpcompressq))]
+pub unsafe fn _mm512_mask_compressstoreu_epi64(base_addr: *mut i64, k: __mmask8, a: __m512i) {
+    vcompressstoreq(base_addr as *mut _, a.as_i64x8(), k)
+}
+
+/// Load contiguous active 64-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// Only the `popcnt(k)` elements starting at `mem_addr` are read, so `mem_addr` only needs to be valid for that many elements.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_expandloadu_epi64)
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpexpandq))]
+pub unsafe fn _mm512_mask_expandloadu_epi64(
+    src: __m512i,
+    k: __mmask8,
+    mem_addr: *const i64,
+) -> __m512i {
+    let mut dst: __m512i = src;
+    asm!(
+        "vpexpandq {dst}{{{k}}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = inout(zmm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Load contiguous active 64-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// Only the `popcnt(k)` elements starting at `mem_addr` are read, so `mem_addr` only needs to be valid for that many elements.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_expandloadu_epi64)
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpexpandq))]
+pub unsafe fn _mm512_maskz_expandloadu_epi64(k: __mmask8, mem_addr: *const i64) -> __m512i {
+    let dst: __m512i;
+    asm!(
+        "vpexpandq {dst}{{{k}}}{{z}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = out(zmm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Contiguously store the active 64-bit integers in a (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+///
+/// Only the `popcnt(k)` active elements are written, to consecutive locations starting at `base_addr`; the memory after them is neither read nor written, so `base_addr` only needs to be valid for that many elements.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_compressstoreu_epi64)
+#[inline]
+#[target_feature(enable = "avx512f,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcompressq))]
+pub unsafe fn _mm256_mask_compressstoreu_epi64(base_addr: *mut i64, k: __mmask8, a: __m256i) {
+    vcompressstoreq256(base_addr as *mut _, a.as_i64x4(), k)
+}
+
+/// Load contiguous active 64-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// Only the `popcnt(k)` elements starting at `mem_addr` are read, so `mem_addr` only needs to be valid for that many elements.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_expandloadu_epi64)
+#[inline]
+#[target_feature(enable = "avx512f,avx512vl")]
+#[cfg_attr(test, assert_instr(vpexpandq))]
+pub unsafe fn _mm256_mask_expandloadu_epi64(
+    src: __m256i,
+    k: __mmask8,
+    mem_addr: *const i64,
+) -> __m256i {
+    let mut dst: __m256i = src;
+    asm!(
+        "vpexpandq {dst}{{{k}}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = inout(ymm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Load contiguous active 64-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// Only the `popcnt(k)` elements starting at `mem_addr` are read, so `mem_addr` only needs to be valid for that many elements.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_expandloadu_epi64)
+#[inline]
+#[target_feature(enable = "avx512f,avx512vl")]
+#[cfg_attr(test, assert_instr(vpexpandq))]
+pub unsafe fn _mm256_maskz_expandloadu_epi64(k: __mmask8, mem_addr: *const i64) -> __m256i {
+    let dst: __m256i;
+    asm!(
+        "vpexpandq {dst}{{{k}}}{{z}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = out(ymm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Contiguously store the active 64-bit integers in a (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+///
+/// Only the `popcnt(k)` active elements are written, to consecutive locations starting at `base_addr`; the memory after them is neither read nor written, so `base_addr` only needs to be valid for that many elements.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_compressstoreu_epi64)
+#[inline]
+#[target_feature(enable = "avx512f,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcompressq))]
+pub unsafe fn _mm_mask_compressstoreu_epi64(base_addr: *mut i64, k: __mmask8, a: __m128i) {
+    vcompressstoreq128(base_addr as *mut _, a.as_i64x2(), k)
+}
+
+/// Load contiguous active 64-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// Only the `popcnt(k)` elements starting at `mem_addr` are read, so `mem_addr` only needs to be valid for that many elements.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_expandloadu_epi64)
+#[inline]
+#[target_feature(enable = "avx512f,avx512vl")]
+#[cfg_attr(test, assert_instr(vpexpandq))]
+pub unsafe fn _mm_mask_expandloadu_epi64(
+    src: __m128i,
+    k: __mmask8,
+    mem_addr: *const i64,
+) -> __m128i {
+    let mut dst: __m128i = src;
+    asm!(
+        "vpexpandq {dst}{{{k}}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = inout(xmm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Load contiguous active 64-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// Only the `popcnt(k)` elements starting at `mem_addr` are read, so `mem_addr` only needs to be valid for that many elements.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_expandloadu_epi64)
+#[inline]
+#[target_feature(enable = "avx512f,avx512vl")]
+#[cfg_attr(test, assert_instr(vpexpandq))]
+pub unsafe fn _mm_maskz_expandloadu_epi64(k: __mmask8, mem_addr: *const i64) -> __m128i {
+    let dst: __m128i;
+    asm!(
+        "vpexpandq {dst}{{{k}}}{{z}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = out(xmm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Contiguously store the active single-precision (32-bit) floating-point elements in a (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+///
+/// Only the `popcnt(k)` active elements are written, to consecutive locations starting at `base_addr`; the memory after them is neither read nor written, so `base_addr` only needs to be valid for that many elements.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_compressstoreu_ps)
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vcompressps))]
+pub unsafe fn _mm512_mask_compressstoreu_ps(base_addr: *mut f32, k: __mmask16, a: __m512) {
+    vcompressstoreps(base_addr as *mut _, a.as_f32x16(), k)
+}
+
+/// Load contiguous active single-precision (32-bit) floating-point elements from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// Only the `popcnt(k)` elements starting at `mem_addr` are read, so `mem_addr` only needs to be valid for that many elements.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_expandloadu_ps)
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vexpandps))]
+pub unsafe fn _mm512_mask_expandloadu_ps(
+    src: __m512,
+    k: __mmask16,
+    mem_addr: *const f32,
+) -> __m512 {
+    let mut dst: __m512 = src;
+    asm!(
+        "vexpandps {dst}{{{k}}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = inout(zmm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Load contiguous active single-precision (32-bit) floating-point elements from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// Only the `popcnt(k)` elements starting at `mem_addr` are read, so `mem_addr` only needs to be valid for that many elements.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_expandloadu_ps)
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vexpandps))]
+pub unsafe fn _mm512_maskz_expandloadu_ps(k: __mmask16, mem_addr: *const f32) -> __m512 {
+    let dst: __m512;
+    asm!(
+        "vexpandps {dst}{{{k}}}{{z}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = out(zmm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Contiguously store the active single-precision (32-bit) floating-point elements in a (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+///
+/// Only the `popcnt(k)` active elements are written, to consecutive locations starting at `base_addr`; the memory after them is neither read nor written, so `base_addr` only needs to be valid for that many elements.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_compressstoreu_ps)
+#[inline]
+#[target_feature(enable = "avx512f,avx512vl")]
+#[cfg_attr(test, assert_instr(vcompressps))]
+pub unsafe fn _mm256_mask_compressstoreu_ps(base_addr: *mut f32, k: __mmask8, a: __m256) {
+    vcompressstoreps256(base_addr as *mut _, a.as_f32x8(), k)
+}
+
+/// Load contiguous active single-precision (32-bit) floating-point elements from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// Only the `popcnt(k)` elements starting at `mem_addr` are read, so `mem_addr` only needs to be valid for that many elements.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_expandloadu_ps)
+#[inline]
+#[target_feature(enable = "avx512f,avx512vl")]
+#[cfg_attr(test, assert_instr(vexpandps))]
+pub unsafe fn _mm256_mask_expandloadu_ps(src: __m256, k: __mmask8, mem_addr: *const f32) -> __m256 {
+    let mut dst: __m256 = src;
+    asm!(
+        "vexpandps {dst}{{{k}}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = inout(ymm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Load contiguous active single-precision (32-bit) floating-point elements from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// Only the `popcnt(k)` elements starting at `mem_addr` are read, so `mem_addr` only needs to be valid for that many elements.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_expandloadu_ps)
+#[inline]
+#[target_feature(enable = "avx512f,avx512vl")]
+#[cfg_attr(test, assert_instr(vexpandps))]
+pub unsafe fn _mm256_maskz_expandloadu_ps(k: __mmask8, mem_addr: *const f32) -> __m256 {
+    let dst: __m256;
+    asm!(
+        "vexpandps {dst}{{{k}}}{{z}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = out(ymm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Contiguously store the active single-precision (32-bit) floating-point elements in a (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+///
+/// Only the `popcnt(k)` active elements are written, to consecutive locations starting at `base_addr`; the memory after them is neither read nor written, so `base_addr` only needs to be valid for that many elements.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_compressstoreu_ps)
+#[inline]
+#[target_feature(enable = "avx512f,avx512vl")]
+#[cfg_attr(test, assert_instr(vcompressps))]
+pub unsafe fn _mm_mask_compressstoreu_ps(base_addr: *mut f32, k: __mmask8, a: __m128) {
+    vcompressstoreps128(base_addr as *mut _, a.as_f32x4(), k)
+}
+
+/// Load contiguous active single-precision (32-bit) floating-point elements from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// Only the `popcnt(k)` elements starting at `mem_addr` are read, so `mem_addr` only needs to be valid for that many elements.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_expandloadu_ps)
+#[inline]
+#[target_feature(enable = "avx512f,avx512vl")]
+#[cfg_attr(test, assert_instr(vexpandps))]
+pub unsafe fn _mm_mask_expandloadu_ps(src: __m128, k: __mmask8, mem_addr: *const f32) -> __m128 {
+    let mut dst: __m128 = src;
+    asm!(
+        "vexpandps {dst}{{{k}}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = inout(xmm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Load contiguous active single-precision (32-bit) floating-point elements from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// Only the `popcnt(k)` elements starting at `mem_addr` are read, so `mem_addr` only needs to be valid for that many elements.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_expandloadu_ps)
+#[inline]
+#[target_feature(enable = "avx512f,avx512vl")]
+#[cfg_attr(test, assert_instr(vexpandps))]
+pub unsafe fn _mm_maskz_expandloadu_ps(k: __mmask8, mem_addr: *const f32) -> __m128 {
+    let dst: __m128;
+    asm!(
+        "vexpandps {dst}{{{k}}}{{z}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = out(xmm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Contiguously store the active double-precision (64-bit) floating-point elements in a (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+///
+/// Only the `popcnt(k)` active elements are written, to consecutive locations starting at `base_addr`; the memory after them is neither read nor written, so `base_addr` only needs to be valid for that many elements.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_compressstoreu_pd)
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vcompresspd))]
+pub unsafe fn _mm512_mask_compressstoreu_pd(base_addr: *mut f64, k: __mmask8, a: __m512d) {
+    vcompressstorepd(base_addr as *mut _, a.as_f64x8(), k)
+}
+
+/// Load contiguous active double-precision (64-bit) floating-point elements from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// Only the `popcnt(k)` elements starting at `mem_addr` are read, so `mem_addr` only needs to be valid for that many elements.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_expandloadu_pd)
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vexpandpd))]
+pub unsafe fn _mm512_mask_expandloadu_pd(
+    src: __m512d,
+    k: __mmask8,
+    mem_addr: *const f64,
+) -> __m512d {
+    let mut dst: __m512d = src;
+    asm!(
+        "vexpandpd {dst}{{{k}}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = inout(zmm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Load contiguous active double-precision (64-bit) floating-point elements from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// Only the `popcnt(k)` elements starting at `mem_addr` are read, so `mem_addr` only needs to be valid for that many elements.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_expandloadu_pd)
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vexpandpd))]
+pub unsafe fn _mm512_maskz_expandloadu_pd(k: __mmask8, mem_addr: *const f64) -> __m512d {
+    let dst: __m512d;
+    asm!(
+        "vexpandpd {dst}{{{k}}}{{z}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = out(zmm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Contiguously store the active double-precision (64-bit) floating-point elements in a (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+///
+/// Only the `popcnt(k)` active elements are written, to consecutive locations starting at `base_addr`; the memory after them is neither read nor written, so `base_addr` only needs to be valid for that many elements.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_compressstoreu_pd)
+#[inline]
+#[target_feature(enable = "avx512f,avx512vl")]
+#[cfg_attr(test, assert_instr(vcompresspd))]
+pub unsafe fn _mm256_mask_compressstoreu_pd(base_addr: *mut f64, k: __mmask8, a: __m256d) {
+    vcompressstorepd256(base_addr as *mut _, a.as_f64x4(), k)
+}
+
+/// Load contiguous active double-precision (64-bit) floating-point elements from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// Only the `popcnt(k)` elements starting at `mem_addr` are read, so `mem_addr` only needs to be valid for that many elements.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_expandloadu_pd)
+#[inline]
+#[target_feature(enable = "avx512f,avx512vl")]
+#[cfg_attr(test, assert_instr(vexpandpd))]
+pub unsafe fn _mm256_mask_expandloadu_pd(
+    src: __m256d,
+    k: __mmask8,
+    mem_addr: *const f64,
+) -> __m256d {
+    let mut dst: __m256d = src;
+    asm!(
+        "vexpandpd {dst}{{{k}}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = inout(ymm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Load contiguous active double-precision (64-bit) floating-point elements from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// Only the `popcnt(k)` elements starting at `mem_addr` are read, so `mem_addr` only needs to be valid for that many elements.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_expandloadu_pd)
+#[inline]
+#[target_feature(enable = "avx512f,avx512vl")]
+#[cfg_attr(test, assert_instr(vexpandpd))]
+pub unsafe fn _mm256_maskz_expandloadu_pd(k: __mmask8, mem_addr: *const f64) -> __m256d {
+    let dst: __m256d;
+    asm!(
+        "vexpandpd {dst}{{{k}}}{{z}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = out(ymm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Contiguously store the active double-precision (64-bit) floating-point elements in a (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+///
+/// Only the `popcnt(k)` active elements are written, to consecutive locations starting at `base_addr`; the memory after them is neither read nor written, so `base_addr` only needs to be valid for that many elements.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_compressstoreu_pd)
+#[inline]
+#[target_feature(enable = "avx512f,avx512vl")]
+#[cfg_attr(test, assert_instr(vcompresspd))]
+pub unsafe fn _mm_mask_compressstoreu_pd(base_addr: *mut f64, k: __mmask8, a: __m128d) {
+    vcompressstorepd128(base_addr as *mut _, a.as_f64x2(), k)
+}
+
+/// Load contiguous active double-precision (64-bit) floating-point elements from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// Only the `popcnt(k)` elements starting at `mem_addr` are read, so `mem_addr` only needs to be valid for that many elements.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_expandloadu_pd)
+#[inline]
+#[target_feature(enable = "avx512f,avx512vl")]
+#[cfg_attr(test, assert_instr(vexpandpd))]
+pub unsafe fn _mm_mask_expandloadu_pd(src: __m128d, k: __mmask8, mem_addr: *const f64) -> __m128d {
+    let mut dst: __m128d = src;
+    asm!(
+        "vexpandpd {dst}{{{k}}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = inout(xmm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Load contiguous active double-precision (64-bit) floating-point elements from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// Only the `popcnt(k)` elements starting at `mem_addr` are read, so `mem_addr` only needs to be valid for that many elements.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_expandloadu_pd)
+#[inline]
+#[target_feature(enable = "avx512f,avx512vl")]
+#[cfg_attr(test, assert_instr(vexpandpd))]
+pub unsafe fn _mm_maskz_expandloadu_pd(k: __mmask8, mem_addr: *const f64) -> __m128d {
+    let dst: __m128d;
+    asm!(
+        "vexpandpd {dst}{{{k}}}{{z}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = out(xmm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
 /// Rotate the bits in each packed 32-bit integer in a to the left by the number of bits specified in imm8, and store the results in dst.
 ///
 /// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_rol_epi32&expand=4685)
@@ -25659,6 +26314,72 @@ pub unsafe fn _mm512_mask2int(k1: __mmask16) -> i32 {
     transmute(r)
 }
 
+/// Store 16-bit mask from a into memory.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_store_mask16)
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(kmovw))]
+pub unsafe fn _store_mask16(mem_addr: *mut __mmask16, a: __mmask16) {
+    asm!(
+        "kmovw word ptr [{p}], {a}",
+        p = in(reg) mem_addr,
+        a = in(kreg) a,
+        options(nostack, preserves_flags)
+    );
+}
+
+/// Load 16-bit mask from memory into k.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_load_mask16)
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(kmovw))]
+pub unsafe fn _load_mask16(mem_addr: *const __mmask16) -> __mmask16 {
+    let r: __mmask16;
+    asm!(
+        "kmovw {r}, word ptr [{p}]",
+        p = in(reg) mem_addr,
+        r = lateout(kreg) r,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    r
+}
+
+/// Convert 16-bit mask a into an integer value, and store the result in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_cvtmask16_u32)
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(kmovw))]
+pub unsafe fn _cvtmask16_u32(a: __mmask16) -> u32 {
+    let r: u32;
+    asm!(
+        "kmovw {r:e}, {a}",
+        a = in(kreg) a,
+        r = lateout(reg) r,
+        options(pure, nomem, nostack, preserves_flags)
+    );
+    r
+}
+
+/// Convert integer value a into a 16-bit mask, and store the result in k.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_cvtu32_mask16)
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(kmovw))]
+pub unsafe fn _cvtu32_mask16(a: u32) -> __mmask16 {
+    let r: __mmask16;
+    asm!(
+        "kmovw {r}, {a:e}",
+        a = in(reg) a,
+        r = lateout(kreg) r,
+        options(pure, nomem, nostack, preserves_flags)
+    );
+    r
+}
+
 /// Unpack and interleave 8 bits from masks a and b, and store the 16-bit result in k.
 ///
 /// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=512_kunpackb&expand=3280)
@@ -25666,8 +26387,8 @@ pub unsafe fn _mm512_mask2int(k1: __mmask16) -> i32 {
 #[target_feature(enable = "avx512f")]
 #[cfg_attr(test, assert_instr(mov))] // generate normal and code instead of kunpckbw
 pub unsafe fn _mm512_kunpackb(a: __mmask16, b: __mmask16) -> __mmask16 {
-    let a = a & 0b00000000_11111111;
-    let b = b & 0b11111111_00000000;
+    let a = (a & 0b00000000_11111111) << 8;
+    let b = b & 0b00000000_11111111;
     transmute(a | b)
 }
 
@@ -25676,14 +26397,126 @@ pub unsafe fn _mm512_kunpackb(a: __mmask16, b: __mmask16) -> __mmask16 {
 /// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=512_kortestc&expand=3247)
 #[inline]
 #[target_feature(enable = "avx512f")]
-#[cfg_attr(test, assert_instr(cmp))] // generate normal and code instead of kortestw
+#[cfg_attr(test, assert_instr(kortestw))]
 pub unsafe fn _mm512_kortestc(a: __mmask16, b: __mmask16) -> i32 {
-    let r = a | b;
-    if r == 0b11111111_11111111 {
-        1
-    } else {
-        0
-    }
+    let r: u8;
+    asm!(
+        "kortestw {a}, {b}",
+        "setc {r}",
+        a = in(kreg) a,
+        b = in(kreg) b,
+        r = out(reg_byte) r,
+        options(pure, nomem, nostack)
+    );
+    r as i32
+}
+
+/// Performs bitwise OR between k1 and k2, storing the result in dst. ZF flag is set if dst is 0.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=512_kortestz)
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(kortestw))]
+pub unsafe fn _mm512_kortestz(a: __mmask16, b: __mmask16) -> i32 {
+    let r: u8;
+    asm!(
+        "kortestw {a}, {b}",
+        "setz {r}",
+        a = in(kreg) a,
+        b = in(kreg) b,
+        r = out(reg_byte) r,
+        options(pure, nomem, nostack)
+    );
+    r as i32
+}
+
+/// Shift the bits of 16-bit mask a left by count while shifting in zeros, and store the least significant 16 bits of the result in k.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kshiftli_mask16)
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(kshiftlw, COUNT = 5))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _kshiftli_mask16<const COUNT: u32>(a: __mmask16) -> __mmask16 {
+    static_assert_imm_u8!(COUNT);
+    let r: __mmask16;
+    asm!(
+        "kshiftlw {r}, {a}, {count}",
+        a = in(kreg) a,
+        r = lateout(kreg) r,
+        count = const COUNT,
+        options(pure, nomem, nostack, preserves_flags)
+    );
+    r
+}
+
+/// Shift the bits of 16-bit mask a right by count while shifting in zeros, and store the least significant 16 bits of the result in k.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kshiftri_mask16)
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(kshiftrw, COUNT = 5))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _kshiftri_mask16<const COUNT: u32>(a: __mmask16) -> __mmask16 {
+    static_assert_imm_u8!(COUNT);
+    let r: __mmask16;
+    asm!(
+        "kshiftrw {r}, {a}, {count}",
+        a = in(kreg) a,
+        r = lateout(kreg) r,
+        count = const COUNT,
+        options(pure, nomem, nostack, preserves_flags)
+    );
+    r
+}
+
+/// Compute the bitwise OR of 16-bit masks a and b. If the result is all zeros, store 1 in dst, otherwise store 0 in dst. If the result is all ones, store 1 in all_ones, otherwise store 0 in all_ones.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kortest_mask16_u8)
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(kortestw))]
+pub unsafe fn _kortest_mask16_u8(a: __mmask16, b: __mmask16, all_ones: *mut u8) -> u8 {
+    *all_ones = _kortestc_mask16_u8(a, b);
+    _kortestz_mask16_u8(a, b)
+}
+
+/// Compute the bitwise OR of 16-bit masks a and b. If the result is all ones, store 1 in dst, otherwise store 0 in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kortestc_mask16_u8)
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(kortestw))]
+pub unsafe fn _kortestc_mask16_u8(a: __mmask16, b: __mmask16) -> u8 {
+    let r: u8;
+    asm!(
+        "kortestw {a}, {b}",
+        "setc {r}",
+        a = in(kreg) a,
+        b = in(kreg) b,
+        r = out(reg_byte) r,
+        options(pure, nomem, nostack)
+    );
+    r
+}
+
+/// Compute the bitwise OR of 16-bit masks a and b. If the result is all zeros, store 1 in dst, otherwise store 0 in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kortestz_mask16_u8)
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(kortestw))]
+pub unsafe fn _kortestz_mask16_u8(a: __mmask16, b: __mmask16) -> u8 {
+    let r: u8;
+    asm!(
+        "kortestw {a}, {b}",
+        "setz {r}",
+        a = in(kreg) a,
+        b = in(kreg) b,
+        r = out(reg_byte) r,
+        options(pure, nomem, nostack)
+    );
+    r
 }
 
 /// Compute the bitwise AND of packed 32-bit integers in a and b, producing intermediate 32-bit values, and set the corresponding bit in result mask k if the intermediate value is non-zero.
@@ -25982,6 +26815,7 @@ pub unsafe fn _mm_mask_testn_epi64_mask(k: __mmask8, a: __m128i, b: __m128i) ->
 #[cfg_attr(test, assert_instr(vmovntps))]
 #[allow(clippy::cast_ptr_alignment)]
 pub unsafe fn _mm512_stream_ps(mem_addr: *mut f32, a: __m512) {
+    debug_assert_aligned!(mem_addr, 64);
     intrinsics::nontemporal_store(mem_addr as *mut __m512, a);
 }
 
@@ -25993,6 +26827,7 @@ pub unsafe fn _mm512_stream_ps(mem_addr: *mut f32, a: __m512) {
 #[cfg_attr(test, assert_instr(vmovntps))] //should be vmovntpd
 #[allow(clippy::cast_ptr_alignment)]
 pub unsafe fn _mm512_stream_pd(mem_addr: *mut f64, a: __m512d) {
+    debug_assert_aligned!(mem_addr, 64);
     intrinsics::nontemporal_store(mem_addr as *mut __m512d, a);
 }
 
@@ -26004,15 +26839,34 @@ pub unsafe fn _mm512_stream_pd(mem_addr: *mut f64, a: __m512d) {
 #[cfg_attr(test, assert_instr(vmovntps))] //should be vmovntdq
 #[allow(clippy::cast_ptr_alignment)]
 pub unsafe fn _mm512_stream_si512(mem_addr: *mut i64, a: __m512i) {
+    debug_assert_aligned!(mem_addr, 64);
     intrinsics::nontemporal_store(mem_addr as *mut __m512i, a);
 }
 
+/// Load 512-bits of integer data from memory into dst using a non-temporal memory hint. mem_addr must be aligned on a 64-byte boundary or a general-protection exception may be generated. The hint only has an effect on write-combining memory; on write-back memory this behaves like a regular aligned load.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_stream_load_si512)
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vmovntdqa))]
+pub unsafe fn _mm512_stream_load_si512(mem_addr: *const __m512i) -> __m512i {
+    debug_assert_aligned!(mem_addr, 64);
+    let dst: __m512i;
+    asm!(
+        "vmovntdqa {dst}, [{p}]",
+        dst = lateout(zmm_reg) dst,
+        p = in(reg) mem_addr,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
 /// Sets packed 32-bit integers in `dst` with the supplied values.
 ///
 /// [Intel's documentation]( https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_set_ps&expand=4931)
 #[inline]
 #[target_feature(enable = "avx512f")]
-pub unsafe fn _mm512_set_ps(
+pub const unsafe fn _mm512_set_ps(
     e0: f32,
     e1: f32,
     e2: f32,
@@ -26041,7 +26895,7 @@ pub unsafe fn _mm512_set_ps(
 /// [Intel's documentation]( https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_setr_ps&expand=5008)
 #[inline]
 #[target_feature(enable = "avx512f")]
-pub unsafe fn _mm512_setr_ps(
+pub const unsafe fn _mm512_setr_ps(
     e0: f32,
     e1: f32,
     e2: f32,
@@ -26070,7 +26924,7 @@ pub unsafe fn _mm512_setr_ps(
 /// [Intel's documentation]( https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_set1_pd&expand=4975)
 #[inline]
 #[target_feature(enable = "avx512f")]
-pub unsafe fn _mm512_set1_pd(a: f64) -> __m512d {
+pub const unsafe fn _mm512_set1_pd(a: f64) -> __m512d {
     transmute(f64x8::splat(a))
 }
 
@@ -26079,7 +26933,7 @@ pub unsafe fn _mm512_set1_pd(a: f64) -> __m512d {
 /// [Intel's documentation]( https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_set1_ps&expand=4981)
 #[inline]
 #[target_feature(enable = "avx512f")]
-pub unsafe fn _mm512_set1_ps(a: f32) -> __m512 {
+pub const unsafe fn _mm512_set1_ps(a: f32) -> __m512 {
     transmute(f32x16::splat(a))
 }
 
@@ -26088,7 +26942,7 @@ pub unsafe fn _mm512_set1_ps(a: f32) -> __m512 {
 /// [Intel's documentation]( https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_set_epi32&expand=4908)
 #[inline]
 #[target_feature(enable = "avx512f")]
-pub unsafe fn _mm512_set_epi32(
+pub const unsafe fn _mm512_set_epi32(
     e15: i32,
     e14: i32,
     e13: i32,
@@ -26116,7 +26970,7 @@ pub unsafe fn _mm512_set_epi32(
 /// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_set1_epi8&expand=4972)
 #[inline]
 #[target_feature(enable = "avx512f")]
-pub unsafe fn _mm512_set1_epi8(a: i8) -> __m512i {
+pub const unsafe fn _mm512_set1_epi8(a: i8) -> __m512i {
     transmute(i8x64::splat(a))
 }
 
@@ -26125,14 +26979,14 @@ pub unsafe fn _mm512_set1_epi8(a: i8) -> __m512i {
 /// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_set1_epi16&expand=4944)
 #[inline]
 #[target_feature(enable = "avx512f")]
-pub unsafe fn _mm512_set1_epi16(a: i16) -> __m512i {
+pub const unsafe fn _mm512_set1_epi16(a: i16) -> __m512i {
     transmute(i16x32::splat(a))
 }
 
 /// Broadcast 32-bit integer `a` to all elements of `dst`.
 #[inline]
 #[target_feature(enable = "avx512f")]
-pub unsafe fn _mm512_set1_epi32(a: i32) -> __m512i {
+pub const unsafe fn _mm512_set1_epi32(a: i32) -> __m512i {
     transmute(i32x16::splat(a))
 }
 
@@ -26210,7 +27064,7 @@ pub unsafe fn _mm_maskz_set1_epi32(k: __mmask8, a: i32) -> __m128i {
 /// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_set1_epi64&expand=4961)
 #[inline]
 #[target_feature(enable = "avx512f")]
-pub unsafe fn _mm512_set1_epi64(a: i64) -> __m512i {
+pub const unsafe fn _mm512_set1_epi64(a: i64) -> __m512i {
     transmute(i64x8::splat(a))
 }
 
@@ -26288,7 +27142,7 @@ pub unsafe fn _mm_maskz_set1_epi64(k: __mmask8, a: i64) -> __m128i {
 /// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_set4_epi64&expand=4983)
 #[inline]
 #[target_feature(enable = "avx512f")]
-pub unsafe fn _mm512_set4_epi64(d: i64, c: i64, b: i64, a: i64) -> __m512i {
+pub const unsafe fn _mm512_set4_epi64(d: i64, c: i64, b: i64, a: i64) -> __m512i {
     let r = i64x8::new(d, c, b, a, d, c, b, a);
     transmute(r)
 }
@@ -26298,7 +27152,7 @@ pub unsafe fn _mm512_set4_epi64(d: i64, c: i64, b: i64, a: i64) -> __m512i {
 /// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_setr4_epi64&expand=5010)
 #[inline]
 #[target_feature(enable = "avx512f")]
-pub unsafe fn _mm512_setr4_epi64(d: i64, c: i64, b: i64, a: i64) -> __m512i {
+pub const unsafe fn _mm512_setr4_epi64(d: i64, c: i64, b: i64, a: i64) -> __m512i {
     let r = i64x8::new(a, b, c, d, a, b, c, d);
     transmute(r)
 }
@@ -30018,6 +30872,180 @@ pub unsafe fn _mm_storeu_epi32(mem_addr: *mut i32, a: __m128i) {
     ptr::write_unaligned(mem_addr as *mut __m128i, a);
 }
 
+/// Load packed 32-bit integers from memory into dst using writemask k (elements are copied from src when the corresponding mask bit is not set). mem_addr does not need to be aligned on any particular boundary.
+///
+/// Elements whose mask bit is not set are not read, so they do not need to be valid memory.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_loadu_epi32)
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vmovdqu32))]
+pub unsafe fn _mm512_mask_loadu_epi32(src: __m512i, k: __mmask16, mem_addr: *const i32) -> __m512i {
+    let mut dst: __m512i = src;
+    asm!(
+        "vmovdqu32 {dst}{{{k}}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = inout(zmm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Load packed 32-bit integers from memory into dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set). mem_addr does not need to be aligned on any particular boundary.
+///
+/// Elements whose mask bit is not set are not read, so they do not need to be valid memory.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_loadu_epi32)
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vmovdqu32))]
+pub unsafe fn _mm512_maskz_loadu_epi32(k: __mmask16, mem_addr: *const i32) -> __m512i {
+    let dst: __m512i;
+    asm!(
+        "vmovdqu32 {dst}{{{k}}}{{z}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = out(zmm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Store packed 32-bit integers from a into memory using writemask k. mem_addr does not need to be aligned on any particular boundary.
+///
+/// Elements whose mask bit is not set are not written, so they do not need to be valid memory.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_storeu_epi32)
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vmovdqu32))]
+pub unsafe fn _mm512_mask_storeu_epi32(mem_addr: *mut i32, k: __mmask16, a: __m512i) {
+    asm!(
+        "vmovdqu32 [{p}]{{{k}}}, {a}",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        a = in(zmm_reg) a,
+        options(nostack, preserves_flags)
+    );
+}
+
+/// Load packed 32-bit integers from memory into dst using writemask k (elements are copied from src when the corresponding mask bit is not set). mem_addr does not need to be aligned on any particular boundary.
+///
+/// Elements whose mask bit is not set are not read, so they do not need to be valid memory.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_loadu_epi32)
+#[inline]
+#[target_feature(enable = "avx512f,avx512vl")]
+#[cfg_attr(test, assert_instr(vmovdqu32))]
+pub unsafe fn _mm256_mask_loadu_epi32(src: __m256i, k: __mmask8, mem_addr: *const i32) -> __m256i {
+    let mut dst: __m256i = src;
+    asm!(
+        "vmovdqu32 {dst}{{{k}}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = inout(ymm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Load packed 32-bit integers from memory into dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set). mem_addr does not need to be aligned on any particular boundary.
+///
+/// Elements whose mask bit is not set are not read, so they do not need to be valid memory.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_loadu_epi32)
+#[inline]
+#[target_feature(enable = "avx512f,avx512vl")]
+#[cfg_attr(test, assert_instr(vmovdqu32))]
+pub unsafe fn _mm256_maskz_loadu_epi32(k: __mmask8, mem_addr: *const i32) -> __m256i {
+    let dst: __m256i;
+    asm!(
+        "vmovdqu32 {dst}{{{k}}}{{z}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = out(ymm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Store packed 32-bit integers from a into memory using writemask k. mem_addr does not need to be aligned on any particular boundary.
+///
+/// Elements whose mask bit is not set are not written, so they do not need to be valid memory.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_storeu_epi32)
+#[inline]
+#[target_feature(enable = "avx512f,avx512vl")]
+#[cfg_attr(test, assert_instr(vmovdqu32))]
+pub unsafe fn _mm256_mask_storeu_epi32(mem_addr: *mut i32, k: __mmask8, a: __m256i) {
+    asm!(
+        "vmovdqu32 [{p}]{{{k}}}, {a}",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        a = in(ymm_reg) a,
+        options(nostack, preserves_flags)
+    );
+}
+
+/// Load packed 32-bit integers from memory into dst using writemask k (elements are copied from src when the corresponding mask bit is not set). mem_addr does not need to be aligned on any particular boundary.
+///
+/// Elements whose mask bit is not set are not read, so they do not need to be valid memory.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_loadu_epi32)
+#[inline]
+#[target_feature(enable = "avx512f,avx512vl")]
+#[cfg_attr(test, assert_instr(vmovdqu32))]
+pub unsafe fn _mm_mask_loadu_epi32(src: __m128i, k: __mmask8, mem_addr: *const i32) -> __m128i {
+    let mut dst: __m128i = src;
+    asm!(
+        "vmovdqu32 {dst}{{{k}}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = inout(xmm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Load packed 32-bit integers from memory into dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set). mem_addr does not need to be aligned on any particular boundary.
+///
+/// Elements whose mask bit is not set are not read, so they do not need to be valid memory.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_loadu_epi32)
+#[inline]
+#[target_feature(enable = "avx512f,avx512vl")]
+#[cfg_attr(test, assert_instr(vmovdqu32))]
+pub unsafe fn _mm_maskz_loadu_epi32(k: __mmask8, mem_addr: *const i32) -> __m128i {
+    let dst: __m128i;
+    asm!(
+        "vmovdqu32 {dst}{{{k}}}{{z}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = out(xmm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Store packed 32-bit integers from a into memory using writemask k. mem_addr does not need to be aligned on any particular boundary.
+///
+/// Elements whose mask bit is not set are not written, so they do not need to be valid memory.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_storeu_epi32)
+#[inline]
+#[target_feature(enable = "avx512f,avx512vl")]
+#[cfg_attr(test, assert_instr(vmovdqu32))]
+pub unsafe fn _mm_mask_storeu_epi32(mem_addr: *mut i32, k: __mmask8, a: __m128i) {
+    asm!(
+        "vmovdqu32 [{p}]{{{k}}}, {a}",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        a = in(xmm_reg) a,
+        options(nostack, preserves_flags)
+    );
+}
+
 /// Load 512-bits (composed of 8 packed 64-bit integers) from memory into dst. mem_addr does not need to be aligned on any particular boundary.
 ///
 /// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_loadu_epi64&expand=3386)
@@ -30078,6 +31106,180 @@ pub unsafe fn _mm_storeu_epi64(mem_addr: *mut i64, a: __m128i) {
     ptr::write_unaligned(mem_addr as *mut __m128i, a);
 }
 
+/// Load packed 64-bit integers from memory into dst using writemask k (elements are copied from src when the corresponding mask bit is not set). mem_addr does not need to be aligned on any particular boundary.
+///
+/// Elements whose mask bit is not set are not read, so they do not need to be valid memory.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_loadu_epi64)
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vmovdqu64))]
+pub unsafe fn _mm512_mask_loadu_epi64(src: __m512i, k: __mmask8, mem_addr: *const i64) -> __m512i {
+    let mut dst: __m512i = src;
+    asm!(
+        "vmovdqu64 {dst}{{{k}}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = inout(zmm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Load packed 64-bit integers from memory into dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set). mem_addr does not need to be aligned on any particular boundary.
+///
+/// Elements whose mask bit is not set are not read, so they do not need to be valid memory.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_loadu_epi64)
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vmovdqu64))]
+pub unsafe fn _mm512_maskz_loadu_epi64(k: __mmask8, mem_addr: *const i64) -> __m512i {
+    let dst: __m512i;
+    asm!(
+        "vmovdqu64 {dst}{{{k}}}{{z}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = out(zmm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Store packed 64-bit integers from a into memory using writemask k. mem_addr does not need to be aligned on any particular boundary.
+///
+/// Elements whose mask bit is not set are not written, so they do not need to be valid memory.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_storeu_epi64)
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vmovdqu64))]
+pub unsafe fn _mm512_mask_storeu_epi64(mem_addr: *mut i64, k: __mmask8, a: __m512i) {
+    asm!(
+        "vmovdqu64 [{p}]{{{k}}}, {a}",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        a = in(zmm_reg) a,
+        options(nostack, preserves_flags)
+    );
+}
+
+/// Load packed 64-bit integers from memory into dst using writemask k (elements are copied from src when the corresponding mask bit is not set). mem_addr does not need to be aligned on any particular boundary.
+///
+/// Elements whose mask bit is not set are not read, so they do not need to be valid memory.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_loadu_epi64)
+#[inline]
+#[target_feature(enable = "avx512f,avx512vl")]
+#[cfg_attr(test, assert_instr(vmovdqu64))]
+pub unsafe fn _mm256_mask_loadu_epi64(src: __m256i, k: __mmask8, mem_addr: *const i64) -> __m256i {
+    let mut dst: __m256i = src;
+    asm!(
+        "vmovdqu64 {dst}{{{k}}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = inout(ymm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Load packed 64-bit integers from memory into dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set). mem_addr does not need to be aligned on any particular boundary.
+///
+/// Elements whose mask bit is not set are not read, so they do not need to be valid memory.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_loadu_epi64)
+#[inline]
+#[target_feature(enable = "avx512f,avx512vl")]
+#[cfg_attr(test, assert_instr(vmovdqu64))]
+pub unsafe fn _mm256_maskz_loadu_epi64(k: __mmask8, mem_addr: *const i64) -> __m256i {
+    let dst: __m256i;
+    asm!(
+        "vmovdqu64 {dst}{{{k}}}{{z}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = out(ymm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Store packed 64-bit integers from a into memory using writemask k. mem_addr does not need to be aligned on any particular boundary.
+///
+/// Elements whose mask bit is not set are not written, so they do not need to be valid memory.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_storeu_epi64)
+#[inline]
+#[target_feature(enable = "avx512f,avx512vl")]
+#[cfg_attr(test, assert_instr(vmovdqu64))]
+pub unsafe fn _mm256_mask_storeu_epi64(mem_addr: *mut i64, k: __mmask8, a: __m256i) {
+    asm!(
+        "vmovdqu64 [{p}]{{{k}}}, {a}",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        a = in(ymm_reg) a,
+        options(nostack, preserves_flags)
+    );
+}
+
+/// Load packed 64-bit integers from memory into dst using writemask k (elements are copied from src when the corresponding mask bit is not set). mem_addr does not need to be aligned on any particular boundary.
+///
+/// Elements whose mask bit is not set are not read, so they do not need to be valid memory.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_loadu_epi64)
+#[inline]
+#[target_feature(enable = "avx512f,avx512vl")]
+#[cfg_attr(test, assert_instr(vmovdqu64))]
+pub unsafe fn _mm_mask_loadu_epi64(src: __m128i, k: __mmask8, mem_addr: *const i64) -> __m128i {
+    let mut dst: __m128i = src;
+    asm!(
+        "vmovdqu64 {dst}{{{k}}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = inout(xmm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Load packed 64-bit integers from memory into dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set). mem_addr does not need to be aligned on any particular boundary.
+///
+/// Elements whose mask bit is not set are not read, so they do not need to be valid memory.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_loadu_epi64)
+#[inline]
+#[target_feature(enable = "avx512f,avx512vl")]
+#[cfg_attr(test, assert_instr(vmovdqu64))]
+pub unsafe fn _mm_maskz_loadu_epi64(k: __mmask8, mem_addr: *const i64) -> __m128i {
+    let dst: __m128i;
+    asm!(
+        "vmovdqu64 {dst}{{{k}}}{{z}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = out(xmm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Store packed 64-bit integers from a into memory using writemask k. mem_addr does not need to be aligned on any particular boundary.
+///
+/// Elements whose mask bit is not set are not written, so they do not need to be valid memory.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_storeu_epi64)
+#[inline]
+#[target_feature(enable = "avx512f,avx512vl")]
+#[cfg_attr(test, assert_instr(vmovdqu64))]
+pub unsafe fn _mm_mask_storeu_epi64(mem_addr: *mut i64, k: __mmask8, a: __m128i) {
+    asm!(
+        "vmovdqu64 [{p}]{{{k}}}, {a}",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        a = in(xmm_reg) a,
+        options(nostack, preserves_flags)
+    );
+}
+
 /// Load 512-bits of integer data from memory into dst. mem_addr does not need to be aligned on any particular boundary.
 ///
 /// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_loadu_si512&expand=3420)
@@ -30154,6 +31356,7 @@ pub unsafe fn _mm512_storeu_ps(mem_addr: *mut f32, a: __m512) {
 #[target_feature(enable = "avx512f")]
 #[cfg_attr(test, assert_instr(vmovaps))] //should be vmovdqa32
 pub unsafe fn _mm512_load_si512(mem_addr: *const i32) -> __m512i {
+    debug_assert_aligned!(mem_addr, 64);
     ptr::read(mem_addr as *const __m512i)
 }
 
@@ -30164,6 +31367,7 @@ pub unsafe fn _mm512_load_si512(mem_addr: *const i32) -> __m512i {
 #[target_feature(enable = "avx512f")]
 #[cfg_attr(test, assert_instr(vmovaps))] //should be vmovdqa32
 pub unsafe fn _mm512_store_si512(mem_addr: *mut i32, a: __m512i) {
+    debug_assert_aligned!(mem_addr, 64);
     ptr::write(mem_addr as *mut __m512i, a);
 }
 
@@ -30174,6 +31378,7 @@ pub unsafe fn _mm512_store_si512(mem_addr: *mut i32, a: __m512i) {
 #[target_feature(enable = "avx512f")]
 #[cfg_attr(test, assert_instr(vmovaps))] //should be vmovdqa32
 pub unsafe fn _mm512_load_epi32(mem_addr: *const i32) -> __m512i {
+    debug_assert_aligned!(mem_addr, 64);
     ptr::read(mem_addr as *const __m512i)
 }
 
@@ -30184,6 +31389,7 @@ pub unsafe fn _mm512_load_epi32(mem_addr: *const i32) -> __m512i {
 #[target_feature(enable = "avx512f,avx512vl")]
 #[cfg_attr(test, assert_instr(vmovaps))] //should be vmovdqa32
 pub unsafe fn _mm256_load_epi32(mem_addr: *const i32) -> __m256i {
+    debug_assert_aligned!(mem_addr, 32);
     ptr::read(mem_addr as *const __m256i)
 }
 
@@ -30194,6 +31400,7 @@ pub unsafe fn _mm256_load_epi32(mem_addr: *const i32) -> __m256i {
 #[target_feature(enable = "avx512f,avx512vl")]
 #[cfg_attr(test, assert_instr(vmovaps))] //should be vmovdqa32
 pub unsafe fn _mm_load_epi32(mem_addr: *const i32) -> __m128i {
+    debug_assert_aligned!(mem_addr, 16);
     ptr::read(mem_addr as *const __m128i)
 }
 
@@ -30204,6 +31411,7 @@ pub unsafe fn _mm_load_epi32(mem_addr: *const i32) -> __m128i {
 #[target_feature(enable = "avx512f")]
 #[cfg_attr(test, assert_instr(vmovaps))] //should be vmovdqa32
 pub unsafe fn _mm512_store_epi32(mem_addr: *mut i32, a: __m512i) {
+    debug_assert_aligned!(mem_addr, 64);
     ptr::write(mem_addr as *mut __m512i, a);
 }
 
@@ -30214,6 +31422,7 @@ pub unsafe fn _mm512_store_epi32(mem_addr: *mut i32, a: __m512i) {
 #[target_feature(enable = "avx512f,avx512vl")]
 #[cfg_attr(test, assert_instr(vmovaps))] //should be vmovdqa32
 pub unsafe fn _mm256_store_epi32(mem_addr: *mut i32, a: __m256i) {
+    debug_assert_aligned!(mem_addr, 32);
     ptr::write(mem_addr as *mut __m256i, a);
 }
 
@@ -30224,6 +31433,7 @@ pub unsafe fn _mm256_store_epi32(mem_addr: *mut i32, a: __m256i) {
 #[target_feature(enable = "avx512f,avx512vl")]
 #[cfg_attr(test, assert_instr(vmovaps))] //should be vmovdqa32
 pub unsafe fn _mm_store_epi32(mem_addr: *mut i32, a: __m128i) {
+    debug_assert_aligned!(mem_addr, 16);
     ptr::write(mem_addr as *mut __m128i, a);
 }
 
@@ -30234,6 +31444,7 @@ pub unsafe fn _mm_store_epi32(mem_addr: *mut i32, a: __m128i) {
 #[target_feature(enable = "avx512f")]
 #[cfg_attr(test, assert_instr(vmovaps))] //should be vmovdqa64
 pub unsafe fn _mm512_load_epi64(mem_addr: *const i64) -> __m512i {
+    debug_assert_aligned!(mem_addr, 64);
     ptr::read(mem_addr as *const __m512i)
 }
 
@@ -30244,6 +31455,7 @@ pub unsafe fn _mm512_load_epi64(mem_addr: *const i64) -> __m512i {
 #[target_feature(enable = "avx512f,avx512vl")]
 #[cfg_attr(test, assert_instr(vmovaps))] //should be vmovdqa64
 pub unsafe fn _mm256_load_epi64(mem_addr: *const i64) -> __m256i {
+    debug_assert_aligned!(mem_addr, 32);
     ptr::read(mem_addr as *const __m256i)
 }
 
@@ -30254,6 +31466,7 @@ pub unsafe fn _mm256_load_epi64(mem_addr: *const i64) -> __m256i {
 #[target_feature(enable = "avx512f,avx512vl")]
 #[cfg_attr(test, assert_instr(vmovaps))] //should be vmovdqa64
 pub unsafe fn _mm_load_epi64(mem_addr: *const i64) -> __m128i {
+    debug_assert_aligned!(mem_addr, 16);
     ptr::read(mem_addr as *const __m128i)
 }
 
@@ -30264,6 +31477,7 @@ pub unsafe fn _mm_load_epi64(mem_addr: *const i64) -> __m128i {
 #[target_feature(enable = "avx512f")]
 #[cfg_attr(test, assert_instr(vmovaps))] //should be vmovdqa64
 pub unsafe fn _mm512_store_epi64(mem_addr: *mut i64, a: __m512i) {
+    debug_assert_aligned!(mem_addr, 64);
     ptr::write(mem_addr as *mut __m512i, a);
 }
 
@@ -30274,6 +31488,7 @@ pub unsafe fn _mm512_store_epi64(mem_addr: *mut i64, a: __m512i) {
 #[target_feature(enable = "avx512f,avx512vl")]
 #[cfg_attr(test, assert_instr(vmovaps))] //should be vmovdqa64
 pub unsafe fn _mm256_store_epi64(mem_addr: *mut i64, a: __m256i) {
+    debug_assert_aligned!(mem_addr, 32);
     ptr::write(mem_addr as *mut __m256i, a);
 }
 
@@ -30284,6 +31499,7 @@ pub unsafe fn _mm256_store_epi64(mem_addr: *mut i64, a: __m256i) {
 #[target_feature(enable = "avx512f,avx512vl")]
 #[cfg_attr(test, assert_instr(vmovaps))] //should be vmovdqa64
 pub unsafe fn _mm_store_epi64(mem_addr: *mut i64, a: __m128i) {
+    debug_assert_aligned!(mem_addr, 16);
     ptr::write(mem_addr as *mut __m128i, a);
 }
 
@@ -30294,6 +31510,7 @@ pub unsafe fn _mm_store_epi64(mem_addr: *mut i64, a: __m128i) {
 #[target_feature(enable = "avx512f")]
 #[cfg_attr(test, assert_instr(vmovaps))]
 pub unsafe fn _mm512_load_ps(mem_addr: *const f32) -> __m512 {
+    debug_assert_aligned!(mem_addr, 64);
     ptr::read(mem_addr as *const __m512)
 }
 
@@ -30304,6 +31521,7 @@ pub unsafe fn _mm512_load_ps(mem_addr: *const f32) -> __m512 {
 #[target_feature(enable = "avx512f")]
 #[cfg_attr(test, assert_instr(vmovaps))]
 pub unsafe fn _mm512_store_ps(mem_addr: *mut f32, a: __m512) {
+    debug_assert_aligned!(mem_addr, 64);
     ptr::write(mem_addr as *mut __m512, a);
 }
 
@@ -30314,6 +31532,7 @@ pub unsafe fn _mm512_store_ps(mem_addr: *mut f32, a: __m512) {
 #[target_feature(enable = "avx512f")]
 #[cfg_attr(test, assert_instr(vmovaps))] //should be vmovapd
 pub unsafe fn _mm512_load_pd(mem_addr: *const f64) -> __m512d {
+    debug_assert_aligned!(mem_addr, 64);
     ptr::read(mem_addr as *const __m512d)
 }
 
@@ -30324,6 +31543,7 @@ pub unsafe fn _mm512_load_pd(mem_addr: *const f64) -> __m512d {
 #[target_feature(enable = "avx512f")]
 #[cfg_attr(test, assert_instr(vmovaps))] //should be vmovapd
 pub unsafe fn _mm512_store_pd(mem_addr: *mut f64, a: __m512d) {
+    debug_assert_aligned!(mem_addr, 64);
     ptr::write(mem_addr as *mut __m512d, a);
 }
 
@@ -30332,7 +31552,7 @@ pub unsafe fn _mm512_store_pd(mem_addr: *mut f64, a: __m512d) {
 /// [Intel's documentation]( https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_setr_pd&expand=5002)
 #[inline]
 #[target_feature(enable = "avx512f")]
-pub unsafe fn _mm512_setr_pd(
+pub const unsafe fn _mm512_setr_pd(
     e0: f64,
     e1: f64,
     e2: f64,
@@ -30351,7 +31571,7 @@ pub unsafe fn _mm512_setr_pd(
 /// [Intel's documentation]( https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_set_pd&expand=4924)
 #[inline]
 #[target_feature(enable = "avx512f")]
-pub unsafe fn _mm512_set_pd(
+pub const unsafe fn _mm512_set_pd(
     e0: f64,
     e1: f64,
     e2: f64,
@@ -36697,6 +37917,31 @@ extern "C" {
     #[link_name = "llvm.x86.avx512.mask.expand.q.128"]
     fn vpexpandq128(a: i64x2, src: i64x2, mask: u8) -> i64x2;
 
+    #[link_name = "llvm.x86.avx512.mask.compress.store.d.512"]
+    fn vcompressstored(mem: *mut i8, data: i32x16, mask: u16);
+    #[link_name = "llvm.x86.avx512.mask.compress.store.d.256"]
+    fn vcompressstored256(mem: *mut i8, data: i32x8, mask: u8);
+    #[link_name = "llvm.x86.avx512.mask.compress.store.d.128"]
+    fn vcompressstored128(mem: *mut i8, data: i32x4, mask: u8);
+    #[link_name = "llvm.x86.avx512.mask.compress.store.q.512"]
+    fn vcompressstoreq(mem: *mut i8, data: i64x8, mask: u8);
+    #[link_name = "llvm.x86.avx512.mask.compress.store.q.256"]
+    fn vcompressstoreq256(mem: *mut i8, data: i64x4, mask: u8);
+    #[link_name = "llvm.x86.avx512.mask.compress.store.q.128"]
+    fn vcompressstoreq128(mem: *mut i8, data: i64x2, mask: u8);
+    #[link_name = "llvm.x86.avx512.mask.compress.store.ps.512"]
+    fn vcompressstoreps(mem: *mut i8, data: f32x16, mask: u16);
+    #[link_name = "llvm.x86.avx512.mask.compress.store.ps.256"]
+    fn vcompressstoreps256(mem: *mut i8, data: f32x8, mask: u8);
+    #[link_name = "llvm.x86.avx512.mask.compress.store.ps.128"]
+    fn vcompressstoreps128(mem: *mut i8, data: f32x4, mask: u8);
+    #[link_name = "llvm.x86.avx512.mask.compress.store.pd.512"]
+    fn vcompressstorepd(mem: *mut i8, data: f64x8, mask: u8);
+    #[link_name = "llvm.x86.avx512.mask.compress.store.pd.256"]
+    fn vcompressstorepd256(mem: *mut i8, data: f64x4, mask: u8);
+    #[link_name = "llvm.x86.avx512.mask.compress.store.pd.128"]
+    fn vcompressstorepd128(mem: *mut i8, data: f64x2, mask: u8);
+
     #[link_name = "llvm.x86.avx512.mask.expand.ps.512"]
     fn vexpandps(a: f32x16, src: f32x16, mask: u16) -> f32x16;
     #[link_name = "llvm.x86.avx512.mask.expand.ps.256"]
@@ -36818,7 +38063,9 @@ mod tests {
 
     use crate::core_arch::x86::*;
     use crate::hint::black_box;
-    use crate::mem::{self};
+    use crate::mem::{self, transmute};
+    use crate::ptr;
+    use std::slice;
 
     #[simd_test(enable = "avx512f")]
     unsafe fn test_mm512_abs_epi32() {
@@ -44476,6 +45723,26 @@ mod tests {
         assert_eq_m512i(_mm512_set1_epi32(0), _mm512_setzero_si512());
     }
 
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_set_const() {
+        const A: __m512i = unsafe { _mm512_set4_epi32(3, 2, 1, 0) };
+        const LO: i64 = 1 << 32;
+        const HI: i64 = 3 << 32 | 2;
+        static B: __m512i = unsafe { _mm512_setr_epi64(LO, HI, LO, HI, LO, HI, LO, HI) };
+        const C: __m512i = unsafe { _mm512_set1_epi16(-1) };
+        static D: __m512i = unsafe { _mm512_set1_epi8(-1) };
+        const E: __m512 = unsafe { _mm512_set1_ps(1.) };
+        const F: __m512d = unsafe { _mm512_setr4_pd(1., 2., 3., 4.) };
+        const ZERO: __m512i = unsafe { _mm512_setzero_si512() };
+        const ZERO_PS: __m512 = unsafe { _mm512_setzero_ps() };
+        assert_eq_m512i(A, B);
+        assert_eq_m512i(C, D);
+        assert_eq_m512(E, _mm512_set4_ps(1., 1., 1., 1.));
+        assert_eq_m512d(F, _mm512_set_pd(4., 3., 2., 1., 4., 3., 2., 1.));
+        assert_eq_m512i(ZERO, _mm512_set1_epi64(0));
+        assert_eq_m512(ZERO_PS, _mm512_set1_ps(0.));
+    }
+
     #[simd_test(enable = "avx512f")]
     unsafe fn test_mm512_setzero_epi32() {
         assert_eq_m512i(_mm512_set1_epi32(0), _mm512_setzero_epi32());
@@ -47077,6 +48344,12 @@ mod tests {
             17., 18., 19., 20., 0., 0., 0., 0., 0., 0., 0., 0., 0., 0., 0., 0.,
         );
         assert_eq_m512(r, e);
+        let a = _mm512_castps512_ps128(black_box(_mm512_set1_ps(-1.)));
+        let r = _mm512_zextps128_ps512(a);
+        let e = _mm512_setr_ps(
+            -1., -1., -1., -1., 0., 0., 0., 0., 0., 0., 0., 0., 0., 0., 0., 0.,
+        );
+        assert_eq_m512(r, e);
     }
 
     #[simd_test(enable = "avx512f")]
@@ -47087,6 +48360,12 @@ mod tests {
             17., 18., 19., 20., 21., 22., 23., 24., 0., 0., 0., 0., 0., 0., 0., 0.,
         );
         assert_eq_m512(r, e);
+        let a = _mm512_castps512_ps256(black_box(_mm512_set1_ps(-1.)));
+        let r = _mm512_zextps256_ps512(a);
+        let e = _mm512_setr_ps(
+            -1., -1., -1., -1., -1., -1., -1., -1., 0., 0., 0., 0., 0., 0., 0., 0.,
+        );
+        assert_eq_m512(r, e);
     }
 
     #[simd_test(enable = "avx512f")]
@@ -48601,12 +49880,50 @@ mod tests {
         assert_eq!(r, e);
     }
 
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_store_mask16() {
+        let a: __mmask16 = 0b11001100_00110011;
+        let mut r = 0;
+        _store_mask16(&mut r, a);
+        assert_eq!(r, a);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_load_mask16() {
+        let p: __mmask16 = 0b11001100_00110011;
+        let r = _load_mask16(&p);
+        let e: __mmask16 = 0b11001100_00110011;
+        assert_eq!(r, e);
+        let mut m: __mmask16 = 0;
+        _store_mask16(&mut m, _load_mask16(&p));
+        assert_eq!(m, p);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_cvtmask16_u32() {
+        let a: __mmask16 = 0b11001100_00110011;
+        let r = _cvtmask16_u32(a);
+        let e: u32 = 0b11001100_00110011;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_cvtu32_mask16() {
+        let a: u32 = 0b11001100_00110011;
+        let r = _cvtu32_mask16(a);
+        let e: __mmask16 = 0b11001100_00110011;
+        assert_eq!(r, e);
+        assert_eq!(_cvtmask16_u32(_cvtu32_mask16(a)), a);
+        let r = _cvtu32_mask16(u32::MAX);
+        assert_eq!(r, __mmask16::MAX);
+    }
+
     #[simd_test(enable = "avx512f")]
     unsafe fn test_mm512_kunpackb() {
         let a: u16 = 0b11001100_00110011;
         let b: u16 = 0b00101110_00001011;
         let r = _mm512_kunpackb(a, b);
-        let e: u16 = 0b00101110_00110011;
+        let e: u16 = 0b00110011_00001011;
         assert_eq!(r, e);
     }
 
@@ -48621,6 +49938,65 @@ mod tests {
         assert_eq!(r, 1);
     }
 
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_kortestz() {
+        let a: u16 = 0b11001100_00110011;
+        let b: u16 = 0b00101110_00001011;
+        let r = _mm512_kortestz(a, b);
+        assert_eq!(r, 0);
+        let r = _mm512_kortestz(0, 0);
+        assert_eq!(r, 1);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_kshiftli_mask16() {
+        let a: __mmask16 = 0b11001100_00110011;
+        assert_eq!(_kshiftli_mask16::<0>(a), a);
+        assert_eq!(_kshiftli_mask16::<1>(a), 0b10011000_01100110);
+        assert_eq!(_kshiftli_mask16::<5>(a), 0b10000110_01100000);
+        assert_eq!(_kshiftli_mask16::<15>(a), 0b10000000_00000000);
+        assert_eq!(_kshiftli_mask16::<16>(a), 0);
+        assert_eq!(_kshiftli_mask16::<255>(a), 0);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_kshiftri_mask16() {
+        let a: __mmask16 = 0b11001100_00110011;
+        assert_eq!(_kshiftri_mask16::<0>(a), a);
+        assert_eq!(_kshiftri_mask16::<1>(a), 0b01100110_00011001);
+        assert_eq!(_kshiftri_mask16::<5>(a), 0b00000110_01100001);
+        assert_eq!(_kshiftri_mask16::<15>(a), 0b00000000_00000001);
+        assert_eq!(_kshiftri_mask16::<16>(a), 0);
+        assert_eq!(_kshiftri_mask16::<255>(a), 0);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_kortest_mask16_u8() {
+        let a: __mmask16 = 0b11001100_00110011;
+        let mut all_ones = 2;
+        let r = _kortest_mask16_u8(a, 0b00110011_11001100, &mut all_ones);
+        assert_eq!((r, all_ones), (0, 1));
+        let r = _kortest_mask16_u8(a, 0b00110011_11001000, &mut all_ones);
+        assert_eq!((r, all_ones), (0, 0));
+        let r = _kortest_mask16_u8(0, 0, &mut all_ones);
+        assert_eq!((r, all_ones), (1, 0));
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_kortestc_mask16_u8() {
+        let a: __mmask16 = 0b11001100_00110011;
+        assert_eq!(_kortestc_mask16_u8(a, 0b00110011_11001100), 1);
+        assert_eq!(_kortestc_mask16_u8(a, u16::MAX), 1);
+        assert_eq!(_kortestc_mask16_u8(a, 0b00110011_11001000), 0);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_kortestz_mask16_u8() {
+        assert_eq!(_kortestz_mask16_u8(0, 0), 1);
+        assert_eq!(_kortestz_mask16_u8(0, 1 << 15), 0);
+        assert_eq!(_kortestz_mask16_u8(1, 0), 0);
+    }
+
     #[simd_test(enable = "avx512f")]
     unsafe fn test_mm512_test_epi32_mask() {
         let a = _mm512_set1_epi32(1 << 0);
@@ -48756,6 +50132,16 @@ mod tests {
         }
     }
 
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_stream_load_si512() {
+        let a = _mm512_set_epi64(1, 2, 3, 4, 5, 6, 7, 8);
+        let mut mem = _mm512_setzero_si512();
+        _mm512_stream_si512(&mut mem as *mut _ as *mut i64, a);
+        _mm_sfence();
+        let r = _mm512_stream_load_si512(&mem as *const _);
+        assert_eq_m512i(r, a);
+    }
+
     #[simd_test(enable = "avx512f")]
     unsafe fn test_mm512_reduce_add_epi32() {
         let a = _mm512_set1_epi32(1);
@@ -49450,6 +50836,180 @@ mod tests {
         assert_eq_m128i(r, a);
     }
 
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_mask_loadu_epi32() {
+        let mut buf = [0_i32; 17];
+        for (i, x) in buf.iter_mut().enumerate() {
+            *x = i as i32;
+        }
+        let src = _mm512_set1_epi32(-1);
+        let m: __mmask16 = 0xdef1;
+        // Offset by one element so that the vector is misaligned.
+        let r = _mm512_mask_loadu_epi32(src, m, black_box(buf.as_ptr().add(1)));
+        let mut e = [-1_i32; 16];
+        for i in 0..16 {
+            if m >> i & 1 != 0 {
+                e[i] = buf[i + 1];
+            }
+        }
+        assert_eq_m512i(r, _mm512_loadu_epi32(e.as_ptr()));
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_maskz_loadu_epi32() {
+        let mut buf = [0_i32; 17];
+        for (i, x) in buf.iter_mut().enumerate() {
+            *x = i as i32 + 1;
+        }
+        let m: __mmask16 = 0xdef1;
+        // Offset by one element so that the vector is misaligned.
+        let r = _mm512_maskz_loadu_epi32(m, black_box(buf.as_ptr().add(1)));
+        let mut e = [0_i32; 16];
+        for i in 0..16 {
+            if m >> i & 1 != 0 {
+                e[i] = buf[i + 1];
+            }
+        }
+        assert_eq_m512i(r, _mm512_loadu_epi32(e.as_ptr()));
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_mask_storeu_epi32() {
+        let mut v = [0_i32; 16];
+        for (i, x) in v.iter_mut().enumerate() {
+            *x = i as i32;
+        }
+        let a = _mm512_loadu_epi32(v.as_ptr());
+        let m: __mmask16 = 0xdef1;
+        let mut buf = [-1_i32; 18];
+        // Offset by one element so that the vector is misaligned.
+        _mm512_mask_storeu_epi32(black_box(buf.as_mut_ptr().add(1)), m, a);
+        // Neither the unselected elements nor the bytes around the vector
+        // are touched.
+        assert_eq!(buf[0], -1);
+        assert_eq!(buf[17], -1);
+        for i in 0..16 {
+            let e = if m >> i & 1 != 0 { v[i] } else { -1 };
+            assert_eq!(buf[i + 1], e);
+        }
+    }
+
+    #[simd_test(enable = "avx512f,avx512vl")]
+    unsafe fn test_mm256_mask_loadu_epi32() {
+        let mut buf = [0_i32; 9];
+        for (i, x) in buf.iter_mut().enumerate() {
+            *x = i as i32;
+        }
+        let src = _mm256_set1_epi32(-1);
+        let m: __mmask8 = 0xf1;
+        // Offset by one element so that the vector is misaligned.
+        let r = _mm256_mask_loadu_epi32(src, m, black_box(buf.as_ptr().add(1)));
+        let mut e = [-1_i32; 8];
+        for i in 0..8 {
+            if m >> i & 1 != 0 {
+                e[i] = buf[i + 1];
+            }
+        }
+        assert_eq_m256i(r, _mm256_loadu_epi32(e.as_ptr()));
+    }
+
+    #[simd_test(enable = "avx512f,avx512vl")]
+    unsafe fn test_mm256_maskz_loadu_epi32() {
+        let mut buf = [0_i32; 9];
+        for (i, x) in buf.iter_mut().enumerate() {
+            *x = i as i32 + 1;
+        }
+        let m: __mmask8 = 0xf1;
+        // Offset by one element so that the vector is misaligned.
+        let r = _mm256_maskz_loadu_epi32(m, black_box(buf.as_ptr().add(1)));
+        let mut e = [0_i32; 8];
+        for i in 0..8 {
+            if m >> i & 1 != 0 {
+                e[i] = buf[i + 1];
+            }
+        }
+        assert_eq_m256i(r, _mm256_loadu_epi32(e.as_ptr()));
+    }
+
+    #[simd_test(enable = "avx512f,avx512vl")]
+    unsafe fn test_mm256_mask_storeu_epi32() {
+        let mut v = [0_i32; 8];
+        for (i, x) in v.iter_mut().enumerate() {
+            *x = i as i32;
+        }
+        let a = _mm256_loadu_epi32(v.as_ptr());
+        let m: __mmask8 = 0xf1;
+        let mut buf = [-1_i32; 10];
+        // Offset by one element so that the vector is misaligned.
+        _mm256_mask_storeu_epi32(black_box(buf.as_mut_ptr().add(1)), m, a);
+        // Neither the unselected elements nor the bytes around the vector
+        // are touched.
+        assert_eq!(buf[0], -1);
+        assert_eq!(buf[9], -1);
+        for i in 0..8 {
+            let e = if m >> i & 1 != 0 { v[i] } else { -1 };
+            assert_eq!(buf[i + 1], e);
+        }
+    }
+
+    #[simd_test(enable = "avx512f,avx512vl")]
+    unsafe fn test_mm_mask_loadu_epi32() {
+        let mut buf = [0_i32; 5];
+        for (i, x) in buf.iter_mut().enumerate() {
+            *x = i as i32;
+        }
+        let src = _mm_set1_epi32(-1);
+        let m: __mmask8 = 0b1101;
+        // Offset by one element so that the vector is misaligned.
+        let r = _mm_mask_loadu_epi32(src, m, black_box(buf.as_ptr().add(1)));
+        let mut e = [-1_i32; 4];
+        for i in 0..4 {
+            if m >> i & 1 != 0 {
+                e[i] = buf[i + 1];
+            }
+        }
+        assert_eq_m128i(r, _mm_loadu_epi32(e.as_ptr()));
+    }
+
+    #[simd_test(enable = "avx512f,avx512vl")]
+    unsafe fn test_mm_maskz_loadu_epi32() {
+        let mut buf = [0_i32; 5];
+        for (i, x) in buf.iter_mut().enumerate() {
+            *x = i as i32 + 1;
+        }
+        let m: __mmask8 = 0b1101;
+        // Offset by one element so that the vector is misaligned.
+        let r = _mm_maskz_loadu_epi32(m, black_box(buf.as_ptr().add(1)));
+        let mut e = [0_i32; 4];
+        for i in 0..4 {
+            if m >> i & 1 != 0 {
+                e[i] = buf[i + 1];
+            }
+        }
+        assert_eq_m128i(r, _mm_loadu_epi32(e.as_ptr()));
+    }
+
+    #[simd_test(enable = "avx512f,avx512vl")]
+    unsafe fn test_mm_mask_storeu_epi32() {
+        let mut v = [0_i32; 4];
+        for (i, x) in v.iter_mut().enumerate() {
+            *x = i as i32;
+        }
+        let a = _mm_loadu_epi32(v.as_ptr());
+        let m: __mmask8 = 0b1101;
+        let mut buf = [-1_i32; 6];
+        // Offset by one element so that the vector is misaligned.
+        _mm_mask_storeu_epi32(black_box(buf.as_mut_ptr().add(1)), m, a);
+        // Neither the unselected elements nor the bytes around the vector
+        // are touched.
+        assert_eq!(buf[0], -1);
+        assert_eq!(buf[5], -1);
+        for i in 0..4 {
+            let e = if m >> i & 1 != 0 { v[i] } else { -1 };
+            assert_eq!(buf[i + 1], e);
+        }
+    }
+
     #[simd_test(enable = "avx512f")]
     unsafe fn test_mm512_loadu_si512() {
         let a = &[4, 3, 2, 5, 8, 9, 64, 50, -4, -3, -2, -5, -8, -9, -64, -50];
@@ -49482,6 +51042,34 @@ mod tests {
         assert_eq_m512i(r, e);
     }
 
+    #[cfg(debug_assertions)]
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_load_store_si512_misaligned() {
+        use std::panic::catch_unwind;
+
+        let mut vals = [0i32; 32];
+        let mut p = vals.as_mut_ptr();
+        if (p as usize) & 0x3f == 0 {
+            p = p.add(8);
+        }
+        assert!(catch_unwind(|| {
+            _mm512_load_si512(p);
+        })
+        .is_err());
+        assert!(catch_unwind(|| _mm512_store_epi32(p, _mm512_set1_epi32(1))).is_err());
+        assert!(catch_unwind(|| _mm512_stream_si512(p as *mut i64, _mm512_set1_epi32(1))).is_err());
+        assert_eq!(vals, [0; 32]);
+
+        #[repr(align(64))]
+        struct Align {
+            data: [i32; 16],
+        }
+        let mut mem = Align { data: [0; 16] };
+        let a = _mm512_set1_epi32(7);
+        _mm512_store_si512(mem.data.as_mut_ptr(), a);
+        assert_eq_m512i(_mm512_load_si512(mem.data.as_ptr()), a);
+    }
+
     #[simd_test(enable = "avx512f")]
     unsafe fn test_mm512_store_si512() {
         let a = _mm512_set1_epi32(9);
@@ -52554,4 +54142,415 @@ mod tests {
         let e = _mm512_setr_pd(4., 3., 8., 7., 0., 0., 0., 0.);
         assert_eq_m512d(r, e);
     }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_mask_compressstoreu_epi32() {
+        let a: __m512i =
+            transmute::<[i32; 16], _>([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]);
+        let mut r = [0_i32; 16];
+        _mm512_mask_compressstoreu_epi32(r.as_mut_ptr(), 0, a);
+        assert_eq!(&r, &[0_i32; 16]);
+        _mm512_mask_compressstoreu_epi32(r.as_mut_ptr(), 0b11011011_01101101, a);
+        assert_eq!(&r, &[1, 3, 4, 6, 7, 9, 10, 12, 13, 15, 16, 0, 0, 0, 0, 0]);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_mask_expandloadu_epi32() {
+        let src: __m512i = transmute([-1_i32; 16]);
+        let m: [i32; 16] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
+        let r = _mm512_mask_expandloadu_epi32(src, 0, m.as_ptr());
+        assert_eq!(&transmute::<_, [i32; 16]>(r), &[-1_i32; 16]);
+        let r = _mm512_mask_expandloadu_epi32(src, 0b11011011_01101101, m.as_ptr());
+        let e: [i32; 16] = [1, -1, 2, 3, -1, 4, 5, -1, 6, 7, -1, 8, 9, -1, 10, 11];
+        assert_eq!(&transmute::<_, [i32; 16]>(r), &e);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_maskz_expandloadu_epi32() {
+        let m: [i32; 16] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
+        let r = _mm512_maskz_expandloadu_epi32(0, m.as_ptr());
+        assert_eq!(&transmute::<_, [i32; 16]>(r), &[0_i32; 16]);
+        let r = _mm512_maskz_expandloadu_epi32(0b11011011_01101101, m.as_ptr());
+        let e: [i32; 16] = [1, 0, 2, 3, 0, 4, 5, 0, 6, 7, 0, 8, 9, 0, 10, 11];
+        assert_eq!(&transmute::<_, [i32; 16]>(r), &e);
+    }
+
+    #[simd_test(enable = "avx512f,avx512vl")]
+    unsafe fn test_mm256_mask_compressstoreu_epi32() {
+        let a: __m256i = transmute::<[i32; 8], _>([1, 2, 3, 4, 5, 6, 7, 8]);
+        let mut r = [0_i32; 8];
+        _mm256_mask_compressstoreu_epi32(r.as_mut_ptr(), 0, a);
+        assert_eq!(&r, &[0_i32; 8]);
+        _mm256_mask_compressstoreu_epi32(r.as_mut_ptr(), 0b01101101, a);
+        assert_eq!(&r, &[1, 3, 4, 6, 7, 0, 0, 0]);
+    }
+
+    #[simd_test(enable = "avx512f,avx512vl")]
+    unsafe fn test_mm256_mask_expandloadu_epi32() {
+        let src: __m256i = transmute([-1_i32; 8]);
+        let m: [i32; 8] = [1, 2, 3, 4, 5, 6, 7, 8];
+        let r = _mm256_mask_expandloadu_epi32(src, 0, m.as_ptr());
+        assert_eq!(&transmute::<_, [i32; 8]>(r), &[-1_i32; 8]);
+        let r = _mm256_mask_expandloadu_epi32(src, 0b01101101, m.as_ptr());
+        let e: [i32; 8] = [1, -1, 2, 3, -1, 4, 5, -1];
+        assert_eq!(&transmute::<_, [i32; 8]>(r), &e);
+    }
+
+    #[simd_test(enable = "avx512f,avx512vl")]
+    unsafe fn test_mm256_maskz_expandloadu_epi32() {
+        let m: [i32; 8] = [1, 2, 3, 4, 5, 6, 7, 8];
+        let r = _mm256_maskz_expandloadu_epi32(0, m.as_ptr());
+        assert_eq!(&transmute::<_, [i32; 8]>(r), &[0_i32; 8]);
+        let r = _mm256_maskz_expandloadu_epi32(0b01101101, m.as_ptr());
+        let e: [i32; 8] = [1, 0, 2, 3, 0, 4, 5, 0];
+        assert_eq!(&transmute::<_, [i32; 8]>(r), &e);
+    }
+
+    #[simd_test(enable = "avx512f,avx512vl")]
+    unsafe fn test_mm_mask_compressstoreu_epi32() {
+        let a: __m128i = transmute::<[i32; 4], _>([1, 2, 3, 4]);
+        let mut r = [0_i32; 4];
+        _mm_mask_compressstoreu_epi32(r.as_mut_ptr(), 0, a);
+        assert_eq!(&r, &[0_i32; 4]);
+        _mm_mask_compressstoreu_epi32(r.as_mut_ptr(), 0b00001101, a);
+        assert_eq!(&r, &[1, 3, 4, 0]);
+    }
+
+    #[simd_test(enable = "avx512f,avx512vl")]
+    unsafe fn test_mm_mask_expandloadu_epi32() {
+        let src: __m128i = transmute([-1_i32; 4]);
+        let m: [i32; 4] = [1, 2, 3, 4];
+        let r = _mm_mask_expandloadu_epi32(src, 0, m.as_ptr());
+        assert_eq!(&transmute::<_, [i32; 4]>(r), &[-1_i32; 4]);
+        let r = _mm_mask_expandloadu_epi32(src, 0b00001101, m.as_ptr());
+        let e: [i32; 4] = [1, -1, 2, 3];
+        assert_eq!(&transmute::<_, [i32; 4]>(r), &e);
+    }
+
+    #[simd_test(enable = "avx512f,avx512vl")]
+    unsafe fn test_mm_maskz_expandloadu_epi32() {
+        let m: [i32; 4] = [1, 2, 3, 4];
+        let r = _mm_maskz_expandloadu_epi32(0, m.as_ptr());
+        assert_eq!(&transmute::<_, [i32; 4]>(r), &[0_i32; 4]);
+        let r = _mm_maskz_expandloadu_epi32(0b00001101, m.as_ptr());
+        let e: [i32; 4] = [1, 0, 2, 3];
+        assert_eq!(&transmute::<_, [i32; 4]>(r), &e);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_mask_compressstoreu_epi64() {
+        let a: __m512i = transmute::<[i64; 8], _>([1, 2, 3, 4, 5, 6, 7, 8]);
+        let mut r = [0_i64; 8];
+        _mm512_mask_compressstoreu_epi64(r.as_mut_ptr(), 0, a);
+        assert_eq!(&r, &[0_i64; 8]);
+        _mm512_mask_compressstoreu_epi64(r.as_mut_ptr(), 0b01101101, a);
+        assert_eq!(&r, &[1, 3, 4, 6, 7, 0, 0, 0]);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_mask_expandloadu_epi64() {
+        let src: __m512i = transmute([-1_i64; 8]);
+        let m: [i64; 8] = [1, 2, 3, 4, 5, 6, 7, 8];
+        let r = _mm512_mask_expandloadu_epi64(src, 0, m.as_ptr());
+        assert_eq!(&transmute::<_, [i64; 8]>(r), &[-1_i64; 8]);
+        let r = _mm512_mask_expandloadu_epi64(src, 0b01101101, m.as_ptr());
+        let e: [i64; 8] = [1, -1, 2, 3, -1, 4, 5, -1];
+        assert_eq!(&transmute::<_, [i64; 8]>(r), &e);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_maskz_expandloadu_epi64() {
+        let m: [i64; 8] = [1, 2, 3, 4, 5, 6, 7, 8];
+        let r = _mm512_maskz_expandloadu_epi64(0, m.as_ptr());
+        assert_eq!(&transmute::<_, [i64; 8]>(r), &[0_i64; 8]);
+        let r = _mm512_maskz_expandloadu_epi64(0b01101101, m.as_ptr());
+        let e: [i64; 8] = [1, 0, 2, 3, 0, 4, 5, 0];
+        assert_eq!(&transmute::<_, [i64; 8]>(r), &e);
+    }
+
+    #[simd_test(enable = "avx512f,avx512vl")]
+    unsafe fn test_mm256_mask_compressstoreu_epi64() {
+        let a: __m256i = transmute::<[i64; 4], _>([1, 2, 3, 4]);
+        let mut r = [0_i64; 4];
+        _mm256_mask_compressstoreu_epi64(r.as_mut_ptr(), 0, a);
+        assert_eq!(&r, &[0_i64; 4]);
+        _mm256_mask_compressstoreu_epi64(r.as_mut_ptr(), 0b00001101, a);
+        assert_eq!(&r, &[1, 3, 4, 0]);
+    }
+
+    #[simd_test(enable = "avx512f,avx512vl")]
+    unsafe fn test_mm256_mask_expandloadu_epi64() {
+        let src: __m256i = transmute([-1_i64; 4]);
+        let m: [i64; 4] = [1, 2, 3, 4];
+        let r = _mm256_mask_expandloadu_epi64(src, 0, m.as_ptr());
+        assert_eq!(&transmute::<_, [i64; 4]>(r), &[-1_i64; 4]);
+        let r = _mm256_mask_expandloadu_epi64(src, 0b00001101, m.as_ptr());
+        let e: [i64; 4] = [1, -1, 2, 3];
+        assert_eq!(&transmute::<_, [i64; 4]>(r), &e);
+    }
+
+    #[simd_test(enable = "avx512f,avx512vl")]
+    unsafe fn test_mm256_maskz_expandloadu_epi64() {
+        let m: [i64; 4] = [1, 2, 3, 4];
+        let r = _mm256_maskz_expandloadu_epi64(0, m.as_ptr());
+        assert_eq!(&transmute::<_, [i64; 4]>(r), &[0_i64; 4]);
+        let r = _mm256_maskz_expandloadu_epi64(0b00001101, m.as_ptr());
+        let e: [i64; 4] = [1, 0, 2, 3];
+        assert_eq!(&transmute::<_, [i64; 4]>(r), &e);
+    }
+
+    #[simd_test(enable = "avx512f,avx512vl")]
+    unsafe fn test_mm_mask_compressstoreu_epi64() {
+        let a: __m128i = transmute::<[i64; 2], _>([1, 2]);
+        let mut r = [0_i64; 2];
+        _mm_mask_compressstoreu_epi64(r.as_mut_ptr(), 0, a);
+        assert_eq!(&r, &[0_i64; 2]);
+        _mm_mask_compressstoreu_epi64(r.as_mut_ptr(), 0b00000001, a);
+        assert_eq!(&r, &[1, 0]);
+    }
+
+    #[simd_test(enable = "avx512f,avx512vl")]
+    unsafe fn test_mm_mask_expandloadu_epi64() {
+        let src: __m128i = transmute([-1_i64; 2]);
+        let m: [i64; 2] = [1, 2];
+        let r = _mm_mask_expandloadu_epi64(src, 0, m.as_ptr());
+        assert_eq!(&transmute::<_, [i64; 2]>(r), &[-1_i64; 2]);
+        let r = _mm_mask_expandloadu_epi64(src, 0b00000001, m.as_ptr());
+        let e: [i64; 2] = [1, -1];
+        assert_eq!(&transmute::<_, [i64; 2]>(r), &e);
+    }
+
+    #[simd_test(enable = "avx512f,avx512vl")]
+    unsafe fn test_mm_maskz_expandloadu_epi64() {
+        let m: [i64; 2] = [1, 2];
+        let r = _mm_maskz_expandloadu_epi64(0, m.as_ptr());
+        assert_eq!(&transmute::<_, [i64; 2]>(r), &[0_i64; 2]);
+        let r = _mm_maskz_expandloadu_epi64(0b00000001, m.as_ptr());
+        let e: [i64; 2] = [1, 0];
+        assert_eq!(&transmute::<_, [i64; 2]>(r), &e);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_mask_compressstoreu_ps() {
+        let a: __m512 = transmute::<[f32; 16], _>([
+            1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12., 13., 14., 15., 16.,
+        ]);
+        let mut r = [0_f32; 16];
+        _mm512_mask_compressstoreu_ps(r.as_mut_ptr(), 0, a);
+        assert_eq!(&r, &[0_f32; 16]);
+        _mm512_mask_compressstoreu_ps(r.as_mut_ptr(), 0b11011011_01101101, a);
+        assert_eq!(
+            &r,
+            &[1., 3., 4., 6., 7., 9., 10., 12., 13., 15., 16., 0., 0., 0., 0., 0.]
+        );
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_mask_expandloadu_ps() {
+        let src: __m512 = transmute([-1_f32; 16]);
+        let m: [f32; 16] = [
+            1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12., 13., 14., 15., 16.,
+        ];
+        let r = _mm512_mask_expandloadu_ps(src, 0, m.as_ptr());
+        assert_eq!(&transmute::<_, [f32; 16]>(r), &[-1_f32; 16]);
+        let r = _mm512_mask_expandloadu_ps(src, 0b11011011_01101101, m.as_ptr());
+        let e: [f32; 16] = [
+            1., -1., 2., 3., -1., 4., 5., -1., 6., 7., -1., 8., 9., -1., 10., 11.,
+        ];
+        assert_eq!(&transmute::<_, [f32; 16]>(r), &e);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_maskz_expandloadu_ps() {
+        let m: [f32; 16] = [
+            1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12., 13., 14., 15., 16.,
+        ];
+        let r = _mm512_maskz_expandloadu_ps(0, m.as_ptr());
+        assert_eq!(&transmute::<_, [f32; 16]>(r), &[0_f32; 16]);
+        let r = _mm512_maskz_expandloadu_ps(0b11011011_01101101, m.as_ptr());
+        let e: [f32; 16] = [
+            1., 0., 2., 3., 0., 4., 5., 0., 6., 7., 0., 8., 9., 0., 10., 11.,
+        ];
+        assert_eq!(&transmute::<_, [f32; 16]>(r), &e);
+    }
+
+    #[simd_test(enable = "avx512f,avx512vl")]
+    unsafe fn test_mm256_mask_compressstoreu_ps() {
+        let a: __m256 = transmute::<[f32; 8], _>([1., 2., 3., 4., 5., 6., 7., 8.]);
+        let mut r = [0_f32; 8];
+        _mm256_mask_compressstoreu_ps(r.as_mut_ptr(), 0, a);
+        assert_eq!(&r, &[0_f32; 8]);
+        _mm256_mask_compressstoreu_ps(r.as_mut_ptr(), 0b01101101, a);
+        assert_eq!(&r, &[1., 3., 4., 6., 7., 0., 0., 0.]);
+    }
+
+    #[simd_test(enable = "avx512f,avx512vl")]
+    unsafe fn test_mm256_mask_expandloadu_ps() {
+        let src: __m256 = transmute([-1_f32; 8]);
+        let m: [f32; 8] = [1., 2., 3., 4., 5., 6., 7., 8.];
+        let r = _mm256_mask_expandloadu_ps(src, 0, m.as_ptr());
+        assert_eq!(&transmute::<_, [f32; 8]>(r), &[-1_f32; 8]);
+        let r = _mm256_mask_expandloadu_ps(src, 0b01101101, m.as_ptr());
+        let e: [f32; 8] = [1., -1., 2., 3., -1., 4., 5., -1.];
+        assert_eq!(&transmute::<_, [f32; 8]>(r), &e);
+    }
+
+    #[simd_test(enable = "avx512f,avx512vl")]
+    unsafe fn test_mm256_maskz_expandloadu_ps() {
+        let m: [f32; 8] = [1., 2., 3., 4., 5., 6., 7., 8.];
+        let r = _mm256_maskz_expandloadu_ps(0, m.as_ptr());
+        assert_eq!(&transmute::<_, [f32; 8]>(r), &[0_f32; 8]);
+        let r = _mm256_maskz_expandloadu_ps(0b01101101, m.as_ptr());
+        let e: [f32; 8] = [1., 0., 2., 3., 0., 4., 5., 0.];
+        assert_eq!(&transmute::<_, [f32; 8]>(r), &e);
+    }
+
+    #[simd_test(enable = "avx512f,avx512vl")]
+    unsafe fn test_mm_mask_compressstoreu_ps() {
+        let a: __m128 = transmute::<[f32; 4], _>([1., 2., 3., 4.]);
+        let mut r = [0_f32; 4];
+        _mm_mask_compressstoreu_ps(r.as_mut_ptr(), 0, a);
+        assert_eq!(&r, &[0_f32; 4]);
+        _mm_mask_compressstoreu_ps(r.as_mut_ptr(), 0b00001101, a);
+        assert_eq!(&r, &[1., 3., 4., 0.]);
+    }
+
+    #[simd_test(enable = "avx512f,avx512vl")]
+    unsafe fn test_mm_mask_expandloadu_ps() {
+        let src: __m128 = transmute([-1_f32; 4]);
+        let m: [f32; 4] = [1., 2., 3., 4.];
+        let r = _mm_mask_expandloadu_ps(src, 0, m.as_ptr());
+        assert_eq!(&transmute::<_, [f32; 4]>(r), &[-1_f32; 4]);
+        let r = _mm_mask_expandloadu_ps(src, 0b00001101, m.as_ptr());
+        let e: [f32; 4] = [1., -1., 2., 3.];
+        assert_eq!(&transmute::<_, [f32; 4]>(r), &e);
+    }
+
+    #[simd_test(enable = "avx512f,avx512vl")]
+    unsafe fn test_mm_maskz_expandloadu_ps() {
+        let m: [f32; 4] = [1., 2., 3., 4.];
+        let r = _mm_maskz_expandloadu_ps(0, m.as_ptr());
+        assert_eq!(&transmute::<_, [f32; 4]>(r), &[0_f32; 4]);
+        let r = _mm_maskz_expandloadu_ps(0b00001101, m.as_ptr());
+        let e: [f32; 4] = [1., 0., 2., 3.];
+        assert_eq!(&transmute::<_, [f32; 4]>(r), &e);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_mask_compressstoreu_pd() {
+        let a: __m512d = transmute::<[f64; 8], _>([1., 2., 3., 4., 5., 6., 7., 8.]);
+        let mut r = [0_f64; 8];
+        _mm512_mask_compressstoreu_pd(r.as_mut_ptr(), 0, a);
+        assert_eq!(&r, &[0_f64; 8]);
+        _mm512_mask_compressstoreu_pd(r.as_mut_ptr(), 0b01101101, a);
+        assert_eq!(&r, &[1., 3., 4., 6., 7., 0., 0., 0.]);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_mask_expandloadu_pd() {
+        let src: __m512d = transmute([-1_f64; 8]);
+        let m: [f64; 8] = [1., 2., 3., 4., 5., 6., 7., 8.];
+        let r = _mm512_mask_expandloadu_pd(src, 0, m.as_ptr());
+        assert_eq!(&transmute::<_, [f64; 8]>(r), &[-1_f64; 8]);
+        let r = _mm512_mask_expandloadu_pd(src, 0b01101101, m.as_ptr());
+        let e: [f64; 8] = [1., -1., 2., 3., -1., 4., 5., -1.];
+        assert_eq!(&transmute::<_, [f64; 8]>(r), &e);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_maskz_expandloadu_pd() {
+        let m: [f64; 8] = [1., 2., 3., 4., 5., 6., 7., 8.];
+        let r = _mm512_maskz_expandloadu_pd(0, m.as_ptr());
+        assert_eq!(&transmute::<_, [f64; 8]>(r), &[0_f64; 8]);
+        let r = _mm512_maskz_expandloadu_pd(0b01101101, m.as_ptr());
+        let e: [f64; 8] = [1., 0., 2., 3., 0., 4., 5., 0.];
+        assert_eq!(&transmute::<_, [f64; 8]>(r), &e);
+    }
+
+    #[simd_test(enable = "avx512f,avx512vl")]
+    unsafe fn test_mm256_mask_compressstoreu_pd() {
+        let a: __m256d = transmute::<[f64; 4], _>([1., 2., 3., 4.]);
+        let mut r = [0_f64; 4];
+        _mm256_mask_compressstoreu_pd(r.as_mut_ptr(), 0, a);
+        assert_eq!(&r, &[0_f64; 4]);
+        _mm256_mask_compressstoreu_pd(r.as_mut_ptr(), 0b00001101, a);
+        assert_eq!(&r, &[1., 3., 4., 0.]);
+    }
+
+    #[simd_test(enable = "avx512f,avx512vl")]
+    unsafe fn test_mm256_mask_expandloadu_pd() {
+        let src: __m256d = transmute([-1_f64; 4]);
+        let m: [f64; 4] = [1., 2., 3., 4.];
+        let r = _mm256_mask_expandloadu_pd(src, 0, m.as_ptr());
+        assert_eq!(&transmute::<_, [f64; 4]>(r), &[-1_f64; 4]);
+        let r = _mm256_mask_expandloadu_pd(src, 0b00001101, m.as_ptr());
+        let e: [f64; 4] = [1., -1., 2., 3.];
+        assert_eq!(&transmute::<_, [f64; 4]>(r), &e);
+    }
+
+    #[simd_test(enable = "avx512f,avx512vl")]
+    unsafe fn test_mm256_maskz_expandloadu_pd() {
+        let m: [f64; 4] = [1., 2., 3., 4.];
+        let r = _mm256_maskz_expandloadu_pd(0, m.as_ptr());
+        assert_eq!(&transmute::<_, [f64; 4]>(r), &[0_f64; 4]);
+        let r = _mm256_maskz_expandloadu_pd(0b00001101, m.as_ptr());
+        let e: [f64; 4] = [1., 0., 2., 3.];
+        assert_eq!(&transmute::<_, [f64; 4]>(r), &e);
+    }
+
+    #[simd_test(enable = "avx512f,avx512vl")]
+    unsafe fn test_mm_mask_compressstoreu_pd() {
+        let a: __m128d = transmute::<[f64; 2], _>([1., 2.]);
+        let mut r = [0_f64; 2];
+        _mm_mask_compressstoreu_pd(r.as_mut_ptr(), 0, a);
+        assert_eq!(&r, &[0_f64; 2]);
+        _mm_mask_compressstoreu_pd(r.as_mut_ptr(), 0b00000001, a);
+        assert_eq!(&r, &[1., 0.]);
+    }
+
+    #[simd_test(enable = "avx512f,avx512vl")]
+    unsafe fn test_mm_mask_expandloadu_pd() {
+        let src: __m128d = transmute([-1_f64; 2]);
+        let m: [f64; 2] = [1., 2.];
+        let r = _mm_mask_expandloadu_pd(src, 0, m.as_ptr());
+        assert_eq!(&transmute::<_, [f64; 2]>(r), &[-1_f64; 2]);
+        let r = _mm_mask_expandloadu_pd(src, 0b00000001, m.as_ptr());
+        let e: [f64; 2] = [1., -1.];
+        assert_eq!(&transmute::<_, [f64; 2]>(r), &e);
+    }
+
+    #[simd_test(enable = "avx512f,avx512vl")]
+    unsafe fn test_mm_maskz_expandloadu_pd() {
+        let m: [f64; 2] = [1., 2.];
+        let r = _mm_maskz_expandloadu_pd(0, m.as_ptr());
+        assert_eq!(&transmute::<_, [f64; 2]>(r), &[0_f64; 2]);
+        let r = _mm_maskz_expandloadu_pd(0b00000001, m.as_ptr());
+        let e: [f64; 2] = [1., 0.];
+        assert_eq!(&transmute::<_, [f64; 2]>(r), &e);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_compressstoreu_expandloadu_epi32_page_end() {
+        // Only the active elements may be accessed, so storing them right in
+        // front of a guard page and loading them back mustn't fault.
+        let buf = match guarded_page() {
+            Some(buf) => buf as *mut i32,
+            None => return,
+        };
+        ptr::write_bytes(buf as *mut u8, 0x55, PAGE_SIZE);
+        let end = buf.add(PAGE_SIZE / mem::size_of::<i32>() - 4);
+        let a: __m512i =
+            transmute::<[i32; 16], _>([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]);
+        let k = 0b10000100_00100001;
+        _mm512_mask_compressstoreu_epi32(end, k, a);
+        assert_eq!(slice::from_raw_parts(end, 4), &[1, 6, 11, 16]);
+        let bytes = slice::from_raw_parts(buf as *const u8, PAGE_SIZE);
+        assert!(bytes[..PAGE_SIZE - 4 * mem::size_of::<i32>()]
+            .iter()
+            .all(|&b| b == 0x55));
+        let r = _mm512_maskz_expandloadu_epi32(k, end);
+        let e: [i32; 16] = [1, 0, 0, 0, 0, 6, 0, 0, 0, 0, 11, 0, 0, 0, 0, 16];
+        assert_eq!(&transmute::<_, [i32; 16]>(r), &e);
+        unmap_guarded_page(buf as *mut u8);
+    }
 }
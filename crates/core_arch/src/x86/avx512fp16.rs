@@ -0,0 +1,2198 @@
+//! AVX-512 FP16 intrinsics
+//!
+//! The intrinsics here correspond to those in the `immintrin.h` C header.
+//!
+//! Rust doesn't have a half-precision floating point type, so scalar
+//! arguments and memory operands are passed as the `u16` bit pattern of an
+//! IEEE 754 `binary16` value.
+//!
+//! The reference is [Intel 64 and IA-32 Architectures Software Developer's
+//! Manual Volume 2: Instruction Set Reference, A-Z][intel64_ref].
+//!
+//! [intel64_ref]: http://www.intel.de/content/dam/www/public/us/en/documents/manuals/64-ia-32-architectures-software-developer-instruction-set-reference-manual-325383.pdf
+
+use crate::{
+    core_arch::{simd::*, simd_llvm::*, x86::*},
+    mem::{self, transmute},
+    ptr,
+};
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+// LLVM models these instructions on vectors of `half`, which doesn't have a
+// Rust equivalent that can cross the FFI boundary, so they're implemented
+// with inline assembly instead.
+macro_rules! fp16_unop {
+    ($inst:expr, $dreg:ident, $sreg:ident, $a:expr) => {{
+        let dst;
+        asm!(
+            concat!($inst, " {dst}, {a}"),
+            dst = lateout($dreg) dst,
+            a = in($sreg) $a,
+            options(pure, nomem, nostack, preserves_flags),
+        );
+        dst
+    }};
+}
+
+macro_rules! fp16_binop {
+    ($inst:expr, $reg:ident, $a:expr, $b:expr) => {{
+        let dst;
+        asm!(
+            concat!($inst, " {dst}, {a}, {b}"),
+            dst = lateout($reg) dst,
+            a = in($reg) $a,
+            b = in($reg) $b,
+            options(pure, nomem, nostack, preserves_flags),
+        );
+        dst
+    }};
+}
+
+macro_rules! fp16_fma {
+    ($inst:expr, $reg:ident, $a:expr, $b:expr, $c:expr) => {{
+        let dst;
+        asm!(
+            concat!($inst, " {a}, {b}, {c}"),
+            a = inlateout($reg) $a => dst,
+            b = in($reg) $b,
+            c = in($reg) $c,
+            options(pure, nomem, nostack, preserves_flags),
+        );
+        dst
+    }};
+}
+
+/// Returns vector of type `__m512h` with all elements set to zero.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_setzero_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vxorps))]
+pub unsafe fn _mm512_setzero_ph() -> __m512h {
+    mem::zeroed()
+}
+
+/// Returns vector of type `__m256h` with all elements set to zero.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_setzero_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vxorps))]
+pub unsafe fn _mm256_setzero_ph() -> __m256h {
+    mem::zeroed()
+}
+
+/// Returns vector of type `__m128h` with all elements set to zero.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_setzero_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vxorps))]
+pub unsafe fn _mm_setzero_ph() -> __m128h {
+    mem::zeroed()
+}
+
+/// Broadcast the half-precision (16-bit) floating-point value `a`, given
+/// as its bit pattern, to all elements of the returned vector.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_set1_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+pub unsafe fn _mm512_set1_ph(a: u16) -> __m512h {
+    transmute(u16x32::splat(a))
+}
+
+/// Broadcast the half-precision (16-bit) floating-point value `a`, given
+/// as its bit pattern, to all elements of the returned vector.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_set1_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+pub unsafe fn _mm256_set1_ph(a: u16) -> __m256h {
+    transmute(u16x16::splat(a))
+}
+
+/// Broadcast the half-precision (16-bit) floating-point value `a`, given
+/// as its bit pattern, to all elements of the returned vector.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_set1_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+pub unsafe fn _mm_set1_ph(a: u16) -> __m128h {
+    transmute(u16x8::splat(a))
+}
+
+/// Set packed half-precision (16-bit) floating-point elements in the
+/// returned vector with the supplied values, given as bit patterns.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_set_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+pub unsafe fn _mm512_set_ph(
+    e31: u16,
+    e30: u16,
+    e29: u16,
+    e28: u16,
+    e27: u16,
+    e26: u16,
+    e25: u16,
+    e24: u16,
+    e23: u16,
+    e22: u16,
+    e21: u16,
+    e20: u16,
+    e19: u16,
+    e18: u16,
+    e17: u16,
+    e16: u16,
+    e15: u16,
+    e14: u16,
+    e13: u16,
+    e12: u16,
+    e11: u16,
+    e10: u16,
+    e9: u16,
+    e8: u16,
+    e7: u16,
+    e6: u16,
+    e5: u16,
+    e4: u16,
+    e3: u16,
+    e2: u16,
+    e1: u16,
+    e0: u16,
+) -> __m512h {
+    transmute(u16x32::new(
+        e0, e1, e2, e3, e4, e5, e6, e7, e8, e9, e10, e11, e12, e13, e14, e15, e16, e17, e18, e19,
+        e20, e21, e22, e23, e24, e25, e26, e27, e28, e29, e30, e31,
+    ))
+}
+
+/// Set packed half-precision (16-bit) floating-point elements in the
+/// returned vector with the supplied values, given as bit patterns, in
+/// reverse order.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_setr_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+pub unsafe fn _mm512_setr_ph(
+    e0: u16,
+    e1: u16,
+    e2: u16,
+    e3: u16,
+    e4: u16,
+    e5: u16,
+    e6: u16,
+    e7: u16,
+    e8: u16,
+    e9: u16,
+    e10: u16,
+    e11: u16,
+    e12: u16,
+    e13: u16,
+    e14: u16,
+    e15: u16,
+    e16: u16,
+    e17: u16,
+    e18: u16,
+    e19: u16,
+    e20: u16,
+    e21: u16,
+    e22: u16,
+    e23: u16,
+    e24: u16,
+    e25: u16,
+    e26: u16,
+    e27: u16,
+    e28: u16,
+    e29: u16,
+    e30: u16,
+    e31: u16,
+) -> __m512h {
+    transmute(u16x32::new(
+        e0, e1, e2, e3, e4, e5, e6, e7, e8, e9, e10, e11, e12, e13, e14, e15, e16, e17, e18, e19,
+        e20, e21, e22, e23, e24, e25, e26, e27, e28, e29, e30, e31,
+    ))
+}
+
+/// Set packed half-precision (16-bit) floating-point elements in the
+/// returned vector with the supplied values, given as bit patterns.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_set_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+pub unsafe fn _mm256_set_ph(
+    e15: u16,
+    e14: u16,
+    e13: u16,
+    e12: u16,
+    e11: u16,
+    e10: u16,
+    e9: u16,
+    e8: u16,
+    e7: u16,
+    e6: u16,
+    e5: u16,
+    e4: u16,
+    e3: u16,
+    e2: u16,
+    e1: u16,
+    e0: u16,
+) -> __m256h {
+    transmute(u16x16::new(
+        e0, e1, e2, e3, e4, e5, e6, e7, e8, e9, e10, e11, e12, e13, e14, e15,
+    ))
+}
+
+/// Set packed half-precision (16-bit) floating-point elements in the
+/// returned vector with the supplied values, given as bit patterns, in
+/// reverse order.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_setr_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+pub unsafe fn _mm256_setr_ph(
+    e0: u16,
+    e1: u16,
+    e2: u16,
+    e3: u16,
+    e4: u16,
+    e5: u16,
+    e6: u16,
+    e7: u16,
+    e8: u16,
+    e9: u16,
+    e10: u16,
+    e11: u16,
+    e12: u16,
+    e13: u16,
+    e14: u16,
+    e15: u16,
+) -> __m256h {
+    transmute(u16x16::new(
+        e0, e1, e2, e3, e4, e5, e6, e7, e8, e9, e10, e11, e12, e13, e14, e15,
+    ))
+}
+
+/// Set packed half-precision (16-bit) floating-point elements in the
+/// returned vector with the supplied values, given as bit patterns.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_set_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+pub unsafe fn _mm_set_ph(
+    e7: u16,
+    e6: u16,
+    e5: u16,
+    e4: u16,
+    e3: u16,
+    e2: u16,
+    e1: u16,
+    e0: u16,
+) -> __m128h {
+    transmute(u16x8::new(e0, e1, e2, e3, e4, e5, e6, e7))
+}
+
+/// Set packed half-precision (16-bit) floating-point elements in the
+/// returned vector with the supplied values, given as bit patterns, in
+/// reverse order.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_setr_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+pub unsafe fn _mm_setr_ph(
+    e0: u16,
+    e1: u16,
+    e2: u16,
+    e3: u16,
+    e4: u16,
+    e5: u16,
+    e6: u16,
+    e7: u16,
+) -> __m128h {
+    transmute(u16x8::new(e0, e1, e2, e3, e4, e5, e6, e7))
+}
+
+/// Load 512-bits (composed of 32 packed half-precision (16-bit)
+/// floating-point elements) from memory into the returned vector.
+/// `mem_addr` must be aligned on a 64-byte boundary or a general-protection
+/// exception may be generated.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_load_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vmovaps))]
+pub unsafe fn _mm512_load_ph(mem_addr: *const u16) -> __m512h {
+    debug_assert_aligned!(mem_addr, 64);
+    ptr::read(mem_addr as *const __m512h)
+}
+
+/// Load 512-bits (composed of 32 packed half-precision (16-bit)
+/// floating-point elements) from memory into the returned vector.
+/// `mem_addr` does not need to be aligned on any particular boundary.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_loadu_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vmovups))]
+pub unsafe fn _mm512_loadu_ph(mem_addr: *const u16) -> __m512h {
+    ptr::read_unaligned(mem_addr as *const __m512h)
+}
+
+/// Store 512-bits (composed of 32 packed half-precision (16-bit)
+/// floating-point elements) from `a` into memory.
+/// `mem_addr` must be aligned on a 64-byte boundary or a general-protection
+/// exception may be generated.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_store_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vmovaps))]
+pub unsafe fn _mm512_store_ph(mem_addr: *mut u16, a: __m512h) {
+    debug_assert_aligned!(mem_addr, 64);
+    ptr::write(mem_addr as *mut __m512h, a);
+}
+
+/// Store 512-bits (composed of 32 packed half-precision (16-bit)
+/// floating-point elements) from `a` into memory.
+/// `mem_addr` does not need to be aligned on any particular boundary.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_storeu_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vmovups))]
+pub unsafe fn _mm512_storeu_ph(mem_addr: *mut u16, a: __m512h) {
+    ptr::write_unaligned(mem_addr as *mut __m512h, a);
+}
+
+/// Load 256-bits (composed of 16 packed half-precision (16-bit)
+/// floating-point elements) from memory into the returned vector.
+/// `mem_addr` must be aligned on a 32-byte boundary or a general-protection
+/// exception may be generated.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_load_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vmovaps))]
+pub unsafe fn _mm256_load_ph(mem_addr: *const u16) -> __m256h {
+    debug_assert_aligned!(mem_addr, 32);
+    ptr::read(mem_addr as *const __m256h)
+}
+
+/// Load 256-bits (composed of 16 packed half-precision (16-bit)
+/// floating-point elements) from memory into the returned vector.
+/// `mem_addr` does not need to be aligned on any particular boundary.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_loadu_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vmovups))]
+pub unsafe fn _mm256_loadu_ph(mem_addr: *const u16) -> __m256h {
+    ptr::read_unaligned(mem_addr as *const __m256h)
+}
+
+/// Store 256-bits (composed of 16 packed half-precision (16-bit)
+/// floating-point elements) from `a` into memory.
+/// `mem_addr` must be aligned on a 32-byte boundary or a general-protection
+/// exception may be generated.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_store_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vmovaps))]
+pub unsafe fn _mm256_store_ph(mem_addr: *mut u16, a: __m256h) {
+    debug_assert_aligned!(mem_addr, 32);
+    ptr::write(mem_addr as *mut __m256h, a);
+}
+
+/// Store 256-bits (composed of 16 packed half-precision (16-bit)
+/// floating-point elements) from `a` into memory.
+/// `mem_addr` does not need to be aligned on any particular boundary.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_storeu_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vmovups))]
+pub unsafe fn _mm256_storeu_ph(mem_addr: *mut u16, a: __m256h) {
+    ptr::write_unaligned(mem_addr as *mut __m256h, a);
+}
+
+/// Load 128-bits (composed of 8 packed half-precision (16-bit)
+/// floating-point elements) from memory into the returned vector.
+/// `mem_addr` must be aligned on a 16-byte boundary or a general-protection
+/// exception may be generated.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_load_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vmovaps))]
+pub unsafe fn _mm_load_ph(mem_addr: *const u16) -> __m128h {
+    debug_assert_aligned!(mem_addr, 16);
+    ptr::read(mem_addr as *const __m128h)
+}
+
+/// Load 128-bits (composed of 8 packed half-precision (16-bit)
+/// floating-point elements) from memory into the returned vector.
+/// `mem_addr` does not need to be aligned on any particular boundary.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_loadu_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vmovups))]
+pub unsafe fn _mm_loadu_ph(mem_addr: *const u16) -> __m128h {
+    ptr::read_unaligned(mem_addr as *const __m128h)
+}
+
+/// Store 128-bits (composed of 8 packed half-precision (16-bit)
+/// floating-point elements) from `a` into memory.
+/// `mem_addr` must be aligned on a 16-byte boundary or a general-protection
+/// exception may be generated.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_store_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vmovaps))]
+pub unsafe fn _mm_store_ph(mem_addr: *mut u16, a: __m128h) {
+    debug_assert_aligned!(mem_addr, 16);
+    ptr::write(mem_addr as *mut __m128h, a);
+}
+
+/// Store 128-bits (composed of 8 packed half-precision (16-bit)
+/// floating-point elements) from `a` into memory.
+/// `mem_addr` does not need to be aligned on any particular boundary.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_storeu_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vmovups))]
+pub unsafe fn _mm_storeu_ph(mem_addr: *mut u16, a: __m128h) {
+    ptr::write_unaligned(mem_addr as *mut __m128h, a);
+}
+
+/// Add packed half-precision (16-bit) floating-point elements in `a` and `b`,
+/// and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_add_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vaddph))]
+pub unsafe fn _mm512_add_ph(a: __m512h, b: __m512h) -> __m512h {
+    fp16_binop!("vaddph", zmm_reg, a, b)
+}
+
+/// Add packed half-precision (16-bit) floating-point elements in `a` and `b`,
+/// and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_add_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vaddph))]
+pub unsafe fn _mm512_mask_add_ph(src: __m512h, k: __mmask32, a: __m512h, b: __m512h) -> __m512h {
+    let r = _mm512_add_ph(a, b).as_u16x32();
+    transmute(simd_select_bitmask(k, r, src.as_u16x32()))
+}
+
+/// Add packed half-precision (16-bit) floating-point elements in `a` and `b`,
+/// and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_add_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vaddph))]
+pub unsafe fn _mm512_maskz_add_ph(k: __mmask32, a: __m512h, b: __m512h) -> __m512h {
+    let r = _mm512_add_ph(a, b).as_u16x32();
+    let zero = u16x32::splat(0);
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Add packed half-precision (16-bit) floating-point elements in `a` and `b`,
+/// and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_add_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vaddph))]
+pub unsafe fn _mm256_add_ph(a: __m256h, b: __m256h) -> __m256h {
+    fp16_binop!("vaddph", ymm_reg, a, b)
+}
+
+/// Add packed half-precision (16-bit) floating-point elements in `a` and `b`,
+/// and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_add_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vaddph))]
+pub unsafe fn _mm256_mask_add_ph(src: __m256h, k: __mmask16, a: __m256h, b: __m256h) -> __m256h {
+    let r = _mm256_add_ph(a, b).as_u16x16();
+    transmute(simd_select_bitmask(k, r, src.as_u16x16()))
+}
+
+/// Add packed half-precision (16-bit) floating-point elements in `a` and `b`,
+/// and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_add_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vaddph))]
+pub unsafe fn _mm256_maskz_add_ph(k: __mmask16, a: __m256h, b: __m256h) -> __m256h {
+    let r = _mm256_add_ph(a, b).as_u16x16();
+    let zero = u16x16::splat(0);
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Add packed half-precision (16-bit) floating-point elements in `a` and `b`,
+/// and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_add_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vaddph))]
+pub unsafe fn _mm_add_ph(a: __m128h, b: __m128h) -> __m128h {
+    fp16_binop!("vaddph", xmm_reg, a, b)
+}
+
+/// Add packed half-precision (16-bit) floating-point elements in `a` and `b`,
+/// and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_add_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vaddph))]
+pub unsafe fn _mm_mask_add_ph(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h {
+    let r = _mm_add_ph(a, b).as_u16x8();
+    transmute(simd_select_bitmask(k, r, src.as_u16x8()))
+}
+
+/// Add packed half-precision (16-bit) floating-point elements in `a` and `b`,
+/// and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_add_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vaddph))]
+pub unsafe fn _mm_maskz_add_ph(k: __mmask8, a: __m128h, b: __m128h) -> __m128h {
+    let r = _mm_add_ph(a, b).as_u16x8();
+    let zero = u16x8::splat(0);
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Subtract packed half-precision (16-bit) floating-point elements in `b` from `a`,
+/// and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_sub_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vsubph))]
+pub unsafe fn _mm512_sub_ph(a: __m512h, b: __m512h) -> __m512h {
+    fp16_binop!("vsubph", zmm_reg, a, b)
+}
+
+/// Subtract packed half-precision (16-bit) floating-point elements in `b` from `a`,
+/// and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_sub_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vsubph))]
+pub unsafe fn _mm512_mask_sub_ph(src: __m512h, k: __mmask32, a: __m512h, b: __m512h) -> __m512h {
+    let r = _mm512_sub_ph(a, b).as_u16x32();
+    transmute(simd_select_bitmask(k, r, src.as_u16x32()))
+}
+
+/// Subtract packed half-precision (16-bit) floating-point elements in `b` from `a`,
+/// and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_sub_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vsubph))]
+pub unsafe fn _mm512_maskz_sub_ph(k: __mmask32, a: __m512h, b: __m512h) -> __m512h {
+    let r = _mm512_sub_ph(a, b).as_u16x32();
+    let zero = u16x32::splat(0);
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Subtract packed half-precision (16-bit) floating-point elements in `b` from `a`,
+/// and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_sub_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vsubph))]
+pub unsafe fn _mm256_sub_ph(a: __m256h, b: __m256h) -> __m256h {
+    fp16_binop!("vsubph", ymm_reg, a, b)
+}
+
+/// Subtract packed half-precision (16-bit) floating-point elements in `b` from `a`,
+/// and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_sub_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vsubph))]
+pub unsafe fn _mm256_mask_sub_ph(src: __m256h, k: __mmask16, a: __m256h, b: __m256h) -> __m256h {
+    let r = _mm256_sub_ph(a, b).as_u16x16();
+    transmute(simd_select_bitmask(k, r, src.as_u16x16()))
+}
+
+/// Subtract packed half-precision (16-bit) floating-point elements in `b` from `a`,
+/// and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_sub_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vsubph))]
+pub unsafe fn _mm256_maskz_sub_ph(k: __mmask16, a: __m256h, b: __m256h) -> __m256h {
+    let r = _mm256_sub_ph(a, b).as_u16x16();
+    let zero = u16x16::splat(0);
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Subtract packed half-precision (16-bit) floating-point elements in `b` from `a`,
+/// and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_sub_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vsubph))]
+pub unsafe fn _mm_sub_ph(a: __m128h, b: __m128h) -> __m128h {
+    fp16_binop!("vsubph", xmm_reg, a, b)
+}
+
+/// Subtract packed half-precision (16-bit) floating-point elements in `b` from `a`,
+/// and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_sub_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vsubph))]
+pub unsafe fn _mm_mask_sub_ph(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h {
+    let r = _mm_sub_ph(a, b).as_u16x8();
+    transmute(simd_select_bitmask(k, r, src.as_u16x8()))
+}
+
+/// Subtract packed half-precision (16-bit) floating-point elements in `b` from `a`,
+/// and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_sub_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vsubph))]
+pub unsafe fn _mm_maskz_sub_ph(k: __mmask8, a: __m128h, b: __m128h) -> __m128h {
+    let r = _mm_sub_ph(a, b).as_u16x8();
+    let zero = u16x8::splat(0);
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in `a` and `b`,
+/// and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mul_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vmulph))]
+pub unsafe fn _mm512_mul_ph(a: __m512h, b: __m512h) -> __m512h {
+    fp16_binop!("vmulph", zmm_reg, a, b)
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in `a` and `b`,
+/// and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_mul_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vmulph))]
+pub unsafe fn _mm512_mask_mul_ph(src: __m512h, k: __mmask32, a: __m512h, b: __m512h) -> __m512h {
+    let r = _mm512_mul_ph(a, b).as_u16x32();
+    transmute(simd_select_bitmask(k, r, src.as_u16x32()))
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in `a` and `b`,
+/// and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_mul_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vmulph))]
+pub unsafe fn _mm512_maskz_mul_ph(k: __mmask32, a: __m512h, b: __m512h) -> __m512h {
+    let r = _mm512_mul_ph(a, b).as_u16x32();
+    let zero = u16x32::splat(0);
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in `a` and `b`,
+/// and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mul_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vmulph))]
+pub unsafe fn _mm256_mul_ph(a: __m256h, b: __m256h) -> __m256h {
+    fp16_binop!("vmulph", ymm_reg, a, b)
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in `a` and `b`,
+/// and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_mul_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vmulph))]
+pub unsafe fn _mm256_mask_mul_ph(src: __m256h, k: __mmask16, a: __m256h, b: __m256h) -> __m256h {
+    let r = _mm256_mul_ph(a, b).as_u16x16();
+    transmute(simd_select_bitmask(k, r, src.as_u16x16()))
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in `a` and `b`,
+/// and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_mul_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vmulph))]
+pub unsafe fn _mm256_maskz_mul_ph(k: __mmask16, a: __m256h, b: __m256h) -> __m256h {
+    let r = _mm256_mul_ph(a, b).as_u16x16();
+    let zero = u16x16::splat(0);
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in `a` and `b`,
+/// and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mul_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vmulph))]
+pub unsafe fn _mm_mul_ph(a: __m128h, b: __m128h) -> __m128h {
+    fp16_binop!("vmulph", xmm_reg, a, b)
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in `a` and `b`,
+/// and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_mul_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vmulph))]
+pub unsafe fn _mm_mask_mul_ph(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h {
+    let r = _mm_mul_ph(a, b).as_u16x8();
+    transmute(simd_select_bitmask(k, r, src.as_u16x8()))
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in `a` and `b`,
+/// and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_mul_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vmulph))]
+pub unsafe fn _mm_maskz_mul_ph(k: __mmask8, a: __m128h, b: __m128h) -> __m128h {
+    let r = _mm_mul_ph(a, b).as_u16x8();
+    let zero = u16x8::splat(0);
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Divide packed half-precision (16-bit) floating-point elements in `a` by `b`,
+/// and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_div_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vdivph))]
+pub unsafe fn _mm512_div_ph(a: __m512h, b: __m512h) -> __m512h {
+    fp16_binop!("vdivph", zmm_reg, a, b)
+}
+
+/// Divide packed half-precision (16-bit) floating-point elements in `a` by `b`,
+/// and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_div_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vdivph))]
+pub unsafe fn _mm512_mask_div_ph(src: __m512h, k: __mmask32, a: __m512h, b: __m512h) -> __m512h {
+    let r = _mm512_div_ph(a, b).as_u16x32();
+    transmute(simd_select_bitmask(k, r, src.as_u16x32()))
+}
+
+/// Divide packed half-precision (16-bit) floating-point elements in `a` by `b`,
+/// and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_div_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vdivph))]
+pub unsafe fn _mm512_maskz_div_ph(k: __mmask32, a: __m512h, b: __m512h) -> __m512h {
+    let r = _mm512_div_ph(a, b).as_u16x32();
+    let zero = u16x32::splat(0);
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Divide packed half-precision (16-bit) floating-point elements in `a` by `b`,
+/// and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_div_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vdivph))]
+pub unsafe fn _mm256_div_ph(a: __m256h, b: __m256h) -> __m256h {
+    fp16_binop!("vdivph", ymm_reg, a, b)
+}
+
+/// Divide packed half-precision (16-bit) floating-point elements in `a` by `b`,
+/// and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_div_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vdivph))]
+pub unsafe fn _mm256_mask_div_ph(src: __m256h, k: __mmask16, a: __m256h, b: __m256h) -> __m256h {
+    let r = _mm256_div_ph(a, b).as_u16x16();
+    transmute(simd_select_bitmask(k, r, src.as_u16x16()))
+}
+
+/// Divide packed half-precision (16-bit) floating-point elements in `a` by `b`,
+/// and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_div_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vdivph))]
+pub unsafe fn _mm256_maskz_div_ph(k: __mmask16, a: __m256h, b: __m256h) -> __m256h {
+    let r = _mm256_div_ph(a, b).as_u16x16();
+    let zero = u16x16::splat(0);
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Divide packed half-precision (16-bit) floating-point elements in `a` by `b`,
+/// and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_div_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vdivph))]
+pub unsafe fn _mm_div_ph(a: __m128h, b: __m128h) -> __m128h {
+    fp16_binop!("vdivph", xmm_reg, a, b)
+}
+
+/// Divide packed half-precision (16-bit) floating-point elements in `a` by `b`,
+/// and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_div_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vdivph))]
+pub unsafe fn _mm_mask_div_ph(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h {
+    let r = _mm_div_ph(a, b).as_u16x8();
+    transmute(simd_select_bitmask(k, r, src.as_u16x8()))
+}
+
+/// Divide packed half-precision (16-bit) floating-point elements in `a` by `b`,
+/// and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_div_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vdivph))]
+pub unsafe fn _mm_maskz_div_ph(k: __mmask8, a: __m128h, b: __m128h) -> __m128h {
+    let r = _mm_div_ph(a, b).as_u16x8();
+    let zero = u16x8::splat(0);
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in `a` and `b`, add the intermediate result to packed elements in `c`,
+/// and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_fmadd_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vfmadd))]
+pub unsafe fn _mm512_fmadd_ph(a: __m512h, b: __m512h, c: __m512h) -> __m512h {
+    fp16_fma!("vfmadd213ph", zmm_reg, a, b, c)
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in `a` and `b`, add the intermediate result to packed elements in `c`,
+/// and store the results in dst using writemask k (elements are copied from a when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_fmadd_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vfmadd))]
+pub unsafe fn _mm512_mask_fmadd_ph(a: __m512h, k: __mmask32, b: __m512h, c: __m512h) -> __m512h {
+    let r = _mm512_fmadd_ph(a, b, c).as_u16x32();
+    transmute(simd_select_bitmask(k, r, a.as_u16x32()))
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in `a` and `b`, add the intermediate result to packed elements in `c`,
+/// and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_fmadd_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vfmadd))]
+pub unsafe fn _mm512_maskz_fmadd_ph(k: __mmask32, a: __m512h, b: __m512h, c: __m512h) -> __m512h {
+    let r = _mm512_fmadd_ph(a, b, c).as_u16x32();
+    let zero = u16x32::splat(0);
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in `a` and `b`, add the intermediate result to packed elements in `c`,
+/// and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_fmadd_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfmadd))]
+pub unsafe fn _mm256_fmadd_ph(a: __m256h, b: __m256h, c: __m256h) -> __m256h {
+    fp16_fma!("vfmadd213ph", ymm_reg, a, b, c)
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in `a` and `b`, add the intermediate result to packed elements in `c`,
+/// and store the results in dst using writemask k (elements are copied from a when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_fmadd_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfmadd))]
+pub unsafe fn _mm256_mask_fmadd_ph(a: __m256h, k: __mmask16, b: __m256h, c: __m256h) -> __m256h {
+    let r = _mm256_fmadd_ph(a, b, c).as_u16x16();
+    transmute(simd_select_bitmask(k, r, a.as_u16x16()))
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in `a` and `b`, add the intermediate result to packed elements in `c`,
+/// and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_fmadd_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfmadd))]
+pub unsafe fn _mm256_maskz_fmadd_ph(k: __mmask16, a: __m256h, b: __m256h, c: __m256h) -> __m256h {
+    let r = _mm256_fmadd_ph(a, b, c).as_u16x16();
+    let zero = u16x16::splat(0);
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in `a` and `b`, add the intermediate result to packed elements in `c`,
+/// and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_fmadd_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfmadd))]
+pub unsafe fn _mm_fmadd_ph(a: __m128h, b: __m128h, c: __m128h) -> __m128h {
+    fp16_fma!("vfmadd213ph", xmm_reg, a, b, c)
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in `a` and `b`, add the intermediate result to packed elements in `c`,
+/// and store the results in dst using writemask k (elements are copied from a when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_fmadd_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfmadd))]
+pub unsafe fn _mm_mask_fmadd_ph(a: __m128h, k: __mmask8, b: __m128h, c: __m128h) -> __m128h {
+    let r = _mm_fmadd_ph(a, b, c).as_u16x8();
+    transmute(simd_select_bitmask(k, r, a.as_u16x8()))
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in `a` and `b`, add the intermediate result to packed elements in `c`,
+/// and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_fmadd_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfmadd))]
+pub unsafe fn _mm_maskz_fmadd_ph(k: __mmask8, a: __m128h, b: __m128h, c: __m128h) -> __m128h {
+    let r = _mm_fmadd_ph(a, b, c).as_u16x8();
+    let zero = u16x8::splat(0);
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Convert packed half-precision (16-bit) floating-point elements in `a` to packed
+/// single-precision (32-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvtxph_ps)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vcvtph2psx))]
+pub unsafe fn _mm512_cvtxph_ps(a: __m256h) -> __m512 {
+    fp16_unop!("vcvtph2psx", zmm_reg, ymm_reg, a)
+}
+
+/// Convert packed half-precision (16-bit) floating-point elements in `a` to packed
+/// single-precision (32-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cvtxph_ps)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtph2psx))]
+pub unsafe fn _mm256_cvtxph_ps(a: __m128h) -> __m256 {
+    fp16_unop!("vcvtph2psx", ymm_reg, xmm_reg, a)
+}
+
+/// Convert packed half-precision (16-bit) floating-point elements in `a` to packed
+/// single-precision (32-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvtxph_ps)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtph2psx))]
+pub unsafe fn _mm_cvtxph_ps(a: __m128h) -> __m128 {
+    fp16_unop!("vcvtph2psx", xmm_reg, xmm_reg, a)
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in `a` to packed
+/// half-precision (16-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvtxps_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vcvtps2phx))]
+pub unsafe fn _mm512_cvtxps_ph(a: __m512) -> __m256h {
+    fp16_unop!("vcvtps2phx", ymm_reg, zmm_reg, a)
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in `a` to packed
+/// half-precision (16-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cvtxps_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtps2phx))]
+pub unsafe fn _mm256_cvtxps_ph(a: __m256) -> __m128h {
+    fp16_unop!("vcvtps2phx", xmm_reg, ymm_reg, a)
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in `a` to packed
+/// half-precision (16-bit) floating-point elements, and store the results in dst.
+/// The upper 64 bits of dst are zeroed out.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvtxps_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtps2phx))]
+pub unsafe fn _mm_cvtxps_ph(a: __m128) -> __m128h {
+    fp16_unop!("vcvtps2phx", xmm_reg, xmm_reg, a)
+}
+
+/// Convert packed half-precision (16-bit) floating-point elements in `a` to packed
+/// double-precision (64-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvtph_pd)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vcvtph2pd))]
+pub unsafe fn _mm512_cvtph_pd(a: __m128h) -> __m512d {
+    fp16_unop!("vcvtph2pd", zmm_reg, xmm_reg, a)
+}
+
+/// Convert packed half-precision (16-bit) floating-point elements in `a` to packed
+/// double-precision (64-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cvtph_pd)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtph2pd))]
+pub unsafe fn _mm256_cvtph_pd(a: __m128h) -> __m256d {
+    fp16_unop!("vcvtph2pd", ymm_reg, xmm_reg, a)
+}
+
+/// Convert packed half-precision (16-bit) floating-point elements in `a` to packed
+/// double-precision (64-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvtph_pd)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtph2pd))]
+pub unsafe fn _mm_cvtph_pd(a: __m128h) -> __m128d {
+    fp16_unop!("vcvtph2pd", xmm_reg, xmm_reg, a)
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in `a` to packed
+/// half-precision (16-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvtpd_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vcvtpd2ph))]
+pub unsafe fn _mm512_cvtpd_ph(a: __m512d) -> __m128h {
+    fp16_unop!("vcvtpd2ph", xmm_reg, zmm_reg, a)
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in `a` to packed
+/// half-precision (16-bit) floating-point elements, and store the results in dst.
+/// The upper 64 bits of dst are zeroed out.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cvtpd_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtpd2ph))]
+pub unsafe fn _mm256_cvtpd_ph(a: __m256d) -> __m128h {
+    fp16_unop!("vcvtpd2ph", xmm_reg, ymm_reg, a)
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in `a` to packed
+/// half-precision (16-bit) floating-point elements, and store the results in dst.
+/// The upper 96 bits of dst are zeroed out.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvtpd_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtpd2ph))]
+pub unsafe fn _mm_cvtpd_ph(a: __m128d) -> __m128h {
+    fp16_unop!("vcvtpd2ph", xmm_reg, xmm_reg, a)
+}
+
+/// Convert packed half-precision (16-bit) floating-point elements in `a` to packed
+/// 16-bit integers, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvtph_epi16)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vcvtph2w))]
+pub unsafe fn _mm512_cvtph_epi16(a: __m512h) -> __m512i {
+    fp16_unop!("vcvtph2w", zmm_reg, zmm_reg, a)
+}
+
+/// Convert packed half-precision (16-bit) floating-point elements in `a` to packed
+/// 16-bit integers, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cvtph_epi16)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtph2w))]
+pub unsafe fn _mm256_cvtph_epi16(a: __m256h) -> __m256i {
+    fp16_unop!("vcvtph2w", ymm_reg, ymm_reg, a)
+}
+
+/// Convert packed half-precision (16-bit) floating-point elements in `a` to packed
+/// 16-bit integers, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvtph_epi16)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtph2w))]
+pub unsafe fn _mm_cvtph_epi16(a: __m128h) -> __m128i {
+    fp16_unop!("vcvtph2w", xmm_reg, xmm_reg, a)
+}
+
+/// Convert packed signed 16-bit integers in `a` to packed half-precision (16-bit)
+/// floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvtepi16_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vcvtw2ph))]
+pub unsafe fn _mm512_cvtepi16_ph(a: __m512i) -> __m512h {
+    fp16_unop!("vcvtw2ph", zmm_reg, zmm_reg, a)
+}
+
+/// Convert packed signed 16-bit integers in `a` to packed half-precision (16-bit)
+/// floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cvtepi16_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtw2ph))]
+pub unsafe fn _mm256_cvtepi16_ph(a: __m256i) -> __m256h {
+    fp16_unop!("vcvtw2ph", ymm_reg, ymm_reg, a)
+}
+
+/// Convert packed signed 16-bit integers in `a` to packed half-precision (16-bit)
+/// floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvtepi16_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtw2ph))]
+pub unsafe fn _mm_cvtepi16_ph(a: __m128i) -> __m128h {
+    fp16_unop!("vcvtw2ph", xmm_reg, xmm_reg, a)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+    use crate::hint::black_box;
+    use crate::mem::transmute;
+    use stdarch_test::simd_test;
+
+    /// Converts `x` to the bit pattern of the nearest `binary16` value,
+    /// rounding ties to even. Values that are too large for `binary16` aren't
+    /// handled, which none of these tests need.
+    fn f16(x: f32) -> u16 {
+        let bits = x.to_bits();
+        let sign = (bits >> 16) as u16 & 0x8000;
+        let exp = ((bits >> 23) & 0xff) as i32 - 127 + 15;
+        // Subnormal results keep the implicit bit and shift it further out,
+        // zero and anything below half the smallest subnormal becomes zero.
+        let (mant, shift) = if exp > 0 {
+            (bits & 0x7f_ffff, 13)
+        } else {
+            ((bits & 0x7f_ffff) | 0x80_0000, 14 - exp)
+        };
+        if shift > 24 {
+            return sign;
+        }
+        let half = 1 << (shift - 1);
+        let rest = mant & ((half << 1) - 1);
+        let mut mant = mant >> shift;
+        if rest > half || (rest == half && mant & 1 == 1) {
+            mant += 1;
+        }
+        // A carry out of the mantissa correctly bumps the exponent.
+        sign | (((exp.max(0) as u32) << 10) + mant) as u16
+    }
+
+    /// Asserts that lane `i` of `r` is `t` if bit `i` of `k` is set, and `f`
+    /// otherwise.
+    fn assert_lanes(r: &[u16], k: u64, t: u16, f: u16) {
+        for (i, &x) in r.iter().enumerate() {
+            let e = if k & (1 << i) != 0 { t } else { f };
+            assert_eq!(x, e, "lane {}", i);
+        }
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_setzero_ph() {
+        let r = _mm512_setzero_ph();
+        assert_eq!(transmute::<_, [u16; 32]>(r), [0; 32]);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_setzero_ph() {
+        let r = _mm256_setzero_ph();
+        assert_eq!(transmute::<_, [u16; 16]>(r), [0; 16]);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_setzero_ph() {
+        let r = _mm_setzero_ph();
+        assert_eq!(transmute::<_, [u16; 8]>(r), [0; 8]);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_set1_ph() {
+        let r = _mm512_set1_ph(f16(1.5));
+        assert_eq!(transmute::<_, [u16; 32]>(r), [0x3e00; 32]);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_set1_ph() {
+        let r = _mm256_set1_ph(f16(1.5));
+        assert_eq!(transmute::<_, [u16; 16]>(r), [0x3e00; 16]);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_set1_ph() {
+        let r = _mm_set1_ph(f16(1.5));
+        assert_eq!(transmute::<_, [u16; 8]>(r), [0x3e00; 8]);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_set_ph() {
+        #[rustfmt::skip]
+        let r = _mm512_set_ph(31, 30, 29, 28, 27, 26, 25, 24, 23, 22, 21, 20, 19, 18, 17, 16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0);
+        let e: [u16; 32] = [
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23,
+            24, 25, 26, 27, 28, 29, 30, 31,
+        ];
+        assert_eq!(transmute::<_, [u16; 32]>(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_setr_ph() {
+        #[rustfmt::skip]
+        let r = _mm512_setr_ph(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31);
+        let e: [u16; 32] = [
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23,
+            24, 25, 26, 27, 28, 29, 30, 31,
+        ];
+        assert_eq!(transmute::<_, [u16; 32]>(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_set_ph() {
+        #[rustfmt::skip]
+        let r = _mm256_set_ph(15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0);
+        let e: [u16; 16] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+        assert_eq!(transmute::<_, [u16; 16]>(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_setr_ph() {
+        #[rustfmt::skip]
+        let r = _mm256_setr_ph(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+        let e: [u16; 16] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+        assert_eq!(transmute::<_, [u16; 16]>(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_set_ph() {
+        let r = _mm_set_ph(7, 6, 5, 4, 3, 2, 1, 0);
+        let e: [u16; 8] = [0, 1, 2, 3, 4, 5, 6, 7];
+        assert_eq!(transmute::<_, [u16; 8]>(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_setr_ph() {
+        let r = _mm_setr_ph(0, 1, 2, 3, 4, 5, 6, 7);
+        let e: [u16; 8] = [0, 1, 2, 3, 4, 5, 6, 7];
+        assert_eq!(transmute::<_, [u16; 8]>(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_load_ph() {
+        #[repr(align(64))]
+        struct Align {
+            data: [u16; 32],
+        }
+        let mut a = Align { data: [0; 32] };
+        for (i, x) in a.data.iter_mut().enumerate() {
+            *x = f16(i as f32);
+        }
+        let r = _mm512_load_ph(black_box(a.data.as_ptr()));
+        assert_eq!(transmute::<_, [u16; 32]>(r), a.data);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_loadu_ph() {
+        let a: [u16; 33] = [
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23,
+            24, 25, 26, 27, 28, 29, 30, 31, 32,
+        ];
+        let r = _mm512_loadu_ph(black_box(a[1..].as_ptr()));
+        let e: [u16; 32] = [
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24,
+            25, 26, 27, 28, 29, 30, 31, 32,
+        ];
+        assert_eq!(transmute::<_, [u16; 32]>(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_store_ph() {
+        let a = _mm512_set1_ph(f16(9.0));
+        let mut r = _mm512_setzero_ph();
+        _mm512_store_ph(&mut r as *mut _ as *mut u16, a);
+        assert_eq!(transmute::<_, [u16; 32]>(r), transmute::<_, [u16; 32]>(a));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_storeu_ph() {
+        let a = _mm512_set1_ph(f16(9.0));
+        let mut r = _mm512_setzero_ph();
+        _mm512_storeu_ph(&mut r as *mut _ as *mut u16, a);
+        assert_eq!(transmute::<_, [u16; 32]>(r), transmute::<_, [u16; 32]>(a));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_load_ph() {
+        #[repr(align(32))]
+        struct Align {
+            data: [u16; 16],
+        }
+        let mut a = Align { data: [0; 16] };
+        for (i, x) in a.data.iter_mut().enumerate() {
+            *x = f16(i as f32);
+        }
+        let r = _mm256_load_ph(black_box(a.data.as_ptr()));
+        assert_eq!(transmute::<_, [u16; 16]>(r), a.data);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_loadu_ph() {
+        let a: [u16; 17] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
+        let r = _mm256_loadu_ph(black_box(a[1..].as_ptr()));
+        let e: [u16; 16] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
+        assert_eq!(transmute::<_, [u16; 16]>(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_store_ph() {
+        let a = _mm256_set1_ph(f16(9.0));
+        let mut r = _mm256_setzero_ph();
+        _mm256_store_ph(&mut r as *mut _ as *mut u16, a);
+        assert_eq!(transmute::<_, [u16; 16]>(r), transmute::<_, [u16; 16]>(a));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_storeu_ph() {
+        let a = _mm256_set1_ph(f16(9.0));
+        let mut r = _mm256_setzero_ph();
+        _mm256_storeu_ph(&mut r as *mut _ as *mut u16, a);
+        assert_eq!(transmute::<_, [u16; 16]>(r), transmute::<_, [u16; 16]>(a));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_load_ph() {
+        #[repr(align(16))]
+        struct Align {
+            data: [u16; 8],
+        }
+        let mut a = Align { data: [0; 8] };
+        for (i, x) in a.data.iter_mut().enumerate() {
+            *x = f16(i as f32);
+        }
+        let r = _mm_load_ph(black_box(a.data.as_ptr()));
+        assert_eq!(transmute::<_, [u16; 8]>(r), a.data);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_loadu_ph() {
+        let a: [u16; 9] = [0, 1, 2, 3, 4, 5, 6, 7, 8];
+        let r = _mm_loadu_ph(black_box(a[1..].as_ptr()));
+        let e: [u16; 8] = [1, 2, 3, 4, 5, 6, 7, 8];
+        assert_eq!(transmute::<_, [u16; 8]>(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_store_ph() {
+        let a = _mm_set1_ph(f16(9.0));
+        let mut r = _mm_setzero_ph();
+        _mm_store_ph(&mut r as *mut _ as *mut u16, a);
+        assert_eq!(transmute::<_, [u16; 8]>(r), transmute::<_, [u16; 8]>(a));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_storeu_ph() {
+        let a = _mm_set1_ph(f16(9.0));
+        let mut r = _mm_setzero_ph();
+        _mm_storeu_ph(&mut r as *mut _ as *mut u16, a);
+        assert_eq!(transmute::<_, [u16; 8]>(r), transmute::<_, [u16; 8]>(a));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_add_ph() {
+        let a = _mm512_set1_ph(f16(1.5));
+        let b = _mm512_set1_ph(f16(2.25));
+        let r = _mm512_add_ph(a, b);
+        assert_eq!(transmute::<_, [u16; 32]>(r), [f16(3.75); 32]);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_mask_add_ph() {
+        let src = _mm512_set1_ph(f16(9.0));
+        let a = _mm512_set1_ph(f16(1.5));
+        let b = _mm512_set1_ph(f16(2.25));
+        let r = _mm512_mask_add_ph(src, 0, a, b);
+        assert_eq!(transmute::<_, [u16; 32]>(r), transmute::<_, [u16; 32]>(src));
+        let r = _mm512_mask_add_ph(src, 0b01010101_01010101_01010101_01010101, a, b);
+        assert_lanes(
+            &transmute::<_, [u16; 32]>(r),
+            0b01010101_01010101_01010101_01010101,
+            f16(3.75),
+            f16(9.0),
+        );
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_maskz_add_ph() {
+        let a = _mm512_set1_ph(f16(1.5));
+        let b = _mm512_set1_ph(f16(2.25));
+        let r = _mm512_maskz_add_ph(0, a, b);
+        assert_eq!(transmute::<_, [u16; 32]>(r), [0; 32]);
+        let r = _mm512_maskz_add_ph(0b01010101_01010101_01010101_01010101, a, b);
+        assert_lanes(
+            &transmute::<_, [u16; 32]>(r),
+            0b01010101_01010101_01010101_01010101,
+            f16(3.75),
+            0,
+        );
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_add_ph() {
+        let a = _mm256_set1_ph(f16(1.5));
+        let b = _mm256_set1_ph(f16(2.25));
+        let r = _mm256_add_ph(a, b);
+        assert_eq!(transmute::<_, [u16; 16]>(r), [f16(3.75); 16]);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_mask_add_ph() {
+        let src = _mm256_set1_ph(f16(9.0));
+        let a = _mm256_set1_ph(f16(1.5));
+        let b = _mm256_set1_ph(f16(2.25));
+        let r = _mm256_mask_add_ph(src, 0, a, b);
+        assert_eq!(transmute::<_, [u16; 16]>(r), transmute::<_, [u16; 16]>(src));
+        let r = _mm256_mask_add_ph(src, 0b01010101_01010101, a, b);
+        assert_lanes(
+            &transmute::<_, [u16; 16]>(r),
+            0b01010101_01010101,
+            f16(3.75),
+            f16(9.0),
+        );
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_maskz_add_ph() {
+        let a = _mm256_set1_ph(f16(1.5));
+        let b = _mm256_set1_ph(f16(2.25));
+        let r = _mm256_maskz_add_ph(0, a, b);
+        assert_eq!(transmute::<_, [u16; 16]>(r), [0; 16]);
+        let r = _mm256_maskz_add_ph(0b01010101_01010101, a, b);
+        assert_lanes(
+            &transmute::<_, [u16; 16]>(r),
+            0b01010101_01010101,
+            f16(3.75),
+            0,
+        );
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_add_ph() {
+        let a = _mm_set1_ph(f16(1.5));
+        let b = _mm_set1_ph(f16(2.25));
+        let r = _mm_add_ph(a, b);
+        assert_eq!(transmute::<_, [u16; 8]>(r), [f16(3.75); 8]);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_mask_add_ph() {
+        let src = _mm_set1_ph(f16(9.0));
+        let a = _mm_set1_ph(f16(1.5));
+        let b = _mm_set1_ph(f16(2.25));
+        let r = _mm_mask_add_ph(src, 0, a, b);
+        assert_eq!(transmute::<_, [u16; 8]>(r), transmute::<_, [u16; 8]>(src));
+        let r = _mm_mask_add_ph(src, 0b01010101, a, b);
+        assert_lanes(
+            &transmute::<_, [u16; 8]>(r),
+            0b01010101,
+            f16(3.75),
+            f16(9.0),
+        );
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_maskz_add_ph() {
+        let a = _mm_set1_ph(f16(1.5));
+        let b = _mm_set1_ph(f16(2.25));
+        let r = _mm_maskz_add_ph(0, a, b);
+        assert_eq!(transmute::<_, [u16; 8]>(r), [0; 8]);
+        let r = _mm_maskz_add_ph(0b01010101, a, b);
+        assert_lanes(&transmute::<_, [u16; 8]>(r), 0b01010101, f16(3.75), 0);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_sub_ph() {
+        let a = _mm512_set1_ph(f16(1.5));
+        let b = _mm512_set1_ph(f16(2.25));
+        let r = _mm512_sub_ph(a, b);
+        assert_eq!(transmute::<_, [u16; 32]>(r), [f16(-0.75); 32]);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_mask_sub_ph() {
+        let src = _mm512_set1_ph(f16(9.0));
+        let a = _mm512_set1_ph(f16(1.5));
+        let b = _mm512_set1_ph(f16(2.25));
+        let r = _mm512_mask_sub_ph(src, 0, a, b);
+        assert_eq!(transmute::<_, [u16; 32]>(r), transmute::<_, [u16; 32]>(src));
+        let r = _mm512_mask_sub_ph(src, 0b01010101_01010101_01010101_01010101, a, b);
+        assert_lanes(
+            &transmute::<_, [u16; 32]>(r),
+            0b01010101_01010101_01010101_01010101,
+            f16(-0.75),
+            f16(9.0),
+        );
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_maskz_sub_ph() {
+        let a = _mm512_set1_ph(f16(1.5));
+        let b = _mm512_set1_ph(f16(2.25));
+        let r = _mm512_maskz_sub_ph(0, a, b);
+        assert_eq!(transmute::<_, [u16; 32]>(r), [0; 32]);
+        let r = _mm512_maskz_sub_ph(0b01010101_01010101_01010101_01010101, a, b);
+        assert_lanes(
+            &transmute::<_, [u16; 32]>(r),
+            0b01010101_01010101_01010101_01010101,
+            f16(-0.75),
+            0,
+        );
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_sub_ph() {
+        let a = _mm256_set1_ph(f16(1.5));
+        let b = _mm256_set1_ph(f16(2.25));
+        let r = _mm256_sub_ph(a, b);
+        assert_eq!(transmute::<_, [u16; 16]>(r), [f16(-0.75); 16]);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_mask_sub_ph() {
+        let src = _mm256_set1_ph(f16(9.0));
+        let a = _mm256_set1_ph(f16(1.5));
+        let b = _mm256_set1_ph(f16(2.25));
+        let r = _mm256_mask_sub_ph(src, 0, a, b);
+        assert_eq!(transmute::<_, [u16; 16]>(r), transmute::<_, [u16; 16]>(src));
+        let r = _mm256_mask_sub_ph(src, 0b01010101_01010101, a, b);
+        assert_lanes(
+            &transmute::<_, [u16; 16]>(r),
+            0b01010101_01010101,
+            f16(-0.75),
+            f16(9.0),
+        );
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_maskz_sub_ph() {
+        let a = _mm256_set1_ph(f16(1.5));
+        let b = _mm256_set1_ph(f16(2.25));
+        let r = _mm256_maskz_sub_ph(0, a, b);
+        assert_eq!(transmute::<_, [u16; 16]>(r), [0; 16]);
+        let r = _mm256_maskz_sub_ph(0b01010101_01010101, a, b);
+        assert_lanes(
+            &transmute::<_, [u16; 16]>(r),
+            0b01010101_01010101,
+            f16(-0.75),
+            0,
+        );
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_sub_ph() {
+        let a = _mm_set1_ph(f16(1.5));
+        let b = _mm_set1_ph(f16(2.25));
+        let r = _mm_sub_ph(a, b);
+        assert_eq!(transmute::<_, [u16; 8]>(r), [f16(-0.75); 8]);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_mask_sub_ph() {
+        let src = _mm_set1_ph(f16(9.0));
+        let a = _mm_set1_ph(f16(1.5));
+        let b = _mm_set1_ph(f16(2.25));
+        let r = _mm_mask_sub_ph(src, 0, a, b);
+        assert_eq!(transmute::<_, [u16; 8]>(r), transmute::<_, [u16; 8]>(src));
+        let r = _mm_mask_sub_ph(src, 0b01010101, a, b);
+        assert_lanes(
+            &transmute::<_, [u16; 8]>(r),
+            0b01010101,
+            f16(-0.75),
+            f16(9.0),
+        );
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_maskz_sub_ph() {
+        let a = _mm_set1_ph(f16(1.5));
+        let b = _mm_set1_ph(f16(2.25));
+        let r = _mm_maskz_sub_ph(0, a, b);
+        assert_eq!(transmute::<_, [u16; 8]>(r), [0; 8]);
+        let r = _mm_maskz_sub_ph(0b01010101, a, b);
+        assert_lanes(&transmute::<_, [u16; 8]>(r), 0b01010101, f16(-0.75), 0);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_mul_ph() {
+        let a = _mm512_set1_ph(f16(1.5));
+        let b = _mm512_set1_ph(f16(2.25));
+        let r = _mm512_mul_ph(a, b);
+        assert_eq!(transmute::<_, [u16; 32]>(r), [f16(3.375); 32]);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_mask_mul_ph() {
+        let src = _mm512_set1_ph(f16(9.0));
+        let a = _mm512_set1_ph(f16(1.5));
+        let b = _mm512_set1_ph(f16(2.25));
+        let r = _mm512_mask_mul_ph(src, 0, a, b);
+        assert_eq!(transmute::<_, [u16; 32]>(r), transmute::<_, [u16; 32]>(src));
+        let r = _mm512_mask_mul_ph(src, 0b01010101_01010101_01010101_01010101, a, b);
+        assert_lanes(
+            &transmute::<_, [u16; 32]>(r),
+            0b01010101_01010101_01010101_01010101,
+            f16(3.375),
+            f16(9.0),
+        );
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_maskz_mul_ph() {
+        let a = _mm512_set1_ph(f16(1.5));
+        let b = _mm512_set1_ph(f16(2.25));
+        let r = _mm512_maskz_mul_ph(0, a, b);
+        assert_eq!(transmute::<_, [u16; 32]>(r), [0; 32]);
+        let r = _mm512_maskz_mul_ph(0b01010101_01010101_01010101_01010101, a, b);
+        assert_lanes(
+            &transmute::<_, [u16; 32]>(r),
+            0b01010101_01010101_01010101_01010101,
+            f16(3.375),
+            0,
+        );
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_mul_ph() {
+        let a = _mm256_set1_ph(f16(1.5));
+        let b = _mm256_set1_ph(f16(2.25));
+        let r = _mm256_mul_ph(a, b);
+        assert_eq!(transmute::<_, [u16; 16]>(r), [f16(3.375); 16]);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_mask_mul_ph() {
+        let src = _mm256_set1_ph(f16(9.0));
+        let a = _mm256_set1_ph(f16(1.5));
+        let b = _mm256_set1_ph(f16(2.25));
+        let r = _mm256_mask_mul_ph(src, 0, a, b);
+        assert_eq!(transmute::<_, [u16; 16]>(r), transmute::<_, [u16; 16]>(src));
+        let r = _mm256_mask_mul_ph(src, 0b01010101_01010101, a, b);
+        assert_lanes(
+            &transmute::<_, [u16; 16]>(r),
+            0b01010101_01010101,
+            f16(3.375),
+            f16(9.0),
+        );
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_maskz_mul_ph() {
+        let a = _mm256_set1_ph(f16(1.5));
+        let b = _mm256_set1_ph(f16(2.25));
+        let r = _mm256_maskz_mul_ph(0, a, b);
+        assert_eq!(transmute::<_, [u16; 16]>(r), [0; 16]);
+        let r = _mm256_maskz_mul_ph(0b01010101_01010101, a, b);
+        assert_lanes(
+            &transmute::<_, [u16; 16]>(r),
+            0b01010101_01010101,
+            f16(3.375),
+            0,
+        );
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_mul_ph() {
+        let a = _mm_set1_ph(f16(1.5));
+        let b = _mm_set1_ph(f16(2.25));
+        let r = _mm_mul_ph(a, b);
+        assert_eq!(transmute::<_, [u16; 8]>(r), [f16(3.375); 8]);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_mask_mul_ph() {
+        let src = _mm_set1_ph(f16(9.0));
+        let a = _mm_set1_ph(f16(1.5));
+        let b = _mm_set1_ph(f16(2.25));
+        let r = _mm_mask_mul_ph(src, 0, a, b);
+        assert_eq!(transmute::<_, [u16; 8]>(r), transmute::<_, [u16; 8]>(src));
+        let r = _mm_mask_mul_ph(src, 0b01010101, a, b);
+        assert_lanes(
+            &transmute::<_, [u16; 8]>(r),
+            0b01010101,
+            f16(3.375),
+            f16(9.0),
+        );
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_maskz_mul_ph() {
+        let a = _mm_set1_ph(f16(1.5));
+        let b = _mm_set1_ph(f16(2.25));
+        let r = _mm_maskz_mul_ph(0, a, b);
+        assert_eq!(transmute::<_, [u16; 8]>(r), [0; 8]);
+        let r = _mm_maskz_mul_ph(0b01010101, a, b);
+        assert_lanes(&transmute::<_, [u16; 8]>(r), 0b01010101, f16(3.375), 0);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_div_ph() {
+        let a = _mm512_set1_ph(f16(3.375));
+        let b = _mm512_set1_ph(f16(1.5));
+        let r = _mm512_div_ph(a, b);
+        assert_eq!(transmute::<_, [u16; 32]>(r), [f16(2.25); 32]);
+        // Rounding the `f32` quotient again to half precision can't
+        // differ from rounding the exact quotient once.
+        let a = _mm512_set1_ph(f16(1.0));
+        let b = _mm512_set1_ph(f16(3.0));
+        let r = _mm512_div_ph(a, b);
+        assert_eq!(transmute::<_, [u16; 32]>(r), [f16(1.0 / 3.0); 32]);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_mask_div_ph() {
+        let src = _mm512_set1_ph(f16(9.0));
+        let a = _mm512_set1_ph(f16(3.375));
+        let b = _mm512_set1_ph(f16(1.5));
+        let r = _mm512_mask_div_ph(src, 0, a, b);
+        assert_eq!(transmute::<_, [u16; 32]>(r), transmute::<_, [u16; 32]>(src));
+        let r = _mm512_mask_div_ph(src, 0b01010101_01010101_01010101_01010101, a, b);
+        assert_lanes(
+            &transmute::<_, [u16; 32]>(r),
+            0b01010101_01010101_01010101_01010101,
+            f16(2.25),
+            f16(9.0),
+        );
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_maskz_div_ph() {
+        let a = _mm512_set1_ph(f16(3.375));
+        let b = _mm512_set1_ph(f16(1.5));
+        let r = _mm512_maskz_div_ph(0, a, b);
+        assert_eq!(transmute::<_, [u16; 32]>(r), [0; 32]);
+        let r = _mm512_maskz_div_ph(0b01010101_01010101_01010101_01010101, a, b);
+        assert_lanes(
+            &transmute::<_, [u16; 32]>(r),
+            0b01010101_01010101_01010101_01010101,
+            f16(2.25),
+            0,
+        );
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_div_ph() {
+        let a = _mm256_set1_ph(f16(3.375));
+        let b = _mm256_set1_ph(f16(1.5));
+        let r = _mm256_div_ph(a, b);
+        assert_eq!(transmute::<_, [u16; 16]>(r), [f16(2.25); 16]);
+        // Rounding the `f32` quotient again to half precision can't
+        // differ from rounding the exact quotient once.
+        let a = _mm256_set1_ph(f16(1.0));
+        let b = _mm256_set1_ph(f16(3.0));
+        let r = _mm256_div_ph(a, b);
+        assert_eq!(transmute::<_, [u16; 16]>(r), [f16(1.0 / 3.0); 16]);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_mask_div_ph() {
+        let src = _mm256_set1_ph(f16(9.0));
+        let a = _mm256_set1_ph(f16(3.375));
+        let b = _mm256_set1_ph(f16(1.5));
+        let r = _mm256_mask_div_ph(src, 0, a, b);
+        assert_eq!(transmute::<_, [u16; 16]>(r), transmute::<_, [u16; 16]>(src));
+        let r = _mm256_mask_div_ph(src, 0b01010101_01010101, a, b);
+        assert_lanes(
+            &transmute::<_, [u16; 16]>(r),
+            0b01010101_01010101,
+            f16(2.25),
+            f16(9.0),
+        );
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_maskz_div_ph() {
+        let a = _mm256_set1_ph(f16(3.375));
+        let b = _mm256_set1_ph(f16(1.5));
+        let r = _mm256_maskz_div_ph(0, a, b);
+        assert_eq!(transmute::<_, [u16; 16]>(r), [0; 16]);
+        let r = _mm256_maskz_div_ph(0b01010101_01010101, a, b);
+        assert_lanes(
+            &transmute::<_, [u16; 16]>(r),
+            0b01010101_01010101,
+            f16(2.25),
+            0,
+        );
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_div_ph() {
+        let a = _mm_set1_ph(f16(3.375));
+        let b = _mm_set1_ph(f16(1.5));
+        let r = _mm_div_ph(a, b);
+        assert_eq!(transmute::<_, [u16; 8]>(r), [f16(2.25); 8]);
+        // Rounding the `f32` quotient again to half precision can't
+        // differ from rounding the exact quotient once.
+        let a = _mm_set1_ph(f16(1.0));
+        let b = _mm_set1_ph(f16(3.0));
+        let r = _mm_div_ph(a, b);
+        assert_eq!(transmute::<_, [u16; 8]>(r), [f16(1.0 / 3.0); 8]);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_mask_div_ph() {
+        let src = _mm_set1_ph(f16(9.0));
+        let a = _mm_set1_ph(f16(3.375));
+        let b = _mm_set1_ph(f16(1.5));
+        let r = _mm_mask_div_ph(src, 0, a, b);
+        assert_eq!(transmute::<_, [u16; 8]>(r), transmute::<_, [u16; 8]>(src));
+        let r = _mm_mask_div_ph(src, 0b01010101, a, b);
+        assert_lanes(
+            &transmute::<_, [u16; 8]>(r),
+            0b01010101,
+            f16(2.25),
+            f16(9.0),
+        );
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_maskz_div_ph() {
+        let a = _mm_set1_ph(f16(3.375));
+        let b = _mm_set1_ph(f16(1.5));
+        let r = _mm_maskz_div_ph(0, a, b);
+        assert_eq!(transmute::<_, [u16; 8]>(r), [0; 8]);
+        let r = _mm_maskz_div_ph(0b01010101, a, b);
+        assert_lanes(&transmute::<_, [u16; 8]>(r), 0b01010101, f16(2.25), 0);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_fmadd_ph() {
+        let a = _mm512_set1_ph(f16(2.0));
+        let b = _mm512_set1_ph(f16(3.0));
+        let c = _mm512_set1_ph(f16(1.0));
+        let r = _mm512_fmadd_ph(a, b, c);
+        assert_eq!(transmute::<_, [u16; 32]>(r), [f16(7.0); 32]);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_mask_fmadd_ph() {
+        let a = _mm512_set1_ph(f16(2.0));
+        let b = _mm512_set1_ph(f16(3.0));
+        let c = _mm512_set1_ph(f16(1.0));
+        let r = _mm512_mask_fmadd_ph(a, 0, b, c);
+        assert_eq!(transmute::<_, [u16; 32]>(r), transmute::<_, [u16; 32]>(a));
+        let r = _mm512_mask_fmadd_ph(a, 0b01010101_01010101_01010101_01010101, b, c);
+        assert_lanes(
+            &transmute::<_, [u16; 32]>(r),
+            0b01010101_01010101_01010101_01010101,
+            f16(7.0),
+            f16(2.0),
+        );
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_maskz_fmadd_ph() {
+        let a = _mm512_set1_ph(f16(2.0));
+        let b = _mm512_set1_ph(f16(3.0));
+        let c = _mm512_set1_ph(f16(1.0));
+        let r = _mm512_maskz_fmadd_ph(0, a, b, c);
+        assert_eq!(transmute::<_, [u16; 32]>(r), [0; 32]);
+        let r = _mm512_maskz_fmadd_ph(0b01010101_01010101_01010101_01010101, a, b, c);
+        assert_lanes(
+            &transmute::<_, [u16; 32]>(r),
+            0b01010101_01010101_01010101_01010101,
+            f16(7.0),
+            0,
+        );
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_fmadd_ph() {
+        let a = _mm256_set1_ph(f16(2.0));
+        let b = _mm256_set1_ph(f16(3.0));
+        let c = _mm256_set1_ph(f16(1.0));
+        let r = _mm256_fmadd_ph(a, b, c);
+        assert_eq!(transmute::<_, [u16; 16]>(r), [f16(7.0); 16]);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_mask_fmadd_ph() {
+        let a = _mm256_set1_ph(f16(2.0));
+        let b = _mm256_set1_ph(f16(3.0));
+        let c = _mm256_set1_ph(f16(1.0));
+        let r = _mm256_mask_fmadd_ph(a, 0, b, c);
+        assert_eq!(transmute::<_, [u16; 16]>(r), transmute::<_, [u16; 16]>(a));
+        let r = _mm256_mask_fmadd_ph(a, 0b01010101_01010101, b, c);
+        assert_lanes(
+            &transmute::<_, [u16; 16]>(r),
+            0b01010101_01010101,
+            f16(7.0),
+            f16(2.0),
+        );
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_maskz_fmadd_ph() {
+        let a = _mm256_set1_ph(f16(2.0));
+        let b = _mm256_set1_ph(f16(3.0));
+        let c = _mm256_set1_ph(f16(1.0));
+        let r = _mm256_maskz_fmadd_ph(0, a, b, c);
+        assert_eq!(transmute::<_, [u16; 16]>(r), [0; 16]);
+        let r = _mm256_maskz_fmadd_ph(0b01010101_01010101, a, b, c);
+        assert_lanes(
+            &transmute::<_, [u16; 16]>(r),
+            0b01010101_01010101,
+            f16(7.0),
+            0,
+        );
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_fmadd_ph() {
+        let a = _mm_set1_ph(f16(2.0));
+        let b = _mm_set1_ph(f16(3.0));
+        let c = _mm_set1_ph(f16(1.0));
+        let r = _mm_fmadd_ph(a, b, c);
+        assert_eq!(transmute::<_, [u16; 8]>(r), [f16(7.0); 8]);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_mask_fmadd_ph() {
+        let a = _mm_set1_ph(f16(2.0));
+        let b = _mm_set1_ph(f16(3.0));
+        let c = _mm_set1_ph(f16(1.0));
+        let r = _mm_mask_fmadd_ph(a, 0, b, c);
+        assert_eq!(transmute::<_, [u16; 8]>(r), transmute::<_, [u16; 8]>(a));
+        let r = _mm_mask_fmadd_ph(a, 0b01010101, b, c);
+        assert_lanes(&transmute::<_, [u16; 8]>(r), 0b01010101, f16(7.0), f16(2.0));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_maskz_fmadd_ph() {
+        let a = _mm_set1_ph(f16(2.0));
+        let b = _mm_set1_ph(f16(3.0));
+        let c = _mm_set1_ph(f16(1.0));
+        let r = _mm_maskz_fmadd_ph(0, a, b, c);
+        assert_eq!(transmute::<_, [u16; 8]>(r), [0; 8]);
+        let r = _mm_maskz_fmadd_ph(0b01010101, a, b, c);
+        assert_lanes(&transmute::<_, [u16; 8]>(r), 0b01010101, f16(7.0), 0);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_cvtxph_ps() {
+        let a = _mm256_set1_ph(f16(-1.5));
+        let r = _mm512_cvtxph_ps(a);
+        assert_eq_m512(r, _mm512_set1_ps(-1.5));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_cvtxph_ps() {
+        let a = _mm_set1_ph(f16(-1.5));
+        let r = _mm256_cvtxph_ps(a);
+        assert_eq_m256(r, _mm256_set1_ps(-1.5));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_cvtxph_ps() {
+        let a = _mm_set1_ph(f16(-1.5));
+        let r = _mm_cvtxph_ps(a);
+        assert_eq_m128(r, _mm_set1_ps(-1.5));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_cvtxps_ph() {
+        let a = _mm512_set1_ps(-1.5);
+        let r = _mm512_cvtxps_ph(a);
+        assert_eq!(transmute::<_, [u16; 16]>(r), [f16(-1.5); 16]);
+        // Not exactly representable, rounds to nearest even.
+        let a = _mm512_set1_ps(1.0 / 3.0);
+        let r = _mm512_cvtxps_ph(a);
+        assert_eq!(transmute::<_, [u16; 16]>(r), [f16(1.0 / 3.0); 16]);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_cvtxps_ph() {
+        let a = _mm256_set1_ps(-1.5);
+        let r = _mm256_cvtxps_ph(a);
+        assert_eq!(transmute::<_, [u16; 8]>(r), [f16(-1.5); 8]);
+        // Not exactly representable, rounds to nearest even.
+        let a = _mm256_set1_ps(1.0 / 3.0);
+        let r = _mm256_cvtxps_ph(a);
+        assert_eq!(transmute::<_, [u16; 8]>(r), [f16(1.0 / 3.0); 8]);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_cvtxps_ph() {
+        let a = _mm_set1_ps(-1.5);
+        let r = _mm_cvtxps_ph(a);
+        let e = f16(-1.5);
+        assert_eq!(transmute::<_, [u16; 8]>(r), [e, e, e, e, 0, 0, 0, 0]);
+        // Not exactly representable, rounds to nearest even.
+        let a = _mm_set1_ps(1.0 / 3.0);
+        let r = _mm_cvtxps_ph(a);
+        let e = f16(1.0 / 3.0);
+        assert_eq!(transmute::<_, [u16; 8]>(r), [e, e, e, e, 0, 0, 0, 0]);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_cvtph_pd() {
+        let a = _mm_set1_ph(f16(-1.5));
+        let r = _mm512_cvtph_pd(a);
+        assert_eq_m512d(r, _mm512_set1_pd(-1.5));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_cvtph_pd() {
+        let a = _mm_set1_ph(f16(-1.5));
+        let r = _mm256_cvtph_pd(a);
+        assert_eq_m256d(r, _mm256_set1_pd(-1.5));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_cvtph_pd() {
+        let a = _mm_set1_ph(f16(-1.5));
+        let r = _mm_cvtph_pd(a);
+        assert_eq_m128d(r, _mm_set1_pd(-1.5));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_cvtpd_ph() {
+        let a = _mm512_set1_pd(-1.5);
+        let r = _mm512_cvtpd_ph(a);
+        assert_eq!(transmute::<_, [u16; 8]>(r), [f16(-1.5); 8]);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_cvtpd_ph() {
+        let a = _mm256_set1_pd(-1.5);
+        let r = _mm256_cvtpd_ph(a);
+        let e = f16(-1.5);
+        assert_eq!(transmute::<_, [u16; 8]>(r), [e, e, e, e, 0, 0, 0, 0]);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_cvtpd_ph() {
+        let a = _mm_set1_pd(-1.5);
+        let r = _mm_cvtpd_ph(a);
+        let e = f16(-1.5);
+        assert_eq!(transmute::<_, [u16; 8]>(r), [e, e, 0, 0, 0, 0, 0, 0]);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_cvtph_epi16() {
+        let a = _mm512_set1_ph(f16(-3.0));
+        let r = _mm512_cvtph_epi16(a);
+        assert_eq_m512i(r, _mm512_set1_epi16(-3));
+        // Rounds to nearest even under the default rounding mode.
+        let a = _mm512_set1_ph(f16(2.5));
+        let r = _mm512_cvtph_epi16(a);
+        assert_eq_m512i(r, _mm512_set1_epi16(2));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_cvtph_epi16() {
+        let a = _mm256_set1_ph(f16(-3.0));
+        let r = _mm256_cvtph_epi16(a);
+        assert_eq_m256i(r, _mm256_set1_epi16(-3));
+        // Rounds to nearest even under the default rounding mode.
+        let a = _mm256_set1_ph(f16(2.5));
+        let r = _mm256_cvtph_epi16(a);
+        assert_eq_m256i(r, _mm256_set1_epi16(2));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_cvtph_epi16() {
+        let a = _mm_set1_ph(f16(-3.0));
+        let r = _mm_cvtph_epi16(a);
+        assert_eq_m128i(r, _mm_set1_epi16(-3));
+        // Rounds to nearest even under the default rounding mode.
+        let a = _mm_set1_ph(f16(2.5));
+        let r = _mm_cvtph_epi16(a);
+        assert_eq_m128i(r, _mm_set1_epi16(2));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_cvtepi16_ph() {
+        let a = _mm512_set1_epi16(-3);
+        let r = _mm512_cvtepi16_ph(a);
+        assert_eq!(transmute::<_, [u16; 32]>(r), [f16(-3.0); 32]);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_cvtepi16_ph() {
+        let a = _mm256_set1_epi16(-3);
+        let r = _mm256_cvtepi16_ph(a);
+        assert_eq!(transmute::<_, [u16; 16]>(r), [f16(-3.0); 16]);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_cvtepi16_ph() {
+        let a = _mm_set1_epi16(-3);
+        let r = _mm_cvtepi16_ph(a);
+        assert_eq!(transmute::<_, [u16; 8]>(r), [f16(-3.0); 8]);
+    }
+}
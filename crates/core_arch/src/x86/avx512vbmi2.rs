@@ -291,6 +291,342 @@ pub unsafe fn _mm_maskz_expand_epi8(k: __mmask16, a: __m128i) -> __m128i {
     ))
 }
 
+/// Contiguously store the active 16-bit integers in a (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+///
+/// Only the `popcnt(k)` active elements are written, to consecutive locations starting at `base_addr`; the memory after them is neither read nor written, so `base_addr` only needs to be valid for that many elements.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_compressstoreu_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2")]
+#[cfg_attr(test, assert_instr(vpcompressw))]
+pub unsafe fn _mm512_mask_compressstoreu_epi16(base_addr: *mut i16, k: __mmask32, a: __m512i) {
+    vcompressstorew(base_addr as *mut _, a.as_i16x32(), k)
+}
+
+/// Load contiguous active 16-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// Only the `popcnt(k)` elements starting at `mem_addr` are read, so `mem_addr` only needs to be valid for that many elements.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_expandloadu_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2")]
+#[cfg_attr(test, assert_instr(vpexpandw))]
+pub unsafe fn _mm512_mask_expandloadu_epi16(
+    src: __m512i,
+    k: __mmask32,
+    mem_addr: *const i16,
+) -> __m512i {
+    let mut dst: __m512i = src;
+    asm!(
+        "vpexpandw {dst}{{{k}}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = inout(zmm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Load contiguous active 16-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// Only the `popcnt(k)` elements starting at `mem_addr` are read, so `mem_addr` only needs to be valid for that many elements.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_expandloadu_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2")]
+#[cfg_attr(test, assert_instr(vpexpandw))]
+pub unsafe fn _mm512_maskz_expandloadu_epi16(k: __mmask32, mem_addr: *const i16) -> __m512i {
+    let dst: __m512i;
+    asm!(
+        "vpexpandw {dst}{{{k}}}{{z}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = out(zmm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Contiguously store the active 16-bit integers in a (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+///
+/// Only the `popcnt(k)` active elements are written, to consecutive locations starting at `base_addr`; the memory after them is neither read nor written, so `base_addr` only needs to be valid for that many elements.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_compressstoreu_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcompressw))]
+pub unsafe fn _mm256_mask_compressstoreu_epi16(base_addr: *mut i16, k: __mmask16, a: __m256i) {
+    vcompressstorew256(base_addr as *mut _, a.as_i16x16(), k)
+}
+
+/// Load contiguous active 16-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// Only the `popcnt(k)` elements starting at `mem_addr` are read, so `mem_addr` only needs to be valid for that many elements.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_expandloadu_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpexpandw))]
+pub unsafe fn _mm256_mask_expandloadu_epi16(
+    src: __m256i,
+    k: __mmask16,
+    mem_addr: *const i16,
+) -> __m256i {
+    let mut dst: __m256i = src;
+    asm!(
+        "vpexpandw {dst}{{{k}}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = inout(ymm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Load contiguous active 16-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// Only the `popcnt(k)` elements starting at `mem_addr` are read, so `mem_addr` only needs to be valid for that many elements.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_expandloadu_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpexpandw))]
+pub unsafe fn _mm256_maskz_expandloadu_epi16(k: __mmask16, mem_addr: *const i16) -> __m256i {
+    let dst: __m256i;
+    asm!(
+        "vpexpandw {dst}{{{k}}}{{z}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = out(ymm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Contiguously store the active 16-bit integers in a (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+///
+/// Only the `popcnt(k)` active elements are written, to consecutive locations starting at `base_addr`; the memory after them is neither read nor written, so `base_addr` only needs to be valid for that many elements.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_compressstoreu_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcompressw))]
+pub unsafe fn _mm_mask_compressstoreu_epi16(base_addr: *mut i16, k: __mmask8, a: __m128i) {
+    vcompressstorew128(base_addr as *mut _, a.as_i16x8(), k)
+}
+
+/// Load contiguous active 16-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// Only the `popcnt(k)` elements starting at `mem_addr` are read, so `mem_addr` only needs to be valid for that many elements.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_expandloadu_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpexpandw))]
+pub unsafe fn _mm_mask_expandloadu_epi16(
+    src: __m128i,
+    k: __mmask8,
+    mem_addr: *const i16,
+) -> __m128i {
+    let mut dst: __m128i = src;
+    asm!(
+        "vpexpandw {dst}{{{k}}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = inout(xmm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Load contiguous active 16-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// Only the `popcnt(k)` elements starting at `mem_addr` are read, so `mem_addr` only needs to be valid for that many elements.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_expandloadu_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpexpandw))]
+pub unsafe fn _mm_maskz_expandloadu_epi16(k: __mmask8, mem_addr: *const i16) -> __m128i {
+    let dst: __m128i;
+    asm!(
+        "vpexpandw {dst}{{{k}}}{{z}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = out(xmm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Contiguously store the active 8-bit integers in a (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+///
+/// Only the `popcnt(k)` active elements are written, to consecutive locations starting at `base_addr`; the memory after them is neither read nor written, so `base_addr` only needs to be valid for that many elements.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_compressstoreu_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi2")]
+#[cfg_attr(test, assert_instr(vpcompressb))]
+pub unsafe fn _mm512_mask_compressstoreu_epi8(base_addr: *mut i8, k: __mmask64, a: __m512i) {
+    vcompressstoreb(base_addr as *mut _, a.as_i8x64(), k)
+}
+
+/// Load contiguous active 8-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// Only the `popcnt(k)` elements starting at `mem_addr` are read, so `mem_addr` only needs to be valid for that many elements.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_expandloadu_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi2")]
+#[cfg_attr(test, assert_instr(vpexpandb))]
+pub unsafe fn _mm512_mask_expandloadu_epi8(
+    src: __m512i,
+    k: __mmask64,
+    mem_addr: *const i8,
+) -> __m512i {
+    let mut dst: __m512i = src;
+    asm!(
+        "vpexpandb {dst}{{{k}}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = inout(zmm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Load contiguous active 8-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// Only the `popcnt(k)` elements starting at `mem_addr` are read, so `mem_addr` only needs to be valid for that many elements.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_expandloadu_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi2")]
+#[cfg_attr(test, assert_instr(vpexpandb))]
+pub unsafe fn _mm512_maskz_expandloadu_epi8(k: __mmask64, mem_addr: *const i8) -> __m512i {
+    let dst: __m512i;
+    asm!(
+        "vpexpandb {dst}{{{k}}}{{z}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = out(zmm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Contiguously store the active 8-bit integers in a (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+///
+/// Only the `popcnt(k)` active elements are written, to consecutive locations starting at `base_addr`; the memory after them is neither read nor written, so `base_addr` only needs to be valid for that many elements.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_compressstoreu_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcompressb))]
+pub unsafe fn _mm256_mask_compressstoreu_epi8(base_addr: *mut i8, k: __mmask32, a: __m256i) {
+    vcompressstoreb256(base_addr as *mut _, a.as_i8x32(), k)
+}
+
+/// Load contiguous active 8-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// Only the `popcnt(k)` elements starting at `mem_addr` are read, so `mem_addr` only needs to be valid for that many elements.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_expandloadu_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpexpandb))]
+pub unsafe fn _mm256_mask_expandloadu_epi8(
+    src: __m256i,
+    k: __mmask32,
+    mem_addr: *const i8,
+) -> __m256i {
+    let mut dst: __m256i = src;
+    asm!(
+        "vpexpandb {dst}{{{k}}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = inout(ymm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Load contiguous active 8-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// Only the `popcnt(k)` elements starting at `mem_addr` are read, so `mem_addr` only needs to be valid for that many elements.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_expandloadu_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpexpandb))]
+pub unsafe fn _mm256_maskz_expandloadu_epi8(k: __mmask32, mem_addr: *const i8) -> __m256i {
+    let dst: __m256i;
+    asm!(
+        "vpexpandb {dst}{{{k}}}{{z}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = out(ymm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Contiguously store the active 8-bit integers in a (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+///
+/// Only the `popcnt(k)` active elements are written, to consecutive locations starting at `base_addr`; the memory after them is neither read nor written, so `base_addr` only needs to be valid for that many elements.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_compressstoreu_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcompressb))]
+pub unsafe fn _mm_mask_compressstoreu_epi8(base_addr: *mut i8, k: __mmask16, a: __m128i) {
+    vcompressstoreb128(base_addr as *mut _, a.as_i8x16(), k)
+}
+
+/// Load contiguous active 8-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// Only the `popcnt(k)` elements starting at `mem_addr` are read, so `mem_addr` only needs to be valid for that many elements.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_expandloadu_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpexpandb))]
+pub unsafe fn _mm_mask_expandloadu_epi8(
+    src: __m128i,
+    k: __mmask16,
+    mem_addr: *const i8,
+) -> __m128i {
+    let mut dst: __m128i = src;
+    asm!(
+        "vpexpandb {dst}{{{k}}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = inout(xmm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
+/// Load contiguous active 8-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// Only the `popcnt(k)` elements starting at `mem_addr` are read, so `mem_addr` only needs to be valid for that many elements.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_expandloadu_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpexpandb))]
+pub unsafe fn _mm_maskz_expandloadu_epi8(k: __mmask16, mem_addr: *const i8) -> __m128i {
+    let dst: __m128i;
+    asm!(
+        "vpexpandb {dst}{{{k}}}{{z}}, [{p}]",
+        p = in(reg) mem_addr,
+        k = in(kreg) k,
+        dst = out(xmm_reg) dst,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
 /// Concatenate packed 64-bit integers in a and b producing an intermediate 128-bit result. Shift the result left by the amount specified in the corresponding element of c, and store the upper 64-bits in dst.
 ///
 /// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_shldv_epi64&expand=5087)
@@ -2018,6 +2354,19 @@ extern "C" {
     #[link_name = "llvm.x86.avx512.mask.expand.b.128"]
     fn vpexpandb128(a: i8x16, src: i8x16, mask: u16) -> i8x16;
 
+    #[link_name = "llvm.x86.avx512.mask.compress.store.w.512"]
+    fn vcompressstorew(mem: *mut i8, data: i16x32, mask: u32);
+    #[link_name = "llvm.x86.avx512.mask.compress.store.w.256"]
+    fn vcompressstorew256(mem: *mut i8, data: i16x16, mask: u16);
+    #[link_name = "llvm.x86.avx512.mask.compress.store.w.128"]
+    fn vcompressstorew128(mem: *mut i8, data: i16x8, mask: u8);
+    #[link_name = "llvm.x86.avx512.mask.compress.store.b.512"]
+    fn vcompressstoreb(mem: *mut i8, data: i8x64, mask: u64);
+    #[link_name = "llvm.x86.avx512.mask.compress.store.b.256"]
+    fn vcompressstoreb256(mem: *mut i8, data: i8x32, mask: u32);
+    #[link_name = "llvm.x86.avx512.mask.compress.store.b.128"]
+    fn vcompressstoreb128(mem: *mut i8, data: i8x16, mask: u16);
+
     #[link_name = "llvm.fshl.v8i64"]
     fn vpshldvq(a: i64x8, b: i64x8, c: i64x8) -> i64x8;
     #[link_name = "llvm.fshl.v4i64"]
@@ -2063,6 +2412,11 @@ mod tests {
     use stdarch_test::simd_test;
 
     use crate::core_arch::x86::*;
+    use crate::{
+        mem::{self, transmute},
+        ptr,
+    };
+    use std::slice;
 
     #[simd_test(enable = "avx512vbmi2")]
     unsafe fn test_mm512_mask_compress_epi16() {
@@ -3545,4 +3899,306 @@ mod tests {
         let e = _mm_set1_epi16(1);
         assert_eq_m128i(r, e);
     }
+
+    #[simd_test(enable = "avx512vbmi2")]
+    unsafe fn test_mm512_mask_compressstoreu_epi16() {
+        let a: __m512i = transmute::<[i16; 32], _>([
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24,
+            25, 26, 27, 28, 29, 30, 31, 32,
+        ]);
+        let mut r = [0_i16; 32];
+        _mm512_mask_compressstoreu_epi16(r.as_mut_ptr(), 0, a);
+        assert_eq!(&r, &[0_i16; 32]);
+        _mm512_mask_compressstoreu_epi16(r.as_mut_ptr(), 0b01101101_10110110_11011011_01101101, a);
+        assert_eq!(
+            &r,
+            &[
+                1, 3, 4, 6, 7, 9, 10, 12, 13, 15, 16, 18, 19, 21, 22, 24, 25, 27, 28, 30, 31, 0, 0,
+                0, 0, 0, 0, 0, 0, 0, 0, 0
+            ]
+        );
+    }
+
+    #[simd_test(enable = "avx512vbmi2")]
+    unsafe fn test_mm512_mask_expandloadu_epi16() {
+        let src: __m512i = transmute([-1_i16; 32]);
+        let m: [i16; 32] = [
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24,
+            25, 26, 27, 28, 29, 30, 31, 32,
+        ];
+        let r = _mm512_mask_expandloadu_epi16(src, 0, m.as_ptr());
+        assert_eq!(&transmute::<_, [i16; 32]>(r), &[-1_i16; 32]);
+        let r =
+            _mm512_mask_expandloadu_epi16(src, 0b01101101_10110110_11011011_01101101, m.as_ptr());
+        let e: [i16; 32] = [
+            1, -1, 2, 3, -1, 4, 5, -1, 6, 7, -1, 8, 9, -1, 10, 11, -1, 12, 13, -1, 14, 15, -1, 16,
+            17, -1, 18, 19, -1, 20, 21, -1,
+        ];
+        assert_eq!(&transmute::<_, [i16; 32]>(r), &e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2")]
+    unsafe fn test_mm512_maskz_expandloadu_epi16() {
+        let m: [i16; 32] = [
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24,
+            25, 26, 27, 28, 29, 30, 31, 32,
+        ];
+        let r = _mm512_maskz_expandloadu_epi16(0, m.as_ptr());
+        assert_eq!(&transmute::<_, [i16; 32]>(r), &[0_i16; 32]);
+        let r = _mm512_maskz_expandloadu_epi16(0b01101101_10110110_11011011_01101101, m.as_ptr());
+        let e: [i16; 32] = [
+            1, 0, 2, 3, 0, 4, 5, 0, 6, 7, 0, 8, 9, 0, 10, 11, 0, 12, 13, 0, 14, 15, 0, 16, 17, 0,
+            18, 19, 0, 20, 21, 0,
+        ];
+        assert_eq!(&transmute::<_, [i16; 32]>(r), &e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm256_mask_compressstoreu_epi16() {
+        let a: __m256i =
+            transmute::<[i16; 16], _>([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]);
+        let mut r = [0_i16; 16];
+        _mm256_mask_compressstoreu_epi16(r.as_mut_ptr(), 0, a);
+        assert_eq!(&r, &[0_i16; 16]);
+        _mm256_mask_compressstoreu_epi16(r.as_mut_ptr(), 0b11011011_01101101, a);
+        assert_eq!(&r, &[1, 3, 4, 6, 7, 9, 10, 12, 13, 15, 16, 0, 0, 0, 0, 0]);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm256_mask_expandloadu_epi16() {
+        let src: __m256i = transmute([-1_i16; 16]);
+        let m: [i16; 16] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
+        let r = _mm256_mask_expandloadu_epi16(src, 0, m.as_ptr());
+        assert_eq!(&transmute::<_, [i16; 16]>(r), &[-1_i16; 16]);
+        let r = _mm256_mask_expandloadu_epi16(src, 0b11011011_01101101, m.as_ptr());
+        let e: [i16; 16] = [1, -1, 2, 3, -1, 4, 5, -1, 6, 7, -1, 8, 9, -1, 10, 11];
+        assert_eq!(&transmute::<_, [i16; 16]>(r), &e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm256_maskz_expandloadu_epi16() {
+        let m: [i16; 16] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
+        let r = _mm256_maskz_expandloadu_epi16(0, m.as_ptr());
+        assert_eq!(&transmute::<_, [i16; 16]>(r), &[0_i16; 16]);
+        let r = _mm256_maskz_expandloadu_epi16(0b11011011_01101101, m.as_ptr());
+        let e: [i16; 16] = [1, 0, 2, 3, 0, 4, 5, 0, 6, 7, 0, 8, 9, 0, 10, 11];
+        assert_eq!(&transmute::<_, [i16; 16]>(r), &e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm_mask_compressstoreu_epi16() {
+        let a: __m128i = transmute::<[i16; 8], _>([1, 2, 3, 4, 5, 6, 7, 8]);
+        let mut r = [0_i16; 8];
+        _mm_mask_compressstoreu_epi16(r.as_mut_ptr(), 0, a);
+        assert_eq!(&r, &[0_i16; 8]);
+        _mm_mask_compressstoreu_epi16(r.as_mut_ptr(), 0b01101101, a);
+        assert_eq!(&r, &[1, 3, 4, 6, 7, 0, 0, 0]);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm_mask_expandloadu_epi16() {
+        let src: __m128i = transmute([-1_i16; 8]);
+        let m: [i16; 8] = [1, 2, 3, 4, 5, 6, 7, 8];
+        let r = _mm_mask_expandloadu_epi16(src, 0, m.as_ptr());
+        assert_eq!(&transmute::<_, [i16; 8]>(r), &[-1_i16; 8]);
+        let r = _mm_mask_expandloadu_epi16(src, 0b01101101, m.as_ptr());
+        let e: [i16; 8] = [1, -1, 2, 3, -1, 4, 5, -1];
+        assert_eq!(&transmute::<_, [i16; 8]>(r), &e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm_maskz_expandloadu_epi16() {
+        let m: [i16; 8] = [1, 2, 3, 4, 5, 6, 7, 8];
+        let r = _mm_maskz_expandloadu_epi16(0, m.as_ptr());
+        assert_eq!(&transmute::<_, [i16; 8]>(r), &[0_i16; 8]);
+        let r = _mm_maskz_expandloadu_epi16(0b01101101, m.as_ptr());
+        let e: [i16; 8] = [1, 0, 2, 3, 0, 4, 5, 0];
+        assert_eq!(&transmute::<_, [i16; 8]>(r), &e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2")]
+    unsafe fn test_mm512_mask_compressstoreu_epi8() {
+        let a: __m512i = transmute::<[i8; 64], _>([
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24,
+            25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46,
+            47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64,
+        ]);
+        let mut r = [0_i8; 64];
+        _mm512_mask_compressstoreu_epi8(r.as_mut_ptr(), 0, a);
+        assert_eq!(&r, &[0_i8; 64]);
+        _mm512_mask_compressstoreu_epi8(
+            r.as_mut_ptr(),
+            0b11011011_01101101_10110110_11011011_01101101_10110110_11011011_01101101,
+            a,
+        );
+        assert_eq!(
+            &r,
+            &[
+                1, 3, 4, 6, 7, 9, 10, 12, 13, 15, 16, 18, 19, 21, 22, 24, 25, 27, 28, 30, 31, 33,
+                34, 36, 37, 39, 40, 42, 43, 45, 46, 48, 49, 51, 52, 54, 55, 57, 58, 60, 61, 63, 64,
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0
+            ]
+        );
+    }
+
+    #[simd_test(enable = "avx512vbmi2")]
+    unsafe fn test_mm512_mask_expandloadu_epi8() {
+        let src: __m512i = transmute([-1_i8; 64]);
+        let m: [i8; 64] = [
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24,
+            25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46,
+            47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64,
+        ];
+        let r = _mm512_mask_expandloadu_epi8(src, 0, m.as_ptr());
+        assert_eq!(&transmute::<_, [i8; 64]>(r), &[-1_i8; 64]);
+        let r = _mm512_mask_expandloadu_epi8(
+            src,
+            0b11011011_01101101_10110110_11011011_01101101_10110110_11011011_01101101,
+            m.as_ptr(),
+        );
+        let e: [i8; 64] = [
+            1, -1, 2, 3, -1, 4, 5, -1, 6, 7, -1, 8, 9, -1, 10, 11, -1, 12, 13, -1, 14, 15, -1, 16,
+            17, -1, 18, 19, -1, 20, 21, -1, 22, 23, -1, 24, 25, -1, 26, 27, -1, 28, 29, -1, 30, 31,
+            -1, 32, 33, -1, 34, 35, -1, 36, 37, -1, 38, 39, -1, 40, 41, -1, 42, 43,
+        ];
+        assert_eq!(&transmute::<_, [i8; 64]>(r), &e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2")]
+    unsafe fn test_mm512_maskz_expandloadu_epi8() {
+        let m: [i8; 64] = [
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24,
+            25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46,
+            47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64,
+        ];
+        let r = _mm512_maskz_expandloadu_epi8(0, m.as_ptr());
+        assert_eq!(&transmute::<_, [i8; 64]>(r), &[0_i8; 64]);
+        let r = _mm512_maskz_expandloadu_epi8(
+            0b11011011_01101101_10110110_11011011_01101101_10110110_11011011_01101101,
+            m.as_ptr(),
+        );
+        let e: [i8; 64] = [
+            1, 0, 2, 3, 0, 4, 5, 0, 6, 7, 0, 8, 9, 0, 10, 11, 0, 12, 13, 0, 14, 15, 0, 16, 17, 0,
+            18, 19, 0, 20, 21, 0, 22, 23, 0, 24, 25, 0, 26, 27, 0, 28, 29, 0, 30, 31, 0, 32, 33, 0,
+            34, 35, 0, 36, 37, 0, 38, 39, 0, 40, 41, 0, 42, 43,
+        ];
+        assert_eq!(&transmute::<_, [i8; 64]>(r), &e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm256_mask_compressstoreu_epi8() {
+        let a: __m256i = transmute::<[i8; 32], _>([
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24,
+            25, 26, 27, 28, 29, 30, 31, 32,
+        ]);
+        let mut r = [0_i8; 32];
+        _mm256_mask_compressstoreu_epi8(r.as_mut_ptr(), 0, a);
+        assert_eq!(&r, &[0_i8; 32]);
+        _mm256_mask_compressstoreu_epi8(r.as_mut_ptr(), 0b01101101_10110110_11011011_01101101, a);
+        assert_eq!(
+            &r,
+            &[
+                1, 3, 4, 6, 7, 9, 10, 12, 13, 15, 16, 18, 19, 21, 22, 24, 25, 27, 28, 30, 31, 0, 0,
+                0, 0, 0, 0, 0, 0, 0, 0, 0
+            ]
+        );
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm256_mask_expandloadu_epi8() {
+        let src: __m256i = transmute([-1_i8; 32]);
+        let m: [i8; 32] = [
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24,
+            25, 26, 27, 28, 29, 30, 31, 32,
+        ];
+        let r = _mm256_mask_expandloadu_epi8(src, 0, m.as_ptr());
+        assert_eq!(&transmute::<_, [i8; 32]>(r), &[-1_i8; 32]);
+        let r =
+            _mm256_mask_expandloadu_epi8(src, 0b01101101_10110110_11011011_01101101, m.as_ptr());
+        let e: [i8; 32] = [
+            1, -1, 2, 3, -1, 4, 5, -1, 6, 7, -1, 8, 9, -1, 10, 11, -1, 12, 13, -1, 14, 15, -1, 16,
+            17, -1, 18, 19, -1, 20, 21, -1,
+        ];
+        assert_eq!(&transmute::<_, [i8; 32]>(r), &e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm256_maskz_expandloadu_epi8() {
+        let m: [i8; 32] = [
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24,
+            25, 26, 27, 28, 29, 30, 31, 32,
+        ];
+        let r = _mm256_maskz_expandloadu_epi8(0, m.as_ptr());
+        assert_eq!(&transmute::<_, [i8; 32]>(r), &[0_i8; 32]);
+        let r = _mm256_maskz_expandloadu_epi8(0b01101101_10110110_11011011_01101101, m.as_ptr());
+        let e: [i8; 32] = [
+            1, 0, 2, 3, 0, 4, 5, 0, 6, 7, 0, 8, 9, 0, 10, 11, 0, 12, 13, 0, 14, 15, 0, 16, 17, 0,
+            18, 19, 0, 20, 21, 0,
+        ];
+        assert_eq!(&transmute::<_, [i8; 32]>(r), &e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm_mask_compressstoreu_epi8() {
+        let a: __m128i =
+            transmute::<[i8; 16], _>([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]);
+        let mut r = [0_i8; 16];
+        _mm_mask_compressstoreu_epi8(r.as_mut_ptr(), 0, a);
+        assert_eq!(&r, &[0_i8; 16]);
+        _mm_mask_compressstoreu_epi8(r.as_mut_ptr(), 0b11011011_01101101, a);
+        assert_eq!(&r, &[1, 3, 4, 6, 7, 9, 10, 12, 13, 15, 16, 0, 0, 0, 0, 0]);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm_mask_expandloadu_epi8() {
+        let src: __m128i = transmute([-1_i8; 16]);
+        let m: [i8; 16] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
+        let r = _mm_mask_expandloadu_epi8(src, 0, m.as_ptr());
+        assert_eq!(&transmute::<_, [i8; 16]>(r), &[-1_i8; 16]);
+        let r = _mm_mask_expandloadu_epi8(src, 0b11011011_01101101, m.as_ptr());
+        let e: [i8; 16] = [1, -1, 2, 3, -1, 4, 5, -1, 6, 7, -1, 8, 9, -1, 10, 11];
+        assert_eq!(&transmute::<_, [i8; 16]>(r), &e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm_maskz_expandloadu_epi8() {
+        let m: [i8; 16] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
+        let r = _mm_maskz_expandloadu_epi8(0, m.as_ptr());
+        assert_eq!(&transmute::<_, [i8; 16]>(r), &[0_i8; 16]);
+        let r = _mm_maskz_expandloadu_epi8(0b11011011_01101101, m.as_ptr());
+        let e: [i8; 16] = [1, 0, 2, 3, 0, 4, 5, 0, 6, 7, 0, 8, 9, 0, 10, 11];
+        assert_eq!(&transmute::<_, [i8; 16]>(r), &e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2")]
+    unsafe fn test_mm512_compressstoreu_expandloadu_epi8_page_end() {
+        // Only the active elements may be accessed, so storing them right in
+        // front of a guard page and loading them back mustn't fault.
+        let buf = match guarded_page() {
+            Some(buf) => buf as *mut i8,
+            None => return,
+        };
+        ptr::write_bytes(buf as *mut u8, 0x55, PAGE_SIZE);
+        let end = buf.add(PAGE_SIZE / mem::size_of::<i8>() - 4);
+        let a: __m512i = transmute::<[i8; 64], _>([
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24,
+            25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46,
+            47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64,
+        ]);
+        let k = 0b10000000_00000000_00000001_00000000_00000000_00000010_00000000_00000001;
+        _mm512_mask_compressstoreu_epi8(end, k, a);
+        assert_eq!(slice::from_raw_parts(end, 4), &[1, 18, 41, 64]);
+        let bytes = slice::from_raw_parts(buf as *const u8, PAGE_SIZE);
+        assert!(bytes[..PAGE_SIZE - 4 * mem::size_of::<i8>()]
+            .iter()
+            .all(|&b| b == 0x55));
+        let r = _mm512_maskz_expandloadu_epi8(k, end);
+        let e: [i8; 64] = [
+            1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 64,
+        ];
+        assert_eq!(&transmute::<_, [i8; 64]>(r), &e);
+        unmap_guarded_page(buf as *mut u8);
+    }
 }
@@ -0,0 +1,249 @@
+//! AVX-512 VP2INTERSECT intrinsics
+//!
+//! The intrinsics here correspond to those in the `immintrin.h` C header.
+//!
+//! The reference is [Intel 64 and IA-32 Architectures Software Developer's
+//! Manual Volume 2: Instruction Set Reference, A-Z][intel64_ref].
+//!
+//! [intel64_ref]: http://www.intel.de/content/dam/www/public/us/en/documents/manuals/64-ia-32-architectures-software-developer-instruction-set-reference-manual-325383.pdf
+
+use crate::core_arch::x86::*;
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+// The `vp2intersect{d,q}` instructions write to an even/odd pair of mask
+// registers, which LLVM models as an intrinsic returning a pair of `i1`
+// vectors. That can't be expressed across the FFI boundary, so these are
+// implemented with inline assembly writing the pair `k2:k3` instead.
+macro_rules! vp2intersect {
+    ($inst:expr, $reg:ident, $mask:ty, $a:expr, $b:expr, $k1:expr, $k2:expr) => {{
+        let m1: $mask;
+        let m2: $mask;
+        asm!(
+            concat!($inst, " k2, {a}, {b}"),
+            a = in($reg) $a,
+            b = in($reg) $b,
+            out("k2") m1,
+            out("k3") m2,
+            options(pure, nomem, nostack),
+        );
+        *$k1 = m1;
+        *$k2 = m2;
+    }};
+}
+
+/// Compute intersection of packed 32-bit integer vectors `a` and `b`, and
+/// store indication of match in the corresponding bit of two mask registers
+/// specified by `k1` and `k2`. A match in corresponding elements of `a` and
+/// `b` is indicated by a set bit in the corresponding bit of the mask
+/// registers.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_2intersect_epi32)
+#[inline]
+#[target_feature(enable = "avx512vp2intersect,avx512f")]
+#[cfg_attr(test, assert_instr(vp2intersectd))]
+pub unsafe fn _mm512_2intersect_epi32(
+    a: __m512i,
+    b: __m512i,
+    k1: *mut __mmask16,
+    k2: *mut __mmask16,
+) {
+    vp2intersect!("vp2intersectd", zmm_reg, __mmask16, a, b, k1, k2)
+}
+
+/// Compute intersection of packed 32-bit integer vectors `a` and `b`, and
+/// store indication of match in the corresponding bit of two mask registers
+/// specified by `k1` and `k2`. A match in corresponding elements of `a` and
+/// `b` is indicated by a set bit in the corresponding bit of the mask
+/// registers.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_2intersect_epi32)
+#[inline]
+#[target_feature(enable = "avx512vp2intersect,avx512vl")]
+#[cfg_attr(test, assert_instr(vp2intersectd))]
+pub unsafe fn _mm256_2intersect_epi32(
+    a: __m256i,
+    b: __m256i,
+    k1: *mut __mmask8,
+    k2: *mut __mmask8,
+) {
+    vp2intersect!("vp2intersectd", ymm_reg, __mmask8, a, b, k1, k2)
+}
+
+/// Compute intersection of packed 32-bit integer vectors `a` and `b`, and
+/// store indication of match in the corresponding bit of two mask registers
+/// specified by `k1` and `k2`. A match in corresponding elements of `a` and
+/// `b` is indicated by a set bit in the corresponding bit of the mask
+/// registers.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_2intersect_epi32)
+#[inline]
+#[target_feature(enable = "avx512vp2intersect,avx512vl")]
+#[cfg_attr(test, assert_instr(vp2intersectd))]
+pub unsafe fn _mm_2intersect_epi32(a: __m128i, b: __m128i, k1: *mut __mmask8, k2: *mut __mmask8) {
+    vp2intersect!("vp2intersectd", xmm_reg, __mmask8, a, b, k1, k2)
+}
+
+/// Compute intersection of packed 64-bit integer vectors `a` and `b`, and
+/// store indication of match in the corresponding bit of two mask registers
+/// specified by `k1` and `k2`. A match in corresponding elements of `a` and
+/// `b` is indicated by a set bit in the corresponding bit of the mask
+/// registers.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_2intersect_epi64)
+#[inline]
+#[target_feature(enable = "avx512vp2intersect,avx512f")]
+#[cfg_attr(test, assert_instr(vp2intersectq))]
+pub unsafe fn _mm512_2intersect_epi64(
+    a: __m512i,
+    b: __m512i,
+    k1: *mut __mmask8,
+    k2: *mut __mmask8,
+) {
+    vp2intersect!("vp2intersectq", zmm_reg, __mmask8, a, b, k1, k2)
+}
+
+/// Compute intersection of packed 64-bit integer vectors `a` and `b`, and
+/// store indication of match in the corresponding bit of two mask registers
+/// specified by `k1` and `k2`. A match in corresponding elements of `a` and
+/// `b` is indicated by a set bit in the corresponding bit of the mask
+/// registers.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_2intersect_epi64)
+#[inline]
+#[target_feature(enable = "avx512vp2intersect,avx512vl")]
+#[cfg_attr(test, assert_instr(vp2intersectq))]
+pub unsafe fn _mm256_2intersect_epi64(
+    a: __m256i,
+    b: __m256i,
+    k1: *mut __mmask8,
+    k2: *mut __mmask8,
+) {
+    vp2intersect!("vp2intersectq", ymm_reg, __mmask8, a, b, k1, k2)
+}
+
+/// Compute intersection of packed 64-bit integer vectors `a` and `b`, and
+/// store indication of match in the corresponding bit of two mask registers
+/// specified by `k1` and `k2`. A match in corresponding elements of `a` and
+/// `b` is indicated by a set bit in the corresponding bit of the mask
+/// registers.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_2intersect_epi64)
+#[inline]
+#[target_feature(enable = "avx512vp2intersect,avx512vl")]
+#[cfg_attr(test, assert_instr(vp2intersectq))]
+pub unsafe fn _mm_2intersect_epi64(a: __m128i, b: __m128i, k1: *mut __mmask8, k2: *mut __mmask8) {
+    vp2intersect!("vp2intersectq", xmm_reg, __mmask8, a, b, k1, k2)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+    use stdarch_test::simd_test;
+
+    #[simd_test(enable = "avx512vp2intersect,avx512f")]
+    unsafe fn test_mm512_2intersect_epi32() {
+        #[rustfmt::skip]
+        let a = _mm512_setr_epi32(
+            0, 1, 2, 3, 4, 5, 6, 7,
+            8, 9, 10, 11, 12, 13, 14, 15,
+        );
+        // Contains duplicates of `3` and values disjoint from `a`.
+        #[rustfmt::skip]
+        let b = _mm512_setr_epi32(
+            3, 3, 100, 101, 102, 103, 104, 105,
+            15, 106, 107, 108, 109, 110, 111, 0,
+        );
+        let mut k1: __mmask16 = 0;
+        let mut k2: __mmask16 = 0;
+        _mm512_2intersect_epi32(a, b, &mut k1, &mut k2);
+        assert_eq!(k1, 0b10000000_00001001);
+        assert_eq!(k2, 0b10000001_00000011);
+
+        let b = _mm512_set1_epi32(-1);
+        _mm512_2intersect_epi32(a, b, &mut k1, &mut k2);
+        assert_eq!(k1, 0);
+        assert_eq!(k2, 0);
+    }
+
+    #[simd_test(enable = "avx512vp2intersect,avx512vl")]
+    unsafe fn test_mm256_2intersect_epi32() {
+        let a = _mm256_setr_epi32(0, 1, 2, 3, 4, 5, 6, 7);
+        let b = _mm256_setr_epi32(7, 7, 100, 2, 101, 102, 103, 104);
+        let mut k1: __mmask8 = 0;
+        let mut k2: __mmask8 = 0;
+        _mm256_2intersect_epi32(a, b, &mut k1, &mut k2);
+        assert_eq!(k1, 0b10000100);
+        assert_eq!(k2, 0b00001011);
+
+        let b = _mm256_set1_epi32(-1);
+        _mm256_2intersect_epi32(a, b, &mut k1, &mut k2);
+        assert_eq!(k1, 0);
+        assert_eq!(k2, 0);
+    }
+
+    #[simd_test(enable = "avx512vp2intersect,avx512vl")]
+    unsafe fn test_mm_2intersect_epi32() {
+        let a = _mm_setr_epi32(0, 1, 2, 3);
+        let b = _mm_setr_epi32(1, 1, 100, 101);
+        let mut k1: __mmask8 = 0;
+        let mut k2: __mmask8 = 0;
+        _mm_2intersect_epi32(a, b, &mut k1, &mut k2);
+        assert_eq!(k1, 0b0010);
+        assert_eq!(k2, 0b0011);
+
+        let b = _mm_set1_epi32(-1);
+        _mm_2intersect_epi32(a, b, &mut k1, &mut k2);
+        assert_eq!(k1, 0);
+        assert_eq!(k2, 0);
+    }
+
+    #[simd_test(enable = "avx512vp2intersect,avx512f")]
+    unsafe fn test_mm512_2intersect_epi64() {
+        let a = _mm512_setr_epi64(0, 1, 2, 3, 4, 5, 6, 7);
+        let b = _mm512_setr_epi64(5, 5, 100, 101, 102, 103, 104, 0);
+        let mut k1: __mmask8 = 0;
+        let mut k2: __mmask8 = 0;
+        _mm512_2intersect_epi64(a, b, &mut k1, &mut k2);
+        assert_eq!(k1, 0b00100001);
+        assert_eq!(k2, 0b10000011);
+
+        let b = _mm512_set1_epi64(-1);
+        _mm512_2intersect_epi64(a, b, &mut k1, &mut k2);
+        assert_eq!(k1, 0);
+        assert_eq!(k2, 0);
+    }
+
+    #[simd_test(enable = "avx512vp2intersect,avx512vl")]
+    unsafe fn test_mm256_2intersect_epi64() {
+        let a = _mm256_setr_epi64x(0, 1, 2, 3);
+        let b = _mm256_setr_epi64x(3, 100, 3, 101);
+        let mut k1: __mmask8 = 0;
+        let mut k2: __mmask8 = 0;
+        _mm256_2intersect_epi64(a, b, &mut k1, &mut k2);
+        assert_eq!(k1, 0b1000);
+        assert_eq!(k2, 0b0101);
+
+        let b = _mm256_set1_epi64x(-1);
+        _mm256_2intersect_epi64(a, b, &mut k1, &mut k2);
+        assert_eq!(k1, 0);
+        assert_eq!(k2, 0);
+    }
+
+    #[simd_test(enable = "avx512vp2intersect,avx512vl")]
+    unsafe fn test_mm_2intersect_epi64() {
+        let a = _mm_setr_epi64x(0, 1);
+        let b = _mm_setr_epi64x(1, 1);
+        let mut k1: __mmask8 = 0;
+        let mut k2: __mmask8 = 0;
+        _mm_2intersect_epi64(a, b, &mut k1, &mut k2);
+        assert_eq!(k1, 0b10);
+        assert_eq!(k2, 0b11);
+
+        let b = _mm_set1_epi64x(-1);
+        _mm_2intersect_epi64(a, b, &mut k1, &mut k2);
+        assert_eq!(k1, 0);
+        assert_eq!(k2, 0);
+    }
+}
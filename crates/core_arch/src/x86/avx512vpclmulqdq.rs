@@ -32,7 +32,7 @@ extern "C" {
 ///
 /// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_clmulepi64_epi128)
 #[inline]
-#[target_feature(enable = "avx512vpclmulqdq,avx512f")]
+#[target_feature(enable = "vpclmulqdq,avx512f")]
 // technically according to Intel's documentation we don't need avx512f here, however LLVM gets confused otherwise
 #[cfg_attr(test, assert_instr(vpclmul, IMM8 = 0))]
 #[rustc_legacy_const_generics(2)]
@@ -50,7 +50,7 @@ pub unsafe fn _mm512_clmulepi64_epi128<const IMM8: i32>(a: __m512i, b: __m512i)
 ///
 /// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_clmulepi64_epi128)
 #[inline]
-#[target_feature(enable = "avx512vpclmulqdq,avx512vl")]
+#[target_feature(enable = "vpclmulqdq")]
 #[cfg_attr(test, assert_instr(vpclmul, IMM8 = 0))]
 #[rustc_legacy_const_generics(2)]
 pub unsafe fn _mm256_clmulepi64_epi128<const IMM8: i32>(a: __m256i, b: __m256i) -> __m256i {
@@ -121,7 +121,7 @@ mod tests {
 
     // this function tests one of the possible 4 instances
     // with different inputs across lanes
-    #[target_feature(enable = "avx512vpclmulqdq,avx512f")]
+    #[target_feature(enable = "vpclmulqdq,avx512f")]
     unsafe fn verify_512_helper(
         linear: unsafe fn(__m128i, __m128i) -> __m128i,
         vectorized: unsafe fn(__m512i, __m512i) -> __m512i,
@@ -161,42 +161,31 @@ mod tests {
     }
 
     // this function tests one of the possible 4 instances
-    // with different inputs across lanes for the VL version
-    #[target_feature(enable = "avx512vpclmulqdq,avx512vl")]
+    // with different inputs across lanes for the 256-bit version
+    #[target_feature(enable = "vpclmulqdq,avx2")]
     unsafe fn verify_256_helper(
         linear: unsafe fn(__m128i, __m128i) -> __m128i,
         vectorized: unsafe fn(__m256i, __m256i) -> __m256i,
     ) {
-        let a = _mm512_set_epi64(
+        let a = _mm256_set_epi64x(
             0xDCB4DB3657BF0B7D,
             0x18DB0601068EDD9F,
             0xB76B908233200DC5,
             0xE478235FA8E22D5E,
-            0xAB05CFFA2621154C,
-            0x1171B47A186174C9,
-            0x8C6B6C0E7595CEC9,
-            0xBE3E7D4934E961BD,
         );
-        let b = _mm512_set_epi64(
+        let b = _mm256_set_epi64x(
             0x672F6F105A94CEA7,
             0x8298B8FFCA5F829C,
             0xA3927047B3FB61D8,
             0x978093862CDE7187,
-            0xB1927AB22F31D0EC,
-            0xA9A5DA619BE4D7AF,
-            0xCA2590F56884FDC6,
-            0x19BE9F660038BDB5,
         );
 
         let mut a_decomp = [_mm_setzero_si128(); 2];
-        unroll! {a_decomp[2] = _mm512_extracti32x4_epi32::<2>(a);}
+        unroll! {a_decomp[2] = _mm256_extracti128_si256::<2>(a);}
         let mut b_decomp = [_mm_setzero_si128(); 2];
-        unroll! {b_decomp[2] = _mm512_extracti32x4_epi32::<2>(b);}
+        unroll! {b_decomp[2] = _mm256_extracti128_si256::<2>(b);}
 
-        let r = vectorized(
-            _mm512_extracti64x4_epi64::<0>(a),
-            _mm512_extracti64x4_epi64::<0>(b),
-        );
+        let r = vectorized(a, b);
         let mut e_decomp = [_mm_setzero_si128(); 2];
         for i in 0..2 {
             e_decomp[i] = linear(a_decomp[i], b_decomp[i]);
@@ -204,7 +193,7 @@ mod tests {
         unroll! {assert_eq_m128i(_mm256_extracti128_si256::<2>(r),e_decomp[2]);}
     }
 
-    #[simd_test(enable = "avx512vpclmulqdq,avx512f")]
+    #[simd_test(enable = "vpclmulqdq,avx512f")]
     unsafe fn test_mm512_clmulepi64_epi128() {
         verify_kat_pclmul!(
             _mm512_broadcast_i32x4,
@@ -230,7 +219,7 @@ mod tests {
         );
     }
 
-    #[simd_test(enable = "avx512vpclmulqdq,avx512vl")]
+    #[simd_test(enable = "vpclmulqdq")]
     unsafe fn test_mm256_clmulepi64_epi128() {
         verify_kat_pclmul!(
             _mm256_broadcastsi128_si256,
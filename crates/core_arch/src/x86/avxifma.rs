@@ -0,0 +1,204 @@
+//! AVX-IFMA intrinsics
+//!
+//! These are the VEX encoded 128-bit and 256-bit forms of the 52-bit
+//! multiply-accumulate instructions in `avx512ifma`, which don't require
+//! AVX-512 support.
+
+use crate::core_arch::x86::*;
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Multiply packed unsigned 52-bit integers in each 64-bit element of
+/// `b` and `c` to form a 104-bit intermediate result. Add the high 52-bit
+/// unsigned integer from the intermediate result with the
+/// corresponding unsigned 64-bit integer in `a`, and store the
+/// results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_madd52hi_avx_epu64)
+#[inline]
+#[target_feature(enable = "avxifma")]
+#[cfg_attr(test, assert_instr(vpmadd52huq))]
+pub unsafe fn _mm256_madd52hi_avx_epu64(a: __m256i, b: __m256i, c: __m256i) -> __m256i {
+    vpmadd52huq_256(a, b, c)
+}
+
+/// Multiply packed unsigned 52-bit integers in each 64-bit element of
+/// `b` and `c` to form a 104-bit intermediate result. Add the low 52-bit
+/// unsigned integer from the intermediate result with the
+/// corresponding unsigned 64-bit integer in `a`, and store the
+/// results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_madd52lo_avx_epu64)
+#[inline]
+#[target_feature(enable = "avxifma")]
+#[cfg_attr(test, assert_instr(vpmadd52luq))]
+pub unsafe fn _mm256_madd52lo_avx_epu64(a: __m256i, b: __m256i, c: __m256i) -> __m256i {
+    vpmadd52luq_256(a, b, c)
+}
+
+/// Multiply packed unsigned 52-bit integers in each 64-bit element of
+/// `b` and `c` to form a 104-bit intermediate result. Add the high 52-bit
+/// unsigned integer from the intermediate result with the
+/// corresponding unsigned 64-bit integer in `a`, and store the
+/// results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_madd52hi_avx_epu64)
+#[inline]
+#[target_feature(enable = "avxifma")]
+#[cfg_attr(test, assert_instr(vpmadd52huq))]
+pub unsafe fn _mm_madd52hi_avx_epu64(a: __m128i, b: __m128i, c: __m128i) -> __m128i {
+    vpmadd52huq_128(a, b, c)
+}
+
+/// Multiply packed unsigned 52-bit integers in each 64-bit element of
+/// `b` and `c` to form a 104-bit intermediate result. Add the low 52-bit
+/// unsigned integer from the intermediate result with the
+/// corresponding unsigned 64-bit integer in `a`, and store the
+/// results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_madd52lo_avx_epu64)
+#[inline]
+#[target_feature(enable = "avxifma")]
+#[cfg_attr(test, assert_instr(vpmadd52luq))]
+pub unsafe fn _mm_madd52lo_avx_epu64(a: __m128i, b: __m128i, c: __m128i) -> __m128i {
+    vpmadd52luq_128(a, b, c)
+}
+
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.x86.avx512.vpmadd52l.uq.128"]
+    fn vpmadd52luq_128(z: __m128i, x: __m128i, y: __m128i) -> __m128i;
+    #[link_name = "llvm.x86.avx512.vpmadd52h.uq.128"]
+    fn vpmadd52huq_128(z: __m128i, x: __m128i, y: __m128i) -> __m128i;
+    #[link_name = "llvm.x86.avx512.vpmadd52l.uq.256"]
+    fn vpmadd52luq_256(z: __m256i, x: __m256i, y: __m256i) -> __m256i;
+    #[link_name = "llvm.x86.avx512.vpmadd52h.uq.256"]
+    fn vpmadd52huq_256(z: __m256i, x: __m256i, y: __m256i) -> __m256i;
+}
+
+#[cfg(test)]
+mod tests {
+    use stdarch_test::simd_test;
+
+    use crate::core_arch::x86::*;
+
+    const MASK52: u64 = (1 << 52) - 1;
+
+    // `(a, b, c)` inputs: a plain product, a product whose low half carries
+    // into the high half, one where the accumulation wraps around and the
+    // bits of `b` and `c` above bit 51 have to be ignored, and an arbitrary
+    // full-width product.
+    const INPUTS: [(u64, u64, u64); 4] = [
+        (10 << 40, (11 << 40) + 4, (12 << 40) + 3),
+        (0, MASK52, MASK52),
+        (u64::MAX, 0xfff0_0000_0000_0003, 0x8000_0000_0000_0005),
+        (1, 0x000f_edcb_a987_6543, 0x000a_bcde_f012_3456),
+    ];
+
+    // Reference implementation of a single lane of `vpmadd52huq` (if `hi`)
+    // or `vpmadd52luq`.
+    fn madd52(a: u64, b: u64, c: u64, hi: bool) -> u64 {
+        let product = (b & MASK52) as u128 * (c & MASK52) as u128;
+        let half = if hi { product >> 52 } else { product };
+        a.wrapping_add(half as u64 & MASK52)
+    }
+
+    #[target_feature(enable = "avx")]
+    unsafe fn check_256(f: unsafe fn(__m256i, __m256i, __m256i) -> __m256i, hi: bool) {
+        let [x0, x1, x2, x3] = INPUTS;
+        let a = _mm256_set_epi64x(x3.0 as i64, x2.0 as i64, x1.0 as i64, x0.0 as i64);
+        let b = _mm256_set_epi64x(x3.1 as i64, x2.1 as i64, x1.1 as i64, x0.1 as i64);
+        let c = _mm256_set_epi64x(x3.2 as i64, x2.2 as i64, x1.2 as i64, x0.2 as i64);
+        let mut e = [0; 4];
+        for (e, &(a, b, c)) in e.iter_mut().zip(INPUTS.iter()) {
+            *e = madd52(a, b, c, hi) as i64;
+        }
+        let e = _mm256_set_epi64x(e[3], e[2], e[1], e[0]);
+        assert_eq_m256i(f(a, b, c), e);
+    }
+
+    #[target_feature(enable = "sse2")]
+    unsafe fn check_128(f: unsafe fn(__m128i, __m128i, __m128i) -> __m128i, hi: bool) {
+        for x in INPUTS.chunks(2) {
+            let a = _mm_set_epi64x(x[1].0 as i64, x[0].0 as i64);
+            let b = _mm_set_epi64x(x[1].1 as i64, x[0].1 as i64);
+            let c = _mm_set_epi64x(x[1].2 as i64, x[0].2 as i64);
+            let e0 = madd52(x[0].0, x[0].1, x[0].2, hi) as i64;
+            let e1 = madd52(x[1].0, x[1].1, x[1].2, hi) as i64;
+            assert_eq_m128i(f(a, b, c), _mm_set_epi64x(e1, e0));
+        }
+    }
+
+    #[simd_test(enable = "avxifma")]
+    unsafe fn test_mm256_madd52hi_avx_epu64() {
+        let a = _mm256_set1_epi64x(10 << 40);
+        let b = _mm256_set1_epi64x((11 << 40) + 4);
+        let c = _mm256_set1_epi64x((12 << 40) + 3);
+        let r = _mm256_madd52hi_avx_epu64(a, b, c);
+        // (10 << 40) + ((((11 << 40) + 4) * ((12 << 40) + 3)) >> 52)
+        let e = _mm256_set1_epi64x(11030549757952);
+        assert_eq_m256i(r, e);
+
+        // (2^52 - 1)^2 = 2^104 - 2^53 + 1
+        let b = _mm256_set1_epi64x(MASK52 as i64);
+        let r = _mm256_madd52hi_avx_epu64(_mm256_setzero_si256(), b, b);
+        assert_eq_m256i(r, _mm256_set1_epi64x(MASK52 as i64 - 1));
+
+        check_256(_mm256_madd52hi_avx_epu64, true);
+    }
+
+    #[simd_test(enable = "avxifma")]
+    unsafe fn test_mm256_madd52lo_avx_epu64() {
+        let a = _mm256_set1_epi64x(10 << 40);
+        let b = _mm256_set1_epi64x((11 << 40) + 4);
+        let c = _mm256_set1_epi64x((12 << 40) + 3);
+        let r = _mm256_madd52lo_avx_epu64(a, b, c);
+        // (10 << 40) + ((((11 << 40) + 4) * ((12 << 40) + 3)) % (1 << 52))
+        let e = _mm256_set1_epi64x(100055558127628);
+        assert_eq_m256i(r, e);
+
+        // (2^52 - 1)^2 = 2^104 - 2^53 + 1
+        let b = _mm256_set1_epi64x(MASK52 as i64);
+        let r = _mm256_madd52lo_avx_epu64(_mm256_setzero_si256(), b, b);
+        assert_eq_m256i(r, _mm256_set1_epi64x(1));
+
+        check_256(_mm256_madd52lo_avx_epu64, false);
+    }
+
+    #[simd_test(enable = "avxifma")]
+    unsafe fn test_mm_madd52hi_avx_epu64() {
+        let a = _mm_set1_epi64x(10 << 40);
+        let b = _mm_set1_epi64x((11 << 40) + 4);
+        let c = _mm_set1_epi64x((12 << 40) + 3);
+        let r = _mm_madd52hi_avx_epu64(a, b, c);
+        // (10 << 40) + ((((11 << 40) + 4) * ((12 << 40) + 3)) >> 52)
+        let e = _mm_set1_epi64x(11030549757952);
+        assert_eq_m128i(r, e);
+
+        // (2^52 - 1)^2 = 2^104 - 2^53 + 1
+        let b = _mm_set1_epi64x(MASK52 as i64);
+        let r = _mm_madd52hi_avx_epu64(_mm_setzero_si128(), b, b);
+        assert_eq_m128i(r, _mm_set1_epi64x(MASK52 as i64 - 1));
+
+        check_128(_mm_madd52hi_avx_epu64, true);
+    }
+
+    #[simd_test(enable = "avxifma")]
+    unsafe fn test_mm_madd52lo_avx_epu64() {
+        let a = _mm_set1_epi64x(10 << 40);
+        let b = _mm_set1_epi64x((11 << 40) + 4);
+        let c = _mm_set1_epi64x((12 << 40) + 3);
+        let r = _mm_madd52lo_avx_epu64(a, b, c);
+        // (10 << 40) + ((((11 << 40) + 4) * ((12 << 40) + 3)) % (1 << 52))
+        let e = _mm_set1_epi64x(100055558127628);
+        assert_eq_m128i(r, e);
+
+        // (2^52 - 1)^2 = 2^104 - 2^53 + 1
+        let b = _mm_set1_epi64x(MASK52 as i64);
+        let r = _mm_madd52lo_avx_epu64(_mm_setzero_si128(), b, b);
+        assert_eq_m128i(r, _mm_set1_epi64x(1));
+
+        check_128(_mm_madd52lo_avx_epu64, false);
+    }
+}
@@ -0,0 +1,338 @@
+//! AVX-NE-CONVERT intrinsics
+//!
+//! These convert between BF16/FP16 and single-precision floating-point
+//! values using VEX encoded instructions, which don't require AVX-512
+//! support.
+//!
+//! Rust doesn't have BF16 or FP16 floating point types, so scalar memory
+//! operands are passed as pointers to the `u16` bit pattern of the value.
+
+use crate::{
+    core_arch::{simd::*, x86::*},
+    mem::transmute,
+};
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.x86.vbcstnebf162ps128"]
+    fn vbcstnebf162ps128(a: *const u16) -> f32x4;
+    #[link_name = "llvm.x86.vbcstnebf162ps256"]
+    fn vbcstnebf162ps256(a: *const u16) -> f32x8;
+    #[link_name = "llvm.x86.vbcstnesh2ps128"]
+    fn vbcstnesh2ps128(a: *const u16) -> f32x4;
+    #[link_name = "llvm.x86.vbcstnesh2ps256"]
+    fn vbcstnesh2ps256(a: *const u16) -> f32x8;
+    #[link_name = "llvm.x86.vcvtneebf162ps128"]
+    fn vcvtneebf162ps128(a: *const __m128bh) -> f32x4;
+    #[link_name = "llvm.x86.vcvtneebf162ps256"]
+    fn vcvtneebf162ps256(a: *const __m256bh) -> f32x8;
+    #[link_name = "llvm.x86.vcvtneeph2ps128"]
+    fn vcvtneeph2ps128(a: *const __m128h) -> f32x4;
+    #[link_name = "llvm.x86.vcvtneeph2ps256"]
+    fn vcvtneeph2ps256(a: *const __m256h) -> f32x8;
+    #[link_name = "llvm.x86.vcvtneobf162ps128"]
+    fn vcvtneobf162ps128(a: *const __m128bh) -> f32x4;
+    #[link_name = "llvm.x86.vcvtneobf162ps256"]
+    fn vcvtneobf162ps256(a: *const __m256bh) -> f32x8;
+    #[link_name = "llvm.x86.vcvtneoph2ps128"]
+    fn vcvtneoph2ps128(a: *const __m128h) -> f32x4;
+    #[link_name = "llvm.x86.vcvtneoph2ps256"]
+    fn vcvtneoph2ps256(a: *const __m256h) -> f32x8;
+    #[link_name = "llvm.x86.vcvtneps2bf16128"]
+    fn vcvtneps2bf16128(a: f32x4) -> i16x8;
+    #[link_name = "llvm.x86.vcvtneps2bf16256"]
+    fn vcvtneps2bf16256(a: f32x8) -> i16x8;
+}
+
+/// Convert scalar BF16 (16-bit) floating-point element stored at memory locations starting at location a to single precision (32-bit) floating-point, broadcast it to packed single precision (32-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_bcstnebf16_ps)
+#[inline]
+#[target_feature(enable = "avxneconvert")]
+#[cfg_attr(test, assert_instr(vbcstnebf162ps))]
+pub unsafe fn _mm_bcstnebf16_ps(a: *const u16) -> __m128 {
+    transmute(vbcstnebf162ps128(a))
+}
+
+/// Convert scalar BF16 (16-bit) floating-point element stored at memory locations starting at location a to single precision (32-bit) floating-point, broadcast it to packed single precision (32-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_bcstnebf16_ps)
+#[inline]
+#[target_feature(enable = "avxneconvert")]
+#[cfg_attr(test, assert_instr(vbcstnebf162ps))]
+pub unsafe fn _mm256_bcstnebf16_ps(a: *const u16) -> __m256 {
+    transmute(vbcstnebf162ps256(a))
+}
+
+/// Convert scalar half-precision (16-bit) floating-point element stored at memory locations starting at location a to a single-precision (32-bit) floating-point, broadcast it to packed single-precision (32-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_bcstnesh_ps)
+#[inline]
+#[target_feature(enable = "avxneconvert")]
+#[cfg_attr(test, assert_instr(vbcstnesh2ps))]
+pub unsafe fn _mm_bcstnesh_ps(a: *const u16) -> __m128 {
+    transmute(vbcstnesh2ps128(a))
+}
+
+/// Convert scalar half-precision (16-bit) floating-point element stored at memory locations starting at location a to a single-precision (32-bit) floating-point, broadcast it to packed single-precision (32-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_bcstnesh_ps)
+#[inline]
+#[target_feature(enable = "avxneconvert")]
+#[cfg_attr(test, assert_instr(vbcstnesh2ps))]
+pub unsafe fn _mm256_bcstnesh_ps(a: *const u16) -> __m256 {
+    transmute(vbcstnesh2ps256(a))
+}
+
+/// Convert packed BF16 (16-bit) floating-point even-indexed elements stored at memory locations starting at location a to single precision (32-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvtneebf16_ps)
+#[inline]
+#[target_feature(enable = "avxneconvert")]
+#[cfg_attr(test, assert_instr(vcvtneebf162ps))]
+pub unsafe fn _mm_cvtneebf16_ps(a: *const __m128bh) -> __m128 {
+    transmute(vcvtneebf162ps128(a))
+}
+
+/// Convert packed BF16 (16-bit) floating-point even-indexed elements stored at memory locations starting at location a to single precision (32-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cvtneebf16_ps)
+#[inline]
+#[target_feature(enable = "avxneconvert")]
+#[cfg_attr(test, assert_instr(vcvtneebf162ps))]
+pub unsafe fn _mm256_cvtneebf16_ps(a: *const __m256bh) -> __m256 {
+    transmute(vcvtneebf162ps256(a))
+}
+
+/// Convert packed half-precision (16-bit) floating-point even-indexed elements stored at memory locations starting at location a to single precision (32-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvtneeph_ps)
+#[inline]
+#[target_feature(enable = "avxneconvert")]
+#[cfg_attr(test, assert_instr(vcvtneeph2ps))]
+pub unsafe fn _mm_cvtneeph_ps(a: *const __m128h) -> __m128 {
+    transmute(vcvtneeph2ps128(a))
+}
+
+/// Convert packed half-precision (16-bit) floating-point even-indexed elements stored at memory locations starting at location a to single precision (32-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cvtneeph_ps)
+#[inline]
+#[target_feature(enable = "avxneconvert")]
+#[cfg_attr(test, assert_instr(vcvtneeph2ps))]
+pub unsafe fn _mm256_cvtneeph_ps(a: *const __m256h) -> __m256 {
+    transmute(vcvtneeph2ps256(a))
+}
+
+/// Convert packed BF16 (16-bit) floating-point odd-indexed elements stored at memory locations starting at location a to single precision (32-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvtneobf16_ps)
+#[inline]
+#[target_feature(enable = "avxneconvert")]
+#[cfg_attr(test, assert_instr(vcvtneobf162ps))]
+pub unsafe fn _mm_cvtneobf16_ps(a: *const __m128bh) -> __m128 {
+    transmute(vcvtneobf162ps128(a))
+}
+
+/// Convert packed BF16 (16-bit) floating-point odd-indexed elements stored at memory locations starting at location a to single precision (32-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cvtneobf16_ps)
+#[inline]
+#[target_feature(enable = "avxneconvert")]
+#[cfg_attr(test, assert_instr(vcvtneobf162ps))]
+pub unsafe fn _mm256_cvtneobf16_ps(a: *const __m256bh) -> __m256 {
+    transmute(vcvtneobf162ps256(a))
+}
+
+/// Convert packed half-precision (16-bit) floating-point odd-indexed elements stored at memory locations starting at location a to single precision (32-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvtneoph_ps)
+#[inline]
+#[target_feature(enable = "avxneconvert")]
+#[cfg_attr(test, assert_instr(vcvtneoph2ps))]
+pub unsafe fn _mm_cvtneoph_ps(a: *const __m128h) -> __m128 {
+    transmute(vcvtneoph2ps128(a))
+}
+
+/// Convert packed half-precision (16-bit) floating-point odd-indexed elements stored at memory locations starting at location a to single precision (32-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cvtneoph_ps)
+#[inline]
+#[target_feature(enable = "avxneconvert")]
+#[cfg_attr(test, assert_instr(vcvtneoph2ps))]
+pub unsafe fn _mm256_cvtneoph_ps(a: *const __m256h) -> __m256 {
+    transmute(vcvtneoph2ps256(a))
+}
+
+/// Convert packed single precision (32-bit) floating-point elements in a to packed BF16 (16-bit) floating-point elements, and store the results in dst. The upper half of dst is zeroed.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvtneps_avx_pbh)
+#[inline]
+#[target_feature(enable = "avxneconvert")]
+#[cfg_attr(test, assert_instr(vcvtneps2bf16))]
+pub unsafe fn _mm_cvtneps_avx_pbh(a: __m128) -> __m128bh {
+    transmute(vcvtneps2bf16128(a.as_f32x4()))
+}
+
+/// Convert packed single precision (32-bit) floating-point elements in a to packed BF16 (16-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cvtneps_avx_pbh)
+#[inline]
+#[target_feature(enable = "avxneconvert")]
+#[cfg_attr(test, assert_instr(vcvtneps2bf16))]
+pub unsafe fn _mm256_cvtneps_avx_pbh(a: __m256) -> __m128bh {
+    transmute(vcvtneps2bf16256(a.as_f32x8()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{core_arch::x86::*, mem::transmute};
+    use stdarch_test::simd_test;
+
+    // Small integers are exactly representable in BF16, whose bit pattern is
+    // the upper half of the corresponding `f32`.
+    fn bf16(x: f32) -> u16 {
+        (x.to_bits() >> 16) as u16
+    }
+
+    // The IEEE 754 `binary16` bit patterns of 1.0 through 16.0.
+    #[rustfmt::skip]
+    const FP16: [u16; 16] = [
+        0x3c00, 0x4000, 0x4200, 0x4400, 0x4500, 0x4600, 0x4700, 0x4800,
+        0x4880, 0x4900, 0x4980, 0x4a00, 0x4a80, 0x4b00, 0x4b80, 0x4c00,
+    ];
+
+    #[simd_test(enable = "avxneconvert")]
+    unsafe fn test_mm_bcstnebf16_ps() {
+        // Only the element pointed to is read and broadcast.
+        let mem = [bf16(1.0), bf16(-2.5), bf16(3.0)];
+        let r = _mm_bcstnebf16_ps(&mem[1]);
+        assert_eq_m128(r, _mm_set1_ps(-2.5));
+    }
+
+    #[simd_test(enable = "avxneconvert")]
+    unsafe fn test_mm256_bcstnebf16_ps() {
+        let mem = [bf16(1.0), bf16(-2.5), bf16(3.0)];
+        let r = _mm256_bcstnebf16_ps(&mem[1]);
+        assert_eq_m256(r, _mm256_set1_ps(-2.5));
+    }
+
+    #[simd_test(enable = "avxneconvert")]
+    unsafe fn test_mm_bcstnesh_ps() {
+        // Only the element pointed to is read and broadcast.
+        let mem = [FP16[0], 0xc100, FP16[2]];
+        let r = _mm_bcstnesh_ps(&mem[1]);
+        assert_eq_m128(r, _mm_set1_ps(-2.5));
+    }
+
+    #[simd_test(enable = "avxneconvert")]
+    unsafe fn test_mm256_bcstnesh_ps() {
+        let mem = [FP16[0], 0xc100, FP16[2]];
+        let r = _mm256_bcstnesh_ps(&mem[1]);
+        assert_eq_m256(r, _mm256_set1_ps(-2.5));
+    }
+
+    #[simd_test(enable = "avxneconvert")]
+    unsafe fn test_mm_cvtneebf16_ps() {
+        let mut mem = [0u16; 8];
+        for i in 0..8 {
+            mem[i] = bf16((i + 1) as f32);
+        }
+        let a: __m128bh = transmute(mem);
+        let r = _mm_cvtneebf16_ps(&a);
+        assert_eq_m128(r, _mm_setr_ps(1.0, 3.0, 5.0, 7.0));
+    }
+
+    #[simd_test(enable = "avxneconvert")]
+    unsafe fn test_mm256_cvtneebf16_ps() {
+        let mut mem = [0u16; 16];
+        for i in 0..16 {
+            mem[i] = bf16((i + 1) as f32);
+        }
+        let a: __m256bh = transmute(mem);
+        let r = _mm256_cvtneebf16_ps(&a);
+        let e = _mm256_setr_ps(1.0, 3.0, 5.0, 7.0, 9.0, 11.0, 13.0, 15.0);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avxneconvert")]
+    unsafe fn test_mm_cvtneeph_ps() {
+        let mut mem = [0u16; 8];
+        mem.copy_from_slice(&FP16[..8]);
+        let a: __m128h = transmute(mem);
+        let r = _mm_cvtneeph_ps(&a);
+        assert_eq_m128(r, _mm_setr_ps(1.0, 3.0, 5.0, 7.0));
+    }
+
+    #[simd_test(enable = "avxneconvert")]
+    unsafe fn test_mm256_cvtneeph_ps() {
+        let a: __m256h = transmute(FP16);
+        let r = _mm256_cvtneeph_ps(&a);
+        let e = _mm256_setr_ps(1.0, 3.0, 5.0, 7.0, 9.0, 11.0, 13.0, 15.0);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avxneconvert")]
+    unsafe fn test_mm_cvtneobf16_ps() {
+        let mut mem = [0u16; 8];
+        for i in 0..8 {
+            mem[i] = bf16((i + 1) as f32);
+        }
+        let a: __m128bh = transmute(mem);
+        let r = _mm_cvtneobf16_ps(&a);
+        assert_eq_m128(r, _mm_setr_ps(2.0, 4.0, 6.0, 8.0));
+    }
+
+    #[simd_test(enable = "avxneconvert")]
+    unsafe fn test_mm256_cvtneobf16_ps() {
+        let mut mem = [0u16; 16];
+        for i in 0..16 {
+            mem[i] = bf16((i + 1) as f32);
+        }
+        let a: __m256bh = transmute(mem);
+        let r = _mm256_cvtneobf16_ps(&a);
+        let e = _mm256_setr_ps(2.0, 4.0, 6.0, 8.0, 10.0, 12.0, 14.0, 16.0);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avxneconvert")]
+    unsafe fn test_mm_cvtneoph_ps() {
+        let mut mem = [0u16; 8];
+        mem.copy_from_slice(&FP16[..8]);
+        let a: __m128h = transmute(mem);
+        let r = _mm_cvtneoph_ps(&a);
+        assert_eq_m128(r, _mm_setr_ps(2.0, 4.0, 6.0, 8.0));
+    }
+
+    #[simd_test(enable = "avxneconvert")]
+    unsafe fn test_mm256_cvtneoph_ps() {
+        let a: __m256h = transmute(FP16);
+        let r = _mm256_cvtneoph_ps(&a);
+        let e = _mm256_setr_ps(2.0, 4.0, 6.0, 8.0, 10.0, 12.0, 14.0, 16.0);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avxneconvert")]
+    unsafe fn test_mm_cvtneps_avx_pbh() {
+        // 1 + 2^-8 and 1 + 3 * 2^-8 are halfway between two BF16 values and
+        // round to the even one.
+        let a = _mm_setr_ps(-4.0, 1.5, 1.00390625, 1.01171875);
+        let r: [u16; 8] = transmute(_mm_cvtneps_avx_pbh(a));
+        assert_eq!(r, [0xc080, 0x3fc0, 0x3f80, 0x3f82, 0, 0, 0, 0]);
+    }
+
+    #[simd_test(enable = "avxneconvert")]
+    unsafe fn test_mm256_cvtneps_avx_pbh() {
+        let a = _mm256_setr_ps(-4.0, 1.5, 1.00390625, 1.01171875, 0.0, -0.0, 178.125, 10.5);
+        let r: [u16; 8] = transmute(_mm256_cvtneps_avx_pbh(a));
+        #[rustfmt::skip]
+        let e: [u16; 8] = [
+            0xc080, 0x3fc0, 0x3f80, 0x3f82,
+            0x0000, 0x8000, 0b0_10000110_0110010, 0b0_10000010_0101000,
+        ];
+        assert_eq!(r, e);
+    }
+}
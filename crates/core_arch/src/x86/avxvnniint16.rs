@@ -0,0 +1,545 @@
+//! AVX-VNNI-INT16 intrinsics
+//!
+//! Dot products of 16-bit integers with any combination of signedness,
+//! accumulated into 32-bit integers.
+
+use crate::{
+    core_arch::{simd::*, x86::*},
+    mem::transmute,
+};
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Multiply groups of 2 adjacent pairs of signed 16-bit integers in a with corresponding unsigned 16-bit integers in b, producing 2 intermediate signed 32-bit results. Sum these 2 results with the corresponding 32-bit integer in src, and store the packed 32-bit results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_dpwsud_epi32)
+#[inline]
+#[target_feature(enable = "avxvnniint16")]
+#[cfg_attr(test, assert_instr(vpdpwsud))]
+pub unsafe fn _mm256_dpwsud_epi32(src: __m256i, a: __m256i, b: __m256i) -> __m256i {
+    transmute(vpdpwsud256(src.as_i32x8(), a.as_i32x8(), b.as_i32x8()))
+}
+
+/// Multiply groups of 2 adjacent pairs of signed 16-bit integers in a with corresponding unsigned 16-bit integers in b, producing 2 intermediate signed 32-bit results. Sum these 2 results with the corresponding 32-bit integer in src, and store the packed 32-bit results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_dpwsud_epi32)
+#[inline]
+#[target_feature(enable = "avxvnniint16")]
+#[cfg_attr(test, assert_instr(vpdpwsud))]
+pub unsafe fn _mm_dpwsud_epi32(src: __m128i, a: __m128i, b: __m128i) -> __m128i {
+    transmute(vpdpwsud128(src.as_i32x4(), a.as_i32x4(), b.as_i32x4()))
+}
+
+/// Multiply groups of 2 adjacent pairs of signed 16-bit integers in a with corresponding unsigned 16-bit integers in b, producing 2 intermediate signed 32-bit results. Sum these 2 results with the corresponding 32-bit integer in src using signed saturation, and store the packed 32-bit results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_dpwsuds_epi32)
+#[inline]
+#[target_feature(enable = "avxvnniint16")]
+#[cfg_attr(test, assert_instr(vpdpwsuds))]
+pub unsafe fn _mm256_dpwsuds_epi32(src: __m256i, a: __m256i, b: __m256i) -> __m256i {
+    transmute(vpdpwsuds256(src.as_i32x8(), a.as_i32x8(), b.as_i32x8()))
+}
+
+/// Multiply groups of 2 adjacent pairs of signed 16-bit integers in a with corresponding unsigned 16-bit integers in b, producing 2 intermediate signed 32-bit results. Sum these 2 results with the corresponding 32-bit integer in src using signed saturation, and store the packed 32-bit results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_dpwsuds_epi32)
+#[inline]
+#[target_feature(enable = "avxvnniint16")]
+#[cfg_attr(test, assert_instr(vpdpwsuds))]
+pub unsafe fn _mm_dpwsuds_epi32(src: __m128i, a: __m128i, b: __m128i) -> __m128i {
+    transmute(vpdpwsuds128(src.as_i32x4(), a.as_i32x4(), b.as_i32x4()))
+}
+
+/// Multiply groups of 2 adjacent pairs of unsigned 16-bit integers in a with corresponding signed 16-bit integers in b, producing 2 intermediate signed 32-bit results. Sum these 2 results with the corresponding 32-bit integer in src, and store the packed 32-bit results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_dpwusd_epi32)
+#[inline]
+#[target_feature(enable = "avxvnniint16")]
+#[cfg_attr(test, assert_instr(vpdpwusd))]
+pub unsafe fn _mm256_dpwusd_epi32(src: __m256i, a: __m256i, b: __m256i) -> __m256i {
+    transmute(vpdpwusd256(src.as_i32x8(), a.as_i32x8(), b.as_i32x8()))
+}
+
+/// Multiply groups of 2 adjacent pairs of unsigned 16-bit integers in a with corresponding signed 16-bit integers in b, producing 2 intermediate signed 32-bit results. Sum these 2 results with the corresponding 32-bit integer in src, and store the packed 32-bit results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_dpwusd_epi32)
+#[inline]
+#[target_feature(enable = "avxvnniint16")]
+#[cfg_attr(test, assert_instr(vpdpwusd))]
+pub unsafe fn _mm_dpwusd_epi32(src: __m128i, a: __m128i, b: __m128i) -> __m128i {
+    transmute(vpdpwusd128(src.as_i32x4(), a.as_i32x4(), b.as_i32x4()))
+}
+
+/// Multiply groups of 2 adjacent pairs of unsigned 16-bit integers in a with corresponding signed 16-bit integers in b, producing 2 intermediate signed 32-bit results. Sum these 2 results with the corresponding 32-bit integer in src using signed saturation, and store the packed 32-bit results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_dpwusds_epi32)
+#[inline]
+#[target_feature(enable = "avxvnniint16")]
+#[cfg_attr(test, assert_instr(vpdpwusds))]
+pub unsafe fn _mm256_dpwusds_epi32(src: __m256i, a: __m256i, b: __m256i) -> __m256i {
+    transmute(vpdpwusds256(src.as_i32x8(), a.as_i32x8(), b.as_i32x8()))
+}
+
+/// Multiply groups of 2 adjacent pairs of unsigned 16-bit integers in a with corresponding signed 16-bit integers in b, producing 2 intermediate signed 32-bit results. Sum these 2 results with the corresponding 32-bit integer in src using signed saturation, and store the packed 32-bit results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_dpwusds_epi32)
+#[inline]
+#[target_feature(enable = "avxvnniint16")]
+#[cfg_attr(test, assert_instr(vpdpwusds))]
+pub unsafe fn _mm_dpwusds_epi32(src: __m128i, a: __m128i, b: __m128i) -> __m128i {
+    transmute(vpdpwusds128(src.as_i32x4(), a.as_i32x4(), b.as_i32x4()))
+}
+
+/// Multiply groups of 2 adjacent pairs of unsigned 16-bit integers in a with corresponding unsigned 16-bit integers in b, producing 2 intermediate unsigned 32-bit results. Sum these 2 results with the corresponding 32-bit integer in src, and store the packed 32-bit results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_dpwuud_epi32)
+#[inline]
+#[target_feature(enable = "avxvnniint16")]
+#[cfg_attr(test, assert_instr(vpdpwuud))]
+pub unsafe fn _mm256_dpwuud_epi32(src: __m256i, a: __m256i, b: __m256i) -> __m256i {
+    transmute(vpdpwuud256(src.as_i32x8(), a.as_i32x8(), b.as_i32x8()))
+}
+
+/// Multiply groups of 2 adjacent pairs of unsigned 16-bit integers in a with corresponding unsigned 16-bit integers in b, producing 2 intermediate unsigned 32-bit results. Sum these 2 results with the corresponding 32-bit integer in src, and store the packed 32-bit results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_dpwuud_epi32)
+#[inline]
+#[target_feature(enable = "avxvnniint16")]
+#[cfg_attr(test, assert_instr(vpdpwuud))]
+pub unsafe fn _mm_dpwuud_epi32(src: __m128i, a: __m128i, b: __m128i) -> __m128i {
+    transmute(vpdpwuud128(src.as_i32x4(), a.as_i32x4(), b.as_i32x4()))
+}
+
+/// Multiply groups of 2 adjacent pairs of unsigned 16-bit integers in a with corresponding unsigned 16-bit integers in b, producing 2 intermediate unsigned 32-bit results. Sum these 2 results with the corresponding 32-bit integer in src using unsigned saturation, and store the packed 32-bit results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_dpwuuds_epi32)
+#[inline]
+#[target_feature(enable = "avxvnniint16")]
+#[cfg_attr(test, assert_instr(vpdpwuuds))]
+pub unsafe fn _mm256_dpwuuds_epi32(src: __m256i, a: __m256i, b: __m256i) -> __m256i {
+    transmute(vpdpwuuds256(src.as_i32x8(), a.as_i32x8(), b.as_i32x8()))
+}
+
+/// Multiply groups of 2 adjacent pairs of unsigned 16-bit integers in a with corresponding unsigned 16-bit integers in b, producing 2 intermediate unsigned 32-bit results. Sum these 2 results with the corresponding 32-bit integer in src using unsigned saturation, and store the packed 32-bit results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_dpwuuds_epi32)
+#[inline]
+#[target_feature(enable = "avxvnniint16")]
+#[cfg_attr(test, assert_instr(vpdpwuuds))]
+pub unsafe fn _mm_dpwuuds_epi32(src: __m128i, a: __m128i, b: __m128i) -> __m128i {
+    transmute(vpdpwuuds128(src.as_i32x4(), a.as_i32x4(), b.as_i32x4()))
+}
+
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.x86.avx2.vpdpwsud.256"]
+    fn vpdpwsud256(src: i32x8, a: i32x8, b: i32x8) -> i32x8;
+    #[link_name = "llvm.x86.avx2.vpdpwsud.128"]
+    fn vpdpwsud128(src: i32x4, a: i32x4, b: i32x4) -> i32x4;
+
+    #[link_name = "llvm.x86.avx2.vpdpwsuds.256"]
+    fn vpdpwsuds256(src: i32x8, a: i32x8, b: i32x8) -> i32x8;
+    #[link_name = "llvm.x86.avx2.vpdpwsuds.128"]
+    fn vpdpwsuds128(src: i32x4, a: i32x4, b: i32x4) -> i32x4;
+
+    #[link_name = "llvm.x86.avx2.vpdpwusd.256"]
+    fn vpdpwusd256(src: i32x8, a: i32x8, b: i32x8) -> i32x8;
+    #[link_name = "llvm.x86.avx2.vpdpwusd.128"]
+    fn vpdpwusd128(src: i32x4, a: i32x4, b: i32x4) -> i32x4;
+
+    #[link_name = "llvm.x86.avx2.vpdpwusds.256"]
+    fn vpdpwusds256(src: i32x8, a: i32x8, b: i32x8) -> i32x8;
+    #[link_name = "llvm.x86.avx2.vpdpwusds.128"]
+    fn vpdpwusds128(src: i32x4, a: i32x4, b: i32x4) -> i32x4;
+
+    #[link_name = "llvm.x86.avx2.vpdpwuud.256"]
+    fn vpdpwuud256(src: i32x8, a: i32x8, b: i32x8) -> i32x8;
+    #[link_name = "llvm.x86.avx2.vpdpwuud.128"]
+    fn vpdpwuud128(src: i32x4, a: i32x4, b: i32x4) -> i32x4;
+
+    #[link_name = "llvm.x86.avx2.vpdpwuuds.256"]
+    fn vpdpwuuds256(src: i32x8, a: i32x8, b: i32x8) -> i32x8;
+    #[link_name = "llvm.x86.avx2.vpdpwuuds.128"]
+    fn vpdpwuuds128(src: i32x4, a: i32x4, b: i32x4) -> i32x4;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+    use stdarch_test::simd_test;
+
+    #[simd_test(enable = "avxvnniint16")]
+    unsafe fn test_mm256_dpwsud_epi32() {
+        // -1 * 65535 in both 16-bit lanes
+        let src = _mm256_set1_epi32(1);
+        let a = _mm256_set1_epi16(-1);
+        let b = _mm256_set1_epi16(-1);
+        let r = _mm256_dpwsud_epi32(src, a, b);
+        assert_eq_m256i(r, _mm256_set1_epi32(1 - 2 * 65535));
+
+        // -32768 * 32768 in both 16-bit lanes
+        let src = _mm256_set1_epi32(0);
+        let a = _mm256_set1_epi16(i16::MIN);
+        let b = _mm256_set1_epi16(i16::MIN);
+        let r = _mm256_dpwsud_epi32(src, a, b);
+        assert_eq_m256i(r, _mm256_set1_epi32(i32::MIN));
+
+        // -32768 * 65535 in both 16-bit lanes, which overflows `i32`
+        let src = _mm256_set1_epi32(0);
+        let a = _mm256_set1_epi16(i16::MIN);
+        let b = _mm256_set1_epi16(-1);
+        let r = _mm256_dpwsud_epi32(src, a, b);
+        assert_eq_m256i(r, _mm256_set1_epi32(65536));
+
+        let a = _mm256_set1_epi16(1);
+        let b = _mm256_set1_epi16(1);
+        // Wraps around instead of saturating.
+        let src = _mm256_set1_epi32(i32::MAX);
+        let r = _mm256_dpwsud_epi32(src, a, b);
+        assert_eq_m256i(r, _mm256_set1_epi32(i32::MIN + 1));
+    }
+
+    #[simd_test(enable = "avxvnniint16")]
+    unsafe fn test_mm_dpwsud_epi32() {
+        // -1 * 65535 in both 16-bit lanes
+        let src = _mm_set1_epi32(1);
+        let a = _mm_set1_epi16(-1);
+        let b = _mm_set1_epi16(-1);
+        let r = _mm_dpwsud_epi32(src, a, b);
+        assert_eq_m128i(r, _mm_set1_epi32(1 - 2 * 65535));
+
+        // -32768 * 32768 in both 16-bit lanes
+        let src = _mm_set1_epi32(0);
+        let a = _mm_set1_epi16(i16::MIN);
+        let b = _mm_set1_epi16(i16::MIN);
+        let r = _mm_dpwsud_epi32(src, a, b);
+        assert_eq_m128i(r, _mm_set1_epi32(i32::MIN));
+
+        // -32768 * 65535 in both 16-bit lanes, which overflows `i32`
+        let src = _mm_set1_epi32(0);
+        let a = _mm_set1_epi16(i16::MIN);
+        let b = _mm_set1_epi16(-1);
+        let r = _mm_dpwsud_epi32(src, a, b);
+        assert_eq_m128i(r, _mm_set1_epi32(65536));
+
+        let a = _mm_set1_epi16(1);
+        let b = _mm_set1_epi16(1);
+        // Wraps around instead of saturating.
+        let src = _mm_set1_epi32(i32::MAX);
+        let r = _mm_dpwsud_epi32(src, a, b);
+        assert_eq_m128i(r, _mm_set1_epi32(i32::MIN + 1));
+    }
+
+    #[simd_test(enable = "avxvnniint16")]
+    unsafe fn test_mm256_dpwsuds_epi32() {
+        // -1 * 65535 in both 16-bit lanes
+        let src = _mm256_set1_epi32(1);
+        let a = _mm256_set1_epi16(-1);
+        let b = _mm256_set1_epi16(-1);
+        let r = _mm256_dpwsuds_epi32(src, a, b);
+        assert_eq_m256i(r, _mm256_set1_epi32(1 - 2 * 65535));
+
+        // -32768 * 32768 in both 16-bit lanes
+        let src = _mm256_set1_epi32(0);
+        let a = _mm256_set1_epi16(i16::MIN);
+        let b = _mm256_set1_epi16(i16::MIN);
+        let r = _mm256_dpwsuds_epi32(src, a, b);
+        assert_eq_m256i(r, _mm256_set1_epi32(i32::MIN));
+
+        // -32768 * 65535 in both 16-bit lanes, which overflows `i32`
+        let src = _mm256_set1_epi32(0);
+        let a = _mm256_set1_epi16(i16::MIN);
+        let b = _mm256_set1_epi16(-1);
+        let r = _mm256_dpwsuds_epi32(src, a, b);
+        assert_eq_m256i(r, _mm256_set1_epi32(i32::MIN));
+
+        let a = _mm256_set1_epi16(1);
+        let b = _mm256_set1_epi16(1);
+        // Saturates at the upper bound of `i32`.
+        let src = _mm256_set1_epi32(i32::MAX);
+        let r = _mm256_dpwsuds_epi32(src, a, b);
+        assert_eq_m256i(r, src);
+        // Saturates at the lower bound of `i32`.
+        let src = _mm256_set1_epi32(i32::MIN);
+        let a = _mm256_set1_epi16(-1);
+        let b = _mm256_set1_epi16(1);
+        let r = _mm256_dpwsuds_epi32(src, a, b);
+        assert_eq_m256i(r, src);
+    }
+
+    #[simd_test(enable = "avxvnniint16")]
+    unsafe fn test_mm_dpwsuds_epi32() {
+        // -1 * 65535 in both 16-bit lanes
+        let src = _mm_set1_epi32(1);
+        let a = _mm_set1_epi16(-1);
+        let b = _mm_set1_epi16(-1);
+        let r = _mm_dpwsuds_epi32(src, a, b);
+        assert_eq_m128i(r, _mm_set1_epi32(1 - 2 * 65535));
+
+        // -32768 * 32768 in both 16-bit lanes
+        let src = _mm_set1_epi32(0);
+        let a = _mm_set1_epi16(i16::MIN);
+        let b = _mm_set1_epi16(i16::MIN);
+        let r = _mm_dpwsuds_epi32(src, a, b);
+        assert_eq_m128i(r, _mm_set1_epi32(i32::MIN));
+
+        // -32768 * 65535 in both 16-bit lanes, which overflows `i32`
+        let src = _mm_set1_epi32(0);
+        let a = _mm_set1_epi16(i16::MIN);
+        let b = _mm_set1_epi16(-1);
+        let r = _mm_dpwsuds_epi32(src, a, b);
+        assert_eq_m128i(r, _mm_set1_epi32(i32::MIN));
+
+        let a = _mm_set1_epi16(1);
+        let b = _mm_set1_epi16(1);
+        // Saturates at the upper bound of `i32`.
+        let src = _mm_set1_epi32(i32::MAX);
+        let r = _mm_dpwsuds_epi32(src, a, b);
+        assert_eq_m128i(r, src);
+        // Saturates at the lower bound of `i32`.
+        let src = _mm_set1_epi32(i32::MIN);
+        let a = _mm_set1_epi16(-1);
+        let b = _mm_set1_epi16(1);
+        let r = _mm_dpwsuds_epi32(src, a, b);
+        assert_eq_m128i(r, src);
+    }
+
+    #[simd_test(enable = "avxvnniint16")]
+    unsafe fn test_mm256_dpwusd_epi32() {
+        // 65535 * -1 in both 16-bit lanes
+        let src = _mm256_set1_epi32(1);
+        let a = _mm256_set1_epi16(-1);
+        let b = _mm256_set1_epi16(-1);
+        let r = _mm256_dpwusd_epi32(src, a, b);
+        assert_eq_m256i(r, _mm256_set1_epi32(1 - 2 * 65535));
+
+        // 32768 * -32768 in both 16-bit lanes
+        let src = _mm256_set1_epi32(0);
+        let a = _mm256_set1_epi16(i16::MIN);
+        let b = _mm256_set1_epi16(i16::MIN);
+        let r = _mm256_dpwusd_epi32(src, a, b);
+        assert_eq_m256i(r, _mm256_set1_epi32(i32::MIN));
+
+        // 65535 * -32768 in both 16-bit lanes, which overflows `i32`
+        let src = _mm256_set1_epi32(0);
+        let a = _mm256_set1_epi16(-1);
+        let b = _mm256_set1_epi16(i16::MIN);
+        let r = _mm256_dpwusd_epi32(src, a, b);
+        assert_eq_m256i(r, _mm256_set1_epi32(65536));
+
+        let a = _mm256_set1_epi16(1);
+        let b = _mm256_set1_epi16(1);
+        // Wraps around instead of saturating.
+        let src = _mm256_set1_epi32(i32::MAX);
+        let r = _mm256_dpwusd_epi32(src, a, b);
+        assert_eq_m256i(r, _mm256_set1_epi32(i32::MIN + 1));
+    }
+
+    #[simd_test(enable = "avxvnniint16")]
+    unsafe fn test_mm_dpwusd_epi32() {
+        // 65535 * -1 in both 16-bit lanes
+        let src = _mm_set1_epi32(1);
+        let a = _mm_set1_epi16(-1);
+        let b = _mm_set1_epi16(-1);
+        let r = _mm_dpwusd_epi32(src, a, b);
+        assert_eq_m128i(r, _mm_set1_epi32(1 - 2 * 65535));
+
+        // 32768 * -32768 in both 16-bit lanes
+        let src = _mm_set1_epi32(0);
+        let a = _mm_set1_epi16(i16::MIN);
+        let b = _mm_set1_epi16(i16::MIN);
+        let r = _mm_dpwusd_epi32(src, a, b);
+        assert_eq_m128i(r, _mm_set1_epi32(i32::MIN));
+
+        // 65535 * -32768 in both 16-bit lanes, which overflows `i32`
+        let src = _mm_set1_epi32(0);
+        let a = _mm_set1_epi16(-1);
+        let b = _mm_set1_epi16(i16::MIN);
+        let r = _mm_dpwusd_epi32(src, a, b);
+        assert_eq_m128i(r, _mm_set1_epi32(65536));
+
+        let a = _mm_set1_epi16(1);
+        let b = _mm_set1_epi16(1);
+        // Wraps around instead of saturating.
+        let src = _mm_set1_epi32(i32::MAX);
+        let r = _mm_dpwusd_epi32(src, a, b);
+        assert_eq_m128i(r, _mm_set1_epi32(i32::MIN + 1));
+    }
+
+    #[simd_test(enable = "avxvnniint16")]
+    unsafe fn test_mm256_dpwusds_epi32() {
+        // 65535 * -1 in both 16-bit lanes
+        let src = _mm256_set1_epi32(1);
+        let a = _mm256_set1_epi16(-1);
+        let b = _mm256_set1_epi16(-1);
+        let r = _mm256_dpwusds_epi32(src, a, b);
+        assert_eq_m256i(r, _mm256_set1_epi32(1 - 2 * 65535));
+
+        // 32768 * -32768 in both 16-bit lanes
+        let src = _mm256_set1_epi32(0);
+        let a = _mm256_set1_epi16(i16::MIN);
+        let b = _mm256_set1_epi16(i16::MIN);
+        let r = _mm256_dpwusds_epi32(src, a, b);
+        assert_eq_m256i(r, _mm256_set1_epi32(i32::MIN));
+
+        // 65535 * -32768 in both 16-bit lanes, which overflows `i32`
+        let src = _mm256_set1_epi32(0);
+        let a = _mm256_set1_epi16(-1);
+        let b = _mm256_set1_epi16(i16::MIN);
+        let r = _mm256_dpwusds_epi32(src, a, b);
+        assert_eq_m256i(r, _mm256_set1_epi32(i32::MIN));
+
+        let a = _mm256_set1_epi16(1);
+        let b = _mm256_set1_epi16(1);
+        // Saturates at the upper bound of `i32`.
+        let src = _mm256_set1_epi32(i32::MAX);
+        let r = _mm256_dpwusds_epi32(src, a, b);
+        assert_eq_m256i(r, src);
+        // Saturates at the lower bound of `i32`.
+        let src = _mm256_set1_epi32(i32::MIN);
+        let a = _mm256_set1_epi16(1);
+        let b = _mm256_set1_epi16(-1);
+        let r = _mm256_dpwusds_epi32(src, a, b);
+        assert_eq_m256i(r, src);
+    }
+
+    #[simd_test(enable = "avxvnniint16")]
+    unsafe fn test_mm_dpwusds_epi32() {
+        // 65535 * -1 in both 16-bit lanes
+        let src = _mm_set1_epi32(1);
+        let a = _mm_set1_epi16(-1);
+        let b = _mm_set1_epi16(-1);
+        let r = _mm_dpwusds_epi32(src, a, b);
+        assert_eq_m128i(r, _mm_set1_epi32(1 - 2 * 65535));
+
+        // 32768 * -32768 in both 16-bit lanes
+        let src = _mm_set1_epi32(0);
+        let a = _mm_set1_epi16(i16::MIN);
+        let b = _mm_set1_epi16(i16::MIN);
+        let r = _mm_dpwusds_epi32(src, a, b);
+        assert_eq_m128i(r, _mm_set1_epi32(i32::MIN));
+
+        // 65535 * -32768 in both 16-bit lanes, which overflows `i32`
+        let src = _mm_set1_epi32(0);
+        let a = _mm_set1_epi16(-1);
+        let b = _mm_set1_epi16(i16::MIN);
+        let r = _mm_dpwusds_epi32(src, a, b);
+        assert_eq_m128i(r, _mm_set1_epi32(i32::MIN));
+
+        let a = _mm_set1_epi16(1);
+        let b = _mm_set1_epi16(1);
+        // Saturates at the upper bound of `i32`.
+        let src = _mm_set1_epi32(i32::MAX);
+        let r = _mm_dpwusds_epi32(src, a, b);
+        assert_eq_m128i(r, src);
+        // Saturates at the lower bound of `i32`.
+        let src = _mm_set1_epi32(i32::MIN);
+        let a = _mm_set1_epi16(1);
+        let b = _mm_set1_epi16(-1);
+        let r = _mm_dpwusds_epi32(src, a, b);
+        assert_eq_m128i(r, src);
+    }
+
+    #[simd_test(enable = "avxvnniint16")]
+    unsafe fn test_mm256_dpwuud_epi32() {
+        // 1 * 1 in both 16-bit lanes
+        let src = _mm256_set1_epi32(1);
+        let a = _mm256_set1_epi16(1);
+        let b = _mm256_set1_epi16(1);
+        let r = _mm256_dpwuud_epi32(src, a, b);
+        assert_eq_m256i(r, _mm256_set1_epi32(3));
+
+        // 32768 * 32768 in both 16-bit lanes
+        let src = _mm256_set1_epi32(0);
+        let a = _mm256_set1_epi16(i16::MIN);
+        let b = _mm256_set1_epi16(i16::MIN);
+        let r = _mm256_dpwuud_epi32(src, a, b);
+        assert_eq_m256i(r, _mm256_set1_epi32(i32::MIN));
+
+        let a = _mm256_set1_epi16(1);
+        let b = _mm256_set1_epi16(1);
+        // Wraps around instead of saturating.
+        let src = _mm256_set1_epi32(i32::MAX);
+        let r = _mm256_dpwuud_epi32(src, a, b);
+        assert_eq_m256i(r, _mm256_set1_epi32(i32::MIN + 1));
+    }
+
+    #[simd_test(enable = "avxvnniint16")]
+    unsafe fn test_mm_dpwuud_epi32() {
+        // 1 * 1 in both 16-bit lanes
+        let src = _mm_set1_epi32(1);
+        let a = _mm_set1_epi16(1);
+        let b = _mm_set1_epi16(1);
+        let r = _mm_dpwuud_epi32(src, a, b);
+        assert_eq_m128i(r, _mm_set1_epi32(3));
+
+        // 32768 * 32768 in both 16-bit lanes
+        let src = _mm_set1_epi32(0);
+        let a = _mm_set1_epi16(i16::MIN);
+        let b = _mm_set1_epi16(i16::MIN);
+        let r = _mm_dpwuud_epi32(src, a, b);
+        assert_eq_m128i(r, _mm_set1_epi32(i32::MIN));
+
+        let a = _mm_set1_epi16(1);
+        let b = _mm_set1_epi16(1);
+        // Wraps around instead of saturating.
+        let src = _mm_set1_epi32(i32::MAX);
+        let r = _mm_dpwuud_epi32(src, a, b);
+        assert_eq_m128i(r, _mm_set1_epi32(i32::MIN + 1));
+    }
+
+    #[simd_test(enable = "avxvnniint16")]
+    unsafe fn test_mm256_dpwuuds_epi32() {
+        // 1 * 1 in both 16-bit lanes
+        let src = _mm256_set1_epi32(1);
+        let a = _mm256_set1_epi16(1);
+        let b = _mm256_set1_epi16(1);
+        let r = _mm256_dpwuuds_epi32(src, a, b);
+        assert_eq_m256i(r, _mm256_set1_epi32(3));
+
+        // 32768 * 32768 in both 16-bit lanes
+        let src = _mm256_set1_epi32(0);
+        let a = _mm256_set1_epi16(i16::MIN);
+        let b = _mm256_set1_epi16(i16::MIN);
+        let r = _mm256_dpwuuds_epi32(src, a, b);
+        assert_eq_m256i(r, _mm256_set1_epi32(i32::MIN));
+
+        let a = _mm256_set1_epi16(1);
+        let b = _mm256_set1_epi16(1);
+        // Saturates at the upper bound of `u32`, but not of `i32`.
+        let src = _mm256_set1_epi32(-1);
+        let r = _mm256_dpwuuds_epi32(src, a, b);
+        assert_eq_m256i(r, src);
+        let src = _mm256_set1_epi32(i32::MAX);
+        let r = _mm256_dpwuuds_epi32(src, a, b);
+        assert_eq_m256i(r, _mm256_set1_epi32(i32::MIN + 1));
+    }
+
+    #[simd_test(enable = "avxvnniint16")]
+    unsafe fn test_mm_dpwuuds_epi32() {
+        // 1 * 1 in both 16-bit lanes
+        let src = _mm_set1_epi32(1);
+        let a = _mm_set1_epi16(1);
+        let b = _mm_set1_epi16(1);
+        let r = _mm_dpwuuds_epi32(src, a, b);
+        assert_eq_m128i(r, _mm_set1_epi32(3));
+
+        // 32768 * 32768 in both 16-bit lanes
+        let src = _mm_set1_epi32(0);
+        let a = _mm_set1_epi16(i16::MIN);
+        let b = _mm_set1_epi16(i16::MIN);
+        let r = _mm_dpwuuds_epi32(src, a, b);
+        assert_eq_m128i(r, _mm_set1_epi32(i32::MIN));
+
+        let a = _mm_set1_epi16(1);
+        let b = _mm_set1_epi16(1);
+        // Saturates at the upper bound of `u32`, but not of `i32`.
+        let src = _mm_set1_epi32(-1);
+        let r = _mm_dpwuuds_epi32(src, a, b);
+        assert_eq_m128i(r, src);
+        let src = _mm_set1_epi32(i32::MAX);
+        let r = _mm_dpwuuds_epi32(src, a, b);
+        assert_eq_m128i(r, _mm_set1_epi32(i32::MIN + 1));
+    }
+}
@@ -0,0 +1,489 @@
+//! AVX-VNNI-INT8 intrinsics
+//!
+//! Dot products of 8-bit integers with any combination of signedness,
+//! accumulated into 32-bit integers.
+
+use crate::{
+    core_arch::{simd::*, x86::*},
+    mem::transmute,
+};
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Multiply groups of 4 adjacent pairs of signed 8-bit integers in a with corresponding signed 8-bit integers in b, producing 4 intermediate signed 16-bit results. Sum these 4 results with the corresponding 32-bit integer in src, and store the packed 32-bit results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_dpbssd_epi32)
+#[inline]
+#[target_feature(enable = "avxvnniint8")]
+#[cfg_attr(test, assert_instr(vpdpbssd))]
+pub unsafe fn _mm256_dpbssd_epi32(src: __m256i, a: __m256i, b: __m256i) -> __m256i {
+    transmute(vpdpbssd256(src.as_i32x8(), a.as_i32x8(), b.as_i32x8()))
+}
+
+/// Multiply groups of 4 adjacent pairs of signed 8-bit integers in a with corresponding signed 8-bit integers in b, producing 4 intermediate signed 16-bit results. Sum these 4 results with the corresponding 32-bit integer in src, and store the packed 32-bit results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_dpbssd_epi32)
+#[inline]
+#[target_feature(enable = "avxvnniint8")]
+#[cfg_attr(test, assert_instr(vpdpbssd))]
+pub unsafe fn _mm_dpbssd_epi32(src: __m128i, a: __m128i, b: __m128i) -> __m128i {
+    transmute(vpdpbssd128(src.as_i32x4(), a.as_i32x4(), b.as_i32x4()))
+}
+
+/// Multiply groups of 4 adjacent pairs of signed 8-bit integers in a with corresponding signed 8-bit integers in b, producing 4 intermediate signed 16-bit results. Sum these 4 results with the corresponding 32-bit integer in src using signed saturation, and store the packed 32-bit results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_dpbssds_epi32)
+#[inline]
+#[target_feature(enable = "avxvnniint8")]
+#[cfg_attr(test, assert_instr(vpdpbssds))]
+pub unsafe fn _mm256_dpbssds_epi32(src: __m256i, a: __m256i, b: __m256i) -> __m256i {
+    transmute(vpdpbssds256(src.as_i32x8(), a.as_i32x8(), b.as_i32x8()))
+}
+
+/// Multiply groups of 4 adjacent pairs of signed 8-bit integers in a with corresponding signed 8-bit integers in b, producing 4 intermediate signed 16-bit results. Sum these 4 results with the corresponding 32-bit integer in src using signed saturation, and store the packed 32-bit results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_dpbssds_epi32)
+#[inline]
+#[target_feature(enable = "avxvnniint8")]
+#[cfg_attr(test, assert_instr(vpdpbssds))]
+pub unsafe fn _mm_dpbssds_epi32(src: __m128i, a: __m128i, b: __m128i) -> __m128i {
+    transmute(vpdpbssds128(src.as_i32x4(), a.as_i32x4(), b.as_i32x4()))
+}
+
+/// Multiply groups of 4 adjacent pairs of signed 8-bit integers in a with corresponding unsigned 8-bit integers in b, producing 4 intermediate signed 16-bit results. Sum these 4 results with the corresponding 32-bit integer in src, and store the packed 32-bit results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_dpbsud_epi32)
+#[inline]
+#[target_feature(enable = "avxvnniint8")]
+#[cfg_attr(test, assert_instr(vpdpbsud))]
+pub unsafe fn _mm256_dpbsud_epi32(src: __m256i, a: __m256i, b: __m256i) -> __m256i {
+    transmute(vpdpbsud256(src.as_i32x8(), a.as_i32x8(), b.as_i32x8()))
+}
+
+/// Multiply groups of 4 adjacent pairs of signed 8-bit integers in a with corresponding unsigned 8-bit integers in b, producing 4 intermediate signed 16-bit results. Sum these 4 results with the corresponding 32-bit integer in src, and store the packed 32-bit results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_dpbsud_epi32)
+#[inline]
+#[target_feature(enable = "avxvnniint8")]
+#[cfg_attr(test, assert_instr(vpdpbsud))]
+pub unsafe fn _mm_dpbsud_epi32(src: __m128i, a: __m128i, b: __m128i) -> __m128i {
+    transmute(vpdpbsud128(src.as_i32x4(), a.as_i32x4(), b.as_i32x4()))
+}
+
+/// Multiply groups of 4 adjacent pairs of signed 8-bit integers in a with corresponding unsigned 8-bit integers in b, producing 4 intermediate signed 16-bit results. Sum these 4 results with the corresponding 32-bit integer in src using signed saturation, and store the packed 32-bit results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_dpbsuds_epi32)
+#[inline]
+#[target_feature(enable = "avxvnniint8")]
+#[cfg_attr(test, assert_instr(vpdpbsuds))]
+pub unsafe fn _mm256_dpbsuds_epi32(src: __m256i, a: __m256i, b: __m256i) -> __m256i {
+    transmute(vpdpbsuds256(src.as_i32x8(), a.as_i32x8(), b.as_i32x8()))
+}
+
+/// Multiply groups of 4 adjacent pairs of signed 8-bit integers in a with corresponding unsigned 8-bit integers in b, producing 4 intermediate signed 16-bit results. Sum these 4 results with the corresponding 32-bit integer in src using signed saturation, and store the packed 32-bit results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_dpbsuds_epi32)
+#[inline]
+#[target_feature(enable = "avxvnniint8")]
+#[cfg_attr(test, assert_instr(vpdpbsuds))]
+pub unsafe fn _mm_dpbsuds_epi32(src: __m128i, a: __m128i, b: __m128i) -> __m128i {
+    transmute(vpdpbsuds128(src.as_i32x4(), a.as_i32x4(), b.as_i32x4()))
+}
+
+/// Multiply groups of 4 adjacent pairs of unsigned 8-bit integers in a with corresponding unsigned 8-bit integers in b, producing 4 intermediate unsigned 16-bit results. Sum these 4 results with the corresponding 32-bit integer in src, and store the packed 32-bit results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_dpbuud_epi32)
+#[inline]
+#[target_feature(enable = "avxvnniint8")]
+#[cfg_attr(test, assert_instr(vpdpbuud))]
+pub unsafe fn _mm256_dpbuud_epi32(src: __m256i, a: __m256i, b: __m256i) -> __m256i {
+    transmute(vpdpbuud256(src.as_i32x8(), a.as_i32x8(), b.as_i32x8()))
+}
+
+/// Multiply groups of 4 adjacent pairs of unsigned 8-bit integers in a with corresponding unsigned 8-bit integers in b, producing 4 intermediate unsigned 16-bit results. Sum these 4 results with the corresponding 32-bit integer in src, and store the packed 32-bit results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_dpbuud_epi32)
+#[inline]
+#[target_feature(enable = "avxvnniint8")]
+#[cfg_attr(test, assert_instr(vpdpbuud))]
+pub unsafe fn _mm_dpbuud_epi32(src: __m128i, a: __m128i, b: __m128i) -> __m128i {
+    transmute(vpdpbuud128(src.as_i32x4(), a.as_i32x4(), b.as_i32x4()))
+}
+
+/// Multiply groups of 4 adjacent pairs of unsigned 8-bit integers in a with corresponding unsigned 8-bit integers in b, producing 4 intermediate unsigned 16-bit results. Sum these 4 results with the corresponding 32-bit integer in src using unsigned saturation, and store the packed 32-bit results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_dpbuuds_epi32)
+#[inline]
+#[target_feature(enable = "avxvnniint8")]
+#[cfg_attr(test, assert_instr(vpdpbuuds))]
+pub unsafe fn _mm256_dpbuuds_epi32(src: __m256i, a: __m256i, b: __m256i) -> __m256i {
+    transmute(vpdpbuuds256(src.as_i32x8(), a.as_i32x8(), b.as_i32x8()))
+}
+
+/// Multiply groups of 4 adjacent pairs of unsigned 8-bit integers in a with corresponding unsigned 8-bit integers in b, producing 4 intermediate unsigned 16-bit results. Sum these 4 results with the corresponding 32-bit integer in src using unsigned saturation, and store the packed 32-bit results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_dpbuuds_epi32)
+#[inline]
+#[target_feature(enable = "avxvnniint8")]
+#[cfg_attr(test, assert_instr(vpdpbuuds))]
+pub unsafe fn _mm_dpbuuds_epi32(src: __m128i, a: __m128i, b: __m128i) -> __m128i {
+    transmute(vpdpbuuds128(src.as_i32x4(), a.as_i32x4(), b.as_i32x4()))
+}
+
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.x86.avx2.vpdpbssd.256"]
+    fn vpdpbssd256(src: i32x8, a: i32x8, b: i32x8) -> i32x8;
+    #[link_name = "llvm.x86.avx2.vpdpbssd.128"]
+    fn vpdpbssd128(src: i32x4, a: i32x4, b: i32x4) -> i32x4;
+
+    #[link_name = "llvm.x86.avx2.vpdpbssds.256"]
+    fn vpdpbssds256(src: i32x8, a: i32x8, b: i32x8) -> i32x8;
+    #[link_name = "llvm.x86.avx2.vpdpbssds.128"]
+    fn vpdpbssds128(src: i32x4, a: i32x4, b: i32x4) -> i32x4;
+
+    #[link_name = "llvm.x86.avx2.vpdpbsud.256"]
+    fn vpdpbsud256(src: i32x8, a: i32x8, b: i32x8) -> i32x8;
+    #[link_name = "llvm.x86.avx2.vpdpbsud.128"]
+    fn vpdpbsud128(src: i32x4, a: i32x4, b: i32x4) -> i32x4;
+
+    #[link_name = "llvm.x86.avx2.vpdpbsuds.256"]
+    fn vpdpbsuds256(src: i32x8, a: i32x8, b: i32x8) -> i32x8;
+    #[link_name = "llvm.x86.avx2.vpdpbsuds.128"]
+    fn vpdpbsuds128(src: i32x4, a: i32x4, b: i32x4) -> i32x4;
+
+    #[link_name = "llvm.x86.avx2.vpdpbuud.256"]
+    fn vpdpbuud256(src: i32x8, a: i32x8, b: i32x8) -> i32x8;
+    #[link_name = "llvm.x86.avx2.vpdpbuud.128"]
+    fn vpdpbuud128(src: i32x4, a: i32x4, b: i32x4) -> i32x4;
+
+    #[link_name = "llvm.x86.avx2.vpdpbuuds.256"]
+    fn vpdpbuuds256(src: i32x8, a: i32x8, b: i32x8) -> i32x8;
+    #[link_name = "llvm.x86.avx2.vpdpbuuds.128"]
+    fn vpdpbuuds128(src: i32x4, a: i32x4, b: i32x4) -> i32x4;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+    use stdarch_test::simd_test;
+
+    #[simd_test(enable = "avxvnniint8")]
+    unsafe fn test_mm256_dpbssd_epi32() {
+        // -1 * -1 in each of the 4 byte lanes
+        let src = _mm256_set1_epi32(1);
+        let a = _mm256_set1_epi8(-1);
+        let b = _mm256_set1_epi8(-1);
+        let r = _mm256_dpbssd_epi32(src, a, b);
+        assert_eq_m256i(r, _mm256_set1_epi32(5));
+
+        // -128 * -128 in each of the 4 byte lanes
+        let src = _mm256_set1_epi32(0);
+        let a = _mm256_set1_epi8(i8::MIN);
+        let b = _mm256_set1_epi8(i8::MIN);
+        let r = _mm256_dpbssd_epi32(src, a, b);
+        assert_eq_m256i(r, _mm256_set1_epi32(4 * 16384));
+
+        let a = _mm256_set1_epi8(1);
+        let b = _mm256_set1_epi8(1);
+        // Wraps around instead of saturating.
+        let src = _mm256_set1_epi32(i32::MAX);
+        let r = _mm256_dpbssd_epi32(src, a, b);
+        assert_eq_m256i(r, _mm256_set1_epi32(i32::MIN + 3));
+    }
+
+    #[simd_test(enable = "avxvnniint8")]
+    unsafe fn test_mm_dpbssd_epi32() {
+        // -1 * -1 in each of the 4 byte lanes
+        let src = _mm_set1_epi32(1);
+        let a = _mm_set1_epi8(-1);
+        let b = _mm_set1_epi8(-1);
+        let r = _mm_dpbssd_epi32(src, a, b);
+        assert_eq_m128i(r, _mm_set1_epi32(5));
+
+        // -128 * -128 in each of the 4 byte lanes
+        let src = _mm_set1_epi32(0);
+        let a = _mm_set1_epi8(i8::MIN);
+        let b = _mm_set1_epi8(i8::MIN);
+        let r = _mm_dpbssd_epi32(src, a, b);
+        assert_eq_m128i(r, _mm_set1_epi32(4 * 16384));
+
+        let a = _mm_set1_epi8(1);
+        let b = _mm_set1_epi8(1);
+        // Wraps around instead of saturating.
+        let src = _mm_set1_epi32(i32::MAX);
+        let r = _mm_dpbssd_epi32(src, a, b);
+        assert_eq_m128i(r, _mm_set1_epi32(i32::MIN + 3));
+    }
+
+    #[simd_test(enable = "avxvnniint8")]
+    unsafe fn test_mm256_dpbssds_epi32() {
+        // -1 * -1 in each of the 4 byte lanes
+        let src = _mm256_set1_epi32(1);
+        let a = _mm256_set1_epi8(-1);
+        let b = _mm256_set1_epi8(-1);
+        let r = _mm256_dpbssds_epi32(src, a, b);
+        assert_eq_m256i(r, _mm256_set1_epi32(5));
+
+        // -128 * -128 in each of the 4 byte lanes
+        let src = _mm256_set1_epi32(0);
+        let a = _mm256_set1_epi8(i8::MIN);
+        let b = _mm256_set1_epi8(i8::MIN);
+        let r = _mm256_dpbssds_epi32(src, a, b);
+        assert_eq_m256i(r, _mm256_set1_epi32(4 * 16384));
+
+        let a = _mm256_set1_epi8(1);
+        let b = _mm256_set1_epi8(1);
+        // Saturates at the upper bound of `i32`.
+        let src = _mm256_set1_epi32(i32::MAX);
+        let r = _mm256_dpbssds_epi32(src, a, b);
+        assert_eq_m256i(r, src);
+        // Saturates at the lower bound of `i32`.
+        let src = _mm256_set1_epi32(i32::MIN);
+        let a = _mm256_set1_epi8(-1);
+        let b = _mm256_set1_epi8(-1);
+        let r = _mm256_dpbssds_epi32(src, a, b);
+        assert_eq_m256i(r, src);
+    }
+
+    #[simd_test(enable = "avxvnniint8")]
+    unsafe fn test_mm_dpbssds_epi32() {
+        // -1 * -1 in each of the 4 byte lanes
+        let src = _mm_set1_epi32(1);
+        let a = _mm_set1_epi8(-1);
+        let b = _mm_set1_epi8(-1);
+        let r = _mm_dpbssds_epi32(src, a, b);
+        assert_eq_m128i(r, _mm_set1_epi32(5));
+
+        // -128 * -128 in each of the 4 byte lanes
+        let src = _mm_set1_epi32(0);
+        let a = _mm_set1_epi8(i8::MIN);
+        let b = _mm_set1_epi8(i8::MIN);
+        let r = _mm_dpbssds_epi32(src, a, b);
+        assert_eq_m128i(r, _mm_set1_epi32(4 * 16384));
+
+        let a = _mm_set1_epi8(1);
+        let b = _mm_set1_epi8(1);
+        // Saturates at the upper bound of `i32`.
+        let src = _mm_set1_epi32(i32::MAX);
+        let r = _mm_dpbssds_epi32(src, a, b);
+        assert_eq_m128i(r, src);
+        // Saturates at the lower bound of `i32`.
+        let src = _mm_set1_epi32(i32::MIN);
+        let a = _mm_set1_epi8(-1);
+        let b = _mm_set1_epi8(-1);
+        let r = _mm_dpbssds_epi32(src, a, b);
+        assert_eq_m128i(r, src);
+    }
+
+    #[simd_test(enable = "avxvnniint8")]
+    unsafe fn test_mm256_dpbsud_epi32() {
+        // -1 * 255 in each of the 4 byte lanes
+        let src = _mm256_set1_epi32(1);
+        let a = _mm256_set1_epi8(-1);
+        let b = _mm256_set1_epi8(-1);
+        let r = _mm256_dpbsud_epi32(src, a, b);
+        assert_eq_m256i(r, _mm256_set1_epi32(1 - 4 * 255));
+
+        // -128 * 128 in each of the 4 byte lanes
+        let src = _mm256_set1_epi32(0);
+        let a = _mm256_set1_epi8(i8::MIN);
+        let b = _mm256_set1_epi8(i8::MIN);
+        let r = _mm256_dpbsud_epi32(src, a, b);
+        assert_eq_m256i(r, _mm256_set1_epi32(-4 * 16384));
+
+        let a = _mm256_set1_epi8(1);
+        let b = _mm256_set1_epi8(1);
+        // Wraps around instead of saturating.
+        let src = _mm256_set1_epi32(i32::MAX);
+        let r = _mm256_dpbsud_epi32(src, a, b);
+        assert_eq_m256i(r, _mm256_set1_epi32(i32::MIN + 3));
+    }
+
+    #[simd_test(enable = "avxvnniint8")]
+    unsafe fn test_mm_dpbsud_epi32() {
+        // -1 * 255 in each of the 4 byte lanes
+        let src = _mm_set1_epi32(1);
+        let a = _mm_set1_epi8(-1);
+        let b = _mm_set1_epi8(-1);
+        let r = _mm_dpbsud_epi32(src, a, b);
+        assert_eq_m128i(r, _mm_set1_epi32(1 - 4 * 255));
+
+        // -128 * 128 in each of the 4 byte lanes
+        let src = _mm_set1_epi32(0);
+        let a = _mm_set1_epi8(i8::MIN);
+        let b = _mm_set1_epi8(i8::MIN);
+        let r = _mm_dpbsud_epi32(src, a, b);
+        assert_eq_m128i(r, _mm_set1_epi32(-4 * 16384));
+
+        let a = _mm_set1_epi8(1);
+        let b = _mm_set1_epi8(1);
+        // Wraps around instead of saturating.
+        let src = _mm_set1_epi32(i32::MAX);
+        let r = _mm_dpbsud_epi32(src, a, b);
+        assert_eq_m128i(r, _mm_set1_epi32(i32::MIN + 3));
+    }
+
+    #[simd_test(enable = "avxvnniint8")]
+    unsafe fn test_mm256_dpbsuds_epi32() {
+        // -1 * 255 in each of the 4 byte lanes
+        let src = _mm256_set1_epi32(1);
+        let a = _mm256_set1_epi8(-1);
+        let b = _mm256_set1_epi8(-1);
+        let r = _mm256_dpbsuds_epi32(src, a, b);
+        assert_eq_m256i(r, _mm256_set1_epi32(1 - 4 * 255));
+
+        // -128 * 128 in each of the 4 byte lanes
+        let src = _mm256_set1_epi32(0);
+        let a = _mm256_set1_epi8(i8::MIN);
+        let b = _mm256_set1_epi8(i8::MIN);
+        let r = _mm256_dpbsuds_epi32(src, a, b);
+        assert_eq_m256i(r, _mm256_set1_epi32(-4 * 16384));
+
+        let a = _mm256_set1_epi8(1);
+        let b = _mm256_set1_epi8(1);
+        // Saturates at the upper bound of `i32`.
+        let src = _mm256_set1_epi32(i32::MAX);
+        let r = _mm256_dpbsuds_epi32(src, a, b);
+        assert_eq_m256i(r, src);
+        // Saturates at the lower bound of `i32`.
+        let src = _mm256_set1_epi32(i32::MIN);
+        let a = _mm256_set1_epi8(-1);
+        let b = _mm256_set1_epi8(1);
+        let r = _mm256_dpbsuds_epi32(src, a, b);
+        assert_eq_m256i(r, src);
+    }
+
+    #[simd_test(enable = "avxvnniint8")]
+    unsafe fn test_mm_dpbsuds_epi32() {
+        // -1 * 255 in each of the 4 byte lanes
+        let src = _mm_set1_epi32(1);
+        let a = _mm_set1_epi8(-1);
+        let b = _mm_set1_epi8(-1);
+        let r = _mm_dpbsuds_epi32(src, a, b);
+        assert_eq_m128i(r, _mm_set1_epi32(1 - 4 * 255));
+
+        // -128 * 128 in each of the 4 byte lanes
+        let src = _mm_set1_epi32(0);
+        let a = _mm_set1_epi8(i8::MIN);
+        let b = _mm_set1_epi8(i8::MIN);
+        let r = _mm_dpbsuds_epi32(src, a, b);
+        assert_eq_m128i(r, _mm_set1_epi32(-4 * 16384));
+
+        let a = _mm_set1_epi8(1);
+        let b = _mm_set1_epi8(1);
+        // Saturates at the upper bound of `i32`.
+        let src = _mm_set1_epi32(i32::MAX);
+        let r = _mm_dpbsuds_epi32(src, a, b);
+        assert_eq_m128i(r, src);
+        // Saturates at the lower bound of `i32`.
+        let src = _mm_set1_epi32(i32::MIN);
+        let a = _mm_set1_epi8(-1);
+        let b = _mm_set1_epi8(1);
+        let r = _mm_dpbsuds_epi32(src, a, b);
+        assert_eq_m128i(r, src);
+    }
+
+    #[simd_test(enable = "avxvnniint8")]
+    unsafe fn test_mm256_dpbuud_epi32() {
+        // 255 * 255 in each of the 4 byte lanes
+        let src = _mm256_set1_epi32(1);
+        let a = _mm256_set1_epi8(-1);
+        let b = _mm256_set1_epi8(-1);
+        let r = _mm256_dpbuud_epi32(src, a, b);
+        assert_eq_m256i(r, _mm256_set1_epi32(1 + 4 * 65025));
+
+        // 128 * 128 in each of the 4 byte lanes
+        let src = _mm256_set1_epi32(0);
+        let a = _mm256_set1_epi8(i8::MIN);
+        let b = _mm256_set1_epi8(i8::MIN);
+        let r = _mm256_dpbuud_epi32(src, a, b);
+        assert_eq_m256i(r, _mm256_set1_epi32(4 * 16384));
+
+        let a = _mm256_set1_epi8(1);
+        let b = _mm256_set1_epi8(1);
+        // Wraps around instead of saturating.
+        let src = _mm256_set1_epi32(i32::MAX);
+        let r = _mm256_dpbuud_epi32(src, a, b);
+        assert_eq_m256i(r, _mm256_set1_epi32(i32::MIN + 3));
+    }
+
+    #[simd_test(enable = "avxvnniint8")]
+    unsafe fn test_mm_dpbuud_epi32() {
+        // 255 * 255 in each of the 4 byte lanes
+        let src = _mm_set1_epi32(1);
+        let a = _mm_set1_epi8(-1);
+        let b = _mm_set1_epi8(-1);
+        let r = _mm_dpbuud_epi32(src, a, b);
+        assert_eq_m128i(r, _mm_set1_epi32(1 + 4 * 65025));
+
+        // 128 * 128 in each of the 4 byte lanes
+        let src = _mm_set1_epi32(0);
+        let a = _mm_set1_epi8(i8::MIN);
+        let b = _mm_set1_epi8(i8::MIN);
+        let r = _mm_dpbuud_epi32(src, a, b);
+        assert_eq_m128i(r, _mm_set1_epi32(4 * 16384));
+
+        let a = _mm_set1_epi8(1);
+        let b = _mm_set1_epi8(1);
+        // Wraps around instead of saturating.
+        let src = _mm_set1_epi32(i32::MAX);
+        let r = _mm_dpbuud_epi32(src, a, b);
+        assert_eq_m128i(r, _mm_set1_epi32(i32::MIN + 3));
+    }
+
+    #[simd_test(enable = "avxvnniint8")]
+    unsafe fn test_mm256_dpbuuds_epi32() {
+        // 255 * 255 in each of the 4 byte lanes
+        let src = _mm256_set1_epi32(1);
+        let a = _mm256_set1_epi8(-1);
+        let b = _mm256_set1_epi8(-1);
+        let r = _mm256_dpbuuds_epi32(src, a, b);
+        assert_eq_m256i(r, _mm256_set1_epi32(1 + 4 * 65025));
+
+        // 128 * 128 in each of the 4 byte lanes
+        let src = _mm256_set1_epi32(0);
+        let a = _mm256_set1_epi8(i8::MIN);
+        let b = _mm256_set1_epi8(i8::MIN);
+        let r = _mm256_dpbuuds_epi32(src, a, b);
+        assert_eq_m256i(r, _mm256_set1_epi32(4 * 16384));
+
+        let a = _mm256_set1_epi8(1);
+        let b = _mm256_set1_epi8(1);
+        // Saturates at the upper bound of `u32`, but not of `i32`.
+        let src = _mm256_set1_epi32(-1);
+        let r = _mm256_dpbuuds_epi32(src, a, b);
+        assert_eq_m256i(r, src);
+        let src = _mm256_set1_epi32(i32::MAX);
+        let r = _mm256_dpbuuds_epi32(src, a, b);
+        assert_eq_m256i(r, _mm256_set1_epi32(i32::MIN + 3));
+    }
+
+    #[simd_test(enable = "avxvnniint8")]
+    unsafe fn test_mm_dpbuuds_epi32() {
+        // 255 * 255 in each of the 4 byte lanes
+        let src = _mm_set1_epi32(1);
+        let a = _mm_set1_epi8(-1);
+        let b = _mm_set1_epi8(-1);
+        let r = _mm_dpbuuds_epi32(src, a, b);
+        assert_eq_m128i(r, _mm_set1_epi32(1 + 4 * 65025));
+
+        // 128 * 128 in each of the 4 byte lanes
+        let src = _mm_set1_epi32(0);
+        let a = _mm_set1_epi8(i8::MIN);
+        let b = _mm_set1_epi8(i8::MIN);
+        let r = _mm_dpbuuds_epi32(src, a, b);
+        assert_eq_m128i(r, _mm_set1_epi32(4 * 16384));
+
+        let a = _mm_set1_epi8(1);
+        let b = _mm_set1_epi8(1);
+        // Saturates at the upper bound of `u32`, but not of `i32`.
+        let src = _mm_set1_epi32(-1);
+        let r = _mm_dpbuuds_epi32(src, a, b);
+        assert_eq_m128i(r, src);
+        let src = _mm_set1_epi32(i32::MAX);
+        let r = _mm_dpbuuds_epi32(src, a, b);
+        assert_eq_m128i(r, _mm_set1_epi32(i32::MIN + 3));
+    }
+}
@@ -0,0 +1,70 @@
+//! Bit scan intrinsics.
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Returns the index of the lowest set bit in `a`.
+///
+/// Unlike [`i32::trailing_zeros`] this maps directly to the `BSF`
+/// instruction and does not handle a zero input: if no bit is set in `a` the
+/// returned value is unspecified.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_bit_scan_forward)
+#[inline]
+#[cfg_attr(test, assert_instr(bsf))]
+pub unsafe fn _bit_scan_forward(a: i32) -> i32 {
+    let r: i32;
+    asm!(
+        "bsf {r:e}, {a:e}",
+        a = in(reg) a,
+        r = lateout(reg) r,
+        options(pure, nomem, nostack)
+    );
+    r
+}
+
+/// Returns the index of the highest set bit in `a`.
+///
+/// Unlike [`i32::leading_zeros`] this maps directly to the `BSR`
+/// instruction, returns the bit index rather than the number of leading
+/// zeros, and does not handle a zero input: if no bit is set in `a` the
+/// returned value is unspecified.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_bit_scan_reverse)
+#[inline]
+#[cfg_attr(test, assert_instr(bsr))]
+pub unsafe fn _bit_scan_reverse(a: i32) -> i32 {
+    let r: i32;
+    asm!(
+        "bsr {r:e}, {a:e}",
+        a = in(reg) a,
+        r = lateout(reg) r,
+        options(pure, nomem, nostack)
+    );
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+
+    #[test]
+    fn test_bit_scan_forward() {
+        unsafe {
+            assert_eq!(_bit_scan_forward(1), 0);
+            assert_eq!(_bit_scan_forward(0b1010_0000), 5);
+            assert_eq!(_bit_scan_forward(-1), 0);
+            assert_eq!(_bit_scan_forward(i32::MIN), 31);
+        }
+    }
+
+    #[test]
+    fn test_bit_scan_reverse() {
+        unsafe {
+            assert_eq!(_bit_scan_reverse(1), 0);
+            assert_eq!(_bit_scan_reverse(0b1010_0000), 7);
+            assert_eq!(_bit_scan_reverse(-1), 31);
+            assert_eq!(_bit_scan_reverse(i32::MIN), 31);
+        }
+    }
+}
@@ -0,0 +1,39 @@
+//! Cache line demote instruction (CLDEMOTE)
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Hints to the hardware that the cache line containing `p` should be moved
+/// ("demoted") from the cache closest to the processor core to a level more
+/// distant from it, such as the shared last level cache.
+///
+/// This speeds up later accesses by other cores, for example when the line was
+/// just written to by the producer of a queue and will be read by its
+/// consumer next. Being a hint, it doesn't change the contents of memory or
+/// the architectural state, and on processors without support for it the
+/// instruction is executed as a NOP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_cldemote)
+#[inline]
+#[cfg_attr(test, assert_instr(cldemote))]
+pub unsafe fn _cldemote(p: *const u8) {
+    asm!("cldemote byte ptr [{}]", in(reg) p, options(nostack, preserves_flags));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+
+    // The hint can be executed without CLDEMOTE.
+    #[test]
+    fn test_cldemote() {
+        let mut x = [0_u8; 64];
+        for (i, v) in x.iter_mut().enumerate() {
+            *v = i as u8;
+        }
+        unsafe { _cldemote(x.as_ptr()) };
+        for (i, v) in x.iter().enumerate() {
+            assert_eq!(*v, i as u8);
+        }
+    }
+}
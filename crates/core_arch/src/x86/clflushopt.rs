@@ -0,0 +1,47 @@
+//! Optimized cache line flush instruction (CLFLUSHOPT)
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Invalidates and flushes the cache line that contains `p` from all levels of
+/// the cache hierarchy, writing it back to memory if it was modified.
+///
+/// Unlike [`_mm_clflush`](super::_mm_clflush), which is ordered with respect
+/// to other `CLFLUSH` instructions and to all writes, `CLFLUSHOPT` is only
+/// ordered with respect to older writes to the same cache line, fences and
+/// locked instructions. This lets flushes of different lines proceed in
+/// parallel, but it also means an [`_mm_sfence`](super::_mm_sfence) is needed
+/// before any later store that must only become visible after the flushed
+/// data reached memory, for example when committing to persistent memory.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_clflushopt)
+#[inline]
+#[cfg_attr(test, assert_instr(clflushopt))]
+pub unsafe fn _mm_clflushopt(p: *const u8) {
+    asm!("clflushopt byte ptr [{}]", in(reg) p, options(nostack, preserves_flags));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+
+    // This can't be a `simd_test`, which enables the target feature.
+
+    #[test]
+    fn test_mm_clflushopt() {
+        if !is_x86_feature_detected!("clflushopt") {
+            return stdarch_test::assert_skip_test_ok("test_mm_clflushopt");
+        }
+        unsafe {
+            let mut x = [0_u8; 64];
+            for (i, v) in x.iter_mut().enumerate() {
+                *v = i as u8;
+            }
+            _mm_clflushopt(x.as_ptr());
+            _mm_sfence();
+            for (i, v) in x.iter().enumerate() {
+                assert_eq!(*v, i as u8);
+            }
+        }
+    }
+}
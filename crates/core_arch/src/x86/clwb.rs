@@ -0,0 +1,47 @@
+//! Cache line write back instruction (CLWB)
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Writes back the cache line that contains `p` to memory if it was
+/// modified, possibly retaining it in the cache hierarchy.
+///
+/// Unlike [`_mm_clflushopt`](super::_mm_clflushopt), the line doesn't have to
+/// be invalidated, so a later access to it may still hit in the cache. The
+/// ordering is the same as for `CLFLUSHOPT`: the write-back is only ordered
+/// with respect to older writes to the same cache line, fences and locked
+/// instructions, so an [`_mm_sfence`](super::_mm_sfence) is needed before any
+/// later store that must only become visible after the data reached memory,
+/// for example when committing to persistent memory.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_clwb)
+#[inline]
+#[cfg_attr(test, assert_instr(clwb))]
+pub unsafe fn _mm_clwb(p: *const u8) {
+    asm!("clwb byte ptr [{}]", in(reg) p, options(nostack, preserves_flags));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+
+    // This can't be a `simd_test`, which enables the target feature.
+
+    #[test]
+    fn test_mm_clwb() {
+        if !is_x86_feature_detected!("clwb") {
+            return stdarch_test::assert_skip_test_ok("test_mm_clwb");
+        }
+        unsafe {
+            let mut x = [0_u8; 64];
+            for (i, v) in x.iter_mut().enumerate() {
+                *v = i as u8;
+            }
+            _mm_clwb(x.as_ptr());
+            _mm_sfence();
+            for (i, v) in x.iter().enumerate() {
+                assert_eq!(*v, i as u8);
+            }
+        }
+    }
+}
@@ -0,0 +1,46 @@
+//! AMD's cache line zero instruction (CLZERO)
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Zeroes the cache line containing `p`.
+///
+/// The zeroed range is the whole naturally aligned 64-byte cache line which
+/// `p` points into, that is, the 64 bytes starting at `p` rounded down to a
+/// multiple of 64, and not the 64 bytes starting at `p`. The write is not
+/// atomic, and is weakly ordered with respect to other stores, so it needs to
+/// be followed by a fence like [`_mm_sfence`](super::_mm_sfence) if the order
+/// matters.
+#[inline]
+#[cfg_attr(test, assert_instr(clzero))]
+pub unsafe fn _mm_clzero(p: *mut u8) {
+    asm!("clzero", in("eax") p, options(nostack, preserves_flags));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+
+    #[repr(align(64))]
+    struct Memory {
+        data: [u8; 192],
+    }
+
+    // This can't be a `simd_test`, which enables the target feature.
+    #[test]
+    fn test_mm_clzero() {
+        if !is_x86_feature_detected!("clzero") {
+            return stdarch_test::assert_skip_test_ok("test_mm_clzero");
+        }
+        let mut mem = Memory { data: [0xff; 192] };
+        // Only the cache line containing the pointer is zeroed, even though
+        // it doesn't point at the start of the line.
+        unsafe {
+            _mm_clzero(mem.data.as_mut_ptr().add(64 + 17));
+            _mm_sfence();
+        }
+        assert_eq!(&mem.data[..64], &[0xff; 64][..]);
+        assert_eq!(&mem.data[64..128], &[0; 64][..]);
+        assert_eq!(&mem.data[128..], &[0xff; 64][..]);
+    }
+}
@@ -0,0 +1,198 @@
+//! Conversions between the integer vector types and plain arrays.
+//!
+//! Element `0` of an array corresponds to the lowest lane of the vector, so
+//! converting an array is the same as loading it with `_mm_loadu_si128`,
+//! `_mm256_loadu_si256` or `_mm512_loadu_si512`, and converting a vector is
+//! the same as storing it with the matching unaligned store. For the same
+//! reason a `u128` is converted to and from an `__m128i` in little-endian
+//! byte order. None of these conversions require any target feature.
+//!
+//! They are inherent functions rather than `From` impls because trait impls
+//! on the stable vector types can't be unstable.
+
+use crate::{core_arch::x86::*, mem::transmute};
+
+macro_rules! impl_array_conversions {
+    ($vec:ident: $([$elem:ty; $n:literal]: $from:ident, $to:ident),* $(,)?) => {
+        impl $vec {
+            $(
+                /// Converts an array into a vector, with element `0` in the
+                /// lowest lane.
+                #[inline]
+                #[unstable(feature = "stdsimd", issue = "27731")]
+                pub fn $from(a: [$elem; $n]) -> Self {
+                    unsafe { transmute(a) }
+                }
+
+                /// Converts a vector into an array, with the lowest lane in
+                /// element `0`.
+                #[inline]
+                #[unstable(feature = "stdsimd", issue = "27731")]
+                pub fn $to(self) -> [$elem; $n] {
+                    unsafe { transmute(self) }
+                }
+            )*
+        }
+    };
+}
+
+impl_array_conversions! {
+    __m128i:
+    [u8; 16]: from_u8_array, to_u8_array,
+    [i8; 16]: from_i8_array, to_i8_array,
+    [u16; 8]: from_u16_array, to_u16_array,
+    [i16; 8]: from_i16_array, to_i16_array,
+    [u32; 4]: from_u32_array, to_u32_array,
+    [i32; 4]: from_i32_array, to_i32_array,
+    [u64; 2]: from_u64_array, to_u64_array,
+    [i64; 2]: from_i64_array, to_i64_array,
+}
+
+impl_array_conversions! {
+    __m256i:
+    [u8; 32]: from_u8_array, to_u8_array,
+    [i8; 32]: from_i8_array, to_i8_array,
+    [u16; 16]: from_u16_array, to_u16_array,
+    [i16; 16]: from_i16_array, to_i16_array,
+    [u32; 8]: from_u32_array, to_u32_array,
+    [i32; 8]: from_i32_array, to_i32_array,
+    [u64; 4]: from_u64_array, to_u64_array,
+    [i64; 4]: from_i64_array, to_i64_array,
+}
+
+impl_array_conversions! {
+    __m512i:
+    [u8; 64]: from_u8_array, to_u8_array,
+    [i8; 64]: from_i8_array, to_i8_array,
+    [u16; 32]: from_u16_array, to_u16_array,
+    [i16; 32]: from_i16_array, to_i16_array,
+    [u32; 16]: from_u32_array, to_u32_array,
+    [i32; 16]: from_i32_array, to_i32_array,
+    [u64; 8]: from_u64_array, to_u64_array,
+    [i64; 8]: from_i64_array, to_i64_array,
+}
+
+impl __m128i {
+    /// Converts a `u128` into a vector, with the least significant byte in
+    /// the lowest byte lane.
+    #[inline]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub fn from_u128(a: u128) -> Self {
+        unsafe { transmute(a) }
+    }
+
+    /// Converts a vector into a `u128`, with the lowest byte lane as the
+    /// least significant byte.
+    #[inline]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub fn to_u128(self) -> u128 {
+        unsafe { transmute(self) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+    use stdarch_test::simd_test;
+
+    fn bytes<const N: usize>() -> [u8; N] {
+        let mut a = [0; N];
+        for (i, b) in a.iter_mut().enumerate() {
+            *b = (i as u8).wrapping_mul(37).wrapping_add(1);
+        }
+        a
+    }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_m128i_array_conversions() {
+        let a = bytes::<16>();
+        let v = __m128i::from_u8_array(a);
+        assert_eq_m128i(v, _mm_loadu_si128(a.as_ptr() as *const _));
+        assert_eq!(v.to_u8_array(), a);
+
+        let w = v.to_u16_array();
+        for (i, e) in w.iter().enumerate() {
+            assert_eq!(*e, u16::from_le_bytes([a[2 * i], a[2 * i + 1]]));
+        }
+        let d = v.to_u32_array();
+        assert_eq!(d[0], u32::from_le_bytes([a[0], a[1], a[2], a[3]]));
+        let q = v.to_i64_array();
+        assert_eq_m128i(__m128i::from_i64_array(q), _mm_set_epi64x(q[1], q[0]));
+
+        assert_eq!(__m128i::from_i8_array([-1; 16]).to_i8_array(), [-1; 16]);
+        assert_eq!(
+            __m128i::from_i16_array([-1, 2, -3, 4, -5, 6, -7, 8]).to_i16_array(),
+            [-1, 2, -3, 4, -5, 6, -7, 8]
+        );
+        assert_eq!(__m128i::from_u32_array(d).to_u32_array(), d);
+        assert_eq!(
+            __m128i::from_i32_array([1, -2, 3, -4]).to_i32_array(),
+            [1, -2, 3, -4]
+        );
+        assert_eq!(
+            __m128i::from_u64_array([1, u64::MAX]).to_u64_array(),
+            [1, u64::MAX]
+        );
+    }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_m128i_u128_conversions() {
+        let x = 0x0f0e0d0c_0b0a0908_07060504_03020100_u128;
+        let v = __m128i::from_u128(x);
+        assert_eq_m128i(v, _mm_loadu_si128(x.to_le_bytes().as_ptr() as *const _));
+        assert_eq_m128i(
+            v,
+            _mm_setr_epi8(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15),
+        );
+        assert_eq!(v.to_u128(), x);
+        assert_eq!(__m128i::from_u128(u128::MAX).to_u128(), u128::MAX);
+    }
+
+    #[simd_test(enable = "avx")]
+    unsafe fn test_m256i_array_conversions() {
+        let a = bytes::<32>();
+        let v = __m256i::from_u8_array(a);
+        assert_eq_m256i(v, _mm256_loadu_si256(a.as_ptr() as *const _));
+        assert_eq!(v.to_u8_array(), a);
+
+        let d = v.to_i32_array();
+        assert_eq_m256i(
+            __m256i::from_i32_array(d),
+            _mm256_setr_epi32(d[0], d[1], d[2], d[3], d[4], d[5], d[6], d[7]),
+        );
+        assert_eq!(__m256i::from_i32_array(d).to_i32_array(), d);
+        assert_eq!(__m256i::from_i8_array([-3; 32]).to_i8_array(), [-3; 32]);
+        assert_eq!(__m256i::from_u16_array([7; 16]).to_u16_array(), [7; 16]);
+        assert_eq!(__m256i::from_i16_array([-7; 16]).to_i16_array(), [-7; 16]);
+        assert_eq!(__m256i::from_u32_array([9; 8]).to_u32_array(), [9; 8]);
+        assert_eq!(
+            __m256i::from_u64_array([1, 2, 3, 4]).to_u64_array(),
+            [1, 2, 3, 4]
+        );
+        assert_eq!(
+            __m256i::from_i64_array([-1, 2, -3, 4]).to_i64_array(),
+            [-1, 2, -3, 4]
+        );
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_m512i_array_conversions() {
+        let a = bytes::<64>();
+        let v = __m512i::from_u8_array(a);
+        assert_eq_m512i(v, _mm512_loadu_si512(a.as_ptr() as *const _));
+        assert_eq!(v.to_u8_array(), a);
+
+        let q = v.to_i64_array();
+        assert_eq_m512i(
+            __m512i::from_i64_array(q),
+            _mm512_setr_epi64(q[0], q[1], q[2], q[3], q[4], q[5], q[6], q[7]),
+        );
+        assert_eq!(__m512i::from_i64_array(q).to_i64_array(), q);
+        assert_eq!(__m512i::from_i8_array([-5; 64]).to_i8_array(), [-5; 64]);
+        assert_eq!(__m512i::from_u16_array([5; 32]).to_u16_array(), [5; 32]);
+        assert_eq!(__m512i::from_i16_array([-5; 32]).to_i16_array(), [-5; 32]);
+        assert_eq!(__m512i::from_u32_array([5; 16]).to_u32_array(), [5; 16]);
+        assert_eq!(__m512i::from_i32_array([-5; 16]).to_i32_array(), [-5; 16]);
+        assert_eq!(__m512i::from_u64_array([5; 8]).to_u64_array(), [5; 8]);
+    }
+}
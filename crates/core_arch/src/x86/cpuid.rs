@@ -178,6 +178,174 @@ pub unsafe fn __get_cpuid_max(leaf: u32) -> (u32, u32) {
     (eax, ebx)
 }
 
+/// Returns the highest leaf in the range of `cpuid` leaves starting at
+/// `base`, or `None` if the range is not supported.
+///
+/// Processors return the data of the highest basic leaf for unsupported
+/// leaves, so a range is only supported if its first leaf reports a
+/// maximum inside of it.
+#[inline]
+fn max_leaf_in_range(base: u32) -> Option<u32> {
+    if !has_cpuid() {
+        return None;
+    }
+    // SAFETY: `cpuid` is available.
+    let max = unsafe { __cpuid(base) }.eax;
+    if max >= base && max - base < 0x1000_0000 {
+        Some(max)
+    } else {
+        None
+    }
+}
+
+/// Returns the highest supported basic `cpuid` leaf, or `None` if the
+/// `cpuid` instruction is not available.
+#[inline]
+pub fn cpuid_max_leaf() -> Option<u32> {
+    max_leaf_in_range(0)
+}
+
+/// Returns the highest supported extended `cpuid` leaf (starting at
+/// `0x8000_0000`), or `None` if the `cpuid` instruction or the extended
+/// leaves are not available.
+#[inline]
+pub fn cpuid_max_extended_leaf() -> Option<u32> {
+    max_leaf_in_range(0x8000_0000)
+}
+
+/// How a [`CpuidIter`] finds the last sub-leaf of its leaf.
+#[derive(Copy, Clone, Debug)]
+enum SubleafEnd {
+    /// All sub-leaves have been returned.
+    Done,
+    /// The leaf has no sub-leaves besides sub-leaf 0.
+    Single,
+    /// Sub-leaf 0 reports the last valid sub-leaf in `EAX`.
+    Last(u32),
+    /// The first sub-leaf with a cache type of 0 in `EAX[4:0]` is invalid.
+    CacheType,
+    /// The first sub-leaf with a level type of 0 in `ECX[15:8]` is invalid.
+    LevelType,
+    /// Sub-leaf `n >= 2` is only valid if bit `n` of the state components
+    /// reported in sub-leaves 0 and 1 is set.
+    StateComponents(u64),
+}
+
+/// An iterator over the sub-leaves of a `cpuid` leaf, created with
+/// [`CpuidIter::new`].
+///
+/// Yields `(leaf, sub_leaf, result)` for each valid sub-leaf, using the
+/// termination rule of the leaf:
+///
+/// * leaves `0x4` and `0x8000_001D` (cache parameters) end with the first
+///   sub-leaf that reports a cache type of 0,
+/// * leaves `0xB` and `0x1F` (extended topology) end with the first sub-leaf
+///   that reports a level type of 0,
+/// * leaf `0xD` (XSAVE state components) yields sub-leaves 0 and 1, followed
+///   by the sub-leaves of the supported state components,
+/// * leaves `0x7`, `0x14`, `0x17`, `0x18`, `0x1D` and `0x20` yield the
+///   sub-leaves up to the one reported in `EAX` of sub-leaf 0,
+/// * all other leaves only yield sub-leaf 0.
+///
+/// Nothing is yielded if the `cpuid` instruction or the leaf is not
+/// supported.
+#[allow(clippy::missing_inline_in_public_items)]
+#[derive(Clone, Debug)]
+pub struct CpuidIter {
+    leaf: u32,
+    sub_leaf: u32,
+    end: SubleafEnd,
+}
+
+impl CpuidIter {
+    /// Creates an iterator over the sub-leaves of `leaf`.
+    #[inline]
+    pub fn new(leaf: u32) -> CpuidIter {
+        let supported = match max_leaf_in_range(leaf & 0xf000_0000) {
+            Some(max) => leaf <= max,
+            None => false,
+        };
+        let end = if !supported {
+            SubleafEnd::Done
+        } else {
+            // SAFETY: `cpuid` is available, which was checked by
+            // `max_leaf_in_range`.
+            unsafe {
+                match leaf {
+                    0x4 | 0x8000_001d => SubleafEnd::CacheType,
+                    0xb | 0x1f => SubleafEnd::LevelType,
+                    0xd => {
+                        let s0 = __cpuid_count(leaf, 0);
+                        let s1 = __cpuid_count(leaf, 1);
+                        let xcr0 = (s0.edx as u64) << 32 | s0.eax as u64;
+                        let xss = (s1.edx as u64) << 32 | s1.ecx as u64;
+                        SubleafEnd::StateComponents(xcr0 | xss)
+                    }
+                    0x7 | 0x14 | 0x17 | 0x18 | 0x1d | 0x20 => {
+                        SubleafEnd::Last(__cpuid_count(leaf, 0).eax)
+                    }
+                    _ => SubleafEnd::Single,
+                }
+            }
+        };
+        CpuidIter {
+            leaf,
+            sub_leaf: 0,
+            end,
+        }
+    }
+}
+
+impl Iterator for CpuidIter {
+    type Item = (u32, u32, CpuidResult);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.end {
+            SubleafEnd::Done => return None,
+            SubleafEnd::Single => self.end = SubleafEnd::Done,
+            SubleafEnd::Last(last) => {
+                if self.sub_leaf > last {
+                    self.end = SubleafEnd::Done;
+                    return None;
+                }
+            }
+            SubleafEnd::StateComponents(components) => {
+                while self.sub_leaf >= 2
+                    && self.sub_leaf < 64
+                    && components & (1 << self.sub_leaf) == 0
+                {
+                    self.sub_leaf += 1;
+                }
+                if self.sub_leaf >= 64 {
+                    self.end = SubleafEnd::Done;
+                    return None;
+                }
+            }
+            SubleafEnd::CacheType | SubleafEnd::LevelType => {}
+        }
+        // SAFETY: `cpuid` is available, otherwise `end` would be `Done`.
+        let result = unsafe { __cpuid_count(self.leaf, self.sub_leaf) };
+        let invalid = match self.end {
+            SubleafEnd::CacheType => result.eax & 0x1f == 0,
+            SubleafEnd::LevelType => (result.ecx >> 8) & 0xff == 0,
+            _ => false,
+        };
+        if invalid {
+            self.end = SubleafEnd::Done;
+            return None;
+        }
+        let sub_leaf = self.sub_leaf;
+        // Leaves with a sub-leaf for each possible value of ECX would go on
+        // forever, stop before the sub-leaf wraps around.
+        match self.sub_leaf.checked_add(1) {
+            Some(next) => self.sub_leaf = next,
+            None => self.end = SubleafEnd::Done,
+        }
+        Some((self.leaf, sub_leaf, result))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::core_arch::x86::*;
@@ -193,4 +361,96 @@ mod tests {
     fn test_has_cpuid_idempotent() {
         assert_eq!(cpuid::has_cpuid(), cpuid::has_cpuid());
     }
+
+    #[test]
+    fn test_cpuid_max_leaf() {
+        let max = cpuid_max_leaf().unwrap();
+        assert!(max >= 1);
+        assert_eq!(max, unsafe { __get_cpuid_max(0) }.0);
+        if let Some(max) = cpuid_max_extended_leaf() {
+            assert!(max >= 0x8000_0000);
+        }
+    }
+
+    #[test]
+    fn test_cpuid_iter_vendor() {
+        let leaves = CpuidIter::new(0).collect::<std::vec::Vec<_>>();
+        assert_eq!(leaves.len(), 1);
+        let (leaf, sub_leaf, r) = leaves[0];
+        assert_eq!((leaf, sub_leaf), (0, 0));
+        assert_eq!(r.eax, cpuid_max_leaf().unwrap());
+        let mut vendor = [0; 12];
+        vendor[0..4].copy_from_slice(&r.ebx.to_le_bytes());
+        vendor[4..8].copy_from_slice(&r.edx.to_le_bytes());
+        vendor[8..12].copy_from_slice(&r.ecx.to_le_bytes());
+        assert!(vendor.iter().all(|b| b.is_ascii_graphic()), "{:?}", vendor);
+    }
+
+    #[test]
+    fn test_cpuid_iter_unsupported_leaf() {
+        let max = cpuid_max_leaf().unwrap();
+        assert_eq!(CpuidIter::new(max + 1).count(), 0);
+        if cpuid_max_extended_leaf().is_none() {
+            assert_eq!(CpuidIter::new(0x8000_0000).count(), 0);
+        }
+    }
+
+    #[test]
+    fn test_cpuid_iter_cache_parameters() {
+        for leaf in [0x4, 0x8000_001d] {
+            let leaves = CpuidIter::new(leaf).take(64).collect::<std::vec::Vec<_>>();
+            assert!(leaves.len() < 64);
+            for (i, &(l, sub_leaf, r)) in leaves.iter().enumerate() {
+                assert_eq!((l, sub_leaf), (leaf, i as u32));
+                assert_ne!(r.eax & 0x1f, 0);
+                // The cache level is at least 1.
+                assert_ne!((r.eax >> 5) & 0x7, 0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_cpuid_iter_topology() {
+        for leaf in [0xb, 0x1f] {
+            let leaves = CpuidIter::new(leaf).take(256).collect::<std::vec::Vec<_>>();
+            assert!(leaves.len() < 256);
+            for (i, &(l, sub_leaf, r)) in leaves.iter().enumerate() {
+                assert_eq!((l, sub_leaf), (leaf, i as u32));
+                // The level number is echoed in ECX[7:0].
+                assert_eq!(r.ecx & 0xff, i as u32);
+                assert_ne!((r.ecx >> 8) & 0xff, 0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_cpuid_iter_xsave() {
+        if cpuid_max_leaf().unwrap() < 0xd {
+            return;
+        }
+        let leaves = CpuidIter::new(0xd).collect::<std::vec::Vec<_>>();
+        assert!(leaves.len() >= 2 && leaves.len() <= 64);
+        assert_eq!(leaves[0].1, 0);
+        assert_eq!(leaves[1].1, 1);
+        // x87 and SSE state are always supported.
+        assert_eq!(leaves[0].2.eax & 0b11, 0b11);
+        for w in leaves.windows(2) {
+            assert!(w[0].1 < w[1].1);
+        }
+        for &(_, sub_leaf, r) in &leaves[2..] {
+            assert!(sub_leaf < 64);
+            // Each supported state component has a size.
+            assert_ne!(r.eax, 0, "state component {}", sub_leaf);
+        }
+    }
+
+    #[test]
+    fn test_cpuid_iter_extended_features() {
+        let max = cpuid_max_leaf().unwrap();
+        if max < 7 {
+            return;
+        }
+        let last = unsafe { __cpuid_count(7, 0) }.eax;
+        assert_eq!(CpuidIter::new(7).count() as u32, last + 1);
+    }
 }
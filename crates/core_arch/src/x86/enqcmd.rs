@@ -0,0 +1,77 @@
+//! Enqueue store instructions (ENQCMD and ENQCMDS)
+//!
+//! These submit 64-byte commands to the shared work queues of accelerators,
+//! such as Intel's Data Streaming Accelerator, through their memory-mapped
+//! enqueue registers. Unlike a direct store, the device reports whether it
+//! accepted the command, which the intrinsics return.
+
+// Returns the zero flag, which the instructions set if the command wasn't
+// accepted.
+macro_rules! enqcmd {
+    ($inst:literal, $dst:expr, $src:expr) => {{
+        let r: u8;
+        asm!(
+            concat!($inst, " {}, zmmword ptr [{}]"),
+            "setz {}",
+            in(reg) $dst,
+            in(reg) $src,
+            out(reg_byte) r,
+            options(nostack),
+        );
+        r
+    }};
+}
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Enqueues the 64-byte command at `src`, which can have any alignment, to
+/// the enqueue register at `dst`, which must be aligned to a 64-byte boundary.
+///
+/// The PASID (process address space identifier) of the command is replaced by
+/// the one the operating system configured for the current process, and the
+/// command is written to `dst` as a single 64-byte write. Returns 0 if the
+/// device accepted the command, and 1 if it didn't, for example because its
+/// work queue was full, in which case the caller can retry.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_enqcmd)
+#[inline]
+#[cfg_attr(test, assert_instr(enqcmd))]
+pub unsafe fn _enqcmd(dst: *mut u8, src: *const u8) -> u8 {
+    enqcmd!("enqcmd", dst, src)
+}
+
+/// Enqueues the 64-byte command at `src`, which can have any alignment, to
+/// the enqueue register at `dst`, which must be aligned to a 64-byte boundary.
+///
+/// Unlike [`_enqcmd`], the PASID and privilege of the command are taken from
+/// `src` unchanged. This instruction can only be executed in supervisor mode.
+/// Returns 0 if the device accepted the command, and 1 if it didn't, for
+/// example because its work queue was full, in which case the caller can
+/// retry.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_enqcmds)
+#[inline]
+#[cfg_attr(test, assert_instr(enqcmds))]
+pub unsafe fn _enqcmds(dst: *mut u8, src: *const u8) -> u8 {
+    enqcmd!("enqcmds", dst, src)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+
+    // Enqueueing commands requires an accelerator's enqueue register and a
+    // PASID assigned by the operating system, so these only check that the
+    // intrinsics can be used with the expected signatures.
+
+    #[test]
+    fn test_enqcmd() {
+        let _: unsafe fn(*mut u8, *const u8) -> u8 = _enqcmd;
+    }
+
+    #[test]
+    fn test_enqcmds() {
+        let _: unsafe fn(*mut u8, *const u8) -> u8 = _enqcmds;
+    }
+}
@@ -3,7 +3,7 @@
 //! [F16C intrinsics]: https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=fp16&expand=1769
 
 use crate::{
-    core_arch::{simd::*, x86::*},
+    core_arch::{simd::*, simd_llvm::*, x86::*},
     //    hint::unreachable_unchecked,
     mem::transmute,
 };
@@ -85,6 +85,36 @@ pub unsafe fn _mm256_cvtps_ph<const IMM_ROUNDING: i32>(a: __m256) -> __m128i {
     transmute(r)
 }
 
+/// Converts the 16-bit half-precision float value `a` into a 32-bit float
+/// value.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_cvtsh_ss)
+#[inline]
+#[target_feature(enable = "f16c")]
+#[cfg_attr(test, assert_instr("vcvtph2ps"))]
+pub unsafe fn _cvtsh_ss(a: u16) -> f32 {
+    let a = i16x8::new(a as i16, 0, 0, 0, 0, 0, 0, 0);
+    simd_extract(llvm_vcvtph2ps_128(a), 0)
+}
+
+/// Converts the 32-bit float value `a` into a 16-bit half-precision float
+/// value.
+///
+/// Rounding is done according to the `IMM_ROUNDING` parameter, which can be
+/// one of the values listed for [`_mm_cvtps_ph`].
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_cvtss_sh)
+#[inline]
+#[target_feature(enable = "f16c")]
+#[cfg_attr(test, assert_instr("vcvtps2ph", IMM_ROUNDING = 0))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _cvtss_sh<const IMM_ROUNDING: i32>(a: f32) -> u16 {
+    static_assert_imm3!(IMM_ROUNDING);
+    let a = f32x4::new(a, 0.0, 0.0, 0.0);
+    let r = llvm_vcvtps2ph_128(a, IMM_ROUNDING);
+    simd_extract::<_, i16>(r, 0) as u16
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{core_arch::x86::*, mem::transmute};
@@ -109,4 +139,65 @@ mod tests {
         let result: [f32; 8] = transmute(floats);
         assert_eq!(result, array);
     }
+
+    #[simd_test(enable = "f16c")]
+    unsafe fn test_cvtsh_ss() {
+        assert_eq!(_cvtsh_ss(0x3c00), 1.0);
+        assert_eq!(_cvtsh_ss(0xc000), -2.0);
+        assert_eq!(_cvtsh_ss(0x0000).to_bits(), 0.0_f32.to_bits());
+        assert_eq!(_cvtsh_ss(0x8000).to_bits(), (-0.0_f32).to_bits());
+        // The largest finite value.
+        assert_eq!(_cvtsh_ss(0x7bff), 65504.0);
+        // Subnormals become normal 32-bit floats.
+        assert_eq!(_cvtsh_ss(0x0001), f32::from_bits(0x3380_0000));
+        assert_eq!(_cvtsh_ss(0x03ff), f32::from_bits(0x387f_c000));
+        assert_eq!(_cvtsh_ss(0x7c00), f32::INFINITY);
+        assert_eq!(_cvtsh_ss(0xfc00), f32::NEG_INFINITY);
+        // The NaN payload is kept in the upper bits of the mantissa.
+        assert_eq!(_cvtsh_ss(0x7e01).to_bits(), 0x7fc0_2000);
+        assert_eq!(_cvtsh_ss(0xfe00).to_bits(), 0xffc0_0000);
+    }
+
+    #[simd_test(enable = "f16c")]
+    unsafe fn test_cvtss_sh() {
+        assert_eq!(_cvtss_sh::<_MM_FROUND_TO_NEAREST_INT>(1.0), 0x3c00);
+        assert_eq!(_cvtss_sh::<_MM_FROUND_TO_NEAREST_INT>(-2.0), 0xc000);
+        assert_eq!(_cvtss_sh::<_MM_FROUND_TO_NEAREST_INT>(-0.0), 0x8000);
+        assert_eq!(_cvtss_sh::<_MM_FROUND_TO_NEAREST_INT>(65504.0), 0x7bff);
+        // Subnormal results.
+        let r = _cvtss_sh::<_MM_FROUND_TO_NEAREST_INT>(f32::from_bits(0x3380_0000));
+        assert_eq!(r, 0x0001);
+        let r = _cvtss_sh::<_MM_FROUND_TO_NEAREST_INT>(f32::from_bits(0x3280_0000));
+        assert_eq!(r, 0x0000);
+        // Overflow and infinities.
+        assert_eq!(_cvtss_sh::<_MM_FROUND_TO_NEAREST_INT>(65520.0), 0x7c00);
+        let r = _cvtss_sh::<_MM_FROUND_TO_NEAREST_INT>(f32::INFINITY);
+        assert_eq!(r, 0x7c00);
+        let r = _cvtss_sh::<_MM_FROUND_TO_NEAREST_INT>(f32::NEG_INFINITY);
+        assert_eq!(r, 0xfc00);
+        // The NaN payload is truncated to the upper 10 bits of the mantissa.
+        let nan = f32::from_bits(0x7fc0_2001);
+        assert_eq!(_cvtss_sh::<_MM_FROUND_TO_NEAREST_INT>(nan), 0x7e01);
+        let nan = f32::from_bits(0xffc0_1fff);
+        assert_eq!(_cvtss_sh::<_MM_FROUND_TO_NEAREST_INT>(nan), 0xfe00);
+    }
+
+    #[simd_test(enable = "f16c")]
+    unsafe fn test_cvtss_sh_rounding() {
+        // Between 0x3c00 and 0x3c01, but closer to the latter.
+        let a = 1.000732421875;
+        assert_eq!(_cvtss_sh::<_MM_FROUND_TO_NEAREST_INT>(a), 0x3c01);
+        assert_eq!(_cvtss_sh::<_MM_FROUND_TO_NEG_INF>(a), 0x3c00);
+        assert_eq!(_cvtss_sh::<_MM_FROUND_TO_POS_INF>(a), 0x3c01);
+        assert_eq!(_cvtss_sh::<_MM_FROUND_TO_ZERO>(a), 0x3c00);
+        assert_eq!(_cvtss_sh::<_MM_FROUND_TO_NEAREST_INT>(-a), 0xbc01);
+        assert_eq!(_cvtss_sh::<_MM_FROUND_TO_NEG_INF>(-a), 0xbc01);
+        assert_eq!(_cvtss_sh::<_MM_FROUND_TO_POS_INF>(-a), 0xbc00);
+        assert_eq!(_cvtss_sh::<_MM_FROUND_TO_ZERO>(-a), 0xbc00);
+        // Ties are rounded to even.
+        let a = 1.00048828125;
+        assert_eq!(_cvtss_sh::<_MM_FROUND_TO_NEAREST_INT>(a), 0x3c00);
+        let a = 1.00146484375;
+        assert_eq!(_cvtss_sh::<_MM_FROUND_TO_NEAREST_INT>(a), 0x3c02);
+    }
 }
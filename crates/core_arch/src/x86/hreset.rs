@@ -0,0 +1,40 @@
+//! History reset instruction (HRESET)
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Resets the processor's prediction history for the components selected by
+/// the bits of `eax`, which currently means the Intel Thread Director
+/// classification history (bit 0). The components that can be reset are
+/// reported by CPUID leaf `0x20`, and enabled by the operating system in the
+/// `IA32_HRESET_ENABLE` MSR.
+///
+/// This is meant to be used by the operating system scheduler when switching
+/// between threads, so that the history of the previous thread doesn't
+/// influence the classification of the next one. The instruction is
+/// privileged, and can only be executed by the kernel.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_hreset)
+#[inline]
+#[cfg_attr(test, assert_instr(hreset))]
+pub unsafe fn _hreset(eax: u32) {
+    // The immediate operand is ignored and has to be 0, the components are
+    // selected in EAX.
+    asm!(
+        "hreset $0",
+        in("eax") eax,
+        options(nomem, nostack, preserves_flags, att_syntax)
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+
+    // The instruction can only be executed by the kernel, so this only checks
+    // that the intrinsic can be used with the expected signature.
+    #[test]
+    fn test_hreset() {
+        let _: unsafe fn(u32) = _hreset;
+    }
+}
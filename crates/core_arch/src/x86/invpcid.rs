@@ -0,0 +1,71 @@
+//! Invalidate process-context identifier instruction (INVPCID)
+//!
+//! This instruction is privileged, and can only be executed by the kernel.
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Individual-address invalidation: invalidates the mappings for the linear
+/// address and PCID specified in the descriptor, except global translations.
+pub const _INVPCID_INDIVIDUAL_ADDRESS: u32 = 0;
+
+/// Single-context invalidation: invalidates all mappings tagged with the PCID
+/// specified in the descriptor, except global translations.
+pub const _INVPCID_SINGLE_CONTEXT: u32 = 1;
+
+/// All-context invalidation, including global translations: invalidates all
+/// mappings tagged with any PCID, and ignores the descriptor.
+pub const _INVPCID_ALL_CONTEXTS: u32 = 2;
+
+/// All-context invalidation, retaining global translations: invalidates all
+/// mappings tagged with any PCID except global translations, and ignores the
+/// descriptor.
+pub const _INVPCID_ALL_NON_GLOBAL: u32 = 3;
+
+/// Invalidate mappings in the Translation Lookaside Buffers (TLBs) and
+/// paging-structure caches for the processor context identifier (PCID)
+/// specified by `descriptor` based on the invalidation type specified in
+/// `ty`.
+///
+/// The `descriptor` is a 16-byte memory operand (with no alignment
+/// restrictions) where bits `[11:0]` specify the PCID, and bits `[127:64]`
+/// specify the linear address; bits `[63:12]` are reserved and must be zero.
+/// The supported types are:
+///
+/// * [`_INVPCID_INDIVIDUAL_ADDRESS`]: invalidates mappings for a single linear
+///   address and tagged with the PCID specified in `descriptor`, except global
+///   translations. The instruction may also invalidate global translations,
+///   mappings for other linear addresses, or mappings tagged with other PCIDs.
+/// * [`_INVPCID_SINGLE_CONTEXT`]: invalidates all mappings tagged with the PCID
+///   specified in `descriptor` except global translations. In some cases, it
+///   may invalidate mappings for other PCIDs as well.
+/// * [`_INVPCID_ALL_CONTEXTS`]: invalidates all mappings tagged with any PCID,
+///   including global translations, ignoring `descriptor`.
+/// * [`_INVPCID_ALL_NON_GLOBAL`]: invalidates all mappings tagged with any PCID
+///   except global translations, ignoring `descriptor`. The instruction may
+///   also invalidate global translations as well.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_invpcid)
+#[inline]
+#[cfg_attr(test, assert_instr(invpcid))]
+pub unsafe fn _invpcid(ty: u32, descriptor: *const u8) {
+    // The type is a 64-bit register in 64-bit mode.
+    asm!(
+        "invpcid {}, xmmword ptr [{}]",
+        in(reg) ty as usize,
+        in(reg) descriptor,
+        options(nostack, preserves_flags),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+
+    // The instruction can only be executed by the kernel, so this only checks
+    // that the intrinsic can be used with the expected signature.
+    #[test]
+    fn test_invpcid() {
+        let _: unsafe fn(u32, *const u8) = _invpcid;
+    }
+}
@@ -0,0 +1,548 @@
+//! AES Key Locker (KL and WIDEKL)
+//!
+//! Key Locker converts AES keys into handles, which are encrypted with a
+//! wrapping key, the internal wrapping key (IWKey), that can't be read back by
+//! software. The handles can then be used for encryption and decryption
+//! without the keys themselves having to be kept in memory. The IWKey is
+//! loaded by the operating system with [`_mm_loadiwkey`].
+//!
+//! The encryption and decryption intrinsics return 0 if the operation
+//! succeeded, and 1 if the handle was invalid, for example because it was
+//! created with a different IWKey or its restrictions don't allow the
+//! operation. In that case the output is zeroed.
+//!
+//! The reference is [Intel Key Locker Specification][kl_spec].
+//!
+//! [kl_spec]: https://www.intel.com/content/www/us/en/develop/download/intel-key-locker-specification.html
+
+use crate::{core_arch::x86::__m128i, ptr};
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+#[repr(C, packed)]
+#[derive(Copy, Clone)]
+struct EncodeKey128Output(u32, __m128i, __m128i, __m128i, __m128i, __m128i, __m128i);
+
+#[repr(C, packed)]
+#[derive(Copy, Clone)]
+struct EncodeKey256Output(
+    u32,
+    __m128i,
+    __m128i,
+    __m128i,
+    __m128i,
+    __m128i,
+    __m128i,
+    __m128i,
+);
+
+#[repr(C, packed)]
+#[derive(Copy, Clone)]
+struct AesOutput(u8, __m128i);
+
+#[repr(C, packed)]
+#[derive(Copy, Clone)]
+struct WideAesOutput(
+    u8,
+    __m128i,
+    __m128i,
+    __m128i,
+    __m128i,
+    __m128i,
+    __m128i,
+    __m128i,
+    __m128i,
+);
+
+#[allow(improper_ctypes)]
+extern "unadjusted" {
+    #[link_name = "llvm.x86.loadiwkey"]
+    fn loadiwkey(integrity_key: __m128i, key_lo: __m128i, key_hi: __m128i, control: u32);
+
+    #[link_name = "llvm.x86.encodekey128"]
+    fn encodekey128(key_params: u32, key: __m128i) -> EncodeKey128Output;
+    #[link_name = "llvm.x86.encodekey256"]
+    fn encodekey256(key_params: u32, key_lo: __m128i, key_hi: __m128i) -> EncodeKey256Output;
+
+    #[link_name = "llvm.x86.aesenc128kl"]
+    fn aesenc128kl(data: __m128i, handle: *const u8) -> AesOutput;
+    #[link_name = "llvm.x86.aesdec128kl"]
+    fn aesdec128kl(data: __m128i, handle: *const u8) -> AesOutput;
+    #[link_name = "llvm.x86.aesenc256kl"]
+    fn aesenc256kl(data: __m128i, handle: *const u8) -> AesOutput;
+    #[link_name = "llvm.x86.aesdec256kl"]
+    fn aesdec256kl(data: __m128i, handle: *const u8) -> AesOutput;
+
+    #[link_name = "llvm.x86.aesencwide128kl"]
+    fn aesencwide128kl(
+        handle: *const u8,
+        i0: __m128i,
+        i1: __m128i,
+        i2: __m128i,
+        i3: __m128i,
+        i4: __m128i,
+        i5: __m128i,
+        i6: __m128i,
+        i7: __m128i,
+    ) -> WideAesOutput;
+    #[link_name = "llvm.x86.aesdecwide128kl"]
+    fn aesdecwide128kl(
+        handle: *const u8,
+        i0: __m128i,
+        i1: __m128i,
+        i2: __m128i,
+        i3: __m128i,
+        i4: __m128i,
+        i5: __m128i,
+        i6: __m128i,
+        i7: __m128i,
+    ) -> WideAesOutput;
+    #[link_name = "llvm.x86.aesencwide256kl"]
+    fn aesencwide256kl(
+        handle: *const u8,
+        i0: __m128i,
+        i1: __m128i,
+        i2: __m128i,
+        i3: __m128i,
+        i4: __m128i,
+        i5: __m128i,
+        i6: __m128i,
+        i7: __m128i,
+    ) -> WideAesOutput;
+    #[link_name = "llvm.x86.aesdecwide256kl"]
+    fn aesdecwide256kl(
+        handle: *const u8,
+        i0: __m128i,
+        i1: __m128i,
+        i2: __m128i,
+        i3: __m128i,
+        i4: __m128i,
+        i5: __m128i,
+        i6: __m128i,
+        i7: __m128i,
+    ) -> WideAesOutput;
+}
+
+/// Loads the internal wrapping key (IWKey) from `integrity_key`, `key_lo` and
+/// `key_hi`. The 256-bit encryption key is `key_hi:key_lo` and the 128-bit
+/// integrity key is `integrity_key`. `control` selects the key source and
+/// whether the IWKey may be backed up.
+///
+/// This instruction is privileged, and can only be executed by the kernel.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_loadiwkey)
+#[inline]
+#[target_feature(enable = "kl")]
+#[cfg_attr(test, assert_instr(loadiwkey))]
+pub unsafe fn _mm_loadiwkey(
+    control: u32,
+    integrity_key: __m128i,
+    key_lo: __m128i,
+    key_hi: __m128i,
+) {
+    loadiwkey(integrity_key, key_lo, key_hi, control);
+}
+
+/// Wraps a 128-bit AES key from `key` into a 384-bit handle, which is written
+/// to `handle`, and returns the handle's metadata. `key_params` selects the
+/// restrictions of the handle, where 0 means that it can be used for both
+/// encryption and decryption without restrictions.
+///
+/// `handle` has to be valid for writing 48 bytes, and has no alignment
+/// requirements.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_encodekey128_u32)
+#[inline]
+#[target_feature(enable = "kl")]
+#[cfg_attr(test, assert_instr(encodekey128))]
+pub unsafe fn _mm_encodekey128_u32(key_params: u32, key: __m128i, handle: *mut u8) -> u32 {
+    let EncodeKey128Output(control, key0, key1, key2, _, _, _) = encodekey128(key_params, key);
+    ptr::write_unaligned(handle as *mut [__m128i; 3], [key0, key1, key2]);
+    control
+}
+
+/// Wraps a 256-bit AES key from `key_hi:key_lo` into a 512-bit handle, which is
+/// written to `handle`, and returns the handle's metadata. `key_params` selects
+/// the restrictions of the handle, where 0 means that it can be used for both
+/// encryption and decryption without restrictions.
+///
+/// `handle` has to be valid for writing 64 bytes, and has no alignment
+/// requirements.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_encodekey256_u32)
+#[inline]
+#[target_feature(enable = "kl")]
+#[cfg_attr(test, assert_instr(encodekey256))]
+pub unsafe fn _mm_encodekey256_u32(
+    key_params: u32,
+    key_lo: __m128i,
+    key_hi: __m128i,
+    handle: *mut u8,
+) -> u32 {
+    let EncodeKey256Output(control, key0, key1, key2, key3, _, _, _) =
+        encodekey256(key_params, key_lo, key_hi);
+    ptr::write_unaligned(handle as *mut [__m128i; 4], [key0, key1, key2, key3]);
+    control
+}
+
+/// Encrypts the block `input` with AES-128 using the 384-bit key handle at
+/// `handle`, and writes the result to `output`. Returns 0 on success, and 1 if
+/// the handle is invalid.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_aesenc128kl_u8)
+#[inline]
+#[target_feature(enable = "kl")]
+#[cfg_attr(test, assert_instr(aesenc128kl))]
+pub unsafe fn _mm_aesenc128kl_u8(output: *mut __m128i, input: __m128i, handle: *const u8) -> u8 {
+    let AesOutput(status, result) = aesenc128kl(input, handle);
+    *output = result;
+    status
+}
+
+/// Decrypts the block `input` with AES-128 using the 384-bit key handle at
+/// `handle`, and writes the result to `output`. Returns 0 on success, and 1 if
+/// the handle is invalid.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_aesdec128kl_u8)
+#[inline]
+#[target_feature(enable = "kl")]
+#[cfg_attr(test, assert_instr(aesdec128kl))]
+pub unsafe fn _mm_aesdec128kl_u8(output: *mut __m128i, input: __m128i, handle: *const u8) -> u8 {
+    let AesOutput(status, result) = aesdec128kl(input, handle);
+    *output = result;
+    status
+}
+
+/// Encrypts the block `input` with AES-256 using the 512-bit key handle at
+/// `handle`, and writes the result to `output`. Returns 0 on success, and 1 if
+/// the handle is invalid.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_aesenc256kl_u8)
+#[inline]
+#[target_feature(enable = "kl")]
+#[cfg_attr(test, assert_instr(aesenc256kl))]
+pub unsafe fn _mm_aesenc256kl_u8(output: *mut __m128i, input: __m128i, handle: *const u8) -> u8 {
+    let AesOutput(status, result) = aesenc256kl(input, handle);
+    *output = result;
+    status
+}
+
+/// Decrypts the block `input` with AES-256 using the 512-bit key handle at
+/// `handle`, and writes the result to `output`. Returns 0 on success, and 1 if
+/// the handle is invalid.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_aesdec256kl_u8)
+#[inline]
+#[target_feature(enable = "kl")]
+#[cfg_attr(test, assert_instr(aesdec256kl))]
+pub unsafe fn _mm_aesdec256kl_u8(output: *mut __m128i, input: __m128i, handle: *const u8) -> u8 {
+    let AesOutput(status, result) = aesdec256kl(input, handle);
+    *output = result;
+    status
+}
+
+/// Encrypts the 8 blocks at `input` with AES-128 using the 384-bit key handle
+/// at `handle`, and writes the results to the 8 blocks at `output`. Returns 0
+/// on success, and 1 if the handle is invalid.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_aesencwide128kl_u8)
+#[inline]
+#[target_feature(enable = "widekl")]
+#[cfg_attr(test, assert_instr(aesencwide128kl))]
+pub unsafe fn _mm_aesencwide128kl_u8(
+    output: *mut __m128i,
+    input: *const __m128i,
+    handle: *const u8,
+) -> u8 {
+    let i = &*(input as *const [__m128i; 8]);
+    let WideAesOutput(status, o0, o1, o2, o3, o4, o5, o6, o7) =
+        aesencwide128kl(handle, i[0], i[1], i[2], i[3], i[4], i[5], i[6], i[7]);
+    *(output as *mut [__m128i; 8]) = [o0, o1, o2, o3, o4, o5, o6, o7];
+    status
+}
+
+/// Decrypts the 8 blocks at `input` with AES-128 using the 384-bit key handle
+/// at `handle`, and writes the results to the 8 blocks at `output`. Returns 0
+/// on success, and 1 if the handle is invalid.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_aesdecwide128kl_u8)
+#[inline]
+#[target_feature(enable = "widekl")]
+#[cfg_attr(test, assert_instr(aesdecwide128kl))]
+pub unsafe fn _mm_aesdecwide128kl_u8(
+    output: *mut __m128i,
+    input: *const __m128i,
+    handle: *const u8,
+) -> u8 {
+    let i = &*(input as *const [__m128i; 8]);
+    let WideAesOutput(status, o0, o1, o2, o3, o4, o5, o6, o7) =
+        aesdecwide128kl(handle, i[0], i[1], i[2], i[3], i[4], i[5], i[6], i[7]);
+    *(output as *mut [__m128i; 8]) = [o0, o1, o2, o3, o4, o5, o6, o7];
+    status
+}
+
+/// Encrypts the 8 blocks at `input` with AES-256 using the 512-bit key handle
+/// at `handle`, and writes the results to the 8 blocks at `output`. Returns 0
+/// on success, and 1 if the handle is invalid.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_aesencwide256kl_u8)
+#[inline]
+#[target_feature(enable = "widekl")]
+#[cfg_attr(test, assert_instr(aesencwide256kl))]
+pub unsafe fn _mm_aesencwide256kl_u8(
+    output: *mut __m128i,
+    input: *const __m128i,
+    handle: *const u8,
+) -> u8 {
+    let i = &*(input as *const [__m128i; 8]);
+    let WideAesOutput(status, o0, o1, o2, o3, o4, o5, o6, o7) =
+        aesencwide256kl(handle, i[0], i[1], i[2], i[3], i[4], i[5], i[6], i[7]);
+    *(output as *mut [__m128i; 8]) = [o0, o1, o2, o3, o4, o5, o6, o7];
+    status
+}
+
+/// Decrypts the 8 blocks at `input` with AES-256 using the 512-bit key handle
+/// at `handle`, and writes the results to the 8 blocks at `output`. Returns 0
+/// on success, and 1 if the handle is invalid.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_aesdecwide256kl_u8)
+#[inline]
+#[target_feature(enable = "widekl")]
+#[cfg_attr(test, assert_instr(aesdecwide256kl))]
+pub unsafe fn _mm_aesdecwide256kl_u8(
+    output: *mut __m128i,
+    input: *const __m128i,
+    handle: *const u8,
+) -> u8 {
+    let i = &*(input as *const [__m128i; 8]);
+    let WideAesOutput(status, o0, o1, o2, o3, o4, o5, o6, o7) =
+        aesdecwide256kl(handle, i[0], i[1], i[2], i[3], i[4], i[5], i[6], i[7]);
+    *(output as *mut [__m128i; 8]) = [o0, o1, o2, o3, o4, o5, o6, o7];
+    status
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{core_arch::x86::*, mem::transmute};
+    use stdarch_test::simd_test;
+
+    // The test vectors from appendix C of FIPS 197.
+    const PLAINTEXT: [u8; 16] = [
+        0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd, 0xee,
+        0xff,
+    ];
+    const CIPHERTEXT_128: [u8; 16] = [
+        0x69, 0xc4, 0xe0, 0xd8, 0x6a, 0x7b, 0x04, 0x30, 0xd8, 0xcd, 0xb7, 0x80, 0x70, 0xb4, 0xc5,
+        0x5a,
+    ];
+    const CIPHERTEXT_256: [u8; 16] = [
+        0x8e, 0xa2, 0xb7, 0xca, 0x51, 0x67, 0x45, 0xbf, 0xea, 0xfc, 0x49, 0x90, 0x4b, 0x49, 0x60,
+        0x89,
+    ];
+
+    // The keys of the test vectors are the bytes 0, 1, 2, ...
+    unsafe fn key(offset: u8) -> __m128i {
+        let mut key = [0_u8; 16];
+        for (i, x) in key.iter_mut().enumerate() {
+            *x = offset + i as u8;
+        }
+        transmute(key)
+    }
+
+    unsafe fn handle_128() -> [u8; 48] {
+        let mut handle = [0; 48];
+        _mm_encodekey128_u32(0, key(0), handle.as_mut_ptr());
+        handle
+    }
+
+    unsafe fn handle_256() -> [u8; 64] {
+        let mut handle = [0; 64];
+        _mm_encodekey256_u32(0, key(0), key(16), handle.as_mut_ptr());
+        handle
+    }
+
+    unsafe fn blocks() -> [__m128i; 8] {
+        let mut blocks = [_mm_setzero_si128(); 8];
+        for (i, x) in blocks.iter_mut().enumerate() {
+            *x = _mm_set_epi64x(i as i64 * 0x0123_4567, !(i as i64));
+        }
+        blocks
+    }
+
+    // AES-128 encryption with the AES-NI intrinsics, to compare against.
+    #[target_feature(enable = "aes")]
+    unsafe fn aes128_encrypt(key: __m128i, block: __m128i) -> __m128i {
+        unsafe fn next(k: __m128i, assist: __m128i) -> __m128i {
+            let k = _mm_xor_si128(k, _mm_slli_si128::<4>(k));
+            let k = _mm_xor_si128(k, _mm_slli_si128::<4>(k));
+            let k = _mm_xor_si128(k, _mm_slli_si128::<4>(k));
+            _mm_xor_si128(k, _mm_shuffle_epi32::<0xff>(assist))
+        }
+        let mut k = [key; 11];
+        macro_rules! expand {
+            ($($i:literal: $rcon:literal),*) => {$(
+                k[$i] = next(k[$i - 1], _mm_aeskeygenassist_si128::<$rcon>(k[$i - 1]));
+            )*};
+        }
+        expand!(1: 0x01, 2: 0x02, 3: 0x04, 4: 0x08, 5: 0x10, 6: 0x20, 7: 0x40, 8: 0x80, 9: 0x1b, 10: 0x36);
+        let mut x = _mm_xor_si128(block, k[0]);
+        for k in &k[1..10] {
+            x = _mm_aesenc_si128(x, *k);
+        }
+        _mm_aesenclast_si128(x, k[10])
+    }
+
+    #[simd_test(enable = "kl")]
+    unsafe fn test_mm_loadiwkey() {
+        // Loading the IWKey is privileged, so this only checks that the
+        // intrinsic can be used with the expected signature.
+        let _: unsafe fn(u32, __m128i, __m128i, __m128i) = _mm_loadiwkey;
+    }
+
+    #[simd_test(enable = "kl")]
+    unsafe fn test_mm_encodekey128_u32() {
+        let mut handle = handle_128();
+        let mut r = _mm_setzero_si128();
+        assert_eq!(
+            _mm_aesenc128kl_u8(&mut r, transmute(PLAINTEXT), handle.as_ptr()),
+            0
+        );
+        assert_eq_m128i(r, transmute(CIPHERTEXT_128));
+
+        // Handles are authenticated, so a modified one is rejected.
+        handle[40] ^= 1;
+        assert_eq!(
+            _mm_aesenc128kl_u8(&mut r, transmute(PLAINTEXT), handle.as_ptr()),
+            1
+        );
+    }
+
+    #[simd_test(enable = "kl")]
+    unsafe fn test_mm_encodekey256_u32() {
+        let mut handle = handle_256();
+        let mut r = _mm_setzero_si128();
+        assert_eq!(
+            _mm_aesenc256kl_u8(&mut r, transmute(PLAINTEXT), handle.as_ptr()),
+            0
+        );
+        assert_eq_m128i(r, transmute(CIPHERTEXT_256));
+
+        // Handles are authenticated, so a modified one is rejected.
+        handle[56] ^= 1;
+        assert_eq!(
+            _mm_aesenc256kl_u8(&mut r, transmute(PLAINTEXT), handle.as_ptr()),
+            1
+        );
+    }
+
+    #[simd_test(enable = "kl,aes")]
+    unsafe fn test_mm_aesenc128kl_u8() {
+        let handle = handle_128();
+        for block in &blocks() {
+            let mut r = _mm_setzero_si128();
+            assert_eq!(_mm_aesenc128kl_u8(&mut r, *block, handle.as_ptr()), 0);
+            assert_eq_m128i(r, aes128_encrypt(key(0), *block));
+        }
+    }
+
+    #[simd_test(enable = "kl")]
+    unsafe fn test_mm_aesdec128kl_u8() {
+        let handle = handle_128();
+        let mut r = _mm_setzero_si128();
+        assert_eq!(
+            _mm_aesdec128kl_u8(&mut r, transmute(CIPHERTEXT_128), handle.as_ptr()),
+            0
+        );
+        assert_eq_m128i(r, transmute(PLAINTEXT));
+    }
+
+    #[simd_test(enable = "kl")]
+    unsafe fn test_mm_aesenc256kl_u8() {
+        let handle = handle_256();
+        let mut r = _mm_setzero_si128();
+        assert_eq!(
+            _mm_aesenc256kl_u8(&mut r, transmute(PLAINTEXT), handle.as_ptr()),
+            0
+        );
+        assert_eq_m128i(r, transmute(CIPHERTEXT_256));
+    }
+
+    #[simd_test(enable = "kl")]
+    unsafe fn test_mm_aesdec256kl_u8() {
+        let handle = handle_256();
+        let mut r = _mm_setzero_si128();
+        assert_eq!(
+            _mm_aesdec256kl_u8(&mut r, transmute(CIPHERTEXT_256), handle.as_ptr()),
+            0
+        );
+        assert_eq_m128i(r, transmute(PLAINTEXT));
+    }
+
+    #[simd_test(enable = "kl,widekl,aes")]
+    unsafe fn test_mm_aesencwide128kl_u8() {
+        let handle = handle_128();
+        let blocks = blocks();
+        let mut r = [_mm_setzero_si128(); 8];
+        assert_eq!(
+            _mm_aesencwide128kl_u8(r.as_mut_ptr(), blocks.as_ptr(), handle.as_ptr()),
+            0
+        );
+        for (r, block) in r.iter().zip(&blocks) {
+            assert_eq_m128i(*r, aes128_encrypt(key(0), *block));
+        }
+    }
+
+    #[simd_test(enable = "kl,widekl,aes")]
+    unsafe fn test_mm_aesdecwide128kl_u8() {
+        let handle = handle_128();
+        let blocks = blocks();
+        let mut encrypted = blocks;
+        for x in encrypted.iter_mut() {
+            *x = aes128_encrypt(key(0), *x);
+        }
+        let mut r = [_mm_setzero_si128(); 8];
+        assert_eq!(
+            _mm_aesdecwide128kl_u8(r.as_mut_ptr(), encrypted.as_ptr(), handle.as_ptr()),
+            0
+        );
+        for (r, block) in r.iter().zip(&blocks) {
+            assert_eq_m128i(*r, *block);
+        }
+    }
+
+    #[simd_test(enable = "kl,widekl")]
+    unsafe fn test_mm_aesencwide256kl_u8() {
+        let handle = handle_256();
+        let blocks = blocks();
+        let mut r = [_mm_setzero_si128(); 8];
+        assert_eq!(
+            _mm_aesencwide256kl_u8(r.as_mut_ptr(), blocks.as_ptr(), handle.as_ptr()),
+            0
+        );
+        for (r, block) in r.iter().zip(&blocks) {
+            let mut e = _mm_setzero_si128();
+            assert_eq!(_mm_aesenc256kl_u8(&mut e, *block, handle.as_ptr()), 0);
+            assert_eq_m128i(*r, e);
+        }
+    }
+
+    #[simd_test(enable = "kl,widekl")]
+    unsafe fn test_mm_aesdecwide256kl_u8() {
+        let handle = handle_256();
+        let blocks = blocks();
+        let mut encrypted = [_mm_setzero_si128(); 8];
+        assert_eq!(
+            _mm_aesencwide256kl_u8(encrypted.as_mut_ptr(), blocks.as_ptr(), handle.as_ptr()),
+            0
+        );
+        let mut r = [_mm_setzero_si128(); 8];
+        assert_eq!(
+            _mm_aesdecwide256kl_u8(r.as_mut_ptr(), encrypted.as_ptr(), handle.as_ptr()),
+            0
+        );
+        for (r, block) in r.iter().zip(&blocks) {
+            assert_eq_m128i(*r, *block);
+        }
+    }
+}
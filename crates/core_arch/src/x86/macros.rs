@@ -81,6 +81,21 @@ macro_rules! static_assert_imm8_scale {
     };
 }
 
+// Checks, in builds with debug assertions, that `$ptr` is aligned to `$align`
+// bytes as required by the aligned load, store and stream intrinsics.
+// Misaligned accesses fault for most of these instructions, but the compiler
+// is free to use a different instruction sequence, so misuse is otherwise
+// undefined behavior that goes unnoticed.
+#[allow(unused)]
+macro_rules! debug_assert_aligned {
+    ($ptr:expr, $align:literal) => {
+        debug_assert!(
+            $ptr as usize % $align == 0,
+            concat!("pointer is not aligned to ", stringify!($align), " bytes")
+        )
+    };
+}
+
 #[cfg(test)]
 macro_rules! assert_approx_eq {
     ($a:expr, $b:expr, $eps:expr) => {{
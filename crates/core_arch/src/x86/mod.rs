@@ -327,6 +327,42 @@ types! {
         u16, u16, u16, u16, u16, u16, u16, u16,
         u16, u16, u16, u16, u16, u16, u16, u16
     );
+
+    /// 128-bit wide set of eight half-precision floating point values,
+    /// x86-specific
+    ///
+    /// This type is the same as the `__m128h` type defined by Intel,
+    /// representing a 128-bit SIMD register holding eight packed IEEE 754
+    /// `binary16` values. Rust has no native half-precision type, so each
+    /// lane is stored as its `u16` bit pattern. It's used by the avx512fp16
+    /// intrinsics.
+    pub struct __m128h(u16, u16, u16, u16, u16, u16, u16, u16);
+
+    /// 256-bit wide set of 16 half-precision floating point values,
+    /// x86-specific
+    ///
+    /// This type is the same as the `__m256h` type defined by Intel,
+    /// representing a 256-bit SIMD register holding 16 packed IEEE 754
+    /// `binary16` values, each stored as its `u16` bit pattern. It's used by
+    /// the avx512fp16 intrinsics.
+    pub struct __m256h(
+        u16, u16, u16, u16, u16, u16, u16, u16,
+        u16, u16, u16, u16, u16, u16, u16, u16
+    );
+
+    /// 512-bit wide set of 32 half-precision floating point values,
+    /// x86-specific
+    ///
+    /// This type is the same as the `__m512h` type defined by Intel,
+    /// representing a 512-bit SIMD register holding 32 packed IEEE 754
+    /// `binary16` values, each stored as its `u16` bit pattern. It's used by
+    /// the avx512fp16 intrinsics.
+    pub struct __m512h(
+        u16, u16, u16, u16, u16, u16, u16, u16,
+        u16, u16, u16, u16, u16, u16, u16, u16,
+        u16, u16, u16, u16, u16, u16, u16, u16,
+        u16, u16, u16, u16, u16, u16, u16, u16
+    );
 }
 
 /// The `__mmask64` type used in AVX-512 intrinsics, a 64-bit integer
@@ -732,6 +768,60 @@ impl m512bhExt for __m512bh {
     }
 }
 
+#[allow(non_camel_case_types)]
+#[unstable(feature = "stdsimd_internal", issue = "none")]
+pub(crate) trait m128hExt: Sized {
+    fn as_m128h(self) -> __m128h;
+
+    #[inline]
+    fn as_u16x8(self) -> crate::core_arch::simd::u16x8 {
+        unsafe { transmute(self.as_m128h()) }
+    }
+}
+
+impl m128hExt for __m128h {
+    #[inline]
+    fn as_m128h(self) -> Self {
+        self
+    }
+}
+
+#[allow(non_camel_case_types)]
+#[unstable(feature = "stdsimd_internal", issue = "none")]
+pub(crate) trait m256hExt: Sized {
+    fn as_m256h(self) -> __m256h;
+
+    #[inline]
+    fn as_u16x16(self) -> crate::core_arch::simd::u16x16 {
+        unsafe { transmute(self.as_m256h()) }
+    }
+}
+
+impl m256hExt for __m256h {
+    #[inline]
+    fn as_m256h(self) -> Self {
+        self
+    }
+}
+
+#[allow(non_camel_case_types)]
+#[unstable(feature = "stdsimd_internal", issue = "none")]
+pub(crate) trait m512hExt: Sized {
+    fn as_m512h(self) -> __m512h;
+
+    #[inline]
+    fn as_u16x32(self) -> crate::core_arch::simd::u16x32 {
+        unsafe { transmute(self.as_m512h()) }
+    }
+}
+
+impl m512hExt for __m512h {
+    #[inline]
+    fn as_m512h(self) -> Self {
+        self
+    }
+}
+
 mod eflags;
 pub use self::eflags::*;
 
@@ -820,6 +910,9 @@ pub use self::avx512bw::*;
 mod avx512cd;
 pub use self::avx512cd::*;
 
+mod avx512dq;
+pub use self::avx512dq::*;
+
 mod avx512ifma;
 pub use self::avx512ifma::*;
 
@@ -858,3 +951,110 @@ pub use self::f16c::*;
 
 mod avx512bf16;
 pub use self::avx512bf16::*;
+
+mod avx512vp2intersect;
+pub use self::avx512vp2intersect::*;
+
+mod avx512fp16;
+pub use self::avx512fp16::*;
+
+mod avxvnni;
+pub use self::avxvnni::*;
+
+mod avxifma;
+pub use self::avxifma::*;
+
+mod avxvnniint8;
+pub use self::avxvnniint8::*;
+
+mod avxvnniint16;
+pub use self::avxvnniint16::*;
+
+mod avxneconvert;
+pub use self::avxneconvert::*;
+
+mod sha512;
+pub use self::sha512::*;
+
+mod sm3;
+pub use self::sm3::*;
+
+mod sm4;
+pub use self::sm4::*;
+
+mod movdiri;
+pub use self::movdiri::*;
+
+mod enqcmd;
+pub use self::enqcmd::*;
+
+mod waitpkg;
+pub use self::waitpkg::*;
+
+mod serialize;
+pub use self::serialize::*;
+
+mod tsxldtrk;
+pub use self::tsxldtrk::*;
+
+mod cldemote;
+pub use self::cldemote::*;
+
+mod ptwrite;
+pub use self::ptwrite::*;
+
+mod rdpid;
+pub use self::rdpid::*;
+
+mod mwaitx;
+pub use self::mwaitx::*;
+
+mod clzero;
+pub use self::clzero::*;
+
+mod invpcid;
+pub use self::invpcid::*;
+
+mod kl;
+pub use self::kl::*;
+
+mod hreset;
+pub use self::hreset::*;
+
+mod prefetchi;
+pub use self::prefetchi::*;
+
+mod raoint;
+pub use self::raoint::*;
+
+mod clflushopt;
+pub use self::clflushopt::*;
+
+mod clwb;
+pub use self::clwb::*;
+
+mod bitscan;
+pub use self::bitscan::*;
+
+mod rotate;
+pub use self::rotate::*;
+
+mod conversions;
+
+mod nontemporal;
+pub use self::nontemporal::*;
+
+mod prfchw;
+pub use self::prfchw::*;
+
+mod wbnoinvd;
+pub use self::wbnoinvd::*;
+
+mod pconfig;
+pub use self::pconfig::*;
+
+mod movbe;
+pub use self::movbe::*;
+
+mod shstk;
+pub use self::shstk::*;
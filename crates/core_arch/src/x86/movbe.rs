@@ -0,0 +1,100 @@
+//! Move data after swapping bytes (MOVBE)
+//!
+//! These intrinsics load and store big-endian integers, for example when
+//! parsing or writing network protocol headers, without a separate `BSWAP`.
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Loads a big-endian 16-bit integer from `ptr`.
+///
+/// `ptr` does not need to be aligned on any particular boundary.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_loadbe_i16)
+#[inline]
+#[target_feature(enable = "movbe")]
+#[cfg_attr(test, assert_instr(movbe))]
+pub unsafe fn _loadbe_i16(ptr: *const u8) -> i16 {
+    (ptr as *const i16).read_unaligned().swap_bytes()
+}
+
+/// Loads a big-endian 32-bit integer from `ptr`.
+///
+/// `ptr` does not need to be aligned on any particular boundary.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_loadbe_i32)
+#[inline]
+#[target_feature(enable = "movbe")]
+#[cfg_attr(test, assert_instr(movbe))]
+pub unsafe fn _loadbe_i32(ptr: *const u8) -> i32 {
+    (ptr as *const i32).read_unaligned().swap_bytes()
+}
+
+/// Stores `data` to `ptr` as a big-endian 16-bit integer.
+///
+/// `ptr` does not need to be aligned on any particular boundary.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_storebe_i16)
+#[inline]
+#[target_feature(enable = "movbe")]
+#[cfg_attr(test, assert_instr(movbe))]
+pub unsafe fn _storebe_i16(ptr: *mut u8, data: i16) {
+    (ptr as *mut i16).write_unaligned(data.swap_bytes());
+}
+
+/// Stores `data` to `ptr` as a big-endian 32-bit integer.
+///
+/// `ptr` does not need to be aligned on any particular boundary.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_storebe_i32)
+#[inline]
+#[target_feature(enable = "movbe")]
+#[cfg_attr(test, assert_instr(movbe))]
+pub unsafe fn _storebe_i32(ptr: *mut u8, data: i32) {
+    (ptr as *mut i32).write_unaligned(data.swap_bytes());
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+    use stdarch_test::simd_test;
+
+    #[simd_test(enable = "movbe")]
+    unsafe fn test_loadbe_i16() {
+        let a = [0x12u8, 0x34, 0x56];
+        assert_eq!(_loadbe_i16(a.as_ptr()), 0x1234);
+        assert_eq!(_loadbe_i16(a[1..].as_ptr()), 0x3456);
+        let x = -2_i16;
+        assert_eq!(_loadbe_i16(&x.swap_bytes() as *const i16 as *const u8), x);
+    }
+
+    #[simd_test(enable = "movbe")]
+    unsafe fn test_loadbe_i32() {
+        let a = [0x12u8, 0x34, 0x56, 0x78, 0x9a];
+        assert_eq!(_loadbe_i32(a.as_ptr()), 0x12345678);
+        assert_eq!(_loadbe_i32(a[1..].as_ptr()), 0x3456789a_u32 as i32);
+        let x = 0x0102_0304_u32;
+        let r = _loadbe_i32(&x as *const u32 as *const u8);
+        assert_eq!(r as u32, x.swap_bytes());
+    }
+
+    #[simd_test(enable = "movbe")]
+    unsafe fn test_storebe_i16() {
+        let mut a = [0u8; 3];
+        _storebe_i16(a[1..].as_mut_ptr(), 0x1234);
+        assert_eq!(a, [0, 0x12, 0x34]);
+        _storebe_i16(a.as_mut_ptr(), -2);
+        assert_eq!(i16::from_be_bytes([a[0], a[1]]), -2);
+    }
+
+    #[simd_test(enable = "movbe")]
+    unsafe fn test_storebe_i32() {
+        let mut a = [0u8; 5];
+        _storebe_i32(a[1..].as_mut_ptr(), 0x12345678);
+        assert_eq!(a, [0, 0x12, 0x34, 0x56, 0x78]);
+        let x = 0x0102_0304_u32;
+        let mut r = 0_u32;
+        _storebe_i32(&mut r as *mut u32 as *mut u8, x as i32);
+        assert_eq!(r, x.swap_bytes());
+    }
+}
@@ -0,0 +1,97 @@
+//! Direct store instructions (MOVDIRI and MOVDIR64B)
+//!
+//! Direct stores are meant for writing to memory-mapped I/O, such as the
+//! work submission portals of accelerators. They are not cached in any
+//! level of the cache hierarchy, which is bypassed just like non-temporal
+//! stores do. Direct stores are also weakly ordered with respect to other
+//! stores, including other direct stores, so a fence is required to
+//! order them.
+//!
+//! Check that the CPU supports them with `is_x86_feature_detected!` before
+//! calling them.
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Direct store of `val` into `dst`.
+///
+/// Writes the 32-bit integer `val` to `dst` as a single write which bypasses
+/// the caches. If `dst` is naturally aligned the write is atomic, and if the
+/// destination is write-back memory it is evicted from the caches as part of
+/// the write. The store is write-combined like non-temporal stores, and is
+/// weakly ordered with respect to other stores.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_directstoreu_u32)
+#[inline]
+#[cfg_attr(test, assert_instr(movdiri))]
+pub unsafe fn _directstoreu_u32(dst: *mut u32, val: u32) {
+    asm!(
+        "movdiri dword ptr [{}], {:e}",
+        in(reg) dst,
+        in(reg) val,
+        options(nostack, preserves_flags),
+    );
+}
+
+/// Direct store of 64 bytes from `src` into `dst`.
+///
+/// Reads 64 bytes from `src`, which can have any alignment, and writes them
+/// to `dst`, which must be aligned to a 64-byte boundary, as a single write
+/// which bypasses the caches. The read from `src` is not atomic, but the
+/// write to `dst` is: it is performed as a single 64-byte write, which is
+/// never split up into smaller writes. Like with non-temporal stores, the
+/// write is write-combined and weakly ordered with respect to other stores.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_movdir64b)
+#[inline]
+#[cfg_attr(test, assert_instr(movdir64b))]
+pub unsafe fn _movdir64b(dst: *mut u8, src: *const u8) {
+    asm!(
+        "movdir64b {}, zmmword ptr [{}]",
+        in(reg) dst,
+        in(reg) src,
+        options(nostack, preserves_flags),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+
+    #[repr(align(64))]
+    struct Memory {
+        data: [u8; 128],
+    }
+
+    #[test]
+    fn test_directstoreu_u32() {
+        if !is_x86_feature_detected!("movdiri") {
+            return stdarch_test::assert_skip_test_ok("test_directstoreu_u32");
+        }
+        unsafe {
+            let mut mem = [0_u32; 4];
+            _directstoreu_u32(mem.as_mut_ptr().add(1), 0xdead_beef);
+            _mm_sfence();
+            assert_eq!(mem, [0, 0xdead_beef, 0, 0]);
+        }
+    }
+
+    #[test]
+    fn test_movdir64b() {
+        if !is_x86_feature_detected!("movdir64b") {
+            return stdarch_test::assert_skip_test_ok("test_movdir64b");
+        }
+        unsafe {
+            let mut src = [0_u8; 65];
+            for (i, x) in src.iter_mut().enumerate() {
+                *x = i as u8;
+            }
+            let mut mem = Memory { data: [0xff; 128] };
+            // The source doesn't need to be aligned.
+            _movdir64b(mem.data.as_mut_ptr().add(64), src.as_ptr().add(1));
+            _mm_sfence();
+            assert_eq!(&mem.data[..64], &[0xff; 64][..]);
+            assert_eq!(&mem.data[64..], &src[1..]);
+        }
+    }
+}
@@ -0,0 +1,98 @@
+//! AMD's extended monitor and wait instructions (MONITORX and MWAITX)
+//!
+//! Unlike `MONITOR` and `MWAIT`, these can be executed in user space, and
+//! `MWAITX` can additionally wake up after a timeout.
+//!
+//! The reference is [AMD64 Architecture Programmer's Manual, Volume 3:
+//! General-Purpose and System Instructions][amd64_ref].
+//!
+//! [amd64_ref]: http://support.amd.com/TechDocs/24594.pdf
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Sets up a linear address range starting at `p` to be monitored by hardware
+/// and activates the monitor, for use by a following [`_mm_mwaitx`].
+///
+/// `extensions` and `hints` are passed to the instruction in `ECX` and `EDX`
+/// respectively. No extensions or hints are currently defined for `MONITORX`,
+/// so both should be 0.
+#[inline]
+#[cfg_attr(test, assert_instr(monitorx))]
+pub unsafe fn _mm_monitorx(p: *const u8, extensions: u32, hints: u32) {
+    asm!(
+        "monitorx",
+        in("eax") p,
+        in("ecx") extensions,
+        in("edx") hints,
+        options(nostack, preserves_flags),
+    );
+}
+
+/// Waits in an implementation-dependent optimized state until a store to the
+/// address range armed by [`_mm_monitorx`], an interrupt, or the end of the
+/// timeout.
+///
+/// `extensions` is passed to the instruction in `ECX`: bit 0 makes interrupts
+/// wake up the processor even if they are masked, and bit 1 enables the
+/// timeout, which is the `clock` number of time-stamp counter ticks. `hints`
+/// is passed in `EAX`, where bits 7:4 select the optimized state to wait in,
+/// with `0xf` selecting C0 without waiting.
+#[inline]
+#[cfg_attr(test, assert_instr(mwaitx))]
+pub unsafe fn _mm_mwaitx(extensions: u32, hints: u32, clock: u32) {
+    // The timeout is passed in `EBX`, which LLVM reserves on x86_64, so it is
+    // swapped in and out around the instruction.
+    #[cfg(target_arch = "x86")]
+    asm!(
+        "xchg {0}, ebx",
+        "mwaitx",
+        "xchg {0}, ebx",
+        in(reg) clock,
+        in("ecx") extensions,
+        in("eax") hints,
+        options(nostack, preserves_flags),
+    );
+    #[cfg(target_arch = "x86_64")]
+    asm!(
+        "xchg {0}, rbx",
+        "mwaitx",
+        "xchg {0}, rbx",
+        in(reg) clock as u64,
+        in("ecx") extensions,
+        in("eax") hints,
+        options(nostack, preserves_flags),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+
+    #[test]
+    fn test_mm_monitorx() {
+        if !is_x86_feature_detected!("mwaitx") {
+            return stdarch_test::assert_skip_test_ok("test_mm_monitorx");
+        }
+        let mut x = 0_u64;
+        unsafe { _mm_monitorx(&x as *const _ as *const u8, 0, 0) };
+        // A store to the monitored address range disarms the monitor.
+        x = 1;
+        assert_eq!(x, 1);
+    }
+
+    #[test]
+    fn test_mm_mwaitx() {
+        if !is_x86_feature_detected!("mwaitx") {
+            return stdarch_test::assert_skip_test_ok("test_mm_mwaitx");
+        }
+        let x = 0_u64;
+        unsafe {
+            _mm_monitorx(&x as *const _ as *const u8, 0, 0);
+            // Enable the timeout, which is short enough that the wait returns
+            // promptly even if no store to `x` happens.
+            _mm_mwaitx(2, 0, 10_000);
+        }
+        assert_eq!(x, 0);
+    }
+}
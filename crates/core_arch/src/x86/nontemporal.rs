@@ -0,0 +1,209 @@
+//! A scope for non-temporal stores.
+//!
+//! Non-temporal ("streaming") stores are weakly ordered: they may become
+//! visible to other cores after later ordinary stores, and they are not
+//! ordered with respect to them even on the same core. This means that an
+//! [`_mm_sfence`] has to be executed after a sequence of non-temporal stores
+//! and before the stored data is published to another thread, for example by
+//! releasing a lock or storing to an atomic flag. [`NonTemporalScope`] ties
+//! the fence to the lifetime of a value so it can't be forgotten.
+
+use crate::{core_arch::x86::*, marker::PhantomData};
+
+/// A guard for a sequence of non-temporal stores which executes an
+/// [`_mm_sfence`] when it is dropped.
+///
+/// The methods of this type perform the same stores as the `_mm_stream_*`
+/// intrinsics and have the same requirements on their pointer arguments.
+/// This is the recommended way of using non-temporal stores: all stores done
+/// through the scope are ordered before anything the current thread does
+/// after the scope ends.
+///
+/// ```rust,ignore
+/// let mut buf = [0i32; 64];
+/// unsafe {
+///     let scope = NonTemporalScope::new();
+///     for (i, x) in buf.iter_mut().enumerate() {
+///         scope.stream_si32(x, i as i32);
+///     }
+/// }
+/// // The fence has been executed, so `buf` can be handed to another thread.
+/// ```
+///
+/// The scope must not be leaked, for example with [`mem::forget`], as the
+/// fence is then never executed.
+///
+/// [`mem::forget`]: crate::mem::forget
+#[allow(clippy::missing_inline_in_public_items)]
+#[must_use = "the fence is executed when the scope is dropped"]
+#[derive(Debug)]
+pub struct NonTemporalScope {
+    // The fence only orders the stores done by the current core, so the scope
+    // must not be sent to another thread.
+    _not_send: PhantomData<*mut ()>,
+}
+
+impl NonTemporalScope {
+    /// Starts a sequence of non-temporal stores.
+    ///
+    /// # Safety
+    ///
+    /// The CPU must support SSE2, which is needed for the stores and the
+    /// fence. The scope must be dropped normally, not leaked.
+    #[inline]
+    pub unsafe fn new() -> NonTemporalScope {
+        NonTemporalScope {
+            _not_send: PhantomData,
+        }
+    }
+
+    /// Stores `a` into `mem_addr` using a non-temporal memory hint, like
+    /// [`_mm_stream_si32`].
+    ///
+    /// # Safety
+    ///
+    /// `mem_addr` must be valid for writes of an `i32`.
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    pub unsafe fn stream_si32(&self, mem_addr: *mut i32, a: i32) {
+        _mm_stream_si32(mem_addr, a);
+    }
+
+    /// Stores `a` into `mem_addr` using a non-temporal memory hint, like
+    /// [`_mm_stream_si128`].
+    ///
+    /// # Safety
+    ///
+    /// `mem_addr` must be valid for writes of 16 bytes and aligned on a
+    /// 16-byte boundary.
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    pub unsafe fn stream_si128(&self, mem_addr: *mut __m128i, a: __m128i) {
+        _mm_stream_si128(mem_addr, a);
+    }
+
+    /// Stores `a` into `mem_addr` using a non-temporal memory hint, like
+    /// [`_mm_stream_ps`].
+    ///
+    /// # Safety
+    ///
+    /// `mem_addr` must be valid for writes of 16 bytes and aligned on a
+    /// 16-byte boundary.
+    #[inline]
+    #[target_feature(enable = "sse")]
+    pub unsafe fn stream_ps(&self, mem_addr: *mut f32, a: __m128) {
+        _mm_stream_ps(mem_addr, a);
+    }
+
+    /// Stores `a` into `mem_addr` using a non-temporal memory hint, like
+    /// [`_mm_stream_pd`].
+    ///
+    /// # Safety
+    ///
+    /// `mem_addr` must be valid for writes of 16 bytes and aligned on a
+    /// 16-byte boundary.
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    pub unsafe fn stream_pd(&self, mem_addr: *mut f64, a: __m128d) {
+        _mm_stream_pd(mem_addr, a);
+    }
+
+    /// Stores the lowest element of `a` into `p` using a non-temporal memory
+    /// hint, like [`_mm_stream_sd`].
+    ///
+    /// # Safety
+    ///
+    /// The CPU must support SSE4a, and `p` must be valid for writes of an
+    /// `f64`.
+    #[inline]
+    #[target_feature(enable = "sse4a")]
+    pub unsafe fn stream_sd(&self, p: *mut f64, a: __m128d) {
+        _mm_stream_sd(p, a);
+    }
+
+    /// Stores the lowest element of `a` into `p` using a non-temporal memory
+    /// hint, like [`_mm_stream_ss`].
+    ///
+    /// # Safety
+    ///
+    /// The CPU must support SSE4a, and `p` must be valid for writes of an
+    /// `f32`.
+    #[inline]
+    #[target_feature(enable = "sse4a")]
+    pub unsafe fn stream_ss(&self, p: *mut f32, a: __m128) {
+        _mm_stream_ss(p, a);
+    }
+}
+
+impl Drop for NonTemporalScope {
+    #[inline]
+    fn drop(&mut self) {
+        // SAFETY: the creator of the scope guaranteed that SSE2 is available.
+        unsafe { _mm_sfence() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+    use stdarch_test::simd_test;
+
+    #[repr(align(16))]
+    struct Memory<T> {
+        data: T,
+    }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_non_temporal_scope() {
+        let mut ints = [0i32; 64];
+        let mut vecs = Memory { data: [0i32; 64] };
+        let mut floats = Memory { data: [0f32; 16] };
+        let mut doubles = Memory { data: [0f64; 8] };
+        {
+            let scope = NonTemporalScope::new();
+            for (i, x) in ints.iter_mut().enumerate() {
+                scope.stream_si32(x, i as i32);
+            }
+            for (i, x) in vecs.data.chunks_exact_mut(4).enumerate() {
+                let i = i as i32 * 4;
+                let a = _mm_setr_epi32(i, i + 1, i + 2, i + 3);
+                scope.stream_si128(x.as_mut_ptr() as *mut __m128i, a);
+            }
+            for (i, x) in floats.data.chunks_exact_mut(4).enumerate() {
+                let i = i as f32 * 4.0;
+                scope.stream_ps(x.as_mut_ptr(), _mm_setr_ps(i, i + 1.0, i + 2.0, i + 3.0));
+            }
+            for (i, x) in doubles.data.chunks_exact_mut(2).enumerate() {
+                let i = i as f64 * 2.0;
+                scope.stream_pd(x.as_mut_ptr(), _mm_setr_pd(i, i + 1.0));
+            }
+        }
+        for (i, (x, y)) in ints.iter().zip(&vecs.data).enumerate() {
+            assert_eq!(*x, i as i32);
+            assert_eq!(*y, i as i32);
+        }
+        for (i, x) in floats.data.iter().enumerate() {
+            assert_eq!(*x, i as f32);
+        }
+        for (i, x) in doubles.data.iter().enumerate() {
+            assert_eq!(*x, i as f64);
+        }
+    }
+
+    #[simd_test(enable = "sse4a")]
+    unsafe fn test_non_temporal_scope_sse4a() {
+        let mut floats = [0f32; 8];
+        let mut doubles = [0f64; 8];
+        {
+            let scope = NonTemporalScope::new();
+            for (i, (x, y)) in floats.iter_mut().zip(&mut doubles).enumerate() {
+                scope.stream_ss(x, _mm_set_ss(i as f32));
+                scope.stream_sd(y, _mm_set_sd(i as f64));
+            }
+        }
+        for (i, (x, y)) in floats.iter().zip(&doubles).enumerate() {
+            assert_eq!(*x, i as f32);
+            assert_eq!(*y, i as f64);
+        }
+    }
+}
@@ -0,0 +1,75 @@
+//! Platform configuration (PCONFIG)
+//!
+//! `PCONFIG` is used to program keys for Multi-Key Total Memory Encryption
+//! (MKTME). It selects a leaf function with `EAX` and passes further
+//! operands in `RBX`, `RCX` and `RDX`, and it is privileged, so it can only
+//! be executed by the kernel.
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Executes the `PCONFIG` leaf function `leaf` and returns the value of
+/// `EAX` afterwards, which is the status of the operation for the
+/// `MKTME_KEY_PROGRAM` leaf.
+///
+/// `data` points to an array of three elements which hold the values of
+/// `RBX`, `RCX` and `RDX` (`EBX`, `ECX` and `EDX` on `x86`). They are loaded
+/// into the registers before executing the instruction and updated with
+/// their values afterwards. For `MKTME_KEY_PROGRAM` (leaf 0) the first
+/// element is the address of a `MKTME_KEY_PROGRAM_STRUCT`.
+///
+/// # Safety
+///
+/// `PCONFIG` can only be executed in ring 0. `data` must be valid for reads
+/// and writes of three `usize`s, and the caller has to uphold the
+/// requirements of the leaf function, like the validity of the addresses
+/// passed in the registers, as described in the Intel SDM.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_pconfig_u32)
+#[inline]
+#[cfg_attr(test, assert_instr(pconfig))]
+pub unsafe fn _pconfig_u32(leaf: u32, data: *mut usize) -> u32 {
+    let data = &mut *(data as *mut [usize; 3]);
+    let eax;
+    // LLVM sometimes reserves `ebx` for its internal use, so it is swapped
+    // with a scratch register around the instruction.
+    #[cfg(target_arch = "x86")]
+    {
+        asm!(
+            "xchgl %ebx, {0}",
+            "pconfig",
+            "xchgl %ebx, {0}",
+            inout(reg) data[0],
+            inlateout("eax") leaf => eax,
+            inout("ecx") data[1],
+            inout("edx") data[2],
+            options(nostack, att_syntax),
+        );
+    }
+    #[cfg(target_arch = "x86_64")]
+    {
+        asm!(
+            "xchgq %rbx, {0:r}",
+            "pconfig",
+            "xchgq %rbx, {0:r}",
+            inout(reg) data[0],
+            inlateout("eax") leaf => eax,
+            inout("rcx") data[1],
+            inout("rdx") data[2],
+            options(nostack, att_syntax),
+        );
+    }
+    eax
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+
+    // The instruction can only be executed by the kernel, so this only checks
+    // that the intrinsic can be used with the expected signature.
+    #[test]
+    fn test_pconfig_u32() {
+        let _: unsafe fn(u32, *mut usize) -> u32 = _pconfig_u32;
+    }
+}
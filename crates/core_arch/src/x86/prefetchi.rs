@@ -0,0 +1,66 @@
+//! Instruction prefetch hints (PREFETCHI)
+//!
+//! The hints are NOPs on processors without PREFETCHI.
+
+/// Fetches the cache line containing the code at `p` into all levels of the
+/// cache hierarchy, for example right before jumping to freshly generated
+/// code.
+///
+/// The instruction only supports RIP-relative addressing, other forms are
+/// executed as a NOP. The inline assembly has to take `p` in a register, so
+/// current processors ignore the hint.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_prefetchit0)
+#[inline]
+// Only the RIP-relative forms are disassembled as the hint, the others show up
+// as `nop`, so the instruction can't be checked for.
+pub unsafe fn _m_prefetchit0(p: *const u8) {
+    asm!(
+        "prefetchit0 byte ptr [{}]",
+        in(reg) p,
+        options(readonly, nostack, preserves_flags),
+    );
+}
+
+/// Fetches the cache line containing the code at `p` into all levels of the
+/// cache hierarchy except the first-level cache, for example right before
+/// jumping to freshly generated code.
+///
+/// The instruction only supports RIP-relative addressing, other forms are
+/// executed as a NOP. The inline assembly has to take `p` in a register, so
+/// current processors ignore the hint.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_prefetchit1)
+#[inline]
+// Only the RIP-relative forms are disassembled as the hint, the others show up
+// as `nop`, so the instruction can't be checked for.
+pub unsafe fn _m_prefetchit1(p: *const u8) {
+    asm!(
+        "prefetchit1 byte ptr [{}]",
+        in(reg) p,
+        options(readonly, nostack, preserves_flags),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+
+    fn target() -> u32 {
+        42
+    }
+
+    // The hints can be executed without PREFETCHI.
+
+    #[test]
+    fn test_m_prefetchit0() {
+        unsafe { _m_prefetchit0(target as *const u8) };
+        assert_eq!(target(), 42);
+    }
+
+    #[test]
+    fn test_m_prefetchit1() {
+        unsafe { _m_prefetchit1(target as *const u8) };
+        assert_eq!(target(), 42);
+    }
+}
@@ -0,0 +1,48 @@
+//! Prefetch for write (PRFCHW)
+
+extern "C" {
+    #[link_name = "llvm.prefetch"]
+    fn prefetch(p: *const i8, rw: i32, loc: i32, ty: i32);
+}
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Fetches the cache line containing `p` into the cache in the exclusive
+/// state, in anticipation of a write to it.
+///
+/// This is useful right before a read-modify-write of a location that is
+/// likely cached by other cores, for example the lock word of a spin lock or
+/// the link of a list node: a read prefetch would fetch the line in the
+/// shared state, and the write would then have to acquire ownership of it
+/// in a second step.
+///
+/// Like [`_mm_prefetch`](super::_mm_prefetch), this is only a hint. It
+/// doesn't modify memory and never faults, so `p` may be any pointer,
+/// including a dangling or null one, and the only requirement for calling it
+/// is for the CPU to support the `prfchw` feature.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_prefetchw)
+#[inline]
+#[target_feature(enable = "prfchw")]
+#[cfg_attr(test, assert_instr(prefetchw))]
+pub unsafe fn _m_prefetchw(p: *const u8) {
+    // We use the `llvm.prefetch` instrinsic with `rw` = 1 (write),
+    // `locality` = 3 (T0) and `cache type` = 1 (data cache).
+    prefetch(p as *const i8, 1, 3, 1);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+    use stdarch_test::simd_test;
+
+    #[simd_test(enable = "prfchw")]
+    unsafe fn test_m_prefetchw() {
+        let mut x = 41_u32;
+        _m_prefetchw(&x as *const u32 as *const u8);
+        x += 1;
+        assert_eq!(x, 42);
+        _m_prefetchw(std::ptr::null());
+    }
+}
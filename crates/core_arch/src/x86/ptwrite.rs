@@ -0,0 +1,35 @@
+//! Processor Trace write instruction (PTWRITE)
+//!
+//! PTWRITE inserts data chosen by the application into the Intel Processor
+//! Trace stream, for example to mark the start and end of interesting
+//! regions. Unless tracing and PTWRITE packets are enabled, the instruction
+//! does nothing.
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Insert the 32-bit data from `a` into a Processor Trace stream via a PTW
+/// packet. The PTW packet will be inserted if tracing is currently enabled and
+/// ptwrite is currently enabled. The current IP will also be inserted via a
+/// FUP packet if FUPonPTW is enabled.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_ptwrite32)
+#[inline]
+#[cfg_attr(test, assert_instr(ptwrite))]
+pub unsafe fn _ptwrite32(a: u32) {
+    asm!("ptwrite {:e}", in(reg) a, options(nostack, preserves_flags));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+
+    // This can't be a `simd_test`, which enables the target feature.
+    #[test]
+    fn test_ptwrite32() {
+        if !is_x86_feature_detected!("ptwrite") {
+            return stdarch_test::assert_skip_test_ok("test_ptwrite32");
+        }
+        unsafe { _ptwrite32(0x1234_5678) };
+    }
+}
@@ -0,0 +1,196 @@
+//! Remote Atomic Operations (RAO-INT)
+//!
+//! These instructions perform an atomic read-modify-write of a memory
+//! location without returning the previous value. Because nothing needs to be
+//! returned to the core, the processor may execute them close to where the
+//! cache line lives instead of pulling it into the local cache, which makes
+//! them much cheaper than `lock xadd` and friends for counters and flags that
+//! are updated by many cores but rarely read.
+//!
+//! The operations are atomic, but they are weakly ordered: they are not
+//! ordered with respect to other stores (including other RAO-INT operations
+//! to different locations), and only ordered with respect to loads from and
+//! stores to the same memory location. To publish the result of one or more
+//! of these operations to another thread through a different location, an
+//! [`_mm_sfence`](super::_mm_sfence) or [`_mm_mfence`](super::_mm_mfence) is
+//! needed between them and the store that does the publishing.
+//!
+//! The reference is [Intel 64 and IA-32 Architectures Software Developer's
+//! Manual Volume 2: Instruction Set Reference, A-Z][intel64_ref], and the
+//! [Intel Architecture Instruction Set Extensions and Future Features
+//! Programming Reference][future_ref].
+//!
+//! [intel64_ref]: http://www.intel.de/content/dam/www/public/us/en/documents/manuals/64-ia-32-architectures-software-developer-instruction-set-reference-manual-325383.pdf
+//! [future_ref]: https://software.intel.com/content/www/us/en/develop/download/intel-architecture-instruction-set-extensions-programming-reference.html
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Atomically adds `v` to the 32-bit integer at `p`, without returning the
+/// previous value.
+///
+/// The operation is weakly ordered, see the [module-level
+/// documentation](self) for the fencing needed to publish its result.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_aadd_i32)
+#[inline]
+#[cfg_attr(test, assert_instr(aadd))]
+pub unsafe fn _aadd_i32(p: *mut i32, v: i32) {
+    asm!(
+        "aadd dword ptr [{}], {:e}",
+        in(reg) p,
+        in(reg) v,
+        options(nostack, preserves_flags),
+    );
+}
+
+/// Atomically computes the bitwise AND of the 32-bit integer at `p` and `v`
+/// and stores it at `p`, without returning the previous value.
+///
+/// The operation is weakly ordered, see the [module-level
+/// documentation](self) for the fencing needed to publish its result.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_aand_i32)
+#[inline]
+#[cfg_attr(test, assert_instr(aand))]
+pub unsafe fn _aand_i32(p: *mut i32, v: i32) {
+    asm!(
+        "aand dword ptr [{}], {:e}",
+        in(reg) p,
+        in(reg) v,
+        options(nostack, preserves_flags),
+    );
+}
+
+/// Atomically computes the bitwise OR of the 32-bit integer at `p` and `v`
+/// and stores it at `p`, without returning the previous value.
+///
+/// The operation is weakly ordered, see the [module-level
+/// documentation](self) for the fencing needed to publish its result.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_aor_i32)
+#[inline]
+#[cfg_attr(test, assert_instr(aor))]
+pub unsafe fn _aor_i32(p: *mut i32, v: i32) {
+    asm!(
+        "aor dword ptr [{}], {:e}",
+        in(reg) p,
+        in(reg) v,
+        options(nostack, preserves_flags),
+    );
+}
+
+/// Atomically computes the bitwise XOR of the 32-bit integer at `p` and `v`
+/// and stores it at `p`, without returning the previous value.
+///
+/// The operation is weakly ordered, see the [module-level
+/// documentation](self) for the fencing needed to publish its result.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_axor_i32)
+#[inline]
+#[cfg_attr(test, assert_instr(axor))]
+pub unsafe fn _axor_i32(p: *mut i32, v: i32) {
+    asm!(
+        "axor dword ptr [{}], {:e}",
+        in(reg) p,
+        in(reg) v,
+        options(nostack, preserves_flags),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+    use std::{
+        sync::atomic::{AtomicI32, Ordering},
+        thread,
+        vec::Vec,
+    };
+
+    #[test]
+    fn test_aadd_i32() {
+        if !is_x86_feature_detected!("raoint") {
+            return stdarch_test::assert_skip_test_ok("test_aadd_i32");
+        }
+        unsafe {
+            let mut x = 40_i32;
+            _aadd_i32(&mut x, 2);
+            assert_eq!(x, 42);
+            _aadd_i32(&mut x, -50);
+            assert_eq!(x, -8);
+            let mut x = i32::MAX;
+            _aadd_i32(&mut x, 1);
+            assert_eq!(x, i32::MIN);
+        }
+    }
+
+    #[test]
+    fn test_aadd_i32_threads() {
+        if !is_x86_feature_detected!("raoint") {
+            return stdarch_test::assert_skip_test_ok("test_aadd_i32_threads");
+        }
+        const THREADS: i32 = 8;
+        const ITERATIONS: i32 = 10_000;
+        static COUNTER: AtomicI32 = AtomicI32::new(0);
+
+        let handles = (0..THREADS)
+            .map(|_| {
+                thread::spawn(|| {
+                    let p = &COUNTER as *const AtomicI32 as *mut i32;
+                    for _ in 0..ITERATIONS {
+                        unsafe { _aadd_i32(p, 1) };
+                    }
+                    // Make the weakly-ordered updates visible before the
+                    // thread is joined.
+                    unsafe { _mm_sfence() };
+                })
+            })
+            .collect::<Vec<_>>();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert_eq!(COUNTER.load(Ordering::SeqCst), THREADS * ITERATIONS);
+    }
+
+    #[test]
+    fn test_aand_i32() {
+        if !is_x86_feature_detected!("raoint") {
+            return stdarch_test::assert_skip_test_ok("test_aand_i32");
+        }
+        unsafe {
+            let mut x = 0b1100_i32;
+            _aand_i32(&mut x, 0b1010);
+            assert_eq!(x, 0b1000);
+            _aand_i32(&mut x, 0);
+            assert_eq!(x, 0);
+        }
+    }
+
+    #[test]
+    fn test_aor_i32() {
+        if !is_x86_feature_detected!("raoint") {
+            return stdarch_test::assert_skip_test_ok("test_aor_i32");
+        }
+        unsafe {
+            let mut x = 0b1100_i32;
+            _aor_i32(&mut x, 0b1010);
+            assert_eq!(x, 0b1110);
+            _aor_i32(&mut x, -1);
+            assert_eq!(x, -1);
+        }
+    }
+
+    #[test]
+    fn test_axor_i32() {
+        if !is_x86_feature_detected!("raoint") {
+            return stdarch_test::assert_skip_test_ok("test_axor_i32");
+        }
+        unsafe {
+            let mut x = 0b1100_i32;
+            _axor_i32(&mut x, 0b1010);
+            assert_eq!(x, 0b0110);
+            _axor_i32(&mut x, 0b0110);
+            assert_eq!(x, 0);
+        }
+    }
+}
@@ -0,0 +1,49 @@
+//! Read processor ID instruction (RDPID)
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Copy the IA32_TSC_AUX MSR (signature value) into `dst`.
+///
+/// Operating systems usually store the number of the current logical
+/// processor in this MSR, which makes this a cheaper way of getting it than
+/// calling into the operating system. It returns the same value as the `aux`
+/// output of [`__rdtscp`](super::__rdtscp), without reading the time-stamp
+/// counter.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_rdpid_u32)
+#[inline]
+#[cfg_attr(test, assert_instr(rdpid))]
+pub unsafe fn _rdpid_u32() -> u32 {
+    // The destination is a 64-bit register in 64-bit mode.
+    let aux: usize;
+    asm!("rdpid {}", out(reg) aux, options(nomem, nostack, preserves_flags));
+    aux as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+
+    // This can't be a `simd_test`, which enables the target feature.
+    #[test]
+    fn test_rdpid_u32() {
+        if !is_x86_feature_detected!("rdpid") {
+            return stdarch_test::assert_skip_test_ok("test_rdpid_u32");
+        }
+        // The thread may be moved to another processor between the two
+        // reads, so give it a few tries.
+        let mut matched = false;
+        for _ in 0..100 {
+            let mut aux = 0;
+            unsafe {
+                __rdtscp(&mut aux);
+                if _rdpid_u32() == aux {
+                    matched = true;
+                    break;
+                }
+            }
+        }
+        assert!(matched);
+    }
+}
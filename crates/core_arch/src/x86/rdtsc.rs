@@ -49,12 +49,38 @@ pub unsafe fn __rdtscp(aux: *mut u32) -> u64 {
     rdtscp(aux as *mut _)
 }
 
+/// Reads the performance-monitoring counter selected by `counter`.
+///
+/// Bits 29:0 of `counter` select the counter, and bit 30 selects between the
+/// general-purpose counters (when clear) and the fixed-function counters (when
+/// set). The width of the counters is implementation specific; the upper bits
+/// of the result are zero if the counter is narrower than 64 bits.
+///
+/// Like RDTSC, the RDPMC instruction is not a serializing instruction.
+///
+/// # Safety
+///
+/// RDPMC raises a general-protection fault if `counter` is not a valid
+/// counter index, and, outside of ring 0, if the operating system has not
+/// set `CR4.PCE`. On Linux, `CR4.PCE` is controlled through
+/// `/sys/devices/cpu/rdpmc`: with the default value of `1` it is only set
+/// while the process has a `perf_event` mapped, and with `2` it is always set.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_rdpmc)
+#[inline]
+#[cfg_attr(test, assert_instr(rdpmc))]
+pub unsafe fn __rdpmc(counter: i32) -> u64 {
+    rdpmc(counter)
+}
+
 #[allow(improper_ctypes)]
 extern "C" {
     #[link_name = "llvm.x86.rdtsc"]
     fn rdtsc() -> u64;
     #[link_name = "llvm.x86.rdtscp"]
     fn rdtscp(aux: *mut u8) -> u64;
+    #[link_name = "llvm.x86.rdpmc"]
+    fn rdpmc(counter: i32) -> u64;
 }
 
 #[cfg(test)]
@@ -74,4 +100,21 @@ mod tests {
         let r = rdtsc::__rdtscp(&mut aux);
         assert_ne!(r, 0); // The chances of this being 0 are infinitesimal
     }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_rdpmc() {
+        // Only `2` allows RDPMC without a `perf_event` being mapped.
+        match std::fs::read_to_string("/sys/devices/cpu/rdpmc") {
+            Ok(s) if s.trim() == "2" => {}
+            _ => {
+                println!("skipping: RDPMC is not permitted in user space");
+                return;
+            }
+        }
+        // General-purpose counter 0 is present on both Intel and AMD, but
+        // nothing may have programmed it, so all we can check is that reading
+        // it doesn't fault and that the result fits in the counter width.
+        let r = rdtsc::__rdpmc(0);
+        assert_eq!(r >> 48, 0);
+    }
 }
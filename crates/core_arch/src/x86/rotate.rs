@@ -0,0 +1,55 @@
+//! Scalar rotate intrinsics.
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Rotates the bits of the 16-bit integer `a` left by `shift` bits.
+///
+/// Only the low 4 bits of `shift` are used.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_rotwl)
+#[inline]
+#[cfg_attr(test, assert_instr(rol))]
+pub unsafe fn _rotwl(a: u16, shift: i32) -> u16 {
+    a.rotate_left(shift as u32)
+}
+
+/// Rotates the bits of the 16-bit integer `a` right by `shift` bits.
+///
+/// Only the low 4 bits of `shift` are used.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_rotwr)
+#[inline]
+#[cfg_attr(test, assert_instr(ror))]
+pub unsafe fn _rotwr(a: u16, shift: i32) -> u16 {
+    a.rotate_right(shift as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+
+    #[test]
+    fn test_rotwl() {
+        unsafe {
+            assert_eq!(_rotwl(0x0001, 0), 0x0001);
+            assert_eq!(_rotwl(0x0001, 1), 0x0002);
+            assert_eq!(_rotwl(0x8000, 1), 0x0001);
+            assert_eq!(_rotwl(0x1234, 4), 0x2341);
+            assert_eq!(_rotwl(0x1234, 16), 0x1234);
+            assert_eq!(_rotwl(0x1234, 20), 0x2341);
+        }
+    }
+
+    #[test]
+    fn test_rotwr() {
+        unsafe {
+            assert_eq!(_rotwr(0x0001, 0), 0x0001);
+            assert_eq!(_rotwr(0x0001, 1), 0x8000);
+            assert_eq!(_rotwr(0x8000, 1), 0x4000);
+            assert_eq!(_rotwr(0x1234, 4), 0x4123);
+            assert_eq!(_rotwr(0x1234, 16), 0x1234);
+            assert_eq!(_rotwr(0x1234, 20), 0x4123);
+        }
+    }
+}
@@ -0,0 +1,44 @@
+//! Serialize instruction (SERIALIZE)
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Serialize instruction execution, ensuring all modifications to flags,
+/// registers, and memory by previous instructions are completed before the
+/// next instruction is fetched.
+///
+/// Unlike [`_mm_mfence`](super::_mm_mfence) and
+/// [`_mm_lfence`](super::_mm_lfence), which only order memory accesses and
+/// the dispatch of later instructions respectively, this also drains the
+/// instruction pipeline and discards any prefetched instructions. That makes
+/// it suitable for cross-modifying code and for fencing the region measured by
+/// a benchmark. Other serializing instructions have drawbacks that this one
+/// avoids: [`__cpuid`](super::__cpuid) clobbers four registers and may cause a
+/// VM exit, and `IRET` or writes to control registers aren't available in user
+/// space or are much slower.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_serialize)
+#[inline]
+#[cfg_attr(test, assert_instr(serialize))]
+pub unsafe fn _serialize() {
+    asm!("serialize", options(nostack, preserves_flags));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{core_arch::x86::*, ptr};
+
+    // This can't be a `simd_test`, which enables the target feature.
+    #[test]
+    fn test_serialize() {
+        if !is_x86_feature_detected!("serialize") {
+            return stdarch_test::assert_skip_test_ok("test_serialize");
+        }
+        unsafe {
+            let mut x = 0_u32;
+            ptr::write_volatile(&mut x, 1);
+            _serialize();
+            assert_eq!(ptr::read_volatile(&x), 1);
+        }
+    }
+}
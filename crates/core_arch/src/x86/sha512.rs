@@ -0,0 +1,243 @@
+//! SHA512 intrinsics
+//!
+//! These accelerate the message schedule and compression rounds of SHA-512
+//! using VEX encoded instructions operating on 256-bit vectors of `u64`.
+
+use crate::{
+    core_arch::{simd::*, x86::*},
+    mem::transmute,
+};
+
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.x86.vsha512msg1"]
+    fn vsha512msg1(a: i64x4, b: i64x2) -> i64x4;
+    #[link_name = "llvm.x86.vsha512msg2"]
+    fn vsha512msg2(a: i64x4, b: i64x4) -> i64x4;
+    #[link_name = "llvm.x86.vsha512rnds2"]
+    fn vsha512rnds2(a: i64x4, b: i64x4, k: i64x2) -> i64x4;
+}
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Performs an intermediate calculation for the next four SHA512 message
+/// values (unsigned 64-bit integers) using previous message values from `a`
+/// and `b`, and returns the result.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_sha512msg1_epi64)
+#[inline]
+#[target_feature(enable = "sha512,avx")]
+#[cfg_attr(test, assert_instr(vsha512msg1))]
+pub unsafe fn _mm256_sha512msg1_epi64(a: __m256i, b: __m128i) -> __m256i {
+    transmute(vsha512msg1(a.as_i64x4(), b.as_i64x2()))
+}
+
+/// Performs the final calculation for the next four SHA512 message values
+/// (unsigned 64-bit integers) using the intermediate result in `a` and the
+/// previous message values in `b`, and returns the result.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_sha512msg2_epi64)
+#[inline]
+#[target_feature(enable = "sha512,avx")]
+#[cfg_attr(test, assert_instr(vsha512msg2))]
+pub unsafe fn _mm256_sha512msg2_epi64(a: __m256i, b: __m256i) -> __m256i {
+    transmute(vsha512msg2(a.as_i64x4(), b.as_i64x4()))
+}
+
+/// Performs two rounds of SHA512 operation using an initial SHA512 state
+/// (C,D,G,H) from `a`, an initial SHA512 state (A,B,E,F) from `b`, and a
+/// pre-computed sum of the next two round message values (unsigned 64-bit
+/// integers) and the corresponding round constants from `k` (only the two
+/// lower elements are used), and returns the updated SHA512 state (A,B,E,F).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_sha512rnds2_epi64)
+#[inline]
+#[target_feature(enable = "sha512,avx")]
+#[cfg_attr(test, assert_instr(vsha512rnds2))]
+pub unsafe fn _mm256_sha512rnds2_epi64(a: __m256i, b: __m256i, k: __m128i) -> __m256i {
+    transmute(vsha512rnds2(a.as_i64x4(), b.as_i64x4(), k.as_i64x2()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{core_arch::x86::*, mem::transmute};
+    use stdarch_test::simd_test;
+
+    #[rustfmt::skip]
+    const K: [u64; 80] = [
+        0x428a2f98d728ae22, 0x7137449123ef65cd, 0xb5c0fbcfec4d3b2f, 0xe9b5dba58189dbbc,
+        0x3956c25bf348b538, 0x59f111f1b605d019, 0x923f82a4af194f9b, 0xab1c5ed5da6d8118,
+        0xd807aa98a3030242, 0x12835b0145706fbe, 0x243185be4ee4b28c, 0x550c7dc3d5ffb4e2,
+        0x72be5d74f27b896f, 0x80deb1fe3b1696b1, 0x9bdc06a725c71235, 0xc19bf174cf692694,
+        0xe49b69c19ef14ad2, 0xefbe4786384f25e3, 0x0fc19dc68b8cd5b5, 0x240ca1cc77ac9c65,
+        0x2de92c6f592b0275, 0x4a7484aa6ea6e483, 0x5cb0a9dcbd41fbd4, 0x76f988da831153b5,
+        0x983e5152ee66dfab, 0xa831c66d2db43210, 0xb00327c898fb213f, 0xbf597fc7beef0ee4,
+        0xc6e00bf33da88fc2, 0xd5a79147930aa725, 0x06ca6351e003826f, 0x142929670a0e6e70,
+        0x27b70a8546d22ffc, 0x2e1b21385c26c926, 0x4d2c6dfc5ac42aed, 0x53380d139d95b3df,
+        0x650a73548baf63de, 0x766a0abb3c77b2a8, 0x81c2c92e47edaee6, 0x92722c851482353b,
+        0xa2bfe8a14cf10364, 0xa81a664bbc423001, 0xc24b8b70d0f89791, 0xc76c51a30654be30,
+        0xd192e819d6ef5218, 0xd69906245565a910, 0xf40e35855771202a, 0x106aa07032bbd1b8,
+        0x19a4c116b8d2d0c8, 0x1e376c085141ab53, 0x2748774cdf8eeb99, 0x34b0bcb5e19b48a8,
+        0x391c0cb3c5c95a63, 0x4ed8aa4ae3418acb, 0x5b9cca4f7763e373, 0x682e6ff3d6b2b8a3,
+        0x748f82ee5defb2fc, 0x78a5636f43172f60, 0x84c87814a1f0ab72, 0x8cc702081a6439ec,
+        0x90befffa23631e28, 0xa4506cebde82bde9, 0xbef9a3f7b2c67915, 0xc67178f2e372532b,
+        0xca273eceea26619c, 0xd186b8c721c0c207, 0xeada7dd6cde0eb1e, 0xf57d4f7fee6ed178,
+        0x06f067aa72176fba, 0x0a637dc5a2c898a6, 0x113f9804bef90dae, 0x1b710b35131c471b,
+        0x28db77f523047d84, 0x32caab7b40c72493, 0x3c9ebe0a15c9bebc, 0x431d67c49c100d4c,
+        0x4cc5d4becb3e42b6, 0x597f299cfc657e2a, 0x5fcb6fab3ad6faec, 0x6c44198c4a475817,
+    ];
+
+    #[rustfmt::skip]
+    const H0: [u64; 8] = [
+        0x6a09e667f3bcc908, 0xbb67ae8584caa73b, 0x3c6ef372fe94f82b, 0xa54ff53a5f1d36f1,
+        0x510e527fade682d1, 0x9b05688c2b3e6c1f, 0x1f83d9abfb41bd6b, 0x5be0cd19137e2179,
+    ];
+
+    // A straightforward implementation of the SHA-512 building blocks from
+    // FIPS 180-4 to compare the intrinsics against.
+
+    fn sigma0(x: u64) -> u64 {
+        x.rotate_right(1) ^ x.rotate_right(8) ^ (x >> 7)
+    }
+
+    fn sigma1(x: u64) -> u64 {
+        x.rotate_right(19) ^ x.rotate_right(61) ^ (x >> 6)
+    }
+
+    fn round(s: &mut [u64; 8], wk: u64) {
+        let [a, b, c, d, e, f, g, h] = *s;
+        let s1 = e.rotate_right(14) ^ e.rotate_right(18) ^ e.rotate_right(41);
+        let ch = (e & f) ^ (!e & g);
+        let t1 = h.wrapping_add(s1).wrapping_add(ch).wrapping_add(wk);
+        let s0 = a.rotate_right(28) ^ a.rotate_right(34) ^ a.rotate_right(39);
+        let maj = (a & b) ^ (a & c) ^ (b & c);
+        let t2 = s0.wrapping_add(maj);
+        *s = [t1.wrapping_add(t2), a, b, c, d.wrapping_add(t1), e, f, g];
+    }
+
+    fn compress(state: &mut [u64; 8], block: &[u64; 16]) {
+        let mut w = [0; 80];
+        w[..16].copy_from_slice(block);
+        for t in 16..80 {
+            w[t] = sigma1(w[t - 2])
+                .wrapping_add(w[t - 7])
+                .wrapping_add(sigma0(w[t - 15]))
+                .wrapping_add(w[t - 16]);
+        }
+        let mut s = *state;
+        for t in 0..80 {
+            round(&mut s, w[t].wrapping_add(K[t]));
+        }
+        for i in 0..8 {
+            state[i] = state[i].wrapping_add(s[i]);
+        }
+    }
+
+    // The padded single block of the message "abc".
+    fn abc_block() -> [u64; 16] {
+        let mut block = [0; 16];
+        block[0] = 0x6162638000000000;
+        block[15] = 24;
+        block
+    }
+
+    unsafe fn abef(s: &[u64; 8]) -> __m256i {
+        transmute([s[5], s[4], s[1], s[0]])
+    }
+
+    unsafe fn cdgh(s: &[u64; 8]) -> __m256i {
+        transmute([s[7], s[6], s[3], s[2]])
+    }
+
+    #[simd_test(enable = "sha512,avx")]
+    unsafe fn test_mm256_sha512msg1_epi64() {
+        let w = [K[0], K[1], K[2], K[3], K[4]];
+        let a = transmute([w[0], w[1], w[2], w[3]]);
+        let b = transmute([w[4], 0x0123456789abcdef_u64]);
+        let r: [u64; 4] = transmute(_mm256_sha512msg1_epi64(a, b));
+        let mut e = [0; 4];
+        for i in 0..4 {
+            e[i] = w[i].wrapping_add(sigma0(w[i + 1]));
+        }
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "sha512,avx")]
+    unsafe fn test_mm256_sha512msg2_epi64() {
+        let x = [K[10], K[11], K[12], K[13]];
+        let w = [K[20], K[21], K[22], K[23]];
+        let r: [u64; 4] = transmute(_mm256_sha512msg2_epi64(transmute(x), transmute(w)));
+        // The last two results depend on the first two.
+        let w16 = x[0].wrapping_add(sigma1(w[2]));
+        let w17 = x[1].wrapping_add(sigma1(w[3]));
+        let w18 = x[2].wrapping_add(sigma1(w16));
+        let w19 = x[3].wrapping_add(sigma1(w17));
+        assert_eq!(r, [w16, w17, w18, w19]);
+    }
+
+    #[simd_test(enable = "sha512,avx")]
+    unsafe fn test_mm256_sha512rnds2_epi64() {
+        let block = abc_block();
+        let wk = [
+            block[0].wrapping_add(K[0]),
+            block[1].wrapping_add(K[1]),
+            0xffffffffffffffff,
+            0xffffffffffffffff,
+        ];
+        let r = _mm256_sha512rnds2_epi64(cdgh(&H0), abef(&H0), transmute([wk[0], wk[1]]));
+
+        let mut s = H0;
+        round(&mut s, wk[0]);
+        round(&mut s, wk[1]);
+        assert_eq_m256i(r, abef(&s));
+    }
+
+    #[simd_test(enable = "sha512,avx2")]
+    unsafe fn test_sha512_compress() {
+        let block = abc_block();
+
+        let mut w = [0_u64; 80];
+        w[..16].copy_from_slice(&block);
+        for t in (16..80).step_by(4) {
+            let load = |i: usize| _mm256_loadu_si256(w.as_ptr().add(i) as *const _);
+            let x = _mm256_sha512msg1_epi64(load(t - 16), _mm256_castsi256_si128(load(t - 12)));
+            let x = _mm256_add_epi64(x, load(t - 7));
+            let x = _mm256_sha512msg2_epi64(x, load(t - 4));
+            _mm256_storeu_si256(w.as_mut_ptr().add(t) as *mut _, x);
+        }
+
+        let mut abef_ = abef(&H0);
+        let mut cdgh_ = cdgh(&H0);
+        for t in (0..80).step_by(4) {
+            let wk = _mm256_add_epi64(
+                _mm256_loadu_si256(w.as_ptr().add(t) as *const _),
+                _mm256_loadu_si256(K.as_ptr().add(t) as *const _),
+            );
+            let r = _mm256_sha512rnds2_epi64(cdgh_, abef_, _mm256_castsi256_si128(wk));
+            cdgh_ = abef_;
+            abef_ = r;
+            let r = _mm256_sha512rnds2_epi64(cdgh_, abef_, _mm256_extracti128_si256::<1>(wk));
+            cdgh_ = abef_;
+            abef_ = r;
+        }
+        let [f, e, b, a]: [u64; 4] = transmute(abef_);
+        let [h, g, d, c]: [u64; 4] = transmute(cdgh_);
+        let mut state = H0;
+        for (s, x) in state.iter_mut().zip(&[a, b, c, d, e, f, g, h]) {
+            *s = s.wrapping_add(*x);
+        }
+
+        let mut expected = H0;
+        compress(&mut expected, &block);
+        assert_eq!(state, expected);
+
+        // SHA-512("abc") from FIPS 180-4.
+        #[rustfmt::skip]
+        let digest = [
+            0xddaf35a193617aba, 0xcc417349ae204131, 0x12e6fa4e89a97ea2, 0x0a9eeee64b55d39a,
+            0x2192992a274fc1a8, 0x36ba3c23a3feebbd, 0x454d4423643ce80e, 0x2a9ac94fa54ca49f,
+        ];
+        assert_eq!(state, digest);
+    }
+}
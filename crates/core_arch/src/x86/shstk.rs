@@ -0,0 +1,211 @@
+//! Control-flow Enforcement Technology shadow stacks (CET_SS)
+//!
+//! When shadow stacks are enabled, `CALL` pushes the return address to a
+//! second stack which can't be written to by ordinary stores, and `RET`
+//! raises a control protection exception (`#CP`) if the return address on
+//! the regular stack doesn't match the one on the shadow stack. Runtimes
+//! that switch between stacks, for example to implement coroutines, have to
+//! switch shadow stacks along with them using these intrinsics.
+//!
+//! All of these intrinsics except [`_rdsspd_i32`] raise an invalid opcode
+//! exception (`#UD`) if shadow stacks are not enabled for the current
+//! privilege level, and all of them can leave the shadow stack out of sync
+//! with the regular stack, after which returning from a function raises
+//! `#CP`. `RDSSP` is executed as a NOP instead, and [`_rdsspd_i32`] then
+//! returns `0`, so it can be used to check whether shadow stacks are enabled.
+//!
+//! The reference is [Intel 64 and IA-32 Architectures Software Developer's
+//! Manual Volume 1: Basic Architecture, Chapter 17][intel64_ref].
+//!
+//! [intel64_ref]: https://www.intel.com/content/www/us/en/developer/articles/technical/intel-sdm.html
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Increments the shadow stack pointer by 4 times the unsigned 8-bit value
+/// in the lowest byte of `a`, popping that many 32-bit entries off the
+/// shadow stack.
+///
+/// # Safety
+///
+/// Shadow stacks must be enabled, and the popped entries must correspond to
+/// frames that are being removed from the regular stack as well.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_incsspd)
+#[inline]
+#[cfg_attr(test, assert_instr(incsspd))]
+pub unsafe fn _incsspd(a: i32) {
+    asm!("incsspd {:e}", in(reg) a, options(nostack, preserves_flags));
+}
+
+/// Reads the low 32 bits of the shadow stack pointer.
+///
+/// Returns `0` if shadow stacks are not enabled.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_rdsspd_i32)
+#[inline]
+#[cfg_attr(test, assert_instr(rdsspd))]
+pub unsafe fn _rdsspd_i32() -> i32 {
+    // `RDSSP` leaves its operand unchanged if shadow stacks are disabled, so
+    // it is initialized with zero.
+    let ssp;
+    asm!(
+        "rdsspd {:e}",
+        inout(reg) 0_i32 => ssp,
+        options(nomem, nostack, preserves_flags),
+    );
+    ssp
+}
+
+/// Pushes a restore token for the previous shadow stack to it, after a
+/// switch to a new shadow stack with [`_rstorssp`].
+///
+/// # Safety
+///
+/// Shadow stacks must be enabled, and the top of the current shadow stack
+/// must hold the previous-SSP token pushed by [`_rstorssp`].
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_saveprevssp)
+#[inline]
+#[cfg_attr(test, assert_instr(saveprevssp))]
+pub unsafe fn _saveprevssp() {
+    asm!("saveprevssp", options(nostack, preserves_flags));
+}
+
+/// Switches to the shadow stack whose restore token is at `p`, leaving a
+/// previous-SSP token on the new shadow stack.
+///
+/// # Safety
+///
+/// Shadow stacks must be enabled, and `p` must point to a valid restore
+/// token on a shadow stack. The regular stack has to be switched to the one
+/// that belongs to the new shadow stack as well.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_rstorssp)
+#[inline]
+#[cfg_attr(test, assert_instr(rstorssp))]
+pub unsafe fn _rstorssp(p: *mut u8) {
+    asm!("rstorssp [{}]", in(reg) p, options(nostack));
+}
+
+/// Writes `val` to the 32-bit shadow stack entry at `p`.
+///
+/// # Safety
+///
+/// Shadow stacks and shadow stack writes must be enabled for the current
+/// privilege level, and `p` must point to shadow stack memory.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_wrssd)
+#[inline]
+#[cfg_attr(test, assert_instr(wrssd))]
+pub unsafe fn _wrssd(val: i32, p: *mut u8) {
+    asm!(
+        "wrssd dword ptr [{}], {:e}",
+        in(reg) p,
+        in(reg) val,
+        options(nostack, preserves_flags),
+    );
+}
+
+/// Writes `val` to the 32-bit user mode shadow stack entry at `p`.
+///
+/// # Safety
+///
+/// `WRUSS` can only be executed in ring 0, and `p` must point to user mode
+/// shadow stack memory.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_wrussd)
+#[inline]
+#[cfg_attr(test, assert_instr(wrussd))]
+pub unsafe fn _wrussd(val: i32, p: *mut u8) {
+    asm!(
+        "wrussd dword ptr [{}], {:e}",
+        in(reg) p,
+        in(reg) val,
+        options(nostack, preserves_flags),
+    );
+}
+
+/// Marks the supervisor shadow stack token pointed to by
+/// `IA32_PL0_SSP` as busy and switches to the shadow stack.
+///
+/// # Safety
+///
+/// `SETSSBSY` can only be executed in ring 0, and the token must be valid
+/// and not busy.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_setssbsy)
+#[inline]
+#[cfg_attr(test, assert_instr(setssbsy))]
+pub unsafe fn _setssbsy() {
+    asm!("setssbsy", options(nostack));
+}
+
+/// Clears the busy flag of the supervisor shadow stack token at `p`.
+///
+/// # Safety
+///
+/// `CLRSSBSY` can only be executed in ring 0, and `p` must point to a valid
+/// busy supervisor shadow stack token.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_clrssbsy)
+#[inline]
+#[cfg_attr(test, assert_instr(clrssbsy))]
+pub unsafe fn _clrssbsy(p: *mut u8) {
+    asm!("clrssbsy [{}]", in(reg) p, options(nostack));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+
+    // `RDSSP` is a NOP on processors without shadow stacks, so this doesn't
+    // need them.
+    #[test]
+    fn test_rdsspd_i32() {
+        // The shadow stack pointer is `0` if the operating system didn't
+        // enable shadow stacks for this process, and 8-byte aligned
+        // otherwise.
+        let ssp = unsafe { _rdsspd_i32() };
+        assert_eq!(ssp % 8, 0);
+    }
+
+    // The other intrinsics either need shadow stacks to be enabled, or can
+    // only be executed by the kernel, so these only check that they can be
+    // used with the expected signatures.
+
+    #[test]
+    fn test_incsspd() {
+        let _: unsafe fn(i32) = _incsspd;
+    }
+
+    #[test]
+    fn test_saveprevssp() {
+        let _: unsafe fn() = _saveprevssp;
+    }
+
+    #[test]
+    fn test_rstorssp() {
+        let _: unsafe fn(*mut u8) = _rstorssp;
+    }
+
+    #[test]
+    fn test_wrssd() {
+        let _: unsafe fn(i32, *mut u8) = _wrssd;
+    }
+
+    #[test]
+    fn test_wrussd() {
+        let _: unsafe fn(i32, *mut u8) = _wrussd;
+    }
+
+    #[test]
+    fn test_setssbsy() {
+        let _: unsafe fn() = _setssbsy;
+    }
+
+    #[test]
+    fn test_clrssbsy() {
+        let _: unsafe fn(*mut u8) = _clrssbsy;
+    }
+}
@@ -0,0 +1,274 @@
+//! SM3 intrinsics
+//!
+//! These accelerate the message expansion and compression rounds of the SM3
+//! hash function specified in GB/T 32905-2016.
+
+use crate::{
+    core_arch::{simd::*, x86::*},
+    mem::transmute,
+};
+
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.x86.vsm3msg1"]
+    fn vsm3msg1(a: i32x4, b: i32x4, c: i32x4) -> i32x4;
+    #[link_name = "llvm.x86.vsm3msg2"]
+    fn vsm3msg2(a: i32x4, b: i32x4, c: i32x4) -> i32x4;
+    #[link_name = "llvm.x86.vsm3rnds2"]
+    fn vsm3rnds2(a: i32x4, b: i32x4, c: i32x4, d: i32) -> i32x4;
+}
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Performs an intermediate calculation for the next four SM3 message words
+/// (unsigned 32-bit integers) using previous message words `W[7..11]` from
+/// `a`, `W[13..16]` from `b` and `W[0..4]` from `c`, and returns the result.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_sm3msg1_epi32)
+#[inline]
+#[target_feature(enable = "sm3,avx")]
+#[cfg_attr(test, assert_instr(vsm3msg1))]
+pub unsafe fn _mm_sm3msg1_epi32(a: __m128i, b: __m128i, c: __m128i) -> __m128i {
+    transmute(vsm3msg1(a.as_i32x4(), b.as_i32x4(), c.as_i32x4()))
+}
+
+/// Performs the final calculation for the next four SM3 message words
+/// (unsigned 32-bit integers) using the intermediate result from
+/// [`_mm_sm3msg1_epi32`] in `a` and previous message words `W[3..7]` from `b`
+/// and `W[10..14]` from `c`, and returns the result.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_sm3msg2_epi32)
+#[inline]
+#[target_feature(enable = "sm3,avx")]
+#[cfg_attr(test, assert_instr(vsm3msg2))]
+pub unsafe fn _mm_sm3msg2_epi32(a: __m128i, b: __m128i, c: __m128i) -> __m128i {
+    transmute(vsm3msg2(a.as_i32x4(), b.as_i32x4(), c.as_i32x4()))
+}
+
+/// Performs two rounds of SM3 operation using an initial SM3 state (C,D,G,H)
+/// from `a`, an initial SM3 state (A,B,E,F) from `b` and the message words
+/// `W[j]`, `W[j+1]`, `W[j+4]` and `W[j+5]` from `c`, and returns the updated
+/// SM3 state (A,B,E,F).
+///
+/// The state in `a` is expected without the left rotations of C, D, G and H
+/// in the previous rounds applied, which is the (A,B,E,F) state from the
+/// previous call. `IMM8` is the number `j` of the first of the two rounds
+/// and has to be an even number in the range `0..=62`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_sm3rnds2_epi32)
+#[inline]
+#[target_feature(enable = "sm3,avx")]
+#[cfg_attr(test, assert_instr(vsm3rnds2, IMM8 = 0))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm_sm3rnds2_epi32<const IMM8: i32>(a: __m128i, b: __m128i, c: __m128i) -> __m128i {
+    static_assert!(IMM8: i32 where IMM8 >= 0 && IMM8 <= 62 && IMM8 % 2 == 0);
+    transmute(vsm3rnds2(a.as_i32x4(), b.as_i32x4(), c.as_i32x4(), IMM8))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{core_arch::x86::*, mem::transmute};
+    use stdarch_test::simd_test;
+
+    const IV: [u32; 8] = [
+        0x7380166f, 0x4914b2b9, 0x172442d7, 0xda8a0600, 0xa96f30bc, 0x163138aa, 0xe38dee4d,
+        0xb0fb0e4e,
+    ];
+
+    // A straightforward implementation of SM3 from GB/T 32905-2016 to
+    // compare the intrinsics against.
+
+    fn p0(x: u32) -> u32 {
+        x ^ x.rotate_left(9) ^ x.rotate_left(17)
+    }
+
+    fn p1(x: u32) -> u32 {
+        x ^ x.rotate_left(15) ^ x.rotate_left(23)
+    }
+
+    fn expand(block: &[u32; 16]) -> [u32; 68] {
+        let mut w = [0; 68];
+        w[..16].copy_from_slice(block);
+        for j in 16..68 {
+            w[j] = p1(w[j - 16] ^ w[j - 9] ^ w[j - 3].rotate_left(15))
+                ^ w[j - 13].rotate_left(7)
+                ^ w[j - 6];
+        }
+        w
+    }
+
+    fn round(s: &mut [u32; 8], j: usize, w: &[u32; 68]) {
+        let [a, b, c, d, e, f, g, h] = *s;
+        let (t, ff, gg) = if j < 16 {
+            (0x79cc4519_u32, a ^ b ^ c, e ^ f ^ g)
+        } else {
+            (
+                0x7a879d8a_u32,
+                (a & b) | (a & c) | (b & c),
+                (e & f) | (!e & g),
+            )
+        };
+        let ss1 = a
+            .rotate_left(12)
+            .wrapping_add(e)
+            .wrapping_add(t.rotate_left(j as u32 % 32))
+            .rotate_left(7);
+        let ss2 = ss1 ^ a.rotate_left(12);
+        let tt1 = ff
+            .wrapping_add(d)
+            .wrapping_add(ss2)
+            .wrapping_add(w[j] ^ w[j + 4]);
+        let tt2 = gg.wrapping_add(h).wrapping_add(ss1).wrapping_add(w[j]);
+        *s = [
+            tt1,
+            a,
+            b.rotate_left(9),
+            c,
+            p0(tt2),
+            e,
+            f.rotate_left(19),
+            g,
+        ];
+    }
+
+    fn compress(state: &mut [u32; 8], block: &[u32; 16]) {
+        let w = expand(block);
+        let mut s = *state;
+        for j in 0..64 {
+            round(&mut s, j, &w);
+        }
+        for i in 0..8 {
+            state[i] ^= s[i];
+        }
+    }
+
+    // The padded single block of the message "abc".
+    fn abc_block() -> [u32; 16] {
+        let mut block = [0; 16];
+        block[0] = 0x61626380;
+        block[15] = 24;
+        block
+    }
+
+    unsafe fn abef(s: &[u32; 8]) -> __m128i {
+        transmute([s[5], s[4], s[1], s[0]])
+    }
+
+    // C, D, G and H without the rotations applied by the previous round.
+    unsafe fn cdgh(s: &[u32; 8]) -> __m128i {
+        transmute([
+            s[7].rotate_right(19),
+            s[6].rotate_right(19),
+            s[3].rotate_right(9),
+            s[2].rotate_right(9),
+        ])
+    }
+
+    unsafe fn words(w: &[u32; 68], j: usize) -> __m128i {
+        transmute([w[j], w[j + 1], w[j + 4], w[j + 5]])
+    }
+
+    #[simd_test(enable = "sm3,avx")]
+    unsafe fn test_mm_sm3msg1_epi32() {
+        let w = expand(&abc_block());
+        let a = _mm_loadu_si128(w.as_ptr().add(23) as *const _);
+        let b = _mm_loadu_si128(w.as_ptr().add(29) as *const _);
+        let c = _mm_loadu_si128(w.as_ptr().add(16) as *const _);
+        let r: [u32; 4] = transmute(_mm_sm3msg1_epi32(a, b, c));
+        // The last word doesn't include `W[j - 3]`, which isn't known yet.
+        let e = [
+            p1(w[16] ^ w[23] ^ w[29].rotate_left(15)),
+            p1(w[17] ^ w[24] ^ w[30].rotate_left(15)),
+            p1(w[18] ^ w[25] ^ w[31].rotate_left(15)),
+            p1(w[19] ^ w[26]),
+        ];
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "sm3,avx")]
+    unsafe fn test_mm_sm3msg2_epi32() {
+        let w = expand(&abc_block());
+        let a = _mm_sm3msg1_epi32(
+            _mm_loadu_si128(w.as_ptr().add(23) as *const _),
+            _mm_loadu_si128(w.as_ptr().add(29) as *const _),
+            _mm_loadu_si128(w.as_ptr().add(16) as *const _),
+        );
+        let b = _mm_loadu_si128(w.as_ptr().add(19) as *const _);
+        let c = _mm_loadu_si128(w.as_ptr().add(26) as *const _);
+        let r: [u32; 4] = transmute(_mm_sm3msg2_epi32(a, b, c));
+        assert_eq!(r, [w[32], w[33], w[34], w[35]]);
+    }
+
+    #[simd_test(enable = "sm3,avx")]
+    unsafe fn test_mm_sm3rnds2_epi32() {
+        let w = expand(&abc_block());
+
+        let r = _mm_sm3rnds2_epi32::<0>(cdgh(&IV), abef(&IV), words(&w, 0));
+        let mut s = IV;
+        round(&mut s, 0, &w);
+        round(&mut s, 1, &w);
+        assert_eq_m128i(r, abef(&s));
+
+        // Rounds 16 and up use different boolean functions and constants.
+        let r = _mm_sm3rnds2_epi32::<16>(cdgh(&IV), abef(&IV), words(&w, 16));
+        let mut s = IV;
+        round(&mut s, 16, &w);
+        round(&mut s, 17, &w);
+        assert_eq_m128i(r, abef(&s));
+    }
+
+    #[simd_test(enable = "sm3,avx")]
+    unsafe fn test_sm3_compress() {
+        let block = abc_block();
+
+        let mut w = [0_u32; 68];
+        w[..16].copy_from_slice(&block);
+        for j in (16..68).step_by(4) {
+            let load = |i: usize| _mm_loadu_si128(w.as_ptr().add(i) as *const _);
+            let x = _mm_sm3msg1_epi32(load(j - 9), load(j - 3), load(j - 16));
+            let x = _mm_sm3msg2_epi32(x, load(j - 13), load(j - 6));
+            _mm_storeu_si128(w.as_mut_ptr().add(j) as *mut _, x);
+        }
+        assert_eq!(&w[..], &expand(&block)[..]);
+
+        let mut abef_ = abef(&IV);
+        let mut cdgh_ = cdgh(&IV);
+        macro_rules! rounds {
+            ($($j:literal)*) => {$(
+                let r = _mm_sm3rnds2_epi32::<$j>(cdgh_, abef_, words(&w, $j));
+                cdgh_ = abef_;
+                abef_ = r;
+            )*};
+        }
+        rounds!(0 2 4 6 8 10 12 14 16 18 20 22 24 26 28 30);
+        rounds!(32 34 36 38 40 42 44 46 48 50 52 54 56 58 60 62);
+        let [f, e, b, a]: [u32; 4] = transmute(abef_);
+        let [h, g, d, c]: [u32; 4] = transmute(cdgh_);
+        let s = [
+            a,
+            b,
+            c.rotate_left(9),
+            d.rotate_left(9),
+            e,
+            f,
+            g.rotate_left(19),
+            h.rotate_left(19),
+        ];
+        let mut state = IV;
+        for (x, y) in state.iter_mut().zip(&s) {
+            *x ^= *y;
+        }
+
+        let mut expected = IV;
+        compress(&mut expected, &block);
+        assert_eq!(state, expected);
+
+        // SM3("abc") from GB/T 32905-2016.
+        let digest = [
+            0x66c7f0f4, 0x62eeedd9, 0xd1f2d46b, 0xdc10e4e2, 0x4167c487, 0x5cf2f7a2, 0x297da02b,
+            0x8f4ba8e0,
+        ];
+        assert_eq!(state, digest);
+    }
+}
@@ -0,0 +1,240 @@
+//! SM4 intrinsics
+//!
+//! These perform four rounds of the SM4 block cipher specified in
+//! GB/T 32907-2016, or of its key expansion, at a time.
+
+use crate::{
+    core_arch::{simd::*, x86::*},
+    mem::transmute,
+};
+
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.x86.vsm4key4128"]
+    fn vsm4key4128(a: i32x4, b: i32x4) -> i32x4;
+    #[link_name = "llvm.x86.vsm4key4256"]
+    fn vsm4key4256(a: i32x8, b: i32x8) -> i32x8;
+    #[link_name = "llvm.x86.vsm4rnds4128"]
+    fn vsm4rnds4128(a: i32x4, b: i32x4) -> i32x4;
+    #[link_name = "llvm.x86.vsm4rnds4256"]
+    fn vsm4rnds4256(a: i32x8, b: i32x8) -> i32x8;
+}
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Performs four rounds of SM4 key expansion using the previous four round
+/// keys (unsigned 32-bit integers) in `a` and the four round constants in
+/// `b`, and returns the next four round keys.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_sm4key4_epi32)
+#[inline]
+#[target_feature(enable = "sm4,avx")]
+#[cfg_attr(test, assert_instr(vsm4key4))]
+pub unsafe fn _mm_sm4key4_epi32(a: __m128i, b: __m128i) -> __m128i {
+    transmute(vsm4key4128(a.as_i32x4(), b.as_i32x4()))
+}
+
+/// Performs four rounds of SM4 key expansion on each 128-bit lane using the
+/// previous four round keys (unsigned 32-bit integers) in `a` and the four
+/// round constants in `b`, and returns the next four round keys.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_sm4key4_epi32)
+#[inline]
+#[target_feature(enable = "sm4,avx")]
+#[cfg_attr(test, assert_instr(vsm4key4))]
+pub unsafe fn _mm256_sm4key4_epi32(a: __m256i, b: __m256i) -> __m256i {
+    transmute(vsm4key4256(a.as_i32x8(), b.as_i32x8()))
+}
+
+/// Performs four rounds of SM4 encryption using the current state (unsigned
+/// 32-bit integers) in `a` and the four round keys in `b`, and returns the
+/// next four state words.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_sm4rnds4_epi32)
+#[inline]
+#[target_feature(enable = "sm4,avx")]
+#[cfg_attr(test, assert_instr(vsm4rnds4))]
+pub unsafe fn _mm_sm4rnds4_epi32(a: __m128i, b: __m128i) -> __m128i {
+    transmute(vsm4rnds4128(a.as_i32x4(), b.as_i32x4()))
+}
+
+/// Performs four rounds of SM4 encryption on each 128-bit lane using the
+/// current state (unsigned 32-bit integers) in `a` and the four round keys in
+/// `b`, and returns the next four state words.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_sm4rnds4_epi32)
+#[inline]
+#[target_feature(enable = "sm4,avx")]
+#[cfg_attr(test, assert_instr(vsm4rnds4))]
+pub unsafe fn _mm256_sm4rnds4_epi32(a: __m256i, b: __m256i) -> __m256i {
+    transmute(vsm4rnds4256(a.as_i32x8(), b.as_i32x8()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{core_arch::x86::*, mem::transmute};
+    use stdarch_test::simd_test;
+
+    #[rustfmt::skip]
+    const SBOX: [u8; 256] = [
+        0xd6, 0x90, 0xe9, 0xfe, 0xcc, 0xe1, 0x3d, 0xb7, 0x16, 0xb6, 0x14, 0xc2, 0x28, 0xfb, 0x2c, 0x05,
+        0x2b, 0x67, 0x9a, 0x76, 0x2a, 0xbe, 0x04, 0xc3, 0xaa, 0x44, 0x13, 0x26, 0x49, 0x86, 0x06, 0x99,
+        0x9c, 0x42, 0x50, 0xf4, 0x91, 0xef, 0x98, 0x7a, 0x33, 0x54, 0x0b, 0x43, 0xed, 0xcf, 0xac, 0x62,
+        0xe4, 0xb3, 0x1c, 0xa9, 0xc9, 0x08, 0xe8, 0x95, 0x80, 0xdf, 0x94, 0xfa, 0x75, 0x8f, 0x3f, 0xa6,
+        0x47, 0x07, 0xa7, 0xfc, 0xf3, 0x73, 0x17, 0xba, 0x83, 0x59, 0x3c, 0x19, 0xe6, 0x85, 0x4f, 0xa8,
+        0x68, 0x6b, 0x81, 0xb2, 0x71, 0x64, 0xda, 0x8b, 0xf8, 0xeb, 0x0f, 0x4b, 0x70, 0x56, 0x9d, 0x35,
+        0x1e, 0x24, 0x0e, 0x5e, 0x63, 0x58, 0xd1, 0xa2, 0x25, 0x22, 0x7c, 0x3b, 0x01, 0x21, 0x78, 0x87,
+        0xd4, 0x00, 0x46, 0x57, 0x9f, 0xd3, 0x27, 0x52, 0x4c, 0x36, 0x02, 0xe7, 0xa0, 0xc4, 0xc8, 0x9e,
+        0xea, 0xbf, 0x8a, 0xd2, 0x40, 0xc7, 0x38, 0xb5, 0xa3, 0xf7, 0xf2, 0xce, 0xf9, 0x61, 0x15, 0xa1,
+        0xe0, 0xae, 0x5d, 0xa4, 0x9b, 0x34, 0x1a, 0x55, 0xad, 0x93, 0x32, 0x30, 0xf5, 0x8c, 0xb1, 0xe3,
+        0x1d, 0xf6, 0xe2, 0x2e, 0x82, 0x66, 0xca, 0x60, 0xc0, 0x29, 0x23, 0xab, 0x0d, 0x53, 0x4e, 0x6f,
+        0xd5, 0xdb, 0x37, 0x45, 0xde, 0xfd, 0x8e, 0x2f, 0x03, 0xff, 0x6a, 0x72, 0x6d, 0x6c, 0x5b, 0x51,
+        0x8d, 0x1b, 0xaf, 0x92, 0xbb, 0xdd, 0xbc, 0x7f, 0x11, 0xd9, 0x5c, 0x41, 0x1f, 0x10, 0x5a, 0xd8,
+        0x0a, 0xc1, 0x31, 0x88, 0xa5, 0xcd, 0x7b, 0xbd, 0x2d, 0x74, 0xd0, 0x12, 0xb8, 0xe5, 0xb4, 0xb0,
+        0x89, 0x69, 0x97, 0x4a, 0x0c, 0x96, 0x77, 0x7e, 0x65, 0xb9, 0xf1, 0x09, 0xc5, 0x6e, 0xc6, 0x84,
+        0x18, 0xf0, 0x7d, 0xec, 0x3a, 0xdc, 0x4d, 0x20, 0x79, 0xee, 0x5f, 0x3e, 0xd7, 0xcb, 0x39, 0x48,
+    ];
+
+    const FK: [u32; 4] = [0xa3b1bac6, 0x56aa3350, 0x677d9197, 0xb27022dc];
+
+    // The example from GB/T 32907-2016, which uses the same value as key and
+    // plaintext.
+    const KEY: [u32; 4] = [0x01234567, 0x89abcdef, 0xfedcba98, 0x76543210];
+    const CIPHERTEXT: [u32; 4] = [0x681edf34, 0xd206965e, 0x86b3e94f, 0x536e4246];
+
+    // A straightforward implementation of SM4 to compare the intrinsics
+    // against.
+
+    fn tau(x: u32) -> u32 {
+        let b = x.to_be_bytes();
+        u32::from_be_bytes([
+            SBOX[b[0] as usize],
+            SBOX[b[1] as usize],
+            SBOX[b[2] as usize],
+            SBOX[b[3] as usize],
+        ])
+    }
+
+    fn t(x: u32) -> u32 {
+        let x = tau(x);
+        x ^ x.rotate_left(2) ^ x.rotate_left(10) ^ x.rotate_left(18) ^ x.rotate_left(24)
+    }
+
+    fn t_key(x: u32) -> u32 {
+        let x = tau(x);
+        x ^ x.rotate_left(13) ^ x.rotate_left(23)
+    }
+
+    fn ck() -> [u32; 32] {
+        let mut ck = [0; 32];
+        for i in 0..32 {
+            let b = |j: usize| ((4 * i + j) * 7) as u8;
+            ck[i] = u32::from_be_bytes([b(0), b(1), b(2), b(3)]);
+        }
+        ck
+    }
+
+    fn round_keys(key: [u32; 4]) -> [u32; 32] {
+        let ck = ck();
+        let mut k = [0; 36];
+        for i in 0..4 {
+            k[i] = key[i] ^ FK[i];
+        }
+        for i in 0..32 {
+            k[i + 4] = k[i] ^ t_key(k[i + 1] ^ k[i + 2] ^ k[i + 3] ^ ck[i]);
+        }
+        let mut rk = [0; 32];
+        rk.copy_from_slice(&k[4..]);
+        rk
+    }
+
+    // Returns the state after the last round, without the final reversal.
+    fn rounds(rk: &[u32; 32], block: [u32; 4]) -> [u32; 4] {
+        let mut x = [0; 36];
+        x[..4].copy_from_slice(&block);
+        for i in 0..32 {
+            x[i + 4] = x[i] ^ t(x[i + 1] ^ x[i + 2] ^ x[i + 3] ^ rk[i]);
+        }
+        [x[32], x[33], x[34], x[35]]
+    }
+
+    fn reverse(x: [u32; 4]) -> [u32; 4] {
+        [x[3], x[2], x[1], x[0]]
+    }
+
+    #[simd_test(enable = "sm4,avx")]
+    unsafe fn test_mm_sm4key4_epi32() {
+        let ck = ck();
+        let mut k: __m128i = transmute([
+            KEY[0] ^ FK[0],
+            KEY[1] ^ FK[1],
+            KEY[2] ^ FK[2],
+            KEY[3] ^ FK[3],
+        ]);
+        let mut rk = [0_u32; 32];
+        for i in (0..32).step_by(4) {
+            k = _mm_sm4key4_epi32(k, _mm_loadu_si128(ck.as_ptr().add(i) as *const _));
+            _mm_storeu_si128(rk.as_mut_ptr().add(i) as *mut _, k);
+        }
+        assert_eq!(rk, round_keys(KEY));
+        assert_eq!(rk[0], 0xf12186f9);
+        assert_eq!(rk[31], 0x9124a012);
+    }
+
+    #[simd_test(enable = "sm4,avx")]
+    unsafe fn test_mm256_sm4key4_epi32() {
+        // Each 128-bit lane expands a different key.
+        let key2 = CIPHERTEXT;
+        let ck = ck();
+        let mut k: __m256i = transmute([
+            KEY[0] ^ FK[0],
+            KEY[1] ^ FK[1],
+            KEY[2] ^ FK[2],
+            KEY[3] ^ FK[3],
+            key2[0] ^ FK[0],
+            key2[1] ^ FK[1],
+            key2[2] ^ FK[2],
+            key2[3] ^ FK[3],
+        ]);
+        let mut rk1 = [0_u32; 32];
+        let mut rk2 = [0_u32; 32];
+        for i in (0..32).step_by(4) {
+            let c = _mm_loadu_si128(ck.as_ptr().add(i) as *const _);
+            k = _mm256_sm4key4_epi32(k, _mm256_set_m128i(c, c));
+            _mm_storeu_si128(rk1.as_mut_ptr().add(i) as *mut _, _mm256_castsi256_si128(k));
+            _mm_storeu_si128(
+                rk2.as_mut_ptr().add(i) as *mut _,
+                _mm256_extractf128_si256::<1>(k),
+            );
+        }
+        assert_eq!(rk1, round_keys(KEY));
+        assert_eq!(rk2, round_keys(key2));
+    }
+
+    #[simd_test(enable = "sm4,avx")]
+    unsafe fn test_mm_sm4rnds4_epi32() {
+        // A full 32 round encryption of a single block.
+        let rk = round_keys(KEY);
+        let mut x: __m128i = transmute(KEY);
+        for i in (0..32).step_by(4) {
+            x = _mm_sm4rnds4_epi32(x, _mm_loadu_si128(rk.as_ptr().add(i) as *const _));
+        }
+        let r: [u32; 4] = transmute(x);
+        assert_eq!(reverse(r), CIPHERTEXT);
+    }
+
+    #[simd_test(enable = "sm4,avx")]
+    unsafe fn test_mm256_sm4rnds4_epi32() {
+        // Each 128-bit lane encrypts a different block.
+        let rk = round_keys(KEY);
+        let mut x: __m256i = transmute([KEY, CIPHERTEXT]);
+        for i in (0..32).step_by(4) {
+            let k = _mm_loadu_si128(rk.as_ptr().add(i) as *const _);
+            x = _mm256_sm4rnds4_epi32(x, _mm256_set_m128i(k, k));
+        }
+        let r: [[u32; 4]; 2] = transmute(x);
+        assert_eq!(reverse(r[0]), CIPHERTEXT);
+        assert_eq!(r[1], rounds(&rk, CIPHERTEXT));
+    }
+}
@@ -892,7 +892,8 @@ pub unsafe fn _mm_cvt_si2ss(a: __m128, b: i32) -> __m128 {
 #[target_feature(enable = "sse")]
 #[cfg_attr(test, assert_instr(movss))]
 #[stable(feature = "simd_x86", since = "1.27.0")]
-pub unsafe fn _mm_set_ss(a: f32) -> __m128 {
+#[rustc_const_unstable(feature = "stdarch_const_x86", issue = "none")]
+pub const unsafe fn _mm_set_ss(a: f32) -> __m128 {
     __m128(a, 0.0, 0.0, 0.0)
 }
 
@@ -903,7 +904,8 @@ pub unsafe fn _mm_set_ss(a: f32) -> __m128 {
 #[target_feature(enable = "sse")]
 #[cfg_attr(test, assert_instr(shufps))]
 #[stable(feature = "simd_x86", since = "1.27.0")]
-pub unsafe fn _mm_set1_ps(a: f32) -> __m128 {
+#[rustc_const_unstable(feature = "stdarch_const_x86", issue = "none")]
+pub const unsafe fn _mm_set1_ps(a: f32) -> __m128 {
     __m128(a, a, a, a)
 }
 
@@ -914,7 +916,8 @@ pub unsafe fn _mm_set1_ps(a: f32) -> __m128 {
 #[target_feature(enable = "sse")]
 #[cfg_attr(test, assert_instr(shufps))]
 #[stable(feature = "simd_x86", since = "1.27.0")]
-pub unsafe fn _mm_set_ps1(a: f32) -> __m128 {
+#[rustc_const_unstable(feature = "stdarch_const_x86", issue = "none")]
+pub const unsafe fn _mm_set_ps1(a: f32) -> __m128 {
     _mm_set1_ps(a)
 }
 
@@ -941,7 +944,8 @@ pub unsafe fn _mm_set_ps1(a: f32) -> __m128 {
 #[target_feature(enable = "sse")]
 #[cfg_attr(test, assert_instr(unpcklps))]
 #[stable(feature = "simd_x86", since = "1.27.0")]
-pub unsafe fn _mm_set_ps(a: f32, b: f32, c: f32, d: f32) -> __m128 {
+#[rustc_const_unstable(feature = "stdarch_const_x86", issue = "none")]
+pub const unsafe fn _mm_set_ps(a: f32, b: f32, c: f32, d: f32) -> __m128 {
     __m128(d, c, b, a)
 }
 
@@ -967,7 +971,8 @@ pub unsafe fn _mm_set_ps(a: f32, b: f32, c: f32, d: f32) -> __m128 {
     assert_instr(movaps)
 )]
 #[stable(feature = "simd_x86", since = "1.27.0")]
-pub unsafe fn _mm_setr_ps(a: f32, b: f32, c: f32, d: f32) -> __m128 {
+#[rustc_const_unstable(feature = "stdarch_const_x86", issue = "none")]
+pub const unsafe fn _mm_setr_ps(a: f32, b: f32, c: f32, d: f32) -> __m128 {
     __m128(a, b, c, d)
 }
 
@@ -978,7 +983,8 @@ pub unsafe fn _mm_setr_ps(a: f32, b: f32, c: f32, d: f32) -> __m128 {
 #[target_feature(enable = "sse")]
 #[cfg_attr(test, assert_instr(xorps))]
 #[stable(feature = "simd_x86", since = "1.27.0")]
-pub unsafe fn _mm_setzero_ps() -> __m128 {
+#[rustc_const_unstable(feature = "stdarch_const_x86", issue = "none")]
+pub const unsafe fn _mm_setzero_ps() -> __m128 {
     __m128(0.0, 0.0, 0.0, 0.0)
 }
 
@@ -1146,6 +1152,7 @@ pub unsafe fn _mm_load_ps1(p: *const f32) -> __m128 {
 #[stable(feature = "simd_x86", since = "1.27.0")]
 #[allow(clippy::cast_ptr_alignment)]
 pub unsafe fn _mm_load_ps(p: *const f32) -> __m128 {
+    debug_assert_aligned!(p, 16);
     *(p as *const __m128)
 }
 
@@ -1200,6 +1207,7 @@ pub unsafe fn _mm_loadu_ps(p: *const f32) -> __m128 {
 #[cfg_attr(test, assert_instr(movaps))]
 #[stable(feature = "simd_x86", since = "1.27.0")]
 pub unsafe fn _mm_loadr_ps(p: *const f32) -> __m128 {
+    debug_assert_aligned!(p, 16);
     let a = _mm_load_ps(p);
     simd_shuffle4(a, a, [3, 2, 1, 0])
 }
@@ -1253,6 +1261,7 @@ pub unsafe fn _mm_store_ss(p: *mut f32, a: __m128) {
 #[stable(feature = "simd_x86", since = "1.27.0")]
 #[allow(clippy::cast_ptr_alignment)]
 pub unsafe fn _mm_store1_ps(p: *mut f32, a: __m128) {
+    debug_assert_aligned!(p, 16);
     let b: __m128 = simd_shuffle4(a, a, [0, 0, 0, 0]);
     *(p as *mut __m128) = b;
 }
@@ -1265,6 +1274,7 @@ pub unsafe fn _mm_store1_ps(p: *mut f32, a: __m128) {
 #[cfg_attr(test, assert_instr(movaps))]
 #[stable(feature = "simd_x86", since = "1.27.0")]
 pub unsafe fn _mm_store_ps1(p: *mut f32, a: __m128) {
+    debug_assert_aligned!(p, 16);
     _mm_store1_ps(p, a);
 }
 
@@ -1285,6 +1295,7 @@ pub unsafe fn _mm_store_ps1(p: *mut f32, a: __m128) {
 #[stable(feature = "simd_x86", since = "1.27.0")]
 #[allow(clippy::cast_ptr_alignment)]
 pub unsafe fn _mm_store_ps(p: *mut f32, a: __m128) {
+    debug_assert_aligned!(p, 16);
     *(p as *mut __m128) = a;
 }
 
@@ -1329,6 +1340,7 @@ pub unsafe fn _mm_storeu_ps(p: *mut f32, a: __m128) {
 #[stable(feature = "simd_x86", since = "1.27.0")]
 #[allow(clippy::cast_ptr_alignment)]
 pub unsafe fn _mm_storer_ps(p: *mut f32, a: __m128) {
+    debug_assert_aligned!(p, 16);
     let b: __m128 = simd_shuffle4(a, a, [3, 2, 1, 0]);
     *(p as *mut __m128) = b;
 }
@@ -1677,6 +1689,109 @@ pub unsafe fn _MM_SET_ROUNDING_MODE(x: u32) {
     _mm_setcsr((_mm_getcsr() & !_MM_ROUND_MASK) | x)
 }
 
+/// Denormals-are-zero bit of the MXCSR register.
+const MXCSR_DAZ: u32 = 0x0040;
+
+/// Returns whether flush-to-zero mode is enabled, i.e. whether SSE
+/// instructions replace denormal results with zero.
+///
+/// See [`_mm_set_flush_zero_mode`] for when changing this is sound.
+#[inline]
+#[target_feature(enable = "sse")]
+#[cfg_attr(test, assert_instr(stmxcsr))]
+pub unsafe fn _mm_get_flush_zero_mode() -> bool {
+    _mm_getcsr() & _MM_FLUSH_ZERO_MASK != 0
+}
+
+/// Enables or disables flush-to-zero mode, in which SSE instructions replace
+/// denormal results with zero, and leaves all other bits of the MXCSR
+/// register alone.
+///
+/// This is typically enabled around hot loops in DSP or audio code, where
+/// values decaying towards zero would otherwise hit the slow path for
+/// denormals in the floating point unit.
+///
+/// # Safety
+///
+/// Rust code is compiled under the assumption that the floating point
+/// environment is the default one: round to nearest, no flushing of
+/// denormals and all exceptions masked. The compiler is free to evaluate
+/// floating point operations at compile time, move them across calls to this
+/// function, or lower them to instructions that are not affected by MXCSR at
+/// all (such as x87 instructions on 32-bit targets), so it is not guaranteed
+/// which operations observe the changed mode. The caller has to make sure that
+/// no code relies on the results of floating point operations while the mode
+/// is changed, other than code that is fine with either result, and has to
+/// restore the previous mode before returning to code that does.
+#[inline]
+#[target_feature(enable = "sse")]
+#[cfg_attr(test, assert_instr(ldmxcsr))]
+pub unsafe fn _mm_set_flush_zero_mode(enable: bool) {
+    let mode = if enable {
+        _MM_FLUSH_ZERO_ON
+    } else {
+        _MM_FLUSH_ZERO_OFF
+    };
+    _mm_setcsr((_mm_getcsr() & !_MM_FLUSH_ZERO_MASK) | mode)
+}
+
+/// Returns whether denormals-are-zero mode is enabled, i.e. whether SSE
+/// instructions treat denormal inputs as zero.
+///
+/// See [`_mm_set_flush_zero_mode`] for when changing this is sound.
+#[inline]
+#[target_feature(enable = "sse")]
+#[cfg_attr(test, assert_instr(stmxcsr))]
+pub unsafe fn _mm_get_denormals_zero_mode() -> bool {
+    _mm_getcsr() & MXCSR_DAZ != 0
+}
+
+/// Enables or disables denormals-are-zero mode, in which SSE instructions
+/// treat denormal inputs as zero, and leaves all other bits of the MXCSR
+/// register alone.
+///
+/// This is usually combined with flush-to-zero mode. Some early SSE
+/// processors do not support it, in which case enabling it raises a general
+/// protection fault.
+///
+/// # Safety
+///
+/// See [`_mm_set_flush_zero_mode`].
+#[inline]
+#[target_feature(enable = "sse")]
+#[cfg_attr(test, assert_instr(ldmxcsr))]
+pub unsafe fn _mm_set_denormals_zero_mode(enable: bool) {
+    let mode = if enable { MXCSR_DAZ } else { 0 };
+    _mm_setcsr((_mm_getcsr() & !MXCSR_DAZ) | mode)
+}
+
+/// Returns the rounding mode used by SSE instructions, one of
+/// `_MM_ROUND_NEAREST`, `_MM_ROUND_DOWN`, `_MM_ROUND_UP` and
+/// `_MM_ROUND_TOWARD_ZERO`.
+///
+/// See [`_mm_set_flush_zero_mode`] for when changing this is sound.
+#[inline]
+#[target_feature(enable = "sse")]
+#[cfg_attr(test, assert_instr(stmxcsr))]
+pub unsafe fn _mm_get_rounding_mode() -> u32 {
+    _mm_getcsr() & _MM_ROUND_MASK
+}
+
+/// Sets the rounding mode used by SSE instructions to `mode`, which is one of
+/// `_MM_ROUND_NEAREST`, `_MM_ROUND_DOWN`, `_MM_ROUND_UP` and
+/// `_MM_ROUND_TOWARD_ZERO`, and leaves all other bits of the MXCSR register
+/// alone.
+///
+/// # Safety
+///
+/// See [`_mm_set_flush_zero_mode`].
+#[inline]
+#[target_feature(enable = "sse")]
+#[cfg_attr(test, assert_instr(ldmxcsr))]
+pub unsafe fn _mm_set_rounding_mode(mode: u32) {
+    _mm_setcsr((_mm_getcsr() & !_MM_ROUND_MASK) | (mode & _MM_ROUND_MASK))
+}
+
 /// See [`_mm_prefetch`](fn._mm_prefetch.html).
 #[stable(feature = "simd_x86", since = "1.27.0")]
 pub const _MM_HINT_T0: i32 = 3;
@@ -1769,10 +1884,14 @@ pub unsafe fn _mm_undefined_ps() -> __m128 {
 
 /// Transpose the 4x4 matrix formed by 4 rows of __m128 in place.
 ///
+/// This is the equivalent of the `_MM_TRANSPOSE4_PS` macro from
+/// `xmmintrin.h`, and is made up of the same four unpacks and four moves.
+///
 /// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_MM_TRANSPOSE4_PS)
 #[inline]
 #[allow(non_snake_case)]
 #[target_feature(enable = "sse")]
+#[cfg_attr(test, assert_instr(unpcklps))]
 #[stable(feature = "simd_x86", since = "1.27.0")]
 pub unsafe fn _MM_TRANSPOSE4_PS(
     row0: &mut __m128,
@@ -1872,6 +1991,8 @@ extern "C" {
 /// `mem_addr` must be aligned on a 16-byte boundary or a general-protection
 /// exception _may_ be generated.
 ///
+/// See [`NonTemporalScope`] for ordering the store with other memory accesses.
+///
 /// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_stream_ps)
 #[inline]
 #[target_feature(enable = "sse")]
@@ -1879,6 +2000,7 @@ extern "C" {
 #[stable(feature = "simd_x86", since = "1.27.0")]
 #[allow(clippy::cast_ptr_alignment)]
 pub unsafe fn _mm_stream_ps(mem_addr: *mut f32, a: __m128) {
+    debug_assert_aligned!(mem_addr, 16);
     intrinsics::nontemporal_store(mem_addr as *mut __m128, a);
 }
 
@@ -2933,6 +3055,16 @@ mod tests {
         assert_eq_m128(r, _mm_set1_ps(0.0));
     }
 
+    #[simd_test(enable = "sse")]
+    unsafe fn test_mm_set_ps_const() {
+        const A: __m128 = unsafe { _mm_set_ps(4.0, 3.0, 2.0, 1.0) };
+        static B: __m128 = unsafe { _mm_setr_ps(1.0, 2.0, 3.0, 4.0) };
+        const ZERO: __m128 = unsafe { _mm_setzero_ps() };
+        assert_eq_m128(A, B);
+        assert_eq_m128(ZERO, _mm_set1_ps(0.0));
+        assert_eq_m128(_mm_set_ss(1.0), _mm_setr_ps(1.0, 0.0, 0.0, 0.0));
+    }
+
     #[simd_test(enable = "sse")]
     unsafe fn test_mm_shuffle() {
         assert_eq!(_MM_SHUFFLE(0, 1, 1, 3), 0b00_01_01_11);
@@ -3225,6 +3357,91 @@ mod tests {
         assert_eq_m128(r, exp); // first component is a denormalized f32
     }
 
+    #[simd_test(enable = "sse")]
+    unsafe fn test_mm_get_flush_zero_mode() {
+        let csr = _mm_getcsr();
+        assert_eq!(_mm_get_flush_zero_mode(), csr & _MM_FLUSH_ZERO_MASK != 0);
+    }
+
+    #[simd_test(enable = "sse")]
+    unsafe fn test_mm_get_denormals_zero_mode() {
+        let csr = _mm_getcsr();
+        assert_eq!(_mm_get_denormals_zero_mode(), csr & 0x0040 != 0);
+    }
+
+    #[simd_test(enable = "sse")]
+    unsafe fn test_mm_get_rounding_mode() {
+        let csr = _mm_getcsr();
+        assert_eq!(_mm_get_rounding_mode(), csr & _MM_ROUND_MASK);
+    }
+
+    #[simd_test(enable = "sse")]
+    unsafe fn test_mm_set_flush_zero_mode() {
+        let saved_csr = _mm_getcsr();
+
+        let a = _mm_setr_ps(1.1e-36, 0.0, 0.0, 1.0);
+        let b = _mm_setr_ps(0.001, 0.0, 0.0, 1.0);
+
+        _mm_set_flush_zero_mode(false);
+        assert!(!_mm_get_flush_zero_mode());
+        let r = _mm_mul_ps(*black_box(&a), *black_box(&b));
+        assert_eq_m128(r, _mm_setr_ps(1.1e-39, 0.0, 0.0, 1.0));
+
+        _mm_set_flush_zero_mode(true);
+        assert!(_mm_get_flush_zero_mode());
+        let r = _mm_mul_ps(*black_box(&a), *black_box(&b));
+        // Only the flush-to-zero bit is changed, the multiplications may have
+        // set exception flags though.
+        let unchanged = !(_MM_FLUSH_ZERO_MASK | _MM_EXCEPT_MASK);
+        assert_eq!(_mm_getcsr() & unchanged, saved_csr & unchanged);
+
+        _mm_setcsr(saved_csr);
+
+        assert_eq_m128(r, _mm_setr_ps(0.0, 0.0, 0.0, 1.0));
+    }
+
+    #[simd_test(enable = "sse")]
+    unsafe fn test_mm_set_denormals_zero_mode() {
+        let saved_csr = _mm_getcsr();
+
+        let a = _mm_setr_ps(1.1e-39, 0.0, 0.0, 1.0);
+        let b = _mm_setr_ps(1e10, 0.0, 0.0, 1.0);
+
+        _mm_set_denormals_zero_mode(true);
+        assert!(_mm_get_denormals_zero_mode());
+        let r = _mm_mul_ps(*black_box(&a), *black_box(&b));
+        _mm_set_denormals_zero_mode(false);
+        assert!(!_mm_get_denormals_zero_mode());
+
+        _mm_setcsr(saved_csr);
+
+        assert_eq_m128(r, _mm_setr_ps(0.0, 0.0, 0.0, 1.0));
+    }
+
+    #[simd_test(enable = "sse")]
+    unsafe fn test_mm_set_rounding_mode() {
+        let saved_csr = _mm_getcsr();
+
+        let a = _mm_setr_ps(1.0, -1.0, 0.0, 0.0);
+        let b = _mm_setr_ps(3.0, 3.0, 1.0, 1.0);
+
+        _mm_set_rounding_mode(_MM_ROUND_DOWN);
+        assert_eq!(_mm_get_rounding_mode(), _MM_ROUND_DOWN);
+        let down = _mm_div_ps(*black_box(&a), *black_box(&b));
+        _mm_set_rounding_mode(_MM_ROUND_UP);
+        assert_eq!(_mm_get_rounding_mode(), _MM_ROUND_UP);
+        let up = _mm_div_ps(*black_box(&a), *black_box(&b));
+        // Only the rounding bits are changed, the divisions may have set
+        // exception flags though.
+        let unchanged = !(_MM_ROUND_MASK | _MM_EXCEPT_MASK);
+        assert_eq!(_mm_getcsr() & unchanged, saved_csr & unchanged);
+
+        _mm_setcsr(saved_csr);
+
+        assert!(get_m128(down, 0) < get_m128(up, 0));
+        assert!(get_m128(down, 1) < get_m128(up, 1));
+    }
+
     #[simd_test(enable = "sse")]
     unsafe fn test_mm_getcsr_setcsr_underflow() {
         _MM_SET_EXCEPTION_STATE(0);
@@ -3256,6 +3473,14 @@ mod tests {
         assert_eq_m128(b, _mm_setr_ps(2.0, 6.0, 10.0, 14.0));
         assert_eq_m128(c, _mm_setr_ps(3.0, 7.0, 11.0, 15.0));
         assert_eq_m128(d, _mm_setr_ps(4.0, 8.0, 12.0, 16.0));
+
+        // Transposing twice gives back the original rows.
+        _MM_TRANSPOSE4_PS(&mut a, &mut b, &mut c, &mut d);
+
+        assert_eq_m128(a, _mm_setr_ps(1.0, 2.0, 3.0, 4.0));
+        assert_eq_m128(b, _mm_setr_ps(5.0, 6.0, 7.0, 8.0));
+        assert_eq_m128(c, _mm_setr_ps(9.0, 10.0, 11.0, 12.0));
+        assert_eq_m128(d, _mm_setr_ps(13.0, 14.0, 15.0, 16.0));
     }
 
     #[repr(align(16))]
@@ -3273,4 +3498,27 @@ mod tests {
             assert_eq!(mem.data[i], get_m128(a, i));
         }
     }
+
+    #[cfg(debug_assertions)]
+    #[simd_test(enable = "sse")]
+    unsafe fn test_mm_load_store_ps_misaligned() {
+        use std::panic::catch_unwind;
+
+        let mut vals = [0.0f32; 8];
+        let mut p = vals.as_mut_ptr();
+        if (p as usize) & 0xf == 0 {
+            p = p.add(1);
+        }
+        assert!(catch_unwind(|| _mm_load_ps(p)).is_err());
+        assert!(catch_unwind(|| _mm_store_ps(p, _mm_set1_ps(1.0))).is_err());
+        assert!(catch_unwind(|| _mm_stream_ps(p, _mm_set1_ps(1.0))).is_err());
+        assert_eq!(vals, [0.0; 8]);
+
+        let mut mem = Memory { data: [-1.0; 4] };
+        _mm_store_ps(mem.data.as_mut_ptr(), _mm_setr_ps(1.0, 2.0, 3.0, 4.0));
+        assert_eq_m128(
+            _mm_load_ps(mem.data.as_ptr()),
+            _mm_setr_ps(1.0, 2.0, 3.0, 4.0),
+        );
+    }
 }
@@ -165,7 +165,10 @@ pub unsafe fn _mm_adds_epu16(a: __m128i, b: __m128i) -> __m128i {
 #[cfg_attr(test, assert_instr(pavgb))]
 #[stable(feature = "simd_x86", since = "1.27.0")]
 pub unsafe fn _mm_avg_epu8(a: __m128i, b: __m128i) -> __m128i {
-    transmute(pavgb(a.as_u8x16(), b.as_u8x16()))
+    let a = simd_cast::<_, u16x16>(a.as_u8x16());
+    let b = simd_cast::<_, u16x16>(b.as_u8x16());
+    let r = simd_shr(simd_add(simd_add(a, b), u16x16::splat(1)), u16x16::splat(1));
+    transmute(simd_cast::<_, u8x16>(r))
 }
 
 /// Averages packed unsigned 16-bit integers in `a` and `b`.
@@ -176,7 +179,10 @@ pub unsafe fn _mm_avg_epu8(a: __m128i, b: __m128i) -> __m128i {
 #[cfg_attr(test, assert_instr(pavgw))]
 #[stable(feature = "simd_x86", since = "1.27.0")]
 pub unsafe fn _mm_avg_epu16(a: __m128i, b: __m128i) -> __m128i {
-    transmute(pavgw(a.as_u16x8(), b.as_u16x8()))
+    let a = simd_cast::<_, u32x8>(a.as_u16x8());
+    let b = simd_cast::<_, u32x8>(b.as_u16x8());
+    let r = simd_shr(simd_add(simd_add(a, b), u32x8::splat(1)), u32x8::splat(1));
+    transmute(simd_cast::<_, u16x8>(r))
 }
 
 /// Multiplies and then horizontally add signed 16 bit integers in `a` and `b`.
@@ -203,7 +209,9 @@ pub unsafe fn _mm_madd_epi16(a: __m128i, b: __m128i) -> __m128i {
 #[cfg_attr(test, assert_instr(pmaxsw))]
 #[stable(feature = "simd_x86", since = "1.27.0")]
 pub unsafe fn _mm_max_epi16(a: __m128i, b: __m128i) -> __m128i {
-    transmute(pmaxsw(a.as_i16x8(), b.as_i16x8()))
+    let a = a.as_i16x8();
+    let b = b.as_i16x8();
+    transmute(simd_select::<i16x8, _>(simd_gt(a, b), a, b))
 }
 
 /// Compares packed unsigned 8-bit integers in `a` and `b`, and returns the
@@ -215,7 +223,9 @@ pub unsafe fn _mm_max_epi16(a: __m128i, b: __m128i) -> __m128i {
 #[cfg_attr(test, assert_instr(pmaxub))]
 #[stable(feature = "simd_x86", since = "1.27.0")]
 pub unsafe fn _mm_max_epu8(a: __m128i, b: __m128i) -> __m128i {
-    transmute(pmaxub(a.as_u8x16(), b.as_u8x16()))
+    let a = a.as_u8x16();
+    let b = b.as_u8x16();
+    transmute(simd_select::<i8x16, _>(simd_gt(a, b), a, b))
 }
 
 /// Compares packed 16-bit integers in `a` and `b`, and returns the packed
@@ -227,7 +237,9 @@ pub unsafe fn _mm_max_epu8(a: __m128i, b: __m128i) -> __m128i {
 #[cfg_attr(test, assert_instr(pminsw))]
 #[stable(feature = "simd_x86", since = "1.27.0")]
 pub unsafe fn _mm_min_epi16(a: __m128i, b: __m128i) -> __m128i {
-    transmute(pminsw(a.as_i16x8(), b.as_i16x8()))
+    let a = a.as_i16x8();
+    let b = b.as_i16x8();
+    transmute(simd_select::<i16x8, _>(simd_lt(a, b), a, b))
 }
 
 /// Compares packed unsigned 8-bit integers in `a` and `b`, and returns the
@@ -239,7 +251,9 @@ pub unsafe fn _mm_min_epi16(a: __m128i, b: __m128i) -> __m128i {
 #[cfg_attr(test, assert_instr(pminub))]
 #[stable(feature = "simd_x86", since = "1.27.0")]
 pub unsafe fn _mm_min_epu8(a: __m128i, b: __m128i) -> __m128i {
-    transmute(pminub(a.as_u8x16(), b.as_u8x16()))
+    let a = a.as_u8x16();
+    let b = b.as_u8x16();
+    transmute(simd_select::<i8x16, _>(simd_lt(a, b), a, b))
 }
 
 /// Multiplies the packed 16-bit integers in `a` and `b`.
@@ -492,7 +506,11 @@ pub unsafe fn _mm_bsrli_si128<const IMM8: i32>(a: __m128i) -> __m128i {
 #[stable(feature = "simd_x86", since = "1.27.0")]
 pub unsafe fn _mm_slli_epi16<const IMM8: i32>(a: __m128i) -> __m128i {
     static_assert_imm8!(IMM8);
-    transmute(pslliw(a.as_i16x8(), IMM8))
+    if IMM8 >= 16 {
+        _mm_setzero_si128()
+    } else {
+        transmute(simd_shl(a.as_u16x8(), u16x8::splat(IMM8 as u16)))
+    }
 }
 
 /// Shifts packed 16-bit integers in `a` left by `count` while shifting in
@@ -517,7 +535,11 @@ pub unsafe fn _mm_sll_epi16(a: __m128i, count: __m128i) -> __m128i {
 #[stable(feature = "simd_x86", since = "1.27.0")]
 pub unsafe fn _mm_slli_epi32<const IMM8: i32>(a: __m128i) -> __m128i {
     static_assert_imm8!(IMM8);
-    transmute(psllid(a.as_i32x4(), IMM8))
+    if IMM8 >= 32 {
+        _mm_setzero_si128()
+    } else {
+        transmute(simd_shl(a.as_u32x4(), u32x4::splat(IMM8 as u32)))
+    }
 }
 
 /// Shifts packed 32-bit integers in `a` left by `count` while shifting in
@@ -542,7 +564,11 @@ pub unsafe fn _mm_sll_epi32(a: __m128i, count: __m128i) -> __m128i {
 #[stable(feature = "simd_x86", since = "1.27.0")]
 pub unsafe fn _mm_slli_epi64<const IMM8: i32>(a: __m128i) -> __m128i {
     static_assert_imm8!(IMM8);
-    transmute(pslliq(a.as_i64x2(), IMM8))
+    if IMM8 >= 64 {
+        _mm_setzero_si128()
+    } else {
+        transmute(simd_shl(a.as_u64x2(), u64x2::splat(IMM8 as u64)))
+    }
 }
 
 /// Shifts packed 64-bit integers in `a` left by `count` while shifting in
@@ -568,7 +594,7 @@ pub unsafe fn _mm_sll_epi64(a: __m128i, count: __m128i) -> __m128i {
 #[stable(feature = "simd_x86", since = "1.27.0")]
 pub unsafe fn _mm_srai_epi16<const IMM8: i32>(a: __m128i) -> __m128i {
     static_assert_imm8!(IMM8);
-    transmute(psraiw(a.as_i16x8(), IMM8))
+    transmute(simd_shr(a.as_i16x8(), i16x8::splat(IMM8.min(15) as i16)))
 }
 
 /// Shifts packed 16-bit integers in `a` right by `count` while shifting in sign
@@ -594,7 +620,7 @@ pub unsafe fn _mm_sra_epi16(a: __m128i, count: __m128i) -> __m128i {
 #[stable(feature = "simd_x86", since = "1.27.0")]
 pub unsafe fn _mm_srai_epi32<const IMM8: i32>(a: __m128i) -> __m128i {
     static_assert_imm8!(IMM8);
-    transmute(psraid(a.as_i32x4(), IMM8))
+    transmute(simd_shr(a.as_i32x4(), i32x4::splat(IMM8.min(31) as i32)))
 }
 
 /// Shifts packed 32-bit integers in `a` right by `count` while shifting in sign
@@ -671,7 +697,11 @@ unsafe fn _mm_srli_si128_impl<const IMM8: i32>(a: __m128i) -> __m128i {
 #[stable(feature = "simd_x86", since = "1.27.0")]
 pub unsafe fn _mm_srli_epi16<const IMM8: i32>(a: __m128i) -> __m128i {
     static_assert_imm8!(IMM8);
-    transmute(psrliw(a.as_i16x8(), IMM8))
+    if IMM8 >= 16 {
+        _mm_setzero_si128()
+    } else {
+        transmute(simd_shr(a.as_u16x8(), u16x8::splat(IMM8 as u16)))
+    }
 }
 
 /// Shifts packed 16-bit integers in `a` right by `count` while shifting in
@@ -697,7 +727,11 @@ pub unsafe fn _mm_srl_epi16(a: __m128i, count: __m128i) -> __m128i {
 #[stable(feature = "simd_x86", since = "1.27.0")]
 pub unsafe fn _mm_srli_epi32<const IMM8: i32>(a: __m128i) -> __m128i {
     static_assert_imm8!(IMM8);
-    transmute(psrlid(a.as_i32x4(), IMM8))
+    if IMM8 >= 32 {
+        _mm_setzero_si128()
+    } else {
+        transmute(simd_shr(a.as_u32x4(), u32x4::splat(IMM8 as u32)))
+    }
 }
 
 /// Shifts packed 32-bit integers in `a` right by `count` while shifting in
@@ -723,7 +757,11 @@ pub unsafe fn _mm_srl_epi32(a: __m128i, count: __m128i) -> __m128i {
 #[stable(feature = "simd_x86", since = "1.27.0")]
 pub unsafe fn _mm_srli_epi64<const IMM8: i32>(a: __m128i) -> __m128i {
     static_assert_imm8!(IMM8);
-    transmute(psrliq(a.as_i64x2(), IMM8))
+    if IMM8 >= 64 {
+        _mm_setzero_si128()
+    } else {
+        transmute(simd_shr(a.as_u64x2(), u64x2::splat(IMM8 as u64)))
+    }
 }
 
 /// Shifts packed 64-bit integers in `a` right by `count` while shifting in
@@ -965,7 +1003,8 @@ pub unsafe fn _mm_cvtsi128_si32(a: __m128i) -> i32 {
 #[target_feature(enable = "sse2")]
 // no particular instruction to test
 #[stable(feature = "simd_x86", since = "1.27.0")]
-pub unsafe fn _mm_set_epi64x(e1: i64, e0: i64) -> __m128i {
+#[rustc_const_unstable(feature = "stdarch_const_x86", issue = "none")]
+pub const unsafe fn _mm_set_epi64x(e1: i64, e0: i64) -> __m128i {
     transmute(i64x2::new(e0, e1))
 }
 
@@ -976,7 +1015,8 @@ pub unsafe fn _mm_set_epi64x(e1: i64, e0: i64) -> __m128i {
 #[target_feature(enable = "sse2")]
 // no particular instruction to test
 #[stable(feature = "simd_x86", since = "1.27.0")]
-pub unsafe fn _mm_set_epi32(e3: i32, e2: i32, e1: i32, e0: i32) -> __m128i {
+#[rustc_const_unstable(feature = "stdarch_const_x86", issue = "none")]
+pub const unsafe fn _mm_set_epi32(e3: i32, e2: i32, e1: i32, e0: i32) -> __m128i {
     transmute(i32x4::new(e0, e1, e2, e3))
 }
 
@@ -987,7 +1027,8 @@ pub unsafe fn _mm_set_epi32(e3: i32, e2: i32, e1: i32, e0: i32) -> __m128i {
 #[target_feature(enable = "sse2")]
 // no particular instruction to test
 #[stable(feature = "simd_x86", since = "1.27.0")]
-pub unsafe fn _mm_set_epi16(
+#[rustc_const_unstable(feature = "stdarch_const_x86", issue = "none")]
+pub const unsafe fn _mm_set_epi16(
     e7: i16,
     e6: i16,
     e5: i16,
@@ -1007,7 +1048,8 @@ pub unsafe fn _mm_set_epi16(
 #[target_feature(enable = "sse2")]
 // no particular instruction to test
 #[stable(feature = "simd_x86", since = "1.27.0")]
-pub unsafe fn _mm_set_epi8(
+#[rustc_const_unstable(feature = "stdarch_const_x86", issue = "none")]
+pub const unsafe fn _mm_set_epi8(
     e15: i8,
     e14: i8,
     e13: i8,
@@ -1038,7 +1080,8 @@ pub unsafe fn _mm_set_epi8(
 #[target_feature(enable = "sse2")]
 // no particular instruction to test
 #[stable(feature = "simd_x86", since = "1.27.0")]
-pub unsafe fn _mm_set1_epi64x(a: i64) -> __m128i {
+#[rustc_const_unstable(feature = "stdarch_const_x86", issue = "none")]
+pub const unsafe fn _mm_set1_epi64x(a: i64) -> __m128i {
     _mm_set_epi64x(a, a)
 }
 
@@ -1049,7 +1092,8 @@ pub unsafe fn _mm_set1_epi64x(a: i64) -> __m128i {
 #[target_feature(enable = "sse2")]
 // no particular instruction to test
 #[stable(feature = "simd_x86", since = "1.27.0")]
-pub unsafe fn _mm_set1_epi32(a: i32) -> __m128i {
+#[rustc_const_unstable(feature = "stdarch_const_x86", issue = "none")]
+pub const unsafe fn _mm_set1_epi32(a: i32) -> __m128i {
     _mm_set_epi32(a, a, a, a)
 }
 
@@ -1060,7 +1104,8 @@ pub unsafe fn _mm_set1_epi32(a: i32) -> __m128i {
 #[target_feature(enable = "sse2")]
 // no particular instruction to test
 #[stable(feature = "simd_x86", since = "1.27.0")]
-pub unsafe fn _mm_set1_epi16(a: i16) -> __m128i {
+#[rustc_const_unstable(feature = "stdarch_const_x86", issue = "none")]
+pub const unsafe fn _mm_set1_epi16(a: i16) -> __m128i {
     _mm_set_epi16(a, a, a, a, a, a, a, a)
 }
 
@@ -1071,7 +1116,8 @@ pub unsafe fn _mm_set1_epi16(a: i16) -> __m128i {
 #[target_feature(enable = "sse2")]
 // no particular instruction to test
 #[stable(feature = "simd_x86", since = "1.27.0")]
-pub unsafe fn _mm_set1_epi8(a: i8) -> __m128i {
+#[rustc_const_unstable(feature = "stdarch_const_x86", issue = "none")]
+pub const unsafe fn _mm_set1_epi8(a: i8) -> __m128i {
     _mm_set_epi8(a, a, a, a, a, a, a, a, a, a, a, a, a, a, a, a)
 }
 
@@ -1082,7 +1128,8 @@ pub unsafe fn _mm_set1_epi8(a: i8) -> __m128i {
 #[target_feature(enable = "sse2")]
 // no particular instruction to test
 #[stable(feature = "simd_x86", since = "1.27.0")]
-pub unsafe fn _mm_setr_epi32(e3: i32, e2: i32, e1: i32, e0: i32) -> __m128i {
+#[rustc_const_unstable(feature = "stdarch_const_x86", issue = "none")]
+pub const unsafe fn _mm_setr_epi32(e3: i32, e2: i32, e1: i32, e0: i32) -> __m128i {
     _mm_set_epi32(e0, e1, e2, e3)
 }
 
@@ -1093,7 +1140,8 @@ pub unsafe fn _mm_setr_epi32(e3: i32, e2: i32, e1: i32, e0: i32) -> __m128i {
 #[target_feature(enable = "sse2")]
 // no particular instruction to test
 #[stable(feature = "simd_x86", since = "1.27.0")]
-pub unsafe fn _mm_setr_epi16(
+#[rustc_const_unstable(feature = "stdarch_const_x86", issue = "none")]
+pub const unsafe fn _mm_setr_epi16(
     e7: i16,
     e6: i16,
     e5: i16,
@@ -1113,7 +1161,8 @@ pub unsafe fn _mm_setr_epi16(
 #[target_feature(enable = "sse2")]
 // no particular instruction to test
 #[stable(feature = "simd_x86", since = "1.27.0")]
-pub unsafe fn _mm_setr_epi8(
+#[rustc_const_unstable(feature = "stdarch_const_x86", issue = "none")]
+pub const unsafe fn _mm_setr_epi8(
     e15: i8,
     e14: i8,
     e13: i8,
@@ -1144,7 +1193,8 @@ pub unsafe fn _mm_setr_epi8(
 #[target_feature(enable = "sse2")]
 #[cfg_attr(test, assert_instr(xorps))]
 #[stable(feature = "simd_x86", since = "1.27.0")]
-pub unsafe fn _mm_setzero_si128() -> __m128i {
+#[rustc_const_unstable(feature = "stdarch_const_x86", issue = "none")]
+pub const unsafe fn _mm_setzero_si128() -> __m128i {
     _mm_set1_epi64x(0)
 }
 
@@ -1178,6 +1228,7 @@ pub unsafe fn _mm_loadl_epi64(mem_addr: *const __m128i) -> __m128i {
 #[cfg_attr(test, assert_instr(movaps))]
 #[stable(feature = "simd_x86", since = "1.27.0")]
 pub unsafe fn _mm_load_si128(mem_addr: *const __m128i) -> __m128i {
+    debug_assert_aligned!(mem_addr, 16);
     *mem_addr
 }
 
@@ -1200,6 +1251,37 @@ pub unsafe fn _mm_loadu_si128(mem_addr: *const __m128i) -> __m128i {
     dst
 }
 
+/// Loads unaligned 16-bits of integer data from memory into the lowest
+/// element of a new vector, and zeroes the other elements.
+///
+/// `mem_addr` does not need to be aligned on any particular boundary, and
+/// only the two bytes at `mem_addr` are read.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_loadu_si16)
+#[inline]
+#[target_feature(enable = "sse2")]
+// FIXME: this is either `pinsrw` or `movzwl` and `movd`, depending on the
+// target.
+pub unsafe fn _mm_loadu_si16(mem_addr: *const u8) -> __m128i {
+    let a = ptr::read_unaligned(mem_addr as *const i16);
+    transmute(i16x8::new(a, 0, 0, 0, 0, 0, 0, 0))
+}
+
+/// Loads unaligned 32-bits of integer data from memory into the lowest
+/// element of a new vector, and zeroes the other elements.
+///
+/// `mem_addr` does not need to be aligned on any particular boundary, and
+/// only the four bytes at `mem_addr` are read.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_loadu_si32)
+#[inline]
+#[target_feature(enable = "sse2")]
+#[cfg_attr(test, assert_instr(movd))]
+pub unsafe fn _mm_loadu_si32(mem_addr: *const u8) -> __m128i {
+    let a = ptr::read_unaligned(mem_addr as *const i32);
+    transmute(i32x4::new(a, 0, 0, 0))
+}
+
 /// Conditionally store 8-bit integer elements from `a` into memory using
 /// `mask`.
 ///
@@ -1228,6 +1310,7 @@ pub unsafe fn _mm_maskmoveu_si128(a: __m128i, mask: __m128i, mem_addr: *mut i8)
 #[cfg_attr(test, assert_instr(movaps))]
 #[stable(feature = "simd_x86", since = "1.27.0")]
 pub unsafe fn _mm_store_si128(mem_addr: *mut __m128i, a: __m128i) {
+    debug_assert_aligned!(mem_addr, 16);
     *mem_addr = a;
 }
 
@@ -1266,16 +1349,68 @@ pub unsafe fn _mm_storel_epi64(mem_addr: *mut __m128i, a: __m128i) {
     ptr::copy_nonoverlapping(&a as *const _ as *const u8, mem_addr as *mut u8, 8);
 }
 
+/// Stores the lowest 16-bit integer of `a` to memory.
+///
+/// `mem_addr` does not need to be aligned on any particular boundary, and
+/// only the two bytes at `mem_addr` are written.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_storeu_si16)
+#[inline]
+#[target_feature(enable = "sse2")]
+// FIXME: this is either `pextrw` or `movd` and `movw`, depending on the
+// target.
+pub unsafe fn _mm_storeu_si16(mem_addr: *mut u8, a: __m128i) {
+    ptr::write_unaligned(mem_addr as *mut i16, simd_extract(a.as_i16x8(), 0));
+}
+
+/// Stores the lowest 32-bit integer of `a` to memory.
+///
+/// `mem_addr` does not need to be aligned on any particular boundary, and
+/// only the four bytes at `mem_addr` are written.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_storeu_si32)
+#[inline]
+#[target_feature(enable = "sse2")]
+#[cfg_attr(test, assert_instr(movd))]
+pub unsafe fn _mm_storeu_si32(mem_addr: *mut u8, a: __m128i) {
+    ptr::write_unaligned(mem_addr as *mut i32, simd_extract(a.as_i32x4(), 0));
+}
+
+/// Stores the lowest 64-bit integer of `a` to memory.
+///
+/// `mem_addr` does not need to be aligned on any particular boundary, and
+/// only the eight bytes at `mem_addr` are written.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_storeu_si64)
+#[inline]
+#[target_feature(enable = "sse2")]
+// FIXME mov on windows, movlps on i686
+#[cfg_attr(
+    all(
+        test,
+        not(windows),
+        not(all(target_os = "linux", target_arch = "x86_64")),
+        target_arch = "x86_64"
+    ),
+    assert_instr(movq)
+)]
+pub unsafe fn _mm_storeu_si64(mem_addr: *mut u8, a: __m128i) {
+    ptr::write_unaligned(mem_addr as *mut i64, simd_extract(a.as_i64x2(), 0));
+}
+
 /// Stores a 128-bit integer vector to a 128-bit aligned memory location.
 /// To minimize caching, the data is flagged as non-temporal (unlikely to be
 /// used again soon).
 ///
+/// See [`NonTemporalScope`] for ordering the store with other memory accesses.
+///
 /// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_stream_si128)
 #[inline]
 #[target_feature(enable = "sse2")]
 #[cfg_attr(test, assert_instr(movntps))] // FIXME movntdq
 #[stable(feature = "simd_x86", since = "1.27.0")]
 pub unsafe fn _mm_stream_si128(mem_addr: *mut __m128i, a: __m128i) {
+    debug_assert_aligned!(mem_addr, 16);
     intrinsics::nontemporal_store(mem_addr, a);
 }
 
@@ -1283,6 +1418,8 @@ pub unsafe fn _mm_stream_si128(mem_addr: *mut __m128i, a: __m128i) {
 /// To minimize caching, the data is flagged as non-temporal (unlikely to be
 /// used again soon).
 ///
+/// See [`NonTemporalScope`] for ordering the store with other memory accesses.
+///
 /// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_stream_si32)
 #[inline]
 #[target_feature(enable = "sse2")]
@@ -1377,7 +1514,9 @@ pub unsafe fn _mm_insert_epi16<const IMM8: i32>(a: __m128i, i: i32) -> __m128i {
 #[cfg_attr(test, assert_instr(pmovmskb))]
 #[stable(feature = "simd_x86", since = "1.27.0")]
 pub unsafe fn _mm_movemask_epi8(a: __m128i) -> i32 {
-    pmovmskb(a.as_i8x16())
+    let z = i8x16::splat(0);
+    let m: i8x16 = simd_lt(a.as_i8x16(), z);
+    simd_bitmask::<_, u16>(m) as u32 as i32
 }
 
 /// Shuffles 32-bit integers in `a` using the control in `IMM8`.
@@ -2328,7 +2467,8 @@ pub unsafe fn _mm_cvttps_epi32(a: __m128) -> __m128i {
 #[inline]
 #[target_feature(enable = "sse2")]
 #[stable(feature = "simd_x86", since = "1.27.0")]
-pub unsafe fn _mm_set_sd(a: f64) -> __m128d {
+#[rustc_const_unstable(feature = "stdarch_const_x86", issue = "none")]
+pub const unsafe fn _mm_set_sd(a: f64) -> __m128d {
     _mm_set_pd(0.0, a)
 }
 
@@ -2339,7 +2479,8 @@ pub unsafe fn _mm_set_sd(a: f64) -> __m128d {
 #[inline]
 #[target_feature(enable = "sse2")]
 #[stable(feature = "simd_x86", since = "1.27.0")]
-pub unsafe fn _mm_set1_pd(a: f64) -> __m128d {
+#[rustc_const_unstable(feature = "stdarch_const_x86", issue = "none")]
+pub const unsafe fn _mm_set1_pd(a: f64) -> __m128d {
     _mm_set_pd(a, a)
 }
 
@@ -2350,7 +2491,8 @@ pub unsafe fn _mm_set1_pd(a: f64) -> __m128d {
 #[inline]
 #[target_feature(enable = "sse2")]
 #[stable(feature = "simd_x86", since = "1.27.0")]
-pub unsafe fn _mm_set_pd1(a: f64) -> __m128d {
+#[rustc_const_unstable(feature = "stdarch_const_x86", issue = "none")]
+pub const unsafe fn _mm_set_pd1(a: f64) -> __m128d {
     _mm_set_pd(a, a)
 }
 
@@ -2361,7 +2503,8 @@ pub unsafe fn _mm_set_pd1(a: f64) -> __m128d {
 #[inline]
 #[target_feature(enable = "sse2")]
 #[stable(feature = "simd_x86", since = "1.27.0")]
-pub unsafe fn _mm_set_pd(a: f64, b: f64) -> __m128d {
+#[rustc_const_unstable(feature = "stdarch_const_x86", issue = "none")]
+pub const unsafe fn _mm_set_pd(a: f64, b: f64) -> __m128d {
     __m128d(b, a)
 }
 
@@ -2372,7 +2515,8 @@ pub unsafe fn _mm_set_pd(a: f64, b: f64) -> __m128d {
 #[inline]
 #[target_feature(enable = "sse2")]
 #[stable(feature = "simd_x86", since = "1.27.0")]
-pub unsafe fn _mm_setr_pd(a: f64, b: f64) -> __m128d {
+#[rustc_const_unstable(feature = "stdarch_const_x86", issue = "none")]
+pub const unsafe fn _mm_setr_pd(a: f64, b: f64) -> __m128d {
     _mm_set_pd(b, a)
 }
 
@@ -2384,7 +2528,8 @@ pub unsafe fn _mm_setr_pd(a: f64, b: f64) -> __m128d {
 #[target_feature(enable = "sse2")]
 #[cfg_attr(test, assert_instr(xorps))] // FIXME xorpd expected
 #[stable(feature = "simd_x86", since = "1.27.0")]
-pub unsafe fn _mm_setzero_pd() -> __m128d {
+#[rustc_const_unstable(feature = "stdarch_const_x86", issue = "none")]
+pub const unsafe fn _mm_setzero_pd() -> __m128d {
     _mm_set_pd(0.0, 0.0)
 }
 
@@ -2414,6 +2559,7 @@ pub unsafe fn _mm_movemask_pd(a: __m128d) -> i32 {
 #[stable(feature = "simd_x86", since = "1.27.0")]
 #[allow(clippy::cast_ptr_alignment)]
 pub unsafe fn _mm_load_pd(mem_addr: *const f64) -> __m128d {
+    debug_assert_aligned!(mem_addr, 16);
     *(mem_addr as *const __m128d)
 }
 
@@ -2460,6 +2606,8 @@ pub unsafe fn _mm_loadl_pd(a: __m128d, mem_addr: *const f64) -> __m128d {
 /// To minimize caching, the data is flagged as non-temporal (unlikely to be
 /// used again soon).
 ///
+/// See [`NonTemporalScope`] for ordering the store with other memory accesses.
+///
 /// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_stream_pd)
 #[inline]
 #[target_feature(enable = "sse2")]
@@ -2467,6 +2615,7 @@ pub unsafe fn _mm_loadl_pd(a: __m128d, mem_addr: *const f64) -> __m128d {
 #[stable(feature = "simd_x86", since = "1.27.0")]
 #[allow(clippy::cast_ptr_alignment)]
 pub unsafe fn _mm_stream_pd(mem_addr: *mut f64, a: __m128d) {
+    debug_assert_aligned!(mem_addr, 16);
     intrinsics::nontemporal_store(mem_addr as *mut __m128d, a);
 }
 
@@ -2493,6 +2642,7 @@ pub unsafe fn _mm_store_sd(mem_addr: *mut f64, a: __m128d) {
 #[stable(feature = "simd_x86", since = "1.27.0")]
 #[allow(clippy::cast_ptr_alignment)]
 pub unsafe fn _mm_store_pd(mem_addr: *mut f64, a: __m128d) {
+    debug_assert_aligned!(mem_addr, 16);
     *(mem_addr as *mut __m128d) = a;
 }
 
@@ -2519,6 +2669,7 @@ pub unsafe fn _mm_storeu_pd(mem_addr: *mut f64, a: __m128d) {
 #[stable(feature = "simd_x86", since = "1.27.0")]
 #[allow(clippy::cast_ptr_alignment)]
 pub unsafe fn _mm_store1_pd(mem_addr: *mut f64, a: __m128d) {
+    debug_assert_aligned!(mem_addr, 16);
     let b: __m128d = simd_shuffle2(a, a, [0, 0]);
     *(mem_addr as *mut __m128d) = b;
 }
@@ -2533,6 +2684,7 @@ pub unsafe fn _mm_store1_pd(mem_addr: *mut f64, a: __m128d) {
 #[stable(feature = "simd_x86", since = "1.27.0")]
 #[allow(clippy::cast_ptr_alignment)]
 pub unsafe fn _mm_store_pd1(mem_addr: *mut f64, a: __m128d) {
+    debug_assert_aligned!(mem_addr, 16);
     let b: __m128d = simd_shuffle2(a, a, [0, 0]);
     *(mem_addr as *mut __m128d) = b;
 }
@@ -2548,6 +2700,7 @@ pub unsafe fn _mm_store_pd1(mem_addr: *mut f64, a: __m128d) {
 #[stable(feature = "simd_x86", since = "1.27.0")]
 #[allow(clippy::cast_ptr_alignment)]
 pub unsafe fn _mm_storer_pd(mem_addr: *mut f64, a: __m128d) {
+    debug_assert_aligned!(mem_addr, 16);
     let b: __m128d = simd_shuffle2(a, a, [1, 0]);
     *(mem_addr as *mut __m128d) = b;
 }
@@ -2611,6 +2764,7 @@ pub unsafe fn _mm_load_pd1(mem_addr: *const f64) -> __m128d {
 #[cfg_attr(test, assert_instr(movaps))]
 #[stable(feature = "simd_x86", since = "1.27.0")]
 pub unsafe fn _mm_loadr_pd(mem_addr: *const f64) -> __m128d {
+    debug_assert_aligned!(mem_addr, 16);
     let a = _mm_load_pd(mem_addr);
     simd_shuffle2(a, a, [1, 0])
 }
@@ -2805,20 +2959,8 @@ extern "C" {
     fn lfence();
     #[link_name = "llvm.x86.sse2.mfence"]
     fn mfence();
-    #[link_name = "llvm.x86.sse2.pavg.b"]
-    fn pavgb(a: u8x16, b: u8x16) -> u8x16;
-    #[link_name = "llvm.x86.sse2.pavg.w"]
-    fn pavgw(a: u16x8, b: u16x8) -> u16x8;
     #[link_name = "llvm.x86.sse2.pmadd.wd"]
     fn pmaddwd(a: i16x8, b: i16x8) -> i32x4;
-    #[link_name = "llvm.x86.sse2.pmaxs.w"]
-    fn pmaxsw(a: i16x8, b: i16x8) -> i16x8;
-    #[link_name = "llvm.x86.sse2.pmaxu.b"]
-    fn pmaxub(a: u8x16, b: u8x16) -> u8x16;
-    #[link_name = "llvm.x86.sse2.pmins.w"]
-    fn pminsw(a: i16x8, b: i16x8) -> i16x8;
-    #[link_name = "llvm.x86.sse2.pminu.b"]
-    fn pminub(a: u8x16, b: u8x16) -> u8x16;
     #[link_name = "llvm.x86.sse2.pmulh.w"]
     fn pmulhw(a: i16x8, b: i16x8) -> i16x8;
     #[link_name = "llvm.x86.sse2.pmulhu.w"]
@@ -2827,36 +2969,20 @@ extern "C" {
     fn pmuludq(a: u32x4, b: u32x4) -> u64x2;
     #[link_name = "llvm.x86.sse2.psad.bw"]
     fn psadbw(a: u8x16, b: u8x16) -> u64x2;
-    #[link_name = "llvm.x86.sse2.pslli.w"]
-    fn pslliw(a: i16x8, imm8: i32) -> i16x8;
     #[link_name = "llvm.x86.sse2.psll.w"]
     fn psllw(a: i16x8, count: i16x8) -> i16x8;
-    #[link_name = "llvm.x86.sse2.pslli.d"]
-    fn psllid(a: i32x4, imm8: i32) -> i32x4;
     #[link_name = "llvm.x86.sse2.psll.d"]
     fn pslld(a: i32x4, count: i32x4) -> i32x4;
-    #[link_name = "llvm.x86.sse2.pslli.q"]
-    fn pslliq(a: i64x2, imm8: i32) -> i64x2;
     #[link_name = "llvm.x86.sse2.psll.q"]
     fn psllq(a: i64x2, count: i64x2) -> i64x2;
-    #[link_name = "llvm.x86.sse2.psrai.w"]
-    fn psraiw(a: i16x8, imm8: i32) -> i16x8;
     #[link_name = "llvm.x86.sse2.psra.w"]
     fn psraw(a: i16x8, count: i16x8) -> i16x8;
-    #[link_name = "llvm.x86.sse2.psrai.d"]
-    fn psraid(a: i32x4, imm8: i32) -> i32x4;
     #[link_name = "llvm.x86.sse2.psra.d"]
     fn psrad(a: i32x4, count: i32x4) -> i32x4;
-    #[link_name = "llvm.x86.sse2.psrli.w"]
-    fn psrliw(a: i16x8, imm8: i32) -> i16x8;
     #[link_name = "llvm.x86.sse2.psrl.w"]
     fn psrlw(a: i16x8, count: i16x8) -> i16x8;
-    #[link_name = "llvm.x86.sse2.psrli.d"]
-    fn psrlid(a: i32x4, imm8: i32) -> i32x4;
     #[link_name = "llvm.x86.sse2.psrl.d"]
     fn psrld(a: i32x4, count: i32x4) -> i32x4;
-    #[link_name = "llvm.x86.sse2.psrli.q"]
-    fn psrliq(a: i64x2, imm8: i32) -> i64x2;
     #[link_name = "llvm.x86.sse2.psrl.q"]
     fn psrlq(a: i64x2, count: i64x2) -> i64x2;
     #[link_name = "llvm.x86.sse2.cvtdq2ps"]
@@ -2871,8 +2997,6 @@ extern "C" {
     fn packssdw(a: i32x4, b: i32x4) -> i16x8;
     #[link_name = "llvm.x86.sse2.packuswb.128"]
     fn packuswb(a: i16x8, b: i16x8) -> u8x16;
-    #[link_name = "llvm.x86.sse2.pmovmskb.128"]
-    fn pmovmskb(a: i8x16) -> i32;
     #[link_name = "llvm.x86.sse2.max.sd"]
     fn maxsd(a: __m128d, b: __m128d) -> __m128d;
     #[link_name = "llvm.x86.sse2.max.pd"]
@@ -3753,6 +3877,24 @@ mod tests {
         assert_eq_m128i(r, _mm_set1_epi64x(0));
     }
 
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_mm_set_epi_const() {
+        const A: __m128i =
+            unsafe { _mm_set_epi8(15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0) };
+        const B: __m128i =
+            unsafe { _mm_setr_epi16(0x100, 0x302, 0x504, 0x706, 0x908, 0xb0a, 0xd0c, 0xf0e) };
+        static C: __m128i =
+            unsafe { _mm_setr_epi32(0x03020100, 0x07060504, 0x0b0a0908, 0x0f0e0d0c) };
+        static D: __m128i = unsafe { _mm_set_epi64x(0x0f0e0d0c0b0a0908, 0x0706050403020100) };
+        const ZERO: __m128i = unsafe { _mm_setzero_si128() };
+        const ONES: __m128i = unsafe { _mm_set1_epi8(-1) };
+        assert_eq_m128i(A, B);
+        assert_eq_m128i(A, C);
+        assert_eq_m128i(A, D);
+        assert_eq_m128i(ZERO, _mm_set1_epi32(0));
+        assert_eq_m128i(ONES, _mm_set1_epi64x(-1));
+    }
+
     #[simd_test(enable = "sse2")]
     unsafe fn test_mm_loadl_epi64() {
         let a = _mm_setr_epi64x(6, 5);
@@ -3767,6 +3909,30 @@ mod tests {
         assert_eq_m128i(a, r);
     }
 
+    #[cfg(debug_assertions)]
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_mm_load_store_si128_misaligned() {
+        use std::panic::catch_unwind;
+
+        let mut vals = [0u8; 32];
+        let mut p = vals.as_mut_ptr();
+        if (p as usize) & 0xf == 0 {
+            p = p.add(8);
+        }
+        let p = p as *mut __m128i;
+        assert!(catch_unwind(|| _mm_load_si128(p)).is_err());
+        assert!(catch_unwind(|| _mm_store_si128(p, _mm_set1_epi8(1))).is_err());
+        assert!(catch_unwind(|| _mm_stream_si128(p, _mm_set1_epi8(1))).is_err());
+        assert!(catch_unwind(|| _mm_load_pd(p as *const f64)).is_err());
+        assert!(catch_unwind(|| _mm_store_pd(p as *mut f64, _mm_set1_pd(1.0))).is_err());
+        assert_eq!(vals, [0; 32]);
+
+        let a = _mm_setr_epi32(1, 2, 3, 4);
+        let mut r = _mm_setzero_si128();
+        _mm_store_si128(&mut r, a);
+        assert_eq_m128i(_mm_load_si128(&r), a);
+    }
+
     #[simd_test(enable = "sse2")]
     unsafe fn test_mm_loadu_si128() {
         let a = _mm_set_epi64x(5, 6);
@@ -3774,6 +3940,26 @@ mod tests {
         assert_eq_m128i(a, r);
     }
 
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_mm_loadu_si16() {
+        // Load from the very end of an allocation to catch overreads.
+        let a = std::vec![1u8, 2, 3, 4].into_boxed_slice();
+        let r = _mm_loadu_si16(a[2..].as_ptr());
+        assert_eq_m128i(r, _mm_setr_epi16(0x0403, 0, 0, 0, 0, 0, 0, 0));
+        let r = _mm_loadu_si16(a[1..].as_ptr());
+        assert_eq_m128i(r, _mm_setr_epi16(0x0302, 0, 0, 0, 0, 0, 0, 0));
+    }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_mm_loadu_si32() {
+        // Load from the very end of an allocation to catch overreads.
+        let a = std::vec![1u8, 2, 3, 4, 5, 6].into_boxed_slice();
+        let r = _mm_loadu_si32(a[2..].as_ptr());
+        assert_eq_m128i(r, _mm_setr_epi32(0x06050403, 0, 0, 0));
+        let r = _mm_loadu_si32(a[1..].as_ptr());
+        assert_eq_m128i(r, _mm_setr_epi32(0x05040302, 0, 0, 0));
+    }
+
     #[simd_test(enable = "sse2")]
     unsafe fn test_mm_maskmoveu_si128() {
         let a = _mm_set1_epi8(9);
@@ -3812,6 +3998,30 @@ mod tests {
         assert_eq_m128i(r, _mm_setr_epi64x(2, 0));
     }
 
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_mm_storeu_si16() {
+        let a = _mm_setr_epi16(0x0201, 3, 4, 5, 6, 7, 8, 9);
+        let mut r = std::vec![0xffu8; 3].into_boxed_slice();
+        _mm_storeu_si16(r[1..].as_mut_ptr(), a);
+        assert_eq!(&r[..], &[0xff, 1, 2]);
+    }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_mm_storeu_si32() {
+        let a = _mm_setr_epi32(0x04030201, 5, 6, 7);
+        let mut r = std::vec![0xffu8; 5].into_boxed_slice();
+        _mm_storeu_si32(r[1..].as_mut_ptr(), a);
+        assert_eq!(&r[..], &[0xff, 1, 2, 3, 4]);
+    }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_mm_storeu_si64() {
+        let a = _mm_setr_epi64x(0x0807060504030201, 9);
+        let mut r = std::vec![0xffu8; 9].into_boxed_slice();
+        _mm_storeu_si64(r[1..].as_mut_ptr(), a);
+        assert_eq!(&r[..], &[0xff, 1, 2, 3, 4, 5, 6, 7, 8]);
+    }
+
     #[simd_test(enable = "sse2")]
     unsafe fn test_mm_stream_si128() {
         let a = _mm_setr_epi32(1, 2, 3, 4);
@@ -4803,6 +5013,15 @@ mod tests {
         assert_eq_m128d(r, _mm_setr_pd(0_f64, 0_f64));
     }
 
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_mm_set_pd_const() {
+        const A: __m128d = unsafe { _mm_set_pd(2.0, 1.0) };
+        static B: __m128d = unsafe { _mm_setr_pd(1.0, 2.0) };
+        const ZERO: __m128d = unsafe { _mm_setzero_pd() };
+        assert_eq_m128d(A, B);
+        assert_eq_m128d(ZERO, _mm_set1_pd(0.0));
+    }
+
     #[simd_test(enable = "sse2")]
     unsafe fn test_mm_load1_pd() {
         let d = -5.0;
@@ -1085,6 +1085,31 @@ pub unsafe fn _mm_test_mix_ones_zeros(a: __m128i, mask: __m128i) -> i32 {
     _mm_testnzc_si128(a, mask)
 }
 
+/// Loads 128 bits of integer data from memory into dst using a
+/// non-temporal memory hint. `mem_addr` must be aligned on a 16-byte
+/// boundary or a general-protection exception may be generated.
+///
+/// The hint only has an effect on write-combining (WC) memory, such as
+/// memory-mapped device or GPU buffers, where the load can be served from a
+/// streaming load buffer instead of being uncached. On ordinary write-back
+/// memory this behaves like a regular aligned load.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_stream_load_si128)
+#[inline]
+#[target_feature(enable = "sse4.1")]
+#[cfg_attr(test, assert_instr(movntdqa))]
+pub unsafe fn _mm_stream_load_si128(mem_addr: *const __m128i) -> __m128i {
+    debug_assert_aligned!(mem_addr, 16);
+    let dst: __m128i;
+    asm!(
+        "movntdqa {dst}, [{p}]",
+        dst = lateout(xmm_reg) dst,
+        p = in(reg) mem_addr,
+        options(pure, readonly, nostack, preserves_flags)
+    );
+    dst
+}
+
 #[allow(improper_ctypes)]
 extern "C" {
     #[link_name = "llvm.x86.sse41.pblendvb"]
@@ -1863,4 +1888,11 @@ mod tests {
         let r = _mm_test_mix_ones_zeros(a, mask);
         assert_eq!(r, 0);
     }
+
+    #[simd_test(enable = "sse4.1")]
+    unsafe fn test_mm_stream_load_si128() {
+        let a = _mm_setr_epi32(1, 2, 3, 4);
+        let r = _mm_stream_load_si128(&a as *const _);
+        assert_eq_m128i(r, a);
+    }
 }
@@ -12,17 +12,18 @@ use stdarch_test::assert_instr;
 extern "C" {
     #[link_name = "llvm.x86.sse4a.extrq"]
     fn extrq(x: i64x2, y: i8x16) -> i64x2;
+    #[link_name = "llvm.x86.sse4a.extrqi"]
+    fn extrqi(x: i64x2, len: u8, idx: u8) -> i64x2;
     #[link_name = "llvm.x86.sse4a.insertq"]
     fn insertq(x: i64x2, y: i64x2) -> i64x2;
+    #[link_name = "llvm.x86.sse4a.insertqi"]
+    fn insertqi(x: i64x2, y: i64x2, len: u8, idx: u8) -> i64x2;
     #[link_name = "llvm.x86.sse4a.movnt.sd"]
     fn movntsd(x: *mut f64, y: __m128d);
     #[link_name = "llvm.x86.sse4a.movnt.ss"]
     fn movntss(x: *mut f32, y: __m128);
 }
 
-// FIXME(blocked on #248): _mm_extracti_si64(x, len, idx) // EXTRQ
-// FIXME(blocked on #248): _mm_inserti_si64(x, y, len, idx) // INSERTQ
-
 /// Extracts the bit range specified by `y` from the lower 64 bits of `x`.
 ///
 /// The `[13:8]` bits of `y` specify the index of the bit-range to extract. The
@@ -42,6 +43,26 @@ pub unsafe fn _mm_extract_si64(x: __m128i, y: __m128i) -> __m128i {
     transmute(extrq(x.as_i64x2(), y.as_i8x16()))
 }
 
+/// Extracts the bit range specified by `LEN` and `IDX` from the lower 64 bits
+/// of `x`.
+///
+/// `IDX` specifies the index of the bit-range to extract and `LEN` specifies
+/// its length. Both must fit in 6 bits.
+///
+/// If the length is zero, it is interpreted as `64`. If the length and index
+/// are zero, the lower 64 bits of `x` are extracted.
+///
+/// If `LEN == 0 && IDX > 0` or `LEN + IDX > 64` the result is undefined.
+#[inline]
+#[target_feature(enable = "sse4a")]
+#[cfg_attr(test, assert_instr(extrq, LEN = 5, IDX = 5))]
+#[rustc_legacy_const_generics(1, 2)]
+pub unsafe fn _mm_extracti_si64<const LEN: i32, const IDX: i32>(x: __m128i) -> __m128i {
+    static_assert_imm6!(LEN);
+    static_assert_imm6!(IDX);
+    transmute(extrqi(x.as_i64x2(), LEN as u8, IDX as u8))
+}
+
 /// Inserts the `[length:0]` bits of `y` into `x` at `index`.
 ///
 /// The bits of `y`:
@@ -59,9 +80,27 @@ pub unsafe fn _mm_insert_si64(x: __m128i, y: __m128i) -> __m128i {
     transmute(insertq(x.as_i64x2(), y.as_i64x2()))
 }
 
+/// Inserts the `[LEN:0]` bits of `y` into `x` at `IDX`.
+///
+/// Both `LEN` and `IDX` must fit in 6 bits.
+///
+/// If `LEN` is zero it is interpreted as `64`. If `IDX + LEN > 64` or
+/// `IDX > 0 && LEN == 0` the result is undefined.
+#[inline]
+#[target_feature(enable = "sse4a")]
+#[cfg_attr(test, assert_instr(insertq, LEN = 5, IDX = 5))]
+#[rustc_legacy_const_generics(2, 3)]
+pub unsafe fn _mm_inserti_si64<const LEN: i32, const IDX: i32>(x: __m128i, y: __m128i) -> __m128i {
+    static_assert_imm6!(LEN);
+    static_assert_imm6!(IDX);
+    transmute(insertqi(x.as_i64x2(), y.as_i64x2(), LEN as u8, IDX as u8))
+}
+
 /// Non-temporal store of `a.0` into `p`.
 ///
 /// Writes 64-bit data to a memory location without polluting the caches.
+///
+/// See [`NonTemporalScope`] for ordering the store with other memory accesses.
 #[inline]
 #[target_feature(enable = "sse4a")]
 #[cfg_attr(test, assert_instr(movntsd))]
@@ -73,6 +112,8 @@ pub unsafe fn _mm_stream_sd(p: *mut f64, a: __m128d) {
 /// Non-temporal store of `a.0` into `p`.
 ///
 /// Writes 32-bit data to a memory location without polluting the caches.
+///
+/// See [`NonTemporalScope`] for ordering the store with other memory accesses.
 #[inline]
 #[target_feature(enable = "sse4a")]
 #[cfg_attr(test, assert_instr(movntss))]
@@ -99,6 +140,21 @@ mod tests {
         assert_eq_m128i(r, e);
     }
 
+    #[simd_test(enable = "sse4a")]
+    unsafe fn test_mm_extracti_si64() {
+        let b = 0b0110_0000_0000_i64;
+        //        ^^^^ bit range extracted
+        let x = _mm_setr_epi64x(b, 0);
+        let e = _mm_setr_epi64x(0b0110_i64, 0);
+        let r = _mm_extracti_si64::<4, 8>(x);
+        assert_eq_m128i(r, e);
+
+        // A length of zero with an index of zero extracts all 64 bits.
+        let x = _mm_setr_epi64x(-1, 0);
+        let r = _mm_extracti_si64::<0, 0>(x);
+        assert_eq_m128i(r, x);
+    }
+
     #[simd_test(enable = "sse4a")]
     unsafe fn test_mm_insert_si64() {
         let i = 0b0110_i64;
@@ -116,6 +172,27 @@ mod tests {
         assert_eq_m128i(r, expected);
     }
 
+    #[simd_test(enable = "sse4a")]
+    unsafe fn test_mm_inserti_si64() {
+        let i = 0b0110_i64;
+        //        ^^^^ bit range inserted
+        let z = 0b1010_1010_1010i64;
+        //        ^^^^ bit range replaced
+        let e = 0b0110_1010_1010i64;
+        //        ^^^^ replaced 1010 with 0110
+        let x = _mm_setr_epi64x(z, 0);
+        let expected = _mm_setr_epi64x(e, 0);
+        let y = _mm_setr_epi64x(i, 0);
+        let r = _mm_inserti_si64::<4, 8>(x, y);
+        assert_eq_m128i(r, expected);
+
+        // A length of zero with an index of zero replaces all 64 bits.
+        let x = _mm_setr_epi64x(z, 0);
+        let y = _mm_setr_epi64x(-1, 0);
+        let r = _mm_inserti_si64::<0, 0>(x, y);
+        assert_eq_m128i(r, y);
+    }
+
     #[repr(align(16))]
     struct MemoryF64 {
         data: [f64; 2],
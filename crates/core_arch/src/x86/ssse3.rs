@@ -77,7 +77,21 @@ pub unsafe fn _mm_abs_epi32(a: __m128i) -> __m128i {
 #[cfg_attr(test, assert_instr(pshufb))]
 #[stable(feature = "simd_x86", since = "1.27.0")]
 pub unsafe fn _mm_shuffle_epi8(a: __m128i, b: __m128i) -> __m128i {
-    transmute(pshufb128(a.as_u8x16(), b.as_u8x16()))
+    if cfg!(miri) {
+        // Miri can't execute the LLVM intrinsic, so shuffle the bytes one
+        // at a time instead.
+        let a: [u8; 16] = transmute(a);
+        let b: [u8; 16] = transmute(b);
+        let mut r = [0u8; 16];
+        for (r, i) in r.iter_mut().zip(b.iter()) {
+            if *i & 0x80 == 0 {
+                *r = a[(*i & 0x0f) as usize];
+            }
+        }
+        transmute(r)
+    } else {
+        transmute(pshufb128(a.as_u8x16(), b.as_u8x16()))
+    }
 }
 
 /// Concatenate 16-byte blocks in `a` and `b` into a 32-byte temporary result,
@@ -142,3 +142,49 @@ pub unsafe fn assert_eq_m512d(a: __m512d, b: __m512d) {
         panic!("{:?} != {:?}", a, b);
     }
 }
+
+/// The size of the pages returned by [`guarded_page`].
+pub const PAGE_SIZE: usize = 4096;
+
+#[cfg(target_os = "linux")]
+extern "C" {
+    fn mmap(addr: *mut u8, len: usize, prot: i32, flags: i32, fd: i32, off: i64) -> *mut u8;
+    fn mprotect(addr: *mut u8, len: usize, prot: i32) -> i32;
+    fn munmap(addr: *mut u8, len: usize) -> i32;
+}
+
+/// Maps a readable and writable page that is followed by an inaccessible
+/// guard page, so that any access past its end faults. Returns `None` if the
+/// OS isn't supported.
+pub unsafe fn guarded_page() -> Option<*mut u8> {
+    #[cfg(target_os = "linux")]
+    {
+        const PROT_NONE: i32 = 0x0;
+        const PROT_READ: i32 = 0x1;
+        const PROT_WRITE: i32 = 0x2;
+        const MAP_PRIVATE: i32 = 0x02;
+        const MAP_ANONYMOUS: i32 = 0x20;
+        let p = mmap(
+            crate::ptr::null_mut(),
+            2 * PAGE_SIZE,
+            PROT_READ | PROT_WRITE,
+            MAP_PRIVATE | MAP_ANONYMOUS,
+            -1,
+            0,
+        );
+        assert_ne!(p as isize, -1);
+        assert_eq!(mprotect(p.add(PAGE_SIZE), PAGE_SIZE, PROT_NONE), 0);
+        Some(p)
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        None
+    }
+}
+
+/// Unmaps a page returned by [`guarded_page`], together with its guard page.
+#[cfg_attr(not(target_os = "linux"), allow(unused_variables))]
+pub unsafe fn unmap_guarded_page(p: *mut u8) {
+    #[cfg(target_os = "linux")]
+    assert_eq!(munmap(p, 2 * PAGE_SIZE), 0);
+}
@@ -0,0 +1,80 @@
+//! Intel TSX suspend load address tracking (TSXLDTRK).
+//!
+//! These instructions let an RTM or HLE transaction, see the [`rtm`](super::rtm)
+//! module, perform loads without adding their addresses to the read set of
+//! the transaction. Loads in a suspend region don't cause the transaction to
+//! abort when another core writes to the loaded memory, which helps
+//! transactions that read data which isn't part of the critical section.
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Marks the start of a TSX (HLE/RTM) suspend load address tracking region.
+///
+/// If this is used inside a transactional region, subsequent loads are not
+/// added to the read set of the transaction. If this is used inside a suspend
+/// load address tracking region it will cause transaction abort. If this is
+/// used outside of a transactional region it behaves like a NOP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_xsusldtrk)
+#[inline]
+#[cfg_attr(test, assert_instr(xsusldtrk))]
+pub unsafe fn _xsusldtrk() {
+    asm!("xsusldtrk", options(nostack, preserves_flags));
+}
+
+/// Marks the end of a TSX (HLE/RTM) suspend load address tracking region.
+///
+/// If this is used inside a suspend load address tracking region it will end
+/// the suspend region and all following load addresses will be added to the
+/// transaction read set. If this is used inside an active transaction but not
+/// in a suspend region it will cause transaction abort. If this is used
+/// outside of a transactional region it behaves like a NOP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_xresldtrk)
+#[inline]
+#[cfg_attr(test, assert_instr(xresldtrk))]
+pub unsafe fn _xresldtrk() {
+    asm!("xresldtrk", options(nostack, preserves_flags));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{core_arch::x86::*, ptr};
+
+    #[test]
+    fn test_xsusldtrk() {
+        if !is_x86_feature_detected!("tsxldtrk") {
+            return stdarch_test::assert_skip_test_ok("test_xsusldtrk");
+        }
+        unsafe {
+            // outside a transactional region both instructions do nothing
+            _xsusldtrk();
+            _xresldtrk();
+        }
+    }
+
+    #[test]
+    fn test_xresldtrk() {
+        if !is_x86_feature_detected!("rtm") || !is_x86_feature_detected!("tsxldtrk") {
+            return stdarch_test::assert_skip_test_ok("test_xresldtrk");
+        }
+        unsafe {
+            let y = 1;
+            let mut x = 0;
+            for _ in 0..10 {
+                let code = _xbegin();
+                if code == _XBEGIN_STARTED {
+                    _xsusldtrk();
+                    let v = ptr::read_volatile(&y);
+                    _xresldtrk();
+                    x += v;
+                    _xend();
+                    assert_eq!(x, 1);
+                    break;
+                }
+                assert_eq!(x, 0);
+            }
+        }
+    }
+}
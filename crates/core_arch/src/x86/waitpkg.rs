@@ -0,0 +1,120 @@
+//! User wait instructions (WAITPKG)
+//!
+//! These let user space wait in a low-power optimized state, either for a
+//! store to a monitored address range or until a deadline expressed as a
+//! value of the time-stamp counter, see [`_rdtsc`](super::_rdtsc).
+//!
+//! The operating system can limit the maximum time spent in the optimized
+//! state through the `IA32_UMWAIT_CONTROL` MSR, in which case the
+//! instructions return early with the carry flag set.
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+// The deadline is passed in edx:eax, and the carry flag is returned.
+macro_rules! wait {
+    ($inst:literal, $ctrl:expr, $counter:expr) => {{
+        let cf: u8;
+        asm!(
+            concat!($inst, " {:e}"),
+            "setc {}",
+            in(reg) $ctrl,
+            out(reg_byte) cf,
+            in("edx") ($counter >> 32) as u32,
+            in("eax") $counter as u32,
+            options(nostack),
+        );
+        cf
+    }};
+}
+
+/// Sets up a linear address range to be monitored by hardware and activates
+/// the monitor. The address range should be a write-back memory caching type.
+/// The address is contained in `a`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_umonitor)
+#[inline]
+#[cfg_attr(test, assert_instr(umonitor))]
+pub unsafe fn _umonitor(a: *mut u8) {
+    asm!("umonitor {}", in(reg) a, options(nostack, preserves_flags));
+}
+
+/// Directs the processor to enter an implementation-dependent optimized state
+/// while monitoring a range of addresses armed by [`_umonitor`]. The
+/// instruction wakes up when the time-stamp counter reaches or exceeds the
+/// value specified in `counter` (if the monitoring hardware did not trigger
+/// beforehand). Bit 0 of `ctrl` selects between a lower power (cleared) or
+/// faster wakeup (set) optimized state. Returns the carry flag (CF), which is
+/// set if the wait ended because of the operating system's time limit.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_umwait)
+#[inline]
+#[cfg_attr(test, assert_instr(umwait))]
+pub unsafe fn _umwait(ctrl: u32, counter: u64) -> u8 {
+    wait!("umwait", ctrl, counter)
+}
+
+/// Directs the processor to enter an implementation-dependent optimized state
+/// until the time-stamp counter reaches or exceeds the value specified in
+/// `counter`. Bit 0 of `ctrl` selects between a lower power (cleared) or
+/// faster wakeup (set) optimized state. Returns the carry flag (CF), which is
+/// set if the wait ended because of the operating system's time limit.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_tpause)
+#[inline]
+#[cfg_attr(test, assert_instr(tpause))]
+pub unsafe fn _tpause(ctrl: u32, counter: u64) -> u8 {
+    wait!("tpause", ctrl, counter)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+
+    #[test]
+    fn test_umonitor() {
+        if !is_x86_feature_detected!("waitpkg") {
+            return stdarch_test::assert_skip_test_ok("test_umonitor");
+        }
+        unsafe {
+            let mut x = 0_u64;
+            _umonitor(&mut x as *mut _ as *mut u8);
+            // A store to the monitored address range disarms the monitor.
+            x = 1;
+            assert_eq!(x, 1);
+        }
+    }
+
+    #[test]
+    fn test_umwait() {
+        if !is_x86_feature_detected!("waitpkg") {
+            return stdarch_test::assert_skip_test_ok("test_umwait");
+        }
+        unsafe {
+            let mut x = 0_u64;
+            _umonitor(&mut x as *mut _ as *mut u8);
+            let deadline = _rdtsc() + 10_000;
+            for ctrl in 0..2 {
+                _umwait(ctrl, deadline);
+            }
+            assert_eq!(x, 0);
+        }
+    }
+
+    #[test]
+    fn test_tpause() {
+        if !is_x86_feature_detected!("waitpkg") {
+            return stdarch_test::assert_skip_test_ok("test_tpause");
+        }
+        unsafe {
+            // Waiting until a deadline slightly in the future returns, no
+            // matter whether the deadline or the operating system's limit is
+            // reached first.
+            for ctrl in 0..2 {
+                let deadline = _rdtsc() + 10_000;
+                let cf = _tpause(ctrl, deadline);
+                assert!(cf == 1 || _rdtsc() >= deadline);
+            }
+        }
+    }
+}
@@ -0,0 +1,34 @@
+//! Write back and do not invalidate cache (WBNOINVD)
+//!
+//! This instruction is privileged, and can only be executed by the kernel.
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Writes back all modified cache lines in the processor's internal caches
+/// to main memory, without invalidating them, and signals external caches
+/// to do the same.
+///
+/// Unlike `WBINVD` the cache lines are retained in a non-modified state, so
+/// later accesses to them don't miss. Hypervisors use this when changing
+/// the memory encryption state of a page, for example for AMD SEV or Intel
+/// TDX guests.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_wbnoinvd)
+#[inline]
+#[cfg_attr(test, assert_instr(wbnoinvd))]
+pub unsafe fn _wbnoinvd() {
+    asm!("wbnoinvd", options(nostack, preserves_flags));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+
+    // The instruction can only be executed by the kernel, so this only checks
+    // that the intrinsic can be used with the expected signature.
+    #[test]
+    fn test_wbnoinvd() {
+        let _: unsafe fn() = _wbnoinvd;
+    }
+}
@@ -0,0 +1,474 @@
+//! Intel Advanced Matrix Extensions (AMX)
+//!
+//! The tile registers are named by an immediate in the range `0..=7`, which
+//! the intrinsics here take as const generics.
+//!
+//! Before any of the tile data intrinsics can be used, a tile configuration
+//! has to be loaded with [`_tile_loadconfig`]. Operating systems may also
+//! require a process to request access to the tile data state first, on Linux
+//! this is done with `arch_prctl(ARCH_REQ_XCOMP_PERM, XFEATURE_XTILEDATA)`.
+//!
+//! The reference is [Intel 64 and IA-32 Architectures Software Developer's
+//! Manual Volume 2: Instruction Set Reference, A-Z][intel64_ref].
+//!
+//! [intel64_ref]: http://www.intel.de/content/dam/www/public/us/en/documents/manuals/64-ia-32-architectures-software-developer-instruction-set-reference-manual-325383.pdf
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.x86.ldtilecfg"]
+    fn ldtilecfg(mem_addr: *const u8);
+    #[link_name = "llvm.x86.sttilecfg"]
+    fn sttilecfg(mem_addr: *mut u8);
+    #[link_name = "llvm.x86.tileloadd64"]
+    fn tileloadd64(dst: i8, base: *const u8, stride: usize);
+    #[link_name = "llvm.x86.tileloaddt164"]
+    fn tileloaddt164(dst: i8, base: *const u8, stride: usize);
+    #[link_name = "llvm.x86.tilerelease"]
+    fn tilerelease();
+    #[link_name = "llvm.x86.tilestored64"]
+    fn tilestored64(dst: i8, base: *mut u8, stride: usize);
+    #[link_name = "llvm.x86.tilezero"]
+    fn tilezero(dst: i8);
+    #[link_name = "llvm.x86.tdpbssd"]
+    fn tdpbssd(dst: i8, a: i8, b: i8);
+    #[link_name = "llvm.x86.tdpbsud"]
+    fn tdpbsud(dst: i8, a: i8, b: i8);
+    #[link_name = "llvm.x86.tdpbusd"]
+    fn tdpbusd(dst: i8, a: i8, b: i8);
+    #[link_name = "llvm.x86.tdpbuud"]
+    fn tdpbuud(dst: i8, a: i8, b: i8);
+}
+
+/// Load tile configuration from a 64-byte memory location specified by
+/// `mem_addr`. The tile configuration format is specified below, and includes
+/// the tile type palette, the number of bytes per row, and the number of rows.
+/// If the specified palette_id is zero, that signifies the init state for both
+/// the tile config and the tile data, and the tiles are zeroed. Any invalid
+/// configurations will result in #GP fault.
+///
+/// | bytes   | field                                |
+/// |---------|--------------------------------------|
+/// | 0       | palette_id                           |
+/// | 1       | start_row                            |
+/// | 2-15    | reserved, must be zero               |
+/// | 16-47   | bytes per row of tiles 0-15 (`u16`)  |
+/// | 48-63   | rows of tiles 0-15 (`u8`)            |
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_tile_loadconfig)
+#[inline]
+#[target_feature(enable = "amx-tile")]
+#[cfg_attr(test, assert_instr(ldtilecfg))]
+pub unsafe fn _tile_loadconfig(mem_addr: *const u8) {
+    ldtilecfg(mem_addr);
+}
+
+/// Stores the current tile configuration to a 64-byte memory location
+/// specified by `mem_addr`. The tile configuration format is the one described
+/// by [`_tile_loadconfig`]. If the tile configuration is in the init state,
+/// all zeroes will be stored to memory.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_tile_storeconfig)
+#[inline]
+#[target_feature(enable = "amx-tile")]
+#[cfg_attr(test, assert_instr(sttilecfg))]
+pub unsafe fn _tile_storeconfig(mem_addr: *mut u8) {
+    sttilecfg(mem_addr);
+}
+
+/// Load tile rows from memory specified by `base` address and `stride` into
+/// destination tile `DST` using the tile configuration previously configured
+/// via [`_tile_loadconfig`].
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_tile_loadd)
+#[inline]
+#[target_feature(enable = "amx-tile")]
+#[cfg_attr(test, assert_instr(tileloadd, DST = 0))]
+#[rustc_legacy_const_generics(0)]
+pub unsafe fn _tile_loadd<const DST: i32>(base: *const u8, stride: usize) {
+    static_assert_imm3!(DST);
+    tileloadd64(DST as i8, base, stride);
+}
+
+/// Load tile rows from memory specified by `base` address and `stride` into
+/// destination tile `DST` using the tile configuration previously configured
+/// via [`_tile_loadconfig`]. This intrinsic provides a hint to the
+/// implementation that the data will likely not be reused in the near future
+/// and the data caching can be optimized accordingly.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_tile_stream_loadd)
+#[inline]
+#[target_feature(enable = "amx-tile")]
+#[cfg_attr(test, assert_instr(tileloaddt1, DST = 0))]
+#[rustc_legacy_const_generics(0)]
+pub unsafe fn _tile_stream_loadd<const DST: i32>(base: *const u8, stride: usize) {
+    static_assert_imm3!(DST);
+    tileloaddt164(DST as i8, base, stride);
+}
+
+/// Release the tile configuration to return to the init state, which
+/// releases all storage it currently holds.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_tile_release)
+#[inline]
+#[target_feature(enable = "amx-tile")]
+#[cfg_attr(test, assert_instr(tilerelease))]
+pub unsafe fn _tile_release() {
+    tilerelease();
+}
+
+/// Store the tile specified by `DST` to memory specified by `base` address and
+/// `stride` using the tile configuration previously configured via
+/// [`_tile_loadconfig`].
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_tile_stored)
+#[inline]
+#[target_feature(enable = "amx-tile")]
+#[cfg_attr(test, assert_instr(tilestored, DST = 0))]
+#[rustc_legacy_const_generics(0)]
+pub unsafe fn _tile_stored<const DST: i32>(base: *mut u8, stride: usize) {
+    static_assert_imm3!(DST);
+    tilestored64(DST as i8, base, stride);
+}
+
+/// Zero the tile specified by `DST`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_tile_zero)
+#[inline]
+#[target_feature(enable = "amx-tile")]
+#[cfg_attr(test, assert_instr(tilezero, DST = 0))]
+#[rustc_legacy_const_generics(0)]
+pub unsafe fn _tile_zero<const DST: i32>() {
+    static_assert_imm3!(DST);
+    tilezero(DST as i8);
+}
+
+/// Compute dot-product of bytes in tiles with a source/destination
+/// accumulator. Multiply groups of 4 adjacent pairs of signed 8-bit integers
+/// in `A` with corresponding signed 8-bit integers in `B`, producing 4
+/// intermediate 32-bit results. Sum these 4 results with the corresponding
+/// 32-bit integer in `DST`, and store the 32-bit result back to tile `DST`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_tile_dpbssd)
+#[inline]
+#[target_feature(enable = "amx-int8")]
+#[cfg_attr(test, assert_instr(tdpbssd, DST = 0, A = 1, B = 2))]
+#[rustc_legacy_const_generics(0, 1, 2)]
+pub unsafe fn _tile_dpbssd<const DST: i32, const A: i32, const B: i32>() {
+    static_assert_imm3!(DST);
+    static_assert_imm3!(A);
+    static_assert_imm3!(B);
+    tdpbssd(DST as i8, A as i8, B as i8);
+}
+
+/// Compute dot-product of bytes in tiles with a source/destination
+/// accumulator. Multiply groups of 4 adjacent pairs of signed 8-bit integers
+/// in `A` with corresponding unsigned 8-bit integers in `B`, producing 4
+/// intermediate 32-bit results. Sum these 4 results with the corresponding
+/// 32-bit integer in `DST`, and store the 32-bit result back to tile `DST`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_tile_dpbsud)
+#[inline]
+#[target_feature(enable = "amx-int8")]
+#[cfg_attr(test, assert_instr(tdpbsud, DST = 0, A = 1, B = 2))]
+#[rustc_legacy_const_generics(0, 1, 2)]
+pub unsafe fn _tile_dpbsud<const DST: i32, const A: i32, const B: i32>() {
+    static_assert_imm3!(DST);
+    static_assert_imm3!(A);
+    static_assert_imm3!(B);
+    tdpbsud(DST as i8, A as i8, B as i8);
+}
+
+/// Compute dot-product of bytes in tiles with a source/destination
+/// accumulator. Multiply groups of 4 adjacent pairs of unsigned 8-bit integers
+/// in `A` with corresponding signed 8-bit integers in `B`, producing 4
+/// intermediate 32-bit results. Sum these 4 results with the corresponding
+/// 32-bit integer in `DST`, and store the 32-bit result back to tile `DST`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_tile_dpbusd)
+#[inline]
+#[target_feature(enable = "amx-int8")]
+#[cfg_attr(test, assert_instr(tdpbusd, DST = 0, A = 1, B = 2))]
+#[rustc_legacy_const_generics(0, 1, 2)]
+pub unsafe fn _tile_dpbusd<const DST: i32, const A: i32, const B: i32>() {
+    static_assert_imm3!(DST);
+    static_assert_imm3!(A);
+    static_assert_imm3!(B);
+    tdpbusd(DST as i8, A as i8, B as i8);
+}
+
+/// Compute dot-product of bytes in tiles with a source/destination
+/// accumulator. Multiply groups of 4 adjacent pairs of unsigned 8-bit integers
+/// in `A` with corresponding unsigned 8-bit integers in `B`, producing 4
+/// intermediate 32-bit results. Sum these 4 results with the corresponding
+/// 32-bit integer in `DST`, and store the 32-bit result back to tile `DST`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_tile_dpbuud)
+#[inline]
+#[target_feature(enable = "amx-int8")]
+#[cfg_attr(test, assert_instr(tdpbuud, DST = 0, A = 1, B = 2))]
+#[rustc_legacy_const_generics(0, 1, 2)]
+pub unsafe fn _tile_dpbuud<const DST: i32, const A: i32, const B: i32>() {
+    static_assert_imm3!(DST);
+    static_assert_imm3!(A);
+    static_assert_imm3!(B);
+    tdpbuud(DST as i8, A as i8, B as i8);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86_64::*;
+    use stdarch_test::simd_test;
+
+    // The 64-byte tile configuration read by `_tile_loadconfig`.
+    #[repr(C)]
+    #[derive(Copy, Clone, Debug, PartialEq)]
+    struct TileConfig {
+        palette_id: u8,
+        start_row: u8,
+        reserved: [u8; 14],
+        colsb: [u16; 16],
+        rows: [u8; 16],
+    }
+
+    impl TileConfig {
+        const ZERO: TileConfig = TileConfig {
+            palette_id: 0,
+            start_row: 0,
+            reserved: [0; 14],
+            colsb: [0; 16],
+            rows: [0; 16],
+        };
+
+        // Configures tiles 0 to 2 with 16 rows of 64 bytes each, the largest
+        // tiles supported by palette 1.
+        fn new() -> TileConfig {
+            let mut config = TileConfig::ZERO;
+            config.palette_id = 1;
+            for i in 0..3 {
+                config.colsb[i] = 64;
+                config.rows[i] = 16;
+            }
+            config
+        }
+    }
+
+    // Linux only allows using the tile data after a process requested
+    // permission to, otherwise touching it raises SIGILL.
+    #[cfg(target_os = "linux")]
+    fn request_permission() -> bool {
+        const SYS_ARCH_PRCTL: usize = 158;
+        const ARCH_REQ_XCOMP_PERM: usize = 0x1023;
+        const XFEATURE_XTILEDATA: usize = 18;
+        let ret: isize;
+        unsafe {
+            asm!(
+                "syscall",
+                inlateout("rax") SYS_ARCH_PRCTL => ret,
+                in("rdi") ARCH_REQ_XCOMP_PERM,
+                in("rsi") XFEATURE_XTILEDATA,
+                lateout("rcx") _,
+                lateout("r11") _,
+                options(nostack),
+            );
+        }
+        ret == 0
+    }
+
+    // Other operating systems either enable the tile data for all processes,
+    // or they don't set the `XCR0` bits checked by `is_x86_feature_detected!`.
+    #[cfg(not(target_os = "linux"))]
+    fn request_permission() -> bool {
+        true
+    }
+
+    // Loads the configuration from `TileConfig::new`, or returns `false` if
+    // the tests can't use the tile data.
+    #[target_feature(enable = "amx-tile")]
+    unsafe fn init() -> bool {
+        if !request_permission() {
+            println!("skipping: the OS doesn't allow using the tile data");
+            return false;
+        }
+        let config = TileConfig::new();
+        _tile_loadconfig(&config as *const _ as *const u8);
+        true
+    }
+
+    fn data() -> [u8; 1024] {
+        let mut data = [0; 1024];
+        for (i, x) in data.iter_mut().enumerate() {
+            *x = (i * 7 + 3) as u8;
+        }
+        data
+    }
+
+    #[simd_test(enable = "amx-tile")]
+    unsafe fn test_tile_loadconfig() {
+        if !init() {
+            return;
+        }
+        let mut config = TileConfig::ZERO;
+        _tile_storeconfig(&mut config as *mut _ as *mut u8);
+        _tile_release();
+        assert_eq!(config, TileConfig::new());
+    }
+
+    #[simd_test(enable = "amx-tile")]
+    unsafe fn test_tile_storeconfig() {
+        if !init() {
+            return;
+        }
+        _tile_release();
+        // The init state is stored as all zeroes.
+        let mut config = TileConfig::new();
+        _tile_storeconfig(&mut config as *mut _ as *mut u8);
+        assert_eq!(config, TileConfig::ZERO);
+    }
+
+    #[simd_test(enable = "amx-tile")]
+    unsafe fn test_tile_release() {
+        if !init() {
+            return;
+        }
+        let a = data();
+        _tile_loadd::<0>(a.as_ptr(), 64);
+        _tile_release();
+        // Loading the configuration again zeroes the tile data.
+        init();
+        let mut r = [1; 1024];
+        _tile_stored::<0>(r.as_mut_ptr(), 64);
+        _tile_release();
+        assert_eq!(&r[..], &[0; 1024][..]);
+    }
+
+    #[simd_test(enable = "amx-tile")]
+    unsafe fn test_tile_zero() {
+        if !init() {
+            return;
+        }
+        let a = data();
+        _tile_loadd::<1>(a.as_ptr(), 64);
+        _tile_zero::<1>();
+        let mut r = [1; 1024];
+        _tile_stored::<1>(r.as_mut_ptr(), 64);
+        _tile_release();
+        assert_eq!(&r[..], &[0; 1024][..]);
+    }
+
+    #[simd_test(enable = "amx-tile")]
+    unsafe fn test_tile_loadd() {
+        if !init() {
+            return;
+        }
+        // Load every other row of a buffer, and store it densely.
+        let mut a = [0; 2048];
+        for row in 0..16 {
+            for (i, x) in a[128 * row..][..64].iter_mut().enumerate() {
+                *x = (row * 64 + i) as u8;
+            }
+        }
+        _tile_loadd::<2>(a.as_ptr(), 128);
+        let mut r = [0; 1024];
+        _tile_stored::<2>(r.as_mut_ptr(), 64);
+        _tile_release();
+        for (i, x) in r.iter().enumerate() {
+            assert_eq!(*x, i as u8);
+        }
+    }
+
+    #[simd_test(enable = "amx-tile")]
+    unsafe fn test_tile_stream_loadd() {
+        if !init() {
+            return;
+        }
+        let a = data();
+        _tile_stream_loadd::<0>(a.as_ptr(), 64);
+        let mut r = [0; 1024];
+        _tile_stored::<0>(r.as_mut_ptr(), 64);
+        _tile_release();
+        assert_eq!(&r[..], &a[..]);
+    }
+
+    #[simd_test(enable = "amx-tile")]
+    unsafe fn test_tile_stored() {
+        if !init() {
+            return;
+        }
+        // Store into every other row of a buffer, leaving the others alone.
+        let a = data();
+        _tile_loadd::<0>(a.as_ptr(), 64);
+        let mut r = [0xff; 2048];
+        _tile_stored::<0>(r.as_mut_ptr(), 128);
+        _tile_release();
+        for row in 0..16 {
+            assert_eq!(&r[128 * row..][..64], &a[64 * row..][..64]);
+            assert_eq!(&r[128 * row + 64..][..64], &[0xff; 64][..]);
+        }
+    }
+
+    // Multiplies a 16x64 matrix of bytes from tile 1 with a 64x16 matrix of
+    // bytes from tile 2, stored in the 16x16x4 layout the dot product
+    // instructions expect, and accumulates the result into a 16x16 matrix of
+    // `i32` in tile 0 using `dp`. The result is compared against a scalar
+    // implementation, interpreting the bytes of `a` and `b` as signed or
+    // unsigned depending on `signed_a` and `signed_b`.
+    #[target_feature(enable = "amx-tile,amx-int8")]
+    unsafe fn check_dp(dp: unsafe fn(), signed_a: bool, signed_b: bool) {
+        if !init() {
+            return;
+        }
+        let a = data();
+        let mut b = [0_u8; 1024];
+        for (i, x) in b.iter_mut().enumerate() {
+            *x = (i * 13 + 5) as u8;
+        }
+        let mut c = [0_i32; 256];
+        for (i, x) in c.iter_mut().enumerate() {
+            *x = i as i32 - 128;
+        }
+
+        _tile_loadd::<0>(c.as_ptr() as *const u8, 64);
+        _tile_loadd::<1>(a.as_ptr(), 64);
+        _tile_loadd::<2>(b.as_ptr(), 64);
+        dp();
+        let mut r = [0_i32; 256];
+        _tile_stored::<0>(r.as_mut_ptr() as *mut u8, 64);
+        _tile_release();
+
+        let extend = |x: u8, signed: bool| if signed { x as i8 as i32 } else { x as i32 };
+        for m in 0..16 {
+            for n in 0..16 {
+                let mut e = c[16 * m + n];
+                for k in 0..64 {
+                    let x = extend(a[64 * m + k], signed_a);
+                    let y = extend(b[64 * (k / 4) + 4 * n + k % 4], signed_b);
+                    e += x * y;
+                }
+                assert_eq!(r[16 * m + n], e, "mismatch at row {} column {}", m, n);
+            }
+        }
+    }
+
+    #[simd_test(enable = "amx-tile,amx-int8")]
+    unsafe fn test_tile_dpbssd() {
+        check_dp(_tile_dpbssd::<0, 1, 2>, true, true);
+    }
+
+    #[simd_test(enable = "amx-tile,amx-int8")]
+    unsafe fn test_tile_dpbsud() {
+        check_dp(_tile_dpbsud::<0, 1, 2>, true, false);
+    }
+
+    #[simd_test(enable = "amx-tile,amx-int8")]
+    unsafe fn test_tile_dpbusd() {
+        check_dp(_tile_dpbusd::<0, 1, 2>, false, true);
+    }
+
+    #[simd_test(enable = "amx-tile,amx-int8")]
+    unsafe fn test_tile_dpbuud() {
+        check_dp(_tile_dpbuud::<0, 1, 2>, false, false);
+    }
+}
@@ -0,0 +1,64 @@
+use crate::core_arch::x86::*;
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Convert 64-bit mask a into an integer value, and store the result in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_cvtmask64_u64)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(kmovq))]
+pub unsafe fn _cvtmask64_u64(a: __mmask64) -> u64 {
+    let r: u64;
+    asm!(
+        "kmovq {r}, {a}",
+        a = in(kreg) a,
+        r = lateout(reg) r,
+        options(pure, nomem, nostack, preserves_flags)
+    );
+    r
+}
+
+/// Convert integer value a into a 64-bit mask, and store the result in k.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_cvtu64_mask64)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(kmovq))]
+pub unsafe fn _cvtu64_mask64(a: u64) -> __mmask64 {
+    let r: __mmask64;
+    asm!(
+        "kmovq {r}, {a}",
+        a = in(reg) a,
+        r = lateout(kreg) r,
+        options(pure, nomem, nostack, preserves_flags)
+    );
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use stdarch_test::simd_test;
+
+    use crate::core_arch::{x86::*, x86_64::*};
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_cvtmask64_u64() {
+        let a: __mmask64 =
+            0b11111111_00000000_11111111_00000000_11001100_00110011_11001100_00110011;
+        let r = _cvtmask64_u64(a);
+        let e: u64 = 0b11111111_00000000_11111111_00000000_11001100_00110011_11001100_00110011;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_cvtu64_mask64() {
+        let a: u64 = 0b11111111_00000000_11111111_00000000_11001100_00110011_11001100_00110011;
+        let r = _cvtu64_mask64(a);
+        let e: __mmask64 =
+            0b11111111_00000000_11111111_00000000_11001100_00110011_11001100_00110011;
+        assert_eq!(r, e);
+        assert_eq!(_cvtmask64_u64(_cvtu64_mask64(a)), a);
+    }
+}
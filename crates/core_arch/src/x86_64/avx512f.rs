@@ -9847,6 +9847,10 @@ mod tests {
         let r = _mm512_zextpd128_pd512(a);
         let e = _mm512_setr_pd(17., 18., 0., 0., 0., 0., 0., 0.);
         assert_eq_m512d(r, e);
+        let a = _mm512_castpd512_pd128(black_box(_mm512_set1_pd(-1.)));
+        let r = _mm512_zextpd128_pd512(a);
+        let e = _mm512_setr_pd(-1., -1., 0., 0., 0., 0., 0., 0.);
+        assert_eq_m512d(r, e);
     }
 
     #[simd_test(enable = "avx512f")]
@@ -9855,6 +9859,10 @@ mod tests {
         let r = _mm512_zextpd256_pd512(a);
         let e = _mm512_setr_pd(17., 18., 19., 20., 0., 0., 0., 0.);
         assert_eq_m512d(r, e);
+        let a = _mm512_castpd512_pd256(black_box(_mm512_set1_pd(-1.)));
+        let r = _mm512_zextpd256_pd512(a);
+        let e = _mm512_setr_pd(-1., -1., -1., -1., 0., 0., 0., 0.);
+        assert_eq_m512d(r, e);
     }
 
     #[simd_test(enable = "avx512f")]
@@ -9917,6 +9925,10 @@ mod tests {
         let r = _mm512_zextsi128_si512(a);
         let e = _mm512_setr_epi64(17, 18, 0, 0, 0, 0, 0, 0);
         assert_eq_m512i(r, e);
+        let a = _mm512_castsi512_si128(black_box(_mm512_set1_epi64(-1)));
+        let r = _mm512_zextsi128_si512(a);
+        let e = _mm512_setr_epi64(-1, -1, 0, 0, 0, 0, 0, 0);
+        assert_eq_m512i(r, e);
     }
 
     #[simd_test(enable = "avx512f")]
@@ -9925,6 +9937,10 @@ mod tests {
         let r = _mm512_zextsi256_si512(a);
         let e = _mm512_setr_epi64(17, 18, 19, 20, 0, 0, 0, 0);
         assert_eq_m512i(r, e);
+        let a = _mm512_castsi512_si256(black_box(_mm512_set1_epi64(-1)));
+        let r = _mm512_zextsi256_si512(a);
+        let e = _mm512_setr_epi64(-1, -1, -1, -1, 0, 0, 0, 0);
+        assert_eq_m512i(r, e);
     }
 
     #[simd_test(enable = "avx512f")]
@@ -11487,6 +11503,180 @@ mod tests {
         assert_eq_m128i(r, e);
     }
 
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_mask_loadu_epi64() {
+        let mut buf = [0_i64; 9];
+        for (i, x) in buf.iter_mut().enumerate() {
+            *x = i as i64;
+        }
+        let src = _mm512_set1_epi32(-1);
+        let m: __mmask8 = 0xf1;
+        // Offset by one element so that the vector is misaligned.
+        let r = _mm512_mask_loadu_epi64(src, m, black_box(buf.as_ptr().add(1)));
+        let mut e = [-1_i64; 8];
+        for i in 0..8 {
+            if m >> i & 1 != 0 {
+                e[i] = buf[i + 1];
+            }
+        }
+        assert_eq_m512i(r, _mm512_loadu_epi64(e.as_ptr()));
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_maskz_loadu_epi64() {
+        let mut buf = [0_i64; 9];
+        for (i, x) in buf.iter_mut().enumerate() {
+            *x = i as i64 + 1;
+        }
+        let m: __mmask8 = 0xf1;
+        // Offset by one element so that the vector is misaligned.
+        let r = _mm512_maskz_loadu_epi64(m, black_box(buf.as_ptr().add(1)));
+        let mut e = [0_i64; 8];
+        for i in 0..8 {
+            if m >> i & 1 != 0 {
+                e[i] = buf[i + 1];
+            }
+        }
+        assert_eq_m512i(r, _mm512_loadu_epi64(e.as_ptr()));
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_mask_storeu_epi64() {
+        let mut v = [0_i64; 8];
+        for (i, x) in v.iter_mut().enumerate() {
+            *x = i as i64;
+        }
+        let a = _mm512_loadu_epi64(v.as_ptr());
+        let m: __mmask8 = 0xf1;
+        let mut buf = [-1_i64; 10];
+        // Offset by one element so that the vector is misaligned.
+        _mm512_mask_storeu_epi64(black_box(buf.as_mut_ptr().add(1)), m, a);
+        // Neither the unselected elements nor the bytes around the vector
+        // are touched.
+        assert_eq!(buf[0], -1);
+        assert_eq!(buf[9], -1);
+        for i in 0..8 {
+            let e = if m >> i & 1 != 0 { v[i] } else { -1 };
+            assert_eq!(buf[i + 1], e);
+        }
+    }
+
+    #[simd_test(enable = "avx512f,avx512vl")]
+    unsafe fn test_mm256_mask_loadu_epi64() {
+        let mut buf = [0_i64; 5];
+        for (i, x) in buf.iter_mut().enumerate() {
+            *x = i as i64;
+        }
+        let src = _mm256_set1_epi32(-1);
+        let m: __mmask8 = 0b1101;
+        // Offset by one element so that the vector is misaligned.
+        let r = _mm256_mask_loadu_epi64(src, m, black_box(buf.as_ptr().add(1)));
+        let mut e = [-1_i64; 4];
+        for i in 0..4 {
+            if m >> i & 1 != 0 {
+                e[i] = buf[i + 1];
+            }
+        }
+        assert_eq_m256i(r, _mm256_loadu_epi64(e.as_ptr()));
+    }
+
+    #[simd_test(enable = "avx512f,avx512vl")]
+    unsafe fn test_mm256_maskz_loadu_epi64() {
+        let mut buf = [0_i64; 5];
+        for (i, x) in buf.iter_mut().enumerate() {
+            *x = i as i64 + 1;
+        }
+        let m: __mmask8 = 0b1101;
+        // Offset by one element so that the vector is misaligned.
+        let r = _mm256_maskz_loadu_epi64(m, black_box(buf.as_ptr().add(1)));
+        let mut e = [0_i64; 4];
+        for i in 0..4 {
+            if m >> i & 1 != 0 {
+                e[i] = buf[i + 1];
+            }
+        }
+        assert_eq_m256i(r, _mm256_loadu_epi64(e.as_ptr()));
+    }
+
+    #[simd_test(enable = "avx512f,avx512vl")]
+    unsafe fn test_mm256_mask_storeu_epi64() {
+        let mut v = [0_i64; 4];
+        for (i, x) in v.iter_mut().enumerate() {
+            *x = i as i64;
+        }
+        let a = _mm256_loadu_epi64(v.as_ptr());
+        let m: __mmask8 = 0b1101;
+        let mut buf = [-1_i64; 6];
+        // Offset by one element so that the vector is misaligned.
+        _mm256_mask_storeu_epi64(black_box(buf.as_mut_ptr().add(1)), m, a);
+        // Neither the unselected elements nor the bytes around the vector
+        // are touched.
+        assert_eq!(buf[0], -1);
+        assert_eq!(buf[5], -1);
+        for i in 0..4 {
+            let e = if m >> i & 1 != 0 { v[i] } else { -1 };
+            assert_eq!(buf[i + 1], e);
+        }
+    }
+
+    #[simd_test(enable = "avx512f,avx512vl")]
+    unsafe fn test_mm_mask_loadu_epi64() {
+        let mut buf = [0_i64; 3];
+        for (i, x) in buf.iter_mut().enumerate() {
+            *x = i as i64;
+        }
+        let src = _mm_set1_epi32(-1);
+        let m: __mmask8 = 0b10;
+        // Offset by one element so that the vector is misaligned.
+        let r = _mm_mask_loadu_epi64(src, m, black_box(buf.as_ptr().add(1)));
+        let mut e = [-1_i64; 2];
+        for i in 0..2 {
+            if m >> i & 1 != 0 {
+                e[i] = buf[i + 1];
+            }
+        }
+        assert_eq_m128i(r, _mm_loadu_epi64(e.as_ptr()));
+    }
+
+    #[simd_test(enable = "avx512f,avx512vl")]
+    unsafe fn test_mm_maskz_loadu_epi64() {
+        let mut buf = [0_i64; 3];
+        for (i, x) in buf.iter_mut().enumerate() {
+            *x = i as i64 + 1;
+        }
+        let m: __mmask8 = 0b10;
+        // Offset by one element so that the vector is misaligned.
+        let r = _mm_maskz_loadu_epi64(m, black_box(buf.as_ptr().add(1)));
+        let mut e = [0_i64; 2];
+        for i in 0..2 {
+            if m >> i & 1 != 0 {
+                e[i] = buf[i + 1];
+            }
+        }
+        assert_eq_m128i(r, _mm_loadu_epi64(e.as_ptr()));
+    }
+
+    #[simd_test(enable = "avx512f,avx512vl")]
+    unsafe fn test_mm_mask_storeu_epi64() {
+        let mut v = [0_i64; 2];
+        for (i, x) in v.iter_mut().enumerate() {
+            *x = i as i64;
+        }
+        let a = _mm_loadu_epi64(v.as_ptr());
+        let m: __mmask8 = 0b10;
+        let mut buf = [-1_i64; 4];
+        // Offset by one element so that the vector is misaligned.
+        _mm_mask_storeu_epi64(black_box(buf.as_mut_ptr().add(1)), m, a);
+        // Neither the unselected elements nor the bytes around the vector
+        // are touched.
+        assert_eq!(buf[0], -1);
+        assert_eq!(buf[3], -1);
+        for i in 0..2 {
+            let e = if m >> i & 1 != 0 { v[i] } else { -1 };
+            assert_eq!(buf[i + 1], e);
+        }
+    }
+
     #[simd_test(enable = "avx512f")]
     unsafe fn test_mm512_mask_cvtepi64_storeu_epi16() {
         let a = _mm512_set1_epi64(9);
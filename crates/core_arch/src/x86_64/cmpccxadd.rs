@@ -0,0 +1,213 @@
+//! Compare and add if condition is met (CMPccXADD)
+//!
+//! These atomically compare a value in memory with one operand, and add
+//! another operand to the memory if the comparison holds, like a combination
+//! of a `lock cmpxchg` and a `lock xadd`. The original value in memory is
+//! returned either way.
+
+use crate::hint::unreachable_unchecked;
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+// The condition is part of the mnemonic, so each one needs its own `asm!`.
+macro_rules! cmpccxadd {
+    ($imm:expr, $operands:literal, $addr:expr, $a:expr, $b:expr) => {{
+        let (addr, mut a, b) = ($addr, $a, $b);
+        cmpccxadd!(
+            @match $imm, $operands, addr, a, b,
+            _CMPCCX_O => "o",
+            _CMPCCX_NO => "no",
+            _CMPCCX_B => "b",
+            _CMPCCX_NB => "nb",
+            _CMPCCX_Z => "z",
+            _CMPCCX_NZ => "nz",
+            _CMPCCX_BE => "be",
+            _CMPCCX_NBE => "nbe",
+            _CMPCCX_S => "s",
+            _CMPCCX_NS => "ns",
+            _CMPCCX_P => "p",
+            _CMPCCX_NP => "np",
+            _CMPCCX_L => "l",
+            _CMPCCX_NL => "nl",
+            _CMPCCX_LE => "le",
+            _CMPCCX_NLE => "nle"
+        );
+        a
+    }};
+    (@match $imm:expr, $operands:literal, $addr:ident, $a:ident, $b:ident,
+     $($cc:ident => $mnemonic:literal),*) => {
+        match $imm {
+            $(
+                $cc => asm!(
+                    concat!("cmp", $mnemonic, "xadd ", $operands),
+                    in(reg) $addr,
+                    inout(reg) $a,
+                    in(reg) $b,
+                    options(nostack),
+                ),
+            )*
+            _ => unreachable_unchecked(),
+        }
+    };
+}
+
+/// Overflow (OF = 1)
+pub const _CMPCCX_O: i32 = 0x00;
+/// No overflow (OF = 0)
+pub const _CMPCCX_NO: i32 = 0x01;
+/// Below, for unsigned comparisons (CF = 1)
+pub const _CMPCCX_B: i32 = 0x02;
+/// Not below, for unsigned comparisons (CF = 0)
+pub const _CMPCCX_NB: i32 = 0x03;
+/// Zero, that is, equal (ZF = 1)
+pub const _CMPCCX_Z: i32 = 0x04;
+/// Not zero, that is, not equal (ZF = 0)
+pub const _CMPCCX_NZ: i32 = 0x05;
+/// Below or equal, for unsigned comparisons (CF = 1 or ZF = 1)
+pub const _CMPCCX_BE: i32 = 0x06;
+/// Not below or equal, for unsigned comparisons (CF = 0, ZF = 0)
+pub const _CMPCCX_NBE: i32 = 0x07;
+/// Sign (SF = 1)
+pub const _CMPCCX_S: i32 = 0x08;
+/// No sign (SF = 0)
+pub const _CMPCCX_NS: i32 = 0x09;
+/// Parity (PF = 1)
+pub const _CMPCCX_P: i32 = 0x0a;
+/// No parity (PF = 0)
+pub const _CMPCCX_NP: i32 = 0x0b;
+/// Less, for signed comparisons (SF != OF)
+pub const _CMPCCX_L: i32 = 0x0c;
+/// Not less, for signed comparisons (SF = OF)
+pub const _CMPCCX_NL: i32 = 0x0d;
+/// Less or equal, for signed comparisons (ZF = 1 or SF != OF)
+pub const _CMPCCX_LE: i32 = 0x0e;
+/// Not less or equal, for signed comparisons (ZF = 0, SF = OF)
+pub const _CMPCCX_NLE: i32 = 0x0f;
+
+/// Compares the 32-bit integer at `addr` with `a`, by subtracting `a` from
+/// it and checking whether the flags fulfil the condition selected by `IMM`,
+/// one of the `_CMPCCX_*` constants. If they do, `b` is added to the value at
+/// `addr`. The whole operation is atomic, and the original value at `addr` is
+/// returned.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_cmpccxadd_epi32)
+#[inline]
+#[cfg_attr(test, assert_instr(cmpoxadd, IMM = _CMPCCX_O))]
+#[cfg_attr(test, assert_instr(cmpnoxadd, IMM = _CMPCCX_NO))]
+#[cfg_attr(test, assert_instr(cmpbxadd, IMM = _CMPCCX_B))]
+#[cfg_attr(test, assert_instr(cmpnbxadd, IMM = _CMPCCX_NB))]
+#[cfg_attr(test, assert_instr(cmpzxadd, IMM = _CMPCCX_Z))]
+#[cfg_attr(test, assert_instr(cmpnzxadd, IMM = _CMPCCX_NZ))]
+#[cfg_attr(test, assert_instr(cmpbexadd, IMM = _CMPCCX_BE))]
+#[cfg_attr(test, assert_instr(cmpnbexadd, IMM = _CMPCCX_NBE))]
+#[cfg_attr(test, assert_instr(cmpsxadd, IMM = _CMPCCX_S))]
+#[cfg_attr(test, assert_instr(cmpnsxadd, IMM = _CMPCCX_NS))]
+#[cfg_attr(test, assert_instr(cmppxadd, IMM = _CMPCCX_P))]
+#[cfg_attr(test, assert_instr(cmpnpxadd, IMM = _CMPCCX_NP))]
+#[cfg_attr(test, assert_instr(cmplxadd, IMM = _CMPCCX_L))]
+#[cfg_attr(test, assert_instr(cmpnlxadd, IMM = _CMPCCX_NL))]
+#[cfg_attr(test, assert_instr(cmplexadd, IMM = _CMPCCX_LE))]
+#[cfg_attr(test, assert_instr(cmpnlexadd, IMM = _CMPCCX_NLE))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _cmpccxadd_epi32<const IMM: i32>(addr: *mut i32, a: i32, b: i32) -> i32 {
+    static_assert_imm4!(IMM);
+    cmpccxadd!(IMM, "dword ptr [{}], {:e}, {:e}", addr, a, b)
+}
+
+/// Compares the 64-bit integer at `addr` with `a`, by subtracting `a` from
+/// it and checking whether the flags fulfil the condition selected by `IMM`,
+/// one of the `_CMPCCX_*` constants. If they do, `b` is added to the value at
+/// `addr`. The whole operation is atomic, and the original value at `addr` is
+/// returned.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_cmpccxadd_epi64)
+#[inline]
+#[cfg_attr(test, assert_instr(cmpoxadd, IMM = _CMPCCX_O))]
+#[cfg_attr(test, assert_instr(cmpnoxadd, IMM = _CMPCCX_NO))]
+#[cfg_attr(test, assert_instr(cmpbxadd, IMM = _CMPCCX_B))]
+#[cfg_attr(test, assert_instr(cmpnbxadd, IMM = _CMPCCX_NB))]
+#[cfg_attr(test, assert_instr(cmpzxadd, IMM = _CMPCCX_Z))]
+#[cfg_attr(test, assert_instr(cmpnzxadd, IMM = _CMPCCX_NZ))]
+#[cfg_attr(test, assert_instr(cmpbexadd, IMM = _CMPCCX_BE))]
+#[cfg_attr(test, assert_instr(cmpnbexadd, IMM = _CMPCCX_NBE))]
+#[cfg_attr(test, assert_instr(cmpsxadd, IMM = _CMPCCX_S))]
+#[cfg_attr(test, assert_instr(cmpnsxadd, IMM = _CMPCCX_NS))]
+#[cfg_attr(test, assert_instr(cmppxadd, IMM = _CMPCCX_P))]
+#[cfg_attr(test, assert_instr(cmpnpxadd, IMM = _CMPCCX_NP))]
+#[cfg_attr(test, assert_instr(cmplxadd, IMM = _CMPCCX_L))]
+#[cfg_attr(test, assert_instr(cmpnlxadd, IMM = _CMPCCX_NL))]
+#[cfg_attr(test, assert_instr(cmplexadd, IMM = _CMPCCX_LE))]
+#[cfg_attr(test, assert_instr(cmpnlexadd, IMM = _CMPCCX_NLE))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _cmpccxadd_epi64<const IMM: i32>(addr: *mut i64, a: i64, b: i64) -> i64 {
+    static_assert_imm4!(IMM);
+    cmpccxadd!(IMM, "qword ptr [{}], {}, {}", addr, a, b)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86_64::*;
+
+    #[test]
+    fn test_cmpccxadd_epi32() {
+        if !is_x86_feature_detected!("cmpccxadd") {
+            return stdarch_test::assert_skip_test_ok("test_cmpccxadd_epi32");
+        }
+        unsafe {
+            // 10 == 5 doesn't hold, so nothing is added.
+            let mut x = 10_i32;
+            assert_eq!(_cmpccxadd_epi32::<_CMPCCX_Z>(&mut x, 5, 3), 10);
+            assert_eq!(x, 10);
+            // 10 != 5 holds, so 3 is added.
+            assert_eq!(_cmpccxadd_epi32::<_CMPCCX_NZ>(&mut x, 5, 3), 10);
+            assert_eq!(x, 13);
+
+            // -1 is less than 1 when compared as signed integers, but not when
+            // compared as unsigned ones.
+            let mut x = -1_i32;
+            assert_eq!(_cmpccxadd_epi32::<_CMPCCX_B>(&mut x, 1, 1), -1);
+            assert_eq!(x, -1);
+            assert_eq!(_cmpccxadd_epi32::<_CMPCCX_L>(&mut x, 1, 1), -1);
+            assert_eq!(x, 0);
+
+            // A bounded counter, which stops being incremented at 3.
+            let mut x = 0_i32;
+            for _ in 0..5 {
+                _cmpccxadd_epi32::<_CMPCCX_L>(&mut x, 3, 1);
+            }
+            assert_eq!(x, 3);
+        }
+    }
+
+    #[test]
+    fn test_cmpccxadd_epi64() {
+        if !is_x86_feature_detected!("cmpccxadd") {
+            return stdarch_test::assert_skip_test_ok("test_cmpccxadd_epi64");
+        }
+        unsafe {
+            // 10 == 5 doesn't hold, so nothing is added.
+            let mut x = 10_i64;
+            assert_eq!(_cmpccxadd_epi64::<_CMPCCX_Z>(&mut x, 5, 3), 10);
+            assert_eq!(x, 10);
+            // 10 != 5 holds, so 3 is added.
+            assert_eq!(_cmpccxadd_epi64::<_CMPCCX_NZ>(&mut x, 5, 3), 10);
+            assert_eq!(x, 13);
+
+            // -1 is less than 1 when compared as signed integers, but not when
+            // compared as unsigned ones.
+            let mut x = -1_i64;
+            assert_eq!(_cmpccxadd_epi64::<_CMPCCX_B>(&mut x, 1, 1), -1);
+            assert_eq!(x, -1);
+            assert_eq!(_cmpccxadd_epi64::<_CMPCCX_L>(&mut x, 1, 1), -1);
+            assert_eq!(x, 0);
+
+            // Subtracting 1 from i64::MIN overflows.
+            let mut x = i64::MIN;
+            assert_eq!(_cmpccxadd_epi64::<_CMPCCX_NO>(&mut x, 1, 1), i64::MIN);
+            assert_eq!(x, i64::MIN);
+            assert_eq!(_cmpccxadd_epi64::<_CMPCCX_O>(&mut x, 1, 1), i64::MIN);
+            assert_eq!(x, i64::MIN + 1);
+        }
+    }
+}
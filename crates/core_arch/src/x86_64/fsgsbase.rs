@@ -0,0 +1,233 @@
+//! FS and GS segment base instructions (FSGSBASE)
+//!
+//! The operating system has to enable these instructions for user space,
+//! otherwise they raise `#UD` even if the processor supports them. Linux
+//! reports this with the `HWCAP2_FSGSBASE` bit of `AT_HWCAP2`.
+//!
+//! Writing the segment bases is extremely unsafe: operating systems and
+//! runtimes use them to locate thread-local storage, on x86_64 Linux and
+//! macOS this is the FS base, and on Windows the GS base. Changing such a
+//! base breaks every access to thread-locals until it is restored, including
+//! those done by the standard library, signal handlers and the allocator.
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Read the FS segment base register and store the 32-bit result in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_readfsbase_u32)
+#[inline]
+#[cfg_attr(test, assert_instr(rdfsbase))]
+pub unsafe fn _readfsbase_u32() -> u32 {
+    let r: u32;
+    asm!("rdfsbase {:e}", out(reg) r, options(nomem, nostack, preserves_flags));
+    r
+}
+
+/// Read the FS segment base register and store the 64-bit result in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_readfsbase_u64)
+#[inline]
+#[cfg_attr(test, assert_instr(rdfsbase))]
+pub unsafe fn _readfsbase_u64() -> u64 {
+    let r: u64;
+    asm!("rdfsbase {}", out(reg) r, options(nomem, nostack, preserves_flags));
+    r
+}
+
+/// Read the GS segment base register and store the 32-bit result in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_readgsbase_u32)
+#[inline]
+#[cfg_attr(test, assert_instr(rdgsbase))]
+pub unsafe fn _readgsbase_u32() -> u32 {
+    let r: u32;
+    asm!("rdgsbase {:e}", out(reg) r, options(nomem, nostack, preserves_flags));
+    r
+}
+
+/// Read the GS segment base register and store the 64-bit result in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_readgsbase_u64)
+#[inline]
+#[cfg_attr(test, assert_instr(rdgsbase))]
+pub unsafe fn _readgsbase_u64() -> u64 {
+    let r: u64;
+    asm!("rdgsbase {}", out(reg) r, options(nomem, nostack, preserves_flags));
+    r
+}
+
+/// Write the unsigned 32-bit integer `a` to the FS segment base register,
+/// zeroing its upper 32 bits.
+///
+/// See the [module documentation](self) for why this is likely to break
+/// thread-local storage.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_writefsbase_u32)
+#[inline]
+#[cfg_attr(test, assert_instr(wrfsbase))]
+pub unsafe fn _writefsbase_u32(a: u32) {
+    asm!("wrfsbase {:e}", in(reg) a, options(nostack, preserves_flags));
+}
+
+/// Write the unsigned 64-bit integer `a` to the FS segment base register.
+///
+/// See the [module documentation](self) for why this is likely to break
+/// thread-local storage.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_writefsbase_u64)
+#[inline]
+#[cfg_attr(test, assert_instr(wrfsbase))]
+pub unsafe fn _writefsbase_u64(a: u64) {
+    asm!("wrfsbase {}", in(reg) a, options(nostack, preserves_flags));
+}
+
+/// Write the unsigned 32-bit integer `a` to the GS segment base register,
+/// zeroing its upper 32 bits.
+///
+/// See the [module documentation](self) for why this is likely to break
+/// thread-local storage.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_writegsbase_u32)
+#[inline]
+#[cfg_attr(test, assert_instr(wrgsbase))]
+pub unsafe fn _writegsbase_u32(a: u32) {
+    asm!("wrgsbase {:e}", in(reg) a, options(nostack, preserves_flags));
+}
+
+/// Write the unsigned 64-bit integer `a` to the GS segment base register.
+///
+/// See the [module documentation](self) for why this is likely to break
+/// thread-local storage.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_writegsbase_u64)
+#[inline]
+#[cfg_attr(test, assert_instr(wrgsbase))]
+pub unsafe fn _writegsbase_u64(a: u64) {
+    asm!("wrgsbase {}", in(reg) a, options(nostack, preserves_flags));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86_64::*;
+    use std::convert::TryInto;
+
+    // Whether the operating system enabled the instructions for user space,
+    // which Linux reports in `AT_HWCAP2`.
+    fn os_enabled() -> bool {
+        if !cfg!(target_os = "linux") {
+            return false;
+        }
+        const AT_HWCAP2: u64 = 26;
+        const HWCAP2_FSGSBASE: u64 = 1 << 1;
+        let auxv = match std::fs::read("/proc/self/auxv") {
+            Ok(auxv) => auxv,
+            Err(_) => return false,
+        };
+        auxv.chunks_exact(16).any(|entry| {
+            let key = u64::from_ne_bytes(entry[..8].try_into().unwrap());
+            let value = u64::from_ne_bytes(entry[8..].try_into().unwrap());
+            key == AT_HWCAP2 && value & HWCAP2_FSGSBASE != 0
+        })
+    }
+
+    macro_rules! skip_if_disabled {
+        ($name:literal) => {
+            if !is_x86_feature_detected!("fsgsbase") {
+                return stdarch_test::assert_skip_test_ok($name);
+            }
+            if !os_enabled() {
+                println!("skipping: the OS didn't enable FSGSBASE");
+                return;
+            }
+        };
+    }
+
+    #[test]
+    fn test_readfsbase_u32() {
+        skip_if_disabled!("test_readfsbase_u32");
+        unsafe {
+            assert_eq!(_readfsbase_u32(), _readfsbase_u64() as u32);
+        }
+    }
+
+    #[test]
+    fn test_readfsbase_u64() {
+        skip_if_disabled!("test_readfsbase_u64");
+        unsafe {
+            let a = _readfsbase_u64();
+            assert_eq!(_readfsbase_u64(), a);
+        }
+    }
+
+    #[test]
+    fn test_readgsbase_u32() {
+        skip_if_disabled!("test_readgsbase_u32");
+        unsafe {
+            assert_eq!(_readgsbase_u32(), _readgsbase_u64() as u32);
+        }
+    }
+
+    #[test]
+    fn test_readgsbase_u64() {
+        skip_if_disabled!("test_readgsbase_u64");
+        unsafe {
+            let a = _readgsbase_u64();
+            assert_eq!(_readgsbase_u64(), a);
+        }
+    }
+
+    // The FS base is used for thread-local storage, so nothing but the
+    // intrinsics may run until it is restored.
+
+    #[test]
+    fn test_writefsbase_u32() {
+        skip_if_disabled!("test_writefsbase_u32");
+        unsafe {
+            let old = _readfsbase_u64();
+            _writefsbase_u32(old as u32);
+            let r = _readfsbase_u64();
+            _writefsbase_u64(old);
+            assert_eq!(r, old as u32 as u64);
+        }
+    }
+
+    #[test]
+    fn test_writefsbase_u64() {
+        skip_if_disabled!("test_writefsbase_u64");
+        unsafe {
+            let old = _readfsbase_u64();
+            _writefsbase_u64(old ^ 0x1000);
+            let r = _readfsbase_u64();
+            _writefsbase_u64(old);
+            assert_eq!(r, old ^ 0x1000);
+            assert_eq!(_readfsbase_u64(), old);
+        }
+    }
+
+    #[test]
+    fn test_writegsbase_u32() {
+        skip_if_disabled!("test_writegsbase_u32");
+        unsafe {
+            let old = _readgsbase_u64();
+            _writegsbase_u32(0x1234_5678);
+            let r = _readgsbase_u64();
+            _writegsbase_u64(old);
+            assert_eq!(r, 0x1234_5678);
+        }
+    }
+
+    #[test]
+    fn test_writegsbase_u64() {
+        skip_if_disabled!("test_writegsbase_u64");
+        unsafe {
+            let old = _readgsbase_u64();
+            // Writing a non-canonical address raises `#GP`.
+            _writegsbase_u64(0x0000_4567_89ab_c000);
+            let r = _readgsbase_u64();
+            _writegsbase_u64(old);
+            assert_eq!(r, 0x0000_4567_89ab_c000);
+            assert_eq!(_readgsbase_u64(), old);
+        }
+    }
+}
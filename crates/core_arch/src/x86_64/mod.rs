@@ -39,6 +39,9 @@ pub use self::avx2::*;
 mod avx512f;
 pub use self::avx512f::*;
 
+mod avx512bw;
+pub use self::avx512bw::*;
+
 mod bswap;
 pub use self::bswap::*;
 
@@ -53,3 +56,41 @@ pub use self::adx::*;
 
 mod bt;
 pub use self::bt::*;
+
+mod amx;
+pub use self::amx::*;
+
+mod movdiri;
+pub use self::movdiri::*;
+
+mod uintr;
+pub use self::uintr::*;
+
+mod ptwrite;
+pub use self::ptwrite::*;
+
+mod fsgsbase;
+pub use self::fsgsbase::*;
+
+mod cmpccxadd;
+pub use self::cmpccxadd::*;
+
+mod raoint;
+pub use self::raoint::*;
+
+mod sgx;
+pub use self::sgx::*;
+
+mod rotate;
+pub use self::rotate::*;
+
+mod usermsr;
+pub use self::usermsr::*;
+
+mod nontemporal;
+
+mod movbe;
+pub use self::movbe::*;
+
+mod shstk;
+pub use self::shstk::*;
@@ -0,0 +1,55 @@
+//! Move data after swapping bytes (MOVBE)
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Loads a big-endian 64-bit integer from `ptr`.
+///
+/// `ptr` does not need to be aligned on any particular boundary.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_loadbe_i64)
+#[inline]
+#[target_feature(enable = "movbe")]
+#[cfg_attr(test, assert_instr(movbe))]
+pub unsafe fn _loadbe_i64(ptr: *const u8) -> i64 {
+    (ptr as *const i64).read_unaligned().swap_bytes()
+}
+
+/// Stores `data` to `ptr` as a big-endian 64-bit integer.
+///
+/// `ptr` does not need to be aligned on any particular boundary.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_storebe_i64)
+#[inline]
+#[target_feature(enable = "movbe")]
+#[cfg_attr(test, assert_instr(movbe))]
+pub unsafe fn _storebe_i64(ptr: *mut u8, data: i64) {
+    (ptr as *mut i64).write_unaligned(data.swap_bytes());
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86_64::*;
+    use stdarch_test::simd_test;
+
+    #[simd_test(enable = "movbe")]
+    unsafe fn test_loadbe_i64() {
+        let a = [1u8, 2, 3, 4, 5, 6, 7, 8, 9];
+        assert_eq!(_loadbe_i64(a.as_ptr()), 0x0102030405060708);
+        assert_eq!(_loadbe_i64(a[1..].as_ptr()), 0x0203040506070809);
+        let x = 0x0123_4567_89ab_cdef_u64;
+        let r = _loadbe_i64(&x as *const u64 as *const u8);
+        assert_eq!(r as u64, x.swap_bytes());
+    }
+
+    #[simd_test(enable = "movbe")]
+    unsafe fn test_storebe_i64() {
+        let mut a = [0u8; 9];
+        _storebe_i64(a[1..].as_mut_ptr(), 0x0102030405060708);
+        assert_eq!(a, [0, 1, 2, 3, 4, 5, 6, 7, 8]);
+        let x = 0x0123_4567_89ab_cdef_u64;
+        let mut r = 0_u64;
+        _storebe_i64(&mut r as *mut u64 as *mut u8, x as i64);
+        assert_eq!(r, x.swap_bytes());
+    }
+}
@@ -0,0 +1,42 @@
+//! Direct store instructions (MOVDIRI)
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Direct store of `val` into `dst`.
+///
+/// Writes the 64-bit integer `val` to `dst` as a single write which bypasses
+/// the caches. If `dst` is naturally aligned the write is atomic, and if the
+/// destination is write-back memory it is evicted from the caches as part of
+/// the write. The store is write-combined like non-temporal stores, and is
+/// weakly ordered with respect to other stores.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_directstoreu_u64)
+#[inline]
+#[cfg_attr(test, assert_instr(movdiri))]
+pub unsafe fn _directstoreu_u64(dst: *mut u64, val: u64) {
+    asm!(
+        "movdiri qword ptr [{}], {}",
+        in(reg) dst,
+        in(reg) val,
+        options(nostack, preserves_flags),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::{x86::*, x86_64::*};
+
+    #[test]
+    fn test_directstoreu_u64() {
+        if !is_x86_feature_detected!("movdiri") {
+            return stdarch_test::assert_skip_test_ok("test_directstoreu_u64");
+        }
+        unsafe {
+            let mut mem = [0_u64; 4];
+            _directstoreu_u64(mem.as_mut_ptr().add(2), 0x0123_4567_89ab_cdef);
+            _mm_sfence();
+            assert_eq!(mem, [0, 0, 0x0123_4567_89ab_cdef, 0]);
+        }
+    }
+}
@@ -0,0 +1,37 @@
+//! `x86_64`-only stores of [`NonTemporalScope`].
+
+use crate::core_arch::{x86::*, x86_64::*};
+
+impl NonTemporalScope {
+    /// Stores `a` into `mem_addr` using a non-temporal memory hint, like
+    /// [`_mm_stream_si64`].
+    ///
+    /// # Safety
+    ///
+    /// `mem_addr` must be valid for writes of an `i64`.
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    pub unsafe fn stream_si64(&self, mem_addr: *mut i64, a: i64) {
+        _mm_stream_si64(mem_addr, a);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+    use stdarch_test::simd_test;
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_non_temporal_scope_si64() {
+        let mut buf = [0i64; 32];
+        {
+            let scope = NonTemporalScope::new();
+            for (i, x) in buf.iter_mut().enumerate() {
+                scope.stream_si64(x, i as i64 * -3);
+            }
+        }
+        for (i, x) in buf.iter().enumerate() {
+            assert_eq!(*x, i as i64 * -3);
+        }
+    }
+}
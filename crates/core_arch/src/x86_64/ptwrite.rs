@@ -0,0 +1,30 @@
+//! Processor Trace write instruction (PTWRITE)
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Insert the 64-bit data from `a` into a Processor Trace stream via a PTW
+/// packet. The PTW packet will be inserted if tracing is currently enabled and
+/// ptwrite is currently enabled. The current IP will also be inserted via a
+/// FUP packet if FUPonPTW is enabled.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_ptwrite64)
+#[inline]
+#[cfg_attr(test, assert_instr(ptwrite))]
+pub unsafe fn _ptwrite64(a: u64) {
+    asm!("ptwrite {}", in(reg) a, options(nostack, preserves_flags));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86_64::*;
+
+    // This can't be a `simd_test`, which enables the target feature.
+    #[test]
+    fn test_ptwrite64() {
+        if !is_x86_feature_detected!("ptwrite") {
+            return stdarch_test::assert_skip_test_ok("test_ptwrite64");
+        }
+        unsafe { _ptwrite64(0x0123_4567_89ab_cdef) };
+    }
+}
@@ -0,0 +1,168 @@
+//! Remote Atomic Operations (RAO-INT)
+//!
+//! See the [`x86` module](crate::core_arch::x86) for a description of the
+//! ordering guarantees of these operations, and why they are written in
+//! inline assembly.
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Atomically adds `v` to the 64-bit integer at `p`, without returning the
+/// previous value.
+///
+/// Like [`_aadd_i32`](crate::core_arch::x86::_aadd_i32), the operation is
+/// weakly ordered with respect to other stores.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_aadd_i64)
+#[inline]
+#[cfg_attr(test, assert_instr(aadd))]
+pub unsafe fn _aadd_i64(p: *mut i64, v: i64) {
+    asm!(
+        "aadd qword ptr [{}], {}",
+        in(reg) p,
+        in(reg) v,
+        options(nostack, preserves_flags),
+    );
+}
+
+/// Atomically computes the bitwise AND of the 64-bit integer at `p` and `v`
+/// and stores it at `p`, without returning the previous value.
+///
+/// Like [`_aand_i32`](crate::core_arch::x86::_aand_i32), the operation is
+/// weakly ordered with respect to other stores.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_aand_i64)
+#[inline]
+#[cfg_attr(test, assert_instr(aand))]
+pub unsafe fn _aand_i64(p: *mut i64, v: i64) {
+    asm!(
+        "aand qword ptr [{}], {}",
+        in(reg) p,
+        in(reg) v,
+        options(nostack, preserves_flags),
+    );
+}
+
+/// Atomically computes the bitwise OR of the 64-bit integer at `p` and `v`
+/// and stores it at `p`, without returning the previous value.
+///
+/// Like [`_aor_i32`](crate::core_arch::x86::_aor_i32), the operation is
+/// weakly ordered with respect to other stores.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_aor_i64)
+#[inline]
+#[cfg_attr(test, assert_instr(aor))]
+pub unsafe fn _aor_i64(p: *mut i64, v: i64) {
+    asm!(
+        "aor qword ptr [{}], {}",
+        in(reg) p,
+        in(reg) v,
+        options(nostack, preserves_flags),
+    );
+}
+
+/// Atomically computes the bitwise XOR of the 64-bit integer at `p` and `v`
+/// and stores it at `p`, without returning the previous value.
+///
+/// Like [`_axor_i32`](crate::core_arch::x86::_axor_i32), the operation is
+/// weakly ordered with respect to other stores.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_axor_i64)
+#[inline]
+#[cfg_attr(test, assert_instr(axor))]
+pub unsafe fn _axor_i64(p: *mut i64, v: i64) {
+    asm!(
+        "axor qword ptr [{}], {}",
+        in(reg) p,
+        in(reg) v,
+        options(nostack, preserves_flags),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::{x86::*, x86_64::*};
+    use std::{
+        sync::atomic::{AtomicI64, Ordering},
+        thread,
+        vec::Vec,
+    };
+
+    #[test]
+    fn test_aadd_i64() {
+        if !is_x86_feature_detected!("raoint") {
+            return stdarch_test::assert_skip_test_ok("test_aadd_i64");
+        }
+        unsafe {
+            let mut x = 1_i64 << 40;
+            _aadd_i64(&mut x, 2);
+            assert_eq!(x, (1 << 40) + 2);
+            _aadd_i64(&mut x, -(1 << 41));
+            assert_eq!(x, -(1 << 40) + 2);
+        }
+    }
+
+    #[test]
+    fn test_aadd_i64_threads() {
+        if !is_x86_feature_detected!("raoint") {
+            return stdarch_test::assert_skip_test_ok("test_aadd_i64_threads");
+        }
+        const THREADS: i64 = 8;
+        const ITERATIONS: i64 = 10_000;
+        static COUNTER: AtomicI64 = AtomicI64::new(0);
+
+        let handles = (0..THREADS)
+            .map(|_| {
+                thread::spawn(|| {
+                    let p = &COUNTER as *const AtomicI64 as *mut i64;
+                    for _ in 0..ITERATIONS {
+                        unsafe { _aadd_i64(p, 1 << 32) };
+                    }
+                    // Make the weakly-ordered updates visible before the
+                    // thread is joined.
+                    unsafe { _mm_sfence() };
+                })
+            })
+            .collect::<Vec<_>>();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert_eq!(COUNTER.load(Ordering::SeqCst), (THREADS * ITERATIONS) << 32);
+    }
+
+    #[test]
+    fn test_aand_i64() {
+        if !is_x86_feature_detected!("raoint") {
+            return stdarch_test::assert_skip_test_ok("test_aand_i64");
+        }
+        unsafe {
+            let mut x = -1_i64;
+            _aand_i64(&mut x, 0xffff_0000_0000);
+            assert_eq!(x, 0xffff_0000_0000);
+        }
+    }
+
+    #[test]
+    fn test_aor_i64() {
+        if !is_x86_feature_detected!("raoint") {
+            return stdarch_test::assert_skip_test_ok("test_aor_i64");
+        }
+        unsafe {
+            let mut x = 0xffff_0000_0000_i64;
+            _aor_i64(&mut x, 0xffff);
+            assert_eq!(x, 0xffff_0000_ffff);
+        }
+    }
+
+    #[test]
+    fn test_axor_i64() {
+        if !is_x86_feature_detected!("raoint") {
+            return stdarch_test::assert_skip_test_ok("test_axor_i64");
+        }
+        unsafe {
+            let mut x = 0xffff_0000_ffff_i64;
+            _axor_i64(&mut x, 0xff00_0000_ff00);
+            assert_eq!(x, 0x00ff_0000_00ff);
+        }
+    }
+}
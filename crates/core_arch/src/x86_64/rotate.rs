@@ -0,0 +1,55 @@
+//! Scalar rotate intrinsics.
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Rotates the bits of the 64-bit integer `a` left by `shift` bits.
+///
+/// This follows GCC and Clang, where `unsigned long` is 64 bits wide on
+/// x86_64. Only the low 6 bits of `shift` are used.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_lrotl)
+#[inline]
+#[cfg_attr(test, assert_instr(rol))]
+pub unsafe fn _lrotl(a: u64, shift: i32) -> u64 {
+    a.rotate_left(shift as u32)
+}
+
+/// Rotates the bits of the 64-bit integer `a` right by `shift` bits.
+///
+/// This follows GCC and Clang, where `unsigned long` is 64 bits wide on
+/// x86_64. Only the low 6 bits of `shift` are used.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_lrotr)
+#[inline]
+#[cfg_attr(test, assert_instr(ror))]
+pub unsafe fn _lrotr(a: u64, shift: i32) -> u64 {
+    a.rotate_right(shift as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86_64::*;
+
+    #[test]
+    fn test_lrotl() {
+        unsafe {
+            assert_eq!(_lrotl(1, 0), 1);
+            assert_eq!(_lrotl(1, 63), 1 << 63);
+            assert_eq!(_lrotl(1 << 63, 1), 1);
+            assert_eq!(_lrotl(0x0123_4567_89ab_cdef, 8), 0x2345_6789_abcd_ef01);
+            assert_eq!(_lrotl(0x0123_4567_89ab_cdef, 64), 0x0123_4567_89ab_cdef);
+        }
+    }
+
+    #[test]
+    fn test_lrotr() {
+        unsafe {
+            assert_eq!(_lrotr(1, 0), 1);
+            assert_eq!(_lrotr(1, 1), 1 << 63);
+            assert_eq!(_lrotr(1 << 63, 63), 1);
+            assert_eq!(_lrotr(0x0123_4567_89ab_cdef, 8), 0xef01_2345_6789_abcd);
+            assert_eq!(_lrotr(0x0123_4567_89ab_cdef, 64), 0x0123_4567_89ab_cdef);
+        }
+    }
+}
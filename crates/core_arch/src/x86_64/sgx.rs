@@ -0,0 +1,128 @@
+//! Software Guard Extensions (SGX)
+//!
+//! SGX exposes all of its functionality through three instructions, `ENCLS`
+//! (privileged), `ENCLU` (user mode) and `ENCLV` (virtualization), each of
+//! which selects a leaf function with `EAX` and passes further operands in
+//! `RBX`, `RCX` and `RDX`. The intrinsics in this module are thin wrappers
+//! that load these registers, execute the instruction and return the values
+//! of the registers afterwards as an [`SgxResult`]. Which of the registers
+//! are inputs and outputs, and what they mean, depends on the leaf.
+//!
+//! The reference is [Intel 64 and IA-32 Architectures Software Developer's
+//! Manual Volume 3D: System Programming Guide, Part 4][intel64_ref].
+//!
+//! [intel64_ref]: https://www.intel.com/content/www/us/en/developer/articles/technical/intel-sdm.html
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Values of the registers after executing an SGX leaf function.
+#[allow(clippy::missing_inline_in_public_items)]
+#[derive(Copy, Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct SgxResult {
+    /// EAX register, which holds the error code for most leaf functions.
+    pub eax: u32,
+    /// RBX register.
+    pub rbx: u64,
+    /// RCX register.
+    pub rcx: u64,
+    /// RDX register.
+    pub rdx: u64,
+}
+
+macro_rules! encl {
+    ($insn:literal, $leaf:expr, $rbx:expr, $rcx:expr, $rdx:expr) => {{
+        let eax;
+        let rbx;
+        let rcx;
+        let rdx;
+        // LLVM reserves `rbx` for its internal use, so it is swapped with a
+        // scratch register around the instruction.
+        asm!(
+            "xchg {0}, rbx",
+            $insn,
+            "xchg {0}, rbx",
+            inout(reg) $rbx => rbx,
+            inlateout("eax") $leaf => eax,
+            inlateout("rcx") $rcx => rcx,
+            inlateout("rdx") $rdx => rdx,
+            options(nostack),
+        );
+        SgxResult { eax, rbx, rcx, rdx }
+    }};
+}
+
+/// Executes the privileged SGX leaf function `leaf` (`ENCLS`) with the given
+/// values of `RBX`, `RCX` and `RDX`.
+///
+/// # Safety
+///
+/// `ENCLS` can only be executed in ring 0, and depending on the leaf it reads
+/// and writes memory through the addresses passed in the registers. The
+/// caller has to uphold the requirements of the leaf function as described
+/// in the Intel SDM.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_encls_u32)
+#[inline]
+#[cfg_attr(test, assert_instr(encls))]
+pub unsafe fn _encls_u32(leaf: u32, rbx: u64, rcx: u64, rdx: u64) -> SgxResult {
+    encl!("encls", leaf, rbx, rcx, rdx)
+}
+
+/// Executes the user mode SGX leaf function `leaf` (`ENCLU`) with the given
+/// values of `RBX`, `RCX` and `RDX`.
+///
+/// # Safety
+///
+/// Depending on the leaf `ENCLU` reads and writes memory through the
+/// addresses passed in the registers, and `EENTER`, `ERESUME` and `EEXIT`
+/// transfer control into or out of an enclave. The caller has to uphold the
+/// requirements of the leaf function as described in the Intel SDM.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_enclu_u32)
+#[inline]
+#[cfg_attr(test, assert_instr(enclu))]
+pub unsafe fn _enclu_u32(leaf: u32, rbx: u64, rcx: u64, rdx: u64) -> SgxResult {
+    encl!("enclu", leaf, rbx, rcx, rdx)
+}
+
+/// Executes the virtualization SGX leaf function `leaf` (`ENCLV`) with the
+/// given values of `RBX`, `RCX` and `RDX`.
+///
+/// # Safety
+///
+/// `ENCLV` can only be executed in VMX root mode, and depending on the leaf
+/// it reads and writes memory through the addresses passed in the registers.
+/// The caller has to uphold the requirements of the leaf function as
+/// described in the Intel SDM.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_enclv_u32)
+#[inline]
+#[cfg_attr(test, assert_instr(enclv))]
+pub unsafe fn _enclv_u32(leaf: u32, rbx: u64, rcx: u64, rdx: u64) -> SgxResult {
+    encl!("enclv", leaf, rbx, rcx, rdx)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86_64::*;
+
+    // None of the leaf functions can be executed without an enclave set up
+    // by the operating system, so these only check that the intrinsics can be
+    // used with the expected signatures.
+
+    #[test]
+    fn test_encls_u32() {
+        let _: unsafe fn(u32, u64, u64, u64) -> SgxResult = _encls_u32;
+    }
+
+    #[test]
+    fn test_enclu_u32() {
+        let _: unsafe fn(u32, u64, u64, u64) -> SgxResult = _enclu_u32;
+    }
+
+    #[test]
+    fn test_enclv_u32() {
+        let _: unsafe fn(u32, u64, u64, u64) -> SgxResult = _enclv_u32;
+    }
+}
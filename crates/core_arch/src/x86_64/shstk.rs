@@ -0,0 +1,117 @@
+//! Control-flow Enforcement Technology shadow stacks (CET_SS)
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Increments the shadow stack pointer by 8 times the unsigned 8-bit value
+/// in the lowest byte of `a`, popping that many 64-bit entries off the
+/// shadow stack.
+///
+/// # Safety
+///
+/// Shadow stacks must be enabled, and the popped entries must correspond to
+/// frames that are being removed from the regular stack as well.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_incsspq)
+#[inline]
+#[cfg_attr(test, assert_instr(incsspq))]
+pub unsafe fn _incsspq(a: i32) {
+    asm!(
+        "incsspq {}",
+        in(reg) a as u32 as u64,
+        options(nostack, preserves_flags),
+    );
+}
+
+/// Reads the shadow stack pointer.
+///
+/// Returns `0` if shadow stacks are not enabled.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_rdsspq_i64)
+#[inline]
+#[cfg_attr(test, assert_instr(rdsspq))]
+pub unsafe fn _rdsspq_i64() -> i64 {
+    // `RDSSP` leaves its operand unchanged if shadow stacks are disabled, so
+    // it is initialized with zero.
+    let ssp;
+    asm!(
+        "rdsspq {}",
+        inout(reg) 0_i64 => ssp,
+        options(nomem, nostack, preserves_flags),
+    );
+    ssp
+}
+
+/// Writes `val` to the 64-bit shadow stack entry at `p`.
+///
+/// # Safety
+///
+/// Shadow stacks and shadow stack writes must be enabled for the current
+/// privilege level, and `p` must point to shadow stack memory.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_wrssq)
+#[inline]
+#[cfg_attr(test, assert_instr(wrssq))]
+pub unsafe fn _wrssq(val: i64, p: *mut u8) {
+    asm!(
+        "wrssq qword ptr [{}], {}",
+        in(reg) p,
+        in(reg) val,
+        options(nostack, preserves_flags),
+    );
+}
+
+/// Writes `val` to the 64-bit user mode shadow stack entry at `p`.
+///
+/// # Safety
+///
+/// `WRUSS` can only be executed in ring 0, and `p` must point to user mode
+/// shadow stack memory.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_wrussq)
+#[inline]
+#[cfg_attr(test, assert_instr(wrussq))]
+pub unsafe fn _wrussq(val: i64, p: *mut u8) {
+    asm!(
+        "wrussq qword ptr [{}], {}",
+        in(reg) p,
+        in(reg) val,
+        options(nostack, preserves_flags),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::{x86::*, x86_64::*};
+
+    // `RDSSP` is a NOP on processors without shadow stacks, so this doesn't
+    // need them.
+    #[test]
+    fn test_rdsspq_i64() {
+        // The shadow stack pointer is `0` if the operating system didn't
+        // enable shadow stacks for this process, and 8-byte aligned
+        // otherwise.
+        let ssp = unsafe { _rdsspq_i64() };
+        assert_eq!(ssp % 8, 0);
+        assert_eq!(ssp as i32, unsafe { _rdsspd_i32() });
+    }
+
+    // The other intrinsics need shadow stacks to be enabled, or can only be
+    // executed by the kernel, so these only check that they can be used with
+    // the expected signatures.
+
+    #[test]
+    fn test_incsspq() {
+        let _: unsafe fn(i32) = _incsspq;
+    }
+
+    #[test]
+    fn test_wrssq() {
+        let _: unsafe fn(i64, *mut u8) = _wrssq;
+    }
+
+    #[test]
+    fn test_wrussq() {
+        let _: unsafe fn(i64, *mut u8) = _wrussq;
+    }
+}
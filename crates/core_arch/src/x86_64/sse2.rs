@@ -66,6 +66,8 @@ pub unsafe fn _mm_cvttsd_si64x(a: __m128d) -> i64 {
 /// To minimize caching, the data is flagged as non-temporal (unlikely to be
 /// used again soon).
 ///
+/// See [`NonTemporalScope`] for ordering the store with other memory accesses.
+///
 /// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_stream_si64)
 #[inline]
 #[target_feature(enable = "sse2")]
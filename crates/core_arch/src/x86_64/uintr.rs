@@ -0,0 +1,97 @@
+//! User interrupts (UINTR)
+//!
+//! User interrupts are delivered directly to a user space thread, without
+//! going through the kernel, and can be sent by another user space thread
+//! with [`_senduipi`]. Both threads have to be set up by the operating system
+//! first. With the Linux user interrupt patches this works roughly like the
+//! following, where the `uintr_*` functions are wrappers around the syscalls
+//! of the same name:
+//!
+//! ```ignore
+//! // In the receiving thread: register the handler, which has to return
+//! // with `UIRET`, and create a file descriptor for one of its vectors.
+//! uintr_register_handler(handler, 0);
+//! let fd = uintr_vector_fd(VECTOR, 0);
+//! _stui();
+//!
+//! // In the sending thread: register as a sender for `fd`, which returns
+//! // the index of the receiver in the sender's target table.
+//! let index = uintr_register_sender(fd, 0);
+//! _senduipi(index as u64);
+//! ```
+//!
+//! Without this setup all of the intrinsics in this module raise `#UD`.
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Clears the user interrupt flag (UIF), which blocks the delivery of user
+/// interrupts to the current thread until [`_stui`] is called.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_clui)
+#[inline]
+#[cfg_attr(test, assert_instr(clui))]
+pub unsafe fn _clui() {
+    asm!("clui", options(nostack, preserves_flags));
+}
+
+/// Sets the user interrupt flag (UIF), which allows user interrupts to be
+/// delivered to the current thread.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_stui)
+#[inline]
+#[cfg_attr(test, assert_instr(stui))]
+pub unsafe fn _stui() {
+    asm!("stui", options(nostack, preserves_flags));
+}
+
+/// Returns the current value of the user interrupt flag (UIF).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_testui)
+#[inline]
+#[cfg_attr(test, assert_instr(testui))]
+pub unsafe fn _testui() -> u8 {
+    // The flag is returned in CF.
+    let uif: u8;
+    asm!("testui", "setc {}", out(reg_byte) uif, options(nomem, nostack));
+    uif
+}
+
+/// Sends a user interprocessor interrupt to the receiver with index `a` in
+/// the user interrupt target table of the current thread.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_senduipi)
+#[inline]
+#[cfg_attr(test, assert_instr(senduipi))]
+pub unsafe fn _senduipi(a: u64) {
+    asm!("senduipi {}", in(reg) a, options(nostack, preserves_flags));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86_64::*;
+
+    // User interrupts have to be enabled by the operating system for each
+    // thread, so these only check that the intrinsics can be used with the
+    // expected signatures.
+
+    #[test]
+    fn test_clui() {
+        let _: unsafe fn() = _clui;
+    }
+
+    #[test]
+    fn test_stui() {
+        let _: unsafe fn() = _stui;
+    }
+
+    #[test]
+    fn test_testui() {
+        let _: unsafe fn() -> u8 = _testui;
+    }
+
+    #[test]
+    fn test_senduipi() {
+        let _: unsafe fn(u64) = _senduipi;
+    }
+}
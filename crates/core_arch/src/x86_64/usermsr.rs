@@ -0,0 +1,72 @@
+//! User mode model specific register access (USER_MSR)
+//!
+//! These instructions read and write model specific registers from user
+//! space. Only the MSRs that the operating system put on the allow list of
+//! the processor can be accessed, all other MSRs raise a general protection
+//! fault (`#GP`), which is usually delivered to the process as `SIGSEGV`.
+//!
+//! The intrinsics always use the encoding that takes the MSR index in a
+//! register, not the one with an immediate index.
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Reads the model specific register `msr` from user space.
+///
+/// # Safety
+///
+/// `msr` has to be on the allow list set up by the operating system,
+/// otherwise a general protection fault is raised.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_urdmsr)
+#[inline]
+#[cfg_attr(test, assert_instr(urdmsr))]
+pub unsafe fn _urdmsr(msr: u64) -> u64 {
+    let value;
+    asm!(
+        "urdmsr {}, {}",
+        lateout(reg) value,
+        in(reg) msr,
+        options(nomem, nostack, preserves_flags),
+    );
+    value
+}
+
+/// Writes `value` to the model specific register `msr` from user space.
+///
+/// # Safety
+///
+/// `msr` has to be on the allow list set up by the operating system,
+/// otherwise a general protection fault is raised. Writing an MSR can change
+/// the behavior of the processor for the whole thread.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_uwrmsr)
+#[inline]
+#[cfg_attr(test, assert_instr(uwrmsr))]
+pub unsafe fn _uwrmsr(msr: u64, value: u64) {
+    asm!(
+        "uwrmsr {}, {}",
+        in(reg) msr,
+        in(reg) value,
+        options(nostack, preserves_flags),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86_64::*;
+
+    // Which MSRs can be accessed depends on the operating system, so these
+    // only check that the intrinsics can be used with the expected
+    // signatures.
+
+    #[test]
+    fn test_urdmsr() {
+        let _: unsafe fn(u64) -> u64 = _urdmsr;
+    }
+
+    #[test]
+    fn test_uwrmsr() {
+        let _: unsafe fn(u64, u64) = _uwrmsr;
+    }
+}
@@ -81,6 +81,7 @@ pub fn simd_test(
             force_test = true;
             "is_mips64_feature_detected"
         }
+        t if t.starts_with("riscv") => "is_riscv_feature_detected",
         t => panic!("unknown target: {}", t),
     };
     let macro_test = Ident::new(macro_test, Span::call_site());
@@ -7,6 +7,7 @@ features! {
     /// Checks if `aarch64` feature is enabled.
     #[unstable(feature = "stdsimd", issue = "27731")]
     @BIND_FEATURE_NAME: "asimd"; "neon";
+    @BIND_FEATURE_NAME: "rng"; "rand";
     @NO_RUNTIME_DETECTION: "ras";
     @NO_RUNTIME_DETECTION: "v8.1a";
     @NO_RUNTIME_DETECTION: "v8.2a";
@@ -21,6 +22,16 @@ features! {
     /// Half-float support.
     @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] sve: "sve";
     /// Scalable Vector Extension (SVE)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] sve2: "sve2";
+    /// Scalable Vector Extension 2 (SVE2)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] sve2_aes: "sve2-aes";
+    /// SVE2 AES and 128-bit polynomial multiply instructions
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] sve2_sm4: "sve2-sm4";
+    /// SVE2 SM4 instructions
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] sve2_sha3: "sve2-sha3";
+    /// SVE2 SHA3 instructions
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] sve2_bitperm: "sve2-bitperm";
+    /// SVE2 bit permute instructions
     @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] crc: "crc";
     /// CRC32 (Cyclic Redundancy Check)
     @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] crypto: "crypto";
@@ -29,10 +40,56 @@ features! {
     /// Atomics (Large System Extension)
     @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] rdm: "rdm";
     /// Rounding Double Multiply (ASIMDRDM)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] sha3: "sha3";
+    /// SHA3 (EOR3, RAX1, XAR, BCAX)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] sm4: "sm4";
+    /// SM3 + SM4
     @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] rcpc: "rcpc";
     /// Release consistent Processor consistent (RcPc)
     @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] dotprod: "dotprod";
     /// Vector Dot-Product (ASIMDDP)
     @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] tme: "tme";
     /// Transactional Memory Extensions (TME)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] fcma: "fcma";
+    /// Floating point complex number support (FCMA)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] i8mm: "i8mm";
+    /// Int8 matrix multiplication (I8MM)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] bf16: "bf16";
+    /// BFloat16 (BF16)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] rand: "rand";
+    /// Random number generation (RNG)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] mte: "mte";
+    /// Memory Tagging Extension (MTE)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] ls64: "ls64";
+    /// 64-byte loads and stores (LD64B, ST64B, ST64BV, ST64BV0)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] paca: "paca";
+    /// Pointer authentication, address authentication (PACA)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] pacg: "pacg";
+    /// Pointer authentication, generic authentication (PACG)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] frintts: "frintts";
+    /// Floating-point round to 32-bit and 64-bit integers (FRINTTS)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] lut: "lut";
+    /// Lookup table instructions (LUTI2, LUTI4)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] faminmax: "faminmax";
+    /// Floating-point absolute minimum and maximum (FAMINMAX)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] fp8: "fp8";
+    /// 8-bit floating-point conversions (FP8)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] fp8fma: "fp8fma";
+    /// 8-bit floating-point widening multiply-adds (FP8FMA)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] fp8dot4: "fp8dot4";
+    /// 8-bit floating-point four-way dot products (FP8DOT4)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] fp8dot2: "fp8dot2";
+    /// 8-bit floating-point two-way dot products (FP8DOT2)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] sme: "sme";
+    /// Scalable Matrix Extension (SME)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] sme2: "sme2";
+    /// Scalable Matrix Extension 2 (SME2)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] sme_f64f64: "sme-f64f64";
+    /// SME double-precision outer products
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] sme_i16i64: "sme-i16i64";
+    /// SME 16-bit integer outer products with 64-bit accumulation
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] sme_fa64: "sme-fa64";
+    /// The full A64 instruction set in streaming mode
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] wfxt: "wfxt";
+    /// Wait for event and interrupt with timeout (WFET, WFIT)
 }
@@ -54,6 +54,12 @@ features! {
     /// * `"sse4.2"`
     /// * `"sse4a"`
     /// * `"sha"`
+    /// * `"sha512"`
+    /// * `"sm3"`
+    /// * `"sm4"`
+    /// * `"gfni"`
+    /// * `"vaes"`
+    /// * `"vpclmulqdq"`
     /// * `"avx"`
     /// * `"avx2"`
     /// * `"avx512f"`
@@ -74,6 +80,14 @@ features! {
     /// * `"avx512bitalg"`
     /// * `"avx512bf16"`
     /// * `"avx512vp2intersect"`
+    /// * `"avx512fp16"`
+    /// * `"avxvnni"`
+    /// * `"avxifma"`
+    /// * `"avxvnniint8"`
+    /// * `"avxvnniint16"`
+    /// * `"avxneconvert"`
+    /// * `"amx-tile"`
+    /// * `"amx-int8"`
     /// * `"f16c"`
     /// * `"fma"`
     /// * `"bmi1"`
@@ -90,6 +104,38 @@ features! {
     /// * `"cmpxchg16b"`
     /// * `"adx"`
     /// * `"rtm"`
+    /// * `"movdiri"`
+    /// * `"movdir64b"`
+    /// * `"enqcmd"`
+    /// * `"waitpkg"`
+    /// * `"serialize"`
+    /// * `"tsxldtrk"`
+    /// * `"uintr"`
+    /// * `"cldemote"`
+    /// * `"ptwrite"`
+    /// * `"rdpid"`
+    /// * `"mwaitx"`
+    /// * `"clzero"`
+    /// * `"fsgsbase"`
+    /// * `"invpcid"`
+    /// * `"kl"`
+    /// * `"widekl"`
+    /// * `"hreset"`
+    /// * `"prefetchi"`
+    /// * `"cmpccxadd"`
+    /// * `"raoint"`
+    /// * `"clflushopt"`
+    /// * `"clwb"`
+    /// * `"sgx"`
+    /// * `"usermsr"`
+    /// * `"avx10.1"`
+    /// * `"avx10.1-256"`
+    /// * `"avx10.1-512"`
+    /// * `"prfchw"`
+    /// * `"wbnoinvd"`
+    /// * `"pconfig"`
+    /// * `"movbe"`
+    /// * `"shstk"`
     ///
     /// [docs]: https://software.intel.com/sites/landingpage/IntrinsicsGuide
     #[stable(feature = "simd_x86", since = "1.27.0")]
@@ -122,6 +168,18 @@ features! {
     /// SSE4a (Streaming SIMD Extensions 4a)
     @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] sha: "sha";
     /// SHA
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] sha512: "sha512";
+    /// SHA512
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] sm3: "sm3";
+    /// SM3 (ShangMi 3 hash function)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] sm4: "sm4";
+    /// SM4 (ShangMi 4 block cipher)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] gfni: "gfni";
+    /// GFNI (Galois Field New Instructions)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] vaes: "vaes";
+    /// VAES (Vectorized AES Instructions)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] vpclmulqdq: "vpclmulqdq";
+    /// VPCLMULQDQ (Vectorized Carry-less Multiplication)
     @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] avx: "avx";
     /// AVX (Advanced Vector Extensions)
     @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] avx2: "avx2";
@@ -163,6 +221,22 @@ features! {
     /// AVX-512 BF16 (BFLOAT16 instructions)
     @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] avx512vp2intersect: "avx512vp2intersect";
     /// AVX-512 P2INTERSECT
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] avx512fp16: "avx512fp16";
+    /// AVX-512 FP16 (IEEE 754 half-precision floating point instructions)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] avxvnni: "avxvnni";
+    /// AVX-VNNI (VEX encoded Vector Neural Network Instructions)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] avxifma: "avxifma";
+    /// AVX-IFMA (VEX encoded Integer Fused Multiply-Add)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] avxvnniint8: "avxvnniint8";
+    /// AVX-VNNI-INT8 (8-bit integer dot products of any signedness)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] avxvnniint16: "avxvnniint16";
+    /// AVX-VNNI-INT16 (16-bit integer dot products of any signedness)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] avxneconvert: "avxneconvert";
+    /// AVX-NE-CONVERT (BF16 and FP16 conversions to and from single-precision)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] amx_tile: "amx-tile";
+    /// AMX-TILE (Advanced Matrix Extensions tile architecture)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] amx_int8: "amx-int8";
+    /// AMX-INT8 (Advanced Matrix Extensions 8-bit integer dot products)
     @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] f16c: "f16c";
     /// F16C (Conversions between IEEE-754 `binary16` and `binary32` formats)
     @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] fma: "fma";
@@ -193,4 +267,68 @@ features! {
     /// ADX, Intel ADX (Multi-Precision Add-Carry Instruction Extensions)
     @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] rtm: "rtm";
     /// RTM, Intel (Restricted Transactional Memory)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] movdiri: "movdiri";
+    /// MOVDIRI (Move Doubleword as Direct Store)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] movdir64b: "movdir64b";
+    /// MOVDIR64B (Move 64 Bytes as Direct Store)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] enqcmd: "enqcmd";
+    /// ENQCMD (Enqueue Stores)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] waitpkg: "waitpkg";
+    /// WAITPKG (User Wait Instructions)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] serialize: "serialize";
+    /// SERIALIZE (Serialize Instruction Execution)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] tsxldtrk: "tsxldtrk";
+    /// TSXLDTRK (TSX Suspend Load Address Tracking)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] uintr: "uintr";
+    /// UINTR (User Interrupts)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] cldemote: "cldemote";
+    /// CLDEMOTE (Cache Line Demote)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] ptwrite: "ptwrite";
+    /// PTWRITE (Write Data to a Processor Trace Packet)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] rdpid: "rdpid";
+    /// RDPID (Read Processor ID)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] mwaitx: "mwaitx";
+    /// MWAITX (AMD Monitor and Wait with Timeout)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] clzero: "clzero";
+    /// CLZERO (AMD Zero Cache Line)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] fsgsbase: "fsgsbase";
+    /// FSGSBASE (Read and Write FS/GS Segment Base Registers)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] invpcid: "invpcid";
+    /// INVPCID (Invalidate Process-Context Identifier)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] kl: "kl";
+    /// KL (AES Key Locker)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] widekl: "widekl";
+    /// WIDEKL (AES Key Locker Wide Instructions)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] hreset: "hreset";
+    /// HRESET (History Reset)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] prefetchi: "prefetchi";
+    /// PREFETCHI (Instruction Prefetch Hints)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] cmpccxadd: "cmpccxadd";
+    /// CMPCCXADD (Compare and Add if Condition is Met)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] raoint: "raoint";
+    /// RAO-INT (Remote Atomic Operations)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] clflushopt: "clflushopt";
+    /// CLFLUSHOPT (Optimized Cache Line Flush)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] clwb: "clwb";
+    /// CLWB (Cache Line Write Back)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] sgx: "sgx";
+    /// SGX (Software Guard Extensions)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] usermsr: "usermsr";
+    /// USER_MSR (User Mode MSR Access)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] avx10_1: "avx10.1";
+    /// AVX10.1 (Converged Vector ISA version 1)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] avx10_1_256: "avx10.1-256";
+    /// AVX10.1 with support for 256-bit vectors
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] avx10_1_512: "avx10.1-512";
+    /// AVX10.1 with support for 512-bit vectors
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] prfchw: "prfchw";
+    /// PRFCHW (Prefetch for Write)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] wbnoinvd: "wbnoinvd";
+    /// WBNOINVD (Write Back and Do Not Invalidate Cache)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] pconfig: "pconfig";
+    /// PCONFIG (Platform Configuration)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] movbe: "movbe";
+    /// MOVBE (Move Data After Swapping Bytes)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] shstk: "shstk";
+    /// CET_SS (Control-flow Enforcement Technology Shadow Stacks)
 }
@@ -4,6 +4,9 @@
 #[allow(dead_code)]
 #[inline]
 pub(crate) fn test(x: usize, bit: u32) -> bool {
-    debug_assert!(bit < 32, "bit index out-of-bounds");
+    debug_assert!(
+        bit < (core::mem::size_of::<usize>() * 8) as u32,
+        "bit index out-of-bounds"
+    );
     x & (1 << bit) != 0
 }
@@ -9,28 +9,28 @@ use core::sync::atomic::AtomicUsize;
 
 /// Sets the `bit` of `x`.
 #[inline]
-const fn set_bit(x: u64, bit: u32) -> u64 {
+const fn set_bit(x: u128, bit: u32) -> u128 {
     x | 1 << bit
 }
 
 /// Tests the `bit` of `x`.
 #[inline]
-const fn test_bit(x: u64, bit: u32) -> bool {
+const fn test_bit(x: u128, bit: u32) -> bool {
     x & (1 << bit) != 0
 }
 
 /// Unset the `bit of `x`.
 #[inline]
-const fn unset_bit(x: u64, bit: u32) -> u64 {
+const fn unset_bit(x: u128, bit: u32) -> u128 {
     x & !(1 << bit)
 }
 
 /// Maximum number of features that can be cached.
-const CACHE_CAPACITY: u32 = 62;
+const CACHE_CAPACITY: u32 = 124;
 
 /// This type is used to initialize the cache
 #[derive(Copy, Clone)]
-pub(crate) struct Initializer(u64);
+pub(crate) struct Initializer(u128);
 
 #[allow(clippy::use_self)]
 impl Default for Initializer {
@@ -76,8 +76,14 @@ impl Initializer {
 }
 
 /// This global variable is a cache of the features supported by the CPU.
-// Note: on x64, we only use the first slot
-static CACHE: [Cache; 2] = [Cache::uninitialized(), Cache::uninitialized()];
+// Note: on x64, we only use the first two slots, and the last slot is only
+// used on targets with 32-bit pointers.
+static CACHE: [Cache; 4] = [
+    Cache::uninitialized(),
+    Cache::uninitialized(),
+    Cache::uninitialized(),
+    Cache::uninitialized(),
+];
 
 /// Feature cache with capacity for `size_of::<usize::MAX>() * 8 - 1` features.
 ///
@@ -107,7 +113,7 @@ impl Cache {
         if cached == 0 {
             None
         } else {
-            Some(test_bit(cached as u64, bit))
+            Some(test_bit(cached as u128, bit))
         }
     }
 
@@ -153,6 +159,11 @@ cfg_if::cfg_if! {
 fn do_initialize(value: Initializer) {
     CACHE[0].initialize((value.0) as usize & Cache::MASK);
     CACHE[1].initialize((value.0 >> Cache::CAPACITY) as usize & Cache::MASK);
+    CACHE[2].initialize((value.0 >> (2 * Cache::CAPACITY)) as usize & Cache::MASK);
+    // With 64-bit pointers all features fit in the first slots, and shifting
+    // by `3 * Cache::CAPACITY` would overflow.
+    let last = value.0.checked_shr(3 * Cache::CAPACITY).unwrap_or(0);
+    CACHE[3].initialize(last as usize & Cache::MASK);
 }
 
 // We only have to detect features once, and it's fairly costly, so hint to LLVM
@@ -173,7 +184,7 @@ fn detect_and_initialize() -> Initializer {
 /// initializes it with the result of `os::detect_features()`.
 ///
 /// On its first invocation, it detects the CPU features and caches them in the
-/// `CACHE` global variable as an `AtomicU64`.
+/// `CACHE` global variable as an array of `AtomicUsize`.
 ///
 /// It uses the `Feature` variant to index into this variable as a bitset. If
 /// the bit is set, the feature is enabled, and otherwise it is disabled.
@@ -185,8 +196,12 @@ fn detect_and_initialize() -> Initializer {
 pub(crate) fn test(bit: u32) -> bool {
     let (relative_bit, idx) = if bit < Cache::CAPACITY {
         (bit, 0)
-    } else {
+    } else if bit < 2 * Cache::CAPACITY {
         (bit - Cache::CAPACITY, 1)
+    } else if bit < 3 * Cache::CAPACITY {
+        (bit - 2 * Cache::CAPACITY, 2)
+    } else {
+        (bit - 3 * Cache::CAPACITY, 3)
     };
     CACHE[idx]
         .test(relative_bit)
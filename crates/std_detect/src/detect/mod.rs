@@ -54,6 +54,10 @@ cfg_if! {
         #[path = "arch/mips64.rs"]
         #[macro_use]
         mod arch;
+    } else if #[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))] {
+        #[path = "arch/riscv.rs"]
+        #[macro_use]
+        mod arch;
     } else {
         // Unimplemented architecture:
         #[allow(dead_code)]
@@ -110,6 +114,9 @@ cfg_if! {
     } else if #[cfg(all(target_os = "windows", target_arch = "aarch64"))] {
         #[path = "os/windows/aarch64.rs"]
         mod os;
+    } else if #[cfg(all(target_os = "macos", target_arch = "aarch64", feature = "libc"))] {
+        #[path = "os/macos/aarch64.rs"]
+        mod os;
     } else {
         #[path = "os/other.rs"]
         mod os;
@@ -138,6 +145,8 @@ pub fn features() -> impl Iterator<Item = (&'static str, bool)> {
             target_arch = "powerpc64",
             target_arch = "mips",
             target_arch = "mips64",
+            target_arch = "riscv32",
+            target_arch = "riscv64",
         ))] {
             (0_u8..Feature::_last as u8).map(|discriminant: u8| {
                 let f: Feature = unsafe { core::mem::transmute(discriminant) };
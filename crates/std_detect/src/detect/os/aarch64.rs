@@ -51,6 +51,7 @@ pub(crate) fn detect_features() -> cache::Initializer {
         enable_feature(Feature::tme, bits_shift(aa64isar0, 27, 24) == 1);
         enable_feature(Feature::lse, bits_shift(aa64isar0, 23, 20) >= 1);
         enable_feature(Feature::crc, bits_shift(aa64isar0, 19, 16) >= 1);
+        enable_feature(Feature::rand, bits_shift(aa64isar0, 63, 60) >= 1);
 
         // ID_AA64PFR0_EL1 - Processor Feature Register 0
         let aa64pfr0: u64;
@@ -77,6 +78,10 @@ pub(crate) fn detect_features() -> cache::Initializer {
             Feature::dotprod,
             asimd && bits_shift(aa64isar0, 47, 44) >= 1,
         );
+        enable_feature(Feature::sha3, asimd && bits_shift(aa64isar0, 35, 32) >= 1);
+        let sm3 = bits_shift(aa64isar0, 39, 36) >= 1;
+        let sm4 = bits_shift(aa64isar0, 43, 40) >= 1;
+        enable_feature(Feature::sm4, asimd && sm3 && sm4);
         enable_feature(Feature::sve, asimd && bits_shift(aa64pfr0, 35, 32) >= 1);
 
         // ID_AA64ISAR1_EL1 - Instruction Set Attribute Register 1
@@ -90,6 +95,125 @@ pub(crate) fn detect_features() -> cache::Initializer {
         }
 
         enable_feature(Feature::rcpc, bits_shift(aa64isar1, 23, 20) >= 1);
+        enable_feature(Feature::fcma, asimd && bits_shift(aa64isar1, 19, 16) >= 1);
+        enable_feature(
+            Feature::frintts,
+            asimd && bits_shift(aa64isar1, 35, 32) >= 1,
+        );
+        enable_feature(Feature::i8mm, asimd && bits_shift(aa64isar1, 55, 52) >= 1);
+        let bf16 = asimd && bits_shift(aa64isar1, 47, 44) >= 1;
+        enable_feature(Feature::bf16, bf16);
+        // ST64BV needs LS64 >= 2 and ST64BV0 LS64 >= 3.
+        enable_feature(Feature::ls64, bits_shift(aa64isar1, 63, 60) >= 3);
+        // Pointer authentication is implemented either with the QARMA
+        // algorithm (APA, GPA) or with an implementation defined one (API, GPI).
+        let apa = bits_shift(aa64isar1, 7, 4) >= 1;
+        let api = bits_shift(aa64isar1, 11, 8) >= 1;
+        let gpa = bits_shift(aa64isar1, 27, 24) >= 1;
+        let gpi = bits_shift(aa64isar1, 31, 28) >= 1;
+        enable_feature(Feature::paca, apa || api);
+        enable_feature(Feature::pacg, gpa || gpi);
+
+        // ID_AA64ISAR2_EL1 - Instruction Set Attribute Register 2, read by
+        // its encoding since older assemblers don't know its name
+        let aa64isar2: u64;
+        unsafe {
+            asm!(
+                "mrs {}, S3_0_C0_C6_2",
+                out(reg) aa64isar2,
+                options(pure, nomem, preserves_flags, nostack)
+            );
+        }
+
+        let lut = asimd && bits_shift(aa64isar2, 59, 56) >= 1;
+        enable_feature(Feature::lut, lut);
+        // WFxT = 1 is reserved, 2 provides WFET and WFIT.
+        enable_feature(Feature::wfxt, bits_shift(aa64isar2, 3, 0) >= 2);
+
+        // ID_AA64ISAR3_EL1 - Instruction Set Attribute Register 3
+        let aa64isar3: u64;
+        unsafe {
+            asm!(
+                "mrs {}, S3_0_C0_C6_3",
+                out(reg) aa64isar3,
+                options(pure, nomem, preserves_flags, nostack)
+            );
+        }
+
+        let faminmax = asimd && bits_shift(aa64isar3, 7, 4) >= 1;
+        enable_feature(Feature::faminmax, faminmax);
+
+        // ID_AA64PFR1_EL1 - Processor Feature Register 1
+        let aa64pfr1: u64;
+        unsafe {
+            asm!(
+                "mrs {}, ID_AA64PFR1_EL1",
+                out(reg) aa64pfr1,
+                options(pure, nomem, preserves_flags, nostack)
+            );
+        }
+
+        // MTE = 1 only provides the instructions, not the tag storage.
+        enable_feature(Feature::mte, bits_shift(aa64pfr1, 11, 8) >= 2);
+        // SME requires BF16, but not SVE. SME2 is SME = 2.
+        let sme = bf16 && bits_shift(aa64pfr1, 27, 24) >= 1;
+        enable_feature(Feature::sme, sme);
+        enable_feature(Feature::sme2, sme && bits_shift(aa64pfr1, 27, 24) >= 2);
+
+        if sme {
+            // ID_AA64SMFR0_EL1 - SME Feature ID Register 0, read by its
+            // encoding like ID_AA64ISAR2_EL1
+            let aa64smfr0: u64;
+            unsafe {
+                asm!(
+                    "mrs {}, S3_0_C0_C4_5",
+                    out(reg) aa64smfr0,
+                    options(pure, nomem, preserves_flags, nostack)
+                );
+            }
+
+            enable_feature(Feature::sme_f64f64, bits_shift(aa64smfr0, 48, 48) == 1);
+            enable_feature(Feature::sme_i16i64, bits_shift(aa64smfr0, 55, 52) == 0xf);
+            // FA64 also requires SVE2, which isn't detected here, so
+            // `sme-fa64` isn't either.
+        }
+
+        // ID_AA64PFR2_EL1 - Processor Feature Register 2, read by its
+        // encoding like ID_AA64ISAR2_EL1
+        let aa64pfr2: u64;
+        unsafe {
+            asm!(
+                "mrs {}, S3_0_C0_C4_2",
+                out(reg) aa64pfr2,
+                options(pure, nomem, preserves_flags, nostack)
+            );
+        }
+
+        // FP8 requires FPMR, FAMINMAX, LUT and BF16.
+        if bits_shift(aa64pfr2, 35, 32) >= 1 && faminmax && lut && bf16 {
+            // ID_AA64FPFR0_EL1 - Floating-point Feature ID Register 0, read by
+            // its encoding like ID_AA64ISAR2_EL1
+            let aa64fpfr0: u64;
+            unsafe {
+                asm!(
+                    "mrs {}, S3_0_C0_C4_7",
+                    out(reg) aa64fpfr0,
+                    options(pure, nomem, preserves_flags, nostack)
+                );
+            }
+
+            // Each of the FP8 extensions requires the previous one.
+            let fp8 = bits_shift(aa64fpfr0, 31, 31) == 1;
+            let fp8fma = fp8 && bits_shift(aa64fpfr0, 30, 30) == 1;
+            let fp8dot4 = fp8fma && bits_shift(aa64fpfr0, 29, 29) == 1;
+            enable_feature(Feature::fp8, fp8);
+            enable_feature(Feature::fp8fma, fp8fma);
+            enable_feature(Feature::fp8dot4, fp8dot4);
+            enable_feature(
+                Feature::fp8dot2,
+                fp8dot4 && bits_shift(aa64fpfr0, 28, 28) == 1,
+            );
+        }
     }
 
     value
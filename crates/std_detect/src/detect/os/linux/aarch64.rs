@@ -36,15 +36,81 @@ struct AtHwcap {
     // cpuid: bool, // 11
     asimdrdm: bool, // 12
     // jscvt: bool, // 13
-    // fcma: bool, // 14
+    fcma: bool,  // 14
     lrcpc: bool, // 15
     // dcpop: bool, // 16
-    // sha3: bool, // 17
-    // sm3: bool, // 18
-    // sm4: bool, // 19
+    sha3: bool,    // 17
+    sm3: bool,     // 18
+    sm4: bool,     // 19
     asimddp: bool, // 20
     // sha512: bool, // 21
     sve: bool, // 22
+    // asimdfhm: bool, // 23
+    // dit: bool, // 24
+    // uscat: bool, // 25
+    // ilrcpc: bool, // 26
+    // flagm: bool, // 27
+    // ssbs: bool, // 28
+    // sb: bool, // 29
+    paca: bool, // 30
+    pacg: bool, // 31
+
+    // hwcap2
+    // dcpodp: bool, // 0
+    sve2: bool,   // 1
+    sveaes: bool, // 2
+    // svepmull: bool, // 3
+    svebitperm: bool, // 4
+    svesha3: bool,    // 5
+    svesm4: bool,     // 6
+    // flagm2: bool, // 7
+    frint: bool, // 8
+    // svei8mm: bool, // 9
+    // svef32mm: bool, // 10
+    // svef64mm: bool, // 11
+    // svebf16: bool, // 12
+    i8mm: bool, // 13
+    bf16: bool, // 14
+    // dgh: bool, // 15
+    rng: bool, // 16
+    // bti: bool, // 17
+    mte: bool, // 18
+    // ecv: bool, // 19
+    // afp: bool, // 20
+    // rpres: bool, // 21
+    // mte3: bool, // 22
+    sme: bool,       // 23
+    smei16i64: bool, // 24
+    smef64f64: bool, // 25
+    // smei8i32: bool, // 26
+    // smef16f32: bool, // 27
+    // smeb16f32: bool, // 28
+    // smef32f32: bool, // 29
+    smefa64: bool, // 30
+    wfxt: bool,    // 31
+    // ebf16: bool, // 32
+    // sveebf16: bool, // 33
+    // cssc: bool, // 34
+    // rprfm: bool, // 35
+    // sve2p1: bool, // 36
+    sme2: bool, // 37
+    // sme2p1: bool, // 38
+    // smei16i32: bool, // 39
+    // smebi32i32: bool, // 40
+    // smeb16b16: bool, // 41
+    // smef16f16: bool, // 42
+    // mops: bool, // 43
+    // hbc: bool, // 44
+    // sveb16b16: bool, // 45
+    // lrcpc3: bool, // 46
+    // lse128: bool, // 47
+    fpmr: bool,     // 48
+    lut: bool,      // 49
+    faminmax: bool, // 50
+    f8cvt: bool,    // 51
+    f8fma: bool,    // 52
+    f8dp4: bool,    // 53
+    f8dp2: bool,    // 54
 }
 
 impl From<auxvec::AuxVec> for AtHwcap {
@@ -65,15 +131,79 @@ impl From<auxvec::AuxVec> for AtHwcap {
             // cpuid: bit::test(auxv.hwcap, 11),
             asimdrdm: bit::test(auxv.hwcap, 12),
             // jscvt: bit::test(auxv.hwcap, 13),
-            // fcma: bit::test(auxv.hwcap, 14),
+            fcma: bit::test(auxv.hwcap, 14),
             lrcpc: bit::test(auxv.hwcap, 15),
             // dcpop: bit::test(auxv.hwcap, 16),
-            // sha3: bit::test(auxv.hwcap, 17),
-            // sm3: bit::test(auxv.hwcap, 18),
-            // sm4: bit::test(auxv.hwcap, 19),
+            sha3: bit::test(auxv.hwcap, 17),
+            sm3: bit::test(auxv.hwcap, 18),
+            sm4: bit::test(auxv.hwcap, 19),
             asimddp: bit::test(auxv.hwcap, 20),
             // sha512: bit::test(auxv.hwcap, 21),
             sve: bit::test(auxv.hwcap, 22),
+            // asimdfhm: bit::test(auxv.hwcap, 23),
+            // dit: bit::test(auxv.hwcap, 24),
+            // uscat: bit::test(auxv.hwcap, 25),
+            // ilrcpc: bit::test(auxv.hwcap, 26),
+            // flagm: bit::test(auxv.hwcap, 27),
+            // ssbs: bit::test(auxv.hwcap, 28),
+            // sb: bit::test(auxv.hwcap, 29),
+            paca: bit::test(auxv.hwcap, 30),
+            pacg: bit::test(auxv.hwcap, 31),
+            // dcpodp: bit::test(auxv.hwcap2, 0),
+            sve2: bit::test(auxv.hwcap2, 1),
+            sveaes: bit::test(auxv.hwcap2, 2),
+            // svepmull: bit::test(auxv.hwcap2, 3),
+            svebitperm: bit::test(auxv.hwcap2, 4),
+            svesha3: bit::test(auxv.hwcap2, 5),
+            svesm4: bit::test(auxv.hwcap2, 6),
+            // flagm2: bit::test(auxv.hwcap2, 7),
+            frint: bit::test(auxv.hwcap2, 8),
+            // svei8mm: bit::test(auxv.hwcap2, 9),
+            // svef32mm: bit::test(auxv.hwcap2, 10),
+            // svef64mm: bit::test(auxv.hwcap2, 11),
+            // svebf16: bit::test(auxv.hwcap2, 12),
+            i8mm: bit::test(auxv.hwcap2, 13),
+            bf16: bit::test(auxv.hwcap2, 14),
+            // dgh: bit::test(auxv.hwcap2, 15),
+            rng: bit::test(auxv.hwcap2, 16),
+            // bti: bit::test(auxv.hwcap2, 17),
+            mte: bit::test(auxv.hwcap2, 18),
+            // ecv: bit::test(auxv.hwcap2, 19),
+            // afp: bit::test(auxv.hwcap2, 20),
+            // rpres: bit::test(auxv.hwcap2, 21),
+            // mte3: bit::test(auxv.hwcap2, 22),
+            sme: bit::test(auxv.hwcap2, 23),
+            smei16i64: bit::test(auxv.hwcap2, 24),
+            smef64f64: bit::test(auxv.hwcap2, 25),
+            // smei8i32: bit::test(auxv.hwcap2, 26),
+            // smef16f32: bit::test(auxv.hwcap2, 27),
+            // smeb16f32: bit::test(auxv.hwcap2, 28),
+            // smef32f32: bit::test(auxv.hwcap2, 29),
+            smefa64: bit::test(auxv.hwcap2, 30),
+            wfxt: bit::test(auxv.hwcap2, 31),
+            // ebf16: bit::test(auxv.hwcap2, 32),
+            // sveebf16: bit::test(auxv.hwcap2, 33),
+            // cssc: bit::test(auxv.hwcap2, 34),
+            // rprfm: bit::test(auxv.hwcap2, 35),
+            // sve2p1: bit::test(auxv.hwcap2, 36),
+            sme2: bit::test(auxv.hwcap2, 37),
+            // sme2p1: bit::test(auxv.hwcap2, 38),
+            // smei16i32: bit::test(auxv.hwcap2, 39),
+            // smebi32i32: bit::test(auxv.hwcap2, 40),
+            // smeb16b16: bit::test(auxv.hwcap2, 41),
+            // smef16f16: bit::test(auxv.hwcap2, 42),
+            // mops: bit::test(auxv.hwcap2, 43),
+            // hbc: bit::test(auxv.hwcap2, 44),
+            // sveb16b16: bit::test(auxv.hwcap2, 45),
+            // lrcpc3: bit::test(auxv.hwcap2, 46),
+            // lse128: bit::test(auxv.hwcap2, 47),
+            fpmr: bit::test(auxv.hwcap2, 48),
+            lut: bit::test(auxv.hwcap2, 49),
+            faminmax: bit::test(auxv.hwcap2, 50),
+            f8cvt: bit::test(auxv.hwcap2, 51),
+            f8fma: bit::test(auxv.hwcap2, 52),
+            f8dp4: bit::test(auxv.hwcap2, 53),
+            f8dp2: bit::test(auxv.hwcap2, 54),
         }
     }
 }
@@ -101,15 +231,79 @@ impl From<super::cpuinfo::CpuInfo> for AtHwcap {
             // cpuid: f.has("cpuid"),
             asimdrdm: f.has("asimdrdm"),
             // jscvt: f.has("jscvt"),
-            // fcma: f.has("fcma"),
+            fcma: f.has("fcma"),
             lrcpc: f.has("lrcpc"),
             // dcpop: f.has("dcpop"),
-            // sha3: f.has("sha3"),
-            // sm3: f.has("sm3"),
-            // sm4: f.has("sm4"),
+            sha3: f.has("sha3"),
+            sm3: f.has("sm3"),
+            sm4: f.has("sm4"),
             asimddp: f.has("asimddp"),
             // sha512: f.has("sha512"),
             sve: f.has("sve"),
+            // asimdfhm: f.has("asimdfhm"),
+            // dit: f.has("dit"),
+            // uscat: f.has("uscat"),
+            // ilrcpc: f.has("ilrcpc"),
+            // flagm: f.has("flagm"),
+            // ssbs: f.has("ssbs"),
+            // sb: f.has("sb"),
+            paca: f.has("paca"),
+            pacg: f.has("pacg"),
+            // dcpodp: f.has("dcpodp"),
+            sve2: f.has("sve2"),
+            sveaes: f.has("sveaes"),
+            // svepmull: f.has("svepmull"),
+            svebitperm: f.has("svebitperm"),
+            svesha3: f.has("svesha3"),
+            svesm4: f.has("svesm4"),
+            // flagm2: f.has("flagm2"),
+            frint: f.has("frint"),
+            // svei8mm: f.has("svei8mm"),
+            // svef32mm: f.has("svef32mm"),
+            // svef64mm: f.has("svef64mm"),
+            // svebf16: f.has("svebf16"),
+            i8mm: f.has("i8mm"),
+            bf16: f.has("bf16"),
+            // dgh: f.has("dgh"),
+            rng: f.has("rng"),
+            // bti: f.has("bti"),
+            mte: f.has("mte"),
+            // ecv: f.has("ecv"),
+            // afp: f.has("afp"),
+            // rpres: f.has("rpres"),
+            // mte3: f.has("mte3"),
+            sme: f.has("sme"),
+            smei16i64: f.has("smei16i64"),
+            smef64f64: f.has("smef64f64"),
+            // smei8i32: f.has("smei8i32"),
+            // smef16f32: f.has("smef16f32"),
+            // smeb16f32: f.has("smeb16f32"),
+            // smef32f32: f.has("smef32f32"),
+            smefa64: f.has("smefa64"),
+            wfxt: f.has("wfxt"),
+            // ebf16: f.has("ebf16"),
+            // sveebf16: f.has("sveebf16"),
+            // cssc: f.has("cssc"),
+            // rprfm: f.has("rprfm"),
+            // sve2p1: f.has("sve2p1"),
+            sme2: f.has("sme2"),
+            // sme2p1: f.has("sme2p1"),
+            // smei16i32: f.has("smei16i32"),
+            // smebi32i32: f.has("smebi32i32"),
+            // smeb16b16: f.has("smeb16b16"),
+            // smef16f16: f.has("smef16f16"),
+            // mops: f.has("mops"),
+            // hbc: f.has("hbc"),
+            // sveb16b16: f.has("sveb16b16"),
+            // lrcpc3: f.has("lrcpc3"),
+            // lse128: f.has("lse128"),
+            fpmr: f.has("fpmr"),
+            lut: f.has("lut"),
+            faminmax: f.has("faminmax"),
+            f8cvt: f.has("f8cvt"),
+            f8fma: f.has("f8fma"),
+            f8dp4: f.has("f8dp4"),
+            f8dp2: f.has("f8dp2"),
         }
     }
 }
@@ -135,6 +329,13 @@ impl AtHwcap {
             enable_feature(Feature::crc, self.crc32);
             enable_feature(Feature::lse, self.atomics);
             enable_feature(Feature::rcpc, self.lrcpc);
+            enable_feature(Feature::rand, self.rng);
+            enable_feature(Feature::mte, self.mte);
+            enable_feature(Feature::wfxt, self.wfxt);
+            enable_feature(Feature::paca, self.paca);
+            enable_feature(Feature::pacg, self.pacg);
+            // Linux doesn't support TME, so it has no hwcap for it and `tme` is
+            // never detected. The same goes for LS64, which has no HWCAP2 bit.
 
             // SIMD support requires float support - if half-floats are
             // supported, it also requires half-float support:
@@ -143,7 +344,39 @@ impl AtHwcap {
             // SIMD extensions require SIMD support:
             enable_feature(Feature::rdm, self.asimdrdm && asimd);
             enable_feature(Feature::dotprod, self.asimddp && asimd);
+            enable_feature(Feature::sha3, self.sha3 && asimd);
+            enable_feature(Feature::sm4, self.sm3 && self.sm4 && asimd);
+            enable_feature(Feature::fcma, self.fcma && asimd);
+            enable_feature(Feature::frintts, self.frint && asimd);
+            enable_feature(Feature::i8mm, self.i8mm && asimd);
+            enable_feature(Feature::bf16, self.bf16 && asimd);
+            enable_feature(Feature::lut, self.lut && asimd);
+            enable_feature(Feature::faminmax, self.faminmax && asimd);
+            // FP8 requires FPMR, FAMINMAX, LUT and BF16, and each of the FP8
+            // extensions the previous one.
+            let fp8 = self.fpmr && self.f8cvt && self.faminmax && self.lut && self.bf16 && asimd;
+            enable_feature(Feature::fp8, fp8);
+            let fp8fma = self.f8fma && fp8;
+            enable_feature(Feature::fp8fma, fp8fma);
+            let fp8dot4 = self.f8dp4 && fp8fma;
+            enable_feature(Feature::fp8dot4, fp8dot4);
+            enable_feature(Feature::fp8dot2, self.f8dp2 && fp8dot4);
             enable_feature(Feature::sve, self.sve && asimd);
+            // SVE extensions require SVE support:
+            let sve2 = self.sve2 && self.sve && asimd;
+            enable_feature(Feature::sve2, sve2);
+            enable_feature(Feature::sve2_aes, self.sveaes && sve2);
+            enable_feature(Feature::sve2_sm4, self.svesm4 && sve2);
+            enable_feature(Feature::sve2_sha3, self.svesha3 && sve2);
+            enable_feature(Feature::sve2_bitperm, self.svebitperm && sve2);
+            // SME requires BF16, but not SVE. FA64 makes all of SVE2 available
+            // in streaming mode, so it requires SVE2 as well.
+            let sme = self.sme && self.bf16 && asimd;
+            enable_feature(Feature::sme, sme);
+            enable_feature(Feature::sme2, self.sme2 && sme);
+            enable_feature(Feature::sme_f64f64, self.smef64f64 && sme);
+            enable_feature(Feature::sme_i16i64, self.smei16i64 && sme);
+            enable_feature(Feature::sme_fa64, self.smefa64 && sme && sve2);
 
             // Crypto is specified as AES + PMULL + SHA1 + SHA2 per LLVM/hosts.cpp
             enable_feature(
@@ -17,7 +17,13 @@ pub(crate) fn detect_features() -> cache::Initializer {
     //
     // [hwcap]: https://github.com/torvalds/linux/blob/master/arch/arm/include/uapi/asm/hwcap.h
     if let Ok(auxv) = auxvec::auxv() {
-        enable_feature(&mut value, Feature::neon, bit::test(auxv.hwcap, 12));
+        let neon = bit::test(auxv.hwcap, 12);
+        enable_feature(&mut value, Feature::neon, neon);
+        enable_feature(
+            &mut value,
+            Feature::dotprod,
+            neon && bit::test(auxv.hwcap, 24),
+        );
         enable_feature(&mut value, Feature::pmull, bit::test(auxv.hwcap2, 1));
         enable_feature(&mut value, Feature::crc, bit::test(auxv.hwcap2, 4));
         enable_feature(
@@ -33,10 +39,12 @@ pub(crate) fn detect_features() -> cache::Initializer {
 
     #[cfg(feature = "std_detect_file_io")]
     if let Ok(c) = super::cpuinfo::CpuInfo::new() {
+        let neon = c.field("Features").has("neon") && !has_broken_neon(&c);
+        enable_feature(&mut value, Feature::neon, neon);
         enable_feature(
             &mut value,
-            Feature::neon,
-            c.field("Features").has("neon") && !has_broken_neon(&c),
+            Feature::dotprod,
+            neon && c.field("Features").has("asimddp"),
         );
         enable_feature(&mut value, Feature::pmull, c.field("Features").has("pmull"));
         enable_feature(&mut value, Feature::crc, c.field("Features").has("crc32"));
@@ -0,0 +1,75 @@
+//! Run-time feature detection for RISC-V on Linux.
+
+use crate::detect::{cache, Feature};
+
+/// The `riscv_hwprobe` system call, available since Linux 6.4.
+const SYS_RISCV_HWPROBE: libc::c_long = 258;
+
+/// A key/value pair of the [hwprobe] interface.
+///
+/// [hwprobe]: https://docs.kernel.org/arch/riscv/hwprobe.html
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct riscv_hwprobe {
+    key: i64,
+    value: u64,
+}
+
+const RISCV_HWPROBE_KEY_IMA_EXT_0: i64 = 4;
+
+/// Queries a single key for the extensions supported by all harts. Returns
+/// `None` if the kernel doesn't know the system call or the key.
+fn hwprobe(key: i64) -> Option<u64> {
+    let mut pair = riscv_hwprobe { key, value: 0 };
+    // A zero-sized, null CPU set asks for the values common to all CPUs.
+    let ret = unsafe {
+        libc::syscall(
+            SYS_RISCV_HWPROBE,
+            &mut pair as *mut riscv_hwprobe,
+            1_usize,
+            0_usize,
+            core::ptr::null_mut::<libc::c_ulong>(),
+            0_u32,
+        )
+    };
+    // Unknown keys are reported by setting the key to -1.
+    if ret != 0 || pair.key != key {
+        return None;
+    }
+    Some(pair.value)
+}
+
+/// Read the extensions from the `riscv_hwprobe` system call.
+///
+/// Older kernels don't implement it, in which case no extensions are reported.
+pub(crate) fn detect_features() -> cache::Initializer {
+    let mut value = cache::Initializer::default();
+    let enable_feature = |value: &mut cache::Initializer, f, enable| {
+        if enable {
+            value.set(f as u32);
+        }
+    };
+
+    // The bit positions are part of [asm/hwprobe.h][hwprobe_h]
+    //
+    // [hwprobe_h]: https://github.com/torvalds/linux/blob/master/arch/riscv/include/uapi/asm/hwprobe.h
+    if let Some(ima_ext_0) = hwprobe(RISCV_HWPROBE_KEY_IMA_EXT_0) {
+        // Newer extensions use bits above 31, so keep all 64 bits on RV32 too.
+        let has = |bit: u32| ima_ext_0 & (1 << bit) != 0;
+        enable_feature(&mut value, Feature::v, has(2));
+        enable_feature(&mut value, Feature::zba, has(3));
+        enable_feature(&mut value, Feature::zbb, has(4));
+        enable_feature(&mut value, Feature::zbs, has(5));
+        enable_feature(&mut value, Feature::zicboz, has(6));
+        enable_feature(&mut value, Feature::zbc, has(7));
+        enable_feature(&mut value, Feature::zbkb, has(8));
+        enable_feature(&mut value, Feature::zbkc, has(9));
+        enable_feature(&mut value, Feature::zbkx, has(10));
+        enable_feature(&mut value, Feature::zknd, has(11));
+        enable_feature(&mut value, Feature::zkne, has(12));
+        enable_feature(&mut value, Feature::zknh, has(13));
+        enable_feature(&mut value, Feature::zksed, has(14));
+        enable_feature(&mut value, Feature::zksh, has(15));
+    }
+    value
+}
@@ -0,0 +1,80 @@
+//! Run-time feature detection for Aarch64 on macOS.
+
+use crate::detect::{cache, Feature};
+
+/// Reads the boolean `sysctl` named by the nul-terminated `name`, which is
+/// missing on OS versions and CPUs that don't know about the feature.
+fn sysctl_enabled(name: &[u8]) -> bool {
+    debug_assert_eq!(name.last(), Some(&0));
+    let mut enabled: i32 = 0;
+    let mut enabled_len: libc::size_t = core::mem::size_of::<i32>();
+    let ret = unsafe {
+        libc::sysctlbyname(
+            name.as_ptr() as *const libc::c_char,
+            &mut enabled as *mut i32 as *mut libc::c_void,
+            &mut enabled_len,
+            core::ptr::null_mut(),
+            0,
+        )
+    };
+    ret == 0 && enabled != 0
+}
+
+/// Try to read the features using sysctlbyname.
+pub(crate) fn detect_features() -> cache::Initializer {
+    let mut value = cache::Initializer::default();
+    {
+        let mut enable_feature = |f, enable| {
+            if enable {
+                value.set(f as u32);
+            }
+        };
+
+        let fp = sysctl_enabled(b"hw.optional.floatingpoint\0");
+        let asimd = sysctl_enabled(b"hw.optional.neon\0");
+        enable_feature(Feature::fp, fp);
+        enable_feature(Feature::asimd, fp && asimd);
+        enable_feature(Feature::fp16, sysctl_enabled(b"hw.optional.neon_fp16\0"));
+        enable_feature(Feature::crc, sysctl_enabled(b"hw.optional.armv8_crc32\0"));
+        enable_feature(
+            Feature::lse,
+            sysctl_enabled(b"hw.optional.armv8_1_atomics\0"),
+        );
+        enable_feature(
+            Feature::sha3,
+            asimd && sysctl_enabled(b"hw.optional.armv8_2_sha3\0"),
+        );
+        enable_feature(Feature::rand, sysctl_enabled(b"hw.optional.arm.FEAT_RNG\0"));
+        enable_feature(
+            Feature::frintts,
+            asimd && sysctl_enabled(b"hw.optional.arm.FEAT_FRINTTS\0"),
+        );
+        enable_feature(
+            Feature::lut,
+            asimd && sysctl_enabled(b"hw.optional.arm.FEAT_LUT\0"),
+        );
+        enable_feature(
+            Feature::faminmax,
+            asimd && sysctl_enabled(b"hw.optional.arm.FEAT_FAMINMAX\0"),
+        );
+        let sme = asimd && sysctl_enabled(b"hw.optional.arm.FEAT_SME\0");
+        enable_feature(Feature::sme, sme);
+        enable_feature(
+            Feature::sme2,
+            sme && sysctl_enabled(b"hw.optional.arm.FEAT_SME2\0"),
+        );
+        enable_feature(
+            Feature::sme_f64f64,
+            sme && sysctl_enabled(b"hw.optional.arm.FEAT_SME_F64F64\0"),
+        );
+        enable_feature(
+            Feature::sme_i16i64,
+            sme && sysctl_enabled(b"hw.optional.arm.FEAT_SME_I16I64\0"),
+        );
+        // FEAT_PAuth covers both address and generic authentication.
+        let pauth = sysctl_enabled(b"hw.optional.arm.FEAT_PAuth\0");
+        enable_feature(Feature::paca, pauth);
+        enable_feature(Feature::pacg, pauth);
+    }
+    value
+}
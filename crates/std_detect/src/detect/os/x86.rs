@@ -73,11 +73,64 @@ pub(crate) fn detect_features() -> cache::Initializer {
 
     // EAX = 7, ECX = 0: Queries "Extended Features";
     // Contains information about bmi,bmi2, and avx2 support.
-    let (extended_features_ebx, extended_features_ecx) = if max_basic_leaf >= 7 {
-        let CpuidResult { ebx, ecx, .. } = unsafe { __cpuid(0x0000_0007_u32) };
-        (ebx, ecx)
+    let (
+        extended_features_max_subleaf,
+        extended_features_ebx,
+        extended_features_ecx,
+        extended_features_edx,
+    ) = if max_basic_leaf >= 7 {
+        let CpuidResult { eax, ebx, ecx, edx } = unsafe { __cpuid(0x0000_0007_u32) };
+        (eax, ebx, ecx, edx)
     } else {
-        (0, 0) // CPUID does not support "Extended Features"
+        (0, 0, 0, 0) // CPUID does not support "Extended Features"
+    };
+
+    // EAX = 7, ECX = 1: Queries "Extended Features" sub-leaf 1, which is
+    // only valid if sub-leaf 0 reports it in EAX;
+    // Contains information about sha512, sm3, sm4, avxvnni, avxifma,
+    // avxvnniint8, avxvnniint16, avxneconvert, hreset, prefetchi, cmpccxadd,
+    // raoint and usermsr support.
+    let (extended_features_leaf_1_eax, extended_features_leaf_1_edx) =
+        if extended_features_max_subleaf >= 1 {
+            let CpuidResult { eax, edx, .. } = unsafe { __cpuid_count(0x0000_0007_u32, 1) };
+            (eax, edx)
+        } else {
+            (0, 0)
+        };
+
+    // EAX = 0x14, ECX = 0: Queries "Intel Processor Trace Enumeration",
+    // which is only valid if the processor supports Intel PT, reported in
+    // EBX bit 25 of leaf 7;
+    // Contains information about ptwrite support.
+    let processor_trace_ebx =
+        if max_basic_leaf >= 0x14 && bit::test(extended_features_ebx as usize, 25) {
+            let CpuidResult { ebx, .. } = unsafe { __cpuid_count(0x0000_0014_u32, 0) };
+            ebx
+        } else {
+            0
+        };
+
+    // EAX = 0x19, ECX = 0: Queries "Key Locker Leaf", which is only valid if
+    // the processor supports Key Locker, reported in ECX bit 23 of leaf 7;
+    // Contains information about kl and widekl support.
+    let key_locker_ebx = if max_basic_leaf >= 0x19 && bit::test(extended_features_ecx as usize, 23)
+    {
+        let CpuidResult { ebx, .. } = unsafe { __cpuid(0x0000_0019_u32) };
+        ebx
+    } else {
+        0
+    };
+
+    // EAX = 0x24, ECX = 0: Queries "AVX10 Converged Vector ISA Leaf", which
+    // is only valid if the processor supports AVX10, reported in EDX bit 19
+    // of leaf 7 sub-leaf 1;
+    // Contains the AVX10 version and the supported vector lengths.
+    let avx10_1 = if max_basic_leaf >= 0x24 && bit::test(extended_features_leaf_1_edx as usize, 19)
+    {
+        let CpuidResult { ebx, .. } = unsafe { __cpuid_count(0x0000_0024_u32, 0) };
+        avx10_1_features(ebx)
+    } else {
+        0
     };
 
     // EAX = 0x8000_0000, ECX = 0: Get Highest Extended Function Supported
@@ -97,6 +150,16 @@ pub(crate) fn detect_features() -> cache::Initializer {
         0
     };
 
+    // EAX = 0x8000_0008, ECX=0: Queries "Processor Capacity Parameters and
+    // Extended Feature Identification";
+    // Contains information about clzero and wbnoinvd support.
+    let extended_features_ebx_8 = if extended_max_basic_leaf >= 0x8000_0008_u32 {
+        let CpuidResult { ebx, .. } = unsafe { __cpuid(0x8000_0008_u32) };
+        ebx
+    } else {
+        0
+    };
+
     {
         // borrows value till the end of this scope:
         let mut enable = |r, rb, f| {
@@ -111,6 +174,7 @@ pub(crate) fn detect_features() -> cache::Initializer {
         enable(proc_info_ecx, 13, Feature::cmpxchg16b);
         enable(proc_info_ecx, 19, Feature::sse4_1);
         enable(proc_info_ecx, 20, Feature::sse4_2);
+        enable(proc_info_ecx, 22, Feature::movbe);
         enable(proc_info_ecx, 23, Feature::popcnt);
         enable(proc_info_ecx, 25, Feature::aes);
         enable(proc_info_ecx, 29, Feature::f16c);
@@ -118,12 +182,43 @@ pub(crate) fn detect_features() -> cache::Initializer {
         enable(extended_features_ebx, 18, Feature::rdseed);
         enable(extended_features_ebx, 19, Feature::adx);
         enable(extended_features_ebx, 11, Feature::rtm);
+        enable(extended_features_ebx, 10, Feature::invpcid);
+        enable(extended_features_ebx, 23, Feature::clflushopt);
+        enable(extended_features_ebx, 24, Feature::clwb);
+        enable(extended_features_ebx, 2, Feature::sgx);
         enable(proc_info_edx, 4, Feature::tsc);
         enable(proc_info_edx, 23, Feature::mmx);
         enable(proc_info_edx, 24, Feature::fxsr);
         enable(proc_info_edx, 25, Feature::sse);
         enable(proc_info_edx, 26, Feature::sse2);
         enable(extended_features_ebx, 29, Feature::sha);
+        enable(extended_features_ecx, 5, Feature::waitpkg);
+        enable(extended_features_ecx, 7, Feature::shstk);
+        enable(extended_features_ecx, 8, Feature::gfni);
+        enable(extended_features_ecx, 22, Feature::rdpid);
+        enable(extended_features_ecx, 25, Feature::cldemote);
+        enable(extended_features_ecx, 27, Feature::movdiri);
+        enable(extended_features_ecx, 28, Feature::movdir64b);
+        enable(extended_features_ecx, 29, Feature::enqcmd);
+        enable(extended_features_edx, 5, Feature::uintr);
+        enable(extended_features_edx, 14, Feature::serialize);
+        enable(extended_features_edx, 16, Feature::tsxldtrk);
+        enable(extended_features_edx, 18, Feature::pconfig);
+        enable(processor_trace_ebx, 4, Feature::ptwrite);
+        enable(extended_features_leaf_1_eax, 22, Feature::hreset);
+        enable(extended_features_leaf_1_edx, 14, Feature::prefetchi);
+        enable(extended_features_leaf_1_eax, 7, Feature::cmpccxadd);
+        enable(extended_features_leaf_1_eax, 3, Feature::raoint);
+        enable(extended_features_leaf_1_edx, 15, Feature::usermsr);
+
+        enable(extended_features_ebx, 0, Feature::fsgsbase);
+        // Key Locker can only be used after the OS loaded the internal
+        // wrapping key, which is reported in `AESKLE`, bit 0 of the
+        // Key Locker leaf.
+        if bit::test(key_locker_ebx as usize, 0) {
+            enable(extended_features_ecx, 23, Feature::kl);
+            enable(key_locker_ebx, 2, Feature::widekl);
+        }
 
         enable(extended_features_ebx, 3, Feature::bmi1);
         enable(extended_features_ebx, 8, Feature::bmi2);
@@ -197,6 +292,24 @@ pub(crate) fn detect_features() -> cache::Initializer {
                     enable(proc_info_ecx, 28, Feature::avx);
                     enable(extended_features_ebx, 5, Feature::avx2);
 
+                    // VAES and VPCLMULQDQ have VEX encoded 256-bit forms,
+                    // which only need the OS to support AVX:
+                    enable(extended_features_ecx, 9, Feature::vaes);
+                    enable(extended_features_ecx, 10, Feature::vpclmulqdq);
+
+                    // The VEX encoded dot product, 52-bit multiply-add and
+                    // BF16/FP16 conversion instructions:
+                    enable(extended_features_leaf_1_eax, 4, Feature::avxvnni);
+                    enable(extended_features_leaf_1_eax, 23, Feature::avxifma);
+                    enable(extended_features_leaf_1_edx, 4, Feature::avxvnniint8);
+                    enable(extended_features_leaf_1_edx, 10, Feature::avxvnniint16);
+                    enable(extended_features_leaf_1_edx, 5, Feature::avxneconvert);
+
+                    // The VEX encoded SHA512, SM3 and SM4 instructions:
+                    enable(extended_features_leaf_1_eax, 0, Feature::sha512);
+                    enable(extended_features_leaf_1_eax, 1, Feature::sm3);
+                    enable(extended_features_leaf_1_eax, 2, Feature::sm4);
+
                     // For AVX-512 the OS also needs to support saving/restoring
                     // the extended state, only then we enable AVX-512 support:
                     if os_avx512_support {
@@ -212,14 +325,32 @@ pub(crate) fn detect_features() -> cache::Initializer {
                         enable(extended_features_ecx, 5, Feature::avx512bf16);
                         enable(extended_features_ecx, 6, Feature::avx512vbmi2);
                         enable(extended_features_ecx, 8, Feature::avx512gfni);
-                        enable(extended_features_ecx, 8, Feature::avx512vp2intersect);
                         enable(extended_features_ecx, 9, Feature::avx512vaes);
                         enable(extended_features_ecx, 10, Feature::avx512vpclmulqdq);
                         enable(extended_features_ecx, 11, Feature::avx512vnni);
                         enable(extended_features_ecx, 12, Feature::avx512bitalg);
                         enable(extended_features_ecx, 14, Feature::avx512vpopcntdq);
+                        enable(extended_features_edx, 8, Feature::avx512vp2intersect);
+                        enable(extended_features_edx, 23, Feature::avx512fp16);
+
+                        // AVX10 uses the same state as AVX-512, even when
+                        // only 256-bit vectors are supported:
+                        enable(avx10_1, 0, Feature::avx10_1);
+                        enable(avx10_1, 1, Feature::avx10_1_256);
+                        enable(avx10_1, 2, Feature::avx10_1_512);
                     }
                 }
+
+                // AMX needs the OS to support saving/restoring the tile
+                // configuration and data, `XCR0.XTILECFG[17]` and
+                // `XCR0.XTILEDATA[18]`. Note that Linux additionally requires
+                // each process to request permission to use the tile data via
+                // `arch_prctl(ARCH_REQ_XCOMP_PERM, XFEATURE_XTILEDATA)`.
+                let os_amx_support = xcr0 & 0x6_0000 == 0x6_0000;
+                if os_amx_support {
+                    enable(extended_features_edx, 24, Feature::amx_tile);
+                    enable(extended_features_edx, 25, Feature::amx_int8);
+                }
             }
         }
 
@@ -231,6 +362,8 @@ pub(crate) fn detect_features() -> cache::Initializer {
         // The `is_x86_feature_detected!("lzcnt")` macro then
         // internally maps to Feature::abm.
         enable(extended_proc_info_ecx, 5, Feature::lzcnt);
+        enable(extended_proc_info_ecx, 8, Feature::prfchw);
+        enable(extended_features_ebx_8, 9, Feature::wbnoinvd);
 
         // As Hygon Dhyana originates from AMD technology and shares most of the architecture with
         // AMD's family 17h, but with different CPU Vendor ID("HygonGenuine")/Family series
@@ -246,8 +379,46 @@ pub(crate) fn detect_features() -> cache::Initializer {
             // These features are available on AMD arch CPUs:
             enable(extended_proc_info_ecx, 6, Feature::sse4a);
             enable(extended_proc_info_ecx, 21, Feature::tbm);
+            enable(extended_proc_info_ecx, 29, Feature::mwaitx);
+            enable(extended_features_ebx_8, 0, Feature::clzero);
         }
     }
 
     value
 }
+
+/// Translates `EBX` of the AVX10 leaf into a bit set of the `avx10.1` (bit 0),
+/// `avx10.1-256` (bit 1) and `avx10.1-512` (bit 2) features.
+///
+/// Every AVX10 version includes the earlier ones, and every supported vector
+/// length implies the shorter ones.
+fn avx10_1_features(ebx: u32) -> u32 {
+    let version = ebx & 0xff;
+    if version < 1 {
+        return 0;
+    }
+    let vl512 = bit::test(ebx as usize, 18);
+    let vl256 = vl512 || bit::test(ebx as usize, 17);
+    1 | (vl256 as u32) << 1 | (vl512 as u32) << 2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::avx10_1_features;
+
+    #[test]
+    fn avx10_1_implications() {
+        // No AVX10 version reported.
+        assert_eq!(avx10_1_features(0), 0);
+        assert_eq!(avx10_1_features(0b111 << 16), 0);
+        // AVX10.1 with 128-bit vectors only.
+        assert_eq!(avx10_1_features(1 | 0b001 << 16), 0b001);
+        // AVX10.1/256.
+        assert_eq!(avx10_1_features(1 | 0b011 << 16), 0b011);
+        // AVX10.1/512, with and without the shorter lengths reported.
+        assert_eq!(avx10_1_features(1 | 0b111 << 16), 0b111);
+        assert_eq!(avx10_1_features(1 | 0b100 << 16), 0b111);
+        // Later versions include AVX10.1.
+        assert_eq!(avx10_1_features(2 | 0b111 << 16), 0b111);
+    }
+}
@@ -10,6 +10,7 @@
 //! * `mips64`: [`is_mips64_feature_detected`]
 //! * `powerpc`: [`is_powerpc_feature_detected`]
 //! * `powerpc64`: [`is_powerpc64_feature_detected`]
+//! * `riscv32` and `riscv64`: [`is_riscv_feature_detected`]
 
 #![unstable(feature = "stdsimd", issue = "27731")]
 #![feature(staged_api, stdsimd, doc_cfg, allow_internal_unstable)]
@@ -20,6 +20,12 @@ fn dump() {
     println!("sse4.2: {:?}", is_x86_feature_detected!("sse4.2"));
     println!("sse4a: {:?}", is_x86_feature_detected!("sse4a"));
     println!("sha: {:?}", is_x86_feature_detected!("sha"));
+    println!("sha512: {:?}", is_x86_feature_detected!("sha512"));
+    println!("sm3: {:?}", is_x86_feature_detected!("sm3"));
+    println!("sm4: {:?}", is_x86_feature_detected!("sm4"));
+    println!("gfni: {:?}", is_x86_feature_detected!("gfni"));
+    println!("vaes: {:?}", is_x86_feature_detected!("vaes"));
+    println!("vpclmulqdq: {:?}", is_x86_feature_detected!("vpclmulqdq"));
     println!("avx: {:?}", is_x86_feature_detected!("avx"));
     println!("avx2: {:?}", is_x86_feature_detected!("avx2"));
     println!("avx512f {:?}", is_x86_feature_detected!("avx512f"));
@@ -52,6 +58,20 @@ fn dump() {
         "avx512vp2intersect {:?}",
         is_x86_feature_detected!("avx512vp2intersect")
     );
+    println!("avx512fp16 {:?}", is_x86_feature_detected!("avx512fp16"));
+    println!("avxvnni {:?}", is_x86_feature_detected!("avxvnni"));
+    println!("avxifma {:?}", is_x86_feature_detected!("avxifma"));
+    println!("avxvnniint8 {:?}", is_x86_feature_detected!("avxvnniint8"));
+    println!(
+        "avxvnniint16 {:?}",
+        is_x86_feature_detected!("avxvnniint16")
+    );
+    println!(
+        "avxneconvert {:?}",
+        is_x86_feature_detected!("avxneconvert")
+    );
+    println!("amx-tile {:?}", is_x86_feature_detected!("amx-tile"));
+    println!("amx-int8 {:?}", is_x86_feature_detected!("amx-int8"));
     println!("fma: {:?}", is_x86_feature_detected!("fma"));
     println!("abm: {:?}", is_x86_feature_detected!("abm"));
     println!("bmi: {:?}", is_x86_feature_detected!("bmi1"));
@@ -67,6 +87,38 @@ fn dump() {
     println!("cmpxchg16b: {:?}", is_x86_feature_detected!("cmpxchg16b"));
     println!("adx: {:?}", is_x86_feature_detected!("adx"));
     println!("rtm: {:?}", is_x86_feature_detected!("rtm"));
+    println!("movdiri: {:?}", is_x86_feature_detected!("movdiri"));
+    println!("movdir64b: {:?}", is_x86_feature_detected!("movdir64b"));
+    println!("enqcmd: {:?}", is_x86_feature_detected!("enqcmd"));
+    println!("waitpkg: {:?}", is_x86_feature_detected!("waitpkg"));
+    println!("serialize: {:?}", is_x86_feature_detected!("serialize"));
+    println!("tsxldtrk: {:?}", is_x86_feature_detected!("tsxldtrk"));
+    println!("uintr: {:?}", is_x86_feature_detected!("uintr"));
+    println!("cldemote: {:?}", is_x86_feature_detected!("cldemote"));
+    println!("ptwrite: {:?}", is_x86_feature_detected!("ptwrite"));
+    println!("rdpid: {:?}", is_x86_feature_detected!("rdpid"));
+    println!("mwaitx: {:?}", is_x86_feature_detected!("mwaitx"));
+    println!("clzero: {:?}", is_x86_feature_detected!("clzero"));
+    println!("fsgsbase: {:?}", is_x86_feature_detected!("fsgsbase"));
+    println!("invpcid: {:?}", is_x86_feature_detected!("invpcid"));
+    println!("kl: {:?}", is_x86_feature_detected!("kl"));
+    println!("widekl: {:?}", is_x86_feature_detected!("widekl"));
+    println!("hreset: {:?}", is_x86_feature_detected!("hreset"));
+    println!("prefetchi: {:?}", is_x86_feature_detected!("prefetchi"));
+    println!("cmpccxadd: {:?}", is_x86_feature_detected!("cmpccxadd"));
+    println!("raoint: {:?}", is_x86_feature_detected!("raoint"));
+    println!("clflushopt: {:?}", is_x86_feature_detected!("clflushopt"));
+    println!("clwb: {:?}", is_x86_feature_detected!("clwb"));
+    println!("sgx: {:?}", is_x86_feature_detected!("sgx"));
+    println!("usermsr: {:?}", is_x86_feature_detected!("usermsr"));
+    println!("avx10.1: {:?}", is_x86_feature_detected!("avx10.1"));
+    println!("avx10.1-256: {:?}", is_x86_feature_detected!("avx10.1-256"));
+    println!("avx10.1-512: {:?}", is_x86_feature_detected!("avx10.1-512"));
+    println!("prfchw: {:?}", is_x86_feature_detected!("prfchw"));
+    println!("wbnoinvd: {:?}", is_x86_feature_detected!("wbnoinvd"));
+    println!("pconfig: {:?}", is_x86_feature_detected!("pconfig"));
+    println!("movbe: {:?}", is_x86_feature_detected!("movbe"));
+    println!("shstk: {:?}", is_x86_feature_detected!("shstk"));
 }
 
 #[cfg(feature = "std_detect_env_override")]
@@ -92,6 +144,23 @@ fn env_override_no_avx() {
     }
 }
 
+#[test]
+fn avx10_implications() {
+    if is_x86_feature_detected!("avx10.1-512") {
+        assert!(is_x86_feature_detected!("avx10.1-256"));
+        // Processors with AVX10.1/512 also enumerate the AVX-512 features
+        // that AVX10.1 is made of.
+        assert!(is_x86_feature_detected!("avx512f"));
+        assert!(is_x86_feature_detected!("avx512cd"));
+        assert!(is_x86_feature_detected!("avx512bw"));
+        assert!(is_x86_feature_detected!("avx512dq"));
+        assert!(is_x86_feature_detected!("avx512vl"));
+    }
+    if is_x86_feature_detected!("avx10.1-256") {
+        assert!(is_x86_feature_detected!("avx10.1"));
+    }
+}
+
 #[test]
 fn compare_with_cupid() {
     let information = cupid::master().unwrap();
@@ -47,6 +47,16 @@
 // b       - Second input for tests, it gets scaled to the size of
 //           the type.
 //
+// load_fn - The intrinsic loads several vectors from a pointer, the
+//           types are given as `*const i8:int8x8x2_t`. The name gets
+//           the suffixes of the vector type and the number of vectors,
+//           like `vld1_s8_x2`.
+//
+// store_fn - The intrinsic stores several vectors to a pointer, the
+//           types are given as `*mut i8:int8x8x2_t:void`.
+//           In the links of loads and stores, `_EXT_` is replaced by
+//           the pointer type and `_EXT2_` by the vector type.
+//
 // # special values
 //
 // TRUE - 'true' all bits are set to 1
@@ -102,6 +112,182 @@ b = 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
 validate 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F
 generate int*_t, uint*_t, int64x*_t, uint64x*_t
 
+/// Three-way exclusive OR
+name = veor3
+a = 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F
+b = 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1A, 0x1B, 0x1C, 0x1D, 0x1E, 0x1F
+c = 0x20, 0x23, 0x26, 0x29, 0x2C, 0x2F, 0x32, 0x35, 0x38, 0x3B, 0x3E, 0x41, 0x44, 0x47, 0x4A, 0x4D
+validate 0x30, 0x33, 0x36, 0x39, 0x3C, 0x3F, 0x22, 0x25, 0x28, 0x2B, 0x2E, 0x51, 0x54, 0x57, 0x5A, 0x5D
+target = sha3
+
+aarch64 = eor3
+link-aarch64 = llvm.aarch64.crypto.eor3s._EXT_
+generate int8x16_t, int16x8_t, int32x4_t, int64x2_t
+link-aarch64 = llvm.aarch64.crypto.eor3u._EXT_
+generate uint8x16_t, uint16x8_t, uint32x4_t, uint64x2_t
+
+/// Rotate and exclusive OR
+name = vrax1
+a = 0x01, 0x02
+b = 0x10, 0x8000000000000020
+validate 0x21, 0x43
+target = sha3
+
+aarch64 = rax1
+link-aarch64 = llvm.aarch64.crypto.rax1
+generate uint64x2_t
+
+/// Exclusive OR and rotate
+name = vxar
+constn = IMM6
+multi_fn = static_assert_imm-in_bits_exp_len-IMM6
+a = 0x01, 0x0F
+b = 0x03, 0x08
+n = 2
+validate 0x8000000000000000, 0xC000000000000001
+target = sha3
+
+aarch64 = xar
+link-aarch64 = llvm.aarch64.crypto.xar
+const-aarch64 = IMM6:i64
+generate uint64x2_t
+
+/// Bit clear and exclusive OR
+name = vbcax
+a = 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x10
+b = 0x0F, 0x0F, 0x0F, 0x0F, 0x0F, 0x0F, 0x0F, 0x0F, 0x0F, 0x0F, 0x0F, 0x0F, 0x0F, 0x0F, 0x0F, 0x0F
+c = 0x03, 0x05, 0x06, 0x09, 0x0A, 0x0C, 0x00, 0x0F, 0x03, 0x05, 0x06, 0x09, 0x0A, 0x0C, 0x00, 0x0F
+validate 0x0D, 0x08, 0x0A, 0x02, 0x00, 0x05, 0x08, 0x08, 0x05, 0x00, 0x02, 0x0A, 0x08, 0x0D, 0x00, 0x10
+target = sha3
+
+aarch64 = bcax
+link-aarch64 = llvm.aarch64.crypto.bcaxs._EXT_
+generate int8x16_t, int16x8_t, int32x4_t, int64x2_t
+link-aarch64 = llvm.aarch64.crypto.bcaxu._EXT_
+generate uint8x16_t, uint16x8_t, uint32x4_t, uint64x2_t
+
+/// SM3 hash update accelerator, SS1 rotate and add
+name = vsm3ss1
+a = 0x01234567, 0x89ABCDEF, 0xFEDCBA98, 0x76543210
+b = 0x00112233, 0x44556677, 0x8899AABB, 0xCCDDEEFF
+c = 0x0F1E2D3C, 0x4B5A6978, 0x8796A5B4, 0xC3D2E1F0
+validate 0x00000000, 0x00000000, 0x00000000, 0xE8EC2A69
+target = sm4
+
+aarch64 = sm3ss1
+link-aarch64 = llvm.aarch64.crypto.sm3ss1
+generate uint32x4_t
+
+/// SM3 hash update accelerator, TT1 for rounds 0 to 15
+name = vsm3tt1a
+constn = IMM2
+multi_fn = static_assert_imm-in_exp_len-IMM2
+a = 0x01234567, 0x89ABCDEF, 0xFEDCBA98, 0x76543210
+b = 0x00112233, 0x44556677, 0x8899AABB, 0xCCDDEEFF
+c = 0x0F1E2D3C, 0x4B5A6978, 0x8796A5B4, 0xC3D2E1F0
+n = 1
+validate 0x89ABCDEF, 0xB97531FD, 0x76543210, 0xDD9DDDE0
+target = sm4
+
+aarch64 = sm3tt1a
+link-aarch64 = llvm.aarch64.crypto.sm3tt1a
+const-aarch64 = IMM2:i64
+generate uint32x4_t
+
+/// SM3 hash update accelerator, TT1 for rounds 16 to 63
+name = vsm3tt1b
+constn = IMM2
+multi_fn = static_assert_imm-in_exp_len-IMM2
+a = 0x01234567, 0x89ABCDEF, 0xFEDCBA98, 0x76543210
+b = 0x00112233, 0x44556677, 0x8899AABB, 0xCCDDEEFF
+c = 0x0F1E2D3C, 0x4B5A6978, 0x8796A5B4, 0xC3D2E1F0
+n = 1
+validate 0x89ABCDEF, 0xB97531FD, 0x76543210, 0xDB575311
+target = sm4
+
+aarch64 = sm3tt1b
+link-aarch64 = llvm.aarch64.crypto.sm3tt1b
+const-aarch64 = IMM2:i64
+generate uint32x4_t
+
+/// SM3 hash update accelerator, TT2 for rounds 0 to 15
+name = vsm3tt2a
+constn = IMM2
+multi_fn = static_assert_imm-in_exp_len-IMM2
+a = 0x01234567, 0x89ABCDEF, 0xFEDCBA98, 0x76543210
+b = 0x00112233, 0x44556677, 0x8899AABB, 0xCCDDEEFF
+c = 0x0F1E2D3C, 0x4B5A6978, 0x8796A5B4, 0xC3D2E1F0
+n = 1
+validate 0x89ABCDEF, 0xD4C7F6E5, 0x76543210, 0x21325D8C
+target = sm4
+
+aarch64 = sm3tt2a
+link-aarch64 = llvm.aarch64.crypto.sm3tt2a
+const-aarch64 = IMM2:i64
+generate uint32x4_t
+
+/// SM3 hash update accelerator, TT2 for rounds 16 to 63
+name = vsm3tt2b
+constn = IMM2
+multi_fn = static_assert_imm-in_exp_len-IMM2
+a = 0x01234567, 0x89ABCDEF, 0xFEDCBA98, 0x76543210
+b = 0x00112233, 0x44556677, 0x8899AABB, 0xCCDDEEFF
+c = 0x0F1E2D3C, 0x4B5A6978, 0x8796A5B4, 0xC3D2E1F0
+n = 1
+validate 0x89ABCDEF, 0xD4C7F6E5, 0x76543210, 0x95DA1558
+target = sm4
+
+aarch64 = sm3tt2b
+link-aarch64 = llvm.aarch64.crypto.sm3tt2b
+const-aarch64 = IMM2:i64
+generate uint32x4_t
+
+/// SM3 message expansion accelerator, first part
+name = vsm3partw1
+a = 0x01234567, 0x89ABCDEF, 0xFEDCBA98, 0x76543210
+b = 0x00112233, 0x44556677, 0x8899AABB, 0xCCDDEEFF
+c = 0x0F1E2D3C, 0x4B5A6978, 0x8796A5B4, 0xC3D2E1F0
+validate 0x68681F1F, 0xC2C2B5B5, 0x5B5B2C2C, 0xECB1316C
+target = sm4
+
+aarch64 = sm3partw1
+link-aarch64 = llvm.aarch64.crypto.sm3partw1
+generate uint32x4_t
+
+/// SM3 message expansion accelerator, second part
+name = vsm3partw2
+a = 0x01234567, 0x89ABCDEF, 0xFEDCBA98, 0x76543210
+b = 0x00112233, 0x44556677, 0x8899AABB, 0xCCDDEEFF
+c = 0x0F1E2D3C, 0x4B5A6978, 0x8796A5B4, 0xC3D2E1F0
+validate 0x8E24F953, 0x60CA17BD, 0xBD17CA60, 0x6FCD8123
+target = sm4
+
+aarch64 = sm3partw2
+link-aarch64 = llvm.aarch64.crypto.sm3partw2
+generate uint32x4_t
+
+/// SM4 encryption accelerator, four rounds
+name = vsm4e
+a = 0x01234567, 0x89ABCDEF, 0xFEDCBA98, 0x76543210
+b = 0x00112233, 0x44556677, 0x8899AABB, 0xCCDDEEFF
+validate 0x5B92F440, 0x36FE281E, 0x01E8918D, 0xE6018FBC
+target = sm4
+
+aarch64 = sm4e
+link-aarch64 = llvm.aarch64.crypto.sm4e
+generate uint32x4_t
+
+/// SM4 key schedule accelerator, four round keys
+name = vsm4ekey
+a = 0x01234567, 0x89ABCDEF, 0xFEDCBA98, 0x76543210
+b = 0x00112233, 0x44556677, 0x8899AABB, 0xCCDDEEFF
+validate 0x3DB9DB44, 0x21BEAA15, 0x51605F70, 0x42D79D0F
+target = sm4
+
+aarch64 = sm4ekey
+link-aarch64 = llvm.aarch64.crypto.sm4ekey
+generate uint32x4_t
+
 ////////////////////
 // Absolute difference between the arguments
 ////////////////////
@@ -842,7 +1028,7 @@ generate float64x2_t:float64x1_t:float64x2_t
 /// Insert vector element from another vector element
 name = vcreate
 out-suffix
-multi_fn = transmute, a
+multi_fn = reinterpret, a
 a = 1
 validate 1, 0, 0, 0, 0, 0, 0, 0
 
@@ -857,7 +1043,7 @@ generate u64:poly64x1_t
 /// Insert vector element from another vector element
 name = vcreate
 out-suffix
-multi_fn = transmute, a
+multi_fn = reinterpret, a
 a = 0
 validate 0., 0.
 
@@ -1345,6 +1531,234 @@ generate float64x2_t
 arm = "vext.8"
 generate float*_t
 
+/// Load multiple single-element structures to one, two, three, or four registers
+name = vld1
+load_fn
+a = 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64
+validate 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64
+
+aarch64 = ld1
+link-aarch64 = ld1x2._EXT2_._EXT_
+arm = vld1
+link-arm = vld1x2._EXT2_._EXT_
+generate *const i8:int8x8x2_t, *const i8:int8x16x2_t, *const i16:int16x4x2_t, *const i16:int16x8x2_t, *const i32:int32x2x2_t, *const i32:int32x4x2_t, *const i64:int64x1x2_t, *const i64:int64x2x2_t
+generate *const u8:uint8x8x2_t, *const u8:uint8x16x2_t, *const u16:uint16x4x2_t, *const u16:uint16x8x2_t, *const u32:uint32x2x2_t, *const u32:uint32x4x2_t, *const u64:uint64x1x2_t, *const u64:uint64x2x2_t
+generate *const p8:poly8x8x2_t, *const p8:poly8x16x2_t, *const p16:poly16x4x2_t, *const p16:poly16x8x2_t
+target = crypto
+generate *const p64:poly64x1x2_t, *const p64:poly64x2x2_t
+
+/// Load multiple single-element structures to one, two, three, or four registers
+name = vld1
+load_fn
+a = 0., 1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12., 13., 14., 15., 16.
+validate 1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12., 13., 14., 15., 16.
+
+aarch64 = ld1
+link-aarch64 = ld1x2._EXT2_._EXT_
+arm = vld1
+link-arm = vld1x2._EXT2_._EXT_
+generate *const f32:float32x2x2_t, *const f32:float32x4x2_t
+
+/// Load multiple single-element structures to one, two, three, or four registers
+name = vld1
+load_fn
+a = 0., 1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12., 13., 14., 15., 16.
+validate 1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12., 13., 14., 15., 16.
+
+aarch64 = ld1
+link-aarch64 = ld1x2._EXT2_._EXT_
+generate *const f64:float64x1x2_t, *const f64:float64x2x2_t
+
+/// Load multiple single-element structures to one, two, three, or four registers
+name = vld1
+load_fn
+a = 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64
+validate 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64
+
+aarch64 = ld1
+link-aarch64 = ld1x3._EXT2_._EXT_
+arm = vld1
+link-arm = vld1x3._EXT2_._EXT_
+generate *const i8:int8x8x3_t, *const i8:int8x16x3_t, *const i16:int16x4x3_t, *const i16:int16x8x3_t, *const i32:int32x2x3_t, *const i32:int32x4x3_t, *const i64:int64x1x3_t, *const i64:int64x2x3_t
+generate *const u8:uint8x8x3_t, *const u8:uint8x16x3_t, *const u16:uint16x4x3_t, *const u16:uint16x8x3_t, *const u32:uint32x2x3_t, *const u32:uint32x4x3_t, *const u64:uint64x1x3_t, *const u64:uint64x2x3_t
+generate *const p8:poly8x8x3_t, *const p8:poly8x16x3_t, *const p16:poly16x4x3_t, *const p16:poly16x8x3_t
+target = crypto
+generate *const p64:poly64x1x3_t, *const p64:poly64x2x3_t
+
+/// Load multiple single-element structures to one, two, three, or four registers
+name = vld1
+load_fn
+a = 0., 1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12., 13., 14., 15., 16.
+validate 1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12., 13., 14., 15., 16.
+
+aarch64 = ld1
+link-aarch64 = ld1x3._EXT2_._EXT_
+arm = vld1
+link-arm = vld1x3._EXT2_._EXT_
+generate *const f32:float32x2x3_t, *const f32:float32x4x3_t
+
+/// Load multiple single-element structures to one, two, three, or four registers
+name = vld1
+load_fn
+a = 0., 1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12., 13., 14., 15., 16.
+validate 1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12., 13., 14., 15., 16.
+
+aarch64 = ld1
+link-aarch64 = ld1x3._EXT2_._EXT_
+generate *const f64:float64x1x3_t, *const f64:float64x2x3_t
+
+/// Load multiple single-element structures to one, two, three, or four registers
+name = vld1
+load_fn
+a = 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64
+validate 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64
+
+aarch64 = ld1
+link-aarch64 = ld1x4._EXT2_._EXT_
+arm = vld1
+link-arm = vld1x4._EXT2_._EXT_
+generate *const i8:int8x8x4_t, *const i8:int8x16x4_t, *const i16:int16x4x4_t, *const i16:int16x8x4_t, *const i32:int32x2x4_t, *const i32:int32x4x4_t, *const i64:int64x1x4_t, *const i64:int64x2x4_t
+generate *const u8:uint8x8x4_t, *const u8:uint8x16x4_t, *const u16:uint16x4x4_t, *const u16:uint16x8x4_t, *const u32:uint32x2x4_t, *const u32:uint32x4x4_t, *const u64:uint64x1x4_t, *const u64:uint64x2x4_t
+generate *const p8:poly8x8x4_t, *const p8:poly8x16x4_t, *const p16:poly16x4x4_t, *const p16:poly16x8x4_t
+target = crypto
+generate *const p64:poly64x1x4_t, *const p64:poly64x2x4_t
+
+/// Load multiple single-element structures to one, two, three, or four registers
+name = vld1
+load_fn
+a = 0., 1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12., 13., 14., 15., 16.
+validate 1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12., 13., 14., 15., 16.
+
+aarch64 = ld1
+link-aarch64 = ld1x4._EXT2_._EXT_
+arm = vld1
+link-arm = vld1x4._EXT2_._EXT_
+generate *const f32:float32x2x4_t, *const f32:float32x4x4_t
+
+/// Load multiple single-element structures to one, two, three, or four registers
+name = vld1
+load_fn
+a = 0., 1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12., 13., 14., 15., 16.
+validate 1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12., 13., 14., 15., 16.
+
+aarch64 = ld1
+link-aarch64 = ld1x4._EXT2_._EXT_
+generate *const f64:float64x1x4_t, *const f64:float64x2x4_t
+
+/// Store multiple single-element structures from one, two, three, or four registers
+name = vst1
+store_fn
+a = 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64
+validate 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64
+
+aarch64 = st1
+link-aarch64 = st1x2._EXT2_._EXT_
+arm = vst1
+link-arm = vst1x2._EXT_._EXT2_
+generate *mut i8:int8x8x2_t:void, *mut i8:int8x16x2_t:void, *mut i16:int16x4x2_t:void, *mut i16:int16x8x2_t:void, *mut i32:int32x2x2_t:void, *mut i32:int32x4x2_t:void, *mut i64:int64x1x2_t:void, *mut i64:int64x2x2_t:void
+generate *mut u8:uint8x8x2_t:void, *mut u8:uint8x16x2_t:void, *mut u16:uint16x4x2_t:void, *mut u16:uint16x8x2_t:void, *mut u32:uint32x2x2_t:void, *mut u32:uint32x4x2_t:void, *mut u64:uint64x1x2_t:void, *mut u64:uint64x2x2_t:void
+generate *mut p8:poly8x8x2_t:void, *mut p8:poly8x16x2_t:void, *mut p16:poly16x4x2_t:void, *mut p16:poly16x8x2_t:void
+target = crypto
+generate *mut p64:poly64x1x2_t:void, *mut p64:poly64x2x2_t:void
+
+/// Store multiple single-element structures from one, two, three, or four registers
+name = vst1
+store_fn
+a = 0., 1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12., 13., 14., 15., 16.
+validate 1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12., 13., 14., 15., 16.
+
+aarch64 = st1
+link-aarch64 = st1x2._EXT2_._EXT_
+arm = vst1
+link-arm = vst1x2._EXT_._EXT2_
+generate *mut f32:float32x2x2_t:void, *mut f32:float32x4x2_t:void
+
+/// Store multiple single-element structures from one, two, three, or four registers
+name = vst1
+store_fn
+a = 0., 1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12., 13., 14., 15., 16.
+validate 1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12., 13., 14., 15., 16.
+
+aarch64 = st1
+link-aarch64 = st1x2._EXT2_._EXT_
+generate *mut f64:float64x1x2_t:void, *mut f64:float64x2x2_t:void
+
+/// Store multiple single-element structures from one, two, three, or four registers
+name = vst1
+store_fn
+a = 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64
+validate 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64
+
+aarch64 = st1
+link-aarch64 = st1x3._EXT2_._EXT_
+arm = vst1
+link-arm = vst1x3._EXT_._EXT2_
+generate *mut i8:int8x8x3_t:void, *mut i8:int8x16x3_t:void, *mut i16:int16x4x3_t:void, *mut i16:int16x8x3_t:void, *mut i32:int32x2x3_t:void, *mut i32:int32x4x3_t:void, *mut i64:int64x1x3_t:void, *mut i64:int64x2x3_t:void
+generate *mut u8:uint8x8x3_t:void, *mut u8:uint8x16x3_t:void, *mut u16:uint16x4x3_t:void, *mut u16:uint16x8x3_t:void, *mut u32:uint32x2x3_t:void, *mut u32:uint32x4x3_t:void, *mut u64:uint64x1x3_t:void, *mut u64:uint64x2x3_t:void
+generate *mut p8:poly8x8x3_t:void, *mut p8:poly8x16x3_t:void, *mut p16:poly16x4x3_t:void, *mut p16:poly16x8x3_t:void
+target = crypto
+generate *mut p64:poly64x1x3_t:void, *mut p64:poly64x2x3_t:void
+
+/// Store multiple single-element structures from one, two, three, or four registers
+name = vst1
+store_fn
+a = 0., 1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12., 13., 14., 15., 16.
+validate 1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12., 13., 14., 15., 16.
+
+aarch64 = st1
+link-aarch64 = st1x3._EXT2_._EXT_
+arm = vst1
+link-arm = vst1x3._EXT_._EXT2_
+generate *mut f32:float32x2x3_t:void, *mut f32:float32x4x3_t:void
+
+/// Store multiple single-element structures from one, two, three, or four registers
+name = vst1
+store_fn
+a = 0., 1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12., 13., 14., 15., 16.
+validate 1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12., 13., 14., 15., 16.
+
+aarch64 = st1
+link-aarch64 = st1x3._EXT2_._EXT_
+generate *mut f64:float64x1x3_t:void, *mut f64:float64x2x3_t:void
+
+/// Store multiple single-element structures from one, two, three, or four registers
+name = vst1
+store_fn
+a = 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64
+validate 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64
+
+aarch64 = st1
+link-aarch64 = st1x4._EXT2_._EXT_
+arm = vst1
+link-arm = vst1x4._EXT_._EXT2_
+generate *mut i8:int8x8x4_t:void, *mut i8:int8x16x4_t:void, *mut i16:int16x4x4_t:void, *mut i16:int16x8x4_t:void, *mut i32:int32x2x4_t:void, *mut i32:int32x4x4_t:void, *mut i64:int64x1x4_t:void, *mut i64:int64x2x4_t:void
+generate *mut u8:uint8x8x4_t:void, *mut u8:uint8x16x4_t:void, *mut u16:uint16x4x4_t:void, *mut u16:uint16x8x4_t:void, *mut u32:uint32x2x4_t:void, *mut u32:uint32x4x4_t:void, *mut u64:uint64x1x4_t:void, *mut u64:uint64x2x4_t:void
+generate *mut p8:poly8x8x4_t:void, *mut p8:poly8x16x4_t:void, *mut p16:poly16x4x4_t:void, *mut p16:poly16x8x4_t:void
+target = crypto
+generate *mut p64:poly64x1x4_t:void, *mut p64:poly64x2x4_t:void
+
+/// Store multiple single-element structures from one, two, three, or four registers
+name = vst1
+store_fn
+a = 0., 1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12., 13., 14., 15., 16.
+validate 1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12., 13., 14., 15., 16.
+
+aarch64 = st1
+link-aarch64 = st1x4._EXT2_._EXT_
+arm = vst1
+link-arm = vst1x4._EXT_._EXT2_
+generate *mut f32:float32x2x4_t:void, *mut f32:float32x4x4_t:void
+
+/// Store multiple single-element structures from one, two, three, or four registers
+name = vst1
+store_fn
+a = 0., 1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12., 13., 14., 15., 16.
+validate 1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12., 13., 14., 15., 16.
+
+aarch64 = st1
+link-aarch64 = st1x4._EXT2_._EXT_
+generate *mut f64:float64x1x4_t:void, *mut f64:float64x2x4_t:void
+
 /// Multiply-add to accumulator
 name = vmla
 multi_fn = simd_add, a, {simd_mul, b, c}
@@ -1912,6 +2326,46 @@ aarch64 = frintx
 link-aarch64 = llvm.rint._EXT_
 generate float*_t, float64x*_t
 
+/// Floating-point round to 32-bit integer, using current rounding mode
+name = vrnd32x
+a = -1.5, 2.9, 1.5, 3000000000.0
+validate -2.0, 3.0, 2.0, -2147483648.0
+target = frintts
+
+aarch64 = frint32x
+link-aarch64 = frint32x._EXT_
+generate float*_t, float64x*_t
+
+/// Floating-point round to 32-bit integer toward zero
+name = vrnd32z
+a = -1.5, 2.9, 1.5, 3000000000.0
+validate -1.0, 2.0, 1.0, -2147483648.0
+target = frintts
+
+aarch64 = frint32z
+link-aarch64 = frint32z._EXT_
+generate float*_t, float64x*_t
+
+/// Floating-point round to 64-bit integer, using current rounding mode
+name = vrnd64x
+a = -1.5, 2.9, 1.5, 1.0e20
+validate -2.0, 3.0, 2.0, -9223372036854775808.0
+target = frintts
+
+aarch64 = frint64x
+link-aarch64 = frint64x._EXT_
+generate float*_t, float64x*_t
+
+/// Floating-point round to 64-bit integer toward zero
+name = vrnd64z
+a = -1.5, 2.9, 1.5, 1.0e20
+validate -1.0, 2.0, 1.0, -9223372036854775808.0
+target = frintts
+
+aarch64 = frint64z
+link-aarch64 = frint64z._EXT_
+generate float*_t, float64x*_t
+
 /// Floating-point round to integral, to nearest with ties to away
 name = vrnda
 a = -1.5, 0.5, 1.5, 2.5
@@ -2449,6 +2903,360 @@ arm = vfma
 aarch64 = fmla
 generate float32x4_t:float32x4_t:f32:float32x4_t
 
+/// Floating-point complex add
+name = vcadd_rot90
+rot-suffix
+a = 1.0, -1.0, 1.0, -1.0
+b = -1.0, 1.0, -1.0, 1.0
+validate 0.0, -2.0, 0.0, -2.0
+
+target = fcma
+aarch64 = fcadd
+link-aarch64 = vcadd.rot90._EXT_
+generate float32x2_t, float32x4_t, float64x2_t
+
+/// Floating-point complex add
+name = vcadd_rot270
+rot-suffix
+a = 1.0, -1.0, 1.0, -1.0
+b = -1.0, 1.0, -1.0, 1.0
+validate 2.0, 0.0, 2.0, 0.0
+
+target = fcma
+aarch64 = fcadd
+link-aarch64 = vcadd.rot270._EXT_
+generate float32x2_t, float32x4_t, float64x2_t
+
+/// Floating-point complex multiply accumulate
+name = vcmla
+a = 1.0, -1.0, 1.0, -1.0
+b = -1.0, 1.0, -1.0, 1.0
+c = 1.0, 1.0, -1.0, -1.0
+validate 0.0, -2.0, 2.0, 0.0
+
+target = fcma
+aarch64 = fcmla
+link-aarch64 = vcmla.rot0._EXT_
+generate float32x2_t, float32x4_t, float64x2_t
+
+/// Floating-point complex multiply accumulate
+name = vcmla_rot90
+rot-suffix
+a = 1.0, -1.0, 1.0, -1.0
+b = -1.0, 1.0, -1.0, 1.0
+c = 1.0, 1.0, -1.0, -1.0
+validate 0.0, 0.0, 2.0, -2.0
+
+target = fcma
+aarch64 = fcmla
+link-aarch64 = vcmla.rot90._EXT_
+generate float32x2_t, float32x4_t, float64x2_t
+
+/// Floating-point complex multiply accumulate
+name = vcmla_rot180
+rot-suffix
+a = 1.0, -1.0, 1.0, -1.0
+b = -1.0, 1.0, -1.0, 1.0
+c = 1.0, 1.0, -1.0, -1.0
+validate 2.0, 0.0, 0.0, -2.0
+
+target = fcma
+aarch64 = fcmla
+link-aarch64 = vcmla.rot180._EXT_
+generate float32x2_t, float32x4_t, float64x2_t
+
+/// Floating-point complex multiply accumulate
+name = vcmla_rot270
+rot-suffix
+a = 1.0, -1.0, 1.0, -1.0
+b = -1.0, 1.0, -1.0, 1.0
+c = 1.0, 1.0, -1.0, -1.0
+validate 2.0, -2.0, 0.0, 0.0
+
+target = fcma
+aarch64 = fcmla
+link-aarch64 = vcmla.rot270._EXT_
+generate float32x2_t, float32x4_t, float64x2_t
+
+/// Floating-point complex multiply accumulate
+name = vcmla
+in2-lane-suffixes
+constn = LANE
+multi_fn = static_assert_imm-in2_rot_exp_len-LANE
+multi_fn = simd_shuffle-out_len-noext, c:out_t, c, c, {base-2-LANE}
+multi_fn = vcmla-self-noext, a, b, c
+a = 1.0, -1.0, 1.0, -1.0
+b = -1.0, 1.0, -1.0, 1.0
+c = 1.0, 1.0, -1.0, -1.0
+n = 0
+validate 0.0, -2.0, 0.0, -2.0
+
+target = fcma
+aarch64 = fcmla
+generate float32x2_t, float32x2_t:float32x2_t:float32x4_t:float32x2_t
+generate float32x4_t:float32x4_t:float32x2_t:float32x4_t, float32x4_t
+
+/// Floating-point complex multiply accumulate
+name = vcmla_rot90
+rot-lane-suffixes
+constn = LANE
+multi_fn = static_assert_imm-in2_rot_exp_len-LANE
+multi_fn = simd_shuffle-out_len-noext, c:out_t, c, c, {base-2-LANE}
+multi_fn = self-rot-noext, a, b, c
+a = 1.0, -1.0, 1.0, -1.0
+b = -1.0, 1.0, -1.0, 1.0
+c = 1.0, 1.0, -1.0, -1.0
+n = 0
+validate 0.0, 0.0, 0.0, 0.0
+
+target = fcma
+aarch64 = fcmla
+generate float32x2_t, float32x2_t:float32x2_t:float32x4_t:float32x2_t
+generate float32x4_t:float32x4_t:float32x2_t:float32x4_t, float32x4_t
+
+/// Floating-point complex multiply accumulate
+name = vcmla_rot180
+rot-lane-suffixes
+constn = LANE
+multi_fn = static_assert_imm-in2_rot_exp_len-LANE
+multi_fn = simd_shuffle-out_len-noext, c:out_t, c, c, {base-2-LANE}
+multi_fn = self-rot-noext, a, b, c
+a = 1.0, -1.0, 1.0, -1.0
+b = -1.0, 1.0, -1.0, 1.0
+c = 1.0, 1.0, -1.0, -1.0
+n = 0
+validate 2.0, 0.0, 2.0, 0.0
+
+target = fcma
+aarch64 = fcmla
+generate float32x2_t, float32x2_t:float32x2_t:float32x4_t:float32x2_t
+generate float32x4_t:float32x4_t:float32x2_t:float32x4_t, float32x4_t
+
+/// Floating-point complex multiply accumulate
+name = vcmla_rot270
+rot-lane-suffixes
+constn = LANE
+multi_fn = static_assert_imm-in2_rot_exp_len-LANE
+multi_fn = simd_shuffle-out_len-noext, c:out_t, c, c, {base-2-LANE}
+multi_fn = self-rot-noext, a, b, c
+a = 1.0, -1.0, 1.0, -1.0
+b = -1.0, 1.0, -1.0, 1.0
+c = 1.0, 1.0, -1.0, -1.0
+n = 0
+validate 2.0, -2.0, 2.0, -2.0
+
+target = fcma
+aarch64 = fcmla
+generate float32x2_t, float32x2_t:float32x2_t:float32x4_t:float32x2_t
+generate float32x4_t:float32x4_t:float32x2_t:float32x4_t, float32x4_t
+
+/// 8-bit integer matrix multiply-accumulate
+name = vmmla
+out-suffix
+a = 1, 2, 3, 4
+b = 1, -2, 3, -4, 5, -6, 7, -8, 9, -10, 11, -12, 13, -14, 15, -16
+c = 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16
+validate -35, -66, -65, -96
+
+target = i8mm
+aarch64 = smmla
+link-aarch64 = llvm.aarch64.neon.smmla.v4i32.v16i8
+generate int32x4_t:int8x16_t:int8x16_t:int32x4_t
+
+/// 8-bit integer matrix multiply-accumulate
+name = vmmla
+out-suffix
+a = 1, 2, 3, 4
+b = 100, 200, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 255
+c = 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16
+validate 700, 3365, 2407, 5120
+
+target = i8mm
+aarch64 = ummla
+link-aarch64 = llvm.aarch64.neon.ummla.v4i32.v16i8
+generate uint32x4_t:uint8x16_t:uint8x16_t:uint32x4_t
+
+/// Unsigned and signed 8-bit integer matrix multiply-accumulate
+name = vusmmla
+out-suffix
+a = 1, 2, 3, 4
+b = 100, 200, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 255
+c = -1, 2, -3, 4, -5, 6, -7, 8, -9, 10, -11, 12, -13, 14, -15, 16
+validate 334, 1159, 1983, 3928
+
+target = i8mm
+aarch64 = usmmla
+link-aarch64 = llvm.aarch64.neon.usmmla.v4i32.v16i8
+generate int32x4_t:uint8x16_t:int8x16_t:int32x4_t
+
+/// Dot product vector form with unsigned and signed integers
+name = vusdot
+out-suffix
+a = 1000, -4200, -1000, 2000
+b = 100, 205, 110, 195, 100, 205, 110, 195, 100, 205, 110, 195, 100, 205, 110, 195
+c = -1, 2, -3, 4, -5, 6, -7, 8, -9, 10, -11, 12, -13, 14, -15, 16
+validate 1760, -2680, 1280, 5040
+
+target = i8mm
+aarch64 = usdot
+link-aarch64 = llvm.aarch64.neon.usdot.v2i32.v8i8
+generate int32x2_t:uint8x8_t:int8x8_t:int32x2_t
+
+/// Dot product vector form with unsigned and signed integers
+name = vusdot
+out-suffix
+a = 1000, -4200, -1000, 2000
+b = 100, 205, 110, 195, 100, 205, 110, 195, 100, 205, 110, 195, 100, 205, 110, 195
+c = -1, 2, -3, 4, -5, 6, -7, 8, -9, 10, -11, 12, -13, 14, -15, 16
+validate 1760, -2680, 1280, 5040
+
+target = i8mm
+aarch64 = usdot
+link-aarch64 = llvm.aarch64.neon.usdot.v4i32.v16i8
+generate int32x4_t:uint8x16_t:int8x16_t:int32x4_t
+
+/// Dot product index form with unsigned and signed integers
+name = vusdot
+out-lane-suffixes
+constn = LANE
+multi_fn = static_assert_imm-in2_dot_exp_len-LANE
+multi_fn = simd_shuffle-in_len-noext, c:in_signed, c, c, {base-4-LANE}
+multi_fn = vusdot-out-noext, a, b, c
+a = 1000, -4200, -1000, 2000
+b = 100, 205, 110, 195, 100, 205, 110, 195, 100, 205, 110, 195, 100, 205, 110, 195
+c = -1, 2, -3, 4, -5, 6, -7, 8, -9, 10, -11, 12, -13, 14, -15, 16
+n = 1
+validate 2520, -2680, 520, 3520
+
+target = i8mm
+aarch64 = usdot
+generate int32x2_t:uint8x8_t:int8x8_t:int32x2_t, int32x2_t:uint8x8_t:int8x16_t:int32x2_t
+generate int32x4_t:uint8x16_t:int8x8_t:int32x4_t, int32x4_t:uint8x16_t:int8x16_t:int32x4_t
+
+/// Dot product index form with signed and unsigned integers
+name = vsudot
+out-lane-suffixes
+constn = LANE
+multi_fn = static_assert_imm-in2_dot_exp_len-LANE
+multi_fn = simd_shuffle-in_len-noext, c:in_unsigned, c, c, {base-4-LANE}
+multi_fn = vusdot-out-noext, a, c, b
+a = 1000, -4200, -1000, 2000
+b = -1, 2, -3, 4, -5, 6, -7, 8, -9, 10, -11, 12, -13, 14, -15, 16
+c = 10, 20, 30, 40, 250, 130, 3, 77, 1, 2, 3, 4, 5, 6, 7, 8
+n = 1
+validate 1309, -4075, -1059, 1757
+
+target = i8mm
+aarch64 = sudot
+generate int32x2_t:int8x8_t:uint8x8_t:int32x2_t, int32x2_t:int8x8_t:uint8x16_t:int32x2_t
+generate int32x4_t:int8x16_t:uint8x8_t:int32x4_t, int32x4_t:int8x16_t:uint8x16_t:int32x4_t
+
+/// Dot product arithmetic
+name = vdot
+out-suffix
+a = 1, 2, 1, 2
+b = -1, 2, -3, 4, -5, 6, -7, 8, -9, 10, -11, 12, -13, 14, -15, 16
+c = 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16
+validate 11, 28, 43, 60
+
+target = dotprod
+arm = vsdot
+aarch64 = sdot
+link-arm = sdot.v2i32.v8i8
+link-aarch64 = sdot.v2i32.v8i8
+generate int32x2_t:int8x8_t:int8x8_t:int32x2_t
+link-arm = sdot.v4i32.v16i8
+link-aarch64 = sdot.v4i32.v16i8
+generate int32x4_t:int8x16_t:int8x16_t:int32x4_t
+
+/// Dot product arithmetic
+name = vdot
+out-suffix
+a = 1, 2, 1, 2
+b = 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16
+c = 255, 255, 255, 255, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16
+validate 2551, 176, 447, 848
+
+target = dotprod
+arm = vudot
+aarch64 = udot
+link-arm = udot.v2i32.v8i8
+link-aarch64 = udot.v2i32.v8i8
+generate uint32x2_t:uint8x8_t:uint8x8_t:uint32x2_t
+link-arm = udot.v4i32.v16i8
+link-aarch64 = udot.v4i32.v16i8
+generate uint32x4_t:uint8x16_t:uint8x16_t:uint32x4_t
+
+/// Dot product arithmetic
+name = vdot
+out-lane-suffixes
+constn = LANE
+multi_fn = static_assert_imm-in2_dot_exp_len-LANE
+multi_fn = simd_shuffle-in_len-noext, c:in_t, c, c, {base-4-LANE}
+multi_fn = vdot-out-noext, a, b, c
+a = 1, 2, 1, 2
+b = -1, 2, -3, 4, -5, 6, -7, 8, -9, 10, -11, 12, -13, 14, -15, 16
+c = 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16
+n = 1
+validate 19, 28, 35, 44
+
+target = dotprod
+arm = vsdot
+aarch64 = sdot
+generate int32x2_t:int8x8_t:int8x8_t:int32x2_t, int32x4_t:int8x16_t:int8x8_t:int32x4_t
+
+/// Dot product arithmetic
+name = vdot
+out-lane-suffixes
+constn = LANE
+multi_fn = static_assert_imm-in2_dot_exp_len-LANE
+multi_fn = simd_shuffle-in_len-noext, c:in_t, c, c, {base-4-LANE}
+multi_fn = vdot-out-noext, a, b, c
+a = 1, 2, 1, 2
+b = -1, 2, -3, 4, -5, 6, -7, 8, -9, 10, -11, 12, -13, 14, -15, 16
+c = 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16
+n = 1
+validate 19, 28, 35, 44
+
+target = dotprod
+aarch64 = sdot
+generate int32x2_t:int8x8_t:int8x16_t:int32x2_t, int32x4_t:int8x16_t:int8x16_t:int32x4_t
+
+/// Dot product arithmetic
+name = vdot
+out-lane-suffixes
+constn = LANE
+multi_fn = static_assert_imm-in2_dot_exp_len-LANE
+multi_fn = simd_shuffle-in_len-noext, c:in_t, c, c, {base-4-LANE}
+multi_fn = vdot-out-noext, a, b, c
+a = 1, 2, 1, 2
+b = 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16
+c = 1, 2, 3, 4, 255, 255, 255, 255, 9, 10, 11, 12, 13, 14, 15, 16
+n = 1
+validate 2551, 6632, 10711, 14792
+
+target = dotprod
+arm = vudot
+aarch64 = udot
+generate uint32x2_t:uint8x8_t:uint8x8_t:uint32x2_t, uint32x4_t:uint8x16_t:uint8x8_t:uint32x4_t
+
+/// Dot product arithmetic
+name = vdot
+out-lane-suffixes
+constn = LANE
+multi_fn = static_assert_imm-in2_dot_exp_len-LANE
+multi_fn = simd_shuffle-in_len-noext, c:in_t, c, c, {base-4-LANE}
+multi_fn = vdot-out-noext, a, b, c
+a = 1, 2, 1, 2
+b = 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16
+c = 1, 2, 3, 4, 255, 255, 255, 255, 9, 10, 11, 12, 13, 14, 15, 16
+n = 1
+validate 2551, 6632, 10711, 14792
+
+target = dotprod
+aarch64 = udot
+generate uint32x2_t:uint8x8_t:uint8x16_t:uint32x2_t, uint32x4_t:uint8x16_t:uint8x16_t:uint32x4_t
+
 /// Divide
 name = vdiv
 fn = simd_div
@@ -4014,7 +4822,7 @@ generate int8x16_t:poly8x16_t, uint8x16_t:poly8x16_t, int16x8_t:poly16x8_t, uint
 /// Vector reinterpret cast operation
 name = vreinterpret
 double-suffixes
-fn = transmute
+fn = reinterpret
 a = 0, 1, 2, 3, 4, 5, 6, 7
 validate 0, 0, 1, 0, 2, 0, 3, 0, 4, 0, 5, 0, 6, 0, 7, 0
 
@@ -4033,7 +4841,7 @@ generate poly16x8_t:poly8x16_t, int16x8_t:poly8x16_t, uint16x8_t:poly8x16_t, int
 /// Vector reinterpret cast operation
 name = vreinterpret
 double-suffixes
-fn = transmute
+fn = reinterpret
 a = 0, 0, 1, 0, 2, 0, 3, 0, 4, 0, 5, 0, 6, 0, 7, 0
 validate 0, 1, 2, 3, 4, 5, 6, 7
 
@@ -4052,7 +4860,7 @@ generate poly8x16_t:poly16x8_t, int8x16_t:poly16x8_t, uint8x16_t:poly16x8_t
 /// Vector reinterpret cast operation
 name = vreinterpret
 double-suffixes
-fn = transmute
+fn = reinterpret
 a = 0, 1, 2, 3
 validate 0, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0
 
@@ -4071,7 +4879,7 @@ generate int32x4_t:poly8x16_t, uint32x4_t:poly8x16_t, int64x2_t:poly16x8_t, uint
 /// Vector reinterpret cast operation
 name = vreinterpret
 double-suffixes
-fn = transmute
+fn = reinterpret
 a = 0, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0
 validate 0, 1, 2, 3
 
@@ -4088,7 +4896,7 @@ generate poly8x16_t:uint32x4_t, int8x16_t:uint32x4_t, uint8x16_t:uint32x4_t, pol
 /// Vector reinterpret cast operation
 name = vreinterpret
 double-suffixes
-fn = transmute
+fn = reinterpret
 a = 0, 1
 validate 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0
 
@@ -4103,7 +4911,7 @@ generate int64x2_t:int8x16_t, uint64x2_t:int8x16_t, int64x2_t:uint8x16_t, uint64
 /// Vector reinterpret cast operation
 name = vreinterpret
 double-suffixes
-fn = transmute
+fn = reinterpret
 a = 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0
 validate 0, 1
 
@@ -4118,7 +4926,7 @@ generate poly8x16_t:int64x2_t, int8x16_t:int64x2_t, uint8x16_t:int64x2_t, poly8x
 /// Vector reinterpret cast operation
 name = vreinterpret
 double-suffixes
-fn = transmute
+fn = reinterpret
 a = 0., 0., 0., 0., 0., 0., 0., 0.
 validate 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0
 
@@ -4141,7 +4949,7 @@ generate float32x4_t:poly8x16_t, float32x4_t:poly16x8_t
 /// Vector reinterpret cast operation
 name = vreinterpret
 double-suffixes
-fn = transmute
+fn = reinterpret
 a = 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0
 validate 0., 0., 0., 0., 0., 0., 0., 0.
 
@@ -4164,7 +4972,7 @@ generate poly8x16_t:float32x4_t, poly16x8_t:float32x4_t
 /// Vector reinterpret cast operation
 name = vreinterpret
 double-suffixes
-fn = transmute
+fn = reinterpret
 a = 0., 0., 0., 0., 0., 0., 0., 0.
 validate 0., 0., 0., 0., 0., 0., 0., 0.
 
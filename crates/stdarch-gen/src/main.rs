@@ -274,6 +274,34 @@ fn type_to_lane_suffixes<'a>(out_t: &'a str, in_t: &'a str) -> String {
     str
 }
 
+fn type_to_out_lane_suffixes<'a>(out_t: &'a str, in_t: &'a str) -> String {
+    // Like `type_to_lane_suffixes`, but with the element type of the output,
+    // as in `vusdot_laneq_s32`.
+    let mut str = String::new();
+    let suf = type_to_suffix(out_t);
+    if suf.starts_with("q") {
+        str.push_str("q");
+    }
+    str.push_str("_lane");
+    if type_to_suffix(in_t).starts_with("q") {
+        str.push_str("q");
+    }
+    str.push_str(suf.trim_start_matches('q'));
+    str
+}
+
+fn type_to_rot_suffix(c_name: &str, suf: &str) -> String {
+    // The `q` goes between the base name and the rotation, as in
+    // `vcmlaq_rot90_f32`.
+    let ns: Vec<_> = c_name.split('_').collect();
+    assert_eq!(ns.len(), 2);
+    if suf.starts_with("q") {
+        format!("{}q_{}{}", ns[0], ns[1], &suf[1..])
+    } else {
+        format!("{}{}", c_name, suf)
+    }
+}
+
 fn type_to_signed(t: &str) -> &str {
     match t {
         "int8x8_t" | "uint8x8_t" | "poly8x8_t" => "int8x8_t",
@@ -372,6 +400,16 @@ enum Suffix {
     Lane,
     In2,
     In2Lane,
+    Rot,
+    RotLane,
+    OutLane,
+}
+
+#[derive(Clone, Copy)]
+enum Fntype {
+    Normal,
+    Load,
+    Store,
 }
 
 #[derive(Clone, Copy)]
@@ -380,6 +418,12 @@ enum TargetFeature {
     ArmV7,
     FPArmV8,
     Crypto,
+    FCMA,
+    I8MM,
+    SHA3,
+    SM4,
+    FRINTTS,
+    DotProd,
 }
 
 fn type_to_global_type(t: &str) -> &str {
@@ -510,6 +554,8 @@ fn type_to_ext(t: &str) -> &str {
         "poly8x16_t" => "v16i8",
         "poly16x4_t" => "v4i16",
         "poly16x8_t" => "v8i16",
+        "poly64x1_t" => "v1i64",
+        "poly64x2_t" => "v2i64",
         "i8" => "i8",
         "i16" => "i16",
         "i32" => "i32",
@@ -522,14 +568,35 @@ fn type_to_ext(t: &str) -> &str {
         "f64" => "f64",
         "p64" => "p64",
         "p128" => "p128",
-        /*
-        "poly64x1_t" => "i64x1",
-        "poly64x2_t" => "i64x2",
-        */
+        "*const i8" | "*mut i8" | "*const u8" | "*mut u8" | "*const p8" | "*mut p8" => "p0i8",
+        "*const i16" | "*mut i16" | "*const u16" | "*mut u16" | "*const p16" | "*mut p16" => {
+            "p0i16"
+        }
+        "*const i32" | "*mut i32" | "*const u32" | "*mut u32" => "p0i32",
+        "*const i64" | "*mut i64" | "*const u64" | "*mut u64" | "*const p64" | "*mut p64" => {
+            "p0i64"
+        }
+        "*const f32" | "*mut f32" => "p0f32",
+        "*const f64" | "*mut f64" => "p0f64",
         _ => panic!("unknown type for extension: {}", t),
     }
 }
 
+/// Splits a type containing several vectors, like `int8x8x2_t`, into the
+/// vector type and the number of vectors.
+fn type_to_sub_type(t: &str) -> (String, usize) {
+    let x = t.rfind('x').unwrap();
+    let n = t[x + 1..t.len() - 2]
+        .parse()
+        .unwrap_or_else(|_| panic!("unknown type with several vectors: {}", t));
+    (format!("{}_t", &t[..x]), n)
+}
+
+/// The element type of a pointer type, like `i8` for `*const i8`.
+fn ptr_to_native_type(t: &str) -> &str {
+    t.trim_start_matches("*const ").trim_start_matches("*mut ")
+}
+
 fn type_to_half(t: &str) -> &str {
     match t {
         "int8x16_t" => "int8x8_t",
@@ -886,12 +953,25 @@ fn gen_aarch64(
         Lane => format!("{}{}", current_name, type_to_lane_suffixes(out_t, in_t[1])),
         In2 => format!("{}{}", current_name, type_to_suffix(in_t[2])),
         In2Lane => format!("{}{}", current_name, type_to_lane_suffixes(out_t, in_t[2])),
+        Rot => type_to_rot_suffix(current_name, type_to_suffix(out_t)),
+        RotLane => type_to_rot_suffix(current_name, &type_to_lane_suffixes(out_t, in_t[2])),
+        OutLane => format!(
+            "{}{}",
+            current_name,
+            type_to_out_lane_suffixes(out_t, in_t[2])
+        ),
     };
     let current_target = match target {
         Default => "neon",
         ArmV7 => "v7",
         FPArmV8 => "fp-armv8,v8",
         Crypto => "neon,crypto",
+        FCMA => "neon,fcma",
+        I8MM => "neon,i8mm",
+        SHA3 => "neon,sha3",
+        SM4 => "neon,sm4",
+        FRINTTS => "neon,frintts",
+        DotProd => "neon,dotprod",
     };
     let current_fn = if let Some(current_fn) = current_fn.clone() {
         if link_aarch64.is_some() {
@@ -918,6 +998,16 @@ fn gen_aarch64(
         in_t[2].to_string(),
         out_t.to_string(),
     ];
+    // `const-aarch64 = N:i64` passes `N` to the LLVM intrinsic as an `i64`,
+    // without a type it's passed as an `i32`.
+    let (const_n, const_t) = match const_aarch64 {
+        Some(c) if c.contains(':') => {
+            let c: Vec<_> = c.split(':').collect();
+            (format!("{} as {}", c[0], c[1]), c[1].to_string())
+        }
+        Some(c) => (c.clone(), String::from("i32")),
+        None => (String::new(), String::new()),
+    };
     if let Some(mut link_aarch64) = link_aarch64.clone() {
         if link_aarch64.contains(":") {
             let links: Vec<_> = link_aarch64.split(':').map(|v| v.to_string()).collect();
@@ -974,13 +1064,16 @@ fn gen_aarch64(
                 current_fn,
                 match para_num {
                     1 => {
-                        format!("a: {}, n: i32", in_t[0])
+                        format!("a: {}, n: {}", in_t[0], const_t)
                     }
                     2 => {
-                        format!("a: {}, b: {}, n: i32", in_t[0], in_t[1])
+                        format!("a: {}, b: {}, n: {}", in_t[0], in_t[1], const_t)
                     }
                     3 => {
-                        format!("a: {}, b: {}, c: {}, n: i32", in_t[0], in_t[1], in_t[2])
+                        format!(
+                            "a: {}, b: {}, c: {}, n: {}",
+                            in_t[0], in_t[1], in_t[2], const_t
+                        )
                     }
                     _ => unimplemented!("unknown para_num"),
                 },
@@ -1060,34 +1153,45 @@ fn gen_aarch64(
     } else {
         ["", ""]
     };
-    let call = if let Some(const_aarch64) = const_aarch64 {
+    let call = if const_aarch64.is_some() {
         match para_num {
             1 => format!(
                 r#"pub unsafe fn {}{}(a: {}) -> {} {{
     {}
     {}{}(a, {})
+}}"#,
+                name, const_declare, in_t[0], out_t, multi_calls, ext_c_const, current_fn, const_n
+            ),
+            2 => format!(
+                r#"pub unsafe fn {}{}(a: {}, b: {}) -> {} {{
+    {}
+    {}{}(a, b, {})
 }}"#,
                 name,
                 const_declare,
                 in_t[0],
+                in_t[1],
                 out_t,
                 multi_calls,
                 ext_c_const,
                 current_fn,
-                const_aarch64
+                const_n
             ),
-            2 => format!(
-                r#"pub unsafe fn {}{}(a: {}) -> {} {{
-    {}{}{}(a, b, {})
+            3 => format!(
+                r#"pub unsafe fn {}{}(a: {}, b: {}, c: {}) -> {} {{
+    {}
+    {}{}(a, b, c, {})
 }}"#,
                 name,
                 const_declare,
                 in_t[0],
+                in_t[1],
+                in_t[2],
                 out_t,
                 multi_calls,
                 ext_c_const,
                 current_fn,
-                const_aarch64
+                const_n
             ),
             _ => String::new(),
         }
@@ -1169,10 +1273,23 @@ fn gen_aarch64(
         [type_len(in_t[0]), type_len(in_t[1]), type_len(in_t[2])],
         type_len(out_t),
         para_num,
+        target,
     );
     (function, test)
 }
 
+fn test_target(target: TargetFeature) -> &'static str {
+    match target {
+        FCMA => "neon,fcma",
+        I8MM => "neon,i8mm",
+        SHA3 => "neon,sha3",
+        SM4 => "neon,sm4",
+        FRINTTS => "neon,frintts",
+        DotProd => "neon,dotprod",
+        _ => "neon",
+    }
+}
+
 fn gen_test(
     name: &str,
     in_t: &[&str; 3],
@@ -1187,11 +1304,13 @@ fn gen_test(
     len_in: [usize; 3],
     len_out: usize,
     para_num: i32,
+    target: TargetFeature,
 ) -> String {
     let mut test = format!(
         r#"
-    #[simd_test(enable = "neon")]
+    #[simd_test(enable = "{}")]
     unsafe fn test_{}() {{"#,
+        test_target(target),
         name,
     );
     for (a, b, c, n, e) in current_tests {
@@ -1332,6 +1451,13 @@ fn gen_arm(
         Lane => format!("{}{}", current_name, type_to_lane_suffixes(out_t, in_t[1])),
         In2 => format!("{}{}", current_name, type_to_suffix(in_t[2])),
         In2Lane => format!("{}{}", current_name, type_to_lane_suffixes(out_t, in_t[2])),
+        Rot => type_to_rot_suffix(current_name, type_to_suffix(out_t)),
+        RotLane => type_to_rot_suffix(current_name, &type_to_lane_suffixes(out_t, in_t[2])),
+        OutLane => format!(
+            "{}{}",
+            current_name,
+            type_to_out_lane_suffixes(out_t, in_t[2])
+        ),
     };
     let current_aarch64 = current_aarch64
         .clone()
@@ -1341,12 +1467,24 @@ fn gen_arm(
         ArmV7 => "neon",
         FPArmV8 => "neon",
         Crypto => "neon,crypto",
+        FCMA => unimplemented!("fcma is only supported on aarch64"),
+        I8MM => unimplemented!("i8mm is only supported on aarch64"),
+        SHA3 => unimplemented!("sha3 is only supported on aarch64"),
+        SM4 => unimplemented!("sm4 is only supported on aarch64"),
+        FRINTTS => unimplemented!("frintts is only supported on aarch64"),
+        DotProd => "neon,dotprod",
     };
     let current_target_arm = match target {
         Default => "v7",
         ArmV7 => "v7",
         FPArmV8 => "fp-armv8,v8",
         Crypto => "crypto,v8",
+        FCMA => unimplemented!("fcma is only supported on aarch64"),
+        I8MM => unimplemented!("i8mm is only supported on aarch64"),
+        SHA3 => unimplemented!("sha3 is only supported on aarch64"),
+        SM4 => unimplemented!("sm4 is only supported on aarch64"),
+        FRINTTS => unimplemented!("frintts is only supported on aarch64"),
+        DotProd => "dotprod,v8",
     };
 
     let current_fn = if let Some(current_fn) = current_fn.clone() {
@@ -1886,11 +2024,306 @@ fn gen_arm(
         [type_len(in_t[0]), type_len(in_t[1]), type_len(in_t[2])],
         type_len(out_t),
         para_num,
+        target,
     );
 
     (function, test)
 }
 
+/// Generates a load from `*const T` to a type containing several vectors, or
+/// a store of it to `*mut T`. These are shared by arm and aarch64 if `arm` is
+/// given, and exclusive to aarch64 otherwise.
+#[allow(clippy::too_many_arguments)]
+fn gen_load_store(
+    current_comment: &str,
+    current_name: &str,
+    current_arm: &Option<String>,
+    link_arm: &Option<String>,
+    current_aarch64: &Option<String>,
+    link_aarch64: &Option<String>,
+    in_t: &[&str; 3],
+    out_t: &str,
+    current_tests: &[(
+        Vec<String>,
+        Vec<String>,
+        Vec<String>,
+        Option<String>,
+        Vec<String>,
+    )],
+    fn_type: Fntype,
+    target: TargetFeature,
+) -> (String, String) {
+    let ptr_t = in_t[0];
+    let (vec_t, n) = match fn_type {
+        Fntype::Load => type_to_sub_type(out_t),
+        Fntype::Store => type_to_sub_type(in_t[1]),
+        Fntype::Normal => unreachable!(),
+    };
+    let name = format!("{}{}_x{}", current_name, type_to_suffix(&vec_t), n);
+    let (target_aarch64, target_arm) = match target {
+        Default => ("neon", "v7"),
+        Crypto => ("neon,crypto", "crypto,v8"),
+        _ => unimplemented!("[{}] unsupported target for loads and stores", name),
+    };
+    // `_EXT_` is replaced by the pointer type and `_EXT2_` by the vector type.
+    let link = |prefix: &str, link: &Option<String>| {
+        let link = link
+            .as_ref()
+            .unwrap_or_else(|| panic!("[{}] link-{} has to be specified", name, prefix));
+        format!("llvm.{}.neon.{}", prefix, link)
+            .replace("_EXT_", type_to_ext(ptr_t))
+            .replace("_EXT2_", type_to_ext(&vec_t))
+    };
+    let current_aarch64 = current_aarch64.clone().unwrap();
+    let link_aarch64 = link("aarch64", link_aarch64);
+    let vecs: Vec<String> = ["a", "b", "c", "d"][..n]
+        .iter()
+        .map(|v| format!("{}: {}", v, vec_t))
+        .collect();
+    let fields: Vec<String> = (0..n).map(|i| format!("b.{}", i)).collect();
+    let function = match (fn_type, current_arm) {
+        // The vectors are returned as a struct, which only the unadjusted ABI
+        // passes as it is.
+        (Fntype::Load, Some(current_arm)) => format!(
+            r#"
+{}
+#[inline]
+#[target_feature(enable = "{}")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "{}"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr({}))]
+#[cfg_attr(all(test, target_arch = "aarch64"), assert_instr({}))]
+pub unsafe fn {}(a: {}) -> {} {{
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {{
+        #[cfg_attr(target_arch = "arm", link_name = "{}")]
+        #[cfg_attr(target_arch = "aarch64", link_name = "{}")]
+        fn {}_(a: {}) -> {};
+    }}
+    {}_(a)
+}}
+"#,
+            current_comment,
+            target_aarch64,
+            target_arm,
+            expand_intrinsic(current_arm, &vec_t),
+            current_aarch64,
+            name,
+            ptr_t,
+            out_t,
+            link("arm", link_arm),
+            link_aarch64,
+            name,
+            ptr_t,
+            out_t,
+            name,
+        ),
+        (Fntype::Load, None) => format!(
+            r#"
+{}
+#[inline]
+#[target_feature(enable = "{}")]
+#[cfg_attr(test, assert_instr({}))]
+pub unsafe fn {}(a: {}) -> {} {{
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {{
+        #[cfg_attr(target_arch = "aarch64", link_name = "{}")]
+        fn {}_(a: {}) -> {};
+    }}
+    {}_(a)
+}}
+"#,
+            current_comment,
+            target_aarch64,
+            current_aarch64,
+            name,
+            ptr_t,
+            out_t,
+            link_aarch64,
+            name,
+            ptr_t,
+            out_t,
+            name,
+        ),
+        // The pointer is the first argument on arm but the last on aarch64.
+        (Fntype::Store, Some(current_arm)) => format!(
+            r#"
+{}
+#[inline]
+#[cfg(target_arch = "arm")]
+#[target_feature(enable = "neon,{}")]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr({}))]
+pub unsafe fn {}(a: {}, b: {}) {{
+    #[allow(improper_ctypes)]
+    extern "C" {{
+        #[cfg_attr(target_arch = "arm", link_name = "{}")]
+        fn {}_(ptr: {}, {});
+    }}
+    {}_(a, {})
+}}
+
+{}
+#[inline]
+#[cfg(target_arch = "aarch64")]
+#[target_feature(enable = "{}")]
+#[cfg_attr(all(test, target_arch = "aarch64"), assert_instr({}))]
+pub unsafe fn {}(a: {}, b: {}) {{
+    #[allow(improper_ctypes)]
+    extern "C" {{
+        #[cfg_attr(target_arch = "aarch64", link_name = "{}")]
+        fn {}_({}, ptr: {});
+    }}
+    {}_({}, a)
+}}
+"#,
+            current_comment,
+            target_arm,
+            expand_intrinsic(current_arm, &vec_t),
+            name,
+            ptr_t,
+            in_t[1],
+            link("arm", link_arm),
+            name,
+            ptr_t,
+            vecs.join(", "),
+            name,
+            fields.join(", "),
+            current_comment,
+            target_aarch64,
+            current_aarch64,
+            name,
+            ptr_t,
+            in_t[1],
+            link_aarch64,
+            name,
+            vecs.join(", "),
+            ptr_t,
+            name,
+            fields.join(", "),
+        ),
+        (Fntype::Store, None) => format!(
+            r#"
+{}
+#[inline]
+#[target_feature(enable = "{}")]
+#[cfg_attr(test, assert_instr({}))]
+pub unsafe fn {}(a: {}, b: {}) {{
+    #[allow(improper_ctypes)]
+    extern "C" {{
+        #[cfg_attr(target_arch = "aarch64", link_name = "{}")]
+        fn {}_({}, ptr: {});
+    }}
+    {}_({}, a)
+}}
+"#,
+            current_comment,
+            target_aarch64,
+            current_aarch64,
+            name,
+            ptr_t,
+            in_t[1],
+            link_aarch64,
+            name,
+            vecs.join(", "),
+            ptr_t,
+            name,
+            fields.join(", "),
+        ),
+        (Fntype::Normal, _) => unreachable!(),
+    };
+    let test = gen_load_store_test(&name, ptr_t, &vec_t, n, current_tests, fn_type, target);
+    (function, test)
+}
+
+/// Generates a test which loads from `a[1..]`, so that the pointer isn't
+/// aligned to the vector size, and checks that the registers hold `e`, or
+/// which stores the same value to memory and checks that it holds `e`.
+fn gen_load_store_test(
+    name: &str,
+    ptr_t: &str,
+    vec_t: &str,
+    n: usize,
+    current_tests: &[(
+        Vec<String>,
+        Vec<String>,
+        Vec<String>,
+        Option<String>,
+        Vec<String>,
+    )],
+    fn_type: Fntype,
+    target: TargetFeature,
+) -> String {
+    let native_t = ptr_to_native_type(ptr_t);
+    let global_t = type_to_global_type(vec_t);
+    let len = type_len(vec_t) * n;
+    let zero = if native_t.starts_with('f') { "0." } else { "0" };
+    let mut test = format!(
+        r#"
+    #[simd_test(enable = "{}")]
+    unsafe fn test_{}() {{"#,
+        test_target(target),
+        name,
+    );
+    for (a, _, _, _, e) in current_tests {
+        let a: Vec<String> = a.iter().take(len + 1).cloned().collect();
+        let e: Vec<String> = e.iter().take(len).cloned().collect();
+        let t = match fn_type {
+            Fntype::Load => {
+                let e: Vec<String> = e
+                    .chunks(type_len(vec_t))
+                    .map(|v| {
+                        if global_t.contains('x') {
+                            format!("{}::new({})", global_t, v.join(", "))
+                        } else {
+                            v.join(", ")
+                        }
+                    })
+                    .collect();
+                format!(
+                    r#"
+        let a: [{}; {}] = [{}];
+        let e: [{}; {}] = [{}];
+        let r: [{}; {}] = transmute({}(a[1..].as_ptr()));
+        assert_eq!(r, e);
+"#,
+                    native_t,
+                    len + 1,
+                    a.join(", "),
+                    global_t,
+                    n,
+                    e.join(", "),
+                    global_t,
+                    n,
+                    name,
+                )
+            }
+            Fntype::Store => format!(
+                r#"
+        let a: [{}; {}] = [{}];
+        let e: [{}; {}] = [{}];
+        let mut r: [{}; {}] = [{}; {}];
+        {}(r.as_mut_ptr(), core::ptr::read_unaligned(a[1..].as_ptr() as _));
+        assert_eq!(r, e);
+"#,
+                native_t,
+                len + 1,
+                a.join(", "),
+                native_t,
+                len,
+                e.join(", "),
+                native_t,
+                len,
+                zero,
+                len,
+                name,
+            ),
+            Fntype::Normal => unreachable!(),
+        };
+        test.push_str(&t);
+    }
+    test.push_str("    }\n");
+    test
+}
+
 fn expand_intrinsic(intr: &str, t: &str) -> String {
     if intr.ends_with('.') {
         let ext = match t {
@@ -2098,6 +2531,26 @@ fn get_call(
         s.push_str("]");
         return s;
     }
+    if fn_name.starts_with("base") {
+        // `base-n-N` selects the `n` consecutive elements starting at
+        // `n * N` and repeats them to fill a vector of the type of the
+        // second argument.
+        let fn_format: Vec<_> = fn_name.split('-').map(|v| v.to_string()).collect();
+        assert_eq!(fn_format.len(), 3);
+        let n: usize = fn_format[1].parse().unwrap();
+        let mut s = String::from("[");
+        for i in 0..type_len(in_t[1]) {
+            if i != 0 {
+                s.push_str(", ");
+            }
+            s.push_str(&format!("{} * {} as u32", n, fn_format[2]));
+            if i % n != 0 {
+                s.push_str(&format!(" + {}", i % n));
+            }
+        }
+        s.push_str("]");
+        return s;
+    }
     if fn_name.starts_with("static_assert_imm") {
         let fn_format: Vec<_> = fn_name.split('-').map(|v| v.to_string()).collect();
         let len = match &*fn_format[1] {
@@ -2108,6 +2561,8 @@ fn get_call(
             "in0_exp_len" => type_exp_len(in_t[0]),
             "in1_exp_len" => type_exp_len(in_t[1]),
             "in2_exp_len" => type_exp_len(in_t[2]),
+            "in2_rot_exp_len" => type_exp_len(in_t[2]) - 1,
+            "in2_dot_exp_len" => type_exp_len(in_t[2]) - 2,
             _ => 0,
         };
         if len == 0 {
@@ -2233,6 +2688,10 @@ fn get_call(
                 re = Some((re_params[0].clone(), out_t.to_string()));
             } else if re_params[1] == "half" {
                 re = Some((re_params[0].clone(), type_to_half(in_t[1]).to_string()));
+            } else if re_params[1] == "in_signed" {
+                re = Some((re_params[0].clone(), type_to_signed(in_t[1]).to_string()));
+            } else if re_params[1] == "in_unsigned" {
+                re = Some((re_params[0].clone(), type_to_unsigned(in_t[1]).to_string()));
             } else if re_params[1] == "in_ntt" {
                 re = Some((
                     re_params[0].clone(),
@@ -2290,6 +2749,8 @@ fn get_call(
             fn_name.push_str(type_to_suffix(in_t[0]));
         } else if fn_format[1] == "in2" {
             fn_name.push_str(type_to_suffix(in_t[2]));
+        } else if fn_format[1] == "rot" {
+            fn_name = type_to_rot_suffix(&fn_name, type_to_suffix(out_t));
         } else if fn_format[1] == "in2lane" {
             fn_name.push_str(&type_to_lane_suffixes(out_t, in_t[2]));
         } else if fn_format[1] == "signed" {
@@ -2398,6 +2859,7 @@ fn main() -> io::Result<()> {
     )> = Vec::new();
     let mut multi_fn: Vec<String> = Vec::new();
     let mut target: TargetFeature = Default;
+    let mut fn_type: Fntype = Fntype::Normal;
 
     //
     // THIS FILE IS GENERATED FORM neon.spec DO NOT CHANGE IT MANUALLY
@@ -2479,6 +2941,7 @@ mod test {
             n = None;
             multi_fn = Vec::new();
             target = Default;
+            fn_type = Fntype::Normal;
         } else if line.starts_with("//") {
         } else if line.starts_with("name = ") {
             current_name = Some(String::from(&line[7..]));
@@ -2514,6 +2977,16 @@ mod test {
             suffix = In2;
         } else if line.starts_with("in2-lane-suffixes") {
             suffix = In2Lane;
+        } else if line.starts_with("rot-suffix") {
+            suffix = Rot;
+        } else if line.starts_with("rot-lane-suffixes") {
+            suffix = RotLane;
+        } else if line.starts_with("out-lane-suffixes") {
+            suffix = OutLane;
+        } else if line.starts_with("load_fn") {
+            fn_type = Fntype::Load;
+        } else if line.starts_with("store_fn") {
+            fn_type = Fntype::Store;
         } else if line.starts_with("a = ") {
             a = line[4..].split(',').map(|v| v.trim().to_string()).collect();
         } else if line.starts_with("b = ") {
@@ -2541,6 +3014,12 @@ mod test {
                     "v7" => ArmV7,
                     "fp-armv8" => FPArmV8,
                     "crypto" => Crypto,
+                    "fcma" => FCMA,
+                    "i8mm" => I8MM,
+                    "sha3" => SHA3,
+                    "sm4" => SM4,
+                    "frintts" => FRINTTS,
+                    "dotprod" => DotProd,
                     _ => Default,
                 },
                 _ => Default,
@@ -2586,7 +3065,28 @@ mod test {
                     para_num = 3;
                 }
                 let current_name = current_name.clone().unwrap();
-                if let Some(current_arm) = current_arm.clone() {
+                if let Fntype::Load | Fntype::Store = fn_type {
+                    let (function, test) = gen_load_store(
+                        &current_comment,
+                        &current_name,
+                        &current_arm,
+                        &link_arm,
+                        &current_aarch64,
+                        &link_aarch64,
+                        &in_t,
+                        &out_t,
+                        &current_tests,
+                        fn_type,
+                        target,
+                    );
+                    if current_arm.is_some() {
+                        out_arm.push_str(&function);
+                        tests_arm.push_str(&test);
+                    } else {
+                        out_aarch64.push_str(&function);
+                        tests_aarch64.push_str(&test);
+                    }
+                } else if let Some(current_arm) = current_arm.clone() {
                     let (function, test) = gen_arm(
                         &current_comment,
                         &current_fn,
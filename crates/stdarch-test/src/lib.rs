@@ -17,7 +17,7 @@ pub use simd_test_macro::*;
 use std::{cmp, collections::HashSet, env, hash, hint::black_box, str, sync::atomic::AtomicPtr};
 
 cfg_if! {
-    if #[cfg(target_arch = "wasm32")] {
+    if #[cfg(any(target_arch = "wasm32", target_arch = "wasm64"))] {
         pub mod wasm;
         use wasm::disassemble_myself;
     } else {
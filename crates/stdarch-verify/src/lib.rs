@@ -67,6 +67,19 @@ fn functions(input: TokenStream, dirs: &[&str]) -> TokenStream {
             if f.sig.unsafety.is_some() {
                 return true;
             }
+            // Intrinsics which only operate on values may be safe functions
+            // gated on a target feature, but anything that takes a pointer
+            // or a reference has to stay `unsafe`.
+            if find_target_feature(&f.attrs).is_some() {
+                if has_pointer_argument(f) {
+                    panic!(
+                        "safe intrinsic `{}` takes a pointer argument, \
+                         it must be an `unsafe fn`",
+                        f.sig.ident
+                    );
+                }
+                return true;
+            }
         }
         false
     });
@@ -81,24 +94,33 @@ fn functions(input: TokenStream, dirs: &[&str]) -> TokenStream {
             // println!("{}", name);
             let mut arguments = Vec::new();
             let mut const_arguments = Vec::new();
+            // Type parameters stand for the pointee type of generic pointer
+            // arguments, like `T *` in ACLE.
+            let type_params = f
+                .sig
+                .generics
+                .type_params()
+                .map(|p| p.ident.to_string())
+                .collect::<Vec<_>>();
             for input in f.sig.inputs.iter() {
                 let ty = match *input {
                     syn::FnArg::Typed(ref c) => &c.ty,
                     _ => panic!("invalid argument on {}", name),
                 };
-                arguments.push(to_type(ty));
+                arguments.push(to_type(ty, &type_params));
             }
             for generic in f.sig.generics.params.iter() {
                 let ty = match *generic {
                     syn::GenericParam::Const(ref c) => &c.ty,
+                    syn::GenericParam::Type(_) => continue,
                     _ => panic!("invalid generic argument on {}", name),
                 };
-                const_arguments.push(to_type(ty));
+                const_arguments.push(to_type(ty, &type_params));
             }
             let ret = match f.sig.output {
                 syn::ReturnType::Default => quote! { None },
                 syn::ReturnType::Type(_, ref t) => {
-                    let ty = to_type(t);
+                    let ty = to_type(t, &type_params);
                     quote! { Some(#ty) }
                 }
             };
@@ -164,21 +186,29 @@ fn functions(input: TokenStream, dirs: &[&str]) -> TokenStream {
     ret.into()
 }
 
-fn to_type(t: &syn::Type) -> proc_macro2::TokenStream {
+fn to_type(t: &syn::Type, type_params: &[String]) -> proc_macro2::TokenStream {
     match *t {
+        syn::Type::Path(ref p)
+            if type_params.contains(&extract_path_ident(&p.path).to_string()) =>
+        {
+            quote! { &GENERIC }
+        }
         syn::Type::Path(ref p) => match extract_path_ident(&p.path).to_string().as_ref() {
             // x86 ...
             "__m128" => quote! { &M128 },
             "__m128bh" => quote! { &M128BH },
             "__m128d" => quote! { &M128D },
+            "__m128h" => quote! { &M128H },
             "__m128i" => quote! { &M128I },
             "__m256" => quote! { &M256 },
             "__m256bh" => quote! { &M256BH },
             "__m256d" => quote! { &M256D },
+            "__m256h" => quote! { &M256H },
             "__m256i" => quote! { &M256I },
             "__m512" => quote! { &M512 },
             "__m512bh" => quote! { &M512BH },
             "__m512d" => quote! { &M512D },
+            "__m512h" => quote! { &M512H },
             "__m512i" => quote! { &M512I },
             "__mmask8" => quote! { &MMASK8 },
             "__mmask16" => quote! { &MMASK16 },
@@ -199,12 +229,16 @@ fn to_type(t: &syn::Type) -> proc_macro2::TokenStream {
             "u16" => quote! { &U16 },
             "u32" => quote! { &U32 },
             "u64" => quote! { &U64 },
+            "fpm_t" => quote! { &U64 },
             "u128" => quote! { &U128 },
             "u8" => quote! { &U8 },
+            "usize" => quote! { &USIZE },
             "p8" => quote! { &P8 },
             "p16" => quote! { &P16 },
+            "mve_pred16_t" => quote! { &U16 },
             "Ordering" => quote! { &ORDERING },
             "CpuidResult" => quote! { &CPUID },
+            "SgxResult" => quote! { &SGX },
 
             // arm ...
             "int8x4_t" => quote! { &I8X4 },
@@ -219,10 +253,28 @@ fn to_type(t: &syn::Type) -> proc_macro2::TokenStream {
             "int16x2_t" => quote! { &I16X2 },
             "int16x4_t" => quote! { &I16X4 },
             "int16x8_t" => quote! { &I16X8 },
+            "int16x4x2_t" => quote! { &I16X4X2 },
+            "int16x4x3_t" => quote! { &I16X4X3 },
+            "int16x4x4_t" => quote! { &I16X4X4 },
+            "int16x8x2_t" => quote! { &I16X8X2 },
+            "int16x8x3_t" => quote! { &I16X8X3 },
+            "int16x8x4_t" => quote! { &I16X8X4 },
             "int32x2_t" => quote! { &I32X2 },
             "int32x4_t" => quote! { &I32X4 },
+            "int32x2x2_t" => quote! { &I32X2X2 },
+            "int32x2x3_t" => quote! { &I32X2X3 },
+            "int32x2x4_t" => quote! { &I32X2X4 },
+            "int32x4x2_t" => quote! { &I32X4X2 },
+            "int32x4x3_t" => quote! { &I32X4X3 },
+            "int32x4x4_t" => quote! { &I32X4X4 },
             "int64x1_t" => quote! { &I64X1 },
             "int64x2_t" => quote! { &I64X2 },
+            "int64x1x2_t" => quote! { &I64X1X2 },
+            "int64x1x3_t" => quote! { &I64X1X3 },
+            "int64x1x4_t" => quote! { &I64X1X4 },
+            "int64x2x2_t" => quote! { &I64X2X2 },
+            "int64x2x3_t" => quote! { &I64X2X3 },
+            "int64x2x4_t" => quote! { &I64X2X4 },
             "uint8x8_t" => quote! { &U8X8 },
             "uint8x4_t" => quote! { &U8X4 },
             "uint8x8x2_t" => quote! { &U8X8X2 },
@@ -232,16 +284,65 @@ fn to_type(t: &syn::Type) -> proc_macro2::TokenStream {
             "uint8x8x3_t" => quote! { &U8X8X3 },
             "uint8x8x4_t" => quote! { &U8X8X4 },
             "uint8x16_t" => quote! { &U8X16 },
+            "uint16x2_t" => quote! { &U16X2 },
             "uint16x4_t" => quote! { &U16X4 },
             "uint16x8_t" => quote! { &U16X8 },
+            "uint16x4x2_t" => quote! { &U16X4X2 },
+            "uint16x4x3_t" => quote! { &U16X4X3 },
+            "uint16x4x4_t" => quote! { &U16X4X4 },
+            "uint16x8x2_t" => quote! { &U16X8X2 },
+            "uint16x8x3_t" => quote! { &U16X8X3 },
+            "uint16x8x4_t" => quote! { &U16X8X4 },
             "uint32x2_t" => quote! { &U32X2 },
             "uint32x4_t" => quote! { &U32X4 },
+            "uint32x2x2_t" => quote! { &U32X2X2 },
+            "uint32x2x3_t" => quote! { &U32X2X3 },
+            "uint32x2x4_t" => quote! { &U32X2X4 },
+            "uint32x4x2_t" => quote! { &U32X4X2 },
+            "uint32x4x3_t" => quote! { &U32X4X3 },
+            "uint32x4x4_t" => quote! { &U32X4X4 },
             "uint64x1_t" => quote! { &U64X1 },
             "uint64x2_t" => quote! { &U64X2 },
+            "uint64x1x2_t" => quote! { &U64X1X2 },
+            "uint64x1x3_t" => quote! { &U64X1X3 },
+            "uint64x1x4_t" => quote! { &U64X1X4 },
+            "uint64x2x2_t" => quote! { &U64X2X2 },
+            "uint64x2x3_t" => quote! { &U64X2X3 },
+            "uint64x2x4_t" => quote! { &U64X2X4 },
+            "float16x4_t" => quote! { &F16X4 },
+            "float16x8_t" => quote! { &F16X8 },
+            "float16x4x2_t" => quote! { &F16X4X2 },
+            "float16x4x3_t" => quote! { &F16X4X3 },
+            "float16x4x4_t" => quote! { &F16X4X4 },
+            "float16x8x2_t" => quote! { &F16X8X2 },
+            "float16x8x3_t" => quote! { &F16X8X3 },
+            "float16x8x4_t" => quote! { &F16X8X4 },
             "float32x2_t" => quote! { &F32X2 },
             "float32x4_t" => quote! { &F32X4 },
+            "float32x2x2_t" => quote! { &F32X2X2 },
+            "float32x2x3_t" => quote! { &F32X2X3 },
+            "float32x2x4_t" => quote! { &F32X2X4 },
+            "float32x4x2_t" => quote! { &F32X4X2 },
+            "float32x4x3_t" => quote! { &F32X4X3 },
+            "float32x4x4_t" => quote! { &F32X4X4 },
             "float64x1_t" => quote! { &F64X1 },
             "float64x2_t" => quote! { &F64X2 },
+            "float64x1x2_t" => quote! { &F64X1X2 },
+            "float64x1x3_t" => quote! { &F64X1X3 },
+            "float64x1x4_t" => quote! { &F64X1X4 },
+            "float64x2x2_t" => quote! { &F64X2X2 },
+            "float64x2x3_t" => quote! { &F64X2X3 },
+            "float64x2x4_t" => quote! { &F64X2X4 },
+            "bfloat16x4_t" => quote! { &BF16X4 },
+            "bfloat16x4x2_t" => quote! { &BF16X4X2 },
+            "bfloat16x4x3_t" => quote! { &BF16X4X3 },
+            "bfloat16x4x4_t" => quote! { &BF16X4X4 },
+            "bfloat16x8_t" => quote! { &BF16X8 },
+            "bfloat16x8x2_t" => quote! { &BF16X8X2 },
+            "bfloat16x8x3_t" => quote! { &BF16X8X3 },
+            "bfloat16x8x4_t" => quote! { &BF16X8X4 },
+            "mfloat8x8_t" => quote! { &MF8X8 },
+            "mfloat8x16_t" => quote! { &MF8X16 },
             "poly8x8_t" => quote! { &POLY8X8 },
             "poly8x8x2_t" => quote! { &POLY8X8X2 },
             "poly8x8x3_t" => quote! { &POLY8X8X3 },
@@ -252,10 +353,23 @@ fn to_type(t: &syn::Type) -> proc_macro2::TokenStream {
             "p64" => quote! { &P64 },
             "poly64x1_t" => quote! { &POLY64X1 },
             "poly64x2_t" => quote! { &POLY64X2 },
+            "poly64x1x2_t" => quote! { &P64X1X2 },
+            "poly64x1x3_t" => quote! { &P64X1X3 },
+            "poly64x1x4_t" => quote! { &P64X1X4 },
+            "poly64x2x2_t" => quote! { &P64X2X2 },
+            "poly64x2x3_t" => quote! { &P64X2X3 },
+            "poly64x2x4_t" => quote! { &P64X2X4 },
             "poly8x16_t" => quote! { &POLY8X16 },
             "poly16x4_t" => quote! { &POLY16X4 },
             "poly16x8_t" => quote! { &POLY16X8 },
+            "poly16x4x2_t" => quote! { &P16X4X2 },
+            "poly16x4x3_t" => quote! { &P16X4X3 },
+            "poly16x4x4_t" => quote! { &P16X4X4 },
+            "poly16x8x2_t" => quote! { &P16X8X2 },
+            "poly16x8x3_t" => quote! { &P16X8X3 },
+            "poly16x8x4_t" => quote! { &P16X8X4 },
             "p128" => quote! { &P128 },
+            "data512_t" => quote! { &DATA512 },
 
             "v16i8" => quote! { &v16i8 },
             "v8i16" => quote! { &v8i16 },
@@ -283,11 +397,11 @@ fn to_type(t: &syn::Type) -> proc_macro2::TokenStream {
         }) => {
             // Both pointers and references can have a mut token (*mut and &mut)
             if mutability.is_some() {
-                let tokens = to_type(&elem);
+                let tokens = to_type(&elem, type_params);
                 quote! { &Type::MutPtr(#tokens) }
             } else {
                 // If they don't (*const or &) then they are "const"
-                let tokens = to_type(&elem);
+                let tokens = to_type(&elem, type_params);
                 quote! { &Type::ConstPtr(#tokens) }
             }
         }
@@ -400,6 +514,15 @@ fn find_instrs(attrs: &[syn::Attribute]) -> Vec<String> {
         .collect()
 }
 
+fn has_pointer_argument(f: &syn::ItemFn) -> bool {
+    f.sig.inputs.iter().any(|input| match *input {
+        syn::FnArg::Typed(ref c) => {
+            matches!(*c.ty, syn::Type::Ptr(_) | syn::Type::Reference(_))
+        }
+        _ => false,
+    })
+}
+
 fn find_target_feature(attrs: &[syn::Attribute]) -> Option<syn::Lit> {
     attrs
         .iter()
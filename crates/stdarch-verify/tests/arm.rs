@@ -32,8 +32,21 @@ static U16: Type = Type::PrimUnsigned(16);
 static U32: Type = Type::PrimUnsigned(32);
 static U64: Type = Type::PrimUnsigned(64);
 static U8: Type = Type::PrimUnsigned(8);
+static BOOL: Type = Type::Bool;
 static NEVER: Type = Type::Never;
+static GENERIC: Type = Type::Generic;
+static DATA512: Type = Type::Data512;
 
+static BF16X4: Type = Type::BF(16, 4, 1);
+static BF16X4X2: Type = Type::BF(16, 4, 2);
+static BF16X4X3: Type = Type::BF(16, 4, 3);
+static BF16X4X4: Type = Type::BF(16, 4, 4);
+static BF16X8: Type = Type::BF(16, 8, 1);
+static BF16X8X2: Type = Type::BF(16, 8, 2);
+static BF16X8X3: Type = Type::BF(16, 8, 3);
+static BF16X8X4: Type = Type::BF(16, 8, 4);
+static MF8X8: Type = Type::MF(8, 8, 1);
+static MF8X16: Type = Type::MF(8, 16, 1);
 static F16X4: Type = Type::F(16, 4, 1);
 static F16X4X2: Type = Type::F(16, 4, 2);
 static F16X4X3: Type = Type::F(16, 4, 3);
@@ -120,6 +133,7 @@ static POLY8X8: Type = Type::P(8, 8, 1);
 static POLY8X8X2: Type = Type::P(8, 8, 2);
 static POLY8X8X3: Type = Type::P(8, 8, 3);
 static POLY8X8X4: Type = Type::P(8, 8, 4);
+static U16X2: Type = Type::U(16, 2, 1);
 static U16X4: Type = Type::U(16, 4, 1);
 static U16X4X2: Type = Type::U(16, 4, 2);
 static U16X4X3: Type = Type::U(16, 4, 3);
@@ -166,7 +180,12 @@ enum Type {
     U(u8, u8, u8),
     P(u8, u8, u8),
     F(u8, u8, u8),
+    BF(u8, u8, u8),
+    MF(u8, u8, u8),
+    Bool,
     Never,
+    Generic,
+    Data512,
 }
 
 stdarch_verify::arm_functions!(static FUNCTIONS);
@@ -249,6 +268,7 @@ fn verify_all_signatures() {
                 "vcombine_u32",
                 "vcombine_u64",
                 "vcombine_p64",
+                "vcombine_f16",
                 "vcombine_f32",
                 "vcombine_p8",
                 "vcombine_p16",
@@ -411,6 +431,47 @@ fn verify_all_signatures() {
                 "__smusdx",
                 "__usad8",
                 "__usada8",
+                "__uadd8",
+                "__uhadd8",
+                "__uhsub8",
+                "__uqadd8",
+                "__uqsub8",
+                "__shasx",
+                "__shsax",
+                "__ssax",
+                "__ssub16",
+                "__uadd16",
+                "__uasx",
+                "__uhadd16",
+                "__uhasx",
+                "__uhsax",
+                "__uhsub16",
+                "__uqadd16",
+                "__uqasx",
+                "__uqsax",
+                "__uqsub16",
+                "__usax",
+                "__usub16",
+                "__smladx",
+                "__smlsdx",
+                "__smlald",
+                "__smlaldx",
+                "__smlsld",
+                "__smlsldx",
+                "__sxtb16",
+                "__sxtab16",
+                "__uxtb16",
+                "__uxtab16",
+                "__ssat16",
+                "__usat16",
+                "__ssat",
+                "__usat",
+                "__arm_cdp",
+                "__arm_ldc",
+                "__arm_ldcl",
+                "__arm_stc",
+                "__arm_stcl",
+                "__arm_mcrr",
                 "vld1_s8",
                 "vld1q_s8",
                 "vld1q_s8",
@@ -500,6 +561,114 @@ fn verify_all_signatures() {
             "vqrdmlshs_lane_s32",
             "vqrdmlshs_laneq_s32",
             "__dbg",
+            // The complex arithmetic, int8 matrix multiply and BFloat16
+            // intrinsics are newer than the documentation we verify against.
+            "vcadd_rot90_f32",
+            "vcaddq_rot90_f32",
+            "vcaddq_rot90_f64",
+            "vcadd_rot270_f32",
+            "vcaddq_rot270_f32",
+            "vcaddq_rot270_f64",
+            "vcmla_f32",
+            "vcmlaq_f32",
+            "vcmlaq_f64",
+            "vcmla_rot90_f32",
+            "vcmlaq_rot90_f32",
+            "vcmlaq_rot90_f64",
+            "vcmla_rot180_f32",
+            "vcmlaq_rot180_f32",
+            "vcmlaq_rot180_f64",
+            "vcmla_rot270_f32",
+            "vcmlaq_rot270_f32",
+            "vcmlaq_rot270_f64",
+            "vcmla_lane_f32",
+            "vcmla_laneq_f32",
+            "vcmlaq_lane_f32",
+            "vcmlaq_laneq_f32",
+            "vcmla_rot90_lane_f32",
+            "vcmla_rot90_laneq_f32",
+            "vcmlaq_rot90_lane_f32",
+            "vcmlaq_rot90_laneq_f32",
+            "vcmla_rot180_lane_f32",
+            "vcmla_rot180_laneq_f32",
+            "vcmlaq_rot180_lane_f32",
+            "vcmlaq_rot180_laneq_f32",
+            "vcmla_rot270_lane_f32",
+            "vcmla_rot270_laneq_f32",
+            "vcmlaq_rot270_lane_f32",
+            "vcmlaq_rot270_laneq_f32",
+            "vmmlaq_s32",
+            "vmmlaq_u32",
+            "vusmmlaq_s32",
+            "vusdot_s32",
+            "vusdotq_s32",
+            "vusdot_lane_s32",
+            "vusdot_laneq_s32",
+            "vusdotq_lane_s32",
+            "vusdotq_laneq_s32",
+            "vsudot_lane_s32",
+            "vsudot_laneq_s32",
+            "vsudotq_lane_s32",
+            "vsudotq_laneq_s32",
+            "vcvt_bf16_f32",
+            "vcvtq_low_bf16_f32",
+            "vcvtq_high_bf16_f32",
+            "vbfdot_f32",
+            "vbfdotq_f32",
+            "vbfdot_lane_f32",
+            "vbfdot_laneq_f32",
+            "vbfdotq_lane_f32",
+            "vbfdotq_laneq_f32",
+            "vbfmmlaq_f32",
+            "vbfmlalbq_f32",
+            "vbfmlaltq_f32",
+            "veor3q_s8",
+            "veor3q_s16",
+            "veor3q_s32",
+            "veor3q_s64",
+            "veor3q_u8",
+            "veor3q_u16",
+            "veor3q_u32",
+            "veor3q_u64",
+            "vrax1q_u64",
+            "vxarq_u64",
+            "vbcaxq_s8",
+            "vbcaxq_s16",
+            "vbcaxq_s32",
+            "vbcaxq_s64",
+            "vbcaxq_u8",
+            "vbcaxq_u16",
+            "vbcaxq_u32",
+            "vbcaxq_u64",
+            "vsm3ss1q_u32",
+            "vsm3tt1aq_u32",
+            "vsm3tt1bq_u32",
+            "vsm3tt2aq_u32",
+            "vsm3tt2bq_u32",
+            "vsm3partw1q_u32",
+            "vsm3partw2q_u32",
+            "vsm4eq_u32",
+            "vsm4ekeyq_u32",
+            "vrnd32x_f32",
+            "vrnd32xq_f32",
+            "vrnd32x_f64",
+            "vrnd32xq_f64",
+            "vrnd32z_f32",
+            "vrnd32zq_f32",
+            "vrnd32z_f64",
+            "vrnd32zq_f64",
+            "vrnd64x_f32",
+            "vrnd64xq_f32",
+            "vrnd64x_f64",
+            "vrnd64xq_f64",
+            "vrnd64z_f32",
+            "vrnd64zq_f32",
+            "vrnd64z_f64",
+            "vrnd64zq_f64",
+            "vdot_laneq_s32",
+            "vdotq_laneq_s32",
+            "vdot_laneq_u32",
+            "vdotq_laneq_u32",
         ];
         let arm = match map.get(rust.name) {
             Some(i) => i,
@@ -511,12 +680,28 @@ fn verify_all_signatures() {
                 // reference for them, need to figure out where though!
                 if !rust.file.ends_with("dsp.rs\"")
                     && !rust.file.ends_with("simd32.rs\"")
+                    && !rust.file.ends_with("sat.rs\"")
+                    && !rust.file.ends_with("coproc.rs\"")
                     && !rust.file.ends_with("cmsis.rs\"")
                     && !rust.file.ends_with("v6.rs\"")
                     && !rust.file.ends_with("v7.rs\"")
                     && !rust.file.ends_with("v8.rs\"")
                     && !rust.file.ends_with("tme.rs\"")
+                    && !rust.file.ends_with("sve.rs\"")
+                    && !rust.file.ends_with("sme.rs\"")
+                    && !rust.file.ends_with("wfxt.rs\"")
+                    && !rust.file.ends_with("fp16.rs\"")
+                    && !rust.file.ends_with("rand.rs\"")
+                    && !rust.file.ends_with("mte.rs\"")
+                    && !rust.file.ends_with("ls64.rs\"")
+                    && !rust.file.ends_with("pauth.rs\"")
+                    && !rust.file.ends_with("frintts.rs\"")
+                    && !rust.file.ends_with("cache.rs\"")
                     && !rust.file.ends_with("ex.rs\"")
+                    && !rust.file.ends_with("lut.rs\"")
+                    && !rust.file.ends_with("faminmax.rs\"")
+                    && !rust.file.ends_with("fp8.rs\"")
+                    && !rust.file.ends_with("mve.rs\"")
                     && !skip_intrinsic_verify.contains(&rust.name)
                 {
                     println!(
@@ -549,7 +734,7 @@ fn matches(rust: &Function, arm: &Intrinsic) -> Result<(), String> {
     let mut nconst = 0;
     let iter = rust.arguments.iter().zip(&arm.arguments).enumerate();
     for (i, (rust_ty, (arm, arm_const))) in iter {
-        if *rust_ty != arm {
+        if *rust_ty != arm && !is_f16_bits(rust, rust_ty, arm) {
             bail!("mismatched arguments")
         }
         if *arm_const {
@@ -598,6 +783,18 @@ fn matches(rust: &Function, arm: &Intrinsic) -> Result<(), String> {
     Ok(())
 }
 
+// Rust doesn't have `f16`, so the pointers to `float16_t` are pointers to
+// its `u16` bit patterns in fp16.rs.
+fn is_f16_bits(rust: &Function, rust_ty: &Type, arm: &Type) -> bool {
+    rust.file.ends_with("fp16.rs\"")
+        && match (rust_ty, arm) {
+            (Type::ConstPtr(r), Type::ConstPtr(a)) | (Type::MutPtr(r), Type::MutPtr(a)) => {
+                **r == U16 && **a == F16
+            }
+            _ => false,
+        }
+}
+
 fn find_accordion(node: &Rc<Node>) -> Option<Rc<Node>> {
     if let NodeData::Element { attrs, .. } = &node.data {
         for attr in attrs.borrow().iter() {
@@ -746,6 +943,14 @@ fn parse_ty(s: &str) -> Type {
 
 fn parse_ty_base(s: &str) -> &'static Type {
     match s {
+        "bfloat16x4_t" => &BF16X4,
+        "bfloat16x4x2_t" => &BF16X4X2,
+        "bfloat16x4x3_t" => &BF16X4X3,
+        "bfloat16x4x4_t" => &BF16X4X4,
+        "bfloat16x8_t" => &BF16X8,
+        "bfloat16x8x2_t" => &BF16X8X2,
+        "bfloat16x8x3_t" => &BF16X8X3,
+        "bfloat16x8x4_t" => &BF16X8X4,
         "float16_t" => &F16,
         "float16x4_t" => &F16X4,
         "float16x4x2_t" => &F16X4X2,
@@ -840,6 +1045,7 @@ fn parse_ty_base(s: &str) -> &'static Type {
         "poly8x8x3_t" => &POLY8X8X3,
         "poly8x8x4_t" => &POLY8X8X4,
         "uint16_t" => &U16,
+        "uint16x2_t" => &U16X2,
         "uint16x4_t" => &U16X4,
         "uint16x4x2_t" => &U16X4X2,
         "uint16x4x3_t" => &U16X4X3,
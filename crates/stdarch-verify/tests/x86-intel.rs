@@ -41,6 +41,8 @@ static U16: Type = Type::PrimUnsigned(16);
 static U32: Type = Type::PrimUnsigned(32);
 static U64: Type = Type::PrimUnsigned(64);
 static U128: Type = Type::PrimUnsigned(128);
+static USIZE: Type = Type::Usize;
+static BOOL: Type = Type::Bool;
 static ORDERING: Type = Type::Ordering;
 
 static M64: Type = Type::M64;
@@ -48,14 +50,17 @@ static M128: Type = Type::M128;
 static M128BH: Type = Type::M128BH;
 static M128I: Type = Type::M128I;
 static M128D: Type = Type::M128D;
+static M128H: Type = Type::M128H;
 static M256: Type = Type::M256;
 static M256BH: Type = Type::M256BH;
 static M256I: Type = Type::M256I;
 static M256D: Type = Type::M256D;
+static M256H: Type = Type::M256H;
 static M512: Type = Type::M512;
 static M512BH: Type = Type::M512BH;
 static M512I: Type = Type::M512I;
 static M512D: Type = Type::M512D;
+static M512H: Type = Type::M512H;
 static MMASK8: Type = Type::MMASK8;
 static MMASK16: Type = Type::MMASK16;
 static MMASK32: Type = Type::MMASK32;
@@ -67,6 +72,7 @@ static MM_PERM_ENUM: Type = Type::MM_PERM_ENUM;
 
 static TUPLE: Type = Type::Tuple;
 static CPUID: Type = Type::CpuidResult;
+static SGX: Type = Type::SgxResult;
 static NEVER: Type = Type::Never;
 
 #[derive(Debug)]
@@ -74,20 +80,25 @@ enum Type {
     PrimFloat(u8),
     PrimSigned(u8),
     PrimUnsigned(u8),
+    Usize,
+    Bool,
     MutPtr(&'static Type),
     ConstPtr(&'static Type),
     M64,
     M128,
     M128BH,
     M128D,
+    M128H,
     M128I,
     M256,
     M256BH,
     M256D,
+    M256H,
     M256I,
     M512,
     M512BH,
     M512D,
+    M512H,
     M512I,
     MMASK8,
     MMASK16,
@@ -99,6 +110,7 @@ enum Type {
     MM_PERM_ENUM,
     Tuple,
     CpuidResult,
+    SgxResult,
     Never,
     Ordering,
 }
@@ -338,6 +350,23 @@ fn verify_all_signatures() {
             // https://github.com/rust-lang/rfcs/issues/2512
             "ud2"
                 => continue,
+            // Intel lists this as `_rdpmc`, we use the name GCC and Clang
+            // use, matching `__rdtscp`
+            "__rdpmc" => continue,
+            // Not defined by Intel, these only change the named MXCSR bits
+            "_mm_get_flush_zero_mode"
+            | "_mm_set_flush_zero_mode"
+            | "_mm_get_denormals_zero_mode"
+            | "_mm_set_denormals_zero_mode"
+            | "_mm_get_rounding_mode"
+            | "_mm_set_rounding_mode" => continue,
+            // Intel passes RBX, RCX and RDX through a `size_t` array that is
+            // updated in place, we take them by value and return them
+            // together with EAX in an `SgxResult` instead
+            "_encls_u32" | "_enclu_u32" | "_enclv_u32" => continue,
+            // Intel takes the leaf as a `const int` that doesn't have to be
+            // an immediate, we take it as a `u32` like the SGX leaves
+            "_pconfig_u32" => continue,
             // Intel requires the mask argument for _mm_shuffle_ps to be an
             // unsigned integer, but all other _mm_shuffle_.. intrinsics
             // take a signed-integer. This breaks `_MM_SHUFFLE` for
@@ -353,6 +382,48 @@ fn verify_all_signatures() {
             }
         }
 
+        // these were added to Intel's intrinsics guide after the version of
+        // `x86-intel.xml` used here, so there's nothing to verify them against
+        let newer_features = [
+            "avx512fp16",
+            "avxvnni",
+            "avxifma",
+            "avxvnniint8",
+            "avxvnniint16",
+            "avxneconvert",
+            "sha512",
+            "sm3",
+            "sm4",
+            "enqcmd",
+            "uintr",
+            "cldemote",
+            "mwaitx",
+            "clzero",
+            "kl",
+            "widekl",
+            "hreset",
+            "prefetchi",
+            "cmpccxadd",
+            "raoint",
+            "usermsr",
+            "prfchw",
+        ];
+        if let Some(feature) = rust.target_feature {
+            if newer_features.iter().any(|f| feature.contains(f)) {
+                continue;
+            }
+        }
+        // the intrinsics for target features that rustc doesn't know have no
+        // `#[target_feature]` attribute, but live in a module named after the
+        // feature
+        if rust.target_feature.is_none()
+            && newer_features
+                .iter()
+                .any(|f| rust.file.contains(&format!("/{}.rs", f)))
+        {
+            continue;
+        }
+
         let intel = match map.remove(rust.name) {
             Some(i) => i,
             None => panic!("missing intel definition for {}", rust.name),
@@ -443,6 +514,16 @@ fn matches(rust: &Function, intel: &Intrinsic) -> Result<(), String> {
         | "_bittestandreset64"
         | "_bittestandcomplement64" => {}
 
+        // The XML file doesn't list a CPUID for PTWRITE, and rustc doesn't
+        // know the "ptwrite" target feature.
+        "_ptwrite32" | "_ptwrite64" => {}
+
+        // The XML file doesn't list a CPUID for the scalar F16C conversions,
+        // which Rust gates behind "f16c" like the packed ones.
+        "_cvtsh_ss" | "_cvtss_sh" => {}
+
+        "_bit_scan_forward" | "_bit_scan_reverse" | "_rotwl" | "_rotwr" | "_lrotl" | "_lrotr" => {}
+
         _ => {
             if intel.cpuid.is_empty() {
                 bail!("missing cpuid for {}", rust.name);
@@ -471,6 +552,19 @@ fn matches(rust: &Function, intel: &Intrinsic) -> Result<(), String> {
             continue;
         }
 
+        // The XML file lists some intrinsics as requiring AVX512VL even
+        // though Intel's architecture manuals also define SSE or VEX
+        // encodings of them that don't: the unmasked 128-bit and 256-bit
+        // GFNI intrinsics, and the 256-bit VAES and VPCLMULQDQ intrinsics.
+        if *cpuid == "AVX512VL" {
+            let gfni = rust.name.contains("gf2p8") && !rust.name.contains("mask");
+            let vaes = rust.name.starts_with("_mm256_aes");
+            let vpclmulqdq = rust.name == "_mm256_clmulepi64_epi128";
+            if gfni || vaes || vpclmulqdq {
+                continue;
+            }
+        }
+
         let cpuid = cpuid
             .chars()
             .flat_map(|c| c.to_lowercase())
@@ -504,10 +598,48 @@ fn matches(rust: &Function, intel: &Intrinsic) -> Result<(), String> {
             "avx512_bf16" => String::from("avx512bf16"),
             // The XML file names VNNI as "avx512_bf16", while Rust calls
             // it "avx512bf16".
+            "avx512_vp2intersect" => String::from("avx512vp2intersect"),
+            // The XML file names VP2INTERSECT as "avx512_vp2intersect", while
+            // Rust calls it "avx512vp2intersect".
+            // The XML file names AMX-TILE as "amxtile", while Rust calls it
+            // "amx-tile".
+            "amxtile" => String::from("amx-tile"),
+            // The XML file names AMX-INT8 as "amxint8", while Rust calls it
+            // "amx-int8".
+            "amxint8" => String::from("amx-int8"),
+            // The XML file names CET shadow stacks as "cet_ss", while Rust
+            // calls them "shstk".
+            "cet_ss" => String::from("shstk"),
             _ => cpuid,
         };
         let fixed_cpuid = fixup_cpuid(cpuid);
 
+        // rustc doesn't know these target features, so the intrinsics that
+        // need them are written in inline assembly without a
+        // `#[target_feature]` attribute. The same goes for some of the
+        // `newer_features` above. Callers have to check for the feature with
+        // `is_x86_feature_detected!` themselves, and so do the tests of these
+        // intrinsics, which can't be `simd_test`s because those enable the
+        // target feature.
+        let unknown_to_rustc = [
+            "movdiri",
+            "movdir64b",
+            "enqcmd",
+            "waitpkg",
+            "serialize",
+            "tsxldtrk",
+            "rdpid",
+            "fsgsbase",
+            "invpcid",
+            "clflushopt",
+            "clwb",
+            "wbnoinvd",
+            "shstk",
+        ];
+        if unknown_to_rustc.contains(&fixed_cpuid.as_str()) {
+            continue;
+        }
+
         let rust_feature = rust
             .target_feature
             .unwrap_or_else(|| panic!("no target feature listed for {}", rust.name));
@@ -589,6 +721,10 @@ fn matches(rust: &Function, intel: &Intrinsic) -> Result<(), String> {
         "_xsave" | "_xrstor" | "_xsetbv" | "_xgetbv" | "_xsaveopt" | "_xsavec" | "_xsaves"
         | "_xrstors" => true,
 
+        // The TSC deadline is split into EDX:EAX, which works the same on
+        // 32-bit and 64-bit targets.
+        "_umwait" | "_tpause" => true,
+
         // Apparently all of clang/msvc/gcc accept these intrinsics on
         // 32-bit, so let's do the same
         "_mm_set_epi64x"
@@ -657,7 +793,7 @@ fn matches(rust: &Function, intel: &Intrinsic) -> Result<(), String> {
         // These return a 64-bit argument but they're assembled from other
         // 32-bit registers, so these work on 32-bit just fine. See #308 for
         // more info.
-        "_rdtsc" | "__rdtscp" => true,
+        "_rdtsc" | "__rdtscp" | "__rdpmc" => true,
 
         _ => false,
     };
@@ -689,10 +825,13 @@ fn equate(
         intel = intel.replace("const ", "");
         intel = intel.replace("*", " const*");
     }
-    if etype == "IMM" {
-        // The _bittest intrinsics claim to only accept immediates but actually
-        // accept run-time values as well.
-        if !is_const && !intrinsic.starts_with("_bittest") {
+    // Tiles are named by compile-time constants, which the Rust intrinsics
+    // take as const generics.
+    if etype == "IMM" || intel == "__tile" {
+        // The _bittest and rotate intrinsics claim to only accept immediates
+        // but actually accept run-time values as well.
+        let runtime = ["_bittest", "_rotw", "_lrot"];
+        if !is_const && !runtime.iter().any(|p| intrinsic.starts_with(p)) {
             return bail!("argument required to be const but isn't");
         }
     } else {
@@ -716,22 +855,31 @@ fn equate(
         (&Type::PrimSigned(8), "char") => {}
         (&Type::PrimUnsigned(16), "unsigned short") => {}
         (&Type::PrimUnsigned(32), "unsigned int") => {}
+        // `unsigned long` is 64 bits wide on x86_64 with GCC and Clang.
+        (&Type::PrimUnsigned(64), "unsigned long") if intrinsic.starts_with("_lrot") => {}
         (&Type::PrimUnsigned(32), "const unsigned int") => {}
         (&Type::PrimUnsigned(64), "unsigned __int64") => {}
         (&Type::PrimUnsigned(8), "unsigned char") => {}
+        (&Type::PrimSigned(32), "__tile") => {}
+        // The AMX intrinsics take the stride as an `int`, but the C
+        // implementations cast it to a `size_t` before using it.
+        (&Type::Usize, "int") if intrinsic.starts_with("_tile_") => {}
         (&Type::M64, "__m64") => {}
         (&Type::M128, "__m128") => {}
         (&Type::M128BH, "__m128bh") => {}
         (&Type::M128I, "__m128i") => {}
         (&Type::M128D, "__m128d") => {}
+        (&Type::M128H, "__m128h") => {}
         (&Type::M256, "__m256") => {}
         (&Type::M256BH, "__m256bh") => {}
         (&Type::M256I, "__m256i") => {}
         (&Type::M256D, "__m256d") => {}
+        (&Type::M256H, "__m256h") => {}
         (&Type::M512, "__m512") => {}
         (&Type::M512BH, "__m512bh") => {}
         (&Type::M512I, "__m512i") => {}
         (&Type::M512D, "__m512d") => {}
+        (&Type::M512H, "__m512h") => {}
         (&Type::MMASK64, "__mmask64") => {}
         (&Type::MMASK32, "__mmask32") => {}
         (&Type::MMASK16, "__mmask16") => {}
@@ -749,11 +897,16 @@ fn equate(
         (&Type::MutPtr(&Type::PrimSigned(64)), "void*") => {}
         (&Type::MutPtr(&Type::PrimSigned(64)), "__int64*") => {}
         (&Type::MutPtr(&Type::PrimSigned(8)), "char*") => {}
+        (&Type::MutPtr(&Type::PrimUnsigned(8)), "unsigned char*") => {}
         (&Type::MutPtr(&Type::PrimUnsigned(16)), "unsigned short*") => {}
         (&Type::MutPtr(&Type::PrimUnsigned(32)), "unsigned int*") => {}
         (&Type::MutPtr(&Type::PrimUnsigned(64)), "unsigned __int64*") => {}
+        (&Type::MutPtr(&Type::PrimUnsigned(32)), "void*") => {}
+        (&Type::MutPtr(&Type::PrimUnsigned(64)), "void*") => {}
         (&Type::MutPtr(&Type::PrimUnsigned(8)), "void*") => {}
         (&Type::MutPtr(&Type::PrimUnsigned(32)), "__mmask32*") => {}
+        (&Type::MutPtr(&Type::MMASK8), "__mmask8*") => {}
+        (&Type::MutPtr(&Type::MMASK16), "__mmask16*") => {}
         (&Type::MutPtr(&Type::PrimUnsigned(64)), "__mmask64*") => {}
         (&Type::MutPtr(&Type::M64), "__m64*") => {}
         (&Type::MutPtr(&Type::M128), "__m128*") => {}
@@ -801,6 +954,8 @@ fn equate(
         (&Type::ConstPtr(&Type::M512D), "__m512d const*") => {}
         (&Type::ConstPtr(&Type::PrimUnsigned(32)), "__mmask32*") => {}
         (&Type::ConstPtr(&Type::PrimUnsigned(64)), "__mmask64*") => {}
+        (&Type::ConstPtr(&Type::MMASK8), "__mmask8*") => {}
+        (&Type::ConstPtr(&Type::MMASK16), "__mmask16*") => {}
 
         (&Type::MM_CMPINT_ENUM, "_MM_CMPINT_ENUM") => {}
         (&Type::MM_MANTISSA_NORM_ENUM, "_MM_MANTISSA_NORM_ENUM") => {}
@@ -817,18 +972,26 @@ fn equate(
         // We have manually fixed the bug by changing the return type to `u64`.
         (&Type::PrimUnsigned(64), "__int64") if intrinsic == "_rdtsc" => {}
 
+        // MOVNTDQA only reads from memory, but the intrinsics guide declares
+        // `_mm_stream_load_si128` with a mutable pointer, and
+        // `_mm512_stream_load_si512` with an untyped one.
+        (&Type::ConstPtr(&Type::M128I), "__m128i*") if intrinsic == "_mm_stream_load_si128" => {}
+        (&Type::ConstPtr(&Type::M512I), "void const*")
+            if intrinsic == "_mm512_stream_load_si512" => {}
+
         // The _bittest and _bittest64 intrinsics takes a mutable pointer in the
         // intrinsics guide even though it never writes through the pointer:
         (&Type::ConstPtr(&Type::PrimSigned(32)), "__int32*") if intrinsic == "_bittest" => {}
         (&Type::ConstPtr(&Type::PrimSigned(64)), "__int64*") if intrinsic == "_bittest64" => {}
-        // The _xrstor, _fxrstor, _xrstor64, _fxrstor64 intrinsics take a
-        // mutable pointer in the intrinsics guide even though they never write
-        // through the pointer:
+        // The _xrstor, _fxrstor, _xrstor64, _fxrstor64 and _invpcid intrinsics
+        // take a mutable pointer in the intrinsics guide even though they never
+        // write through the pointer:
         (&Type::ConstPtr(&Type::PrimUnsigned(8)), "void*")
             if intrinsic == "_xrstor"
                 || intrinsic == "_xrstor64"
                 || intrinsic == "_fxrstor"
-                || intrinsic == "_fxrstor64" => {}
+                || intrinsic == "_fxrstor64"
+                || intrinsic == "_invpcid" => {}
 
         _ => bail!(
             "failed to equate: `{}` and {:?} for {}",